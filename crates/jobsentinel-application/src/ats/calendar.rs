@@ -0,0 +1,16 @@
+//! Interview calendar feed
+
+use chrono::{DateTime, Utc};
+
+use super::InterviewWithJob;
+use crate::notify::interview_calendar_event;
+
+/// File name of the subscribable interview calendar in the data directory.
+pub const INTERVIEW_CALENDAR_FILE: &str = "interviews.ics";
+
+/// Every interview in `interviews` as one iCalendar feed
+#[must_use]
+pub fn interview_calendar_feed(interviews: &[InterviewWithJob], now: DateTime<Utc>) -> String {
+    let events: Vec<_> = interviews.iter().map(interview_calendar_event).collect();
+    jobsentinel_notifications::format_interview_calendar(&events, now)
+}
@@ -0,0 +1,143 @@
+//! Cover letter drafts
+//!
+//! A draft fills a cover letter template's placeholders from a job and a
+//! resume. Skills come from the resume, best match for the job first, and
+//! years of experience from the longest experience recorded for a resume
+//! skill. Anything the resume does not say stays as a bracketed blank.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::followup_draft::{fill_placeholders, long_date};
+use super::ApplicationAttachment;
+use crate::user_data::CoverLetterTemplate;
+
+/// Job and resume details a cover letter is filled from
+#[derive(Debug, Clone, Copy)]
+pub struct CoverLetterContext<'a> {
+    pub job_title: &'a str,
+    pub company: &'a str,
+    pub location: Option<&'a str>,
+    /// Resume skills, most relevant to the job first
+    pub skills: &'a [String],
+    /// Longest experience with any resume skill, in years
+    pub years_experience: Option<f64>,
+    /// Name from the application profile
+    pub your_name: Option<&'a str>,
+}
+
+/// A filled-in cover letter for the user to review
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverLetterDraft {
+    pub template_id: String,
+    pub template_name: String,
+    pub body: String,
+    /// Set when the draft was saved to an application
+    pub attachment: Option<ApplicationAttachment>,
+}
+
+/// Fill `template` for the job and resume in `context`, dated `today`
+#[must_use]
+pub fn cover_letter_draft(
+    template: &CoverLetterTemplate,
+    context: &CoverLetterContext<'_>,
+    today: NaiveDate,
+) -> CoverLetterDraft {
+    let skill =
+        |index: usize, blank: &'static str| context.skills.get(index).map_or(blank, String::as_str);
+    let years_experience = context
+        .years_experience
+        .filter(|years| *years >= 1.0)
+        .map_or_else(|| "[X]".to_string(), |years| format!("{years:.0}"));
+    let your_name = context
+        .your_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("[Your Name]");
+    let date = long_date(today);
+
+    let placeholders = [
+        ("{company}", context.company),
+        ("{position}", context.job_title),
+        ("{location}", context.location.unwrap_or("Remote")),
+        ("{hiring_manager}", "Hiring Manager"),
+        ("{your_name}", your_name),
+        ("{date}", date.as_str()),
+        ("{skill1}", skill(0, "[Your Primary Skill]")),
+        ("{skill2}", skill(1, "[Your Secondary Skill]")),
+        ("{years_experience}", years_experience.as_str()),
+    ];
+
+    CoverLetterDraft {
+        template_id: template.id.clone(),
+        template_name: template.name.clone(),
+        body: fill_placeholders(&template.content, &placeholders),
+        attachment: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_data::TemplateCategory;
+
+    fn template(content: &str) -> CoverLetterTemplate {
+        CoverLetterTemplate {
+            id: "template-1".to_string(),
+            name: "Healthcare".to_string(),
+            content: content.to_string(),
+            category: TemplateCategory::Healthcare,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn cover_letters_fill_job_and_resume_fields() {
+        let skills = ["Case Management".to_string(), "Epic".to_string()];
+        let context = CoverLetterContext {
+            job_title: "Case Manager",
+            company: "CommunityCare",
+            location: Some("Denver, CO"),
+            skills: &skills,
+            years_experience: Some(6.4),
+            your_name: Some("Alex Kim"),
+        };
+
+        let draft = cover_letter_draft(
+            &template("{date}\nDear {hiring_manager}, I want to join {company} as {position} in {location}. I bring {years_experience} years of {skill1} and {skill2}.\n{your_name}"),
+            &context,
+            NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(),
+        );
+
+        assert_eq!(
+            draft.body,
+            "October 15, 2026\nDear Hiring Manager, I want to join CommunityCare as Case Manager in Denver, CO. I bring 6 years of Case Management and Epic.\nAlex Kim"
+        );
+        assert_eq!(draft.template_id, "template-1");
+        assert!(draft.attachment.is_none());
+    }
+
+    #[test]
+    fn missing_resume_details_stay_as_blanks() {
+        let context = CoverLetterContext {
+            job_title: "Case Manager",
+            company: "CommunityCare",
+            location: None,
+            skills: &[],
+            years_experience: Some(0.5),
+            your_name: Some("  "),
+        };
+
+        let draft = cover_letter_draft(
+            &template("{location}: {skill1}, {skill2}, {years_experience}, {your_name}"),
+            &context,
+            NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(),
+        );
+
+        assert_eq!(
+            draft.body,
+            "Remote: [Your Primary Skill], [Your Secondary Skill], [X], [Your Name]"
+        );
+    }
+}
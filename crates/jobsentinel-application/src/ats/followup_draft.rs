@@ -0,0 +1,262 @@
+//! Follow-up and thank-you email drafts
+//!
+//! Drafts are filled from the user's email templates with the same
+//! placeholders as cover letters. Anything JobSentinel does not know, such as
+//! skills, stays as a bracketed blank for the user to fill in before sending.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Contact, InterviewWithJob};
+use crate::user_data::{CoverLetterTemplate, TemplateCategory};
+
+/// Which email to draft
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FollowUpDraftKind {
+    ThankYou,
+    FollowUp,
+    Withdrawal,
+}
+
+impl FollowUpDraftKind {
+    /// Template category drafts of this kind are written from
+    #[must_use]
+    pub const fn template_category(self) -> TemplateCategory {
+        match self {
+            Self::ThankYou => TemplateCategory::ThankYou,
+            Self::FollowUp => TemplateCategory::FollowUp,
+            Self::Withdrawal => TemplateCategory::Withdrawal,
+        }
+    }
+
+    fn subject(self, position: &str) -> String {
+        match self {
+            Self::ThankYou => format!("Thank you - {position} interview"),
+            Self::FollowUp => format!("Following up on my {position} application"),
+            Self::Withdrawal => format!("Withdrawing my {position} application"),
+        }
+    }
+}
+
+/// Application records a draft is filled from
+#[derive(Debug, Clone, Copy)]
+pub struct FollowUpDraftContext<'a> {
+    pub job_title: &'a str,
+    pub company: &'a str,
+    pub location: Option<&'a str>,
+    pub applied_at: Option<DateTime<Utc>>,
+    /// Person the email is addressed to
+    pub contact: Option<&'a Contact>,
+    /// Interview the email refers to
+    pub interview: Option<&'a InterviewWithJob>,
+    /// Name from the application profile
+    pub your_name: Option<&'a str>,
+}
+
+/// A filled-in email for the user to copy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowUpDraft {
+    pub template_id: String,
+    pub template_name: String,
+    /// Contact email address, when known
+    pub to: Option<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+/// The most recent interview that has already started, if any
+#[must_use]
+pub fn latest_past_interview(
+    interviews: &[InterviewWithJob],
+    now: DateTime<Utc>,
+) -> Option<&InterviewWithJob> {
+    let today = now.date_naive();
+    interviews
+        .iter()
+        .filter(|interview| interview_date(interview).is_some_and(|date| date <= today))
+        .max_by(|a, b| a.scheduled_at.cmp(&b.scheduled_at))
+}
+
+fn interview_date(interview: &InterviewWithJob) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(interview.scheduled_at.get(..10)?, "%Y-%m-%d").ok()
+}
+
+pub(super) fn long_date(date: NaiveDate) -> String {
+    date.format("%B %-d, %Y").to_string()
+}
+
+/// Replace every `{placeholder}` in `content` with its value
+pub(super) fn fill_placeholders(content: &str, placeholders: &[(&str, &str)]) -> String {
+    placeholders
+        .iter()
+        .fold(content.to_string(), |body, (placeholder, value)| {
+            body.replace(placeholder, value)
+        })
+}
+
+/// Fill `template` for the application in `context`
+///
+/// `{date}` is the interview date when there is an interview, otherwise the
+/// date the application was sent.
+#[must_use]
+pub fn followup_draft(
+    kind: FollowUpDraftKind,
+    template: &CoverLetterTemplate,
+    context: &FollowUpDraftContext<'_>,
+) -> FollowUpDraft {
+    let hiring_manager = context
+        .contact
+        .map(|contact| contact.name.clone())
+        .or_else(|| context.interview?.interviewer_name.clone())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "Hiring Manager".to_string());
+    let date = context
+        .interview
+        .and_then(interview_date)
+        .or_else(|| context.applied_at.map(|applied_at| applied_at.date_naive()))
+        .map_or_else(|| "[Date]".to_string(), long_date);
+    let your_name = context
+        .your_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("[Your Name]");
+
+    let placeholders = [
+        ("{company}", context.company),
+        ("{position}", context.job_title),
+        ("{location}", context.location.unwrap_or("Remote")),
+        ("{hiring_manager}", hiring_manager.as_str()),
+        ("{your_name}", your_name),
+        ("{date}", date.as_str()),
+        ("{skill1}", "[Your Primary Skill]"),
+        ("{skill2}", "[Your Secondary Skill]"),
+        ("{years_experience}", "[X]"),
+    ];
+    let body = fill_placeholders(&template.content, &placeholders);
+
+    FollowUpDraft {
+        template_id: template.id.clone(),
+        template_name: template.name.clone(),
+        to: context.contact.and_then(|contact| contact.email.clone()),
+        subject: kind.subject(context.job_title),
+        body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn template(content: &str) -> CoverLetterTemplate {
+        CoverLetterTemplate {
+            id: "template-1".to_string(),
+            name: "Thank You - Post Interview".to_string(),
+            content: content.to_string(),
+            category: TemplateCategory::ThankYou,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn interview(scheduled_at: &str, interviewer_name: Option<&str>) -> InterviewWithJob {
+        InterviewWithJob {
+            id: 1,
+            application_id: 1,
+            interview_type: "phone_interview".to_string(),
+            scheduled_at: scheduled_at.to_string(),
+            duration_minutes: 30,
+            location: None,
+            interviewer_name: interviewer_name.map(str::to_string),
+            interviewer_title: None,
+            notes: None,
+            completed: false,
+            outcome: None,
+            post_interview_notes: None,
+            job_title: "Case Manager".to_string(),
+            company: "CommunityCare".to_string(),
+        }
+    }
+
+    #[test]
+    fn drafts_fill_placeholders_from_the_application() {
+        let interview = interview("2026-02-03T10:00", Some("Sam Rivera"));
+        let context = FollowUpDraftContext {
+            job_title: "Case Manager",
+            company: "CommunityCare",
+            location: None,
+            applied_at: None,
+            contact: None,
+            interview: Some(&interview),
+            your_name: Some("Alex Kim"),
+        };
+
+        let draft = followup_draft(
+            FollowUpDraftKind::ThankYou,
+            &template("Dear {hiring_manager}, thanks for {date} about {position} at {company} ({location}). I use {skill1}. {unknown}\n{your_name}"),
+            &context,
+        );
+
+        assert_eq!(draft.subject, "Thank you - Case Manager interview");
+        assert_eq!(
+            draft.body,
+            "Dear Sam Rivera, thanks for February 3, 2026 about Case Manager at CommunityCare (Remote). I use [Your Primary Skill]. {unknown}\nAlex Kim"
+        );
+        assert_eq!(draft.to, None);
+    }
+
+    #[test]
+    fn follow_ups_without_an_interview_use_the_applied_date_and_contact() {
+        let contact = Contact {
+            id: 1,
+            name: "Jordan Lee".to_string(),
+            email: Some("jordan.lee@communitycare.org".to_string()),
+            phone: None,
+            linkedin_url: None,
+            company: None,
+            notes: None,
+            last_contacted_at: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let context = FollowUpDraftContext {
+            job_title: "Case Manager",
+            company: "CommunityCare",
+            location: Some("Denver, CO"),
+            applied_at: Some(Utc.with_ymd_and_hms(2026, 1, 12, 15, 0, 0).unwrap()),
+            contact: Some(&contact),
+            interview: None,
+            your_name: None,
+        };
+
+        let draft = followup_draft(
+            FollowUpDraftKind::FollowUp,
+            &template("Dear {hiring_manager}, I applied on {date}. {your_name}"),
+            &context,
+        );
+
+        assert_eq!(
+            draft.body,
+            "Dear Jordan Lee, I applied on January 12, 2026. [Your Name]"
+        );
+        assert_eq!(draft.to.as_deref(), Some("jordan.lee@communitycare.org"));
+    }
+
+    #[test]
+    fn latest_past_interview_skips_future_interviews() {
+        let now = Utc.with_ymd_and_hms(2026, 2, 10, 12, 0, 0).unwrap();
+        let interviews = [
+            interview("2026-02-01T10:00", None),
+            interview("2026-02-09T10:00:00Z", None),
+            interview("2026-02-20T10:00", None),
+        ];
+
+        assert_eq!(
+            latest_past_interview(&interviews, now)
+                .map(|interview| interview.scheduled_at.as_str()),
+            Some("2026-02-09T10:00:00Z")
+        );
+        assert!(latest_past_interview(&interviews[2..], now).is_none());
+    }
+}
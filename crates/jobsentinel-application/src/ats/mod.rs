@@ -1,3 +1,12 @@
 //! Application-tracking storage facade.
 
+mod calendar;
+mod cover_letter_draft;
+mod followup_draft;
+
+pub use calendar::{interview_calendar_feed, INTERVIEW_CALENDAR_FILE};
+pub use cover_letter_draft::{cover_letter_draft, CoverLetterContext, CoverLetterDraft};
+pub use followup_draft::{
+    followup_draft, latest_past_interview, FollowUpDraft, FollowUpDraftContext, FollowUpDraftKind,
+};
 pub use jobsentinel_storage::application_tracking::*;
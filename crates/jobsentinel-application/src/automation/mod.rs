@@ -1,11 +1,13 @@
 //! Candidate-controlled application-assistance facade.
 
 pub use jobsentinel_assistance::{
-    ApplicationAttempt, ApplicationProfile, ApplicationProfileInput, AtsDetector, AtsPlatform,
-    AutomationError, AutomationPage, AutomationResult, AutomationStats, AutomationStatus,
-    BrowserManager, FillResult, FormFiller, ScreeningAnswer,
+    ApplicationAttempt, ApplicationProfile, ApplicationProfileInput, ApplicationProfileSummary,
+    AtsDetector, AtsPlatform, AttemptFillSummary, AutomationError, AutomationPage, AutomationQuota,
+    AutomationResult, AutomationStats, AutomationStatus, BrowserManager, FillResult, FormFiller,
+    ScreeningAnswer,
 };
+pub use jobsentinel_domain::AutomationPermission;
 pub use jobsentinel_storage::automation::{
     AnswerLearningManager, AnswerSource, AnswerStatistics, AnswerSuggestion, AutomationManager,
-    ModificationExample, ProfileManager,
+    AutomationPermissionManager, ModificationExample, ProfileManager,
 };
@@ -0,0 +1,221 @@
+//! Configuration backups and corruption recovery
+//!
+//! Every save copies the config it replaces into `config.json.bak.1`, shifting
+//! older copies up to [`CONFIG_BACKUP_COUNT`]. When the config no longer loads
+//! at startup, the newest backup that does is restored and the broken file is
+//! kept as `config.json.corrupt` for inspection.
+
+use super::io::write_file_atomic_private;
+use super::types::Config;
+use super::validation::validate_config;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Previous configs kept next to the live file.
+const CONFIG_BACKUP_COUNT: usize = 5;
+
+/// What happened when a broken config was replaced by a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigRecovery {
+    /// Why the saved config could not be loaded
+    pub reason: String,
+    /// File name of the backup that was restored
+    pub restored_from: String,
+    /// File name the broken config was moved to, when it could be kept
+    pub corrupt_copy: Option<String>,
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+fn backup_path(path: &Path, index: usize) -> PathBuf {
+    sibling_path(path, &format!(".bak.{index}"))
+}
+
+fn file_label(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn parse_config(content: &str) -> Result<Config, Box<dyn std::error::Error>> {
+    let config: Config = serde_json::from_str(content)?;
+    validate_config(&config)?;
+    Ok(config)
+}
+
+/// Copy the config at `path` into the newest backup slot before it is
+/// replaced with `replacement`.
+///
+/// Only configs that still load are kept, and saves that change nothing do
+/// not push out older backups.
+pub(super) fn backup_config(path: &Path, replacement: &str) -> std::io::Result<()> {
+    let Ok(current) = std::fs::read_to_string(path) else {
+        return Ok(());
+    };
+    if current == replacement {
+        return Ok(());
+    }
+    if parse_config(&current).is_err() {
+        tracing::warn!("Existing config does not load; not keeping it as a backup");
+        return Ok(());
+    }
+    let newest = backup_path(path, 1);
+    if std::fs::read_to_string(&newest).is_ok_and(|previous| previous == current) {
+        return Ok(());
+    }
+
+    for index in (1..CONFIG_BACKUP_COUNT).rev() {
+        let from = backup_path(path, index);
+        if from.exists() {
+            std::fs::rename(&from, backup_path(path, index + 1))?;
+        }
+    }
+    write_file_atomic_private(&newest, &current)
+}
+
+/// Restore the newest backup that loads, after `path` failed with `reason`.
+///
+/// Returns `None` when no backup loads; the broken file is then left alone.
+pub(super) fn recover_from_backup(path: &Path, reason: &str) -> Option<(Config, ConfigRecovery)> {
+    let (backup, content, config) = (1..=CONFIG_BACKUP_COUNT).find_map(|index| {
+        let backup = backup_path(path, index);
+        let content = std::fs::read_to_string(&backup).ok()?;
+        match parse_config(&content) {
+            Ok(config) => Some((backup, content, config)),
+            Err(error) => {
+                tracing::warn!(backup = index, %error, "Config backup does not load");
+                None
+            }
+        }
+    })?;
+
+    let corrupt = sibling_path(path, ".corrupt");
+    let corrupt_copy = match std::fs::rename(path, &corrupt) {
+        Ok(()) => Some(file_label(&corrupt)),
+        Err(error) => {
+            tracing::warn!(%error, "Failed to keep the broken config");
+            None
+        }
+    };
+    if let Err(error) = write_file_atomic_private(path, &content) {
+        // The restored settings still apply for this run; the next save
+        // writes them back.
+        tracing::error!(%error, "Failed to write the restored config");
+    }
+
+    Some((
+        config,
+        ConfigRecovery {
+            reason: reason.to_string(),
+            restored_from: file_label(&backup),
+            corrupt_copy,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_config(fields: &str) -> String {
+        let mut config = serde_json::to_value(Config::first_run()).unwrap();
+        let overrides: serde_json::Value = serde_json::from_str(fields).unwrap();
+        for (key, value) in overrides.as_object().unwrap() {
+            config[key] = value.clone();
+        }
+        serde_json::to_string_pretty(&config).unwrap()
+    }
+
+    #[test]
+    fn saves_rotate_distinct_backups_up_to_the_limit() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.json");
+
+        let mut config = Config::first_run();
+        for threshold in 0..=CONFIG_BACKUP_COUNT + 1 {
+            config.immediate_alert_threshold = 0.5 + threshold as f64 / 100.0;
+            config.save(&path).unwrap();
+            // Saving the same config again does not push out older backups.
+            config.save(&path).unwrap();
+        }
+
+        let newest: Config =
+            serde_json::from_str(&std::fs::read_to_string(backup_path(&path, 1)).unwrap()).unwrap();
+        assert!((newest.immediate_alert_threshold - 0.55).abs() < 1e-9);
+        let oldest: Config = serde_json::from_str(
+            &std::fs::read_to_string(backup_path(&path, CONFIG_BACKUP_COUNT)).unwrap(),
+        )
+        .unwrap();
+        assert!((oldest.immediate_alert_threshold - 0.51).abs() < 1e-9);
+        assert!(!backup_path(&path, CONFIG_BACKUP_COUNT + 1).exists());
+    }
+
+    #[test]
+    fn broken_config_is_not_kept_as_a_backup() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.json");
+        std::fs::write(&path, "{\"salary_floor").unwrap();
+
+        Config::first_run().save(&path).unwrap();
+
+        assert!(!backup_path(&path, 1).exists());
+    }
+
+    #[test]
+    fn load_with_recovery_restores_newest_loadable_backup() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.json");
+        std::fs::write(&path, "{\"salary_floor").unwrap();
+        std::fs::write(backup_path(&path, 1), "not json").unwrap();
+        std::fs::write(
+            backup_path(&path, 2),
+            saved_config(r#"{"salary_floor_usd": 95000}"#),
+        )
+        .unwrap();
+        std::fs::write(
+            backup_path(&path, 3),
+            saved_config(r#"{"salary_floor_usd": 80000}"#),
+        )
+        .unwrap();
+
+        let (config, recovery) = Config::load_with_recovery(&path).unwrap();
+
+        assert_eq!(config.salary_floor_usd, 95000);
+        let recovery = recovery.unwrap();
+        assert_eq!(recovery.restored_from, "config.json.bak.2");
+        assert_eq!(
+            recovery.corrupt_copy.as_deref(),
+            Some("config.json.corrupt")
+        );
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("config.json.corrupt")).unwrap(),
+            "{\"salary_floor"
+        );
+        assert_eq!(Config::load(&path).unwrap().salary_floor_usd, 95000);
+    }
+
+    #[test]
+    fn load_with_recovery_reports_original_error_without_backups() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.json");
+        std::fs::write(&path, "{").unwrap();
+
+        assert!(Config::load_with_recovery(&path).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{");
+    }
+
+    #[test]
+    fn load_with_recovery_leaves_valid_config_alone() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.json");
+        Config::first_run().save(&path).unwrap();
+
+        let (_, recovery) = Config::load_with_recovery(&path).unwrap();
+
+        assert!(recovery.is_none());
+    }
+}
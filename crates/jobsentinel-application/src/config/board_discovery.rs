@@ -0,0 +1,130 @@
+//! Company job-board discovery for source setup.
+//!
+//! Wraps the source-level probe with what the current config already knows,
+//! so the UI can offer to add a verified Greenhouse or Lever board.
+
+use anyhow::{bail, Result};
+use jobsentinel_sources::{
+    discover_company_boards, parse_greenhouse_company_url, parse_lever_company_url, AtsProvider,
+    CompanyBoardUrl, DiscoveredBoard,
+};
+use serde::Serialize;
+
+use super::Config;
+
+/// Verified boards found for a company name.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompanyBoardDiscovery {
+    pub company_name: String,
+    pub boards: Vec<CompanyBoardSuggestion>,
+}
+
+/// One verified board and whether it can be added to scheduled checks.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompanyBoardSuggestion {
+    #[serde(flatten)]
+    pub board: DiscoveredBoard,
+    /// Config list the board URL belongs in (`None` when the provider is
+    /// not yet a scheduled source)
+    pub config_field: Option<&'static str>,
+    pub already_configured: bool,
+}
+
+/// Probe common ATS boards for `company_name` and annotate each verified
+/// board with how it fits the current config.
+pub async fn discover_company_board(
+    config: &Config,
+    company_name: &str,
+) -> Result<CompanyBoardDiscovery> {
+    let company_name = company_name.trim();
+    if company_name.is_empty() {
+        bail!("Enter a company name to search for its job board");
+    }
+
+    let boards = discover_company_boards(company_name).await;
+    Ok(CompanyBoardDiscovery {
+        company_name: company_name.to_string(),
+        boards: suggest_company_boards(config, boards),
+    })
+}
+
+fn suggest_company_boards(
+    config: &Config,
+    boards: Vec<DiscoveredBoard>,
+) -> Vec<CompanyBoardSuggestion> {
+    boards
+        .into_iter()
+        .map(|board| {
+            let (config_field, configured) = match board.provider {
+                AtsProvider::Greenhouse => (
+                    Some("greenhouse_urls"),
+                    configured_ids(&config.greenhouse_urls, parse_greenhouse_company_url),
+                ),
+                AtsProvider::Lever => (
+                    Some("lever_urls"),
+                    configured_ids(&config.lever_urls, parse_lever_company_url),
+                ),
+                _ => (None, Vec::new()),
+            };
+            CompanyBoardSuggestion {
+                already_configured: configured
+                    .iter()
+                    .any(|id| id.eq_ignore_ascii_case(&board.slug)),
+                config_field,
+                board,
+            }
+        })
+        .collect()
+}
+
+fn configured_ids(
+    urls: &[String],
+    parse: fn(&str) -> Result<CompanyBoardUrl, String>,
+) -> Vec<String> {
+    urls.iter()
+        .filter_map(|url| parse(url).ok())
+        .map(|board| board.id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(provider: AtsProvider, slug: &str) -> DiscoveredBoard {
+        DiscoveredBoard {
+            provider,
+            slug: slug.to_string(),
+            board_url: provider.board_url(slug),
+            job_count: 3,
+        }
+    }
+
+    #[test]
+    fn suggestions_mark_configured_and_unsupported_boards() {
+        let mut config = Config::first_run();
+        config.greenhouse_urls = vec!["https://boards.greenhouse.io/harborcare".to_string()];
+
+        let suggestions = suggest_company_boards(
+            &config,
+            vec![
+                board(AtsProvider::Greenhouse, "harborcare"),
+                board(AtsProvider::Lever, "harborcare"),
+                board(AtsProvider::Ashby, "harborcare"),
+            ],
+        );
+
+        assert_eq!(suggestions[0].config_field, Some("greenhouse_urls"));
+        assert!(suggestions[0].already_configured);
+        assert_eq!(suggestions[1].config_field, Some("lever_urls"));
+        assert!(!suggestions[1].already_configured);
+        assert_eq!(suggestions[2].config_field, None);
+        assert!(!suggestions[2].already_configured);
+    }
+
+    #[tokio::test]
+    async fn discovery_rejects_blank_company_name() {
+        let config = Config::first_run();
+        assert!(discover_company_board(&config, "   ").await.is_err());
+    }
+}
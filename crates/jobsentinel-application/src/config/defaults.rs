@@ -27,6 +27,18 @@ pub(crate) const fn default_scraping_interval() -> u64 {
     2
 }
 
+/// Default number of sources checked at the same time (3)
+#[must_use]
+pub(crate) const fn default_scraping_concurrency() -> usize {
+    3
+}
+
+/// Default commute radius in kilometres (40 km, about 25 miles)
+#[must_use]
+pub(crate) const fn default_commute_radius_km() -> f64 {
+    40.0
+}
+
 /// Default country code (US)
 pub(crate) fn default_country() -> String {
     "US".to_string()
@@ -61,3 +73,15 @@ pub(crate) const fn default_usajobs_date_posted() -> u8 {
 pub(crate) const fn default_usajobs_limit() -> usize {
     100
 }
+
+/// Default request budget shared by all source checks (120/minute)
+#[must_use]
+pub(crate) const fn default_global_requests_per_minute() -> u32 {
+    120
+}
+
+/// Default request budget per source domain (30/minute)
+#[must_use]
+pub(crate) const fn default_domain_requests_per_minute() -> u32 {
+    30
+}
@@ -1,5 +1,6 @@
 //! Configuration I/O operations
 
+use super::backups::{backup_config, recover_from_backup, ConfigRecovery};
 use super::types::Config;
 use super::validation::validate_config;
 use std::io;
@@ -21,6 +22,35 @@ impl Config {
         Ok(config)
     }
 
+    /// Load configuration, falling back to the newest loadable backup when
+    /// the file is corrupt or no longer valid
+    ///
+    /// The recovery details are returned so the UI can tell the user which
+    /// settings were restored.
+    pub fn load_with_recovery(
+        path: &Path,
+    ) -> Result<(Self, Option<ConfigRecovery>), Box<dyn std::error::Error>> {
+        match Self::load(path) {
+            Ok(config) => Ok((config, None)),
+            Err(error) if path.exists() => {
+                let Some((config, recovery)) = recover_from_backup(path, &error.to_string()) else {
+                    return Err(error);
+                };
+                tracing::warn!(
+                    restored_from = %recovery.restored_from,
+                    "Saved config could not be loaded; restored a backup"
+                );
+                Ok((config, Some(recovery)))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Validate configuration without saving it
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        validate_config(self)
+    }
+
     /// Save configuration to file
     pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
         // Validate before saving
@@ -34,6 +64,9 @@ impl Config {
                 .map_err(|_e| std::io::Error::other("Failed to create config directory"))?;
         }
 
+        if let Err(error) = backup_config(path, &content) {
+            tracing::warn!(%error, "Failed to back up the previous config");
+        }
         write_file_atomic_private(path, &content)?;
         Ok(())
     }
@@ -3,22 +3,39 @@
 //! Handles loading, validating, and saving user preferences.
 
 // Module declarations
+mod backups;
+mod board_discovery;
 mod defaults;
 mod io;
+mod presets;
+mod setup_import;
 mod types;
 mod validation;
 mod validation_error;
 
 // Re-export public types
+pub use backups::ConfigRecovery;
+pub use board_discovery::{discover_company_board, CompanyBoardDiscovery, CompanyBoardSuggestion};
 pub use jobsentinel_domain::{ExternalAiConfig, ExternalAiProvider, ExternalAiRedactionConfig};
+pub use presets::{setup_preset, SetupPreset, SETUP_PRESETS};
+pub use setup_import::{import_setup_folder, SetupFolderImport, SetupImportError};
+pub use types::commute::CommutePreferences;
+pub use types::power_saving::{PowerSavingAction, PowerSavingConfig};
+pub use types::proxy::ScraperProxyConfig;
+pub use types::rate_limits::SourceRateLimitConfig;
+pub use types::retention::RetentionConfig;
+pub use types::scoring::{ScoringPreferences, ScoringProfile};
+pub use types::source_limits::{SourceLimit, SourceLimitsConfig};
 pub use types::sources::{
-    BuiltInConfig, DiceConfig, GlassdoorConfig, HnHiringConfig, LinkedInConfig, RemoteOkConfig,
-    SimplyHiredConfig, UsaJobsConfig, WeWorkRemotelyConfig, YcStartupConfig,
+    BuiltInConfig, CareerPageConfig, CountryBoard, DiceConfig, GlassdoorConfig, HnHiringConfig,
+    LinkedInConfig, RemoteOkConfig, SimplyHiredConfig, UsaJobsConfig, WeWorkRemotelyConfig,
+    YcStartupConfig, COUNTRY_BOARDS, SCHEDULED_SOURCE_IDS,
 };
 pub use types::{
-    AlertConfig, AutoRefreshConfig, Config, DesktopConfig, DiscordConfig, EmailConfig,
-    JobsWithGptApproval, JobsWithGptPayload, LocationPreferences, RestrictedSourceAcknowledgements,
-    SlackConfig, TeamsConfig, TelegramConfig,
+    AlertConfig, AutoRefreshConfig, Config, CountryPreference, CustomWebhookConfig, DesktopConfig,
+    DigestFrequency, DiscordConfig, EmailConfig, EmailDigestConfig, JobsWithGptApproval,
+    JobsWithGptPayload, LocationPreferences, NtfyConfig, PushoverConfig,
+    RestrictedSourceAcknowledgements, SlackConfig, TeamsConfig, TelegramConfig, WeeklyReportConfig,
 };
 pub use validation_error::{ValidationError, ValidationErrors};
 
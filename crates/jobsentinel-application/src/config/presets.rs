@@ -0,0 +1,291 @@
+//! Role-based starting points for first-run setup
+//!
+//! A blank config matches nothing, so setup offers these templates. Each
+//! fills the search lists and turns on public feeds that need no account or
+//! extra details, which the user can then adjust before saving.
+
+use serde::Serialize;
+
+use super::defaults::default_scraper_limit;
+use super::types::Config;
+
+/// A role-based template for a first-run config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SetupPreset {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub titles: &'static [&'static str],
+    pub keywords_boost: &'static [&'static str],
+    pub keywords_exclude: &'static [&'static str],
+    pub salary_floor_usd: i64,
+    pub allow_remote: bool,
+    pub allow_hybrid: bool,
+    pub allow_onsite: bool,
+    /// RemoteOK tags to filter on; empty leaves RemoteOK off
+    pub remoteok_tags: &'static [&'static str],
+    /// WeWorkRemotely feed category; `None` leaves WeWorkRemotely off
+    pub weworkremotely_category: Option<&'static str>,
+    /// Whether to read the monthly Hacker News hiring thread
+    pub hn_hiring: bool,
+}
+
+const COMMON_TITLE_BLOCKLIST: &[&str] = &["Intern", "Internship", "Unpaid", "Volunteer"];
+
+/// Templates offered by first-run setup, in display order.
+pub const SETUP_PRESETS: &[SetupPreset] = &[
+    SetupPreset {
+        id: "software-engineering",
+        name: "Software Engineering",
+        description: "Developers and engineers building web, mobile, and backend software",
+        titles: &[
+            "Software Engineer",
+            "Software Developer",
+            "Backend Engineer",
+            "Frontend Engineer",
+            "Full Stack Engineer",
+            "Web Developer",
+            "Mobile Engineer",
+        ],
+        keywords_boost: &["TypeScript", "Python", "Rust", "Go", "React", "SQL", "AWS"],
+        keywords_exclude: &["unpaid", "commission only"],
+        salary_floor_usd: 80_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: false,
+        remoteok_tags: &["dev", "engineer"],
+        weworkremotely_category: Some("remote-programming-jobs"),
+        hn_hiring: true,
+    },
+    SetupPreset {
+        id: "security",
+        name: "Security",
+        description: "Security engineers, analysts, and cloud security roles",
+        titles: &[
+            "Security Engineer",
+            "Security Analyst",
+            "Application Security Engineer",
+            "Cloud Security Engineer",
+            "SOC Analyst",
+            "Detection Engineer",
+        ],
+        keywords_boost: &[
+            "SIEM",
+            "Incident Response",
+            "AWS",
+            "Threat Detection",
+            "Python",
+        ],
+        keywords_exclude: &["unpaid", "commission only"],
+        salary_floor_usd: 85_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: false,
+        remoteok_tags: &["security"],
+        weworkremotely_category: Some("remote-devops-sysadmin-jobs"),
+        hn_hiring: true,
+    },
+    SetupPreset {
+        id: "data-analytics",
+        name: "Data & Analytics",
+        description: "Data analysts, data scientists, and analytics engineers",
+        titles: &[
+            "Data Analyst",
+            "Data Scientist",
+            "Analytics Engineer",
+            "Business Intelligence Analyst",
+            "Data Engineer",
+        ],
+        keywords_boost: &["SQL", "Python", "Tableau", "Power BI", "dbt", "Statistics"],
+        keywords_exclude: &["unpaid", "commission only"],
+        salary_floor_usd: 70_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: false,
+        remoteok_tags: &["data", "analyst"],
+        weworkremotely_category: Some("remote-programming-jobs"),
+        hn_hiring: true,
+    },
+    SetupPreset {
+        id: "product-design",
+        name: "Product & Design",
+        description: "Product managers and product, UX, and visual designers",
+        titles: &[
+            "Product Manager",
+            "Product Designer",
+            "UX Designer",
+            "UI Designer",
+            "UX Researcher",
+        ],
+        keywords_boost: &[
+            "Figma",
+            "User Research",
+            "Roadmap",
+            "Prototyping",
+            "Design Systems",
+        ],
+        keywords_exclude: &["unpaid", "commission only"],
+        salary_floor_usd: 75_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: false,
+        remoteok_tags: &["design", "product"],
+        weworkremotely_category: Some("remote-design-jobs"),
+        hn_hiring: false,
+    },
+    SetupPreset {
+        id: "customer-support",
+        name: "Customer Support",
+        description: "Support specialists, customer success, and help desk roles",
+        titles: &[
+            "Customer Support Specialist",
+            "Customer Success Manager",
+            "Support Specialist",
+            "Help Desk Technician",
+            "Technical Support Specialist",
+        ],
+        keywords_boost: &[
+            "Zendesk",
+            "Customer Service",
+            "Troubleshooting",
+            "CRM",
+            "Onboarding",
+        ],
+        keywords_exclude: &["door to door", "commission only", "MLM", "unpaid"],
+        salary_floor_usd: 40_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: true,
+        remoteok_tags: &["support", "customer success"],
+        weworkremotely_category: Some("remote-customer-support-jobs"),
+        hn_hiring: false,
+    },
+    SetupPreset {
+        id: "sales-marketing",
+        name: "Sales & Marketing",
+        description: "Account executives, marketers, and growth roles",
+        titles: &[
+            "Account Executive",
+            "Sales Development Representative",
+            "Marketing Manager",
+            "Content Marketing Manager",
+            "Growth Marketing Manager",
+        ],
+        keywords_boost: &[
+            "B2B",
+            "Salesforce",
+            "HubSpot",
+            "SEO",
+            "Pipeline",
+            "Campaigns",
+        ],
+        keywords_exclude: &["door to door", "commission only", "MLM", "unpaid"],
+        salary_floor_usd: 50_000,
+        allow_remote: true,
+        allow_hybrid: true,
+        allow_onsite: true,
+        remoteok_tags: &["sales", "marketing"],
+        weworkremotely_category: Some("remote-sales-and-marketing-jobs"),
+        hn_hiring: false,
+    },
+    SetupPreset {
+        id: "office-administration",
+        name: "Office & Admin",
+        description: "Administrative assistants, office managers, and coordinators",
+        titles: &[
+            "Administrative Assistant",
+            "Executive Assistant",
+            "Office Manager",
+            "Office Coordinator",
+            "Program Coordinator",
+        ],
+        keywords_boost: &[
+            "Scheduling",
+            "Calendar Management",
+            "Microsoft Office",
+            "Data Entry",
+        ],
+        keywords_exclude: &["door to door", "commission only", "MLM", "unpaid"],
+        salary_floor_usd: 40_000,
+        allow_remote: false,
+        allow_hybrid: true,
+        allow_onsite: true,
+        remoteok_tags: &[],
+        weworkremotely_category: None,
+        hn_hiring: false,
+    },
+    SetupPreset {
+        id: "healthcare-operations",
+        name: "Healthcare Operations",
+        description: "Care coordinators, patient access, and medical office roles",
+        titles: &[
+            "Care Coordinator",
+            "Patient Services Representative",
+            "Medical Office Assistant",
+            "Case Manager",
+            "Patient Access Specialist",
+        ],
+        keywords_boost: &[
+            "EHR",
+            "Epic",
+            "Scheduling",
+            "Insurance Verification",
+            "HIPAA",
+        ],
+        keywords_exclude: &["commission only", "MLM", "unpaid"],
+        salary_floor_usd: 40_000,
+        allow_remote: false,
+        allow_hybrid: true,
+        allow_onsite: true,
+        remoteok_tags: &[],
+        weworkremotely_category: None,
+        hn_hiring: false,
+    },
+];
+
+/// Look up a setup template by id.
+#[must_use]
+pub fn setup_preset(preset_id: &str) -> Option<&'static SetupPreset> {
+    SETUP_PRESETS.iter().find(|preset| preset.id == preset_id)
+}
+
+impl SetupPreset {
+    /// Fill `config`'s search lists, pay floor, work styles, and public feeds.
+    ///
+    /// Location cities, alerts, and sources that need an account or company
+    /// link are left as they are.
+    pub fn apply_to(&self, config: &mut Config) {
+        config.title_allowlist = to_strings(self.titles);
+        config.title_blocklist = to_strings(COMMON_TITLE_BLOCKLIST);
+        config.keywords_boost = to_strings(self.keywords_boost);
+        config.keywords_exclude = to_strings(self.keywords_exclude);
+        config.salary_floor_usd = self.salary_floor_usd;
+
+        let location = &mut config.location_preferences;
+        location.allow_remote = self.allow_remote;
+        location.allow_hybrid = self.allow_hybrid;
+        location.allow_onsite = self.allow_onsite;
+
+        config.remoteok.enabled = !self.remoteok_tags.is_empty();
+        config.remoteok.tags = to_strings(self.remoteok_tags);
+        config.weworkremotely.enabled = self.weworkremotely_category.is_some();
+        config.weworkremotely.category = self.weworkremotely_category.map(str::to_string);
+        config.hn_hiring.enabled = self.hn_hiring;
+        config.hn_hiring.remote_only = self.hn_hiring && !self.allow_onsite;
+
+        // Drafts built in code rather than parsed from JSON start with no limit
+        for limit in [
+            &mut config.remoteok.limit,
+            &mut config.weworkremotely.limit,
+            &mut config.hn_hiring.limit,
+        ] {
+            if *limit == 0 {
+                *limit = default_scraper_limit();
+            }
+        }
+    }
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| (*value).to_string()).collect()
+}
@@ -0,0 +1,88 @@
+//! Bring settings and saved jobs from an existing JobSentinel folder into
+//! first-run setup
+
+use serde::Serialize;
+use std::path::Path;
+use thiserror::Error;
+
+use super::types::Config;
+use jobsentinel_storage::{Database, DatabaseImportSummary};
+
+/// Settings file name inside a JobSentinel config folder.
+const CONFIG_FILE_NAME: &str = "config.json";
+/// Database file name inside a JobSentinel data folder.
+const DATABASE_FILE_NAME: &str = "jobs.db";
+
+/// Errors from importing an existing JobSentinel folder
+#[derive(Error, Debug)]
+pub enum SetupImportError {
+    #[error("Choose a folder, not a file")]
+    NotAFolder,
+
+    #[error("Folder has no JobSentinel settings or database")]
+    NothingToImport,
+
+    #[error("Saved settings could not be read: {0}")]
+    InvalidConfig(String),
+
+    #[error("Saved jobs could not be imported: {0}")]
+    Database(String),
+}
+
+/// What an existing folder contributed to setup
+#[derive(Debug, Clone, Serialize)]
+pub struct SetupFolderImport {
+    /// Validated settings from the folder, for setup to review and save
+    pub config: Option<Config>,
+    pub config_imported: bool,
+    pub database: Option<DatabaseImportSummary>,
+}
+
+/// Read `config.json` and copy saved jobs from `jobs.db` in `folder`.
+///
+/// Settings are validated before any jobs are copied, so a bad settings file
+/// leaves the database untouched. Nothing is saved to the config file; the
+/// wizard shows the returned settings and saves them on completion.
+pub async fn import_setup_folder(
+    folder: &Path,
+    database: &Database,
+    work_dir: &Path,
+) -> Result<SetupFolderImport, SetupImportError> {
+    if !folder.is_dir() {
+        return Err(SetupImportError::NotAFolder);
+    }
+    let config_path = folder.join(CONFIG_FILE_NAME);
+    let database_path = folder.join(DATABASE_FILE_NAME);
+    if !config_path.is_file() && !database_path.is_file() {
+        return Err(SetupImportError::NothingToImport);
+    }
+
+    let config = if config_path.is_file() {
+        let config = Config::load(&config_path)
+            .map_err(|error| SetupImportError::InvalidConfig(error.to_string()))?;
+        Some(config)
+    } else {
+        None
+    };
+
+    let database_summary = if database_path.is_file() {
+        let summary = database
+            .import_from_database_file(&database_path, work_dir)
+            .await
+            .map_err(|error| SetupImportError::Database(error.to_string()))?;
+        Some(summary)
+    } else {
+        None
+    };
+
+    tracing::info!(
+        config_imported = config.is_some(),
+        database_imported = database_summary.is_some(),
+        "Imported existing JobSentinel folder"
+    );
+    Ok(SetupFolderImport {
+        config_imported: config.is_some(),
+        config,
+        database: database_summary,
+    })
+}
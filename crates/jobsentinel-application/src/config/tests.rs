@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::defaults::*;
+    use super::super::types::sources::CareerPageConfig;
     use super::super::types::*;
     use super::super::validation::validate_config;
     use std::fs;
@@ -114,6 +115,13 @@ mod tests {
     #[path = "persistence_tests.rs"]
     mod persistence_tests;
 
+    // ========================================
+    // First-Run Setup Tests
+    // ========================================
+
+    #[path = "setup_tests.rs"]
+    mod setup_tests;
+
     #[test]
     fn test_default_values() {
         // Test that default functions return expected values
@@ -152,6 +160,20 @@ mod tests {
     #[path = "teams_tests.rs"]
     mod teams_tests;
 
+    // ========================================
+    // Custom Webhook Configuration Tests
+    // ========================================
+
+    #[path = "webhook_tests.rs"]
+    mod webhook_tests;
+
+    // ========================================
+    // Push Notification Configuration Tests
+    // ========================================
+
+    #[path = "push_tests.rs"]
+    mod push_tests;
+
     // ========================================
     // LinkedIn Configuration Tests
     // ========================================
@@ -371,6 +393,28 @@ mod tests {
         assert_eq!(config.location_preferences.country, "US");
     }
 
+    #[test]
+    fn test_deserialize_company_lists_from_allowlist_and_blocklist_names() {
+        let json = r#"{
+            "title_allowlist": ["Care Coordinator"],
+            "location_preferences": {
+                "allow_remote": true
+            },
+            "salary_floor_usd": 100000,
+            "alerts": {},
+            "company_allowlist": ["Metro Transit"],
+            "company_blocklist": ["Harbor Retail LLC"]
+        }"#;
+
+        let config: Config = serde_json::from_str(json).expect("Failed to deserialize config");
+
+        assert_eq!(config.preferred_companies, vec!["Metro Transit"]);
+        assert_eq!(config.blocked_companies, vec!["Harbor Retail LLC"]);
+        assert!(config.prefers_company("METRO TRANSIT, INC."));
+        assert!(config.blocks_company("harbor retail"));
+        assert!(!config.blocks_company("Metro Transit"));
+    }
+
     #[test]
     fn test_deserialize_alert_config_with_defaults() {
         let json = r#"{
@@ -229,3 +229,53 @@ fn test_email_valid_configuration_passes() {
         "Valid email configuration should pass"
     );
 }
+
+#[test]
+fn test_email_digest_requires_smtp_settings_and_uses_own_recipients() {
+    let mut config = create_valid_config();
+    config.alerts.email.digest.enabled = true;
+
+    let error = validate_config(&config).unwrap_err().to_string();
+    assert!(error.contains("SMTP server is required"), "{error}");
+
+    config.alerts.email.smtp_server = "smtp.gmail.com".to_string();
+    config.alerts.email.smtp_username = "user@example.com".to_string();
+    config.alerts.email.from_email = "from@example.com".to_string();
+    config.alerts.email.digest.recipients = vec!["digest@example.com".to_string()];
+    assert!(
+        validate_config(&config).is_ok(),
+        "Digest recipients should stand in for alert recipients"
+    );
+}
+
+#[test]
+fn test_email_digest_schedule_out_of_range_fails() {
+    let mut config = create_valid_config();
+    config.alerts.email.smtp_server = "smtp.gmail.com".to_string();
+    config.alerts.email.smtp_username = "user@example.com".to_string();
+    config.alerts.email.from_email = "from@example.com".to_string();
+    config.alerts.email.to_emails = vec!["to@example.com".to_string()];
+    config.alerts.email.digest.enabled = true;
+    config.alerts.email.digest.send_hour = 24;
+    config.alerts.email.digest.send_weekday = 0;
+    config.alerts.email.digest.min_score = 1.5;
+    config.alerts.email.digest.recipients = vec!["not-an-email".to_string()];
+
+    let error = validate_config(&config).unwrap_err().to_string();
+    assert!(
+        error.contains("send hour must be between 0 and 23"),
+        "{error}"
+    );
+    assert!(
+        error.contains("send weekday must be between 1 and 7"),
+        "{error}"
+    );
+    assert!(
+        error.contains("min score must be between 0 and 1"),
+        "{error}"
+    );
+    assert!(
+        error.contains("digest recipients[0]: not-an-email"),
+        "{error}"
+    );
+}
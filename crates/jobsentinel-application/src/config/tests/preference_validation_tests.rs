@@ -322,6 +322,79 @@ fn test_country_name_too_long_fails() {
         .contains("Country name too long"));
 }
 
+#[test]
+fn test_extra_countries_with_matching_boards_pass() {
+    let mut config = create_valid_config();
+    config.location_preferences.countries = vec![CountryPreference {
+        code: "de".to_string(),
+        boards: vec!["arbeitnow".to_string()],
+    }];
+
+    assert!(validate_config(&config).is_ok());
+    assert_eq!(
+        config.location_preferences.country_codes(),
+        vec!["US", "DE"]
+    );
+    assert!(config
+        .location_preferences
+        .country_board_enabled("arbeitnow"));
+}
+
+#[test]
+fn test_guardian_jobs_board_covers_the_united_kingdom() {
+    let mut config = create_valid_config();
+    config.location_preferences.countries = vec![CountryPreference {
+        code: "GB".to_string(),
+        boards: vec!["guardian_jobs".to_string()],
+    }];
+
+    assert!(validate_config(&config).is_ok());
+    assert!(config
+        .location_preferences
+        .country_board_enabled("guardian_jobs"));
+
+    config.location_preferences.countries[0].code = "DE".to_string();
+    let error = validate_config(&config).unwrap_err().to_string();
+    assert!(
+        error.contains("Guardian Jobs does not list jobs in DE"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_extra_country_code_must_be_two_letters() {
+    let mut config = create_valid_config();
+    config.location_preferences.countries = vec![CountryPreference {
+        code: "Germany".to_string(),
+        boards: Vec::new(),
+    }];
+
+    let error = validate_config(&config).unwrap_err().to_string();
+    assert!(error.contains("two-letter country code"), "{error}");
+}
+
+#[test]
+fn test_country_board_must_cover_the_country() {
+    let mut config = create_valid_config();
+    config.location_preferences.countries = vec![
+        CountryPreference {
+            code: "FR".to_string(),
+            boards: vec!["arbeitnow".to_string()],
+        },
+        CountryPreference {
+            code: "GB".to_string(),
+            boards: vec!["otta".to_string()],
+        },
+    ];
+
+    let error = validate_config(&config).unwrap_err().to_string();
+    assert!(
+        error.contains("Arbeitnow does not list jobs in FR"),
+        "{error}"
+    );
+    assert!(error.contains("known country board"), "{error}");
+}
+
 #[test]
 fn test_slack_enabled_with_empty_webhook_passes_validation() {
     // In v2.9+, Slack webhook is stored through CredentialService, not config.
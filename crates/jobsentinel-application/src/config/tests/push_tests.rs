@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn test_ntfy_enabled_with_defaults_passes_validation() {
+    // The topic is stored through CredentialService, not config.
+    let mut config = create_valid_config();
+    config.alerts.ntfy.enabled = true;
+
+    assert!(validate_config(&config).is_ok());
+}
+
+#[test]
+fn test_ntfy_rejects_plain_http_server() {
+    let mut config = create_valid_config();
+    config.alerts.ntfy.enabled = true;
+    config.alerts.ntfy.server_url = "http://ntfy.example.com".to_string();
+
+    assert!(validate_config(&config).is_err());
+}
+
+#[test]
+fn test_ntfy_priority_must_be_between_one_and_five() {
+    let mut config = create_valid_config();
+    config.alerts.ntfy.enabled = true;
+    config.alerts.ntfy.priority = 0;
+
+    let err = validate_config(&config).unwrap_err().to_string();
+    assert!(err.contains("priority"), "{err}");
+}
+
+#[test]
+fn test_pushover_priority_range() {
+    let mut config = create_valid_config();
+    config.alerts.pushover.enabled = true;
+
+    for priority in [-2, 0, 1] {
+        config.alerts.pushover.priority = priority;
+        assert!(validate_config(&config).is_ok(), "priority {priority}");
+    }
+    for priority in [-3, 2] {
+        config.alerts.pushover.priority = priority;
+        assert!(validate_config(&config).is_err(), "priority {priority}");
+    }
+}
@@ -0,0 +1,110 @@
+use super::super::super::presets::{setup_preset, SETUP_PRESETS};
+use super::super::super::setup_import::{import_setup_folder, SetupImportError};
+use super::*;
+
+#[test]
+fn test_every_setup_preset_produces_a_valid_config() {
+    for preset in SETUP_PRESETS {
+        let mut config = crate::test_support::minimal_test_config();
+        preset.apply_to(&mut config);
+
+        assert!(
+            !config.title_allowlist.is_empty(),
+            "{} should set titles",
+            preset.id
+        );
+        if let Err(error) = validate_config(&config) {
+            panic!("{} should pass validation: {error}", preset.id);
+        }
+    }
+}
+
+#[test]
+fn test_setup_preset_ids_are_unique() {
+    for (index, preset) in SETUP_PRESETS.iter().enumerate() {
+        assert!(SETUP_PRESETS[index + 1..]
+            .iter()
+            .all(|other| other.id != preset.id));
+    }
+}
+
+#[test]
+fn test_setup_preset_turns_on_only_matching_public_feeds() {
+    let mut config = crate::test_support::minimal_test_config();
+    setup_preset("software-engineering")
+        .unwrap()
+        .apply_to(&mut config);
+    assert!(config.remoteok.enabled);
+    assert_eq!(
+        config.weworkremotely.category.as_deref(),
+        Some("remote-programming-jobs")
+    );
+    assert!(config.hn_hiring.enabled);
+
+    setup_preset("office-administration")
+        .unwrap()
+        .apply_to(&mut config);
+    assert!(!config.remoteok.enabled);
+    assert!(!config.weworkremotely.enabled);
+    assert!(!config.hn_hiring.enabled);
+    assert!(config.location_preferences.allow_onsite);
+    assert!(setup_preset("unknown").is_none());
+}
+
+async fn migrated_database() -> jobsentinel_storage::Database {
+    let database = jobsentinel_storage::Database::connect_memory()
+        .await
+        .unwrap();
+    database.migrate().await.unwrap();
+    database
+}
+
+#[tokio::test]
+async fn test_import_setup_folder_reads_saved_settings() {
+    let folder = TempDir::new().unwrap();
+    let mut saved = create_valid_config();
+    saved.title_allowlist = vec!["Care Navigator".to_string()];
+    saved.save(&folder.path().join("config.json")).unwrap();
+
+    let imported = import_setup_folder(folder.path(), &migrated_database().await, folder.path())
+        .await
+        .unwrap();
+
+    assert!(imported.config_imported);
+    assert!(imported.database.is_none());
+    assert_eq!(imported.config.unwrap().title_allowlist, ["Care Navigator"]);
+}
+
+#[tokio::test]
+async fn test_import_setup_folder_rejects_invalid_settings() {
+    let folder = TempDir::new().unwrap();
+    fs::write(
+        folder.path().join("config.json"),
+        "{\"title_allowlist\": 3}",
+    )
+    .unwrap();
+
+    let error = import_setup_folder(folder.path(), &migrated_database().await, folder.path())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SetupImportError::InvalidConfig(_)));
+}
+
+#[tokio::test]
+async fn test_import_setup_folder_needs_jobsentinel_files() {
+    let folder = TempDir::new().unwrap();
+    let database = migrated_database().await;
+
+    let empty = import_setup_folder(folder.path(), &database, folder.path())
+        .await
+        .unwrap_err();
+    let file = folder.path().join("notes.txt");
+    fs::write(&file, "not a folder").unwrap();
+    let not_folder = import_setup_folder(&file, &database, folder.path())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(empty, SetupImportError::NothingToImport));
+    assert!(matches!(not_folder, SetupImportError::NotAFolder));
+}
@@ -205,3 +205,29 @@ fn test_too_many_lever_urls_fails() {
         .to_string()
         .contains("Too many Lever URLs"));
 }
+
+#[test]
+fn test_career_pages_require_https_urls() {
+    let mut config = create_valid_config();
+    config.career_pages = vec![CareerPageConfig {
+        url: "https://harborcare.example/careers".to_string(),
+        company: "Harbor Care".to_string(),
+        render: true,
+    }];
+    assert!(validate_config(&config).is_ok());
+
+    config.career_pages[0].url = "http://harborcare.example/careers".to_string();
+    let result = validate_config(&config);
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Invalid URL in career pages[0] url"));
+}
+
+#[test]
+fn test_career_page_render_flag_defaults_off() {
+    let page: CareerPageConfig =
+        serde_json::from_str(r#"{"url": "https://harborcare.example/careers"}"#).unwrap();
+    assert!(!page.render);
+    assert!(page.company.is_empty());
+}
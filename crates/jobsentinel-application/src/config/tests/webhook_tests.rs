@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_webhook_enabled_requires_a_url() {
+    let mut config = create_valid_config();
+    config.alerts.webhook.enabled = true;
+
+    let result = validate_config(&config);
+    assert!(result.is_err(), "Enabled custom webhook needs a URL");
+}
+
+#[test]
+fn test_webhook_accepts_public_https_urls_and_known_template_fields() {
+    let mut config = create_valid_config();
+    config.alerts.webhook.enabled = true;
+    config.alerts.webhook.urls = vec!["https://hooks.zapier.com/hooks/catch/1/abc".to_string()];
+    config.alerts.webhook.payload_template = Some(serde_json::json!({
+        "text": "{{title}} at {{company}}",
+        "score": "{{score}}"
+    }));
+
+    assert!(validate_config(&config).is_ok());
+}
+
+#[test]
+fn test_webhook_rejects_plain_http_and_private_urls() {
+    for url in [
+        "http://hooks.example.com/job",
+        "https://192.168.1.20/api/webhook/job",
+    ] {
+        let mut config = create_valid_config();
+        config.alerts.webhook.enabled = true;
+        config.alerts.webhook.urls = vec![url.to_string()];
+
+        assert!(
+            validate_config(&config).is_err(),
+            "{url} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_webhook_rejects_unknown_template_fields() {
+    let mut config = create_valid_config();
+    config.alerts.webhook.enabled = true;
+    config.alerts.webhook.urls = vec!["https://hooks.example.com/job".to_string()];
+    config.alerts.webhook.payload_template =
+        Some(serde_json::json!({ "text": "{{salary_floor}}" }));
+
+    let err = validate_config(&config).unwrap_err().to_string();
+    assert!(
+        err.contains("Unknown webhook template field: salary_floor"),
+        "{err}"
+    );
+}
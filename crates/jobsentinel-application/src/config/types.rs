@@ -1,16 +1,33 @@
 //! Configuration type definitions
 
+pub(super) mod commute;
+pub(super) mod power_saving;
+pub(super) mod proxy;
+pub(super) mod rate_limits;
+pub(super) mod retention;
+pub(super) mod scoring;
+pub(super) mod source_limits;
 pub(super) mod sources;
 
 use super::ExternalAiConfig;
+use crate::scoring::fuzzy_match_company;
+use commute::CommutePreferences;
+use jobsentinel_domain::normalization::country_code;
 pub use jobsentinel_notifications::{
-    AlertConfig, DesktopConfig, DiscordConfig, EmailConfig, SlackConfig, TeamsConfig,
-    TelegramConfig,
+    AlertConfig, CustomWebhookConfig, DesktopConfig, DigestFrequency, DiscordConfig, EmailConfig,
+    EmailDigestConfig, NtfyConfig, PushoverConfig, SlackConfig, TeamsConfig, TelegramConfig,
+    WeeklyReportConfig,
 };
+use power_saving::PowerSavingConfig;
+use proxy::ScraperProxyConfig;
+use rate_limits::SourceRateLimitConfig;
+use retention::RetentionConfig;
+use scoring::ScoringPreferences;
 use serde::{Deserialize, Serialize};
+use source_limits::SourceLimitsConfig;
 use sources::{
-    BuiltInConfig, DiceConfig, GlassdoorConfig, HnHiringConfig, LinkedInConfig, RemoteOkConfig,
-    SimplyHiredConfig, UsaJobsConfig, WeWorkRemotelyConfig, YcStartupConfig,
+    BuiltInConfig, CareerPageConfig, DiceConfig, GlassdoorConfig, HnHiringConfig, LinkedInConfig,
+    RemoteOkConfig, SimplyHiredConfig, UsaJobsConfig, WeWorkRemotelyConfig, YcStartupConfig,
 };
 
 /// User configuration
@@ -34,6 +51,10 @@ pub struct Config {
     /// Location preferences
     pub location_preferences: LocationPreferences,
 
+    /// Home coordinates and commute radius for onsite and hybrid jobs
+    #[serde(default)]
+    pub commute: CommutePreferences,
+
     /// Minimum salary in USD (hard floor - jobs below this get low scores)
     pub salary_floor_usd: i64,
 
@@ -42,6 +63,12 @@ pub struct Config {
     #[serde(default)]
     pub salary_target_usd: Option<i64>,
 
+    /// Top of the salary range you are aiming for, in USD. When set, jobs are
+    /// scored by how their posted range overlaps target..target_max instead
+    /// of against the target alone.
+    #[serde(default)]
+    pub salary_target_max_usd: Option<i64>,
+
     /// Penalize jobs with missing salary information
     /// If true, jobs without salary get 0.3 score; if false, get 0.5 (neutral)
     #[serde(default)]
@@ -63,6 +90,10 @@ pub struct Config {
     #[serde(default = "super::defaults::default_scraping_interval")]
     pub scraping_interval_hours: u64,
 
+    /// Maximum number of sources checked at the same time during a cycle
+    #[serde(default = "super::defaults::default_scraping_concurrency")]
+    pub scraping_concurrency: usize,
+
     /// Alert configuration
     pub alerts: AlertConfig,
 
@@ -74,6 +105,11 @@ pub struct Config {
     #[serde(default)]
     pub lever_urls: Vec<String>,
 
+    /// Company career pages outside a supported ATS. Pages with `render` set
+    /// are loaded in headless Chrome because their jobs are drawn client-side.
+    #[serde(default)]
+    pub career_pages: Vec<CareerPageConfig>,
+
     /// LinkedIn source configuration
     #[serde(default)]
     pub linkedin: LinkedInConfig,
@@ -118,6 +154,23 @@ pub struct Config {
     #[serde(default)]
     pub glassdoor: GlassdoorConfig,
 
+    /// Optional proxy for scheduled source checks
+    #[serde(default)]
+    pub proxy: ScraperProxyConfig,
+
+    /// Per-source priority and result quotas for scheduled source checks
+    #[serde(default)]
+    pub source_limits: SourceLimitsConfig,
+
+    /// Request budgets and adaptive backoff for scheduled source checks
+    #[serde(default)]
+    pub rate_limits: SourceRateLimitConfig,
+
+    /// Fewer or no scheduled source checks on a low battery or a metered
+    /// connection
+    #[serde(default)]
+    pub power_saving: PowerSavingConfig,
+
     /// Optional JobsWithGPT MCP endpoint URL.
     ///
     /// Empty by default. A configured endpoint is not enough to send data:
@@ -149,14 +202,22 @@ pub struct Config {
     #[serde(default)]
     pub use_resume_matching: bool,
 
+    /// Scoring weights and the named scoring profiles to switch between
+    #[serde(default)]
+    pub scoring: ScoringPreferences,
+
+    /// Rules for deleting old jobs
+    #[serde(default)]
+    pub retention: RetentionConfig,
+
     /// Preferred companies for scoring bonuses (case-insensitive fuzzy matching).
     /// Companies in this list receive scoring bonus
-    #[serde(default, alias = "company_\u{77}hitelist")]
+    #[serde(default, alias = "company_allowlist", alias = "company_\u{77}hitelist")]
     pub preferred_companies: Vec<String>,
 
     /// Blocked companies for scoring penalties (case-insensitive fuzzy matching).
-    /// Jobs from companies in this list receive very low scores
-    #[serde(default, alias = "company_\u{62}lacklist")]
+    /// Jobs from companies in this list receive very low scores and never alert
+    #[serde(default, alias = "company_blocklist", alias = "company_\u{62}lacklist")]
     pub blocked_companies: Vec<String>,
 }
 
@@ -209,17 +270,22 @@ impl Config {
                 cities: vec![],
                 states: vec![],
                 country: "US".to_string(),
+                countries: Vec::new(),
             },
+            commute: CommutePreferences::default(),
             salary_floor_usd: 0,
             salary_target_usd: None,
+            salary_target_max_usd: None,
             penalize_missing_salary: false,
             auto_refresh: AutoRefreshConfig::default(),
             bookmarklet_port: 4321,
             immediate_alert_threshold: 0.9,
             scraping_interval_hours: 2,
+            scraping_concurrency: super::defaults::default_scraping_concurrency(),
             alerts: AlertConfig::default(),
             greenhouse_urls: vec![],
             lever_urls: vec![],
+            career_pages: vec![],
             linkedin: LinkedInConfig::default(),
             restricted_source_acknowledgements: RestrictedSourceAcknowledgements::default(),
             remoteok: RemoteOkConfig::default(),
@@ -231,16 +297,57 @@ impl Config {
             usajobs: UsaJobsConfig::default(),
             simplyhired: SimplyHiredConfig::default(),
             glassdoor: GlassdoorConfig::default(),
+            proxy: ScraperProxyConfig::default(),
+            source_limits: SourceLimitsConfig::default(),
+            rate_limits: SourceRateLimitConfig::default(),
+            power_saving: PowerSavingConfig::default(),
             jobswithgpt_endpoint: String::new(),
             jobswithgpt_approval: JobsWithGptApproval::default(),
             external_ai: ExternalAiConfig::default(),
             preferred_companies: vec![],
             blocked_companies: vec![],
             use_resume_matching: false,
+            scoring: ScoringPreferences::default(),
+            retention: RetentionConfig::default(),
             ghost_config: None,
         }
     }
 
+    /// Whether `company` fuzzy-matches an entry in `blocked_companies`
+    #[must_use]
+    pub fn blocks_company(&self, company: &str) -> bool {
+        self.blocked_companies
+            .iter()
+            .any(|blocked| fuzzy_match_company(company, blocked))
+    }
+
+    /// Add `company` to `blocked_companies` unless an entry already matches
+    /// it. Returns whether the list changed.
+    pub fn block_company(&mut self, company: &str) -> bool {
+        if self.blocks_company(company) {
+            return false;
+        }
+        self.blocked_companies.push(company.to_string());
+        true
+    }
+
+    /// Remove every `blocked_companies` entry matching `company`. Returns
+    /// whether the list changed.
+    pub fn unblock_company(&mut self, company: &str) -> bool {
+        let before = self.blocked_companies.len();
+        self.blocked_companies
+            .retain(|blocked| !fuzzy_match_company(company, blocked));
+        self.blocked_companies.len() != before
+    }
+
+    /// Whether `company` fuzzy-matches an entry in `preferred_companies`
+    #[must_use]
+    pub fn prefers_company(&self, company: &str) -> bool {
+        self.preferred_companies
+            .iter()
+            .any(|preferred| fuzzy_match_company(company, preferred))
+    }
+
     pub fn jobswithgpt_payload_preview(&self) -> Option<JobsWithGptPayload> {
         let endpoint = self.jobswithgpt_endpoint.trim().to_string();
         if endpoint.is_empty() {
@@ -294,8 +401,50 @@ pub struct LocationPreferences {
     #[serde(default)]
     pub states: Vec<String>,
 
+    /// Home country, as an ISO code or name (default "US")
     #[serde(default = "super::defaults::default_country")]
     pub country: String,
+
+    /// Other countries to search, each with the country boards to check
+    #[serde(default)]
+    pub countries: Vec<CountryPreference>,
+}
+
+impl LocationPreferences {
+    /// ISO codes of the home country and every extra country, without repeats.
+    ///
+    /// Countries that cannot be resolved to a code are skipped.
+    #[must_use]
+    pub fn country_codes(&self) -> Vec<&'static str> {
+        let mut codes = Vec::new();
+        let names = std::iter::once(self.country.as_str())
+            .chain(self.countries.iter().map(|country| country.code.as_str()));
+        for code in names.filter_map(country_code) {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        codes
+    }
+
+    /// Whether any country lists the given country board
+    #[must_use]
+    pub fn country_board_enabled(&self, board_id: &str) -> bool {
+        self.countries
+            .iter()
+            .any(|country| country.boards.iter().any(|board| board == board_id))
+    }
+}
+
+/// A country the user wants jobs in, beyond their home country
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountryPreference {
+    /// ISO 3166-1 alpha-2 code, e.g. "DE" or "GB"
+    pub code: String,
+
+    /// Country boards to check for this country, by id (see `COUNTRY_BOARDS`)
+    #[serde(default)]
+    pub boards: Vec<String>,
 }
 
 /// Auto-refresh configuration for the frontend
@@ -0,0 +1,26 @@
+use super::super::defaults::default_commute_radius_km;
+use jobsentinel_domain::Coordinates;
+use serde::{Deserialize, Serialize};
+
+/// Home location and commute range for onsite and hybrid jobs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommutePreferences {
+    /// Home coordinates. Commute scoring is off while this is unset.
+    ///
+    /// Entered by the user and never sent to a geocoding service.
+    #[serde(default)]
+    pub home: Option<Coordinates>,
+
+    /// Farthest straight-line distance from home to work, in kilometres
+    #[serde(default = "super::super::defaults::default_commute_radius_km")]
+    pub max_distance_km: f64,
+}
+
+impl Default for CommutePreferences {
+    fn default() -> Self {
+        Self {
+            home: None,
+            max_distance_km: default_commute_radius_km(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// What a scheduled scrape does while power or data is limited, from least
+/// to most restrictive
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerSavingAction {
+    /// Check every enabled source as usual
+    Run,
+    /// Check only the highest priority sources
+    Reduce,
+    /// Skip the scrape and check conditions again later
+    Skip,
+}
+
+/// How scheduled scrapes behave on a low battery or a metered connection.
+///
+/// Scrapes started by hand always check every requested source.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PowerSavingConfig {
+    /// What to do on battery power at or below `battery_threshold_percent`
+    /// (default: skip)
+    pub low_battery: PowerSavingAction,
+
+    /// Battery charge, in percent, that counts as low (default: 20)
+    pub battery_threshold_percent: u8,
+
+    /// What to do on a metered connection (default: reduce)
+    pub metered_connection: PowerSavingAction,
+
+    /// Sources checked by a reduced scrape, highest priority first
+    /// (default: 2)
+    pub reduced_source_count: usize,
+}
+
+impl Default for PowerSavingConfig {
+    fn default() -> Self {
+        Self {
+            low_battery: PowerSavingAction::Skip,
+            battery_threshold_percent: 20,
+            metered_connection: PowerSavingAction::Reduce,
+            reduced_source_count: 2,
+        }
+    }
+}
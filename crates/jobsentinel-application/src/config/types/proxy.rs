@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Optional proxy for scheduled source checks.
+///
+/// Useful behind corporate proxies or to avoid IP-based blocking. The proxy
+/// password is stored through `CredentialService` under
+/// `scraper_proxy_password`, never in config.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ScraperProxyConfig {
+    /// Route source checks through the proxy
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Proxy URL (e.g., "http://proxy.corp.example:8080", "socks5h://127.0.0.1:1080")
+    #[serde(default)]
+    pub url: String,
+
+    /// Proxy username. When set, the stored proxy password is sent with it.
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// Source ids to route through the proxy (e.g., ["dice", "glassdoor"]).
+    /// Empty routes every scheduled source.
+    #[serde(default)]
+    pub sources: Vec<String>,
+}
+
+impl ScraperProxyConfig {
+    /// Whether checks for `source_id` should use the proxy.
+    #[must_use]
+    pub fn applies_to(&self, source_id: &str) -> bool {
+        self.enabled
+            && (self.sources.is_empty()
+                || self
+                    .sources
+                    .iter()
+                    .any(|source| source.trim().eq_ignore_ascii_case(source_id)))
+    }
+
+    /// Username to authenticate with, ignoring blank values.
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        self.username
+            .as_deref()
+            .map(str::trim)
+            .filter(|username| !username.is_empty())
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::super::defaults::{
+    default_domain_requests_per_minute, default_global_requests_per_minute,
+};
+
+/// Request budgets for scheduled source checks.
+///
+/// Every source shares the global budget, and each domain has its own
+/// budget on top. Sites that answer 429 or 403 are backed off automatically.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceRateLimitConfig {
+    /// Requests per minute across all sources (default: 120)
+    #[serde(default = "super::super::defaults::default_global_requests_per_minute")]
+    pub global_requests_per_minute: u32,
+
+    /// Requests per minute for any single domain (default: 30)
+    #[serde(default = "super::super::defaults::default_domain_requests_per_minute")]
+    pub domain_requests_per_minute: u32,
+
+    /// Per-domain overrides in requests per minute (e.g., {"dice.com": 10}).
+    /// An entry also covers the domain's subdomains.
+    #[serde(default)]
+    pub domains: BTreeMap<String, u32>,
+}
+
+impl Default for SourceRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            global_requests_per_minute: default_global_requests_per_minute(),
+            domain_requests_per_minute: default_domain_requests_per_minute(),
+            domains: BTreeMap::new(),
+        }
+    }
+}
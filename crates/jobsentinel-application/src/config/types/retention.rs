@@ -0,0 +1,53 @@
+use chrono::{DateTime, Duration, Utc};
+use jobsentinel_storage::RetentionPolicy;
+use serde::{Deserialize, Serialize};
+
+/// Rules for deleting old jobs.
+///
+/// Jobs behind a tracked application or automation attempt, and jobs with
+/// tags or feedback, are always kept. The background purge only runs while
+/// `enabled` is set; `purge_now` applies the rules either way.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RetentionConfig {
+    /// Purge old jobs once a day in the background
+    pub enabled: bool,
+
+    /// Delete jobs no scrape has seen for this many days (default: 90)
+    pub unseen_for_days: u32,
+
+    /// Only delete jobs scoring below this (0.0 - 1.0). Unscored jobs always
+    /// qualify; unset deletes old jobs of any score.
+    pub below_score: Option<f64>,
+
+    /// Keep bookmarked jobs however old they are (default: true)
+    pub keep_bookmarked: bool,
+
+    /// Keep jobs with notes however old they are (default: true)
+    pub keep_with_notes: bool,
+}
+
+impl RetentionConfig {
+    /// The storage policy these rules describe as of `now`.
+    #[must_use]
+    pub fn policy(&self, now: DateTime<Utc>) -> RetentionPolicy {
+        RetentionPolicy {
+            last_seen_before: now - Duration::days(i64::from(self.unseen_for_days)),
+            below_score: self.below_score,
+            keep_bookmarked: self.keep_bookmarked,
+            keep_with_notes: self.keep_with_notes,
+        }
+    }
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            unseen_for_days: 90,
+            below_score: None,
+            keep_bookmarked: true,
+            keep_with_notes: true,
+        }
+    }
+}
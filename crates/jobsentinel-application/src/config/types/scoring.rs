@@ -0,0 +1,83 @@
+use jobsentinel_domain::ScoringConfig;
+use serde::{Deserialize, Serialize};
+
+/// Named set of scoring weights, e.g. "Backend roles" or "Management roles".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// Name shown when switching profiles. Unique, compared case-insensitively.
+    pub name: String,
+
+    /// Weights applied while this profile is active
+    pub weights: ScoringConfig,
+}
+
+/// Scoring weights and the named profiles a user can switch between.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ScoringPreferences {
+    /// Weights used when no profile is active
+    #[serde(default)]
+    pub weights: ScoringConfig,
+
+    /// Saved weight profiles
+    #[serde(default)]
+    pub profiles: Vec<ScoringProfile>,
+
+    /// Name of the active profile. `None` uses `weights`.
+    #[serde(default)]
+    pub active_profile: Option<String>,
+}
+
+impl ScoringPreferences {
+    /// Profile with this name, ignoring case and surrounding whitespace.
+    #[must_use]
+    pub fn profile(&self, name: &str) -> Option<&ScoringProfile> {
+        let name = name.trim();
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    /// Weights scoring should use right now.
+    ///
+    /// An active profile that no longer exists falls back to `weights`.
+    #[must_use]
+    pub fn active_weights(&self) -> &ScoringConfig {
+        self.active_profile
+            .as_deref()
+            .and_then(|name| self.profile(name))
+            .map_or(&self.weights, |profile| &profile.weights)
+    }
+
+    /// Add a profile, or replace the weights of the one with the same name.
+    pub fn upsert_profile(&mut self, profile: ScoringProfile) {
+        let name = profile.name.trim();
+        match self
+            .profiles
+            .iter_mut()
+            .find(|existing| existing.name.trim().eq_ignore_ascii_case(name))
+        {
+            Some(existing) => existing.weights = profile.weights,
+            None => self.profiles.push(ScoringProfile {
+                name: name.to_string(),
+                weights: profile.weights,
+            }),
+        }
+    }
+
+    /// Remove a profile. Returns whether one was removed; removing the
+    /// active profile switches scoring back to `weights`.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        let before = self.profiles.len();
+        self.profiles
+            .retain(|profile| !profile.name.trim().eq_ignore_ascii_case(name));
+        if self
+            .active_profile
+            .as_deref()
+            .is_some_and(|active| active.trim().eq_ignore_ascii_case(name))
+        {
+            self.active_profile = None;
+        }
+        self.profiles.len() != before
+    }
+}
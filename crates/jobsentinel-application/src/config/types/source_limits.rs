@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Per-source scheduling limits for a scraping cycle.
+///
+/// Keyed by scheduled source id (e.g., "dice", "greenhouse"). Sources without
+/// an entry keep the default priority and return every result.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SourceLimitsConfig(pub BTreeMap<String, SourceLimit>);
+
+/// Priority and result quota for one source.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct SourceLimit {
+    /// Higher priorities run first. Ties keep the built-in source order.
+    #[serde(default)]
+    pub priority: u8,
+
+    /// Maximum results accepted from this source per cycle. Results past the
+    /// quota are held and offered first on the next cycle.
+    #[serde(default)]
+    pub max_results_per_run: Option<usize>,
+}
+
+impl SourceLimitsConfig {
+    /// Priority for `source_id` (0 when unset).
+    #[must_use]
+    pub fn priority(&self, source_id: &str) -> u8 {
+        self.0.get(source_id).map_or(0, |limit| limit.priority)
+    }
+
+    /// Per-cycle result quota for `source_id`, if any.
+    #[must_use]
+    pub fn quota(&self, source_id: &str) -> Option<usize> {
+        self.0
+            .get(source_id)
+            .and_then(|limit| limit.max_results_per_run)
+    }
+
+    /// Order `source_ids` by descending priority, keeping ties stable.
+    #[must_use]
+    pub fn ordered<'a>(&self, source_ids: &[&'a str]) -> Vec<&'a str> {
+        let mut ordered = source_ids.to_vec();
+        ordered.sort_by_key(|source_id| std::cmp::Reverse(self.priority(source_id)));
+        ordered
+    }
+}
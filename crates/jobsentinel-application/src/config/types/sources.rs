@@ -2,6 +2,71 @@ use jobsentinel_security::redacted_secret_for_debug;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Stable ids of every source the scheduler can check, in default run order.
+pub const SCHEDULED_SOURCE_IDS: &[&str] = &[
+    "greenhouse",
+    "lever",
+    "career_pages",
+    "jobswithgpt",
+    "remoteok",
+    "weworkremotely",
+    "builtin",
+    "hn_hiring",
+    "dice",
+    "yc_startup",
+    "usajobs",
+    "simplyhired",
+    "glassdoor",
+    "arbeitnow",
+    "guardian_jobs",
+];
+
+/// A job board that lists roles in specific countries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountryBoard {
+    /// Scheduled source id
+    pub id: &'static str,
+    pub name: &'static str,
+    /// ISO codes of the countries the board covers
+    pub countries: &'static [&'static str],
+}
+
+/// Country boards that can be attached to a country preference.
+///
+/// Arbeitnow covers Germany, Austria, and Switzerland; Guardian Jobs covers
+/// the United Kingdom. Otta and Welcome to the Jungle are not listed: neither
+/// offers a public feed, so they stay reachable through job-site search links
+/// instead.
+pub const COUNTRY_BOARDS: &[CountryBoard] = &[
+    CountryBoard {
+        id: "arbeitnow",
+        name: "Arbeitnow",
+        countries: &["DE", "AT", "CH"],
+    },
+    CountryBoard {
+        id: "guardian_jobs",
+        name: "Guardian Jobs",
+        countries: &["GB"],
+    },
+];
+
+/// A company career page checked on schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CareerPageConfig {
+    /// Career page listing the company's openings (https only)
+    pub url: String,
+
+    /// Company name for jobs that do not name their employer. Defaults to
+    /// the page's host name.
+    #[serde(default)]
+    pub company: String,
+
+    /// Load the page in headless Chrome before reading it. Needed for boards
+    /// that draw their job list with JavaScript.
+    #[serde(default)]
+    pub render: bool,
+}
+
 /// LinkedIn search-link configuration.
 ///
 /// Hidden background monitoring is not run. LinkedIn can still be opened
@@ -89,7 +154,8 @@ pub struct WeWorkRemotelyConfig {
 /// No authentication required.
 ///
 /// Note: BuiltIn changed their URL structure in late 2025.
-/// City-specific URLs no longer work. Use remote_only filter instead.
+/// City-specific URLs and regional sites no longer work; cities are sent as
+/// search filters instead.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct BuiltInConfig {
     /// Enable BuiltIn job scraping
@@ -100,6 +166,15 @@ pub struct BuiltInConfig {
     #[serde(default)]
     pub remote_only: bool,
 
+    /// Keyword search (e.g., "platform engineer"). Empty lists all jobs.
+    #[serde(default)]
+    pub query: String,
+
+    /// Cities to search (e.g., "Denver, CO", or a former BuiltIn site such
+    /// as "nyc" or "sf"). Empty searches nationwide.
+    #[serde(default)]
+    pub cities: Vec<String>,
+
     /// Maximum results to return (default: 50)
     #[serde(default = "super::super::defaults::default_scraper_limit")]
     pub limit: usize,
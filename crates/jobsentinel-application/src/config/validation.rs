@@ -1,8 +1,18 @@
 //! Configuration validation logic
 
 mod alerts;
+mod career_pages;
+mod commute;
+mod countries;
 mod external_ai;
+mod power_saving;
+mod proxy;
+mod rate_limits;
+mod retention;
+mod salary;
+mod scoring;
 mod scrapers;
+mod source_limits;
 
 use super::types::Config;
 use super::validation_error::{ValidationError, ValidationErrors};
@@ -11,18 +21,28 @@ use jobsentinel_sources::{parse_greenhouse_company_url, parse_lever_company_url}
 
 const MIN_BOOKMARKLET_PORT: u16 = 1024;
 const MAX_BOOKMARKLET_PORT: u16 = u16::MAX;
+const MAX_SCRAPING_CONCURRENCY: usize = 8;
 
 pub(super) fn validate_config(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     let mut errors = ValidationErrors::new();
 
     validate_core_settings(config, &mut errors);
-    validate_salary(config, &mut errors);
+    salary::validate_salary(config, &mut errors);
     validate_lists(config, &mut errors);
     validate_location(config, &mut errors);
+    commute::validate_commute(config, &mut errors);
+    countries::validate_countries(config, &mut errors);
     alerts::validate_alerts(config, &mut errors);
     scrapers::validate_scrapers(config, &mut errors);
     external_ai::validate_external_ai(config, &mut errors);
+    proxy::validate_proxy(config, &mut errors);
+    source_limits::validate_source_limits(config, &mut errors);
+    rate_limits::validate_rate_limits(config, &mut errors);
+    power_saving::validate_power_saving(config, &mut errors);
+    scoring::validate_scoring(config, &mut errors);
+    retention::validate_retention(config, &mut errors);
     validate_urls(config, &mut errors);
+    career_pages::validate_career_pages(config, &mut errors);
 
     if errors.is_empty() {
         Ok(())
@@ -52,6 +72,15 @@ fn validate_core_settings(config: &Config, errors: &mut ValidationErrors) {
         ));
     }
 
+    if config.scraping_concurrency < 1 || config.scraping_concurrency > MAX_SCRAPING_CONCURRENCY {
+        errors.add(ValidationError::out_of_range(
+            "scraping_concurrency",
+            config.scraping_concurrency,
+            Some(1_usize),
+            Some(MAX_SCRAPING_CONCURRENCY),
+        ));
+    }
+
     if config.auto_refresh.enabled && config.auto_refresh.interval_minutes == 0 {
         errors.add(ValidationError::out_of_range(
             "auto_refresh.interval_minutes",
@@ -71,60 +100,6 @@ fn validate_core_settings(config: &Config, errors: &mut ValidationErrors) {
     }
 }
 
-/// Validate salary configuration
-fn validate_salary(config: &Config, errors: &mut ValidationErrors) {
-    // Validate salary floor (must be non-negative)
-    if config.salary_floor_usd < 0 {
-        errors.add(ValidationError::out_of_range(
-            "salary_floor_usd",
-            config.salary_floor_usd,
-            Some(0_i64),
-            Some(10_000_000_i64),
-        ));
-    }
-
-    // Validate salary is reasonable (less than $10M USD)
-    if config.salary_floor_usd > 10_000_000 {
-        errors.add(ValidationError::out_of_range(
-            "salary_floor_usd",
-            config.salary_floor_usd,
-            Some(0_i64),
-            Some(10_000_000_i64),
-        ));
-    }
-
-    // Validate salary_target_usd if set
-    if let Some(target) = config.salary_target_usd {
-        if target < 0 {
-            errors.add(ValidationError::out_of_range(
-                "salary_target_usd",
-                target,
-                Some(0_i64),
-                Some(10_000_000_i64),
-            ));
-        }
-        if target > 10_000_000 {
-            errors.add(ValidationError::out_of_range(
-                "salary_target_usd",
-                target,
-                Some(0_i64),
-                Some(10_000_000_i64),
-            ));
-        }
-        // Validate that target >= floor
-        if target < config.salary_floor_usd {
-            errors.add(ValidationError::inconsistent_values(
-                "salary_target_usd",
-                "salary_floor_usd",
-                format!(
-                    "salary_target_usd ({}) must be >= salary_floor_usd ({})",
-                    target, config.salary_floor_usd
-                ),
-            ));
-        }
-    }
-}
-
 /// Validate lists (title allowlist/blocklist, keywords, companies)
 fn validate_lists(config: &Config, errors: &mut ValidationErrors) {
     const MAX_TITLE_LENGTH: usize = 200;
@@ -1,77 +1,73 @@
-use crate::config::types::Config;
+use crate::config::types::{Config, CustomWebhookConfig, EmailDigestConfig, WeeklyReportConfig};
 use crate::config::validation::is_valid_email;
 use crate::config::validation_error::{ValidationError, ValidationErrors};
+use jobsentinel_security::validate_external_https_url;
+
+const MAX_EMAIL_LENGTH: usize = 100;
 
 /// Validate alert configuration
 pub(super) fn validate_alerts(config: &Config, errors: &mut ValidationErrors) {
-    const MAX_EMAIL_LENGTH: usize = 100;
     const MAX_HOSTNAME_LENGTH: usize = 100;
     const MAX_CHAT_ID_LENGTH: usize = 50;
 
-    // Validate Email configuration if enabled
-    if config.alerts.email.enabled {
-        if config.alerts.email.smtp_server.is_empty() {
+    // Validate Email configuration if immediate alerts or digests are enabled
+    let email = &config.alerts.email;
+    if email.enabled || email.digest.enabled {
+        if email.smtp_server.is_empty() {
             errors.add(ValidationError::required_field(
                 "alerts.email.smtp_server",
                 "required when email alerts are enabled",
             ));
-        } else if config.alerts.email.smtp_server.len() > MAX_HOSTNAME_LENGTH {
+        } else if email.smtp_server.len() > MAX_HOSTNAME_LENGTH {
             errors.add(ValidationError::too_long(
                 "alerts.email.smtp_server",
-                config.alerts.email.smtp_server.len(),
+                email.smtp_server.len(),
                 MAX_HOSTNAME_LENGTH,
             ));
         }
 
         // Port 0 is accepted here because serde applies the stored default when loading config.
 
-        if config.alerts.email.smtp_username.is_empty() {
+        if email.smtp_username.is_empty() {
             errors.add(ValidationError::required_field(
                 "alerts.email.smtp_username",
                 "required when email alerts are enabled",
             ));
         }
 
-        if config.alerts.email.from_email.is_empty() {
+        if email.from_email.is_empty() {
             errors.add(ValidationError::required_field(
                 "alerts.email.from_email",
                 "required when email alerts are enabled",
             ));
-        } else if !is_valid_email(&config.alerts.email.from_email) {
+        } else if !is_valid_email(&email.from_email) {
             errors.add(ValidationError::invalid_email(
                 "alerts.email.from_email",
-                &config.alerts.email.from_email,
+                &email.from_email,
             ));
         }
 
-        if config.alerts.email.to_emails.is_empty() {
-            errors.add(ValidationError::required_field(
-                "alerts.email.to_emails",
-                "at least one recipient email is required when email alerts are enabled",
-            ));
-        } else {
-            for (i, email) in config.alerts.email.to_emails.iter().enumerate() {
-                if email.is_empty() {
-                    errors.add(ValidationError::empty_string(format!(
-                        "alerts.email.to_emails[{}]",
-                        i
-                    )));
-                } else if !is_valid_email(email) {
-                    errors.add(ValidationError::invalid_email(
-                        format!("alerts.email.to_emails[{}]", i),
-                        email,
-                    ));
-                } else if email.len() > MAX_EMAIL_LENGTH {
-                    errors.add(ValidationError::too_long(
-                        format!("alerts.email.to_emails[{}]", i),
-                        email.len(),
-                        MAX_EMAIL_LENGTH,
-                    ));
-                }
+        // Digests fall back to the alert recipients when they have none of their own.
+        if email.enabled || email.digest.recipients.is_empty() {
+            if email.to_emails.is_empty() {
+                errors.add(ValidationError::required_field(
+                    "alerts.email.to_emails",
+                    "at least one recipient email is required when email alerts are enabled",
+                ));
+            } else {
+                validate_recipients("alerts.email.to_emails", &email.to_emails, errors);
             }
         }
     }
 
+    if email.digest.enabled {
+        validate_email_digest(&email.digest, errors);
+    }
+
+    if config.alerts.weekly_report.enabled {
+        validate_weekly_report(&config.alerts.weekly_report, errors);
+    }
+
     // Validate Discord configuration
     if config.alerts.discord.enabled {
         if let Some(user_id) = &config.alerts.discord.user_id_to_mention {
@@ -101,4 +97,152 @@ pub(super) fn validate_alerts(config: &Config, errors: &mut ValidationErrors) {
             ));
         }
     }
+
+    if config.alerts.webhook.enabled {
+        validate_custom_webhook(&config.alerts.webhook, errors);
+    }
+
+    // Validate ntfy configuration
+    let ntfy = &config.alerts.ntfy;
+    if ntfy.enabled {
+        if let Err(reason) = validate_external_https_url(&ntfy.server_url) {
+            errors.add(ValidationError::invalid_url(
+                "alerts.ntfy.server_url",
+                &ntfy.server_url,
+                reason,
+            ));
+        }
+
+        if !(1..=5).contains(&ntfy.priority) {
+            errors.add(ValidationError::out_of_range(
+                "alerts.ntfy.priority",
+                ntfy.priority,
+                Some(1_u8),
+                Some(5_u8),
+            ));
+        }
+    }
+
+    // Validate Pushover configuration; priority 2 needs retry settings we do not send
+    let pushover = &config.alerts.pushover;
+    if pushover.enabled && !(-2..=1).contains(&pushover.priority) {
+        errors.add(ValidationError::out_of_range(
+            "alerts.pushover.priority",
+            pushover.priority,
+            Some(-2_i8),
+            Some(1_i8),
+        ));
+    }
+}
+
+fn validate_recipients(field: &str, emails: &[String], errors: &mut ValidationErrors) {
+    for (i, email) in emails.iter().enumerate() {
+        if email.is_empty() {
+            errors.add(ValidationError::empty_string(format!("{}[{}]", field, i)));
+        } else if !is_valid_email(email) {
+            errors.add(ValidationError::invalid_email(
+                format!("{}[{}]", field, i),
+                email,
+            ));
+        } else if email.len() > MAX_EMAIL_LENGTH {
+            errors.add(ValidationError::too_long(
+                format!("{}[{}]", field, i),
+                email.len(),
+                MAX_EMAIL_LENGTH,
+            ));
+        }
+    }
+}
+
+/// Validate the email digest schedule and filters
+fn validate_email_digest(digest: &EmailDigestConfig, errors: &mut ValidationErrors) {
+    if digest.send_hour > 23 {
+        errors.add(ValidationError::out_of_range(
+            "alerts.email.digest.send_hour",
+            digest.send_hour,
+            Some(0_u32),
+            Some(23_u32),
+        ));
+    }
+
+    if !(1..=7).contains(&digest.send_weekday) {
+        errors.add(ValidationError::out_of_range(
+            "alerts.email.digest.send_weekday",
+            digest.send_weekday,
+            Some(1_u32),
+            Some(7_u32),
+        ));
+    }
+
+    if !digest.min_score.is_finite() || !(0.0..=1.0).contains(&digest.min_score) {
+        errors.add(ValidationError::out_of_range(
+            "alerts.email.digest.min_score",
+            digest.min_score,
+            Some(0),
+            Some(1),
+        ));
+    }
+
+    validate_recipients("alerts.email.digest.recipients", &digest.recipients, errors);
+}
+
+/// Validate the weekly report schedule
+fn validate_weekly_report(report: &WeeklyReportConfig, errors: &mut ValidationErrors) {
+    if !(1..=7).contains(&report.send_weekday) {
+        errors.add(ValidationError::out_of_range(
+            "alerts.weekly_report.send_weekday",
+            report.send_weekday,
+            Some(1_u32),
+            Some(7_u32),
+        ));
+    }
+
+    if report.send_hour > 23 {
+        errors.add(ValidationError::out_of_range(
+            "alerts.weekly_report.send_hour",
+            report.send_hour,
+            Some(0_u32),
+            Some(23_u32),
+        ));
+    }
+}
+
+/// Validate custom webhook URLs and the payload template
+fn validate_custom_webhook(webhook: &CustomWebhookConfig, errors: &mut ValidationErrors) {
+    const MAX_WEBHOOK_URLS: usize = 10;
+    const MAX_URL_LENGTH: usize = 500;
+
+    if webhook.urls.is_empty() {
+        errors.add(ValidationError::required_field(
+            "alerts.webhook.urls",
+            "at least one URL is required when custom webhook alerts are enabled",
+        ));
+    } else if webhook.urls.len() > MAX_WEBHOOK_URLS {
+        errors.add(ValidationError::too_many_elements(
+            "alerts.webhook.urls",
+            webhook.urls.len(),
+            MAX_WEBHOOK_URLS,
+        ));
+    }
+
+    for (i, url) in webhook.urls.iter().enumerate() {
+        let field = format!("alerts.webhook.urls[{i}]");
+        if url.is_empty() {
+            errors.add(ValidationError::empty_string(field));
+        } else if url.len() > MAX_URL_LENGTH {
+            errors.add(ValidationError::too_long(field, url.len(), MAX_URL_LENGTH));
+        } else if let Err(reason) = validate_external_https_url(url) {
+            errors.add(ValidationError::invalid_url(field, url, reason));
+        }
+    }
+
+    if let Some(template) = &webhook.payload_template {
+        if let Err(reason) = jobsentinel_notifications::validate_webhook_template(template) {
+            errors.add(ValidationError::invalid_value(
+                "alerts.webhook.payload_template",
+                "template",
+                reason,
+            ));
+        }
+    }
 }
@@ -0,0 +1,41 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+use jobsentinel_security::validate_external_https_url;
+
+/// Validate company career pages
+pub(super) fn validate_career_pages(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_CAREER_PAGES: usize = 50;
+    const MAX_URL_LENGTH: usize = 500;
+    const MAX_COMPANY_LENGTH: usize = 200;
+
+    if config.career_pages.len() > MAX_CAREER_PAGES {
+        errors.add(ValidationError::too_many_elements(
+            "career_pages",
+            config.career_pages.len(),
+            MAX_CAREER_PAGES,
+        ));
+    }
+
+    for (i, page) in config.career_pages.iter().enumerate() {
+        let field = format!("career_pages[{i}].url");
+        if page.url.is_empty() {
+            errors.add(ValidationError::empty_string(field));
+        } else if page.url.len() > MAX_URL_LENGTH {
+            errors.add(ValidationError::too_long(
+                field,
+                page.url.len(),
+                MAX_URL_LENGTH,
+            ));
+        } else if let Err(reason) = validate_external_https_url(&page.url) {
+            errors.add(ValidationError::invalid_url(field, &page.url, reason));
+        }
+
+        if page.company.len() > MAX_COMPANY_LENGTH {
+            errors.add(ValidationError::too_long(
+                format!("career_pages[{i}].company"),
+                page.company.len(),
+                MAX_COMPANY_LENGTH,
+            ));
+        }
+    }
+}
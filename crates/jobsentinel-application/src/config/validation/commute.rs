@@ -0,0 +1,39 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate home coordinates and the commute radius
+pub(super) fn validate_commute(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_COMMUTE_KM: f64 = 500.0;
+
+    let commute = &config.commute;
+    if let Some(home) = commute.home {
+        if !home.latitude.is_finite() || !(-90.0..=90.0).contains(&home.latitude) {
+            errors.add(ValidationError::out_of_range(
+                "commute.home.latitude",
+                home.latitude,
+                Some(-90),
+                Some(90),
+            ));
+        }
+        if !home.longitude.is_finite() || !(-180.0..=180.0).contains(&home.longitude) {
+            errors.add(ValidationError::out_of_range(
+                "commute.home.longitude",
+                home.longitude,
+                Some(-180),
+                Some(180),
+            ));
+        }
+    }
+
+    if !commute.max_distance_km.is_finite()
+        || commute.max_distance_km <= 0.0
+        || commute.max_distance_km > MAX_COMMUTE_KM
+    {
+        errors.add(ValidationError::out_of_range(
+            "commute.max_distance_km",
+            commute.max_distance_km,
+            Some(0),
+            Some(MAX_COMMUTE_KM),
+        ));
+    }
+}
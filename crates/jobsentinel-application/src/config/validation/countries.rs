@@ -0,0 +1,64 @@
+use crate::config::types::sources::COUNTRY_BOARDS;
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate extra countries and the country boards attached to them
+pub(super) fn validate_countries(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_COUNTRIES: usize = 30;
+    const MAX_BOARDS_PER_COUNTRY: usize = 10;
+
+    let countries = &config.location_preferences.countries;
+    if countries.len() > MAX_COUNTRIES {
+        errors.add(ValidationError::too_many_elements(
+            "location_preferences.countries",
+            countries.len(),
+            MAX_COUNTRIES,
+        ));
+    }
+
+    for (i, country) in countries.iter().enumerate() {
+        let field = format!("location_preferences.countries[{}]", i);
+        let code = country.code.trim();
+        if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            errors.add(ValidationError::invalid_value(
+                format!("{field}.code"),
+                code,
+                "must be a two-letter country code such as \"DE\" or \"GB\"",
+            ));
+            continue;
+        }
+
+        if country.boards.len() > MAX_BOARDS_PER_COUNTRY {
+            errors.add(ValidationError::too_many_elements(
+                format!("{field}.boards"),
+                country.boards.len(),
+                MAX_BOARDS_PER_COUNTRY,
+            ));
+        }
+        for (j, board_id) in country.boards.iter().enumerate() {
+            let Some(board) = COUNTRY_BOARDS.iter().find(|board| board.id == board_id) else {
+                errors.add(ValidationError::invalid_value(
+                    format!("{field}.boards[{}]", j),
+                    board_id,
+                    "must be a known country board such as \"arbeitnow\"",
+                ));
+                continue;
+            };
+            if !board
+                .countries
+                .iter()
+                .any(|covered| covered.eq_ignore_ascii_case(code))
+            {
+                errors.add(ValidationError::invalid_value(
+                    format!("{field}.boards[{}]", j),
+                    board_id,
+                    format!(
+                        "{} does not list jobs in {}",
+                        board.name,
+                        code.to_uppercase()
+                    ),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate the low battery and metered connection rules
+pub(super) fn validate_power_saving(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_REDUCED_SOURCES: usize = 20;
+
+    let power_saving = &config.power_saving;
+    if !(1..=100).contains(&power_saving.battery_threshold_percent) {
+        errors.add(ValidationError::out_of_range(
+            "power_saving.battery_threshold_percent",
+            power_saving.battery_threshold_percent,
+            Some(1_u8),
+            Some(100_u8),
+        ));
+    }
+
+    if !(1..=MAX_REDUCED_SOURCES).contains(&power_saving.reduced_source_count) {
+        errors.add(ValidationError::out_of_range(
+            "power_saving.reduced_source_count",
+            power_saving.reduced_source_count,
+            Some(1_usize),
+            Some(MAX_REDUCED_SOURCES),
+        ));
+    }
+}
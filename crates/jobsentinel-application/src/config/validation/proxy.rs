@@ -0,0 +1,51 @@
+use crate::config::types::sources::SCHEDULED_SOURCE_IDS;
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+use jobsentinel_network::OutboundProxy;
+
+/// Validate the optional scraping proxy
+pub(super) fn validate_proxy(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_URL_LENGTH: usize = 500;
+    const MAX_USERNAME_LENGTH: usize = 200;
+
+    let proxy = &config.proxy;
+    if !proxy.enabled {
+        return;
+    }
+
+    if proxy.url.trim().is_empty() {
+        errors.add(ValidationError::required_field(
+            "proxy.url",
+            "required when the scraping proxy is enabled",
+        ));
+    } else if proxy.url.len() > MAX_URL_LENGTH {
+        errors.add(ValidationError::too_long(
+            "proxy.url",
+            proxy.url.len(),
+            MAX_URL_LENGTH,
+        ));
+    } else if let Err(reason) = OutboundProxy::parse(&proxy.url) {
+        // The URL is never echoed because it may contain pasted credentials.
+        errors.add(ValidationError::invalid_url("proxy.url", "", reason));
+    }
+
+    if let Some(username) = proxy.username.as_deref() {
+        if username.len() > MAX_USERNAME_LENGTH {
+            errors.add(ValidationError::too_long(
+                "proxy.username",
+                username.len(),
+                MAX_USERNAME_LENGTH,
+            ));
+        }
+    }
+
+    for (i, source) in proxy.sources.iter().enumerate() {
+        if !SCHEDULED_SOURCE_IDS.contains(&source.trim()) {
+            errors.add(ValidationError::invalid_value(
+                format!("proxy.sources[{}]", i),
+                source,
+                "must be a scheduled source id such as \"dice\" or \"greenhouse\"",
+            ));
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate source request budgets
+pub(super) fn validate_rate_limits(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_REQUESTS_PER_MINUTE: u32 = 600;
+    const MAX_DOMAIN_OVERRIDES: usize = 100;
+    const MAX_DOMAIN_LENGTH: usize = 253;
+
+    let rate_limits = &config.rate_limits;
+    let mut check_budget = |field: String, value: u32| {
+        if value == 0 || value > MAX_REQUESTS_PER_MINUTE {
+            errors.add(ValidationError::out_of_range(
+                field,
+                value,
+                Some(1_u32),
+                Some(MAX_REQUESTS_PER_MINUTE),
+            ));
+        }
+    };
+
+    check_budget(
+        "rate_limits.global_requests_per_minute".to_string(),
+        rate_limits.global_requests_per_minute,
+    );
+    check_budget(
+        "rate_limits.domain_requests_per_minute".to_string(),
+        rate_limits.domain_requests_per_minute,
+    );
+    for (domain, per_minute) in &rate_limits.domains {
+        check_budget(format!("rate_limits.domains.{domain}"), *per_minute);
+    }
+
+    if rate_limits.domains.len() > MAX_DOMAIN_OVERRIDES {
+        errors.add(ValidationError::too_many_elements(
+            "rate_limits.domains",
+            rate_limits.domains.len(),
+            MAX_DOMAIN_OVERRIDES,
+        ));
+    }
+
+    for domain in rate_limits.domains.keys() {
+        let is_hostname = domain.len() <= MAX_DOMAIN_LENGTH
+            && domain.contains('.')
+            && domain.split('.').all(|label| {
+                !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+        if !is_hostname {
+            errors.add(ValidationError::invalid_value(
+                "rate_limits.domains",
+                domain,
+                "must be a domain name such as \"dice.com\" without a scheme or path",
+            ));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate the job retention rules
+pub(super) fn validate_retention(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_UNSEEN_DAYS: u32 = 3650;
+
+    let retention = &config.retention;
+    if !(1..=MAX_UNSEEN_DAYS).contains(&retention.unseen_for_days) {
+        errors.add(ValidationError::out_of_range(
+            "retention.unseen_for_days",
+            retention.unseen_for_days,
+            Some(1_u32),
+            Some(MAX_UNSEEN_DAYS),
+        ));
+    }
+
+    if let Some(below_score) = retention.below_score {
+        if !below_score.is_finite() || !(0.0..=1.0).contains(&below_score) {
+            errors.add(ValidationError::out_of_range(
+                "retention.below_score",
+                below_score,
+                Some(0),
+                Some(1),
+            ));
+        }
+    }
+}
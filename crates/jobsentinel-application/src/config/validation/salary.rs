@@ -0,0 +1,76 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate salary configuration
+pub(super) fn validate_salary(config: &Config, errors: &mut ValidationErrors) {
+    // Validate salary floor (must be non-negative)
+    if config.salary_floor_usd < 0 {
+        errors.add(ValidationError::out_of_range(
+            "salary_floor_usd",
+            config.salary_floor_usd,
+            Some(0_i64),
+            Some(10_000_000_i64),
+        ));
+    }
+
+    // Validate salary is reasonable (less than $10M USD)
+    if config.salary_floor_usd > 10_000_000 {
+        errors.add(ValidationError::out_of_range(
+            "salary_floor_usd",
+            config.salary_floor_usd,
+            Some(0_i64),
+            Some(10_000_000_i64),
+        ));
+    }
+
+    // Validate salary_target_usd if set
+    if let Some(target) = config.salary_target_usd {
+        if target < 0 {
+            errors.add(ValidationError::out_of_range(
+                "salary_target_usd",
+                target,
+                Some(0_i64),
+                Some(10_000_000_i64),
+            ));
+        }
+        if target > 10_000_000 {
+            errors.add(ValidationError::out_of_range(
+                "salary_target_usd",
+                target,
+                Some(0_i64),
+                Some(10_000_000_i64),
+            ));
+        }
+        // Validate that target >= floor
+        if target < config.salary_floor_usd {
+            errors.add(ValidationError::inconsistent_values(
+                "salary_target_usd",
+                "salary_floor_usd",
+                format!(
+                    "salary_target_usd ({}) must be >= salary_floor_usd ({})",
+                    target, config.salary_floor_usd
+                ),
+            ));
+        }
+    }
+
+    if let Some(target_max) = config.salary_target_max_usd {
+        let range_start = config.salary_target_usd.unwrap_or(config.salary_floor_usd);
+        if !(0..=10_000_000).contains(&target_max) {
+            errors.add(ValidationError::out_of_range(
+                "salary_target_max_usd",
+                target_max,
+                Some(0_i64),
+                Some(10_000_000_i64),
+            ));
+        } else if target_max <= range_start {
+            errors.add(ValidationError::inconsistent_values(
+                "salary_target_max_usd",
+                "salary_target_usd",
+                format!(
+                    "salary_target_max_usd ({target_max}) must be > the target or floor ({range_start})"
+                ),
+            ));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+use std::collections::HashSet;
+
+/// Validate scoring weights and named scoring profiles
+pub(super) fn validate_scoring(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_PROFILES: usize = 50;
+    const MAX_PROFILE_NAME_LENGTH: usize = 100;
+
+    let scoring = &config.scoring;
+    if let Err(reason) = scoring.weights.validate() {
+        errors.add(ValidationError::invalid_value(
+            "scoring.weights",
+            format!("{:.2}", scoring.weights.sum()),
+            reason,
+        ));
+    }
+
+    if scoring.profiles.len() > MAX_PROFILES {
+        errors.add(ValidationError::too_many_elements(
+            "scoring.profiles",
+            scoring.profiles.len(),
+            MAX_PROFILES,
+        ));
+    }
+
+    let mut seen_names = HashSet::new();
+    for (i, profile) in scoring.profiles.iter().enumerate() {
+        let name = profile.name.trim();
+        if name.is_empty() {
+            errors.add(ValidationError::empty_string(format!(
+                "scoring.profiles[{}].name",
+                i
+            )));
+        } else if name.len() > MAX_PROFILE_NAME_LENGTH {
+            errors.add(ValidationError::too_long(
+                format!("scoring.profiles[{}].name", i),
+                name.len(),
+                MAX_PROFILE_NAME_LENGTH,
+            ));
+        } else if !seen_names.insert(name.to_lowercase()) {
+            errors.add(ValidationError::invalid_value(
+                format!("scoring.profiles[{}].name", i),
+                name,
+                "another scoring profile already uses this name",
+            ));
+        }
+
+        if let Err(reason) = profile.weights.validate() {
+            errors.add(ValidationError::invalid_value(
+                format!("scoring.profiles[{}].weights", i),
+                format!("{:.2}", profile.weights.sum()),
+                reason,
+            ));
+        }
+    }
+
+    if let Some(active) = scoring.active_profile.as_deref() {
+        if scoring.profile(active).is_none() {
+            errors.add(ValidationError::invalid_value(
+                "scoring.active_profile",
+                active,
+                "must name a saved scoring profile",
+            ));
+        }
+    }
+}
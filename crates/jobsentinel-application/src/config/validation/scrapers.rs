@@ -8,6 +8,7 @@ pub(super) fn validate_scrapers(config: &Config, errors: &mut ValidationErrors)
     const MAX_QUERY_LENGTH: usize = 200;
     const MAX_LOCATION_LENGTH: usize = 100;
     const MAX_EMAIL_LENGTH: usize = 100;
+    const MAX_BUILTIN_CITIES: usize = 10;
 
     // Validate RemoteOK scraper
     if config.remoteok.enabled
@@ -34,15 +35,40 @@ pub(super) fn validate_scrapers(config: &Config, errors: &mut ValidationErrors)
     }
 
     // Validate BuiltIn scraper
-    if config.builtin.enabled
-        && (config.builtin.limit == 0 || config.builtin.limit > MAX_SCRAPER_LIMIT)
-    {
-        errors.add(ValidationError::out_of_range(
-            "builtin.limit",
-            config.builtin.limit,
-            Some(1_usize),
-            Some(MAX_SCRAPER_LIMIT),
-        ));
+    if config.builtin.enabled {
+        if config.builtin.limit == 0 || config.builtin.limit > MAX_SCRAPER_LIMIT {
+            errors.add(ValidationError::out_of_range(
+                "builtin.limit",
+                config.builtin.limit,
+                Some(1_usize),
+                Some(MAX_SCRAPER_LIMIT),
+            ));
+        }
+
+        if config.builtin.query.len() > MAX_QUERY_LENGTH {
+            errors.add(ValidationError::too_long(
+                "builtin.query",
+                config.builtin.query.len(),
+                MAX_QUERY_LENGTH,
+            ));
+        }
+
+        if config.builtin.cities.len() > MAX_BUILTIN_CITIES {
+            errors.add(ValidationError::too_many_elements(
+                "builtin.cities",
+                config.builtin.cities.len(),
+                MAX_BUILTIN_CITIES,
+            ));
+        }
+        for (index, city) in config.builtin.cities.iter().enumerate() {
+            if city.len() > MAX_LOCATION_LENGTH {
+                errors.add(ValidationError::too_long(
+                    format!("builtin.cities[{index}]"),
+                    city.len(),
+                    MAX_LOCATION_LENGTH,
+                ));
+            }
+        }
     }
 
     // Validate HN Hiring scraper
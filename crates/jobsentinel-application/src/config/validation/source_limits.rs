@@ -0,0 +1,30 @@
+use crate::config::types::sources::SCHEDULED_SOURCE_IDS;
+use crate::config::types::Config;
+use crate::config::validation_error::{ValidationError, ValidationErrors};
+
+/// Validate per-source priority and quota settings
+pub(super) fn validate_source_limits(config: &Config, errors: &mut ValidationErrors) {
+    const MAX_RESULTS_PER_RUN: usize = 10_000;
+
+    for (source_id, limit) in &config.source_limits.0 {
+        if !SCHEDULED_SOURCE_IDS.contains(&source_id.as_str()) {
+            errors.add(ValidationError::invalid_value(
+                "source_limits",
+                source_id,
+                "must be a scheduled source id such as \"dice\" or \"greenhouse\"",
+            ));
+            continue;
+        }
+
+        if let Some(max_results) = limit.max_results_per_run {
+            if max_results == 0 || max_results > MAX_RESULTS_PER_RUN {
+                errors.add(ValidationError::out_of_range(
+                    format!("source_limits.{source_id}.max_results_per_run"),
+                    max_results,
+                    Some(1_usize),
+                    Some(MAX_RESULTS_PER_RUN),
+                ));
+            }
+        }
+    }
+}
@@ -22,5 +22,10 @@ pub use jobsentinel_security::{
     path_label_for_logging, sanitize_url_for_logging, validate_external_https_url,
 };
 pub use jobsentinel_sources::{detect_location, LocationInfo};
-pub use jobsentinel_storage::{Database, DuplicateGroup};
+pub use jobsentinel_storage::{
+    BackupKind, CompanyReputation, Database, DatabaseBackup, DatabaseHealth, DuplicateGroup,
+    GhostReanalysisRun, JobCursor, JobFeedback, JobFilter, JobPage, JobTagCount,
+    NearDuplicateGroup, NotificationHistoryEntry, PurgeReport, TimeToFillEstimate, TimeToFillScope,
+    VacationMode, VacationSummary, WeeklyReportRecord,
+};
 pub use startup::{DesktopServices, DesktopStartupError, SchedulerStatus};
@@ -9,10 +9,12 @@ use tokio::sync::RwLock;
 
 use super::{path_label_for_logging, BookmarkletConfig, BookmarkletServer, Database};
 use crate::{
+    config::ConfigRecovery,
     credentials::{
         clear_config_credentials, extract_plaintext_credentials, is_migrated, set_migrated,
         CredentialService,
     },
+    ghost_patterns::load_saved_pattern_pack,
     scheduler::Scheduler,
     Config, PendingUrlImports,
 };
@@ -22,6 +24,9 @@ pub struct SchedulerStatus {
     pub is_running: bool,
     pub last_run: Option<DateTime<Utc>>,
     pub next_run: Option<DateTime<Utc>>,
+    /// Why the last scheduled scrape was skipped or cut down to save power
+    /// or data
+    pub skip_reason: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -51,10 +56,13 @@ pub struct DesktopServices {
     pub bookmarklet_server: Arc<RwLock<BookmarkletServer>>,
     pub pending_url_imports: PendingUrlImports,
     pub is_first_run: bool,
+    /// Set when the saved config was corrupt and a backup was restored
+    pub config_recovery: Option<ConfigRecovery>,
 }
 
 impl DesktopServices {
     pub async fn initialize() -> Result<Self, DesktopStartupError> {
+        load_saved_pattern_pack(&jobsentinel_platform::get_data_dir());
         Self::initialize_at(Config::default_path(), Database::default_path()).await
     }
 
@@ -62,7 +70,7 @@ impl DesktopServices {
         config_path: PathBuf,
         database_path: PathBuf,
     ) -> Result<Self, DesktopStartupError> {
-        let (config, is_first_run) = load_startup_config(&config_path)?;
+        let startup_config = load_startup_config(&config_path)?;
         tracing::info!(
             db_path = %path_label_for_logging(&database_path),
             "Connecting to database"
@@ -71,7 +79,7 @@ impl DesktopServices {
             .await
             .map_err(|error| DesktopStartupError::Database(error.to_string()))?;
 
-        Self::initialize_loaded(config_path, config, is_first_run, database).await
+        Self::initialize_loaded(config_path, startup_config, database).await
     }
 
     #[cfg(test)]
@@ -79,16 +87,20 @@ impl DesktopServices {
         config_path: PathBuf,
         database: Database,
     ) -> Result<Self, DesktopStartupError> {
-        let (config, is_first_run) = load_startup_config(&config_path)?;
-        Self::initialize_loaded(config_path, config, is_first_run, database).await
+        let startup_config = load_startup_config(&config_path)?;
+        Self::initialize_loaded(config_path, startup_config, database).await
     }
 
     async fn initialize_loaded(
         config_path: PathBuf,
-        mut config: Config,
-        is_first_run: bool,
+        startup_config: StartupConfig,
         database: Database,
     ) -> Result<Self, DesktopStartupError> {
+        let StartupConfig {
+            mut config,
+            is_first_run,
+            recovery: config_recovery,
+        } = startup_config;
         database
             .migrate()
             .await
@@ -125,24 +137,40 @@ impl DesktopServices {
             bookmarklet_server,
             pending_url_imports: PendingUrlImports::default(),
             is_first_run,
+            config_recovery,
         })
     }
 }
 
-fn load_startup_config(config_path: &Path) -> Result<(Config, bool), DesktopStartupError> {
+struct StartupConfig {
+    config: Config,
+    is_first_run: bool,
+    recovery: Option<ConfigRecovery>,
+}
+
+fn load_startup_config(config_path: &Path) -> Result<StartupConfig, DesktopStartupError> {
     let is_first_run = !config_path.exists();
     if is_first_run {
         tracing::info!("No configuration file found, first-run setup required");
-        return Ok((Config::first_run(), true));
+        return Ok(StartupConfig {
+            config: Config::first_run(),
+            is_first_run: true,
+            recovery: None,
+        });
     }
 
-    let config = Config::load(config_path)
+    let (config, recovery) = Config::load_with_recovery(config_path)
         .map_err(|error| DesktopStartupError::Configuration(error.to_string()))?;
     tracing::info!(
         config_path = %path_label_for_logging(config_path),
+        recovered = recovery.is_some(),
         "Loaded configuration"
     );
-    Ok((config, false))
+    Ok(StartupConfig {
+        config,
+        is_first_run: false,
+        recovery,
+    })
 }
 
 async fn migrate_plaintext_credentials_to_secure_storage(
@@ -256,4 +284,30 @@ mod tests {
 
         assert!(matches!(result, Err(DesktopStartupError::Configuration(_))));
     }
+
+    #[tokio::test]
+    async fn restores_config_backup_when_saved_config_is_corrupt() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let mut config = Config::first_run();
+        config.salary_floor_usd = 90_000;
+        config.save(&config_path).unwrap();
+        config.salary_floor_usd = 95_000;
+        config.save(&config_path).unwrap();
+        std::fs::write(&config_path, "{\"salary_floor").unwrap();
+
+        let services = DesktopServices::initialize_with_database(
+            config_path,
+            Database::connect_memory().await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert!(!services.is_first_run);
+        assert_eq!(services.config.read().await.salary_floor_usd, 90_000);
+        assert_eq!(
+            services.config_recovery.unwrap().restored_from,
+            "config.json.bak.1"
+        );
+    }
 }
@@ -0,0 +1,351 @@
+//! Streaming job exports
+//!
+//! Jobs matching a filter are read in keyset pages and written straight to
+//! the output, so an export of any size holds one page in memory.
+
+use anyhow::Result;
+use jobsentinel_domain::Job;
+use jobsentinel_storage::{Database, JobFilter};
+use parquet::ParquetWriter;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::io::Write;
+
+mod parquet;
+
+/// Jobs read per page while exporting.
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// File format of a job export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    #[default]
+    #[serde(rename = "csv")]
+    Csv,
+    /// One JSON object per line
+    #[serde(rename = "jsonl")]
+    JsonLines,
+    /// Columnar file with one row group per export page
+    #[serde(rename = "parquet")]
+    Parquet,
+}
+
+impl ExportFormat {
+    /// File extension without the dot
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::JsonLines => "jsonl",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// A job field that can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportColumn {
+    Id,
+    Title,
+    Company,
+    Url,
+    Location,
+    Source,
+    Remote,
+    Score,
+    SalaryMin,
+    SalaryMax,
+    Currency,
+    PayPeriod,
+    Status,
+    Bookmarked,
+    Notes,
+    GhostScore,
+    CreatedAt,
+    LastSeen,
+}
+
+/// Columns exported when the caller picks none
+pub const DEFAULT_EXPORT_COLUMNS: [ExportColumn; 10] = [
+    ExportColumn::Title,
+    ExportColumn::Company,
+    ExportColumn::Location,
+    ExportColumn::Url,
+    ExportColumn::Source,
+    ExportColumn::Score,
+    ExportColumn::SalaryMin,
+    ExportColumn::SalaryMax,
+    ExportColumn::Status,
+    ExportColumn::CreatedAt,
+];
+
+impl ExportColumn {
+    /// Header and JSON key, matching the serialized name
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Company => "company",
+            Self::Url => "url",
+            Self::Location => "location",
+            Self::Source => "source",
+            Self::Remote => "remote",
+            Self::Score => "score",
+            Self::SalaryMin => "salary_min",
+            Self::SalaryMax => "salary_max",
+            Self::Currency => "currency",
+            Self::PayPeriod => "pay_period",
+            Self::Status => "status",
+            Self::Bookmarked => "bookmarked",
+            Self::Notes => "notes",
+            Self::GhostScore => "ghost_score",
+            Self::CreatedAt => "created_at",
+            Self::LastSeen => "last_seen",
+        }
+    }
+
+    fn value(self, job: &Job) -> Value {
+        match self {
+            Self::Id => job.id.into(),
+            Self::Title => job.title.as_str().into(),
+            Self::Company => job.company.as_str().into(),
+            Self::Url => job.url.as_str().into(),
+            Self::Location => job.location.as_deref().into(),
+            Self::Source => job.source.as_str().into(),
+            Self::Remote => job.remote.into(),
+            Self::Score => job.score.into(),
+            Self::SalaryMin => job.salary_min.into(),
+            Self::SalaryMax => job.salary_max.into(),
+            Self::Currency => job.currency.as_deref().into(),
+            Self::PayPeriod => job.pay_period.map(|period| period.as_str()).into(),
+            Self::Status => job.status.as_str().into(),
+            Self::Bookmarked => job.bookmarked.into(),
+            Self::Notes => job.notes.as_deref().into(),
+            Self::GhostScore => job.ghost_score.into(),
+            Self::CreatedAt => job.created_at.to_rfc3339().into(),
+            Self::LastSeen => job.last_seen.to_rfc3339().into(),
+        }
+    }
+}
+
+/// Write every job matching `filter` to `out`, newest first.
+///
+/// `mask` sees each page before it is written, so guest mode can redact it.
+/// An empty `columns` list exports `DEFAULT_EXPORT_COLUMNS`. Returns the
+/// number of jobs written.
+pub async fn export_jobs(
+    database: &Database,
+    filter: &JobFilter,
+    columns: &[ExportColumn],
+    format: ExportFormat,
+    out: &mut impl Write,
+    mask: impl Fn(Vec<Job>) -> Vec<Job>,
+) -> Result<u64> {
+    let columns = if columns.is_empty() {
+        &DEFAULT_EXPORT_COLUMNS[..]
+    } else {
+        columns
+    };
+    if format == ExportFormat::Csv {
+        let header: Vec<&str> = columns.iter().map(|column| column.name()).collect();
+        writeln!(out, "{}", header.join(","))?;
+    }
+
+    let mut parquet = ParquetWriter::new(columns);
+
+    let mut rows = 0;
+    let mut cursor = None;
+    loop {
+        let page = database
+            .query_jobs_page(filter, cursor, EXPORT_PAGE_SIZE)
+            .await?;
+        let jobs = mask(page.jobs);
+        match format {
+            ExportFormat::Csv => {
+                for job in &jobs {
+                    write_csv_row(out, columns, job)?;
+                }
+            }
+            ExportFormat::JsonLines => {
+                for job in &jobs {
+                    write_json_line(out, columns, job)?;
+                }
+            }
+            ExportFormat::Parquet => parquet.write_row_group(out, &jobs)?,
+        }
+        rows += jobs.len() as u64;
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    if format == ExportFormat::Parquet {
+        parquet.finish(out)?;
+    }
+    out.flush()?;
+
+    Ok(rows)
+}
+
+fn write_csv_row(out: &mut impl Write, columns: &[ExportColumn], job: &Job) -> Result<()> {
+    let cells: Vec<String> = columns
+        .iter()
+        .map(|column| csv_cell(&column.value(job)))
+        .collect();
+    writeln!(out, "{}", cells.join(","))?;
+    Ok(())
+}
+
+fn write_json_line(out: &mut impl Write, columns: &[ExportColumn], job: &Job) -> Result<()> {
+    let row: Map<String, Value> = columns
+        .iter()
+        .map(|column| (column.name().to_string(), column.value(job)))
+        .collect();
+    serde_json::to_writer(&mut *out, &row)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// One RFC 4180 cell.
+///
+/// Scraped text that starts like a spreadsheet formula gets a leading `'`
+/// so opening the file cannot run it.
+fn csv_cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => return String::new(),
+        Value::String(text) if text.starts_with(['=', '+', '-', '@', '\t', '\r']) => {
+            format!("'{text}")
+        }
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_job;
+
+    async fn database_with_jobs(count: usize) -> Database {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        for index in 0..count {
+            let job = test_job(&format!("export-{index}"), "Care Coordinator", "Clinic");
+            database.upsert_job(&job).await.unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn column_names_match_their_serialized_form() {
+        for column in DEFAULT_EXPORT_COLUMNS {
+            assert_eq!(
+                serde_json::to_value(column).unwrap(),
+                Value::from(column.name())
+            );
+        }
+    }
+
+    #[test]
+    fn csv_cells_are_quoted_and_defused() {
+        assert_eq!(csv_cell(&Value::Null), "");
+        assert_eq!(csv_cell(&Value::from(0.75)), "0.75");
+        assert_eq!(csv_cell(&Value::from("Nurse, RN")), "\"Nurse, RN\"");
+        assert_eq!(
+            csv_cell(&Value::from("The \"A\" team")),
+            "\"The \"\"A\"\" team\""
+        );
+        assert_eq!(csv_cell(&Value::from("=HYPERLINK(1)")), "'=HYPERLINK(1)");
+    }
+
+    #[tokio::test]
+    async fn csv_export_streams_every_page() {
+        let database = database_with_jobs(EXPORT_PAGE_SIZE as usize + 3).await;
+        let mut out = Vec::new();
+
+        let rows = export_jobs(
+            &database,
+            &JobFilter::default(),
+            &[ExportColumn::Title, ExportColumn::Company],
+            ExportFormat::Csv,
+            &mut out,
+            |jobs| jobs,
+        )
+        .await
+        .unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(rows, EXPORT_PAGE_SIZE as u64 + 3);
+        assert_eq!(text.lines().count(), EXPORT_PAGE_SIZE as usize + 4);
+        assert_eq!(text.lines().next(), Some("title,company"));
+        assert_eq!(text.lines().nth(1), Some("Care Coordinator,Clinic"));
+    }
+
+    #[tokio::test]
+    async fn json_lines_export_writes_selected_columns() {
+        let database = database_with_jobs(2).await;
+        let mut out = Vec::new();
+
+        export_jobs(
+            &database,
+            &JobFilter::default(),
+            &[ExportColumn::Company, ExportColumn::Score],
+            ExportFormat::JsonLines,
+            &mut out,
+            |jobs| jobs,
+        )
+        .await
+        .unwrap();
+
+        let rows: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            serde_json::json!({ "company": "Clinic", "score": null })
+        );
+    }
+
+    #[tokio::test]
+    async fn parquet_export_writes_a_row_group_per_page() {
+        let database = database_with_jobs(EXPORT_PAGE_SIZE as usize + 3).await;
+        let mut out = Vec::new();
+
+        let rows = export_jobs(
+            &database,
+            &JobFilter::default(),
+            &[
+                ExportColumn::Title,
+                ExportColumn::Score,
+                ExportColumn::Remote,
+            ],
+            ExportFormat::Parquet,
+            &mut out,
+            |jobs| jobs,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rows, EXPORT_PAGE_SIZE as u64 + 3);
+        assert!(out.starts_with(b"PAR1"));
+        assert!(out.ends_with(b"PAR1"));
+        let footer_start = out.len() - 8;
+        let footer_len =
+            u32::from_le_bytes(out[footer_start..footer_start + 4].try_into().unwrap()) as usize;
+        let footer = &out[footer_start - footer_len..footer_start];
+        // Two row groups of three column chunks, each in the footer.
+        assert_eq!(footer.windows(5).filter(|w| w == b"title").count(), 3);
+        assert_eq!(
+            out.windows(16).filter(|w| w == b"Care Coordinator").count(),
+            EXPORT_PAGE_SIZE as usize + 3
+        );
+    }
+}
@@ -0,0 +1,397 @@
+//! Minimal Parquet writer for job exports
+//!
+//! Each export page becomes one row group with one uncompressed, PLAIN
+//! encoded data page per column, so the writer holds a single page in memory
+//! like the CSV and JSON Lines paths. Every column is optional; text columns
+//! are UTF-8 byte arrays and timestamps stay RFC 3339 text, matching the
+//! other formats. Metadata uses the Thrift compact protocol the format
+//! specifies.
+
+use super::ExportColumn;
+use anyhow::Result;
+use jobsentinel_domain::Job;
+use serde_json::Value;
+use std::io::Write;
+
+const MAGIC: &[u8; 4] = b"PAR1";
+
+// Parquet enum values from parquet.thrift
+const TYPE_BOOLEAN: i32 = 0;
+const TYPE_INT64: i32 = 2;
+const TYPE_DOUBLE: i32 = 5;
+const TYPE_BYTE_ARRAY: i32 = 6;
+const REPETITION_OPTIONAL: i32 = 1;
+const CONVERTED_TYPE_UTF8: i32 = 0;
+const ENCODING_PLAIN: i32 = 0;
+const ENCODING_RLE: i32 = 3;
+const CODEC_UNCOMPRESSED: i32 = 0;
+const PAGE_TYPE_DATA: i32 = 0;
+
+impl ExportColumn {
+    /// Parquet physical type of the column's values
+    const fn parquet_type(self) -> i32 {
+        match self {
+            Self::Id | Self::SalaryMin | Self::SalaryMax => TYPE_INT64,
+            Self::Remote | Self::Bookmarked => TYPE_BOOLEAN,
+            Self::Score | Self::GhostScore => TYPE_DOUBLE,
+            _ => TYPE_BYTE_ARRAY,
+        }
+    }
+}
+
+/// Where a written column chunk sits in the file
+struct ColumnChunkMeta {
+    column: ExportColumn,
+    offset: i64,
+    size: i64,
+    num_values: i64,
+}
+
+struct RowGroupMeta {
+    columns: Vec<ColumnChunkMeta>,
+    num_rows: i64,
+}
+
+/// Writes a Parquet file one row group at a time.
+///
+/// Nothing is written until the first row group or `finish`, so creating a
+/// writer for a non-Parquet export costs nothing.
+pub(super) struct ParquetWriter<'a> {
+    columns: &'a [ExportColumn],
+    offset: u64,
+    row_groups: Vec<RowGroupMeta>,
+}
+
+impl<'a> ParquetWriter<'a> {
+    pub(super) fn new(columns: &'a [ExportColumn]) -> Self {
+        Self {
+            columns,
+            offset: 0,
+            row_groups: Vec::new(),
+        }
+    }
+
+    /// Write `jobs` as one row group
+    pub(super) fn write_row_group(&mut self, out: &mut impl Write, jobs: &[Job]) -> Result<()> {
+        if jobs.is_empty() {
+            return Ok(());
+        }
+        self.start(out)?;
+
+        let mut columns = Vec::with_capacity(self.columns.len());
+        for &column in self.columns {
+            let values: Vec<Value> = jobs.iter().map(|job| column.value(job)).collect();
+            let chunk = column_chunk(column, &values);
+            out.write_all(&chunk)?;
+            columns.push(ColumnChunkMeta {
+                column,
+                offset: self.offset as i64,
+                size: chunk.len() as i64,
+                num_values: values.len() as i64,
+            });
+            self.offset += chunk.len() as u64;
+        }
+        self.row_groups.push(RowGroupMeta {
+            columns,
+            num_rows: jobs.len() as i64,
+        });
+        Ok(())
+    }
+
+    /// Write the footer, completing the file
+    pub(super) fn finish(mut self, out: &mut impl Write) -> Result<()> {
+        self.start(out)?;
+        let metadata = self.file_metadata();
+        out.write_all(&metadata)?;
+        out.write_all(&(metadata.len() as u32).to_le_bytes())?;
+        out.write_all(MAGIC)?;
+        Ok(())
+    }
+
+    fn start(&mut self, out: &mut impl Write) -> Result<()> {
+        if self.offset == 0 {
+            out.write_all(MAGIC)?;
+            self.offset = MAGIC.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn file_metadata(&self) -> Vec<u8> {
+        let mut thrift = CompactWriter::default();
+        thrift.i32_field(1, 1);
+
+        thrift.list_field(2, STRUCT, self.columns.len() + 1);
+        thrift.begin_element();
+        thrift.binary_field(4, b"schema");
+        thrift.i32_field(5, self.columns.len() as i32);
+        thrift.end_struct();
+        for &column in self.columns {
+            thrift.begin_element();
+            thrift.i32_field(1, column.parquet_type());
+            thrift.i32_field(3, REPETITION_OPTIONAL);
+            thrift.binary_field(4, column.name().as_bytes());
+            if column.parquet_type() == TYPE_BYTE_ARRAY {
+                thrift.i32_field(6, CONVERTED_TYPE_UTF8);
+            }
+            thrift.end_struct();
+        }
+
+        let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
+        thrift.i64_field(3, num_rows);
+
+        thrift.list_field(4, STRUCT, self.row_groups.len());
+        for group in &self.row_groups {
+            thrift.begin_element();
+            thrift.list_field(1, STRUCT, group.columns.len());
+            for chunk in &group.columns {
+                thrift.begin_element();
+                thrift.i64_field(2, chunk.offset);
+                thrift.begin_struct(3);
+                thrift.i32_field(1, chunk.column.parquet_type());
+                thrift.list_field(2, I32, 2);
+                thrift.i32_element(ENCODING_PLAIN);
+                thrift.i32_element(ENCODING_RLE);
+                thrift.list_field(3, BINARY, 1);
+                thrift.binary_element(chunk.column.name().as_bytes());
+                thrift.i32_field(4, CODEC_UNCOMPRESSED);
+                thrift.i64_field(5, chunk.num_values);
+                thrift.i64_field(6, chunk.size);
+                thrift.i64_field(7, chunk.size);
+                thrift.i64_field(9, chunk.offset);
+                thrift.end_struct();
+                thrift.end_struct();
+            }
+            let total_size = group.columns.iter().map(|chunk| chunk.size).sum();
+            thrift.i64_field(2, total_size);
+            thrift.i64_field(3, group.num_rows);
+            thrift.end_struct();
+        }
+
+        thrift.binary_field(6, b"JobSentinel");
+        thrift.end_struct();
+        thrift.buf
+    }
+}
+
+/// One column chunk: a data page header followed by the page
+fn column_chunk(column: ExportColumn, values: &[Value]) -> Vec<u8> {
+    let mut present = Vec::with_capacity(values.len());
+    let mut plain = Vec::new();
+    let mut bits = Vec::new();
+    for value in values {
+        let is_present = if column.parquet_type() == TYPE_BOOLEAN {
+            value.as_bool().map(|bit| bits.push(bit)).is_some()
+        } else {
+            plain_value(column, value)
+                .map(|bytes| plain.extend(bytes))
+                .is_some()
+        };
+        present.push(is_present);
+    }
+    plain.extend(pack_bits(&bits));
+
+    let levels = definition_levels(&present);
+    let mut page = Vec::with_capacity(4 + levels.len() + plain.len());
+    page.extend((levels.len() as u32).to_le_bytes());
+    page.extend(levels);
+    page.extend(plain);
+
+    let mut header = CompactWriter::default();
+    header.i32_field(1, PAGE_TYPE_DATA);
+    header.i32_field(2, page.len() as i32);
+    header.i32_field(3, page.len() as i32);
+    header.begin_struct(5);
+    header.i32_field(1, values.len() as i32);
+    header.i32_field(2, ENCODING_PLAIN);
+    header.i32_field(3, ENCODING_RLE);
+    header.i32_field(4, ENCODING_RLE);
+    header.end_struct();
+    header.end_struct();
+
+    let mut chunk = header.buf;
+    chunk.extend(page);
+    chunk
+}
+
+/// PLAIN encoding of one non-boolean value, or `None` for a null
+fn plain_value(column: ExportColumn, value: &Value) -> Option<Vec<u8>> {
+    match column.parquet_type() {
+        TYPE_INT64 => value.as_i64().map(|n| n.to_le_bytes().to_vec()),
+        TYPE_DOUBLE => value.as_f64().map(|n| n.to_le_bytes().to_vec()),
+        TYPE_BYTE_ARRAY => {
+            let text = match value {
+                Value::Null => return None,
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            let mut bytes = (text.len() as u32).to_le_bytes().to_vec();
+            bytes.extend(text.as_bytes());
+            Some(bytes)
+        }
+        _ => None,
+    }
+}
+
+/// Definition levels (1 = present, 0 = null) as one bit-packed run of the
+/// RLE/bit-packing hybrid encoding, bit width 1
+fn definition_levels(present: &[bool]) -> Vec<u8> {
+    let packed = pack_bits(present);
+    let mut levels = Vec::with_capacity(packed.len() + 5);
+    write_varint(&mut levels, ((packed.len() as u64) << 1) | 1);
+    levels.extend(packed);
+    levels
+}
+
+/// Bits packed eight to a byte, least significant bit first
+fn pack_bits(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|group| {
+            group
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (index, &bit)| byte | (u8::from(bit) << index))
+        })
+        .collect()
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            return;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+const fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+// Thrift compact protocol type IDs
+const I32: u8 = 5;
+const I64: u8 = 6;
+const BINARY: u8 = 8;
+const LIST: u8 = 9;
+const STRUCT: u8 = 12;
+
+/// Thrift compact protocol encoder for the few shapes Parquet metadata needs
+#[derive(Default)]
+struct CompactWriter {
+    buf: Vec<u8>,
+    last_field_id: i16,
+    outer_field_ids: Vec<i16>,
+}
+
+impl CompactWriter {
+    fn field_header(&mut self, id: i16, kind: u8) {
+        let delta = id - self.last_field_id;
+        if (1..=15).contains(&delta) {
+            self.buf.push(((delta as u8) << 4) | kind);
+        } else {
+            self.buf.push(kind);
+            write_varint(&mut self.buf, zigzag(i64::from(id)));
+        }
+        self.last_field_id = id;
+    }
+
+    fn i32_field(&mut self, id: i16, value: i32) {
+        self.field_header(id, I32);
+        self.i32_element(value);
+    }
+
+    fn i64_field(&mut self, id: i16, value: i64) {
+        self.field_header(id, I64);
+        write_varint(&mut self.buf, zigzag(value));
+    }
+
+    fn binary_field(&mut self, id: i16, bytes: &[u8]) {
+        self.field_header(id, BINARY);
+        self.binary_element(bytes);
+    }
+
+    fn list_field(&mut self, id: i16, element: u8, len: usize) {
+        self.field_header(id, LIST);
+        if len < 15 {
+            self.buf.push(((len as u8) << 4) | element);
+        } else {
+            self.buf.push(0xf0 | element);
+            write_varint(&mut self.buf, len as u64);
+        }
+    }
+
+    fn begin_struct(&mut self, id: i16) {
+        self.field_header(id, STRUCT);
+        self.begin_element();
+    }
+
+    /// Start a struct that is a list element, which has no field header
+    fn begin_element(&mut self) {
+        self.outer_field_ids.push(self.last_field_id);
+        self.last_field_id = 0;
+    }
+
+    fn end_struct(&mut self) {
+        self.buf.push(0);
+        self.last_field_id = self.outer_field_ids.pop().unwrap_or(0);
+    }
+
+    fn i32_element(&mut self, value: i32) {
+        write_varint(&mut self.buf, zigzag(i64::from(value)));
+    }
+
+    fn binary_element(&mut self, bytes: &[u8]) {
+        write_varint(&mut self.buf, bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_protocol_encodes_fields_and_nested_structs() {
+        let mut thrift = CompactWriter::default();
+        thrift.i32_field(1, -1);
+        thrift.begin_struct(3);
+        thrift.binary_field(1, b"ab");
+        thrift.end_struct();
+        thrift.i64_field(20, 300);
+        thrift.end_struct();
+
+        assert_eq!(
+            thrift.buf,
+            [
+                0x15, 0x01, // field 1, i32, zigzag(-1)
+                0x2c, // field 3 (delta 2), struct
+                0x18, 0x02, b'a', b'b', // nested field 1, binary "ab"
+                0x00, // end nested struct
+                0x06, 0x28, 0xd8, 0x04, // field 20 (long form), i64 zigzag(300)
+                0x00, // end
+            ]
+        );
+    }
+
+    #[test]
+    fn definition_levels_are_one_bit_packed_run() {
+        let present = [true, false, true, true, false, false, false, false, true];
+        assert_eq!(
+            definition_levels(&present),
+            [0x05, 0b0000_1101, 0b0000_0001]
+        );
+    }
+
+    #[test]
+    fn nulls_are_left_out_of_plain_values() {
+        let chunk = column_chunk(ExportColumn::SalaryMin, &[Value::from(90_000), Value::Null]);
+        let page_tail = [
+            2, 0, 0, 0, // definition levels length
+            0x03, 0b01, // one group of levels
+            0x90, 0x5f, 0x01, 0, 0, 0, 0, 0, // 90000 little-endian
+        ];
+        assert!(chunk.ends_with(&page_tail));
+    }
+}
@@ -0,0 +1,260 @@
+//! Geocoding job locations for commute scoring
+//!
+//! Onsite and hybrid job locations in a city the bundled gazetteer knows take
+//! its coordinates. Other places are looked up with OpenStreetMap's public
+//! Nominatim search, which needs no account, and cached in SQLite. Only the
+//! job's location text is sent; the user's home coordinates never leave the
+//! device. Nothing is looked up until home coordinates are set.
+
+use anyhow::{Context, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use jobsentinel_domain::geo::{find_city, City};
+use jobsentinel_domain::{Coordinates, Job};
+use jobsentinel_network::fetch_external_https_text_with_user_agent;
+use jobsentinel_storage::Database;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::scoring::{detect_remote_status, RemoteStatus};
+
+const GEOCODER_URL: &str = "https://nominatim.openstreetmap.org/search";
+/// Nominatim's usage policy asks for an identifying user agent.
+const GEOCODER_USER_AGENT: &str = "JobSentinel (https://github.com/cboyd0319/JobSentinel)";
+/// Nominatim allows at most one request per second.
+const LOOKUP_INTERVAL: Duration = Duration::from_millis(1_100);
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// Locations that found nothing are looked up again after this many days.
+const MISS_RETRY_DAYS: i64 = 30;
+
+/// New lookups allowed in one scoring cycle; the rest wait for later cycles.
+pub const MAX_LOOKUPS_PER_CYCLE: usize = 10;
+
+/// Key for a job location: a known city's gazetteer key such as
+/// "seattle, wa", otherwise the location lowercased with whitespace collapsed.
+#[must_use]
+pub fn normalize_location(location: &str) -> Option<String> {
+    let normalized = location
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    if normalized.is_empty() {
+        return None;
+    }
+    Some(find_city(location).map_or(normalized, City::key))
+}
+
+/// Coordinates of the onsite and hybrid job locations in `jobs`, keyed by
+/// `normalize_location`.
+///
+/// Known cities come from the gazetteer; other places use cached results
+/// first and at most `max_lookups` new lookups. Returns nothing while no
+/// home location is set.
+pub async fn job_coordinates(
+    config: &Config,
+    database: &Database,
+    jobs: &[Job],
+    max_lookups: usize,
+) -> HashMap<String, Coordinates> {
+    if config.commute.home.is_none() {
+        return HashMap::new();
+    }
+
+    let mut known = HashMap::new();
+    let mut locations = Vec::new();
+    for location in jobs
+        .iter()
+        .filter(|job| detect_remote_status(job) != RemoteStatus::Remote)
+        .filter_map(|job| job.location.as_deref())
+    {
+        if let Some(city) = find_city(location) {
+            known.insert(city.key(), city.coordinates);
+        } else if let Some(key) = normalize_location(location) {
+            locations.push(key);
+        }
+    }
+    locations.sort();
+    locations.dedup();
+    if locations.is_empty() {
+        return known;
+    }
+
+    let misses_since = Utc::now() - ChronoDuration::days(MISS_RETRY_DAYS);
+    let mut cached = match database.cached_geocodes(&locations, misses_since).await {
+        Ok(cached) => cached,
+        Err(error) => {
+            tracing::warn!(error = %error, "Failed to read geocoding cache");
+            return known;
+        }
+    };
+
+    let uncached: Vec<&String> = locations
+        .iter()
+        .filter(|location| !cached.contains_key(*location))
+        .take(max_lookups)
+        .collect();
+    for (i, location) in uncached.iter().enumerate() {
+        if i > 0 {
+            tokio::time::sleep(LOOKUP_INTERVAL).await;
+        }
+        match geocode(location).await {
+            Ok(coordinates) => {
+                if let Err(error) = database.save_geocode(location, coordinates).await {
+                    tracing::warn!(error = %error, "Failed to cache geocoding result");
+                }
+                cached.insert((*location).clone(), coordinates);
+            }
+            Err(error) => {
+                // Leave it uncached so the next cycle tries again.
+                tracing::warn!(error = %error, "Geocoding lookup failed");
+                break;
+            }
+        }
+    }
+
+    known.extend(
+        cached
+            .into_iter()
+            .filter_map(|(location, coordinates)| Some((location, coordinates?))),
+    );
+    known
+}
+
+async fn geocode(location: &str) -> Result<Option<Coordinates>> {
+    let url = url::Url::parse_with_params(
+        GEOCODER_URL,
+        [("format", "jsonv2"), ("limit", "1"), ("q", location)],
+    )?;
+    let response = fetch_external_https_text_with_user_agent(
+        url.as_str(),
+        FETCH_TIMEOUT,
+        Some(GEOCODER_USER_AGENT),
+    )
+    .await
+    .map_err(|error| anyhow::anyhow!("Geocoder fetch failed: {error:?}"))?;
+    anyhow::ensure!(
+        (200..300).contains(&response.status),
+        "Geocoder returned HTTP {}",
+        response.status
+    );
+    parse_geocoder_response(&response.body)
+}
+
+/// First result of a Nominatim search, which reports coordinates as strings.
+fn parse_geocoder_response(body: &str) -> Result<Option<Coordinates>> {
+    let results: serde_json::Value =
+        serde_json::from_str(body).context("Geocoder response is not JSON")?;
+    let results = results
+        .as_array()
+        .context("Geocoder response is not a list")?;
+    let Some(first) = results.first() else {
+        return Ok(None);
+    };
+
+    let coordinate = |key: &str| {
+        first[key]
+            .as_str()
+            .and_then(|value| value.parse::<f64>().ok())
+            .or_else(|| first[key].as_f64())
+    };
+    Ok(coordinate("lat")
+        .zip(coordinate("lon"))
+        .map(|(latitude, longitude)| Coordinates::new(latitude, longitude))
+        .filter(Coordinates::is_valid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{minimal_test_config, test_job};
+
+    #[test]
+    fn locations_are_normalized_for_the_cache() {
+        assert_eq!(
+            normalize_location("  Tacoma,   WA "),
+            Some("tacoma, wa".to_string())
+        );
+        assert_eq!(normalize_location("   "), None);
+    }
+
+    #[test]
+    fn known_cities_share_the_gazetteer_key() {
+        for location in ["Seattle, WA", "Greater Seattle Area", "seattle,  wa"] {
+            assert_eq!(
+                normalize_location(location).as_deref(),
+                Some("seattle, wa"),
+                "{location}"
+            );
+        }
+    }
+
+    #[test]
+    fn first_search_result_is_used() {
+        let coordinates = parse_geocoder_response(
+            r#"[{"lat": "47.6038321", "lon": "-122.330062"}, {"lat": "0", "lon": "0"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(coordinates, Some(Coordinates::new(47.6038321, -122.330062)));
+        assert_eq!(parse_geocoder_response("[]").unwrap(), None);
+        assert!(parse_geocoder_response("<html>").is_err());
+    }
+
+    #[tokio::test]
+    async fn nothing_is_looked_up_without_a_home_location() {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        let mut job = test_job("onsite-hash", "Nurse", "Example Clinic");
+        job.location = Some("Seattle, WA".to_string());
+        job.remote = None;
+
+        let coordinates = job_coordinates(&minimal_test_config(), &database, &[job], 10).await;
+
+        assert!(coordinates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cached_locations_need_no_lookup() {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        database
+            .save_geocode("tacoma, wa", Some(Coordinates::new(47.25, -122.44)))
+            .await
+            .unwrap();
+        let mut config = minimal_test_config();
+        config.commute.home = Some(Coordinates::new(47.67, -122.12));
+        let mut job = test_job("onsite-hash", "Nurse", "Example Clinic");
+        job.location = Some("Tacoma,  WA".to_string());
+        job.remote = None;
+
+        let coordinates = job_coordinates(&config, &database, &[job], 0).await;
+
+        assert_eq!(
+            coordinates.get("tacoma, wa"),
+            Some(&Coordinates::new(47.25, -122.44))
+        );
+    }
+
+    #[tokio::test]
+    async fn known_cities_need_no_cache_or_lookup() {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        let mut config = minimal_test_config();
+        config.commute.home = Some(Coordinates::new(47.67, -122.12));
+        let jobs = ["Seattle, WA", "Greater Seattle Area"].map(|location| {
+            let mut job = test_job(location, "Nurse", "Example Clinic");
+            job.location = Some(location.to_string());
+            job.remote = None;
+            job
+        });
+
+        let coordinates = job_coordinates(&config, &database, &jobs, 0).await;
+
+        let seattle = find_city("Seattle, WA").unwrap().coordinates;
+        assert_eq!(
+            coordinates,
+            HashMap::from([("seattle, wa".to_string(), seattle)])
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Imported ghost pattern packs
+//!
+//! An imported pack is saved in the data dir and installed again at startup,
+//! so updated detection patterns survive restarts. A saved pack older than
+//! the bundled one is ignored, which lets app updates ship newer patterns.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use jobsentinel_intelligence::{
+    active_pattern_pack, install_pattern_pack, GhostPatternPack, PatternPackError,
+};
+use jobsentinel_platform::{ensure_private_dir, write_file_atomic_private};
+use thiserror::Error;
+
+/// File the imported pack is saved to, inside the data dir
+const PATTERN_PACK_FILE: &str = "ghost-patterns.json";
+
+#[derive(Debug, Error)]
+pub enum GhostPatternImportError {
+    #[error(transparent)]
+    Invalid(#[from] PatternPackError),
+    #[error("Pattern pack version {imported} is not newer than the current version {active}")]
+    NotNewer { imported: i64, active: i64 },
+    #[error("Failed to save the pattern pack: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+fn pattern_pack_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(PATTERN_PACK_FILE)
+}
+
+/// Install the pack saved in `data_dir`, if there is one newer than the
+/// active pack. Returns the installed pack version.
+pub fn load_saved_pattern_pack(data_dir: &Path) -> Option<i64> {
+    let path = pattern_pack_path(data_dir);
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(error) => {
+            tracing::warn!(%error, "Failed to read the saved ghost pattern pack");
+            return None;
+        }
+    };
+    let pack = match GhostPatternPack::from_json(&json) {
+        Ok(pack) => pack,
+        Err(error) => {
+            tracing::warn!(%error, "Ignoring an invalid saved ghost pattern pack");
+            return None;
+        }
+    };
+
+    let active = active_pattern_pack().version();
+    if pack.version() <= active {
+        tracing::info!(
+            saved_version = pack.version(),
+            active_version = active,
+            "Ignoring a saved ghost pattern pack that is not newer than the bundled one"
+        );
+        return None;
+    }
+
+    let version = pack.version();
+    install_pattern_pack(Arc::new(pack));
+    tracing::info!(version, "Installed the saved ghost pattern pack");
+    Some(version)
+}
+
+/// Validate `json` as a pattern pack newer than the active one, save it to
+/// `data_dir`, and install it. Returns the new pack version.
+///
+/// Saved ghost scores from the previous pack become outdated and are picked
+/// up by the next ghost re-analysis.
+pub fn import_pattern_pack(data_dir: &Path, json: &str) -> Result<i64, GhostPatternImportError> {
+    let pack = GhostPatternPack::from_json(json)?;
+    let active = active_pattern_pack().version();
+    if pack.version() <= active {
+        return Err(GhostPatternImportError::NotNewer {
+            imported: pack.version(),
+            active,
+        });
+    }
+
+    ensure_private_dir(data_dir)?;
+    write_file_atomic_private(&pattern_pack_path(data_dir), json)?;
+
+    let version = pack.version();
+    install_pattern_pack(Arc::new(pack));
+    tracing::info!(version, "Imported ghost pattern pack");
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn pack_json(version: i64) -> String {
+        let mut english: Value = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/taxonomies/job-posting-risk.json"
+        )))
+        .unwrap();
+        let mut pack: Value = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/taxonomies/job-posting-risk-languages.json"
+        )))
+        .unwrap();
+        english.as_object_mut().unwrap().remove("schemaVersion");
+        pack["patternSets"]["en"] = english;
+        pack["packVersion"] = json!(version);
+        pack.to_string()
+    }
+
+    #[test]
+    fn import_rejects_invalid_and_older_packs() {
+        let data_dir = tempfile::tempdir().unwrap();
+
+        assert!(matches!(
+            import_pattern_pack(data_dir.path(), &pack_json(1)),
+            Err(GhostPatternImportError::NotNewer { imported: 1, .. })
+        ));
+        assert!(matches!(
+            import_pattern_pack(data_dir.path(), "{\"schemaVersion\": 1}"),
+            Err(GhostPatternImportError::Invalid(_))
+        ));
+        assert!(!pattern_pack_path(data_dir.path()).exists());
+    }
+
+    #[test]
+    fn missing_invalid_or_older_saved_packs_are_ignored() {
+        let data_dir = tempfile::tempdir().unwrap();
+        assert_eq!(load_saved_pattern_pack(data_dir.path()), None);
+
+        std::fs::write(pattern_pack_path(data_dir.path()), "not json").unwrap();
+        assert_eq!(load_saved_pattern_pack(data_dir.path()), None);
+
+        std::fs::write(pattern_pack_path(data_dir.path()), pack_json(1)).unwrap();
+        assert_eq!(load_saved_pattern_pack(data_dir.path()), None);
+    }
+}
@@ -0,0 +1,340 @@
+//! Read-only guest mode for screen sharing
+//!
+//! While guest mode is active, only read commands on [`GUEST_ALLOWED_COMMANDS`]
+//! may run, and their responses pass through [`GuestRedact`] so salary
+//! expectations, notes, and contact details never reach the webview.
+
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::ats::{ApplicationWithJob, ApplicationsByStatus, InterviewWithJob, PendingReminder};
+use jobsentinel_domain::Job;
+
+/// Commands that stay available while guest mode is active.
+///
+/// Everything else (writes, credentials, settings, notes, contacts, resumes,
+/// and automation) is rejected before it reaches its handler.
+pub const GUEST_ALLOWED_COMMANDS: &[&str] = &[
+    "get_guest_mode_status",
+    "enable_guest_mode",
+    "unlock_guest_mode",
+    "is_first_run",
+    "get_recent_jobs",
+    "get_recent_jobs_page",
+    "get_job_by_id",
+    "search_jobs_query",
+    "get_bookmarked_jobs",
+    "get_bookmarked_jobs_page",
+    "get_statistics",
+    "get_scraping_status",
+    "get_jobs_by_source",
+    "get_jobs_by_source_page",
+    "get_time_to_fill_estimate",
+    "get_salary_distribution",
+    "get_dashboard_preferences",
+    "get_applications_kanban",
+    "get_application_stats",
+    "get_pending_reminders",
+    "get_upcoming_interviews",
+    "get_past_interviews",
+    "get_trending_skills",
+    "get_most_active_companies",
+    "get_hottest_locations",
+    "get_market_alerts",
+    "get_market_snapshot",
+    "get_historical_snapshots",
+    "get_ghost_jobs",
+    "get_ghost_statistics",
+    "get_recent_jobs_filtered",
+    "get_scraper_health",
+    "get_health_summary",
+    "get_scraper_runs",
+    "get_scrape_history",
+    "get_scrape_run_details",
+    "get_supported_sites",
+    "get_sites_by_category_cmd",
+];
+
+/// User-facing message for commands blocked by guest mode.
+pub const GUEST_MODE_BLOCKED_MESSAGE: &str =
+    "Guest mode is on. Unlock guest mode to use this feature.";
+
+const REDACTED: &str = "[hidden in guest mode]";
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GuestModeError {
+    #[error("Incorrect guest mode PIN")]
+    IncorrectPin,
+}
+
+/// Snapshot of guest mode for the frontend.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestModeStatus {
+    pub active: bool,
+    pub pin_required: bool,
+    pub since: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+struct GuestSession {
+    since: DateTime<Utc>,
+    pin_hash: Option<[u8; 32]>,
+}
+
+/// In-memory guest mode switch shared by all command handlers.
+///
+/// Guest mode is deliberately not persisted: restarting the app always
+/// starts unlocked.
+#[derive(Clone, Default)]
+pub struct GuestMode {
+    session: Arc<RwLock<Option<GuestSession>>>,
+}
+
+impl GuestMode {
+    /// Turn guest mode on, optionally protected by a PIN.
+    ///
+    /// Enabling again while active keeps the original session and PIN.
+    pub fn enable(&self, pin: Option<&str>, now: DateTime<Utc>) -> GuestModeStatus {
+        let mut session = self.write_session();
+        if session.is_none() {
+            *session = Some(GuestSession {
+                since: now,
+                pin_hash: normalized_pin(pin).map(hash_pin),
+            });
+        }
+        status_of(session.as_ref())
+    }
+
+    /// Turn guest mode off. A PIN set when enabling must match.
+    pub fn unlock(&self, pin: Option<&str>) -> Result<GuestModeStatus, GuestModeError> {
+        let mut session = self.write_session();
+        if let Some(expected) = session.as_ref().and_then(|session| session.pin_hash) {
+            if normalized_pin(pin).map(hash_pin) != Some(expected) {
+                return Err(GuestModeError::IncorrectPin);
+            }
+        }
+        *session = None;
+        Ok(status_of(None))
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.read_session().is_some()
+    }
+
+    #[must_use]
+    pub fn status(&self) -> GuestModeStatus {
+        status_of(self.read_session().as_ref())
+    }
+
+    /// Whether `command` may run right now.
+    #[must_use]
+    pub fn allows_command(&self, command: &str) -> bool {
+        !self.is_active() || GUEST_ALLOWED_COMMANDS.contains(&command)
+    }
+
+    /// Redact `value` when guest mode is active, otherwise return it as-is.
+    #[must_use]
+    pub fn mask<T: GuestRedact>(&self, mut value: T) -> T {
+        if self.is_active() {
+            value.redact();
+        }
+        value
+    }
+
+    fn read_session(&self) -> std::sync::RwLockReadGuard<'_, Option<GuestSession>> {
+        match self.session.read() {
+            Ok(session) => session,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn write_session(&self) -> std::sync::RwLockWriteGuard<'_, Option<GuestSession>> {
+        match self.session.write() {
+            Ok(session) => session,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+fn status_of(session: Option<&GuestSession>) -> GuestModeStatus {
+    GuestModeStatus {
+        active: session.is_some(),
+        pin_required: session.is_some_and(|session| session.pin_hash.is_some()),
+        since: session.map(|session| session.since),
+    }
+}
+
+fn normalized_pin(pin: Option<&str>) -> Option<&str> {
+    pin.map(str::trim).filter(|pin| !pin.is_empty())
+}
+
+fn hash_pin(pin: &str) -> [u8; 32] {
+    Sha256::digest(pin.as_bytes()).into()
+}
+
+/// Strips private fields from a command response shown in guest mode.
+pub trait GuestRedact {
+    fn redact(&mut self);
+}
+
+impl<T: GuestRedact> GuestRedact for Vec<T> {
+    fn redact(&mut self) {
+        self.iter_mut().for_each(GuestRedact::redact);
+    }
+}
+
+impl<T: GuestRedact> GuestRedact for Option<T> {
+    fn redact(&mut self) {
+        if let Some(value) = self {
+            value.redact();
+        }
+    }
+}
+
+impl GuestRedact for Job {
+    fn redact(&mut self) {
+        self.notes = None;
+    }
+}
+
+impl GuestRedact for ApplicationWithJob {
+    fn redact(&mut self) {
+        self.notes = None;
+    }
+}
+
+impl GuestRedact for ApplicationsByStatus {
+    fn redact(&mut self) {
+        for column in [
+            &mut self.to_apply,
+            &mut self.applied,
+            &mut self.screening_call,
+            &mut self.phone_interview,
+            &mut self.technical_interview,
+            &mut self.onsite_interview,
+            &mut self.offer_received,
+            &mut self.offer_accepted,
+            &mut self.offer_rejected,
+            &mut self.rejected,
+            &mut self.ghosted,
+            &mut self.withdrawn,
+        ] {
+            column.redact();
+        }
+    }
+}
+
+impl GuestRedact for InterviewWithJob {
+    fn redact(&mut self) {
+        self.notes = None;
+        self.post_interview_notes = None;
+        if self.interviewer_name.is_some() {
+            self.interviewer_name = Some(REDACTED.to_string());
+        }
+        self.interviewer_title = None;
+        if self.location.is_some() {
+            self.location = Some(REDACTED.to_string());
+        }
+    }
+}
+
+impl GuestRedact for PendingReminder {
+    fn redact(&mut self) {
+        self.message = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interview() -> InterviewWithJob {
+        InterviewWithJob {
+            id: 1,
+            application_id: 1,
+            interview_type: "phone".to_string(),
+            scheduled_at: "2026-01-05T15:00:00Z".to_string(),
+            duration_minutes: 30,
+            location: Some("https://meet.example.com/abc".to_string()),
+            interviewer_name: Some("Jordan Lee".to_string()),
+            interviewer_title: Some("Program Director".to_string()),
+            notes: Some("Ask about caseload".to_string()),
+            completed: false,
+            outcome: None,
+            post_interview_notes: None,
+            job_title: "Case Manager".to_string(),
+            company: "CommunityCare".to_string(),
+        }
+    }
+
+    #[test]
+    fn guest_mode_blocks_unlisted_commands_until_unlocked() {
+        let guest = GuestMode::default();
+        assert!(guest.allows_command("save_config"));
+
+        guest.enable(None, Utc::now());
+        assert!(guest.allows_command("get_recent_jobs"));
+        assert!(!guest.allows_command("save_config"));
+        assert!(!guest.allows_command("get_job_notes"));
+        assert!(!guest.allows_command("get_credential_status"));
+
+        assert!(!guest.unlock(None).unwrap().active);
+        assert!(guest.allows_command("save_config"));
+    }
+
+    #[test]
+    fn guest_mode_allows_paged_versions_of_allowed_job_lists() {
+        let guest = GuestMode::default();
+        guest.enable(None, Utc::now());
+
+        for (command, paged) in [
+            ("get_recent_jobs", "get_recent_jobs_page"),
+            ("get_bookmarked_jobs", "get_bookmarked_jobs_page"),
+            ("get_jobs_by_source", "get_jobs_by_source_page"),
+        ] {
+            assert!(guest.allows_command(command));
+            assert!(guest.allows_command(paged), "{paged} should be allowed");
+        }
+    }
+
+    #[test]
+    fn guest_mode_pin_must_match_to_unlock() {
+        let guest = GuestMode::default();
+        let status = guest.enable(Some(" 2468 "), Utc::now());
+        assert!(status.active && status.pin_required);
+
+        // Re-enabling cannot replace the PIN.
+        guest.enable(Some("0000"), Utc::now());
+        assert_eq!(
+            guest.unlock(Some("0000")),
+            Err(GuestModeError::IncorrectPin)
+        );
+        assert_eq!(guest.unlock(None), Err(GuestModeError::IncorrectPin));
+        assert!(guest.is_active());
+
+        assert!(guest.unlock(Some("2468")).is_ok());
+        assert!(!guest.is_active());
+    }
+
+    #[test]
+    fn mask_redacts_private_fields_only_while_active() {
+        let guest = GuestMode::default();
+        assert_eq!(
+            guest.mask(vec![interview()])[0].notes.as_deref(),
+            Some("Ask about caseload")
+        );
+
+        guest.enable(None, Utc::now());
+        let masked = guest.mask(vec![interview()]).remove(0);
+        assert_eq!(masked.notes, None);
+        assert_eq!(masked.interviewer_name.as_deref(), Some(REDACTED));
+        assert_eq!(masked.interviewer_title, None);
+        assert_eq!(masked.job_title, "Case Manager");
+    }
+}
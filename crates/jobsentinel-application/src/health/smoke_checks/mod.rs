@@ -7,9 +7,9 @@ use jobsentinel_storage::Database;
 use std::time::Instant;
 
 use self::sources::{
-    test_builtin, test_dice, test_glassdoor, test_greenhouse, test_hn_hiring, test_indeed,
-    test_jobswithgpt, test_lever, test_remoteok, test_simplyhired, test_usajobs, test_wellfound,
-    test_weworkremotely, test_yc_startup, test_ziprecruiter,
+    test_arbeitnow, test_builtin, test_dice, test_glassdoor, test_greenhouse, test_guardian_jobs,
+    test_hn_hiring, test_indeed, test_jobswithgpt, test_lever, test_remoteok, test_simplyhired,
+    test_usajobs, test_wellfound, test_weworkremotely, test_yc_startup, test_ziprecruiter,
 };
 use jobsentinel_storage::health::{record_smoke_test, SmokeTestResult, SmokeTestType};
 
@@ -29,6 +29,8 @@ const SMOKE_TEST_SCRAPERS: &[&str] = &[
     "usajobs",
     "simplyhired",
     "glassdoor",
+    "arbeitnow",
+    "guardian_jobs",
 ];
 const SOURCE_CHECK_RATE_LIMITED: &str =
     "This source is asking JobSentinel to wait before checking again.";
@@ -154,6 +156,8 @@ fn smoke_rate_limit(scraper_name: &str) -> u32 {
         "usajobs" => limits::USAJOBS,
         "simplyhired" => limits::SIMPLYHIRED,
         "glassdoor" => limits::GLASSDOOR,
+        "arbeitnow" => limits::ARBEITNOW,
+        "guardian_jobs" => limits::GUARDIAN_JOBS,
         _ => 60,
     }
 }
@@ -255,6 +259,8 @@ pub async fn run_smoke_test_with_credentials_and_acknowledgement(
         "usajobs" => test_usajobs(config, credentials).await,
         "simplyhired" => test_simplyhired().await,
         "glassdoor" => test_glassdoor().await,
+        "arbeitnow" => test_arbeitnow().await,
+        "guardian_jobs" => test_guardian_jobs().await,
         _ => Err(anyhow::anyhow!("Unknown scraper")),
     };
 
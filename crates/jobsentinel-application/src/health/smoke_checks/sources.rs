@@ -115,6 +115,42 @@ pub(super) async fn test_remoteok() -> Result<serde_json::Value> {
     }))
 }
 
+pub(super) async fn test_arbeitnow() -> Result<serde_json::Value> {
+    let url = "https://www.arbeitnow.com/api/job-board-api";
+    let response = require_success(
+        smoke_request(
+            ExternalHttpRequest::get(url),
+            "Arbeitnow smoke test request failed",
+        )
+        .await?,
+    )?;
+    let json = parse_json(&response)?;
+    let job_count = json["data"].as_array().map_or(0, Vec::len);
+
+    Ok(serde_json::json!({
+        "status": response.status,
+        "jobs_found": job_count
+    }))
+}
+
+pub(super) async fn test_guardian_jobs() -> Result<serde_json::Value> {
+    let url = "https://jobs.theguardian.com/jobsrss/";
+    let response = require_success(
+        smoke_request(
+            ExternalHttpRequest::get(url),
+            "Guardian Jobs smoke test request failed",
+        )
+        .await?,
+    )?;
+    let item_count = response.body.matches("<item>").count();
+
+    Ok(serde_json::json!({
+        "status": response.status,
+        "jobs_found": item_count,
+        "format": "rss"
+    }))
+}
+
 pub(super) async fn test_wellfound() -> Result<serde_json::Value> {
     let url = "https://wellfound.com/role/software-engineer";
     let response = require_success(
@@ -116,8 +116,10 @@ fn test_smoke_test_type_serialization() {
 fn test_smoke_test_scraper_list_matches_configured_sources() {
     let scrapers = smoke_test_scrapers();
 
-    assert_eq!(scrapers.len(), 15);
+    assert_eq!(scrapers.len(), 17);
     assert!(scrapers.contains(&"greenhouse"));
+    assert!(scrapers.contains(&"arbeitnow"));
+    assert!(scrapers.contains(&"guardian_jobs"));
     assert!(scrapers.contains(&"yc_startup"));
     assert!(scrapers.contains(&"usajobs"));
     assert!(scrapers.contains(&"simplyhired"));
@@ -132,3 +134,117 @@ fn test_known_scraper_validation() {
     assert!(!is_known_scraper_name("usa_jobs"));
     assert!(!is_known_scraper_name("unknown"));
 }
+
+#[tokio::test]
+async fn test_scrape_run_history_records_sources_and_errors() {
+    let db = jobsentinel_storage::Database::connect_memory()
+        .await
+        .unwrap();
+    db.migrate().await.unwrap();
+
+    let run_id = start_scrape_run(&db, 3).await.unwrap();
+    record_scrape_run_source(&db, run_id, "greenhouse", 12, 4, 8, &[])
+        .await
+        .unwrap();
+    record_scrape_run_source(
+        &db,
+        run_id,
+        "lever",
+        0,
+        0,
+        0,
+        &["Lever request timed out".to_string()],
+    )
+    .await
+    .unwrap();
+
+    let running = get_scrape_history(&db, 10).await.unwrap();
+    assert_eq!(running[0].status, ScrapeRunStatus::Running);
+    assert_eq!(running[0].sources_completed, 2);
+
+    let totals = ScrapeRunTotals {
+        jobs_found: 12,
+        jobs_new: 4,
+        jobs_updated: 8,
+        high_matches: 1,
+        alerts_sent: 1,
+    };
+    finish_scrape_run(
+        &db,
+        run_id,
+        &totals,
+        &["LinkedIn is manual-only".to_string()],
+    )
+    .await
+    .unwrap();
+
+    let history = get_scrape_history(&db, 10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].status, ScrapeRunStatus::CompletedWithErrors);
+    assert_eq!(history[0].sources_total, 3);
+    assert_eq!(history[0].jobs_new, 4);
+    assert_eq!(history[0].error_count, 2);
+    assert!(history[0].duration_ms.is_some());
+
+    let details = get_scrape_run_details(&db, run_id).await.unwrap().unwrap();
+    assert_eq!(details.errors, ["LinkedIn is manual-only"]);
+    assert_eq!(details.sources.len(), 2);
+    assert_eq!(details.sources[0].source, "greenhouse");
+    assert_eq!(details.sources[1].errors, ["Lever request timed out"]);
+
+    assert!(get_scrape_run_details(&db, run_id + 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_scrape_run_without_errors_completes_cleanly() {
+    let db = jobsentinel_storage::Database::connect_memory()
+        .await
+        .unwrap();
+    db.migrate().await.unwrap();
+
+    let run_id = start_scrape_run(&db, 0).await.unwrap();
+    finish_scrape_run(&db, run_id, &ScrapeRunTotals::default(), &[])
+        .await
+        .unwrap();
+
+    let history = get_scrape_history(&db, 10).await.unwrap();
+    assert_eq!(history[0].status, ScrapeRunStatus::Completed);
+    assert_eq!(history[0].error_count, 0);
+}
+
+#[tokio::test]
+async fn test_scrape_run_history_reports_database_maintenance() {
+    use jobsentinel_storage::{DatabaseMaintenance, MaintenanceStep};
+
+    let db = jobsentinel_storage::Database::connect_memory()
+        .await
+        .unwrap();
+    db.migrate().await.unwrap();
+
+    let run_id = start_scrape_run(&db, 0).await.unwrap();
+    finish_scrape_run(&db, run_id, &ScrapeRunTotals::default(), &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        get_scrape_history(&db, 10).await.unwrap()[0].maintenance,
+        None
+    );
+
+    let maintenance = DatabaseMaintenance {
+        size_before_bytes: 8_192_000,
+        size_after_bytes: 4_096_000,
+        steps: vec![
+            MaintenanceStep::FtsOptimize,
+            MaintenanceStep::IncrementalVacuum,
+        ],
+    };
+    record_scrape_run_maintenance(&db, run_id, &maintenance)
+        .await
+        .unwrap();
+
+    let history = get_scrape_history(&db, 10).await.unwrap();
+    assert_eq!(history[0].maintenance, Some(maintenance));
+}
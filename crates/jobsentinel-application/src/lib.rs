@@ -3,6 +3,7 @@
 mod bookmarklet;
 mod external_ai;
 mod fetcher;
+mod guest_mode;
 mod pending;
 mod service;
 #[cfg(test)]
@@ -14,10 +15,14 @@ pub mod automation;
 pub mod config;
 pub mod credentials;
 pub mod desktop;
+pub mod export;
+pub mod geocoding;
+pub mod ghost_patterns;
 pub mod health;
 pub mod linkedin_workbench;
 pub mod market_intelligence;
 pub mod notify;
+pub mod quick_actions;
 pub mod resume;
 pub mod salary;
 pub mod scheduler;
@@ -29,7 +34,11 @@ pub use config::Config;
 pub use external_ai::{
     send_external_ai_request, ExternalAiCommandRequest, ExternalAiCommandResponse,
 };
-pub use jobsentinel_domain::Job;
+pub use guest_mode::{
+    GuestMode, GuestModeError, GuestModeStatus, GuestRedact, GUEST_ALLOWED_COMMANDS,
+    GUEST_MODE_BLOCKED_MESSAGE,
+};
+pub use jobsentinel_domain::{Job, JobStatus};
 pub use pending::PendingUrlImports;
 pub use service::{confirm_job_import, preview_job_import};
 pub use types::{ImportError, ImportedJobSummary, JobImportPreview};
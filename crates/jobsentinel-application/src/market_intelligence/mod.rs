@@ -1,3 +1,6 @@
 //! SQL-backed market-intelligence facade.
 
+mod report;
+
 pub use jobsentinel_storage::market_intelligence::*;
+pub use report::{build_market_report, MarketReportFormat, MarketReportPeriod};
@@ -0,0 +1,220 @@
+//! Market reports built from stored Hiring Trends data
+//!
+//! A report pairs the latest market snapshot with the oldest one in the
+//! period, so it can show how job counts and salaries moved, and adds the
+//! current skill, company, and location rankings and the alerts raised.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use jobsentinel_storage::Database;
+use serde::{Deserialize, Serialize};
+
+use crate::notify::{
+    format_market_report_markdown, format_market_report_pdf, MarketReport, MarketReportEntry,
+    MarketReportSummary, ReportMarketAlert,
+};
+
+/// Most entries listed per ranking
+const MARKET_REPORT_TOP_ENTRIES: usize = 10;
+
+/// Most market alerts listed in one report
+const MARKET_REPORT_ALERTS: usize = 20;
+
+/// How far back a market report looks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketReportPeriod {
+    #[default]
+    Week,
+    Month,
+    Quarter,
+}
+
+impl MarketReportPeriod {
+    /// Length of the period in days
+    pub const fn days(self) -> i64 {
+        match self {
+            Self::Week => 7,
+            Self::Month => 30,
+            Self::Quarter => 90,
+        }
+    }
+}
+
+/// File format of a market report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketReportFormat {
+    #[default]
+    Markdown,
+    Pdf,
+}
+
+impl MarketReportFormat {
+    /// File extension without the dot
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    /// Render `report` in this format
+    pub fn render(self, report: &MarketReport) -> Vec<u8> {
+        match self {
+            Self::Markdown => format_market_report_markdown(report).into_bytes(),
+            Self::Pdf => format_market_report_pdf(report),
+        }
+    }
+}
+
+/// Build a market report for the `period` ending at `now`
+///
+/// Skill, company, and location rankings cover the last 30 days whatever the
+/// period, matching the Hiring Trends page.
+pub async fn build_market_report(
+    database: &Database,
+    period: MarketReportPeriod,
+    now: DateTime<Utc>,
+) -> Result<MarketReport> {
+    let market = database.market_intelligence();
+    let since = now - ChronoDuration::days(period.days());
+
+    let snapshots = market
+        .get_historical_snapshots(period.days().unsigned_abs() as usize)
+        .await?;
+    let summary = market.get_market_snapshot().await?.map(|latest| {
+        // Snapshots are newest first; changes need a second day
+        let earliest = snapshots
+            .last()
+            .filter(|earliest| earliest.date < latest.date);
+        MarketReportSummary {
+            total_jobs: latest.total_jobs,
+            total_jobs_change: earliest.map(|earliest| latest.total_jobs - earliest.total_jobs),
+            median_salary: latest.median_salary,
+            median_salary_change: earliest
+                .and_then(|earliest| Some(latest.median_salary? - earliest.median_salary?)),
+            remote_job_percentage: latest.remote_job_percentage,
+            companies_hiring: latest.total_companies_hiring,
+            sentiment: latest.market_sentiment,
+        }
+    });
+
+    let trending_skills = market
+        .get_trending_skills(MARKET_REPORT_TOP_ENTRIES)
+        .await?
+        .into_iter()
+        .map(|skill| MarketReportEntry {
+            detail: (skill.trend_direction != "flat").then(|| {
+                format!(
+                    "{} {:.0}%",
+                    skill.trend_direction,
+                    skill.change_percent.abs()
+                )
+            }),
+            name: skill.skill_name,
+            jobs: skill.total_jobs,
+        })
+        .collect();
+    let active_companies = market
+        .get_most_active_companies(MARKET_REPORT_TOP_ENTRIES)
+        .await?
+        .into_iter()
+        .map(|company| MarketReportEntry {
+            name: company.company_name,
+            jobs: company.total_posted,
+            detail: company.hiring_trend,
+        })
+        .collect();
+    let hottest_locations = market
+        .get_hottest_locations(MARKET_REPORT_TOP_ENTRIES)
+        .await?
+        .into_iter()
+        .map(|location| MarketReportEntry {
+            name: location.location,
+            jobs: location.total_jobs,
+            detail: Some(format!("{:.0}% remote", location.remote_percent)),
+        })
+        .collect();
+    let alerts = market
+        .get_alerts_since(since)
+        .await?
+        .into_iter()
+        .take(MARKET_REPORT_ALERTS)
+        .map(|alert| ReportMarketAlert {
+            title: alert.title,
+            description: alert.description,
+            severity: alert.severity.as_str().to_string(),
+        })
+        .collect();
+
+    Ok(MarketReport {
+        period_start: since.date_naive(),
+        period_end: now.date_naive(),
+        summary,
+        trending_skills,
+        active_companies,
+        hottest_locations,
+        alerts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_job;
+
+    #[test]
+    fn test_report_periods_and_formats() {
+        assert_eq!(MarketReportPeriod::Week.days(), 7);
+        assert_eq!(MarketReportPeriod::Quarter.days(), 90);
+        assert_eq!(
+            serde_json::from_str::<MarketReportPeriod>("\"month\"").unwrap(),
+            MarketReportPeriod::Month
+        );
+        assert_eq!(MarketReportFormat::Pdf.extension(), "pdf");
+        assert_eq!(
+            serde_json::from_str::<MarketReportFormat>("\"markdown\"").unwrap(),
+            MarketReportFormat::Markdown
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_market_report_summarizes_the_latest_snapshot() {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        let empty = build_market_report(&database, MarketReportPeriod::Week, Utc::now())
+            .await
+            .unwrap();
+        assert!(empty.summary.is_none());
+        assert!(empty.alerts.is_empty());
+
+        database
+            .upsert_job(&test_job("market_report", "Care Coordinator", "Acme"))
+            .await
+            .unwrap();
+        database
+            .market_intelligence()
+            .run_daily_analysis()
+            .await
+            .unwrap();
+
+        let now = Utc::now();
+        let report = build_market_report(&database, MarketReportPeriod::Month, now)
+            .await
+            .unwrap();
+        let summary = report.summary.as_ref().unwrap();
+        assert_eq!(summary.total_jobs, 1);
+        assert_eq!(summary.total_jobs_change, None);
+        assert_eq!(report.period_end, now.date_naive());
+        assert_eq!(
+            report.period_end - report.period_start,
+            ChronoDuration::days(30)
+        );
+        assert!(
+            String::from_utf8(MarketReportFormat::Markdown.render(&report))
+                .unwrap()
+                .contains("- Jobs tracked: 1\n")
+        );
+    }
+}
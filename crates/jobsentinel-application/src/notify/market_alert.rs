@@ -0,0 +1,29 @@
+//! Market alert delivery
+//!
+//! New Hiring Trends alerts, such as skill surges and hiring sprees, are sent
+//! through the email, Slack, and Discord channels that are turned on.
+
+use super::NotificationService;
+use crate::market_intelligence::MarketAlert;
+
+/// Alert subject, such as "JobSentinel market alert: Case Management demand
+/// surging!"
+#[must_use]
+pub fn market_alert_subject(alert: &MarketAlert) -> String {
+    format!("JobSentinel market alert: {}", alert.title)
+}
+
+/// Alert text: the title, then the description
+#[must_use]
+pub fn market_alert_message(alert: &MarketAlert) -> String {
+    format!("{}\n{}", alert.title, alert.description)
+}
+
+impl NotificationService {
+    /// Send a market alert through the enabled email, Slack, and Discord
+    /// channels. Returns the channels it reached.
+    pub async fn send_market_alert(&self, alert: &MarketAlert) -> Vec<&'static str> {
+        self.send_text_alert(&market_alert_subject(alert), &market_alert_message(alert))
+            .await
+    }
+}
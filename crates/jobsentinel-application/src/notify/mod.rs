@@ -1,27 +1,100 @@
 //! Notification Services
 //!
-//! Sends alerts via multiple channels: Slack, Email, Discord, Telegram, Teams, and Desktop.
+//! Sends alerts via multiple channels: Slack, Email, Discord, Telegram, Teams, custom
+//! webhooks, ntfy, Pushover, and Desktop.
 //!
 //! Credentials are stored in the encrypted local vault and fetched at runtime.
 
 use crate::{
+    ats::InterviewWithJob,
     config::Config,
     credentials::{
         decode_smtp_password_for_binding, CredentialKey, CredentialService, SmtpCredentialBinding,
     },
+    user_data::{MessageTemplateChannel, NotificationMessageTemplates},
 };
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
 use std::sync::Arc;
 
-pub use jobsentinel_notifications::{validate_email_config, validate_slack_webhook, Notification};
+use jobsentinel_notifications::InterviewCalendarEvent;
+
+mod market_alert;
+mod quiet_hours;
+mod reminder;
+mod routing;
+mod search_agent;
+mod source_failure;
+mod text_alert;
+mod weekly_report;
+
+pub use market_alert::{market_alert_message, market_alert_subject};
+pub use quiet_hours::QuietHours;
+pub use routing::{enabled_channels, preview_route, route_job, RoutePreview, RouteSkip};
+pub use search_agent::{
+    search_agent_alert_message, search_agent_alert_subject, SEARCH_AGENT_ALERT_JOB_LIMIT,
+};
+pub use weekly_report::ReportDelivery;
+
+pub use jobsentinel_notifications::{
+    format_market_report_markdown, format_market_report_pdf, format_report_html,
+    format_report_markdown, render_message_template, validate_custom_webhook,
+    validate_discord_webhook, validate_email_config, validate_message_template,
+    validate_ntfy_config, validate_pushover_config, validate_slack_webhook, validate_teams_webhook,
+    validate_telegram_bot, CalendarUpdate, MarketReport, MarketReportEntry, MarketReportSummary,
+    Notification, ReportApplicationActivity, ReportMarketAlert, WeeklyReport,
+};
 
 #[cfg(test)]
 use jobsentinel_notifications::notification_job_href;
 
+const NOT_CONFIGURED_REASON: &str = "not configured";
+
+/// Every enabled alert channel failed to deliver an alert
+#[derive(Debug)]
+pub struct AlertDeliveryError {
+    summary: String,
+    retryable: bool,
+}
+
+impl AlertDeliveryError {
+    /// Whether a later attempt could succeed. Failures caused only by
+    /// channels with no saved credential are not retryable.
+    pub fn is_retryable(&self) -> bool {
+        self.retryable
+    }
+}
+
+impl std::fmt::Display for AlertDeliveryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "All notification channels failed: {}", self.summary)
+    }
+}
+
+impl std::error::Error for AlertDeliveryError {}
+
+/// An alert was held because it is quiet hours
+#[derive(Debug)]
+pub struct AlertDeferred {
+    /// When quiet hours end and the alert may be sent
+    pub until: DateTime<Utc>,
+}
+
+impl std::fmt::Display for AlertDeferred {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Alert held for quiet hours until {}", self.until)
+    }
+}
+
+impl std::error::Error for AlertDeferred {}
+
 /// Notification service
 pub struct NotificationService {
     config: Arc<Config>,
     credentials: Arc<CredentialService>,
+    quiet_hours: Option<QuietHours>,
+    templates: NotificationMessageTemplates,
 }
 
 fn log_notification_sent(channel: &'static str, notification: &Notification) {
@@ -52,7 +125,7 @@ fn record_notification_configuration_missing(errors: &mut Vec<String>, channel:
         failure_kind = "credential_not_configured",
         "Notification credential not configured"
     );
-    errors.push(notification_channel_error(channel, "not configured"));
+    errors.push(notification_channel_error(channel, NOT_CONFIGURED_REASON));
 }
 
 fn record_notification_credential_failure(errors: &mut Vec<String>, channel: &'static str) {
@@ -72,6 +145,8 @@ impl NotificationService {
         Self {
             config,
             credentials: Arc::new(CredentialService::compatibility_keyring()),
+            quiet_hours: None,
+            templates: NotificationMessageTemplates::default(),
         }
     }
 
@@ -79,13 +154,37 @@ impl NotificationService {
         Self {
             config,
             credentials,
+            quiet_hours: None,
+            templates: NotificationMessageTemplates::default(),
         }
     }
 
+    /// Hold immediate alerts during `quiet_hours`
+    pub fn with_quiet_hours(mut self, quiet_hours: Option<QuietHours>) -> Self {
+        self.quiet_hours = quiet_hours;
+        self
+    }
+
+    /// Use the user's message templates for Slack, Discord, and email alerts
+    pub fn with_message_templates(mut self, templates: NotificationMessageTemplates) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// When quiet hours end, if they are in effect at `now`
+    pub fn deferred_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.quiet_hours?.deferred_until(now)
+    }
+
     /// Send immediate alert for high-scoring job across all enabled channels
     ///
     /// Credentials are fetched from secure storage at runtime (not stored in config).
+    /// During quiet hours nothing is sent and the error is [`AlertDeferred`].
     pub async fn send_immediate_alert(&self, notification: &Notification) -> Result<()> {
+        if let Some(until) = self.deferred_until(Utc::now()) {
+            return Err(AlertDeferred { until }.into());
+        }
+
         let mut errors = Vec::new();
 
         // Send to Slack if enabled
@@ -95,6 +194,7 @@ impl NotificationService {
                     if let Err(_e) = jobsentinel_notifications::send_slack_notification(
                         &webhook_url,
                         notification,
+                        self.templates.get(MessageTemplateChannel::Slack),
                     )
                     .await
                     {
@@ -123,18 +223,13 @@ impl NotificationService {
                 Ok(smtp_password) => {
                     // Create config with password from secure storage.
                     let email_config = crate::config::EmailConfig {
-                        enabled: self.config.alerts.email.enabled,
-                        smtp_server: self.config.alerts.email.smtp_server.clone(),
-                        smtp_port: self.config.alerts.email.smtp_port,
-                        smtp_username: self.config.alerts.email.smtp_username.clone(),
                         smtp_password,
-                        from_email: self.config.alerts.email.from_email.clone(),
-                        to_emails: self.config.alerts.email.to_emails.clone(),
-                        use_starttls: self.config.alerts.email.use_starttls,
+                        ..self.config.alerts.email.clone()
                     };
                     if let Err(_e) = jobsentinel_notifications::send_email_notification(
                         &email_config,
                         notification,
+                        self.templates.get(MessageTemplateChannel::Email),
                     )
                     .await
                     {
@@ -166,6 +261,7 @@ impl NotificationService {
                     if let Err(_e) = jobsentinel_notifications::send_discord_notification(
                         &discord_config,
                         notification,
+                        self.templates.get(MessageTemplateChannel::Discord),
                     )
                     .await
                     {
@@ -241,6 +337,83 @@ impl NotificationService {
             }
         }
 
+        // Send to custom webhooks if enabled; the signing secret is optional
+        if self.config.alerts.webhook.enabled {
+            match self
+                .credentials
+                .retrieve(CredentialKey::CustomWebhookSecret)
+                .await
+            {
+                Ok(signing_secret) => {
+                    let webhook_config = crate::config::CustomWebhookConfig {
+                        signing_secret: signing_secret.unwrap_or_default(),
+                        ..self.config.alerts.webhook.clone()
+                    };
+                    if let Err(_e) = jobsentinel_notifications::send_webhook_notification(
+                        &webhook_config,
+                        notification,
+                    )
+                    .await
+                    {
+                        record_notification_delivery_failure(&mut errors, "Webhook");
+                    } else {
+                        log_notification_sent("webhook", notification);
+                    }
+                }
+                Err(_e) => {
+                    record_notification_credential_failure(&mut errors, "Webhook");
+                }
+            }
+        }
+
+        // Send to ntfy if enabled; the access token is only needed for protected topics
+        if self.config.alerts.ntfy.enabled {
+            match self.ntfy_config().await {
+                Ok(Some(ntfy_config)) => {
+                    if let Err(_e) = jobsentinel_notifications::send_ntfy_notification(
+                        &ntfy_config,
+                        notification,
+                    )
+                    .await
+                    {
+                        record_notification_delivery_failure(&mut errors, "ntfy");
+                    } else {
+                        log_notification_sent("ntfy", notification);
+                    }
+                }
+                Ok(None) => {
+                    record_notification_configuration_missing(&mut errors, "ntfy");
+                }
+                Err(_e) => {
+                    record_notification_credential_failure(&mut errors, "ntfy");
+                }
+            }
+        }
+
+        // Send to Pushover if enabled
+        if self.config.alerts.pushover.enabled {
+            match self.pushover_config().await {
+                Ok(Some(pushover_config)) => {
+                    if let Err(_e) = jobsentinel_notifications::send_pushover_notification(
+                        &pushover_config,
+                        notification,
+                    )
+                    .await
+                    {
+                        record_notification_delivery_failure(&mut errors, "Pushover");
+                    } else {
+                        log_notification_sent("pushover", notification);
+                    }
+                }
+                Ok(None) => {
+                    record_notification_configuration_missing(&mut errors, "Pushover");
+                }
+                Err(_e) => {
+                    record_notification_credential_failure(&mut errors, "Pushover");
+                }
+            }
+        }
+
         // If all enabled channels failed, return error
         if !errors.is_empty() {
             // Count enabled channels
@@ -250,21 +423,174 @@ impl NotificationService {
                 self.config.alerts.discord.enabled,
                 self.config.alerts.telegram.enabled,
                 self.config.alerts.teams.enabled,
+                self.config.alerts.webhook.enabled,
+                self.config.alerts.ntfy.enabled,
+                self.config.alerts.pushover.enabled,
             ]
             .iter()
             .filter(|&&e| e)
             .count();
 
             if errors.len() == enabled_count {
-                return Err(anyhow::anyhow!(
-                    "All notification channels failed: {}",
-                    errors.join("; ")
-                ));
+                // A missing setup will not fix itself; anything else may.
+                let retryable = errors
+                    .iter()
+                    .any(|error| !error.ends_with(NOT_CONFIGURED_REASON));
+                return Err(AlertDeliveryError {
+                    summary: errors.join("; "),
+                    retryable,
+                }
+                .into());
             }
         }
 
         Ok(())
     }
+
+    /// ntfy settings with the stored topic and access token, or `None` when
+    /// no topic is saved
+    async fn ntfy_config(&self) -> Result<Option<crate::config::NtfyConfig>> {
+        let Some(topic) = self
+            .credentials
+            .retrieve(CredentialKey::NtfyTopic)
+            .await
+            .map_err(anyhow::Error::msg)?
+        else {
+            return Ok(None);
+        };
+        let access_token = self
+            .credentials
+            .retrieve(CredentialKey::NtfyAccessToken)
+            .await
+            .map_err(anyhow::Error::msg)?
+            .unwrap_or_default();
+        Ok(Some(crate::config::NtfyConfig {
+            topic,
+            access_token,
+            ..self.config.alerts.ntfy.clone()
+        }))
+    }
+
+    /// Pushover settings with the stored keys, or `None` when either is missing
+    async fn pushover_config(&self) -> Result<Option<crate::config::PushoverConfig>> {
+        let user_key = self
+            .credentials
+            .retrieve(CredentialKey::PushoverUserKey)
+            .await
+            .map_err(anyhow::Error::msg)?;
+        let app_token = self
+            .credentials
+            .retrieve(CredentialKey::PushoverAppToken)
+            .await
+            .map_err(anyhow::Error::msg)?;
+        let (Some(user_key), Some(app_token)) = (user_key, app_token) else {
+            return Ok(None);
+        };
+        Ok(Some(crate::config::PushoverConfig {
+            user_key,
+            app_token,
+            ..self.config.alerts.pushover.clone()
+        }))
+    }
+
+    /// Email an interview invite or cancellation when calendar emails are on
+    ///
+    /// Returns `false` without sending when email alerts or interview
+    /// calendar emails are disabled.
+    pub async fn send_interview_calendar_update(
+        &self,
+        interview: &InterviewWithJob,
+        update: CalendarUpdate,
+    ) -> Result<bool> {
+        let email = &self.config.alerts.email;
+        if !email.enabled || !email.send_interview_calendar {
+            return Ok(false);
+        }
+        let smtp_password =
+            resolve_smtp_password_for_email_config(email, &self.credentials).await?;
+        let email_config = crate::config::EmailConfig {
+            smtp_password,
+            ..email.clone()
+        };
+        let event = interview_calendar_event(interview);
+        jobsentinel_notifications::send_interview_calendar_email(&email_config, &event, update)
+            .await?;
+        tracing::info!(
+            channel = "email",
+            interview_id = interview.id,
+            ?update,
+            "Sent interview calendar update"
+        );
+        Ok(true)
+    }
+
+    /// Email a digest of `jobs` to the digest recipients
+    ///
+    /// Uses the stored SMTP password even when immediate email alerts are off,
+    /// so digests can be the only email channel. A `market_report` is attached
+    /// as a PDF.
+    pub async fn send_email_digest(
+        &self,
+        jobs: &[Job],
+        market_report: Option<&MarketReport>,
+    ) -> Result<()> {
+        let email = &self.config.alerts.email;
+        let smtp_password =
+            resolve_smtp_password_for_email_config(email, &self.credentials).await?;
+        let email_config = crate::config::EmailConfig {
+            smtp_password,
+            ..email.clone()
+        };
+        jobsentinel_notifications::send_email_digest(&email_config, jobs, market_report).await?;
+        tracing::info!(
+            channel = "email",
+            job_count = jobs.len(),
+            market_report = market_report.is_some(),
+            frequency = ?email.digest.frequency,
+            "Sent email digest"
+        );
+        Ok(())
+    }
+}
+
+/// Render a message template with a saved job, for previews
+///
+/// Uses the job's saved match score; nothing is sent.
+pub fn preview_message_template(template: &str, job: Job) -> Result<String, String> {
+    validate_message_template(template)?;
+    let score = crate::scoring::JobScore {
+        total: job.score.unwrap_or(0.0),
+        breakdown: crate::scoring::ScoreBreakdown {
+            skills: 0.0,
+            salary: 0.0,
+            location: 0.0,
+            company: 0.0,
+            recency: 0.0,
+        },
+        reasons: Vec::new(),
+    };
+    Ok(render_message_template(
+        template,
+        &Notification {
+            job,
+            score,
+            resume_match: None,
+        },
+    ))
+}
+
+pub(crate) fn interview_calendar_event(interview: &InterviewWithJob) -> InterviewCalendarEvent {
+    InterviewCalendarEvent {
+        interview_id: interview.id,
+        job_title: interview.job_title.clone(),
+        company: interview.company.clone(),
+        interview_type: interview.interview_type.clone(),
+        scheduled_at: interview.scheduled_at.clone(),
+        duration_minutes: interview.duration_minutes,
+        location: interview.location.clone(),
+        interviewer_name: interview.interviewer_name.clone(),
+        notes: interview.notes.clone(),
+    }
 }
 
 async fn resolve_smtp_password_for_email_config(
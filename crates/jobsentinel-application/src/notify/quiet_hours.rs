@@ -0,0 +1,80 @@
+//! Quiet hours for immediate alerts
+//!
+//! During the user's quiet-hours window, alerts are deferred rather than
+//! dropped: the outbox holds them until the window ends and then sends them.
+
+use chrono::{
+    DateTime, Duration as ChronoDuration, Local, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+
+use crate::user_data::GlobalNotificationSettings;
+
+/// A daily local-time window with no immediate alerts
+///
+/// The window may cross midnight, such as 22:00 to 08:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl QuietHours {
+    /// A window from `start` up to `end`, or `None` when they are equal.
+    #[must_use]
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Option<Self> {
+        (start != end).then_some(Self { start, end })
+    }
+
+    /// The window from saved notification settings, or `None` when quiet
+    /// hours are off or the times are not `HH:MM`.
+    #[must_use]
+    pub fn from_settings(settings: &GlobalNotificationSettings) -> Option<Self> {
+        if !settings.quiet_hours_enabled {
+            return None;
+        }
+        let parse = |time: &str| NaiveTime::parse_from_str(time.trim(), "%H:%M").ok();
+        Self::new(
+            parse(&settings.quiet_hours_start)?,
+            parse(&settings.quiet_hours_end)?,
+        )
+    }
+
+    /// Whether `time` falls inside the window. The end time is not included.
+    #[must_use]
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+
+    /// When the window containing `now` ends, or `None` when `now` is
+    /// outside the window.
+    #[must_use]
+    pub fn window_end(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if !self.contains(now.time()) {
+            return None;
+        }
+        let end = now.date().and_time(self.end);
+        Some(if end > now {
+            end
+        } else {
+            end + ChronoDuration::days(1)
+        })
+    }
+
+    /// When alerts held at `now` may be sent, or `None` when `now` is
+    /// outside the window. Uses the local time zone.
+    #[must_use]
+    pub fn deferred_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let end = self.window_end(now.with_timezone(&Local).naive_local())?;
+        // A window ending inside a daylight-saving gap ends an hour later.
+        let end = Local.from_local_datetime(&end).earliest().or_else(|| {
+            Local
+                .from_local_datetime(&(end + ChronoDuration::hours(1)))
+                .earliest()
+        })?;
+        Some(end.with_timezone(&Utc))
+    }
+}
@@ -0,0 +1,16 @@
+//! Application reminder alerts
+//!
+//! Sent when a follow-up or other application reminder comes due. The alert
+//! goes to the email, Slack, and Discord channels that are turned on; the app
+//! shows its own desktop notification. A failed channel does not stop the
+//! others.
+
+use super::NotificationService;
+
+impl NotificationService {
+    /// Send a due reminder through the enabled email, Slack, and Discord
+    /// channels. Returns the channels it reached.
+    pub async fn send_reminder_alert(&self, subject: &str, message: &str) -> Vec<&'static str> {
+        self.send_text_alert(subject, message).await
+    }
+}
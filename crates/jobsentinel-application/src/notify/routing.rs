@@ -0,0 +1,220 @@
+//! Notification routing
+//!
+//! Decides whether a new job alerts, using the per-source score thresholds
+//! and advanced filters saved in notification preferences. The rules match
+//! the desktop alert check in the app, so every channel sees the same jobs.
+
+use jobsentinel_domain::Job;
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::user_data::{AdvancedFilters, NotificationPreferences};
+
+/// Score threshold, 0-100, for sources without their own settings
+const DEFAULT_SOURCE_THRESHOLD: i32 = 70;
+
+/// Advanced filter salaries are entered in thousands
+const SALARY_INPUT_MULTIPLIER: i64 = 1000;
+
+/// Why a job does not alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RouteSkip {
+    /// The company is on the blocked companies list
+    BlockedCompany,
+    /// The score is below the immediate alert threshold
+    BelowAlertThreshold,
+    /// Notifications are turned off
+    NotificationsOff,
+    /// Alerts for the job's source are turned off
+    SourceOff,
+    /// The score is below the source's threshold
+    BelowSourceThreshold,
+    /// The title has none of the include keywords
+    MissingIncludeKeyword,
+    /// The title has an exclude keyword
+    ExcludedKeyword,
+    /// The listed salary is below the minimum
+    BelowMinSalary,
+    /// Only remote jobs alert and this one is not remote
+    NotRemote,
+    /// Only favorite companies alert and this is not one
+    NotIncludedCompany,
+    /// The company is on the companies-to-skip list
+    ExcludedCompany,
+}
+
+impl RouteSkip {
+    /// A short explanation for the user
+    #[must_use]
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::BlockedCompany => "The company is blocked",
+            Self::BelowAlertThreshold => "The match score is below the alert threshold",
+            Self::NotificationsOff => "Notifications are turned off",
+            Self::SourceOff => "Alerts for this job source are turned off",
+            Self::BelowSourceThreshold => "The match score is below this source's threshold",
+            Self::MissingIncludeKeyword => "The title has none of the include keywords",
+            Self::ExcludedKeyword => "The title has an exclude keyword",
+            Self::BelowMinSalary => "The salary is below the minimum salary filter",
+            Self::NotRemote => "The job is not remote",
+            Self::NotIncludedCompany => "The company is not a favorite company",
+            Self::ExcludedCompany => "The company is on the companies to skip list",
+        }
+    }
+}
+
+/// Where a job's alert would go, from [`preview_route`]
+#[derive(Debug, Clone, Serialize)]
+pub struct RoutePreview {
+    pub would_notify: bool,
+    /// Why the job would not alert
+    pub skipped_because: Option<RouteSkip>,
+    pub reason: Option<&'static str>,
+    /// Channels that would receive the alert
+    pub channels: Vec<&'static str>,
+}
+
+/// Check a job against the saved per-source thresholds and advanced filters.
+///
+/// `score` runs from 0 to 1. Quiet hours are not checked here; alerts in
+/// quiet hours are deferred rather than skipped.
+pub fn route_job(
+    job: &Job,
+    score: f64,
+    preferences: &NotificationPreferences,
+) -> Result<(), RouteSkip> {
+    if !preferences.global.enabled {
+        return Err(RouteSkip::NotificationsOff);
+    }
+
+    let threshold = source_threshold(preferences, &job.source)?;
+    if score * 100.0 < f64::from(threshold) {
+        return Err(RouteSkip::BelowSourceThreshold);
+    }
+
+    check_advanced_filters(job, &preferences.advanced_filters)
+}
+
+/// The source's score threshold, 0-100, or why the source never alerts
+fn source_threshold(preferences: &NotificationPreferences, source: &str) -> Result<i32, RouteSkip> {
+    let key: String = source
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    let config = match key.as_str() {
+        // LinkedIn is a search-link destination, never an alert source.
+        "linkedin" => return Err(RouteSkip::SourceOff),
+        "indeed" => &preferences.indeed,
+        "greenhouse" => &preferences.greenhouse,
+        "lever" => &preferences.lever,
+        "jobswithgpt" => &preferences.jobswithgpt,
+        _ => return Ok(DEFAULT_SOURCE_THRESHOLD),
+    };
+    if config.enabled {
+        Ok(config.min_score_threshold)
+    } else {
+        Err(RouteSkip::SourceOff)
+    }
+}
+
+fn check_advanced_filters(job: &Job, filters: &AdvancedFilters) -> Result<(), RouteSkip> {
+    let title = job.title.to_lowercase();
+    let company = job.company.to_lowercase();
+    let contains_any = |text: &str, needles: &[String]| {
+        needles
+            .iter()
+            .any(|needle| text.contains(&needle.to_lowercase()))
+    };
+
+    if !filters.include_keywords.is_empty() && !contains_any(&title, &filters.include_keywords) {
+        return Err(RouteSkip::MissingIncludeKeyword);
+    }
+    if contains_any(&title, &filters.exclude_keywords) {
+        return Err(RouteSkip::ExcludedKeyword);
+    }
+
+    if let Some(min_salary) = filters.min_salary {
+        // Jobs without a listed salary are not filtered out.
+        let salary = job.salary_max.or(job.salary_min).unwrap_or(0);
+        if salary > 0 && salary < min_salary.saturating_mul(SALARY_INPUT_MULTIPLIER) {
+            return Err(RouteSkip::BelowMinSalary);
+        }
+    }
+
+    if filters.remote_only {
+        let remote = job.remote == Some(true)
+            || job
+                .location
+                .as_deref()
+                .is_some_and(|location| location.to_lowercase().contains("remote"))
+            || title.contains("remote");
+        if !remote {
+            return Err(RouteSkip::NotRemote);
+        }
+    }
+
+    if !filters.included_companies.is_empty()
+        && !contains_any(&company, &filters.included_companies)
+    {
+        return Err(RouteSkip::NotIncludedCompany);
+    }
+    if contains_any(&company, &filters.excluded_companies) {
+        return Err(RouteSkip::ExcludedCompany);
+    }
+
+    Ok(())
+}
+
+/// Alert channels turned on in settings
+#[must_use]
+pub fn enabled_channels(config: &Config) -> Vec<&'static str> {
+    let alerts = &config.alerts;
+    [
+        (alerts.desktop.enabled, "Desktop"),
+        (alerts.slack.enabled, "Slack"),
+        (alerts.email.enabled, "Email"),
+        (alerts.discord.enabled, "Discord"),
+        (alerts.telegram.enabled, "Telegram"),
+        (alerts.teams.enabled, "Teams"),
+        (alerts.webhook.enabled, "Webhook"),
+        (alerts.ntfy.enabled, "ntfy"),
+        (alerts.pushover.enabled, "Pushover"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, channel)| enabled.then_some(channel))
+    .collect()
+}
+
+/// Which channels a job with `score` would alert, without sending anything
+///
+/// Applies the same checks as a scraping run: blocked companies, the
+/// immediate alert threshold, then [`route_job`].
+#[must_use]
+pub fn preview_route(
+    config: &Config,
+    preferences: &NotificationPreferences,
+    job: &Job,
+    score: f64,
+) -> RoutePreview {
+    let skip = if config.blocks_company(&job.company) {
+        Err(RouteSkip::BlockedCompany)
+    } else if score < config.immediate_alert_threshold {
+        Err(RouteSkip::BelowAlertThreshold)
+    } else {
+        route_job(job, score, preferences)
+    }
+    .err();
+
+    RoutePreview {
+        would_notify: skip.is_none(),
+        skipped_because: skip,
+        reason: skip.map(RouteSkip::describe),
+        channels: if skip.is_none() {
+            enabled_channels(config)
+        } else {
+            Vec::new()
+        },
+    }
+}
@@ -0,0 +1,76 @@
+//! Job agent alerts
+//!
+//! A job agent is a saved search that alerts on new matching jobs. Its alert
+//! lists the new matches, best first, and goes to the agent's channels, or to
+//! every enabled email, Slack, and Discord channel when it has none.
+
+use jobsentinel_domain::Job;
+
+use super::NotificationService;
+use crate::user_data::SavedSearch;
+
+/// Most jobs listed in one alert; the rest are counted
+pub const SEARCH_AGENT_ALERT_JOB_LIMIT: usize = 10;
+
+/// "1 new job" or "3 new jobs"
+fn new_jobs(count: usize) -> String {
+    let noun = if count == 1 { "job" } else { "jobs" };
+    format!("{count} new {noun}")
+}
+
+/// Alert subject, such as "JobSentinel: 3 new jobs for Remote support"
+#[must_use]
+pub fn search_agent_alert_subject(search: &SavedSearch, match_count: usize) -> String {
+    format!("JobSentinel: {} for {}", new_jobs(match_count), search.name)
+}
+
+/// Alert text: one line per job with its score, company, location, and link,
+/// then how many more matched
+#[must_use]
+pub fn search_agent_alert_message(search: &SavedSearch, jobs: &[Job]) -> String {
+    let mut lines = vec![format!(
+        "{} for your saved search \"{}\":",
+        new_jobs(jobs.len()),
+        search.name
+    )];
+    for job in jobs.iter().take(SEARCH_AGENT_ALERT_JOB_LIMIT) {
+        let score = job
+            .score
+            .map(|score| format!(" ({:.0}% match)", score * 100.0))
+            .unwrap_or_default();
+        let location = job
+            .location
+            .as_deref()
+            .filter(|location| !location.trim().is_empty())
+            .map(|location| format!(", {location}"))
+            .unwrap_or_default();
+        lines.push(format!(
+            "- {} at {}{location}{score}\n  {}",
+            job.title, job.company, job.url
+        ));
+    }
+    if jobs.len() > SEARCH_AGENT_ALERT_JOB_LIMIT {
+        lines.push(format!(
+            "...and {} more in JobSentinel.",
+            jobs.len() - SEARCH_AGENT_ALERT_JOB_LIMIT
+        ));
+    }
+    lines.join("\n")
+}
+
+impl NotificationService {
+    /// Send a job agent's new matches through its channels. Returns the
+    /// channels it reached.
+    pub async fn send_search_agent_alert(
+        &self,
+        search: &SavedSearch,
+        jobs: &[Job],
+    ) -> Vec<&'static str> {
+        self.send_text_alert_via(
+            &search.agent.channels,
+            &search_agent_alert_subject(search, jobs.len()),
+            &search_agent_alert_message(search, jobs),
+        )
+        .await
+    }
+}
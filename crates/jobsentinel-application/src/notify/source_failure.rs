@@ -0,0 +1,51 @@
+//! Failing source alerts
+//!
+//! Sent once when a source has failed several scheduled checks in a row. The
+//! alert goes to the Slack and Discord channels that are turned on; the app
+//! shows its own desktop notification.
+
+use super::NotificationService;
+use crate::credentials::CredentialKey;
+
+impl NotificationService {
+    /// Send `message` about a failing source through the enabled Slack and
+    /// Discord channels. Returns the channels it reached.
+    pub async fn send_source_failure_alert(&self, message: &str) -> Vec<&'static str> {
+        let alerts = &self.config.alerts;
+        let mut delivered = Vec::new();
+
+        if alerts.slack.enabled {
+            if let Ok(Some(webhook_url)) =
+                self.credentials.retrieve(CredentialKey::SlackWebhook).await
+            {
+                if jobsentinel_notifications::send_slack_report(&webhook_url, message)
+                    .await
+                    .is_ok()
+                {
+                    delivered.push("Slack");
+                }
+            }
+        }
+
+        if alerts.discord.enabled {
+            if let Ok(Some(webhook_url)) = self
+                .credentials
+                .retrieve(CredentialKey::DiscordWebhook)
+                .await
+            {
+                let discord_config = crate::config::DiscordConfig {
+                    webhook_url,
+                    ..alerts.discord.clone()
+                };
+                if jobsentinel_notifications::send_discord_report(&discord_config, message)
+                    .await
+                    .is_ok()
+                {
+                    delivered.push("Discord");
+                }
+            }
+        }
+
+        delivered
+    }
+}
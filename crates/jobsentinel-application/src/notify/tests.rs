@@ -30,6 +30,8 @@ fn create_disabled_config() -> Arc<Config> {
             from_email: String::new(),
             to_emails: vec![],
             use_starttls: true,
+            send_interview_calendar: false,
+            digest: Default::default(),
         },
         discord: DiscordConfig {
             enabled: false,
@@ -45,11 +47,16 @@ fn create_disabled_config() -> Arc<Config> {
             enabled: false,
             webhook_url: String::new(),
         },
+        webhook: Default::default(),
+        ntfy: Default::default(),
+        pushover: Default::default(),
         desktop: crate::config::DesktopConfig {
             enabled: false,
             show_when_focused: false,
             play_sound: false,
         },
+        weekly_report: Default::default(),
+        include_resume_match: false,
     };
     Arc::new(config)
 }
@@ -273,4 +280,8 @@ async fn test_send_immediate_alert_all_channels_disabled() {
 
 mod service_and_failure_tests;
 
+mod quiet_hours_tests;
+
+mod routing_tests;
+
 mod channel_edge_cases;
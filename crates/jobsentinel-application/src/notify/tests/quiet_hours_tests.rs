@@ -0,0 +1,85 @@
+use super::*;
+use crate::user_data::GlobalNotificationSettings;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 3, day)
+        .unwrap()
+        .and_time(time(hour, minute))
+}
+
+#[test]
+fn test_quiet_hours_same_day_window() {
+    let quiet = QuietHours::new(time(12, 0), time(14, 0)).unwrap();
+
+    assert!(!quiet.contains(time(11, 59)));
+    assert!(quiet.contains(time(12, 0)));
+    assert!(quiet.contains(time(13, 30)));
+    assert!(!quiet.contains(time(14, 0)));
+}
+
+#[test]
+fn test_quiet_hours_overnight_window() {
+    let quiet = QuietHours::new(time(22, 0), time(8, 0)).unwrap();
+
+    assert!(quiet.contains(time(23, 15)));
+    assert!(quiet.contains(time(0, 0)));
+    assert!(quiet.contains(time(7, 59)));
+    assert!(!quiet.contains(time(8, 0)));
+    assert!(!quiet.contains(time(21, 59)));
+}
+
+#[test]
+fn test_quiet_hours_equal_times_is_no_window() {
+    assert!(QuietHours::new(time(9, 0), time(9, 0)).is_none());
+}
+
+#[test]
+fn test_quiet_hours_window_end() {
+    let quiet = QuietHours::new(time(22, 0), time(8, 0)).unwrap();
+
+    assert_eq!(quiet.window_end(at(10, 23, 0)), Some(at(11, 8, 0)));
+    assert_eq!(quiet.window_end(at(11, 6, 30)), Some(at(11, 8, 0)));
+    assert_eq!(quiet.window_end(at(11, 12, 0)), None);
+}
+
+#[test]
+fn test_quiet_hours_from_settings() {
+    let mut settings = GlobalNotificationSettings {
+        quiet_hours_enabled: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        QuietHours::from_settings(&settings),
+        QuietHours::new(time(22, 0), time(8, 0))
+    );
+
+    settings.quiet_hours_enabled = false;
+    assert!(QuietHours::from_settings(&settings).is_none());
+
+    settings.quiet_hours_enabled = true;
+    settings.quiet_hours_start = "10pm".to_string();
+    assert!(QuietHours::from_settings(&settings).is_none());
+}
+
+#[tokio::test]
+async fn test_immediate_alert_is_deferred_during_quiet_hours() {
+    let config = create_disabled_config();
+    let now = chrono::Local::now().time();
+    let quiet = QuietHours::new(
+        now - chrono::Duration::minutes(5),
+        now + chrono::Duration::minutes(60),
+    );
+    let service = NotificationService::new(config).with_quiet_hours(quiet);
+
+    let error = service
+        .send_immediate_alert(&notification_fixture())
+        .await
+        .unwrap_err();
+    let deferred = error.downcast_ref::<AlertDeferred>().unwrap();
+    assert!(deferred.until > chrono::Utc::now());
+}
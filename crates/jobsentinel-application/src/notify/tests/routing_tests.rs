@@ -0,0 +1,148 @@
+use super::*;
+use crate::test_support::test_job;
+use crate::user_data::NotificationPreferences;
+
+fn job_from(source: &str) -> jobsentinel_domain::Job {
+    let mut job = test_job("routing", "Senior Security Engineer", "Harbor Care");
+    job.source = source.to_string();
+    job
+}
+
+#[test]
+fn test_route_job_uses_source_threshold() {
+    let preferences = NotificationPreferences::default();
+
+    // Greenhouse defaults to 80, unknown sources to 70.
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.75, &preferences),
+        Err(RouteSkip::BelowSourceThreshold)
+    );
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.85, &preferences),
+        Ok(())
+    );
+    assert_eq!(route_job(&job_from("remoteok"), 0.75, &preferences), Ok(()));
+}
+
+#[test]
+fn test_route_job_skips_disabled_sources_and_linkedin() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.lever.enabled = false;
+    preferences.linkedin.enabled = true;
+
+    assert_eq!(
+        route_job(&job_from("Lever"), 0.99, &preferences),
+        Err(RouteSkip::SourceOff)
+    );
+    assert_eq!(
+        route_job(&job_from("linkedin"), 0.99, &preferences),
+        Err(RouteSkip::SourceOff)
+    );
+}
+
+#[test]
+fn test_route_job_skips_when_notifications_off() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.global.enabled = false;
+
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.99, &preferences),
+        Err(RouteSkip::NotificationsOff)
+    );
+}
+
+#[test]
+fn test_route_job_keyword_filters() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.advanced_filters.include_keywords = vec!["Rust".to_string()];
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.9, &preferences),
+        Err(RouteSkip::MissingIncludeKeyword)
+    );
+
+    preferences.advanced_filters.include_keywords = vec!["SECURITY".to_string()];
+    preferences.advanced_filters.exclude_keywords = vec!["senior".to_string()];
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.9, &preferences),
+        Err(RouteSkip::ExcludedKeyword)
+    );
+}
+
+#[test]
+fn test_route_job_salary_filter_ignores_unlisted_salary() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.advanced_filters.min_salary = Some(150);
+
+    let mut job = job_from("greenhouse");
+    assert_eq!(route_job(&job, 0.9, &preferences), Ok(()));
+
+    job.salary_min = Some(120_000);
+    job.salary_max = Some(140_000);
+    assert_eq!(
+        route_job(&job, 0.9, &preferences),
+        Err(RouteSkip::BelowMinSalary)
+    );
+
+    job.salary_max = Some(160_000);
+    assert_eq!(route_job(&job, 0.9, &preferences), Ok(()));
+}
+
+#[test]
+fn test_route_job_remote_only() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.advanced_filters.remote_only = true;
+
+    let mut job = job_from("greenhouse");
+    job.remote = Some(false);
+    job.location = Some("Austin, TX".to_string());
+    assert_eq!(
+        route_job(&job, 0.9, &preferences),
+        Err(RouteSkip::NotRemote)
+    );
+
+    job.location = Some("Remote - US".to_string());
+    assert_eq!(route_job(&job, 0.9, &preferences), Ok(()));
+}
+
+#[test]
+fn test_route_job_company_lists() {
+    let mut preferences = NotificationPreferences::default();
+    preferences.advanced_filters.included_companies = vec!["Northwind".to_string()];
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.9, &preferences),
+        Err(RouteSkip::NotIncludedCompany)
+    );
+
+    preferences.advanced_filters.included_companies = vec!["harbor".to_string()];
+    preferences.advanced_filters.excluded_companies = vec!["Harbor Care".to_string()];
+    assert_eq!(
+        route_job(&job_from("greenhouse"), 0.9, &preferences),
+        Err(RouteSkip::ExcludedCompany)
+    );
+}
+
+#[test]
+fn test_preview_route_lists_enabled_channels() {
+    let mut config = (*create_disabled_config()).clone();
+    config.alerts.slack.enabled = true;
+    config.alerts.ntfy.enabled = true;
+    let preferences = NotificationPreferences::default();
+
+    let preview = preview_route(&config, &preferences, &job_from("greenhouse"), 0.95);
+    assert!(preview.would_notify);
+    assert_eq!(preview.skipped_because, None);
+    assert_eq!(preview.channels, vec!["Slack", "ntfy"]);
+
+    let preview = preview_route(&config, &preferences, &job_from("greenhouse"), 0.5);
+    assert!(!preview.would_notify);
+    assert_eq!(
+        preview.skipped_because,
+        Some(RouteSkip::BelowAlertThreshold)
+    );
+    assert!(preview.reason.is_some());
+    assert!(preview.channels.is_empty());
+
+    config.blocked_companies = vec!["Harbor Care".to_string()];
+    let preview = preview_route(&config, &preferences, &job_from("greenhouse"), 0.95);
+    assert_eq!(preview.skipped_because, Some(RouteSkip::BlockedCompany));
+}
@@ -146,6 +146,8 @@ fn email_config_for(server: &str, username: &str) -> EmailConfig {
         from_email: "from@example.com".to_string(),
         to_emails: vec!["to@example.com".to_string()],
         use_starttls: true,
+        send_interview_calendar: false,
+        digest: Default::default(),
     }
 }
 
@@ -190,6 +192,37 @@ async fn runtime_email_resolution_rejects_legacy_unbound_smtp_password() {
     assert!(!err.contains("smtp-secret"));
 }
 
+#[tokio::test]
+async fn interview_calendar_update_is_skipped_unless_opted_in() {
+    let mut config = create_disabled_config();
+    Arc::get_mut(&mut config).unwrap().alerts.email =
+        email_config_for("smtp.example.com", "user@example.com");
+    let service = NotificationService::with_credentials(config, Arc::new(test_credentials().await));
+    let interview = InterviewWithJob {
+        id: 1,
+        application_id: 1,
+        interview_type: "phone_interview".to_string(),
+        scheduled_at: "2026-02-01T10:00".to_string(),
+        duration_minutes: 30,
+        location: None,
+        interviewer_name: None,
+        interviewer_title: None,
+        notes: None,
+        completed: false,
+        outcome: None,
+        post_interview_notes: None,
+        job_title: "Care Coordinator".to_string(),
+        company: "Community Care Network".to_string(),
+    };
+
+    let sent = service
+        .send_interview_calendar_update(&interview, CalendarUpdate::Scheduled)
+        .await
+        .unwrap();
+
+    assert!(!sent, "Calendar emails need their own opt-in");
+}
+
 #[test]
 fn test_notification_service_checks_discord_enabled() {
     let mut config = create_disabled_config();
@@ -414,3 +447,15 @@ fn test_webhook_url_empty_when_disabled() {
     assert!(!config.alerts.teams.enabled);
     assert!(config.alerts.teams.webhook_url.is_empty());
 }
+
+#[test]
+fn test_preview_message_template_uses_saved_score() {
+    let mut job = notification_fixture().job;
+    job.score = Some(0.875);
+
+    assert_eq!(
+        preview_message_template("{{title}}: {{score_percent}}%", job.clone()),
+        Ok("Care Coordinator: 88%".to_string())
+    );
+    assert!(preview_message_template("{{reasons}}", job).is_err());
+}
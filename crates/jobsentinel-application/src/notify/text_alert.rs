@@ -0,0 +1,82 @@
+//! Plain text alerts
+//!
+//! Short messages that are not about a single job, such as due reminders and
+//! market alerts. They go to the email, Slack, and Discord channels that are
+//! turned on; a failed channel does not stop the others.
+
+use super::{resolve_smtp_password_for_email_config, NotificationService};
+use crate::credentials::CredentialKey;
+use crate::user_data::MessageTemplateChannel;
+
+impl NotificationService {
+    /// Send `message` through the enabled email, Slack, and Discord channels,
+    /// with `subject` as the email subject. Returns the channels it reached.
+    pub(super) async fn send_text_alert(&self, subject: &str, message: &str) -> Vec<&'static str> {
+        self.send_text_alert_via(&[], subject, message).await
+    }
+
+    /// Like [`Self::send_text_alert`], limited to `channels` when any are
+    /// given. A listed channel that is turned off is still skipped.
+    pub(super) async fn send_text_alert_via(
+        &self,
+        channels: &[MessageTemplateChannel],
+        subject: &str,
+        message: &str,
+    ) -> Vec<&'static str> {
+        let alerts = &self.config.alerts;
+        let wanted =
+            |channel: MessageTemplateChannel| channels.is_empty() || channels.contains(&channel);
+        let mut delivered = Vec::new();
+
+        if alerts.email.enabled && wanted(MessageTemplateChannel::Email) {
+            if let Ok(smtp_password) =
+                resolve_smtp_password_for_email_config(&alerts.email, &self.credentials).await
+            {
+                let email_config = crate::config::EmailConfig {
+                    smtp_password,
+                    ..alerts.email.clone()
+                };
+                if jobsentinel_notifications::send_text_email(&email_config, subject, message)
+                    .await
+                    .is_ok()
+                {
+                    delivered.push("Email");
+                }
+            }
+        }
+
+        if alerts.slack.enabled && wanted(MessageTemplateChannel::Slack) {
+            if let Ok(Some(webhook_url)) =
+                self.credentials.retrieve(CredentialKey::SlackWebhook).await
+            {
+                if jobsentinel_notifications::send_slack_report(&webhook_url, message)
+                    .await
+                    .is_ok()
+                {
+                    delivered.push("Slack");
+                }
+            }
+        }
+
+        if alerts.discord.enabled && wanted(MessageTemplateChannel::Discord) {
+            if let Ok(Some(webhook_url)) = self
+                .credentials
+                .retrieve(CredentialKey::DiscordWebhook)
+                .await
+            {
+                let discord_config = crate::config::DiscordConfig {
+                    webhook_url,
+                    ..alerts.discord.clone()
+                };
+                if jobsentinel_notifications::send_discord_report(&discord_config, message)
+                    .await
+                    .is_ok()
+                {
+                    delivered.push("Discord");
+                }
+            }
+        }
+
+        delivered
+    }
+}
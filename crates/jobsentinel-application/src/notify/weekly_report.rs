@@ -0,0 +1,98 @@
+//! Weekly report delivery
+//!
+//! Reports go to the email, Slack, and Discord channels that are turned on.
+//! A failed channel does not stop the others; the report is saved locally
+//! whatever happens here.
+
+use serde::Serialize;
+
+use super::{resolve_smtp_password_for_email_config, NotificationService};
+use crate::credentials::CredentialKey;
+use jobsentinel_notifications::WeeklyReport;
+
+/// Channels a weekly report reached and channels that failed
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReportDelivery {
+    pub delivered: Vec<&'static str>,
+    pub failed: Vec<&'static str>,
+}
+
+impl ReportDelivery {
+    fn record(&mut self, channel: &'static str, sent: bool) {
+        if sent {
+            tracing::info!(channel, "Sent weekly report");
+            self.delivered.push(channel);
+        } else {
+            tracing::warn!(channel, "Weekly report delivery failed");
+            self.failed.push(channel);
+        }
+    }
+}
+
+impl NotificationService {
+    /// Send a weekly report through the enabled email, Slack, and Discord
+    /// channels. `markdown` is the rendered report used for Slack and Discord.
+    pub async fn send_weekly_report(
+        &self,
+        report: &WeeklyReport,
+        markdown: &str,
+    ) -> ReportDelivery {
+        let alerts = &self.config.alerts;
+        let mut delivery = ReportDelivery::default();
+
+        if alerts.email.enabled {
+            let sent = match resolve_smtp_password_for_email_config(
+                &alerts.email,
+                &self.credentials,
+            )
+            .await
+            {
+                Ok(smtp_password) => {
+                    let email_config = crate::config::EmailConfig {
+                        smtp_password,
+                        ..alerts.email.clone()
+                    };
+                    jobsentinel_notifications::send_report_email(&email_config, report)
+                        .await
+                        .is_ok()
+                }
+                Err(_e) => false,
+            };
+            delivery.record("Email", sent);
+        }
+
+        if alerts.slack.enabled {
+            let sent = match self.credentials.retrieve(CredentialKey::SlackWebhook).await {
+                Ok(Some(webhook_url)) => {
+                    jobsentinel_notifications::send_slack_report(&webhook_url, markdown)
+                        .await
+                        .is_ok()
+                }
+                Ok(None) | Err(_) => false,
+            };
+            delivery.record("Slack", sent);
+        }
+
+        if alerts.discord.enabled {
+            let sent = match self
+                .credentials
+                .retrieve(CredentialKey::DiscordWebhook)
+                .await
+            {
+                Ok(Some(webhook_url)) => {
+                    let discord_config = crate::config::DiscordConfig {
+                        webhook_url,
+                        ..alerts.discord.clone()
+                    };
+                    jobsentinel_notifications::send_discord_report(&discord_config, markdown)
+                        .await
+                        .is_ok()
+                }
+                Ok(None) | Err(_) => false,
+            };
+            delivery.record("Discord", sent);
+        }
+
+        delivery
+    }
+}
@@ -0,0 +1,258 @@
+//! Keyboard-first triage actions behind one command.
+//!
+//! Each action checks the revision the caller last saw before writing, so a
+//! key press aimed at a stale card fails instead of undoing someone else's
+//! change. Reversible actions push undo steps onto an in-memory stack,
+//! grouped into batches so one undo reverts a multi-select triage pass.
+
+mod undo;
+
+use chrono::{Duration, Utc};
+use jobsentinel_domain::Job;
+use jobsentinel_storage::application_tracking::ReminderSchedule;
+use jobsentinel_storage::Database;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use undo::UndoStep;
+pub use undo::{QuickActionUndo, QuickActions};
+
+/// How far `snooze_reminder` pushes a reminder.
+const REMINDER_SNOOZE: Duration = Duration::days(1);
+
+/// Triage operations available from the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuickAction {
+    /// Toggle the job's bookmark. Target is a job id.
+    Bookmark,
+    /// Hide the job from the feed. Target is a job id.
+    Dismiss,
+    /// Return the job's posting URL for the UI to open. Target is a job id.
+    Open,
+    /// Start tracking an application for the job. Target is a job id.
+    CreateApplication,
+    /// Push an application reminder back a day. Target is a reminder id.
+    SnoozeReminder,
+}
+
+/// One quick action from the triage UI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuickActionRequest {
+    pub action: QuickAction,
+    pub target_id: i64,
+    /// Revision the caller last saw; the action fails if the target changed.
+    #[serde(default)]
+    pub expected_revision: Option<String>,
+    /// Undo batch to join, so one undo reverts several actions together.
+    #[serde(default)]
+    pub batch_id: Option<String>,
+}
+
+/// What a quick action changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuickActionOutcome {
+    pub action: QuickAction,
+    pub target_id: i64,
+    /// Target revision after the action, to send with the next one.
+    pub revision: String,
+    /// Undo batch holding this action, or `None` if nothing can be undone.
+    pub batch_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bookmarked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<String>,
+}
+
+/// Quick action failures.
+#[derive(Error, Debug)]
+pub enum QuickActionError {
+    #[error("Quick action target not found")]
+    NotFound,
+
+    /// The target changed after the caller loaded it.
+    #[error("Target changed since it was loaded")]
+    Conflict { current_revision: String },
+
+    #[error("Reminder is already completed")]
+    ReminderCompleted,
+
+    #[error("Database operation failed")]
+    Database(String),
+}
+
+fn database_error(error: impl std::fmt::Display) -> QuickActionError {
+    QuickActionError::Database(error.to_string())
+}
+
+/// Revision token for a job's triage state.
+#[must_use]
+pub fn job_revision(job: &Job) -> String {
+    format!(
+        "job:{}:{}:{}",
+        job.updated_at.timestamp_millis(),
+        u8::from(job.hidden),
+        u8::from(job.bookmarked)
+    )
+}
+
+/// Revision token for a reminder's schedule.
+#[must_use]
+pub fn reminder_revision(schedule: &ReminderSchedule) -> String {
+    format!(
+        "reminder:{}:{}",
+        schedule.reminder_time,
+        u8::from(schedule.completed)
+    )
+}
+
+fn check_revision(expected: Option<&str>, current: String) -> Result<(), QuickActionError> {
+    match expected {
+        Some(expected) if expected != current => Err(QuickActionError::Conflict {
+            current_revision: current,
+        }),
+        _ => Ok(()),
+    }
+}
+
+impl QuickActions {
+    /// Run one quick action and record how to undo it.
+    pub async fn apply(
+        &self,
+        database: &Database,
+        request: QuickActionRequest,
+    ) -> Result<QuickActionOutcome, QuickActionError> {
+        let (mut outcome, step) = match request.action {
+            QuickAction::SnoozeReminder => snooze_reminder(database, &request).await?,
+            _ => apply_to_job(database, &request).await?,
+        };
+        if let Some(step) = step {
+            outcome.batch_id = Some(self.push(request.batch_id, step));
+        }
+        Ok(outcome)
+    }
+}
+
+async fn apply_to_job(
+    database: &Database,
+    request: &QuickActionRequest,
+) -> Result<(QuickActionOutcome, Option<UndoStep>), QuickActionError> {
+    let mut job = database
+        .get_job_by_id(request.target_id)
+        .await
+        .map_err(database_error)?
+        .ok_or(QuickActionError::NotFound)?;
+    check_revision(request.expected_revision.as_deref(), job_revision(&job))?;
+
+    let mut outcome = QuickActionOutcome {
+        action: request.action,
+        target_id: job.id,
+        revision: String::new(),
+        batch_id: None,
+        url: None,
+        bookmarked: None,
+        application_id: None,
+        snoozed_until: None,
+    };
+    let step = match request.action {
+        QuickAction::Bookmark => {
+            let previous = job.bookmarked;
+            job.bookmarked = database
+                .toggle_bookmark(job.id)
+                .await
+                .map_err(database_error)?;
+            outcome.bookmarked = Some(job.bookmarked);
+            Some(UndoStep::SetBookmark {
+                job_id: job.id,
+                bookmarked: previous,
+            })
+        }
+        QuickAction::Dismiss if job.hidden => None,
+        QuickAction::Dismiss => {
+            database.hide_job(job.id).await.map_err(database_error)?;
+            job.hidden = true;
+            Some(UndoStep::Unhide { job_id: job.id })
+        }
+        QuickAction::Open => {
+            outcome.url = Some(job.url.clone());
+            None
+        }
+        QuickAction::CreateApplication => {
+            let tracker = database.application_tracker();
+            match tracker
+                .find_application_id_by_job_hash(&job.hash)
+                .await
+                .map_err(database_error)?
+            {
+                Some(existing) => {
+                    outcome.application_id = Some(existing);
+                    None
+                }
+                None => {
+                    let application_id = tracker
+                        .create_application(&job.hash)
+                        .await
+                        .map_err(database_error)?;
+                    outcome.application_id = Some(application_id);
+                    Some(UndoStep::DeleteApplication { application_id })
+                }
+            }
+        }
+        QuickAction::SnoozeReminder => None,
+    };
+    outcome.revision = job_revision(&job);
+    Ok((outcome, step))
+}
+
+async fn snooze_reminder(
+    database: &Database,
+    request: &QuickActionRequest,
+) -> Result<(QuickActionOutcome, Option<UndoStep>), QuickActionError> {
+    let tracker = database.application_tracker();
+    let mut schedule = tracker
+        .get_reminder_schedule(request.target_id)
+        .await
+        .map_err(database_error)?
+        .ok_or(QuickActionError::NotFound)?;
+    check_revision(
+        request.expected_revision.as_deref(),
+        reminder_revision(&schedule),
+    )?;
+    if schedule.completed {
+        return Err(QuickActionError::ReminderCompleted);
+    }
+
+    let now = Utc::now();
+    let due = schedule.due_at().unwrap_or(now);
+    let snoozed_until = (due.max(now) + REMINDER_SNOOZE).to_rfc3339();
+    if !tracker
+        .reschedule_reminder(schedule.id, &snoozed_until)
+        .await
+        .map_err(database_error)?
+    {
+        return Err(QuickActionError::NotFound);
+    }
+
+    let step = UndoStep::RescheduleReminder {
+        reminder_id: schedule.id,
+        reminder_time: std::mem::replace(&mut schedule.reminder_time, snoozed_until.clone()),
+    };
+    let outcome = QuickActionOutcome {
+        action: request.action,
+        target_id: schedule.id,
+        revision: reminder_revision(&schedule),
+        batch_id: None,
+        url: None,
+        bookmarked: None,
+        application_id: None,
+        snoozed_until: Some(snoozed_until),
+    };
+    Ok((outcome, Some(step)))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,147 @@
+use super::*;
+use crate::test_support::test_job;
+
+async fn database_with_job(hash: &str) -> (Database, i64) {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    let job_id = database
+        .upsert_job(&test_job(hash, "Care Coordinator", "Example Health"))
+        .await
+        .unwrap();
+    (database, job_id)
+}
+
+fn request(action: QuickAction, target_id: i64) -> QuickActionRequest {
+    QuickActionRequest {
+        action,
+        target_id,
+        expected_revision: None,
+        batch_id: None,
+    }
+}
+
+#[tokio::test]
+async fn batched_actions_undo_together() {
+    let (database, job_id) = database_with_job("quick-batch").await;
+    let quick_actions = QuickActions::default();
+
+    let bookmark = quick_actions
+        .apply(&database, request(QuickAction::Bookmark, job_id))
+        .await
+        .unwrap();
+    assert_eq!(bookmark.bookmarked, Some(true));
+    let dismiss = quick_actions
+        .apply(
+            &database,
+            QuickActionRequest {
+                expected_revision: Some(bookmark.revision.clone()),
+                batch_id: bookmark.batch_id.clone(),
+                ..request(QuickAction::Dismiss, job_id)
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(dismiss.batch_id, bookmark.batch_id);
+
+    let undo = quick_actions.undo_latest(&database).await.unwrap().unwrap();
+    assert_eq!(undo.actions_reverted, 2);
+    assert_eq!(undo.batches_remaining, 0);
+    let job = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert!(!job.bookmarked);
+    assert!(!job.hidden);
+    assert!(quick_actions
+        .undo_latest(&database)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn stale_revision_is_rejected_without_writing() {
+    let (database, job_id) = database_with_job("quick-conflict").await;
+    let quick_actions = QuickActions::default();
+    let job = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    let loaded_revision = job_revision(&job);
+    database.toggle_bookmark(job_id).await.unwrap();
+
+    let error = quick_actions
+        .apply(
+            &database,
+            QuickActionRequest {
+                expected_revision: Some(loaded_revision),
+                ..request(QuickAction::Dismiss, job_id)
+            },
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, QuickActionError::Conflict { .. }));
+    let job = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert!(!job.hidden);
+    assert!(quick_actions
+        .undo_latest(&database)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn open_and_existing_applications_leave_nothing_to_undo() {
+    let (database, job_id) = database_with_job("quick-open").await;
+    let quick_actions = QuickActions::default();
+
+    let open = quick_actions
+        .apply(&database, request(QuickAction::Open, job_id))
+        .await
+        .unwrap();
+    assert!(open.url.is_some());
+    assert!(open.batch_id.is_none());
+
+    let created = quick_actions
+        .apply(&database, request(QuickAction::CreateApplication, job_id))
+        .await
+        .unwrap();
+    let again = quick_actions
+        .apply(&database, request(QuickAction::CreateApplication, job_id))
+        .await
+        .unwrap();
+    assert_eq!(again.application_id, created.application_id);
+    assert!(again.batch_id.is_none());
+
+    quick_actions.undo_latest(&database).await.unwrap().unwrap();
+    let application = database
+        .application_tracker()
+        .find_application_id_by_job_hash("quick-open")
+        .await
+        .unwrap();
+    assert!(application.is_none());
+}
+
+#[tokio::test]
+async fn snoozed_reminder_moves_a_day_and_undo_restores_it() {
+    let (database, _) = database_with_job("quick-snooze").await;
+    let tracker = database.application_tracker();
+    let application_id = tracker.create_application("quick-snooze").await.unwrap();
+    let due = Utc::now() - Duration::hours(1);
+    tracker
+        .set_reminder(application_id, "follow_up", due, "Check in")
+        .await
+        .unwrap();
+    let reminder_id = tracker.get_pending_reminders().await.unwrap()[0].id;
+    let quick_actions = QuickActions::default();
+
+    let snooze = quick_actions
+        .apply(&database, request(QuickAction::SnoozeReminder, reminder_id))
+        .await
+        .unwrap();
+
+    assert!(snooze.snoozed_until.is_some());
+    assert!(tracker.get_pending_reminders().await.unwrap().is_empty());
+    quick_actions.undo_latest(&database).await.unwrap().unwrap();
+    let schedule = tracker
+        .get_reminder_schedule(reminder_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(schedule.reminder_time, due.to_rfc3339());
+}
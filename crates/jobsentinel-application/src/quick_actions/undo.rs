@@ -0,0 +1,137 @@
+//! Batched undo stack for quick actions
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use jobsentinel_storage::Database;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{database_error, QuickActionError};
+
+/// Oldest batches are dropped past this depth.
+const MAX_UNDO_BATCHES: usize = 50;
+
+/// How to reverse one quick action.
+#[derive(Debug, Clone)]
+pub(super) enum UndoStep {
+    SetBookmark {
+        job_id: i64,
+        bookmarked: bool,
+    },
+    Unhide {
+        job_id: i64,
+    },
+    DeleteApplication {
+        application_id: i64,
+    },
+    RescheduleReminder {
+        reminder_id: i64,
+        reminder_time: String,
+    },
+}
+
+#[derive(Debug)]
+struct UndoBatch {
+    id: String,
+    steps: Vec<UndoStep>,
+}
+
+/// Quick action runner holding the session's undo stack.
+#[derive(Clone, Default)]
+pub struct QuickActions {
+    batches: Arc<Mutex<VecDeque<UndoBatch>>>,
+}
+
+/// Result of undoing the latest batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuickActionUndo {
+    pub batch_id: String,
+    pub actions_reverted: usize,
+    pub batches_remaining: usize,
+}
+
+impl QuickActions {
+    /// Add a step to `batch_id` when it is the latest batch, otherwise start
+    /// a new batch. Returns the batch id.
+    pub(super) fn push(&self, batch_id: Option<String>, step: UndoStep) -> String {
+        let mut batches = self.lock_batches();
+        if let Some(latest) = batches
+            .back_mut()
+            .filter(|latest| batch_id.as_deref() == Some(latest.id.as_str()))
+        {
+            latest.steps.push(step);
+            return latest.id.clone();
+        }
+
+        while batches.len() >= MAX_UNDO_BATCHES {
+            batches.pop_front();
+        }
+        let id = batch_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        batches.push_back(UndoBatch {
+            id: id.clone(),
+            steps: vec![step],
+        });
+        id
+    }
+
+    /// Revert the most recent batch, newest action first.
+    ///
+    /// Returns `None` when there is nothing to undo. If a step fails, the
+    /// steps not yet reverted go back on the stack.
+    pub async fn undo_latest(
+        &self,
+        database: &Database,
+    ) -> Result<Option<QuickActionUndo>, QuickActionError> {
+        let Some(mut batch) = self.lock_batches().pop_back() else {
+            return Ok(None);
+        };
+
+        let mut actions_reverted = 0;
+        while let Some(step) = batch.steps.pop() {
+            if let Err(error) = revert(database, &step).await {
+                batch.steps.push(step);
+                self.lock_batches().push_back(batch);
+                return Err(error);
+            }
+            actions_reverted += 1;
+        }
+
+        Ok(Some(QuickActionUndo {
+            batch_id: batch.id,
+            actions_reverted,
+            batches_remaining: self.lock_batches().len(),
+        }))
+    }
+
+    fn lock_batches(&self) -> MutexGuard<'_, VecDeque<UndoBatch>> {
+        match self.batches.lock() {
+            Ok(batches) => batches,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+async fn revert(database: &Database, step: &UndoStep) -> Result<(), QuickActionError> {
+    match step {
+        UndoStep::SetBookmark { job_id, bookmarked } => database
+            .set_bookmark(*job_id, *bookmarked)
+            .await
+            .map_err(database_error),
+        UndoStep::Unhide { job_id } => database.unhide_job(*job_id).await.map_err(database_error),
+        UndoStep::DeleteApplication { application_id } => database
+            .application_tracker()
+            .delete_application(*application_id)
+            .await
+            .map_err(database_error),
+        UndoStep::RescheduleReminder {
+            reminder_id,
+            reminder_time,
+        } => database
+            .application_tracker()
+            .reschedule_reminder(*reminder_id, reminder_time)
+            .await
+            .map(|_| ())
+            .map_err(database_error),
+    }
+}
@@ -0,0 +1,115 @@
+//! Keeping currency conversion rates current
+//!
+//! Bundled rates ship with the app. When saved jobs list pay in another
+//! currency, rates are refreshed weekly from a public feed that needs no
+//! account, and saved salaries are re-converted to US dollars.
+
+use anyhow::{Context, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use jobsentinel_domain::ExchangeRates;
+use jobsentinel_network::fetch_external_https_text_with_user_agent;
+use jobsentinel_storage::Database;
+use std::time::Duration;
+
+/// Daily rates quoted per US dollar.
+const EXCHANGE_RATE_FEED_URL: &str = "https://open.er-api.com/v6/latest/USD";
+/// `exchange_rates.source` for rates from the feed.
+const EXCHANGE_RATE_SOURCE: &str = "open.er-api.com";
+/// How long refreshed rates are used before fetching again.
+const REFRESH_INTERVAL_DAYS: i64 = 7;
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Stored rates, or the bundled ones if they cannot be read.
+pub async fn load_exchange_rates(database: &Database) -> ExchangeRates {
+    database.exchange_rates().await.unwrap_or_else(|error| {
+        tracing::warn!(error = %error, "Failed to load exchange rates; using bundled rates");
+        ExchangeRates::bundled()
+    })
+}
+
+/// Refresh rates when saved jobs list non-USD pay and the last refresh is
+/// more than a week old. Returns whether new rates were saved.
+pub async fn refresh_exchange_rates_if_stale(database: &Database) -> Result<bool> {
+    if !database.has_non_usd_salaries().await? {
+        return Ok(false);
+    }
+    let stale_before = Utc::now() - ChronoDuration::days(REFRESH_INTERVAL_DAYS);
+    if database
+        .exchange_rates_refreshed_at()
+        .await?
+        .is_some_and(|refreshed_at| refreshed_at > stale_before)
+    {
+        return Ok(false);
+    }
+
+    let response =
+        fetch_external_https_text_with_user_agent(EXCHANGE_RATE_FEED_URL, FETCH_TIMEOUT, None)
+            .await
+            .map_err(|error| anyhow::anyhow!("Exchange rate fetch failed: {error:?}"))?;
+    anyhow::ensure!(
+        (200..300).contains(&response.status),
+        "Exchange rate feed returned HTTP {}",
+        response.status
+    );
+    let rates = parse_exchange_rate_feed(&response.body)?;
+    let saved = database
+        .save_exchange_rates(&rates, EXCHANGE_RATE_SOURCE, Utc::now())
+        .await?;
+    tracing::info!(saved, "Refreshed exchange rates");
+    Ok(true)
+}
+
+/// Turn the feed's units-per-dollar quotes into `(code, usd_per_unit)` pairs.
+fn parse_exchange_rate_feed(body: &str) -> Result<Vec<(String, f64)>> {
+    let feed: serde_json::Value =
+        serde_json::from_str(body).context("Exchange rate feed is not JSON")?;
+    anyhow::ensure!(
+        feed["result"].as_str() == Some("success"),
+        "Exchange rate feed reported an error"
+    );
+    let quotes = feed["rates"]
+        .as_object()
+        .context("Exchange rate feed has no rates")?;
+
+    Ok(quotes
+        .iter()
+        .filter_map(|(code, per_dollar)| {
+            let per_dollar = per_dollar.as_f64()?;
+            (per_dollar.is_finite() && per_dollar > 0.0).then(|| (code.clone(), 1.0 / per_dollar))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feed_quotes_are_inverted_to_dollars_per_unit() {
+        let rates = parse_exchange_rate_feed(
+            r#"{"result": "success", "rates": {"USD": 1, "EUR": 0.8, "BAD": 0, "TXT": "x"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(rates.len(), 2);
+        assert!(rates.contains(&("EUR".to_string(), 1.25)));
+    }
+
+    #[test]
+    fn feed_errors_are_rejected() {
+        assert!(parse_exchange_rate_feed(r#"{"result": "error"}"#).is_err());
+        assert!(parse_exchange_rate_feed("<html>").is_err());
+    }
+
+    #[tokio::test]
+    async fn rates_are_not_fetched_without_foreign_salaries() {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+
+        assert!(!refresh_exchange_rates_if_stale(&database).await.unwrap());
+        assert_eq!(
+            load_exchange_rates(&database).await.usd_per_unit("EUR"),
+            ExchangeRates::bundled().usd_per_unit("EUR")
+        );
+    }
+}
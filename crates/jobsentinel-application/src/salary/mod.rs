@@ -1,3 +1,9 @@
 //! SQL-backed salary-analysis facade.
 
+mod exchange_rates;
+mod pay_period;
+
+pub use exchange_rates::{load_exchange_rates, refresh_exchange_rates_if_stale};
+pub use jobsentinel_domain::normalize_currency_code;
 pub use jobsentinel_storage::salary::*;
+pub(crate) use pay_period::detect_pay_period;
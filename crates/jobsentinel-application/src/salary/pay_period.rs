@@ -0,0 +1,77 @@
+//! Pay period detection for scraped postings.
+
+use jobsentinel_domain::{Job, PayPeriod, PostedPayRate};
+
+/// Fill in how often the job's pay is quoted, before it is scored and saved.
+///
+/// Rates written in the title or description ("$45-55/hr", "€450 per day")
+/// fill a missing salary. When the source already gave amounts, a detected
+/// period is kept only if it fits them, since many sources annualize before
+/// the job reaches us. Small amounts with no stated period are hourly.
+pub(crate) fn detect_pay_period(job: &mut Job) {
+    if job.pay_period.is_some() {
+        return;
+    }
+
+    let posted_rate = PostedPayRate::find(&job.title)
+        .or_else(|| job.description.as_deref().and_then(PostedPayRate::find));
+    let posted_amount = job.salary_max.or(job.salary_min);
+
+    match (posted_amount, posted_rate) {
+        (None, Some(rate)) if rate.period.is_plausible(rate.min) => {
+            job.salary_min = Some(rate.min);
+            job.salary_max = rate.max;
+            job.pay_period = Some(rate.period);
+            if job.currency.is_none() {
+                job.currency = rate.currency;
+            }
+        }
+        (Some(amount), Some(rate)) if rate.period.is_plausible(amount) => {
+            job.pay_period = Some(rate.period);
+        }
+        (Some(amount), _) => {
+            job.pay_period = Some(PayPeriod::inferred_from_amount(amount));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_job;
+
+    #[test]
+    fn rates_in_the_description_fill_a_missing_salary() {
+        let mut job = test_job("contract-hash", "Contract Editor", "Example Press");
+        job.description = Some("Pay: £40 - £50 per hour, 6 month contract".to_string());
+
+        detect_pay_period(&mut job);
+
+        assert_eq!(job.salary_min, Some(40));
+        assert_eq!(job.salary_max, Some(50));
+        assert_eq!(job.pay_period, Some(PayPeriod::Hour));
+        assert_eq!(job.currency.as_deref(), Some("GBP"));
+    }
+
+    #[test]
+    fn detected_periods_must_fit_source_amounts() {
+        let mut annualized = test_job("annual-hash", "Nurse", "Example Health");
+        annualized.salary_min = Some(83_200);
+        annualized.description = Some("Paid $40/hr with overtime".to_string());
+        detect_pay_period(&mut annualized);
+        assert_eq!(annualized.pay_period, Some(PayPeriod::Year));
+
+        let mut daily = test_job("daily-hash", "Site Lead", "Example Builders");
+        daily.salary_min = Some(400);
+        daily.salary_max = Some(450);
+        daily.description = Some("Day rate $400-450 per day".to_string());
+        detect_pay_period(&mut daily);
+        assert_eq!(daily.pay_period, Some(PayPeriod::Day));
+
+        let mut unstated = test_job("hourly-hash", "Tutor", "Example Learning");
+        unstated.salary_min = Some(35);
+        detect_pay_period(&mut unstated);
+        assert_eq!(unstated.pay_period, Some(PayPeriod::Hour));
+    }
+}
@@ -0,0 +1,91 @@
+//! Manual control of background scraping
+//!
+//! Scheduled scraping can be paused and resumed, and the pause is saved so it
+//! outlasts a restart. A scrape started by hand runs whether or not scraping
+//! is paused.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::types::{Scheduler, ScrapingResult};
+use super::workers::enabled_sources;
+use crate::config::{Config, SCHEDULED_SOURCE_IDS};
+
+/// Errors from a scrape started by hand
+#[derive(Error, Debug)]
+pub enum ScrapeNowError {
+    #[error("Unknown job source: {0}")]
+    UnknownSource(String),
+
+    #[error("Job source is off or missing setup details: {0}")]
+    SourceNotEnabled(String),
+
+    #[error("Scrape failed: {0}")]
+    Failed(#[from] anyhow::Error),
+}
+
+/// The known, enabled sources named in `requested`, in the order given and
+/// without repeats.
+fn requested_sources(
+    config: &Config,
+    requested: &[String],
+) -> Result<Vec<&'static str>, ScrapeNowError> {
+    let enabled = enabled_sources(config);
+    let mut sources = Vec::with_capacity(requested.len());
+    for source_id in requested {
+        let known = SCHEDULED_SOURCE_IDS
+            .iter()
+            .copied()
+            .find(|known| known == source_id)
+            .ok_or_else(|| ScrapeNowError::UnknownSource(source_id.clone()))?;
+        if !enabled.contains(&known) {
+            return Err(ScrapeNowError::SourceNotEnabled(source_id.clone()));
+        }
+        if !sources.contains(&known) {
+            sources.push(known);
+        }
+    }
+    Ok(sources)
+}
+
+impl Scheduler {
+    /// When scheduled scraping was paused, or `None` while it is running.
+    pub async fn scraping_paused_at(&self) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.database.scraping_paused_at().await?)
+    }
+
+    /// Stop scheduled scraping until it is resumed. Returns when the pause
+    /// began; pausing again keeps the original time.
+    pub async fn pause_scraping(&self) -> Result<DateTime<Utc>> {
+        let paused_at = self.database.pause_scraping(Utc::now()).await?;
+        tracing::info!(%paused_at, "Scheduled scraping paused");
+        Ok(paused_at)
+    }
+
+    /// Let scheduled scraping run again. Returns whether it was paused.
+    pub async fn resume_scraping(&self) -> Result<bool> {
+        let was_paused = self.database.resume_scraping().await?;
+        if was_paused {
+            tracing::info!("Scheduled scraping resumed");
+        }
+        Ok(was_paused)
+    }
+
+    /// Scrape `sources` right away, or every enabled source when the list is
+    /// empty. Runs even while scheduled scraping is paused.
+    ///
+    /// # Errors
+    ///
+    /// Fails before scraping if a source is unknown or turned off.
+    pub async fn run_scrape_now(
+        &self,
+        sources: &[String],
+    ) -> Result<ScrapingResult, ScrapeNowError> {
+        if sources.is_empty() {
+            return Ok(self.run_cycle(None).await?);
+        }
+        let sources = requested_sources(&*self.config.read().await, sources)?;
+        Ok(self.run_cycle(Some(&sources)).await?)
+    }
+}
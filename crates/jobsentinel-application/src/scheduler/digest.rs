@@ -0,0 +1,150 @@
+//! Scheduled email digests of new high-scoring jobs
+
+use anyhow::Result;
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, Local, NaiveDateTime, NaiveTime, Utc,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::types::Scheduler;
+use crate::config::{DigestFrequency, EmailDigestConfig};
+use crate::market_intelligence::{build_market_report, MarketReportPeriod};
+use crate::notify::{MarketReport, NotificationService};
+
+/// How often the background task checks whether a digest is due.
+pub const EMAIL_DIGEST_CHECK_INTERVAL: Duration = Duration::from_mins(15);
+
+/// Most jobs listed in one digest
+const EMAIL_DIGEST_MAX_JOBS: i64 = 25;
+
+/// How far back a digest looks for new jobs
+fn digest_lookback(frequency: DigestFrequency) -> ChronoDuration {
+    match frequency {
+        DigestFrequency::Daily => ChronoDuration::days(1),
+        DigestFrequency::Weekly => ChronoDuration::weeks(1),
+    }
+}
+
+/// The most recent send time at or before `now`, in local time. With a
+/// `weekday` (1 = Monday), only sends on that day of the week count.
+pub(super) fn latest_send_slot(
+    send_hour: u32,
+    weekday: Option<u32>,
+    now: NaiveDateTime,
+) -> NaiveDateTime {
+    let send_time = NaiveTime::from_hms_opt(send_hour.min(23), 0, 0).unwrap_or_default();
+    let mut slot = now.date().and_time(send_time);
+    if slot > now {
+        slot -= ChronoDuration::days(1);
+    }
+    if let Some(send_weekday) = weekday {
+        let weekday = slot.weekday().number_from_monday();
+        let days_back = (weekday + 7 - send_weekday.clamp(1, 7)) % 7;
+        slot -= ChronoDuration::days(i64::from(days_back));
+    }
+    slot
+}
+
+/// The most recent scheduled digest time at or before `now`, in local time.
+fn latest_digest_slot(digest: &EmailDigestConfig, now: NaiveDateTime) -> NaiveDateTime {
+    let weekday = (digest.frequency == DigestFrequency::Weekly).then_some(digest.send_weekday);
+    latest_send_slot(digest.send_hour, weekday, now)
+}
+
+/// Whether a digest should go out at `now`, given when the last one was
+/// sent. Both times are local.
+#[must_use]
+pub fn email_digest_due(
+    digest: &EmailDigestConfig,
+    last_sent: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+) -> bool {
+    digest.enabled && last_sent.is_none_or(|last_sent| last_sent < latest_digest_slot(digest, now))
+}
+
+impl Scheduler {
+    /// The market report for the past week, when weekly digests attach one
+    async fn digest_market_report(
+        &self,
+        digest: &EmailDigestConfig,
+        now: DateTime<Utc>,
+    ) -> Result<Option<MarketReport>> {
+        if digest.frequency != DigestFrequency::Weekly || !digest.attach_market_report {
+            return Ok(None);
+        }
+        build_market_report(&self.database, MarketReportPeriod::Week, now)
+            .await
+            .map(Some)
+    }
+
+    /// Send the email digest if its scheduled time has passed since the last
+    /// one. Returns the number of jobs sent, or `None` when no digest was due.
+    ///
+    /// A due digest with no new jobs sends no email but is still recorded, so
+    /// the next check waits for the following send time. Digests are held
+    /// while vacation mode is on. Weekly digests can attach a market report.
+    pub async fn send_email_digest_if_due(&self) -> Result<Option<usize>> {
+        let config = self.config.read().await.clone();
+        let digest = &config.alerts.email.digest;
+        let last_sent = self.database.last_email_digest_at().await?;
+        let now = Utc::now();
+        if !email_digest_due(
+            digest,
+            last_sent.map(|sent_at| sent_at.with_timezone(&Local).naive_local()),
+            now.with_timezone(&Local).naive_local(),
+        ) {
+            return Ok(None);
+        }
+        if self.database.get_vacation_mode().await?.enabled {
+            tracing::info!("Vacation mode is on; holding the email digest");
+            return Ok(None);
+        }
+
+        let jobs = self
+            .database
+            .jobs_for_email_digest(
+                now - digest_lookback(digest.frequency),
+                digest.min_score,
+                EMAIL_DIGEST_MAX_JOBS,
+            )
+            .await?;
+        if !jobs.is_empty() {
+            let market_report = self.digest_market_report(digest, now).await?;
+            let config = Arc::new(config.clone());
+            NotificationService::with_credentials(config, Arc::clone(&self.credentials))
+                .send_email_digest(&jobs, market_report.as_ref())
+                .await?;
+            let job_ids: Vec<i64> = jobs.iter().map(|job| job.id).collect();
+            self.database.mark_included_in_digest(&job_ids).await?;
+        }
+        self.database
+            .record_email_digest(now, jobs.len() as i64)
+            .await?;
+        Ok(Some(jobs.len()))
+    }
+
+    /// Send a digest of the current top new jobs right away to check the
+    /// email settings. The jobs stay eligible for the next scheduled digest.
+    pub async fn send_test_email_digest(&self) -> Result<usize> {
+        let config = self.config.read().await.clone();
+        let digest = &config.alerts.email.digest;
+        let now = Utc::now();
+        let jobs = self
+            .database
+            .jobs_for_email_digest(
+                now - digest_lookback(digest.frequency),
+                digest.min_score,
+                EMAIL_DIGEST_MAX_JOBS,
+            )
+            .await?;
+        let market_report = self.digest_market_report(digest, now).await?;
+        NotificationService::with_credentials(
+            Arc::new(config.clone()),
+            Arc::clone(&self.credentials),
+        )
+        .send_email_digest(&jobs, market_report.as_ref())
+        .await?;
+        Ok(jobs.len())
+    }
+}
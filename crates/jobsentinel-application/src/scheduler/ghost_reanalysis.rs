@@ -0,0 +1,188 @@
+//! Re-analysis of saved ghost scores, in the background after a detector
+//! upgrade or on demand after ghost settings change
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+use jobsentinel_intelligence::{ghost_detector_version, GhostBucket, GhostDetector};
+use jobsentinel_storage::{Database, GhostReanalysisRun};
+use serde::Serialize;
+
+use super::types::Scheduler;
+use super::workers::apply_ghost_analysis;
+use crate::config::Config;
+
+/// Jobs loaded per re-analysis batch.
+const REANALYSIS_BATCH_SIZE: i64 = 200;
+
+/// Progress of a ghost analysis batch run, reported after each saved batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct GhostBatchProgress {
+    /// Jobs analyzed and saved so far
+    pub jobs_analyzed: usize,
+    /// Saved jobs when the run started
+    pub jobs_total: usize,
+}
+
+impl Scheduler {
+    /// Re-analyze saved postings scored by an older ghost detector.
+    ///
+    /// Holds the scrape lock so a scraping cycle never races the rewrite.
+    /// Returns `None` when every saved score is already current.
+    pub async fn reanalyze_outdated_ghost_scores(&self) -> Result<Option<GhostReanalysisRun>> {
+        let _scrape_guard = self.scrape_lock.lock().await;
+        let config = self.config.read().await.clone();
+        reanalyze_outdated_ghost_scores(&config, &self.database).await
+    }
+
+    /// Re-analyze every saved posting with the ghost settings and patterns
+    /// in use now, calling `on_progress` after each saved batch.
+    ///
+    /// Holds the scrape lock so a scraping cycle never races the rewrite.
+    pub async fn analyze_all_ghost_scores(
+        &self,
+        on_progress: impl Fn(GhostBatchProgress) + Send,
+    ) -> Result<GhostReanalysisRun> {
+        let _scrape_guard = self.scrape_lock.lock().await;
+        let config = self.config.read().await.clone();
+        analyze_all_ghost_scores(&config, &self.database, on_progress).await
+    }
+}
+
+async fn reanalyze_outdated_ghost_scores(
+    config: &Config,
+    database: &Database,
+) -> Result<Option<GhostReanalysisRun>> {
+    let detector = GhostDetector::new(config.ghost_config.clone().unwrap_or_default());
+    let detector_version = ghost_detector_version();
+    let started_at = Utc::now();
+    let mut run = GhostReanalysisRun {
+        detector_version,
+        ..GhostReanalysisRun::default()
+    };
+
+    loop {
+        let jobs = database
+            .jobs_with_outdated_ghost_analysis(detector_version, REANALYSIS_BATCH_SIZE)
+            .await?;
+        if jobs.is_empty() {
+            break;
+        }
+
+        for job in jobs {
+            reanalyze_job(job, &detector, database, &mut run).await?;
+        }
+    }
+
+    if run.jobs_reanalyzed == 0 {
+        return Ok(None);
+    }
+
+    finish_run(&mut run, started_at, database).await?;
+    tracing::info!(
+        detector_version = run.detector_version,
+        jobs_reanalyzed = run.jobs_reanalyzed,
+        moved_to_clear = run.moved_to_clear,
+        moved_to_warning = run.moved_to_warning,
+        moved_to_hidden = run.moved_to_hidden,
+        "Ghost scores re-analyzed with the current detector"
+    );
+    Ok(Some(run))
+}
+
+async fn analyze_all_ghost_scores(
+    config: &Config,
+    database: &Database,
+    on_progress: impl Fn(GhostBatchProgress),
+) -> Result<GhostReanalysisRun> {
+    let detector = GhostDetector::new(config.ghost_config.clone().unwrap_or_default());
+    let started_at = Utc::now();
+    let mut run = GhostReanalysisRun {
+        detector_version: ghost_detector_version(),
+        ..GhostReanalysisRun::default()
+    };
+    let mut progress = GhostBatchProgress {
+        jobs_analyzed: 0,
+        jobs_total: usize::try_from(database.count_jobs().await?).unwrap_or_default(),
+    };
+    on_progress(progress);
+
+    let mut after_id = 0;
+    loop {
+        let jobs = database
+            .jobs_after_id(after_id, REANALYSIS_BATCH_SIZE)
+            .await?;
+        let Some(last) = jobs.last() else {
+            break;
+        };
+        after_id = last.id;
+
+        let batch_size = jobs.len();
+        for job in jobs {
+            reanalyze_job(job, &detector, database, &mut run).await?;
+        }
+
+        progress.jobs_analyzed += batch_size;
+        // Jobs saved while the run was underway can push past the first count.
+        progress.jobs_total = progress.jobs_total.max(progress.jobs_analyzed);
+        on_progress(progress);
+    }
+
+    if run.jobs_reanalyzed > 0 {
+        finish_run(&mut run, started_at, database).await?;
+    }
+    tracing::info!(
+        jobs_reanalyzed = run.jobs_reanalyzed,
+        moved_to_clear = run.moved_to_clear,
+        moved_to_warning = run.moved_to_warning,
+        moved_to_hidden = run.moved_to_hidden,
+        "Saved ghost scores re-analyzed with current settings"
+    );
+    Ok(run)
+}
+
+/// Re-analyze one saved job, save the new score, and count any bucket move
+async fn reanalyze_job(
+    mut job: Job,
+    detector: &GhostDetector,
+    database: &Database,
+    run: &mut GhostReanalysisRun,
+) -> Result<()> {
+    let previous = job.ghost_score.map(|score| detector.bucket(score));
+    apply_ghost_analysis(&mut job, detector, database).await;
+    let score = job.ghost_score.unwrap_or_default();
+    database
+        .save_reanalyzed_ghost_score(
+            job.id,
+            score,
+            job.ghost_reasons.as_deref(),
+            run.detector_version,
+        )
+        .await?;
+
+    run.jobs_reanalyzed += 1;
+    let current = detector.bucket(score);
+    if previous.is_some_and(|previous| previous != current) {
+        match current {
+            GhostBucket::Clear => run.moved_to_clear += 1,
+            GhostBucket::Warning => run.moved_to_warning += 1,
+            GhostBucket::Hidden => run.moved_to_hidden += 1,
+        }
+    }
+    Ok(())
+}
+
+async fn finish_run(
+    run: &mut GhostReanalysisRun,
+    started_at: DateTime<Utc>,
+    database: &Database,
+) -> Result<()> {
+    run.started_at = started_at.to_rfc3339();
+    run.finished_at = Utc::now().to_rfc3339();
+    database.record_ghost_reanalysis_run(run).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/ghost_reanalysis_tests.rs"]
+mod tests;
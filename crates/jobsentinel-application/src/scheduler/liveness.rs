@@ -0,0 +1,143 @@
+//! Periodic liveness check of saved postings
+//!
+//! Re-fetches the pages of active jobs, the least recently checked first, and
+//! marks postings the source reports gone as closed. Jobs no scrape has seen
+//! for a long time are marked expired. A closed posting behind a tracked
+//! application is also marked on the application.
+
+use anyhow::Result;
+use chrono::Utc;
+use jobsentinel_domain::{Job, JobStatus};
+use jobsentinel_storage::Database;
+use serde::Serialize;
+use std::time::Duration;
+use tokio::time;
+
+use super::types::Scheduler;
+use crate::{ats::ClosedPostingApplication, fetcher::fetch_job_page, ImportError};
+
+/// How often the background liveness check runs.
+pub const LIVENESS_CHECK_INTERVAL: Duration = Duration::from_hours(6);
+/// Job pages fetched per check, to stay polite to job boards.
+const LIVENESS_BATCH_SIZE: i64 = 40;
+/// A job page is checked again this long after its last check.
+const RECHECK_AFTER_DAYS: i64 = 3;
+/// Active jobs no scrape has seen for this long are marked expired.
+const EXPIRE_AFTER_DAYS: i64 = 60;
+/// Pause between page fetches.
+const FETCH_DELAY: Duration = Duration::from_secs(2);
+
+/// Outcome of one liveness check
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LivenessCheck {
+    pub jobs_checked: usize,
+    pub jobs_closed: usize,
+    pub jobs_expired: u64,
+    /// Tracked applications whose posting closed during this check
+    pub closed_applications: Vec<ClosedPostingApplication>,
+}
+
+/// What a page fetch says about a posting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Liveness {
+    Live,
+    /// The posting is gone, with the stored reason
+    Closed(&'static str),
+    /// Network errors, throttling, and server errors say nothing either way
+    Unknown,
+}
+
+impl Scheduler {
+    /// Re-fetch the pages of active jobs due for a check and record which
+    /// postings have closed, and expire jobs no scrape has seen for a long
+    /// time.
+    pub async fn check_job_liveness(&self) -> Result<LivenessCheck> {
+        check_job_liveness(&self.database).await
+    }
+}
+
+async fn check_job_liveness(database: &Database) -> Result<LivenessCheck> {
+    let now = Utc::now();
+    let mut check = LivenessCheck {
+        jobs_expired: database
+            .expire_unseen_jobs(now - chrono::Duration::days(EXPIRE_AFTER_DAYS))
+            .await?,
+        ..LivenessCheck::default()
+    };
+
+    let jobs = database
+        .jobs_due_for_liveness_check(
+            now - chrono::Duration::days(RECHECK_AFTER_DAYS),
+            LIVENESS_BATCH_SIZE,
+        )
+        .await?;
+    for (index, job) in jobs.iter().enumerate() {
+        if index > 0 {
+            time::sleep(FETCH_DELAY).await;
+        }
+        let liveness = liveness_of(&fetch_job_page(&job.url).await);
+        record_liveness(database, job, liveness, &mut check).await?;
+    }
+
+    tracing::info!(
+        jobs_checked = check.jobs_checked,
+        jobs_closed = check.jobs_closed,
+        jobs_expired = check.jobs_expired,
+        "Job liveness check complete"
+    );
+    Ok(check)
+}
+
+fn liveness_of(fetched: &Result<String, ImportError>) -> Liveness {
+    match fetched {
+        Ok(_) => Liveness::Live,
+        Err(ImportError::HttpStatus(404)) => Liveness::Closed("http_404"),
+        Err(ImportError::HttpStatus(410)) => Liveness::Closed("http_410"),
+        // Boards send closed postings to a search or careers page.
+        Err(ImportError::RedirectBlocked { .. }) => Liveness::Closed("redirected"),
+        Err(_) => Liveness::Unknown,
+    }
+}
+
+async fn record_liveness(
+    database: &Database,
+    job: &Job,
+    liveness: Liveness,
+    check: &mut LivenessCheck,
+) -> Result<()> {
+    check.jobs_checked += 1;
+    let Liveness::Closed(reason) = liveness else {
+        // Unknown results still count as checked so other jobs get a turn.
+        database
+            .set_job_status(job.id, JobStatus::Active, None)
+            .await?;
+        if liveness == Liveness::Live {
+            if let Err(error) = database
+                .application_tracker()
+                .record_posting_reopened(&job.hash)
+                .await
+            {
+                tracing::warn!(job_id = job.id, error = %error, "Failed to clear closed posting mark");
+            }
+        }
+        return Ok(());
+    };
+
+    database
+        .set_job_status(job.id, JobStatus::Closed, Some(reason))
+        .await?;
+    check.jobs_closed += 1;
+    tracing::info!(job_id = job.id, reason, "Posting closed");
+    if let Some(application) = database
+        .application_tracker()
+        .record_posting_closed(&job.hash, reason)
+        .await?
+    {
+        check.closed_applications.push(application);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/liveness_tests.rs"]
+mod tests;
@@ -0,0 +1,98 @@
+//! Scheduled daily market analysis
+//!
+//! Once a day the saved jobs are summarised into a market snapshot, skill,
+//! company, and location trends are recomputed, and new market alerts are
+//! raised. Snapshots are dated in UTC, so "once a day" follows the UTC date.
+//!
+//! New alerts are sent once through the enabled email, Slack, and Discord
+//! channels when their type is turned on in the notification preferences.
+//! Nothing is sent during quiet hours or while vacation mode is on; the next
+//! check sends what was held.
+
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use jobsentinel_storage::market_intelligence::MarketSnapshot;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::outbox::load_quiet_hours;
+use super::types::Scheduler;
+use crate::notify::NotificationService;
+
+/// How often the background task checks whether today's analysis has run.
+pub const MARKET_ANALYSIS_CHECK_INTERVAL: Duration = Duration::from_hours(1);
+
+/// Most market alerts sent in one pass
+const MARKET_ALERT_BATCH_SIZE: i64 = 20;
+
+/// Whether the daily analysis should run, given the date of the latest
+/// snapshot and today's UTC date.
+#[must_use]
+pub fn market_analysis_due(latest_snapshot: Option<NaiveDate>, today: NaiveDate) -> bool {
+    latest_snapshot.is_none_or(|latest| latest < today)
+}
+
+impl Scheduler {
+    /// Run the daily market analysis unless today's snapshot already exists,
+    /// for example because the user ran the analysis by hand.
+    pub async fn run_market_analysis_if_due(&self) -> Result<Option<MarketSnapshot>> {
+        let intel = self.database.market_intelligence();
+        let latest = intel.get_market_snapshot().await?;
+        if !market_analysis_due(
+            latest.map(|snapshot| snapshot.date),
+            Utc::now().date_naive(),
+        ) {
+            return Ok(None);
+        }
+
+        intel.run_daily_analysis().await.map(Some)
+    }
+    /// Send new market alerts that the notification preferences allow and
+    /// mark every new alert as handled. Returns the number sent.
+    ///
+    /// Alerts whose type is turned off, or that are below its minimum
+    /// severity, are marked too, so turning a type on later does not send old
+    /// alerts.
+    pub async fn deliver_market_alerts(&self) -> Result<usize> {
+        let intel = self.database.market_intelligence();
+        let pending = intel.get_unnotified_alerts(MARKET_ALERT_BATCH_SIZE).await?;
+        if pending.is_empty() {
+            return Ok(0);
+        }
+        if self.database.get_vacation_mode().await?.enabled {
+            tracing::info!("Vacation mode is on; holding market alerts");
+            return Ok(0);
+        }
+        if let Some(until) = load_quiet_hours(&self.database)
+            .await
+            .and_then(|quiet_hours| quiet_hours.deferred_until(Utc::now()))
+        {
+            tracing::info!(%until, "Quiet hours; holding market alerts");
+            return Ok(0);
+        }
+
+        let preferences = self
+            .database
+            .user_data_manager()
+            .get_notification_preferences()
+            .await?;
+        let config = Arc::new(self.config.read().await.clone());
+        let service = NotificationService::with_credentials(config, Arc::clone(&self.credentials));
+        let mut sent = 0;
+        for alert in &pending {
+            if preferences.market_alerts.allows(alert) {
+                let channels = service.send_market_alert(alert).await;
+                tracing::info!(
+                    alert_id = alert.id,
+                    alert_type = alert.alert_type.as_str(),
+                    ?channels,
+                    "Delivered market alert"
+                );
+                sent += 1;
+            }
+            intel.mark_alert_notified(alert.id, Utc::now()).await?;
+        }
+
+        Ok(sent)
+    }
+}
@@ -8,12 +8,50 @@ use std::time::Duration;
 use tokio::{sync::RwLock, time};
 
 // Module declarations
+mod control;
+mod digest;
+mod ghost_reanalysis;
+mod liveness;
+mod market_analysis;
+mod outbox;
 mod pipeline;
+mod power_saving;
+mod refresh;
+mod reminders;
+mod rescoring;
+mod retention;
+mod search_agents;
+mod source_backoff;
+mod source_check;
 mod types;
+mod weekly_report;
 mod workers;
 
+/// Scrape events buffered per receiver before the oldest are dropped.
+const SCRAPE_EVENT_CAPACITY: usize = 128;
+
 // Re-exports
-pub use types::{ScheduleConfig, Scheduler, ScrapingResult};
+pub use control::ScrapeNowError;
+pub use digest::{email_digest_due, EMAIL_DIGEST_CHECK_INTERVAL};
+pub use ghost_reanalysis::GhostBatchProgress;
+pub use liveness::{LivenessCheck, LIVENESS_CHECK_INTERVAL};
+pub use market_analysis::{market_analysis_due, MARKET_ANALYSIS_CHECK_INTERVAL};
+pub use outbox::{notification_retry_delay, OutboxDelivery, NOTIFICATION_OUTBOX_INTERVAL};
+pub use power_saving::{scrape_plan, ScheduledScrape, ScrapePlan, POWER_SAVING_RECHECK_INTERVAL};
+pub use refresh::{refresh_job, JobRefresh, JobRefreshError};
+pub use reminders::{reminder_alert_message, reminder_alert_subject, REMINDER_CHECK_INTERVAL};
+pub use rescoring::RescoreProgress;
+pub use retention::RETENTION_PURGE_INTERVAL;
+pub use search_agents::search_agent_due;
+pub use source_backoff::{
+    source_backoff, source_failure_alert_message, source_retry_at, SOURCE_FAILURE_ALERT_THRESHOLD,
+};
+pub use source_check::{check_source_now, SourceCheck, SourceCheckError};
+pub use types::{
+    ScheduleConfig, Scheduler, ScrapeCounts, ScrapeErrorEvent, ScrapeEvent, ScrapeProgress,
+    ScrapingResult, SourceFailureAlert, SourceScrapeSummary, SourceThrottleEvent,
+};
+pub use weekly_report::{weekly_report_due, WeeklyReportRun, WEEKLY_REPORT_CHECK_INTERVAL};
 
 impl Scheduler {
     pub fn new(
@@ -40,12 +78,15 @@ impl Scheduler {
         credentials: Arc<crate::credentials::CredentialService>,
     ) -> Self {
         let (shutdown_tx, _) = tokio::sync::broadcast::channel(1);
+        let (events_tx, _) = tokio::sync::broadcast::channel(SCRAPE_EVENT_CAPACITY);
         Self {
             config,
             database,
             credentials,
             shutdown_tx,
             scrape_lock: Arc::new(tokio::sync::Mutex::new(())),
+            source_overflow: Arc::default(),
+            events_tx,
         }
     }
 
@@ -56,6 +97,14 @@ impl Scheduler {
         self.shutdown_tx.subscribe()
     }
 
+    /// Get a receiver for live progress, per-source results, and errors
+    /// during scraping cycles
+    ///
+    /// Slow receivers skip older updates rather than delaying the cycle.
+    pub fn subscribe_scrape_events(&self) -> tokio::sync::broadcast::Receiver<ScrapeEvent> {
+        self.events_tx.subscribe()
+    }
+
     /// Shutdown the scheduler gracefully
     pub fn shutdown(&self) -> Result<()> {
         tracing::info!("Shutting down scheduler");
@@ -94,14 +143,57 @@ impl Scheduler {
                 }
             }
 
+            let vacation_holds_scraping = self
+                .database
+                .get_vacation_mode()
+                .await
+                .is_ok_and(|vacation| vacation.enabled && !vacation.keep_scraping);
+            if vacation_holds_scraping {
+                tracing::info!(
+                    "Scheduler: vacation mode paused scraping; waiting before rechecking"
+                );
+
+                tokio::select! {
+                    _ = time::sleep(Duration::from_mins(1)) => {
+                        continue;
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Scheduler received shutdown signal, stopping gracefully");
+                        break;
+                    }
+                }
+            }
+
+            if self
+                .scraping_paused_at()
+                .await
+                .is_ok_and(|paused_at| paused_at.is_some())
+            {
+                tracing::info!("Scheduler: scraping paused; waiting before rechecking");
+
+                tokio::select! {
+                    _ = time::sleep(Duration::from_mins(1)) => {
+                        continue;
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Scheduler received shutdown signal, stopping gracefully");
+                        break;
+                    }
+                }
+            }
+
             let interval = Duration::from_secs(schedule.interval_hours.saturating_mul(3600));
 
             tracing::info!("Scheduler: Running job scraping cycle");
 
+            let mut skipped = false;
             tokio::select! {
-                result = self.run_scraping_cycle() => {
+                result = self.run_scheduled_cycle() => {
                     match result {
-                        Ok(result) => {
+                        Ok(ScheduledScrape { result: None, .. }) => {
+                            skipped = true;
+                        }
+                        Ok(ScheduledScrape { result: Some(result), .. }) => {
                             tracing::info!(
                                 "Scraping cycle complete: {} jobs found, {} new, {} high matches, {} alerts sent",
                                 result.jobs_found,
@@ -129,7 +221,13 @@ impl Scheduler {
             }
 
             // Wait for next run or shutdown signal
-            tracing::info!("Next scraping cycle in {} hours", schedule.interval_hours);
+            let interval = if skipped {
+                tracing::info!("Rechecking power and network conditions in 15 minutes");
+                POWER_SAVING_RECHECK_INTERVAL
+            } else {
+                tracing::info!("Next scraping cycle in {} hours", schedule.interval_hours);
+                interval
+            };
 
             tokio::select! {
                 _ = time::sleep(interval) => {
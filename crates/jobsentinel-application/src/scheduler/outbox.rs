@@ -0,0 +1,268 @@
+//! Notification outbox delivery with retries
+//!
+//! Immediate alerts are queued in the outbox and sent from here. A failed
+//! alert is retried with exponential backoff until it has been tried
+//! `MAX_DELIVERY_ATTEMPTS` times; failures that cannot improve, such as a
+//! channel with no saved credential, are given up on at once. Alerts that
+//! come due during quiet hours are deferred to the end of the window, and the
+//! first pass after that sends them.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use jobsentinel_storage::{database_error_kind, Database, PendingNotification};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::types::Scheduler;
+use crate::config::Config;
+use crate::credentials::CredentialService;
+use crate::notify::{
+    AlertDeferred, AlertDeliveryError, Notification, NotificationService, QuietHours,
+};
+use crate::scoring::JobScore;
+use crate::user_data::NotificationMessageTemplates;
+
+/// How often the background task looks for alerts due to be sent or retried.
+pub const NOTIFICATION_OUTBOX_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Attempts made before an alert is marked failed
+const MAX_DELIVERY_ATTEMPTS: i64 = 5;
+
+/// Most alerts sent in one pass
+const OUTBOX_BATCH_SIZE: i64 = 50;
+
+/// How long a claimed alert stays hidden from other workers
+const DELIVERY_LEASE_MINUTES: i64 = 5;
+
+/// Alerts sent, failed, and deferred in one delivery pass
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutboxDelivery {
+    pub sent: usize,
+    /// Failed attempts, whether or not the alert will be retried
+    pub failed: usize,
+    /// Alerts held until quiet hours end
+    pub deferred: usize,
+}
+
+/// Wait after the `attempts`-th failed attempt: 1, 2, 4, then 8 minutes
+#[must_use]
+pub fn notification_retry_delay(attempts: i64) -> ChronoDuration {
+    ChronoDuration::minutes(1 << (attempts - 1).clamp(0, 10))
+}
+
+/// Queue an immediate alert for `job_hash`.
+pub(crate) async fn enqueue_alert(
+    database: &Database,
+    job_hash: &str,
+    score: &JobScore,
+) -> Result<i64> {
+    let score_json = serde_json::to_string(score)?;
+    Ok(database
+        .enqueue_notification(job_hash, score.total, &score_json, Utc::now())
+        .await?)
+}
+
+/// Match the active resume against `job_hash` and save the result, so its
+/// alert can show the resume match. Does nothing without an active resume.
+pub(crate) async fn match_active_resume(database: &Database, job_hash: &str) {
+    let matcher = database.resume_matcher();
+    let resume = match matcher.get_active_resume().await {
+        Ok(Some(resume)) => resume,
+        Ok(None) => return,
+        Err(_e) => {
+            tracing::warn!(
+                error_kind = "database",
+                "Failed to read the active resume; alert will not show a resume match"
+            );
+            return;
+        }
+    };
+    if let Err(_e) = matcher.match_resume_to_job(resume.id, job_hash).await {
+        tracing::warn!(
+            job_hash,
+            error_kind = "database",
+            "Failed to match the active resume; alert will not show a resume match"
+        );
+    }
+}
+
+/// The active resume's saved match for `job_hash`, if there is one
+async fn saved_resume_match(database: &Database, job_hash: &str) -> Option<f64> {
+    let matcher = database.resume_matcher();
+    let resume = matcher.get_active_resume().await.ok().flatten()?;
+    matcher
+        .get_match_result(resume.id, job_hash)
+        .await
+        .ok()
+        .flatten()
+        .map(|match_result| match_result.overall_match_score)
+}
+
+/// Send every alert that is due, recording each outcome in the outbox.
+pub(crate) async fn deliver_due_notifications(
+    config: &Arc<Config>,
+    database: &Database,
+    credentials: &Arc<CredentialService>,
+) -> Result<OutboxDelivery> {
+    let now = Utc::now();
+    let due = database.due_notifications(now, OUTBOX_BATCH_SIZE).await?;
+    let mut delivery = OutboxDelivery::default();
+    if due.is_empty() {
+        return Ok(delivery);
+    }
+
+    let service =
+        NotificationService::with_credentials(Arc::clone(config), Arc::clone(credentials))
+            .with_quiet_hours(load_quiet_hours(database).await)
+            .with_message_templates(load_message_templates(database).await);
+    for entry in due {
+        let lease_until = now + ChronoDuration::minutes(DELIVERY_LEASE_MINUTES);
+        if !database
+            .claim_notification(entry.id, now, lease_until)
+            .await?
+        {
+            continue;
+        }
+
+        match deliver_notification(&service, config, database, &entry).await {
+            Ok(()) => {
+                database
+                    .mark_notification_sent(entry.id, Utc::now())
+                    .await?;
+                delivery.sent += 1;
+            }
+            Err(DeliveryFailure::Deferred(until)) => {
+                database.defer_notification(entry.id, until).await?;
+                delivery.deferred += 1;
+            }
+            Err(DeliveryFailure::Failed { reason, retryable }) => {
+                let attempts = entry.attempts + 1;
+                let retry_at = (retryable && attempts < MAX_DELIVERY_ATTEMPTS)
+                    .then(|| Utc::now() + notification_retry_delay(attempts));
+                tracing::warn!(
+                    outbox_id = entry.id,
+                    job_hash = %entry.job_hash,
+                    attempts,
+                    will_retry = retry_at.is_some(),
+                    "Notification delivery failed"
+                );
+                database
+                    .mark_notification_failed(entry.id, &reason, retry_at)
+                    .await?;
+                delivery.failed += 1;
+            }
+        }
+    }
+
+    Ok(delivery)
+}
+
+/// Saved quiet hours, or `None` when they are off or cannot be read
+pub(super) async fn load_quiet_hours(database: &Database) -> Option<QuietHours> {
+    match database
+        .user_data_manager()
+        .get_notification_preferences()
+        .await
+    {
+        Ok(preferences) => QuietHours::from_settings(&preferences.global),
+        Err(e) => {
+            tracing::warn!(
+                error_kind = database_error_kind(&e),
+                "Failed to read quiet hours; sending alerts normally"
+            );
+            None
+        }
+    }
+}
+
+/// Saved message templates, or the default layouts when they cannot be read
+async fn load_message_templates(database: &Database) -> NotificationMessageTemplates {
+    database
+        .user_data_manager()
+        .get_message_templates()
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                error_kind = database_error_kind(&e),
+                "Failed to read message templates; using default messages"
+            );
+            NotificationMessageTemplates::default()
+        })
+}
+
+enum DeliveryFailure {
+    /// Held for quiet hours until the given time
+    Deferred(DateTime<Utc>),
+    Failed {
+        reason: String,
+        retryable: bool,
+    },
+}
+
+impl DeliveryFailure {
+    fn permanent(reason: impl Into<String>) -> Self {
+        Self::Failed {
+            reason: reason.into(),
+            retryable: false,
+        }
+    }
+}
+
+async fn deliver_notification(
+    service: &NotificationService,
+    config: &Config,
+    database: &Database,
+    entry: &PendingNotification,
+) -> Result<(), DeliveryFailure> {
+    let job = match database.get_job_by_hash(&entry.job_hash).await {
+        Ok(Some(job)) => job,
+        Ok(None) => return Err(DeliveryFailure::permanent("Job is no longer saved")),
+        Err(e) => {
+            return Err(DeliveryFailure::Failed {
+                reason: format!(
+                    "Database error while loading the job ({})",
+                    database_error_kind(&e)
+                ),
+                retryable: true,
+            })
+        }
+    };
+    let score: JobScore = serde_json::from_str(&entry.score_json)
+        .map_err(|_| DeliveryFailure::permanent("Saved match score could not be read"))?;
+    let resume_match = if config.alerts.include_resume_match {
+        saved_resume_match(database, &job.hash).await
+    } else {
+        None
+    };
+
+    service
+        .send_immediate_alert(&Notification {
+            job,
+            score,
+            resume_match,
+        })
+        .await
+        .map_err(|error| {
+            if let Some(deferred) = error.downcast_ref::<AlertDeferred>() {
+                return DeliveryFailure::Deferred(deferred.until);
+            }
+            match error.downcast_ref::<AlertDeliveryError>() {
+                Some(delivery_error) => DeliveryFailure::Failed {
+                    reason: delivery_error.to_string(),
+                    retryable: delivery_error.is_retryable(),
+                },
+                None => DeliveryFailure::Failed {
+                    reason: "Notification delivery error".to_string(),
+                    retryable: true,
+                },
+            }
+        })
+}
+
+impl Scheduler {
+    /// Send alerts that are due in the outbox, including retries.
+    pub async fn deliver_pending_notifications(&self) -> Result<OutboxDelivery> {
+        let config = Arc::new(self.config.read().await.clone());
+        deliver_due_notifications(&config, &self.database, &self.credentials).await
+    }
+}
@@ -2,88 +2,275 @@
 
 use anyhow::Result;
 use std::sync::Arc;
+use tokio::sync::mpsc;
 
-use super::types::{Scheduler, ScrapingResult};
-use super::workers::{persist_and_notify, run_scrapers, score_jobs};
+use super::types::{
+    Scheduler, ScrapeCounts, ScrapeErrorEvent, ScrapeEvent, ScrapeProgress, ScrapingResult,
+    SourceScrapeSummary,
+};
+use super::workers::{enabled_sources, persist_and_notify, run_scrapers, score_jobs, SourceBatch};
+use crate::health::{
+    finish_scrape_run, record_scrape_run_maintenance, record_scrape_run_source, start_scrape_run,
+    ScrapeRunTotals,
+};
+use crate::salary::refresh_exchange_rates_if_stale;
+
+/// Totals accumulated as source batches are saved.
+#[derive(Debug, Default)]
+struct CycleTotals {
+    /// Scrape history row, if it could be created
+    run_id: Option<i64>,
+    pending_sources: Vec<&'static str>,
+    sources_completed: usize,
+    sources_total: usize,
+    counts: ScrapeCounts,
+    high_matches: usize,
+    alerts_sent: usize,
+    errors: Vec<String>,
+}
+
+impl CycleTotals {
+    fn progress(&self) -> ScrapeEvent {
+        ScrapeEvent::Progress(ScrapeProgress {
+            sources_completed: self.sources_completed,
+            sources_total: self.sources_total,
+            pending_sources: self
+                .pending_sources
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            counts: self.counts,
+        })
+    }
+}
 
 impl Scheduler {
     /// Run a single scraping cycle
     ///
     /// This is the main pipeline:
-    /// 1. Run all scrapers (Greenhouse, Lever, JobsWithGPT)
-    /// 2. Score each job
-    /// 3. Store in database (with deduplication)
-    /// 4. Send notifications for high-scoring jobs
+    /// 1. Run enabled scrapers concurrently (bounded by `scraping_concurrency`)
+    /// 2. As each source finishes, score its jobs and run ghost detection
+    /// 3. Store them in the database (with deduplication)
+    /// 4. Send notifications for high-scoring jobs and report progress
+    /// 5. Link new jobs that repeat a role already saved from another source
     #[tracing::instrument(skip(self), level = "info")]
     pub async fn run_scraping_cycle(&self) -> Result<ScrapingResult> {
+        self.run_cycle(None).await
+    }
+
+    /// Run a scraping cycle over every enabled source, or only the enabled
+    /// sources in `only`.
+    pub(super) async fn run_cycle(&self, only: Option<&[&'static str]>) -> Result<ScrapingResult> {
         use std::time::Instant;
 
         let _scrape_guard = self.scrape_lock.lock().await;
         let cycle_start = Instant::now();
-        tracing::info!("Starting full scraping cycle");
+        if only.is_some() {
+            tracing::info!("Starting scraping cycle for selected sources");
+        } else {
+            tracing::info!("Starting full scraping cycle");
+        }
 
         let config = {
             let config = self.config.read().await;
             Arc::new(config.clone())
         };
+        if let Err(e) = refresh_exchange_rates_if_stale(&self.database).await {
+            tracing::warn!(error = %e, "Failed to refresh exchange rates; using saved rates");
+        }
+        let mut sources = enabled_sources(&config);
+        if let Some(only) = only {
+            sources.retain(|source_id| only.contains(source_id));
+        }
+        let run_id = start_scrape_run(&self.database, sources.len())
+            .await
+            .inspect_err(|e| tracing::warn!(error = %e, "Failed to record scrape run start"))
+            .ok();
+        let mut totals = CycleTotals {
+            run_id,
+            sources_total: sources.len(),
+            pending_sources: sources.clone(),
+            ..CycleTotals::default()
+        };
+        self.emit(totals.progress());
 
-        // 1. Run all scrapers
-        let stage1_start = Instant::now();
-        tracing::info!("Pipeline stage 1/3: Running scrapers");
-        let (all_jobs, mut errors) = run_scrapers(&config, &self.database, &self.credentials).await;
-        let stage1_duration = stage1_start.elapsed();
-        tracing::info!(
-            job_count = all_jobs.len(),
-            elapsed_ms = stage1_duration.as_millis(),
-            "Stage 1 complete: Scrapers finished"
-        );
-
-        // 2. Score all jobs and run ghost detection
-        let stage2_start = Instant::now();
-        tracing::info!("Pipeline stage 2/3: Scoring jobs and detecting ghost postings");
-        let scored_jobs = score_jobs(all_jobs, &config, &self.database).await;
-        let stage2_duration = stage2_start.elapsed();
-        tracing::info!(
-            job_count = scored_jobs.len(),
-            elapsed_ms = stage2_duration.as_millis(),
-            "Stage 2 complete: Scoring and ghost detection finished"
+        // Scrapers keep running while finished sources are scored and saved.
+        let (batch_tx, mut batch_rx) = mpsc::channel::<SourceBatch>(totals.sources_total.max(1));
+        let scrape = run_scrapers(
+            &config,
+            &self.database,
+            &self.credentials,
+            &sources,
+            Arc::clone(&self.source_overflow),
+            batch_tx,
         );
+        let persist = async {
+            while let Some(batch) = batch_rx.recv().await {
+                self.save_batch(batch, &config, &mut totals).await;
+            }
+        };
+        let (scraped, ()) = tokio::join!(scrape, persist);
 
-        // 3. Store in database and send notifications
-        let stage3_start = Instant::now();
-        tracing::info!("Pipeline stage 3/3: Persisting jobs and sending notifications");
-        let stats =
-            persist_and_notify(&scored_jobs, &config, &self.database, &self.credentials).await;
-        let stage3_duration = stage3_start.elapsed();
-        tracing::info!(
-            elapsed_ms = stage3_duration.as_millis(),
-            "Stage 3 complete: Persistence and notifications finished"
-        );
+        if totals.counts.jobs_new > 0 {
+            match self.database.link_cross_source_duplicates().await {
+                Ok(linked) => tracing::debug!(linked, "Cross-source duplicate pass complete"),
+                Err(e) => tracing::warn!(error = %e, "Failed to link cross-source duplicates"),
+            }
+        }
+        match self.deliver_search_agent_alerts().await {
+            Ok(sent) => tracing::debug!(sent, "Job agent pass complete"),
+            Err(e) => tracing::warn!(error = %e, "Failed to send job agent alerts"),
+        }
 
-        // Combine errors from all stages
-        errors.extend(stats.errors);
+        // Cycle-level errors (e.g. LinkedIn notice) come before per-source ones
+        for message in &scraped.errors {
+            self.emit(ScrapeEvent::Error(ScrapeErrorEvent {
+                source: None,
+                message: message.clone(),
+                counts: ScrapeCounts::default(),
+            }));
+        }
+        if let Some(run_id) = totals.run_id {
+            let run_totals = ScrapeRunTotals {
+                jobs_found: totals.counts.jobs_found,
+                jobs_new: totals.counts.jobs_new,
+                jobs_updated: totals.counts.jobs_updated,
+                high_matches: totals.high_matches,
+                alerts_sent: totals.alerts_sent,
+            };
+            if let Err(e) =
+                finish_scrape_run(&self.database, run_id, &run_totals, &scraped.errors).await
+            {
+                tracing::warn!(error = %e, "Failed to record scrape run result");
+            }
+        }
+        if let Err(e) = self.database.refresh_company_reputation().await {
+            tracing::warn!(error = %e, "Failed to refresh company reputation");
+        }
+        self.maintain_database(totals.run_id).await;
+        let mut errors = scraped.errors;
+        errors.append(&mut totals.errors);
 
-        let total_duration = cycle_start.elapsed();
         tracing::info!(
-            jobs_new = stats.jobs_new,
-            jobs_updated = stats.jobs_updated,
-            high_matches = stats.high_matches,
-            alerts_sent = stats.alerts_sent,
+            sources_completed = totals.sources_completed,
+            sources_total = totals.sources_total,
+            jobs_found = totals.counts.jobs_found,
+            jobs_new = totals.counts.jobs_new,
+            jobs_updated = totals.counts.jobs_updated,
+            high_matches = totals.high_matches,
+            alerts_sent = totals.alerts_sent,
             error_count = errors.len(),
-            total_elapsed_ms = total_duration.as_millis(),
-            stage1_ms = stage1_duration.as_millis(),
-            stage2_ms = stage2_duration.as_millis(),
-            stage3_ms = stage3_duration.as_millis(),
+            throttle_event_count = scraped.throttle_events.len(),
+            total_elapsed_ms = cycle_start.elapsed().as_millis(),
             "Scraping cycle complete"
         );
 
         Ok(ScrapingResult {
+            jobs_found: totals.counts.jobs_found,
+            jobs_new: totals.counts.jobs_new,
+            jobs_updated: totals.counts.jobs_updated,
+            high_matches: totals.high_matches,
+            alerts_sent: totals.alerts_sent,
+            errors,
+            throttle_events: scraped.throttle_events,
+        })
+    }
+
+    /// Run database maintenance after the cycle's writes, and note it on the
+    /// cycle's history entry when a threshold was hit.
+    async fn maintain_database(&self, run_id: Option<i64>) {
+        match self.database.run_maintenance().await {
+            Ok(maintenance) if !maintenance.steps.is_empty() => {
+                let Some(run_id) = run_id else { return };
+                if let Err(e) =
+                    record_scrape_run_maintenance(&self.database, run_id, &maintenance).await
+                {
+                    tracing::warn!(error = %e, "Failed to record database maintenance");
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(error = %e, "Database maintenance failed"),
+        }
+    }
+
+    /// Score, store, and notify for one finished source, then report it.
+    async fn save_batch(
+        &self,
+        batch: SourceBatch,
+        config: &Arc<crate::config::Config>,
+        totals: &mut CycleTotals,
+    ) {
+        let source = batch.source_id;
+        // A source that returned jobs is working, even if one of its
+        // scraper runs failed.
+        let failure = batch.failure.filter(|_| batch.jobs.is_empty());
+        let scored_jobs = score_jobs(batch.jobs, config, &self.database).await;
+        let stats =
+            persist_and_notify(&scored_jobs, config, &self.database, &self.credentials).await;
+        let counts = ScrapeCounts {
             jobs_found: scored_jobs.len(),
             jobs_new: stats.jobs_new,
             jobs_updated: stats.jobs_updated,
-            high_matches: stats.high_matches,
-            alerts_sent: stats.alerts_sent,
-            errors,
-        })
+        };
+
+        totals.pending_sources.retain(|pending| *pending != source);
+        totals.sources_completed += 1;
+        totals.counts.jobs_found += counts.jobs_found;
+        totals.counts.jobs_new += counts.jobs_new;
+        totals.counts.jobs_updated += counts.jobs_updated;
+        totals.high_matches += stats.high_matches;
+        totals.alerts_sent += stats.alerts_sent;
+
+        tracing::info!(
+            source,
+            jobs_found = counts.jobs_found,
+            jobs_new = counts.jobs_new,
+            sources_completed = totals.sources_completed,
+            sources_total = totals.sources_total,
+            "Source results saved"
+        );
+
+        let errors: Vec<String> = batch.errors.into_iter().chain(stats.errors).collect();
+        if let Some(run_id) = totals.run_id {
+            if let Err(e) = record_scrape_run_source(
+                &self.database,
+                run_id,
+                source,
+                counts.jobs_found,
+                counts.jobs_new,
+                counts.jobs_updated,
+                &errors,
+            )
+            .await
+            {
+                tracing::warn!(source, error = %e, "Failed to record source results");
+            }
+        }
+        if let Err(e) = self
+            .record_source_outcome(source, failure.as_ref(), config)
+            .await
+        {
+            tracing::warn!(source, error = %e, "Failed to record source failures");
+        }
+        for message in errors {
+            self.emit(ScrapeEvent::Error(ScrapeErrorEvent {
+                source: Some(source.to_string()),
+                message: message.clone(),
+                counts,
+            }));
+            totals.errors.push(message);
+        }
+        self.emit(ScrapeEvent::SourceComplete(SourceScrapeSummary {
+            source: source.to_string(),
+            sources_completed: totals.sources_completed,
+            sources_total: totals.sources_total,
+            counts,
+        }));
+        self.emit(totals.progress());
+    }
+
+    fn emit(&self, event: ScrapeEvent) {
+        // No subscribers is normal (e.g. headless runs and tests).
+        let _ = self.events_tx.send(event);
     }
 }
@@ -0,0 +1,110 @@
+//! Scheduled scrapes on a low battery or a metered connection
+//!
+//! Before each scheduled scrape the scheduler reads the battery and network
+//! state and, following `Config::power_saving`, checks every enabled source,
+//! only the highest priority ones, or none. Sources still backing off after
+//! repeated failures are left out either way. Scrapes started by hand are not
+//! affected.
+
+use anyhow::Result;
+use chrono::Utc;
+use jobsentinel_platform::{current_power_status, PowerStatus};
+use std::time::Duration;
+
+use super::types::{Scheduler, ScrapingResult};
+use crate::config::{PowerSavingAction, PowerSavingConfig};
+
+/// How soon conditions are read again after a skipped scrape.
+pub const POWER_SAVING_RECHECK_INTERVAL: Duration = Duration::from_mins(15);
+
+/// How a scheduled scrape goes ahead
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrapePlan {
+    /// Check every enabled source
+    Full,
+    /// Check only the `max_sources` highest priority enabled sources
+    Reduced { max_sources: usize, reason: String },
+    /// Check nothing this time
+    Skipped { reason: String },
+}
+
+/// What a scheduled scrape did
+#[derive(Debug, Clone)]
+pub struct ScheduledScrape {
+    /// Why the scrape was skipped or cut down, for the scraping status
+    pub skip_reason: Option<String>,
+    /// `None` when the scrape was skipped
+    pub result: Option<ScrapingResult>,
+}
+
+/// Decide how a scheduled scrape goes ahead under `status`. When both a low
+/// battery and a metered connection apply, the stricter action wins.
+#[must_use]
+pub fn scrape_plan(config: &PowerSavingConfig, status: &PowerStatus) -> ScrapePlan {
+    let mut limits = Vec::with_capacity(2);
+    if let Some(percent) = status
+        .battery_percent
+        .filter(|percent| status.on_battery && *percent <= config.battery_threshold_percent)
+    {
+        limits.push((config.low_battery, format!("on battery at {percent}%")));
+    }
+    if status.metered_connection {
+        limits.push((
+            config.metered_connection,
+            "on a metered connection".to_string(),
+        ));
+    }
+
+    match limits.into_iter().max_by_key(|(action, _)| *action) {
+        Some((PowerSavingAction::Skip, reason)) => ScrapePlan::Skipped { reason },
+        Some((PowerSavingAction::Reduce, reason)) => ScrapePlan::Reduced {
+            max_sources: config.reduced_source_count,
+            reason,
+        },
+        Some((PowerSavingAction::Run, _)) | None => ScrapePlan::Full,
+    }
+}
+
+impl Scheduler {
+    /// Run a scheduled scrape, cut down or skipped when the battery is low or
+    /// the connection is metered.
+    pub async fn run_scheduled_cycle(&self) -> Result<ScheduledScrape> {
+        let config = self.config.read().await.clone();
+        match scrape_plan(&config.power_saving, &current_power_status().await) {
+            ScrapePlan::Full => {
+                let sources = self.sources_due(&config, Utc::now()).await?;
+                Ok(ScheduledScrape {
+                    skip_reason: None,
+                    result: Some(self.run_cycle(Some(&sources)).await?),
+                })
+            }
+            ScrapePlan::Reduced {
+                max_sources,
+                reason,
+            } => {
+                let mut sources = config
+                    .source_limits
+                    .ordered(&self.sources_due(&config, Utc::now()).await?);
+                sources.truncate(max_sources);
+                tracing::info!(
+                    source_count = sources.len(),
+                    reason = %reason,
+                    "Scheduled scrape reduced to save power or data"
+                );
+                Ok(ScheduledScrape {
+                    skip_reason: Some(format!(
+                        "Checked only the top {max_sources} sources: {reason}"
+                    )),
+                    result: Some(self.run_cycle(Some(&sources)).await?),
+                })
+            }
+            ScrapePlan::Skipped { reason } => {
+                tracing::info!(reason = %reason, "Scheduled scrape skipped to save power or data");
+                Ok(ScheduledScrape {
+                    skip_reason: Some(format!("Skipped: {reason}")),
+                    result: None,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! On-demand refresh of a single saved posting
+//!
+//! Re-fetches one job page outside the scrape schedule, merges what the page
+//! shows now into the saved job, and recomputes score, ghost analysis, and
+//! extracted skills before saving the change diff.
+
+use super::workers::{apply_ghost_analysis, serialize_score_reasons};
+use crate::{
+    ats::ClosedPostingApplication,
+    config::Config,
+    fetcher::fetch_job_page,
+    geocoding::job_coordinates,
+    salary::load_exchange_rates,
+    scoring::{invalidate_job, load_keyword_weights, ScoringEngine},
+    ImportError,
+};
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::{Job, JobStatus, PayPeriod};
+use jobsentinel_intelligence::{ghost_detector_version, GhostDetector};
+use jobsentinel_sources::{parse_single_job_page, JobPageParseError, ParsedJobPage};
+use jobsentinel_storage::{Database, JobFieldChange};
+use serde::Serialize;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors from refreshing a single posting
+#[derive(Error, Debug)]
+pub enum JobRefreshError {
+    #[error("Job not found")]
+    NotFound,
+
+    /// The source reports the posting gone. A tracked application is
+    /// marked closed the first time, and returned for alerting.
+    #[error("Posting is no longer available (HTTP {status})")]
+    PostingRemoved {
+        status: u16,
+        closed_application: Option<ClosedPostingApplication>,
+    },
+
+    #[error("Posting fetch failed: {0}")]
+    Fetch(#[from] ImportError),
+
+    #[error("Database operation failed")]
+    Database(String),
+}
+
+/// The refreshed job and what changed on it
+#[derive(Debug, Clone, Serialize)]
+pub struct JobRefresh {
+    pub job: Job,
+    pub changes: Vec<JobFieldChange>,
+    pub refreshed_at: DateTime<Utc>,
+}
+
+/// Re-fetch one saved posting from its source right now.
+pub async fn refresh_job(
+    config: &Arc<Config>,
+    database: &Arc<Database>,
+    job_id: i64,
+) -> Result<JobRefresh, JobRefreshError> {
+    let job = load_job(database, job_id).await?;
+    let html = match fetch_job_page(&job.url).await {
+        Ok(html) => html,
+        Err(ImportError::HttpStatus(status @ (404 | 410))) => {
+            let reason = format!("http_{status}");
+            if let Err(error) = database
+                .set_job_status(job.id, JobStatus::Closed, Some(&reason))
+                .await
+            {
+                tracing::warn!(job_id = job.id, error = %error, "Failed to mark job closed");
+            }
+            return Err(JobRefreshError::PostingRemoved {
+                status,
+                closed_application: mark_application_posting_closed(database, &job.hash).await,
+            });
+        }
+        Err(other) => return Err(JobRefreshError::Fetch(other)),
+    };
+
+    refresh_job_from_html(config, database, job, &html).await
+}
+
+/// Annotate the job's application, if any; failures only skip the alert.
+async fn mark_application_posting_closed(
+    database: &Database,
+    job_hash: &str,
+) -> Option<ClosedPostingApplication> {
+    database
+        .application_tracker()
+        .record_posting_closed(job_hash, "posting_removed")
+        .await
+        .unwrap_or_else(|error| {
+            tracing::warn!(error = %error, "Failed to mark application posting closed");
+            None
+        })
+}
+
+async fn load_job(database: &Database, job_id: i64) -> Result<Job, JobRefreshError> {
+    database
+        .get_job_by_id(job_id)
+        .await
+        .map_err(database_error)?
+        .ok_or(JobRefreshError::NotFound)
+}
+
+async fn refresh_job_from_html(
+    config: &Arc<Config>,
+    database: &Arc<Database>,
+    previous: Job,
+    html: &str,
+) -> Result<JobRefresh, JobRefreshError> {
+    let parsed = parse_single_job_page(html).map_err(map_parse_error)?;
+    let mut job = merge_parsed_page(&previous, &parsed);
+
+    invalidate_job(&job.hash).await;
+    let scoring_engine = ScoringEngine::with_database(Arc::clone(config), Arc::clone(database))
+        .with_exchange_rates(load_exchange_rates(database).await)
+        .with_keyword_weights(load_keyword_weights(database).await)
+        .with_job_coordinates(
+            job_coordinates(config, database, std::slice::from_ref(&job), 1).await,
+        );
+    let score = if config.use_resume_matching {
+        scoring_engine.score_async(&job).await
+    } else {
+        scoring_engine.score(&job)
+    };
+    job.score = Some(score.total);
+    job.score_reasons = Some(serialize_score_reasons(&job.hash, &score.reasons));
+
+    let ghost_detector = GhostDetector::new(config.ghost_config.clone().unwrap_or_default());
+    apply_ghost_analysis(&mut job, &ghost_detector, database).await;
+
+    let refreshed_at = Utc::now();
+    job.updated_at = refreshed_at;
+    job.last_seen = refreshed_at;
+    let changes = diff_jobs(&previous, &job);
+    database
+        .apply_job_refresh(&job, &changes, refreshed_at)
+        .await
+        .map_err(database_error)?;
+
+    if let Err(error) = database
+        .set_ghost_detector_version(&job.hash, ghost_detector_version())
+        .await
+    {
+        tracing::debug!(job_id = job.id, error = %error, "Failed to record ghost detector version");
+    }
+    if let Err(error) = database
+        .application_tracker()
+        .record_posting_reopened(&job.hash)
+        .await
+    {
+        tracing::warn!(job_id = job.id, error = %error, "Failed to clear closed posting mark");
+    }
+
+    tracing::info!(
+        job_id = job.id,
+        change_count = changes.len(),
+        "Job posting refreshed"
+    );
+
+    Ok(JobRefresh {
+        job: load_job(database, job.id).await?,
+        changes,
+        refreshed_at,
+    })
+}
+
+/// Overlay the fields a job page reports onto the saved job.
+///
+/// Fields missing from the page keep their saved values so a sparse page
+/// never erases data the original source provided.
+fn merge_parsed_page(previous: &Job, parsed: &ParsedJobPage) -> Job {
+    let mut job = previous.clone();
+    if !parsed.title.is_empty() {
+        job.title.clone_from(&parsed.title);
+    }
+    if !parsed.company.is_empty() {
+        job.company.clone_from(&parsed.company);
+    }
+    if parsed.location.is_some() {
+        job.location.clone_from(&parsed.location);
+    }
+    if parsed.description.is_some() {
+        job.description.clone_from(&parsed.description);
+    }
+    if parsed.salary_min.is_some() || parsed.salary_max.is_some() {
+        job.salary_min = parsed.salary_min;
+        job.salary_max = parsed.salary_max;
+        job.currency.clone_from(&parsed.currency);
+        // Job pages report salaries already annualized.
+        job.pay_period = Some(PayPeriod::Year);
+    }
+    if parsed.remote {
+        job.remote = Some(true);
+    }
+    job
+}
+
+fn diff_jobs(before: &Job, after: &Job) -> Vec<JobFieldChange> {
+    let fields = [
+        (
+            "title",
+            Some(before.title.clone()),
+            Some(after.title.clone()),
+        ),
+        (
+            "company",
+            Some(before.company.clone()),
+            Some(after.company.clone()),
+        ),
+        ("location", before.location.clone(), after.location.clone()),
+        (
+            "salary_min",
+            before.salary_min.map(|v| v.to_string()),
+            after.salary_min.map(|v| v.to_string()),
+        ),
+        (
+            "salary_max",
+            before.salary_max.map(|v| v.to_string()),
+            after.salary_max.map(|v| v.to_string()),
+        ),
+        ("currency", before.currency.clone(), after.currency.clone()),
+        (
+            "remote",
+            before.remote.map(|v| v.to_string()),
+            after.remote.map(|v| v.to_string()),
+        ),
+        (
+            "score",
+            before.score.map(score_text),
+            after.score.map(score_text),
+        ),
+        (
+            "ghost_score",
+            before.ghost_score.map(score_text),
+            after.ghost_score.map(score_text),
+        ),
+    ];
+
+    let mut changes: Vec<JobFieldChange> = fields
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(field, before, after)| JobFieldChange {
+            field: field.to_string(),
+            before,
+            after,
+        })
+        .collect();
+    if before.description != after.description {
+        changes.push(JobFieldChange {
+            field: "description".to_string(),
+            before: description_summary(before),
+            after: description_summary(after),
+        });
+    }
+    changes
+}
+
+/// Descriptions can be long, so the diff records their size rather than text
+fn description_summary(job: &Job) -> Option<String> {
+    job.description
+        .as_ref()
+        .map(|description| format!("{} characters", description.chars().count()))
+}
+
+fn score_text(score: f64) -> String {
+    format!("{score:.2}")
+}
+
+fn map_parse_error(error: JobPageParseError) -> JobRefreshError {
+    JobRefreshError::Fetch(match error {
+        JobPageParseError::NoSchemaOrgData => ImportError::NoSchemaOrgData,
+        JobPageParseError::MultipleJobPostings(count) => ImportError::MultipleJobPostings(count),
+        JobPageParseError::HtmlParseError(details) => ImportError::HtmlParseError(details),
+    })
+}
+
+fn database_error(error: impl ToString) -> JobRefreshError {
+    JobRefreshError::Database(error.to_string())
+}
+
+#[cfg(test)]
+#[path = "tests/refresh_tests.rs"]
+mod tests;
@@ -0,0 +1,102 @@
+//! Due application reminder delivery
+//!
+//! Reminders that have come due are sent once through the enabled email,
+//! Slack, and Discord channels and marked delivered. Nothing is sent during
+//! quiet hours or while vacation mode is on; the first pass after that sends
+//! what came due in the meantime. Alerts name the job and company but leave
+//! out the reminder's private note.
+
+use anyhow::Result;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::outbox::load_quiet_hours;
+use super::types::Scheduler;
+use crate::ats::PendingReminder;
+use crate::notify::NotificationService;
+
+/// How often the background task looks for reminders that have come due.
+pub const REMINDER_CHECK_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Most reminders sent in one pass
+const REMINDER_BATCH_SIZE: i64 = 20;
+
+fn reminder_label(reminder_type: &str) -> &'static str {
+    match reminder_type {
+        "follow_up" => "Follow-up",
+        "interview_prep" => "Interview prep",
+        _ => "Application",
+    }
+}
+
+/// Alert subject, such as "JobSentinel reminder: Case Manager at CommunityCare"
+#[must_use]
+pub fn reminder_alert_subject(reminder: &PendingReminder) -> String {
+    format!(
+        "JobSentinel reminder: {} at {}",
+        reminder.job_title, reminder.company
+    )
+}
+
+/// Alert text, such as "Follow-up reminder for Case Manager at CommunityCare
+/// is due. Open JobSentinel to see your note."
+#[must_use]
+pub fn reminder_alert_message(reminder: &PendingReminder) -> String {
+    format!(
+        "{} reminder for {} at {} is due. Open JobSentinel to see your note.",
+        reminder_label(&reminder.reminder_type),
+        reminder.job_title,
+        reminder.company
+    )
+}
+
+impl Scheduler {
+    /// Send reminders that have come due and mark them delivered.
+    ///
+    /// Returns the reminders delivered in this pass, so the app can show a
+    /// desktop notification for each.
+    pub async fn deliver_due_reminders(&self) -> Result<Vec<PendingReminder>> {
+        let tracker = self.database.application_tracker();
+        let now = Utc::now();
+        let due = tracker
+            .get_undelivered_reminders(now, REMINDER_BATCH_SIZE)
+            .await?;
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.database.get_vacation_mode().await?.enabled {
+            tracing::info!("Vacation mode is on; holding due reminders");
+            return Ok(Vec::new());
+        }
+        if let Some(until) = load_quiet_hours(&self.database)
+            .await
+            .and_then(|quiet_hours| quiet_hours.deferred_until(now))
+        {
+            tracing::info!(%until, "Quiet hours; holding due reminders");
+            return Ok(Vec::new());
+        }
+
+        let config = Arc::new(self.config.read().await.clone());
+        let service = NotificationService::with_credentials(config, Arc::clone(&self.credentials));
+        for reminder in &due {
+            let channels = service
+                .send_reminder_alert(
+                    &reminder_alert_subject(reminder),
+                    &reminder_alert_message(reminder),
+                )
+                .await;
+            tracing::info!(
+                reminder_id = reminder.id,
+                application_id = reminder.application_id,
+                ?channels,
+                "Delivered due reminder"
+            );
+            tracker
+                .mark_reminder_delivered(reminder.id, Utc::now())
+                .await?;
+        }
+
+        Ok(due)
+    }
+}
@@ -0,0 +1,103 @@
+//! Re-scoring saved jobs after scoring weights or search preferences change
+
+use anyhow::Result;
+use jobsentinel_storage::{Database, RescoredJob};
+use serde::Serialize;
+use std::sync::Arc;
+
+use super::types::Scheduler;
+use super::workers::serialize_score_reasons;
+use crate::config::Config;
+use crate::geocoding::job_coordinates;
+use crate::salary::load_exchange_rates;
+use crate::scoring::{clear_score_cache, load_keyword_weights, ScoringEngine};
+
+/// Jobs loaded and saved per re-scoring batch.
+const RESCORE_BATCH_SIZE: i64 = 200;
+
+/// Progress of a re-scoring run, reported after each saved batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RescoreProgress {
+    /// Jobs re-scored and saved so far
+    pub jobs_rescored: usize,
+    /// Saved jobs when the run started
+    pub jobs_total: usize,
+}
+
+impl Scheduler {
+    /// Re-score every saved job with the preferences and weights now in the
+    /// config, calling `on_progress` after each saved batch.
+    ///
+    /// Holds the scrape lock so a scraping cycle never races the rewrite.
+    pub async fn rescore_saved_jobs(
+        &self,
+        on_progress: impl Fn(RescoreProgress) + Send,
+    ) -> Result<RescoreProgress> {
+        let _scrape_guard = self.scrape_lock.lock().await;
+        let config = Arc::new(self.config.read().await.clone());
+        rescore_saved_jobs(&config, &self.database, on_progress).await
+    }
+}
+
+async fn rescore_saved_jobs(
+    config: &Arc<Config>,
+    database: &Arc<Database>,
+    on_progress: impl Fn(RescoreProgress),
+) -> Result<RescoreProgress> {
+    // Cached scores were computed with the previous settings.
+    clear_score_cache().await;
+    let exchange_rates = load_exchange_rates(database).await;
+    let keyword_weights = load_keyword_weights(database).await;
+
+    let mut progress = RescoreProgress {
+        jobs_rescored: 0,
+        jobs_total: usize::try_from(database.count_jobs().await?).unwrap_or_default(),
+    };
+    on_progress(progress);
+
+    let mut after_id = 0;
+    loop {
+        let jobs = database.jobs_after_id(after_id, RESCORE_BATCH_SIZE).await?;
+        let Some(last) = jobs.last() else {
+            break;
+        };
+        after_id = last.id;
+
+        // Commute distances use cached geocoding only; lookups happen while scraping.
+        let scoring_engine = ScoringEngine::with_database(Arc::clone(config), Arc::clone(database))
+            .with_exchange_rates(exchange_rates.clone())
+            .with_keyword_weights(keyword_weights.clone())
+            .with_job_coordinates(job_coordinates(config, database, &jobs, 0).await);
+
+        let mut batch = Vec::with_capacity(jobs.len());
+        for job in &jobs {
+            let score = if config.use_resume_matching {
+                scoring_engine.score_async(job).await
+            } else {
+                scoring_engine.score(job)
+            };
+            batch.push(RescoredJob {
+                job_id: job.id,
+                score: score.total,
+                score_reasons: serialize_score_reasons(&job.hash, &score.reasons),
+            });
+        }
+        database.save_rescored_jobs(&batch).await?;
+
+        progress.jobs_rescored += batch.len();
+        // Jobs saved while the run was underway can push past the first count.
+        progress.jobs_total = progress.jobs_total.max(progress.jobs_rescored);
+        on_progress(progress);
+    }
+
+    tracing::info!(
+        jobs_rescored = progress.jobs_rescored,
+        profile_active = config.scoring.active_profile.is_some(),
+        "Saved jobs re-scored with current settings"
+    );
+    Ok(progress)
+}
+
+#[cfg(test)]
+#[path = "tests/rescoring_tests.rs"]
+mod tests;
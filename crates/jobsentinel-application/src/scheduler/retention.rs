@@ -0,0 +1,28 @@
+//! Purging old jobs under the configured retention rules
+
+use anyhow::Result;
+use chrono::Utc;
+use jobsentinel_storage::PurgeReport;
+use std::time::Duration;
+
+use super::types::Scheduler;
+
+/// How often the background purge runs while retention is enabled.
+pub const RETENTION_PURGE_INTERVAL: Duration = Duration::from_hours(24);
+
+impl Scheduler {
+    /// Delete the jobs the retention rules select, or only report them when
+    /// `dry_run` is set.
+    ///
+    /// A real purge holds the scrape lock so a scraping cycle never sees a
+    /// job vanish mid-save.
+    pub async fn purge_jobs(&self, dry_run: bool) -> Result<PurgeReport> {
+        let _scrape_guard = if dry_run {
+            None
+        } else {
+            Some(self.scrape_lock.lock().await)
+        };
+        let policy = self.config.read().await.retention.policy(Utc::now());
+        Ok(self.database.purge_jobs(&policy, dry_run).await?)
+    }
+}
@@ -0,0 +1,105 @@
+//! Job agent alerts
+//!
+//! A job agent is a saved search marked to alert on new jobs. After each
+//! scrape cycle, every agent that is due runs its filter against the jobs
+//! saved since it last checked and sends what matched, best first. Daily
+//! agents check at most once a day, so one alert covers the day's jobs.
+//!
+//! Nothing is sent during quiet hours or while vacation mode is on; the jobs
+//! wait for the first check after that.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use jobsentinel_storage::{JobFilter, JobSort};
+use std::sync::Arc;
+
+use super::outbox::load_quiet_hours;
+use super::types::Scheduler;
+use crate::notify::NotificationService;
+use crate::user_data::{SearchAgentFrequency, SearchAgentSettings};
+
+/// Most new matches looked up for one agent alert
+const SEARCH_AGENT_MATCH_LIMIT: i64 = 50;
+
+fn last_checked(settings: &SearchAgentSettings) -> Option<DateTime<Utc>> {
+    settings
+        .last_checked_at
+        .as_deref()
+        .and_then(|checked| DateTime::parse_from_rfc3339(checked).ok())
+        .map(|checked| checked.with_timezone(&Utc))
+}
+
+/// Whether an agent should check for new jobs at `now`: after every scrape,
+/// or a day after its last check for daily agents.
+#[must_use]
+pub fn search_agent_due(settings: &SearchAgentSettings, now: DateTime<Utc>) -> bool {
+    match settings.frequency {
+        SearchAgentFrequency::EachScrape => true,
+        SearchAgentFrequency::Daily => {
+            last_checked(settings).is_none_or(|checked| now - checked >= Duration::days(1))
+        }
+    }
+}
+
+impl Scheduler {
+    /// Send each due job agent's new matches and move it past the jobs it
+    /// checked. Returns the number of alerts sent.
+    pub async fn deliver_search_agent_alerts(&self) -> Result<usize> {
+        let manager = self.database.user_data_manager();
+        let now = Utc::now();
+        let agents: Vec<_> = manager
+            .list_search_agents()
+            .await?
+            .into_iter()
+            .filter(|search| search_agent_due(&search.agent, now))
+            .collect();
+        if agents.is_empty() {
+            return Ok(0);
+        }
+        if self.database.get_vacation_mode().await?.enabled {
+            tracing::info!("Vacation mode is on; holding job agent alerts");
+            return Ok(0);
+        }
+        if let Some(until) = load_quiet_hours(&self.database)
+            .await
+            .and_then(|quiet_hours| quiet_hours.deferred_until(now))
+        {
+            tracing::info!(%until, "Quiet hours; holding job agent alerts");
+            return Ok(0);
+        }
+
+        let config = Arc::new(self.config.read().await.clone());
+        let service = NotificationService::with_credentials(config, Arc::clone(&self.credentials));
+        let checked_at = now.to_rfc3339();
+        let mut sent = 0;
+        for search in &agents {
+            // An agent without a start time begins from this check
+            if let Some(since) = last_checked(&search.agent) {
+                let filter = JobFilter {
+                    saved_after: Some(since),
+                    sort: JobSort::ScoreDesc,
+                    ..JobFilter::from_saved_search(search)
+                };
+                let jobs = self
+                    .database
+                    .query_jobs(&filter, SEARCH_AGENT_MATCH_LIMIT)
+                    .await?;
+                if !jobs.is_empty() {
+                    let channels = service.send_search_agent_alert(search, &jobs).await;
+                    tracing::info!(
+                        search_id = %search.id,
+                        matches = jobs.len(),
+                        ?channels,
+                        "Sent job agent alert"
+                    );
+                    sent += 1;
+                }
+            }
+            manager
+                .mark_search_agent_checked(&search.id, &checked_at)
+                .await?;
+        }
+
+        Ok(sent)
+    }
+}
@@ -0,0 +1,128 @@
+//! Backoff and alerts for failing sources
+//!
+//! Each failed check of a source is counted until one succeeds. Scheduled
+//! scrapes leave a failing source out for a while, waiting twice as long after
+//! each failure. When the count reaches `SOURCE_FAILURE_ALERT_THRESHOLD` the
+//! user is told once, so a broken source does not go unnoticed for days.
+//! Scrapes started by hand still check every source they ask for.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use jobsentinel_storage::SourceFailure;
+use std::sync::Arc;
+
+use super::types::{Scheduler, ScrapeEvent, SourceFailureAlert};
+use super::workers::{enabled_sources, SourceFailureReason};
+use crate::config::Config;
+use crate::notify::NotificationService;
+
+/// Failed checks in a row before the user is alerted
+pub const SOURCE_FAILURE_ALERT_THRESHOLD: i64 = 5;
+
+/// Wait after the `consecutive_failures`-th failure: 30 minutes, doubling
+/// each time, up to a day
+#[must_use]
+pub fn source_backoff(consecutive_failures: i64) -> ChronoDuration {
+    ChronoDuration::minutes(30 << (consecutive_failures - 1).clamp(0, 6))
+        .min(ChronoDuration::days(1))
+}
+
+/// When scheduled scrapes will check a failing source again
+#[must_use]
+pub fn source_retry_at(failure: &SourceFailure) -> DateTime<Utc> {
+    failure.last_failed_at + source_backoff(failure.consecutive_failures)
+}
+
+/// Alert text, such as "Greenhouse scraping has failed 5 times: 403 Forbidden"
+#[must_use]
+pub fn source_failure_alert_message(
+    source_label: &str,
+    consecutive_failures: i64,
+    reason: &str,
+) -> String {
+    format!("{source_label} scraping has failed {consecutive_failures} times: {reason}")
+}
+
+impl Scheduler {
+    /// Enabled sources a scheduled scrape should check at `now`, leaving out
+    /// failing sources that are still backing off.
+    pub(super) async fn sources_due(
+        &self,
+        config: &Config,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<&'static str>> {
+        let backing_off: Vec<String> = self
+            .database
+            .source_failures()
+            .await?
+            .into_iter()
+            .filter(|failure| source_retry_at(failure) > now)
+            .map(|failure| failure.source_id)
+            .collect();
+        let mut sources = enabled_sources(config);
+        sources.retain(|source_id| !backing_off.iter().any(|id| id == source_id));
+        if !backing_off.is_empty() {
+            tracing::info!(
+                backing_off = backing_off.len(),
+                "Leaving failing sources out of this scrape"
+            );
+        }
+        Ok(sources)
+    }
+
+    /// Count a failed check of `source_id`, or clear its failures when
+    /// `failure` is `None`. Alerts the user when the count reaches
+    /// `SOURCE_FAILURE_ALERT_THRESHOLD`.
+    pub(super) async fn record_source_outcome(
+        &self,
+        source_id: &'static str,
+        failure: Option<&SourceFailureReason>,
+        config: &Arc<Config>,
+    ) -> Result<()> {
+        let Some(failure) = failure else {
+            if self.database.clear_source_failures(source_id).await? {
+                tracing::info!(source = source_id, "Source is working again");
+            }
+            return Ok(());
+        };
+
+        let recorded = self
+            .database
+            .record_source_failure(source_id, &failure.reason, Utc::now())
+            .await?;
+        tracing::warn!(
+            source = source_id,
+            consecutive_failures = recorded.consecutive_failures,
+            retry_at = %source_retry_at(&recorded),
+            "Source check failed; backing off"
+        );
+        if recorded.consecutive_failures != SOURCE_FAILURE_ALERT_THRESHOLD {
+            return Ok(());
+        }
+
+        let message = source_failure_alert_message(
+            failure.source_label,
+            recorded.consecutive_failures,
+            &failure.reason,
+        );
+        let _ = self
+            .events_tx
+            .send(ScrapeEvent::SourceFailing(SourceFailureAlert {
+                source: source_id.to_string(),
+                consecutive_failures: recorded.consecutive_failures,
+                message: message.clone(),
+            }));
+        let delivered = NotificationService::with_credentials(
+            Arc::clone(config),
+            Arc::clone(&self.credentials),
+        )
+        .send_source_failure_alert(&message)
+        .await;
+        tracing::warn!(
+            source = source_id,
+            channels = ?delivered,
+            "Alerted user about a failing source"
+        );
+        Ok(())
+    }
+}
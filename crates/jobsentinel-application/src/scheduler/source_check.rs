@@ -0,0 +1,138 @@
+//! One-off source checks for first-run setup
+//!
+//! Lets the setup wizard try a draft config against a single source before
+//! anything is saved, so an empty search is caught while the user can still
+//! change it.
+
+use super::workers::{check_single_source, enabled_sources};
+use crate::{
+    config::{Config, SCHEDULED_SOURCE_IDS},
+    credentials::CredentialService,
+};
+use jobsentinel_storage::Database;
+use serde::Serialize;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Titles returned with a source check so the user can judge the results.
+const SAMPLE_TITLE_LIMIT: usize = 5;
+
+/// Errors from checking a source during setup
+#[derive(Error, Debug)]
+pub enum SourceCheckError {
+    #[error("Unknown job source")]
+    UnknownSource,
+
+    #[error("Job source is off or missing setup details")]
+    SourceNotEnabled,
+
+    #[error("Settings need attention: {0}")]
+    InvalidConfig(String),
+}
+
+/// What one source returned for a draft config
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceCheck {
+    pub source_id: String,
+    pub jobs_found: usize,
+    pub sample_titles: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Validate `config` and check one of its enabled sources right now.
+///
+/// Nothing is saved; the config does not have to be the active one.
+pub async fn check_source_now(
+    config: Config,
+    database: &Arc<Database>,
+    credentials: &CredentialService,
+    source_id: &str,
+) -> Result<SourceCheck, SourceCheckError> {
+    let source_id = SCHEDULED_SOURCE_IDS
+        .iter()
+        .copied()
+        .find(|known| *known == source_id)
+        .ok_or(SourceCheckError::UnknownSource)?;
+    config
+        .validate()
+        .map_err(|error| SourceCheckError::InvalidConfig(error.to_string()))?;
+    if !enabled_sources(&config).contains(&source_id) {
+        return Err(SourceCheckError::SourceNotEnabled);
+    }
+
+    let config = Arc::new(config);
+    let batch = check_single_source(source_id, &config, database, credentials).await;
+    tracing::info!(
+        source_id,
+        jobs_found = batch.jobs.len(),
+        error_count = batch.errors.len(),
+        "Setup source check complete"
+    );
+
+    Ok(SourceCheck {
+        source_id: source_id.to_string(),
+        jobs_found: batch.jobs.len(),
+        sample_titles: batch
+            .jobs
+            .iter()
+            .take(SAMPLE_TITLE_LIMIT)
+            .map(|job| job.title.clone())
+            .collect(),
+        errors: batch.errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::minimal_test_config;
+
+    async fn database() -> Arc<Database> {
+        let database = Database::connect_memory().await.unwrap();
+        database.migrate().await.unwrap();
+        Arc::new(database)
+    }
+
+    #[tokio::test]
+    async fn unknown_sources_are_rejected() {
+        let credentials = CredentialService::compatibility_keyring();
+
+        let error = check_source_now(
+            minimal_test_config(),
+            &database().await,
+            &credentials,
+            "not_a_source",
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, SourceCheckError::UnknownSource));
+    }
+
+    #[tokio::test]
+    async fn sources_that_are_off_are_not_checked() {
+        let credentials = CredentialService::compatibility_keyring();
+        let mut config = minimal_test_config();
+        config.remoteok.enabled = false;
+
+        let error = check_source_now(config, &database().await, &credentials, "remoteok")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, SourceCheckError::SourceNotEnabled));
+    }
+
+    #[tokio::test]
+    async fn invalid_settings_are_reported_before_checking() {
+        let credentials = CredentialService::compatibility_keyring();
+        let mut config = minimal_test_config();
+        config.remoteok.enabled = true;
+        config.salary_floor_usd = -1;
+
+        let error = check_source_now(config, &database().await, &credentials, "remoteok")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, SourceCheckError::InvalidConfig(_)));
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     config::{AutoRefreshConfig, Config},
     test_support::{minimal_test_config, test_job},
 };
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 use jobsentinel_storage::Database as Db;
 use std::sync::Arc;
 use std::time::Duration;
@@ -244,7 +244,40 @@ async fn test_scheduler_rapid_shutdown_subscribe_cycle() {
 #[path = "tests/scraper_cycle_tests.rs"]
 mod scraper_cycle_tests;
 
+#[path = "tests/cycle_reporting_tests.rs"]
+mod cycle_reporting_tests;
+
+#[path = "tests/vacation_mode_tests.rs"]
+mod vacation_mode_tests;
+
 mod lifecycle_and_error_tests;
 
 #[path = "tests/error_path_tests.rs"]
 mod error_path_tests;
+
+#[path = "tests/digest_tests.rs"]
+mod digest_tests;
+
+#[path = "tests/outbox_tests.rs"]
+mod outbox_tests;
+
+#[path = "tests/weekly_report_tests.rs"]
+mod weekly_report_tests;
+
+#[path = "tests/control_tests.rs"]
+mod control_tests;
+
+#[path = "tests/power_saving_tests.rs"]
+mod power_saving_tests;
+
+#[path = "tests/source_backoff_tests.rs"]
+mod source_backoff_tests;
+
+#[path = "tests/reminder_tests.rs"]
+mod reminder_tests;
+
+#[path = "tests/market_analysis_tests.rs"]
+mod market_analysis_tests;
+
+#[path = "tests/search_agent_tests.rs"]
+mod search_agent_tests;
@@ -165,6 +165,7 @@ fn test_scraping_result_creation() {
         high_matches: 10,
         alerts_sent: 5,
         errors: vec!["Test error".to_string()],
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.jobs_found, 100);
@@ -184,6 +185,7 @@ fn test_scraping_result_no_errors() {
         high_matches: 5,
         alerts_sent: 5,
         errors: vec![],
+        throttle_events: Vec::new(),
     };
 
     assert!(result.errors.is_empty(), "Should have no errors");
@@ -226,6 +228,7 @@ fn test_scraping_result_clone() {
         high_matches: 2,
         alerts_sent: 1,
         errors: vec!["Error 1".to_string()],
+        throttle_events: Vec::new(),
     };
 
     let cloned = result.clone();
@@ -316,6 +319,7 @@ fn test_scraping_result_debug() {
         high_matches: 2,
         alerts_sent: 1,
         errors: vec![],
+        throttle_events: Vec::new(),
     };
 
     let debug_str = format!("{:?}", result);
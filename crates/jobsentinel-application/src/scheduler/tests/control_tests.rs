@@ -0,0 +1,68 @@
+use super::*;
+
+// ========================================
+// Pause, Resume, and Run-Now Tests
+// ========================================
+
+async fn test_database() -> Arc<Database> {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    Arc::new(db)
+}
+
+#[tokio::test]
+async fn test_pause_is_saved_and_resume_clears_it() {
+    let config = Arc::new(create_test_config());
+    let database = test_database().await;
+    let scheduler = Scheduler::new(Arc::clone(&config), Arc::clone(&database));
+
+    assert!(scheduler.scraping_paused_at().await.unwrap().is_none());
+    let paused_at = scheduler.pause_scraping().await.unwrap();
+
+    // A scheduler built later (as after a restart) sees the same pause.
+    let restarted = Scheduler::new(Arc::clone(&config), Arc::clone(&database));
+    assert_eq!(
+        restarted
+            .scraping_paused_at()
+            .await
+            .unwrap()
+            .map(|t| t.timestamp()),
+        Some(paused_at.timestamp())
+    );
+
+    assert!(restarted.resume_scraping().await.unwrap());
+    assert!(!restarted.resume_scraping().await.unwrap());
+    assert!(scheduler.scraping_paused_at().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_run_scrape_now_rejects_unknown_and_disabled_sources() {
+    let mut config = create_test_config();
+    config.remoteok.enabled = false;
+    let scheduler = Scheduler::new(Arc::new(config), test_database().await);
+
+    let error = scheduler
+        .run_scrape_now(&["not_a_source".to_string()])
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ScrapeNowError::UnknownSource(id) if id == "not_a_source"));
+
+    let error = scheduler
+        .run_scrape_now(&["remoteok".to_string()])
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ScrapeNowError::SourceNotEnabled(id) if id == "remoteok"));
+}
+
+#[tokio::test]
+async fn test_run_scrape_now_runs_while_paused() {
+    let mut config = create_test_config();
+    config.remoteok.enabled = false;
+    let scheduler = Scheduler::new(Arc::new(config), test_database().await);
+    scheduler.pause_scraping().await.unwrap();
+
+    let result = scheduler.run_scrape_now(&[]).await.unwrap();
+
+    assert_eq!(result.jobs_found, 0);
+    assert!(scheduler.scraping_paused_at().await.unwrap().is_some());
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+// ========================================
+// Cycle Progress and History Tests
+// ========================================
+
+#[tokio::test]
+async fn test_scraping_cycle_reports_progress_per_checked_source() {
+    let mut config = create_test_config();
+    // Both restricted sources are skipped locally, so nothing touches the network.
+    config.dice.enabled = true;
+    config.dice.query = "case manager".to_string();
+    config.builtin.enabled = true;
+    config.scraping_concurrency = 2;
+    let config = Arc::new(config);
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+
+    let scheduler = Scheduler::new(Arc::clone(&config), Arc::clone(&database));
+    let mut events = scheduler.subscribe_scrape_events();
+
+    let result = scheduler.run_scraping_cycle().await.unwrap();
+
+    assert_eq!(result.errors.len(), 2);
+    let mut received = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        received.push(event);
+    }
+
+    let ScrapeEvent::Progress(started) = &received[0] else {
+        panic!("cycle should start with a progress event");
+    };
+    assert_eq!(started.sources_total, 2);
+    assert_eq!(started.sources_completed, 0);
+    assert_eq!(started.pending_sources, ["builtin", "dice"]);
+
+    let mut completed: Vec<&str> = received
+        .iter()
+        .filter_map(|event| match event {
+            ScrapeEvent::SourceComplete(summary) => Some(summary.source.as_str()),
+            _ => None,
+        })
+        .collect();
+    completed.sort_unstable();
+    assert_eq!(completed, ["builtin", "dice"]);
+
+    let error_sources: Vec<Option<&str>> = received
+        .iter()
+        .filter_map(|event| match event {
+            ScrapeEvent::Error(error) => Some(error.source.as_deref()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(error_sources.len(), 2);
+    assert!(error_sources.iter().all(Option::is_some));
+
+    let Some(ScrapeEvent::Progress(finished)) = received.last() else {
+        panic!("cycle should end with a progress event");
+    };
+    assert_eq!(finished.sources_completed, 2);
+    assert!(finished.pending_sources.is_empty());
+}
+
+#[tokio::test]
+async fn test_scraping_cycle_is_saved_to_scrape_history() {
+    let mut config = create_test_config();
+    config.dice.enabled = true;
+    config.dice.query = "case manager".to_string();
+    let config = Arc::new(config);
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+
+    let scheduler = Scheduler::new(Arc::clone(&config), Arc::clone(&database));
+    scheduler.run_scraping_cycle().await.unwrap();
+
+    let history = crate::health::get_scrape_history(&database, 10)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history[0].status,
+        crate::health::ScrapeRunStatus::CompletedWithErrors
+    );
+    assert_eq!(history[0].sources_total, 1);
+
+    let details = crate::health::get_scrape_run_details(&database, history[0].id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(details.sources.len(), 1);
+    assert_eq!(details.sources[0].source, "dice");
+    assert_eq!(details.sources[0].errors.len(), 1);
+}
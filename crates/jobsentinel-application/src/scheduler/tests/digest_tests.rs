@@ -0,0 +1,74 @@
+use super::*;
+use crate::config::{DigestFrequency, EmailDigestConfig};
+use chrono::NaiveDate;
+
+// ========================================
+// Email Digest Tests
+// ========================================
+
+fn local(day: u32, hour: u32) -> chrono::NaiveDateTime {
+    // 2026-10-12 is a Monday
+    NaiveDate::from_ymd_opt(2026, 10, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_daily_digest_is_due_once_per_day_after_send_hour() {
+    let digest = EmailDigestConfig {
+        enabled: true,
+        send_hour: 8,
+        ..EmailDigestConfig::default()
+    };
+
+    assert!(email_digest_due(&digest, None, local(13, 9)));
+    assert!(email_digest_due(&digest, Some(local(12, 8)), local(13, 8)));
+    assert!(!email_digest_due(
+        &digest,
+        Some(local(13, 8)),
+        local(13, 20)
+    ));
+    assert!(!email_digest_due(&digest, Some(local(12, 9)), local(13, 7)));
+
+    let disabled = EmailDigestConfig {
+        enabled: false,
+        ..digest
+    };
+    assert!(!email_digest_due(&disabled, None, local(13, 9)));
+}
+
+#[test]
+fn test_weekly_digest_waits_for_send_weekday() {
+    let digest = EmailDigestConfig {
+        enabled: true,
+        frequency: DigestFrequency::Weekly,
+        send_hour: 8,
+        send_weekday: 3,
+        ..EmailDigestConfig::default()
+    };
+
+    // Sent Wednesday the 7th; not due again until Wednesday the 14th at 8:00
+    assert!(!email_digest_due(&digest, Some(local(7, 8)), local(13, 9)));
+    assert!(!email_digest_due(&digest, Some(local(7, 8)), local(14, 7)));
+    assert!(email_digest_due(&digest, Some(local(7, 8)), local(14, 8)));
+    assert!(!email_digest_due(
+        &digest,
+        Some(local(14, 8)),
+        local(18, 12)
+    ));
+}
+
+#[tokio::test]
+async fn test_due_digest_without_new_jobs_is_recorded_without_sending() {
+    let mut config = create_test_config();
+    config.alerts.email.digest.enabled = true;
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let scheduler = Scheduler::new(Arc::new(config), Arc::clone(&database));
+
+    assert_eq!(scheduler.send_email_digest_if_due().await.unwrap(), Some(0));
+    assert!(database.last_email_digest_at().await.unwrap().is_some());
+    assert_eq!(scheduler.send_email_digest_if_due().await.unwrap(), None);
+}
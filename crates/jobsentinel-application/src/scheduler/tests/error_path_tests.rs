@@ -152,6 +152,7 @@ async fn test_scraping_cycle_alert_already_sent_skip() {
         salary_min: None,
         salary_max: None,
         currency: None,
+        pay_period: None,
         created_at: now,
         updated_at: now,
         last_seen: now,
@@ -164,6 +165,8 @@ async fn test_scraping_cycle_alert_already_sent_skip() {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
         notes: None,
     };
     database.upsert_job(&job_with_alert).await.unwrap();
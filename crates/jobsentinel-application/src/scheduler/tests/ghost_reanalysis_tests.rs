@@ -0,0 +1,86 @@
+use super::*;
+use crate::test_support::{minimal_test_config, test_job};
+
+#[tokio::test]
+async fn outdated_scores_are_reanalyzed_and_bucket_moves_counted() {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    let mut job = test_job("stale-ghost-hash", "Care Coordinator", "Example Health");
+    job.description = Some("Coordinate patient care plans with a clinical team.".to_string());
+    job.salary_min = Some(70_000);
+    job.salary_max = Some(85_000);
+    job.ghost_score = Some(0.95);
+    let job_id = database.upsert_job(&job).await.unwrap();
+    let config = minimal_test_config();
+
+    let run = reanalyze_outdated_ghost_scores(&config, &database)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(run.detector_version, ghost_detector_version());
+    assert_eq!(run.jobs_reanalyzed, 1);
+    assert_eq!(run.moved_to_hidden, 0);
+    assert_eq!(run.moved_to_clear + run.moved_to_warning, 1);
+    let saved = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert!(saved.ghost_score.unwrap() < 0.95);
+    assert_eq!(
+        database.latest_ghost_reanalysis_run().await.unwrap(),
+        Some(run)
+    );
+
+    let rerun = reanalyze_outdated_ghost_scores(&config, &database)
+        .await
+        .unwrap();
+    assert!(rerun.is_none());
+}
+
+#[tokio::test]
+async fn batch_analysis_rescores_current_jobs_with_progress() {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    for index in 0..3 {
+        let mut job = test_job(
+            &format!("batch-ghost-{index}"),
+            "Care Coordinator",
+            &format!("Example Health {index}"),
+        );
+        job.description = Some("Coordinate patient care plans with a clinical team.".to_string());
+        job.ghost_score = Some(0.95);
+        database.upsert_job(&job).await.unwrap();
+    }
+    let config = minimal_test_config();
+    reanalyze_outdated_ghost_scores(&config, &database)
+        .await
+        .unwrap();
+
+    let reports = std::sync::Mutex::new(Vec::new());
+    let run = analyze_all_ghost_scores(&config, &database, |progress| {
+        reports.lock().unwrap().push(progress);
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(run.jobs_reanalyzed, 3);
+    assert_eq!(
+        run.moved_to_clear + run.moved_to_warning + run.moved_to_hidden,
+        0
+    );
+    assert_eq!(
+        reports.into_inner().unwrap(),
+        vec![
+            GhostBatchProgress {
+                jobs_analyzed: 0,
+                jobs_total: 3
+            },
+            GhostBatchProgress {
+                jobs_analyzed: 3,
+                jobs_total: 3
+            },
+        ]
+    );
+    assert_eq!(
+        database.latest_ghost_reanalysis_run().await.unwrap(),
+        Some(run)
+    );
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::test_support::test_job;
+
+async fn database_with_job(hash: &str) -> (Database, Job) {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    let mut job = test_job(hash, "Care Coordinator", "Example Health");
+    job.id = database.upsert_job(&job).await.unwrap();
+    (database, job)
+}
+
+#[test]
+fn gone_and_redirected_pages_close_the_posting() {
+    assert_eq!(
+        liveness_of(&Ok("<html></html>".to_string())),
+        Liveness::Live
+    );
+    assert_eq!(
+        liveness_of(&Err(ImportError::HttpStatus(410))),
+        Liveness::Closed("http_410")
+    );
+    assert_eq!(
+        liveness_of(&Err(ImportError::RedirectBlocked {
+            location: "https://example.com/careers".to_string(),
+        })),
+        Liveness::Closed("redirected")
+    );
+    assert_eq!(
+        liveness_of(&Err(ImportError::HttpStatus(503))),
+        Liveness::Unknown
+    );
+    assert_eq!(liveness_of(&Err(ImportError::Timeout)), Liveness::Unknown);
+}
+
+#[tokio::test]
+async fn closed_posting_is_surfaced_for_a_tracked_application() {
+    let (database, job) = database_with_job("liveness-applied").await;
+    database
+        .application_tracker()
+        .create_application(&job.hash)
+        .await
+        .unwrap();
+    let mut check = LivenessCheck::default();
+
+    record_liveness(&database, &job, Liveness::Closed("http_404"), &mut check)
+        .await
+        .unwrap();
+
+    assert_eq!(check.jobs_checked, 1);
+    assert_eq!(check.jobs_closed, 1);
+    assert_eq!(check.closed_applications.len(), 1);
+    assert_eq!(check.closed_applications[0].job_hash, job.hash);
+    let saved = database.get_job_by_id(job.id).await.unwrap().unwrap();
+    assert_eq!(saved.status, JobStatus::Closed);
+    assert!(database.get_recent_jobs(10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn unknown_results_keep_the_job_active_and_checked() {
+    let (database, job) = database_with_job("liveness-unknown").await;
+    let mut check = LivenessCheck::default();
+
+    record_liveness(&database, &job, Liveness::Unknown, &mut check)
+        .await
+        .unwrap();
+
+    assert_eq!(check.jobs_checked, 1);
+    assert_eq!(check.jobs_closed, 0);
+    let saved = database.get_job_by_id(job.id).await.unwrap().unwrap();
+    assert_eq!(saved.status, JobStatus::Active);
+    let due = database
+        .jobs_due_for_liveness_check(Utc::now() - chrono::Duration::days(1), 10)
+        .await
+        .unwrap();
+    assert!(due.is_empty());
+}
+
+#[tokio::test]
+async fn long_unseen_jobs_expire_without_fetching() {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    let mut job = test_job("liveness-stale", "Care Coordinator", "Example Health");
+    job.last_seen = Utc::now() - chrono::Duration::days(EXPIRE_AFTER_DAYS + 1);
+    let job_id = database.upsert_job(&job).await.unwrap();
+
+    let check = check_job_liveness(&database).await.unwrap();
+
+    assert_eq!(check.jobs_expired, 1);
+    assert_eq!(check.jobs_checked, 0);
+    let saved = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert_eq!(saved.status, JobStatus::Expired);
+}
@@ -0,0 +1,120 @@
+use super::*;
+use chrono::{NaiveDate, Utc};
+
+// ========================================
+// Market Analysis Tests
+// ========================================
+
+#[test]
+fn test_market_analysis_is_due_once_per_day() {
+    let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+    assert!(market_analysis_due(None, today));
+    assert!(market_analysis_due(today.pred_opt(), today));
+    assert!(!market_analysis_due(Some(today), today));
+}
+
+#[tokio::test]
+async fn test_run_market_analysis_if_due_runs_once_a_day() {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    database
+        .upsert_job(&test_job("market_daily", "Security Engineer", "Acme"))
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    let snapshot = scheduler
+        .run_market_analysis_if_due()
+        .await
+        .unwrap()
+        .expect("first check should run the analysis");
+    assert_eq!(snapshot.date, Utc::now().date_naive());
+    assert_eq!(snapshot.total_jobs, 1);
+
+    assert!(scheduler
+        .run_market_analysis_if_due()
+        .await
+        .unwrap()
+        .is_none());
+}
+
+/// A database with one new hiring spree alert: ten jobs from one company
+/// posted today
+async fn hiring_spree_database() -> Arc<Database> {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    for index in 0..10 {
+        db.upsert_job(&test_job(
+            &format!("spree_{index}"),
+            &format!("Care Coordinator {index}"),
+            "CareBridge",
+        ))
+        .await
+        .unwrap();
+    }
+    db.market_intelligence().run_daily_analysis().await.unwrap();
+    Arc::new(db)
+}
+
+#[tokio::test]
+async fn test_deliver_market_alerts_sends_new_alerts_once() {
+    let database = hiring_spree_database().await;
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    assert_eq!(scheduler.deliver_market_alerts().await.unwrap(), 1);
+    assert_eq!(scheduler.deliver_market_alerts().await.unwrap(), 0);
+    // Sent alerts stay in the app until the user reads or dismisses them.
+    assert_eq!(
+        database
+            .market_intelligence()
+            .get_unread_alerts()
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_deliver_market_alerts_skips_types_turned_off() {
+    let database = hiring_spree_database().await;
+    let mut preferences = crate::user_data::NotificationPreferences::default();
+    preferences.market_alerts.hiring_spree.enabled = false;
+    database
+        .user_data_manager()
+        .save_notification_preferences(&preferences)
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    assert_eq!(scheduler.deliver_market_alerts().await.unwrap(), 0);
+    assert!(database
+        .market_intelligence()
+        .get_unnotified_alerts(10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_market_alerts_are_held_during_vacation_mode() {
+    let database = hiring_spree_database().await;
+    database
+        .start_vacation_mode(false, Utc::now())
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    assert_eq!(scheduler.deliver_market_alerts().await.unwrap(), 0);
+    assert_eq!(
+        database
+            .market_intelligence()
+            .get_unnotified_alerts(10)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
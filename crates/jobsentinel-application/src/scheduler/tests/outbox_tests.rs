@@ -0,0 +1,229 @@
+use super::*;
+use crate::credentials::{CredentialKey, CredentialService};
+use crate::scoring::{JobScore, ScoreBreakdown};
+use chrono::Duration as ChronoDuration;
+
+// ========================================
+// Notification Outbox Tests
+// ========================================
+
+fn high_score() -> JobScore {
+    JobScore {
+        total: 0.95,
+        breakdown: ScoreBreakdown {
+            skills: 0.4,
+            salary: 0.25,
+            location: 0.2,
+            company: 0.05,
+            recency: 0.05,
+        },
+        reasons: vec!["Strong title match".to_string()],
+    }
+}
+
+async fn outbox_fixture() -> (Arc<Database>, Arc<CredentialService>) {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let credentials =
+        CredentialService::with_fixed_master_key(db.credentials(), [31_u8; 32], false);
+    (Arc::new(db), Arc::new(credentials))
+}
+
+#[test]
+fn test_notification_retry_delay_doubles() {
+    let delays: Vec<i64> = (1..=4)
+        .map(|attempts| notification_retry_delay(attempts).num_minutes())
+        .collect();
+    assert_eq!(delays, vec![1, 2, 4, 8]);
+}
+
+#[tokio::test]
+async fn test_high_match_alert_is_recorded_as_sent() {
+    let config = Arc::new(create_test_config());
+    let (database, credentials) = outbox_fixture().await;
+
+    let scored = vec![(
+        test_job("outbox_sent", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.alerts_sent, 1);
+    assert!(stats.errors.is_empty());
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].job_hash, "outbox_sent");
+    assert_eq!(history[0].status, "sent");
+    assert_eq!(history[0].attempts, 1);
+}
+
+#[tokio::test]
+async fn test_missing_credential_fails_without_retry() {
+    let mut config = create_test_config();
+    config.alerts.slack.enabled = true;
+    let config = Arc::new(config);
+    let (database, credentials) = outbox_fixture().await;
+
+    let scored = vec![(
+        test_job("outbox_missing", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.alerts_sent, 0);
+    assert_eq!(stats.errors.len(), 1);
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history[0].status, "failed");
+    assert_eq!(history[0].next_attempt_at, None);
+    assert!(history[0]
+        .last_error
+        .as_deref()
+        .unwrap()
+        .contains("Slack: not configured"));
+}
+
+#[tokio::test]
+async fn test_delivery_failure_is_retried_with_backoff_then_given_up() {
+    // A saved bot token with no chat ID fails before any request is made.
+    let mut config = create_test_config();
+    config.alerts.telegram.enabled = true;
+    let config = Arc::new(config);
+    let (database, credentials) = outbox_fixture().await;
+    credentials
+        .store(
+            CredentialKey::TelegramBotToken,
+            "123456789:ABCdefGHIjklMNOpqrsTUVwxyz",
+        )
+        .await
+        .unwrap();
+
+    let scored = vec![(
+        test_job("outbox_retry", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let before = chrono::Utc::now();
+    super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history[0].status, "pending");
+    assert_eq!(history[0].attempts, 1);
+    let retry_at = history[0].next_attempt_at.unwrap();
+    assert!(retry_at >= before + ChronoDuration::minutes(1));
+
+    // Nothing is due until the backoff passes.
+    let delivery = super::outbox::deliver_due_notifications(&config, &database, &credentials)
+        .await
+        .unwrap();
+    assert_eq!(delivery, OutboxDelivery::default());
+
+    let id = history[0].id;
+    for _ in 0..3 {
+        database
+            .mark_notification_failed(id, "forced", Some(chrono::Utc::now()))
+            .await
+            .unwrap();
+    }
+    let delivery = super::outbox::deliver_due_notifications(&config, &database, &credentials)
+        .await
+        .unwrap();
+    assert_eq!(delivery.failed, 1);
+
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history[0].attempts, 5);
+    assert_eq!(history[0].status, "failed");
+    assert_eq!(history[0].next_attempt_at, None);
+}
+
+#[tokio::test]
+async fn test_alert_during_quiet_hours_is_deferred_without_an_attempt() {
+    let config = Arc::new(create_test_config());
+    let (database, credentials) = outbox_fixture().await;
+    let now = chrono::Local::now().time();
+    let mut preferences = crate::user_data::NotificationPreferences::default();
+    preferences.global.quiet_hours_enabled = true;
+    preferences.global.quiet_hours_start = (now - ChronoDuration::minutes(5))
+        .format("%H:%M")
+        .to_string();
+    preferences.global.quiet_hours_end = (now + ChronoDuration::minutes(60))
+        .format("%H:%M")
+        .to_string();
+    database
+        .user_data_manager()
+        .save_notification_preferences(&preferences)
+        .await
+        .unwrap();
+
+    let scored = vec![(
+        test_job("outbox_quiet", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.alerts_sent, 0);
+    assert!(stats.errors.is_empty());
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history[0].status, "pending");
+    assert_eq!(history[0].attempts, 0);
+    assert!(history[0].next_attempt_at.unwrap() > chrono::Utc::now());
+}
+
+#[tokio::test]
+async fn test_alert_filtered_by_notification_preferences_is_not_queued() {
+    let config = Arc::new(create_test_config());
+    let (database, credentials) = outbox_fixture().await;
+    let mut preferences = crate::user_data::NotificationPreferences::default();
+    preferences.advanced_filters.exclude_keywords = vec!["security".to_string()];
+    database
+        .user_data_manager()
+        .save_notification_preferences(&preferences)
+        .await
+        .unwrap();
+
+    let scored = vec![
+        (
+            test_job("routing_skipped", "Security Engineer", "Harbor Care"),
+            high_score(),
+        ),
+        (
+            test_job("routing_sent", "Platform Engineer", "Harbor Care"),
+            high_score(),
+        ),
+    ];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.high_matches, 2);
+    assert_eq!(stats.alerts_sent, 1);
+    let history = database.notification_history(10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].job_hash, "routing_sent");
+}
+
+#[tokio::test]
+async fn test_alert_with_resume_match_saves_the_active_resume_match() {
+    let mut config = create_test_config();
+    config.alerts.include_resume_match = true;
+    let config = Arc::new(config);
+    let (database, credentials) = outbox_fixture().await;
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let resume_path = temp_dir.path().join("resume.txt");
+    std::fs::write(&resume_path, "SKILLS\nSecurity\nCommunication").unwrap();
+    let resume_id = database
+        .resume_matcher()
+        .upload_resume("Resume", &resume_path.to_string_lossy())
+        .await
+        .unwrap();
+
+    let scored = vec![(
+        test_job("outbox_resume", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.alerts_sent, 1);
+    assert!(database
+        .resume_matcher()
+        .get_match_result(resume_id, "outbox_resume")
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::config::{PowerSavingAction, PowerSavingConfig};
+use jobsentinel_platform::PowerStatus;
+
+// ========================================
+// Power Saving Tests
+// ========================================
+
+fn on_battery(percent: u8) -> PowerStatus {
+    PowerStatus {
+        on_battery: true,
+        battery_percent: Some(percent),
+        metered_connection: false,
+    }
+}
+
+#[test]
+fn test_low_battery_skips_and_charging_runs() {
+    let config = PowerSavingConfig::default();
+
+    assert_eq!(
+        scrape_plan(&config, &on_battery(20)),
+        ScrapePlan::Skipped {
+            reason: "on battery at 20%".to_string()
+        }
+    );
+    assert_eq!(scrape_plan(&config, &on_battery(21)), ScrapePlan::Full);
+
+    let charging = PowerStatus {
+        on_battery: false,
+        ..on_battery(5)
+    };
+    assert_eq!(scrape_plan(&config, &charging), ScrapePlan::Full);
+    assert_eq!(
+        scrape_plan(&config, &PowerStatus::default()),
+        ScrapePlan::Full
+    );
+}
+
+#[test]
+fn test_metered_connection_reduces_sources() {
+    let config = PowerSavingConfig {
+        reduced_source_count: 3,
+        ..PowerSavingConfig::default()
+    };
+    let metered = PowerStatus {
+        metered_connection: true,
+        ..PowerStatus::default()
+    };
+
+    assert_eq!(
+        scrape_plan(&config, &metered),
+        ScrapePlan::Reduced {
+            max_sources: 3,
+            reason: "on a metered connection".to_string()
+        }
+    );
+
+    let config = PowerSavingConfig {
+        metered_connection: PowerSavingAction::Run,
+        ..config
+    };
+    assert_eq!(scrape_plan(&config, &metered), ScrapePlan::Full);
+}
+
+#[test]
+fn test_stricter_action_wins_when_both_apply() {
+    let config = PowerSavingConfig {
+        low_battery: PowerSavingAction::Reduce,
+        metered_connection: PowerSavingAction::Skip,
+        ..PowerSavingConfig::default()
+    };
+    let status = PowerStatus {
+        metered_connection: true,
+        ..on_battery(10)
+    };
+
+    assert_eq!(
+        scrape_plan(&config, &status),
+        ScrapePlan::Skipped {
+            reason: "on a metered connection".to_string()
+        }
+    );
+}
@@ -0,0 +1,133 @@
+use super::*;
+use crate::test_support::{minimal_test_config, test_job};
+
+const REFRESHED_HTML: &str = r#"
+    <script type="application/ld+json">
+    {
+        "@context": "https://schema.org",
+        "@type": "JobPosting",
+        "title": "Senior Care Coordinator",
+        "description": "Coordinate patient care plans using Python and SQL reporting.",
+        "hiringOrganization": {"name": "Example Health"},
+        "jobLocation": {"address": {"addressLocality": "Denver", "addressRegion": "CO"}},
+        "baseSalary": {"currency": "USD", "value": {"minValue": 90000, "maxValue": 110000, "unitText": "YEAR"}}
+    }
+    </script>
+"#;
+
+async fn database_with_job() -> (Arc<Database>, Job) {
+    let database = Database::connect_memory().await.unwrap();
+    database.migrate().await.unwrap();
+    let mut job = test_job("refresh-hash", "Care Coordinator", "Example Health");
+    job.description = Some("Coordinate patient care.".to_string());
+    job.id = database.upsert_job(&job).await.unwrap();
+    let job = database.get_job_by_id(job.id).await.unwrap().unwrap();
+    (Arc::new(database), job)
+}
+
+#[tokio::test]
+async fn refresh_saves_page_fields_and_records_changes() {
+    let (database, job) = database_with_job().await;
+    let config = Arc::new(minimal_test_config());
+
+    let refresh = refresh_job_from_html(&config, &database, job.clone(), REFRESHED_HTML)
+        .await
+        .unwrap();
+
+    assert_eq!(refresh.job.id, job.id);
+    assert_eq!(refresh.job.hash, job.hash);
+    assert_eq!(refresh.job.title, "Senior Care Coordinator");
+    assert_eq!(refresh.job.location.as_deref(), Some("Denver, CO"));
+    assert_eq!(refresh.job.salary_min, Some(90_000));
+    assert!(refresh.job.score.is_some());
+    assert!(refresh.job.ghost_score.is_some());
+
+    let changed: Vec<&str> = refresh
+        .changes
+        .iter()
+        .map(|change| change.field.as_str())
+        .collect();
+    assert!(changed.contains(&"title"));
+    assert!(changed.contains(&"salary_min"));
+    assert!(changed.contains(&"description"));
+    assert!(!changed.contains(&"company"));
+
+    let history = database.get_job_refreshes(job.id, 10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].changes, refresh.changes);
+}
+
+#[tokio::test]
+async fn refresh_keeps_saved_fields_the_page_leaves_out() {
+    let (database, job) = database_with_job().await;
+    let config = Arc::new(minimal_test_config());
+    let sparse_html = r#"
+        <script type="application/ld+json">
+        {"@type": "JobPosting", "title": "Care Coordinator",
+         "hiringOrganization": {"name": "Example Health"}}
+        </script>
+    "#;
+
+    let refresh = refresh_job_from_html(&config, &database, job.clone(), sparse_html)
+        .await
+        .unwrap();
+
+    assert_eq!(refresh.job.location, job.location);
+    assert_eq!(refresh.job.description, job.description);
+    assert!(refresh
+        .changes
+        .iter()
+        .all(|change| change.field != "location" && change.field != "description"));
+}
+
+#[tokio::test]
+async fn refresh_rejects_pages_without_posting_data() {
+    let (database, job) = database_with_job().await;
+    let config = Arc::new(minimal_test_config());
+
+    let error = refresh_job_from_html(&config, &database, job.clone(), "<html></html>")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        JobRefreshError::Fetch(ImportError::NoSchemaOrgData)
+    ));
+    assert!(database
+        .get_job_refreshes(job.id, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn refresh_of_missing_job_reports_not_found() {
+    let (database, _job) = database_with_job().await;
+    let config = Arc::new(minimal_test_config());
+
+    let error = refresh_job(&config, &database, 9_999).await.unwrap_err();
+
+    assert!(matches!(error, JobRefreshError::NotFound));
+}
+
+#[tokio::test]
+async fn removed_posting_marks_application_and_refresh_clears_it() {
+    let (database, job) = database_with_job().await;
+    let config = Arc::new(minimal_test_config());
+    let tracker = database.application_tracker();
+    tracker.create_application(&job.hash).await.unwrap();
+
+    let closed = mark_application_posting_closed(&database, &job.hash)
+        .await
+        .unwrap();
+    assert!(closed.needs_attention());
+    assert!(mark_application_posting_closed(&database, &job.hash)
+        .await
+        .is_none());
+
+    refresh_job_from_html(&config, &database, job, REFRESHED_HTML)
+        .await
+        .unwrap();
+    let board = tracker.get_applications_by_status().await.unwrap();
+    assert!(board.to_apply[0].posting_closed_at.is_none());
+}
@@ -0,0 +1,117 @@
+use super::*;
+use crate::ats::PendingReminder;
+use chrono::Duration as ChronoDuration;
+
+// ========================================
+// Due Reminder Tests
+// ========================================
+
+async fn reminder_scheduler() -> (Scheduler, Arc<Database>) {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    database
+        .upsert_job(&test_job("reminder_job", "Case Manager", "CommunityCare"))
+        .await
+        .unwrap();
+    let tracker = database.application_tracker();
+    let app_id = tracker.create_application("reminder_job").await.unwrap();
+    tracker
+        .set_reminder(
+            app_id,
+            "follow_up",
+            chrono::Utc::now() - ChronoDuration::minutes(10),
+            "Ask about the salary floor",
+        )
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+    (scheduler, database)
+}
+
+#[test]
+fn test_reminder_alert_names_the_job_but_not_the_note() {
+    let reminder = PendingReminder {
+        id: 1,
+        application_id: 1,
+        reminder_type: "follow_up".to_string(),
+        reminder_time: "2026-10-12T09:00:00+00:00".to_string(),
+        message: Some("Ask about the salary floor".to_string()),
+        job_hash: "reminder_job".to_string(),
+        job_title: "Case Manager".to_string(),
+        company: "CommunityCare".to_string(),
+    };
+
+    assert_eq!(
+        reminder_alert_subject(&reminder),
+        "JobSentinel reminder: Case Manager at CommunityCare"
+    );
+    let message = reminder_alert_message(&reminder);
+    assert_eq!(
+        message,
+        "Follow-up reminder for Case Manager at CommunityCare is due. Open JobSentinel to see your note."
+    );
+    assert!(!message.contains("salary floor"));
+}
+
+#[tokio::test]
+async fn test_due_reminders_are_delivered_once() {
+    let (scheduler, database) = reminder_scheduler().await;
+
+    let delivered = scheduler.deliver_due_reminders().await.unwrap();
+    assert_eq!(delivered.len(), 1);
+    assert_eq!(delivered[0].job_title, "Case Manager");
+
+    assert!(scheduler.deliver_due_reminders().await.unwrap().is_empty());
+    // Delivered reminders stay on the board until the user completes them.
+    assert_eq!(
+        database
+            .application_tracker()
+            .get_pending_reminders()
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_due_reminders_are_held_during_quiet_hours() {
+    let (scheduler, database) = reminder_scheduler().await;
+    let now = chrono::Local::now().time();
+    let mut preferences = crate::user_data::NotificationPreferences::default();
+    preferences.global.quiet_hours_enabled = true;
+    preferences.global.quiet_hours_start = (now - ChronoDuration::minutes(5))
+        .format("%H:%M")
+        .to_string();
+    preferences.global.quiet_hours_end = (now + ChronoDuration::minutes(60))
+        .format("%H:%M")
+        .to_string();
+    database
+        .user_data_manager()
+        .save_notification_preferences(&preferences)
+        .await
+        .unwrap();
+
+    assert!(scheduler.deliver_due_reminders().await.unwrap().is_empty());
+    assert_eq!(
+        database
+            .application_tracker()
+            .get_undelivered_reminders(chrono::Utc::now(), 10)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_due_reminders_are_held_during_vacation_mode() {
+    let (scheduler, database) = reminder_scheduler().await;
+    database
+        .start_vacation_mode(false, chrono::Utc::now())
+        .await
+        .unwrap();
+
+    assert!(scheduler.deliver_due_reminders().await.unwrap().is_empty());
+}
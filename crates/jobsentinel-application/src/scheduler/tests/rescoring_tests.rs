@@ -0,0 +1,73 @@
+use super::*;
+use crate::config::ScoringProfile;
+use crate::test_support::{minimal_test_config, test_job};
+use jobsentinel_domain::ScoringConfig;
+
+fn recency_only_profile() -> ScoringProfile {
+    ScoringProfile {
+        name: "Fresh postings".to_string(),
+        weights: ScoringConfig {
+            skills_weight: 0.0,
+            salary_weight: 0.0,
+            location_weight: 0.0,
+            company_weight: 0.0,
+            recency_weight: 1.0,
+        },
+    }
+}
+
+#[tokio::test]
+async fn saved_jobs_are_rescored_with_the_active_profile() {
+    let database = Arc::new(Database::connect_memory().await.unwrap());
+    database.migrate().await.unwrap();
+    let mut job = test_job("rescore-hash", "Warehouse Associate", "Example Logistics");
+    job.score = Some(0.2);
+    let job_id = database.upsert_job(&job).await.unwrap();
+
+    let mut config = minimal_test_config();
+    config.scoring.profiles.push(recency_only_profile());
+    config.scoring.active_profile = Some("fresh postings".to_string());
+
+    let reports = std::sync::Mutex::new(Vec::new());
+    let rescored = rescore_saved_jobs(&Arc::new(config), &database, |progress| {
+        reports.lock().unwrap().push(progress);
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(rescored.jobs_rescored, 1);
+    assert_eq!(
+        reports.into_inner().unwrap(),
+        vec![
+            RescoreProgress {
+                jobs_rescored: 0,
+                jobs_total: 1
+            },
+            RescoreProgress {
+                jobs_rescored: 1,
+                jobs_total: 1
+            },
+        ]
+    );
+    let saved = database.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert!((saved.score.unwrap() - 1.0).abs() < 1e-9);
+    assert!(saved.score_reasons.unwrap().contains("fresh"));
+}
+
+#[tokio::test]
+async fn rescoring_with_no_saved_jobs_is_a_no_op() {
+    let database = Arc::new(Database::connect_memory().await.unwrap());
+    database.migrate().await.unwrap();
+
+    let rescored = rescore_saved_jobs(&Arc::new(minimal_test_config()), &database, |_| {})
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rescored,
+        RescoreProgress {
+            jobs_rescored: 0,
+            jobs_total: 0
+        }
+    );
+}
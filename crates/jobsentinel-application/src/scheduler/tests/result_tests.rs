@@ -12,6 +12,7 @@ async fn test_scraping_result_partial_errors() {
             "Greenhouse scraper timeout".to_string(),
             "Lever scraper rate limit".to_string(),
         ],
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.jobs_found, 50);
@@ -34,6 +35,7 @@ fn test_scraping_result_multiple_errors() {
         high_matches: 0,
         alerts_sent: 0,
         errors: errors.clone(),
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.errors, errors);
@@ -49,6 +51,7 @@ fn test_scraping_result_all_zeros() {
         high_matches: 0,
         alerts_sent: 0,
         errors: vec![],
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.jobs_found, 0);
@@ -68,6 +71,7 @@ fn test_scraping_result_max_values() {
         high_matches: usize::MAX,
         alerts_sent: usize::MAX,
         errors: vec!["error".to_string(); 100],
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.jobs_found, usize::MAX);
@@ -83,6 +87,7 @@ fn test_scraping_result_equality_after_clone() {
         high_matches: 8,
         alerts_sent: 4,
         errors: vec!["test".to_string()],
+        throttle_events: Vec::new(),
     };
 
     let cloned = original.clone();
@@ -218,6 +218,7 @@ async fn test_scraping_cycle_skips_already_alerted_jobs() {
         salary_min: None,
         salary_max: None,
         currency: None,
+        pay_period: None,
         created_at: now,
         updated_at: now,
         last_seen: now,
@@ -230,6 +231,8 @@ async fn test_scraping_cycle_skips_already_alerted_jobs() {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
         notes: None,
     };
     database.upsert_job(&alerted_job).await.unwrap();
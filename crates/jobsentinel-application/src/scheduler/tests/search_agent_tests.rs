@@ -0,0 +1,155 @@
+use super::*;
+use crate::notify::{search_agent_alert_message, search_agent_alert_subject};
+use crate::user_data::{
+    MessageTemplateChannel, SavedSearch, SearchAgentFrequency, SearchAgentSettings,
+};
+use chrono::{Duration as ChronoDuration, Utc};
+
+// ========================================
+// Job Agent Tests
+// ========================================
+
+fn saved_search(name: &str, text_search: &str) -> SavedSearch {
+    SavedSearch {
+        id: String::new(),
+        name: name.to_string(),
+        sort_by: "date-desc".to_string(),
+        score_filter: "all".to_string(),
+        source_filter: "all".to_string(),
+        remote_filter: "all".to_string(),
+        bookmark_filter: "all".to_string(),
+        notes_filter: "all".to_string(),
+        posted_date_filter: None,
+        salary_min_filter: None,
+        salary_max_filter: None,
+        ghost_filter: None,
+        text_search: Some(text_search.to_string()),
+        created_at: String::new(),
+        last_used_at: None,
+        agent: SearchAgentSettings::default(),
+    }
+}
+
+#[test]
+fn test_search_agent_due_by_frequency() {
+    let now = Utc::now();
+    let checked = |hours_ago: i64| SearchAgentSettings {
+        enabled: true,
+        frequency: SearchAgentFrequency::Daily,
+        last_checked_at: Some((now - ChronoDuration::hours(hours_ago)).to_rfc3339()),
+        ..SearchAgentSettings::default()
+    };
+
+    assert!(search_agent_due(
+        &SearchAgentSettings {
+            frequency: SearchAgentFrequency::EachScrape,
+            ..checked(0)
+        },
+        now
+    ));
+    assert!(!search_agent_due(&checked(3), now));
+    assert!(search_agent_due(&checked(25), now));
+}
+
+#[test]
+fn test_search_agent_alert_lists_best_matches() {
+    let search = saved_search("Care coordination", "coordinator");
+    let mut jobs: Vec<Job> = (0..12)
+        .map(|index| test_job(&format!("agent_{index}"), "Care Coordinator", "CareBridge"))
+        .collect();
+    jobs[0].score = Some(0.91);
+
+    assert_eq!(
+        search_agent_alert_subject(&search, jobs.len()),
+        "JobSentinel: 12 new jobs for Care coordination"
+    );
+    let message = search_agent_alert_message(&search, &jobs);
+    assert!(message.starts_with(
+        "12 new jobs for your saved search \"Care coordination\":\n- Care Coordinator at CareBridge, Remote (91% match)\n  https://example.com/job/agent_0"
+    ));
+    assert!(message.ends_with("...and 2 more in JobSentinel."));
+}
+
+#[tokio::test]
+async fn test_deliver_search_agent_alerts_sends_only_new_matches_once() {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let manager = database.user_data_manager();
+    let search = manager
+        .create_saved_search(saved_search("Care coordination", "coordinator"))
+        .await
+        .unwrap();
+    manager
+        .update_saved_search_agent(
+            &search.id,
+            &SearchAgentSettings {
+                enabled: true,
+                channels: vec![MessageTemplateChannel::Slack],
+                ..SearchAgentSettings::default()
+            },
+        )
+        .await
+        .unwrap();
+    let started = Utc::now() - ChronoDuration::hours(1);
+    manager
+        .mark_search_agent_checked(&search.id, &started.to_rfc3339())
+        .await
+        .unwrap();
+
+    let mut before_agent = test_job("agent_old", "Care Coordinator", "CareBridge");
+    before_agent.created_at = started - ChronoDuration::hours(1);
+    database.upsert_job(&before_agent).await.unwrap();
+    database
+        .upsert_job(&test_job("agent_other", "Security Engineer", "Acme"))
+        .await
+        .unwrap();
+    database
+        .upsert_job(&test_job("agent_new", "Care Coordinator", "CommunityCare"))
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    assert_eq!(scheduler.deliver_search_agent_alerts().await.unwrap(), 1);
+    assert_eq!(scheduler.deliver_search_agent_alerts().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_search_agent_alerts_are_held_during_vacation_mode() {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let manager = database.user_data_manager();
+    let search = manager
+        .create_saved_search(SavedSearch {
+            agent: SearchAgentSettings {
+                enabled: true,
+                ..SearchAgentSettings::default()
+            },
+            ..saved_search("Care coordination", "coordinator")
+        })
+        .await
+        .unwrap();
+    let started = Utc::now() - ChronoDuration::hours(1);
+    manager
+        .mark_search_agent_checked(&search.id, &started.to_rfc3339())
+        .await
+        .unwrap();
+    database
+        .upsert_job(&test_job("agent_held", "Care Coordinator", "CareBridge"))
+        .await
+        .unwrap();
+    database
+        .start_vacation_mode(false, Utc::now())
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    assert_eq!(scheduler.deliver_search_agent_alerts().await.unwrap(), 0);
+    let agents = manager.list_search_agents().await.unwrap();
+    assert_eq!(
+        agents[0].agent.last_checked_at,
+        Some(started.to_rfc3339()),
+        "held jobs stay for the next check"
+    );
+}
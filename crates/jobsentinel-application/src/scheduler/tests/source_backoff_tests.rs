@@ -0,0 +1,106 @@
+use super::*;
+use chrono::{Duration as ChronoDuration, Utc};
+
+// ========================================
+// Failing Source Backoff and Alert Tests
+// ========================================
+
+#[test]
+fn test_source_backoff_doubles_up_to_a_day() {
+    assert_eq!(source_backoff(1), ChronoDuration::minutes(30));
+    assert_eq!(source_backoff(2), ChronoDuration::hours(1));
+    assert_eq!(source_backoff(5), ChronoDuration::hours(8));
+    assert_eq!(source_backoff(7), ChronoDuration::days(1));
+    assert_eq!(source_backoff(40), ChronoDuration::days(1));
+}
+
+#[test]
+fn test_source_failure_alert_message_names_source_and_reason() {
+    assert_eq!(
+        source_failure_alert_message("Greenhouse", 5, "403 Forbidden"),
+        "Greenhouse scraping has failed 5 times: 403 Forbidden"
+    );
+}
+
+/// RemoteOK routed through a proxy that cannot be prepared, so every check
+/// fails without touching the network.
+fn failing_remoteok_config() -> Config {
+    let mut config = create_test_config();
+    config.remoteok.enabled = true;
+    config.proxy.enabled = true;
+    config.proxy.url = "not a proxy".to_string();
+    config.proxy.sources = vec!["remoteok".to_string()];
+    config
+}
+
+#[tokio::test]
+async fn test_repeated_source_failures_alert_once_and_back_off() {
+    let config = Arc::new(failing_remoteok_config());
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let scheduler = Scheduler::new(Arc::clone(&config), Arc::clone(&database));
+    let mut events = scheduler.subscribe_scrape_events();
+    let remoteok = ["remoteok".to_string()];
+
+    for _ in 0..SOURCE_FAILURE_ALERT_THRESHOLD + 1 {
+        scheduler.run_scrape_now(&remoteok).await.unwrap();
+    }
+
+    let failures = database.source_failures().await.unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].source_id, "remoteok");
+    assert_eq!(
+        failures[0].consecutive_failures,
+        SOURCE_FAILURE_ALERT_THRESHOLD + 1
+    );
+    assert_eq!(failures[0].last_error, "proxy unavailable");
+
+    let mut alerts = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let ScrapeEvent::SourceFailing(alert) = event {
+            alerts.push(alert);
+        }
+    }
+    assert_eq!(
+        alerts,
+        [SourceFailureAlert {
+            source: "remoteok".to_string(),
+            consecutive_failures: SOURCE_FAILURE_ALERT_THRESHOLD,
+            message: "RemoteOK scraping has failed 5 times: proxy unavailable".to_string(),
+        }]
+    );
+
+    // Scheduled scrapes leave the source out until its backoff ends.
+    let now = Utc::now();
+    assert!(scheduler
+        .sources_due(&config, now)
+        .await
+        .unwrap()
+        .is_empty());
+    let retry_at = source_retry_at(&failures[0]);
+    assert_eq!(
+        scheduler.sources_due(&config, retry_at).await.unwrap(),
+        ["remoteok"]
+    );
+}
+
+#[tokio::test]
+async fn test_successful_check_clears_source_failures() {
+    let config = create_test_config();
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    database
+        .record_source_failure("remoteok", "timed out", Utc::now())
+        .await
+        .unwrap();
+    let scheduler = Scheduler::new(Arc::new(config), Arc::clone(&database));
+
+    scheduler
+        .record_source_outcome("remoteok", None, &Arc::new(create_test_config()))
+        .await
+        .unwrap();
+
+    assert!(database.source_failures().await.unwrap().is_empty());
+}
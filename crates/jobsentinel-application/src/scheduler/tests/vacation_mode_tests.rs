@@ -0,0 +1,79 @@
+use super::*;
+use crate::scoring::{JobScore, ScoreBreakdown};
+use chrono::Utc;
+
+// ========================================
+// Vacation Mode Tests
+// ========================================
+
+fn high_score() -> JobScore {
+    JobScore {
+        total: 0.95,
+        breakdown: ScoreBreakdown {
+            skills: 0.4,
+            salary: 0.25,
+            location: 0.2,
+            company: 0.05,
+            recency: 0.05,
+        },
+        reasons: vec!["Strong title match".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn test_vacation_mode_holds_alerts_instead_of_sending() {
+    let config = Arc::new(create_test_config());
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let credentials = Arc::new(crate::credentials::CredentialService::compatibility_keyring());
+    database
+        .start_vacation_mode(true, Utc::now())
+        .await
+        .unwrap();
+
+    let scored = vec![(
+        test_job("vacation_hold", "Security Engineer", "Harbor Care"),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.jobs_new, 1);
+    assert_eq!(stats.high_matches, 1);
+    assert_eq!(stats.alerts_sent, 0);
+    assert!(stats.errors.is_empty());
+    assert_eq!(database.get_vacation_mode().await.unwrap().queued_alerts, 1);
+
+    let summary = database
+        .end_vacation_mode(Utc::now())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(summary.highlights.len(), 1);
+    assert_eq!(summary.highlights[0].job_hash, "vacation_hold");
+}
+
+#[tokio::test]
+async fn test_blocked_company_is_never_held_for_alerting() {
+    let mut config = create_test_config();
+    config.blocked_companies = vec!["harbor care".to_string()];
+    let config = Arc::new(config);
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let credentials = Arc::new(crate::credentials::CredentialService::compatibility_keyring());
+    database
+        .start_vacation_mode(true, Utc::now())
+        .await
+        .unwrap();
+
+    let scored = vec![(
+        test_job("blocked_hold", "Security Engineer", "Harbor Care, Inc."),
+        high_score(),
+    )];
+    let stats = super::workers::persist_and_notify(&scored, &config, &database, &credentials).await;
+
+    assert_eq!(stats.jobs_new, 1);
+    assert_eq!(stats.high_matches, 0);
+    assert_eq!(database.get_vacation_mode().await.unwrap().queued_alerts, 0);
+}
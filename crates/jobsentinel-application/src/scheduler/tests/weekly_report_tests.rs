@@ -0,0 +1,96 @@
+use super::*;
+use crate::config::WeeklyReportConfig;
+use chrono::NaiveDate;
+
+// ========================================
+// Weekly Report Tests
+// ========================================
+
+fn local(day: u32, hour: u32) -> chrono::NaiveDateTime {
+    // 2026-10-12 is a Monday
+    NaiveDate::from_ymd_opt(2026, 10, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_weekly_report_is_due_once_a_week_after_send_time() {
+    let report = WeeklyReportConfig {
+        enabled: true,
+        send_weekday: 1,
+        send_hour: 9,
+    };
+
+    assert!(weekly_report_due(&report, None, local(13, 10)));
+    assert!(!weekly_report_due(
+        &report,
+        Some(local(12, 9)),
+        local(18, 23)
+    ));
+    assert!(!weekly_report_due(
+        &report,
+        Some(local(12, 9)),
+        local(19, 8)
+    ));
+    assert!(weekly_report_due(&report, Some(local(12, 9)), local(19, 9)));
+
+    let disabled = WeeklyReportConfig {
+        enabled: false,
+        ..report
+    };
+    assert!(!weekly_report_due(&disabled, None, local(19, 9)));
+}
+
+async fn report_database() -> Arc<Database> {
+    let db = Database::connect_memory().await.unwrap();
+    db.migrate().await.unwrap();
+    let database = Arc::new(db);
+    let job = Job {
+        score: Some(0.93),
+        ..test_job("weekly_top", "Staff Security Engineer", "Acme")
+    };
+    database.upsert_job(&job).await.unwrap();
+    database
+}
+
+#[tokio::test]
+async fn test_generate_weekly_report_now_saves_report_without_moving_schedule() {
+    let database = report_database().await;
+    let scheduler = Scheduler::new(Arc::new(create_test_config()), Arc::clone(&database));
+
+    let run = scheduler.generate_weekly_report_now().await.unwrap();
+
+    assert!(!run.report.scheduled);
+    assert!(run.report.markdown.contains("1 new job found this week."));
+    assert!(run
+        .report
+        .markdown
+        .contains("**Staff Security Engineer** at Acme"));
+    assert!(run.report.html.contains("Staff Security Engineer"));
+    assert!(run.delivery.delivered.is_empty());
+    assert!(run.delivery.failed.is_empty());
+    assert_eq!(database.weekly_reports(10).await.unwrap().len(), 1);
+    assert!(database
+        .last_scheduled_weekly_report_at()
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_scheduled_weekly_report_runs_once_until_next_send_time() {
+    let mut config = create_test_config();
+    config.alerts.weekly_report.enabled = true;
+    let database = report_database().await;
+    let scheduler = Scheduler::new(Arc::new(config), Arc::clone(&database));
+
+    let run = scheduler.send_weekly_report_if_due().await.unwrap();
+    assert!(run.is_some_and(|run| run.report.scheduled));
+    assert!(scheduler
+        .send_weekly_report_if_due()
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(database.weekly_reports(10).await.unwrap().len(), 1);
+}
@@ -2,6 +2,7 @@
 
 use crate::config::Config;
 use crate::credentials::CredentialService;
+use serde::Serialize;
 use std::sync::Arc;
 use tokio::sync::{broadcast, Mutex, RwLock};
 
@@ -32,6 +33,8 @@ pub struct Scheduler {
     pub(crate) credentials: Arc<CredentialService>,
     pub(crate) shutdown_tx: broadcast::Sender<()>,
     pub(crate) scrape_lock: Arc<Mutex<()>>,
+    pub(crate) source_overflow: Arc<Mutex<super::workers::SourceOverflow>>,
+    pub(crate) events_tx: broadcast::Sender<ScrapeEvent>,
 }
 
 /// Scraping result statistics
@@ -43,4 +46,87 @@ pub struct ScrapingResult {
     pub high_matches: usize,
     pub alerts_sent: usize,
     pub errors: Vec<String>,
+    /// Rate-limit waits and remote throttling seen during the cycle
+    pub throttle_events: Vec<SourceThrottleEvent>,
+}
+
+/// A delay applied to a source check because of rate limiting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceThrottleEvent {
+    /// Scheduled source id (e.g., "greenhouse")
+    pub source: String,
+    /// Host that was throttled
+    pub domain: String,
+    /// "local_limit", "rate_limited" (HTTP 429), or "forbidden" (HTTP 403)
+    pub reason: &'static str,
+    /// How long requests to the domain were held
+    pub delay_ms: u64,
+}
+
+/// Job counts for one source, or for a whole cycle so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ScrapeCounts {
+    pub jobs_found: usize,
+    pub jobs_new: usize,
+    pub jobs_updated: usize,
+}
+
+/// Live update from a running scraping cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrapeEvent {
+    /// Cycle-wide progress, sent when the cycle starts and after each source is saved
+    Progress(ScrapeProgress),
+    /// One source finished and its jobs were saved
+    SourceComplete(SourceScrapeSummary),
+    /// A source check, or saving its jobs, reported an error
+    Error(ScrapeErrorEvent),
+    /// A source has failed enough checks in a row to alert the user
+    SourceFailing(SourceFailureAlert),
+}
+
+/// Cycle-wide progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScrapeProgress {
+    /// Source checks finished so far in this cycle
+    pub sources_completed: usize,
+    /// Source checks this cycle will run
+    pub sources_total: usize,
+    /// Scheduled source ids still running or waiting for a slot
+    pub pending_sources: Vec<String>,
+    /// Totals across every finished source
+    #[serde(flatten)]
+    pub counts: ScrapeCounts,
+}
+
+/// Result of one finished source check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceScrapeSummary {
+    /// Scheduled source id (e.g., "greenhouse")
+    pub source: String,
+    pub sources_completed: usize,
+    pub sources_total: usize,
+    /// Jobs from this source after its quota was applied
+    #[serde(flatten)]
+    pub counts: ScrapeCounts,
+}
+
+/// A user-safe error message from a scraping cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScrapeErrorEvent {
+    /// Scheduled source id, or `None` for cycle-wide notices
+    pub source: Option<String>,
+    pub message: String,
+    /// Jobs this source still returned and saved despite the error
+    #[serde(flatten)]
+    pub counts: ScrapeCounts,
+}
+
+/// A source that has failed several checks in a row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceFailureAlert {
+    /// Scheduled source id
+    pub source: String,
+    pub consecutive_failures: i64,
+    /// Alert text, such as "Greenhouse scraping has failed 5 times: 403 Forbidden"
+    pub message: String,
 }
@@ -0,0 +1,150 @@
+//! Scheduled weekly summary reports
+//!
+//! Each report covers the past seven days: new jobs, the top matches among
+//! them, application tracking activity, and market alerts. Reports are saved
+//! locally and sent through the enabled email, Slack, and Discord channels.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDateTime, Utc};
+use jobsentinel_storage::{ApplicationActivity, WeeklyReportRecord};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::digest::latest_send_slot;
+use super::types::Scheduler;
+use crate::config::WeeklyReportConfig;
+use crate::notify::{
+    format_report_html, format_report_markdown, NotificationService, ReportApplicationActivity,
+    ReportDelivery, ReportMarketAlert, WeeklyReport,
+};
+
+/// How often the background task checks whether a report is due.
+pub const WEEKLY_REPORT_CHECK_INTERVAL: Duration = Duration::from_mins(15);
+
+/// Most top matches listed in one report
+const WEEKLY_REPORT_TOP_MATCHES: i64 = 10;
+
+/// Most market alerts listed in one report
+const WEEKLY_REPORT_MARKET_ALERTS: usize = 10;
+
+/// A saved report and where it was sent
+#[derive(Debug, Clone, Serialize)]
+pub struct WeeklyReportRun {
+    pub report: WeeklyReportRecord,
+    pub delivery: ReportDelivery,
+}
+
+/// Whether a report should go out at `now`, given when the last scheduled
+/// one was made. Both times are local.
+#[must_use]
+pub fn weekly_report_due(
+    report: &WeeklyReportConfig,
+    last_sent: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+) -> bool {
+    report.enabled
+        && last_sent.is_none_or(|last_sent| {
+            last_sent < latest_send_slot(report.send_hour, Some(report.send_weekday), now)
+        })
+}
+
+fn report_application_activity(activity: &ApplicationActivity) -> ReportApplicationActivity {
+    ReportApplicationActivity {
+        applied: activity.applied,
+        interviews: activity.interviews,
+        offers: activity.offers,
+        rejections: activity.rejections,
+        active: activity.active,
+    }
+}
+
+impl Scheduler {
+    /// Make, save, and send the weekly report if its scheduled time has
+    /// passed since the last one. Reports are held while vacation mode is on.
+    pub async fn send_weekly_report_if_due(&self) -> Result<Option<WeeklyReportRun>> {
+        let config = self.config.read().await.clone();
+        let last_sent = self.database.last_scheduled_weekly_report_at().await?;
+        if !weekly_report_due(
+            &config.alerts.weekly_report,
+            last_sent.map(|sent_at| sent_at.with_timezone(&Local).naive_local()),
+            Local::now().naive_local(),
+        ) {
+            return Ok(None);
+        }
+        if self.database.get_vacation_mode().await?.enabled {
+            tracing::info!("Vacation mode is on; holding the weekly report");
+            return Ok(None);
+        }
+
+        self.run_weekly_report(true).await.map(Some)
+    }
+
+    /// Make, save, and send a report for the past seven days right away.
+    /// The next scheduled report still goes out at its usual time.
+    pub async fn generate_weekly_report_now(&self) -> Result<WeeklyReportRun> {
+        self.run_weekly_report(false).await
+    }
+
+    async fn run_weekly_report(&self, scheduled: bool) -> Result<WeeklyReportRun> {
+        let config = Arc::new(self.config.read().await.clone());
+        let report = self.build_weekly_report(Utc::now()).await?;
+        let markdown = format_report_markdown(&report);
+        let html = format_report_html(&report);
+        let id = self
+            .database
+            .save_weekly_report(
+                report.period_start,
+                report.period_end,
+                scheduled,
+                &markdown,
+                &html,
+            )
+            .await?;
+
+        let delivery = NotificationService::with_credentials(config, Arc::clone(&self.credentials))
+            .send_weekly_report(&report, &markdown)
+            .await;
+
+        let record = self
+            .database
+            .weekly_report(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Saved weekly report was not found"))?;
+        Ok(WeeklyReportRun {
+            report: record,
+            delivery,
+        })
+    }
+
+    async fn build_weekly_report(&self, now: DateTime<Utc>) -> Result<WeeklyReport> {
+        let since = now - ChronoDuration::weeks(1);
+        let market_alerts = self
+            .database
+            .market_intelligence()
+            .get_alerts_since(since)
+            .await?
+            .into_iter()
+            .take(WEEKLY_REPORT_MARKET_ALERTS)
+            .map(|alert| ReportMarketAlert {
+                title: alert.title,
+                description: alert.description,
+                severity: alert.severity.as_str().to_string(),
+            })
+            .collect();
+
+        Ok(WeeklyReport {
+            period_start: since,
+            period_end: now,
+            new_jobs: self.database.count_jobs_found_since(since).await?,
+            top_matches: self
+                .database
+                .top_jobs_found_since(since, WEEKLY_REPORT_TOP_MATCHES)
+                .await?,
+            applications: report_application_activity(
+                &self.database.application_activity_since(since).await?,
+            ),
+            market_alerts,
+        })
+    }
+}
@@ -10,5 +10,8 @@ mod scoring;
 mod scrapers;
 
 pub(super) use persistence::persist_and_notify;
-pub(super) use scoring::score_jobs;
-pub(super) use scrapers::run_scrapers;
+pub(super) use scoring::{apply_ghost_analysis, score_jobs, serialize_score_reasons};
+pub(super) use scrapers::{
+    check_single_source, enabled_sources, run_scrapers, SourceBatch, SourceFailureReason,
+    SourceOverflow,
+};
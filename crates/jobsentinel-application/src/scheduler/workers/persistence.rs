@@ -1,12 +1,15 @@
-//! Database persistence and notification logic
+//! Database persistence and notification queueing
 
 use crate::{
     config::Config,
     credentials::CredentialService,
-    notify::{Notification, NotificationService},
+    notify::route_job,
+    scheduler::outbox::{deliver_due_notifications, enqueue_alert, match_active_resume},
     scoring::{JobScore, ScoringEngine},
+    user_data::NotificationPreferences,
 };
-use jobsentinel_storage::Database;
+use jobsentinel_intelligence::ghost_detector_version;
+use jobsentinel_storage::{Database, JobUpsert};
 use std::sync::Arc;
 
 use jobsentinel_storage::database_error_kind;
@@ -21,7 +24,9 @@ pub(crate) struct PersistenceStats {
     pub errors: Vec<String>,
 }
 
-/// Persist jobs to database and send notifications for high-scoring jobs
+/// Persist jobs to database and queue notifications for high-scoring jobs
+///
+/// Queued alerts, and any retries that are due, are sent before returning.
 #[tracing::instrument(skip_all, fields(job_count = scored_jobs.len()), level = "info")]
 pub(crate) async fn persist_and_notify(
     scored_jobs: &[(jobsentinel_domain::Job, JobScore)],
@@ -42,33 +47,56 @@ pub(crate) async fn persist_and_notify(
     let job_count = scored_jobs.len();
     tracing::debug!(job_count, "Starting database persistence");
 
-    for (job, _score) in scored_jobs {
-        // Check if job exists before upserting
-        let was_existing = database
-            .get_job_by_hash(&job.hash)
-            .await
-            .ok()
-            .flatten()
-            .is_some();
-
-        if was_existing {
-            jobs_updated += 1;
-        } else {
-            jobs_new += 1;
-        }
-
-        if let Err(e) = database.upsert_job(job).await {
+    let upserts = match database
+        .upsert_jobs_batch(scored_jobs.iter().map(|(job, _score)| job))
+        .await
+    {
+        Ok(upserts) => upserts,
+        Err(e) => {
             tracing::error!(
-                job_hash = %job.hash,
+                job_count,
                 error_kind = database_error_kind(&e),
-                "Failed to upsert job"
+                "Failed to save job batch"
             );
             errors.push(format!(
-                "Database error while saving one job ({})",
+                "Database error while saving {job_count} jobs ({})",
                 database_error_kind(&e)
             ));
+            Vec::new()
+        }
+    };
+
+    for ((job, _score), upsert) in scored_jobs.iter().zip(&upserts) {
+        match upsert {
+            Ok(JobUpsert::New(_)) => jobs_new += 1,
+            Ok(JobUpsert::Updated(_)) => jobs_updated += 1,
+            Err(e) => {
+                tracing::error!(
+                    job_hash = %job.hash,
+                    error_kind = database_error_kind(e),
+                    "Failed to upsert job"
+                );
+                errors.push(format!(
+                    "Database error while saving one job ({})",
+                    database_error_kind(e)
+                ));
+            }
+        }
+        if upsert.is_ok() && job.ghost_score.is_some() {
+            if let Err(e) = database
+                .set_ghost_detector_version(&job.hash, ghost_detector_version())
+                .await
+            {
+                tracing::debug!(
+                    job_hash = %job.hash,
+                    error_kind = database_error_kind(&e),
+                    "Failed to record ghost detector version"
+                );
+            }
         }
+    }
 
+    for (job, _score) in scored_jobs {
         // Track reposts for ghost detection
         if let Err(e) = database
             .track_repost(&job.hash, &job.company, &job.title, &job.source)
@@ -90,17 +118,55 @@ pub(crate) async fn persist_and_notify(
         "Database persistence complete"
     );
 
-    // Send notifications for high-scoring jobs
+    // Queue notifications for high-scoring jobs, then send everything due
     let notify_start = Instant::now();
     tracing::debug!("Processing notifications");
-    let notification_service =
-        NotificationService::with_credentials(Arc::clone(config), Arc::clone(credentials));
     let scoring_engine = ScoringEngine::new(Arc::clone(config));
+    // Vacation mode holds alerts for the "while you were away" summary.
+    let vacation_enabled = match database.get_vacation_mode().await {
+        Ok(vacation) => vacation.enabled,
+        Err(_e) => {
+            tracing::warn!(
+                error_kind = "database",
+                "Failed to read vacation mode; sending alerts normally"
+            );
+            false
+        }
+    };
+    // Per-source thresholds and advanced filters decide which matches alert.
+    let preferences = match database
+        .user_data_manager()
+        .get_notification_preferences()
+        .await
+    {
+        Ok(preferences) => preferences,
+        Err(e) => {
+            tracing::warn!(
+                error_kind = database_error_kind(&e),
+                "Failed to read notification preferences; using defaults"
+            );
+            NotificationPreferences::default()
+        }
+    };
 
     for (job, score) in scored_jobs {
+        // Blocked companies never alert, whatever their score.
+        if config.blocks_company(&job.company) {
+            continue;
+        }
+
         if scoring_engine.should_alert_immediately(score) {
             high_matches += 1;
 
+            if let Err(skip) = route_job(job, score.total, &preferences) {
+                tracing::debug!(
+                    job_hash = %job.hash,
+                    ?skip,
+                    "Alert skipped by notification preferences"
+                );
+                continue;
+            }
+
             match database.claim_immediate_alert(&job.hash).await {
                 Ok(true) => {}
                 Ok(false) => continue,
@@ -118,32 +184,47 @@ pub(crate) async fn persist_and_notify(
                 }
             }
 
-            let notification = Notification {
-                job: job.clone(),
-                score: score.clone(),
-            };
-
-            match notification_service
-                .send_immediate_alert(&notification)
-                .await
-            {
-                Ok(()) => {
-                    tracing::info!(
-                        job_hash = %job.hash,
-                        job_score = score.total,
-                        "Notification alert sent"
-                    );
-                    alerts_sent += 1;
-                }
-                Err(_e) => {
+            if vacation_enabled {
+                if let Err(_e) = database.queue_vacation_alert(&job.hash, score.total).await {
                     tracing::error!(
                         job_hash = %job.hash,
-                        error_kind = "notification_delivery",
-                        "Failed to send notification alert"
+                        error_kind = "database",
+                        "Failed to hold alert during vacation mode"
                     );
-                    errors.push("Notification delivery error for one job".to_string());
+                    errors.push("Database error while holding one alert".to_string());
                 }
+                continue;
+            }
+
+            if config.alerts.include_resume_match {
+                match_active_resume(database, &job.hash).await;
             }
+
+            if let Err(_e) = enqueue_alert(database, &job.hash, score).await {
+                tracing::error!(
+                    job_hash = %job.hash,
+                    error_kind = "database",
+                    "Failed to queue notification alert"
+                );
+                errors.push("Database error while queueing one alert".to_string());
+            }
+        }
+    }
+
+    // Retries that came due are sent along with the new alerts.
+    match deliver_due_notifications(config, database, credentials).await {
+        Ok(delivery) => {
+            alerts_sent = delivery.sent;
+            for _ in 0..delivery.failed {
+                errors.push("Notification delivery error for one job".to_string());
+            }
+        }
+        Err(_e) => {
+            tracing::error!(
+                error_kind = "database",
+                "Failed to deliver queued notification alerts"
+            );
+            errors.push("Database error while sending queued alerts".to_string());
         }
     }
 
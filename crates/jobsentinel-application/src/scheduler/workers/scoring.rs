@@ -2,11 +2,16 @@
 
 use crate::{
     config::Config,
-    scoring::{get_cached_score, set_cached_score, JobScore, ScoreCacheKey, ScoringEngine},
+    geocoding::{job_coordinates, MAX_LOOKUPS_PER_CYCLE},
+    salary::{detect_pay_period, load_exchange_rates},
+    scoring::{
+        get_cached_score, load_keyword_weights, set_cached_score, JobScore, ScoreCacheKey,
+        ScoringEngine,
+    },
 };
 use jobsentinel_domain::Job;
 use jobsentinel_intelligence::{GhostConfig, GhostDetector};
-use jobsentinel_storage::Database;
+use jobsentinel_storage::{database_error_kind, Database};
 use std::sync::Arc;
 
 fn score_cache_key(job_hash: &str, active_resume_id: Option<i64>) -> ScoreCacheKey {
@@ -42,7 +47,7 @@ async fn active_resume_cache_id(config: &Config, database: &Database) -> Option<
     level = "info"
 )]
 pub(crate) async fn score_jobs(
-    jobs: Vec<Job>,
+    mut jobs: Vec<Job>,
     config: &Arc<Config>,
     database: &Arc<Database>,
 ) -> Vec<(Job, JobScore)> {
@@ -56,8 +61,15 @@ pub(crate) async fn score_jobs(
         "Starting job scoring"
     );
 
+    jobs.iter_mut().for_each(detect_pay_period);
+
     // Use with_db to enable resume-based scoring when configured
-    let scoring_engine = ScoringEngine::with_database(Arc::clone(config), Arc::clone(database));
+    let scoring_engine = ScoringEngine::with_database(Arc::clone(config), Arc::clone(database))
+        .with_exchange_rates(load_exchange_rates(database).await)
+        .with_keyword_weights(load_keyword_weights(database).await)
+        .with_job_coordinates(
+            job_coordinates(config, database, &jobs, MAX_LOOKUPS_PER_CYCLE).await,
+        );
 
     let mut scored_jobs: Vec<(Job, JobScore)> = Vec::with_capacity(jobs.len());
 
@@ -139,49 +151,7 @@ pub(crate) async fn score_jobs(
     let ghost_detector = GhostDetector::new(ghost_config);
 
     for (job, _score) in &mut scored_jobs {
-        // Get repost count from database (if job was seen before)
-        let repost_count = database
-            .get_repost_count(&job.company, &job.title, &job.source)
-            .await
-            .unwrap_or(0);
-
-        // Get count of open jobs from this company
-        let company_open_jobs = database
-            .count_company_open_jobs(&job.company)
-            .await
-            .unwrap_or(0);
-
-        // Analyze for ghost indicators (using ML-enhanced analysis v2.5.5)
-        let analysis = ghost_detector.analyze_enhanced(
-            &job.title,
-            job.description.as_deref(),
-            job.salary_min,
-            job.salary_max,
-            job.location.as_deref(),
-            job.remote,
-            job.created_at,
-            repost_count,
-            company_open_jobs,
-        );
-
-        // Update job with ghost analysis results
-        job.ghost_score = Some(analysis.score);
-        job.ghost_reasons = if analysis.reasons.is_empty() {
-            None
-        } else {
-            Some(serde_json::to_string(&analysis.reasons).unwrap_or_default())
-        };
-        job.repost_count = repost_count;
-
-        if analysis.score >= 0.5 {
-            tracing::debug!(
-                title_chars = job.title.chars().count(),
-                company_chars = job.company.chars().count(),
-                score = analysis.score,
-                reason_count = analysis.reasons.len(),
-                "Ghost indicator detected"
-            );
-        }
+        apply_ghost_analysis(job, &ghost_detector, database).await;
     }
 
     let ghost_count = scored_jobs
@@ -198,7 +168,71 @@ pub(crate) async fn score_jobs(
     scored_jobs
 }
 
-fn serialize_score_reasons(job_hash: &str, reasons: &[String]) -> String {
+/// Run ghost detection on one job and store the result on it
+pub(crate) async fn apply_ghost_analysis(
+    job: &mut Job,
+    ghost_detector: &GhostDetector,
+    database: &Database,
+) {
+    // Get repost count from database (if job was seen before)
+    let exact_reposts = database
+        .get_repost_count(&job.company, &job.title, &job.source)
+        .await
+        .unwrap_or(0);
+    // Reworded reposts get a new hash, so also count the job's repost chain.
+    let chained_reposts = match database.link_repost_chain(job).await {
+        Ok(count) => count,
+        Err(e) => {
+            tracing::debug!(
+                job_hash = %job.hash,
+                error_kind = database_error_kind(&e),
+                "Failed to link repost chain"
+            );
+            0
+        }
+    };
+    let repost_count = exact_reposts.max(chained_reposts);
+
+    // Get count of open jobs from this company
+    let company_open_jobs = database
+        .count_company_open_jobs(&job.company)
+        .await
+        .unwrap_or(0);
+
+    // Analyze for ghost indicators (using ML-enhanced analysis v2.5.5)
+    let analysis = ghost_detector.analyze_enhanced(
+        &job.title,
+        job.description.as_deref(),
+        job.salary_min,
+        job.salary_max,
+        job.location.as_deref(),
+        job.remote,
+        job.created_at,
+        repost_count,
+        company_open_jobs,
+    );
+
+    // Update job with ghost analysis results
+    job.ghost_score = Some(analysis.score);
+    job.ghost_reasons = if analysis.reasons.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&analysis.reasons).unwrap_or_default())
+    };
+    job.repost_count = repost_count;
+
+    if analysis.score >= 0.5 {
+        tracing::debug!(
+            title_chars = job.title.chars().count(),
+            company_chars = job.company.chars().count(),
+            score = analysis.score,
+            reason_count = analysis.reasons.len(),
+            "Ghost indicator detected"
+        );
+    }
+}
+
+pub(crate) fn serialize_score_reasons(job_hash: &str, reasons: &[String]) -> String {
     serde_json::to_string(reasons).unwrap_or_else(|_e| {
         tracing::warn!(
             job_hash_len = job_hash.len(),
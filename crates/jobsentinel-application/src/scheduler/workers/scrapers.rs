@@ -1,21 +1,32 @@
 //! Scraper execution logic
 //!
-//! Runs all configured scrapers and collects jobs
+//! Runs configured scrapers concurrently and hands each source's jobs to the
+//! pipeline as it finishes
 
 mod browser_sources;
+mod career_pages;
+mod company_boards;
+mod context;
+mod dispatch;
 mod federal;
 mod jobswithgpt_worker;
+mod quota;
 
-use crate::{config::Config, credentials::CredentialService};
-use jobsentinel_domain::Job;
-use jobsentinel_sources::{
-    parse_greenhouse_company_url, parse_lever_company_url, BuiltInScraper, DiceScraper,
-    GreenhouseCompany, GreenhouseScraper, HnHiringScraper, JobScraper, LeverCompany, LeverScraper,
-    RemoteOkScraper, ScraperError, WeWorkRemotelyScraper, YcStartupScraper,
-    LINKEDIN_AUTOMATION_DISABLED_MESSAGE,
+use crate::{
+    config::{Config, SCHEDULED_SOURCE_IDS},
+    credentials::CredentialService,
+    scheduler::SourceThrottleEvent,
 };
+use futures::stream::{self, StreamExt};
+use jobsentinel_domain::Job;
+use jobsentinel_network::{with_rate_limits, with_source_proxy};
+use jobsentinel_sources::{JobScraper, ScraperError, LINKEDIN_AUTOMATION_DISABLED_MESSAGE};
 use jobsentinel_storage::Database;
 use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+use context::{SourceRoute, SourceRunContext};
+pub(crate) use quota::SourceOverflow;
 
 fn scraper_failure_kind(error: &ScraperError) -> &'static str {
     match error {
@@ -32,6 +43,42 @@ fn scraper_failure_kind(error: &ScraperError) -> &'static str {
     }
 }
 
+/// Short, user-facing reason for a failed scraper run, such as
+/// "403 Forbidden". Never includes URLs or response text.
+fn scraper_failure_reason(error: &ScraperError) -> String {
+    match error {
+        ScraperError::HttpStatus { status, .. } => http_status_reason(*status),
+        ScraperError::Network => "network error".to_string(),
+        ScraperError::ParseError { .. } => "unreadable response".to_string(),
+        ScraperError::SelectorNotFound { .. } | ScraperError::MissingField { .. } => {
+            "page layout changed".to_string()
+        }
+        ScraperError::InvalidUrl { .. } => "invalid URL".to_string(),
+        ScraperError::BotProtection { .. } => "blocked by bot protection".to_string(),
+        ScraperError::Timeout { .. } => "timed out".to_string(),
+        ScraperError::InvalidConfiguration { .. } => "invalid configuration".to_string(),
+        ScraperError::Generic { .. } => "unexpected error".to_string(),
+    }
+}
+
+fn http_status_reason(status: u16) -> String {
+    let reason = match status {
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        408 => "Request Timeout",
+        410 => "Gone",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => return format!("HTTP {status}"),
+    };
+    format!("{status} {reason}")
+}
+
 fn source_failure_message(source_label: &'static str, failure_kind: &'static str) -> String {
     format!("{source_label} source check failed ({failure_kind})")
 }
@@ -43,18 +90,35 @@ pub(super) enum ScraperRunOutcome {
     Failure,
 }
 
-pub(super) async fn run_scraper<S: JobScraper + ?Sized>(
-    db: &Arc<Database>,
+async fn run_scraper<S: JobScraper + ?Sized>(
+    ctx: &SourceRunContext,
     scraper: &S,
     source_id: &'static str,
     source_label: &'static str,
     all_jobs: &mut Vec<Job>,
     errors: &mut Vec<String>,
 ) -> ScraperRunOutcome {
+    let proxy = match ctx.route_for(source_id) {
+        SourceRoute::Direct => None,
+        SourceRoute::Proxy(proxy) => Some(proxy),
+        SourceRoute::ProxyUnavailable => {
+            ctx.record_failure(source_id, source_label, "proxy unavailable".to_string());
+            record_source_proxy_unavailable(errors, source_label);
+            return ScraperRunOutcome::Failure;
+        }
+    };
+    let db = &ctx.db;
     let run_id = crate::health::start_run(db, source_id).await.unwrap_or(0);
     let started_at = std::time::Instant::now();
 
-    match scraper.scrape().await {
+    let (result, throttle_events) = with_rate_limits(
+        Arc::clone(&ctx.rate_limits),
+        with_source_proxy(proxy, scraper.scrape()),
+    )
+    .await;
+    ctx.record_throttling(source_id, throttle_events);
+
+    match result {
         Ok(jobs) => {
             let jobs_found = jobs.len();
             let _ = crate::health::complete_run(
@@ -65,12 +129,15 @@ pub(super) async fn run_scraper<S: JobScraper + ?Sized>(
                 0,
             )
             .await;
+            let admitted = ctx.admit(source_id, jobs).await;
             tracing::info!(
                 source = source_label,
                 jobs_found,
+                jobs_carried_in = admitted.carried_in,
+                jobs_deferred = admitted.deferred,
                 "Scraper source check completed"
             );
-            all_jobs.extend(jobs);
+            all_jobs.extend(admitted.jobs);
             ScraperRunOutcome::Success { jobs_found }
         }
         Err(error) => {
@@ -79,6 +146,7 @@ pub(super) async fn run_scraper<S: JobScraper + ?Sized>(
             } else {
                 ScraperRunOutcome::Failure
             };
+            ctx.record_failure(source_id, source_label, scraper_failure_reason(&error));
             record_scraper_failure(
                 db,
                 run_id,
@@ -130,6 +198,16 @@ fn record_source_credential_failure(errors: &mut Vec<String>, source_label: &'st
     errors.push(source_failure_message(source_label, failure_kind));
 }
 
+fn record_source_proxy_unavailable(errors: &mut Vec<String>, source_label: &'static str) {
+    let failure_kind = "proxy_unavailable";
+    tracing::error!(
+        source = source_label,
+        failure_kind,
+        "Source check skipped because the configured proxy is unavailable"
+    );
+    errors.push(source_failure_message(source_label, failure_kind));
+}
+
 fn restricted_source_acknowledged(config: &Config, source_id: &str) -> bool {
     match source_id {
         "builtin" => config.restricted_source_acknowledgements.builtin,
@@ -160,196 +238,145 @@ fn record_restricted_source_acknowledgement_missing(
     ));
 }
 
-/// Run all configured scrapers and return jobs and errors
+/// Jobs and errors from one finished source check
+#[derive(Debug)]
+pub(crate) struct SourceBatch {
+    pub(crate) source_id: &'static str,
+    pub(crate) jobs: Vec<Job>,
+    pub(crate) errors: Vec<String>,
+    /// The latest failed scraper run, if any failed
+    pub(crate) failure: Option<SourceFailureReason>,
+}
+
+/// Why a scraper run failed, safe to show the user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceFailureReason {
+    pub(crate) source_label: &'static str,
+    /// Short reason such as "403 Forbidden"
+    pub(crate) reason: String,
+}
+
+/// Cycle-level errors and throttling left after every batch was handed off
+#[derive(Debug, Default)]
+pub(crate) struct ScraperCycleOutput {
+    pub(crate) errors: Vec<String>,
+    pub(crate) throttle_events: Vec<SourceThrottleEvent>,
+}
+
+/// Source checks a cycle with `config` will run, in built-in order.
+pub(crate) fn enabled_sources(config: &Config) -> Vec<&'static str> {
+    SCHEDULED_SOURCE_IDS
+        .iter()
+        .copied()
+        .filter(|source_id| dispatch::source_enabled(config, source_id))
+        .collect()
+}
+
+/// Run the enabled scrapers among `source_ids`, sending each source's result
+/// to `batches` as soon as it finishes
+///
+/// Up to `config.scraping_concurrency` sources run at once. They start in
+/// descending configured priority. Results past a source quota are held in
+/// `overflow` and offered first on the next cycle.
 #[tracing::instrument(skip_all)]
 pub(crate) async fn run_scrapers(
     config: &Arc<Config>,
     db: &Arc<Database>,
     credentials: &CredentialService,
-) -> (Vec<Job>, Vec<String>) {
-    tracing::info!("Starting scraper execution across all enabled sources");
-    let mut all_jobs = Vec::new();
+    source_ids: &[&'static str],
+    overflow: Arc<Mutex<SourceOverflow>>,
+    batches: mpsc::Sender<SourceBatch>,
+) -> ScraperCycleOutput {
+    let concurrency = config.scraping_concurrency.max(1);
+    tracing::info!(
+        concurrency,
+        source_count = source_ids.len(),
+        "Starting scraper execution across enabled sources"
+    );
     let mut errors = Vec::new();
+    let ctx = SourceRunContext::new(Arc::clone(db))
+        .with_proxy(config, credentials)
+        .await
+        .with_limits(config, overflow);
 
-    // 1. Greenhouse scraper - use URLs from config
-    if !config.greenhouse_urls.is_empty() {
-        let greenhouse_companies: Vec<GreenhouseCompany> = config
-            .greenhouse_urls
-            .iter()
-            .filter_map(|url| {
-                parse_greenhouse_company_url(url)
-                    .ok()
-                    .map(|board| GreenhouseCompany {
-                        id: board.id.clone(),
-                        name: board.id,
-                        url: board.url,
-                    })
-            })
-            .collect();
-
-        if !greenhouse_companies.is_empty() {
-            let greenhouse = GreenhouseScraper::new(greenhouse_companies);
-            run_scraper(
-                db,
-                &greenhouse,
-                "greenhouse",
-                "Greenhouse",
-                &mut all_jobs,
-                &mut errors,
-            )
-            .await;
-        }
-    }
-
-    // 2. Lever scraper - use URLs from config
-    if !config.lever_urls.is_empty() {
-        let lever_companies: Vec<LeverCompany> = config
-            .lever_urls
-            .iter()
-            .filter_map(|url| {
-                parse_lever_company_url(url).ok().map(|board| LeverCompany {
-                    id: board.id.clone(),
-                    name: board.id,
-                    url: board.url,
-                })
-            })
-            .collect();
-
-        if !lever_companies.is_empty() {
-            let lever = LeverScraper::new(lever_companies);
-            run_scraper(db, &lever, "lever", "Lever", &mut all_jobs, &mut errors).await;
-        }
-    }
-
-    jobswithgpt_worker::run_jobswithgpt_scraper(config.as_ref(), db, &mut all_jobs, &mut errors)
-        .await;
-
-    // 4. LinkedIn stays user-directed. Warn without running hidden monitoring.
+    // LinkedIn stays user-directed. Warn without running hidden monitoring.
     if config.linkedin.enabled {
         tracing::warn!("{}", LINKEDIN_AUTOMATION_DISABLED_MESSAGE);
         errors.push(LINKEDIN_AUTOMATION_DISABLED_MESSAGE.to_string());
     }
 
-    // 5. RemoteOK scraper - public JSON API
-    if config.remoteok.enabled {
-        tracing::info!("Running RemoteOK scraper");
-        let remoteok = RemoteOkScraper::new(config.remoteok.tags.clone(), config.remoteok.limit);
-        run_scraper(
-            db,
-            &remoteok,
-            "remoteok",
-            "RemoteOK",
-            &mut all_jobs,
-            &mut errors,
-        )
-        .await;
-    }
-
-    // 6. WeWorkRemotely scraper - RSS feed
-    if config.weworkremotely.enabled {
-        tracing::info!("Running WeWorkRemotely scraper");
-        let weworkremotely = WeWorkRemotelyScraper::new(
-            config.weworkremotely.category.clone(),
-            config.weworkremotely.limit,
-        );
-        run_scraper(
-            db,
-            &weworkremotely,
-            "weworkremotely",
-            "WeWorkRemotely",
-            &mut all_jobs,
-            &mut errors,
-        )
-        .await;
-    }
-
-    // 7. BuiltIn scraper - tech job board
-    if config.builtin.enabled {
-        if !restricted_source_acknowledged(config, "builtin") {
-            record_restricted_source_acknowledgement_missing(&mut errors, "builtin", "BuiltIn");
-        } else {
-            let mode = if config.builtin.remote_only {
-                "remote"
-            } else {
-                "all"
-            };
-            tracing::info!("Running BuiltIn scraper ({})", mode);
-            let builtin = BuiltInScraper::new(config.builtin.remote_only, config.builtin.limit);
-            run_scraper(
-                db,
-                &builtin,
-                "builtin",
-                "BuiltIn",
-                &mut all_jobs,
-                &mut errors,
-            )
-            .await;
+    // Futures are built up front (they do nothing until polled) so the stream
+    // holds no closure, which keeps the cycle future `Send`.
+    let checks: Vec<_> = ctx
+        .source_order(source_ids)
+        .into_iter()
+        .filter(|source_id| dispatch::source_enabled(config, source_id))
+        .map(|source_id| check_source(source_id, config, &ctx, credentials))
+        .collect();
+    let mut checks = stream::iter(checks).buffer_unordered(concurrency);
+
+    let (mut job_count, mut error_count) = (0, 0);
+    while let Some(batch) = checks.next().await {
+        job_count += batch.jobs.len();
+        error_count += batch.errors.len();
+        if batches.send(batch).await.is_err() {
+            tracing::warn!("Scraper results receiver closed; stopping source checks");
+            break;
         }
     }
-
-    // 8. Hacker News Who's Hiring scraper
-    if config.hn_hiring.enabled {
-        tracing::info!("Running HN Who's Hiring scraper");
-        let hn_hiring = HnHiringScraper::new(config.hn_hiring.limit, config.hn_hiring.remote_only);
-        run_scraper(
-            db,
-            &hn_hiring,
-            "hn_hiring",
-            "HN Who's Hiring",
-            &mut all_jobs,
-            &mut errors,
-        )
-        .await;
-    }
-
-    // 9. Dice scraper - tech job board
-    if config.dice.enabled && !config.dice.query.is_empty() {
-        if !restricted_source_acknowledged(config, "dice") {
-            record_restricted_source_acknowledgement_missing(&mut errors, "dice", "Dice");
-        } else {
-            tracing::info!("Running Dice scraper");
-            let dice = DiceScraper::new(
-                config.dice.query.clone(),
-                config.dice.location.clone(),
-                config.dice.limit,
-            );
-            run_scraper(db, &dice, "dice", "Dice", &mut all_jobs, &mut errors).await;
-        }
-    }
-
-    // 10. Y Combinator Work at a Startup scraper
-    if config.yc_startup.enabled {
-        tracing::info!("Running YC Startup scraper");
-        let yc_startup = YcStartupScraper::new(
-            config.yc_startup.query.clone(),
-            config.yc_startup.remote_only,
-            config.yc_startup.limit,
-        );
-        run_scraper(
-            db,
-            &yc_startup,
-            "yc_startup",
-            "YC Startup",
-            &mut all_jobs,
-            &mut errors,
-        )
-        .await;
-    }
-
-    federal::run_usajobs(config, db, credentials, &mut all_jobs, &mut errors).await;
-    browser_sources::run_restricted_browser_sources(config, db, &mut all_jobs, &mut errors).await;
+    drop(checks);
 
     tracing::info!(
-        "Scraper execution complete: {} total jobs, {} errors",
-        all_jobs.len(),
-        errors.len()
+        job_count,
+        error_count = error_count + errors.len(),
+        "Scraper execution complete"
     );
 
-    if !errors.is_empty() {
-        tracing::warn!("Scraping errors encountered: {:?}", errors);
+    ScraperCycleOutput {
+        errors,
+        throttle_events: ctx.into_throttle_events(),
     }
+}
 
-    (all_jobs, errors)
+/// Check one source right away with `config`, outside any scrape cycle.
+///
+/// The jobs are returned, not saved.
+pub(crate) async fn check_single_source(
+    source_id: &'static str,
+    config: &Arc<Config>,
+    db: &Arc<Database>,
+    credentials: &CredentialService,
+) -> SourceBatch {
+    let ctx = SourceRunContext::new(Arc::clone(db))
+        .with_proxy(config, credentials)
+        .await
+        .with_limits(config, Arc::new(Mutex::new(SourceOverflow::default())));
+    check_source(source_id, config, &ctx, credentials).await
+}
+
+async fn check_source(
+    source_id: &'static str,
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    credentials: &CredentialService,
+) -> SourceBatch {
+    let mut batch = SourceBatch {
+        source_id,
+        jobs: Vec::new(),
+        errors: Vec::new(),
+        failure: None,
+    };
+    dispatch::run_source(
+        source_id,
+        config,
+        ctx,
+        credentials,
+        &mut batch.jobs,
+        &mut batch.errors,
+    )
+    .await;
+    batch.failure = ctx.take_failure(source_id);
+    batch
 }
 
 #[cfg(test)]
@@ -3,20 +3,19 @@ use std::sync::Arc;
 use crate::config::Config;
 use jobsentinel_domain::Job;
 use jobsentinel_sources::{GlassdoorScraper, SimplyHiredScraper};
-use jobsentinel_storage::Database;
 
 use super::{
     record_restricted_source_acknowledgement_missing, restricted_source_acknowledged, run_scraper,
-    ScraperRunOutcome,
+    ScraperRunOutcome, SourceRunContext,
 };
 
-pub(super) async fn run_restricted_browser_sources(
+/// SimplyHired job aggregator (v2.5.5) - may be blocked by Cloudflare
+pub(super) async fn run_simplyhired(
     config: &Arc<Config>,
-    db: &Arc<Database>,
+    ctx: &SourceRunContext,
     all_jobs: &mut Vec<Job>,
     errors: &mut Vec<String>,
 ) {
-    // 12. SimplyHired job aggregator (v2.5.5) - may be blocked by Cloudflare
     if config.simplyhired.enabled && !config.simplyhired.query.is_empty() {
         if !restricted_source_acknowledged(config, "simplyhired") {
             record_restricted_source_acknowledgement_missing(errors, "simplyhired", "SimplyHired");
@@ -30,7 +29,7 @@ pub(super) async fn run_restricted_browser_sources(
 
             if matches!(
                 run_scraper(
-                    db,
+                    ctx,
                     &simplyhired,
                     "simplyhired",
                     "SimplyHired",
@@ -44,8 +43,15 @@ pub(super) async fn run_restricted_browser_sources(
             }
         }
     }
+}
 
-    // 13. Glassdoor job board (v2.5.5) - may be blocked by Cloudflare
+/// Glassdoor job board (v2.5.5) - may be blocked by Cloudflare
+pub(super) async fn run_glassdoor(
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    all_jobs: &mut Vec<Job>,
+    errors: &mut Vec<String>,
+) {
     if config.glassdoor.enabled && !config.glassdoor.query.is_empty() {
         if !restricted_source_acknowledged(config, "glassdoor") {
             record_restricted_source_acknowledgement_missing(errors, "glassdoor", "Glassdoor");
@@ -58,7 +64,7 @@ pub(super) async fn run_restricted_browser_sources(
             );
 
             if matches!(
-                run_scraper(db, &glassdoor, "glassdoor", "Glassdoor", all_jobs, errors,).await,
+                run_scraper(ctx, &glassdoor, "glassdoor", "Glassdoor", all_jobs, errors,).await,
                 ScraperRunOutcome::Success { jobs_found: 0 }
             ) {
                 tracing::warn!("Glassdoor: 0 jobs (may be Cloudflare blocked)");
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use jobsentinel_domain::Job;
+use jobsentinel_sources::{CareerPage, CareerPageScraper, PageRenderer, ScraperError};
+
+use crate::{automation::BrowserManager, config::Config};
+
+use super::{run_scraper, SourceRunContext};
+
+/// Jobs kept from one career page per check.
+const CAREER_PAGE_JOB_LIMIT: usize = 100;
+/// Time client-side scripts get to draw the job list after the page loads.
+const RENDER_SETTLE_TIME: Duration = Duration::from_secs(3);
+/// Upper bound for loading and reading one rendered page.
+const RENDER_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// Headless Chrome renderer for career pages flagged with `render`.
+///
+/// The browser starts on the first rendered page and is closed when the
+/// source check finishes.
+struct HeadlessPageRenderer {
+    browser: BrowserManager,
+}
+
+#[async_trait]
+impl PageRenderer for HeadlessPageRenderer {
+    async fn render_html(&self, url: &str) -> Result<String, ScraperError> {
+        tokio::time::timeout(
+            RENDER_TIMEOUT,
+            self.browser.render_html(url, RENDER_SETTLE_TIME),
+        )
+        .await
+        .map_err(|_| ScraperError::Timeout {
+            timeout_secs: RENDER_TIMEOUT.as_secs(),
+        })?
+        .map_err(|error| ScraperError::from_anyhow("career_pages", error))
+    }
+}
+
+/// Company career pages - rendered pages go through headless Chrome
+pub(super) async fn run_career_pages(
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    all_jobs: &mut Vec<Job>,
+    errors: &mut Vec<String>,
+) {
+    let pages: Vec<CareerPage> = config
+        .career_pages
+        .iter()
+        .map(|page| CareerPage {
+            url: page.url.clone(),
+            company: page.company.clone(),
+            render: page.render,
+        })
+        .collect();
+    if pages.is_empty() {
+        return;
+    }
+
+    let renderer = pages.iter().any(|page| page.render).then(|| {
+        Arc::new(HeadlessPageRenderer {
+            browser: BrowserManager::headless(),
+        })
+    });
+    let mut scraper = CareerPageScraper::new(pages, CAREER_PAGE_JOB_LIMIT);
+    if let Some(renderer) = &renderer {
+        scraper = scraper.with_renderer(Arc::clone(renderer) as Arc<dyn PageRenderer>);
+    }
+
+    tracing::info!("Running career page scraper");
+    run_scraper(
+        ctx,
+        &scraper,
+        "career_pages",
+        "Career pages",
+        all_jobs,
+        errors,
+    )
+    .await;
+
+    if let Some(renderer) = renderer {
+        let _ = renderer.browser.close().await;
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::config::Config;
+use jobsentinel_domain::Job;
+use jobsentinel_sources::{
+    parse_greenhouse_company_url, parse_lever_company_url, GreenhouseCompany, GreenhouseScraper,
+    LeverCompany, LeverScraper,
+};
+
+use super::{run_scraper, SourceRunContext};
+
+/// Greenhouse scraper - use URLs from config
+pub(super) async fn run_greenhouse(
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    all_jobs: &mut Vec<Job>,
+    errors: &mut Vec<String>,
+) {
+    let greenhouse_companies: Vec<GreenhouseCompany> = config
+        .greenhouse_urls
+        .iter()
+        .filter_map(|url| {
+            parse_greenhouse_company_url(url)
+                .ok()
+                .map(|board| GreenhouseCompany {
+                    id: board.id.clone(),
+                    name: board.id,
+                    url: board.url,
+                })
+        })
+        .collect();
+
+    if !greenhouse_companies.is_empty() {
+        let greenhouse = GreenhouseScraper::new(greenhouse_companies);
+        run_scraper(
+            ctx,
+            &greenhouse,
+            "greenhouse",
+            "Greenhouse",
+            all_jobs,
+            errors,
+        )
+        .await;
+    }
+}
+
+/// Lever scraper - use URLs from config
+pub(super) async fn run_lever(
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    all_jobs: &mut Vec<Job>,
+    errors: &mut Vec<String>,
+) {
+    let lever_companies: Vec<LeverCompany> = config
+        .lever_urls
+        .iter()
+        .filter_map(|url| {
+            parse_lever_company_url(url).ok().map(|board| LeverCompany {
+                id: board.id.clone(),
+                name: board.id,
+                url: board.url,
+            })
+        })
+        .collect();
+
+    if !lever_companies.is_empty() {
+        let lever = LeverScraper::new(lever_companies);
+        run_scraper(ctx, &lever, "lever", "Lever", all_jobs, errors).await;
+    }
+}
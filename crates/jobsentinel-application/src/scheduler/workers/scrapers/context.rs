@@ -0,0 +1,195 @@
+//! Per-cycle state shared by every source check.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use crate::config::{Config, ScraperProxyConfig, SourceLimitsConfig, SourceRateLimitConfig};
+use crate::credentials::{CredentialKey, CredentialService};
+use crate::scheduler::SourceThrottleEvent;
+use jobsentinel_domain::Job;
+use jobsentinel_network::{DomainRateLimits, OutboundProxy, ThrottleEvent, ThrottleReason};
+use jobsentinel_storage::Database;
+use tokio::sync::Mutex;
+
+use super::quota::{AdmittedJobs, SourceOverflow};
+use super::SourceFailureReason;
+
+/// How a source check reaches the network.
+#[derive(Debug, Clone)]
+pub(super) enum SourceRoute {
+    Direct,
+    Proxy(OutboundProxy),
+    /// The user asked for a proxy that could not be prepared. The source is
+    /// skipped instead of silently falling back to a direct connection.
+    ProxyUnavailable,
+}
+
+/// Shared state for one scraping cycle.
+pub(super) struct SourceRunContext {
+    pub(super) db: Arc<Database>,
+    proxy_config: ScraperProxyConfig,
+    proxy: Option<OutboundProxy>,
+    limits: SourceLimitsConfig,
+    overflow: Arc<Mutex<SourceOverflow>>,
+    pub(super) rate_limits: Arc<DomainRateLimits>,
+    throttle_events: StdMutex<Vec<SourceThrottleEvent>>,
+    failures: StdMutex<HashMap<&'static str, SourceFailureReason>>,
+}
+
+impl SourceRunContext {
+    pub(super) fn new(db: Arc<Database>) -> Self {
+        Self {
+            db,
+            proxy_config: ScraperProxyConfig::default(),
+            proxy: None,
+            limits: SourceLimitsConfig::default(),
+            overflow: Arc::default(),
+            rate_limits: Arc::new(domain_rate_limits(&SourceRateLimitConfig::default())),
+            throttle_events: StdMutex::default(),
+            failures: StdMutex::default(),
+        }
+    }
+
+    /// Apply per-source priorities and quotas, carrying overflow in `overflow`.
+    pub(super) fn with_limits(
+        mut self,
+        config: &Config,
+        overflow: Arc<Mutex<SourceOverflow>>,
+    ) -> Self {
+        self.limits = config.source_limits.clone();
+        self.overflow = overflow;
+        self.rate_limits = Arc::new(domain_rate_limits(&config.rate_limits));
+        self
+    }
+
+    /// Prepare the configured proxy, loading its password from the credential
+    /// store when a username is set.
+    pub(super) async fn with_proxy(
+        mut self,
+        config: &Config,
+        credentials: &CredentialService,
+    ) -> Self {
+        self.proxy_config = config.proxy.clone();
+        if !self.proxy_config.enabled {
+            return self;
+        }
+
+        self.proxy = match resolve_proxy(&self.proxy_config, credentials).await {
+            Ok(proxy) => Some(proxy),
+            Err(failure_kind) => {
+                tracing::error!(
+                    failure_kind,
+                    "Scraping proxy unavailable; proxied sources will be skipped"
+                );
+                None
+            }
+        };
+        self
+    }
+
+    pub(super) fn route_for(&self, source_id: &str) -> SourceRoute {
+        if !self.proxy_config.applies_to(source_id) {
+            return SourceRoute::Direct;
+        }
+        self.proxy
+            .clone()
+            .map_or(SourceRoute::ProxyUnavailable, SourceRoute::Proxy)
+    }
+
+    /// Scheduled source ids in the order this cycle should check them.
+    pub(super) fn source_order(&self, source_ids: &[&'static str]) -> Vec<&'static str> {
+        self.limits.ordered(source_ids)
+    }
+
+    /// Apply the source quota to a fresh result.
+    pub(super) async fn admit(&self, source_id: &str, jobs: Vec<Job>) -> AdmittedJobs {
+        self.overflow
+            .lock()
+            .await
+            .admit(source_id, self.limits.quota(source_id), jobs)
+    }
+
+    /// Keep throttling events from one source check for the cycle result.
+    pub(super) fn record_throttling(&self, source_id: &str, events: Vec<ThrottleEvent>) {
+        if events.is_empty() {
+            return;
+        }
+        tracing::warn!(
+            source = source_id,
+            event_count = events.len(),
+            "Source check was slowed by rate limiting"
+        );
+        if let Ok(mut recorded) = self.throttle_events.lock() {
+            recorded.extend(events.into_iter().map(|event| SourceThrottleEvent {
+                source: source_id.to_string(),
+                domain: event.domain,
+                reason: match event.reason {
+                    ThrottleReason::LocalLimit => "local_limit",
+                    ThrottleReason::RateLimited => "rate_limited",
+                    ThrottleReason::Forbidden => "forbidden",
+                },
+                delay_ms: event.delay_ms,
+            }));
+        }
+    }
+
+    /// Keep the reason a scraper run for `source_id` failed. A later failure
+    /// replaces it.
+    pub(super) fn record_failure(
+        &self,
+        source_id: &'static str,
+        source_label: &'static str,
+        reason: String,
+    ) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.insert(
+                source_id,
+                SourceFailureReason {
+                    source_label,
+                    reason,
+                },
+            );
+        }
+    }
+
+    /// The latest failure reason recorded for `source_id`, if any.
+    pub(super) fn take_failure(&self, source_id: &str) -> Option<SourceFailureReason> {
+        self.failures
+            .lock()
+            .ok()
+            .and_then(|mut failures| failures.remove(source_id))
+    }
+
+    pub(super) fn into_throttle_events(self) -> Vec<SourceThrottleEvent> {
+        self.throttle_events.into_inner().unwrap_or_default()
+    }
+}
+
+fn domain_rate_limits(config: &SourceRateLimitConfig) -> DomainRateLimits {
+    config.domains.iter().fold(
+        DomainRateLimits::new(
+            config.global_requests_per_minute,
+            config.domain_requests_per_minute,
+        ),
+        |limits, (domain, per_minute)| limits.with_domain(domain, *per_minute),
+    )
+}
+
+async fn resolve_proxy(
+    proxy_config: &ScraperProxyConfig,
+    credentials: &CredentialService,
+) -> Result<OutboundProxy, &'static str> {
+    let proxy = OutboundProxy::parse(&proxy_config.url).map_err(|_| "invalid_proxy_url")?;
+    let Some(username) = proxy_config.username() else {
+        return Ok(proxy);
+    };
+
+    match credentials
+        .retrieve(CredentialKey::ScraperProxyPassword)
+        .await
+    {
+        Ok(Some(password)) => Ok(proxy.with_credentials(username, password)),
+        Ok(None) => Ok(proxy.with_credentials(username, "")),
+        Err(_) => Err("credential_unavailable"),
+    }
+}
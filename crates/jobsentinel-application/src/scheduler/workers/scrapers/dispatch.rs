@@ -0,0 +1,194 @@
+//! Maps scheduled source ids to their scrapers.
+
+use std::sync::Arc;
+
+use crate::{config::Config, credentials::CredentialService};
+use jobsentinel_domain::Job;
+use jobsentinel_sources::{
+    ArbeitnowScraper, BuiltInScraper, DiceScraper, GuardianJobsScraper, HnHiringScraper,
+    RemoteOkScraper, WeWorkRemotelyScraper, YcStartupScraper,
+};
+
+use super::{
+    browser_sources, career_pages, company_boards, federal, jobswithgpt_worker,
+    record_restricted_source_acknowledgement_missing, restricted_source_acknowledged, run_scraper,
+    SourceRunContext,
+};
+
+/// Jobs read from each country board per check.
+const COUNTRY_BOARD_LIMIT: usize = 100;
+
+/// Whether a scheduled source is switched on and configured enough to check.
+///
+/// Mirrors the guards in [`run_source`] so a cycle knows up front how many
+/// source checks it will report.
+pub(super) fn source_enabled(config: &Config, source_id: &str) -> bool {
+    match source_id {
+        "greenhouse" => !config.greenhouse_urls.is_empty(),
+        "lever" => !config.lever_urls.is_empty(),
+        "career_pages" => !config.career_pages.is_empty(),
+        "jobswithgpt" => config.jobswithgpt_payload_preview().is_some(),
+        "remoteok" => config.remoteok.enabled,
+        "weworkremotely" => config.weworkremotely.enabled,
+        "builtin" => config.builtin.enabled,
+        "hn_hiring" => config.hn_hiring.enabled,
+        "dice" => config.dice.enabled && !config.dice.query.is_empty(),
+        "yc_startup" => config.yc_startup.enabled,
+        "usajobs" => config.usajobs.enabled && !config.usajobs.email.is_empty(),
+        "simplyhired" => config.simplyhired.enabled && !config.simplyhired.query.is_empty(),
+        "glassdoor" => config.glassdoor.enabled && !config.glassdoor.query.is_empty(),
+        "arbeitnow" => config
+            .location_preferences
+            .country_board_enabled("arbeitnow"),
+        "guardian_jobs" => config
+            .location_preferences
+            .country_board_enabled("guardian_jobs"),
+        _ => false,
+    }
+}
+
+/// Run one scheduled source if it is enabled and configured.
+pub(super) async fn run_source(
+    source_id: &'static str,
+    config: &Arc<Config>,
+    ctx: &SourceRunContext,
+    credentials: &CredentialService,
+    all_jobs: &mut Vec<Job>,
+    errors: &mut Vec<String>,
+) {
+    match source_id {
+        "greenhouse" => company_boards::run_greenhouse(config, ctx, all_jobs, errors).await,
+        "lever" => company_boards::run_lever(config, ctx, all_jobs, errors).await,
+        "career_pages" => career_pages::run_career_pages(config, ctx, all_jobs, errors).await,
+        "jobswithgpt" => {
+            jobswithgpt_worker::run_jobswithgpt_scraper(config.as_ref(), ctx, all_jobs, errors)
+                .await;
+        }
+        // RemoteOK - public JSON API
+        "remoteok" if config.remoteok.enabled => {
+            tracing::info!("Running RemoteOK scraper");
+            let remoteok =
+                RemoteOkScraper::new(config.remoteok.tags.clone(), config.remoteok.limit);
+            run_scraper(ctx, &remoteok, "remoteok", "RemoteOK", all_jobs, errors).await;
+        }
+        // WeWorkRemotely - RSS feed
+        "weworkremotely" if config.weworkremotely.enabled => {
+            tracing::info!("Running WeWorkRemotely scraper");
+            let weworkremotely = WeWorkRemotelyScraper::new(
+                config.weworkremotely.category.clone(),
+                config.weworkremotely.limit,
+            );
+            run_scraper(
+                ctx,
+                &weworkremotely,
+                "weworkremotely",
+                "WeWorkRemotely",
+                all_jobs,
+                errors,
+            )
+            .await;
+        }
+        // BuiltIn - tech job board
+        "builtin" if config.builtin.enabled => {
+            if !restricted_source_acknowledged(config, "builtin") {
+                record_restricted_source_acknowledgement_missing(errors, "builtin", "BuiltIn");
+                return;
+            }
+            let mode = if config.builtin.remote_only {
+                "remote"
+            } else {
+                "all"
+            };
+            tracing::info!(
+                cities = config.builtin.cities.len(),
+                "Running BuiltIn scraper ({})",
+                mode
+            );
+            let builtin = BuiltInScraper::new(config.builtin.remote_only, config.builtin.limit)
+                .with_search(
+                    Some(config.builtin.query.clone()),
+                    config.builtin.cities.clone(),
+                );
+            run_scraper(ctx, &builtin, "builtin", "BuiltIn", all_jobs, errors).await;
+        }
+        // Hacker News Who's Hiring
+        "hn_hiring" if config.hn_hiring.enabled => {
+            tracing::info!("Running HN Who's Hiring scraper");
+            let hn_hiring =
+                HnHiringScraper::new(config.hn_hiring.limit, config.hn_hiring.remote_only);
+            run_scraper(
+                ctx,
+                &hn_hiring,
+                "hn_hiring",
+                "HN Who's Hiring",
+                all_jobs,
+                errors,
+            )
+            .await;
+        }
+        // Dice - tech job board
+        "dice" if config.dice.enabled && !config.dice.query.is_empty() => {
+            if !restricted_source_acknowledged(config, "dice") {
+                record_restricted_source_acknowledgement_missing(errors, "dice", "Dice");
+                return;
+            }
+            tracing::info!("Running Dice scraper");
+            let dice = DiceScraper::new(
+                config.dice.query.clone(),
+                config.dice.location.clone(),
+                config.dice.limit,
+            );
+            run_scraper(ctx, &dice, "dice", "Dice", all_jobs, errors).await;
+        }
+        // Y Combinator Work at a Startup
+        "yc_startup" if config.yc_startup.enabled => {
+            tracing::info!("Running YC Startup scraper");
+            let yc_startup = YcStartupScraper::new(
+                config.yc_startup.query.clone(),
+                config.yc_startup.remote_only,
+                config.yc_startup.limit,
+            );
+            run_scraper(
+                ctx,
+                &yc_startup,
+                "yc_startup",
+                "YC Startup",
+                all_jobs,
+                errors,
+            )
+            .await;
+        }
+        "usajobs" => federal::run_usajobs(config, ctx, credentials, all_jobs, errors).await,
+        "simplyhired" => browser_sources::run_simplyhired(config, ctx, all_jobs, errors).await,
+        "glassdoor" => browser_sources::run_glassdoor(config, ctx, all_jobs, errors).await,
+        // Arbeitnow - country board for Germany, Austria, and Switzerland
+        "arbeitnow"
+            if config
+                .location_preferences
+                .country_board_enabled("arbeitnow") =>
+        {
+            tracing::info!("Running Arbeitnow scraper");
+            let arbeitnow = ArbeitnowScraper::new(COUNTRY_BOARD_LIMIT);
+            run_scraper(ctx, &arbeitnow, "arbeitnow", "Arbeitnow", all_jobs, errors).await;
+        }
+        // Guardian Jobs - country board for the United Kingdom
+        "guardian_jobs"
+            if config
+                .location_preferences
+                .country_board_enabled("guardian_jobs") =>
+        {
+            tracing::info!("Running Guardian Jobs scraper");
+            let guardian_jobs = GuardianJobsScraper::new(COUNTRY_BOARD_LIMIT);
+            run_scraper(
+                ctx,
+                &guardian_jobs,
+                "guardian_jobs",
+                "Guardian Jobs",
+                all_jobs,
+                errors,
+            )
+            .await;
+        }
+        _ => {}
+    }
+}
@@ -6,18 +6,17 @@ use crate::{
 };
 use jobsentinel_domain::Job;
 use jobsentinel_sources::UsaJobsScraper;
-use jobsentinel_storage::Database;
 
-use super::{record_source_credential_failure, run_scraper};
+use super::{record_source_credential_failure, run_scraper, SourceRunContext};
 
 pub(super) async fn run_usajobs(
     config: &Arc<Config>,
-    db: &Arc<Database>,
+    ctx: &SourceRunContext,
     credentials: &CredentialService,
     all_jobs: &mut Vec<Job>,
     errors: &mut Vec<String>,
 ) {
-    // 11. USAJobs federal government scraper - requires API key from keyring
+    // USAJobs federal government scraper - requires API key from keyring
     if config.usajobs.enabled && !config.usajobs.email.is_empty() {
         match credentials.retrieve(CredentialKey::UsaJobsApiKey).await {
             Ok(Some(api_key)) => {
@@ -43,7 +42,7 @@ pub(super) async fn run_usajobs(
                     .posted_within_days(config.usajobs.date_posted_days)
                     .with_limit(config.usajobs.limit);
 
-                run_scraper(db, &scraper, "usajobs", "USAJobs", all_jobs, errors).await;
+                run_scraper(ctx, &scraper, "usajobs", "USAJobs", all_jobs, errors).await;
             }
             Ok(None) => {
                 tracing::warn!("USAJobs enabled but API key not configured in keyring");
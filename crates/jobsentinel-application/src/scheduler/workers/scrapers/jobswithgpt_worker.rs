@@ -1,4 +1,4 @@
-use super::{run_scraper, ScraperRunOutcome};
+use super::{run_scraper, ScraperRunOutcome, SourceRunContext};
 use crate::{config::Config, health::SourceRequestOutcome};
 use jobsentinel_domain::Job;
 use jobsentinel_sources::{JobQuery, JobsWithGptScraper};
@@ -23,7 +23,7 @@ async fn finish_source_request_if_recorded(
 
 pub(super) async fn run_jobswithgpt_scraper(
     config: &Config,
-    db: &Arc<Database>,
+    ctx: &SourceRunContext,
     all_jobs: &mut Vec<Job>,
     errors: &mut Vec<String>,
 ) {
@@ -64,7 +64,7 @@ pub(super) async fn run_jobswithgpt_scraper(
 
     let endpoint_host = endpoint_host_for_source_request(&jobswithgpt_payload.endpoint);
     let source_request_id = match crate::health::record_source_request_started(
-        db,
+        &ctx.db,
         "jobswithgpt",
         endpoint_host.as_deref(),
         jobswithgpt_payload.titles.len(),
@@ -85,7 +85,7 @@ pub(super) async fn run_jobswithgpt_scraper(
     };
 
     let outcome = run_scraper(
-        db,
+        ctx,
         &jobswithgpt,
         "jobswithgpt",
         "JobsWithGPT",
@@ -98,5 +98,5 @@ pub(super) async fn run_jobswithgpt_scraper(
         ScraperRunOutcome::Timeout => SourceRequestOutcome::Timeout,
         ScraperRunOutcome::Failure => SourceRequestOutcome::Failure,
     };
-    finish_source_request_if_recorded(db, source_request_id, source_request_outcome).await;
+    finish_source_request_if_recorded(&ctx.db, source_request_id, source_request_outcome).await;
 }
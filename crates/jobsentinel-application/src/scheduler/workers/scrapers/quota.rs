@@ -0,0 +1,69 @@
+//! Per-source result quotas with overflow carried between cycles.
+
+use std::collections::{HashMap, HashSet};
+
+use jobsentinel_domain::Job;
+
+/// Upper bound on jobs held for one source, so a quota that is far below a
+/// source's volume cannot grow the backlog without limit.
+const MAX_CARRIED_JOBS_PER_SOURCE: usize = 1_000;
+
+/// Jobs that exceeded a source quota, held in memory for the next cycle.
+#[derive(Debug, Default)]
+pub(crate) struct SourceOverflow {
+    carried: HashMap<String, Vec<Job>>,
+}
+
+/// Jobs accepted for this cycle and how many were held back.
+#[derive(Debug)]
+pub(super) struct AdmittedJobs {
+    pub(super) jobs: Vec<Job>,
+    pub(super) carried_in: usize,
+    pub(super) deferred: usize,
+}
+
+impl SourceOverflow {
+    /// Merge previously held jobs with a fresh source result and apply the
+    /// quota. Held jobs go first so overflow drains before newer results.
+    pub(super) fn admit(
+        &mut self,
+        source_id: &str,
+        quota: Option<usize>,
+        fresh: Vec<Job>,
+    ) -> AdmittedJobs {
+        let carried = self.carried.remove(source_id).unwrap_or_default();
+        let carried_in = carried.len();
+
+        let mut seen = HashSet::new();
+        let mut jobs: Vec<Job> = carried
+            .into_iter()
+            .chain(fresh)
+            .filter(|job| seen.insert(job.hash.clone()))
+            .collect();
+
+        let Some(quota) = quota.filter(|quota| jobs.len() > *quota) else {
+            return AdmittedJobs {
+                jobs,
+                carried_in,
+                deferred: 0,
+            };
+        };
+
+        let mut overflow = jobs.split_off(quota);
+        let deferred = overflow.len();
+        overflow.truncate(MAX_CARRIED_JOBS_PER_SOURCE);
+        self.carried.insert(source_id.to_string(), overflow);
+
+        AdmittedJobs {
+            jobs,
+            carried_in,
+            deferred,
+        }
+    }
+
+    /// Number of jobs currently held for `source_id`.
+    #[cfg(test)]
+    pub(super) fn carried(&self, source_id: &str) -> usize {
+        self.carried.get(source_id).map_or(0, Vec::len)
+    }
+}
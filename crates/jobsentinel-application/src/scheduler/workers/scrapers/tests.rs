@@ -21,10 +21,10 @@ impl JobScraper for StubScraper {
     }
 }
 
-async fn test_database() -> Arc<Database> {
+async fn test_context() -> SourceRunContext {
     let database = Database::connect_memory().await.unwrap();
     database.migrate().await.unwrap();
-    Arc::new(database)
+    SourceRunContext::new(Arc::new(database))
 }
 
 fn test_job() -> Job {
@@ -40,14 +40,14 @@ fn test_job() -> Job {
 
 #[tokio::test]
 async fn scraper_runner_records_and_accumulates_success() {
-    let database = test_database().await;
+    let ctx = test_context().await;
     let scraper = StubScraper {
         outcome: StubOutcome::Success(vec![test_job()]),
     };
     let mut jobs = Vec::new();
     let mut errors = Vec::new();
 
-    let outcome = run_scraper(&database, &scraper, "stub", "Stub", &mut jobs, &mut errors).await;
+    let outcome = run_scraper(&ctx, &scraper, "stub", "Stub", &mut jobs, &mut errors).await;
 
     assert_eq!(outcome, ScraperRunOutcome::Success { jobs_found: 1 });
     assert_eq!(jobs.len(), 1);
@@ -56,7 +56,7 @@ async fn scraper_runner_records_and_accumulates_success() {
 
 #[tokio::test]
 async fn scraper_runner_preserves_partial_results_after_a_later_failure() {
-    let database = test_database().await;
+    let ctx = test_context().await;
     let successful = StubScraper {
         outcome: StubOutcome::Success(vec![test_job()]),
     };
@@ -66,24 +66,8 @@ async fn scraper_runner_preserves_partial_results_after_a_later_failure() {
     let mut jobs = Vec::new();
     let mut errors = Vec::new();
 
-    run_scraper(
-        &database,
-        &successful,
-        "first",
-        "First",
-        &mut jobs,
-        &mut errors,
-    )
-    .await;
-    let outcome = run_scraper(
-        &database,
-        &failed,
-        "second",
-        "Second",
-        &mut jobs,
-        &mut errors,
-    )
-    .await;
+    run_scraper(&ctx, &successful, "first", "First", &mut jobs, &mut errors).await;
+    let outcome = run_scraper(&ctx, &failed, "second", "Second", &mut jobs, &mut errors).await;
 
     assert_eq!(outcome, ScraperRunOutcome::Failure);
     assert_eq!(jobs.len(), 1);
@@ -92,20 +76,128 @@ async fn scraper_runner_preserves_partial_results_after_a_later_failure() {
 
 #[tokio::test]
 async fn scraper_runner_preserves_timeout_outcome() {
-    let database = test_database().await;
+    let ctx = test_context().await;
     let scraper = StubScraper {
         outcome: StubOutcome::Timeout,
     };
     let mut jobs = Vec::new();
     let mut errors = Vec::new();
 
-    let outcome = run_scraper(&database, &scraper, "stub", "Stub", &mut jobs, &mut errors).await;
+    let outcome = run_scraper(&ctx, &scraper, "stub", "Stub", &mut jobs, &mut errors).await;
 
     assert_eq!(outcome, ScraperRunOutcome::Timeout);
     assert!(jobs.is_empty());
     assert_eq!(errors, ["Stub source check failed (timeout)"]);
 }
 
+fn numbered_jobs(range: std::ops::Range<usize>) -> Vec<Job> {
+    range
+        .map(|n| {
+            Job::newly_discovered(
+                "Care Coordinator",
+                "Community Care",
+                format!("https://example.com/jobs/{n}"),
+                Some("Remote".to_string()),
+                "stub",
+                chrono::Utc::now(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn scraper_runner_caps_results_and_carries_overflow_to_next_cycle() {
+    let mut config = Config::first_run();
+    config.source_limits.0.insert(
+        "stub".to_string(),
+        crate::config::SourceLimit {
+            priority: 0,
+            max_results_per_run: Some(2),
+        },
+    );
+    let overflow = Arc::new(Mutex::new(SourceOverflow::default()));
+    let ctx = test_context()
+        .await
+        .with_limits(&config, Arc::clone(&overflow));
+    let scraper = StubScraper {
+        outcome: StubOutcome::Success(numbered_jobs(0..3)),
+    };
+
+    let mut first_cycle = Vec::new();
+    let mut errors = Vec::new();
+    let outcome = run_scraper(
+        &ctx,
+        &scraper,
+        "stub",
+        "Stub",
+        &mut first_cycle,
+        &mut errors,
+    )
+    .await;
+
+    assert_eq!(outcome, ScraperRunOutcome::Success { jobs_found: 3 });
+    assert_eq!(first_cycle.len(), 2);
+    assert_eq!(overflow.lock().await.carried("stub"), 1);
+
+    let mut second_cycle = Vec::new();
+    run_scraper(
+        &ctx,
+        &scraper,
+        "stub",
+        "Stub",
+        &mut second_cycle,
+        &mut errors,
+    )
+    .await;
+
+    assert_eq!(second_cycle[0].url, "https://example.com/jobs/2");
+    assert_eq!(second_cycle.len(), 2);
+    assert!(errors.is_empty());
+}
+
+#[tokio::test]
+async fn source_order_runs_higher_priority_sources_first() {
+    let mut config = Config::first_run();
+    for (source_id, priority) in [("dice", 5), ("glassdoor", 9)] {
+        config.source_limits.0.insert(
+            source_id.to_string(),
+            crate::config::SourceLimit {
+                priority,
+                max_results_per_run: None,
+            },
+        );
+    }
+    let ctx = test_context().await.with_limits(&config, Arc::default());
+
+    let order = ctx.source_order(&["greenhouse", "dice", "lever", "glassdoor"]);
+
+    assert_eq!(order, ["glassdoor", "dice", "greenhouse", "lever"]);
+}
+
+#[tokio::test]
+async fn context_labels_throttle_events_with_their_source() {
+    let ctx = test_context().await;
+
+    ctx.record_throttling(
+        "dice",
+        vec![jobsentinel_network::ThrottleEvent {
+            domain: "www.dice.com".to_string(),
+            reason: jobsentinel_network::ThrottleReason::RateLimited,
+            delay_ms: 5_000,
+        }],
+    );
+
+    assert_eq!(
+        ctx.into_throttle_events(),
+        [crate::scheduler::SourceThrottleEvent {
+            source: "dice".to_string(),
+            domain: "www.dice.com".to_string(),
+            reason: "rate_limited",
+            delay_ms: 5_000,
+        }]
+    );
+}
+
 #[test]
 fn source_failure_message_omits_raw_scraper_error_details() {
     let error = ScraperError::Generic {
@@ -142,17 +234,22 @@ fn restricted_source_acknowledged_reads_local_user_acceptance() {
             cities: vec![],
             states: vec![],
             country: "US".to_string(),
+            countries: Vec::new(),
         },
+        commute: Default::default(),
         salary_floor_usd: 0,
         salary_target_usd: None,
+        salary_target_max_usd: None,
         penalize_missing_salary: false,
         auto_refresh: Default::default(),
         bookmarklet_port: 4321,
         immediate_alert_threshold: 0.9,
         scraping_interval_hours: 2,
+        scraping_concurrency: 3,
         alerts: Default::default(),
         greenhouse_urls: vec![],
         lever_urls: vec![],
+        career_pages: vec![],
         linkedin: Default::default(),
         restricted_source_acknowledgements: Default::default(),
         remoteok: Default::default(),
@@ -164,11 +261,17 @@ fn restricted_source_acknowledged_reads_local_user_acceptance() {
         usajobs: Default::default(),
         simplyhired: Default::default(),
         glassdoor: Default::default(),
+        proxy: Default::default(),
+        source_limits: Default::default(),
+        rate_limits: Default::default(),
+        power_saving: Default::default(),
         jobswithgpt_endpoint: String::new(),
         jobswithgpt_approval: Default::default(),
         external_ai: Default::default(),
         ghost_config: None,
         use_resume_matching: false,
+        scoring: Default::default(),
+        retention: Default::default(),
         preferred_companies: vec![],
         blocked_companies: vec![],
     };
@@ -186,3 +289,64 @@ fn restricted_source_acknowledgement_message_is_user_recoverable() {
         "Dice source check skipped until you review and accept restricted-source risk in Settings"
     );
 }
+
+#[test]
+fn enabled_sources_skips_sources_without_configuration() {
+    let mut config = crate::test_support::minimal_test_config();
+    config.jobswithgpt_endpoint.clear();
+    assert!(enabled_sources(&config).is_empty());
+
+    config.remoteok.enabled = true;
+    config.dice.enabled = true;
+    assert_eq!(enabled_sources(&config), ["remoteok"], "dice needs a query");
+
+    config.dice.query = "case manager".to_string();
+    config.lever_urls = vec!["https://jobs.lever.co/example".to_string()];
+    assert_eq!(enabled_sources(&config), ["lever", "remoteok", "dice"]);
+
+    config.career_pages = vec![crate::config::CareerPageConfig {
+        url: "https://harborcare.example/careers".to_string(),
+        company: String::new(),
+        render: true,
+    }];
+    assert_eq!(
+        enabled_sources(&config),
+        ["lever", "career_pages", "remoteok", "dice"]
+    );
+}
+
+#[tokio::test]
+async fn scraper_runner_keeps_failure_reason_for_the_source() {
+    let ctx = test_context().await;
+    let failed = StubScraper {
+        outcome: StubOutcome::Failure,
+    };
+    let mut jobs = Vec::new();
+    let mut errors = Vec::new();
+
+    run_scraper(&ctx, &failed, "second", "Second", &mut jobs, &mut errors).await;
+
+    assert_eq!(
+        ctx.take_failure("second"),
+        Some(SourceFailureReason {
+            source_label: "Second",
+            reason: "network error".to_string(),
+        })
+    );
+    assert_eq!(ctx.take_failure("second"), None);
+}
+
+#[test]
+fn scraper_failure_reason_names_http_status_without_url() {
+    let error = ScraperError::http_status(
+        403,
+        "https://boards.greenhouse.io/private-company?token=secret",
+        "Forbidden",
+    );
+
+    assert_eq!(scraper_failure_reason(&error), "403 Forbidden");
+    assert_eq!(
+        scraper_failure_reason(&ScraperError::http_status(418, "https://example.com", "")),
+        "HTTP 418"
+    );
+}
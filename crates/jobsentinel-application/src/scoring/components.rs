@@ -13,17 +13,32 @@ impl ScoringEngine {
             None => job.title.clone(),
         };
 
-        let matches = self
-            .config
-            .keywords_boost
-            .iter()
-            .filter(|keyword| {
-                self.synonym_map
-                    .matches_with_synonyms(keyword, &description_text)
-            })
-            .count();
-
-        (matches as f64 / self.config.keywords_boost.len() as f64).min(1.0)
+        self.weighted_keyword_matches(&description_text).0
+    }
+
+    /// Share of boost keywords found in `text`, each weighted by feedback,
+    /// and the keywords that matched (with synonym matching)
+    pub(super) fn weighted_keyword_matches(&self, text: &str) -> (f64, Vec<&str>) {
+        let mut matched = Vec::new();
+        let mut matched_weight = 0.0;
+        let mut total_weight = 0.0;
+        for keyword in &self.config.keywords_boost {
+            let multiplier = self
+                .keyword_weights
+                .get(&keyword.to_lowercase())
+                .map_or(1.0, |weight| feedback::keyword_multiplier(*weight));
+            total_weight += multiplier;
+            if self.synonym_map.matches_with_synonyms(keyword, text) {
+                matched_weight += multiplier;
+                matched.push(keyword.as_str());
+            }
+        }
+
+        if total_weight > 0.0 {
+            ((matched_weight / total_weight).min(1.0), matched)
+        } else {
+            (0.0, matched)
+        }
     }
 
     /// Score skills match (40% weight)
@@ -71,144 +86,22 @@ impl ScoringEngine {
             return (0.0, vec!["Contains excluded keyword".to_string()]);
         }
 
-        // Count boost keywords matches (with synonym matching)
-        let mut boost_matches = 0;
-        for keyword in &self.config.keywords_boost {
-            if self
-                .synonym_map
-                .matches_with_synonyms(keyword, &description_text)
-            {
-                boost_matches += 1;
-                reasons.push(format!("Keyword match: {}", keyword));
-            }
+        // Weigh boost keyword matches by learned feedback
+        let (match_ratio, matched_keywords) = self.weighted_keyword_matches(&description_text);
+        for keyword in matched_keywords {
+            reasons.push(format!("Keyword match: {}", keyword));
         }
 
         // Calculate skills score based on boost keyword matches
         let score = if self.config.keywords_boost.is_empty() {
             max_score // Full score if no boost keywords configured
         } else {
-            let match_ratio = boost_matches as f64 / self.config.keywords_boost.len() as f64;
-            max_score * match_ratio.min(1.0)
+            max_score * match_ratio
         };
 
         (score, reasons)
     }
 
-    /// Score salary match (25% weight)
-    ///
-    /// Graduated scoring based on comparison to target salary:
-    /// - >= target: 1.0 (full score)
-    /// - 90-99% of target: 0.9
-    /// - 80-89% of target: 0.8
-    /// - 70-79% of target: 0.6
-    /// - < 70% of target: 0.3
-    /// - Significantly above target (120%+): 1.0 + bonus (capped at 1.2)
-    ///
-    /// For salary ranges (min-max), uses midpoint for comparison.
-    pub(super) fn score_salary(&self, job: &Job) -> (f64, Vec<String>) {
-        let max_score = self.scoring_config.salary_weight;
-        let mut reasons = Vec::new();
-
-        // If no salary requirements configured, give full score
-        if self.config.salary_floor_usd == 0 {
-            return (max_score, vec!["No salary requirement".to_string()]);
-        }
-
-        // Determine target salary (use salary_target_usd if set, otherwise salary_floor_usd)
-        let target_salary = self
-            .config
-            .salary_target_usd
-            .unwrap_or(self.config.salary_floor_usd) as f64;
-
-        // Handle missing salary data
-        if job.salary_min.is_none() && job.salary_max.is_none() {
-            let penalty_score = if self.config.penalize_missing_salary {
-                0.3
-            } else {
-                0.5
-            };
-            reasons.push(format!(
-                "Salary not specified ({}% credit)",
-                (penalty_score * 100.0) as i32
-            ));
-            return (max_score * penalty_score, reasons);
-        }
-
-        // Calculate effective salary for comparison
-        // If both min and max are available, use midpoint
-        // Otherwise use whichever is available
-        let effective_salary = match (job.salary_min, job.salary_max) {
-            (Some(min), Some(max)) => {
-                let midpoint = (min + max) as f64 / 2.0;
-                reasons.push(format!(
-                    "Salary range: ${}-${} (midpoint: ${})",
-                    min, max, midpoint as i64
-                ));
-                midpoint
-            }
-            (Some(min), None) => {
-                reasons.push(format!("Salary: ${} (minimum only)", min));
-                min as f64
-            }
-            (None, Some(max)) => {
-                reasons.push(format!("Salary: ${} (maximum only)", max));
-                max as f64
-            }
-            (None, None) => unreachable!(), // Already handled above
-        };
-
-        // Calculate percentage of target
-        let percentage = effective_salary / target_salary;
-
-        // Graduated scoring
-        let multiplier = if percentage >= 1.2 {
-            // Significantly above target - give bonus (capped)
-            reasons.push(format!(
-                "Salary {}% of target ({}% credit + bonus)",
-                (percentage * 100.0) as i32,
-                120
-            ));
-            1.2
-        } else if percentage >= 1.0 {
-            // At or above target - full score
-            reasons.push(format!(
-                "Salary {}% of target (100% credit)",
-                (percentage * 100.0) as i32
-            ));
-            1.0
-        } else if percentage >= 0.9 {
-            // 90-99% of target
-            reasons.push(format!(
-                "Salary {}% of target (90% credit)",
-                (percentage * 100.0) as i32
-            ));
-            0.9
-        } else if percentage >= 0.8 {
-            // 80-89% of target
-            reasons.push(format!(
-                "Salary {}% of target (80% credit)",
-                (percentage * 100.0) as i32
-            ));
-            0.8
-        } else if percentage >= 0.7 {
-            // 70-79% of target
-            reasons.push(format!(
-                "Salary {}% of target (60% credit)",
-                (percentage * 100.0) as i32
-            ));
-            0.6
-        } else {
-            // Below 70% of target
-            reasons.push(format!(
-                "Salary below target: {}% of target (30% credit)",
-                (percentage * 100.0) as i32
-            ));
-            0.3
-        };
-
-        (max_score * multiplier, reasons)
-    }
-
     /// Score location match (20% weight)
     pub(super) fn score_location(&self, job: &Job) -> (f64, Vec<String>) {
         let max_score = self.scoring_config.location_weight;
@@ -219,19 +112,37 @@ impl ScoringEngine {
             status => status,
         };
 
-        if remote_status == RemoteStatus::Remote && self.config.location_preferences.allow_remote {
-            reasons.push("Remote job (matches preference)".to_string());
-            return (max_score, reasons);
+        let prefs = &self.config.location_preferences;
+        let location = job.location.as_deref().unwrap_or_default();
+
+        if let Some(reason) = location_match::country_mismatch(prefs, location) {
+            reasons.push(reason);
+            return (0.0, reasons);
         }
 
-        if remote_status == RemoteStatus::Hybrid && self.config.location_preferences.allow_hybrid {
-            reasons.push("Hybrid job (matches preference)".to_string());
+        if remote_status == RemoteStatus::Remote && prefs.allow_remote {
+            reasons.push("Remote job (matches preference)".to_string());
             return (max_score, reasons);
         }
 
-        if remote_status == RemoteStatus::Onsite && self.config.location_preferences.allow_onsite {
-            reasons.push("Onsite job (matches preference)".to_string());
-            return (max_score, reasons);
+        let arrangement_matches = match remote_status {
+            RemoteStatus::Hybrid if prefs.allow_hybrid => Some("Hybrid job (matches preference)"),
+            RemoteStatus::Onsite if prefs.allow_onsite => Some("Onsite job (matches preference)"),
+            _ => None,
+        };
+        if let Some(reason) = arrangement_matches {
+            reasons.push(reason.to_string());
+            reasons.extend(location_match::preferred_place(prefs, location));
+            let Some(distance_km) = self.commute_distance_km(location) else {
+                return (max_score, reasons);
+            };
+            let (share, reason) = location_match::commute_fit(
+                remote_status,
+                distance_km,
+                self.config.commute.max_distance_km,
+            );
+            reasons.push(reason);
+            return (max_score * share, reasons);
         }
 
         // Location doesn't match preferences
@@ -239,6 +150,15 @@ impl ScoringEngine {
         (0.0, reasons)
     }
 
+    /// Straight-line distance from home to a geocoded job location
+    fn commute_distance_km(&self, location: &str) -> Option<f64> {
+        let home = self.config.commute.home?;
+        let key = crate::geocoding::normalize_location(location)?;
+        self.job_coordinates
+            .get(&key)
+            .map(|coordinates| home.distance_km(coordinates))
+    }
+
     /// Score company preference (10% weight)
     pub(super) fn score_company(&self, job: &Job) -> (f64, Vec<String>) {
         let base_score = self.scoring_config.company_weight;
@@ -254,23 +174,19 @@ impl ScoringEngine {
         }
 
         // Blocked companies take precedence.
-        for blocked in &self.config.blocked_companies {
-            if fuzzy_match_company(&job.company, blocked) {
-                reasons.push(format!("Company '{}' is blocklisted", job.company));
-                return (0.0, reasons);
-            }
+        if self.config.blocks_company(&job.company) {
+            reasons.push(format!("Company '{}' is blocklisted", job.company));
+            return (0.0, reasons);
         }
 
         // Preferred companies receive a bonus.
-        for preferred in &self.config.preferred_companies {
-            if fuzzy_match_company(&job.company, preferred) {
-                let bonus_score = base_score * 1.5; // 50% bonus
-                reasons.push(format!(
-                    "Company '{}' is preferred (+50% bonus)",
-                    job.company
-                ));
-                return (bonus_score, reasons);
-            }
+        if self.config.prefers_company(&job.company) {
+            let bonus_score = base_score * 1.5; // 50% bonus
+            reasons.push(format!(
+                "Company '{}' is preferred (+50% bonus)",
+                job.company
+            ));
+            return (bonus_score, reasons);
         }
 
         // Neutral company - base score
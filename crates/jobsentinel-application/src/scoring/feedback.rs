@@ -0,0 +1,147 @@
+//! Learning keyword weights from job feedback
+//!
+//! A small logistic regression predicts whether the user marks a job as a
+//! good match from which boost keywords it mentions. Each keyword's learned
+//! coefficient scales that keyword's share of the skills score: keywords
+//! common in liked jobs count for more, keywords common in disliked jobs for
+//! less. With no feedback every keyword counts the same.
+
+use anyhow::Result;
+use jobsentinel_storage::{Database, JobFeedback, JobFeedbackExample};
+use std::collections::HashMap;
+
+use super::{clear_score_cache, SynonymMap};
+use crate::config::Config;
+
+/// Gradient descent passes over the feedback
+const TRAINING_EPOCHS: usize = 300;
+const LEARNING_RATE: f64 = 0.5;
+/// L2 penalty that keeps weights small while there is little feedback
+const L2_PENALTY: f64 = 0.05;
+/// Bounds on how far feedback can shrink or grow a keyword's share
+const MIN_KEYWORD_MULTIPLIER: f64 = 0.25;
+const MAX_KEYWORD_MULTIPLIER: f64 = 4.0;
+
+/// Multiplier applied to a keyword's share of the skills score
+#[must_use]
+pub(super) fn keyword_multiplier(weight: f64) -> f64 {
+    weight
+        .exp()
+        .clamp(MIN_KEYWORD_MULTIPLIER, MAX_KEYWORD_MULTIPLIER)
+}
+
+/// Fit a weight per boost keyword to the user's feedback.
+///
+/// Keys are lowercased keywords. Keywords no rated job mentions are left out
+/// and keep the neutral weight of 0.
+#[must_use]
+pub fn learn_keyword_weights(
+    keywords: &[String],
+    examples: &[JobFeedbackExample],
+) -> HashMap<String, f64> {
+    if keywords.is_empty() || examples.is_empty() {
+        return HashMap::new();
+    }
+
+    let synonym_map = SynonymMap::new();
+    let features: Vec<Vec<bool>> = examples
+        .iter()
+        .map(|example| {
+            let text = match &example.description {
+                Some(description) => format!("{} {}", example.title, description),
+                None => example.title.clone(),
+            };
+            keywords
+                .iter()
+                .map(|keyword| synonym_map.matches_with_synonyms(keyword, &text))
+                .collect()
+        })
+        .collect();
+    let labels: Vec<f64> = examples
+        .iter()
+        .map(|example| match example.feedback {
+            JobFeedback::Good => 1.0,
+            JobFeedback::Bad => 0.0,
+        })
+        .collect();
+
+    let n = examples.len() as f64;
+    let mut bias = 0.0;
+    let mut weights = vec![0.0; keywords.len()];
+    for _ in 0..TRAINING_EPOCHS {
+        let mut bias_gradient = 0.0;
+        let mut gradients = vec![0.0; keywords.len()];
+        for (matched, label) in features.iter().zip(&labels) {
+            let logit = bias
+                + matched
+                    .iter()
+                    .zip(&weights)
+                    .filter(|(is_match, _)| **is_match)
+                    .map(|(_, weight)| weight)
+                    .sum::<f64>();
+            let error = sigmoid(logit) - label;
+            bias_gradient += error;
+            for (gradient, is_match) in gradients.iter_mut().zip(matched) {
+                if *is_match {
+                    *gradient += error;
+                }
+            }
+        }
+
+        bias -= LEARNING_RATE * bias_gradient / n;
+        for (weight, gradient) in weights.iter_mut().zip(gradients) {
+            *weight -= LEARNING_RATE * (gradient / n + L2_PENALTY * *weight);
+        }
+    }
+
+    keywords
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| features.iter().any(|matched| matched[*i]))
+        .map(|(i, keyword)| (keyword.to_lowercase(), weights[i]))
+        .collect()
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Stored keyword weights, or none if they cannot be read.
+pub async fn load_keyword_weights(database: &Database) -> HashMap<String, f64> {
+    database
+        .keyword_feedback_weights()
+        .await
+        .unwrap_or_else(|error| {
+            tracing::warn!(error = %error, "Failed to load keyword weights; using equal weights");
+            HashMap::new()
+        })
+}
+
+/// Save the user's verdict for a job, or clear it with `None`, then retrain
+/// keyword weights from all feedback. Returns how many jobs are rated.
+///
+/// Scores computed from now on use the new weights.
+pub async fn record_job_feedback(
+    config: &Config,
+    database: &Database,
+    job_id: i64,
+    feedback: Option<JobFeedback>,
+) -> Result<usize> {
+    match feedback {
+        Some(feedback) => database.set_job_feedback(job_id, feedback).await?,
+        None => database.clear_job_feedback(job_id).await?,
+    }
+
+    let examples = database.job_feedback_examples().await?;
+    let weights = learn_keyword_weights(&config.keywords_boost, &examples);
+    database.replace_keyword_feedback_weights(&weights).await?;
+    // Cached scores were computed with the previous weights.
+    clear_score_cache().await;
+
+    tracing::info!(
+        rated_jobs = examples.len(),
+        keywords_weighted = weights.len(),
+        "Keyword weights retrained from feedback"
+    );
+    Ok(examples.len())
+}
@@ -0,0 +1,86 @@
+//! Country, place, and commute checks for location scoring
+//!
+//! Understands region formats outside the US ("Toronto, ON", "Leeds,
+//! England", "Köln, Nordrhein-Westfalen") through the shared region tables,
+//! and city aliases ("NYC", "Greater Seattle Area") through the gazetteer.
+
+use crate::config::LocationPreferences;
+use jobsentinel_domain::geo::{find_city, location_region};
+use jobsentinel_domain::normalization::{resolve_region, RemoteStatus};
+
+/// Explain why a job sits outside the user's countries, if it does.
+///
+/// Locations that name no recognisable country are never rejected.
+pub(super) fn country_mismatch(prefs: &LocationPreferences, location: &str) -> Option<String> {
+    let resolved = location_region(location)?;
+    let preferred = prefs.country_codes();
+    if preferred.is_empty() || preferred.contains(&resolved.country) {
+        return None;
+    }
+
+    // "Berlin, DE" reads as Delaware; a trailing code the user picked wins.
+    let last_part = location.rsplit(',').next().unwrap_or_default().trim();
+    if preferred
+        .iter()
+        .any(|code| code.eq_ignore_ascii_case(last_part))
+    {
+        return None;
+    }
+
+    let place = resolved.region.unwrap_or(resolved.country);
+    Some(format!("Located in {place} (outside your countries)"))
+}
+
+/// Describe a match with the user's preferred cities or states/provinces.
+pub(super) fn preferred_place(prefs: &LocationPreferences, location: &str) -> Option<String> {
+    let city = location.split(',').next().unwrap_or_default().trim();
+    let known_city = find_city(location);
+    if let Some(preferred_city) = prefs.cities.iter().find(|preferred| {
+        (!city.is_empty() && preferred.trim().eq_ignore_ascii_case(city))
+            || known_city.is_some_and(|known| find_city(preferred) == Some(known))
+    }) {
+        return Some(format!("In preferred city {}", preferred_city.trim()));
+    }
+
+    let region = location_region(location)?;
+    let region_name = region.region?;
+    prefs
+        .states
+        .iter()
+        .any(|state| {
+            resolve_region(state).is_some_and(|preferred| preferred == region)
+                || state.trim().eq_ignore_ascii_case(region_name)
+        })
+        .then(|| format!("In preferred region {region_name}"))
+}
+
+/// Share of location credit for an onsite or hybrid job `distance_km` from
+/// home, with the reason to show.
+///
+/// Jobs within the radius keep full credit. Beyond it, onsite credit falls
+/// with distance (half at twice the radius); hybrid jobs lose half as much
+/// because the commute is only some days.
+pub(super) fn commute_fit(
+    status: RemoteStatus,
+    distance_km: f64,
+    max_distance_km: f64,
+) -> (f64, String) {
+    if distance_km <= max_distance_km {
+        return (
+            1.0,
+            format!("About {distance_km:.0} km from home (within your commute)"),
+        );
+    }
+
+    let onsite_share = max_distance_km / distance_km;
+    let share = match status {
+        RemoteStatus::Hybrid => 0.5 + onsite_share / 2.0,
+        _ => onsite_share,
+    };
+    (
+        share,
+        format!(
+            "About {distance_km:.0} km from home (beyond your {max_distance_km:.0} km commute)"
+        ),
+    )
+}
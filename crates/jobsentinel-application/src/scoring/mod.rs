@@ -6,17 +6,27 @@
 //! - Location: 20% (default)
 //! - Company: 10% (default)
 //! - Recency: 5% (default)
+//!
+//! Weights come from `Config::scoring`, using the active scoring profile when
+//! one is selected. Within the skills factor, boost keywords are weighted by
+//! what the user's job feedback has taught (see `feedback`). With home
+//! coordinates set, onsite and hybrid jobs beyond the commute radius lose
+//! location credit (see `crate::geocoding`).
 
 mod cache;
 mod company_normalization;
 mod components;
+mod feedback;
+mod location_match;
 mod remote;
+mod salary;
 mod synonyms;
 
 pub use cache::{
     clear_score_cache, get_cached_score, invalidate_job, invalidate_resume, score_cache_stats,
     set_cached_score, ScoreCacheKey, ScoreCacheStats,
 };
+pub use feedback::{learn_keyword_weights, load_keyword_weights, record_job_feedback};
 pub use jobsentinel_domain::ScoringConfig;
 pub use jobsentinel_intelligence::{JobScore, ScoreBreakdown};
 pub use remote::{detect_remote_status, score_remote_match, RemoteStatus, UserRemotePreference};
@@ -25,13 +35,15 @@ pub use synonyms::SynonymMap;
 use crate::config::Config;
 use chrono::Utc;
 use company_normalization::company_suffix_patterns;
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Coordinates, ExchangeRates, Job};
 use jobsentinel_storage::Database;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::debug;
 
 /// Normalize company name for fuzzy matching
-/// Strips common suffixes, extra whitespace, and converts to lowercase
+/// Strips common suffixes (and a comma before them), extra whitespace, and
+/// converts to lowercase
 #[must_use]
 pub(crate) fn normalize_company_name(name: &str) -> String {
     // Normalize: lowercase, trim, normalize whitespace
@@ -55,7 +67,8 @@ pub(crate) fn normalize_company_name(name: &str) -> String {
 
     for suffix in company_suffix_patterns() {
         if let Some(stripped) = result.strip_suffix(suffix.as_str()) {
-            result.truncate(stripped.len());
+            // "Acme, Inc." leaves a trailing comma behind.
+            result.truncate(stripped.trim_end_matches(',').len());
             break; // Only remove one suffix
         }
     }
@@ -67,6 +80,10 @@ pub(crate) fn normalize_company_name(name: &str) -> String {
 pub(crate) fn fuzzy_match_company(job_company: &str, config_company: &str) -> bool {
     let normalized_job = normalize_company_name(job_company);
     let normalized_config = normalize_company_name(config_company);
+    // An empty name would be contained in every other name.
+    if normalized_job.is_empty() || normalized_config.is_empty() {
+        return false;
+    }
     if normalized_job == normalized_config {
         return true;
     }
@@ -83,16 +100,24 @@ pub struct ScoringEngine {
     scoring_config: ScoringConfig,
     synonym_map: SynonymMap,
     database: Option<Arc<Database>>,
+    exchange_rates: ExchangeRates,
+    /// Learned weight per lowercased boost keyword
+    keyword_weights: HashMap<String, f64>,
+    /// Geocoded job locations, keyed by `geocoding::normalize_location`
+    job_coordinates: HashMap<String, Coordinates>,
 }
 
 impl ScoringEngine {
     #[must_use]
     pub fn new(config: Arc<Config>) -> Self {
         Self {
+            scoring_config: config.scoring.active_weights().clone(),
             config,
-            scoring_config: ScoringConfig::default(),
             synonym_map: SynonymMap::new(),
             database: None,
+            exchange_rates: ExchangeRates::bundled(),
+            keyword_weights: HashMap::new(),
+            job_coordinates: HashMap::new(),
         }
     }
 
@@ -100,13 +125,37 @@ impl ScoringEngine {
     #[must_use]
     pub fn with_database(config: Arc<Config>, database: Arc<Database>) -> Self {
         Self {
+            scoring_config: config.scoring.active_weights().clone(),
             config,
-            scoring_config: ScoringConfig::default(),
             synonym_map: SynonymMap::new(),
             database: Some(database),
+            exchange_rates: ExchangeRates::bundled(),
+            keyword_weights: HashMap::new(),
+            job_coordinates: HashMap::new(),
         }
     }
 
+    /// Use these rates instead of the bundled ones to compare non-USD pay
+    #[must_use]
+    pub fn with_exchange_rates(mut self, exchange_rates: ExchangeRates) -> Self {
+        self.exchange_rates = exchange_rates;
+        self
+    }
+
+    /// Weight boost keywords by what the user's job feedback has taught
+    #[must_use]
+    pub fn with_keyword_weights(mut self, keyword_weights: HashMap<String, f64>) -> Self {
+        self.keyword_weights = keyword_weights;
+        self
+    }
+
+    /// Use these geocoded job locations to score commute distance
+    #[must_use]
+    pub fn with_job_coordinates(mut self, job_coordinates: HashMap<String, Coordinates>) -> Self {
+        self.job_coordinates = job_coordinates;
+        self
+    }
+
     /// Get the current scoring configuration
     #[must_use]
     pub const fn scoring_config(&self) -> &ScoringConfig {
@@ -332,14 +381,14 @@ impl ScoringEngine {
             reasons.push(format!("Missing skills: {}", missing_str));
         }
 
+        if let Some(education) = &match_result.education {
+            reasons.push(education.explanation());
+        }
+
         // Also show keyword matches
-        for keyword in &self.config.keywords_boost {
-            if self
-                .synonym_map
-                .matches_with_synonyms(keyword, &description_text)
-            {
-                reasons.push(format!("Keyword match: {}", keyword));
-            }
+        let (_, matched_keywords) = self.weighted_keyword_matches(&description_text);
+        for keyword in matched_keywords {
+            reasons.push(format!("Keyword match: {}", keyword));
         }
 
         Ok((final_score, reasons))
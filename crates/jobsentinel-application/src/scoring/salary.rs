@@ -0,0 +1,250 @@
+//! Salary scoring against the user's floor, target, and optional target range
+
+use super::*;
+use jobsentinel_domain::PayPeriod;
+
+fn format_posted_range(min: i64, max: i64) -> String {
+    if min == max {
+        format!("${min}")
+    } else {
+        format!("${min}-${max}")
+    }
+}
+
+impl ScoringEngine {
+    /// The job's posted salary as an annual US dollar `(min, max)` range. A
+    /// single posted figure becomes a one-point range; pay in a currency
+    /// without a known rate gives `None`.
+    fn posted_annual_range(&self, job: &Job) -> Option<(i64, i64)> {
+        match self.exchange_rates.job_salary_usd(job) {
+            (Some(min), Some(max)) => Some((min.min(max), min.max(max))),
+            (Some(amount), None) | (None, Some(amount)) => Some((amount, amount)),
+            (None, None) => None,
+        }
+    }
+
+    /// Score salary match (25% weight)
+    ///
+    /// With `salary_target_max_usd` set, see [`Self::score_salary_range`].
+    /// Otherwise scoring is graduated against the target salary:
+    /// - >= target: 1.0 (full score)
+    /// - 90-99% of target: 0.9
+    /// - 80-89% of target: 0.8
+    /// - 70-79% of target: 0.6
+    /// - < 70% of target: 0.3
+    /// - Significantly above target (120%+): 1.0 + bonus (capped at 1.2)
+    ///
+    /// For salary ranges (min-max), uses midpoint for comparison. Hourly,
+    /// daily, weekly, and monthly pay is annualized first, and other currencies are converted to
+    /// US dollars.
+    pub(super) fn score_salary(&self, job: &Job) -> (f64, Vec<String>) {
+        let max_score = self.scoring_config.salary_weight;
+        let mut reasons = Vec::new();
+
+        let target_range = self.salary_target_range();
+
+        // If no salary requirements configured, give full score
+        if self.config.salary_floor_usd == 0 && target_range.is_none() {
+            return (max_score, vec!["No salary requirement".to_string()]);
+        }
+
+        // Handle missing salary data
+        let Some((posted_min, posted_max)) = self.posted_annual_range(job) else {
+            let penalty_score = if self.config.penalize_missing_salary {
+                0.3
+            } else {
+                0.5
+            };
+            let posted = job.salary_min.is_some() || job.salary_max.is_some();
+            match job.currency.as_deref() {
+                Some(currency) if posted => reasons.push(format!(
+                    "Salary in {currency} could not be converted to USD ({}% credit)",
+                    (penalty_score * 100.0) as i32
+                )),
+                _ => reasons.push(format!(
+                    "Salary not specified ({}% credit)",
+                    (penalty_score * 100.0) as i32
+                )),
+            }
+            return (max_score * penalty_score, reasons);
+        };
+        if let Some(currency) = job
+            .currency
+            .as_deref()
+            .filter(|currency| !currency.trim().eq_ignore_ascii_case("USD"))
+        {
+            reasons.push(format!("Salary converted from {} to USD", currency.trim()));
+        }
+        let period = job.effective_pay_period();
+        if period != PayPeriod::Year {
+            reasons.push(format!("Pay quoted per {}, annualized", period.as_str()));
+        }
+
+        if let Some((target_min, target_max)) = target_range {
+            let (multiplier, reason) =
+                score_salary_range((posted_min, posted_max), (target_min, target_max));
+            reasons.push(reason);
+            return (max_score * multiplier, reasons);
+        }
+
+        // Determine target salary (use salary_target_usd if set, otherwise salary_floor_usd)
+        let target_salary = self
+            .config
+            .salary_target_usd
+            .unwrap_or(self.config.salary_floor_usd) as f64;
+
+        // Calculate effective salary for comparison
+        // If both min and max are available, use midpoint
+        // Otherwise use whichever is available
+        let effective_salary = match (job.salary_min, job.salary_max) {
+            (Some(_), Some(_)) => {
+                let midpoint = (posted_min + posted_max) as f64 / 2.0;
+                reasons.push(format!(
+                    "Salary range: ${}-${} (midpoint: ${})",
+                    posted_min, posted_max, midpoint as i64
+                ));
+                midpoint
+            }
+            (Some(_), None) => {
+                reasons.push(format!("Salary: ${} (minimum only)", posted_min));
+                posted_min as f64
+            }
+            _ => {
+                reasons.push(format!("Salary: ${} (maximum only)", posted_max));
+                posted_max as f64
+            }
+        };
+
+        // Calculate percentage of target
+        let percentage = effective_salary / target_salary;
+
+        // Graduated scoring
+        let multiplier = if percentage >= 1.2 {
+            // Significantly above target - give bonus (capped)
+            reasons.push(format!(
+                "Salary {}% of target ({}% credit + bonus)",
+                (percentage * 100.0) as i32,
+                120
+            ));
+            1.2
+        } else if percentage >= 1.0 {
+            // At or above target - full score
+            reasons.push(format!(
+                "Salary {}% of target (100% credit)",
+                (percentage * 100.0) as i32
+            ));
+            1.0
+        } else if percentage >= 0.9 {
+            // 90-99% of target
+            reasons.push(format!(
+                "Salary {}% of target (90% credit)",
+                (percentage * 100.0) as i32
+            ));
+            0.9
+        } else if percentage >= 0.8 {
+            // 80-89% of target
+            reasons.push(format!(
+                "Salary {}% of target (80% credit)",
+                (percentage * 100.0) as i32
+            ));
+            0.8
+        } else if percentage >= 0.7 {
+            // 70-79% of target
+            reasons.push(format!(
+                "Salary {}% of target (60% credit)",
+                (percentage * 100.0) as i32
+            ));
+            0.6
+        } else {
+            // Below 70% of target
+            reasons.push(format!(
+                "Salary below target: {}% of target (30% credit)",
+                (percentage * 100.0) as i32
+            ));
+            0.3
+        };
+
+        (max_score * multiplier, reasons)
+    }
+
+    /// The user's `(min, max)` target range, when one is configured.
+    ///
+    /// The range starts at `salary_target_usd` (or the floor) and ends at
+    /// `salary_target_max_usd`.
+    fn salary_target_range(&self) -> Option<(i64, i64)> {
+        let target_max = self.config.salary_target_max_usd?;
+        let target_min = self
+            .config
+            .salary_target_usd
+            .unwrap_or(self.config.salary_floor_usd);
+        (target_min > 0 && target_max > target_min).then_some((target_min, target_max))
+    }
+}
+
+/// Graded salary multiplier for a posted range against the target range.
+///
+/// - Entirely at or above the top of the target range: 1.2 (bonus)
+/// - Overlapping: 0.7-1.0, rising with the share of the posted range that
+///   meets the target minimum and with how far into the target range the
+///   posted maximum reaches
+/// - Entirely below: 0.3-0.7, by how close the posted maximum comes to the
+///   target minimum (0.3 at 70% or less)
+fn score_salary_range(posted: (i64, i64), target: (i64, i64)) -> (f64, String) {
+    let (posted_min, posted_max) = posted;
+    let (target_min, target_max) = target;
+    let posted_label = format_posted_range(posted_min, posted_max);
+    let target_label = format!("${target_min}-${target_max}");
+
+    if posted_min >= target_max {
+        return (
+            1.2,
+            format!(
+                "Salary {posted_label} at or above the top of your {target_label} range (100% credit + bonus)"
+            ),
+        );
+    }
+
+    if posted_max < target_min {
+        let reach = posted_max as f64 / target_min as f64;
+        let multiplier = 0.3 + 0.4 * ((reach - 0.7) / 0.3).clamp(0.0, 1.0);
+        return (
+            multiplier,
+            format!(
+                "Salary {posted_label} below your {target_label} range (top is {}% of your minimum; {}% credit)",
+                (reach * 100.0) as i32,
+                (multiplier * 100.0).round() as i32
+            ),
+        );
+    }
+
+    let meets_minimum = if posted_max == posted_min {
+        1.0
+    } else {
+        (posted_max - posted_min.max(target_min)) as f64 / (posted_max - posted_min) as f64
+    };
+    let top_percentile =
+        (posted_max.min(target_max) - target_min) as f64 / (target_max - target_min) as f64;
+    let multiplier = 0.7 + 0.2 * meets_minimum + 0.1 * top_percentile;
+
+    (
+        multiplier,
+        format!(
+            "Salary {posted_label} overlaps your {target_label} range: {}% of the posted range meets your minimum, top reaches the {} percentile of your range ({}% credit)",
+            (meets_minimum * 100.0).round() as i32,
+            ordinal((top_percentile * 100.0).round() as i32),
+            (multiplier * 100.0).round() as i32
+        ),
+    )
+}
+
+/// `n` with its English ordinal suffix, such as "1st", "12th", or "22nd"
+pub(super) fn ordinal(n: i32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
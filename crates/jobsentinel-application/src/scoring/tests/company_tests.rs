@@ -190,6 +190,7 @@ fn test_normalize_company_name() {
     assert_eq!(normalize_company_name("Harbor Retail Co"), "harbor retail");
     assert_eq!(normalize_company_name("  Spaces  Inc  "), "spaces");
     assert_eq!(normalize_company_name("Company L.L.C."), "company");
+    assert_eq!(normalize_company_name("Harbor Care, Inc."), "harbor care");
 }
 
 #[test]
@@ -220,6 +221,10 @@ fn test_fuzzy_match_company() {
         "County Services",
         "Public Benefit Office"
     ));
+
+    // An empty name matches nothing
+    assert!(!fuzzy_match_company("", "Metro Transit"));
+    assert!(!fuzzy_match_company("Metro Transit", "   "));
 }
 
 #[test]
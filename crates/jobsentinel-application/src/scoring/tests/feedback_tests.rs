@@ -0,0 +1,54 @@
+use super::*;
+use jobsentinel_storage::{JobFeedback, JobFeedbackExample};
+
+fn example(description: &str, feedback: JobFeedback) -> JobFeedbackExample {
+    JobFeedbackExample {
+        title: "Case Manager".to_string(),
+        description: Some(description.to_string()),
+        feedback,
+    }
+}
+
+fn scheduling_liked_crm_disliked() -> Vec<JobFeedbackExample> {
+    vec![
+        example("Scheduling for clinic visits", JobFeedback::Good),
+        example("Own the Scheduling calendar", JobFeedback::Good),
+        example("Daily CRM data entry", JobFeedback::Bad),
+        example("CRM cleanup and reporting", JobFeedback::Bad),
+    ]
+}
+
+#[test]
+fn test_feedback_raises_liked_keywords_and_lowers_disliked_ones() {
+    let config = create_test_config();
+
+    let weights = learn_keyword_weights(&config.keywords_boost, &scheduling_liked_crm_disliked());
+
+    assert!(weights["scheduling"] > 0.0);
+    assert!(weights["crm"] < 0.0);
+}
+
+#[test]
+fn test_no_feedback_learns_no_weights() {
+    let config = create_test_config();
+
+    assert!(learn_keyword_weights(&config.keywords_boost, &[]).is_empty());
+}
+
+#[test]
+fn test_learned_weights_shift_skills_score() {
+    let config = Arc::new(create_test_config());
+    let mut job = create_test_job();
+    job.description = Some("Case Manager handling Scheduling".to_string());
+    let weights = learn_keyword_weights(&config.keywords_boost, &scheduling_liked_crm_disliked());
+
+    let unweighted = ScoringEngine::new(Arc::clone(&config)).score(&job);
+    let weighted = ScoringEngine::new(config)
+        .with_keyword_weights(weights)
+        .score(&job);
+
+    assert!((unweighted.breakdown.skills - 0.20).abs() < 1e-9);
+    assert!(weighted.breakdown.skills > unweighted.breakdown.skills);
+    assert!(weighted.breakdown.skills <= 0.40);
+    assert_eq!(weighted.reasons, unweighted.reasons);
+}
@@ -0,0 +1,193 @@
+use super::*;
+use crate::config::CountryPreference;
+use jobsentinel_domain::Coordinates;
+use std::collections::HashMap;
+
+fn onsite_engine(configure: impl FnOnce(&mut Config)) -> ScoringEngine {
+    let mut config = create_test_config();
+    config.location_preferences.allow_onsite = true;
+    config.location_preferences.allow_hybrid = true;
+    configure(&mut config);
+    ScoringEngine::new(Arc::new(config))
+}
+
+fn onsite_job(location: &str) -> Job {
+    Job {
+        remote: Some(false),
+        location: Some(location.to_string()),
+        ..create_test_job()
+    }
+}
+
+#[test]
+fn onsite_job_in_another_country_scores_zero() {
+    let engine = onsite_engine(|_| {});
+
+    let score = engine.score(&onsite_job("Toronto, ON"));
+
+    assert_eq!(score.breakdown.location, 0.0);
+    assert!(score
+        .reasons
+        .iter()
+        .any(|reason| reason.contains("Ontario (outside your countries)")));
+}
+
+#[test]
+fn extra_countries_accept_their_region_formats() {
+    let engine = onsite_engine(|config| {
+        config.location_preferences.countries = vec![
+            CountryPreference {
+                code: "CA".to_string(),
+                boards: Vec::new(),
+            },
+            CountryPreference {
+                code: "de".to_string(),
+                boards: vec!["arbeitnow".to_string()],
+            },
+        ];
+    });
+
+    for location in [
+        "Austin, TX",
+        "Toronto, ON",
+        "Vancouver, British Columbia, Canada",
+        "Köln, Nordrhein-Westfalen",
+        "Berlin, DE",
+    ] {
+        let score = engine.score(&onsite_job(location));
+        assert_eq!(score.breakdown.location, 0.20, "{location}");
+    }
+
+    let score = engine.score(&onsite_job("Leeds, England"));
+    assert_eq!(score.breakdown.location, 0.0);
+}
+
+#[test]
+fn home_country_accepts_names_as_well_as_codes() {
+    let engine = onsite_engine(|config| {
+        config.location_preferences.country = "United Kingdom".to_string();
+    });
+
+    assert_eq!(
+        engine
+            .score(&onsite_job("Glasgow, Scotland"))
+            .breakdown
+            .location,
+        0.20
+    );
+    assert_eq!(
+        engine.score(&onsite_job("Seattle, WA")).breakdown.location,
+        0.0
+    );
+}
+
+#[test]
+fn unrecognised_locations_are_not_rejected() {
+    let engine = onsite_engine(|_| {});
+
+    assert_eq!(
+        engine.score(&onsite_job("Springfield")).breakdown.location,
+        0.20
+    );
+}
+
+#[test]
+fn preferred_cities_and_provinces_are_named_in_reasons() {
+    let engine = onsite_engine(|config| {
+        config.location_preferences.country = "CA".to_string();
+        config.location_preferences.cities = vec!["Calgary".to_string()];
+        config.location_preferences.states = vec!["QC".to_string()];
+    });
+
+    let calgary = engine.score(&onsite_job("Calgary, AB"));
+    assert!(calgary
+        .reasons
+        .iter()
+        .any(|reason| reason == "In preferred city Calgary"));
+
+    let montreal = engine.score(&onsite_job("Montréal, Québec"));
+    assert!(montreal
+        .reasons
+        .iter()
+        .any(|reason| reason == "In preferred region Quebec"));
+}
+
+#[test]
+fn city_aliases_match_preferred_cities_and_countries() {
+    let engine = onsite_engine(|config| {
+        config.location_preferences.cities = vec!["New York City".to_string()];
+    });
+
+    let nyc = engine.score(&onsite_job("Brooklyn, NY"));
+    assert!(nyc
+        .reasons
+        .iter()
+        .any(|reason| reason == "In preferred city New York City"));
+
+    let munich = engine.score(&onsite_job("München"));
+    assert_eq!(munich.breakdown.location, 0.0);
+    assert!(munich
+        .reasons
+        .iter()
+        .any(|reason| reason.contains("Bavaria (outside your countries)")));
+}
+
+fn commute_engine() -> ScoringEngine {
+    let mut config = create_test_config();
+    config.location_preferences.allow_onsite = true;
+    config.location_preferences.allow_hybrid = true;
+    // Downtown Austin, with a 30 km commute
+    config.commute.home = Some(Coordinates::new(30.2672, -97.7431));
+    config.commute.max_distance_km = 30.0;
+    ScoringEngine::new(Arc::new(config)).with_job_coordinates(HashMap::from([
+        (
+            "round rock, tx".to_string(),
+            Coordinates::new(30.5083, -97.6789),
+        ),
+        (
+            "houston, tx".to_string(),
+            Coordinates::new(29.7604, -95.3698),
+        ),
+    ]))
+}
+
+#[test]
+fn onsite_jobs_within_commute_keep_full_location_credit() {
+    let score = commute_engine().score(&onsite_job("Round Rock, TX"));
+
+    assert_eq!(score.breakdown.location, 0.20);
+    assert!(score
+        .reasons
+        .iter()
+        .any(|reason| reason.contains("within your commute")));
+}
+
+#[test]
+fn jobs_beyond_commute_lose_location_credit_hybrid_less_so() {
+    let engine = commute_engine();
+
+    let onsite = engine.score(&onsite_job("Houston, TX"));
+    let hybrid = engine.score(&Job {
+        title: "Hybrid Case Manager".to_string(),
+        ..onsite_job("Houston, TX")
+    });
+
+    // Houston is about 235 km away: roughly an eighth of the radius
+    assert!(onsite.breakdown.location > 0.0 && onsite.breakdown.location < 0.04);
+    assert!(hybrid.breakdown.location > 0.10 && hybrid.breakdown.location < 0.13);
+    assert!(onsite
+        .reasons
+        .iter()
+        .any(|reason| reason.contains("beyond your 30 km commute")));
+}
+
+#[test]
+fn jobs_without_coordinates_score_as_before() {
+    let score = commute_engine().score(&onsite_job("Dallas, TX"));
+
+    assert_eq!(score.breakdown.location, 0.20);
+    assert!(!score
+        .reasons
+        .iter()
+        .any(|reason| reason.contains("from home")));
+}
@@ -418,4 +418,6 @@ mod boundary_tests;
 mod component_edge_cases;
 
 mod company_tests;
+mod feedback_tests;
+mod location_tests;
 mod salary_tests;
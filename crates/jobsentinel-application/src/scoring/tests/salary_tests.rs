@@ -199,3 +199,127 @@ fn test_salary_above_target_exact_boundaries() {
         "Salary at exactly 120% of target should get 1.2 multiplier (bonus)"
     );
 }
+
+fn salary_score_with_range(min: Option<i64>, max: Option<i64>) -> (f64, Vec<String>) {
+    let mut config = create_test_config();
+    config.salary_target_max_usd = Some(220000);
+    let mut job = create_test_job();
+    job.salary_min = min;
+    job.salary_max = max;
+
+    let engine = ScoringEngine::new(Arc::new(config));
+    engine.score_salary(&job)
+}
+
+#[test]
+fn test_salary_range_overlap_is_graded() {
+    // Target range 180k-220k, salary weight 0.25.
+    let (inside, reasons) = salary_score_with_range(Some(190000), Some(210000));
+    assert!((inside - 0.25 * 0.975).abs() < 1e-9);
+    assert!(reasons[0].contains("overlaps your $180000-$220000 range"));
+    assert!(reasons[0].contains("100% of the posted range meets your minimum"));
+    assert!(reasons[0].contains("top reaches the 75th percentile"));
+
+    let (straddling, _) = salary_score_with_range(Some(160000), Some(200000));
+    assert!((straddling - 0.25 * 0.85).abs() < 1e-9);
+    assert!(straddling < inside);
+
+    let (above, reasons) = salary_score_with_range(Some(230000), Some(260000));
+    assert!((above - 0.30).abs() < 1e-9);
+    assert!(reasons[0].contains("at or above the top"));
+}
+
+#[test]
+fn test_salary_range_below_target_scales_with_shortfall() {
+    let (near, reasons) = salary_score_with_range(Some(150000), Some(171000));
+    // Top is 95% of the 180k minimum.
+    assert!((near - 0.25 * (0.3 + 0.4 * (0.25 / 0.3))).abs() < 1e-9);
+    assert!(reasons[0].contains("below your $180000-$220000 range"));
+
+    let (far, _) = salary_score_with_range(Some(90000), Some(110000));
+    assert!((far - 0.25 * 0.3).abs() < 1e-9);
+}
+
+#[test]
+fn test_hourly_salary_is_annualized() {
+    // $95/hour is about $197,600 a year, inside the target range.
+    let (score, reasons) = salary_score_with_range(Some(95), None);
+    assert!(score > 0.25 * 0.9);
+    assert!(reasons.iter().any(|reason| reason.contains("$197600")));
+    assert!(reasons
+        .iter()
+        .any(|reason| reason == "Pay quoted per hour, annualized"));
+}
+
+#[test]
+fn test_day_rate_uses_the_posted_pay_period() {
+    let config = create_test_config();
+    let mut job = create_test_job();
+    // $800/day over 260 working days is $208,000 a year.
+    job.salary_min = Some(800);
+    job.salary_max = Some(800);
+    job.pay_period = Some(jobsentinel_domain::PayPeriod::Day);
+
+    let engine = ScoringEngine::new(Arc::new(config));
+    let (_, reasons) = engine.score_salary(&job);
+
+    assert!(reasons.iter().any(|reason| reason.contains("$208000")));
+    assert!(reasons
+        .iter()
+        .any(|reason| reason == "Pay quoted per day, annualized"));
+}
+
+#[test]
+fn test_non_usd_salary_is_converted_before_comparing() {
+    let config = create_test_config();
+    let mut job = create_test_job();
+    // 150,000 EUR is 180,000 USD at 1.2.
+    job.salary_min = Some(150000);
+    job.salary_max = Some(150000);
+    job.currency = Some("EUR".to_string());
+    let mut rates = jobsentinel_domain::ExchangeRates::bundled();
+    rates.set_rate("EUR", 1.2);
+
+    let engine = ScoringEngine::new(Arc::new(config)).with_exchange_rates(rates);
+    let (score, reasons) = engine.score_salary(&job);
+
+    assert!((score - 0.25).abs() < 1e-9);
+    assert!(reasons
+        .iter()
+        .any(|reason| reason.contains("converted from EUR")));
+    assert!(reasons.iter().any(|reason| reason.contains("$180000")));
+}
+
+#[test]
+fn test_unknown_currency_gets_missing_salary_credit() {
+    let config = create_test_config();
+    let mut job = create_test_job();
+    job.salary_min = Some(150000);
+    job.currency = Some("ABC".to_string());
+
+    let engine = ScoringEngine::new(Arc::new(config));
+    let (score, reasons) = engine.score_salary(&job);
+
+    assert!((score - 0.25 * 0.5).abs() < 1e-9);
+    assert!(reasons[0].contains("could not be converted"));
+}
+
+#[test]
+fn test_salary_percentile_ordinals() {
+    use crate::scoring::salary::ordinal;
+
+    assert_eq!(ordinal(1), "1st");
+    assert_eq!(ordinal(2), "2nd");
+    assert_eq!(ordinal(3), "3rd");
+    assert_eq!(ordinal(4), "4th");
+    assert_eq!(ordinal(11), "11th");
+    assert_eq!(ordinal(12), "12th");
+    assert_eq!(ordinal(13), "13th");
+    assert_eq!(ordinal(21), "21st");
+    assert_eq!(ordinal(22), "22nd");
+    assert_eq!(ordinal(100), "100th");
+
+    // Target range 180k-220k; a posted top of $181,200 is 3% of the way up.
+    let (_, reasons) = salary_score_with_range(Some(170000), Some(181200));
+    assert!(reasons[0].contains("top reaches the 3rd percentile"));
+}
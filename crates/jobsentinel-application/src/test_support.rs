@@ -1,6 +1,6 @@
 use crate::config::{Config, LocationPreferences};
 use chrono::Utc;
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 
 pub(crate) fn minimal_test_config() -> Config {
     Config {
@@ -15,17 +15,22 @@ pub(crate) fn minimal_test_config() -> Config {
             cities: vec![],
             states: vec![],
             country: "US".to_string(),
+            countries: Vec::new(),
         },
+        commute: Default::default(),
         salary_floor_usd: 100_000,
         salary_target_usd: None,
+        salary_target_max_usd: None,
         penalize_missing_salary: false,
         auto_refresh: Default::default(),
         bookmarklet_port: 4321,
         immediate_alert_threshold: 0.8,
         scraping_interval_hours: 2,
+        scraping_concurrency: 3,
         alerts: Default::default(),
         greenhouse_urls: vec![],
         lever_urls: vec![],
+        career_pages: vec![],
         linkedin: Default::default(),
         restricted_source_acknowledgements: Default::default(),
         jobswithgpt_endpoint: "https://api.jobswithgpt.com/mcp".to_string(),
@@ -40,10 +45,16 @@ pub(crate) fn minimal_test_config() -> Config {
         usajobs: Default::default(),
         simplyhired: Default::default(),
         glassdoor: Default::default(),
+        proxy: Default::default(),
+        source_limits: Default::default(),
+        rate_limits: Default::default(),
+        power_saving: Default::default(),
         ghost_config: None,
         preferred_companies: vec![],
         blocked_companies: vec![],
         use_resume_matching: false,
+        scoring: Default::default(),
+        retention: Default::default(),
     }
 }
 
@@ -64,6 +75,7 @@ pub(crate) fn test_job(hash: &str, title: &str, company: &str) -> Job {
         salary_min: None,
         salary_max: None,
         currency: None,
+        pay_period: None,
         created_at: now,
         updated_at: now,
         last_seen: now,
@@ -77,5 +89,7 @@ pub(crate) fn test_job(hash: &str, title: &str, company: &str) -> Job {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
     }
 }
@@ -386,8 +386,9 @@ mod salary_commands {
         let (database, _config, _temp_dir) = setup_test_env().await;
         let analyzer = database.salary_analyzer();
 
-        // compare_offers takes Vec<i64> of offer IDs
-        let result = analyzer.compare_offers(vec![]).await;
+        // compare_offers takes Vec<i64> of offer IDs and the exchange rates to use
+        let rates = jobsentinel_application::salary::load_exchange_rates(&database).await;
+        let result = analyzer.compare_offers(vec![], &rates).await;
         assert!(result.is_ok());
     }
 }
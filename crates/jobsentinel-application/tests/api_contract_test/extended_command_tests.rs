@@ -122,7 +122,7 @@ mod config_commands {
 
 mod user_data_commands {
     use super::*;
-    use jobsentinel_application::user_data::{SavedSearch, TemplateCategory};
+    use jobsentinel_application::user_data::{SavedSearch, SearchAgentSettings, TemplateCategory};
 
     #[tokio::test]
     async fn test_cover_letter_template_crud() {
@@ -175,6 +175,7 @@ mod user_data_commands {
             text_search: Some("remote customer support".to_string()),
             created_at: String::new(),
             last_used_at: None,
+            agent: SearchAgentSettings::default(),
         };
 
         let created = manager.create_saved_search(search).await.unwrap();
@@ -100,6 +100,7 @@ async fn test_scraping_result_error_collection() {
             "Greenhouse scraper failed: timeout".to_string(),
             "Lever scraper failed: rate limited".to_string(),
         ],
+        throttle_events: Vec::new(),
     };
 
     assert_eq!(result.errors.len(), 2);
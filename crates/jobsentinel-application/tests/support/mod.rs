@@ -1,6 +1,6 @@
 use chrono::Utc;
 use jobsentinel_application::config::{Config, LocationPreferences};
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 
 pub(crate) fn test_config() -> Config {
     Config {
@@ -18,18 +18,23 @@ pub(crate) fn test_config() -> Config {
             cities: vec!["Chicago".to_string()],
             states: vec!["IL".to_string()],
             country: "US".to_string(),
+            countries: Vec::new(),
         },
+        commute: Default::default(),
         salary_floor_usd: 50000,
         salary_target_usd: None,
+        salary_target_max_usd: None,
         penalize_missing_salary: false,
         auto_refresh: Default::default(),
         immediate_alert_threshold: 0.85,
         scraping_interval_hours: 2,
+        scraping_concurrency: 3,
         bookmarklet_port: 4321,
         alerts: Default::default(),
         external_ai: Default::default(),
         greenhouse_urls: vec![],
         lever_urls: vec![],
+        career_pages: vec![],
         linkedin: Default::default(),
         restricted_source_acknowledgements: Default::default(),
         jobswithgpt_endpoint: "https://api.jobswithgpt.com/mcp".to_string(),
@@ -43,8 +48,14 @@ pub(crate) fn test_config() -> Config {
         usajobs: Default::default(),
         simplyhired: Default::default(),
         glassdoor: Default::default(),
+        proxy: Default::default(),
+        source_limits: Default::default(),
+        rate_limits: Default::default(),
+        power_saving: Default::default(),
         ghost_config: None,
         use_resume_matching: false,
+        scoring: Default::default(),
+        retention: Default::default(),
         preferred_companies: vec![],
         blocked_companies: vec![],
     }
@@ -67,6 +78,7 @@ pub(crate) fn test_job(hash: &str, title: &str, company: &str) -> Job {
         salary_min: None,
         salary_max: None,
         currency: Some("USD".to_string()),
+        pay_period: None,
         created_at: now,
         updated_at: now,
         last_seen: now,
@@ -80,5 +92,7 @@ pub(crate) fn test_job(hash: &str, title: &str, company: &str) -> Job {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
     }
 }
@@ -59,12 +59,16 @@ fn cleanup_automation_browser_profile_dir(profile_dir: &Path) {
     }
 }
 
-fn automation_browser_config(profile_dir: &Path) -> Result<BrowserConfig> {
+fn automation_browser_config(profile_dir: &Path, headless: bool) -> Result<BrowserConfig> {
     let mut config_builder = BrowserConfig::builder()
         .window_size(1280, 900)
-        .user_data_dir(profile_dir)
+        .user_data_dir(profile_dir);
+    config_builder = if headless {
+        config_builder.new_headless_mode()
+    } else {
         // Visible mode - user can see form being filled
-        .with_head();
+        config_builder.with_head()
+    };
 
     for arg in automation_browser_launch_args() {
         config_builder = config_builder.arg(*arg);
@@ -108,6 +112,7 @@ async fn close_browser_session(session: &mut BrowserSession) {
 /// Browser manager for automation
 pub struct BrowserManager {
     browser: Arc<Mutex<Option<BrowserSession>>>,
+    headless: bool,
 }
 
 impl BrowserManager {
@@ -115,18 +120,29 @@ impl BrowserManager {
     pub fn new() -> Self {
         Self {
             browser: Arc::new(Mutex::new(None)),
+            headless: false,
+        }
+    }
+
+    /// Create a manager that launches Chrome without a window, for rendering
+    /// pages in the background rather than filling forms
+    pub fn headless() -> Self {
+        Self {
+            headless: true,
+            ..Self::new()
         }
     }
 
     /// Launch the browser
     ///
-    /// Launches Chrome in visible mode so user can watch form filling.
+    /// Launches Chrome in visible mode so user can watch form filling, unless
+    /// the manager was created with [`BrowserManager::headless`].
     #[tracing::instrument(skip(self), level = "info")]
     pub async fn launch(&self) -> Result<()> {
         use std::time::Instant;
 
         let start = Instant::now();
-        tracing::info!("Launching browser in visible mode");
+        tracing::info!(headless = self.headless, "Launching browser");
         let mut browser_guard = self.browser.lock().await;
 
         if browser_guard.is_some() {
@@ -135,7 +151,7 @@ impl BrowserManager {
         }
 
         let profile_dir = create_automation_browser_profile_dir()?;
-        let config = match automation_browser_config(&profile_dir) {
+        let config = match automation_browser_config(&profile_dir, self.headless) {
             Ok(config) => config,
             Err(error) => {
                 cleanup_automation_browser_profile_dir(&profile_dir);
@@ -166,7 +182,8 @@ impl BrowserManager {
         let duration = start.elapsed();
         tracing::info!(
             elapsed_ms = duration.as_millis(),
-            "Browser launched successfully (visible mode, 1280x900)"
+            headless = self.headless,
+            "Browser launched successfully (1280x900)"
         );
         *browser_guard = Some(BrowserSession {
             browser,
@@ -211,6 +228,20 @@ impl BrowserManager {
         Ok(AutomationPage::new(page))
     }
 
+    /// Load `url`, give client-side scripts `settle` to render, and return
+    /// the resulting DOM as HTML
+    ///
+    /// Launches the browser on first use; the page is closed afterwards.
+    pub async fn render_html(&self, url: &str, settle: Duration) -> Result<String> {
+        self.launch().await?;
+        let page = self.new_page(url).await?;
+        tokio::time::sleep(settle).await;
+        let html = page.get_html().await;
+        let _ =
+            tokio::time::timeout(BROWSER_PAGE_CLOSE_TIMEOUT, page.inner().clone().close()).await;
+        html
+    }
+
     /// Close the browser
     pub async fn close(&self) -> Result<()> {
         let mut browser_guard = self.browser.lock().await;
@@ -268,7 +299,7 @@ mod tests {
         let profile_dir = temp_dir.path().join("profile");
         std::fs::create_dir_all(&profile_dir).unwrap();
 
-        let config = automation_browser_config(&profile_dir).unwrap();
+        let config = automation_browser_config(&profile_dir, false).unwrap();
 
         assert_eq!(config.user_data_dir.as_deref(), Some(profile_dir.as_path()));
     }
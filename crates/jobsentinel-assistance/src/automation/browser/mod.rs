@@ -8,6 +8,7 @@
 //! - Form field filling
 //! - File upload support (resumes)
 //! - Screenshot capture
+//! - Headless page rendering for JavaScript-driven career pages
 //!
 //! **Safety:**
 //! - No final-submit action
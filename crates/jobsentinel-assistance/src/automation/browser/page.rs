@@ -7,8 +7,10 @@ use chromiumoxide::cdp::browser_protocol::page::{
     CaptureScreenshotFormat, CaptureScreenshotParams,
 };
 use chromiumoxide::Page;
+use jobsentinel_domain::AttemptFillSummary;
 use jobsentinel_security::path_label_for_logging;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 
 const FILE_UPLOAD_UNAVAILABLE: &str = "Could not attach the selected resume file";
@@ -36,6 +38,43 @@ fn dropdown_select_script(selector: &str, value: &str) -> Result<String> {
     ))
 }
 
+/// Elements that mark a CAPTCHA or anti-bot challenge
+const CHALLENGE_SELECTORS: &[&str] = &[
+    // reCAPTCHA
+    ".g-recaptcha",
+    "#recaptcha",
+    "iframe[src*='recaptcha']",
+    // hCaptcha
+    ".h-captcha",
+    "iframe[src*='hcaptcha']",
+    // Cloudflare challenge page and Turnstile
+    "#challenge-form",
+    "#challenge-running",
+    "#cf-challenge-running",
+    ".cf-turnstile",
+    "iframe[src*='challenges.cloudflare.com']",
+    // Generic
+    "[data-captcha]",
+    ".captcha",
+    "#captcha",
+];
+
+/// Text in page HTML that marks a CAPTCHA or anti-bot challenge
+const CHALLENGE_HTML_MARKERS: &[&str] = &[
+    "recaptcha",
+    "hcaptcha",
+    "challenges.cloudflare.com",
+    "cf-turnstile",
+    "cf_chl_opt",
+];
+
+fn html_has_security_challenge(html: &str) -> bool {
+    let html = html.to_lowercase();
+    CHALLENGE_HTML_MARKERS
+        .iter()
+        .any(|marker| html.contains(marker))
+}
+
 /// Automation page wrapper
 pub struct AutomationPage {
     page: Page,
@@ -47,10 +86,15 @@ pub struct AutomationPage {
 pub struct FillResult {
     /// Fields that were successfully filled
     pub filled_fields: Vec<String>,
+    /// Value entered for each filled field, keyed by field name or question
+    #[serde(default)]
+    pub filled_values: BTreeMap<String, String>,
     /// Review topics for saved screening answers that were prepared
     pub screening_answer_topics: Vec<String>,
     /// Fields that could not be filled (not found or error)
     pub unfilled_fields: Vec<String>,
+    /// Application questions with no saved answer, for the user to answer
+    pub unanswered_questions: Vec<String>,
     /// Whether a CAPTCHA was detected
     pub captcha_detected: bool,
     /// Whether the form is ready for user to review and submit
@@ -63,8 +107,10 @@ impl FillResult {
     pub fn new() -> Self {
         Self {
             filled_fields: Vec::new(),
+            filled_values: BTreeMap::new(),
             screening_answer_topics: Vec::new(),
             unfilled_fields: Vec::new(),
+            unanswered_questions: Vec::new(),
             captcha_detected: false,
             ready_for_review: false,
             error_message: None,
@@ -74,8 +120,10 @@ impl FillResult {
     pub fn success(filled: Vec<String>) -> Self {
         Self {
             filled_fields: filled,
+            filled_values: BTreeMap::new(),
             screening_answer_topics: Vec::new(),
             unfilled_fields: Vec::new(),
+            unanswered_questions: Vec::new(),
             captcha_detected: false,
             ready_for_review: true,
             error_message: None,
@@ -85,8 +133,10 @@ impl FillResult {
     pub fn partial(filled: Vec<String>, unfilled: Vec<String>) -> Self {
         Self {
             filled_fields: filled,
+            filled_values: BTreeMap::new(),
             screening_answer_topics: Vec::new(),
             unfilled_fields: unfilled,
+            unanswered_questions: Vec::new(),
             captcha_detected: false,
             ready_for_review: true,
             error_message: None,
@@ -99,6 +149,22 @@ impl FillResult {
         self
     }
 
+    /// What was entered and what is left, for the review screen
+    pub fn summary(&self) -> AttemptFillSummary {
+        AttemptFillSummary {
+            filled_values: self.filled_values.clone(),
+            unfilled_fields: self.unfilled_fields.clone(),
+            unanswered_questions: self.unanswered_questions.clone(),
+        }
+    }
+
+    /// Record a filled field and the value entered into it
+    pub fn record_filled(&mut self, field: &str, key: &str, value: &str) {
+        self.filled_fields.push(field.to_string());
+        self.filled_values
+            .insert(key.to_string(), value.to_string());
+    }
+
     pub fn add_screening_answer_topic(&mut self, topic: Option<&str>) {
         let Some(topic) = topic else {
             return;
@@ -274,6 +340,11 @@ impl AutomationPage {
         Ok(())
     }
 
+    /// Close the page, discarding anything filled into it
+    pub async fn close(self) -> Result<()> {
+        self.page.close().await.context("Failed to close page")
+    }
+
     /// Get the page HTML content
     pub async fn get_html(&self) -> Result<String> {
         let html = self
@@ -284,34 +355,21 @@ impl AutomationPage {
         Ok(html)
     }
 
-    /// Check if a CAPTCHA is present on the page
+    /// Check if a CAPTCHA or anti-bot challenge is present on the page
+    ///
+    /// Covers reCAPTCHA, hCaptcha, and Cloudflare challenge pages and
+    /// Turnstile widgets. Callers stop and hand the page back to the user.
     pub async fn has_captcha(&self) -> bool {
-        // Common CAPTCHA indicators
-        let captcha_selectors = [
-            // reCAPTCHA
-            ".g-recaptcha",
-            "#recaptcha",
-            "iframe[src*='recaptcha']",
-            // hCaptcha
-            ".h-captcha",
-            "iframe[src*='hcaptcha']",
-            // Generic
-            "[data-captcha]",
-            ".captcha",
-            "#captcha",
-        ];
-
-        for selector in captcha_selectors {
-            if self.page.find_element(selector).await.is_ok() {
+        for selector in CHALLENGE_SELECTORS {
+            if self.page.find_element(*selector).await.is_ok() {
                 tracing::warn!("CAPTCHA detected with selector: {}", selector);
                 return true;
             }
         }
 
-        // Also check HTML content for CAPTCHA mentions
+        // Also check HTML content for challenge scripts and frames
         if let Ok(html) = self.get_html().await {
-            let html_lower = html.to_lowercase();
-            if html_lower.contains("recaptcha") || html_lower.contains("hcaptcha") {
+            if html_has_security_challenge(&html) {
                 tracing::warn!("CAPTCHA detected in page HTML");
                 return true;
             }
@@ -335,6 +393,7 @@ mod tests {
         let result = FillResult::new();
         assert!(result.filled_fields.is_empty());
         assert!(result.unfilled_fields.is_empty());
+        assert!(result.unanswered_questions.is_empty());
         assert!(!result.captcha_detected);
         assert!(!result.ready_for_review);
     }
@@ -376,6 +435,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn security_challenges_are_detected_in_page_html() {
+        assert!(html_has_security_challenge(
+            r#"<script src="https://www.google.com/reCAPTCHA/api.js"></script>"#
+        ));
+        assert!(html_has_security_challenge(
+            r#"<iframe src="https://newassets.hcaptcha.com/captcha/v1"></iframe>"#
+        ));
+        assert!(html_has_security_challenge(
+            r#"<script>window._cf_chl_opt = { cType: "managed" };</script>"#
+        ));
+        assert!(html_has_security_challenge(
+            r#"<div class="cf-turnstile" data-sitekey="0x4AAA"></div>"#
+        ));
+        assert!(!html_has_security_challenge(
+            r#"<form id="application-form"><input name="email"></form>"#
+        ));
+    }
+
     #[test]
     fn file_upload_errors_do_not_echo_local_paths() {
         for message in [FILE_UPLOAD_UNAVAILABLE, FILE_UPLOAD_SETUP_ERROR] {
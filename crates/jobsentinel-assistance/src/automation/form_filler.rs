@@ -2,7 +2,8 @@
 //!
 //! Fills job application forms with user profile data.
 //! Platform-specific selectors for each ATS type.
-//! Also handles screening questions using stored answer patterns.
+//! Also handles screening questions using stored answer patterns, flagging
+//! Lever custom questions that have no saved answer.
 
 use super::browser::{AutomationPage, FillResult};
 use super::AtsPlatform;
@@ -89,6 +90,16 @@ fn screening_answer_review_topic(pattern: &str) -> Option<&'static str> {
     }
 }
 
+/// Resume name shown for review, without the managed copy's unique prefix
+fn resume_display_name(path: &Path) -> &str {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return "resume";
+    };
+    file_name
+        .split_once("--")
+        .map_or(file_name, |(_, display_name)| display_name)
+}
+
 /// Form filler - fills application forms based on ATS platform
 pub struct FormFiller {
     profile: ApplicationProfile,
@@ -182,8 +193,12 @@ impl FormFiller {
                 .await;
         }
 
-        // Fill screening questions using stored answers
-        if !self.screening_answers.is_empty() {
+        // Fill screening questions using stored answers. Lever's custom
+        // questions are found directly so unanswered ones can be flagged.
+        if *platform == AtsPlatform::Lever {
+            tracing::debug!("Filling Lever custom questions");
+            self.fill_lever_custom_questions(page, &mut result).await;
+        } else if !self.screening_answers.is_empty() {
             let answer_count = self.screening_answers.len();
             tracing::debug!(answer_count, "Filling screening questions");
             self.fill_screening_questions(page, &mut result).await;
@@ -222,7 +237,7 @@ impl FormFiller {
                 .unwrap_or("");
             for selector in sel_list {
                 if let Ok(true) = page.fill(selector, first_name).await {
-                    result.filled_fields.push("first_name".to_string());
+                    result.record_filled("first_name", "first_name", first_name);
                     break;
                 }
             }
@@ -238,7 +253,7 @@ impl FormFiller {
                 .unwrap_or("");
             for selector in sel_list {
                 if let Ok(true) = page.fill(selector, last_name).await {
-                    result.filled_fields.push("last_name".to_string());
+                    result.record_filled("last_name", "last_name", last_name);
                     break;
                 }
             }
@@ -248,7 +263,7 @@ impl FormFiller {
         if let Some(sel_list) = selectors.get(&FieldType::FullName) {
             for selector in sel_list {
                 if let Ok(true) = page.fill(selector, &self.profile.full_name).await {
-                    result.filled_fields.push("full_name".to_string());
+                    result.record_filled("full_name", "full_name", &self.profile.full_name);
                     break;
                 }
             }
@@ -258,7 +273,7 @@ impl FormFiller {
         if let Some(sel_list) = selectors.get(&FieldType::Email) {
             for selector in sel_list {
                 if let Ok(true) = page.fill(selector, &self.profile.email).await {
-                    result.filled_fields.push("email".to_string());
+                    result.record_filled("email", "email", &self.profile.email);
                     break;
                 }
             }
@@ -269,7 +284,7 @@ impl FormFiller {
             if let Some(sel_list) = selectors.get(&FieldType::Phone) {
                 for selector in sel_list {
                     if let Ok(true) = page.fill(selector, phone).await {
-                        result.filled_fields.push("phone".to_string());
+                        result.record_filled("phone", "phone", phone);
                         break;
                     }
                 }
@@ -289,7 +304,7 @@ impl FormFiller {
             if let Some(sel_list) = selectors.get(&FieldType::LinkedIn) {
                 for selector in sel_list {
                     if let Ok(true) = page.fill(selector, url).await {
-                        result.filled_fields.push("linkedin".to_string());
+                        result.record_filled("linkedin", "linkedin", url);
                         break;
                     }
                 }
@@ -301,7 +316,7 @@ impl FormFiller {
             if let Some(sel_list) = selectors.get(&FieldType::GitHub) {
                 for selector in sel_list {
                     if let Ok(true) = page.fill(selector, url).await {
-                        result.filled_fields.push("github".to_string());
+                        result.record_filled("github", "github", url);
                         break;
                     }
                 }
@@ -313,7 +328,7 @@ impl FormFiller {
             if let Some(sel_list) = selectors.get(&FieldType::Portfolio) {
                 for selector in sel_list {
                     if let Ok(true) = page.fill(selector, url).await {
-                        result.filled_fields.push("portfolio".to_string());
+                        result.record_filled("portfolio", "portfolio", url);
                         break;
                     }
                 }
@@ -325,7 +340,7 @@ impl FormFiller {
             if let Some(sel_list) = selectors.get(&FieldType::Website) {
                 for selector in sel_list {
                     if let Ok(true) = page.fill(selector, url).await {
-                        result.filled_fields.push("website".to_string());
+                        result.record_filled("website", "website", url);
                         break;
                     }
                 }
@@ -350,11 +365,11 @@ impl FormFiller {
             for selector in sel_list {
                 // Try both fill (for text input) and select (for dropdown)
                 if let Ok(true) = page.fill(selector, value).await {
-                    result.filled_fields.push("work_authorized".to_string());
+                    result.record_filled("work_authorized", "work_authorized", value);
                     break;
                 }
                 if let Ok(true) = page.select(selector, value).await {
-                    result.filled_fields.push("work_authorized".to_string());
+                    result.record_filled("work_authorized", "work_authorized", value);
                     break;
                 }
             }
@@ -369,15 +384,11 @@ impl FormFiller {
             };
             for selector in sel_list {
                 if let Ok(true) = page.fill(selector, value).await {
-                    result
-                        .filled_fields
-                        .push("requires_sponsorship".to_string());
+                    result.record_filled("requires_sponsorship", "requires_sponsorship", value);
                     break;
                 }
                 if let Ok(true) = page.select(selector, value).await {
-                    result
-                        .filled_fields
-                        .push("requires_sponsorship".to_string());
+                    result.record_filled("requires_sponsorship", "requires_sponsorship", value);
                     break;
                 }
             }
@@ -393,9 +404,10 @@ impl FormFiller {
         result: &mut FillResult,
     ) {
         if let Some(sel_list) = selectors.get(&FieldType::Resume) {
+            let file_name = resume_display_name(resume_path);
             for selector in sel_list {
                 if let Ok(true) = page.upload_file(selector, resume_path).await {
-                    result.filled_fields.push("resume".to_string());
+                    result.record_filled("resume", "resume", file_name);
                     break;
                 }
             }
@@ -408,8 +420,13 @@ impl FormFiller {
     }
 }
 
+mod lever_questions;
 mod questions;
 
+#[cfg(test)]
+use lever_questions::{
+    clean_question_text, matching_option, parse_lever_questions, LeverQuestionKind,
+};
 #[cfg(test)]
 use questions::question_discovery_error;
 
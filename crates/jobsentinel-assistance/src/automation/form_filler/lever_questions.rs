@@ -0,0 +1,161 @@
+use super::questions::question_discovery_error;
+use super::*;
+use serde::Deserialize;
+
+/// Tags each Lever custom question's inputs with `data-jobsentinel-field` so
+/// they can be addressed without building selectors from page content
+const LEVER_QUESTION_SCRIPT: &str = r#"
+    (function() {
+        const results = [];
+        const questions = document.querySelectorAll('.application-question.custom-question');
+
+        questions.forEach((question, index) => {
+            const label = question.querySelector('.application-label .text, .application-label');
+            const text = label?.textContent?.trim();
+            if (!text) return;
+
+            const field = `lever-question-${index}`;
+            const choices = question.querySelectorAll('input[type="radio"], input[type="checkbox"]');
+            if (choices.length > 0) {
+                const options = [];
+                choices.forEach((choice, optionIndex) => {
+                    const optionField = `${field}-${optionIndex}`;
+                    choice.setAttribute('data-jobsentinel-field', optionField);
+                    const optionLabel = choice.closest('label')?.textContent?.trim() || choice.value;
+                    options.push({ label: optionLabel, field: optionField });
+                });
+                results.push({ text, kind: choices[0].type, field, options });
+                return;
+            }
+
+            const input = question.querySelector('textarea, select, input:not([type="hidden"]):not([type="file"])');
+            if (!input) return;
+            input.setAttribute('data-jobsentinel-field', field);
+            const kind = input.tagName === 'SELECT' ? 'select' : 'text';
+            results.push({ text, kind, field, options: [] });
+        });
+
+        return results;
+    })()
+"#;
+
+/// A custom question found on a Lever application form
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(super) struct LeverQuestion {
+    pub(super) text: String,
+    pub(super) kind: LeverQuestionKind,
+    pub(super) field: String,
+    #[serde(default)]
+    pub(super) options: Vec<LeverQuestionOption>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum LeverQuestionKind {
+    Text,
+    Select,
+    Radio,
+    Checkbox,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(super) struct LeverQuestionOption {
+    pub(super) label: String,
+    pub(super) field: String,
+}
+
+fn field_selector(field: &str) -> String {
+    format!("[data-jobsentinel-field=\"{field}\"]")
+}
+
+/// Question text without Lever's required-field marker and extra whitespace
+pub(super) fn clean_question_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['✱', '*'])
+        .trim_end()
+        .to_string()
+}
+
+/// The choice whose label matches a saved answer, ignoring case
+pub(super) fn matching_option<'a>(
+    options: &'a [LeverQuestionOption],
+    answer: &str,
+) -> Option<&'a LeverQuestionOption> {
+    let answer = answer.trim();
+    options
+        .iter()
+        .find(|option| option.label.trim().eq_ignore_ascii_case(answer))
+}
+
+impl FormFiller {
+    /// Answer Lever's custom questions from saved screening answers
+    ///
+    /// Questions without a saved answer, or whose answer is not one of the
+    /// choices offered, are added to `unanswered_questions` for the user.
+    pub(super) async fn fill_lever_custom_questions(
+        &self,
+        page: &AutomationPage,
+        result: &mut FillResult,
+    ) {
+        let Ok(questions) = find_lever_questions(page).await else {
+            tracing::warn!("Could not inspect Lever custom questions");
+            return;
+        };
+        tracing::debug!(
+            question_count = questions.len(),
+            "Found Lever custom questions"
+        );
+
+        for question in questions {
+            let text = clean_question_text(&question.text);
+            let Some(answer) = self.find_screening_answer_for_question(&text) else {
+                result.unanswered_questions.push(text);
+                continue;
+            };
+
+            if answer_lever_question(page, &question, &answer.answer).await {
+                result.record_filled(SCREENING_FIELD_LABEL, &text, &answer.answer);
+                result.add_screening_answer_topic(screening_answer_review_topic(
+                    &answer.question_pattern,
+                ));
+            } else {
+                result.unanswered_questions.push(text);
+            }
+        }
+    }
+}
+
+async fn answer_lever_question(
+    page: &AutomationPage,
+    question: &LeverQuestion,
+    answer: &str,
+) -> bool {
+    let filled = match question.kind {
+        LeverQuestionKind::Text => page.fill(&field_selector(&question.field), answer).await,
+        LeverQuestionKind::Select => page.select(&field_selector(&question.field), answer).await,
+        LeverQuestionKind::Radio | LeverQuestionKind::Checkbox => {
+            match matching_option(&question.options, answer) {
+                Some(option) => page.click(&field_selector(&option.field)).await,
+                None => Ok(false),
+            }
+        }
+    };
+
+    filled.unwrap_or(false)
+}
+
+async fn find_lever_questions(page: &AutomationPage) -> Result<Vec<LeverQuestion>> {
+    let value = page
+        .inner()
+        .evaluate(LEVER_QUESTION_SCRIPT)
+        .await
+        .map_err(|_| question_discovery_error())?;
+
+    parse_lever_questions(value.into_value().map_err(|_| question_discovery_error())?)
+}
+
+pub(super) fn parse_lever_questions(value: serde_json::Value) -> Result<Vec<LeverQuestion>> {
+    serde_json::from_value(value).map_err(|_| question_discovery_error())
+}
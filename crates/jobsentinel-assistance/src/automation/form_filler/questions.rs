@@ -39,14 +39,22 @@ impl FormFiller {
 
                         // Try to fill the associated input
                         if let Ok(true) = page.fill(&input_selector, &answer_value).await {
-                            result.filled_fields.push(SCREENING_FIELD_LABEL.to_string());
+                            result.record_filled(
+                                SCREENING_FIELD_LABEL,
+                                &question_text,
+                                &answer_value,
+                            );
                             result.add_screening_answer_topic(review_topic);
                             tracing::debug!(
                                 question_chars,
                                 "Filled screening question with answer"
                             );
                         } else if let Ok(true) = page.select(&input_selector, &answer_value).await {
-                            result.filled_fields.push(SCREENING_FIELD_LABEL.to_string());
+                            result.record_filled(
+                                SCREENING_FIELD_LABEL,
+                                &question_text,
+                                &answer_value,
+                            );
                             result.add_screening_answer_topic(review_topic);
                             tracing::debug!(question_chars, "Selected screening answer");
                         }
@@ -159,7 +167,10 @@ impl FormFiller {
             .map(|answer| answer.answer.clone())
     }
 
-    fn find_screening_answer_for_question(&self, question: &str) -> Option<&ScreeningAnswer> {
+    pub(super) fn find_screening_answer_for_question(
+        &self,
+        question: &str,
+    ) -> Option<&ScreeningAnswer> {
         for answer in &self.screening_answers {
             if screening_question_matches(&answer.question_pattern, question) {
                 tracing::debug!(
@@ -4,6 +4,8 @@ use chrono::Utc;
 fn make_test_profile() -> ApplicationProfile {
     ApplicationProfile {
         id: 1,
+        name: "My profile".to_string(),
+        is_default: true,
         full_name: "Jordan Lee".to_string(),
         email: "jordan@example.com".to_string(),
         phone: Some("+1234567890".to_string()),
@@ -17,6 +19,7 @@ fn make_test_profile() -> ApplicationProfile {
         us_work_authorized: true,
         requires_sponsorship: false,
         max_applications_per_day: 10,
+        max_applications_per_week: None,
         require_manual_approval: true,
         created_at: Utc::now(),
         updated_at: Utc::now(),
@@ -26,6 +29,7 @@ fn make_test_profile() -> ApplicationProfile {
 fn make_screening_answer(pattern: &str, answer: &str) -> ScreeningAnswer {
     ScreeningAnswer {
         id: 1,
+        profile_id: None,
         question_pattern: pattern.to_string(),
         answer: answer.to_string(),
         answer_type: Some("text".to_string()),
@@ -198,6 +202,42 @@ fn screening_field_label_does_not_echo_question_text() {
     assert!(!SCREENING_FIELD_LABEL.contains("authorized"));
 }
 
+#[test]
+fn resume_display_name_drops_the_managed_copy_prefix() {
+    assert_eq!(
+        resume_display_name(Path::new(
+            "/data/application-resumes/3f2c9a1e-8d4b-4f6a-9c2e-1b7d5e0a4c3f--Jordan_Lee.pdf"
+        )),
+        "Jordan_Lee.pdf"
+    );
+    assert_eq!(
+        resume_display_name(Path::new("/home/jordan/resume.pdf")),
+        "resume.pdf"
+    );
+}
+
+#[test]
+fn recorded_fields_keep_the_value_entered() {
+    let mut result = FillResult::new();
+    result.record_filled("email", "email", "jordan@example.com");
+    result.record_filled(
+        SCREENING_FIELD_LABEL,
+        "Are you willing to work weekends?",
+        "Yes",
+    );
+
+    assert_eq!(
+        result.filled_fields,
+        vec!["email".to_string(), SCREENING_FIELD_LABEL.to_string()]
+    );
+    assert_eq!(
+        result
+            .filled_values
+            .get("Are you willing to work weekends?"),
+        Some(&"Yes".to_string())
+    );
+}
+
 #[test]
 fn screening_answer_review_topics_are_bounded() {
     assert_eq!(
@@ -225,3 +265,57 @@ fn question_discovery_error_does_not_echo_browser_detail() {
     assert!(!error.contains("selector"));
     assert!(!error.contains("Jordan Lee"));
 }
+
+#[test]
+fn lever_questions_parse_from_the_page_script() {
+    let questions = parse_lever_questions(serde_json::json!([
+        {
+            "text": "Are you legally authorized to work in the US?✱",
+            "kind": "radio",
+            "field": "lever-question-0",
+            "options": [
+                { "label": "Yes", "field": "lever-question-0-0" },
+                { "label": "No", "field": "lever-question-0-1" }
+            ]
+        },
+        { "text": "Why this role?", "kind": "text", "field": "lever-question-1" }
+    ]))
+    .unwrap();
+
+    assert_eq!(questions.len(), 2);
+    assert_eq!(questions[0].kind, LeverQuestionKind::Radio);
+    assert_eq!(questions[0].options.len(), 2);
+    assert_eq!(questions[1].kind, LeverQuestionKind::Text);
+    assert!(questions[1].options.is_empty());
+    assert!(parse_lever_questions(serde_json::json!({ "text": 1 })).is_err());
+}
+
+#[test]
+fn lever_question_text_drops_the_required_marker() {
+    assert_eq!(
+        clean_question_text("  Are you willing\n   to relocate? ✱ "),
+        "Are you willing to relocate?"
+    );
+    assert_eq!(clean_question_text("Notice period*"), "Notice period");
+}
+
+#[test]
+fn lever_choices_match_saved_answers_ignoring_case() {
+    let questions = parse_lever_questions(serde_json::json!([{
+        "text": "Do you need visa sponsorship?",
+        "kind": "radio",
+        "field": "lever-question-0",
+        "options": [
+            { "label": "Yes", "field": "lever-question-0-0" },
+            { "label": "No", "field": "lever-question-0-1" }
+        ]
+    }]))
+    .unwrap();
+    let options = &questions[0].options;
+
+    assert_eq!(
+        matching_option(options, " no ").map(|option| option.field.as_str()),
+        Some("lever-question-0-1")
+    );
+    assert!(matching_option(options, "Maybe later").is_none());
+}
@@ -15,7 +15,8 @@ pub use error::{AutomationError, AutomationResult};
 pub use form_filler::FormFiller;
 pub use jobsentinel_domain::{
     screening_question_matches, ApplicationAttempt, ApplicationProfile, ApplicationProfileInput,
-    AtsPlatform, AutomationStats, AutomationStatus, ScreeningAnswer,
+    ApplicationProfileSummary, AtsPlatform, AttemptFillSummary, AutomationQuota, AutomationStats,
+    AutomationStatus, ScreeningAnswer,
 };
 
 pub(super) const GENERIC_AUTOMATION_PLATFORMS: &[AtsPlatform] = &[
@@ -6,8 +6,9 @@ mod deeplinks;
 
 pub use automation::{
     screening_question_matches, ApplicationAttempt, ApplicationProfile, ApplicationProfileInput,
-    AtsDetector, AtsPlatform, AutomationError, AutomationPage, AutomationResult, AutomationStats,
-    AutomationStatus, BrowserManager, FillResult, FormFiller, ScreeningAnswer,
+    ApplicationProfileSummary, AtsDetector, AtsPlatform, AttemptFillSummary, AutomationError,
+    AutomationPage, AutomationQuota, AutomationResult, AutomationStats, AutomationStatus,
+    BrowserManager, FillResult, FormFiller, ScreeningAnswer,
 };
 
 pub use bookmarklet::{
@@ -24,6 +24,18 @@ pub enum CredentialKey {
     LinkedInCookieExpiry,
     /// USAJobs API key (free from developer.usajobs.gov) for API access.
     UsaJobsApiKey,
+    /// Password for the optional scraping proxy.
+    ScraperProxyPassword,
+    /// Shared secret for signing custom webhook requests.
+    CustomWebhookSecret,
+    /// ntfy topic name for ntfy push notifications.
+    NtfyTopic,
+    /// Optional ntfy access token for protected topics.
+    NtfyAccessToken,
+    /// Pushover user key for Pushover notifications.
+    PushoverUserKey,
+    /// Pushover application token for Pushover notifications.
+    PushoverAppToken,
     /// OpenAI API key for optional outside-AI features.
     ExternalAiOpenAiApiKey,
     /// Anthropic API key for optional outside-AI features.
@@ -59,6 +71,12 @@ impl CredentialKey {
             Self::LinkedInCookie => "jobsentinel_linkedin_cookie",
             Self::LinkedInCookieExpiry => "jobsentinel_linkedin_cookie_expiry",
             Self::UsaJobsApiKey => "jobsentinel_usajobs_api_key",
+            Self::ScraperProxyPassword => "jobsentinel_scraper_proxy_password",
+            Self::CustomWebhookSecret => "jobsentinel_custom_webhook_secret",
+            Self::NtfyTopic => "jobsentinel_ntfy_topic",
+            Self::NtfyAccessToken => "jobsentinel_ntfy_access_token",
+            Self::PushoverUserKey => "jobsentinel_pushover_user_key",
+            Self::PushoverAppToken => "jobsentinel_pushover_app_token",
             Self::ExternalAiOpenAiApiKey => "jobsentinel_external_ai_openai_api_key",
             Self::ExternalAiAnthropicApiKey => "jobsentinel_external_ai_anthropic_api_key",
             Self::ExternalAiGoogleApiKey => "jobsentinel_external_ai_google_api_key",
@@ -85,6 +103,12 @@ impl CredentialKey {
             Self::DiscordWebhook,
             Self::TeamsWebhook,
             Self::UsaJobsApiKey,
+            Self::ScraperProxyPassword,
+            Self::CustomWebhookSecret,
+            Self::NtfyTopic,
+            Self::NtfyAccessToken,
+            Self::PushoverUserKey,
+            Self::PushoverAppToken,
             Self::ExternalAiOpenAiApiKey,
             Self::ExternalAiAnthropicApiKey,
             Self::ExternalAiGoogleApiKey,
@@ -117,6 +141,16 @@ impl FromStr for CredentialKey {
                 Ok(Self::LinkedInCookieExpiry)
             }
             "usajobs_api_key" | "jobsentinel_usajobs_api_key" => Ok(Self::UsaJobsApiKey),
+            "scraper_proxy_password" | "jobsentinel_scraper_proxy_password" => {
+                Ok(Self::ScraperProxyPassword)
+            }
+            "custom_webhook_secret" | "jobsentinel_custom_webhook_secret" => {
+                Ok(Self::CustomWebhookSecret)
+            }
+            "ntfy_topic" | "jobsentinel_ntfy_topic" => Ok(Self::NtfyTopic),
+            "ntfy_access_token" | "jobsentinel_ntfy_access_token" => Ok(Self::NtfyAccessToken),
+            "pushover_user_key" | "jobsentinel_pushover_user_key" => Ok(Self::PushoverUserKey),
+            "pushover_app_token" | "jobsentinel_pushover_app_token" => Ok(Self::PushoverAppToken),
             "external_ai_openai_api_key" | "jobsentinel_external_ai_openai_api_key" => {
                 Ok(Self::ExternalAiOpenAiApiKey)
             }
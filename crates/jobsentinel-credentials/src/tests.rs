@@ -21,6 +21,24 @@ fn test_credential_key_from_str() {
             .unwrap(),
         CredentialKey::SlackWebhook
     );
+    assert_eq!(
+        "scraper_proxy_password".parse::<CredentialKey>().unwrap(),
+        CredentialKey::ScraperProxyPassword
+    );
+    assert_eq!(
+        "custom_webhook_secret".parse::<CredentialKey>().unwrap(),
+        CredentialKey::CustomWebhookSecret
+    );
+    assert_eq!(
+        "ntfy_topic".parse::<CredentialKey>().unwrap(),
+        CredentialKey::NtfyTopic
+    );
+    assert_eq!(
+        "jobsentinel_pushover_app_token"
+            .parse::<CredentialKey>()
+            .unwrap(),
+        CredentialKey::PushoverAppToken
+    );
     assert!("unknown_key".parse::<CredentialKey>().is_err());
 }
 
@@ -105,6 +123,52 @@ fn teams_credential_validation_rejects_base_current_hosts() {
     }
 }
 
+#[test]
+fn custom_webhook_secret_validation_rejects_short_or_spaced_secrets() {
+    for secret in [
+        "too-short",
+        "has a space in the secret",
+        "line\nbreak-in-secret",
+    ] {
+        let err =
+            validate_credential_value(CredentialKey::CustomWebhookSecret, secret).unwrap_err();
+        assert!(
+            !err.contains(secret),
+            "error must not echo the secret: {err}"
+        );
+    }
+    assert!(
+        validate_credential_value(CredentialKey::CustomWebhookSecret, "f3a9c1d27b8e4a6c9d0e")
+            .is_ok()
+    );
+}
+
+#[test]
+fn ntfy_topic_validation_rejects_unsupported_characters() {
+    assert!(validate_credential_value(CredentialKey::NtfyTopic, "job-alerts_7f3a9c").is_ok());
+    for topic in ["", "job alerts", "jobs/alerts", &"a".repeat(65)] {
+        assert!(validate_credential_value(CredentialKey::NtfyTopic, topic).is_err());
+    }
+}
+
+#[test]
+fn pushover_key_validation_requires_thirty_alphanumerics() {
+    let key = "uQiRzpo4DXghDmr9QzzfQu27cmVRsG";
+    assert!(validate_credential_value(CredentialKey::PushoverUserKey, key).is_ok());
+    assert!(validate_credential_value(CredentialKey::PushoverAppToken, key).is_ok());
+
+    let err = validate_credential_value(CredentialKey::PushoverUserKey, &key[..29]).unwrap_err();
+    assert!(
+        !err.contains(&key[..29]),
+        "error must not echo the key: {err}"
+    );
+    assert!(validate_credential_value(
+        CredentialKey::PushoverAppToken,
+        "uQiRzpo4DXghDmr9QzzfQu27cmVR-!"
+    )
+    .is_err());
+}
+
 #[test]
 fn discord_credential_validation_accepts_hooks_domain() {
     assert!(validate_credential_value(
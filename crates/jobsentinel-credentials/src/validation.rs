@@ -14,6 +14,13 @@ pub(super) fn validate_credential_value(key: CredentialKey, value: &str) -> Resu
             validate_webhook_credential(value, WebhookTarget::Teams, "Teams")
         }
         CredentialKey::TelegramBotToken => validate_telegram_bot_token_credential(value),
+        CredentialKey::CustomWebhookSecret => validate_webhook_secret_credential(value),
+        CredentialKey::NtfyTopic => validate_ntfy_topic_credential(value),
+        CredentialKey::NtfyAccessToken => validate_api_key_credential(value, "ntfy access token"),
+        CredentialKey::PushoverUserKey => validate_pushover_key_credential(value, "user key"),
+        CredentialKey::PushoverAppToken => {
+            validate_pushover_key_credential(value, "application token")
+        }
         CredentialKey::ExternalAiOpenAiApiKey
         | CredentialKey::ExternalAiAnthropicApiKey
         | CredentialKey::ExternalAiGoogleApiKey
@@ -110,3 +117,50 @@ pub(super) fn validate_telegram_bot_token_credential(value: &str) -> Result<(),
 
     Ok(())
 }
+
+pub(super) fn validate_webhook_secret_credential(value: &str) -> Result<(), String> {
+    if value.len() < 16 || value.len() > 256 {
+        return Err(
+            "Use a webhook signing secret between 16 and 256 characters long, or leave it blank to send unsigned requests."
+                .to_string(),
+        );
+    }
+
+    if value
+        .chars()
+        .any(|ch| ch.is_control() || ch.is_whitespace())
+    {
+        return Err(
+            "Webhook signing secret should not include spaces, line breaks, or hidden characters."
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+pub(super) fn validate_ntfy_topic_credential(value: &str) -> Result<(), String> {
+    if value.is_empty()
+        || value.len() > 64
+        || !value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+    {
+        return Err(
+            "Use an ntfy topic of up to 64 letters, numbers, underscores, or dashes. Pick one that is hard to guess."
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+pub(super) fn validate_pushover_key_credential(value: &str, label: &str) -> Result<(), String> {
+    if value.len() != 30 || !value.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+        return Err(format!(
+            "Paste the 30-character Pushover {label} copied from pushover.net. If you are not sure, leave it blank and set it up later."
+        ));
+    }
+
+    Ok(())
+}
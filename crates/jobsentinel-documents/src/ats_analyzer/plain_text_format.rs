@@ -1,7 +1,7 @@
 use super::super::ats_types::{
     AtsAnalysisResult, AtsSuggestion, FormatIssue, IssueSeverity, SuggestionCategory,
 };
-use super::super::format_taxonomy::resume_format_taxonomy;
+use super::super::format_taxonomy::{is_standard_resume_heading, resume_format_taxonomy};
 use super::AtsAnalyzer;
 use super::{format_result, structured_format};
 
@@ -387,18 +387,3 @@ fn text_has_icon_font_family(text: &str) -> bool {
         .iter()
         .any(|family| lower.contains(family.as_str()))
 }
-
-fn is_standard_resume_heading(line: &str) -> bool {
-    let normalized = line
-        .trim()
-        .trim_end_matches(':')
-        .to_lowercase()
-        .replace('/', " ")
-        .replace('&', " and ");
-    let normalized = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
-
-    resume_format_taxonomy()
-        .standard_resume_headings
-        .iter()
-        .any(|heading| heading == &normalized)
-}
@@ -6,7 +6,9 @@ use crate::structured_resume::{
 
 pub(super) fn format_contact_line(personal: &ResumePersonalInfo) -> String {
     let mut parts: Vec<&str> = Vec::with_capacity(5);
-    parts.push(&personal.email);
+    if !personal.email.is_empty() {
+        parts.push(&personal.email);
+    }
     if let Some(phone) = &personal.phone {
         parts.push(phone);
     }
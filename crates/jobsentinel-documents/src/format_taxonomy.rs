@@ -83,3 +83,20 @@ fn assert_nonblank_list(values: &[String], label: &str) {
 pub(super) fn resume_format_taxonomy() -> &'static ResumeFormatTaxonomy {
     &RESUME_FORMAT_TAXONOMY
 }
+
+/// Whether a line is one of the standard resume headings, ignoring case, a
+/// trailing colon, and `/` or `&` separators.
+pub(super) fn is_standard_resume_heading(line: &str) -> bool {
+    let normalized = line
+        .trim()
+        .trim_end_matches(':')
+        .to_lowercase()
+        .replace('/', " ")
+        .replace('&', " and ");
+    let normalized = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    resume_format_taxonomy()
+        .standard_resume_headings
+        .iter()
+        .any(|heading| heading == &normalized)
+}
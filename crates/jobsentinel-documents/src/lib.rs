@@ -5,7 +5,9 @@ mod ats_types;
 mod export;
 mod format_taxonomy;
 mod parser;
+mod redaction;
 mod resume_match_score;
+mod resume_sections;
 mod skills;
 mod structured_resume;
 mod templates;
@@ -19,7 +21,12 @@ pub use ats_types::{
 };
 pub use export::ResumeExporter;
 pub use parser::ResumeParser;
+pub use redaction::{redact_resume, redact_resume_text, ResumeRedactionRules, REDACTED_TEXT};
 pub use resume_match_score::calculate_resume_match_score;
+pub use resume_sections::{
+    diff_resume_sections, split_resume_sections, ResumeSection, ResumeSectionChange,
+    ResumeSectionChangeKind,
+};
 pub use skills::{ExtractedSkill, SkillExtractor};
 pub use structured_resume::{
     ResumeAnalysisInput, ResumeCertification, ResumeEducation, ResumeExperience,
@@ -28,6 +35,6 @@ pub use structured_resume::{
 };
 pub use templates::{Template, TemplateRenderer};
 pub use types::{
-    DegreeLevel, EducationRequirement, ExperienceRequirement, JobSkill, MatchResult,
-    MatchResultWithJob, NewSkill, NullableFieldUpdate, Resume, SkillUpdate, UserSkill,
+    DegreeLevel, EducationMatch, EducationRequirement, ExperienceRequirement, JobSkill,
+    MatchResult, MatchResultWithJob, NewSkill, NullableFieldUpdate, Resume, SkillUpdate, UserSkill,
 };
@@ -60,7 +60,9 @@ impl ResumeParser {
         match resume_extension(&canonical_path).as_deref() {
             Some("pdf") => self.parse_pdf_from_canonical(&canonical_path),
             Some("docx") => self.parse_docx_from_canonical(&canonical_path),
-            Some("txt" | "md") => self.parse_plain_text_from_canonical(&canonical_path),
+            Some("txt" | "md" | "markdown") => {
+                self.parse_plain_text_from_canonical(&canonical_path)
+            }
             Some("html" | "htm") => self.parse_html_from_canonical(&canonical_path),
             _ => Err(anyhow::anyhow!(
                 "File must be PDF, DOCX, TXT, Markdown, or HTML"
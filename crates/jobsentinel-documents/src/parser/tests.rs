@@ -254,6 +254,26 @@ fn test_parse_resume_md_extracts_markdown_text() {
     assert!(text.contains("Scheduling"));
 }
 
+#[test]
+fn test_parse_resume_markdown_extension_extracts_markdown_text() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("operations-resume.MARKDOWN");
+    std::fs::write(
+        &file_path,
+        "# Jordan Lee\n\n## Experience\nProgram Operations Lead\n\n## Skills\nScheduling",
+    )
+    .unwrap();
+
+    let parser = ResumeParser::new();
+    let text = parser.parse_resume(&file_path).unwrap();
+
+    assert!(text.contains("Jordan Lee"));
+    assert!(text.contains("Program Operations Lead"));
+    assert!(text.contains("Scheduling"));
+}
+
 #[test]
 #[ignore = "requires local private resume paths"]
 fn test_parse_local_resume_smoke_paths() {
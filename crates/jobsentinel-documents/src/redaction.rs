@@ -0,0 +1,331 @@
+//! Masking personal details in a resume before it is shared publicly
+
+#![allow(clippy::expect_used)] // Regex patterns are compile-time constants
+
+use super::format_taxonomy::is_standard_resume_heading;
+use crate::structured_resume::StructuredResume;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Text that replaces a masked detail
+pub const REDACTED_TEXT: &str = "[redacted]";
+/// Company name shown when employers are hidden
+const HIDDEN_EMPLOYER: &str = "Confidential employer";
+
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("email regex must compile")
+});
+static PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{3}\)|\b\d{3})[\s.-]?\d{3}[\s.-]?\d{4}\b")
+        .expect("phone regex must compile")
+});
+static STREET_ADDRESS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b\d{1,6}\s+(?:[A-Za-z0-9.'-]+\s+){1,4}(?:street|st|avenue|ave|road|rd|boulevard|blvd|lane|ln|drive|dr|court|ct|way|place|pl|terrace|ter|circle|cir|parkway|pkwy)\b\.?(?:,?\s*(?:apt|suite|ste|unit|#)\.?\s*[A-Za-z0-9-]+)?",
+    )
+    .expect("street address regex must compile")
+});
+static LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:https?://|www\.|(?:linkedin|github)\.com/)[^\s<>()|,;]+")
+        .expect("link regex must compile")
+});
+static CREDENTIAL_ID_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b((?:credential|license|licence|certificate|certification)\s+(?:id\b|no\b\.?|number\b|#))\s*[:#]?\s*[A-Za-z0-9][A-Za-z0-9-]*",
+    )
+    .expect("credential ID regex must compile")
+});
+static REFERENCES_ON_REQUEST_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^references\b.*\bupon request\.?$")
+        .expect("references on request regex must compile")
+});
+
+/// Which personal details to strip or mask in a shareable resume
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResumeRedactionRules {
+    /// Replace the name with this text, such as "Candidate"; `None` keeps it
+    pub name_replacement: Option<String>,
+    pub remove_email: bool,
+    pub remove_phone: bool,
+    /// Street addresses, and the city or region in the contact details
+    pub remove_address: bool,
+    /// LinkedIn, GitHub, website, and project links
+    pub remove_links: bool,
+    /// The References section and "references available upon request"
+    pub remove_references: bool,
+    /// Certification credential and license numbers
+    pub remove_credential_ids: bool,
+    /// Show each employer as "Confidential employer"
+    pub hide_employers: bool,
+    /// Extra words or phrases to mask wherever they appear, ignoring case
+    pub custom_terms: Vec<String>,
+}
+
+impl Default for ResumeRedactionRules {
+    fn default() -> Self {
+        Self {
+            name_replacement: None,
+            remove_email: true,
+            remove_phone: true,
+            remove_address: true,
+            remove_links: false,
+            remove_references: true,
+            remove_credential_ids: true,
+            hide_employers: false,
+            custom_terms: Vec::new(),
+        }
+    }
+}
+
+impl ResumeRedactionRules {
+    fn name_replacement(&self) -> Option<&str> {
+        self.name_replacement
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Mask the details these rules cover in free text, plus `terms`
+    fn mask(&self, text: &str, terms: &[(Regex, String)]) -> String {
+        let mut masked = text.to_string();
+        for (pattern, replacement) in terms {
+            masked = pattern
+                .replace_all(&masked, replacement.as_str())
+                .into_owned();
+        }
+        if self.remove_email {
+            masked = EMAIL_REGEX.replace_all(&masked, REDACTED_TEXT).into_owned();
+        }
+        if self.remove_links {
+            masked = LINK_REGEX.replace_all(&masked, REDACTED_TEXT).into_owned();
+        }
+        if self.remove_phone {
+            masked = PHONE_REGEX.replace_all(&masked, REDACTED_TEXT).into_owned();
+        }
+        if self.remove_address {
+            masked = STREET_ADDRESS_REGEX
+                .replace_all(&masked, REDACTED_TEXT)
+                .into_owned();
+        }
+        if self.remove_credential_ids {
+            masked = CREDENTIAL_ID_REGEX
+                .replace_all(&masked, format!("$1: {REDACTED_TEXT}"))
+                .into_owned();
+        }
+        masked
+    }
+
+    /// Custom terms, plus any extra terms, as case-insensitive patterns
+    fn term_patterns(&self, extra: &[(&str, &str)]) -> Vec<(Regex, String)> {
+        self.custom_terms
+            .iter()
+            .map(|term| (term.as_str(), REDACTED_TEXT))
+            .chain(extra.iter().copied())
+            .filter(|(term, _)| !term.trim().is_empty())
+            .filter_map(|(term, replacement)| {
+                let pattern = format!(r"(?i)\b{}\b", regex::escape(term.trim()));
+                Regex::new(&pattern)
+                    .ok()
+                    .map(|regex| (regex, replacement.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// A copy of a structured resume with the rules' personal details removed.
+///
+/// Contact fields are cleared rather than masked, so exports leave them out;
+/// details found in summaries, bullets, and descriptions are masked.
+pub fn redact_resume(resume: &StructuredResume, rules: &ResumeRedactionRules) -> StructuredResume {
+    let mut redacted = resume.clone();
+    let mut extra_terms = Vec::new();
+
+    if let Some(replacement) = rules.name_replacement() {
+        extra_terms.push((resume.personal.name.as_str(), replacement));
+        redacted.personal.name = replacement.to_string();
+    }
+    if rules.hide_employers {
+        for experience in &resume.experience {
+            extra_terms.push((experience.company.as_str(), HIDDEN_EMPLOYER));
+        }
+    }
+    let terms = rules.term_patterns(&extra_terms);
+    let mask = |text: &str| rules.mask(text, &terms);
+
+    if rules.remove_email {
+        redacted.personal.email = String::new();
+    }
+    if rules.remove_phone {
+        redacted.personal.phone = None;
+    }
+    if rules.remove_address {
+        redacted.personal.location = None;
+    }
+    if rules.remove_links {
+        redacted.personal.linkedin = None;
+        redacted.personal.github = None;
+        redacted.personal.website = None;
+        for project in &mut redacted.projects {
+            project.url = None;
+        }
+    }
+    if rules.remove_credential_ids {
+        for certification in &mut redacted.certifications {
+            certification.credential_id = None;
+        }
+    }
+
+    redacted.summary = redacted.summary.as_deref().map(mask);
+    for experience in &mut redacted.experience {
+        if rules.hide_employers {
+            experience.company = HIDDEN_EMPLOYER.to_string();
+        }
+        for achievement in &mut experience.achievements {
+            *achievement = mask(achievement.as_str());
+        }
+    }
+    for education in &mut redacted.education {
+        for honor in &mut education.honors {
+            *honor = mask(honor.as_str());
+        }
+    }
+    for project in &mut redacted.projects {
+        project.description = mask(project.description.as_str());
+    }
+    redacted.clearance = redacted.clearance.as_deref().map(mask);
+    redacted.military_info = redacted.military_info.as_deref().map(mask);
+
+    redacted
+}
+
+/// Resume plain text with the rules' personal details masked.
+///
+/// The first line is taken as the name when it looks like one. With
+/// `remove_references`, the References section is dropped up to the next
+/// standard heading.
+pub fn redact_resume_text(text: &str, rules: &ResumeRedactionRules) -> String {
+    let name = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .filter(|line| looks_like_name(line));
+    let extra_terms: Vec<(&str, &str)> = match (name, rules.name_replacement()) {
+        (Some(name), Some(replacement)) => vec![(name, replacement)],
+        _ => Vec::new(),
+    };
+    let terms = rules.term_patterns(&extra_terms);
+
+    let mut lines = Vec::new();
+    let mut in_references = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if is_standard_resume_heading(trimmed) {
+            in_references = rules.remove_references
+                && trimmed
+                    .trim_end_matches(':')
+                    .trim()
+                    .eq_ignore_ascii_case("references");
+        }
+        if in_references
+            || (rules.remove_references && REFERENCES_ON_REQUEST_REGEX.is_match(trimmed))
+        {
+            continue;
+        }
+        lines.push(rules.mask(line, &terms));
+    }
+
+    let mut redacted = lines.join("\n");
+    if text.ends_with('\n') {
+        redacted.push('\n');
+    }
+    redacted
+}
+
+fn looks_like_name(line: &str) -> bool {
+    let words = line.split_whitespace().count();
+    (2..=4).contains(&words)
+        && !is_standard_resume_heading(line)
+        && line
+            .chars()
+            .all(|c| c.is_alphabetic() || c.is_whitespace() || matches!(c, '.' | '\'' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structured_resume::{ResumeCertification, ResumeExperience, ResumePersonalInfo};
+
+    #[test]
+    fn redacts_contact_details_and_masks_free_text() {
+        let resume = StructuredResume {
+            personal: ResumePersonalInfo {
+                name: "Jane Doe".to_string(),
+                email: "jane@example.com".to_string(),
+                phone: Some("(555) 123-4567".to_string()),
+                location: Some("Denver, CO".to_string()),
+                linkedin: Some("linkedin.com/in/janedoe".to_string()),
+                ..Default::default()
+            },
+            summary: Some(
+                "Jane Doe, reachable at 555.123.4567, led work at Acme Corp.".to_string(),
+            ),
+            experience: vec![ResumeExperience {
+                title: "SRE".to_string(),
+                company: "Acme Corp".to_string(),
+                start_date: "2020".to_string(),
+                achievements: vec!["Cut Acme Corp paging by 40%".to_string()],
+                ..Default::default()
+            }],
+            certifications: vec![ResumeCertification {
+                name: "CKA".to_string(),
+                issuer: "CNCF".to_string(),
+                credential_id: Some("LF-123".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let rules = ResumeRedactionRules {
+            name_replacement: Some("Candidate".to_string()),
+            hide_employers: true,
+            ..Default::default()
+        };
+
+        let redacted = redact_resume(&resume, &rules);
+
+        assert_eq!(redacted.personal.name, "Candidate");
+        assert_eq!(redacted.personal.email, "");
+        assert_eq!(redacted.personal.phone, None);
+        assert_eq!(redacted.personal.location, None);
+        assert_eq!(
+            redacted.personal.linkedin.as_deref(),
+            Some("linkedin.com/in/janedoe")
+        );
+        assert_eq!(
+            redacted.summary.as_deref(),
+            Some("Candidate, reachable at [redacted], led work at Confidential employer.")
+        );
+        assert_eq!(redacted.experience[0].company, "Confidential employer");
+        assert_eq!(
+            redacted.experience[0].achievements,
+            vec!["Cut Confidential employer paging by 40%".to_string()]
+        );
+        assert_eq!(redacted.certifications[0].credential_id, None);
+    }
+
+    #[test]
+    fn masks_resume_text_and_drops_references() {
+        let text = "Jane Doe\n12 Elm Street, Apt 4\njane@example.com | 555-123-4567\n\nExperience\nSRE at Globex, 2019 - 2023\nLicense No. RN-88812\n\nReferences\nJohn Smith, 555-987-6543\n\nSkills\nRust, Kubernetes\n";
+        let rules = ResumeRedactionRules {
+            name_replacement: Some("Candidate".to_string()),
+            custom_terms: vec!["globex".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            redact_resume_text(text, &rules),
+            "Candidate\n[redacted]\n[redacted] | [redacted]\n\nExperience\nSRE at [redacted], 2019 - 2023\nLicense No.: [redacted]\n\nSkills\nRust, Kubernetes\n"
+        );
+    }
+}
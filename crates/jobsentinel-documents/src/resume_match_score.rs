@@ -6,6 +6,9 @@ const SKILLS_WEIGHT: f64 = 0.5;
 const EXPERIENCE_WEIGHT: f64 = 0.3;
 #[cfg(any(not(feature = "embedded-ml"), test))]
 const EDUCATION_WEIGHT: f64 = 0.2;
+/// Highest score a match can reach while missing a hard degree requirement.
+#[cfg(any(not(feature = "embedded-ml"), test))]
+const HARD_EDUCATION_GAP_CAP: f64 = 0.6;
 
 pub fn calculate_resume_match_score(
     matching_skills: &[String],
@@ -31,17 +34,13 @@ pub fn calculate_resume_match_score(
 
     #[cfg(not(feature = "embedded-ml"))]
     {
-        let _ = (
-            matching_skills,
-            missing_skills,
-            experience_reqs,
-            education_req,
-        );
-        calculate_legacy_score(
+        let _ = (matching_skills, missing_skills, experience_reqs);
+        let score = calculate_legacy_score(
             skills_match_score,
             experience_match_score,
             education_match_score,
-        )
+        );
+        cap_hard_education_gap(score, education_match_score, education_req)
     }
 }
 
@@ -56,6 +55,21 @@ fn calculate_legacy_score(
         + (education_match_score * EDUCATION_WEIGHT)
 }
 
+/// Unmet hard degree requirements (required, no equivalent-experience
+/// clause) cap the score so strong skills alone cannot mark the job a fit.
+#[cfg(any(not(feature = "embedded-ml"), test))]
+fn cap_hard_education_gap(
+    score: f64,
+    education_match_score: f64,
+    education_req: Option<&EducationRequirement>,
+) -> f64 {
+    if education_req.is_some_and(EducationRequirement::is_hard) && education_match_score < 1.0 {
+        score.min(HARD_EDUCATION_GAP_CAP)
+    } else {
+        score
+    }
+}
+
 #[cfg(feature = "embedded-ml")]
 fn calculate_hybrid_score(
     matching_skills: &[String],
@@ -92,9 +106,7 @@ fn calculate_hybrid_score(
             "experience requirement".to_string(),
         ));
     }
-    if education_req.is_some_and(|requirement| requirement.is_required)
-        && education_match_score < 1.0
-    {
+    if education_req.is_some_and(EducationRequirement::is_hard) && education_match_score < 1.0 {
         blockers.push(MatchBlocker::MissingRequiredSkill(
             "education requirement".to_string(),
         ));
@@ -150,7 +162,7 @@ fn required_coverage(
     {
         scores.push(clamp01(experience_match_score));
     }
-    if education_req.is_some_and(|requirement| requirement.is_required) {
+    if education_req.is_some_and(EducationRequirement::is_hard) {
         scores.push(clamp01(education_match_score));
     }
 
@@ -181,7 +193,6 @@ fn clamp01(value: f64) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(feature = "embedded-ml")]
     use crate::DegreeLevel;
 
     #[test]
@@ -191,6 +202,24 @@ mod tests {
         assert!((score - 0.75).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn hard_degree_gap_caps_score_unless_experience_substitutes() {
+        let mut requirement = EducationRequirement {
+            degree_level: DegreeLevel::Bachelor,
+            fields: Vec::new(),
+            is_required: true,
+            equivalent_experience: false,
+        };
+        let score = calculate_legacy_score(1.0, 1.0, 0.0);
+
+        let capped = cap_hard_education_gap(score, 0.0, Some(&requirement));
+        assert!((capped - HARD_EDUCATION_GAP_CAP).abs() < f64::EPSILON);
+
+        requirement.equivalent_experience = true;
+        let uncapped = cap_hard_education_gap(score, 0.0, Some(&requirement));
+        assert!((uncapped - 0.8).abs() < f64::EPSILON);
+    }
+
     #[cfg(feature = "embedded-ml")]
     #[test]
     fn hybrid_score_caps_missing_required_skills() {
@@ -226,6 +255,7 @@ mod tests {
                 degree_level: DegreeLevel::Bachelor,
                 fields: Vec::new(),
                 is_required: true,
+                equivalent_experience: false,
             }),
         );
 
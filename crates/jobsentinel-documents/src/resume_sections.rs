@@ -0,0 +1,210 @@
+//! Splitting resume text into headed sections and comparing two versions
+
+use super::format_taxonomy::is_standard_resume_heading;
+use serde::{Deserialize, Serialize};
+
+/// Heading used for the lines before the first standard heading
+const CONTACT_HEADING: &str = "Contact";
+
+/// One headed section of a resume's plain text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumeSection {
+    /// Heading as written, without a trailing colon
+    pub heading: String,
+    /// Nonblank, trimmed lines under the heading
+    pub lines: Vec<String>,
+}
+
+/// How a section differs between two resume versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResumeSectionChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A section that was added, removed, or edited between two resume versions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumeSectionChange {
+    pub heading: String,
+    pub change: ResumeSectionChangeKind,
+    /// Lines only in the newer version
+    pub added_lines: Vec<String>,
+    /// Lines only in the older version
+    pub removed_lines: Vec<String>,
+}
+
+/// Split resume text into sections at standard resume headings.
+///
+/// Lines before the first heading, usually the name and contact details,
+/// form a "Contact" section. Headings that repeat are merged.
+pub fn split_resume_sections(text: &str) -> Vec<ResumeSection> {
+    let mut sections: Vec<ResumeSection> = Vec::new();
+    let mut current = None;
+
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if is_standard_resume_heading(line) {
+            let heading = line.trim_end_matches(':').trim();
+            current = Some(section_index(&mut sections, heading));
+            continue;
+        }
+        let index = match current {
+            Some(index) => index,
+            None => *current.insert(section_index(&mut sections, CONTACT_HEADING)),
+        };
+        sections[index].lines.push(line.to_string());
+    }
+
+    sections
+}
+
+/// Compare two versions' sections, matching headings case-insensitively.
+///
+/// Line changes ignore order, so moving a bullet within a section is not a
+/// change. Sections are listed in the newer version's order, followed by the
+/// removed ones.
+pub fn diff_resume_sections(
+    older: &[ResumeSection],
+    newer: &[ResumeSection],
+) -> Vec<ResumeSectionChange> {
+    let find = |sections: &[ResumeSection], heading: &str| {
+        sections
+            .iter()
+            .position(|section| section.heading.eq_ignore_ascii_case(heading))
+    };
+    let mut changes = Vec::new();
+
+    for section in newer {
+        match find(older, &section.heading) {
+            None => changes.push(ResumeSectionChange {
+                heading: section.heading.clone(),
+                change: ResumeSectionChangeKind::Added,
+                added_lines: section.lines.clone(),
+                removed_lines: Vec::new(),
+            }),
+            Some(index) => {
+                let added_lines = lines_missing_from(&section.lines, &older[index].lines);
+                let removed_lines = lines_missing_from(&older[index].lines, &section.lines);
+                if !added_lines.is_empty() || !removed_lines.is_empty() {
+                    changes.push(ResumeSectionChange {
+                        heading: section.heading.clone(),
+                        change: ResumeSectionChangeKind::Changed,
+                        added_lines,
+                        removed_lines,
+                    });
+                }
+            }
+        }
+    }
+
+    for section in older {
+        if find(newer, &section.heading).is_none() {
+            changes.push(ResumeSectionChange {
+                heading: section.heading.clone(),
+                change: ResumeSectionChangeKind::Removed,
+                added_lines: Vec::new(),
+                removed_lines: section.lines.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn section_index(sections: &mut Vec<ResumeSection>, heading: &str) -> usize {
+    if let Some(index) = sections
+        .iter()
+        .position(|section| section.heading.eq_ignore_ascii_case(heading))
+    {
+        return index;
+    }
+    sections.push(ResumeSection {
+        heading: heading.to_string(),
+        lines: Vec::new(),
+    });
+    sections.len() - 1
+}
+
+/// Lines of `lines` not in `other`, counting repeated lines separately
+fn lines_missing_from(lines: &[String], other: &[String]) -> Vec<String> {
+    let mut remaining: Vec<&String> = other.iter().collect();
+    lines
+        .iter()
+        .filter(|line| {
+            if let Some(index) = remaining.iter().position(|other| other == line) {
+                remaining.swap_remove(index);
+                false
+            } else {
+                true
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_text_at_standard_headings() {
+        let sections = split_resume_sections(
+            "Jane Doe\njane@example.com\n\nSUMMARY\nPlatform engineer.\n\nSkills:\nRust, SQL\n\nskills\nDocker\n",
+        );
+
+        assert_eq!(
+            sections,
+            vec![
+                ResumeSection {
+                    heading: "Contact".to_string(),
+                    lines: vec!["Jane Doe".to_string(), "jane@example.com".to_string()],
+                },
+                ResumeSection {
+                    heading: "SUMMARY".to_string(),
+                    lines: vec!["Platform engineer.".to_string()],
+                },
+                ResumeSection {
+                    heading: "Skills".to_string(),
+                    lines: vec!["Rust, SQL".to_string(), "Docker".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_sections() {
+        let older = split_resume_sections(
+            "SUMMARY\nBackend engineer.\nEXPERIENCE\n- Built APIs\n- Ran on-call\nINTERESTS\nChess",
+        );
+        let newer = split_resume_sections(
+            "Summary\nBackend engineer.\nEXPERIENCE\n- Ran on-call\n- Led migrations\nPROJECTS\nJobSentinel",
+        );
+
+        let changes = diff_resume_sections(&older, &newer);
+
+        assert_eq!(
+            changes,
+            vec![
+                ResumeSectionChange {
+                    heading: "EXPERIENCE".to_string(),
+                    change: ResumeSectionChangeKind::Changed,
+                    added_lines: vec!["- Led migrations".to_string()],
+                    removed_lines: vec!["- Built APIs".to_string()],
+                },
+                ResumeSectionChange {
+                    heading: "PROJECTS".to_string(),
+                    change: ResumeSectionChangeKind::Added,
+                    added_lines: vec!["JobSentinel".to_string()],
+                    removed_lines: Vec::new(),
+                },
+                ResumeSectionChange {
+                    heading: "INTERESTS".to_string(),
+                    change: ResumeSectionChangeKind::Removed,
+                    added_lines: Vec::new(),
+                    removed_lines: vec!["Chess".to_string()],
+                },
+            ]
+        );
+    }
+}
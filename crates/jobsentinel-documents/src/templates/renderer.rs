@@ -64,7 +64,9 @@ impl TemplateRenderer {
 
         // Contact
         text.push_str(&format!("{}\n", resume.personal.name));
-        text.push_str(&format!("{}\n", resume.personal.email));
+        if !resume.personal.email.is_empty() {
+            text.push_str(&format!("{}\n", resume.personal.email));
+        }
         if let Some(phone) = &resume.personal.phone {
             text.push_str(&format!("{}\n", phone));
         }
@@ -18,14 +18,14 @@ impl TemplateRenderer {
     }
 
     fn append_contact(html: &mut String, contact: &ResumePersonalInfo) {
+        let parts: Vec<String> = std::iter::once(contact.email.as_str())
+            .filter(|email| !email.is_empty())
+            .chain(contact.phone.as_deref())
+            .chain(contact.location.as_deref())
+            .map(escape_html)
+            .collect();
         html.push_str("<div class=\"contact\">\n");
-        html.push_str(&format!("{}", escape_html(&contact.email)));
-        if let Some(phone) = &contact.phone {
-            html.push_str(&format!(" • {}", escape_html(phone)));
-        }
-        if let Some(location) = &contact.location {
-            html.push_str(&format!(" • {}", escape_html(location)));
-        }
+        html.push_str(&parts.join(" • "));
         html.push_str("\n</div>\n\n");
     }
 
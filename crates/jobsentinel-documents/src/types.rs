@@ -52,6 +52,9 @@ pub struct MatchResult {
     pub matching_skills: Vec<String>,
     pub gap_analysis: Option<String>,
     pub created_at: DateTime<Utc>,
+    /// Structured education comparison (computed at match time, not stored)
+    #[serde(default)]
+    pub education: Option<EducationMatch>,
 }
 
 /// Resume-job match result with job details (for frontend display)
@@ -231,4 +234,60 @@ pub struct EducationRequirement {
     pub fields: Vec<String>,
     /// Whether this is required or preferred
     pub is_required: bool,
+    /// Whether the posting accepts equivalent experience in place of the degree
+    #[serde(default)]
+    pub equivalent_experience: bool,
+}
+
+impl EducationRequirement {
+    /// A hard requirement is required and offers no experience substitute.
+    pub fn is_hard(&self) -> bool {
+        self.is_required && !self.equivalent_experience
+    }
+}
+
+/// Job education requirement compared against the resume
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EducationMatch {
+    pub requirement: EducationRequirement,
+    /// Highest degree found on the resume
+    pub resume_degree: Option<DegreeLevel>,
+    /// Whether the resume mentions a required field of study
+    /// (`None` when the job names no field)
+    pub field_matched: Option<bool>,
+    /// Whether the resume degree meets the required level
+    pub meets_level: bool,
+}
+
+impl EducationMatch {
+    /// Unmet hard requirements count against the match score.
+    pub fn is_hard_gap(&self) -> bool {
+        self.requirement.is_hard() && !self.meets_level
+    }
+
+    /// One-line explanation for match breakdowns.
+    pub fn explanation(&self) -> String {
+        let requirement = &self.requirement;
+        let mut wanted = requirement.degree_level.as_str().to_string();
+        if !requirement.fields.is_empty() {
+            wanted.push_str(&format!(" in {}", requirement.fields.join(" or ")));
+        }
+        let kind = if requirement.is_hard() {
+            "required"
+        } else if requirement.is_required {
+            "required or equivalent experience"
+        } else {
+            "preferred"
+        };
+        let resume = self
+            .resume_degree
+            .map_or("none found", |degree| degree.as_str());
+        let outcome = match (self.meets_level, self.field_matched) {
+            (true, Some(false)) => "level met, field not found",
+            (true, _) => "met",
+            (false, _) if requirement.is_hard() => "not met",
+            (false, _) => "not met, experience may substitute",
+        };
+        format!("Education: {wanted} {kind} (resume: {resume}; {outcome})")
+    }
 }
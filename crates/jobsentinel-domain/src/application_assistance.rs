@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, sync::LazyLock};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::LazyLock,
+};
 
 const APPLICATION_SCREENING_ALIAS_TAXONOMY_JSON: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -243,6 +246,20 @@ impl AtsPlatform {
     }
 }
 
+/// A user's standing permission for Application Assist on one site.
+///
+/// Granted once per host before the browser navigates, fills fields, or
+/// attaches a resume there.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutomationPermission {
+    /// Lowercase host the grant covers (e.g., "boards.greenhouse.io").
+    pub domain: String,
+    /// When the user granted permission.
+    pub granted_at: DateTime<Utc>,
+    /// Last time Application Assist acted on this host.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
 /// Record of a single automation attempt for a job application.
 ///
 /// Tracks the full lifecycle including timing, status, errors, and user approval.
@@ -267,6 +284,10 @@ pub struct ApplicationAttempt {
     pub confirmation_screenshot_path: Option<String>,
     /// Total time spent automating this application (milliseconds).
     pub automation_duration_ms: Option<i64>,
+    /// Application profile the form was filled from, if recorded.
+    pub profile_id: Option<i64>,
+    /// Whether this attempt was a dry run that closed the form after filling.
+    pub dry_run: bool,
     /// Whether user has approved this for submission (human-in-the-loop).
     pub user_approved: bool,
     /// Timestamp when application was submitted (if successful).
@@ -275,6 +296,21 @@ pub struct ApplicationAttempt {
     pub created_at: DateTime<Utc>,
 }
 
+/// What an automation attempt entered into a form, kept for review.
+///
+/// Saved when the form is filled so the user can check each value and the
+/// questions left for them before approving the attempt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttemptFillSummary {
+    /// Value entered for each field, keyed by field name or question text.
+    pub filled_values: BTreeMap<String, String>,
+    /// Fields that could not be filled.
+    pub unfilled_fields: Vec<String>,
+    /// Application questions with no saved answer.
+    pub unanswered_questions: Vec<String>,
+}
+
 /// Aggregated statistics for automation performance tracking.
 ///
 /// Used for dashboard metrics and health monitoring.
@@ -293,10 +329,37 @@ pub struct AutomationStats {
     pub success_rate: f64,
 }
 
+/// Remaining application-form quota for the current day and week.
+///
+/// Days start at midnight UTC and weeks on Monday. Cancelled attempts do not
+/// count toward the quota.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationQuota {
+    /// Forms that can be prepared per day.
+    pub daily_limit: i64,
+    /// Forms that can be prepared per week, if capped.
+    pub weekly_limit: Option<i64>,
+    /// Forms prepared so far today.
+    pub used_today: i64,
+    /// Forms prepared so far this week.
+    pub used_this_week: i64,
+    /// Forms that can still be prepared now.
+    pub remaining: i64,
+    /// When more forms become available: the next day, or the next week when
+    /// the weekly cap is what ran out.
+    pub resets_at: DateTime<Utc>,
+}
+
 /// User's application profile.
+///
+/// A user can keep several named profiles, such as one per kind of role. The
+/// default profile is used when an application does not name one.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationProfile {
     pub id: i64,
+    pub name: String,
+    pub is_default: bool,
     pub full_name: String,
     pub email: String,
     pub phone: Option<String>,
@@ -310,14 +373,32 @@ pub struct ApplicationProfile {
     pub us_work_authorized: bool,
     pub requires_sponsorship: bool,
     pub max_applications_per_day: i32,
+    /// Weekly cap on prepared forms; `None` means only the daily limit applies
+    pub max_applications_per_week: Option<i32>,
     pub require_manual_approval: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Name and default flag of a saved application profile, for choosing one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationProfileSummary {
+    pub id: i64,
+    pub name: String,
+    pub is_default: bool,
+    /// Uploaded resume this profile applies with, if linked
+    #[serde(default)]
+    pub default_resume_id: Option<i64>,
+}
+
 /// Input for creating or updating an application profile.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ApplicationProfileInput {
+    /// Profile name; `None` keeps the current name, or uses "My profile" for
+    /// a new profile
+    #[serde(default)]
+    pub name: Option<String>,
     pub full_name: String,
     pub email: String,
     pub phone: Option<String>,
@@ -333,6 +414,7 @@ pub struct ApplicationProfileInput {
     pub us_work_authorized: bool,
     pub requires_sponsorship: bool,
     pub max_applications_per_day: i32,
+    pub max_applications_per_week: Option<i32>,
     pub require_manual_approval: bool,
 }
 
@@ -340,6 +422,8 @@ pub struct ApplicationProfileInput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScreeningAnswer {
     pub id: i64,
+    /// Profile this answer belongs to; `None` for answers shared by every profile
+    pub profile_id: Option<i64>,
     pub question_pattern: String,
     pub answer: String,
     pub answer_type: Option<String>,
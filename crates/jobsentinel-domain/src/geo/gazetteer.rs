@@ -0,0 +1,1012 @@
+//! Bundled city gazetteer
+//!
+//! Resolves city names, nicknames, and metro-area names ("NYC", "New York
+//! City", "Greater Seattle Area", "SF Bay Area") to one canonical city with
+//! its region and country. A name shared by several cities, such as Portland
+//! or London, only resolves when the location also names a region or country
+//! that settles which one is meant.
+
+use super::Coordinates;
+use crate::normalization::{
+    country_code, normalize_location, resolve_location_region, resolve_region, LocationRegion,
+};
+
+/// A city in the bundled gazetteer.
+#[derive(Debug, PartialEq)]
+pub struct City {
+    pub name: &'static str,
+    /// Canonical region name, as in the shared region tables
+    pub region: Option<&'static str>,
+    /// Short region code written after the city, e.g. "WA" or "ON"
+    pub region_code: Option<&'static str>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: &'static str,
+    /// City centre
+    pub coordinates: Coordinates,
+    /// Lowercase names, nicknames, and metro-area names
+    aliases: &'static [&'static str],
+}
+
+impl City {
+    /// Grouping key such as "seattle, wa" or "london, gb"
+    #[must_use]
+    pub fn key(&self) -> String {
+        format!(
+            "{}, {}",
+            self.name,
+            self.region_code.unwrap_or(self.country)
+        )
+        .to_lowercase()
+    }
+
+    /// The country and region this city is in
+    #[must_use]
+    pub const fn location_region(&self) -> LocationRegion {
+        LocationRegion {
+            country: self.country,
+            region: self.region,
+        }
+    }
+}
+
+/// A city in a country whose regions are written with a short code.
+const fn coded(
+    country: &'static str,
+    name: &'static str,
+    region: &'static str,
+    region_code: &'static str,
+    coordinates: Coordinates,
+    aliases: &'static [&'static str],
+) -> City {
+    City {
+        name,
+        region: Some(region),
+        region_code: Some(region_code),
+        country,
+        coordinates,
+        aliases,
+    }
+}
+
+/// A city in a country whose regions, if any, are written out in full.
+const fn named(
+    country: &'static str,
+    name: &'static str,
+    region: Option<&'static str>,
+    coordinates: Coordinates,
+    aliases: &'static [&'static str],
+) -> City {
+    City {
+        name,
+        region,
+        region_code: None,
+        country,
+        coordinates,
+        aliases,
+    }
+}
+
+/// City-centre coordinates in decimal degrees.
+const fn at(latitude: f64, longitude: f64) -> Coordinates {
+    Coordinates::new(latitude, longitude)
+}
+
+const CITIES: &[City] = &[
+    coded(
+        "US",
+        "New York",
+        "New York",
+        "NY",
+        at(40.7128, -74.006),
+        &[
+            "new york",
+            "new york city",
+            "nyc",
+            "manhattan",
+            "brooklyn",
+            "queens",
+            "bronx",
+            "the bronx",
+            "staten island",
+        ],
+    ),
+    coded(
+        "US",
+        "San Francisco",
+        "California",
+        "CA",
+        at(37.7749, -122.4194),
+        &[
+            "san francisco",
+            "sf",
+            "san fran",
+            "bay area",
+            "sf bay",
+            "san francisco bay",
+        ],
+    ),
+    coded(
+        "US",
+        "San Jose",
+        "California",
+        "CA",
+        at(37.3382, -121.8863),
+        &["san jose", "san josé", "silicon valley"],
+    ),
+    coded(
+        "US",
+        "Oakland",
+        "California",
+        "CA",
+        at(37.8044, -122.2712),
+        &["oakland"],
+    ),
+    coded(
+        "US",
+        "Palo Alto",
+        "California",
+        "CA",
+        at(37.4419, -122.143),
+        &["palo alto"],
+    ),
+    coded(
+        "US",
+        "Mountain View",
+        "California",
+        "CA",
+        at(37.3861, -122.0839),
+        &["mountain view"],
+    ),
+    coded(
+        "US",
+        "Sunnyvale",
+        "California",
+        "CA",
+        at(37.3688, -122.0363),
+        &["sunnyvale"],
+    ),
+    coded(
+        "US",
+        "Los Angeles",
+        "California",
+        "CA",
+        at(34.0522, -118.2437),
+        &["los angeles"],
+    ),
+    coded(
+        "US",
+        "San Diego",
+        "California",
+        "CA",
+        at(32.7157, -117.1611),
+        &["san diego"],
+    ),
+    coded(
+        "US",
+        "Irvine",
+        "California",
+        "CA",
+        at(33.6846, -117.8265),
+        &["irvine"],
+    ),
+    coded(
+        "US",
+        "Sacramento",
+        "California",
+        "CA",
+        at(38.5816, -121.4944),
+        &["sacramento"],
+    ),
+    coded(
+        "US",
+        "Seattle",
+        "Washington",
+        "WA",
+        at(47.6062, -122.3321),
+        &["seattle", "puget sound"],
+    ),
+    coded(
+        "US",
+        "Bellevue",
+        "Washington",
+        "WA",
+        at(47.6101, -122.2015),
+        &["bellevue"],
+    ),
+    coded(
+        "US",
+        "Redmond",
+        "Washington",
+        "WA",
+        at(47.674, -122.1215),
+        &["redmond"],
+    ),
+    coded(
+        "US",
+        "Vancouver",
+        "Washington",
+        "WA",
+        at(45.6387, -122.6615),
+        &["vancouver"],
+    ),
+    coded(
+        "US",
+        "Portland",
+        "Oregon",
+        "OR",
+        at(45.5152, -122.6784),
+        &["portland"],
+    ),
+    coded(
+        "US",
+        "Portland",
+        "Maine",
+        "ME",
+        at(43.6591, -70.2568),
+        &["portland"],
+    ),
+    coded(
+        "US",
+        "Austin",
+        "Texas",
+        "TX",
+        at(30.2672, -97.7431),
+        &["austin"],
+    ),
+    coded(
+        "US",
+        "Dallas",
+        "Texas",
+        "TX",
+        at(32.7767, -96.797),
+        &["dallas", "dfw", "dallas-fort worth", "dallas fort worth"],
+    ),
+    coded(
+        "US",
+        "Houston",
+        "Texas",
+        "TX",
+        at(29.7604, -95.3698),
+        &["houston"],
+    ),
+    coded(
+        "US",
+        "San Antonio",
+        "Texas",
+        "TX",
+        at(29.4241, -98.4936),
+        &["san antonio"],
+    ),
+    coded(
+        "US",
+        "Denver",
+        "Colorado",
+        "CO",
+        at(39.7392, -104.9903),
+        &["denver"],
+    ),
+    coded(
+        "US",
+        "Boulder",
+        "Colorado",
+        "CO",
+        at(40.015, -105.2705),
+        &["boulder"],
+    ),
+    coded(
+        "US",
+        "Chicago",
+        "Illinois",
+        "IL",
+        at(41.8781, -87.6298),
+        &["chicago", "chicagoland"],
+    ),
+    coded(
+        "US",
+        "Boston",
+        "Massachusetts",
+        "MA",
+        at(42.3601, -71.0589),
+        &["boston"],
+    ),
+    coded(
+        "US",
+        "Cambridge",
+        "Massachusetts",
+        "MA",
+        at(42.3736, -71.1097),
+        &["cambridge"],
+    ),
+    coded(
+        "US",
+        "Atlanta",
+        "Georgia",
+        "GA",
+        at(33.749, -84.388),
+        &["atlanta", "atl"],
+    ),
+    coded(
+        "US",
+        "Miami",
+        "Florida",
+        "FL",
+        at(25.7617, -80.1918),
+        &["miami", "south florida"],
+    ),
+    coded(
+        "US",
+        "Tampa",
+        "Florida",
+        "FL",
+        at(27.9506, -82.4572),
+        &["tampa", "tampa bay"],
+    ),
+    coded(
+        "US",
+        "Orlando",
+        "Florida",
+        "FL",
+        at(28.5383, -81.3792),
+        &["orlando"],
+    ),
+    coded(
+        "US",
+        "Washington",
+        "District of Columbia",
+        "DC",
+        at(38.9072, -77.0369),
+        &["washington dc", "washington d.c.", "dc", "d.c."],
+    ),
+    coded(
+        "US",
+        "Arlington",
+        "Virginia",
+        "VA",
+        at(38.8816, -77.091),
+        &["arlington"],
+    ),
+    coded(
+        "US",
+        "Raleigh",
+        "North Carolina",
+        "NC",
+        at(35.7796, -78.6382),
+        &["raleigh", "raleigh-durham", "research triangle"],
+    ),
+    coded(
+        "US",
+        "Durham",
+        "North Carolina",
+        "NC",
+        at(35.994, -78.8986),
+        &["durham"],
+    ),
+    coded(
+        "US",
+        "Charlotte",
+        "North Carolina",
+        "NC",
+        at(35.2271, -80.8431),
+        &["charlotte"],
+    ),
+    coded(
+        "US",
+        "Nashville",
+        "Tennessee",
+        "TN",
+        at(36.1627, -86.7816),
+        &["nashville"],
+    ),
+    coded(
+        "US",
+        "Minneapolis",
+        "Minnesota",
+        "MN",
+        at(44.9778, -93.265),
+        &["minneapolis", "twin cities", "minneapolis-st. paul"],
+    ),
+    coded(
+        "US",
+        "Detroit",
+        "Michigan",
+        "MI",
+        at(42.3314, -83.0458),
+        &["detroit"],
+    ),
+    coded(
+        "US",
+        "Philadelphia",
+        "Pennsylvania",
+        "PA",
+        at(39.9526, -75.1652),
+        &["philadelphia", "philly"],
+    ),
+    coded(
+        "US",
+        "Pittsburgh",
+        "Pennsylvania",
+        "PA",
+        at(40.4406, -79.9959),
+        &["pittsburgh"],
+    ),
+    coded(
+        "US",
+        "Phoenix",
+        "Arizona",
+        "AZ",
+        at(33.4484, -112.074),
+        &["phoenix"],
+    ),
+    coded(
+        "US",
+        "Salt Lake City",
+        "Utah",
+        "UT",
+        at(40.7608, -111.891),
+        &["salt lake city", "slc"],
+    ),
+    coded(
+        "US",
+        "Las Vegas",
+        "Nevada",
+        "NV",
+        at(36.1699, -115.1398),
+        &["las vegas"],
+    ),
+    coded(
+        "US",
+        "Columbus",
+        "Ohio",
+        "OH",
+        at(39.9612, -82.9988),
+        &["columbus"],
+    ),
+    coded(
+        "US",
+        "St. Louis",
+        "Missouri",
+        "MO",
+        at(38.627, -90.1994),
+        &["st. louis", "st louis", "saint louis"],
+    ),
+    coded(
+        "US",
+        "Kansas City",
+        "Missouri",
+        "MO",
+        at(39.0997, -94.5786),
+        &["kansas city"],
+    ),
+    coded(
+        "US",
+        "Baltimore",
+        "Maryland",
+        "MD",
+        at(39.2904, -76.6122),
+        &["baltimore"],
+    ),
+    coded(
+        "US",
+        "Indianapolis",
+        "Indiana",
+        "IN",
+        at(39.7684, -86.1581),
+        &["indianapolis"],
+    ),
+    coded(
+        "US",
+        "Madison",
+        "Wisconsin",
+        "WI",
+        at(43.0731, -89.4012),
+        &["madison"],
+    ),
+    coded(
+        "CA",
+        "Toronto",
+        "Ontario",
+        "ON",
+        at(43.6532, -79.3832),
+        &["toronto", "gta"],
+    ),
+    coded(
+        "CA",
+        "Ottawa",
+        "Ontario",
+        "ON",
+        at(45.4215, -75.6972),
+        &["ottawa"],
+    ),
+    coded(
+        "CA",
+        "Waterloo",
+        "Ontario",
+        "ON",
+        at(43.4643, -80.5204),
+        &["waterloo", "kitchener-waterloo"],
+    ),
+    coded(
+        "CA",
+        "London",
+        "Ontario",
+        "ON",
+        at(42.9849, -81.2453),
+        &["london"],
+    ),
+    coded(
+        "CA",
+        "Vancouver",
+        "British Columbia",
+        "BC",
+        at(49.2827, -123.1207),
+        &["vancouver"],
+    ),
+    coded(
+        "CA",
+        "Montreal",
+        "Quebec",
+        "QC",
+        at(45.5017, -73.5673),
+        &["montreal", "montréal"],
+    ),
+    coded(
+        "CA",
+        "Calgary",
+        "Alberta",
+        "AB",
+        at(51.0447, -114.0719),
+        &["calgary"],
+    ),
+    coded(
+        "CA",
+        "Edmonton",
+        "Alberta",
+        "AB",
+        at(53.5461, -113.4938),
+        &["edmonton"],
+    ),
+    coded(
+        "AU",
+        "Sydney",
+        "New South Wales",
+        "NSW",
+        at(-33.8688, 151.2093),
+        &["sydney"],
+    ),
+    coded(
+        "AU",
+        "Melbourne",
+        "Victoria",
+        "VIC",
+        at(-37.8136, 144.9631),
+        &["melbourne"],
+    ),
+    coded(
+        "AU",
+        "Brisbane",
+        "Queensland",
+        "QLD",
+        at(-27.4698, 153.0251),
+        &["brisbane"],
+    ),
+    named(
+        "GB",
+        "London",
+        Some("England"),
+        at(51.5074, -0.1278),
+        &["london"],
+    ),
+    named(
+        "GB",
+        "Manchester",
+        Some("England"),
+        at(53.4808, -2.2426),
+        &["manchester"],
+    ),
+    named(
+        "GB",
+        "Birmingham",
+        Some("England"),
+        at(52.4862, -1.8904),
+        &["birmingham"],
+    ),
+    named(
+        "GB",
+        "Bristol",
+        Some("England"),
+        at(51.4545, -2.5879),
+        &["bristol"],
+    ),
+    named(
+        "GB",
+        "Leeds",
+        Some("England"),
+        at(53.8008, -1.5491),
+        &["leeds"],
+    ),
+    named(
+        "GB",
+        "Cambridge",
+        Some("England"),
+        at(52.2053, 0.1218),
+        &["cambridge"],
+    ),
+    named(
+        "GB",
+        "Edinburgh",
+        Some("Scotland"),
+        at(55.9533, -3.1883),
+        &["edinburgh"],
+    ),
+    named(
+        "GB",
+        "Glasgow",
+        Some("Scotland"),
+        at(55.8642, -4.2518),
+        &["glasgow"],
+    ),
+    named(
+        "DE",
+        "Berlin",
+        Some("Berlin"),
+        at(52.52, 13.405),
+        &["berlin"],
+    ),
+    named(
+        "DE",
+        "Hamburg",
+        Some("Hamburg"),
+        at(53.5511, 9.9937),
+        &["hamburg"],
+    ),
+    named(
+        "DE",
+        "Munich",
+        Some("Bavaria"),
+        at(48.1351, 11.582),
+        &["munich", "münchen", "muenchen"],
+    ),
+    named(
+        "DE",
+        "Frankfurt",
+        Some("Hesse"),
+        at(50.1109, 8.6821),
+        &["frankfurt", "frankfurt am main"],
+    ),
+    named(
+        "DE",
+        "Cologne",
+        Some("North Rhine-Westphalia"),
+        at(50.9375, 6.9603),
+        &["cologne", "köln", "koeln"],
+    ),
+    named(
+        "DE",
+        "Stuttgart",
+        Some("Baden-Württemberg"),
+        at(48.7758, 9.1829),
+        &["stuttgart"],
+    ),
+    named("IE", "Dublin", None, at(53.3498, -6.2603), &["dublin"]),
+    named("NL", "Amsterdam", None, at(52.3676, 4.9041), &["amsterdam"]),
+    named("FR", "Paris", None, at(48.8566, 2.3522), &["paris"]),
+    named("ES", "Madrid", None, at(40.4168, -3.7038), &["madrid"]),
+    named("ES", "Barcelona", None, at(41.3874, 2.1686), &["barcelona"]),
+    named(
+        "PT",
+        "Lisbon",
+        None,
+        at(38.7223, -9.1393),
+        &["lisbon", "lisboa"],
+    ),
+    named("IT", "Milan", None, at(45.4642, 9.19), &["milan", "milano"]),
+    named(
+        "CH",
+        "Zurich",
+        None,
+        at(47.3769, 8.5417),
+        &["zurich", "zürich"],
+    ),
+    named(
+        "AT",
+        "Vienna",
+        None,
+        at(48.2082, 16.3738),
+        &["vienna", "wien"],
+    ),
+    named(
+        "SE",
+        "Stockholm",
+        None,
+        at(59.3293, 18.0686),
+        &["stockholm"],
+    ),
+    named(
+        "DK",
+        "Copenhagen",
+        None,
+        at(55.6761, 12.5683),
+        &["copenhagen", "københavn"],
+    ),
+    named("NO", "Oslo", None, at(59.9139, 10.7522), &["oslo"]),
+    named("FI", "Helsinki", None, at(60.1699, 24.9384), &["helsinki"]),
+    named(
+        "PL",
+        "Warsaw",
+        None,
+        at(52.2297, 21.0122),
+        &["warsaw", "warszawa"],
+    ),
+    named(
+        "CZ",
+        "Prague",
+        None,
+        at(50.0755, 14.4378),
+        &["prague", "praha"],
+    ),
+    named(
+        "NZ",
+        "Auckland",
+        None,
+        at(-36.8485, 174.7633),
+        &["auckland"],
+    ),
+    named(
+        "IN",
+        "Bangalore",
+        None,
+        at(12.9716, 77.5946),
+        &["bangalore", "bengaluru"],
+    ),
+    named(
+        "SG",
+        "Singapore",
+        None,
+        at(1.3521, 103.8198),
+        &["singapore"],
+    ),
+];
+
+/// Words that turn a city into its metro area, e.g. "Greater Boston Area".
+const METRO_PREFIXES: &[&str] = &["greater ", "metro "];
+const METRO_SUFFIXES: &[&str] = &[
+    " metropolitan area",
+    " metro area",
+    " area",
+    " metro",
+    " region",
+];
+
+fn without_metro_words(part: &str) -> &str {
+    let part = METRO_PREFIXES
+        .iter()
+        .find_map(|prefix| part.strip_prefix(prefix))
+        .unwrap_or(part);
+    METRO_SUFFIXES
+        .iter()
+        .find_map(|suffix| part.strip_suffix(suffix))
+        .unwrap_or(part)
+        .trim()
+}
+
+fn cities_named(name: &str) -> Vec<&'static City> {
+    let cities: Vec<_> = CITIES
+        .iter()
+        .filter(|city| city.aliases.contains(&name))
+        .collect();
+    if !cities.is_empty() {
+        return cities;
+    }
+
+    let name = without_metro_words(name);
+    CITIES
+        .iter()
+        .filter(|city| city.aliases.contains(&name))
+        .collect()
+}
+
+/// Parts of a location that could name a city, most likely first: the first
+/// part, the first two together ("Washington, DC"), then later parts that
+/// are not a region or country, so "Albany, New York" is not New York City.
+fn possible_city_names(location: &str) -> Vec<String> {
+    let parts: Vec<String> = location
+        .to_lowercase()
+        .split([',', '(', ')', '/', '|'])
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|part| !part.is_empty())
+        .collect();
+    let Some((first, rest)) = parts.split_first() else {
+        return Vec::new();
+    };
+
+    let mut names = vec![first.clone()];
+    names.extend(rest.first().map(|second| format!("{first} {second}")));
+    names.extend(
+        rest.iter()
+            .filter(|part| resolve_region(part).is_none() && country_code(part).is_none())
+            .cloned(),
+    );
+    names
+}
+
+/// Find the city a job location names, if the gazetteer knows it.
+///
+/// A region or country elsewhere in the location must agree with the city,
+/// so "Paris, TX" does not resolve to Paris, France.
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::geo::find_city;
+///
+/// assert_eq!(find_city("NYC").unwrap().key(), "new york, ny");
+/// assert_eq!(find_city("Greater Seattle Area").unwrap().name, "Seattle");
+/// assert_eq!(find_city("London, ON").unwrap().country, "CA");
+/// assert_eq!(find_city("Portland"), None);
+/// ```
+#[must_use]
+pub fn find_city(location: &str) -> Option<&'static City> {
+    let candidates = possible_city_names(location)
+        .iter()
+        .map(|name| cities_named(name))
+        .find(|cities| !cities.is_empty())?;
+
+    match resolve_location_region(location) {
+        Some(named) => candidates.into_iter().find(|city| {
+            city.country == named.country
+                && named
+                    .region
+                    .is_none_or(|region| city.region == Some(region))
+        }),
+        None => match candidates.as_slice() {
+            [city] => Some(city),
+            _ => None,
+        },
+    }
+}
+
+/// Detect the country and region a job location is in.
+///
+/// Regions and countries named in the text come first; a known city fills in
+/// what the text leaves out, so "Toronto" and "Toronto, Canada" both resolve
+/// to Ontario.
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::geo::location_region;
+///
+/// let munich = location_region("München").unwrap();
+/// assert_eq!((munich.country, munich.region), ("DE", Some("Bavaria")));
+/// assert_eq!(location_region("Springfield"), None);
+/// ```
+#[must_use]
+pub fn location_region(location: &str) -> Option<LocationRegion> {
+    match (resolve_location_region(location), find_city(location)) {
+        (Some(named), Some(city)) if named.region.is_none() => Some(city.location_region()),
+        (Some(named), _) => Some(named),
+        (None, city) => city.map(City::location_region),
+    }
+}
+
+/// Key for grouping job locations: "remote", a known city such as
+/// "seattle, wa", or otherwise the trimmed, lowercased location.
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::geo::location_key;
+///
+/// assert_eq!(location_key("SF Bay Area"), "san francisco, ca");
+/// assert_eq!(location_key("Remote - US"), "remote");
+/// assert_eq!(location_key(" Springfield "), "springfield");
+/// ```
+#[must_use]
+pub fn location_key(location: &str) -> String {
+    if normalize_location(location) == "remote" {
+        return "remote".to_string();
+    }
+    find_city(location).map_or_else(|| location.trim().to_lowercase(), City::key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_city, location_key, location_region, CITIES};
+    use crate::normalization::{country_code, resolve_region};
+
+    fn city_key(location: &str) -> Option<String> {
+        find_city(location).map(|city| city.key())
+    }
+
+    #[test]
+    fn aliases_and_metro_areas_resolve_to_one_city() {
+        for (input, expected) in [
+            ("NYC", "new york, ny"),
+            ("New York City", "new york, ny"),
+            ("Brooklyn, NY", "new york, ny"),
+            ("New York, NY, USA", "new york, ny"),
+            ("Greater Seattle Area", "seattle, wa"),
+            ("Seattle Metropolitan Area", "seattle, wa"),
+            ("San Francisco Bay Area", "san francisco, ca"),
+            ("SF, CA", "san francisco, ca"),
+            ("Washington, DC", "washington, dc"),
+            ("Austin, TX (hybrid)", "austin, tx"),
+            ("Greater London, England", "london, gb"),
+            ("Köln, Nordrhein-Westfalen", "cologne, de"),
+        ] {
+            assert_eq!(city_key(input).as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn shared_city_names_need_a_region_or_country() {
+        assert_eq!(city_key("Portland"), None);
+        assert_eq!(city_key("Portland, OR").as_deref(), Some("portland, or"));
+        assert_eq!(city_key("Portland, ME").as_deref(), Some("portland, me"));
+        assert_eq!(city_key("London, UK").as_deref(), Some("london, gb"));
+        assert_eq!(city_key("London, Ontario").as_deref(), Some("london, on"));
+        assert_eq!(city_key("Vancouver, BC").as_deref(), Some("vancouver, bc"));
+        assert_eq!(city_key("Paris, TX"), None);
+        assert_eq!(city_key("Albany, New York"), None);
+    }
+
+    #[test]
+    fn cities_fill_in_country_and_region() {
+        let toronto = location_region("Toronto, Canada").unwrap();
+        assert_eq!((toronto.country, toronto.region), ("CA", Some("Ontario")));
+
+        let dublin = location_region("Dublin").unwrap();
+        assert_eq!((dublin.country, dublin.region), ("IE", None));
+
+        let austin = location_region("Austin, TX").unwrap();
+        assert_eq!((austin.country, austin.region), ("US", Some("Texas")));
+
+        assert_eq!(location_region("Remote"), None);
+    }
+
+    #[test]
+    fn location_keys_group_remote_cities_and_the_rest() {
+        assert_eq!(location_key("Fully Remote"), "remote");
+        assert_eq!(location_key("Seattle, WA"), "seattle, wa");
+        assert_eq!(
+            location_key("Satisfactory Location"),
+            "satisfactory location"
+        );
+        assert_eq!(location_key(""), "");
+    }
+
+    #[test]
+    fn gazetteer_entries_match_the_region_tables() {
+        for city in CITIES {
+            assert_eq!(
+                country_code(city.country),
+                Some(city.country),
+                "{}",
+                city.name
+            );
+            if let Some(code) = city.region_code {
+                let region = resolve_region(code).unwrap();
+                assert_eq!(region.country, city.country, "{}", city.name);
+                assert_eq!(region.region, city.region, "{}", city.name);
+            }
+            if let Some(name) = city.region {
+                assert!(
+                    resolve_region(name).is_some() || city.region_code.is_some(),
+                    "{}",
+                    city.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cities_sharing_a_name_sit_in_different_places() {
+        for city in CITIES {
+            assert!(city.coordinates.is_valid(), "{}", city.name);
+        }
+
+        let portland_or = find_city("Portland, OR").unwrap().coordinates;
+        let portland_me = find_city("Portland, ME").unwrap().coordinates;
+        let london_gb = find_city("London, UK").unwrap().coordinates;
+        let london_on = find_city("London, Ontario").unwrap().coordinates;
+        assert!(portland_or.distance_km(&portland_me) > 3_000.0);
+        assert!(london_gb.distance_km(&london_on) > 5_000.0);
+    }
+}
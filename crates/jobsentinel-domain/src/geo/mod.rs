@@ -0,0 +1,77 @@
+//! Places and distances.
+//!
+//! Map coordinates and straight-line distances for commute scoring, and a
+//! bundled gazetteer that resolves city names and aliases to one place.
+
+mod gazetteer;
+
+use serde::{Deserialize, Serialize};
+
+pub use gazetteer::{find_city, location_key, location_region, City};
+
+/// Mean Earth radius used for great-circle distances.
+const EARTH_RADIUS_KM: f64 = 6_371.0;
+
+/// A point on the map in decimal degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    #[must_use]
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Whether both values are finite and on the map.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.latitude.is_finite()
+            && self.longitude.is_finite()
+            && (-90.0..=90.0).contains(&self.latitude)
+            && (-180.0..=180.0).contains(&self.longitude)
+    }
+
+    /// Great-circle distance to `other` in kilometres (haversine formula).
+    #[must_use]
+    pub fn distance_km(&self, other: &Self) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lat = (other.latitude - self.latitude).to_radians();
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Coordinates;
+
+    #[test]
+    fn distance_between_known_cities() {
+        let london = Coordinates::new(51.5074, -0.1278);
+        let paris = Coordinates::new(48.8566, 2.3522);
+
+        let distance = london.distance_km(&paris);
+
+        assert!((distance - 343.5).abs() < 2.0, "got {distance}");
+        assert!((paris.distance_km(&london) - distance).abs() < 1e-9);
+        assert_eq!(london.distance_km(&london), 0.0);
+    }
+
+    #[test]
+    fn off_map_coordinates_are_invalid() {
+        assert!(Coordinates::new(45.0, -122.0).is_valid());
+        assert!(!Coordinates::new(91.0, 0.0).is_valid());
+        assert!(!Coordinates::new(0.0, 181.0).is_valid());
+        assert!(!Coordinates::new(f64::NAN, 0.0).is_valid());
+    }
+}
@@ -3,7 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::calculate_job_hash;
+use crate::{calculate_job_hash, JobStatus, PayPeriod};
 
 /// Canonical job record used across core business logic.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +34,10 @@ pub struct Job {
     pub salary_max: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
+    /// Period the posted salary is paid in; `None` when the posting did not
+    /// say.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_period: Option<PayPeriod>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_seen: DateTime<Utc>,
@@ -64,6 +68,12 @@ pub struct Job {
     /// Number of times this job has been reposted.
     #[serde(default)]
     pub repost_count: i64,
+    /// Whether the posting is still open.
+    #[serde(default)]
+    pub status: JobStatus,
+    /// Skill tags the source attached to the posting.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<String>,
 }
 
 impl Job {
@@ -96,6 +106,7 @@ impl Job {
             salary_min: None,
             salary_max: None,
             currency: None,
+            pay_period: None,
             created_at: discovered_at,
             updated_at: discovered_at,
             last_seen: discovered_at,
@@ -109,14 +120,36 @@ impl Job {
             ghost_reasons: None,
             first_seen: Some(discovered_at),
             repost_count: 0,
+            status: JobStatus::Active,
+            skills: Vec::new(),
         }
     }
+
+    /// Period the posted salary is paid in, inferring hourly pay from small
+    /// amounts when the posting did not say.
+    #[must_use]
+    pub fn effective_pay_period(&self) -> PayPeriod {
+        self.pay_period.unwrap_or_else(|| {
+            PayPeriod::inferred_from_amount(self.salary_max.or(self.salary_min).unwrap_or(0))
+        })
+    }
+
+    /// The posted `(min, max)` salary as annual amounts in the posting
+    /// currency.
+    #[must_use]
+    pub fn annual_salary(&self) -> (Option<i64>, Option<i64>) {
+        let period = self.effective_pay_period();
+        (
+            self.salary_min.map(|amount| period.annualize(amount)),
+            self.salary_max.map(|amount| period.annualize(amount)),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Job;
-    use crate::calculate_job_hash;
+    use crate::{calculate_job_hash, JobStatus, PayPeriod};
     use chrono::{TimeZone, Utc};
 
     #[test]
@@ -151,6 +184,7 @@ mod tests {
         assert!(!job.hidden);
         assert!(!job.bookmarked);
         assert_eq!(job.repost_count, 0);
+        assert_eq!(job.status, JobStatus::Active);
         assert!(job.description.is_none());
         assert!(job.score.is_none());
         assert!(job.score_reasons.is_none());
@@ -158,6 +192,7 @@ mod tests {
         assert!(job.salary_min.is_none());
         assert!(job.salary_max.is_none());
         assert!(job.currency.is_none());
+        assert!(job.pay_period.is_none());
         assert!(job.notes.is_none());
         assert!(job.ghost_score.is_none());
         assert!(job.ghost_reasons.is_none());
@@ -0,0 +1,52 @@
+//! Lifecycle status of a saved posting.
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a saved posting is still open.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Listed by a source, or its page still loads.
+    #[default]
+    Active,
+    /// The page is gone or redirects elsewhere.
+    Closed,
+    /// No scrape has seen the posting for a long time.
+    Expired,
+}
+
+impl JobStatus {
+    /// Stored name of the status.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Closed => "closed",
+            Self::Expired => "expired",
+        }
+    }
+
+    /// Parse a stored name.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim() {
+            "active" => Some(Self::Active),
+            "closed" => Some(Self::Closed),
+            "expired" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_names_round_trip() {
+        for status in [JobStatus::Active, JobStatus::Closed, JobStatus::Expired] {
+            assert_eq!(JobStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(JobStatus::parse("filled"), None);
+    }
+}
@@ -2,18 +2,29 @@
 
 mod application_assistance;
 mod external_ai;
+pub mod geo;
 mod job;
 mod job_hash;
+mod job_status;
 pub mod normalization;
+mod pay_period;
+mod salary;
 mod scoring_config;
 
 pub use application_assistance::{
     screening_question_matches, AnswerSource, AnswerStatistics, AnswerSuggestion,
-    ApplicationAttempt, ApplicationProfile, ApplicationProfileInput, AtsPlatform, AutomationStats,
+    ApplicationAttempt, ApplicationProfile, ApplicationProfileInput, ApplicationProfileSummary,
+    AtsPlatform, AttemptFillSummary, AutomationPermission, AutomationQuota, AutomationStats,
     AutomationStatus, ModificationExample, ScreeningAnswer,
 };
 pub use external_ai::{ExternalAiConfig, ExternalAiProvider, ExternalAiRedactionConfig};
+pub use geo::Coordinates;
 pub use job::Job;
 pub use job_hash::calculate_job_hash;
+pub use job_status::JobStatus;
 pub use normalization::canonicalize_job_url;
+pub use pay_period::{PayPeriod, PostedPayRate, HOURLY_AMOUNT_CEILING};
+pub use salary::{
+    normalize_currency_code, ExchangeRates, BUNDLED_EXCHANGE_RATES, BUNDLED_RATES_AS_OF,
+};
 pub use scoring_config::ScoringConfig;
@@ -1,11 +1,13 @@
 //! Owner-neutral normalization used by job identity and source adapters.
 
 mod location;
+mod region;
 mod title;
 mod url;
 mod work_arrangement;
 
 pub use location::normalize_location;
+pub use region::{country_code, resolve_location_region, resolve_region, LocationRegion};
 pub use title::{normalize_title, titles_match};
 pub use url::{canonicalize_job_url, normalize_url};
 pub use work_arrangement::{infer_remote_status, resolve_remote_status, RemoteStatus};
@@ -0,0 +1,336 @@
+//! Country and region detection for location strings
+//!
+//! Reads the trailing parts of a location such as "Austin, TX",
+//! "Toronto, ON, Canada", "Manchester, England", or "München, Bayern" and
+//! reports the country and first-level region it names. Two-letter codes are
+//! read as a US state, Canadian province, or Australian state before an ISO
+//! country code, because that is how most boards write them.
+
+/// Country (ISO 3166-1 alpha-2) and optional region named by a location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocationRegion {
+    pub country: &'static str,
+    /// Canonical region name, e.g. "Texas", "Ontario", "Scotland", "Bavaria"
+    pub region: Option<&'static str>,
+}
+
+/// (country, canonical name, aliases); aliases are lowercase.
+type RegionEntry = (&'static str, &'static str, &'static [&'static str]);
+
+/// Regions written with a short code; order decides ambiguous codes.
+const CODED_REGIONS: &[RegionEntry] = &[
+    ("US", "Alabama", &["al", "alabama"]),
+    ("US", "Alaska", &["ak", "alaska"]),
+    ("US", "Arizona", &["az", "arizona"]),
+    ("US", "Arkansas", &["ar", "arkansas"]),
+    ("US", "California", &["ca", "california"]),
+    ("US", "Colorado", &["co", "colorado"]),
+    ("US", "Connecticut", &["ct", "connecticut"]),
+    ("US", "Delaware", &["de", "delaware"]),
+    (
+        "US",
+        "District of Columbia",
+        &["dc", "district of columbia"],
+    ),
+    ("US", "Florida", &["fl", "florida"]),
+    ("US", "Georgia", &["ga"]),
+    ("US", "Hawaii", &["hi", "hawaii"]),
+    ("US", "Idaho", &["id", "idaho"]),
+    ("US", "Illinois", &["il", "illinois"]),
+    ("US", "Indiana", &["in", "indiana"]),
+    ("US", "Iowa", &["ia", "iowa"]),
+    ("US", "Kansas", &["ks", "kansas"]),
+    ("US", "Kentucky", &["ky", "kentucky"]),
+    ("US", "Louisiana", &["la", "louisiana"]),
+    ("US", "Maine", &["me", "maine"]),
+    ("US", "Maryland", &["md", "maryland"]),
+    ("US", "Massachusetts", &["ma", "massachusetts"]),
+    ("US", "Michigan", &["mi", "michigan"]),
+    ("US", "Minnesota", &["mn", "minnesota"]),
+    ("US", "Mississippi", &["ms", "mississippi"]),
+    ("US", "Missouri", &["mo", "missouri"]),
+    ("US", "Montana", &["mt", "montana"]),
+    ("US", "Nebraska", &["ne", "nebraska"]),
+    ("US", "Nevada", &["nv", "nevada"]),
+    ("US", "New Hampshire", &["nh", "new hampshire"]),
+    ("US", "New Jersey", &["nj", "new jersey"]),
+    ("US", "New Mexico", &["nm", "new mexico"]),
+    ("US", "New York", &["ny", "new york"]),
+    ("US", "North Carolina", &["nc", "north carolina"]),
+    ("US", "North Dakota", &["nd", "north dakota"]),
+    ("US", "Ohio", &["oh", "ohio"]),
+    ("US", "Oklahoma", &["ok", "oklahoma"]),
+    ("US", "Oregon", &["or", "oregon"]),
+    ("US", "Pennsylvania", &["pa", "pennsylvania"]),
+    ("US", "Rhode Island", &["ri", "rhode island"]),
+    ("US", "South Carolina", &["sc", "south carolina"]),
+    ("US", "South Dakota", &["sd", "south dakota"]),
+    ("US", "Tennessee", &["tn", "tennessee"]),
+    ("US", "Texas", &["tx", "texas"]),
+    ("US", "Utah", &["ut", "utah"]),
+    ("US", "Vermont", &["vt", "vermont"]),
+    ("US", "Virginia", &["va", "virginia"]),
+    ("US", "Washington", &["wa", "washington state"]),
+    ("US", "West Virginia", &["wv", "west virginia"]),
+    ("US", "Wisconsin", &["wi", "wisconsin"]),
+    ("US", "Wyoming", &["wy", "wyoming"]),
+    ("CA", "Alberta", &["ab", "alberta"]),
+    ("CA", "British Columbia", &["bc", "british columbia"]),
+    ("CA", "Manitoba", &["mb", "manitoba"]),
+    ("CA", "New Brunswick", &["nb", "new brunswick"]),
+    (
+        "CA",
+        "Newfoundland and Labrador",
+        &["nl", "newfoundland and labrador", "newfoundland"],
+    ),
+    ("CA", "Nova Scotia", &["ns", "nova scotia"]),
+    ("CA", "Ontario", &["on", "ontario"]),
+    (
+        "CA",
+        "Prince Edward Island",
+        &["pe", "pei", "prince edward island"],
+    ),
+    ("CA", "Quebec", &["qc", "quebec", "québec"]),
+    ("CA", "Saskatchewan", &["sk", "saskatchewan"]),
+    ("AU", "New South Wales", &["nsw", "new south wales"]),
+    ("AU", "Victoria", &["vic", "victoria"]),
+    ("AU", "Queensland", &["qld", "queensland"]),
+    ("AU", "Western Australia", &["western australia"]),
+    ("AU", "South Australia", &["south australia"]),
+    ("AU", "Tasmania", &["tas", "tasmania"]),
+    (
+        "AU",
+        "Australian Capital Territory",
+        &["act", "australian capital territory"],
+    ),
+    ("AU", "Northern Territory", &["northern territory"]),
+];
+
+/// Regions that are only written out in full.
+const NAMED_REGIONS: &[RegionEntry] = &[
+    ("GB", "England", &["england"]),
+    ("GB", "Scotland", &["scotland"]),
+    ("GB", "Wales", &["wales"]),
+    ("GB", "Northern Ireland", &["northern ireland"]),
+    (
+        "DE",
+        "Baden-Württemberg",
+        &[
+            "baden-württemberg",
+            "baden-wurttemberg",
+            "baden-wuerttemberg",
+        ],
+    ),
+    ("DE", "Bavaria", &["bavaria", "bayern"]),
+    ("DE", "Berlin", &["berlin"]),
+    ("DE", "Brandenburg", &["brandenburg"]),
+    ("DE", "Bremen", &["bremen"]),
+    ("DE", "Hamburg", &["hamburg"]),
+    ("DE", "Hesse", &["hesse", "hessen"]),
+    ("DE", "Lower Saxony", &["lower saxony", "niedersachsen"]),
+    (
+        "DE",
+        "Mecklenburg-Vorpommern",
+        &["mecklenburg-vorpommern", "mecklenburg-western pomerania"],
+    ),
+    (
+        "DE",
+        "North Rhine-Westphalia",
+        &["north rhine-westphalia", "nordrhein-westfalen", "nrw"],
+    ),
+    (
+        "DE",
+        "Rhineland-Palatinate",
+        &["rhineland-palatinate", "rheinland-pfalz"],
+    ),
+    ("DE", "Saarland", &["saarland"]),
+    ("DE", "Saxony", &["saxony", "sachsen"]),
+    ("DE", "Saxony-Anhalt", &["saxony-anhalt", "sachsen-anhalt"]),
+    ("DE", "Schleswig-Holstein", &["schleswig-holstein"]),
+    ("DE", "Thuringia", &["thuringia", "thüringen", "thueringen"]),
+];
+
+/// (country code, lowercase names); the code itself is also accepted.
+const COUNTRIES: &[(&str, &[&str])] = &[
+    (
+        "US",
+        &["usa", "united states", "united states of america", "u.s."],
+    ),
+    ("CA", &["canada"]),
+    ("GB", &["uk", "united kingdom", "great britain", "britain"]),
+    ("IE", &["ireland"]),
+    ("DE", &["germany", "deutschland"]),
+    ("AT", &["austria", "österreich", "oesterreich"]),
+    ("CH", &["switzerland", "schweiz", "suisse"]),
+    ("FR", &["france"]),
+    ("NL", &["netherlands", "the netherlands", "holland"]),
+    ("BE", &["belgium"]),
+    ("LU", &["luxembourg"]),
+    ("ES", &["spain", "españa"]),
+    ("PT", &["portugal"]),
+    ("IT", &["italy", "italia"]),
+    ("SE", &["sweden"]),
+    ("DK", &["denmark"]),
+    ("NO", &["norway"]),
+    ("FI", &["finland"]),
+    ("PL", &["poland"]),
+    ("CZ", &["czechia", "czech republic"]),
+    ("AU", &["australia"]),
+    ("NZ", &["new zealand"]),
+    ("IN", &["india"]),
+    ("SG", &["singapore"]),
+];
+
+/// Resolve a country name, alias, or ISO code to its ISO 3166-1 alpha-2 code.
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::normalization::country_code;
+///
+/// assert_eq!(country_code("United Kingdom"), Some("GB"));
+/// assert_eq!(country_code("de"), Some("DE"));
+/// assert_eq!(country_code("Atlantis"), None);
+/// ```
+#[must_use]
+pub fn country_code(value: &str) -> Option<&'static str> {
+    let lower = value.trim().to_lowercase();
+    COUNTRIES
+        .iter()
+        .find(|(code, names)| code.eq_ignore_ascii_case(&lower) || names.contains(&lower.as_str()))
+        .map(|(code, _)| *code)
+}
+
+/// Resolve a region name or code to its country and canonical name.
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::normalization::resolve_region;
+///
+/// let ontario = resolve_region("ON").unwrap();
+/// assert_eq!((ontario.country, ontario.region), ("CA", Some("Ontario")));
+/// ```
+#[must_use]
+pub fn resolve_region(value: &str) -> Option<LocationRegion> {
+    let lower = value.trim().to_lowercase();
+    CODED_REGIONS
+        .iter()
+        .chain(NAMED_REGIONS)
+        .find(|(_, _, aliases)| aliases.contains(&lower.as_str()))
+        .map(|(country, name, _)| LocationRegion {
+            country,
+            region: Some(name),
+        })
+}
+
+/// Detect the country and region a job location names, if any.
+///
+/// Parts are read from the end, so "Portland, ME, USA" resolves to Maine in
+/// the US. Returns `None` for locations without a recognised country or
+/// region, such as a bare city name or "Remote".
+///
+/// # Examples
+/// ```
+/// use jobsentinel_domain::normalization::resolve_location_region;
+///
+/// let toronto = resolve_location_region("Toronto, ON").unwrap();
+/// assert_eq!(toronto.country, "CA");
+/// assert_eq!(toronto.region, Some("Ontario"));
+///
+/// let london = resolve_location_region("London, United Kingdom").unwrap();
+/// assert_eq!((london.country, london.region), ("GB", None));
+/// ```
+#[must_use]
+pub fn resolve_location_region(location: &str) -> Option<LocationRegion> {
+    let parts: Vec<&str> = location
+        .split([',', '(', ')', '/', '|'])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let mut country = None;
+    for part in parts.iter().rev() {
+        if let Some(region) = resolve_region(part) {
+            match country {
+                Some(code) if code != region.country => continue,
+                _ => return Some(region),
+            }
+        }
+        if country.is_none() {
+            country = country_code(part);
+        }
+    }
+
+    country.map(|country| LocationRegion {
+        country,
+        region: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{country_code, resolve_location_region, resolve_region, LocationRegion};
+
+    fn region(country: &'static str, name: &'static str) -> Option<LocationRegion> {
+        Some(LocationRegion {
+            country,
+            region: Some(name),
+        })
+    }
+
+    #[test]
+    fn resolves_region_formats_per_country() {
+        for (input, expected) in [
+            ("Austin, TX", region("US", "Texas")),
+            ("Portland, ME, USA", region("US", "Maine")),
+            ("Toronto, ON", region("CA", "Ontario")),
+            ("Montréal, Québec, Canada", region("CA", "Quebec")),
+            ("Sydney NSW", None),
+            ("Sydney, NSW", region("AU", "New South Wales")),
+            ("Edinburgh, Scotland", region("GB", "Scotland")),
+            ("München, Bayern, Deutschland", region("DE", "Bavaria")),
+        ] {
+            assert_eq!(resolve_location_region(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn country_suffix_settles_ambiguous_region_codes() {
+        assert_eq!(
+            resolve_location_region("Perth, WA"),
+            region("US", "Washington")
+        );
+        assert_eq!(
+            resolve_location_region("Perth, Western Australia, Australia"),
+            region("AU", "Western Australia")
+        );
+        assert_eq!(
+            resolve_location_region("Halifax, NS, Canada"),
+            region("CA", "Nova Scotia")
+        );
+    }
+
+    #[test]
+    fn resolves_country_only_locations() {
+        let london = resolve_location_region("London, United Kingdom").unwrap();
+        assert_eq!((london.country, london.region), ("GB", None));
+
+        let vienna = resolve_location_region("Vienna (Austria)").unwrap();
+        assert_eq!(vienna.country, "AT");
+
+        assert_eq!(resolve_location_region("Remote"), None);
+        assert_eq!(resolve_location_region("Springfield"), None);
+        assert_eq!(resolve_location_region(""), None);
+    }
+
+    #[test]
+    fn resolves_country_names_and_codes() {
+        assert_eq!(country_code("United States"), Some("US"));
+        assert_eq!(country_code(" uk "), Some("GB"));
+        assert_eq!(country_code("ch"), Some("CH"));
+        assert_eq!(country_code("Narnia"), None);
+        assert_eq!(
+            resolve_region("Nordrhein-Westfalen"),
+            region("DE", "North Rhine-Westphalia")
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Pay periods for posted salaries and hourly or contract rates.
+//!
+//! Salaries are stored as posted, next to the period they are paid in.
+//! Scoring, filters, and statistics compare annual figures, so each period
+//! carries the full-time multiplier used to annualize it.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Posted amounts below this with no stated period are treated as hourly.
+pub const HOURLY_AMOUNT_CEILING: i64 = 1_000;
+
+/// Smallest annual figure a detected period may produce.
+const MIN_PLAUSIBLE_ANNUAL: i64 = 5_000;
+/// Largest annual figure a detected period may produce.
+const MAX_PLAUSIBLE_ANNUAL: i64 = 5_000_000;
+
+/// How often a posted salary or rate is paid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayPeriod {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl PayPeriod {
+    /// Stored name of the period.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+
+    /// Parse a stored name or a common posting label such as `hr`, `daily`,
+    /// or `per annum`.
+    #[must_use]
+    pub fn parse(label: &str) -> Option<Self> {
+        let normalized = label
+            .trim()
+            .to_ascii_lowercase()
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "");
+        match normalized.as_str() {
+            "hour" | "hourly" | "hr" | "h" | "perhour" => Some(Self::Hour),
+            "day" | "daily" | "perday" => Some(Self::Day),
+            "week" | "weekly" | "wk" | "perweek" => Some(Self::Week),
+            "month" | "monthly" | "mo" | "permonth" => Some(Self::Month),
+            "year" | "yearly" | "yr" | "annual" | "annually" | "annum" | "perannum" | "peryear" => {
+                Some(Self::Year)
+            }
+            _ => None,
+        }
+    }
+
+    /// Full-time periods in a year: 2,080 hours, 260 days, 52 weeks, or 12
+    /// months.
+    #[must_use]
+    pub const fn periods_per_year(self) -> i64 {
+        match self {
+            Self::Hour => 2_080,
+            Self::Day => 260,
+            Self::Week => 52,
+            Self::Month => 12,
+            Self::Year => 1,
+        }
+    }
+
+    /// Annual equivalent of an amount paid in this period.
+    #[must_use]
+    pub const fn annualize(self, amount: i64) -> i64 {
+        amount.saturating_mul(self.periods_per_year())
+    }
+
+    /// Whether `amount` paid in this period gives a believable annual
+    /// figure. Guards against reading "$120,000 per hour" or "$45 a year".
+    #[must_use]
+    pub const fn is_plausible(self, amount: i64) -> bool {
+        let annual = self.annualize(amount);
+        annual >= MIN_PLAUSIBLE_ANNUAL && annual <= MAX_PLAUSIBLE_ANNUAL
+    }
+
+    /// Period assumed for a posting that does not state one: small amounts
+    /// are hourly, everything else is yearly.
+    #[must_use]
+    pub const fn inferred_from_amount(amount: i64) -> Self {
+        if amount > 0 && amount < HOURLY_AMOUNT_CEILING {
+            Self::Hour
+        } else {
+            Self::Year
+        }
+    }
+}
+
+/// A pay rate found in posting text, such as "$45-55/hr".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostedPayRate {
+    pub min: i64,
+    pub max: Option<i64>,
+    pub period: PayPeriod,
+    pub currency: Option<String>,
+}
+
+#[allow(clippy::expect_used)] // A constant pattern must fail fast.
+static PAY_RATE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?ix)
+        (?P<symbol>[$€£]|\b(?:usd|eur|gbp|cad|aud|chf)\s?)\s*
+        (?P<min>\d[\d,]*(?:\.\d+)?)(?P<min_k>k)?
+        (?:\s*(?:-|–|to)\s*(?:[$€£]|(?:usd|eur|gbp|cad|aud|chf)\s?)?\s*
+            (?P<max>\d[\d,]*(?:\.\d+)?)(?P<max_k>k)?)?
+        \s*(?:(?:usd|eur|gbp|cad|aud|chf)\b\s*)?
+        (?:/\s*|\s*per\s+|\s+an?\s+|\s+)
+        (?P<period>hourly|hour|hr|h|daily|day|weekly|week|wk|monthly|month|mo
+            |annually|annual|annum|yearly|year|yr)\b",
+    )
+    .expect("Valid pay rate regex")
+});
+
+impl PostedPayRate {
+    /// First amount in `text` that names its pay period.
+    #[must_use]
+    pub fn find(text: &str) -> Option<Self> {
+        PAY_RATE_PATTERN.captures_iter(text).find_map(|captures| {
+            let period = PayPeriod::parse(&captures["period"])?;
+            let min = parse_amount(&captures["min"], captures.name("min_k").is_some())?;
+            let max = captures
+                .name("max")
+                .and_then(|max| parse_amount(max.as_str(), captures.name("max_k").is_some()))
+                .filter(|max| *max >= min);
+            Some(Self {
+                min,
+                max,
+                period,
+                currency: currency_from_symbol(&captures["symbol"]),
+            })
+        })
+    }
+}
+
+fn parse_amount(digits: &str, thousands: bool) -> Option<i64> {
+    let amount: f64 = digits.replace(',', "").parse().ok()?;
+    let amount = if thousands { amount * 1_000.0 } else { amount };
+    (amount.is_finite() && amount > 0.0).then(|| amount.round() as i64)
+}
+
+fn currency_from_symbol(symbol: &str) -> Option<String> {
+    match symbol.trim() {
+        "$" => None,
+        "€" => Some("EUR".to_string()),
+        "£" => Some("GBP".to_string()),
+        code => Some(code.to_ascii_uppercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_posting_labels() {
+        assert_eq!(PayPeriod::parse("per hour"), Some(PayPeriod::Hour));
+        assert_eq!(PayPeriod::parse("Daily"), Some(PayPeriod::Day));
+        assert_eq!(PayPeriod::parse("per annum"), Some(PayPeriod::Year));
+        assert_eq!(PayPeriod::parse("fortnight"), None);
+    }
+
+    #[test]
+    fn finds_hourly_and_daily_rates_in_text() {
+        let hourly = PostedPayRate::find("Contract role paying $45 - $55/hr, remote").unwrap();
+        assert_eq!(hourly.min, 45);
+        assert_eq!(hourly.max, Some(55));
+        assert_eq!(hourly.period, PayPeriod::Hour);
+        assert_eq!(hourly.currency, None);
+
+        let daily = PostedPayRate::find("Day rate: €450 per day, outside IR35").unwrap();
+        assert_eq!(daily.min, 450);
+        assert_eq!(daily.period, PayPeriod::Day);
+        assert_eq!(daily.currency.as_deref(), Some("EUR"));
+
+        let yearly = PostedPayRate::find("Salary $90k-110k a year plus equity").unwrap();
+        assert_eq!((yearly.min, yearly.max), (90_000, Some(110_000)));
+        assert_eq!(yearly.period, PayPeriod::Year);
+    }
+
+    #[test]
+    fn ignores_hours_without_money() {
+        assert_eq!(
+            PostedPayRate::find("Work 40 hours per week from home"),
+            None
+        );
+    }
+
+    #[test]
+    fn plausibility_rejects_mismatched_periods() {
+        assert!(PayPeriod::Hour.is_plausible(45));
+        assert!(!PayPeriod::Hour.is_plausible(120_000));
+        assert!(!PayPeriod::Year.is_plausible(45));
+        assert_eq!(PayPeriod::Day.annualize(400), 104_000);
+    }
+}
@@ -0,0 +1,176 @@
+//! Currency conversion for posted salaries.
+//!
+//! Pay floors and salary statistics are kept in US dollars, so postings in
+//! other currencies are converted with a bundled rate table that can be
+//! overlaid with refreshed rates.
+
+use std::collections::HashMap;
+
+use crate::Job;
+
+/// Date the bundled rates were taken.
+pub const BUNDLED_RATES_AS_OF: &str = "2026-09-30";
+
+/// US dollars per unit of each currency, as of [`BUNDLED_RATES_AS_OF`].
+pub const BUNDLED_EXCHANGE_RATES: &[(&str, f64)] = &[
+    ("USD", 1.0),
+    ("EUR", 1.08),
+    ("GBP", 1.27),
+    ("CAD", 0.73),
+    ("AUD", 0.66),
+    ("NZD", 0.60),
+    ("CHF", 1.12),
+    ("SEK", 0.095),
+    ("NOK", 0.093),
+    ("DKK", 0.145),
+    ("PLN", 0.25),
+    ("CZK", 0.043),
+    ("HUF", 0.0027),
+    ("RON", 0.22),
+    ("UAH", 0.024),
+    ("TRY", 0.03),
+    ("ILS", 0.27),
+    ("AED", 0.272),
+    ("ZAR", 0.054),
+    ("INR", 0.012),
+    ("JPY", 0.0067),
+    ("KRW", 0.000_73),
+    ("CNY", 0.138),
+    ("HKD", 0.128),
+    ("SGD", 0.74),
+    ("PHP", 0.0175),
+    ("BRL", 0.18),
+    ("MXN", 0.055),
+];
+
+/// Currency assumed when a posting does not say.
+const DEFAULT_CURRENCY: &str = "USD";
+
+/// Conversion table from currency codes to US dollars.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRates {
+    usd_per_unit: HashMap<String, f64>,
+}
+
+impl Default for ExchangeRates {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+impl ExchangeRates {
+    /// Rates shipped with the app.
+    #[must_use]
+    pub fn bundled() -> Self {
+        let usd_per_unit = BUNDLED_EXCHANGE_RATES
+            .iter()
+            .map(|(code, rate)| ((*code).to_string(), *rate))
+            .collect();
+        Self { usd_per_unit }
+    }
+
+    /// Replace or add one rate. Invalid codes and non-positive rates are
+    /// ignored, and US dollars always stay at 1.
+    pub fn set_rate(&mut self, currency: &str, usd_per_unit: f64) {
+        let Some(code) = normalize_currency_code(currency) else {
+            return;
+        };
+        if code == DEFAULT_CURRENCY || !usd_per_unit.is_finite() || usd_per_unit <= 0.0 {
+            return;
+        }
+        self.usd_per_unit.insert(code, usd_per_unit);
+    }
+
+    /// US dollars per unit of `currency`, when it is known.
+    #[must_use]
+    pub fn usd_per_unit(&self, currency: &str) -> Option<f64> {
+        let code = normalize_currency_code(currency)?;
+        self.usd_per_unit.get(&code).copied()
+    }
+
+    /// Convert `amount` to whole US dollars. A missing currency is treated
+    /// as US dollars; an unknown one gives `None`.
+    #[must_use]
+    pub fn to_usd(&self, amount: i64, currency: Option<&str>) -> Option<i64> {
+        let rate = self.usd_per_unit(currency.unwrap_or(DEFAULT_CURRENCY))?;
+        Some((amount as f64 * rate).round() as i64)
+    }
+
+    /// The job's posted `(min, max)` salary as annual US dollars.
+    #[must_use]
+    pub fn job_salary_usd(&self, job: &Job) -> (Option<i64>, Option<i64>) {
+        let currency = job.currency.as_deref();
+        let (min, max) = job.annual_salary();
+        (
+            min.and_then(|amount| self.to_usd(amount, currency)),
+            max.and_then(|amount| self.to_usd(amount, currency)),
+        )
+    }
+
+    /// Every known `(code, usd_per_unit)` pair, sorted by code.
+    #[must_use]
+    pub fn rates(&self) -> Vec<(String, f64)> {
+        let mut rates: Vec<_> = self
+            .usd_per_unit
+            .iter()
+            .map(|(code, rate)| (code.clone(), *rate))
+            .collect();
+        rates.sort_by(|a, b| a.0.cmp(&b.0));
+        rates
+    }
+}
+
+/// Upper-case three-letter ISO 4217 code, or `None` for anything else.
+#[must_use]
+pub fn normalize_currency_code(currency: &str) -> Option<String> {
+    let code = currency.trim().to_ascii_uppercase();
+    (code.len() == 3 && code.bytes().all(|byte| byte.is_ascii_uppercase())).then_some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn converts_known_currencies_and_treats_missing_as_usd() {
+        let rates = ExchangeRates::bundled();
+
+        assert_eq!(rates.to_usd(50_000, Some("eur")), Some(54_000));
+        assert_eq!(rates.to_usd(50_000, None), Some(50_000));
+        assert_eq!(rates.to_usd(50_000, Some("XYZ")), None);
+        assert_eq!(rates.to_usd(50_000, Some("$")), None);
+    }
+
+    #[test]
+    fn refreshed_rates_override_bundled_ones_but_not_usd() {
+        let mut rates = ExchangeRates::bundled();
+        rates.set_rate("EUR", 1.2);
+        rates.set_rate("USD", 2.0);
+        rates.set_rate("GBP", 0.0);
+
+        assert_eq!(rates.usd_per_unit("EUR"), Some(1.2));
+        assert_eq!(rates.usd_per_unit("USD"), Some(1.0));
+        assert_eq!(rates.usd_per_unit("GBP"), Some(1.27));
+    }
+
+    #[test]
+    fn job_salary_uses_the_posting_currency() {
+        let mut job = Job::newly_discovered(
+            "Data Analyst",
+            "Example GmbH",
+            "https://example.com/jobs/2",
+            Some("Berlin".to_string()),
+            "arbeitnow",
+            Utc::now(),
+        );
+        job.salary_min = Some(60_000);
+        job.salary_max = Some(70_000);
+        job.currency = Some("EUR".to_string());
+
+        let (min, max) = ExchangeRates::bundled().job_salary_usd(&job);
+
+        assert_eq!(min, Some(64_800));
+        assert_eq!(max, Some(75_600));
+    }
+}
@@ -1,3 +1,4 @@
+use super::patterns::LanguagePatterns;
 use super::*;
 
 impl GhostDetector {
@@ -32,17 +33,26 @@ impl GhostDetector {
     }
 
     /// Count generic/buzzword phrases in description
-    pub(super) fn count_generic_phrases(&self, description: &str) -> usize {
-        patterns::generic_phrases()
+    pub(super) fn count_generic_phrases(
+        &self,
+        description: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> usize {
+        text_patterns
+            .generic_phrases
             .iter()
             .filter(|re| re.is_match(description))
             .count()
     }
 
     /// Check for missing important details
+    ///
+    /// Pay counts as mentioned when the description contains a currency
+    /// symbol or one of `pay_keywords`.
     pub(super) fn check_missing_details(
         &self,
         description: &str,
+        pay_keywords: &[String],
         salary_min: Option<i64>,
         salary_max: Option<i64>,
         location: Option<&str>,
@@ -55,9 +65,10 @@ impl GhostDetector {
         if self.config.penalize_missing_salary
             && salary_min.is_none()
             && salary_max.is_none()
-            && !desc_lower.contains("salary")
-            && !description.contains('$')
-            && !desc_lower.contains("compensation")
+            && !description.contains(['$', '€', '£'])
+            && !pay_keywords
+                .iter()
+                .any(|keyword| desc_lower.contains(keyword.as_str()))
         {
             missing.push("salary information");
         }
@@ -81,16 +92,25 @@ impl GhostDetector {
     }
 
     /// Check for unrealistic experience requirements
-    pub(super) fn has_unrealistic_requirements(&self, title: &str, description: &str) -> bool {
+    pub(super) fn has_unrealistic_requirements(
+        &self,
+        title: &str,
+        description: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> bool {
         let combined = format!("{title} {description}");
-        patterns::unrealistic_patterns()
+        text_patterns
+            .unrealistic_requirements
             .iter()
             .any(|re| re.is_match(&combined))
     }
 
     /// Check for vague/generic job titles
-    pub(super) fn has_vague_title(&self, title: &str) -> bool {
-        patterns::vague_titles().iter().any(|re| re.is_match(title))
+    pub(super) fn has_vague_title(&self, title: &str, text_patterns: &LanguagePatterns) -> bool {
+        text_patterns
+            .vague_titles
+            .iter()
+            .any(|re| re.is_match(title))
     }
 
     /// Calculate analysis confidence based on data availability
@@ -126,23 +146,37 @@ impl GhostDetector {
     // ==================== ML-Enhanced Methods (v2.5.5) ====================
 
     /// Count urgency-style wording patterns
-    pub(super) fn count_urgency_patterns(&self, text: &str) -> usize {
-        patterns::urgency_patterns()
+    pub(super) fn count_urgency_patterns(
+        &self,
+        text: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> usize {
+        text_patterns
+            .urgency
             .iter()
             .filter(|re| re.is_match(text))
             .count()
     }
 
     /// Count promotional/overly positive language
-    pub(super) fn count_promotional_patterns(&self, text: &str) -> usize {
-        patterns::promotional_patterns()
+    pub(super) fn count_promotional_patterns(
+        &self,
+        text: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> usize {
+        text_patterns
+            .promotional
             .iter()
             .filter(|re| re.is_match(text))
             .count()
     }
 
     /// Calculate substance-to-fluff ratio (higher = more substance)
-    pub(super) fn calculate_substance_ratio(&self, text: &str) -> f64 {
+    pub(super) fn calculate_substance_ratio(
+        &self,
+        text: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> f64 {
         let text_lower = text.to_lowercase();
 
         // Check if text has any words
@@ -150,12 +184,14 @@ impl GhostDetector {
             return 0.0;
         }
 
-        let substance_count = patterns::substance_keywords()
+        let substance_count = text_patterns
+            .substance_keywords
             .iter()
             .filter(|keyword| text_lower.contains(keyword.as_str()))
             .count();
 
-        let fluff_count = patterns::fluff_keywords()
+        let fluff_count = text_patterns
+            .fluff_keywords
             .iter()
             .filter(|keyword| text_lower.contains(keyword.as_str()))
             .count();
@@ -174,10 +210,14 @@ impl GhostDetector {
     }
 
     /// Calculate similarity to known low-detail posting patterns (TF-IDF style)
-    pub(super) fn calculate_template_similarity(&self, text: &str) -> f64 {
+    pub(super) fn calculate_template_similarity(
+        &self,
+        text: &str,
+        text_patterns: &LanguagePatterns,
+    ) -> f64 {
         let text_lower = text.to_lowercase();
         let mut matches = 0;
-        let templates = patterns::ghost_templates();
+        let templates = &text_patterns.ghost_templates;
         let total = templates.len();
 
         for template in templates {
@@ -235,78 +275,81 @@ impl GhostDetector {
             return base_analysis;
         }
 
-        let combined_text = format!("{} {}", title, description);
-
-        // === ML-Enhanced Signals ===
-
-        // 1. Urgency-style wording
-        let urgency_count = self.count_urgency_patterns(&combined_text);
-        if urgency_count >= 2 {
-            let weight = 0.08 * (urgency_count.min(4) as f64 / 4.0);
-            base_analysis.reasons.push(GhostReason {
-                category: GhostCategory::Generic,
-                description: format!("{urgency_count} urgency-style phrases found"),
-                weight,
-                severity: if urgency_count >= 3 {
-                    Severity::Medium
-                } else {
-                    Severity::Low
-                },
-            });
-        }
+        // Postings in unsupported languages keep only the structural signals.
+        if let Some(text_patterns) = self.patterns.language_patterns(base_analysis.language) {
+            let combined_text = format!("{} {}", title, description);
+
+            // === ML-Enhanced Signals ===
+
+            // 1. Urgency-style wording
+            let urgency_count = self.count_urgency_patterns(&combined_text, text_patterns);
+            if urgency_count >= 2 {
+                let weight = 0.08 * (urgency_count.min(4) as f64 / 4.0);
+                base_analysis.reasons.push(GhostReason {
+                    category: GhostCategory::Generic,
+                    description: format!("{urgency_count} urgency-style phrases found"),
+                    weight,
+                    severity: if urgency_count >= 3 {
+                        Severity::Medium
+                    } else {
+                        Severity::Low
+                    },
+                });
+            }
 
-        // 2. Promotional language (overly positive sentiment)
-        let promotional_count = self.count_promotional_patterns(&combined_text);
-        if promotional_count >= 2 {
-            let weight = 0.1 * (promotional_count.min(4) as f64 / 4.0);
-            base_analysis.reasons.push(GhostReason {
-                category: GhostCategory::Generic,
-                description: format!("promotional wording ({} phrases)", promotional_count),
-                weight,
-                severity: if promotional_count >= 3 {
-                    Severity::Medium
-                } else {
-                    Severity::Low
-                },
-            });
-        }
+            // 2. Promotional language (overly positive sentiment)
+            let promotional_count = self.count_promotional_patterns(&combined_text, text_patterns);
+            if promotional_count >= 2 {
+                let weight = 0.1 * (promotional_count.min(4) as f64 / 4.0);
+                base_analysis.reasons.push(GhostReason {
+                    category: GhostCategory::Generic,
+                    description: format!("promotional wording ({} phrases)", promotional_count),
+                    weight,
+                    severity: if promotional_count >= 3 {
+                        Severity::Medium
+                    } else {
+                        Severity::Low
+                    },
+                });
+            }
 
-        // 3. Low substance-to-fluff ratio
-        let substance_ratio = self.calculate_substance_ratio(description);
-        if substance_ratio < 0.3 && description.len() > 200 {
-            let weight = 0.12 * (1.0 - substance_ratio);
-            base_analysis.reasons.push(GhostReason {
-                category: GhostCategory::MissingDetails,
-                description: format!(
-                    "Limited concrete detail ({:.0}% actionable content)",
-                    substance_ratio * 100.0
-                ),
-                weight,
-                severity: if substance_ratio < 0.15 {
-                    Severity::Medium
-                } else {
-                    Severity::Low
-                },
-            });
-        }
+            // 3. Low substance-to-fluff ratio
+            let substance_ratio = self.calculate_substance_ratio(description, text_patterns);
+            if substance_ratio < 0.3 && description.len() > 200 {
+                let weight = 0.12 * (1.0 - substance_ratio);
+                base_analysis.reasons.push(GhostReason {
+                    category: GhostCategory::MissingDetails,
+                    description: format!(
+                        "Limited concrete detail ({:.0}% actionable content)",
+                        substance_ratio * 100.0
+                    ),
+                    weight,
+                    severity: if substance_ratio < 0.15 {
+                        Severity::Medium
+                    } else {
+                        Severity::Low
+                    },
+                });
+            }
 
-        // 4. High template similarity
-        let template_sim = self.calculate_template_similarity(&combined_text);
-        if template_sim >= 0.3 {
-            let weight = 0.15 * template_sim;
-            base_analysis.reasons.push(GhostReason {
-                category: GhostCategory::Generic,
-                description: format!(
-                    "Similar to repeated low-detail posting patterns ({:.0}% match)",
-                    template_sim * 100.0
-                ),
-                weight,
-                severity: if template_sim >= 0.5 {
-                    Severity::High
-                } else {
-                    Severity::Medium
-                },
-            });
+            // 4. High template similarity
+            let template_sim = self.calculate_template_similarity(&combined_text, text_patterns);
+            if template_sim >= 0.3 {
+                let weight = 0.15 * template_sim;
+                base_analysis.reasons.push(GhostReason {
+                    category: GhostCategory::Generic,
+                    description: format!(
+                        "Similar to repeated low-detail posting patterns ({:.0}% match)",
+                        template_sim * 100.0
+                    ),
+                    weight,
+                    severity: if template_sim >= 0.5 {
+                        Severity::High
+                    } else {
+                        Severity::Medium
+                    },
+                });
+            }
         }
 
         // Recalculate total score with all signals
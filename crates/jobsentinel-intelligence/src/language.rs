@@ -0,0 +1,121 @@
+//! Posting language detection
+//!
+//! Text-pattern signals only work in the language they were written in, so
+//! each posting's language is guessed from its common function words first.
+//! Stopwords also cover a few languages without pattern sets, so those are
+//! recognized instead of being read as English. Postings in an unsupported
+//! language are checked on age, reposts, and structure only.
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::pattern_pack::active_pattern_pack;
+
+/// Fewer words than this are too little to tell and are treated as English.
+const MIN_SAMPLE_WORDS: usize = 8;
+/// Words read when guessing the language
+const MAX_SAMPLE_WORDS: usize = 400;
+/// Share of sampled words that must be function words of the best match
+const MIN_STOPWORD_SHARE: f64 = 0.08;
+/// Share of letters outside the Latin script above which a posting is
+/// unsupported without counting words
+const MAX_NON_LATIN_SHARE: f64 = 0.5;
+
+/// Language a job posting is written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostingLanguage {
+    #[default]
+    English,
+    Spanish,
+    German,
+    French,
+    /// A language without a pattern set
+    Unsupported,
+}
+
+impl PostingLanguage {
+    /// Languages with a pattern set
+    pub const SUPPORTED: [Self; 4] = [Self::English, Self::Spanish, Self::German, Self::French];
+
+    /// ISO 639-1 code, or `None` for unsupported languages
+    #[must_use]
+    pub const fn code(self) -> Option<&'static str> {
+        match self {
+            Self::English => Some("en"),
+            Self::Spanish => Some("es"),
+            Self::German => Some("de"),
+            Self::French => Some("fr"),
+            Self::Unsupported => None,
+        }
+    }
+
+    fn from_code(code: &str) -> Self {
+        Self::SUPPORTED
+            .into_iter()
+            .find(|language| language.code() == Some(code))
+            .unwrap_or(Self::Unsupported)
+    }
+}
+
+/// Guess the language of posting text, usually the title and description,
+/// with the active pattern pack
+#[must_use]
+pub fn detect_posting_language(text: &str) -> PostingLanguage {
+    active_pattern_pack().detect_language(text)
+}
+
+pub(super) fn detect_with_stopwords(
+    stopwords: &BTreeMap<String, HashSet<String>>,
+    text: &str,
+) -> PostingLanguage {
+    if non_latin_share(text) > MAX_NON_LATIN_SHARE {
+        return PostingLanguage::Unsupported;
+    }
+
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .take(MAX_SAMPLE_WORDS)
+        .map(str::to_lowercase)
+        .collect();
+    if words.len() < MIN_SAMPLE_WORDS {
+        return PostingLanguage::English;
+    }
+
+    // Ties go to English.
+    let best = stopwords
+        .iter()
+        .map(|(code, stopwords)| {
+            let hits = words
+                .iter()
+                .filter(|word| stopwords.contains(word.as_str()))
+                .count();
+            (code.as_str(), hits)
+        })
+        .max_by_key(|&(code, hits)| (hits, code == "en"));
+    match best {
+        Some((code, hits)) if hits as f64 / words.len() as f64 >= MIN_STOPWORD_SHARE => {
+            PostingLanguage::from_code(code)
+        }
+        // Too few function words to tell, such as a list of buzzwords
+        _ => PostingLanguage::English,
+    }
+}
+
+/// Share of letters outside the Latin script
+fn non_latin_share(text: &str) -> f64 {
+    let (letters, non_latin) = text.chars().filter(|c| c.is_alphabetic()).fold(
+        (0_usize, 0_usize),
+        |(letters, non_latin), c| {
+            let latin = matches!(c, '\0'..='\u{24F}' | '\u{1E00}'..='\u{1EFF}');
+            (letters + 1, non_latin + usize::from(!latin))
+        },
+    );
+    if letters == 0 {
+        0.0
+    } else {
+        non_latin as f64 / letters as f64
+    }
+}
@@ -18,6 +18,13 @@
 //! - **Pattern matching**: TF-IDF style matching against low-detail patterns
 //! - **Sigmoid scoring**: Non-linear weight combination for better discrimination
 //!
+//! ## Languages
+//! Text patterns exist for English, Spanish, German, and French postings,
+//! picked by [`detect_posting_language`]. Postings in other languages are
+//! checked on age, reposts, and structure only, with lower confidence.
+//! Patterns and stopwords come from a versioned [`GhostPatternPack`], which
+//! can be replaced at runtime with [`install_pattern_pack`].
+//!
 //! # Example
 //!
 //! ```ignore
@@ -30,12 +37,28 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
+mod language;
+mod pattern_pack;
 mod patterns;
 mod scoring;
 
+pub use language::{detect_posting_language, PostingLanguage};
+pub use pattern_pack::{
+    active_pattern_pack, ghost_detector_version, install_pattern_pack, GhostPatternPack,
+    PatternPackError,
+};
 pub use scoring::{JobScore, ScoreBreakdown};
 
+/// Version of the ghost detection heuristics.
+///
+/// Bump whenever signals, weights, or scoring change so saved scores from
+/// older versions are re-analyzed instead of being compared as equals.
+/// Saved analyses record [`ghost_detector_version`], which also includes the
+/// pattern pack version.
+pub const GHOST_DETECTOR_VERSION: i64 = 2;
+
 /// Ghost detection result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GhostAnalysis {
@@ -45,6 +68,9 @@ pub struct GhostAnalysis {
     pub reasons: Vec<GhostReason>,
     /// Confidence level of the analysis (0.0 - 1.0)
     pub confidence: f64,
+    /// Detected language of the posting text
+    #[serde(default)]
+    pub language: PostingLanguage,
 }
 
 /// Individual ghost signal detection
@@ -87,6 +113,18 @@ pub enum Severity {
     High,
 }
 
+/// Which review bucket a ghost score falls into under a [`GhostConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GhostBucket {
+    /// Below the warning threshold
+    Clear,
+    /// At or above the warning threshold, below the hide threshold
+    Warning,
+    /// At or above the hide threshold
+    Hidden,
+}
+
 /// Ghost detection engine configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GhostConfig {
@@ -117,16 +155,44 @@ impl Default for GhostConfig {
     }
 }
 
+impl GhostConfig {
+    /// Bucket for `score` under this configuration's thresholds.
+    #[must_use]
+    pub fn bucket(&self, score: f64) -> GhostBucket {
+        if score >= self.hide_threshold {
+            GhostBucket::Hidden
+        } else if score >= self.warning_threshold {
+            GhostBucket::Warning
+        } else {
+            GhostBucket::Clear
+        }
+    }
+}
+
 /// Ghost job detection engine
 pub struct GhostDetector {
     config: GhostConfig,
+    patterns: Arc<GhostPatternPack>,
 }
 
 impl GhostDetector {
-    /// Create a new ghost detector with the given configuration
+    /// Create a new ghost detector with the given configuration and the
+    /// active pattern pack
     #[must_use]
     pub fn new(config: GhostConfig) -> Self {
-        Self { config }
+        Self::with_pattern_pack(config, active_pattern_pack())
+    }
+
+    /// Create a new ghost detector that uses `patterns`
+    #[must_use]
+    pub fn with_pattern_pack(config: GhostConfig, patterns: Arc<GhostPatternPack>) -> Self {
+        Self { config, patterns }
+    }
+
+    /// Review bucket for `score` under this detector's thresholds.
+    #[must_use]
+    pub fn bucket(&self, score: f64) -> GhostBucket {
+        self.config.bucket(score)
     }
 
     /// Analyze a job for ghost indicators
@@ -159,6 +225,10 @@ impl GhostDetector {
 
         let description = description.unwrap_or("");
         let title_lower = title.to_lowercase();
+        let language = self
+            .patterns
+            .detect_language(&format!("{title} {description}"));
+        let text_patterns = self.patterns.language_patterns(language);
 
         // === Age-based signals ===
 
@@ -209,7 +279,8 @@ impl GhostDetector {
         // === Content-based signals ===
 
         // 3. Generic/template descriptions
-        let generic_count = self.count_generic_phrases(description);
+        let generic_count =
+            text_patterns.map_or(0, |set| self.count_generic_phrases(description, set));
         if generic_count >= 3 {
             let weight = 0.1 * (f64::from(generic_count.min(6) as u32) / 6.0);
             reasons.push(GhostReason {
@@ -226,8 +297,15 @@ impl GhostDetector {
         }
 
         // 4. Missing key details
-        let missing_details =
-            self.check_missing_details(description, salary_min, salary_max, location, remote);
+        let pay_keywords = text_patterns.map_or(&[][..], |set| set.pay_keywords.as_slice());
+        let missing_details = self.check_missing_details(
+            description,
+            pay_keywords,
+            salary_min,
+            salary_max,
+            location,
+            remote,
+        );
         if !missing_details.is_empty() {
             let weight = 0.05 * missing_details.len() as f64;
             let weight = weight.min(0.15);
@@ -245,7 +323,9 @@ impl GhostDetector {
         }
 
         // 5. Unrealistic requirements
-        if self.has_unrealistic_requirements(&title_lower, description) {
+        if text_patterns
+            .is_some_and(|set| self.has_unrealistic_requirements(&title_lower, description, set))
+        {
             reasons.push(GhostReason {
                 category: GhostCategory::Unrealistic,
                 description: "Unusual experience requirement".to_string(),
@@ -256,7 +336,7 @@ impl GhostDetector {
         }
 
         // 6. Broad or unclear title
-        if self.has_vague_title(title) {
+        if text_patterns.is_some_and(|set| self.has_vague_title(title, set)) {
             reasons.push(GhostReason {
                 category: GhostCategory::Generic,
                 description: "Broad or unclear job title".to_string(),
@@ -294,14 +374,19 @@ impl GhostDetector {
         // Calculate final score (capped at 1.0)
         let score = total_weight.min(1.0);
 
-        // Calculate confidence based on data availability
-        let confidence =
+        // Calculate confidence based on data availability. Without text
+        // patterns, only part of the posting could be checked.
+        let mut confidence =
             self.calculate_confidence(description.len(), salary_min, salary_max, location);
+        if text_patterns.is_none() {
+            confidence -= 0.2;
+        }
 
         GhostAnalysis {
             score,
             reasons,
             confidence,
+            language,
         }
     }
 }
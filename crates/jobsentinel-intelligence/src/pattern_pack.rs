@@ -0,0 +1,146 @@
+//! Versioned ghost pattern packs
+//!
+//! The text patterns behind ghost detection ship as a pattern pack, so they
+//! can be updated by importing a newer pack instead of waiting for a release.
+//! The pack built into the app is the fallback; an imported pack replaces it
+//! for every detector created afterwards.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, LazyLock, RwLock};
+
+use super::language::{self, PostingLanguage};
+use super::patterns::{self, LanguagePatterns, PatternPackSource};
+use super::GHOST_DETECTOR_VERSION;
+
+/// Newest pattern pack schema this build can read
+const MAX_SCHEMA_VERSION: u32 = 1;
+/// Pack versions fit below this so they can share one saved version number
+/// with the detector version.
+const MAX_PACK_VERSION: i64 = 1_000_000;
+
+static BUNDLED_PACK: LazyLock<Arc<GhostPatternPack>> =
+    LazyLock::new(
+        || match GhostPatternPack::from_source(patterns::bundled_pack_source()) {
+            Ok(pack) => Arc::new(pack),
+            Err(error) => panic!("bundled ghost pattern pack must be valid: {error}"),
+        },
+    );
+
+static ACTIVE_PACK: LazyLock<RwLock<Arc<GhostPatternPack>>> =
+    LazyLock::new(|| RwLock::new(GhostPatternPack::bundled()));
+
+/// Errors reading a pattern pack
+#[derive(Debug, thiserror::Error)]
+pub enum PatternPackError {
+    #[error("Pattern pack is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Pattern pack schema version {0} is not supported")]
+    UnsupportedSchema(u32),
+
+    #[error("Pattern pack version {0} is out of range")]
+    InvalidVersion(i64),
+
+    #[error("Pattern pack has no patterns or stopwords for language '{0}'")]
+    MissingLanguage(String),
+
+    #[error("Pattern pack has an invalid {label} pattern '{pattern}': {message}")]
+    InvalidPattern {
+        label: String,
+        pattern: String,
+        message: String,
+    },
+}
+
+/// Compiled text patterns and language stopwords for ghost detection
+pub struct GhostPatternPack {
+    version: i64,
+    patterns: HashMap<PostingLanguage, LanguagePatterns>,
+    stopwords: BTreeMap<String, HashSet<String>>,
+}
+
+impl GhostPatternPack {
+    /// The pack built into the app
+    #[must_use]
+    pub fn bundled() -> Arc<Self> {
+        Arc::clone(&BUNDLED_PACK)
+    }
+
+    /// Read and compile a pack. Every supported language needs a pattern set
+    /// and stopwords.
+    pub fn from_json(json: &str) -> Result<Self, PatternPackError> {
+        Self::from_source(serde_json::from_str(json)?)
+    }
+
+    fn from_source(source: PatternPackSource) -> Result<Self, PatternPackError> {
+        if source.schema_version == 0 || source.schema_version > MAX_SCHEMA_VERSION {
+            return Err(PatternPackError::UnsupportedSchema(source.schema_version));
+        }
+        if !(1..MAX_PACK_VERSION).contains(&source.pack_version) {
+            return Err(PatternPackError::InvalidVersion(source.pack_version));
+        }
+        for code in PostingLanguage::SUPPORTED
+            .into_iter()
+            .filter_map(PostingLanguage::code)
+        {
+            if !source.stopwords.contains_key(code) {
+                return Err(PatternPackError::MissingLanguage(code.to_string()));
+            }
+        }
+
+        let stopwords = source
+            .stopwords
+            .into_iter()
+            .map(|(code, words)| {
+                let words = words.into_iter().map(|word| word.to_lowercase()).collect();
+                (code, words)
+            })
+            .collect();
+
+        Ok(Self {
+            version: source.pack_version,
+            patterns: patterns::compile_pattern_sets(source.pattern_sets)?,
+            stopwords,
+        })
+    }
+
+    /// Version of the pack, counting up from 1
+    #[must_use]
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// Guess the language of posting text with this pack's stopwords
+    #[must_use]
+    pub fn detect_language(&self, text: &str) -> PostingLanguage {
+        language::detect_with_stopwords(&self.stopwords, text)
+    }
+
+    pub(crate) fn language_patterns(&self, language: PostingLanguage) -> Option<&LanguagePatterns> {
+        self.patterns.get(&language)
+    }
+}
+
+/// The pack new detectors use
+#[must_use]
+pub fn active_pattern_pack() -> Arc<GhostPatternPack> {
+    match ACTIVE_PACK.read() {
+        Ok(pack) => Arc::clone(&pack),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+    }
+}
+
+/// Make `pack` the one new detectors use
+pub fn install_pattern_pack(pack: Arc<GhostPatternPack>) {
+    match ACTIVE_PACK.write() {
+        Ok(mut active) => *active = pack,
+        Err(poisoned) => *poisoned.into_inner() = pack,
+    }
+}
+
+/// Version saved with ghost analyses: the detector version combined with the
+/// active pack version, so a newer pack also re-analyzes saved scores.
+#[must_use]
+pub fn ghost_detector_version() -> i64 {
+    GHOST_DETECTOR_VERSION * MAX_PACK_VERSION + active_pattern_pack().version()
+}
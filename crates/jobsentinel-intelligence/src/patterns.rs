@@ -1,91 +1,104 @@
 use regex::Regex;
 use serde::Deserialize;
-use std::sync::LazyLock;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::language::PostingLanguage;
+use super::pattern_pack::PatternPackError;
 
 const JOB_POSTING_RISK_TAXONOMY_JSON: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/../../resources/taxonomies/job-posting-risk.json"
 ));
 
-static JOB_POSTING_RISK_TAXONOMY: LazyLock<JobPostingRiskTaxonomy> =
-    LazyLock::new(load_job_posting_risk_taxonomy);
-
-static GENERIC_PHRASES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
-    compile_case_insensitive_patterns(&JOB_POSTING_RISK_TAXONOMY.generic_phrases, "generic phrase")
-});
-
-static VAGUE_TITLES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
-    compile_case_insensitive_patterns(
-        &JOB_POSTING_RISK_TAXONOMY.vague_title_patterns,
-        "vague title",
-    )
-});
-
-static UNREALISTIC_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
-    compile_case_insensitive_patterns(
-        &JOB_POSTING_RISK_TAXONOMY.unrealistic_requirement_patterns,
-        "unrealistic requirement",
-    )
-});
-
-static URGENCY_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
-    compile_case_insensitive_patterns(&JOB_POSTING_RISK_TAXONOMY.urgency_patterns, "urgency")
-});
-
-static PROMOTIONAL_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
-    compile_case_insensitive_patterns(
-        &JOB_POSTING_RISK_TAXONOMY.promotional_patterns,
-        "promotional",
-    )
-});
+const JOB_POSTING_RISK_LANGUAGES_JSON: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../resources/taxonomies/job-posting-risk-languages.json"
+));
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct JobPostingRiskTaxonomy {
     schema_version: u32,
+    #[serde(flatten)]
+    patterns: PatternSetSource,
+}
+
+/// A pattern pack as written in JSON. The bundled pack takes its English
+/// set from `job-posting-risk.json`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PatternPackSource {
+    pub(super) schema_version: u32,
+    pub(super) pack_version: i64,
+    /// Keyed by ISO 639-1 code. Some languages have stopwords but no
+    /// pattern set, so they are recognized rather than read as English.
+    pub(super) stopwords: BTreeMap<String, HashSet<String>>,
+    pub(super) pattern_sets: HashMap<String, PatternSetSource>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PatternSetSource {
     generic_phrases: Vec<String>,
     vague_title_patterns: Vec<String>,
     unrealistic_requirement_patterns: Vec<String>,
     urgency_patterns: Vec<String>,
     promotional_patterns: Vec<String>,
+    pay_keywords: Vec<String>,
     substance_keywords: Vec<String>,
     fluff_keywords: Vec<String>,
     ghost_templates: Vec<String>,
 }
 
-pub(super) fn generic_phrases() -> &'static [Regex] {
-    &GENERIC_PHRASES
-}
-
-pub(super) fn vague_titles() -> &'static [Regex] {
-    &VAGUE_TITLES
-}
-
-pub(super) fn unrealistic_patterns() -> &'static [Regex] {
-    &UNREALISTIC_PATTERNS
-}
-
-pub(super) fn urgency_patterns() -> &'static [Regex] {
-    &URGENCY_PATTERNS
-}
-
-pub(super) fn promotional_patterns() -> &'static [Regex] {
-    &PROMOTIONAL_PATTERNS
-}
-
-pub(super) fn substance_keywords() -> &'static [String] {
-    &JOB_POSTING_RISK_TAXONOMY.substance_keywords
-}
-
-pub(super) fn fluff_keywords() -> &'static [String] {
-    &JOB_POSTING_RISK_TAXONOMY.fluff_keywords
+/// Text patterns for postings written in one language
+pub(super) struct LanguagePatterns {
+    pub(super) generic_phrases: Vec<Regex>,
+    pub(super) vague_titles: Vec<Regex>,
+    pub(super) unrealistic_requirements: Vec<Regex>,
+    pub(super) urgency: Vec<Regex>,
+    pub(super) promotional: Vec<Regex>,
+    /// Lowercase words that show a description mentions pay
+    pub(super) pay_keywords: Vec<String>,
+    pub(super) substance_keywords: Vec<String>,
+    pub(super) fluff_keywords: Vec<String>,
+    pub(super) ghost_templates: Vec<String>,
 }
 
-pub(super) fn ghost_templates() -> &'static [String] {
-    &JOB_POSTING_RISK_TAXONOMY.ghost_templates
+impl LanguagePatterns {
+    fn compile(source: PatternSetSource, code: &str) -> Result<Self, PatternPackError> {
+        Ok(Self {
+            generic_phrases: compile_case_insensitive_patterns(
+                &source.generic_phrases,
+                code,
+                "generic phrase",
+            )?,
+            vague_titles: compile_case_insensitive_patterns(
+                &source.vague_title_patterns,
+                code,
+                "vague title",
+            )?,
+            unrealistic_requirements: compile_case_insensitive_patterns(
+                &source.unrealistic_requirement_patterns,
+                code,
+                "unrealistic requirement",
+            )?,
+            urgency: compile_case_insensitive_patterns(&source.urgency_patterns, code, "urgency")?,
+            promotional: compile_case_insensitive_patterns(
+                &source.promotional_patterns,
+                code,
+                "promotional",
+            )?,
+            pay_keywords: lowercase(source.pay_keywords),
+            substance_keywords: lowercase(source.substance_keywords),
+            fluff_keywords: lowercase(source.fluff_keywords),
+            ghost_templates: lowercase(source.ghost_templates),
+        })
+    }
 }
 
-fn load_job_posting_risk_taxonomy() -> JobPostingRiskTaxonomy {
+/// The pack built into the app: the English set from `job-posting-risk.json`
+/// and the rest from `job-posting-risk-languages.json`
+pub(super) fn bundled_pack_source() -> PatternPackSource {
     let taxonomy: JobPostingRiskTaxonomy =
         match serde_json::from_str(JOB_POSTING_RISK_TAXONOMY_JSON) {
             Ok(taxonomy) => taxonomy,
@@ -97,18 +110,58 @@ fn load_job_posting_risk_taxonomy() -> JobPostingRiskTaxonomy {
         "unsupported job posting risk taxonomy schema version"
     );
 
-    taxonomy
+    let mut source: PatternPackSource = match serde_json::from_str(JOB_POSTING_RISK_LANGUAGES_JSON)
+    {
+        Ok(source) => source,
+        Err(error) => panic!("job posting risk languages must be valid JSON: {error}"),
+    };
+    source
+        .pattern_sets
+        .insert("en".to_string(), taxonomy.patterns);
+
+    source
+}
+
+/// Compile the pattern set of every supported language
+pub(super) fn compile_pattern_sets(
+    mut pattern_sets: HashMap<String, PatternSetSource>,
+) -> Result<HashMap<PostingLanguage, LanguagePatterns>, PatternPackError> {
+    let mut patterns = HashMap::new();
+    for language in PostingLanguage::SUPPORTED {
+        let Some(code) = language.code() else {
+            continue;
+        };
+        let source = pattern_sets
+            .remove(code)
+            .ok_or_else(|| PatternPackError::MissingLanguage(code.to_string()))?;
+        patterns.insert(language, LanguagePatterns::compile(source, code)?);
+    }
+
+    Ok(patterns)
 }
 
-fn compile_case_insensitive_patterns(patterns: &[String], label: &str) -> Vec<Regex> {
+fn compile_case_insensitive_patterns(
+    patterns: &[String],
+    code: &str,
+    label: &str,
+) -> Result<Vec<Regex>, PatternPackError> {
     patterns
         .iter()
         .map(|pattern| {
-            let case_insensitive_pattern = format!("(?i){pattern}");
-            match Regex::new(&case_insensitive_pattern) {
-                Ok(regex) => regex,
-                Err(error) => panic!("invalid {label} regex pattern {pattern:?}: {error}"),
-            }
+            Regex::new(&format!("(?i){pattern}")).map_err(|error| {
+                PatternPackError::InvalidPattern {
+                    label: format!("{code} {label}"),
+                    pattern: pattern.clone(),
+                    message: error.to_string(),
+                }
+            })
         })
         .collect()
 }
+
+fn lowercase(keywords: Vec<String>) -> Vec<String> {
+    keywords
+        .into_iter()
+        .map(|keyword| keyword.to_lowercase())
+        .collect()
+}
@@ -420,5 +420,21 @@ fn test_confidence_increases_with_data() {
     );
 }
 
+#[test]
+fn test_buckets_follow_configured_thresholds() {
+    let detector = GhostDetector::new(GhostConfig::default());
+
+    assert_eq!(detector.bucket(0.1), GhostBucket::Clear);
+    assert_eq!(detector.bucket(0.3), GhostBucket::Warning);
+    assert_eq!(detector.bucket(0.69), GhostBucket::Warning);
+    assert_eq!(detector.bucket(0.7), GhostBucket::Hidden);
+}
+
 #[path = "tests/ml_signal_tests.rs"]
 mod ml_signal_tests;
+
+#[path = "tests/language_tests.rs"]
+mod language_tests;
+
+#[path = "tests/pattern_pack_tests.rs"]
+mod pattern_pack_tests;
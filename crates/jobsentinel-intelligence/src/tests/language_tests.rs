@@ -0,0 +1,101 @@
+use super::*;
+
+const SPANISH_URGENT: &str = "Buscamos una persona para el puesto de coordinador de atención al \
+                              cliente con experiencia en gestión de equipos. Incorporación \
+                              inmediata, urgente: no te lo pierdas y postúlate hoy.";
+
+const ITALIAN: &str = "Cerchiamo un responsabile del servizio clienti con esperienza nella \
+                       gestione di un gruppo di lavoro. Il candidato ideale è una persona \
+                       dinamica e motivata che vuole crescere con noi.";
+
+#[test]
+fn test_detects_posting_languages() {
+    for (text, expected) in [
+        (
+            "We are looking for a case manager to coordinate client schedules and \
+             document care plans with our partners.",
+            PostingLanguage::English,
+        ),
+        (SPANISH_URGENT, PostingLanguage::Spanish),
+        (
+            "Wir suchen ab sofort eine engagierte Pflegefachkraft für unser Team. Sie \
+             koordinieren die Versorgung der Patienten und dokumentieren die Pflegeplanung.",
+            PostingLanguage::German,
+        ),
+        (
+            "Nous recherchons un coordinateur de soins pour rejoindre notre équipe. Vous \
+             serez responsable du suivi des patients et des rendez-vous avec les partenaires.",
+            PostingLanguage::French,
+        ),
+        (ITALIAN, PostingLanguage::Unsupported),
+        (
+            "カスタマーサポート担当者を募集しています。未経験者歓迎、研修制度あり。",
+            PostingLanguage::Unsupported,
+        ),
+        (
+            "Fast-paced environment. Work hard, play hard. Rockstar ninja wanted, \
+             synergy guaranteed.",
+            PostingLanguage::English,
+        ),
+        ("Coordinador de atención", PostingLanguage::English),
+    ] {
+        assert_eq!(detect_posting_language(text), expected, "{text}");
+    }
+}
+
+#[test]
+fn test_spanish_postings_use_spanish_patterns() {
+    let detector = GhostDetector::new(GhostConfig::default());
+
+    let analysis = detector.analyze_enhanced(
+        "Coordinador de atención al cliente",
+        Some(SPANISH_URGENT),
+        Some(30000),
+        Some(36000),
+        Some("Madrid"),
+        None,
+        Utc::now() - chrono::Duration::days(5),
+        0,
+        10,
+    );
+
+    assert_eq!(analysis.language, PostingLanguage::Spanish);
+    assert!(
+        analysis
+            .reasons
+            .iter()
+            .any(|reason| reason.description.contains("urgency")),
+        "Spanish urgency wording should be detected: {:?}",
+        analysis.reasons
+    );
+}
+
+#[test]
+fn test_unsupported_languages_fall_back_to_structural_signals() {
+    let detector = GhostDetector::new(GhostConfig::default());
+    // English buzzwords inside another language must not be matched.
+    let description = format!("{ITALIAN} Rockstar ninja, fast-paced environment.");
+
+    let analysis = detector.analyze_enhanced(
+        "Responsabile servizio clienti",
+        Some(&description),
+        None,
+        None,
+        None,
+        None,
+        Utc::now() - chrono::Duration::days(100),
+        0,
+        10,
+    );
+
+    assert_eq!(analysis.language, PostingLanguage::Unsupported);
+    let categories: Vec<GhostCategory> = analysis
+        .reasons
+        .iter()
+        .map(|reason| reason.category)
+        .collect();
+    assert!(categories.contains(&GhostCategory::Stale));
+    assert!(categories.contains(&GhostCategory::MissingDetails));
+    assert!(!categories.contains(&GhostCategory::Generic));
+    assert!(analysis.confidence < 0.7);
+}
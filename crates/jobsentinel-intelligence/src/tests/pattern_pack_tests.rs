@@ -0,0 +1,110 @@
+use super::*;
+use serde_json::{json, Value};
+
+/// The bundled pack as one JSON document, with the English set moved in
+fn bundled_pack_json() -> Value {
+    let mut english: Value = serde_json::from_str(include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../resources/taxonomies/job-posting-risk.json"
+    )))
+    .unwrap();
+    let mut pack: Value = serde_json::from_str(include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../resources/taxonomies/job-posting-risk-languages.json"
+    )))
+    .unwrap();
+    english.as_object_mut().unwrap().remove("schemaVersion");
+    pack["patternSets"]["en"] = english;
+    pack
+}
+
+#[test]
+fn test_reads_pattern_pack_json() {
+    let mut json = bundled_pack_json();
+    json["packVersion"] = json!(7);
+
+    let pack = GhostPatternPack::from_json(&json.to_string()).unwrap();
+
+    assert_eq!(pack.version(), 7);
+    assert_eq!(
+        pack.detect_language("Buscamos una persona para el puesto de coordinador con experiencia en la gestión de equipos."),
+        PostingLanguage::Spanish
+    );
+}
+
+#[test]
+fn test_rejects_invalid_pattern_packs() {
+    let mut future_schema = bundled_pack_json();
+    future_schema["schemaVersion"] = json!(2);
+    assert!(matches!(
+        GhostPatternPack::from_json(&future_schema.to_string()),
+        Err(PatternPackError::UnsupportedSchema(2))
+    ));
+
+    let mut zero_version = bundled_pack_json();
+    zero_version["packVersion"] = json!(0);
+    assert!(matches!(
+        GhostPatternPack::from_json(&zero_version.to_string()),
+        Err(PatternPackError::InvalidVersion(0))
+    ));
+
+    let mut no_german = bundled_pack_json();
+    no_german["patternSets"]
+        .as_object_mut()
+        .unwrap()
+        .remove("de");
+    assert!(matches!(
+        GhostPatternPack::from_json(&no_german.to_string()),
+        Err(PatternPackError::MissingLanguage(code)) if code == "de"
+    ));
+
+    let mut bad_regex = bundled_pack_json();
+    bad_regex["patternSets"]["fr"]["urgencyPatterns"] = json!(["(urgent"]);
+    assert!(matches!(
+        GhostPatternPack::from_json(&bad_regex.to_string()),
+        Err(PatternPackError::InvalidPattern { pattern, .. }) if pattern == "(urgent"
+    ));
+
+    assert!(matches!(
+        GhostPatternPack::from_json("{"),
+        Err(PatternPackError::Json(_))
+    ));
+}
+
+#[test]
+fn test_detector_uses_its_pattern_pack() {
+    let mut json = bundled_pack_json();
+    json["packVersion"] = json!(2);
+    json["patternSets"]["en"]["vagueTitlePatterns"] =
+        json!(["^\\s*chief\\s+happiness\\s+officer\\s*$"]);
+    let pack = Arc::new(GhostPatternPack::from_json(&json.to_string()).unwrap());
+    let title = "Chief Happiness Officer";
+    let description = "Lead the support team, own the onboarding process, and report \
+                       weekly on customer retention to the operations director.";
+    let has_vague_title = |detector: &GhostDetector| {
+        detector
+            .analyze(
+                title,
+                Some(description),
+                None,
+                None,
+                None,
+                None,
+                Utc::now(),
+                0,
+                0,
+            )
+            .reasons
+            .iter()
+            .any(|reason| reason.description == "Broad or unclear job title")
+    };
+
+    assert!(has_vague_title(&GhostDetector::with_pattern_pack(
+        GhostConfig::default(),
+        pack
+    )));
+    assert!(!has_vague_title(&GhostDetector::with_pattern_pack(
+        GhostConfig::default(),
+        GhostPatternPack::bundled()
+    )));
+}
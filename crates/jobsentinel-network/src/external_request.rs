@@ -148,11 +148,16 @@ async fn send_resolved_request_with_retry(
     if let Some((host, addrs)) = target.dns_override() {
         client_builder = client_builder.resolve_to_addrs(host, addrs);
     }
+    if let Some(proxy) = crate::proxy::current_source_proxy() {
+        client_builder = client_builder.proxy(proxy.to_reqwest()?);
+    }
     let client = client_builder
         .build()
         .map_err(|_| ExternalFetchError::Client)?;
+    let host = target.url().host_str().unwrap_or_default();
 
     for attempt in 0..=request.max_retries {
+        crate::throttle::acquire(host).await;
         let mut builder = match request.method {
             ExternalHttpMethod::Get => client.get(target.as_str()),
             ExternalHttpMethod::Post => client.post(target.as_str()),
@@ -186,18 +191,22 @@ async fn send_resolved_request_with_retry(
         };
 
         let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        let held = crate::throttle::note_response(host, status.as_u16(), retry_after).await;
         if attempt == request.max_retries
             || !(status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
         {
             return bounded_text_response(response, target.as_str()).await;
         }
 
-        let retry_after = response
-            .headers()
-            .get(RETRY_AFTER)
-            .and_then(|value| value.to_str().ok())
-            .and_then(|value| value.parse::<u64>().ok());
-        tokio::time::sleep(backoff_delay(retry_after, attempt)).await;
+        // A scoped hold is enforced by the next rate-limit acquire.
+        if held.is_none() {
+            tokio::time::sleep(backoff_delay(retry_after, attempt)).await;
+        }
     }
 
     Err(ExternalFetchError::Request)
@@ -298,4 +307,53 @@ mod tests {
         assert_eq!(response.status, 500);
         server.verify().await;
     }
+
+    #[tokio::test]
+    async fn request_facade_routes_scoped_source_checks_through_proxy() {
+        let proxy_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("via proxy"))
+            .expect(1)
+            .mount(&proxy_server)
+            .await;
+        let proxy = crate::OutboundProxy::parse(&proxy_server.uri()).unwrap();
+
+        let response = crate::with_source_proxy(
+            Some(proxy),
+            send_test_http_text_with_retry(ExternalHttpRequest::get("http://jobs.invalid/jobs")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "via proxy");
+        proxy_server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn request_facade_records_remote_throttling_in_rate_limit_scope() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/blocked"))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let limits = std::sync::Arc::new(crate::DomainRateLimits::new(600, 600));
+
+        let (response, events) = crate::with_rate_limits(
+            limits,
+            send_test_http_text_with_retry(ExternalHttpRequest::get(format!(
+                "{}/blocked",
+                server.uri()
+            ))),
+        )
+        .await;
+
+        assert_eq!(response.unwrap().status, 403);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].reason, crate::ThrottleReason::Forbidden);
+        server.verify().await;
+    }
 }
@@ -2,6 +2,8 @@
 
 mod body;
 mod external_request;
+mod proxy;
+mod throttle;
 
 pub use body::{
     read_json_with_limit, read_text_with_limit, HttpBodyReadError, DEFAULT_MAX_HTTP_BODY_BYTES,
@@ -13,6 +15,8 @@ pub use external_request::{
     send_external_http_text_with_retry, send_external_https_text_with_retry, ExternalHttpMethod,
     ExternalHttpRequest,
 };
+pub use proxy::{with_source_proxy, OutboundProxy, OutboundProxyScheme};
+pub use throttle::{with_rate_limits, DomainRateLimits, ThrottleEvent, ThrottleReason};
 
 use std::net::{IpAddr, SocketAddr};
 use std::time::Duration;
@@ -0,0 +1,336 @@
+//! Token-bucket rate limits and adaptive backoff for source adapter requests.
+//!
+//! Bucket state is process-wide so every source check shares one global
+//! budget plus one budget per domain. Limits and event collection are scoped:
+//! the scheduler wraps a source check with [`with_rate_limits`], and requests
+//! made outside that scope (AI providers, notifications) are not throttled.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+/// Bucket key for the budget shared by every domain.
+const GLOBAL_BUCKET: &str = "*";
+/// Local waits shorter than this are normal pacing and are not reported.
+const REPORTABLE_WAIT: Duration = Duration::from_secs(1);
+const BASE_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_BACKOFF: Duration = Duration::from_mins(5);
+/// Bound on events kept for one scoped source check.
+const MAX_EVENTS_PER_SCOPE: usize = 50;
+
+static BUCKETS: LazyLock<Mutex<HashMap<String, DomainBucket>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+tokio::task_local! {
+    static THROTTLE_SCOPE: ThrottleScope;
+}
+
+/// Requests-per-minute budgets for source checks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainRateLimits {
+    global_per_minute: u32,
+    domain_per_minute: u32,
+    overrides: HashMap<String, u32>,
+}
+
+impl DomainRateLimits {
+    /// Create limits with a shared budget and a default per-domain budget.
+    #[must_use]
+    pub fn new(global_per_minute: u32, domain_per_minute: u32) -> Self {
+        Self {
+            global_per_minute: global_per_minute.max(1),
+            domain_per_minute: domain_per_minute.max(1),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Override the budget for `domain` and its subdomains.
+    #[must_use]
+    pub fn with_domain(mut self, domain: &str, per_minute: u32) -> Self {
+        self.overrides.insert(
+            domain.trim().trim_end_matches('.').to_ascii_lowercase(),
+            per_minute.max(1),
+        );
+        self
+    }
+
+    /// Budget for `host`, using the most specific matching override.
+    fn per_minute_for(&self, host: &str) -> u32 {
+        let mut candidate = host;
+        loop {
+            if let Some(limit) = self.overrides.get(candidate) {
+                return *limit;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return self.domain_per_minute,
+            }
+        }
+    }
+}
+
+/// Why a source request was slowed down.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThrottleReason {
+    /// The local token bucket was empty.
+    LocalLimit,
+    /// The site answered 429 Too Many Requests.
+    RateLimited,
+    /// The site answered 403 Forbidden, which often means soft blocking.
+    Forbidden,
+}
+
+/// One throttling delay applied during a source check.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ThrottleEvent {
+    pub domain: String,
+    pub reason: ThrottleReason,
+    pub delay_ms: u64,
+}
+
+#[derive(Clone)]
+struct ThrottleScope {
+    limits: Arc<DomainRateLimits>,
+    events: Arc<StdMutex<Vec<ThrottleEvent>>>,
+}
+
+impl ThrottleScope {
+    fn record(&self, domain: &str, reason: ThrottleReason, delay: Duration) {
+        let Ok(mut events) = self.events.lock() else {
+            return;
+        };
+        if events.len() < MAX_EVENTS_PER_SCOPE {
+            events.push(ThrottleEvent {
+                domain: domain.to_string(),
+                reason,
+                delay_ms: u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+            });
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DomainBucket {
+    per_minute: u32,
+    tokens: f64,
+    last_refill: Instant,
+    backoff_until: Option<Instant>,
+    strikes: u32,
+}
+
+impl DomainBucket {
+    fn new(per_minute: u32, now: Instant) -> Self {
+        Self {
+            per_minute,
+            tokens: f64::from(per_minute),
+            last_refill: now,
+            backoff_until: None,
+            strikes: 0,
+        }
+    }
+
+    /// Apply a changed limit and refill tokens for elapsed time.
+    fn refill(&mut self, per_minute: u32, now: Instant) {
+        if self.per_minute != per_minute {
+            self.per_minute = per_minute;
+            self.tokens = self.tokens.min(f64::from(per_minute));
+        }
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * f64::from(per_minute) / 60.0).min(f64::from(per_minute));
+        self.last_refill = now;
+    }
+
+    /// Time until this bucket can grant a request.
+    fn wait_time(&self, now: Instant) -> Duration {
+        let backoff = self
+            .backoff_until
+            .map_or(Duration::ZERO, |until| until.saturating_duration_since(now));
+        let refill = if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) * 60.0 / f64::from(self.per_minute))
+        };
+        backoff.max(refill)
+    }
+}
+
+/// Run a source check with rate limits applied to every request it makes.
+///
+/// Returns the future's output together with the throttling events recorded
+/// while it ran.
+pub async fn with_rate_limits<F: Future>(
+    limits: Arc<DomainRateLimits>,
+    future: F,
+) -> (F::Output, Vec<ThrottleEvent>) {
+    let scope = ThrottleScope {
+        limits,
+        events: Arc::default(),
+    };
+    let events = Arc::clone(&scope.events);
+    let output = THROTTLE_SCOPE.scope(scope, future).await;
+    let events = events
+        .lock()
+        .map(|mut events| std::mem::take(&mut *events))
+        .unwrap_or_default();
+    (output, events)
+}
+
+fn current_scope() -> Option<ThrottleScope> {
+    THROTTLE_SCOPE.try_with(Clone::clone).ok()
+}
+
+/// Wait until both the global and the domain bucket can grant a request.
+pub(crate) async fn acquire(host: &str) {
+    let Some(scope) = current_scope() else {
+        return;
+    };
+    let domain_limit = scope.limits.per_minute_for(host);
+    let global_limit = scope.limits.global_per_minute;
+    let mut waited = Duration::ZERO;
+
+    loop {
+        let wait = {
+            let mut buckets = BUCKETS.lock().await;
+            let now = Instant::now();
+            let domain = buckets
+                .entry(host.to_string())
+                .or_insert_with(|| DomainBucket::new(domain_limit, now));
+            domain.refill(domain_limit, now);
+            let domain_wait = domain.wait_time(now);
+
+            let global = buckets
+                .entry(GLOBAL_BUCKET.to_string())
+                .or_insert_with(|| DomainBucket::new(global_limit, now));
+            global.refill(global_limit, now);
+            let wait = domain_wait.max(global.wait_time(now));
+
+            if wait.is_zero() {
+                global.tokens -= 1.0;
+                if let Some(domain) = buckets.get_mut(host) {
+                    domain.tokens -= 1.0;
+                }
+            }
+            wait
+        };
+
+        if wait.is_zero() {
+            break;
+        }
+        waited += wait;
+        tokio::time::sleep(wait).await;
+    }
+
+    if waited >= REPORTABLE_WAIT {
+        scope.record(host, ThrottleReason::LocalLimit, waited);
+    }
+}
+
+/// Update backoff state from a response status.
+///
+/// Returns the hold placed on the domain when the request was throttled inside
+/// a rate-limit scope. The next [`acquire`] for the domain waits it out, so
+/// callers must not sleep again.
+pub(crate) async fn note_response(
+    host: &str,
+    status: u16,
+    retry_after_secs: Option<u64>,
+) -> Option<Duration> {
+    let scope = current_scope()?;
+    let reason = match status {
+        429 => ThrottleReason::RateLimited,
+        403 => ThrottleReason::Forbidden,
+        _ => {
+            if (200..300).contains(&status) {
+                if let Some(bucket) = BUCKETS.lock().await.get_mut(host) {
+                    bucket.strikes = 0;
+                }
+            }
+            return None;
+        }
+    };
+
+    let mut buckets = BUCKETS.lock().await;
+    let now = Instant::now();
+    let bucket = buckets
+        .entry(host.to_string())
+        .or_insert_with(|| DomainBucket::new(scope.limits.per_minute_for(host), now));
+    let hold = adaptive_backoff(bucket.strikes, retry_after_secs);
+    bucket.strikes = bucket.strikes.saturating_add(1);
+    bucket.backoff_until = Some(now + hold);
+    drop(buckets);
+
+    scope.record(host, reason, hold);
+    Some(hold)
+}
+
+/// Exponential hold for repeated throttling, honoring `Retry-After` when sent.
+fn adaptive_backoff(strikes: u32, retry_after_secs: Option<u64>) -> Duration {
+    retry_after_secs
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| BASE_BACKOFF.saturating_mul(1_u32 << strikes.min(6)))
+        .min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_overrides_apply_to_subdomains() {
+        let limits = DomainRateLimits::new(120, 30).with_domain("Greenhouse.io", 10);
+
+        assert_eq!(limits.per_minute_for("boards-api.greenhouse.io"), 10);
+        assert_eq!(limits.per_minute_for("greenhouse.io"), 10);
+        assert_eq!(limits.per_minute_for("api.lever.co"), 30);
+    }
+
+    #[test]
+    fn adaptive_backoff_grows_and_is_capped() {
+        assert_eq!(adaptive_backoff(0, None), BASE_BACKOFF);
+        assert_eq!(adaptive_backoff(2, None), BASE_BACKOFF * 4);
+        assert_eq!(adaptive_backoff(10, None), MAX_BACKOFF);
+        assert_eq!(adaptive_backoff(0, Some(12)), Duration::from_secs(12));
+        assert_eq!(adaptive_backoff(0, Some(86_400)), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn empty_bucket_reports_refill_wait() {
+        let now = Instant::now();
+        let mut bucket = DomainBucket::new(60, now);
+        bucket.tokens = 0.0;
+
+        assert_eq!(bucket.wait_time(now), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn requests_outside_a_scope_are_not_throttled() {
+        assert!(note_response("unscoped.example", 429, None).await.is_none());
+        assert!(!BUCKETS.lock().await.contains_key("unscoped.example"));
+    }
+
+    #[tokio::test]
+    async fn scoped_throttling_records_events() {
+        let limits = Arc::new(DomainRateLimits::new(600, 600));
+        let (hold, events) = with_rate_limits(limits, async {
+            acquire("scoped-429.example").await;
+            note_response("scoped-429.example", 429, Some(0)).await
+        })
+        .await;
+
+        assert_eq!(hold, Some(Duration::ZERO));
+        assert_eq!(
+            events,
+            [ThrottleEvent {
+                domain: "scoped-429.example".to_string(),
+                reason: ThrottleReason::RateLimited,
+                delay_ms: 0,
+            }]
+        );
+    }
+}
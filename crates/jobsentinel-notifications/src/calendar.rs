@@ -0,0 +1,347 @@
+//! Interview calendar invites
+//!
+//! Builds iCalendar (RFC 5545) events for scheduled interviews so they can be
+//! mailed as `.ics` attachments and picked up by phone calendars.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Reminder offset matching the in-app calendar export.
+const REMINDER_MINUTES: i64 = 30;
+
+/// Interview details carried into a calendar invite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterviewCalendarEvent {
+    pub interview_id: i64,
+    pub job_title: String,
+    pub company: String,
+    pub interview_type: String,
+    /// Local `YYYY-MM-DDTHH:MM[:SS]` or RFC 3339 start time
+    pub scheduled_at: String,
+    pub duration_minutes: i32,
+    pub location: Option<String>,
+    pub interviewer_name: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Whether an invite adds the interview to the calendar or removes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarUpdate {
+    Scheduled,
+    Cancelled,
+}
+
+impl CalendarUpdate {
+    pub(crate) fn method(self) -> &'static str {
+        match self {
+            Self::Scheduled => "REQUEST",
+            Self::Cancelled => "CANCEL",
+        }
+    }
+}
+
+impl InterviewCalendarEvent {
+    /// Title shown in the calendar and used for the email subject.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} interview: {} at {}",
+            interview_type_label(&self.interview_type),
+            self.job_title,
+            self.company
+        )
+    }
+}
+
+fn interview_type_label(interview_type: &str) -> String {
+    let words: Vec<String> = interview_type
+        .trim_end_matches("_interview")
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect();
+    if words.is_empty() {
+        "Job".to_string()
+    } else {
+        words.join(" ")
+    }
+}
+
+/// Start time as an iCalendar date-time.
+///
+/// RFC 3339 times become UTC; local times without an offset stay floating so
+/// the phone shows them at the wall-clock time they were entered.
+fn ics_start(scheduled_at: &str) -> Option<(String, String)> {
+    if let Ok(start) = DateTime::parse_from_rfc3339(scheduled_at) {
+        let start = start.with_timezone(&Utc).naive_utc();
+        return Some((
+            format_ics_time(start, true),
+            start.format("%Y%m%dT%H%M%S").to_string(),
+        ));
+    }
+    let start = NaiveDateTime::parse_from_str(scheduled_at, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(scheduled_at, "%Y-%m-%dT%H:%M"))
+        .or_else(|_| NaiveDateTime::parse_from_str(scheduled_at, "%Y-%m-%d %H:%M:%S"))
+        .ok()?;
+    Some((
+        format_ics_time(start, false),
+        start.format("%Y%m%dT%H%M%S").to_string(),
+    ))
+}
+
+fn format_ics_time(time: NaiveDateTime, utc: bool) -> String {
+    let formatted = time.format("%Y%m%dT%H%M%S").to_string();
+    if utc {
+        format!("{formatted}Z")
+    } else {
+        formatted
+    }
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+/// Fold content lines longer than 75 octets, as RFC 5545 requires.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_len = 0;
+    for ch in line.chars() {
+        if line_len + ch.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_len = 1;
+        }
+        folded.push(ch);
+        line_len += ch.len_utf8();
+    }
+    folded
+}
+
+/// Build the `.ics` body for an interview invite or cancellation.
+///
+/// The UID includes the start time, so moving an interview cancels the old
+/// slot and sends a fresh event instead of relying on clients to honour
+/// sequence numbers. Returns `None` when the start time cannot be parsed.
+#[must_use]
+pub fn format_interview_ics(
+    event: &InterviewCalendarEvent,
+    update: CalendarUpdate,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let (dtstart, uid_start) = ics_start(&event.scheduled_at)?;
+    let uid = format!("interview-{}-{uid_start}@jobsentinel", event.interview_id);
+    let (status, sequence) = match update {
+        CalendarUpdate::Scheduled => ("CONFIRMED", 0),
+        CalendarUpdate::Cancelled => ("CANCELLED", 1),
+    };
+
+    let mut lines = calendar_header(update.method());
+    lines.extend(event_lines(
+        event,
+        &uid,
+        &dtstart,
+        now,
+        &[format!("SEQUENCE:{sequence}"), format!("STATUS:{status}")],
+        update == CalendarUpdate::Scheduled,
+    ));
+    lines.push("END:VCALENDAR".to_string());
+    Some(join_lines(lines))
+}
+
+/// Build a calendar feed holding every interview in `events`, for export or
+/// for a calendar app to subscribe to.
+///
+/// Each interview keeps one UID for its whole life, so a refreshed feed moves
+/// a rescheduled interview instead of adding a second event. Interviews whose
+/// start time cannot be parsed are left out.
+#[must_use]
+pub fn format_interview_calendar(events: &[InterviewCalendarEvent], now: DateTime<Utc>) -> String {
+    let mut lines = calendar_header("PUBLISH");
+    lines.push("X-WR-CALNAME:JobSentinel interviews".to_string());
+    for event in events {
+        let Some((dtstart, _)) = ics_start(&event.scheduled_at) else {
+            continue;
+        };
+        let uid = format!("interview-{}@jobsentinel", event.interview_id);
+        lines.extend(event_lines(event, &uid, &dtstart, now, &[], true));
+    }
+    lines.push("END:VCALENDAR".to_string());
+    join_lines(lines)
+}
+
+fn calendar_header(method: &str) -> Vec<String> {
+    vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//JobSentinel//Interview Scheduler//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("METHOD:{method}"),
+    ]
+}
+
+fn event_lines(
+    event: &InterviewCalendarEvent,
+    uid: &str,
+    dtstart: &str,
+    now: DateTime<Utc>,
+    extra: &[String],
+    reminder: bool,
+) -> Vec<String> {
+    let mut description = Vec::new();
+    if let Some(interviewer) = event.interviewer_name.as_deref() {
+        description.push(format!("Interviewer: {interviewer}"));
+    }
+    if let Some(notes) = event.notes.as_deref() {
+        description.push(format!("Notes: {notes}"));
+    }
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{uid}"),
+        format!("DTSTAMP:{}", now.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART:{dtstart}"),
+        format!("DURATION:PT{}M", event.duration_minutes.max(1)),
+    ];
+    lines.extend_from_slice(extra);
+    lines.push(format!("SUMMARY:{}", escape_ics_text(&event.summary())));
+    if !description.is_empty() {
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_ics_text(&description.join("\n"))
+        ));
+    }
+    if let Some(location) = event.location.as_deref() {
+        lines.push(format!("LOCATION:{}", escape_ics_text(location)));
+    }
+    if reminder {
+        lines.extend([
+            "BEGIN:VALARM".to_string(),
+            format!("TRIGGER:-PT{REMINDER_MINUTES}M"),
+            "ACTION:DISPLAY".to_string(),
+            "DESCRIPTION:Interview reminder".to_string(),
+            "END:VALARM".to_string(),
+        ]);
+    }
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+fn join_lines(lines: Vec<String>) -> String {
+    let mut ics = String::new();
+    for line in lines {
+        ics.push_str(&fold_line(&line));
+        ics.push_str("\r\n");
+    }
+    ics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event() -> InterviewCalendarEvent {
+        InterviewCalendarEvent {
+            interview_id: 7,
+            job_title: "Care Coordinator".to_string(),
+            company: "Community Care Network".to_string(),
+            interview_type: "phone_interview".to_string(),
+            scheduled_at: "2026-02-01T10:00".to_string(),
+            duration_minutes: 45,
+            location: Some("Room 4, Main St".to_string()),
+            interviewer_name: Some("Dana Lee".to_string()),
+            notes: None,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 20, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn scheduled_invite_uses_local_start_and_reminder() {
+        let ics = format_interview_ics(&event(), CalendarUpdate::Scheduled, now()).unwrap();
+
+        assert!(ics.contains("METHOD:REQUEST\r\n"));
+        assert!(ics.contains("UID:interview-7-20260201T100000@jobsentinel\r\n"));
+        assert!(ics.contains("DTSTART:20260201T100000\r\n"));
+        assert!(ics.contains("DURATION:PT45M\r\n"));
+        assert!(ics.contains("STATUS:CONFIRMED\r\n"));
+        assert!(
+            ics.contains("SUMMARY:Phone interview: Care Coordinator at Community Care Network\r\n")
+        );
+        assert!(ics.contains("LOCATION:Room 4\\, Main St\r\n"));
+        assert!(ics.contains("DESCRIPTION:Interviewer: Dana Lee\r\n"));
+        assert!(ics.contains("TRIGGER:-PT30M\r\n"));
+    }
+
+    #[test]
+    fn cancellation_matches_the_scheduled_uid() {
+        let ics = format_interview_ics(&event(), CalendarUpdate::Cancelled, now()).unwrap();
+
+        assert!(ics.contains("METHOD:CANCEL\r\n"));
+        assert!(ics.contains("UID:interview-7-20260201T100000@jobsentinel\r\n"));
+        assert!(ics.contains("STATUS:CANCELLED\r\n"));
+        assert!(ics.contains("SEQUENCE:1\r\n"));
+        assert!(!ics.contains("VALARM"));
+    }
+
+    #[test]
+    fn rfc3339_start_is_sent_in_utc() {
+        let mut event = event();
+        event.scheduled_at = "2026-02-01T10:00:00-05:00".to_string();
+
+        let ics = format_interview_ics(&event, CalendarUpdate::Scheduled, now()).unwrap();
+
+        assert!(ics.contains("DTSTART:20260201T150000Z\r\n"));
+    }
+
+    #[test]
+    fn unparseable_start_produces_no_invite() {
+        let mut event = event();
+        event.scheduled_at = "next Tuesday".to_string();
+
+        assert!(format_interview_ics(&event, CalendarUpdate::Scheduled, now()).is_none());
+    }
+
+    #[test]
+    fn calendar_feed_lists_each_interview_with_a_stable_uid() {
+        let mut moved = event();
+        moved.interview_id = 8;
+        moved.scheduled_at = "2026-02-03T09:30:00Z".to_string();
+        let mut unparseable = event();
+        unparseable.interview_id = 9;
+        unparseable.scheduled_at = "next Tuesday".to_string();
+
+        let ics = format_interview_calendar(&[event(), moved, unparseable], now());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.contains("METHOD:PUBLISH\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("UID:interview-7@jobsentinel\r\n"));
+        assert!(ics.contains("UID:interview-8@jobsentinel\r\n"));
+        assert!(ics.contains("DTSTART:20260203T093000Z\r\n"));
+        assert!(!ics.contains("interview-9"));
+        assert!(!ics.contains("STATUS:"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn long_lines_are_folded() {
+        let mut event = event();
+        event.notes = Some("Bring portfolio ".repeat(10));
+
+        let ics = format_interview_ics(&event, CalendarUpdate::Scheduled, now()).unwrap();
+
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+        assert!(ics.contains("\r\n "));
+    }
+}
@@ -19,8 +19,24 @@ pub struct AlertConfig {
     #[serde(default)]
     pub teams: TeamsConfig,
 
+    #[serde(default)]
+    pub webhook: CustomWebhookConfig,
+
+    #[serde(default)]
+    pub ntfy: NtfyConfig,
+
+    #[serde(default)]
+    pub pushover: PushoverConfig,
+
     #[serde(default)]
     pub desktop: DesktopConfig,
+
+    #[serde(default)]
+    pub weekly_report: WeeklyReportConfig,
+
+    /// Add the active resume's match to immediate alerts
+    #[serde(default)]
+    pub include_resume_match: bool,
 }
 
 #[derive(Clone, Serialize, Deserialize, Default)]
@@ -83,6 +99,14 @@ pub struct EmailConfig {
     /// Use STARTTLS (true for port 587, false for port 465)
     #[serde(default = "default_use_starttls")]
     pub use_starttls: bool,
+
+    /// Email `.ics` invites when interviews are scheduled, moved, or deleted
+    #[serde(default)]
+    pub send_interview_calendar: bool,
+
+    /// Daily or weekly digest of new high-scoring jobs
+    #[serde(default)]
+    pub digest: EmailDigestConfig,
 }
 
 impl fmt::Debug for EmailConfig {
@@ -99,10 +123,104 @@ impl fmt::Debug for EmailConfig {
             .field("from_email", &self.from_email)
             .field("to_emails", &self.to_emails)
             .field("use_starttls", &self.use_starttls)
+            .field("send_interview_calendar", &self.send_interview_calendar)
+            .field("digest", &self.digest)
             .finish()
     }
 }
 
+impl EmailConfig {
+    /// Addresses the digest goes to: its own recipients, or the alert
+    /// recipients when none are set
+    #[must_use]
+    pub fn digest_recipients(&self) -> &[String] {
+        if self.digest.recipients.is_empty() {
+            &self.to_emails
+        } else {
+            &self.digest.recipients
+        }
+    }
+}
+
+/// How often the email digest is sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    #[default]
+    Daily,
+    Weekly,
+}
+
+/// Email digest of new high-scoring jobs
+///
+/// Uses the SMTP settings of [`EmailConfig`]; immediate email alerts do not
+/// need to be on. Each job is included in at most one digest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailDigestConfig {
+    pub enabled: bool,
+
+    pub frequency: DigestFrequency,
+
+    /// Local hour (0-23) the digest is sent at (default: 8)
+    pub send_hour: u32,
+
+    /// Day weekly digests are sent, from 1 (Monday) to 7 (Sunday)
+    /// (default: 1)
+    pub send_weekday: u32,
+
+    /// Digest recipients; empty sends to the alert recipients
+    pub recipients: Vec<String>,
+
+    /// Lowest score (0.0 - 1.0) a job needs to be included (default: 0.7)
+    pub min_score: f64,
+
+    /// Attach a PDF market report for the past week to weekly digests
+    /// (default: false)
+    pub attach_market_report: bool,
+}
+
+impl Default for EmailDigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            frequency: DigestFrequency::Daily,
+            send_hour: 8,
+            send_weekday: 1,
+            recipients: Vec::new(),
+            min_score: 0.7,
+            attach_market_report: false,
+        }
+    }
+}
+
+/// Weekly summary report of new jobs, top matches, application progress,
+/// and market alerts
+///
+/// Sent through whichever of email, Slack, and Discord alerts are on, and
+/// saved locally either way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeeklyReportConfig {
+    pub enabled: bool,
+
+    /// Day the report is sent, from 1 (Monday) to 7 (Sunday) (default: 1)
+    pub send_weekday: u32,
+
+    /// Local hour (0-23) the report is sent at (default: 9)
+    pub send_hour: u32,
+}
+
+impl Default for WeeklyReportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            send_weekday: 1,
+            send_hour: 9,
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Default)]
 pub struct DiscordConfig {
     #[serde(default)]
@@ -170,6 +288,123 @@ impl fmt::Debug for TeamsConfig {
     }
 }
 
+/// Custom webhook that receives a JSON payload for each job alert
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct CustomWebhookConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Public HTTPS endpoints that receive each alert
+    #[serde(default)]
+    pub urls: Vec<String>,
+
+    /// JSON payload with `{{field}}` placeholders; a built-in payload is sent
+    /// when unset
+    #[serde(default)]
+    pub payload_template: Option<serde_json::Value>,
+
+    /// HMAC signing secret - stored through `CredentialService`, not serialized.
+    /// Requests are unsigned when empty.
+    #[serde(skip)]
+    pub signing_secret: String,
+}
+
+impl fmt::Debug for CustomWebhookConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomWebhookConfig")
+            .field("enabled", &self.enabled)
+            .field("url_count", &self.urls.len())
+            .field("has_payload_template", &self.payload_template.is_some())
+            .field(
+                "signing_secret",
+                &redacted_secret_for_debug(&self.signing_secret),
+            )
+            .finish()
+    }
+}
+
+/// ntfy push notifications to a topic on ntfy.sh or a self-hosted server
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NtfyConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Public HTTPS ntfy server
+    #[serde(default = "default_ntfy_server_url")]
+    pub server_url: String,
+
+    /// Message priority from 1 (min) to 5 (max)
+    #[serde(default = "default_ntfy_priority")]
+    pub priority: u8,
+
+    /// Topic name - stored through `CredentialService`, not serialized,
+    /// because anyone who knows a public topic can read it.
+    #[serde(skip)]
+    pub topic: String,
+
+    /// Access token for protected topics - stored through
+    /// `CredentialService`, not serialized. Optional.
+    #[serde(skip)]
+    pub access_token: String,
+}
+
+impl Default for NtfyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server_url: default_ntfy_server_url(),
+            priority: default_ntfy_priority(),
+            topic: String::new(),
+            access_token: String::new(),
+        }
+    }
+}
+
+impl fmt::Debug for NtfyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NtfyConfig")
+            .field("enabled", &self.enabled)
+            .field("server_url", &self.server_url)
+            .field("priority", &self.priority)
+            .field("topic", &redacted_secret_for_debug(&self.topic))
+            .field(
+                "access_token",
+                &redacted_secret_for_debug(&self.access_token),
+            )
+            .finish()
+    }
+}
+
+/// Pushover push notifications
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct PushoverConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Message priority from -2 (silent) to 1 (high)
+    #[serde(default)]
+    pub priority: i8,
+
+    /// Pushover user key - stored through `CredentialService`, not serialized.
+    #[serde(skip)]
+    pub user_key: String,
+
+    /// Pushover application token - stored through `CredentialService`, not serialized.
+    #[serde(skip)]
+    pub app_token: String,
+}
+
+impl fmt::Debug for PushoverConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PushoverConfig")
+            .field("enabled", &self.enabled)
+            .field("priority", &self.priority)
+            .field("user_key", &redacted_secret_for_debug(&self.user_key))
+            .field("app_token", &redacted_secret_for_debug(&self.app_token))
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DesktopConfig {
     #[serde(default = "default_desktop_enabled")]
@@ -184,6 +419,14 @@ pub struct DesktopConfig {
     pub play_sound: bool,
 }
 
+fn default_ntfy_server_url() -> String {
+    "https://ntfy.sh".to_string()
+}
+
+const fn default_ntfy_priority() -> u8 {
+    3
+}
+
 const fn default_smtp_port() -> u16 {
     587
 }
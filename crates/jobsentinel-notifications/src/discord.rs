@@ -2,6 +2,7 @@
 //!
 //! Sends rich-formatted job alerts to Discord using webhooks with embeds.
 
+use super::template::render_message_template;
 use super::{
     format_salary_range, notification_job_href, notification_provider_failure_summary,
     Notification, LOCAL_MATCH_DETAILS_MESSAGE, NOTIFICATION_HTTP_TIMEOUT,
@@ -27,7 +28,10 @@ fn build_discord_payload(config: &DiscordConfig, notification: &Notification) ->
 
     let mut embed = json!({
         "title": format!("🎯 {} - {}", job.title, job.company),
-        "description": format!("**{}% Match** • {}", (score.total * 100.0).round(), job.source),
+        "description": match notification.resume_match {
+            Some(_) => format!("**{}** • {}", notification.match_summary(), job.source),
+            None => format!("**{}% Match** • {}", (score.total * 100.0).round(), job.source),
+        },
         "color": color,
         "fields": [
             {
@@ -72,6 +76,30 @@ fn build_discord_payload(config: &DiscordConfig, notification: &Notification) ->
     payload
 }
 
+/// Build a Discord message whose embed text is the user's message template
+fn build_templated_discord_payload(
+    config: &DiscordConfig,
+    template: &str,
+    notification: &Notification,
+) -> serde_json::Value {
+    let mut payload = json!({
+        "embeds": [{
+            "description": render_message_template(template, notification),
+            "color": discord_embed_color(notification.score.total),
+            "footer": {
+                "text": "JobSentinel • Job Search Assistant"
+            },
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }]
+    });
+
+    if let Some(user_id) = &config.user_id_to_mention {
+        payload["content"] = json!(format!("<@{}>", user_id));
+    }
+
+    payload
+}
+
 fn discord_embed_color(score: f64) -> u32 {
     if score >= 0.9 {
         0x10b981
@@ -83,14 +111,21 @@ fn discord_embed_color(score: f64) -> u32 {
 }
 
 /// Send Discord notification via webhook
+///
+/// With a message template, the embed holds the rendered template instead of
+/// the default fields.
 pub async fn send_discord_notification(
     config: &DiscordConfig,
     notification: &Notification,
+    template: Option<&str>,
 ) -> Result<()> {
     // Validate webhook URL before sending
     validate_webhook_url(&config.webhook_url)?;
 
-    let payload = build_discord_payload(config, notification);
+    let payload = match template {
+        Some(template) => build_templated_discord_payload(config, template, notification),
+        None => build_discord_payload(config, notification),
+    };
 
     // Send POST request to Discord webhook with DNS/IP validation and pinned resolution.
     let response = jobsentinel_network::post_external_https_json(
@@ -109,6 +144,84 @@ pub async fn send_discord_notification(
     Ok(())
 }
 
+/// Longest embed description Discord accepts, in characters
+const DISCORD_EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// A weekly report as a Discord embed
+///
+/// Discord embeds do not render headings, so they are shown in bold. Reports
+/// longer than an embed allows are cut off with an ellipsis.
+fn build_discord_report_payload(config: &DiscordConfig, markdown: &str) -> serde_json::Value {
+    let mut description: String = markdown
+        .lines()
+        .map(
+            |line| match line.strip_prefix("## ").or_else(|| line.strip_prefix("# ")) {
+                Some(heading) => format!("**{heading}**\n"),
+                None => format!("{line}\n"),
+            },
+        )
+        .collect();
+    if description.chars().count() > DISCORD_EMBED_DESCRIPTION_LIMIT {
+        description = description
+            .chars()
+            .take(DISCORD_EMBED_DESCRIPTION_LIMIT - 1)
+            .collect();
+        description.push('…');
+    }
+
+    let mut payload = json!({
+        "embeds": [{
+            "description": description,
+            "color": 0x667eea,
+            "footer": {
+                "text": "JobSentinel • Job Search Assistant"
+            },
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }]
+    });
+
+    if let Some(user_id) = &config.user_id_to_mention {
+        payload["content"] = json!(format!("<@{}>", user_id));
+    }
+
+    payload
+}
+
+/// Send a Markdown message, such as a weekly report
+pub async fn send_discord_report(config: &DiscordConfig, markdown: &str) -> Result<()> {
+    validate_webhook_url(&config.webhook_url)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        &config.webhook_url,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &build_discord_report_payload(config, markdown),
+    )
+    .await
+    .map_err(|error| anyhow!("Discord webhook request failed: {error}"))?;
+
+    if !(200..300).contains(&response.status) {
+        let error_summary = notification_provider_failure_summary(&response);
+        return Err(anyhow!("Discord webhook failed: {}", error_summary));
+    }
+
+    Ok(())
+}
+
+/// Validate a Discord webhook by sending a test message
+pub async fn validate_webhook(webhook_url: &str) -> Result<bool> {
+    validate_webhook_url(webhook_url)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        webhook_url,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &json!({"content": "JobSentinel: Webhook validation successful ✅"}),
+    )
+    .await
+    .map_err(|error| anyhow!("Discord webhook validation failed: {error}"))?;
+
+    Ok((200..300).contains(&response.status))
+}
+
 #[cfg(test)]
 #[path = "discord_tests.rs"]
 mod tests;
@@ -274,3 +274,49 @@ fn test_whitespace_leading_url_succeeds() {
         _result
     );
 }
+
+#[test]
+fn test_templated_payload_replaces_default_fields() {
+    let config = DiscordConfig {
+        enabled: true,
+        webhook_url: "https://discord.com/api/webhooks/123456789/token".to_string(),
+        user_id_to_mention: Some("42".to_string()),
+    };
+    let payload = build_templated_discord_payload(
+        &config,
+        "**{{title}}** — {{salary}}",
+        &notification_fixture(),
+    );
+
+    let embed = &payload["embeds"][0];
+    assert_eq!(
+        embed["description"],
+        "**Care Coordinator** — $180,000 - $220,000"
+    );
+    assert!(embed.get("fields").is_none());
+    assert_eq!(embed["color"], 0x10b981);
+    assert_eq!(payload["content"], "<@42>");
+}
+
+#[test]
+fn test_report_payload_bolds_headings_and_truncates_long_reports() {
+    let config = DiscordConfig {
+        enabled: true,
+        webhook_url: String::new(),
+        user_id_to_mention: Some("123".to_string()),
+    };
+    let payload =
+        build_discord_report_payload(&config, "# Weekly report\n## Applications\n- Applied: 1");
+
+    assert_eq!(
+        payload["embeds"][0]["description"],
+        "**Weekly report**\n**Applications**\n- Applied: 1\n"
+    );
+    assert_eq!(payload["content"], "<@123>");
+
+    let long = "x".repeat(DISCORD_EMBED_DESCRIPTION_LIMIT + 100);
+    let payload = build_discord_report_payload(&config, &long);
+    let description = payload["embeds"][0]["description"].as_str().unwrap();
+    assert_eq!(description.chars().count(), DISCORD_EMBED_DESCRIPTION_LIMIT);
+    assert!(description.ends_with('…'));
+}
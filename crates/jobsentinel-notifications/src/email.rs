@@ -2,25 +2,33 @@
 //!
 //! Sends rich HTML-formatted job alerts via email using SMTP.
 
+use super::template::render_message_template;
 use super::{
     notification_job_href, Notification, LOCAL_JOB_LINK_MESSAGE, LOCAL_MATCH_DETAILS_MESSAGE,
 };
-use crate::EmailConfig;
+use crate::calendar::{format_interview_ics, CalendarUpdate, InterviewCalendarEvent};
+use crate::market_report::{format_market_report_pdf, MarketReport};
+use crate::report::{format_report_html, format_report_markdown, WeeklyReport};
+use crate::{DigestFrequency, EmailConfig};
 use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
 use jobsentinel_security::encode_html_text as escape_html;
 use lettre::{
-    message::{header::ContentType, Mailbox},
+    message::{header::ContentType, Attachment, Mailbox, MultiPart, SinglePart},
     transport::smtp::authentication::Credentials,
     Message, SmtpTransport, Transport,
 };
 
 /// Send email notification
+///
+/// With a message template, the email is the rendered template as plain text
+/// instead of the default HTML layout.
 pub async fn send_email_notification(
     config: &EmailConfig,
     notification: &Notification,
+    template: Option<&str>,
 ) -> Result<()> {
     let job = &notification.job;
-    let score = &notification.score;
 
     // Build email recipients
     let to_addresses: Vec<Mailbox> = config
@@ -36,11 +44,17 @@ pub async fn send_email_notification(
         .parse()
         .context("Invalid from email address")?;
 
-    // Generate HTML email body
-    let html_body = format_html_email(job, score);
+    // Generate the email body
+    let (content_type, body) = match template {
+        Some(template) => (
+            ContentType::TEXT_PLAIN,
+            render_message_template(template, notification),
+        ),
+        None => (ContentType::TEXT_HTML, format_html_email(notification)),
+    };
 
     // Generate plain text version (for email clients that don't support HTML)
-    let _text_body = format_text_email(job, score);
+    let _text_body = format_text_email(notification);
 
     // Send to each recipient (some SMTP servers require individual sends)
     for to_address in to_addresses {
@@ -52,31 +66,12 @@ pub async fn send_email_notification(
                 "🎯 High Match Job Alert: {} at {}",
                 job.title, job.company
             ))
-            .header(ContentType::TEXT_HTML)
-            .body(html_body.clone())
+            .header(content_type.clone())
+            .body(body.clone())
             .context("Failed to build email message")?;
 
-        // Create SMTP client
-        let creds = Credentials::new(config.smtp_username.clone(), config.smtp_password.clone());
-
-        let mailer = if config.use_starttls {
-            // STARTTLS (port 587)
-            SmtpTransport::starttls_relay(&config.smtp_server)
-                .context("Failed to create STARTTLS relay")?
-                .credentials(creds)
-                .port(config.smtp_port)
-                .build()
-        } else {
-            // Direct TLS/SSL (port 465)
-            SmtpTransport::relay(&config.smtp_server)
-                .context("Failed to create SMTP relay")?
-                .credentials(creds)
-                .port(config.smtp_port)
-                .build()
-        };
-
         // Send email
-        mailer
+        smtp_mailer(config)?
             .send(&email)
             .context("Failed to send email via SMTP")?;
     }
@@ -85,10 +80,9 @@ pub async fn send_email_notification(
 }
 
 /// Format email as HTML
-fn format_html_email(
-    job: &jobsentinel_domain::Job,
-    score: &jobsentinel_intelligence::JobScore,
-) -> String {
+fn format_html_email(notification: &Notification) -> String {
+    let job = &notification.job;
+    let score = &notification.score;
     let title = escape_html(&job.title);
     let company = escape_html(&job.company);
     let location = escape_html(job.location.as_deref().unwrap_or("N/A"));
@@ -97,6 +91,22 @@ fn format_html_email(
     let salary_display = escape_html(&salary_display);
 
     let reason_items = format!("<li>{}</li>", escape_html(LOCAL_MATCH_DETAILS_MESSAGE));
+    let resume_match_row = notification
+        .resume_match_percent()
+        .map(|resume_match| {
+            format!(
+                r#"
+                <tr>
+                    <td style="padding: 12px 0; border-bottom: 1px solid #e5e7eb;">
+                        <strong style="color: #6b7280;">Resume match:</strong>
+                    </td>
+                    <td style="padding: 12px 0; border-bottom: 1px solid #e5e7eb; text-align: right;">
+                        {resume_match}
+                    </td>
+                </tr>"#
+            )
+        })
+        .unwrap_or_default();
 
     let job_link = validated_job_href(&job.url)
         .map(|href| {
@@ -177,7 +187,7 @@ fn format_html_email(
                     <td style="padding: 12px 0; border-bottom: 1px solid #e5e7eb; text-align: right;">
                         {}
                     </td>
-                </tr>
+                </tr>{}
             </table>
 
             <div style="margin: 24px 0;">
@@ -206,6 +216,7 @@ fn format_html_email(
         location,
         salary_display,
         source,
+        resume_match_row,
         reason_items,
         job_link,
         score.total * 100.0,
@@ -217,19 +228,22 @@ fn validated_job_href(url: &str) -> Option<String> {
 }
 
 /// Format email as plain text (fallback for non-HTML clients)
-fn format_text_email(
-    job: &jobsentinel_domain::Job,
-    score: &jobsentinel_intelligence::JobScore,
-) -> String {
+fn format_text_email(notification: &Notification) -> String {
+    let job = &notification.job;
+    let score = &notification.score;
     let salary_display = super::format_salary_range(job.salary_min, job.salary_max);
     let local_match_details = format!("  - {}", LOCAL_MATCH_DETAILS_MESSAGE);
+    let resume_match = notification
+        .resume_match_percent()
+        .map(|resume_match| format!("\nResume Match: {resume_match}"))
+        .unwrap_or_default();
     let job_link =
         validated_job_href(&job.url).unwrap_or_else(|| LOCAL_JOB_LINK_MESSAGE.to_string());
 
     format!(
         r#"🎯 HIGH MATCH JOB ALERT
 {}
-Match Score: {:.0}%
+Match Score: {:.0}%{}
 
 COMPANY: {}
 LOCATION: {}
@@ -248,6 +262,7 @@ You can adjust your notification preferences in the app settings.
 "#,
         job.title,
         score.total * 100.0,
+        resume_match,
         job.company,
         job.location.as_deref().unwrap_or("N/A"),
         salary_display,
@@ -298,25 +313,317 @@ pub async fn validate_email_config(config: &EmailConfig) -> Result<bool> {
         )
         .context("Failed to build test email")?;
 
-    // Create SMTP client
-    let creds = Credentials::new(config.smtp_username.clone(), config.smtp_password.clone());
+    // Send test email
+    smtp_mailer(config)?
+        .send(&email)
+        .context("Failed to send test email")?;
 
-    let mailer = if config.use_starttls {
-        SmtpTransport::starttls_relay(&config.smtp_server)?
-            .credentials(creds)
-            .port(config.smtp_port)
-            .build()
-    } else {
-        SmtpTransport::relay(&config.smtp_server)?
-            .credentials(creds)
-            .port(config.smtp_port)
-            .build()
+    Ok(true)
+}
+
+/// Email an interview invite or cancellation as an `.ics` attachment
+pub async fn send_interview_calendar_email(
+    config: &EmailConfig,
+    event: &InterviewCalendarEvent,
+    update: CalendarUpdate,
+) -> Result<()> {
+    let ics = format_interview_ics(event, update, Utc::now())
+        .ok_or_else(|| anyhow!("Interview start time is not a valid date"))?;
+    let from_address: Mailbox = config
+        .from_email
+        .parse()
+        .context("Invalid from email address")?;
+    let calendar_type = ContentType::parse(&format!(
+        "text/calendar; method={}; charset=UTF-8",
+        update.method()
+    ))
+    .context("Invalid calendar content type")?;
+    let (subject, text_body) = match update {
+        CalendarUpdate::Scheduled => (
+            format!("Interview scheduled: {}", event.summary()),
+            "Open the attached invite to add this interview to your calendar.",
+        ),
+        CalendarUpdate::Cancelled => (
+            format!("Interview cancelled: {}", event.summary()),
+            "Open the attached cancellation to remove this interview from your calendar.",
+        ),
     };
 
-    // Send test email
-    mailer.send(&email).context("Failed to send test email")?;
+    let mailer = smtp_mailer(config)?;
+    for to_email in &config.to_emails {
+        let to_address: Mailbox = to_email
+            .parse()
+            .context("Invalid recipient email address")?;
+        let email = Message::builder()
+            .from(from_address.clone())
+            .to(to_address)
+            .subject(subject.clone())
+            .multipart(
+                MultiPart::mixed()
+                    .singlepart(SinglePart::plain(text_body.to_string()))
+                    .singlepart(
+                        Attachment::new("interview.ics".to_string())
+                            .body(ics.clone(), calendar_type.clone()),
+                    ),
+            )
+            .context("Failed to build calendar email")?;
+        mailer
+            .send(&email)
+            .context("Failed to send calendar email via SMTP")?;
+    }
 
-    Ok(true)
+    Ok(())
+}
+
+/// Email a digest of `jobs` to the digest recipients
+///
+/// Sends one message per recipient with HTML and plain text parts. A
+/// `market_report` is attached as a PDF.
+pub async fn send_email_digest(
+    config: &EmailConfig,
+    jobs: &[jobsentinel_domain::Job],
+    market_report: Option<&MarketReport>,
+) -> Result<()> {
+    let from_address: Mailbox = config
+        .from_email
+        .parse()
+        .context("Invalid from email address")?;
+    let recipients = config.digest_recipients();
+    if recipients.is_empty() {
+        return Err(anyhow!("No recipient emails configured"));
+    }
+
+    let frequency = config.digest.frequency;
+    let subject = format!(
+        "JobSentinel {} digest: {} new {}",
+        digest_period_label(frequency),
+        jobs.len(),
+        if jobs.len() == 1 { "match" } else { "matches" }
+    );
+    let html_body = format_html_digest(jobs, frequency);
+    let text_body = format_text_digest(jobs, frequency);
+    let report_attachment = market_report
+        .map(|report| {
+            let pdf_type =
+                ContentType::parse("application/pdf").context("Invalid PDF content type")?;
+            Ok::<_, anyhow::Error>((
+                format!("{}.pdf", report.file_stem()),
+                format_market_report_pdf(report),
+                pdf_type,
+            ))
+        })
+        .transpose()?;
+
+    let mailer = smtp_mailer(config)?;
+    for to_email in recipients {
+        let to_address: Mailbox = to_email
+            .parse()
+            .context("Invalid recipient email address")?;
+        let body = MultiPart::alternative_plain_html(text_body.clone(), html_body.clone());
+        let body = match &report_attachment {
+            Some((filename, pdf, pdf_type)) => MultiPart::mixed()
+                .multipart(body)
+                .singlepart(Attachment::new(filename.clone()).body(pdf.clone(), pdf_type.clone())),
+            None => body,
+        };
+        let email = Message::builder()
+            .from(from_address.clone())
+            .to(to_address)
+            .subject(subject.clone())
+            .multipart(body)
+            .context("Failed to build digest email")?;
+        mailer
+            .send(&email)
+            .context("Failed to send digest email via SMTP")?;
+    }
+
+    Ok(())
+}
+
+/// Email a weekly report to the alert recipients
+pub async fn send_report_email(config: &EmailConfig, report: &WeeklyReport) -> Result<()> {
+    let from_address: Mailbox = config
+        .from_email
+        .parse()
+        .context("Invalid from email address")?;
+    if config.to_emails.is_empty() {
+        return Err(anyhow!("No recipient emails configured"));
+    }
+
+    let subject = report.subject();
+    let text_body = format_report_markdown(report);
+    let html_body = format_report_html(report);
+
+    let mailer = smtp_mailer(config)?;
+    for to_email in &config.to_emails {
+        let to_address: Mailbox = to_email
+            .parse()
+            .context("Invalid recipient email address")?;
+        let email = Message::builder()
+            .from(from_address.clone())
+            .to(to_address)
+            .subject(subject.clone())
+            .multipart(MultiPart::alternative_plain_html(
+                text_body.clone(),
+                html_body.clone(),
+            ))
+            .context("Failed to build report email")?;
+        mailer
+            .send(&email)
+            .context("Failed to send report email via SMTP")?;
+    }
+
+    Ok(())
+}
+
+/// Email a short plain-text message to the alert recipients
+pub async fn send_text_email(config: &EmailConfig, subject: &str, body: &str) -> Result<()> {
+    let from_address: Mailbox = config
+        .from_email
+        .parse()
+        .context("Invalid from email address")?;
+    if config.to_emails.is_empty() {
+        return Err(anyhow!("No recipient emails configured"));
+    }
+
+    let mailer = smtp_mailer(config)?;
+    for to_email in &config.to_emails {
+        let to_address: Mailbox = to_email
+            .parse()
+            .context("Invalid recipient email address")?;
+        let email = Message::builder()
+            .from(from_address.clone())
+            .to(to_address)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .context("Failed to build email")?;
+        mailer
+            .send(&email)
+            .context("Failed to send email via SMTP")?;
+    }
+
+    Ok(())
+}
+
+fn digest_period_label(frequency: DigestFrequency) -> &'static str {
+    match frequency {
+        DigestFrequency::Daily => "daily",
+        DigestFrequency::Weekly => "weekly",
+    }
+}
+
+fn digest_score_percent(job: &jobsentinel_domain::Job) -> f64 {
+    (job.score.unwrap_or_default() * 100.0).round()
+}
+
+/// Format a digest as HTML, one table row per job
+fn format_html_digest(jobs: &[jobsentinel_domain::Job], frequency: DigestFrequency) -> String {
+    let rows: String = jobs
+        .iter()
+        .map(|job| {
+            let title = escape_html(&job.title);
+            let title = match validated_job_href(&job.url) {
+                Some(href) => format!(
+                    r#"<a href="{}" style="color: #1d4ed8; text-decoration: none; font-weight: 600;">{title}</a>"#,
+                    escape_html(&href)
+                ),
+                None => format!(r#"<span style="font-weight: 600;">{title}</span>"#),
+            };
+            let salary = super::format_salary_range(job.salary_min, job.salary_max);
+            format!(
+                r#"
+            <tr>
+                <td style="padding: 12px 8px; border-bottom: 1px solid #e5e7eb;">
+                    {}<br>
+                    <span style="color: #6b7280; font-size: 14px;">{} &middot; {} &middot; {}</span>
+                </td>
+                <td style="padding: 12px 8px; border-bottom: 1px solid #e5e7eb; text-align: right; white-space: nowrap;">
+                    <strong>{:.0}%</strong>
+                </td>
+            </tr>"#,
+                title,
+                escape_html(&job.company),
+                escape_html(job.location.as_deref().unwrap_or("N/A")),
+                escape_html(&salary),
+                digest_score_percent(job),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Job Digest</title>
+</head>
+<body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, 'Helvetica Neue', Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px;">
+    <div style="background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 24px; border-radius: 8px 8px 0 0; text-align: center;">
+        <h1 style="margin: 0; font-size: 22px;">Your {} job digest</h1>
+        <p style="margin: 8px 0 0 0; opacity: 0.9;">{} new {} since the last digest</p>
+    </div>
+
+    <div style="background: #f9fafb; padding: 24px; border-radius: 0 0 8px 8px; border: 1px solid #e5e7eb; border-top: none;">
+        <table style="width: 100%; border-collapse: collapse; background: white; border-radius: 8px;">{}
+        </table>
+
+        <div style="text-align: center; margin-top: 20px; color: #6b7280; font-size: 14px;">
+            <p>{}</p>
+            <p style="margin-top: 8px; font-size: 12px;">You can change or turn off digests in the JobSentinel app settings</p>
+        </div>
+    </div>
+</body>
+</html>"#,
+        digest_period_label(frequency),
+        jobs.len(),
+        if jobs.len() == 1 { "match" } else { "matches" },
+        rows,
+        escape_html(LOCAL_MATCH_DETAILS_MESSAGE),
+    )
+}
+
+/// Format a digest as plain text (fallback for non-HTML clients)
+fn format_text_digest(jobs: &[jobsentinel_domain::Job], frequency: DigestFrequency) -> String {
+    let entries: Vec<String> = jobs
+        .iter()
+        .map(|job| {
+            let job_link =
+                validated_job_href(&job.url).unwrap_or_else(|| LOCAL_JOB_LINK_MESSAGE.to_string());
+            format!(
+                "{} ({:.0}%)\n{} - {} - {}\n{}",
+                job.title,
+                digest_score_percent(job),
+                job.company,
+                job.location.as_deref().unwrap_or("N/A"),
+                super::format_salary_range(job.salary_min, job.salary_max),
+                job_link,
+            )
+        })
+        .collect();
+
+    format!(
+        "YOUR {} JOB DIGEST\n{} new {} since the last digest\n\n{}\n\n---\n{}\nYou can change or turn off digests in the app settings.\n",
+        digest_period_label(frequency).to_uppercase(),
+        jobs.len(),
+        if jobs.len() == 1 { "match" } else { "matches" },
+        entries.join("\n\n"),
+        LOCAL_MATCH_DETAILS_MESSAGE,
+    )
+}
+
+/// SMTP client for the configured server: STARTTLS (port 587) or direct
+/// TLS/SSL (port 465)
+fn smtp_mailer(config: &EmailConfig) -> Result<SmtpTransport> {
+    let creds = Credentials::new(config.smtp_username.clone(), config.smtp_password.clone());
+    let relay = if config.use_starttls {
+        SmtpTransport::starttls_relay(&config.smtp_server)
+            .context("Failed to create STARTTLS relay")?
+    } else {
+        SmtpTransport::relay(&config.smtp_server).context("Failed to create SMTP relay")?
+    };
+    Ok(relay.credentials(creds).port(config.smtp_port).build())
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use crate::test_support::notification_fixture;
 #[test]
 fn test_html_email_formatting() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify key components are present
     assert!(html.contains("Care Coordinator"));
@@ -21,7 +21,7 @@ fn test_html_email_formatting() {
 #[test]
 fn test_text_email_formatting() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     // Verify key components are present
     assert!(text.contains("Care Coordinator"));
@@ -39,7 +39,7 @@ fn test_html_email_handles_missing_salary() {
     notification.job.salary_min = None;
     notification.job.salary_max = None;
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(html.contains("Not specified"));
 }
 
@@ -48,7 +48,7 @@ fn test_html_email_handles_min_salary_only() {
     let mut notification = notification_fixture();
     notification.job.salary_max = None;
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(html.contains("$180,000+"));
 }
 
@@ -57,14 +57,14 @@ fn test_html_email_handles_missing_location() {
     let mut notification = notification_fixture();
     notification.job.location = None;
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(html.contains("N/A"));
 }
 
 #[test]
 fn test_html_email_keeps_match_reasons_local() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains(LOCAL_MATCH_DETAILS_MESSAGE));
     for reason in &notification.score.reasons {
@@ -79,7 +79,7 @@ fn test_html_email_keeps_match_reasons_local() {
 #[test]
 fn test_text_email_keeps_match_reasons_local() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains(LOCAL_MATCH_DETAILS_MESSAGE));
     for reason in &notification.score.reasons {
@@ -97,7 +97,7 @@ fn test_text_email_handles_missing_salary() {
     notification.job.salary_min = None;
     notification.job.salary_max = None;
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
     assert!(text.contains("Not specified"));
 }
 
@@ -106,7 +106,7 @@ fn test_text_email_handles_min_salary_only() {
     let mut notification = notification_fixture();
     notification.job.salary_max = None;
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
     assert!(text.contains("$180,000+"));
 }
 
@@ -115,7 +115,7 @@ fn test_text_email_handles_missing_location() {
     let mut notification = notification_fixture();
     notification.job.location = None;
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
     assert!(text.contains("N/A"));
 }
 
@@ -124,7 +124,7 @@ fn test_html_email_no_remote_badge() {
     let mut notification = notification_fixture();
     notification.job.remote = Some(false);
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(
         !html.contains("REMOTE"),
         "Non-remote job should not have REMOTE badge"
@@ -136,7 +136,7 @@ fn test_html_email_handles_none_remote() {
     let mut notification = notification_fixture();
     notification.job.remote = None;
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(
         !html.contains("REMOTE"),
         "Job with None remote should not have REMOTE badge"
@@ -148,7 +148,7 @@ fn test_text_email_remote_no() {
     let mut notification = notification_fixture();
     notification.job.remote = Some(false);
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
     assert!(text.contains("REMOTE: No"));
 }
 
@@ -159,7 +159,7 @@ fn test_html_email_score_formatting() {
     // Test various scores
     for (score, expected) in [(0.95, "95"), (0.90, "90"), (1.00, "100"), (0.876, "88")] {
         notification.score.total = score;
-        let html = format_html_email(&notification.job, &notification.score);
+        let html = format_html_email(&notification);
         assert!(
             html.contains(&format!("{}%", expected)),
             "Score {} should format to {}%",
@@ -175,7 +175,7 @@ fn test_text_email_score_formatting() {
 
     for (score, expected) in [(0.95, "95%"), (0.90, "90%"), (1.00, "100%")] {
         notification.score.total = score;
-        let text = format_text_email(&notification.job, &notification.score);
+        let text = format_text_email(&notification);
         assert!(
             text.contains(expected),
             "Score {} should format to {}",
@@ -188,7 +188,7 @@ fn test_text_email_score_formatting() {
 #[test]
 fn test_html_email_structure() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify HTML structure
     assert!(html.contains("<!DOCTYPE html>"));
@@ -201,7 +201,7 @@ fn test_html_email_structure() {
 #[test]
 fn test_text_email_structure() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     // Verify plain text structure
     assert!(text.contains("HIGH MATCH JOB ALERT"));
@@ -217,7 +217,7 @@ fn test_html_email_empty_reasons() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec![];
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
     assert!(
         html.contains("Why this matches"),
         "Should have 'Why this matches' header even with empty reasons"
@@ -229,7 +229,7 @@ fn test_text_email_empty_reasons() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec![];
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
     assert!(
         text.contains("WHY THIS MATCHES:"),
         "Should have 'WHY THIS MATCHES' header even with empty reasons"
@@ -239,7 +239,7 @@ fn test_text_email_empty_reasons() {
 #[test]
 fn test_html_email_url_appears_in_link() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // URL should appear in the href attribute
     assert!(html.contains(&format!("href=\"{}\"", notification.job.url)));
@@ -248,7 +248,7 @@ fn test_html_email_url_appears_in_link() {
 #[test]
 fn test_text_email_url_appears() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("VIEW JOB: https://example.com/jobs/123"));
 }
@@ -259,7 +259,7 @@ fn test_html_email_with_special_characters() {
     notification.job.title = "Care Coordinator & Intake Lead".to_string();
     notification.job.company = "Community Care Network <North Clinic>".to_string();
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("Care Coordinator &amp; Intake Lead"));
     assert!(html.contains("Community Care Network &lt;North Clinic&gt;"));
@@ -271,7 +271,7 @@ fn test_text_email_with_special_characters() {
     notification.job.title = "Care Coordinator & Intake Lead".to_string();
     notification.job.company = "Community Care Network <North Clinic>".to_string();
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("Care Coordinator & Intake Lead"));
     assert!(text.contains("Community Care Network <North Clinic>"));
@@ -284,7 +284,7 @@ fn test_html_email_with_unicode() {
     notification.job.company = "Société Française".to_string();
     notification.job.location = Some("Montréal, Québec".to_string());
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("Développeur Senior 🚀"));
     assert!(html.contains("Société Française"));
@@ -297,7 +297,7 @@ fn test_text_email_with_unicode() {
     notification.job.title = "Entwickler 中文 日本語".to_string();
     notification.job.company = "グローバル株式会社".to_string();
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("Entwickler 中文 日本語"));
     assert!(text.contains("グローバル株式会社"));
@@ -310,7 +310,7 @@ fn test_html_email_with_long_title() {
         "Regional Senior Lead Care Coordination Program Operations Support Services Director"
             .to_string();
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("Regional Senior Lead Care"));
 }
@@ -323,7 +323,7 @@ fn test_text_email_with_long_location() {
             .to_string(),
     );
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("San Francisco Bay Area, California"));
 }
@@ -336,7 +336,7 @@ fn test_html_email_with_newlines_in_reasons() {
         "Another reason".to_string(),
     ];
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains(LOCAL_MATCH_DETAILS_MESSAGE));
     assert!(!html.contains("Reason with\nnewline"));
@@ -351,13 +351,25 @@ fn test_text_email_with_quotes_in_reasons() {
         "Uses 'best practices'".to_string(),
     ];
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains(LOCAL_MATCH_DETAILS_MESSAGE));
     assert!(!text.contains(r#"Matches "preferred" keyword"#));
     assert!(!text.contains("Uses 'best practices'"));
 }
 
+#[test]
+fn test_email_includes_resume_match_when_present() {
+    let mut notification = notification_fixture();
+    assert!(!format_text_email(&notification).contains("Resume Match"));
+
+    notification.resume_match = Some(0.78);
+
+    assert!(format_text_email(&notification).contains("Match Score: 95%\nResume Match: 78%\n"));
+    assert!(format_html_email(&notification).contains("Resume match:"));
+}
+
 #[path = "tests/content_and_structure_tests.rs"]
 mod content_and_structure_tests;
+mod digest_tests;
 mod rendering_edge_cases;
@@ -17,7 +17,7 @@ fn test_html_email_score_boundary_values() {
 
     for (score, expected) in test_cases {
         notification.score.total = score;
-        let html = format_html_email(&notification.job, &notification.score);
+        let html = format_html_email(&notification);
         assert!(
             html.contains(expected),
             "Score {} should contain '{}' in HTML",
@@ -35,7 +35,7 @@ fn test_text_email_score_boundary_values() {
 
     for (score, expected) in test_cases {
         notification.score.total = score;
-        let text = format_text_email(&notification.job, &notification.score);
+        let text = format_text_email(&notification);
         assert!(
             text.contains(expected),
             "Score {} should format to {}",
@@ -52,7 +52,7 @@ fn test_html_email_url_with_query_params() {
         "https://example.com/jobs/123?utm_source=jobsentinel&gh_jid=123&token=secret&candidate_email=person@example.com#private"
             .to_string();
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("href=\"https://example.com/jobs/123?gh_jid=123\""));
     assert!(!html.contains("utm_source"));
@@ -67,7 +67,7 @@ fn test_html_email_omits_invalid_job_href() {
     let mut notification = notification_fixture();
     notification.job.url = "http://localhost:3000/private".to_string();
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(!html.contains("href=\"http://localhost:3000/private\""));
     assert!(html.contains("Open this job in JobSentinel"));
@@ -79,7 +79,7 @@ fn test_text_email_url_with_fragments() {
     notification.job.url =
         "https://example.com/careers?gh_jid=123&token=secret#senior-engineer-role".to_string();
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("VIEW JOB: https://example.com/careers?gh_jid=123"));
     assert!(!text.contains("token"));
@@ -97,7 +97,7 @@ fn test_html_email_multiple_reasons_formatting() {
         "Fifth reason".to_string(),
     ];
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains(&format!("<li>{}</li>", LOCAL_MATCH_DETAILS_MESSAGE)));
     for reason in &notification.score.reasons {
@@ -114,7 +114,7 @@ fn test_text_email_multiple_reasons_formatting() {
         "Third reason".to_string(),
     ];
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains(&format!("  - {}", LOCAL_MATCH_DETAILS_MESSAGE)));
     for reason in &notification.score.reasons {
@@ -127,7 +127,7 @@ fn test_html_email_single_reason() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec!["Only one reason".to_string()];
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains(&format!("<li>{}</li>", LOCAL_MATCH_DETAILS_MESSAGE)));
     assert!(!html.contains("Only one reason"));
@@ -138,7 +138,7 @@ fn test_text_email_single_reason() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec!["Only one reason".to_string()];
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains(&format!("  - {}", LOCAL_MATCH_DETAILS_MESSAGE)));
     assert!(!text.contains("Only one reason"));
@@ -147,7 +147,7 @@ fn test_text_email_single_reason() {
 #[test]
 fn test_html_email_contains_meta_tags() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify HTML meta tags for proper rendering
     assert!(html.contains(r#"<meta charset="UTF-8">"#));
@@ -159,7 +159,7 @@ fn test_html_email_contains_meta_tags() {
 #[test]
 fn test_html_email_contains_styling() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify inline CSS styles are present
     assert!(html.contains("font-family:"));
@@ -171,7 +171,7 @@ fn test_html_email_contains_styling() {
 #[test]
 fn test_html_email_gradient_header() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify gradient background is present
     assert!(html.contains("linear-gradient"));
@@ -182,7 +182,7 @@ fn test_html_email_gradient_header() {
 #[test]
 fn test_html_email_call_to_action_button() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify CTA button is present with proper styling
     assert!(html.contains("View Full Job Posting"));
@@ -195,7 +195,7 @@ fn test_html_email_source_field() {
     let mut notification = notification_fixture();
     notification.job.source = "linkedin".to_string();
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("linkedin"));
     assert!(html.contains("Source:"));
@@ -206,7 +206,7 @@ fn test_text_email_source_field() {
     let mut notification = notification_fixture();
     notification.job.source = "remoteok".to_string();
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("SOURCE: remoteok"));
 }
@@ -214,7 +214,7 @@ fn test_text_email_source_field() {
 #[test]
 fn test_html_email_remote_badge_styling() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify remote badge has green background
     assert!(html.contains("#10b981"));
@@ -224,7 +224,7 @@ fn test_html_email_remote_badge_styling() {
 #[test]
 fn test_html_email_match_score_badge() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify match score badge styling
     assert!(html.contains("#3b82f6")); // Blue badge color
@@ -234,7 +234,7 @@ fn test_html_email_match_score_badge() {
 #[test]
 fn test_text_email_formatting_separators() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     // Verify text formatting separators
     assert!(text.contains("---"));
@@ -246,7 +246,7 @@ fn test_text_email_formatting_separators() {
 #[test]
 fn test_html_email_table_structure() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify table structure for job details
     assert!(html.contains("<table"));
@@ -260,7 +260,7 @@ fn test_html_email_table_structure() {
 #[test]
 fn test_html_email_footer_text() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     // Verify footer messaging
     assert!(html.contains("This alert was sent by"));
@@ -271,7 +271,7 @@ fn test_html_email_footer_text() {
 #[test]
 fn test_text_email_footer_text() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     // Verify footer messaging
     assert!(text.contains("This alert was sent by JobSentinel"));
@@ -0,0 +1,56 @@
+use super::*;
+
+fn digest_jobs() -> Vec<jobsentinel_domain::Job> {
+    let first = notification_fixture().job;
+    let mut second = notification_fixture().job;
+    second.id = 2;
+    second.title = "Benefits <Navigator>".to_string();
+    second.company = "Riverside Health".to_string();
+    second.url = "javascript:alert(1)".to_string();
+    second.score = Some(0.724);
+    vec![first, second]
+}
+
+#[test]
+fn test_html_digest_lists_each_job_with_score() {
+    let html = format_html_digest(&digest_jobs(), DigestFrequency::Weekly);
+
+    assert!(html.contains("Your weekly job digest"));
+    assert!(html.contains("2 new matches since the last digest"));
+    assert!(html.contains("Care Coordinator"));
+    assert!(html.contains("https://example.com/jobs/123"));
+    assert!(html.contains("95%"));
+    assert!(html.contains("Riverside Health"));
+    assert!(html.contains("72%"));
+}
+
+#[test]
+fn test_html_digest_escapes_titles_and_drops_unsafe_links() {
+    let html = format_html_digest(&digest_jobs(), DigestFrequency::Daily);
+
+    assert!(html.contains("Benefits &lt;Navigator&gt;"));
+    assert!(!html.contains("<Navigator>"));
+    assert!(!html.contains("javascript:"));
+}
+
+#[test]
+fn test_text_digest_falls_back_to_local_link() {
+    let text = format_text_digest(&digest_jobs(), DigestFrequency::Daily);
+
+    assert!(text.starts_with("YOUR DAILY JOB DIGEST\n2 new matches"));
+    assert!(text.contains("Care Coordinator (95%)"));
+    assert!(text.contains("Benefits <Navigator> (72%)"));
+    assert!(text.contains(LOCAL_JOB_LINK_MESSAGE));
+}
+
+#[test]
+fn test_digest_recipients_fall_back_to_alert_recipients() {
+    let mut config = EmailConfig {
+        to_emails: vec!["alerts@example.com".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(config.digest_recipients(), ["alerts@example.com"]);
+
+    config.digest.recipients = vec!["digest@example.com".to_string()];
+    assert_eq!(config.digest_recipients(), ["digest@example.com"]);
+}
@@ -5,7 +5,7 @@ fn test_html_email_with_empty_string_location() {
     let mut notification = notification_fixture();
     notification.job.location = Some("".to_string());
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("<td style=\"padding: 12px 0; border-bottom: 1px solid #e5e7eb; text-align: right;\">\n                        \n                    </td>"));
 }
@@ -15,7 +15,7 @@ fn test_text_email_with_empty_string_location() {
     let mut notification = notification_fixture();
     notification.job.location = Some("".to_string());
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("LOCATION: "));
 }
@@ -26,7 +26,7 @@ fn test_salary_formatting_large_values() {
     notification.job.salary_min = Some(500000);
     notification.job.salary_max = Some(800000);
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("$500,000 - $800,000"));
 }
@@ -37,7 +37,7 @@ fn test_salary_formatting_small_values() {
     notification.job.salary_min = Some(30000);
     notification.job.salary_max = Some(50000);
 
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("$30,000 - $50,000"));
 }
@@ -47,7 +47,7 @@ fn test_html_email_reason_with_html_like_text() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec!["Matches <keyword>".to_string(), "Has & symbol".to_string()];
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains(LOCAL_MATCH_DETAILS_MESSAGE));
     assert!(!html.contains("Matches &lt;keyword&gt;"));
@@ -57,7 +57,7 @@ fn test_html_email_reason_with_html_like_text() {
 #[test]
 fn test_text_email_preserves_exact_formatting() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.starts_with("🎯 HIGH MATCH JOB ALERT"));
     assert!(text.contains("\n\nCOMPANY:"));
@@ -70,7 +70,7 @@ fn test_html_email_with_zero_salary() {
     notification.job.salary_min = Some(0);
     notification.job.salary_max = Some(0);
 
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("$0,000 - $0,000"));
 }
@@ -78,7 +78,7 @@ fn test_html_email_with_zero_salary() {
 #[test]
 fn test_html_email_emojis_preserved() {
     let notification = notification_fixture();
-    let html = format_html_email(&notification.job, &notification.score);
+    let html = format_html_email(&notification);
 
     assert!(html.contains("🎯"));
 }
@@ -86,7 +86,7 @@ fn test_html_email_emojis_preserved() {
 #[test]
 fn test_text_email_emojis_preserved() {
     let notification = notification_fixture();
-    let text = format_text_email(&notification.job, &notification.score);
+    let text = format_text_email(&notification);
 
     assert!(text.contains("🎯"));
 }
@@ -1,11 +1,19 @@
 //! Notification formatting and bounded delivery transports.
 
+mod calendar;
 mod config;
 mod discord;
 mod email;
+mod market_report;
+mod ntfy;
+mod pdf;
+mod pushover;
+mod report;
 mod slack;
 mod teams;
 mod telegram;
+mod template;
+mod webhook;
 
 use jobsentinel_domain::Job;
 use jobsentinel_intelligence::JobScore;
@@ -14,15 +22,43 @@ use jobsentinel_security::canonicalize_user_supplied_job_url;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+pub use calendar::{
+    format_interview_calendar, format_interview_ics, CalendarUpdate, InterviewCalendarEvent,
+};
 pub use config::{
-    AlertConfig, DesktopConfig, DiscordConfig, EmailConfig, SlackConfig, TeamsConfig,
-    TelegramConfig,
+    AlertConfig, CustomWebhookConfig, DesktopConfig, DigestFrequency, DiscordConfig, EmailConfig,
+    EmailDigestConfig, NtfyConfig, PushoverConfig, SlackConfig, TeamsConfig, TelegramConfig,
+    WeeklyReportConfig,
+};
+pub use discord::{
+    send_discord_notification, send_discord_report, validate_webhook as validate_discord_webhook,
+};
+pub use email::{
+    send_email_digest, send_email_notification, send_interview_calendar_email, send_report_email,
+    send_text_email, validate_email_config,
+};
+pub use market_report::{
+    format_market_report_markdown, format_market_report_pdf, MarketReport, MarketReportEntry,
+    MarketReportSummary,
+};
+pub use ntfy::{send_ntfy_notification, validate_config as validate_ntfy_config};
+pub use pushover::{send_pushover_notification, validate_config as validate_pushover_config};
+pub use report::{
+    format_report_html, format_report_markdown, ReportApplicationActivity, ReportMarketAlert,
+    WeeklyReport,
+};
+pub use slack::{
+    send_slack_notification, send_slack_report, validate_webhook as validate_slack_webhook,
+};
+pub use teams::{send_teams_notification, validate_webhook as validate_teams_webhook};
+pub use telegram::{send_telegram_notification, validate_bot as validate_telegram_bot};
+pub use template::{
+    render_message_template, validate_message_template, MAX_MESSAGE_TEMPLATE_CHARS, TEMPLATE_FIELDS,
+};
+pub use webhook::{
+    send_webhook_notification, validate_webhook as validate_custom_webhook,
+    validate_webhook_template, WEBHOOK_SIGNATURE_HEADER,
 };
-pub use discord::send_discord_notification;
-pub use email::{send_email_notification, validate_email_config};
-pub use slack::{send_slack_notification, validate_webhook as validate_slack_webhook};
-pub use teams::send_teams_notification;
-pub use telegram::send_telegram_notification;
 
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
@@ -32,6 +68,32 @@ pub mod test_support;
 pub struct Notification {
     pub job: Job,
     pub score: JobScore,
+    /// Active resume's overall match (0.0 - 1.0), when alerts include it
+    #[serde(default)]
+    pub resume_match: Option<f64>,
+}
+
+impl Notification {
+    /// Short match line such as "92% match", or
+    /// "92% config match, 78% resume match" when a resume match is included
+    #[must_use]
+    pub fn match_summary(&self) -> String {
+        let score_percent = (self.score.total * 100.0).round();
+        match self.resume_match {
+            Some(resume_match) => format!(
+                "{score_percent:.0}% config match, {:.0}% resume match",
+                (resume_match * 100.0).round()
+            ),
+            None => format!("{score_percent:.0}% match"),
+        }
+    }
+
+    /// Resume match as a whole percentage, such as "78%"
+    #[must_use]
+    pub fn resume_match_percent(&self) -> Option<String> {
+        self.resume_match
+            .map(|resume_match| format!("{:.0}%", (resume_match * 100.0).round()))
+    }
 }
 
 pub(crate) const LOCAL_MATCH_DETAILS_MESSAGE: &str =
@@ -103,4 +165,18 @@ mod tests {
             assert_eq!(format_salary_range(min, max), expected);
         }
     }
+    #[test]
+    fn match_summary_adds_resume_match_when_present() {
+        let mut notification = test_support::notification_fixture();
+        notification.score.total = 0.92;
+        assert_eq!(notification.match_summary(), "92% match");
+        assert_eq!(notification.resume_match_percent(), None);
+
+        notification.resume_match = Some(0.776);
+        assert_eq!(
+            notification.match_summary(),
+            "92% config match, 78% resume match"
+        );
+        assert_eq!(notification.resume_match_percent().as_deref(), Some("78%"));
+    }
 }
@@ -0,0 +1,262 @@
+//! Market Reports
+//!
+//! A market report summarizes Hiring Trends for a period: the latest market
+//! snapshot and how it changed, trending skills, the most active companies,
+//! the busiest locations, and the alerts raised. It is rendered as Markdown or
+//! as a PDF for saving and emailing.
+
+use super::report::{escape_markdown, ReportMarketAlert};
+use crate::pdf::{render_pdf, PdfLine};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Everything a market report covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketReport {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    /// `None` until the market has been analyzed at least once
+    pub summary: Option<MarketReportSummary>,
+    pub trending_skills: Vec<MarketReportEntry>,
+    pub active_companies: Vec<MarketReportEntry>,
+    pub hottest_locations: Vec<MarketReportEntry>,
+    pub alerts: Vec<ReportMarketAlert>,
+}
+
+/// The latest market snapshot, with changes since the start of the period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketReportSummary {
+    pub total_jobs: i64,
+    pub total_jobs_change: Option<i64>,
+    pub median_salary: Option<i64>,
+    pub median_salary_change: Option<i64>,
+    pub remote_job_percentage: f64,
+    pub companies_hiring: i64,
+    /// `bullish`, `neutral`, or `bearish`
+    pub sentiment: String,
+}
+
+/// A ranked skill, company, or location
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketReportEntry {
+    pub name: String,
+    pub jobs: i64,
+    /// Short note such as "up 12%" or "40% remote"
+    pub detail: Option<String>,
+}
+
+impl MarketReport {
+    /// The report period, such as "Sep 15 - Oct 15, 2026"
+    #[must_use]
+    pub fn period_label(&self) -> String {
+        format!(
+            "{} - {}",
+            self.period_start.format("%b %-d"),
+            self.period_end.format("%b %-d, %Y")
+        )
+    }
+
+    /// File name for a saved copy, without the extension
+    #[must_use]
+    pub fn file_stem(&self) -> String {
+        format!("jobsentinel-market-report-{}", self.period_end)
+    }
+}
+
+fn signed(change: i64) -> String {
+    if change >= 0 {
+        format!("+{change}")
+    } else {
+        change.to_string()
+    }
+}
+
+/// A whole-dollar amount with thousands separators, such as "$145,000"
+fn dollars(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut out = String::from("$");
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+fn signed_dollars(change: i64) -> String {
+    let sign = if change >= 0 { '+' } else { '-' };
+    format!("{sign}{}", dollars(change.unsigned_abs()))
+}
+
+fn summary_lines(summary: &MarketReportSummary) -> Vec<(&'static str, String)> {
+    let with_change = |value: String, change: Option<String>| match change {
+        Some(change) => format!("{value} ({change} this period)"),
+        None => value,
+    };
+    vec![
+        (
+            "Jobs tracked",
+            with_change(
+                summary.total_jobs.to_string(),
+                summary.total_jobs_change.map(signed),
+            ),
+        ),
+        (
+            "Median salary",
+            summary.median_salary.map_or_else(
+                || "Not enough salary data".to_string(),
+                |median| {
+                    with_change(
+                        dollars(median.unsigned_abs()),
+                        summary.median_salary_change.map(signed_dollars),
+                    )
+                },
+            ),
+        ),
+        (
+            "Remote jobs",
+            format!("{:.0}%", summary.remote_job_percentage),
+        ),
+        ("Companies hiring", summary.companies_hiring.to_string()),
+        ("Market sentiment", summary.sentiment.clone()),
+    ]
+}
+
+/// Report sections: heading, entries, and the note shown when empty
+fn entry_sections(
+    report: &MarketReport,
+) -> [(&'static str, &[MarketReportEntry], &'static str); 3] {
+    [
+        (
+            "Trending skills",
+            &report.trending_skills,
+            "No skill trends yet.",
+        ),
+        (
+            "Most active companies",
+            &report.active_companies,
+            "No company activity yet.",
+        ),
+        (
+            "Hottest locations",
+            &report.hottest_locations,
+            "No location data yet.",
+        ),
+    ]
+}
+
+const NO_SNAPSHOT: &str = "No market snapshot yet. Refresh Hiring Trends to create one.";
+const NO_ALERTS: &str = "No market alerts this period.";
+
+fn entry_text(entry: &MarketReportEntry) -> String {
+    let jobs = format!(
+        "{} {}",
+        entry.jobs,
+        if entry.jobs == 1 { "job" } else { "jobs" }
+    );
+    match &entry.detail {
+        Some(detail) => format!("{jobs}, {detail}"),
+        None => jobs,
+    }
+}
+
+/// Format a market report as Markdown
+#[must_use]
+pub fn format_market_report_markdown(report: &MarketReport) -> String {
+    let mut out = format!(
+        "# JobSentinel market report\n{}\n\n## Summary\n",
+        report.period_label()
+    );
+    match &report.summary {
+        Some(summary) => {
+            for (label, value) in summary_lines(summary) {
+                out.push_str(&format!("- {label}: {}\n", escape_markdown(&value)));
+            }
+        }
+        None => out.push_str(&format!("{NO_SNAPSHOT}\n")),
+    }
+
+    for (heading, entries, empty) in entry_sections(report) {
+        out.push_str(&format!("\n## {heading}\n"));
+        if entries.is_empty() {
+            out.push_str(&format!("{empty}\n"));
+        }
+        for (rank, entry) in entries.iter().enumerate() {
+            out.push_str(&format!(
+                "{}. **{}**: {}\n",
+                rank + 1,
+                escape_markdown(&entry.name),
+                escape_markdown(&entry_text(entry)),
+            ));
+        }
+    }
+
+    out.push_str("\n## Market alerts\n");
+    if report.alerts.is_empty() {
+        out.push_str(&format!("{NO_ALERTS}\n"));
+    }
+    for alert in &report.alerts {
+        out.push_str(&format!(
+            "- **{}** ({}): {}\n",
+            escape_markdown(&alert.title),
+            escape_markdown(&alert.severity),
+            escape_markdown(&alert.description),
+        ));
+    }
+
+    out
+}
+
+/// Format a market report as a PDF document
+#[must_use]
+pub fn format_market_report_pdf(report: &MarketReport) -> Vec<u8> {
+    let mut lines = vec![
+        PdfLine::Title("JobSentinel market report".to_string()),
+        PdfLine::Text(report.period_label()),
+        PdfLine::Blank,
+        PdfLine::Heading("Summary".to_string()),
+    ];
+    match &report.summary {
+        Some(summary) => lines.extend(
+            summary_lines(summary)
+                .into_iter()
+                .map(|(label, value)| PdfLine::Text(format!("{label}: {value}"))),
+        ),
+        None => lines.push(PdfLine::Text(NO_SNAPSHOT.to_string())),
+    }
+
+    for (heading, entries, empty) in entry_sections(report) {
+        lines.push(PdfLine::Blank);
+        lines.push(PdfLine::Heading(heading.to_string()));
+        if entries.is_empty() {
+            lines.push(PdfLine::Text(empty.to_string()));
+        }
+        lines.extend(entries.iter().enumerate().map(|(rank, entry)| {
+            PdfLine::Text(format!(
+                "{}. {}: {}",
+                rank + 1,
+                entry.name,
+                entry_text(entry)
+            ))
+        }));
+    }
+
+    lines.push(PdfLine::Blank);
+    lines.push(PdfLine::Heading("Market alerts".to_string()));
+    if report.alerts.is_empty() {
+        lines.push(PdfLine::Text(NO_ALERTS.to_string()));
+    }
+    lines.extend(report.alerts.iter().map(|alert| {
+        PdfLine::Text(format!(
+            "{} ({}): {}",
+            alert.title, alert.severity, alert.description
+        ))
+    }));
+
+    render_pdf(&lines)
+}
+
+#[cfg(test)]
+#[path = "market_report_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+
+fn market_report_fixture() -> MarketReport {
+    MarketReport {
+        period_start: NaiveDate::from_ymd_opt(2026, 9, 15).unwrap(),
+        period_end: NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(),
+        summary: Some(MarketReportSummary {
+            total_jobs: 1240,
+            total_jobs_change: Some(85),
+            median_salary: Some(145_000),
+            median_salary_change: Some(-3_000),
+            remote_job_percentage: 34.4,
+            companies_hiring: 210,
+            sentiment: "bullish".to_string(),
+        }),
+        trending_skills: vec![MarketReportEntry {
+            name: "Case_Management".to_string(),
+            jobs: 120,
+            detail: Some("up 15%".to_string()),
+        }],
+        active_companies: vec![MarketReportEntry {
+            name: "Community Care Network".to_string(),
+            jobs: 1,
+            detail: None,
+        }],
+        hottest_locations: Vec::new(),
+        alerts: vec![ReportMarketAlert {
+            title: "Demand for <care> roles up".to_string(),
+            description: "More listings than last month".to_string(),
+            severity: "info".to_string(),
+        }],
+    }
+}
+
+#[test]
+fn test_markdown_market_report_covers_every_section() {
+    let report = market_report_fixture();
+    let markdown = format_market_report_markdown(&report);
+
+    assert!(markdown.starts_with("# JobSentinel market report\nSep 15 - Oct 15, 2026\n"));
+    assert!(markdown.contains("- Jobs tracked: 1240 (+85 this period)\n"));
+    assert!(markdown.contains("- Median salary: $145,000 (-$3,000 this period)\n"));
+    assert!(markdown.contains("- Remote jobs: 34%\n"));
+    assert!(markdown.contains("1. **Case\\_Management**: 120 jobs, up 15%\n"));
+    assert!(markdown.contains("1. **Community Care Network**: 1 job\n"));
+    assert!(markdown.contains("## Hottest locations\nNo location data yet.\n"));
+    assert!(markdown.contains("- **Demand for \\<care\\> roles up** (info)"));
+    assert_eq!(report.file_stem(), "jobsentinel-market-report-2026-10-15");
+}
+
+#[test]
+fn test_markdown_market_report_without_a_snapshot() {
+    let report = MarketReport {
+        summary: None,
+        alerts: Vec::new(),
+        ..market_report_fixture()
+    };
+    let markdown = format_market_report_markdown(&report);
+
+    assert!(markdown.contains("## Summary\nNo market snapshot yet."));
+    assert!(markdown.contains("No market alerts this period."));
+}
+
+#[test]
+fn test_pdf_market_report_contains_plain_report_text() {
+    let pdf = format_market_report_pdf(&market_report_fixture());
+    let text = String::from_utf8_lossy(&pdf);
+
+    assert!(pdf.starts_with(b"%PDF-1.4"));
+    assert!(text.contains("(JobSentinel market report) Tj"));
+    assert!(text.contains("(Median salary: $145,000 \\(-$3,000 this period\\)) Tj"));
+    assert!(text.contains("(1. Case_Management: 120 jobs, up 15%) Tj"));
+    assert!(
+        text.contains("(Demand for <care> roles up \\(info\\): More listings than last month) Tj")
+    );
+}
+
+#[test]
+fn test_dollar_amounts_use_thousands_separators() {
+    assert_eq!(dollars(0), "$0");
+    assert_eq!(dollars(950), "$950");
+    assert_eq!(dollars(1_250_000), "$1,250,000");
+    assert_eq!(signed_dollars(2_500), "+$2,500");
+}
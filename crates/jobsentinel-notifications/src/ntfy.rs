@@ -0,0 +1,126 @@
+//! ntfy Push Notifications
+//!
+//! Publishes job alerts to an ntfy topic with the JSON publish API, so phones
+//! running the ntfy app get a push without Slack or email.
+
+use super::{
+    format_salary_range, notification_job_href, notification_provider_failure_summary,
+    Notification, NOTIFICATION_HTTP_TIMEOUT,
+};
+use crate::NtfyConfig;
+use anyhow::{anyhow, Result};
+use jobsentinel_network::{
+    send_external_https_text_with_retry, ExternalHttpRequest, ExternalTextResponse,
+};
+use jobsentinel_security::validate_external_https_url;
+use serde_json::{json, Value};
+
+const MAX_TOPIC_LENGTH: usize = 64;
+
+/// Validate an ntfy server URL
+fn validate_server_url(server_url: &str) -> Result<()> {
+    validate_external_https_url(server_url)
+        .map(|_| ())
+        .map_err(|_| {
+            anyhow!("Use the https:// address of your ntfy server, such as https://ntfy.sh")
+        })
+}
+
+/// Validate an ntfy topic name
+///
+/// ntfy topics are 1-64 letters, digits, underscores, or dashes.
+fn validate_topic(topic: &str) -> Result<()> {
+    if topic.is_empty() {
+        return Err(anyhow!("ntfy topic cannot be empty"));
+    }
+
+    if topic.len() > MAX_TOPIC_LENGTH
+        || !topic
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(anyhow!(
+            "Invalid ntfy topic: use up to 64 letters, numbers, underscores, or dashes"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build the JSON publish body for a job alert
+fn ntfy_payload(config: &NtfyConfig, notification: &Notification) -> Value {
+    let job = &notification.job;
+    let remote = if job.remote.unwrap_or(false) {
+        "Yes"
+    } else {
+        "No"
+    };
+    let message = format!(
+        "{} • {}\nSalary: {}\nRemote: {}",
+        job.company,
+        job.location.as_deref().unwrap_or("N/A"),
+        format_salary_range(job.salary_min, job.salary_max),
+        remote
+    );
+
+    let mut payload = json!({
+        "topic": config.topic,
+        "title": format!("{}: {}", notification.match_summary(), job.title),
+        "message": message,
+        "priority": config.priority.clamp(1, 5),
+        "tags": ["briefcase"]
+    });
+    if let Some(href) = notification_job_href(&job.url) {
+        payload["click"] = json!(href);
+    }
+    payload
+}
+
+/// POST a JSON publish body to the server root
+async fn publish(config: &NtfyConfig, payload: Value) -> Result<ExternalTextResponse> {
+    validate_server_url(&config.server_url)?;
+    validate_topic(&config.topic)?;
+
+    let mut request = ExternalHttpRequest::post(config.server_url.trim_end_matches('/'))
+        .timeout(NOTIFICATION_HTTP_TIMEOUT)
+        .without_retries();
+    if !config.access_token.is_empty() {
+        request = request.header("Authorization", format!("Bearer {}", config.access_token));
+    }
+
+    send_external_https_text_with_retry(request.json(payload))
+        .await
+        .map_err(|error| anyhow!("ntfy request failed: {error}"))
+}
+
+/// Send ntfy notification
+pub async fn send_ntfy_notification(
+    config: &NtfyConfig,
+    notification: &Notification,
+) -> Result<()> {
+    let response = publish(config, ntfy_payload(config, notification)).await?;
+
+    if !(200..300).contains(&response.status) {
+        let error_summary = notification_provider_failure_summary(&response);
+        return Err(anyhow!("ntfy publish failed: {}", error_summary));
+    }
+
+    Ok(())
+}
+
+/// Validate ntfy settings by publishing a test message to the topic
+pub async fn validate_config(config: &NtfyConfig) -> Result<bool> {
+    let response = publish(
+        config,
+        json!({
+            "topic": config.topic,
+            "message": "JobSentinel: ntfy alerts are connected ✅"
+        }),
+    )
+    .await?;
+
+    Ok((200..300).contains(&response.status))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+use crate::test_support::notification_fixture;
+
+fn ntfy_config() -> NtfyConfig {
+    NtfyConfig {
+        enabled: true,
+        topic: "jobsentinel-alerts_42".to_string(),
+        ..NtfyConfig::default()
+    }
+}
+
+#[test]
+fn test_ntfy_defaults_to_public_server_and_normal_priority() {
+    let config = NtfyConfig::default();
+
+    assert_eq!(config.server_url, "https://ntfy.sh");
+    assert_eq!(config.priority, 3);
+}
+
+#[test]
+fn test_ntfy_payload_contains_job_summary_and_click_link() {
+    let notification = notification_fixture();
+    let payload = ntfy_payload(&ntfy_config(), &notification);
+
+    assert_eq!(payload["topic"], "jobsentinel-alerts_42");
+    assert_eq!(payload["title"], "95% match: Care Coordinator");
+    assert_eq!(payload["priority"], 3);
+    assert_eq!(payload["click"], "https://example.com/jobs/123");
+    let message = payload["message"].as_str().unwrap();
+    assert!(message.contains("Community Care Network • Remote"));
+    assert!(message.contains("$180,000 - $220,000"));
+    assert!(message.contains("Remote: Yes"));
+}
+
+#[test]
+fn test_ntfy_payload_clamps_priority_and_omits_unsafe_links() {
+    let mut notification = notification_fixture();
+    notification.job.url = "javascript:alert(1)".to_string();
+    let config = NtfyConfig {
+        priority: 9,
+        ..ntfy_config()
+    };
+
+    let payload = ntfy_payload(&config, &notification);
+
+    assert_eq!(payload["priority"], 5);
+    assert!(payload.get("click").is_none());
+}
+
+#[test]
+fn test_ntfy_payload_keeps_match_reasons_local() {
+    let notification = notification_fixture();
+    let payload = ntfy_payload(&ntfy_config(), &notification).to_string();
+
+    assert!(!payload.contains("Keyword match: case management"));
+    assert!(!payload.contains("Title matches"));
+}
+
+#[test]
+fn test_ntfy_topic_validation() {
+    assert!(validate_topic("jobsentinel-alerts_42").is_ok());
+    assert!(validate_topic("").is_err());
+    assert!(validate_topic("has space").is_err());
+    assert!(validate_topic("a/b").is_err());
+    assert!(validate_topic(&"a".repeat(65)).is_err());
+}
+
+#[test]
+fn test_ntfy_server_url_must_be_public_https() {
+    assert!(validate_server_url("https://ntfy.sh").is_ok());
+    assert!(validate_server_url("https://ntfy.example.com/").is_ok());
+    assert!(validate_server_url("http://ntfy.sh").is_err());
+    assert!(validate_server_url("https://localhost").is_err());
+    assert!(validate_server_url("https://192.168.1.10").is_err());
+}
+
+#[test]
+fn test_ntfy_debug_redacts_topic_and_token() {
+    let config = NtfyConfig {
+        access_token: "tk_secretsecretsecretsecretsecret".to_string(),
+        ..ntfy_config()
+    };
+
+    let debug = format!("{config:?}");
+    assert!(!debug.contains("jobsentinel-alerts_42"));
+    assert!(!debug.contains("tk_secret"));
+}
@@ -0,0 +1,223 @@
+//! Minimal PDF writer for text reports
+//!
+//! Lays out a title, headings, and lines of text on US Letter pages with the
+//! standard Helvetica fonts, so nothing is embedded and no PDF library is
+//! needed. Characters Windows-1252 cannot show are written as "?".
+
+const PAGE_WIDTH: u32 = 612;
+const PAGE_HEIGHT: u32 = 792;
+const MARGIN: u32 = 54;
+
+/// One line of a text document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PdfLine {
+    Title(String),
+    Heading(String),
+    Text(String),
+    Blank,
+}
+
+impl PdfLine {
+    /// Font resource, font size, line height, and wrap width in characters
+    const fn style(&self) -> (&'static str, u32, u32, usize) {
+        match self {
+            Self::Title(_) => ("F2", 18, 26, 50),
+            Self::Heading(_) => ("F2", 13, 20, 70),
+            Self::Text(_) | Self::Blank => ("F1", 10, 14, 95),
+        }
+    }
+
+    fn text(&self) -> &str {
+        match self {
+            Self::Title(text) | Self::Heading(text) | Self::Text(text) => text,
+            Self::Blank => "",
+        }
+    }
+}
+
+/// Split `text` into lines of at most `width` characters at spaces
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Windows-1252 byte for a character, if it has one
+fn win_ansi_byte(c: char) -> Option<u8> {
+    match c {
+        ' '..='~' | '\u{a0}'..='\u{ff}' => u8::try_from(c).ok(),
+        '€' => Some(0x80),
+        '‘' => Some(0x91),
+        '’' => Some(0x92),
+        '“' => Some(0x93),
+        '”' => Some(0x94),
+        '•' => Some(0x95),
+        '–' => Some(0x96),
+        '—' => Some(0x97),
+        _ => None,
+    }
+}
+
+/// A PDF literal string, escaped and encoded as Windows-1252
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut out = vec![b'('];
+    for c in text.chars() {
+        let byte = win_ansi_byte(c).unwrap_or(b'?');
+        if matches!(byte, b'(' | b')' | b'\\') {
+            out.push(b'\\');
+        }
+        out.push(byte);
+    }
+    out.push(b')');
+    out
+}
+
+/// Content streams for each page
+fn page_streams(lines: &[PdfLine]) -> Vec<Vec<u8>> {
+    let mut pages = Vec::new();
+    let mut stream = Vec::new();
+    let mut y = PAGE_HEIGHT - MARGIN;
+
+    for line in lines {
+        let (font, size, height, width) = line.style();
+        for text in wrap(line.text(), width) {
+            if y < MARGIN + height {
+                pages.push(std::mem::take(&mut stream));
+                y = PAGE_HEIGHT - MARGIN;
+            }
+            y -= height;
+            if text.is_empty() {
+                continue;
+            }
+            stream.extend_from_slice(format!("BT /{font} {size} Tf {MARGIN} {y} Td ").as_bytes());
+            stream.extend(pdf_string(&text));
+            stream.extend_from_slice(b" Tj ET\n");
+        }
+    }
+    pages.push(stream);
+    pages
+}
+
+/// Render `lines` as a PDF document
+pub(crate) fn render_pdf(lines: &[PdfLine]) -> Vec<u8> {
+    let pages = page_streams(lines);
+    let first_page_id = 5;
+    let kids = (0..pages.len())
+        .map(|index| format!("{} 0 R", first_page_id + index * 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    ];
+    for (index, stream) in pages.into_iter().enumerate() {
+        let contents_id = first_page_id + index * 2 + 1;
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {contents_id} 0 R >>"
+            )
+            .into_bytes(),
+        );
+        let mut contents = format!("<< /Length {} >>\nstream\n", stream.len()).into_bytes();
+        contents.extend(stream);
+        contents.extend_from_slice(b"\nendstream");
+        objects.push(contents);
+    }
+
+    let mut pdf = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pdf_string, render_pdf, wrap, PdfLine};
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn renders_a_document_with_a_valid_trailer() {
+        let pdf = render_pdf(&[
+            PdfLine::Title("Market report".to_string()),
+            PdfLine::Blank,
+            PdfLine::Text("Rust (up 12%)".to_string()),
+        ]);
+
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(contains(&pdf, b"/Count 1"));
+        assert!(contains(&pdf, b"(Market report) Tj"));
+        assert!(contains(&pdf, b"(Rust \\(up 12%\\)) Tj"));
+
+        let text = String::from_utf8_lossy(&pdf);
+        let xref_offset: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|rest| rest.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[xref_offset..].starts_with(b"xref\n"));
+    }
+
+    #[test]
+    fn long_documents_continue_on_new_pages() {
+        let lines = vec![PdfLine::Text("A line of text".to_string()); 120];
+
+        let pdf = render_pdf(&lines);
+
+        assert!(contains(&pdf, b"/Count 3"));
+    }
+
+    #[test]
+    fn text_is_wrapped_and_encoded_for_standard_fonts() {
+        assert_eq!(
+            wrap("one two three four", 9),
+            vec!["one two", "three", "four"]
+        );
+        assert_eq!(wrap("", 10), vec![String::new()]);
+        assert_eq!(pdf_string("Zürich – 日本"), b"(Z\xfcrich \x96 ??)".to_vec());
+    }
+}
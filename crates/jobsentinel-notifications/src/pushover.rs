@@ -0,0 +1,110 @@
+//! Pushover Push Notifications
+//!
+//! Sends job alerts through the Pushover messages API.
+
+use super::{
+    format_salary_range, notification_job_href, notification_provider_failure_summary,
+    Notification, NOTIFICATION_HTTP_TIMEOUT,
+};
+use crate::PushoverConfig;
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+const PUSHOVER_MESSAGES_URL: &str = "https://api.pushover.net/1/messages.json";
+
+/// Validate a Pushover user key or application token
+///
+/// Both are 30 letters and digits.
+fn validate_key(value: &str, label: &str) -> Result<()> {
+    if value.is_empty() {
+        return Err(anyhow!("Pushover {label} cannot be empty"));
+    }
+
+    if value.len() != 30 || !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(anyhow!(
+            "Invalid Pushover {label}: expected 30 letters and numbers"
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_keys(config: &PushoverConfig) -> Result<()> {
+    validate_key(&config.app_token, "application token")?;
+    validate_key(&config.user_key, "user key")
+}
+
+/// Build the messages API body for a job alert
+fn pushover_payload(config: &PushoverConfig, notification: &Notification) -> Value {
+    let job = &notification.job;
+    let remote = if job.remote.unwrap_or(false) {
+        "Yes"
+    } else {
+        "No"
+    };
+    let message = format!(
+        "{} • {}\nSalary: {}\nRemote: {}",
+        job.company,
+        job.location.as_deref().unwrap_or("N/A"),
+        format_salary_range(job.salary_min, job.salary_max),
+        remote
+    );
+
+    let mut payload = json!({
+        "token": config.app_token,
+        "user": config.user_key,
+        "title": format!("{}: {}", notification.match_summary(), job.title),
+        "message": message,
+        "priority": config.priority.clamp(-2, 1)
+    });
+    if let Some(href) = notification_job_href(&job.url) {
+        payload["url"] = json!(href);
+        payload["url_title"] = json!("View Job");
+    }
+    payload
+}
+
+/// Send Pushover notification
+pub async fn send_pushover_notification(
+    config: &PushoverConfig,
+    notification: &Notification,
+) -> Result<()> {
+    validate_keys(config)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        PUSHOVER_MESSAGES_URL,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &pushover_payload(config, notification),
+    )
+    .await
+    .map_err(|error| anyhow!("Pushover API request failed: {error}"))?;
+
+    if !(200..300).contains(&response.status) {
+        let error_summary = notification_provider_failure_summary(&response);
+        return Err(anyhow!("Pushover API failed: {}", error_summary));
+    }
+
+    Ok(())
+}
+
+/// Validate Pushover keys by sending a test message
+pub async fn validate_config(config: &PushoverConfig) -> Result<bool> {
+    validate_keys(config)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        PUSHOVER_MESSAGES_URL,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &json!({
+            "token": config.app_token,
+            "user": config.user_key,
+            "message": "JobSentinel: Pushover alerts are connected ✅"
+        }),
+    )
+    .await
+    .map_err(|error| anyhow!("Pushover validation failed: {error}"))?;
+
+    Ok((200..300).contains(&response.status))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::test_support::notification_fixture;
+
+const APP_TOKEN: &str = "azGDORePK8gMaC0QOYAMyEEuzJnyUi";
+const USER_KEY: &str = "uQiRzpo4DXghDmr9QzzfQu27cmVRsG";
+
+fn pushover_config() -> PushoverConfig {
+    PushoverConfig {
+        enabled: true,
+        priority: 0,
+        user_key: USER_KEY.to_string(),
+        app_token: APP_TOKEN.to_string(),
+    }
+}
+
+#[test]
+fn test_pushover_payload_contains_keys_job_summary_and_link() {
+    let notification = notification_fixture();
+    let payload = pushover_payload(&pushover_config(), &notification);
+
+    assert_eq!(payload["token"], APP_TOKEN);
+    assert_eq!(payload["user"], USER_KEY);
+    assert_eq!(payload["title"], "95% match: Care Coordinator");
+    assert_eq!(payload["priority"], 0);
+    assert_eq!(payload["url"], "https://example.com/jobs/123");
+    assert_eq!(payload["url_title"], "View Job");
+    let message = payload["message"].as_str().unwrap();
+    assert!(message.contains("Community Care Network • Remote"));
+    assert!(message.contains("Remote: Yes"));
+}
+
+#[test]
+fn test_pushover_payload_clamps_priority_and_handles_missing_details() {
+    let mut notification = notification_fixture();
+    notification.job.location = None;
+    notification.job.salary_min = None;
+    notification.job.salary_max = None;
+    notification.job.url = "javascript:alert(1)".to_string();
+    let config = PushoverConfig {
+        priority: 2,
+        ..pushover_config()
+    };
+
+    let payload = pushover_payload(&config, &notification);
+
+    // Priority 2 needs retry settings, so it is capped at high priority.
+    assert_eq!(payload["priority"], 1);
+    assert!(payload.get("url").is_none());
+    let message = payload["message"].as_str().unwrap();
+    assert!(message.contains("N/A"));
+    assert!(message.contains("Not specified"));
+}
+
+#[test]
+fn test_pushover_key_validation() {
+    assert!(validate_keys(&pushover_config()).is_ok());
+
+    let short = PushoverConfig {
+        user_key: "short".to_string(),
+        ..pushover_config()
+    };
+    assert!(validate_keys(&short).is_err());
+
+    let symbols = PushoverConfig {
+        app_token: "azGDORePK8gMaC0QOYAMyEEuzJny-!".to_string(),
+        ..pushover_config()
+    };
+    assert!(validate_keys(&symbols).is_err());
+
+    let missing = PushoverConfig {
+        app_token: String::new(),
+        ..pushover_config()
+    };
+    assert!(validate_keys(&missing)
+        .unwrap_err()
+        .to_string()
+        .contains("application token cannot be empty"));
+}
+
+#[test]
+fn test_pushover_debug_redacts_keys() {
+    let debug = format!("{:?}", pushover_config());
+
+    assert!(!debug.contains(APP_TOKEN));
+    assert!(!debug.contains(USER_KEY));
+}
@@ -0,0 +1,269 @@
+//! Weekly Summary Reports
+//!
+//! One report per week covering new jobs, top matches, application progress,
+//! and market alerts. It is rendered as Markdown for Slack, Discord, and the
+//! saved copy, and as HTML for email.
+
+use super::{format_salary_range, notification_job_href};
+use chrono::{DateTime, Local, Utc};
+use jobsentinel_domain::Job;
+use jobsentinel_security::encode_html_text as escape_html;
+use serde::{Deserialize, Serialize};
+
+/// Everything a weekly report covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyReport {
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    /// Jobs found during the period
+    pub new_jobs: i64,
+    /// Best scoring jobs found during the period, best first
+    pub top_matches: Vec<Job>,
+    pub applications: ReportApplicationActivity,
+    pub market_alerts: Vec<ReportMarketAlert>,
+}
+
+/// Application tracking activity during the report period
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportApplicationActivity {
+    pub applied: i64,
+    pub interviews: i64,
+    pub offers: i64,
+    pub rejections: i64,
+    /// Applications still in progress at the end of the period
+    pub active: i64,
+}
+
+/// A market alert raised during the report period
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportMarketAlert {
+    pub title: String,
+    pub description: String,
+    /// `info`, `warning`, or `critical`
+    pub severity: String,
+}
+
+impl WeeklyReport {
+    /// Email subject and report heading
+    #[must_use]
+    pub fn subject(&self) -> String {
+        format!(
+            "JobSentinel weekly report: {} new {}",
+            self.new_jobs,
+            if self.new_jobs == 1 { "job" } else { "jobs" }
+        )
+    }
+
+    /// The report period in local dates, such as "Oct 5 - Oct 12, 2026"
+    #[must_use]
+    pub fn period_label(&self) -> String {
+        let start = self.period_start.with_timezone(&Local);
+        let end = self.period_end.with_timezone(&Local);
+        format!("{} - {}", start.format("%b %-d"), end.format("%b %-d, %Y"))
+    }
+}
+
+fn score_percent(job: &Job) -> f64 {
+    (job.score.unwrap_or_default() * 100.0).round()
+}
+
+/// Escape characters Markdown would treat as formatting
+pub(crate) fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#' | '~' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn application_lines(activity: &ReportApplicationActivity) -> [(&'static str, i64); 5] {
+    [
+        ("Applied", activity.applied),
+        ("Moved to interviews", activity.interviews),
+        ("Offers", activity.offers),
+        ("Rejections", activity.rejections),
+        ("Still in progress", activity.active),
+    ]
+}
+
+/// Format a report as Markdown
+#[must_use]
+pub fn format_report_markdown(report: &WeeklyReport) -> String {
+    let mut out = format!(
+        "# JobSentinel weekly report\n{}\n\n## New jobs\n{} new {} found this week.\n\n## Top matches\n",
+        report.period_label(),
+        report.new_jobs,
+        if report.new_jobs == 1 { "job" } else { "jobs" },
+    );
+
+    if report.top_matches.is_empty() {
+        out.push_str("No new matches this week.\n");
+    }
+    for (rank, job) in report.top_matches.iter().enumerate() {
+        out.push_str(&format!(
+            "{}. **{}** at {} ({:.0}% match, {})\n",
+            rank + 1,
+            escape_markdown(&job.title),
+            escape_markdown(&job.company),
+            score_percent(job),
+            format_salary_range(job.salary_min, job.salary_max),
+        ));
+        if let Some(href) = notification_job_href(&job.url) {
+            out.push_str(&format!("   <{href}>\n"));
+        }
+    }
+
+    out.push_str("\n## Applications\n");
+    for (label, count) in application_lines(&report.applications) {
+        out.push_str(&format!("- {label}: {count}\n"));
+    }
+
+    out.push_str("\n## Market alerts\n");
+    if report.market_alerts.is_empty() {
+        out.push_str("No market alerts this week.\n");
+    }
+    for alert in &report.market_alerts {
+        out.push_str(&format!(
+            "- **{}** ({}): {}\n",
+            escape_markdown(&alert.title),
+            escape_markdown(&alert.severity),
+            escape_markdown(&alert.description),
+        ));
+    }
+
+    out
+}
+
+/// Format a report as an HTML email
+#[must_use]
+pub fn format_report_html(report: &WeeklyReport) -> String {
+    let section = |title: &str, body: String| {
+        format!(
+            r#"
+        <h2 style="font-size: 18px; margin: 24px 0 8px 0;">{title}</h2>{body}"#
+        )
+    };
+    let empty = |message: &str| {
+        format!(
+            r#"
+        <p style="color: #6b7280; margin: 0;">{message}</p>"#
+        )
+    };
+
+    let matches = if report.top_matches.is_empty() {
+        empty("No new matches this week.")
+    } else {
+        let items: String = report
+            .top_matches
+            .iter()
+            .map(|job| {
+                let title = escape_html(&job.title);
+                let title = match notification_job_href(&job.url) {
+                    Some(href) => format!(
+                        r#"<a href="{}" style="color: #1d4ed8; text-decoration: none; font-weight: 600;">{title}</a>"#,
+                        escape_html(&href)
+                    ),
+                    None => format!(r#"<span style="font-weight: 600;">{title}</span>"#),
+                };
+                format!(
+                    r#"
+            <li style="margin-bottom: 8px;">{} &middot; {:.0}%<br><span style="color: #6b7280; font-size: 14px;">{} &middot; {}</span></li>"#,
+                    title,
+                    score_percent(job),
+                    escape_html(&job.company),
+                    escape_html(&format_salary_range(job.salary_min, job.salary_max)),
+                )
+            })
+            .collect();
+        format!(
+            r#"
+        <ol style="padding-left: 20px; margin: 0;">{items}
+        </ol>"#
+        )
+    };
+
+    let applications: String = application_lines(&report.applications)
+        .iter()
+        .map(|(label, count)| {
+            format!(
+                r#"
+            <tr><td style="padding: 4px 0;">{label}</td><td style="padding: 4px 0; text-align: right;"><strong>{count}</strong></td></tr>"#
+            )
+        })
+        .collect();
+    let applications = format!(
+        r#"
+        <table style="width: 100%; border-collapse: collapse;">{applications}
+        </table>"#
+    );
+
+    let alerts = if report.market_alerts.is_empty() {
+        empty("No market alerts this week.")
+    } else {
+        let items: String = report
+            .market_alerts
+            .iter()
+            .map(|alert| {
+                format!(
+                    r#"
+            <li style="margin-bottom: 8px;"><strong>{}</strong> ({})<br>{}</li>"#,
+                    escape_html(&alert.title),
+                    escape_html(&alert.severity),
+                    escape_html(&alert.description),
+                )
+            })
+            .collect();
+        format!(
+            r#"
+        <ul style="padding-left: 20px; margin: 0;">{items}
+        </ul>"#
+        )
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Weekly Report</title>
+</head>
+<body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, 'Helvetica Neue', Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px;">
+    <div style="background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 24px; border-radius: 8px 8px 0 0; text-align: center;">
+        <h1 style="margin: 0; font-size: 22px;">Your weekly report</h1>
+        <p style="margin: 8px 0 0 0; opacity: 0.9;">{}</p>
+    </div>
+
+    <div style="background: #f9fafb; padding: 24px; border-radius: 0 0 8px 8px; border: 1px solid #e5e7eb; border-top: none;">{}{}{}{}
+
+        <div style="text-align: center; margin-top: 20px; color: #6b7280; font-size: 12px;">
+            <p>You can change or turn off weekly reports in the JobSentinel app settings</p>
+        </div>
+    </div>
+</body>
+</html>"#,
+        escape_html(&report.period_label()),
+        section(
+            "New jobs",
+            format!(
+                r#"
+        <p style="margin: 0;">{} new {} found this week.</p>"#,
+                report.new_jobs,
+                if report.new_jobs == 1 { "job" } else { "jobs" },
+            ),
+        ),
+        section("Top matches", matches),
+        section("Applications", applications),
+        section("Market alerts", alerts),
+    )
+}
+
+#[cfg(test)]
+#[path = "report_tests.rs"]
+mod tests;
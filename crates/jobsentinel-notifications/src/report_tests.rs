@@ -0,0 +1,77 @@
+use super::*;
+use crate::test_support::notification_fixture;
+
+fn report_fixture() -> WeeklyReport {
+    let now = Utc::now();
+    WeeklyReport {
+        period_start: now - chrono::Duration::weeks(1),
+        period_end: now,
+        new_jobs: 12,
+        top_matches: vec![notification_fixture().job],
+        applications: ReportApplicationActivity {
+            applied: 3,
+            interviews: 1,
+            offers: 0,
+            rejections: 2,
+            active: 4,
+        },
+        market_alerts: vec![ReportMarketAlert {
+            title: "Demand for <care> roles up".to_string(),
+            description: "More listings than last week".to_string(),
+            severity: "info".to_string(),
+        }],
+    }
+}
+
+#[test]
+fn test_markdown_report_covers_every_section() {
+    let markdown = format_report_markdown(&report_fixture());
+
+    assert!(markdown.starts_with("# JobSentinel weekly report\n"));
+    assert!(markdown.contains("12 new jobs found this week."));
+    assert!(markdown.contains(
+        "1. **Care Coordinator** at Community Care Network (95% match, $180,000 - $220,000)"
+    ));
+    assert!(markdown.contains("   <https://example.com/jobs/123>"));
+    assert!(markdown.contains("- Applied: 3\n"));
+    assert!(markdown.contains("- Still in progress: 4\n"));
+    assert!(markdown.contains("- **Demand for \\<care\\> roles up** (info)"));
+}
+
+#[test]
+fn test_markdown_report_notes_empty_sections() {
+    let report = WeeklyReport {
+        new_jobs: 1,
+        top_matches: Vec::new(),
+        market_alerts: Vec::new(),
+        ..report_fixture()
+    };
+    let markdown = format_report_markdown(&report);
+
+    assert!(markdown.contains("1 new job found this week."));
+    assert!(markdown.contains("No new matches this week."));
+    assert!(markdown.contains("No market alerts this week."));
+    assert_eq!(report.subject(), "JobSentinel weekly report: 1 new job");
+}
+
+#[test]
+fn test_html_report_escapes_job_and_alert_text() {
+    let mut report = report_fixture();
+    report.top_matches[0].title = "<script>alert(1)</script>".to_string();
+    let html = format_report_html(&report);
+
+    assert!(html.contains("&lt;script&gt;"));
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("Demand for &lt;care&gt; roles up"));
+    assert!(html.contains(r#"href="https://example.com/jobs/123""#));
+    assert!(html.contains("<strong>3</strong>"));
+}
+
+#[test]
+fn test_escape_markdown_escapes_formatting_characters() {
+    assert_eq!(
+        escape_markdown("C++ *lead* [remote]"),
+        "C++ \\*lead\\* \\[remote\\]"
+    );
+    assert_eq!(escape_markdown("plain"), "plain");
+}
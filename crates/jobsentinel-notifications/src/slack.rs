@@ -2,6 +2,7 @@
 //!
 //! Sends rich-formatted job alerts to Slack via incoming webhooks.
 
+use super::template::render_message_template;
 use super::{
     notification_job_href, Notification, LOCAL_MATCH_DETAILS_MESSAGE, NOTIFICATION_HTTP_TIMEOUT,
 };
@@ -34,7 +35,7 @@ fn build_fields_section_block(notification: &Notification) -> serde_json::Value
     let job = &notification.job;
     let score = &notification.score;
 
-    json!({
+    let mut block = json!({
         "type": "section",
         "fields": [
             {
@@ -54,7 +55,17 @@ fn build_fields_section_block(notification: &Notification) -> serde_json::Value
                 "text": format!("*Source:*\n{}", job.source)
             }
         ]
-    })
+    });
+    if let (Some(resume_match), Some(fields)) = (
+        notification.resume_match_percent(),
+        block["fields"].as_array_mut(),
+    ) {
+        fields.push(json!({
+            "type": "mrkdwn",
+            "text": format!("*Resume match:*\n{resume_match}")
+        }));
+    }
+    block
 }
 
 /// Build match details section without exporting private local scoring reasons.
@@ -114,13 +125,41 @@ fn build_slack_payload(notification: &Notification) -> serde_json::Value {
     json!({ "blocks": blocks })
 }
 
+/// Build a Slack message from the user's message template
+fn build_templated_slack_payload(template: &str, notification: &Notification) -> serde_json::Value {
+    let text = render_message_template(template, notification);
+    let mut blocks = vec![json!({
+        "type": "section",
+        "text": {
+            "type": "mrkdwn",
+            "text": text
+        }
+    })];
+
+    if let Some(actions) = build_actions_block(&notification.job.url) {
+        blocks.push(actions);
+    }
+
+    json!({ "text": text, "blocks": blocks })
+}
+
 /// Send Slack notification
-pub async fn send_slack_notification(webhook_url: &str, notification: &Notification) -> Result<()> {
+///
+/// With a message template, the message is the rendered template and a
+/// "View Job" button instead of the default layout.
+pub async fn send_slack_notification(
+    webhook_url: &str,
+    notification: &Notification,
+    template: Option<&str>,
+) -> Result<()> {
     // Validate webhook URL before sending
     validate_webhook_url(webhook_url)?;
 
     // Build Slack message with blocks
-    let payload = build_slack_payload(notification);
+    let payload = match template {
+        Some(template) => build_templated_slack_payload(template, notification),
+        None => build_slack_payload(notification),
+    };
 
     // Send POST request to Slack webhook with DNS/IP validation and pinned resolution.
     let response = jobsentinel_network::post_external_https_json(
@@ -138,6 +177,66 @@ pub async fn send_slack_notification(webhook_url: &str, notification: &Notificat
     Ok(())
 }
 
+/// Convert report Markdown to Slack mrkdwn
+///
+/// Headings and `**bold**` become `*bold*`, backslash escapes are dropped,
+/// and `&`, `<`, and `>` in text are escaped. `<url>` links pass through.
+fn markdown_to_mrkdwn(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    for line in markdown.lines() {
+        match line.strip_prefix("## ").or_else(|| line.strip_prefix("# ")) {
+            Some(heading) => {
+                out.push('*');
+                push_mrkdwn_inline(&mut out, heading);
+                out.push('*');
+            }
+            None => push_mrkdwn_inline(&mut out, line),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn push_mrkdwn_inline(out: &mut String, text: &str) {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('&') => out.push_str("&amp;"),
+                Some('<') => out.push_str("&lt;"),
+                Some('>') => out.push_str("&gt;"),
+                Some(escaped) => out.push(escaped),
+                None => {}
+            },
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                out.push('*');
+            }
+            '&' => out.push_str("&amp;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Send a Markdown message, such as a weekly report
+pub async fn send_slack_report(webhook_url: &str, markdown: &str) -> Result<()> {
+    validate_webhook_url(webhook_url)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        webhook_url,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &json!({ "text": markdown_to_mrkdwn(markdown) }),
+    )
+    .await
+    .map_err(|error| anyhow!("Slack webhook request failed: {error}"))?;
+
+    if !(200..300).contains(&response.status) {
+        return Err(anyhow!("Slack webhook failed: {}", response.status));
+    }
+
+    Ok(())
+}
+
 /// Validate Slack webhook URL
 pub async fn validate_webhook(webhook_url: &str) -> Result<bool> {
     // First validate the URL format
@@ -467,3 +467,17 @@ fn test_builder_functions_integration() {
 // but we've tested all the validation and JSON construction logic.
 // In a production environment, you could use `mockito` or `wiremock` crates
 // to mock HTTP responses.
+
+#[test]
+fn test_build_templated_slack_payload_uses_rendered_text() {
+    let notification = notification_fixture();
+    let payload = build_templated_slack_payload(
+        "*{{title}}* at {{company}} ({{score_percent}}%)",
+        &notification,
+    );
+
+    let text = "*Care Coordinator* at Community Care Network (95%)";
+    assert_eq!(payload["text"], text);
+    assert_eq!(payload["blocks"][0]["text"]["text"], text);
+    assert_eq!(payload["blocks"][1]["type"], "actions");
+}
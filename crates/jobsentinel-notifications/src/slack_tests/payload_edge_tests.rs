@@ -352,3 +352,15 @@ fn test_notification_reasons_section_title_bold() {
     let reasons_text = payload["blocks"][0]["text"]["text"].as_str().unwrap();
     assert!(reasons_text.starts_with("*Why this matches:*"));
 }
+
+#[test]
+fn test_report_markdown_converts_to_mrkdwn() {
+    let mrkdwn = markdown_to_mrkdwn(
+        "# Weekly report\n1. **Staff \\*Engineer\\*** at A \\& B\n   <https://example.com/jobs?a=1&b=2>\n- **Alert \\<x\\>**",
+    );
+
+    assert_eq!(
+        mrkdwn,
+        "*Weekly report*\n1. *Staff *Engineer** at A &amp; B\n   <https://example.com/jobs?a=1&amp;b=2>\n- *Alert &lt;x&gt;*\n"
+    );
+}
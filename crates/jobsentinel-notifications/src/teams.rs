@@ -57,6 +57,16 @@ fn build_teams_payload(notification: &Notification) -> serde_json::Value {
         ]
     });
 
+    if let (Some(resume_match), Some(facts)) = (
+        notification.resume_match_percent(),
+        payload["sections"][0]["facts"].as_array_mut(),
+    ) {
+        facts.push(json!({
+            "name": "Resume Match:",
+            "value": resume_match
+        }));
+    }
+
     if let Some(href) = notification_job_href(&job.url) {
         payload["potentialAction"] = json!([
              {
@@ -112,6 +122,26 @@ pub async fn send_teams_notification(webhook_url: &str, notification: &Notificat
     Ok(())
 }
 
+/// Validate a Teams webhook by sending a test message
+pub async fn validate_webhook(webhook_url: &str) -> Result<bool> {
+    validate_webhook_url(webhook_url)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        webhook_url,
+        NOTIFICATION_HTTP_TIMEOUT,
+        &json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": "JobSentinel",
+            "text": "JobSentinel: Webhook validation successful ✅"
+        }),
+    )
+    .await
+    .map_err(|error| anyhow!("Teams webhook validation failed: {error}"))?;
+
+    Ok((200..300).contains(&response.status))
+}
+
 #[cfg(test)]
 #[path = "teams_tests.rs"]
 mod tests;
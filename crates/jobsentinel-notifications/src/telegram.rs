@@ -99,17 +99,8 @@ pub async fn send_telegram_notification(
     validate_bot_token(&config.bot_token)?;
     validate_chat_id(&config.chat_id)?;
 
-    let job = &notification.job;
-    let score = &notification.score;
-
     // Format message using Telegram's MarkdownV2 format
-    let message = format_telegram_message(job, score);
-
-    // Build Telegram Bot API request
-    let api_url = format!(
-        "https://api.telegram.org/bot{}/sendMessage",
-        config.bot_token
-    );
+    let message = format_telegram_message(notification);
 
     let payload = json!({
         "chat_id": config.chat_id,
@@ -121,7 +112,7 @@ pub async fn send_telegram_notification(
 
     // Send POST request to Telegram Bot API with DNS/IP validation and pinned resolution.
     let response = jobsentinel_network::post_external_https_json(
-        &api_url,
+        &send_message_url(&config.bot_token),
         NOTIFICATION_HTTP_TIMEOUT,
         &payload,
     )
@@ -136,13 +127,36 @@ pub async fn send_telegram_notification(
     Ok(())
 }
 
+/// Validate a Telegram bot token and chat ID by sending a test message
+pub async fn validate_bot(config: &TelegramConfig) -> Result<bool> {
+    validate_bot_token(&config.bot_token)?;
+    validate_chat_id(&config.chat_id)?;
+
+    let response = jobsentinel_network::post_external_https_json(
+        &send_message_url(&config.bot_token),
+        NOTIFICATION_HTTP_TIMEOUT,
+        &json!({
+            "chat_id": config.chat_id,
+            "text": "JobSentinel: Telegram alerts are connected ✅"
+        }),
+    )
+    .await
+    .map_err(|error| anyhow!("Telegram bot validation failed: {error}"))?;
+
+    Ok((200..300).contains(&response.status))
+}
+
+/// Bot API `sendMessage` endpoint for `bot_token`
+fn send_message_url(bot_token: &str) -> String {
+    format!("https://api.telegram.org/bot{bot_token}/sendMessage")
+}
+
 /// Format message for Telegram using MarkdownV2
 ///
 /// Note: MarkdownV2 requires escaping these characters: _*[]()~`>#+-=|{}.!
-fn format_telegram_message(
-    job: &jobsentinel_domain::Job,
-    score: &jobsentinel_intelligence::JobScore,
-) -> String {
+fn format_telegram_message(notification: &Notification) -> String {
+    let job = &notification.job;
+    let score = &notification.score;
     // Helper to escape MarkdownV2 special characters
     let escape = |s: &str| -> String {
         s.chars()
@@ -169,6 +183,10 @@ fn format_telegram_message(
     };
 
     let score_percent = escape(&format!("{:.0}%", score.total * 100.0));
+    let resume_match = notification
+        .resume_match_percent()
+        .map(|percent| format!("\n*Resume match:* {}", escape(&percent)))
+        .unwrap_or_default();
 
     let reasons = format!("  {}", escape(LOCAL_MATCH_DETAILS_MESSAGE));
     let job_link = notification_job_href(&job.url)
@@ -186,7 +204,7 @@ fn format_telegram_message(
 *Location:* {}
 *Salary:* {}
 *Source:* {}
-*Remote:* {}
+*Remote:* {}{}
 
 *Why this matches:*
 {}
@@ -202,6 +220,7 @@ _Sent by JobSentinel • Job Search Assistant_"#,
         salary_display,
         source,
         remote,
+        resume_match,
         reasons,
         job_link
     )
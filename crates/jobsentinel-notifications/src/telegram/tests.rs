@@ -4,7 +4,7 @@ use crate::test_support::notification_fixture;
 #[test]
 fn test_telegram_message_formatting() {
     let notification = notification_fixture();
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     // Verify key components are present (with escaped special chars)
     assert!(message.contains("Care Coordinator"));
@@ -14,12 +14,23 @@ fn test_telegram_message_formatting() {
     assert!(message.contains("https://example.com/jobs/123"));
 }
 
+#[test]
+fn test_telegram_message_includes_resume_match() {
+    let mut notification = notification_fixture();
+    assert!(!format_telegram_message(&notification).contains("Resume match"));
+
+    notification.resume_match = Some(0.78);
+    let message = format_telegram_message(&notification);
+
+    assert!(message.contains("*Resume match:* 78%"));
+}
+
 #[test]
 fn test_telegram_escapes_special_characters() {
     let mut notification = notification_fixture();
     notification.job.title = "Care Coordinator (Remote)".to_string();
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     // Parentheses should be escaped in MarkdownV2
     assert!(message.contains("\\(") && message.contains("\\)"));
@@ -30,7 +41,7 @@ fn test_telegram_handles_missing_location() {
     let mut notification = notification_fixture();
     notification.job.location = None;
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(message.contains("N/A"));
 }
 
@@ -40,14 +51,14 @@ fn test_telegram_handles_missing_salary() {
     notification.job.salary_min = None;
     notification.job.salary_max = None;
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(message.contains("Not specified"));
 }
 
 #[test]
 fn test_telegram_keeps_match_reasons_local() {
     let notification = notification_fixture();
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     assert!(message.contains("Open JobSentinel to review match details saved on this computer"));
     assert!(!message.contains("Title matches"));
@@ -100,7 +111,7 @@ fn test_escape_function_preserves_normal_chars() {
 #[test]
 fn test_remote_badge_yes() {
     let notification = notification_fixture();
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     assert!(message.contains("✅ Yes"));
 }
@@ -110,7 +121,7 @@ fn test_remote_badge_no() {
     let mut notification = notification_fixture();
     notification.job.remote = Some(false);
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     assert!(message.contains("❌ No"));
 }
@@ -242,7 +253,7 @@ fn test_validate_chat_id_alphanumeric() {
 #[test]
 fn test_telegram_message_structure() {
     let notification = notification_fixture();
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     // Verify key sections are present
     assert!(message.contains("🎯 *High Match Job Alert*"));
@@ -262,7 +273,7 @@ fn test_telegram_message_minimizes_job_url() {
         "https://example.com/jobs?utm_source=alert&gh_jid=123&token=secret&candidate_email=person@example.com#private"
             .to_string();
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     assert!(message.contains("[View Full Job Posting](https://example.com/jobs?gh_jid=123)"));
     assert!(!message.contains("utm_source"));
@@ -275,7 +286,7 @@ fn test_telegram_message_minimizes_job_url() {
 #[test]
 fn test_telegram_message_salary_with_range() {
     let notification = notification_fixture();
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
 
     assert!(message.contains("$180,000 \\- $220,000") || message.contains("180,000"));
 }
@@ -285,7 +296,7 @@ fn test_telegram_message_salary_min_only() {
     let mut notification = notification_fixture();
     notification.job.salary_max = None;
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(message.contains("180,000+") || message.contains("180,000\\+"));
 }
 
@@ -314,7 +325,7 @@ fn test_telegram_escape_special_chars_comprehensive() {
 #[test]
 fn test_telegram_api_url_format() {
     let bot_token = "123456789:ABCdefGHIjklMNOpqrsTUVwxyz";
-    let expected_url = format!("https://api.telegram.org/bot{}/sendMessage", bot_token);
+    let expected_url = send_message_url(bot_token);
 
     assert!(expected_url.starts_with("https://api.telegram.org/bot"));
     assert!(expected_url.ends_with("/sendMessage"));
@@ -326,7 +337,7 @@ fn test_remote_badge_with_none() {
     let mut notification = notification_fixture();
     notification.job.remote = None;
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(
         message.contains("❌ No"),
         "None remote should default to No"
@@ -338,7 +349,7 @@ fn test_telegram_message_empty_reasons() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec![];
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(
         message.contains("*Why this matches:*"),
         "Should have header even with empty reasons"
@@ -350,7 +361,7 @@ fn test_telegram_message_single_reason() {
     let mut notification = notification_fixture();
     notification.score.reasons = vec!["Only one reason".to_string()];
 
-    let message = format_telegram_message(&notification.job, &notification.score);
+    let message = format_telegram_message(&notification);
     assert!(message.contains("Open JobSentinel to review match details saved on this computer"));
     assert!(!message.contains("Only one reason"));
 }
@@ -0,0 +1,122 @@
+//! Message Templates
+//!
+//! `{{field}}` placeholders filled in from a job alert. Custom webhooks use
+//! them inside JSON payloads; Slack, Discord, and email can use them for the
+//! message text.
+
+use super::{format_salary_range, notification_job_href, Notification};
+use serde_json::{json, Value};
+
+/// Job fields a template can reference as `{{name}}`
+pub const TEMPLATE_FIELDS: &[&str] = &[
+    "id",
+    "hash",
+    "title",
+    "company",
+    "location",
+    "url",
+    "source",
+    "remote",
+    "salary_min",
+    "salary_max",
+    "currency",
+    "salary",
+    "score",
+    "score_percent",
+    "resume_match",
+    "resume_match_percent",
+    "ghost_score",
+];
+
+/// Longest message template accepted, in characters
+pub const MAX_MESSAGE_TEMPLATE_CHARS: usize = 2000;
+
+/// The next `{{name}}` in `text`, with the text before and after it
+pub(crate) fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find("{{")?;
+    let len = text[start + 2..].find("}}")?;
+    let name = text[start + 2..start + 2 + len].trim();
+    Some((&text[..start], name, &text[start + len + 4..]))
+}
+
+/// The JSON value of a template field, or `None` for unknown fields
+pub(crate) fn template_field(notification: &Notification, name: &str) -> Option<Value> {
+    let job = &notification.job;
+    let value = match name {
+        "id" => json!(job.id),
+        "hash" => json!(job.hash),
+        "title" => json!(job.title),
+        "company" => json!(job.company),
+        "location" => json!(job.location),
+        "url" => json!(notification_job_href(&job.url)),
+        "source" => json!(job.source),
+        "remote" => json!(job.remote),
+        "salary_min" => json!(job.salary_min),
+        "salary_max" => json!(job.salary_max),
+        "currency" => json!(job.currency),
+        "salary" => json!(format_salary_range(job.salary_min, job.salary_max)),
+        "score" => json!(notification.score.total),
+        "score_percent" => json!((notification.score.total * 100.0).round() as i64),
+        "resume_match" => json!(notification.resume_match),
+        "resume_match_percent" => json!(notification
+            .resume_match
+            .map(|resume_match| (resume_match * 100.0).round() as i64)),
+        "ghost_score" => json!(job.ghost_score),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// The first unknown field in `text`, if any
+pub(crate) fn unknown_template_field(text: &str) -> Option<&str> {
+    let mut rest = text;
+    while let Some((_, name, after)) = next_placeholder(rest) {
+        if !TEMPLATE_FIELDS.contains(&name) {
+            return Some(name);
+        }
+        rest = after;
+    }
+    None
+}
+
+/// Check a message template's length and that it only uses known fields
+pub fn validate_message_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("Message template cannot be empty".to_string());
+    }
+    if template.chars().count() > MAX_MESSAGE_TEMPLATE_CHARS {
+        return Err(format!(
+            "Message template is too long (max {MAX_MESSAGE_TEMPLATE_CHARS} characters)"
+        ));
+    }
+    match unknown_template_field(template) {
+        Some(name) => Err(format!("Unknown message template field: {name}")),
+        None => Ok(()),
+    }
+}
+
+/// Fill every `{{field}}` in `template` with text from the alert
+///
+/// Missing values become empty text and unknown placeholders are left as
+/// written.
+#[must_use]
+pub fn render_message_template(template: &str, notification: &Notification) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some((before, name, after)) = next_placeholder(rest) {
+        rendered.push_str(before);
+        match template_field(notification, name) {
+            Some(Value::String(value)) => rendered.push_str(&value),
+            Some(Value::Null) => {}
+            Some(value) => rendered.push_str(&value.to_string()),
+            None => rendered.push_str(&rest[before.len()..rest.len() - after.len()]),
+        }
+        rest = after;
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+#[path = "template_tests.rs"]
+mod tests;
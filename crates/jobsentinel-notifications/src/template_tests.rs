@@ -0,0 +1,55 @@
+use super::*;
+use crate::test_support::notification_fixture;
+
+#[test]
+fn test_render_message_template_fills_fields() {
+    let mut notification = notification_fixture();
+    notification.job.ghost_score = Some(0.25);
+
+    let text = render_message_template(
+        "{{title}} @ {{ company }}\n{{score_percent}}% match, ghost {{ghost_score}}\n{{url}}",
+        &notification,
+    );
+
+    assert_eq!(
+        text,
+        "Care Coordinator @ Community Care Network\n95% match, ghost 0.25\nhttps://example.com/jobs/123"
+    );
+}
+
+#[test]
+fn test_render_message_template_handles_missing_and_unknown_fields() {
+    let mut notification = notification_fixture();
+    notification.job.location = None;
+
+    let text = render_message_template("[{{location}}] {{nope}} {{unclosed", &notification);
+
+    assert_eq!(text, "[] {{nope}} {{unclosed");
+}
+
+#[test]
+fn test_render_message_template_fills_resume_match() {
+    let mut notification = notification_fixture();
+    let template = "{{score_percent}}% config, {{resume_match_percent}}% resume";
+    assert_eq!(
+        render_message_template(template, &notification),
+        "95% config, % resume"
+    );
+
+    notification.resume_match = Some(0.78);
+    assert_eq!(
+        render_message_template(template, &notification),
+        "95% config, 78% resume"
+    );
+}
+
+#[test]
+fn test_validate_message_template() {
+    assert!(validate_message_template("{{title}} ({{salary}})").is_ok());
+    assert_eq!(
+        validate_message_template("{{title}} {{reasons}}"),
+        Err("Unknown message template field: reasons".to_string())
+    );
+    assert!(validate_message_template("  ").is_err());
+    assert!(validate_message_template(&"x".repeat(MAX_MESSAGE_TEMPLATE_CHARS + 1)).is_err());
+}
@@ -1,6 +1,6 @@
 use crate::Notification;
 use chrono::Utc;
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 use jobsentinel_intelligence::{JobScore, ScoreBreakdown};
 
 /// Shared scored-job fixture for notification contract tests.
@@ -23,6 +23,7 @@ pub fn notification_fixture() -> Notification {
             salary_min: Some(180000),
             salary_max: Some(220000),
             currency: Some("USD".to_string()),
+            pay_period: None,
             created_at: now,
             updated_at: now,
             last_seen: now,
@@ -34,6 +35,8 @@ pub fn notification_fixture() -> Notification {
             ghost_reasons: None,
             first_seen: None,
             repost_count: 0,
+            status: JobStatus::Active,
+            skills: Vec::new(),
             notes: None,
             included_in_digest: false,
         },
@@ -53,5 +56,6 @@ pub fn notification_fixture() -> Notification {
                 "Remote job (matches preference)".to_string(),
             ],
         },
+        resume_match: None,
     }
 }
@@ -0,0 +1,192 @@
+//! Custom Webhook Notifications
+//!
+//! POSTs a JSON payload built from a user template to any public HTTPS
+//! endpoint, for automation services such as Zapier, n8n, or Home Assistant.
+//! Requests are signed with HMAC-SHA256 when a signing secret is saved.
+
+use super::template::{
+    next_placeholder, render_message_template, template_field, unknown_template_field,
+};
+use super::{notification_provider_failure_summary, Notification, NOTIFICATION_HTTP_TIMEOUT};
+use crate::CustomWebhookConfig;
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, KeyInit, Mac};
+use jobsentinel_network::{
+    send_external_https_text_with_retry, ExternalHttpRequest, ExternalTextResponse,
+};
+use jobsentinel_security::validate_external_https_url;
+use serde_json::{json, Value};
+use sha2::Sha256;
+
+/// Header carrying `sha256=<hex HMAC of the request body>` on signed requests
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-JobSentinel-Signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Validate a custom webhook URL format
+fn validate_webhook_url(url: &str) -> Result<()> {
+    validate_external_https_url(url).map(|_| ()).map_err(|_| {
+        anyhow!("Use the full https:// webhook address copied from your automation service.")
+    })
+}
+
+/// Check that a payload template only uses known `{{field}}` placeholders
+pub fn validate_webhook_template(template: &Value) -> Result<(), String> {
+    match first_unknown_field(template) {
+        Some(name) => Err(format!("Unknown webhook template field: {name}")),
+        None => Ok(()),
+    }
+}
+
+fn first_unknown_field(template: &Value) -> Option<&str> {
+    match template {
+        Value::String(text) => unknown_template_field(text),
+        Value::Array(items) => items.iter().find_map(first_unknown_field),
+        Value::Object(fields) => fields.values().find_map(first_unknown_field),
+        _ => None,
+    }
+}
+
+fn default_payload_template() -> Value {
+    json!({
+        "event": "job_alert",
+        "job": {
+            "id": "{{id}}",
+            "title": "{{title}}",
+            "company": "{{company}}",
+            "location": "{{location}}",
+            "url": "{{url}}",
+            "source": "{{source}}",
+            "remote": "{{remote}}",
+            "salary_min": "{{salary_min}}",
+            "salary_max": "{{salary_max}}",
+            "currency": "{{currency}}"
+        },
+        "score": "{{score}}"
+    })
+}
+
+/// Fill a payload template from a job
+///
+/// A string that is exactly one placeholder takes the field's JSON value, so
+/// `"{{score}}"` becomes a number. Placeholders inside longer strings are
+/// replaced with text, and unknown placeholders are left as written.
+fn render_payload(template: &Value, notification: &Notification) -> Value {
+    match template {
+        Value::String(text) => render_template_string(text, notification),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| render_payload(item, notification))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), render_payload(value, notification)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn render_template_string(text: &str, notification: &Notification) -> Value {
+    if let Some(("", name, "")) = next_placeholder(text) {
+        if let Some(value) = template_field(notification, name) {
+            return value;
+        }
+    }
+    Value::String(render_message_template(text, notification))
+}
+
+/// `sha256=<hex>` HMAC-SHA256 signature of `body`
+fn sign_payload(secret: &str, body: &[u8]) -> Result<String> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|_| anyhow!("Webhook signing secret is invalid"))?;
+    mac.update(body);
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+/// POST `payload` once, signing the exact body bytes when a secret is set
+async fn post_webhook(
+    url: &str,
+    payload: Value,
+    signing_secret: &str,
+) -> Result<ExternalTextResponse> {
+    validate_webhook_url(url)?;
+
+    let mut request = ExternalHttpRequest::post(url)
+        .timeout(NOTIFICATION_HTTP_TIMEOUT)
+        .without_retries();
+    if !signing_secret.is_empty() {
+        let body = serde_json::to_vec(&payload)?;
+        request = request.header(
+            WEBHOOK_SIGNATURE_HEADER,
+            sign_payload(signing_secret, &body)?,
+        );
+    }
+
+    send_external_https_text_with_retry(request.json(payload))
+        .await
+        .map_err(|error| anyhow!("Custom webhook request failed: {error}"))
+}
+
+/// Send a job alert to every configured custom webhook URL
+///
+/// Every URL is tried; the error lists how many failed. URLs are left out of
+/// errors because automation services often put secrets in them.
+pub async fn send_webhook_notification(
+    config: &CustomWebhookConfig,
+    notification: &Notification,
+) -> Result<()> {
+    if config.urls.is_empty() {
+        return Err(anyhow!("No custom webhook URLs configured"));
+    }
+
+    let payload = match &config.payload_template {
+        Some(template) => render_payload(template, notification),
+        None => render_payload(&default_payload_template(), notification),
+    };
+
+    let mut errors = Vec::new();
+    for url in &config.urls {
+        match post_webhook(url, payload.clone(), &config.signing_secret).await {
+            Ok(response) if (200..300).contains(&response.status) => {}
+            Ok(response) => errors.push(notification_provider_failure_summary(&response)),
+            Err(error) => errors.push(error.to_string()),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!(
+            "Custom webhook failed for {} of {} URLs: {}",
+            errors.len(),
+            config.urls.len(),
+            errors.join("; ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate a custom webhook by sending a signed test event
+pub async fn validate_webhook(url: &str, signing_secret: &str) -> Result<bool> {
+    let response = post_webhook(
+        url,
+        json!({
+            "event": "test",
+            "message": "JobSentinel: Webhook validation successful ✅"
+        }),
+        signing_secret,
+    )
+    .await?;
+
+    Ok((200..300).contains(&response.status))
+}
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::test_support::notification_fixture;
+
+#[test]
+fn test_default_payload_keeps_field_types() {
+    let notification = notification_fixture();
+    let payload = render_payload(&default_payload_template(), &notification);
+
+    assert_eq!(payload["event"], "job_alert");
+    assert_eq!(payload["job"]["title"], "Care Coordinator");
+    assert_eq!(payload["job"]["company"], "Community Care Network");
+    assert_eq!(payload["job"]["url"], "https://example.com/jobs/123");
+    assert_eq!(payload["job"]["remote"], true);
+    assert_eq!(payload["job"]["salary_min"], 180000);
+    assert_eq!(payload["score"], 0.95);
+}
+
+#[test]
+fn test_template_interpolates_fields_inside_text() {
+    let mut notification = notification_fixture();
+    notification.job.location = None;
+    let template = json!({
+        "message": "{{title}} at {{company}} ({{score_percent}}% match){{location}}",
+        "tags": ["jobsentinel", "{{ source }}"],
+        "unknown": "keep {{not_a_field}} as written",
+        "count": 1
+    });
+
+    let payload = render_payload(&template, &notification);
+
+    assert_eq!(
+        payload["message"],
+        "Care Coordinator at Community Care Network (95% match)"
+    );
+    assert_eq!(payload["tags"], json!(["jobsentinel", "greenhouse"]));
+    assert_eq!(payload["unknown"], "keep {{not_a_field}} as written");
+    assert_eq!(payload["count"], 1);
+}
+
+#[test]
+fn test_template_values_are_json_escaped() {
+    let mut notification = notification_fixture();
+    notification.job.title = "Lead \"Care\" Coordinator\n}".to_string();
+    let template = json!({ "text": "New: {{title}}" });
+
+    let payload = render_payload(&template, &notification);
+    let body = serde_json::to_string(&payload).unwrap();
+
+    assert_eq!(payload["text"], "New: Lead \"Care\" Coordinator\n}");
+    assert_eq!(serde_json::from_str::<Value>(&body).unwrap(), payload);
+}
+
+#[test]
+fn test_template_validation_rejects_unknown_fields() {
+    assert!(validate_webhook_template(&default_payload_template()).is_ok());
+    assert_eq!(
+        validate_webhook_template(&json!({ "a": ["{{title}}", "{{salary_range}}"] })),
+        Err("Unknown webhook template field: salary_range".to_string())
+    );
+}
+
+#[test]
+fn test_signature_matches_hmac_sha256_reference() {
+    // RFC 4231 test case 2
+    let signature = sign_payload("Jefe", b"what do ya want for nothing?").unwrap();
+
+    assert_eq!(
+        signature,
+        "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn test_webhook_url_must_be_public_https() {
+    assert!(validate_webhook_url("https://hooks.zapier.com/hooks/catch/123/abc/").is_ok());
+    for url in [
+        "http://hooks.zapier.com/hooks/catch/123/abc/",
+        "https://127.0.0.1/webhook",
+        "not-a-url",
+    ] {
+        assert!(
+            validate_webhook_url(url).is_err(),
+            "{url} should be rejected"
+        );
+    }
+}
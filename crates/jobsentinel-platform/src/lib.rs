@@ -33,6 +33,7 @@
 
 mod credential_vault_key;
 mod database_key;
+mod power;
 mod private_files;
 mod secure_storage;
 
@@ -42,6 +43,7 @@ pub use credential_vault_key::{
     CredentialVaultKeyStoragePolicy, SECURE_STORAGE_UNAVAILABLE_MESSAGE,
 };
 pub use database_key::{load_or_create_database_key, DatabaseKeyError};
+pub use power::{current_power_status, PowerStatus};
 pub use private_files::write_file_atomic_private;
 pub use secure_storage::{
     delete_device_secret, retrieve_device_secret, store_device_secret, SecureStorageError,
@@ -3,10 +3,23 @@
 //! This module contains Linux-specific directory handling for JobSentinel using
 //! the XDG base directory conventions.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use jobsentinel_security::path_label_for_logging;
 
+use crate::PowerStatus;
+
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Terse per-device connection state and metered flag
+const NMCLI_METERED_ARGS: [&str; 5] = [
+    "-t",
+    "-f",
+    "GENERAL.STATE,GENERAL.METERED",
+    "device",
+    "show",
+];
+
 /// Get Linux data directory (XDG_DATA_HOME)
 ///
 /// Returns: ~/.local/share/jobsentinel
@@ -63,6 +76,65 @@ pub(crate) fn initialize() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Battery state from sysfs and the metered flag from NetworkManager
+pub(crate) fn power_status() -> PowerStatus {
+    let (on_battery, battery_percent) = read_power_supplies(Path::new(POWER_SUPPLY_DIR));
+    let metered_connection = std::process::Command::new("nmcli")
+        .args(NMCLI_METERED_ARGS)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| nmcli_reports_metered(&String::from_utf8_lossy(&output.stdout)));
+
+    PowerStatus {
+        on_battery,
+        battery_percent,
+        metered_connection,
+    }
+}
+
+/// Whether a system battery is discharging, and the lowest system battery
+/// charge. Peripheral batteries (mice, headsets) are ignored.
+fn read_power_supplies(dir: &Path) -> (bool, Option<u8>) {
+    let read = |supply: &Path, name: &str| {
+        std::fs::read_to_string(supply.join(name))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut on_battery = false;
+    let mut battery_percent: Option<u8> = None;
+    for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let supply = entry.path();
+        if read(&supply, "type") != "Battery" || read(&supply, "scope") == "Device" {
+            continue;
+        }
+        on_battery |= read(&supply, "status") == "Discharging";
+        if let Ok(capacity) = read(&supply, "capacity").parse::<u8>() {
+            let capacity = capacity.min(100);
+            battery_percent = Some(battery_percent.map_or(capacity, |lowest| lowest.min(capacity)));
+        }
+    }
+    (on_battery, battery_percent)
+}
+
+/// Whether a connected device is metered in terse `nmcli device show`
+/// output. Guessed values count, since NetworkManager guesses from the link
+/// (for example, a phone hotspot).
+fn nmcli_reports_metered(output: &str) -> bool {
+    let mut connected = false;
+    for line in output.lines() {
+        if let Some(state) = line.strip_prefix("GENERAL.STATE:") {
+            connected = state.starts_with("100");
+        } else if let Some(metered) = line.strip_prefix("GENERAL.METERED:") {
+            if connected && metered.starts_with("yes") {
+                return true;
+            }
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +156,62 @@ mod tests {
         let dir = get_cache_dir();
         assert!(dir.to_string_lossy().contains("jobsentinel"));
     }
+
+    fn write_supply(dir: &Path, name: &str, files: &[(&str, &str)]) {
+        let supply = dir.join(name);
+        std::fs::create_dir_all(&supply).unwrap();
+        for (file, value) in files {
+            std::fs::write(supply.join(file), format!("{value}\n")).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_power_supplies_report_discharging_system_battery() {
+        let dir = tempfile::tempdir().unwrap();
+        write_supply(dir.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+        write_supply(
+            dir.path(),
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("capacity", "18"),
+            ],
+        );
+        write_supply(
+            dir.path(),
+            "hidpp_battery_0",
+            &[
+                ("type", "Battery"),
+                ("scope", "Device"),
+                ("status", "Discharging"),
+                ("capacity", "5"),
+            ],
+        );
+
+        assert_eq!(read_power_supplies(dir.path()), (true, Some(18)));
+    }
+
+    #[test]
+    fn test_power_supplies_without_battery_read_as_plugged_in() {
+        let dir = tempfile::tempdir().unwrap();
+        write_supply(dir.path(), "AC", &[("type", "Mains"), ("online", "1")]);
+
+        assert_eq!(read_power_supplies(dir.path()), (false, None));
+        assert_eq!(
+            read_power_supplies(&dir.path().join("missing")),
+            (false, None)
+        );
+    }
+
+    #[test]
+    fn test_nmcli_metered_counts_only_connected_devices() {
+        let output = "GENERAL.STATE:100 (connected)\nGENERAL.METERED:no (guessed)\n\n\
+                      GENERAL.STATE:30 (disconnected)\nGENERAL.METERED:yes\n";
+        assert!(!nmcli_reports_metered(output));
+
+        let output = "GENERAL.STATE:100 (connected)\nGENERAL.METERED:yes (guessed)\n";
+        assert!(nmcli_reports_metered(output));
+        assert!(!nmcli_reports_metered(""));
+    }
 }
@@ -7,6 +7,8 @@ use std::path::PathBuf;
 
 use jobsentinel_security::path_label_for_logging;
 
+use crate::PowerStatus;
+
 pub(crate) const PACKAGE_SMOKE_ROOT_ENV: &str = "JOBSENTINEL_MACOS_PACKAGE_SMOKE_ROOT";
 
 /// Get macOS application support directory
@@ -119,6 +121,38 @@ pub(crate) fn get_macos_version() -> String {
     "macOS (unknown)".to_string()
 }
 
+/// Battery state from `pmset`
+///
+/// macOS only reports a metered ("expensive") connection through the Network
+/// framework, so connections always read as unmetered here.
+pub(crate) fn power_status() -> PowerStatus {
+    let (on_battery, battery_percent) = std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| parse_pmset_battery(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default();
+
+    PowerStatus {
+        on_battery,
+        battery_percent,
+        metered_connection: false,
+    }
+}
+
+/// Power source and charge from `pmset -g batt` output, such as
+/// "Now drawing from 'Battery Power'" followed by "-InternalBattery-0
+/// (id=1234)	76%; discharging; 4:01 remaining present: true".
+fn parse_pmset_battery(output: &str) -> (bool, Option<u8>) {
+    let on_battery = output.contains("'Battery Power'");
+    let battery_percent = output
+        .split(|c: char| c.is_whitespace() || c == ';')
+        .find_map(|token| token.strip_suffix('%')?.parse::<u8>().ok())
+        .map(|percent| percent.min(100));
+    (on_battery, battery_percent)
+}
+
 /// Get the appropriate cache directory for macOS
 ///
 /// Returns: ~/Library/Caches/JobSentinel
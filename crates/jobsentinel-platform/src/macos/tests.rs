@@ -388,3 +388,18 @@ fn test_config_dir_follows_xdg_spec() {
         None => env::remove_var("XDG_CONFIG_HOME"),
     }
 }
+
+#[test]
+fn test_parse_pmset_battery() {
+    let output = "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=4653155)\t18%; discharging; 1:02 remaining present: true\n";
+    assert_eq!(parse_pmset_battery(output), (true, Some(18)));
+
+    let output = "Now drawing from 'AC Power'\n -InternalBattery-0 (id=4653155)\t100%; charged; 0:00 remaining present: true\n";
+    assert_eq!(parse_pmset_battery(output), (false, Some(100)));
+
+    // Desktops without a battery only name the power source.
+    assert_eq!(
+        parse_pmset_battery("Now drawing from 'AC Power'\n"),
+        (false, None)
+    );
+}
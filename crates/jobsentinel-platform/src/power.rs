@@ -0,0 +1,43 @@
+//! Battery and network conditions for background work
+//!
+//! The scheduler reads these before a scheduled scrape so it can hold back on
+//! a low battery or a metered connection. Anything the system does not report
+//! reads as plugged in and unmetered.
+
+/// Power and network state at one moment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PowerStatus {
+    /// Running from a battery rather than mains power
+    pub on_battery: bool,
+    /// Battery charge from 0 to 100, when the system reports one
+    pub battery_percent: Option<u8>,
+    /// The active network connection is marked metered
+    pub metered_connection: bool,
+}
+
+/// Read the current power and network state.
+///
+/// Runs the platform checks off the async runtime since some of them start a
+/// short-lived system tool.
+pub async fn current_power_status() -> PowerStatus {
+    tokio::task::spawn_blocking(read_power_status)
+        .await
+        .unwrap_or_default()
+}
+
+fn read_power_status() -> PowerStatus {
+    #[cfg(target_os = "windows")]
+    {
+        crate::windows::power_status()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        crate::macos::power_status()
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        crate::linux::power_status()
+    }
+}
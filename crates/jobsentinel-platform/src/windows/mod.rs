@@ -11,6 +11,11 @@ use std::path::PathBuf;
 
 use jobsentinel_security::path_label_for_logging;
 
+use crate::PowerStatus;
+
+/// Reads the cost type of the internet connection profile through WinRT.
+const CONNECTION_COST_SCRIPT: &str = "[Windows.Networking.Connectivity.NetworkInformation,Windows.Networking.Connectivity,ContentType=WindowsRuntime]::GetInternetConnectionProfile().GetConnectionCost().NetworkCostType";
+
 /// Get Windows application data directory
 ///
 /// Returns: %LOCALAPPDATA%\JobSentinel
@@ -218,6 +223,71 @@ pub(crate) fn get_windows_version() -> String {
     "Not Windows".to_string()
 }
 
+/// Battery state from `GetSystemPowerStatus` and the metered flag from the
+/// internet connection profile
+pub(crate) fn power_status() -> PowerStatus {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> i32;
+    }
+
+    // Windows fills in every field; only some are read.
+    #[allow(dead_code)]
+    #[repr(C)]
+    #[derive(Default)]
+    struct SystemPowerStatus {
+        ac_line_status: u8,
+        battery_flag: u8,
+        battery_life_percent: u8,
+        system_status_flag: u8,
+        battery_life_time: u32,
+        battery_full_life_time: u32,
+    }
+
+    const AC_OFFLINE: u8 = 0;
+    const NO_SYSTEM_BATTERY: u8 = 128;
+    const UNKNOWN_PERCENT: u8 = 255;
+
+    let mut status = SystemPowerStatus::default();
+    // SAFETY: FFI call to kernel32!GetSystemPowerStatus with a pointer to a
+    // #[repr(C)] struct matching SYSTEM_POWER_STATUS. The struct is only read
+    // when the call reports success.
+    let read = unsafe { GetSystemPowerStatus(&mut status) } != 0;
+    let has_battery = read && status.battery_flag & NO_SYSTEM_BATTERY == 0;
+
+    PowerStatus {
+        on_battery: has_battery && status.ac_line_status == AC_OFFLINE,
+        battery_percent: (has_battery && status.battery_life_percent != UNKNOWN_PERCENT)
+            .then_some(status.battery_life_percent.min(100)),
+        metered_connection: connection_is_metered(),
+    }
+}
+
+fn connection_is_metered() -> bool {
+    use std::os::windows::process::CommandExt;
+
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            CONNECTION_COST_SCRIPT,
+        ])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| is_metered_cost_type(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// `Fixed` and `Variable` connection costs are metered; `Unrestricted` and
+/// `Unknown` are not.
+fn is_metered_cost_type(cost_type: &str) -> bool {
+    matches!(cost_type.trim(), "Fixed" | "Variable")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +310,13 @@ mod tests {
         assert!(dir.to_string_lossy().contains("JobSentinel"));
         assert_eq!(dir.file_name().unwrap(), "Cache");
     }
+
+    #[test]
+    fn test_is_metered_cost_type() {
+        assert!(is_metered_cost_type("Fixed\r\n"));
+        assert!(is_metered_cost_type("Variable"));
+        assert!(!is_metered_cost_type("Unrestricted\r\n"));
+        assert!(!is_metered_cost_type("Unknown"));
+        assert!(!is_metered_cost_type(""));
+    }
 }
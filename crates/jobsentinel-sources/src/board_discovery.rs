@@ -0,0 +1,249 @@
+//! Career-page discovery for a company name.
+//!
+//! Guesses board slugs from the company name and probes each ATS's public
+//! job-board API. A board counts as found only when its API answers with a
+//! parseable job list, so parked or unrelated pages are never suggested.
+
+use std::time::Duration;
+
+use futures::future::join_all;
+use jobsentinel_network::fetch_external_https_text_with_user_agent;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::is_safe_company_board_id;
+use crate::scrapers::JOBSENTINEL_USER_AGENT;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_SLUG_CANDIDATES: usize = 3;
+const MAX_COMPANY_NAME_CHARS: usize = 120;
+
+/// Legal and filler words dropped before building slugs.
+const COMPANY_SUFFIXES: &[&str] = &[
+    "inc",
+    "incorporated",
+    "llc",
+    "ltd",
+    "limited",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "plc",
+    "gmbh",
+    "the",
+];
+
+/// Applicant tracking systems with public job-board APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtsProvider {
+    Greenhouse,
+    Lever,
+    Ashby,
+    Workable,
+    SmartRecruiters,
+}
+
+impl AtsProvider {
+    pub const ALL: [AtsProvider; 5] = [
+        AtsProvider::Greenhouse,
+        AtsProvider::Lever,
+        AtsProvider::Ashby,
+        AtsProvider::Workable,
+        AtsProvider::SmartRecruiters,
+    ];
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            AtsProvider::Greenhouse => "Greenhouse",
+            AtsProvider::Lever => "Lever",
+            AtsProvider::Ashby => "Ashby",
+            AtsProvider::Workable => "Workable",
+            AtsProvider::SmartRecruiters => "SmartRecruiters",
+        }
+    }
+
+    /// Public job-board page people open in a browser.
+    #[must_use]
+    pub fn board_url(self, slug: &str) -> String {
+        match self {
+            AtsProvider::Greenhouse => format!("https://job-boards.greenhouse.io/{slug}"),
+            AtsProvider::Lever => format!("https://jobs.lever.co/{slug}"),
+            AtsProvider::Ashby => format!("https://jobs.ashbyhq.com/{slug}"),
+            AtsProvider::Workable => format!("https://apply.workable.com/{slug}"),
+            AtsProvider::SmartRecruiters => format!("https://jobs.smartrecruiters.com/{slug}"),
+        }
+    }
+
+    fn api_url(self, slug: &str) -> String {
+        match self {
+            AtsProvider::Greenhouse => {
+                format!("https://boards-api.greenhouse.io/v1/boards/{slug}/jobs")
+            }
+            AtsProvider::Lever => format!("https://api.lever.co/v0/postings/{slug}?mode=json"),
+            AtsProvider::Ashby => {
+                format!("https://api.ashbyhq.com/posting-api/job-board/{slug}")
+            }
+            AtsProvider::Workable => {
+                format!("https://apply.workable.com/api/v1/widget/accounts/{slug}")
+            }
+            AtsProvider::SmartRecruiters => {
+                format!("https://api.smartrecruiters.com/v1/companies/{slug}/postings")
+            }
+        }
+    }
+}
+
+/// A job board that answered for one of the guessed slugs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveredBoard {
+    pub provider: AtsProvider,
+    pub slug: String,
+    pub board_url: String,
+    /// Open postings reported by the board when it was probed
+    pub job_count: usize,
+}
+
+/// Probe common ATS boards for `company_name`.
+///
+/// Returns at most one board per provider, in [`AtsProvider::ALL`] order.
+/// Unreachable boards and network failures are treated as "not found".
+pub async fn discover_company_boards(company_name: &str) -> Vec<DiscoveredBoard> {
+    let slugs = candidate_board_slugs(company_name);
+    let probes = AtsProvider::ALL.into_iter().map(|provider| {
+        let slugs = slugs.clone();
+        async move {
+            for slug in slugs {
+                if let Some(job_count) = probe_board(provider, &slug).await {
+                    return Some(DiscoveredBoard {
+                        provider,
+                        board_url: provider.board_url(&slug),
+                        slug,
+                        job_count,
+                    });
+                }
+            }
+            None
+        }
+    });
+
+    join_all(probes).await.into_iter().flatten().collect()
+}
+
+async fn probe_board(provider: AtsProvider, slug: &str) -> Option<usize> {
+    let response = fetch_external_https_text_with_user_agent(
+        &provider.api_url(slug),
+        PROBE_TIMEOUT,
+        Some(JOBSENTINEL_USER_AGENT),
+    )
+    .await
+    .map_err(|error| {
+        tracing::debug!(provider = provider.label(), %error, "Board discovery probe failed");
+    })
+    .ok()?;
+
+    if response.status != 200 {
+        return None;
+    }
+    count_board_jobs(provider, &response.body)
+}
+
+/// Job count from a board API response, or `None` when the response is not
+/// a real board.
+fn count_board_jobs(provider: AtsProvider, body: &str) -> Option<usize> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    match provider {
+        AtsProvider::Greenhouse | AtsProvider::Ashby | AtsProvider::Workable => {
+            value.get("jobs")?.as_array().map(Vec::len)
+        }
+        AtsProvider::Lever => value.as_array().map(Vec::len),
+        // SmartRecruiters answers every company id with an empty list, so only
+        // a board with postings proves the slug is real.
+        AtsProvider::SmartRecruiters => value
+            .get("totalFound")?
+            .as_u64()
+            .and_then(|count| usize::try_from(count).ok())
+            .filter(|count| *count > 0),
+    }
+}
+
+/// Slugs worth probing for a company name, most likely first.
+///
+/// "Harbor Community Services, Inc." yields `harborcommunityservices`,
+/// `harbor-community-services`, and `harbor`.
+#[must_use]
+pub fn candidate_board_slugs(company_name: &str) -> Vec<String> {
+    let name: String = company_name.chars().take(MAX_COMPANY_NAME_CHARS).collect();
+    let name = name.replace('&', " and ");
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .filter(|word| !COMPANY_SUFFIXES.contains(&word.as_str()))
+        .collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut slugs = Vec::with_capacity(MAX_SLUG_CANDIDATES);
+    for candidate in [words.concat(), words.join("-"), words[0].clone()] {
+        if is_safe_company_board_id(&candidate) && !slugs.contains(&candidate) {
+            slugs.push(candidate);
+        }
+    }
+    slugs.truncate(MAX_SLUG_CANDIDATES);
+    slugs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidate_slugs_drop_legal_suffixes_and_deduplicate() {
+        assert_eq!(
+            candidate_board_slugs("Harbor Community Services, Inc."),
+            vec![
+                "harborcommunityservices".to_string(),
+                "harbor-community-services".to_string(),
+                "harbor".to_string(),
+            ]
+        );
+        assert_eq!(candidate_board_slugs("Cloudflare"), vec!["cloudflare"]);
+        assert_eq!(
+            candidate_board_slugs("Bread & Butter Co"),
+            vec!["breadandbutter", "bread-and-butter", "bread"]
+        );
+        assert!(candidate_board_slugs(" LLC ").is_empty());
+    }
+
+    #[test]
+    fn count_board_jobs_requires_each_provider_shape() {
+        assert_eq!(
+            count_board_jobs(AtsProvider::Greenhouse, r#"{"jobs":[{"id":1},{"id":2}]}"#),
+            Some(2)
+        );
+        assert_eq!(count_board_jobs(AtsProvider::Lever, "[]"), Some(0));
+        assert_eq!(
+            count_board_jobs(AtsProvider::Lever, r#"{"ok":false}"#),
+            None
+        );
+        assert_eq!(count_board_jobs(AtsProvider::Ashby, "<html></html>"), None);
+        assert_eq!(
+            count_board_jobs(
+                AtsProvider::SmartRecruiters,
+                r#"{"totalFound":0,"content":[]}"#
+            ),
+            None
+        );
+        assert_eq!(
+            count_board_jobs(
+                AtsProvider::SmartRecruiters,
+                r#"{"totalFound":4,"content":[]}"#
+            ),
+            Some(4)
+        );
+    }
+}
@@ -20,6 +20,14 @@ pub fn parse_single_job_page(html: &str) -> Result<ParsedJobPage, JobPageParseEr
     Ok(create_parsed_job_page(&postings.remove(0)))
 }
 
+/// Parse every Schema.org JobPosting on a page, such as a careers listing.
+pub fn parse_job_pages(html: &str) -> Result<Vec<ParsedJobPage>, JobPageParseError> {
+    Ok(parse_schema_org_job_posting(html)?
+        .iter()
+        .map(create_parsed_job_page)
+        .collect())
+}
+
 /// Parse Schema.org JobPosting data from HTML
 ///
 /// Looks for <script type="application/ld+json"> tags containing JobPosting data.
@@ -203,6 +211,12 @@ fn create_parsed_job_page(posting: &SchemaOrgJobPosting) -> ParsedJobPage {
         valid_through,
         employment_types,
         remote,
+        url: posting
+            .url
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
         missing_fields,
     }
 }
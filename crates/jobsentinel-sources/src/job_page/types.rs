@@ -29,6 +29,8 @@ pub struct ParsedJobPage {
     pub valid_through: Option<DateTime<Utc>>,
     pub employment_types: Vec<String>,
     pub remote: bool,
+    /// Posting link from the JSON-LD, when the page provides one
+    pub url: Option<String>,
     pub missing_fields: Vec<String>,
 }
 
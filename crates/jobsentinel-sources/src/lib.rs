@@ -3,18 +3,23 @@
 //! Detects user location from IP address using FreeIPAPI over HTTPS.
 //! Used to suggest location after explicit user action.
 
+mod board_discovery;
 mod board_urls;
 mod job_page;
 mod scrapers;
 
+pub use board_discovery::{
+    candidate_board_slugs, discover_company_boards, AtsProvider, DiscoveredBoard,
+};
 pub use board_urls::{
     is_safe_company_board_id, parse_greenhouse_company_url, parse_lever_company_url,
     CompanyBoardUrl,
 };
-pub use job_page::{parse_single_job_page, JobPageParseError, ParsedJobPage};
+pub use job_page::{parse_job_pages, parse_single_job_page, JobPageParseError, ParsedJobPage};
 pub use scrapers::{
-    limits, BuiltInScraper, DiceScraper, GlassdoorScraper, GreenhouseCompany, GreenhouseScraper,
-    HnHiringScraper, JobQuery, JobScraper, JobsWithGptScraper, LeverCompany, LeverScraper,
+    limits, ArbeitnowScraper, BuiltInScraper, CareerPage, CareerPageScraper, DiceScraper,
+    GlassdoorScraper, GreenhouseCompany, GreenhouseScraper, GuardianJobsScraper, HnHiringScraper,
+    JobQuery, JobScraper, JobsWithGptScraper, LeverCompany, LeverScraper, PageRenderer,
     RateLimiter, RemoteOkScraper, ScraperError, SimplyHiredScraper, UsaJobsScraper,
     WeWorkRemotelyScraper, YcStartupScraper, LINKEDIN_AUTOMATION_DISABLED_MESSAGE,
 };
@@ -0,0 +1,145 @@
+//! Arbeitnow Job Scraper
+//!
+//! Reads jobs from Arbeitnow's public JSON API. Arbeitnow lists roles at
+//! companies in Germany and neighbouring EU countries, many of them in
+//! English and some offering visa sponsorship.
+
+use super::error::ScraperError;
+use super::rate_limiter::{limits, RateLimiter};
+use super::{
+    source_skill_tags, strip_html_markup, JobScraper, ScraperResult, JOBSENTINEL_USER_AGENT,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+use jobsentinel_network::{send_external_http_text_with_retry, ExternalHttpRequest};
+
+const ARBEITNOW_API_URL: &str = "https://www.arbeitnow.com/api/job-board-api";
+/// Pages read per check; each page holds up to 100 jobs.
+const MAX_PAGES: usize = 3;
+
+/// Arbeitnow job scraper
+#[derive(Debug, Clone)]
+pub struct ArbeitnowScraper {
+    /// Maximum results to return
+    pub limit: usize,
+    /// Rate limiter for respecting Arbeitnow's request limits
+    pub rate_limiter: RateLimiter,
+}
+
+impl ArbeitnowScraper {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            rate_limiter: RateLimiter::shared(),
+        }
+    }
+
+    async fn fetch_jobs(&self) -> ScraperResult {
+        tracing::info!("Fetching jobs from Arbeitnow");
+
+        let mut jobs = Vec::new();
+        for page in 1..=MAX_PAGES {
+            self.rate_limiter.wait("arbeitnow", limits::ARBEITNOW).await;
+
+            let url = format!("{ARBEITNOW_API_URL}?page={page}");
+            let response = send_external_http_text_with_retry(
+                ExternalHttpRequest::get(&url).user_agent(JOBSENTINEL_USER_AGENT),
+            )
+            .await
+            .map_err(|error| ScraperError::from_external("arbeitnow", error))?;
+
+            if !(200..300).contains(&response.status) {
+                return Err(ScraperError::http_status(
+                    response.status,
+                    &url,
+                    format!("Arbeitnow API failed: {}", response.status),
+                ));
+            }
+
+            let json: serde_json::Value = ScraperError::parse_json(&url, &response.body)?;
+            let page_jobs = self.parse_page(&json);
+            let has_next = json["links"]["next"].as_str().is_some();
+            let page_was_empty = page_jobs.is_empty();
+            jobs.extend(page_jobs);
+
+            if jobs.len() >= self.limit || page_was_empty || !has_next {
+                break;
+            }
+        }
+
+        jobs.truncate(self.limit);
+        tracing::info!("Found {} jobs from Arbeitnow", jobs.len());
+        Ok(jobs)
+    }
+
+    fn parse_page(&self, json: &serde_json::Value) -> Vec<Job> {
+        json["data"]
+            .as_array()
+            .map(|entries| entries.iter().filter_map(Self::parse_job).collect())
+            .unwrap_or_default()
+    }
+
+    /// Parse one job from the Arbeitnow `data` array
+    fn parse_job(data: &serde_json::Value) -> Option<Job> {
+        let title = data["title"]
+            .as_str()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())?;
+        let url = data["url"]
+            .as_str()
+            .filter(|url| url.starts_with("https://"))?;
+        let company = data["company_name"]
+            .as_str()
+            .map(str::trim)
+            .filter(|company| !company.is_empty())
+            .unwrap_or("Unknown");
+        let location = data["location"]
+            .as_str()
+            .map(str::trim)
+            .filter(|location| !location.is_empty())
+            .map(str::to_string);
+        let description = data["description"]
+            .as_str()
+            .map(|html| strip_html_markup(html).trim().to_string())
+            .filter(|text| !text.is_empty());
+        let discovered_at = data["created_at"]
+            .as_i64()
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+            .unwrap_or_else(Utc::now);
+        let skills = data["tags"]
+            .as_array()
+            .map(|tags| source_skill_tags(tags.iter().filter_map(|tag| tag.as_str())))
+            .unwrap_or_default();
+
+        Some(Job {
+            description,
+            skills,
+            remote: data["remote"].as_bool(),
+            currency: Some("EUR".to_string()),
+            ..Job::newly_discovered(
+                title.to_string(),
+                company.to_string(),
+                url.to_string(),
+                location,
+                "arbeitnow",
+                discovered_at,
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl JobScraper for ArbeitnowScraper {
+    async fn scrape(&self) -> ScraperResult {
+        self.fetch_jobs().await
+    }
+
+    #[cfg(test)]
+    fn name(&self) -> &'static str {
+        "arbeitnow"
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,64 @@
+use super::*;
+
+fn sample_job() -> serde_json::Value {
+    serde_json::json!({
+        "slug": "backend-engineer-berlin-123",
+        "company_name": "Beispiel GmbH",
+        "title": "Backend Engineer (Rust)",
+        "description": "<p>Build our <strong>payments</strong> platform.</p>",
+        "remote": false,
+        "url": "https://www.arbeitnow.com/jobs/companies/beispiel/backend-engineer-berlin-123",
+        "tags": ["Software Development", "Rust", "rust"],
+        "job_types": ["Full Time"],
+        "location": "Berlin",
+        "created_at": 1_700_000_000
+    })
+}
+
+#[test]
+fn parses_complete_job() {
+    let job = ArbeitnowScraper::parse_job(&sample_job()).unwrap();
+
+    assert_eq!(job.title, "Backend Engineer (Rust)");
+    assert_eq!(job.company, "Beispiel GmbH");
+    assert_eq!(job.location.as_deref(), Some("Berlin"));
+    assert_eq!(job.source, "arbeitnow");
+    assert_eq!(job.remote, Some(false));
+    assert_eq!(job.currency.as_deref(), Some("EUR"));
+    assert_eq!(
+        job.description.as_deref(),
+        Some("Build our payments platform.")
+    );
+    assert_eq!(job.skills, vec!["Software Development", "Rust"]);
+    assert_eq!(job.created_at.timestamp(), 1_700_000_000);
+}
+
+#[test]
+fn skips_jobs_without_title_or_https_url() {
+    let mut untitled = sample_job();
+    untitled["title"] = serde_json::json!("  ");
+    assert!(ArbeitnowScraper::parse_job(&untitled).is_none());
+
+    let mut insecure = sample_job();
+    insecure["url"] = serde_json::json!("http://www.arbeitnow.com/jobs/1");
+    assert!(ArbeitnowScraper::parse_job(&insecure).is_none());
+}
+
+#[test]
+fn parse_page_reads_data_array() {
+    let scraper = ArbeitnowScraper::new(10);
+    let page = serde_json::json!({
+        "data": [sample_job(), { "title": "" }],
+        "links": { "next": null }
+    });
+
+    let jobs = scraper.parse_page(&page);
+
+    assert_eq!(jobs.len(), 1);
+    assert!(scraper.parse_page(&serde_json::json!({})).is_empty());
+}
+
+#[test]
+fn scraper_name_is_arbeitnow() {
+    assert_eq!(ArbeitnowScraper::new(5).name(), "arbeitnow");
+}
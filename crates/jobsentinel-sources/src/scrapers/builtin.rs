@@ -4,8 +4,10 @@
 //! BuiltIn focuses on tech companies and startups.
 //!
 //! Note: BuiltIn changed their URL structure in late 2025.
-//! Old: /city/jobs (e.g., /nyc/jobs) - no longer works
-//! New: /jobs with optional /remote filter
+//! Old: /city/jobs (e.g., /nyc/jobs) and the regional sites (builtinnyc.com,
+//! builtinsf.com, ...) - no longer work
+//! New: /jobs with optional /remote filter, plus `search` and `city`/`state`
+//! query parameters
 
 use super::error::ScraperError;
 use super::rate_limiter::RateLimiter;
@@ -21,11 +23,30 @@ use chrono::Utc;
 use regex::Regex;
 use scraper::{Html, Selector};
 
+const BUILTIN_JOBS_URL: &str = "https://builtin.com/jobs";
+
+/// Former BuiltIn regional sites and the city/state filter that replaced them.
+const BUILTIN_HUBS: &[(&[&str], &str, &str)] = &[
+    (&["nyc", "new york"], "New York", "New York"),
+    (&["sf", "san francisco"], "San Francisco", "California"),
+    (&["la", "los angeles"], "Los Angeles", "California"),
+    (&["chicago"], "Chicago", "Illinois"),
+    (&["boston"], "Boston", "Massachusetts"),
+    (&["seattle"], "Seattle", "Washington"),
+    (&["austin"], "Austin", "Texas"),
+    (&["colorado", "denver"], "Denver", "Colorado"),
+];
+
 /// BuiltIn job scraper
 #[derive(Debug, Clone)]
 pub struct BuiltInScraper {
     /// Whether to filter for remote jobs only
     pub remote_only: bool,
+    /// Keyword search (e.g., "platform engineer")
+    pub query: Option<String>,
+    /// Cities to search, as city names ("Denver, CO") or former regional
+    /// site names ("nyc", "builtinsf"). Ignored for remote-only searches.
+    pub cities: Vec<String>,
     /// Maximum results to return
     pub limit: usize,
     /// Rate limiter for respecting BuiltIn's request limits
@@ -41,32 +62,106 @@ impl BuiltInScraper {
     pub fn new(remote_only: bool, limit: usize) -> Self {
         Self {
             remote_only,
+            query: None,
+            cities: Vec::new(),
             limit,
             rate_limiter: RateLimiter::shared(),
         }
     }
 
-    /// Build the search URL
-    fn build_url(&self) -> String {
-        if self.remote_only {
-            "https://builtin.com/jobs/remote".to_string()
+    /// Narrow the search by keyword and city
+    #[must_use]
+    pub fn with_search(mut self, query: Option<String>, cities: Vec<String>) -> Self {
+        self.query = query
+            .map(|query| query.trim().to_string())
+            .filter(|query| !query.is_empty());
+        self.cities = cities
+            .into_iter()
+            .map(|city| city.trim().to_string())
+            .filter(|city| !city.is_empty())
+            .collect();
+        self
+    }
+
+    /// Build the search URL for one city (or no city)
+    fn build_url(&self, city: Option<&str>) -> String {
+        let mut url = if self.remote_only {
+            format!("{BUILTIN_JOBS_URL}/remote")
         } else {
-            "https://builtin.com/jobs".to_string()
+            BUILTIN_JOBS_URL.to_string()
+        };
+
+        let mut params = Vec::new();
+        if let Some(query) = &self.query {
+            params.push(format!("search={}", urlencoding::encode(query)));
+        }
+        if let Some((city, state)) = city.and_then(builtin_city_filter) {
+            params.push(format!("city={}", urlencoding::encode(&city)));
+            if let Some(state) = state {
+                params.push(format!("state={}", urlencoding::encode(&state)));
+            }
+            params.push("country=USA".to_string());
+        }
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&params.join("&"));
         }
+        url
     }
 
-    /// Fetch and parse jobs from BuiltIn
+    /// Fetch and parse jobs from BuiltIn, one search per configured city
     async fn fetch_jobs(&self) -> ScraperResult {
         let mode = if self.remote_only { "remote" } else { "all" };
-        tracing::info!("Fetching jobs from BuiltIn (mode: {})", mode);
+        tracing::info!(
+            has_query = self.query.is_some(),
+            cities = self.cities.len(),
+            "Fetching jobs from BuiltIn (mode: {})",
+            mode
+        );
+
+        let cities: Vec<Option<&str>> = if self.remote_only || self.cities.is_empty() {
+            vec![None]
+        } else {
+            self.cities.iter().map(|city| Some(city.as_str())).collect()
+        };
+
+        let mut jobs: Vec<Job> = Vec::new();
+        let mut first_error = None;
+        let mut succeeded = false;
+        for city in cities {
+            if jobs.len() >= self.limit {
+                break;
+            }
+            match self.fetch_search(&self.build_url(city)).await {
+                Ok(found) => {
+                    succeeded = true;
+                    for job in found {
+                        if jobs.len() < self.limit && !jobs.iter().any(|seen| seen.url == job.url) {
+                            jobs.push(job);
+                        }
+                    }
+                }
+                Err(error) => {
+                    tracing::warn!(has_city = city.is_some(), %error, "BuiltIn search failed");
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        if let (false, Some(error)) = (succeeded, first_error) {
+            return Err(error);
+        }
+        tracing::info!("Found {} jobs from BuiltIn", jobs.len());
+        Ok(jobs)
+    }
 
+    /// Fetch one BuiltIn search page
+    async fn fetch_search(&self, url: &str) -> ScraperResult {
         // Use rate limiter (job board, reasonable limit)
         self.rate_limiter.wait("builtin", 300).await;
 
-        let url = self.build_url();
-
         let response = send_external_http_text_with_retry(
-            ExternalHttpRequest::get(&url)
+            ExternalHttpRequest::get(url)
                 .user_agent(MINIMAL_BROWSER_USER_AGENT)
                 .header("Accept", "text/html,application/xhtml+xml"),
         )
@@ -76,16 +171,12 @@ impl BuiltInScraper {
         if !(200..300).contains(&response.status) {
             return Err(ScraperError::http_status(
                 response.status,
-                &url,
+                url,
                 format!("BuiltIn request failed: {}", response.status),
             ));
         }
 
-        let html = response.body;
-        let jobs = self.parse_html(&html)?;
-
-        tracing::info!("Found {} jobs from BuiltIn", jobs.len());
-        Ok(jobs)
+        self.parse_html(&response.body)
     }
 
     /// Parse HTML to extract job listings
@@ -241,6 +332,33 @@ impl BuiltInScraper {
     }
 }
 
+/// BuiltIn's `(city, state)` filter for a configured city.
+///
+/// Former regional site names map to their metro; anything else is read as
+/// "City" or "City, State".
+fn builtin_city_filter(city: &str) -> Option<(String, Option<String>)> {
+    let normalized = city.trim().to_lowercase();
+    let hub = normalized
+        .trim_start_matches("https://")
+        .trim_start_matches("www.")
+        .trim_end_matches(".com")
+        .trim_start_matches("builtin");
+    if let Some((_, city, state)) = BUILTIN_HUBS
+        .iter()
+        .find(|(aliases, _, _)| aliases.contains(&hub.trim()))
+    {
+        return Some(((*city).to_string(), Some((*state).to_string())));
+    }
+
+    let mut parts = city.splitn(2, ',').map(str::trim);
+    let name = parts.next().filter(|name| !name.is_empty())?;
+    let state = parts
+        .next()
+        .filter(|state| !state.is_empty())
+        .map(ToOwned::to_owned);
+    Some((name.to_string(), state))
+}
+
 #[async_trait]
 impl JobScraper for BuiltInScraper {
     async fn scrape(&self) -> ScraperResult {
@@ -3,13 +3,54 @@ use super::*;
 #[test]
 fn test_build_url_default() {
     let scraper = BuiltInScraper::new(false, 50);
-    assert_eq!(scraper.build_url(), "https://builtin.com/jobs");
+    assert_eq!(scraper.build_url(None), "https://builtin.com/jobs");
 }
 
 #[test]
 fn test_build_url_remote() {
     let scraper = BuiltInScraper::new(true, 50);
-    assert_eq!(scraper.build_url(), "https://builtin.com/jobs/remote");
+    assert_eq!(scraper.build_url(None), "https://builtin.com/jobs/remote");
+}
+
+#[test]
+fn test_build_url_with_search_and_city() {
+    let scraper = BuiltInScraper::new(false, 50).with_search(
+        Some(" platform engineer ".to_string()),
+        vec!["Denver, CO".to_string(), " ".to_string()],
+    );
+
+    assert_eq!(scraper.cities, vec!["Denver, CO"]);
+    assert_eq!(
+        scraper.build_url(Some("Denver, CO")),
+        "https://builtin.com/jobs?search=platform%20engineer&city=Denver&state=CO&country=USA"
+    );
+}
+
+#[test]
+fn test_build_url_maps_former_regional_sites() {
+    let scraper = BuiltInScraper::new(false, 50);
+
+    for hub in ["nyc", "builtinnyc", "www.builtinnyc.com"] {
+        assert_eq!(
+            scraper.build_url(Some(hub)),
+            "https://builtin.com/jobs?city=New%20York&state=New%20York&country=USA"
+        );
+    }
+    assert_eq!(
+        scraper.build_url(Some("BuiltInSF")),
+        "https://builtin.com/jobs?city=San%20Francisco&state=California&country=USA"
+    );
+}
+
+#[test]
+fn test_build_url_remote_search_skips_city() {
+    let scraper = BuiltInScraper::new(true, 50)
+        .with_search(Some("rust".to_string()), vec!["nyc".to_string()]);
+
+    assert_eq!(
+        scraper.build_url(None),
+        "https://builtin.com/jobs/remote?search=rust"
+    );
 }
 
 #[test]
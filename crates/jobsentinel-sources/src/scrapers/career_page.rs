@@ -0,0 +1,302 @@
+//! Company Career Page Scraper
+//!
+//! Reads jobs from career pages that are not on a supported ATS. Pages flagged
+//! for rendering are loaded through a [`PageRenderer`] (headless Chrome in the
+//! desktop app) because their job lists only exist after client-side scripts
+//! run. Jobs come from Schema.org JobPosting data when the page has it, and
+//! otherwise from links that point at individual postings.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use jobsentinel_domain::Job;
+use jobsentinel_network::{send_external_http_text_with_retry, ExternalHttpRequest};
+use jobsentinel_security::validate_external_https_url;
+use scraper::{Html, Selector};
+use url::Url;
+
+use super::error::ScraperError;
+use super::{
+    collect_company_scrape_result, has_bot_protection_marker, require_company_scrape_success,
+    JobScraper, ScraperResult, BROWSER_USER_AGENT,
+};
+use crate::job_page::parse_job_pages;
+
+const SOURCE: &str = "career_pages";
+
+/// URL path segments that usually lead to one posting.
+const JOB_PATH_MARKERS: &[&str] = &[
+    "/job/",
+    "/jobs/",
+    "/career/",
+    "/careers/",
+    "/position/",
+    "/positions/",
+    "/opening/",
+    "/openings/",
+    "/vacancy/",
+    "/vacancies/",
+    "/posting/",
+    "/postings/",
+];
+
+/// Query parameters ATS embeds use for a single posting.
+const JOB_QUERY_MARKERS: &[&str] = &["gh_jid=", "jobid=", "job_id=", "jobreq="];
+
+/// Link text that points at navigation rather than a posting.
+const GENERIC_LINK_TEXT: &[&str] = &[
+    "apply",
+    "apply now",
+    "careers",
+    "jobs",
+    "learn more",
+    "open positions",
+    "read more",
+    "see all jobs",
+    "view all jobs",
+    "view job",
+    "view openings",
+];
+
+const MIN_TITLE_CHARS: usize = 3;
+const MAX_TITLE_CHARS: usize = 150;
+
+/// Renders a JavaScript-driven page and returns the resulting DOM as HTML.
+#[async_trait]
+pub trait PageRenderer: Send + Sync {
+    async fn render_html(&self, url: &str) -> Result<String, ScraperError>;
+}
+
+/// A company career page to check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CareerPage {
+    pub url: String,
+    /// Company name used for jobs that do not name their employer
+    pub company: String,
+    /// Load through the page renderer instead of a plain HTTP fetch
+    pub render: bool,
+}
+
+/// Career page scraper
+#[derive(Clone)]
+pub struct CareerPageScraper {
+    pub pages: Vec<CareerPage>,
+    /// Maximum jobs kept per page
+    pub limit: usize,
+    renderer: Option<Arc<dyn PageRenderer>>,
+}
+
+impl CareerPageScraper {
+    pub fn new(pages: Vec<CareerPage>, limit: usize) -> Self {
+        Self {
+            pages,
+            limit,
+            renderer: None,
+        }
+    }
+
+    /// Use `renderer` for pages flagged with `render`.
+    #[must_use]
+    pub fn with_renderer(mut self, renderer: Arc<dyn PageRenderer>) -> Self {
+        self.renderer = Some(renderer);
+        self
+    }
+
+    async fn scrape_page(&self, page: &CareerPage) -> ScraperResult {
+        let page_url =
+            validate_external_https_url(&page.url).map_err(|reason| ScraperError::InvalidUrl {
+                url: page.url.clone(),
+                reason,
+            })?;
+
+        let html = if page.render {
+            let renderer =
+                self.renderer
+                    .as_ref()
+                    .ok_or_else(|| ScraperError::InvalidConfiguration {
+                        scraper: SOURCE.to_string(),
+                        message: "Rendered career pages need the desktop browser".to_string(),
+                    })?;
+            renderer.render_html(page_url.as_str()).await?
+        } else {
+            fetch_page_html(page_url.as_str()).await?
+        };
+
+        if has_bot_protection_marker(&html, &[]) {
+            return Err(ScraperError::BotProtection {
+                url: page.url.clone(),
+                protection_type: "challenge page".to_string(),
+            });
+        }
+
+        let mut jobs = parse_career_page_jobs(&html, &page_url, &page.company);
+        jobs.truncate(self.limit);
+        tracing::info!(
+            jobs_found = jobs.len(),
+            rendered = page.render,
+            "Career page checked"
+        );
+        Ok(jobs)
+    }
+}
+
+impl std::fmt::Debug for CareerPageScraper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CareerPageScraper")
+            .field("pages", &self.pages.len())
+            .field("limit", &self.limit)
+            .field("has_renderer", &self.renderer.is_some())
+            .finish()
+    }
+}
+
+async fn fetch_page_html(url: &str) -> Result<String, ScraperError> {
+    let response = send_external_http_text_with_retry(
+        ExternalHttpRequest::get(url)
+            .user_agent(BROWSER_USER_AGENT)
+            .header("Accept", "text/html,application/xhtml+xml"),
+    )
+    .await
+    .map_err(|error| ScraperError::from_external(SOURCE, error))?;
+
+    if !(200..300).contains(&response.status) {
+        return Err(ScraperError::http_status(
+            response.status,
+            url,
+            "Career page request failed",
+        ));
+    }
+    Ok(response.body)
+}
+
+/// Jobs found in a career page's HTML.
+///
+/// Structured JobPosting data wins; job-detail links are the fallback for
+/// pages that only render a list of titles.
+pub(crate) fn parse_career_page_jobs(html: &str, page_url: &Url, company: &str) -> Vec<Job> {
+    let company = if company.trim().is_empty() {
+        page_url.host_str().unwrap_or_default()
+    } else {
+        company.trim()
+    };
+
+    let structured: Vec<Job> = parse_job_pages(html)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|posting| !posting.title.is_empty())
+        .map(|posting| {
+            let url = posting
+                .url
+                .as_deref()
+                .and_then(|url| page_url.join(url).ok())
+                .map_or_else(|| page_url.to_string(), String::from);
+            let employer = if posting.company.is_empty() {
+                company.to_string()
+            } else {
+                posting.company
+            };
+            Job {
+                description: posting.description,
+                remote: posting.remote.then_some(true),
+                salary_min: posting.salary_min,
+                salary_max: posting.salary_max,
+                currency: posting.currency,
+                ..Job::newly_discovered(
+                    posting.title,
+                    employer,
+                    url,
+                    posting.location,
+                    SOURCE,
+                    Utc::now(),
+                )
+            }
+        })
+        .collect();
+    if !structured.is_empty() {
+        return structured;
+    }
+
+    job_links(html, page_url)
+        .into_iter()
+        .map(|(title, url)| Job::newly_discovered(title, company, url, None, SOURCE, Utc::now()))
+        .collect()
+}
+
+/// `(title, absolute url)` for links that look like single postings.
+fn job_links(html: &str, page_url: &Url) -> Vec<(String, String)> {
+    let Ok(selector) = Selector::parse("a[href]") else {
+        return Vec::new();
+    };
+    let document = Html::parse_document(html);
+    let mut seen = HashSet::new();
+
+    document
+        .select(&selector)
+        .filter_map(|link| {
+            let href = link.value().attr("href")?;
+            let url = page_url.join(href).ok()?;
+            if !matches!(url.scheme(), "http" | "https") || !is_job_detail_url(&url, page_url) {
+                return None;
+            }
+            let title = link.text().collect::<Vec<_>>().join(" ");
+            let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+            let title_chars = title.chars().count();
+            if !(MIN_TITLE_CHARS..=MAX_TITLE_CHARS).contains(&title_chars)
+                || GENERIC_LINK_TEXT.contains(&title.to_lowercase().as_str())
+            {
+                return None;
+            }
+            seen.insert(url.to_string())
+                .then(|| (title, url.to_string()))
+        })
+        .collect()
+}
+
+fn is_job_detail_url(url: &Url, page_url: &Url) -> bool {
+    if url.path() == page_url.path() && url.query() == page_url.query() {
+        return false;
+    }
+    let lower = url.as_str().to_lowercase();
+    if JOB_QUERY_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+    {
+        return true;
+    }
+    let path = url.path().to_lowercase();
+    JOB_PATH_MARKERS.iter().any(|marker| {
+        path.find(marker)
+            .is_some_and(|index| !path[index + marker.len()..].trim_matches('/').is_empty())
+    })
+}
+
+#[async_trait]
+impl JobScraper for CareerPageScraper {
+    async fn scrape(&self) -> ScraperResult {
+        let mut jobs = Vec::new();
+        let mut failed_pages = 0;
+
+        for page in &self.pages {
+            collect_company_scrape_result(
+                self.scrape_page(page).await,
+                &mut jobs,
+                &mut failed_pages,
+                SOURCE,
+            );
+        }
+
+        require_company_scrape_success(self.pages.len(), failed_pages, SOURCE)?;
+        Ok(jobs)
+    }
+
+    #[cfg(test)]
+    fn name(&self) -> &'static str {
+        SOURCE
+    }
+}
+
+#[cfg(test)]
+#[path = "career_page_tests.rs"]
+mod tests;
@@ -0,0 +1,127 @@
+use super::*;
+
+struct FixedRenderer(&'static str);
+
+#[async_trait]
+impl PageRenderer for FixedRenderer {
+    async fn render_html(&self, _url: &str) -> Result<String, ScraperError> {
+        Ok(self.0.to_string())
+    }
+}
+
+fn page(url: &str, render: bool) -> CareerPage {
+    CareerPage {
+        url: url.to_string(),
+        company: "Harbor Care".to_string(),
+        render,
+    }
+}
+
+fn careers_url() -> Url {
+    Url::parse("https://harborcare.example/careers").unwrap()
+}
+
+#[test]
+fn structured_postings_are_preferred_over_links() {
+    let html = r#"
+        <script type="application/ld+json">
+        [{"@type": "JobPosting", "title": "Case Manager", "url": "/careers/case-manager",
+          "jobLocationType": "TELECOMMUTE"},
+         {"@type": "JobPosting", "title": "Care Coordinator",
+          "hiringOrganization": {"name": "Harbor Health"}}]
+        </script>
+        <a href="/careers/intake-specialist">Intake Specialist</a>
+    "#;
+
+    let jobs = parse_career_page_jobs(html, &careers_url(), "Harbor Care");
+
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].title, "Case Manager");
+    assert_eq!(jobs[0].company, "Harbor Care");
+    assert_eq!(
+        jobs[0].url,
+        "https://harborcare.example/careers/case-manager"
+    );
+    assert_eq!(jobs[0].remote, Some(true));
+    assert_eq!(jobs[0].source, "career_pages");
+    assert_eq!(jobs[1].company, "Harbor Health");
+    assert_eq!(jobs[1].url, "https://harborcare.example/careers");
+}
+
+#[test]
+fn job_links_skip_navigation_and_duplicates() {
+    let html = r#"
+        <nav><a href="/careers">Careers</a><a href="/careers/">View all jobs</a></nav>
+        <ul>
+          <li><a href="/careers/123-case-manager">
+              Case   Manager</a></li>
+          <li><a href="/careers/123-case-manager">Case Manager</a></li>
+          <li><a href="https://boards.example/embed?gh_jid=42">Peer Support Specialist</a></li>
+          <li><a href="/careers/456/apply">Apply</a></li>
+          <li><a href="/about">About us</a></li>
+          <li><a href="mailto:jobs@harborcare.example">jobs/openings@harborcare</a></li>
+        </ul>
+    "#;
+
+    let jobs = parse_career_page_jobs(html, &careers_url(), "");
+
+    let found: Vec<(&str, &str)> = jobs
+        .iter()
+        .map(|job| (job.title.as_str(), job.url.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                "Case Manager",
+                "https://harborcare.example/careers/123-case-manager"
+            ),
+            (
+                "Peer Support Specialist",
+                "https://boards.example/embed?gh_jid=42"
+            ),
+        ]
+    );
+    assert_eq!(jobs[0].company, "harborcare.example");
+}
+
+#[tokio::test]
+async fn rendered_pages_use_the_renderer() {
+    let scraper =
+        CareerPageScraper::new(vec![page("https://harborcare.example/careers", true)], 10)
+            .with_renderer(Arc::new(FixedRenderer(
+                r#"<div id="app"><a href="/careers/7">Housing Navigator</a></div>"#,
+            )));
+
+    let jobs = scraper.scrape().await.unwrap();
+
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].title, "Housing Navigator");
+    assert_eq!(scraper.name(), "career_pages");
+}
+
+#[tokio::test]
+async fn rendered_pages_without_renderer_fail() {
+    let scraper =
+        CareerPageScraper::new(vec![page("https://harborcare.example/careers", true)], 10);
+    assert!(scraper.scrape().await.is_err());
+}
+
+#[tokio::test]
+async fn rendered_challenge_pages_are_reported_as_bot_protection() {
+    let scraper =
+        CareerPageScraper::new(vec![page("https://harborcare.example/careers", true)], 10)
+            .with_renderer(Arc::new(FixedRenderer("<p>Verify you are human</p>")));
+
+    let error = scraper.scrape_page(&scraper.pages[0]).await.unwrap_err();
+    assert!(matches!(error, ScraperError::BotProtection { .. }));
+}
+
+#[tokio::test]
+async fn insecure_page_urls_are_rejected_before_fetching() {
+    let scraper =
+        CareerPageScraper::new(vec![page("http://harborcare.example/careers", false)], 10);
+
+    let error = scraper.scrape_page(&scraper.pages[0]).await.unwrap_err();
+    assert!(matches!(error, ScraperError::InvalidUrl { .. }));
+}
@@ -0,0 +1,139 @@
+//! Guardian Jobs Scraper
+//!
+//! Reads the latest jobs from Guardian Jobs' public RSS feed. Guardian Jobs
+//! lists roles at employers across the United Kingdom, from charities and
+//! councils to universities and private companies.
+
+use super::error::ScraperError;
+use super::rate_limiter::{limits, RateLimiter};
+use super::rss::{parse_rss_items, RssItem};
+use super::{
+    decode_common_html_entities, source_skill_tags, strip_html_markup, JobScraper, ScraperResult,
+    JOBSENTINEL_USER_AGENT,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+use jobsentinel_network::{send_external_http_text_with_retry, ExternalHttpRequest};
+
+const GUARDIAN_JOBS_RSS_URL: &str = "https://jobs.theguardian.com/jobsrss/";
+/// Location for items that do not name one; the board lists UK roles.
+const DEFAULT_LOCATION: &str = "United Kingdom";
+
+/// Guardian Jobs scraper
+#[derive(Debug, Clone)]
+pub struct GuardianJobsScraper {
+    /// Maximum results to return
+    pub limit: usize,
+    /// Rate limiter for respecting Guardian Jobs' request limits
+    pub rate_limiter: RateLimiter,
+}
+
+impl GuardianJobsScraper {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            rate_limiter: RateLimiter::shared(),
+        }
+    }
+
+    async fn fetch_jobs(&self) -> ScraperResult {
+        tracing::info!("Fetching jobs from Guardian Jobs");
+
+        self.rate_limiter
+            .wait("guardian_jobs", limits::GUARDIAN_JOBS)
+            .await;
+
+        let response = send_external_http_text_with_retry(
+            ExternalHttpRequest::get(GUARDIAN_JOBS_RSS_URL).user_agent(JOBSENTINEL_USER_AGENT),
+        )
+        .await
+        .map_err(|error| ScraperError::from_external("guardian_jobs", error))?;
+
+        if !(200..300).contains(&response.status) {
+            return Err(ScraperError::http_status(
+                response.status,
+                GUARDIAN_JOBS_RSS_URL,
+                format!("Guardian Jobs feed failed: {}", response.status),
+            ));
+        }
+
+        let jobs = self.parse_rss(&response.body)?;
+        tracing::info!("Found {} jobs from Guardian Jobs", jobs.len());
+        Ok(jobs)
+    }
+
+    fn parse_rss(&self, xml: &str) -> Result<Vec<Job>, ScraperError> {
+        let items =
+            parse_rss_items(xml, self.limit).map_err(|source| ScraperError::ParseError {
+                format: "RSS".to_string(),
+                url: GUARDIAN_JOBS_RSS_URL.to_string(),
+                source,
+            })?;
+
+        Ok(items.iter().filter_map(Self::parse_item).collect())
+    }
+
+    /// Parse one job from a feed `<item>`
+    fn parse_item(item: &RssItem) -> Option<Job> {
+        let title = item
+            .get("title")
+            .map(|title| decode_common_html_entities(title.trim()))
+            .filter(|title| !title.is_empty())?;
+        let url = item
+            .get("link")
+            .map(str::trim)
+            .filter(|url| url.starts_with("https://"))?;
+        let company = item
+            .get("dc:creator")
+            .or_else(|| item.get("author"))
+            .map(str::trim)
+            .unwrap_or("Unknown");
+        let location = item
+            .get("location")
+            .map(str::trim)
+            .unwrap_or(DEFAULT_LOCATION);
+        let description = item
+            .get("description")
+            .map(|html| {
+                strip_html_markup(&decode_common_html_entities(html))
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|text| !text.is_empty());
+        let discovered_at = item
+            .get("pubDate")
+            .and_then(|date| DateTime::parse_from_rfc2822(date.trim()).ok())
+            .map_or_else(Utc::now, |date| date.with_timezone(&Utc));
+
+        Some(Job {
+            description,
+            skills: source_skill_tags(item.get("category")),
+            currency: Some("GBP".to_string()),
+            ..Job::newly_discovered(
+                title,
+                company.to_string(),
+                url.to_string(),
+                Some(location.to_string()),
+                "guardian_jobs",
+                discovered_at,
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl JobScraper for GuardianJobsScraper {
+    async fn scrape(&self) -> ScraperResult {
+        self.fetch_jobs().await
+    }
+
+    #[cfg(test)]
+    fn name(&self) -> &'static str {
+        "guardian_jobs"
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,65 @@
+use super::*;
+
+const SAMPLE_FEED: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Guardian Jobs</title>
+    <item>
+      <title>Data Analyst &amp; Reporting Lead</title>
+      <link>https://jobs.theguardian.com/job/9012345/data-analyst/</link>
+      <description><![CDATA[<p>Join our <strong>insight</strong> team in Manchester.</p>]]></description>
+      <dc:creator>Example Housing Trust</dc:creator>
+      <category>Data</category>
+      <pubDate>Tue, 14 Nov 2023 22:13:20 GMT</pubDate>
+    </item>
+    <item>
+      <title>   </title>
+      <link>https://jobs.theguardian.com/job/9012346/untitled/</link>
+    </item>
+    <item>
+      <title>Policy Officer</title>
+      <link>http://jobs.theguardian.com/job/9012347/policy-officer/</link>
+    </item>
+  </channel>
+</rss>"#;
+
+#[test]
+fn parses_complete_item() {
+    let jobs = GuardianJobsScraper::new(10).parse_rss(SAMPLE_FEED).unwrap();
+    let job = &jobs[0];
+
+    assert_eq!(job.title, "Data Analyst & Reporting Lead");
+    assert_eq!(job.company, "Example Housing Trust");
+    assert_eq!(
+        job.url,
+        "https://jobs.theguardian.com/job/9012345/data-analyst/"
+    );
+    assert_eq!(job.location.as_deref(), Some("United Kingdom"));
+    assert_eq!(job.source, "guardian_jobs");
+    assert_eq!(job.currency.as_deref(), Some("GBP"));
+    assert_eq!(
+        job.description.as_deref(),
+        Some("Join our insight team in Manchester.")
+    );
+    assert_eq!(job.skills, vec!["Data"]);
+    assert_eq!(job.created_at.timestamp(), 1_700_000_000);
+}
+
+#[test]
+fn skips_items_without_title_or_https_link() {
+    let jobs = GuardianJobsScraper::new(10).parse_rss(SAMPLE_FEED).unwrap();
+
+    assert_eq!(jobs.len(), 1);
+}
+
+#[test]
+fn parse_rss_respects_the_limit() {
+    let jobs = GuardianJobsScraper::new(0).parse_rss(SAMPLE_FEED).unwrap();
+
+    assert!(jobs.is_empty());
+}
+
+#[test]
+fn scraper_name_is_guardian_jobs() {
+    assert_eq!(GuardianJobsScraper::new(5).name(), "guardian_jobs");
+}
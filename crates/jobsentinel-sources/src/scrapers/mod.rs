@@ -6,7 +6,11 @@ use async_trait::async_trait;
 use jobsentinel_domain::Job;
 use jobsentinel_network::FULL_BROWSER_USER_AGENT as BROWSER_USER_AGENT;
 
-const JOBSENTINEL_USER_AGENT: &str = "JobSentinel/1.0";
+pub(crate) const JOBSENTINEL_USER_AGENT: &str = "JobSentinel/1.0";
+/// Most source tags kept per job, so tag-heavy postings cannot flood skills.
+const MAX_SOURCE_SKILL_TAGS: usize = 20;
+/// Longest source tag kept as a skill; longer values are free text, not tags.
+const MAX_SOURCE_SKILL_TAG_CHARS: usize = 50;
 const COMPANY_SCRAPE_FAILED: &str =
     "Company board scrape failed; continuing with other company boards";
 const COMMON_BOT_PROTECTION_MARKERS: &[&str] = &[
@@ -52,6 +56,26 @@ fn strip_html_markup(html: &str) -> String {
     result
 }
 
+/// Turn board tags into job skills: trimmed, de-duplicated ignoring case,
+/// and bounded in count and length.
+fn source_skill_tags<'a>(tags: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut skills: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = decode_common_html_entities(tag.trim());
+        if tag.is_empty()
+            || tag.chars().count() > MAX_SOURCE_SKILL_TAG_CHARS
+            || skills.iter().any(|skill| skill.eq_ignore_ascii_case(&tag))
+        {
+            continue;
+        }
+        skills.push(tag);
+        if skills.len() == MAX_SOURCE_SKILL_TAGS {
+            break;
+        }
+    }
+    skills
+}
+
 fn collect_company_scrape_result(
     result: ScraperResult,
     jobs: &mut Vec<Job>,
@@ -85,11 +109,14 @@ fn require_company_scrape_success(
     Ok(())
 }
 
+mod arbeitnow;
 mod builtin;
+mod career_page;
 mod dice;
 mod error;
 mod glassdoor;
 mod greenhouse;
+mod guardian_jobs;
 mod hn_hiring;
 mod jobswithgpt;
 mod lever;
@@ -104,11 +131,14 @@ mod usajobs;
 mod weworkremotely;
 mod yc_startup;
 
+pub use arbeitnow::ArbeitnowScraper;
 pub use builtin::BuiltInScraper;
+pub use career_page::{CareerPage, CareerPageScraper, PageRenderer};
 pub use dice::DiceScraper;
 pub use error::ScraperError;
 pub use glassdoor::GlassdoorScraper;
 pub use greenhouse::{GreenhouseCompany, GreenhouseScraper};
+pub use guardian_jobs::GuardianJobsScraper;
 pub use hn_hiring::HnHiringScraper;
 pub use jobswithgpt::{JobQuery, JobsWithGptScraper};
 pub use lever::{LeverCompany, LeverScraper};
@@ -194,6 +194,12 @@ pub mod limits {
 
     /// BuiltIn: 300 requests/hour (job board)
     pub const BUILTIN: u32 = 300;
+
+    /// Arbeitnow: 300 requests/hour (public API)
+    pub const ARBEITNOW: u32 = 300;
+
+    /// Guardian Jobs: 300 requests/hour (RSS feed)
+    pub const GUARDIAN_JOBS: u32 = 300;
 }
 
 #[cfg(test)]
@@ -5,7 +5,7 @@
 
 use super::error::ScraperError;
 use super::rate_limiter::RateLimiter;
-use super::{JobScraper, ScraperResult, JOBSENTINEL_USER_AGENT};
+use super::{source_skill_tags, JobScraper, ScraperResult, JOBSENTINEL_USER_AGENT};
 use async_trait::async_trait;
 use chrono::Utc;
 use jobsentinel_domain::Job;
@@ -98,8 +98,11 @@ impl RemoteOkScraper {
             .map(|d| d.to_lowercase())
             .unwrap_or_default();
 
-        tags.iter()
-            .any(|tag| title_lower.contains(tag) || description_lower.contains(tag))
+        tags.iter().any(|tag| {
+            title_lower.contains(tag)
+                || description_lower.contains(tag)
+                || job.skills.iter().any(|skill| skill.to_lowercase() == *tag)
+        })
     }
 
     /// Parse a job from RemoteOK JSON response
@@ -133,8 +136,15 @@ impl RemoteOkScraper {
         let salary_min = data["salary_min"].as_i64();
         let salary_max = data["salary_max"].as_i64();
 
+        // Board tags ("rust", "support", "senior") become the job's skills
+        let skills = data["tags"]
+            .as_array()
+            .map(|tags| source_skill_tags(tags.iter().filter_map(|tag| tag.as_str())))
+            .unwrap_or_default();
+
         Ok(Some(Job {
             description,
+            skills,
             remote: Some(true), // All RemoteOK jobs are remote
             salary_min,
             salary_max,
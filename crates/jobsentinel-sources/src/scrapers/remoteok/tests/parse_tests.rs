@@ -410,3 +410,19 @@ fn test_remote_always_true() {
     // All RemoteOK jobs are remote by definition
     assert_eq!(job.remote, Some(true));
 }
+
+#[test]
+fn test_parse_job_maps_tags_to_skills() {
+    let scraper = RemoteOkScraper::new(vec![], 10);
+
+    let job_data = serde_json::json!({
+        "position": "Support Engineer",
+        "company": "RemoteTech",
+        "url": "/job/789",
+        "tags": [" customer support ", "zendesk", "Zendesk", "", 42]
+    });
+
+    let job = scraper.parse_job(&job_data).unwrap().unwrap();
+
+    assert_eq!(job.skills, vec!["customer support", "zendesk"]);
+}
@@ -96,3 +96,12 @@ fn test_tags_lowercase_conversion() {
 
     assert!(scraper.job_matches_tags(&job("RUST developer", None), &tags));
 }
+
+#[test]
+fn test_job_matches_tags_in_board_skills() {
+    let tags = vec!["zendesk".to_string()];
+    let mut job = job("Support Engineer", None);
+    job.skills = vec!["Zendesk".to_string()];
+
+    assert!(RemoteOkScraper::new(tags.clone(), 10).job_matches_tags(&job, &tags));
+}
@@ -9,7 +9,7 @@ use super::rate_limiter::RateLimiter;
 use super::rss::extract_xml_tag;
 use super::rss::parse_rss_items;
 use super::{
-    decode_common_html_entities, strip_html_markup, JobScraper, ScraperResult,
+    decode_common_html_entities, source_skill_tags, strip_html_markup, JobScraper, ScraperResult,
     JOBSENTINEL_USER_AGENT,
 };
 use jobsentinel_domain::Job;
@@ -116,8 +116,17 @@ impl WeWorkRemotelyScraper {
             // Try to extract location from description
             let location = description.as_ref().and_then(|d| Self::extract_location(d));
 
+            // The feed's comma-separated skills and its category become skills
+            let skills = source_skill_tags(
+                item.get("skills")
+                    .into_iter()
+                    .flat_map(|skills| skills.split(','))
+                    .chain(item.get("category")),
+            );
+
             jobs.push(Job {
                 description,
+                skills,
                 remote: Some(true), // All WeWorkRemotely jobs are remote
                 ..Job::newly_discovered(
                     job_title,
@@ -185,3 +185,28 @@ fn test_new_constructor() {
     assert_eq!(scraper.category, Some("design".to_string()));
     assert_eq!(scraper.limit, 25);
 }
+
+#[test]
+fn test_parse_rss_maps_skills_and_category() {
+    let scraper = WeWorkRemotelyScraper::new(None, 10);
+    let rss = r#"
+        <rss>
+            <channel>
+                <item>
+                    <title>Company: Support Specialist</title>
+                    <link>https://weworkremotely.com/jobs/42</link>
+                    <category>Customer Support</category>
+                    <skills>Zendesk, Intercom , customer support</skills>
+                </item>
+            </channel>
+        </rss>
+    "#;
+
+    let jobs = scraper.parse_rss(rss).expect("parse_rss should succeed");
+
+    assert_eq!(jobs[0].remote, Some(true));
+    assert_eq!(
+        jobs[0].skills,
+        vec!["Zendesk", "Intercom", "customer support"]
+    );
+}
@@ -0,0 +1,10 @@
+-- Per-domain consent for Application Assist browser actions.
+-- A row means the user allowed JobSentinel to open, fill, and attach a resume
+-- on that exact host. Revoking deletes the row.
+
+CREATE TABLE IF NOT EXISTS automation_permissions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain TEXT NOT NULL UNIQUE,
+    granted_at TIMESTAMP NOT NULL DEFAULT (datetime('now')),
+    last_used_at TIMESTAMP
+);
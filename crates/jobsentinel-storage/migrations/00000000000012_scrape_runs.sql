@@ -0,0 +1,33 @@
+-- History of full scraping cycles, so users can see why a run found nothing.
+-- One scrape_runs row per cycle; one scrape_run_sources row per source that
+-- finished in that cycle. Errors are stored as JSON arrays of user-facing
+-- messages (no credentials, URLs with secrets, or job content).
+
+CREATE TABLE IF NOT EXISTS scrape_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    started_at TIMESTAMP NOT NULL DEFAULT (datetime('now')),
+    finished_at TIMESTAMP,
+    status TEXT NOT NULL DEFAULT 'running' CHECK(status IN ('running', 'completed', 'completed_with_errors')),
+    sources_total INTEGER NOT NULL DEFAULT 0,
+    jobs_found INTEGER NOT NULL DEFAULT 0,
+    jobs_new INTEGER NOT NULL DEFAULT 0,
+    jobs_updated INTEGER NOT NULL DEFAULT 0,
+    high_matches INTEGER NOT NULL DEFAULT 0,
+    alerts_sent INTEGER NOT NULL DEFAULT 0,
+    errors TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX IF NOT EXISTS idx_scrape_runs_started ON scrape_runs(started_at DESC);
+
+CREATE TABLE IF NOT EXISTS scrape_run_sources (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL REFERENCES scrape_runs(id) ON DELETE CASCADE,
+    source TEXT NOT NULL,
+    finished_at TIMESTAMP NOT NULL DEFAULT (datetime('now')),
+    jobs_found INTEGER NOT NULL DEFAULT 0,
+    jobs_new INTEGER NOT NULL DEFAULT 0,
+    jobs_updated INTEGER NOT NULL DEFAULT 0,
+    errors TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX IF NOT EXISTS idx_scrape_run_sources_run ON scrape_run_sources(run_id);
@@ -0,0 +1,18 @@
+-- Job search pause ("vacation mode").
+-- A single settings row records whether the pause is on and whether
+-- scheduled scraping keeps running. High-match alerts that would have been
+-- sent while away are queued for the "while you were away" summary.
+
+CREATE TABLE IF NOT EXISTS vacation_mode (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled INTEGER NOT NULL DEFAULT 0,
+    keep_scraping INTEGER NOT NULL DEFAULT 1,
+    started_at TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS vacation_queued_alerts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_hash TEXT NOT NULL UNIQUE,
+    score REAL NOT NULL,
+    queued_at TIMESTAMP NOT NULL DEFAULT (datetime('now'))
+);
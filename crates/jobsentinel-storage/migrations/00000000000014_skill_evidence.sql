@@ -0,0 +1,20 @@
+-- Evidence backing resume skills.
+-- Each row links a user skill to a project, repository, certification, or
+-- other proof. Certifications may carry an expiry date so the app can warn
+-- before the credential backing a skill lapses.
+
+CREATE TABLE IF NOT EXISTS skill_evidence (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    skill_id INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK(kind IN ('project', 'repository', 'certification', 'other')),
+    title TEXT NOT NULL,
+    url TEXT,
+    issued_on TEXT, -- YYYY-MM-DD
+    expires_on TEXT, -- YYYY-MM-DD
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (skill_id) REFERENCES user_skills(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_skill_evidence_skill ON skill_evidence(skill_id);
+CREATE INDEX IF NOT EXISTS idx_skill_evidence_expires ON skill_evidence(expires_on)
+    WHERE expires_on IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Arbeitnow country board (Germany and neighbouring EU countries)
+INSERT OR IGNORE INTO scraper_config (scraper_name, display_name, requires_auth, auth_type, scraper_type, rate_limit_per_hour) VALUES
+    ('arbeitnow', 'Arbeitnow', 0, NULL, 'api', 300);
@@ -0,0 +1,11 @@
+-- On-demand posting refreshes and the fields each one changed
+CREATE TABLE IF NOT EXISTS job_refreshes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    refreshed_at TIMESTAMP NOT NULL,
+    -- JSON array of {"field", "before", "after"}
+    changes TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_refreshes_job
+    ON job_refreshes(job_id, refreshed_at DESC);
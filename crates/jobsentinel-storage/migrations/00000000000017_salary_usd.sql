@@ -0,0 +1,20 @@
+-- Exchange rates and US dollar salaries for pay floors and statistics
+
+-- US dollars per unit of each currency. Bundled rows are written at startup;
+-- refreshed rows replace them and are never overwritten by bundled ones.
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    currency TEXT PRIMARY KEY CHECK (length(currency) = 3),
+    usd_per_unit REAL NOT NULL CHECK (usd_per_unit > 0),
+    source TEXT NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+ALTER TABLE jobs ADD COLUMN salary_min_usd INTEGER;
+ALTER TABLE jobs ADD COLUMN salary_max_usd INTEGER;
+
+UPDATE jobs
+SET salary_min_usd = salary_min, salary_max_usd = salary_max
+WHERE currency IS NULL OR UPPER(TRIM(currency)) = 'USD';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_salary_usd
+    ON jobs(salary_min_usd, salary_max_usd);
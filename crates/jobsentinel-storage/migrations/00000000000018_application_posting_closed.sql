@@ -0,0 +1,43 @@
+-- Track when the posting behind an application is found closed.
+--
+-- applications.posting_closed_at feeds the Kanban badge and is cleared if a
+-- later check finds the posting online again. The timeline gains
+-- posting_closed and posting_reopened events; SQLite cannot change a CHECK
+-- constraint in place, so application_events is recreated with its rows.
+
+ALTER TABLE applications ADD COLUMN posting_closed_at TEXT;
+
+PRAGMA foreign_keys = OFF;
+
+CREATE TABLE application_events_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    application_id INTEGER NOT NULL,
+    event_type TEXT NOT NULL CHECK(event_type IN (
+        'status_change',
+        'email_received',
+        'email_sent',
+        'phone_call',
+        'interview_scheduled',
+        'note_added',
+        'reminder_set',
+        'posting_closed',
+        'posting_reopened'
+    )),
+    event_data TEXT, -- JSON string for flexible schema
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+INSERT INTO application_events_new (id, application_id, event_type, event_data, created_at)
+SELECT id, application_id, event_type, event_data, created_at
+FROM application_events;
+
+DROP TABLE application_events;
+ALTER TABLE application_events_new RENAME TO application_events;
+
+CREATE INDEX IF NOT EXISTS idx_application_events_application_id
+    ON application_events(application_id);
+CREATE INDEX IF NOT EXISTS idx_application_events_created_at
+    ON application_events(created_at DESC);
+
+PRAGMA foreign_keys = ON;
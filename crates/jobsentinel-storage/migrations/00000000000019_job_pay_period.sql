@@ -0,0 +1,16 @@
+-- Pay period for posted salaries, so hourly and contract rates can be
+-- annualized for scoring, filters, and salary statistics
+
+ALTER TABLE jobs ADD COLUMN pay_period TEXT
+    CHECK (pay_period IN ('hour', 'day', 'week', 'month', 'year'));
+
+-- Earlier scoring treated small amounts with no period as hourly pay
+UPDATE jobs
+SET pay_period = 'hour'
+WHERE COALESCE(salary_max, salary_min) > 0
+  AND COALESCE(salary_max, salary_min) < 1000;
+
+UPDATE jobs
+SET salary_min_usd = salary_min_usd * 2080,
+    salary_max_usd = salary_max_usd * 2080
+WHERE pay_period = 'hour';
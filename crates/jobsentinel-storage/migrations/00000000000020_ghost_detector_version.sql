@@ -0,0 +1,20 @@
+-- Ghost detector versions, so scores from older heuristics can be re-analyzed
+
+-- NULL means the score predates detector versioning
+ALTER TABLE jobs ADD COLUMN ghost_detector_version INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_ghost_detector_version
+    ON jobs(ghost_detector_version);
+
+-- One row per background re-analysis after a detector upgrade, counting how
+-- many postings moved into each review bucket
+CREATE TABLE IF NOT EXISTS ghost_reanalysis_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    detector_version INTEGER NOT NULL,
+    started_at TEXT NOT NULL,
+    finished_at TEXT NOT NULL,
+    jobs_reanalyzed INTEGER NOT NULL DEFAULT 0,
+    moved_to_clear INTEGER NOT NULL DEFAULT 0,
+    moved_to_warning INTEGER NOT NULL DEFAULT 0,
+    moved_to_hidden INTEGER NOT NULL DEFAULT 0
+);
@@ -0,0 +1,17 @@
+-- Thumbs-up/thumbs-down feedback on job scores, and the keyword weights
+-- learned from it
+
+-- One verdict per job; changing it replaces the earlier one
+CREATE TABLE IF NOT EXISTS job_feedback (
+    job_id INTEGER PRIMARY KEY REFERENCES jobs(id) ON DELETE CASCADE,
+    feedback TEXT NOT NULL CHECK(feedback IN ('good', 'bad')),
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Learned log-odds weight per boost keyword (lowercased), replaced as a
+-- whole each time feedback changes
+CREATE TABLE IF NOT EXISTS keyword_feedback_weights (
+    keyword TEXT PRIMARY KEY,
+    weight REAL NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
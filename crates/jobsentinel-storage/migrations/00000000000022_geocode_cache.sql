@@ -0,0 +1,10 @@
+-- Geocoding results for job locations, used for commute distances
+
+-- One row per normalized location text. NULL coordinates record a lookup
+-- that found nothing, so the same text is not looked up again right away.
+CREATE TABLE IF NOT EXISTS geocode_cache (
+    location TEXT PRIMARY KEY,
+    latitude REAL,
+    longitude REAL,
+    looked_up_at TEXT NOT NULL
+);
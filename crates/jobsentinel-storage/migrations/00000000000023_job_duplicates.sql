@@ -0,0 +1,23 @@
+-- Cross-source duplicates: the same role posted on several boards, each copy
+-- linked to the canonical job shown in place of all of them
+
+-- One row per duplicate job. Dismissed rows record that the user split the
+-- job back out; it stays visible and is never linked again.
+CREATE TABLE IF NOT EXISTS job_duplicates (
+    job_id INTEGER PRIMARY KEY REFERENCES jobs(id) ON DELETE CASCADE,
+    canonical_job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    similarity REAL NOT NULL,
+    dismissed INTEGER NOT NULL DEFAULT 0,
+    linked_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_duplicates_canonical
+    ON job_duplicates(canonical_job_id);
+
+-- Jobs that list and search queries leave out: linked copies whose canonical
+-- job is still visible
+CREATE VIEW IF NOT EXISTS collapsed_duplicate_jobs AS
+SELECT d.job_id
+FROM job_duplicates d
+JOIN jobs c ON c.id = d.canonical_job_id
+WHERE d.dismissed = 0 AND c.hidden = 0;
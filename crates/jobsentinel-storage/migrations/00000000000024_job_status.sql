@@ -0,0 +1,15 @@
+-- Job lifecycle status: whether a saved posting is still open
+--
+-- active:  listed by a source or its page still loads
+-- closed:  the page is gone (HTTP 404/410) or redirects elsewhere
+-- expired: no scrape has seen the posting for a long time
+
+ALTER TABLE jobs ADD COLUMN status TEXT NOT NULL DEFAULT 'active'
+    CHECK (status IN ('active', 'closed', 'expired'));
+-- Why the status last changed, such as 'http_410' or 'redirected'
+ALTER TABLE jobs ADD COLUMN status_reason TEXT;
+-- When the posting page was last re-fetched by the liveness check
+ALTER TABLE jobs ADD COLUMN status_checked_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_status_checked
+    ON jobs(status, status_checked_at);
@@ -0,0 +1,10 @@
+-- User tags on saved jobs, such as "referral" or "apply this week"
+
+CREATE TABLE IF NOT EXISTS job_tags (
+    job_id INTEGER NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL COLLATE NOCASE,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (job_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_tags_tag ON job_tags(tag);
@@ -0,0 +1,8 @@
+-- Indexes for structured job queries (remote and posted-date filters, and
+-- date-ordered results) over visible jobs
+
+CREATE INDEX IF NOT EXISTS idx_jobs_hidden_remote_score
+    ON jobs(hidden, remote, score DESC);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_hidden_created
+    ON jobs(hidden, created_at DESC);
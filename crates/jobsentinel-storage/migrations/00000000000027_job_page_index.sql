@@ -0,0 +1,8 @@
+-- Keyset pagination orders job lists by (created_at, id), newest first.
+-- The new index covers that order in full, so the created_at-only index
+-- it extends is no longer needed.
+
+CREATE INDEX IF NOT EXISTS idx_jobs_hidden_created_id
+    ON jobs(hidden, created_at DESC, id DESC);
+
+DROP INDEX IF EXISTS idx_jobs_hidden_created;
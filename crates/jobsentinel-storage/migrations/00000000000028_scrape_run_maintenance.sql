@@ -0,0 +1,4 @@
+-- Database maintenance that ran after a scraping cycle, as JSON with the
+-- database size before and after and the steps taken. NULL when the cycle
+-- hit no maintenance threshold.
+ALTER TABLE scrape_runs ADD COLUMN maintenance TEXT;
@@ -0,0 +1,53 @@
+-- Ranked job search. jobs_fts gains the company column and prefix indexes
+-- for search-as-you-type, and jobs_trigram indexes title and company by
+-- trigram for the misspelling fallback. Both are external-content tables,
+-- so the triggers delete with the old indexed values before reinserting.
+-- The update trigger only fires when an indexed column changes.
+DROP TRIGGER IF EXISTS jobs_ai;
+DROP TRIGGER IF EXISTS jobs_au;
+DROP TRIGGER IF EXISTS jobs_ad;
+DROP TABLE IF EXISTS jobs_fts;
+
+CREATE VIRTUAL TABLE jobs_fts USING fts5(
+    title,
+    company,
+    description,
+    content=jobs,
+    content_rowid=id,
+    prefix='2 3'
+);
+INSERT INTO jobs_fts(jobs_fts) VALUES ('rebuild');
+
+CREATE VIRTUAL TABLE jobs_trigram USING fts5(
+    title,
+    company,
+    content=jobs,
+    content_rowid=id,
+    tokenize='trigram'
+);
+INSERT INTO jobs_trigram(jobs_trigram) VALUES ('rebuild');
+
+CREATE TRIGGER jobs_ai AFTER INSERT ON jobs BEGIN
+    INSERT INTO jobs_fts(rowid, title, company, description)
+    VALUES (new.id, new.title, new.company, new.description);
+    INSERT INTO jobs_trigram(rowid, title, company)
+    VALUES (new.id, new.title, new.company);
+END;
+
+CREATE TRIGGER jobs_au AFTER UPDATE OF title, company, description ON jobs BEGIN
+    INSERT INTO jobs_fts(jobs_fts, rowid, title, company, description)
+    VALUES ('delete', old.id, old.title, old.company, old.description);
+    INSERT INTO jobs_fts(rowid, title, company, description)
+    VALUES (new.id, new.title, new.company, new.description);
+    INSERT INTO jobs_trigram(jobs_trigram, rowid, title, company)
+    VALUES ('delete', old.id, old.title, old.company);
+    INSERT INTO jobs_trigram(rowid, title, company)
+    VALUES (new.id, new.title, new.company);
+END;
+
+CREATE TRIGGER jobs_ad AFTER DELETE ON jobs BEGIN
+    INSERT INTO jobs_fts(jobs_fts, rowid, title, company, description)
+    VALUES ('delete', old.id, old.title, old.company, old.description);
+    INSERT INTO jobs_trigram(jobs_trigram, rowid, title, company)
+    VALUES ('delete', old.id, old.title, old.company);
+END;
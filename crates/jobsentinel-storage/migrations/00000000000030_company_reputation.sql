@@ -0,0 +1,13 @@
+-- Per-company rollup of saved postings, rebuilt after each scraping cycle.
+-- company_key is the company name trimmed and lowercased. fill_rate is NULL
+-- until a company has three settled postings.
+CREATE TABLE company_reputation (
+    company_key TEXT PRIMARY KEY,
+    company TEXT NOT NULL,
+    postings INTEGER NOT NULL,
+    avg_ghost_score REAL,
+    repost_rate REAL NOT NULL,
+    fill_rate REAL,
+    settled_postings INTEGER NOT NULL,
+    updated_at TEXT NOT NULL
+);
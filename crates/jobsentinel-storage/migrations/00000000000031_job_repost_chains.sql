@@ -0,0 +1,14 @@
+-- Repost chains link near-identical postings from the same company that were
+-- first seen at different times, since a reworded repost gets a new hash.
+-- chain_hash is the hash of the earliest posting in the chain, and similarity
+-- is how closely a posting matched the one it was linked to (1 for the
+-- earliest). Rows are kept after their job is deleted so the chain still
+-- counts older reposts.
+CREATE TABLE job_repost_chains (
+    job_hash TEXT PRIMARY KEY,
+    chain_hash TEXT NOT NULL,
+    similarity REAL NOT NULL,
+    linked_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_job_repost_chains_chain ON job_repost_chains(chain_hash);
@@ -0,0 +1,7 @@
+-- One row per scheduled email digest, so the next digest knows when the last
+-- one went out. Digests with no new jobs are recorded too.
+CREATE TABLE email_digests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    sent_at TEXT NOT NULL,
+    job_count INTEGER NOT NULL
+);
@@ -0,0 +1,20 @@
+-- Every immediate alert goes through this outbox so failed deliveries can be
+-- retried and users can check what was sent. score_json is the serialized
+-- match score the alert was built from. Rows move from 'pending' to 'sent',
+-- or to 'failed' once retries run out or the failure cannot be retried.
+CREATE TABLE notification_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_hash TEXT NOT NULL,
+    score REAL NOT NULL,
+    score_json TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TEXT NOT NULL,
+    next_attempt_at TEXT,
+    sent_at TEXT
+);
+
+CREATE INDEX idx_notification_outbox_due ON notification_outbox(status, next_attempt_at);
+CREATE INDEX idx_notification_outbox_created ON notification_outbox(created_at);
@@ -0,0 +1,7 @@
+-- User message templates for alert channels. A channel without a row uses
+-- JobSentinel's default message layout.
+CREATE TABLE notification_message_templates (
+    channel TEXT PRIMARY KEY CHECK (channel IN ('slack', 'discord', 'email')),
+    template TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,12 @@
+-- Weekly summary reports, kept so past weeks can be read again. Scheduled
+-- reports also tell the scheduler when the last one went out; reports made
+-- on demand do not move the schedule.
+CREATE TABLE weekly_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    period_start TEXT NOT NULL,
+    period_end TEXT NOT NULL,
+    generated_at TEXT NOT NULL,
+    scheduled INTEGER NOT NULL DEFAULT 0,
+    markdown TEXT NOT NULL,
+    html TEXT NOT NULL
+);
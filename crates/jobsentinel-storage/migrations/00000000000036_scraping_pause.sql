@@ -0,0 +1,8 @@
+-- Paused background scraping.
+-- A single row exists while scheduled scraping is paused, so the pause
+-- survives restarts. Resuming deletes the row.
+
+CREATE TABLE IF NOT EXISTS scraping_pause (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    paused_at TIMESTAMP NOT NULL
+);
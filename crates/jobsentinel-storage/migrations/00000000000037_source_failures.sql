@@ -0,0 +1,11 @@
+-- Failing scheduled sources.
+-- One row per source whose latest checks failed, counting the failures in a
+-- row so the scheduler can back off and alert. A successful check deletes
+-- the row.
+
+CREATE TABLE IF NOT EXISTS source_failures (
+    source_id TEXT PRIMARY KEY,
+    consecutive_failures INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    last_failed_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1,12 @@
+-- Offer details for comparing offers.
+-- Base salary can be quoted per hour, day, week, month, or year. Equity and
+-- benefits carry a yearly value so they count toward total pay, and the cost
+-- of living index (100 = where you live now) adjusts pay between locations.
+
+ALTER TABLE offers ADD COLUMN pay_period TEXT NOT NULL DEFAULT 'year'
+    CHECK (pay_period IN ('hour', 'day', 'week', 'month', 'year'));
+ALTER TABLE offers ADD COLUMN equity_annual_value INTEGER;
+ALTER TABLE offers ADD COLUMN benefits_annual_value INTEGER;
+ALTER TABLE offers ADD COLUMN location TEXT;
+ALTER TABLE offers ADD COLUMN cost_of_living_index REAL
+    CHECK (cost_of_living_index > 0);
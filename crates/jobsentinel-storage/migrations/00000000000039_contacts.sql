@@ -0,0 +1,115 @@
+-- Recruiter and hiring contacts.
+-- Contacts replace the recruiter_name, recruiter_email, and recruiter_phone
+-- columns on applications, which are kept only so older databases can still
+-- be imported. A contact can be linked to several applications and
+-- interviews, and logged interactions update when they were last contacted.
+
+CREATE TABLE IF NOT EXISTS contacts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    email TEXT COLLATE NOCASE,
+    phone TEXT,
+    linkedin_url TEXT COLLATE NOCASE,
+    company TEXT,
+    notes TEXT,
+    last_contacted_at TEXT, -- RFC 3339 timestamp
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- One contact per email address and per LinkedIn profile
+CREATE UNIQUE INDEX IF NOT EXISTS idx_contacts_email
+    ON contacts(email) WHERE email IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_contacts_linkedin_url
+    ON contacts(linkedin_url) WHERE linkedin_url IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_contacts_last_contacted_at
+    ON contacts(last_contacted_at DESC);
+
+CREATE TABLE IF NOT EXISTS application_contacts (
+    application_id INTEGER NOT NULL REFERENCES applications(id) ON DELETE CASCADE,
+    contact_id INTEGER NOT NULL REFERENCES contacts(id) ON DELETE CASCADE,
+    PRIMARY KEY (application_id, contact_id)
+);
+CREATE INDEX IF NOT EXISTS idx_application_contacts_contact_id
+    ON application_contacts(contact_id);
+
+CREATE TABLE IF NOT EXISTS interview_contacts (
+    interview_id INTEGER NOT NULL REFERENCES interviews(id) ON DELETE CASCADE,
+    contact_id INTEGER NOT NULL REFERENCES contacts(id) ON DELETE CASCADE,
+    PRIMARY KEY (interview_id, contact_id)
+);
+CREATE INDEX IF NOT EXISTS idx_interview_contacts_contact_id
+    ON interview_contacts(contact_id);
+
+CREATE TABLE IF NOT EXISTS contact_interactions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    contact_id INTEGER NOT NULL REFERENCES contacts(id) ON DELETE CASCADE,
+    application_id INTEGER REFERENCES applications(id) ON DELETE SET NULL,
+    interaction_type TEXT NOT NULL
+        CHECK (interaction_type IN ('email', 'call', 'message', 'meeting', 'other')),
+    occurred_at TEXT NOT NULL, -- RFC 3339 timestamp
+    summary TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_contact_interactions_contact_id
+    ON contact_interactions(contact_id, occurred_at DESC);
+
+-- Contact search with prefix indexes for search-as-you-type
+CREATE VIRTUAL TABLE contacts_fts USING fts5(
+    name,
+    email,
+    company,
+    notes,
+    content=contacts,
+    content_rowid=id,
+    prefix='2 3'
+);
+
+CREATE TRIGGER contacts_ai AFTER INSERT ON contacts BEGIN
+    INSERT INTO contacts_fts(rowid, name, email, company, notes)
+    VALUES (new.id, new.name, new.email, new.company, new.notes);
+END;
+
+CREATE TRIGGER contacts_au AFTER UPDATE OF name, email, company, notes ON contacts BEGIN
+    INSERT INTO contacts_fts(contacts_fts, rowid, name, email, company, notes)
+    VALUES ('delete', old.id, old.name, old.email, old.company, old.notes);
+    INSERT INTO contacts_fts(rowid, name, email, company, notes)
+    VALUES (new.id, new.name, new.email, new.company, new.notes);
+END;
+
+CREATE TRIGGER contacts_ad AFTER DELETE ON contacts BEGIN
+    INSERT INTO contacts_fts(contacts_fts, rowid, name, email, company, notes)
+    VALUES ('delete', old.id, old.name, old.email, old.company, old.notes);
+END;
+
+-- Move existing recruiter details into contacts: one contact per email
+-- address, or per name and company when there is no email.
+INSERT INTO contacts (name, email, phone, company, last_contacted_at)
+SELECT
+    COALESCE(MAX(NULLIF(TRIM(a.recruiter_name), '')), LOWER(TRIM(a.recruiter_email))),
+    LOWER(NULLIF(TRIM(a.recruiter_email), '')),
+    MAX(NULLIF(TRIM(a.recruiter_phone), '')),
+    MAX(j.company),
+    MAX(a.last_contact)
+FROM applications a
+JOIN jobs j ON j.hash = a.job_hash
+WHERE NULLIF(TRIM(a.recruiter_name), '') IS NOT NULL
+   OR NULLIF(TRIM(a.recruiter_email), '') IS NOT NULL
+GROUP BY COALESCE(
+    LOWER(NULLIF(TRIM(a.recruiter_email), '')),
+    LOWER(TRIM(a.recruiter_name)) || char(31) || LOWER(j.company)
+);
+
+INSERT OR IGNORE INTO application_contacts (application_id, contact_id)
+SELECT a.id, c.id
+FROM applications a
+JOIN jobs j ON j.hash = a.job_hash
+JOIN contacts c ON (
+    c.email = LOWER(NULLIF(TRIM(a.recruiter_email), ''))
+    OR (
+        NULLIF(TRIM(a.recruiter_email), '') IS NULL
+        AND c.email IS NULL
+        AND LOWER(c.name) = LOWER(TRIM(a.recruiter_name))
+        AND LOWER(c.company) = LOWER(j.company)
+    )
+);
@@ -0,0 +1,18 @@
+-- Files attached to applications, such as the tailored resume, cover letter,
+-- or take-home assignment that was sent. Files are copied into the
+-- application-attachments folder in the JobSentinel data folder; stored_name
+-- is the copy's path relative to that folder. Attaching another file of the
+-- same kind to an application adds a new version instead of replacing it.
+
+CREATE TABLE IF NOT EXISTS application_attachments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    application_id INTEGER NOT NULL REFERENCES applications(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL
+        CHECK (kind IN ('resume', 'cover_letter', 'take_home', 'other')),
+    version INTEGER NOT NULL CHECK (version > 0),
+    file_name TEXT NOT NULL,
+    stored_name TEXT NOT NULL UNIQUE,
+    size_bytes INTEGER NOT NULL CHECK (size_bytes >= 0),
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (application_id, kind, version)
+);
@@ -0,0 +1,17 @@
+-- Record when a due application reminder was sent to the user.
+--
+-- The background reminder task sends each due reminder once through the
+-- notification channels and stamps delivered_at. Rescheduling a reminder
+-- clears it so the new time is announced too. Reminders that were already
+-- due or done before this upgrade are marked delivered so they are not all
+-- sent at once on first launch.
+
+ALTER TABLE application_reminders ADD COLUMN delivered_at TEXT;
+
+UPDATE application_reminders
+SET delivered_at = COALESCE(completed_at, datetime('now'))
+WHERE completed = 1 OR reminder_time <= strftime('%Y-%m-%dT%H:%M:%S', 'now');
+
+CREATE INDEX IF NOT EXISTS idx_application_reminders_undelivered
+    ON application_reminders(reminder_time)
+    WHERE completed = 0 AND delivered_at IS NULL;
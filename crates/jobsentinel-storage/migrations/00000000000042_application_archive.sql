@@ -0,0 +1,45 @@
+-- Archive applications and log bulk board actions.
+--
+-- applications.archived_at hides an application from the Kanban board while
+-- keeping it, and its history, for stats and reports. The timeline gains
+-- application_created and archived events; SQLite cannot change a CHECK
+-- constraint in place, so application_events is recreated with its rows.
+
+ALTER TABLE applications ADD COLUMN archived_at TEXT;
+
+PRAGMA foreign_keys = OFF;
+
+CREATE TABLE application_events_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    application_id INTEGER NOT NULL,
+    event_type TEXT NOT NULL CHECK(event_type IN (
+        'status_change',
+        'email_received',
+        'email_sent',
+        'phone_call',
+        'interview_scheduled',
+        'note_added',
+        'reminder_set',
+        'posting_closed',
+        'posting_reopened',
+        'application_created',
+        'archived'
+    )),
+    event_data TEXT, -- JSON string for flexible schema
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+INSERT INTO application_events_new (id, application_id, event_type, event_data, created_at)
+SELECT id, application_id, event_type, event_data, created_at
+FROM application_events;
+
+DROP TABLE application_events;
+ALTER TABLE application_events_new RENAME TO application_events;
+
+CREATE INDEX IF NOT EXISTS idx_application_events_application_id
+    ON application_events(application_id);
+CREATE INDEX IF NOT EXISTS idx_application_events_created_at
+    ON application_events(created_at DESC);
+
+PRAGMA foreign_keys = ON;
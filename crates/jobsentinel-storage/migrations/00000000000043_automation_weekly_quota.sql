@@ -0,0 +1,6 @@
+-- Optional weekly cap on prepared application forms.
+--
+-- max_applications_per_day already limits each day. A NULL weekly limit
+-- means only the daily limit applies.
+
+ALTER TABLE application_profile ADD COLUMN max_applications_per_week INTEGER;
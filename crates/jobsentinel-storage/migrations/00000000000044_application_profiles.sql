@@ -0,0 +1,87 @@
+-- Multiple named application profiles.
+--
+-- Each profile keeps its own contact links, resume, and review pace. One
+-- profile is the default used when an application does not name one.
+-- Screening answers with a NULL profile_id are shared by every profile;
+-- answers tied to a profile take precedence over shared ones for that profile.
+-- SQLite cannot change UNIQUE constraints in place, so screening_answers is
+-- recreated with its existing ids and usage metrics.
+
+ALTER TABLE application_profile ADD COLUMN name TEXT NOT NULL DEFAULT 'My profile';
+ALTER TABLE application_profile ADD COLUMN is_default INTEGER NOT NULL DEFAULT 0;
+
+UPDATE application_profile
+SET is_default = 1
+WHERE id = (SELECT MIN(id) FROM application_profile);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_application_profile_default
+    ON application_profile(is_default)
+    WHERE is_default = 1;
+
+ALTER TABLE application_attempts ADD COLUMN profile_id INTEGER
+    REFERENCES application_profile(id) ON DELETE SET NULL;
+
+PRAGMA foreign_keys = OFF;
+
+CREATE TABLE screening_answers_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    profile_id INTEGER,
+    question_pattern TEXT NOT NULL,
+    answer TEXT NOT NULL,
+    answer_type TEXT CHECK(answer_type IN ('text', 'yes_no', 'textarea', 'select')),
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    times_used INTEGER NOT NULL DEFAULT 0,
+    times_modified INTEGER NOT NULL DEFAULT 0,
+    last_used_at TEXT,
+    confidence_score REAL NOT NULL DEFAULT 1.0 CHECK(confidence_score >= 0.0 AND confidence_score <= 1.0),
+    FOREIGN KEY (profile_id) REFERENCES application_profile(id) ON DELETE CASCADE,
+    UNIQUE(profile_id, question_pattern)
+);
+
+INSERT INTO screening_answers_new (
+    id,
+    profile_id,
+    question_pattern,
+    answer,
+    answer_type,
+    notes,
+    created_at,
+    updated_at,
+    times_used,
+    times_modified,
+    last_used_at,
+    confidence_score
+)
+SELECT
+    id,
+    NULL,
+    question_pattern,
+    answer,
+    answer_type,
+    notes,
+    created_at,
+    updated_at,
+    times_used,
+    times_modified,
+    last_used_at,
+    confidence_score
+FROM screening_answers;
+
+DROP TABLE screening_answers;
+ALTER TABLE screening_answers_new RENAME TO screening_answers;
+
+-- UNIQUE(profile_id, question_pattern) treats NULL profile ids as distinct,
+-- so shared answers need their own index to stay one per pattern.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_screening_answers_shared_pattern
+    ON screening_answers(question_pattern)
+    WHERE profile_id IS NULL;
+CREATE INDEX IF NOT EXISTS idx_screening_answers_profile_id
+    ON screening_answers(profile_id);
+CREATE INDEX IF NOT EXISTS idx_screening_answers_confidence_score
+    ON screening_answers(confidence_score DESC);
+CREATE INDEX IF NOT EXISTS idx_screening_answers_last_used_at
+    ON screening_answers(last_used_at DESC);
+
+PRAGMA foreign_keys = ON;
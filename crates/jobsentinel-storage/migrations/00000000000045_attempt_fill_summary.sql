@@ -0,0 +1,7 @@
+-- What each automation attempt entered into the form.
+--
+-- JSON with the value entered per field, the fields that could not be
+-- filled, and questions left for the user, shown on the review screen before
+-- the attempt is approved. Attempts filled before this migration have none.
+
+ALTER TABLE application_attempts ADD COLUMN fill_summary TEXT;
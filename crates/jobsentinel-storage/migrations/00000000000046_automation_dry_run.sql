@@ -0,0 +1,13 @@
+-- Dry-run mode for Application Assist.
+--
+-- A single settings row records whether dry runs are on. A dry run detects
+-- the site, opens the form, fills it, and records what was entered, then
+-- closes the form so nothing from it can be sent. Dry-run attempts are kept
+-- for review but cannot be approved or marked as submitted.
+
+CREATE TABLE IF NOT EXISTS automation_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    dry_run INTEGER NOT NULL DEFAULT 0
+);
+
+ALTER TABLE application_attempts ADD COLUMN dry_run INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,10 @@
+-- Indexes for the daily market analysis.
+--
+-- Company and location trends are computed in one grouped pass over jobs,
+-- ranking each company's titles and locations and each location's companies
+-- and titles. These covering indexes let SQLite group without extra sorts.
+
+CREATE INDEX IF NOT EXISTS idx_jobs_company_title_location
+    ON jobs(company, title, location);
+CREATE INDEX IF NOT EXISTS idx_jobs_location_company_title
+    ON jobs(location, company, title);
@@ -0,0 +1,14 @@
+-- Market alerts sent through notification channels.
+--
+-- Each new market alert is sent once, through the email, Slack, and Discord
+-- channels, when its type is turned on in the notification preferences.
+-- Alerts raised before this migration count as sent so an upgrade does not
+-- send the backlog.
+
+ALTER TABLE market_alerts ADD COLUMN notified_at TIMESTAMP;
+UPDATE market_alerts SET notified_at = created_at;
+CREATE INDEX IF NOT EXISTS idx_market_alerts_unnotified
+    ON market_alerts(created_at) WHERE notified_at IS NULL;
+
+-- JSON with the per-type settings; '{}' uses the defaults.
+ALTER TABLE notification_preferences ADD COLUMN market_alerts TEXT NOT NULL DEFAULT '{}';
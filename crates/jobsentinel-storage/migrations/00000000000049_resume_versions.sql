@@ -0,0 +1,20 @@
+-- Resume versions for comparing edits over time.
+--
+-- A version is saved each time a resume file is uploaded and each time a
+-- builder draft is saved with different content. Uploads with the same name
+-- (ignoring case) and saves of the same draft share a version history.
+
+CREATE TABLE IF NOT EXISTS resume_versions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    source TEXT NOT NULL CHECK (source IN ('upload', 'builder')),
+    -- Lowercased resume name for uploads, draft id for builder saves
+    source_key TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    resume_id INTEGER REFERENCES resumes(id) ON DELETE SET NULL,
+    -- JSON arrays of resume sections and skill names
+    sections TEXT NOT NULL DEFAULT '[]',
+    skills TEXT NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (source, source_key, version)
+);
@@ -0,0 +1,11 @@
+-- Saved searches that alert on new jobs ("job agents").
+--
+-- After each scrape cycle an agent runs its saved filter against jobs saved
+-- since it last checked and sends the matches through its channels. Channels
+-- are a JSON list of "email", "slack", and "discord"; an empty list uses
+-- every enabled channel. Frequency is 'each_scrape' or 'daily'.
+
+ALTER TABLE saved_searches ADD COLUMN agent_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE saved_searches ADD COLUMN agent_channels TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE saved_searches ADD COLUMN agent_frequency TEXT NOT NULL DEFAULT 'each_scrape';
+ALTER TABLE saved_searches ADD COLUMN agent_last_checked_at TEXT;
@@ -0,0 +1,8 @@
+-- Search suggestions: pinned and removed queries.
+--
+-- Pinned queries are always suggested first. A removed query is kept as a
+-- dismissal so it is not suggested again from history or from job titles and
+-- companies; searching for it again brings it back.
+
+ALTER TABLE search_history ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE search_history ADD COLUMN dismissed INTEGER NOT NULL DEFAULT 0;
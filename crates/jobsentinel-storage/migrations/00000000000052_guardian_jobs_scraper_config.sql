@@ -0,0 +1,3 @@
+-- Guardian Jobs country board (United Kingdom)
+INSERT OR IGNORE INTO scraper_config (scraper_name, display_name, requires_auth, auth_type, scraper_type, rate_limit_per_hour) VALUES
+    ('guardian_jobs', 'Guardian Jobs', 0, NULL, 'rss', 300);
@@ -1,5 +1,14 @@
+use jobsentinel_domain::geo::{find_city, City};
 use jobsentinel_domain::normalization::{normalize_location, normalize_title};
 
+/// Cities with their own salary benchmark bucket
+const SALARY_CITY_BUCKETS: &[&str] = &[
+    "san francisco, ca",
+    "new york, ny",
+    "seattle, wa",
+    "austin, tx",
+];
+
 pub(crate) fn salary_title_bucket(title: &str) -> String {
     let canonical = normalize_title(title);
 
@@ -15,9 +24,14 @@ pub(crate) fn salary_title_bucket(title: &str) -> String {
 pub(crate) fn salary_location_bucket(location: &str) -> String {
     let canonical = normalize_location(location);
     let fallback = location.trim().to_lowercase();
+    let city = find_city(location)
+        .map(City::key)
+        .filter(|city| SALARY_CITY_BUCKETS.contains(&city.as_str()));
 
     if canonical == "remote" {
         "remote".to_string()
+    } else if let Some(city) = city {
+        city
     } else if canonical.contains("san francisco") || contains_token(&canonical, "sf") {
         "san francisco, ca".to_string()
     } else if canonical.contains("new york") || contains_token(&canonical, "nyc") {
@@ -37,6 +51,8 @@ pub(crate) fn market_location_bucket(location: &str) -> String {
 
     if canonical == "remote" {
         "remote".to_string()
+    } else if let Some(city) = find_city(location) {
+        city.key()
     } else if canonical.contains("san francisco") || contains_token(&canonical, "sf") {
         "san francisco, ca".to_string()
     } else if canonical.contains("new york") || contains_token(&canonical, "nyc") {
@@ -0,0 +1,95 @@
+//! Files attached to applications
+//!
+//! Only metadata is stored here. The desktop app copies each file into its
+//! attachments folder and records the copy's relative path as `stored_name`.
+
+use super::tracker::ApplicationTracker;
+use super::types::*;
+use anyhow::Result;
+
+const ATTACHMENT_COLUMNS: &str =
+    "id, application_id, kind, version, file_name, stored_name, size_bytes, created_at";
+
+impl ApplicationTracker {
+    /// Record a file attached to an application
+    ///
+    /// The attachment becomes the next version of its kind for the
+    /// application, so earlier resumes or cover letters are kept.
+    pub async fn add_attachment(
+        &self,
+        application_id: i64,
+        kind: &str,
+        file_name: &str,
+        stored_name: &str,
+        size_bytes: i64,
+    ) -> Result<ApplicationAttachment> {
+        let mut tx = self.db.begin().await?;
+        let attachment = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            r#"
+            INSERT INTO application_attachments (
+                application_id, kind, version, file_name, stored_name, size_bytes
+            )
+            SELECT ?1, ?2, COALESCE(MAX(version), 0) + 1, ?3, ?4, ?5
+            FROM application_attachments
+            WHERE application_id = ?1 AND kind = ?2
+            RETURNING {ATTACHMENT_COLUMNS}
+            "#
+        )))
+        .bind(application_id)
+        .bind(kind)
+        .bind(file_name)
+        .bind(stored_name)
+        .bind(size_bytes)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(attachment)
+    }
+
+    /// Attachments for an application, newest version of each kind first
+    pub async fn get_attachments(&self, application_id: i64) -> Result<Vec<ApplicationAttachment>> {
+        let attachments = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            r#"
+            SELECT {ATTACHMENT_COLUMNS} FROM application_attachments
+            WHERE application_id = ?
+            ORDER BY kind, version DESC
+            "#
+        )))
+        .bind(application_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(attachments)
+    }
+
+    /// Get a single attachment
+    pub async fn get_attachment(
+        &self,
+        attachment_id: i64,
+    ) -> Result<Option<ApplicationAttachment>> {
+        let attachment = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            "SELECT {ATTACHMENT_COLUMNS} FROM application_attachments WHERE id = ?"
+        )))
+        .bind(attachment_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(attachment)
+    }
+
+    /// Delete an attachment record and return it so its file can be removed
+    pub async fn delete_attachment(
+        &self,
+        attachment_id: i64,
+    ) -> Result<Option<ApplicationAttachment>> {
+        let attachment = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            "DELETE FROM application_attachments WHERE id = ? RETURNING {ATTACHMENT_COLUMNS}"
+        )))
+        .bind(attachment_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(attachment)
+    }
+}
@@ -0,0 +1,115 @@
+//! Bulk actions for applications selected together on the board
+//!
+//! Each application is handled on its own, with the same timeline events as
+//! the single-application actions. Applications that do not apply, such as a
+//! job that is already tracked, are skipped rather than failing the batch.
+
+use anyhow::Result;
+use chrono::Utc;
+use sqlx::Row;
+
+use super::tracker::ApplicationTracker;
+use super::types::ApplicationStatus;
+
+impl ApplicationTracker {
+    /// Start an application in `to_apply` for each job in `job_ids`
+    ///
+    /// Jobs that already have an application, or do not exist, are skipped.
+    /// Returns the ids of the new applications.
+    pub async fn bulk_create_applications(&self, job_ids: &[i64]) -> Result<Vec<i64>> {
+        let mut created = Vec::new();
+        for &job_id in job_ids {
+            let Some(application_id) = sqlx::query_scalar::<_, i64>(
+                r#"
+                INSERT INTO applications (job_hash, status)
+                SELECT j.hash, 'to_apply' FROM jobs j
+                WHERE j.id = ?
+                  AND NOT EXISTS (SELECT 1 FROM applications a WHERE a.job_hash = j.hash)
+                RETURNING id
+                "#,
+            )
+            .bind(job_id)
+            .fetch_optional(&self.db)
+            .await?
+            else {
+                continue;
+            };
+
+            self.log_event(
+                application_id,
+                "application_created",
+                serde_json::json!({ "status": ApplicationStatus::ToApply.to_string() }),
+            )
+            .await?;
+            created.push(application_id);
+        }
+
+        Ok(created)
+    }
+
+    /// Move each application in `application_ids` to `status`
+    ///
+    /// Logs a status change and sets reminders as [`Self::update_status`]
+    /// does. Applications already in `status`, or that do not exist, are
+    /// skipped. Returns how many moved.
+    pub async fn bulk_update_status(
+        &self,
+        application_ids: &[i64],
+        status: ApplicationStatus,
+    ) -> Result<usize> {
+        let mut updated = 0;
+        for &application_id in application_ids {
+            let current =
+                sqlx::query_scalar::<_, String>("SELECT status FROM applications WHERE id = ?")
+                    .bind(application_id)
+                    .fetch_optional(&self.db)
+                    .await?;
+            if current.is_none_or(|current| current == status.to_string()) {
+                continue;
+            }
+
+            self.update_status(application_id, status).await?;
+            updated += 1;
+        }
+
+        Ok(updated)
+    }
+
+    /// Archive the rejected applications in `application_ids`
+    ///
+    /// Archived applications leave the Kanban board but stay in stats and
+    /// reports. Applications in any other status are skipped. Returns how
+    /// many were archived.
+    pub async fn archive_rejected_applications(&self, application_ids: &[i64]) -> Result<usize> {
+        let now = Utc::now().to_rfc3339();
+        let mut archived = 0;
+        for &application_id in application_ids {
+            let Some(row) = sqlx::query(
+                r#"
+                UPDATE applications SET archived_at = ?, updated_at = ?
+                WHERE id = ? AND status = ? AND archived_at IS NULL
+                RETURNING status
+                "#,
+            )
+            .bind(&now)
+            .bind(&now)
+            .bind(application_id)
+            .bind(ApplicationStatus::Rejected.to_string())
+            .fetch_optional(&self.db)
+            .await?
+            else {
+                continue;
+            };
+
+            self.log_event(
+                application_id,
+                "archived",
+                serde_json::json!({ "status": row.try_get::<String, _>("status")? }),
+            )
+            .await?;
+            archived += 1;
+        }
+
+        Ok(archived)
+    }
+}
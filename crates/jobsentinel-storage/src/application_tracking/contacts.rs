@@ -0,0 +1,400 @@
+//! Recruiter and hiring contacts
+//!
+//! Contacts are shared across applications and interviews. Saving a contact
+//! that matches an existing one by email, LinkedIn profile, or name and
+//! company updates that contact instead of adding a duplicate. Logged
+//! interactions keep "last contacted" current for the contact and the
+//! application, which ghosted-application detection reads.
+
+use super::tracker::ApplicationTracker;
+use super::types::*;
+use crate::job_search::{prefix_match, query_words};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+const CONTACT_COLUMNS: &str = r#"
+    c.id, c.name, c.email, c.phone, c.linkedin_url, c.company, c.notes,
+    c.last_contacted_at, c.created_at, c.updated_at
+"#;
+
+/// Trimmed value, or `None` when blank
+fn clean(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Input with blank fields removed, email lowercased, and trailing slashes
+/// dropped from the LinkedIn URL, so duplicates compare equal
+fn normalized(contact: &ContactInput) -> ContactInput {
+    ContactInput {
+        name: contact.name.trim().to_string(),
+        email: clean(contact.email.as_deref()).map(|email| email.to_lowercase()),
+        phone: clean(contact.phone.as_deref()),
+        linkedin_url: clean(contact.linkedin_url.as_deref())
+            .map(|url| url.trim_end_matches('/').to_string()),
+        company: clean(contact.company.as_deref()),
+        notes: clean(contact.notes.as_deref()),
+    }
+}
+
+impl ApplicationTracker {
+    /// Save a contact and return its id
+    ///
+    /// A contact with the same email or LinkedIn profile, or the same name
+    /// and company and no conflicting email, is updated instead: fields given
+    /// here replace the stored ones and blank fields keep them.
+    pub async fn save_contact(&self, contact: &ContactInput) -> Result<i64> {
+        let contact = normalized(contact);
+        let mut tx = self.db.begin().await?;
+
+        let existing: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM contacts
+            WHERE (?1 IS NOT NULL AND email = ?1)
+               OR (?2 IS NOT NULL AND linkedin_url = ?2)
+               OR (
+                   (email IS NULL OR ?1 IS NULL)
+                   AND name = ?3 COLLATE NOCASE
+                   AND COALESCE(company, '') = COALESCE(?4, '') COLLATE NOCASE
+               )
+            ORDER BY id
+            LIMIT 1
+            "#,
+        )
+        .bind(&contact.email)
+        .bind(&contact.linkedin_url)
+        .bind(&contact.name)
+        .bind(&contact.company)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let now = Utc::now().to_rfc3339();
+        let id = if let Some(id) = existing {
+            sqlx::query(
+                r#"
+                UPDATE contacts
+                SET name = ?,
+                    email = COALESCE(?, email),
+                    phone = COALESCE(?, phone),
+                    linkedin_url = COALESCE(?, linkedin_url),
+                    company = COALESCE(?, company),
+                    notes = COALESCE(?, notes),
+                    updated_at = ?
+                WHERE id = ?
+                "#,
+            )
+            .bind(&contact.name)
+            .bind(&contact.email)
+            .bind(&contact.phone)
+            .bind(&contact.linkedin_url)
+            .bind(&contact.company)
+            .bind(&contact.notes)
+            .bind(&now)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+            id
+        } else {
+            sqlx::query(
+                r#"
+                INSERT INTO contacts (
+                    name, email, phone, linkedin_url, company, notes, created_at, updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&contact.name)
+            .bind(&contact.email)
+            .bind(&contact.phone)
+            .bind(&contact.linkedin_url)
+            .bind(&contact.company)
+            .bind(&contact.notes)
+            .bind(&now)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid()
+        };
+        tx.commit().await?;
+
+        Ok(id)
+    }
+
+    /// Replace a contact's details, clearing fields left blank
+    ///
+    /// Returns `false` when the contact does not exist.
+    pub async fn update_contact(&self, contact_id: i64, contact: &ContactInput) -> Result<bool> {
+        let contact = normalized(contact);
+        let result = sqlx::query(
+            r#"
+            UPDATE contacts
+            SET name = ?, email = ?, phone = ?, linkedin_url = ?, company = ?, notes = ?,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&contact.name)
+        .bind(&contact.email)
+        .bind(&contact.phone)
+        .bind(&contact.linkedin_url)
+        .bind(&contact.company)
+        .bind(&contact.notes)
+        .bind(Utc::now().to_rfc3339())
+        .bind(contact_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a contact with its links and interaction log
+    ///
+    /// Returns `false` when the contact does not exist.
+    pub async fn delete_contact(&self, contact_id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM contacts WHERE id = ?")
+            .bind(contact_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Get a single contact
+    pub async fn get_contact(&self, contact_id: i64) -> Result<Option<Contact>> {
+        let contact = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            "SELECT {CONTACT_COLUMNS} FROM contacts c WHERE c.id = ?"
+        )))
+        .bind(contact_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(contact)
+    }
+
+    /// Search contacts by name, email, company, and notes, best match first
+    ///
+    /// The last word matches as a prefix. A blank query lists the most
+    /// recently contacted first.
+    pub async fn search_contacts(&self, query: &str, limit: i64) -> Result<Vec<Contact>> {
+        let words = query_words(query);
+        let contacts = if words.is_empty() {
+            sqlx::query_as(sqlx::AssertSqlSafe(format!(
+                r#"
+                SELECT {CONTACT_COLUMNS} FROM contacts c
+                ORDER BY c.last_contacted_at IS NULL, c.last_contacted_at DESC, c.name
+                LIMIT ?
+                "#
+            )))
+            .bind(limit)
+            .fetch_all(&self.db)
+            .await?
+        } else {
+            sqlx::query_as(sqlx::AssertSqlSafe(format!(
+                r#"
+                SELECT {CONTACT_COLUMNS} FROM contacts_fts
+                JOIN contacts c ON c.id = contacts_fts.rowid
+                WHERE contacts_fts MATCH ?
+                ORDER BY bm25(contacts_fts, 10.0, 5.0, 5.0, 1.0)
+                LIMIT ?
+                "#
+            )))
+            .bind(prefix_match(&words))
+            .bind(limit)
+            .fetch_all(&self.db)
+            .await?
+        };
+
+        Ok(contacts)
+    }
+
+    /// Link a contact to an application
+    pub async fn link_contact_to_application(
+        &self,
+        contact_id: i64,
+        application_id: i64,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO application_contacts (application_id, contact_id) VALUES (?, ?)",
+        )
+        .bind(application_id)
+        .bind(contact_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Remove a contact from an application
+    pub async fn unlink_contact_from_application(
+        &self,
+        contact_id: i64,
+        application_id: i64,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM application_contacts WHERE application_id = ? AND contact_id = ?")
+            .bind(application_id)
+            .bind(contact_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Link a contact to an interview, such as the interviewer
+    pub async fn link_contact_to_interview(
+        &self,
+        contact_id: i64,
+        interview_id: i64,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO interview_contacts (interview_id, contact_id) VALUES (?, ?)",
+        )
+        .bind(interview_id)
+        .bind(contact_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Remove a contact from an interview
+    pub async fn unlink_contact_from_interview(
+        &self,
+        contact_id: i64,
+        interview_id: i64,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM interview_contacts WHERE interview_id = ? AND contact_id = ?")
+            .bind(interview_id)
+            .bind(contact_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Contacts linked to an application, by name
+    pub async fn get_application_contacts(&self, application_id: i64) -> Result<Vec<Contact>> {
+        let contacts = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            r#"
+            SELECT {CONTACT_COLUMNS} FROM application_contacts ac
+            JOIN contacts c ON c.id = ac.contact_id
+            WHERE ac.application_id = ?
+            ORDER BY c.name COLLATE NOCASE
+            "#
+        )))
+        .bind(application_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(contacts)
+    }
+
+    /// Contacts linked to an interview, by name
+    pub async fn get_interview_contacts(&self, interview_id: i64) -> Result<Vec<Contact>> {
+        let contacts = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            r#"
+            SELECT {CONTACT_COLUMNS} FROM interview_contacts ic
+            JOIN contacts c ON c.id = ic.contact_id
+            WHERE ic.interview_id = ?
+            ORDER BY c.name COLLATE NOCASE
+            "#
+        )))
+        .bind(interview_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(contacts)
+    }
+
+    /// Log an interaction with a contact and return its id
+    ///
+    /// Moves the contact's last contacted time forward. With an application,
+    /// the contact is linked to it and its last contact time moves forward
+    /// too, so it is not marked ghosted.
+    pub async fn log_contact_interaction(
+        &self,
+        contact_id: i64,
+        application_id: Option<i64>,
+        interaction_type: &str,
+        occurred_at: DateTime<Utc>,
+        summary: Option<&str>,
+    ) -> Result<i64> {
+        let occurred_at = occurred_at.to_rfc3339();
+        let now = Utc::now().to_rfc3339();
+        let mut tx = self.db.begin().await?;
+
+        let id = sqlx::query(
+            r#"
+            INSERT INTO contact_interactions (
+                contact_id, application_id, interaction_type, occurred_at, summary
+            ) VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(contact_id)
+        .bind(application_id)
+        .bind(interaction_type)
+        .bind(&occurred_at)
+        .bind(clean(summary))
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        sqlx::query(
+            r#"
+            UPDATE contacts
+            SET last_contacted_at = MAX(COALESCE(last_contacted_at, ''), ?), updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&occurred_at)
+        .bind(&now)
+        .bind(contact_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(application_id) = application_id {
+            sqlx::query(
+                "INSERT OR IGNORE INTO application_contacts (application_id, contact_id) VALUES (?, ?)",
+            )
+            .bind(application_id)
+            .bind(contact_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                r#"
+                UPDATE applications
+                SET last_contact = MAX(COALESCE(last_contact, ''), ?), updated_at = ?
+                WHERE id = ?
+                "#,
+            )
+            .bind(&occurred_at)
+            .bind(&now)
+            .bind(application_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(id)
+    }
+
+    /// A contact's logged interactions, newest first
+    pub async fn get_contact_interactions(
+        &self,
+        contact_id: i64,
+    ) -> Result<Vec<ContactInteraction>> {
+        let interactions = sqlx::query_as(
+            r#"
+            SELECT id, contact_id, application_id, interaction_type, occurred_at, summary
+            FROM contact_interactions
+            WHERE contact_id = ?
+            ORDER BY occurred_at DESC, id DESC
+            "#,
+        )
+        .bind(contact_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(interactions)
+    }
+}
@@ -34,6 +34,15 @@ macro_rules! interview_with_job_query {
     };
 }
 
+/// Prep checklist items every new interview starts with
+const DEFAULT_PREP_ITEMS: [&str; 5] = [
+    "research",
+    "review_jd",
+    "prepare_questions",
+    "star_stories",
+    "tech_review",
+];
+
 fn interview_with_job_from_row(row: SqliteRow) -> Result<InterviewWithJob> {
     Ok(InterviewWithJob {
         id: row.try_get("id")?,
@@ -70,6 +79,7 @@ impl ApplicationTracker {
         interviewer_title: Option<&str>,
         notes: Option<&str>,
     ) -> Result<i64> {
+        let mut tx = self.db.begin().await?;
         let result = sqlx::query!(
             r#"
             INSERT INTO interviews (
@@ -86,10 +96,22 @@ impl ApplicationTracker {
             interviewer_title,
             notes
         )
-        .execute(&self.db)
+        .execute(&mut *tx)
         .await?;
+        let interview_id = result.last_insert_rowid();
 
-        Ok(result.last_insert_rowid())
+        for item_id in DEFAULT_PREP_ITEMS {
+            sqlx::query(
+                "INSERT INTO interview_prep_checklists (interview_id, item_id) VALUES (?, ?)",
+            )
+            .bind(interview_id)
+            .bind(item_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(interview_id)
     }
 
     /// Get upcoming interviews (next 30 days, not completed)
@@ -111,6 +133,24 @@ impl ApplicationTracker {
             .collect()
     }
 
+    /// Interviews for the calendar feed: everything from the last 90 days
+    /// onward, completed or not, earliest first
+    pub async fn get_calendar_interviews(&self) -> Result<Vec<InterviewWithJob>> {
+        let interviews = sqlx::query(interview_with_job_query!(
+            r#"
+                WHERE datetime(i.scheduled_at) >= datetime('now', '-90 days')
+                ORDER BY i.scheduled_at ASC
+            "#
+        ))
+        .fetch_all(&self.db)
+        .await?;
+
+        interviews
+            .into_iter()
+            .map(interview_with_job_from_row)
+            .collect()
+    }
+
     /// Get past interviews (completed, last 90 days)
     pub async fn get_past_interviews(&self) -> Result<Vec<InterviewWithJob>> {
         let interviews = sqlx::query(interview_with_job_query!(
@@ -129,6 +169,66 @@ impl ApplicationTracker {
             .collect()
     }
 
+    /// Every interview for an application, earliest first
+    pub async fn get_application_interviews(
+        &self,
+        application_id: i64,
+    ) -> Result<Vec<InterviewWithJob>> {
+        let interviews = sqlx::query(interview_with_job_query!(
+            r#"
+                WHERE i.application_id = ?
+                ORDER BY i.scheduled_at ASC
+            "#
+        ))
+        .bind(application_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        interviews
+            .into_iter()
+            .map(interview_with_job_from_row)
+            .collect()
+    }
+
+    /// Get one interview with its job details
+    pub async fn get_interview(&self, interview_id: i64) -> Result<Option<InterviewWithJob>> {
+        sqlx::query(interview_with_job_query!("WHERE i.id = ?"))
+            .bind(interview_id)
+            .fetch_optional(&self.db)
+            .await?
+            .map(interview_with_job_from_row)
+            .transpose()
+    }
+
+    /// Move an interview to a new time, optionally changing its length
+    ///
+    /// Returns `false` when the interview does not exist.
+    pub async fn reschedule_interview(
+        &self,
+        interview_id: i64,
+        scheduled_at: &str,
+        duration_minutes: Option<i32>,
+    ) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        let result = sqlx::query(
+            r#"
+            UPDATE interviews
+            SET scheduled_at = ?,
+                duration_minutes = COALESCE(?, duration_minutes),
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(scheduled_at)
+        .bind(duration_minutes)
+        .bind(now)
+        .bind(interview_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Update interview outcome with optional post-interview notes
     ///
     /// Note: post_notes is stored in the post_interview_notes column added via migration.
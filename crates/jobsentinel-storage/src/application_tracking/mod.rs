@@ -4,7 +4,12 @@
 //! automated reminders, and comprehensive timeline tracking.
 
 // Module declarations
+mod attachments;
+mod bulk;
+mod contacts;
 mod interview;
+mod offers;
+mod posting_status;
 mod reminders;
 mod tracker;
 mod types;
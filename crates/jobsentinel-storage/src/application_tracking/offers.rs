@@ -0,0 +1,154 @@
+//! Offer records for applications
+
+use super::tracker::ApplicationTracker;
+use super::types::*;
+use anyhow::Result;
+use chrono::Utc;
+use jobsentinel_domain::PayPeriod;
+use sqlx::{sqlite::SqliteRow, Row};
+
+macro_rules! offer_with_job_query {
+    ($criteria:literal) => {
+        concat!(
+            r#"
+                SELECT
+                    o.id,
+                    o.application_id,
+                    o.base_salary,
+                    o.currency,
+                    o.pay_period,
+                    o.annual_bonus,
+                    o.signing_bonus,
+                    o.equity_annual_value,
+                    o.benefits_summary,
+                    o.benefits_annual_value,
+                    o.location,
+                    o.cost_of_living_index,
+                    o.start_date,
+                    o.offer_expires_at,
+                    o.offer_received_at,
+                    o.accepted,
+                    j.title as job_title,
+                    j.company
+                FROM offers o
+                JOIN applications a ON o.application_id = a.id
+                JOIN jobs j ON a.job_hash = j.hash
+            "#,
+            $criteria
+        )
+    };
+}
+
+fn offer_with_job_from_row(row: SqliteRow) -> Result<OfferWithJob> {
+    Ok(OfferWithJob {
+        id: row.try_get("id")?,
+        application_id: row.try_get("application_id")?,
+        job_title: row.try_get("job_title")?,
+        company: row.try_get("company")?,
+        base_salary: row
+            .try_get::<Option<i64>, _>("base_salary")?
+            .unwrap_or_default(),
+        currency: row
+            .try_get::<Option<String>, _>("currency")?
+            .unwrap_or_else(|| "USD".to_string()),
+        pay_period: PayPeriod::parse(&row.try_get::<String, _>("pay_period")?)
+            .unwrap_or(PayPeriod::Year),
+        annual_bonus: row.try_get("annual_bonus")?,
+        signing_bonus: row.try_get("signing_bonus")?,
+        equity_annual_value: row.try_get("equity_annual_value")?,
+        benefits_summary: row.try_get("benefits_summary")?,
+        benefits_annual_value: row.try_get("benefits_annual_value")?,
+        location: row.try_get("location")?,
+        cost_of_living_index: row.try_get("cost_of_living_index")?,
+        start_date: row.try_get("start_date")?,
+        offer_expires_at: row.try_get("offer_expires_at")?,
+        offer_received_at: row.try_get("offer_received_at")?,
+        accepted: row
+            .try_get::<Option<i64>, _>("accepted")?
+            .map(|accepted| accepted != 0),
+    })
+}
+
+impl ApplicationTracker {
+    /// Save the offer for an application, replacing its terms if one was
+    /// already saved. Returns the offer id.
+    pub async fn save_offer(&self, application_id: i64, offer: &OfferInput) -> Result<i64> {
+        let id = sqlx::query_scalar(
+            r#"
+            INSERT INTO offers (
+                application_id, base_salary, currency, pay_period, annual_bonus,
+                signing_bonus, equity_annual_value, benefits_summary,
+                benefits_annual_value, location, cost_of_living_index,
+                start_date, offer_expires_at, offer_received_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(application_id) DO UPDATE SET
+                base_salary = excluded.base_salary,
+                currency = excluded.currency,
+                pay_period = excluded.pay_period,
+                annual_bonus = excluded.annual_bonus,
+                signing_bonus = excluded.signing_bonus,
+                equity_annual_value = excluded.equity_annual_value,
+                benefits_summary = excluded.benefits_summary,
+                benefits_annual_value = excluded.benefits_annual_value,
+                location = excluded.location,
+                cost_of_living_index = excluded.cost_of_living_index,
+                start_date = excluded.start_date,
+                offer_expires_at = excluded.offer_expires_at
+            RETURNING id
+            "#,
+        )
+        .bind(application_id)
+        .bind(offer.base_salary)
+        .bind(offer.currency.as_deref().unwrap_or("USD"))
+        .bind(offer.pay_period.as_str())
+        .bind(offer.annual_bonus)
+        .bind(offer.signing_bonus)
+        .bind(offer.equity_annual_value)
+        .bind(&offer.benefits_summary)
+        .bind(offer.benefits_annual_value)
+        .bind(&offer.location)
+        .bind(offer.cost_of_living_index)
+        .bind(&offer.start_date)
+        .bind(&offer.offer_expires_at)
+        .bind(Utc::now().to_rfc3339())
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Saved offers, soonest deadline first; offers without a deadline last
+    pub async fn get_offers(&self) -> Result<Vec<OfferWithJob>> {
+        let offers = sqlx::query(offer_with_job_query!(
+            r#"
+                ORDER BY o.offer_expires_at IS NULL, o.offer_expires_at ASC, o.id ASC
+            "#
+        ))
+        .fetch_all(&self.db)
+        .await?;
+
+        offers.into_iter().map(offer_with_job_from_row).collect()
+    }
+
+    /// Get a single offer with job details
+    pub async fn get_offer(&self, offer_id: i64) -> Result<Option<OfferWithJob>> {
+        sqlx::query(offer_with_job_query!("WHERE o.id = ?"))
+            .bind(offer_id)
+            .fetch_optional(&self.db)
+            .await?
+            .map(offer_with_job_from_row)
+            .transpose()
+    }
+
+    /// Delete an offer
+    ///
+    /// Returns `false` when the offer does not exist.
+    pub async fn delete_offer(&self, offer_id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM offers WHERE id = ?")
+            .bind(offer_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,100 @@
+//! Closed postings behind tracked applications
+//!
+//! When a saved posting is found to be gone, its application is marked so
+//! the Kanban card can show it and a timeline event records when it happened.
+
+use anyhow::Result;
+use chrono::Utc;
+use sqlx::Row;
+
+use super::tracker::ApplicationTracker;
+use super::types::{ApplicationStatus, ClosedPostingApplication};
+
+impl ApplicationTracker {
+    /// Mark the application for `job_hash` as having a closed posting.
+    ///
+    /// Returns `None` when the job has no application or it was already
+    /// marked, so callers only alert once per closure.
+    pub async fn record_posting_closed(
+        &self,
+        job_hash: &str,
+        reason: &str,
+    ) -> Result<Option<ClosedPostingApplication>> {
+        let Some(row) = sqlx::query(
+            r#"
+            SELECT a.id, a.status, a.posting_closed_at, j.title, j.company
+            FROM applications a
+            JOIN jobs j ON a.job_hash = j.hash
+            WHERE a.job_hash = ?
+            "#,
+        )
+        .bind(job_hash)
+        .fetch_optional(&self.db)
+        .await?
+        else {
+            return Ok(None);
+        };
+        if row
+            .try_get::<Option<String>, _>("posting_closed_at")?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        let application_id: i64 = row.try_get("id")?;
+        let closed_at = Utc::now().to_rfc3339();
+        sqlx::query("UPDATE applications SET posting_closed_at = ? WHERE id = ?")
+            .bind(&closed_at)
+            .bind(application_id)
+            .execute(&self.db)
+            .await?;
+        self.log_event(
+            application_id,
+            "posting_closed",
+            serde_json::json!({ "reason": reason }),
+        )
+        .await?;
+
+        let status: String = row.try_get("status")?;
+        tracing::info!(application_id, "Marked application posting as closed");
+        Ok(Some(ClosedPostingApplication {
+            application_id,
+            job_hash: job_hash.to_string(),
+            job_title: row.try_get("title")?,
+            company: row.try_get("company")?,
+            status: status.parse()?,
+            closed_at,
+        }))
+    }
+
+    /// Clear the closed mark after the posting is found online again.
+    ///
+    /// Returns whether a mark was cleared.
+    pub async fn record_posting_reopened(&self, job_hash: &str) -> Result<bool> {
+        let Some(application_id) = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM applications WHERE job_hash = ? AND posting_closed_at IS NOT NULL",
+        )
+        .bind(job_hash)
+        .fetch_optional(&self.db)
+        .await?
+        else {
+            return Ok(false);
+        };
+
+        sqlx::query("UPDATE applications SET posting_closed_at = NULL WHERE id = ?")
+            .bind(application_id)
+            .execute(&self.db)
+            .await?;
+        self.log_event(application_id, "posting_reopened", serde_json::json!({}))
+            .await?;
+        Ok(true)
+    }
+}
+
+impl ClosedPostingApplication {
+    /// Whether the user has not applied yet, so prep time may be wasted.
+    #[must_use]
+    pub fn needs_attention(&self) -> bool {
+        self.status == ApplicationStatus::ToApply
+    }
+}
@@ -2,8 +2,10 @@
 
 use super::tracker::ApplicationTracker;
 use super::types::*;
+use crate::sqlite_time::parse_sqlite_datetime;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use sqlx::Row;
 
 impl ApplicationTracker {
     /// Set a reminder for an application
@@ -73,6 +75,54 @@ impl ApplicationTracker {
         Ok(reminders)
     }
 
+    /// Due reminders that have not been sent to the user yet, oldest first
+    pub async fn get_undelivered_reminders(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingReminder>> {
+        let reminders = sqlx::query_as::<_, PendingReminder>(
+            r#"
+            SELECT
+                r.id,
+                r.application_id,
+                r.reminder_type,
+                r.reminder_time,
+                r.message,
+                a.job_hash,
+                j.title AS job_title,
+                j.company
+            FROM application_reminders r
+            JOIN applications a ON r.application_id = a.id
+            JOIN jobs j ON a.job_hash = j.hash
+            WHERE r.completed = 0 AND r.delivered_at IS NULL AND r.reminder_time <= ?
+            ORDER BY r.reminder_time ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(now.to_rfc3339())
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(reminders)
+    }
+
+    /// Record that a due reminder was sent to the user
+    pub async fn mark_reminder_delivered(
+        &self,
+        reminder_id: i64,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE application_reminders SET delivered_at = ? WHERE id = ?")
+            .bind(delivered_at.to_rfc3339())
+            .bind(reminder_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
     /// Mark reminder as completed
     pub async fn complete_reminder(&self, reminder_id: i64) -> Result<()> {
         let now = Utc::now().to_rfc3339();
@@ -87,4 +137,77 @@ impl ApplicationTracker {
 
         Ok(())
     }
+
+    /// Get when a reminder is due, or `None` if it does not exist
+    pub async fn get_reminder_schedule(
+        &self,
+        reminder_id: i64,
+    ) -> Result<Option<ReminderSchedule>> {
+        let schedule = sqlx::query_as::<_, ReminderSchedule>(
+            "SELECT id, reminder_time, completed FROM application_reminders WHERE id = ?",
+        )
+        .bind(reminder_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(schedule)
+    }
+
+    /// Move one reminder to a new due time
+    ///
+    /// The reminder is sent again when the new time comes. Returns `false`
+    /// when the reminder does not exist.
+    pub async fn reschedule_reminder(&self, reminder_id: i64, reminder_time: &str) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE application_reminders SET reminder_time = ?, delivered_at = NULL WHERE id = ?",
+        )
+        .bind(reminder_time)
+        .bind(reminder_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Move pending reminders that came due between `from` and `until` forward
+    /// by the length of that window, keeping their order and spacing.
+    ///
+    /// Returns how many reminders moved.
+    pub async fn shift_pending_reminders(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<u64> {
+        let shift = until - from;
+        if shift <= chrono::Duration::zero() {
+            return Ok(0);
+        }
+
+        let rows =
+            sqlx::query("SELECT id, reminder_time FROM application_reminders WHERE completed = 0")
+                .fetch_all(&self.db)
+                .await?;
+
+        let mut shifted = 0;
+        for row in rows {
+            let reminder_time: String = row.try_get("reminder_time")?;
+            let Ok(due) = parse_sqlite_datetime(&reminder_time) else {
+                continue;
+            };
+            if due < from || due >= until {
+                continue;
+            }
+
+            sqlx::query(
+                "UPDATE application_reminders SET reminder_time = ?, delivered_at = NULL WHERE id = ?",
+            )
+            .bind((due + shift).to_rfc3339())
+            .bind(row.try_get::<i64, _>("id")?)
+            .execute(&self.db)
+            .await?;
+            shifted += 1;
+        }
+
+        Ok(shifted)
+    }
 }
@@ -5,6 +5,22 @@ use super::types::*;
 use chrono::{Duration, Utc};
 use sqlx::Row;
 
+/// A tracker over a fresh database holding one job under `job_hash` and an
+/// application for it.
+async fn tracker_with_application(job_hash: &str) -> (ApplicationTracker, i64) {
+    let pool = crate::test_support::migrated_pool().await;
+    sqlx::query(
+        "INSERT INTO jobs (hash, title, company, url, source) VALUES (?, 'Case Manager', 'CommunityCare', 'http://test.com', 'test')",
+    )
+    .bind(job_hash)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let tracker = ApplicationTracker::new(pool);
+    let app_id = tracker.create_application(job_hash).await.unwrap();
+    (tracker, app_id)
+}
+
 // ========================================
 // Database integration tests
 // ========================================
@@ -375,8 +391,20 @@ mod ghosting_and_stats_tests;
 
 mod lifecycle_events;
 
+#[path = "tests/attachment_tests.rs"]
+mod attachment_tests;
+#[path = "tests/bulk_tests.rs"]
+mod bulk_tests;
+#[path = "tests/contact_tests.rs"]
+mod contact_tests;
 #[path = "tests/edge_case_tests.rs"]
 mod edge_case_tests;
+#[path = "tests/interview_tests.rs"]
+mod interview_tests;
+#[path = "tests/offer_tests.rs"]
+mod offer_tests;
+#[path = "tests/posting_status_tests.rs"]
+mod posting_status_tests;
 #[path = "tests/reminder_tests.rs"]
 mod reminder_tests;
 #[path = "tests/stats_edge_tests.rs"]
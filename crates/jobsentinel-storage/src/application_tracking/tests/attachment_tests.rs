@@ -0,0 +1,58 @@
+use super::*;
+
+#[tokio::test]
+async fn test_attachments_are_versioned_per_kind() {
+    let pool = crate::test_support::migrated_pool().await;
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test123', 'Case Manager', 'CommunityCare', 'http://test.com', 'test')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let tracker = ApplicationTracker::new(pool);
+    let app_id = tracker.create_application("test123").await.unwrap();
+
+    let first = tracker
+        .add_attachment(app_id, "resume", "resume.pdf", "1/a--resume.pdf", 1200)
+        .await
+        .unwrap();
+    let second = tracker
+        .add_attachment(
+            app_id,
+            "resume",
+            "resume-v2.pdf",
+            "1/b--resume-v2.pdf",
+            1300,
+        )
+        .await
+        .unwrap();
+    let letter = tracker
+        .add_attachment(
+            app_id,
+            "cover_letter",
+            "letter.docx",
+            "1/c--letter.docx",
+            800,
+        )
+        .await
+        .unwrap();
+    assert_eq!((first.version, second.version, letter.version), (1, 2, 1));
+
+    let attachments = tracker.get_attachments(app_id).await.unwrap();
+    let order: Vec<_> = attachments
+        .iter()
+        .map(|attachment| (attachment.kind.as_str(), attachment.version))
+        .collect();
+    assert_eq!(order, [("cover_letter", 1), ("resume", 2), ("resume", 1)]);
+
+    let deleted = tracker.delete_attachment(second.id).await.unwrap().unwrap();
+    assert_eq!(deleted.stored_name, "1/b--resume-v2.pdf");
+    assert!(tracker.get_attachment(second.id).await.unwrap().is_none());
+    assert!(tracker
+        .delete_attachment(second.id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(tracker
+        .add_attachment(app_id, "portfolio", "site.zip", "1/d--site.zip", 10)
+        .await
+        .is_err());
+}
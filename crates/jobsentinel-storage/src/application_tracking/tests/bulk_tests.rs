@@ -0,0 +1,94 @@
+use super::*;
+
+async fn tracker_with_jobs() -> (ApplicationTracker, Vec<i64>) {
+    let pool = crate::test_support::migrated_pool().await;
+    let mut job_ids = Vec::new();
+    for hash in ["job_a", "job_b", "job_c"] {
+        let result = sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES (?, 'Case Manager', 'CommunityCare', 'http://test.com', 'test')")
+            .bind(hash)
+            .execute(&pool)
+            .await
+            .unwrap();
+        job_ids.push(result.last_insert_rowid());
+    }
+    (ApplicationTracker::new(pool), job_ids)
+}
+
+async fn event_count(tracker: &ApplicationTracker, event_type: &str) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM application_events WHERE event_type = ?")
+        .bind(event_type)
+        .fetch_one(&tracker.db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_bulk_create_skips_tracked_and_missing_jobs() {
+    let (tracker, job_ids) = tracker_with_jobs().await;
+    let existing = tracker.create_application("job_a").await.unwrap();
+
+    let created = tracker
+        .bulk_create_applications(&[job_ids[0], job_ids[1], job_ids[2], 9999])
+        .await
+        .unwrap();
+
+    assert_eq!(created.len(), 2);
+    assert!(!created.contains(&existing));
+    assert_eq!(event_count(&tracker, "application_created").await, 2);
+    assert_eq!(
+        tracker
+            .get_applications_by_status()
+            .await
+            .unwrap()
+            .to_apply
+            .len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_status_update_logs_each_change() {
+    let (tracker, job_ids) = tracker_with_jobs().await;
+    let ids = tracker.bulk_create_applications(&job_ids).await.unwrap();
+    tracker
+        .update_status(ids[0], ApplicationStatus::Applied)
+        .await
+        .unwrap();
+
+    let updated = tracker
+        .bulk_update_status(&[ids[0], ids[1], ids[2], 9999], ApplicationStatus::Applied)
+        .await
+        .unwrap();
+
+    assert_eq!(updated, 2);
+    assert_eq!(event_count(&tracker, "status_change").await, 3);
+    let board = tracker.get_applications_by_status().await.unwrap();
+    assert_eq!(board.applied.len(), 3);
+    assert!(board
+        .applied
+        .iter()
+        .all(|application| application.applied_at.is_some()));
+}
+
+#[tokio::test]
+async fn test_archive_only_moves_rejected_applications_off_the_board() {
+    let (tracker, job_ids) = tracker_with_jobs().await;
+    let ids = tracker.bulk_create_applications(&job_ids).await.unwrap();
+    tracker
+        .bulk_update_status(&ids[..2], ApplicationStatus::Rejected)
+        .await
+        .unwrap();
+
+    let archived = tracker.archive_rejected_applications(&ids).await.unwrap();
+    assert_eq!(archived, 2);
+    assert_eq!(
+        tracker.archive_rejected_applications(&ids).await.unwrap(),
+        0
+    );
+    assert_eq!(event_count(&tracker, "archived").await, 2);
+
+    let board = tracker.get_applications_by_status().await.unwrap();
+    assert!(board.rejected.is_empty());
+    assert_eq!(board.to_apply.len(), 1);
+    assert_eq!(tracker.get_application_stats().await.unwrap().total, 3);
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+fn recruiter() -> ContactInput {
+    ContactInput {
+        name: "Jordan Lee".to_string(),
+        email: Some("Jordan.Lee@CommunityCare.org ".to_string()),
+        company: Some("CommunityCare".to_string()),
+        ..ContactInput::default()
+    }
+}
+
+#[tokio::test]
+async fn test_save_contact_merges_duplicates() {
+    let (tracker, _) = tracker_with_application("test123").await;
+    let id = tracker.save_contact(&recruiter()).await.unwrap();
+
+    let same_email = ContactInput {
+        name: "Jordan Lee".to_string(),
+        email: Some("jordan.lee@communitycare.org".to_string()),
+        phone: Some("555-0100".to_string()),
+        ..ContactInput::default()
+    };
+    let same_name = ContactInput {
+        name: "jordan lee".to_string(),
+        company: Some("communitycare".to_string()),
+        linkedin_url: Some("https://www.linkedin.com/in/jordanlee/".to_string()),
+        ..ContactInput::default()
+    };
+    let other_person = ContactInput {
+        name: "Jordan Lee".to_string(),
+        email: Some("jlee@harborhealth.org".to_string()),
+        company: Some("CommunityCare".to_string()),
+        ..ContactInput::default()
+    };
+
+    assert_eq!(tracker.save_contact(&same_email).await.unwrap(), id);
+    assert_eq!(tracker.save_contact(&same_name).await.unwrap(), id);
+    assert_ne!(tracker.save_contact(&other_person).await.unwrap(), id);
+
+    let contact = tracker.get_contact(id).await.unwrap().unwrap();
+    assert_eq!(
+        contact.email.as_deref(),
+        Some("jordan.lee@communitycare.org")
+    );
+    assert_eq!(contact.phone.as_deref(), Some("555-0100"));
+    assert_eq!(
+        contact.linkedin_url.as_deref(),
+        Some("https://www.linkedin.com/in/jordanlee")
+    );
+    assert_eq!(contact.company.as_deref(), Some("communitycare"));
+}
+
+#[tokio::test]
+async fn test_search_contacts_matches_prefixes() {
+    let (tracker, _) = tracker_with_application("test123").await;
+    let id = tracker.save_contact(&recruiter()).await.unwrap();
+    tracker
+        .save_contact(&ContactInput {
+            name: "Sam Rivera".to_string(),
+            company: Some("Harbor Health".to_string()),
+            ..ContactInput::default()
+        })
+        .await
+        .unwrap();
+
+    let found = tracker.search_contacts("communityc", 10).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, id);
+    assert_eq!(tracker.search_contacts("  ", 10).await.unwrap().len(), 2);
+
+    tracker
+        .update_contact(
+            id,
+            &ContactInput {
+                name: "Jordan Lee".to_string(),
+                ..ContactInput::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(tracker
+        .search_contacts("communityc", 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_contact_links_follow_applications_and_interviews() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    let contact_id = tracker.save_contact(&recruiter()).await.unwrap();
+    let interview_id = tracker
+        .schedule_interview(
+            app_id,
+            "phone_interview",
+            "2026-02-01T10:00",
+            30,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    tracker
+        .link_contact_to_application(contact_id, app_id)
+        .await
+        .unwrap();
+    tracker
+        .link_contact_to_application(contact_id, app_id)
+        .await
+        .unwrap();
+    tracker
+        .link_contact_to_interview(contact_id, interview_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        tracker
+            .get_application_contacts(app_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(
+        tracker.get_interview_contacts(interview_id).await.unwrap()[0].id,
+        contact_id
+    );
+
+    tracker
+        .unlink_contact_from_application(contact_id, app_id)
+        .await
+        .unwrap();
+    assert!(tracker
+        .get_application_contacts(app_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert!(tracker.delete_contact(contact_id).await.unwrap());
+    assert!(tracker
+        .get_interview_contacts(interview_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!tracker.delete_contact(contact_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_logged_interactions_update_last_contacted_and_hold_off_ghosting() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    tracker
+        .update_status(app_id, ApplicationStatus::Applied)
+        .await
+        .unwrap();
+    let three_weeks_ago = Utc::now() - Duration::days(21);
+    sqlx::query("UPDATE applications SET applied_at = ? WHERE id = ?")
+        .bind(three_weeks_ago.to_rfc3339())
+        .bind(app_id)
+        .execute(&tracker.db)
+        .await
+        .unwrap();
+    let contact_id = tracker.save_contact(&recruiter()).await.unwrap();
+
+    let recent = Utc::now() - Duration::days(2);
+    tracker
+        .log_contact_interaction(
+            contact_id,
+            Some(app_id),
+            "call",
+            recent,
+            Some(" Phone screen "),
+        )
+        .await
+        .unwrap();
+    tracker
+        .log_contact_interaction(contact_id, None, "email", three_weeks_ago, None)
+        .await
+        .unwrap();
+
+    let contact = tracker.get_contact(contact_id).await.unwrap().unwrap();
+    assert_eq!(contact.last_contacted_at, Some(recent.to_rfc3339()));
+    let interactions = tracker.get_contact_interactions(contact_id).await.unwrap();
+    assert_eq!(interactions.len(), 2);
+    assert_eq!(interactions[0].interaction_type, "call");
+    assert_eq!(interactions[0].application_id, Some(app_id));
+    assert_eq!(interactions[0].summary.as_deref(), Some("Phone screen"));
+    assert_eq!(
+        tracker
+            .get_application_contacts(app_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Only the contact's recent interaction keeps the application active.
+    sqlx::query("UPDATE applications SET last_contact = ? WHERE id = ?")
+        .bind(three_weeks_ago.to_rfc3339())
+        .bind(app_id)
+        .execute(&tracker.db)
+        .await
+        .unwrap();
+    assert_eq!(tracker.auto_detect_ghosted().await.unwrap(), 0);
+
+    tracker
+        .unlink_contact_from_application(contact_id, app_id)
+        .await
+        .unwrap();
+    assert_eq!(tracker.auto_detect_ghosted().await.unwrap(), 1);
+}
@@ -0,0 +1,138 @@
+use super::*;
+
+#[tokio::test]
+async fn test_get_interview_includes_job_details() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    let interview_id = tracker
+        .schedule_interview(
+            app_id,
+            "phone_interview",
+            "2026-02-01T10:00",
+            30,
+            Some("Zoom"),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let interview = tracker.get_interview(interview_id).await.unwrap().unwrap();
+
+    assert_eq!(interview.scheduled_at, "2026-02-01T10:00");
+    assert_eq!(interview.location.as_deref(), Some("Zoom"));
+    assert_eq!(interview.job_title, "Case Manager");
+    assert_eq!(interview.company, "CommunityCare");
+    assert!(tracker
+        .get_interview(interview_id + 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_reschedule_interview_moves_time_and_keeps_duration() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    let interview_id = tracker
+        .schedule_interview(
+            app_id,
+            "onsite",
+            "2026-02-01T10:00",
+            90,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(tracker
+        .reschedule_interview(interview_id, "2026-02-03T14:30", None)
+        .await
+        .unwrap());
+    let interview = tracker.get_interview(interview_id).await.unwrap().unwrap();
+    assert_eq!(interview.scheduled_at, "2026-02-03T14:30");
+    assert_eq!(interview.duration_minutes, 90);
+
+    assert!(tracker
+        .reschedule_interview(interview_id, "2026-02-04T09:00", Some(45))
+        .await
+        .unwrap());
+    let interview = tracker.get_interview(interview_id).await.unwrap().unwrap();
+    assert_eq!(interview.duration_minutes, 45);
+
+    assert!(!tracker
+        .reschedule_interview(interview_id + 1, "2026-02-04T09:00", None)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_schedule_interview_starts_an_unchecked_prep_checklist() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    let interview_id = tracker
+        .schedule_interview(
+            app_id,
+            "technical_interview",
+            "2026-02-01T10:00",
+            60,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let items: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT item_id, completed FROM interview_prep_checklists WHERE interview_id = ? ORDER BY id",
+    )
+    .bind(interview_id)
+    .fetch_all(&tracker.db)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        items,
+        [
+            ("research".to_string(), 0),
+            ("review_jd".to_string(), 0),
+            ("prepare_questions".to_string(), 0),
+            ("star_stories".to_string(), 0),
+            ("tech_review".to_string(), 0),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_application_interviews_lists_earliest_first() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    for scheduled_at in ["2026-02-08T10:00", "2026-02-01T10:00"] {
+        tracker
+            .schedule_interview(
+                app_id,
+                "phone_interview",
+                scheduled_at,
+                30,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    let interviews = tracker.get_application_interviews(app_id).await.unwrap();
+    let times: Vec<_> = interviews
+        .iter()
+        .map(|interview| interview.scheduled_at.as_str())
+        .collect();
+    assert_eq!(times, ["2026-02-01T10:00", "2026-02-08T10:00"]);
+    assert!(tracker
+        .get_application_interviews(app_id + 1)
+        .await
+        .unwrap()
+        .is_empty());
+}
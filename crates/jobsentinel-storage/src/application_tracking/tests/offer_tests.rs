@@ -0,0 +1,85 @@
+use super::*;
+use jobsentinel_domain::PayPeriod;
+
+fn offer_input(base_salary: i64) -> OfferInput {
+    OfferInput {
+        base_salary,
+        currency: None,
+        pay_period: PayPeriod::Year,
+        annual_bonus: Some(5_000),
+        signing_bonus: None,
+        equity_annual_value: None,
+        benefits_summary: Some("Health, dental, 4% match".to_string()),
+        benefits_annual_value: Some(3_000),
+        location: Some("Denver, CO".to_string()),
+        cost_of_living_index: Some(105.0),
+        start_date: None,
+        offer_expires_at: Some("2026-03-01T17:00:00Z".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_save_offer_replaces_terms_for_the_same_application() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+
+    let offer_id = tracker
+        .save_offer(app_id, &offer_input(60_000))
+        .await
+        .unwrap();
+    let mut revised = offer_input(42);
+    revised.currency = Some("EUR".to_string());
+    revised.pay_period = PayPeriod::Hour;
+    let revised_id = tracker.save_offer(app_id, &revised).await.unwrap();
+
+    assert_eq!(revised_id, offer_id);
+    let offer = tracker.get_offer(offer_id).await.unwrap().unwrap();
+    assert_eq!(offer.base_salary, 42);
+    assert_eq!(offer.currency, "EUR");
+    assert_eq!(offer.pay_period, PayPeriod::Hour);
+    assert_eq!(offer.location.as_deref(), Some("Denver, CO"));
+    assert_eq!(offer.cost_of_living_index, Some(105.0));
+    assert_eq!(offer.job_title, "Case Manager");
+    assert_eq!(offer.company, "CommunityCare");
+    assert!(offer.offer_received_at.is_some());
+    assert_eq!(offer.accepted, None);
+}
+
+#[tokio::test]
+async fn test_get_offers_lists_soonest_deadline_first() {
+    let (tracker, first_app) = tracker_with_application("test123").await;
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test456', 'Program Lead', 'Harbor Health', 'http://test.com/2', 'test')")
+        .execute(&tracker.db)
+        .await
+        .unwrap();
+    let second_app = tracker.create_application("test456").await.unwrap();
+
+    let mut no_deadline = offer_input(60_000);
+    no_deadline.offer_expires_at = None;
+    tracker.save_offer(first_app, &no_deadline).await.unwrap();
+    tracker
+        .save_offer(second_app, &offer_input(65_000))
+        .await
+        .unwrap();
+
+    let companies: Vec<_> = tracker
+        .get_offers()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|offer| offer.company)
+        .collect();
+    assert_eq!(companies, ["Harbor Health", "CommunityCare"]);
+}
+
+#[tokio::test]
+async fn test_delete_offer_reports_missing_offers() {
+    let (tracker, app_id) = tracker_with_application("test123").await;
+    let offer_id = tracker
+        .save_offer(app_id, &offer_input(60_000))
+        .await
+        .unwrap();
+
+    assert!(tracker.delete_offer(offer_id).await.unwrap());
+    assert!(!tracker.delete_offer(offer_id).await.unwrap());
+    assert!(tracker.get_offers().await.unwrap().is_empty());
+}
@@ -0,0 +1,77 @@
+use super::*;
+
+#[tokio::test]
+async fn test_closed_posting_marks_application_once() {
+    let (tracker, application_id) = tracker_with_application("closed_hash").await;
+
+    let closed = tracker
+        .record_posting_closed("closed_hash", "posting_removed")
+        .await
+        .unwrap()
+        .unwrap();
+    let again = tracker
+        .record_posting_closed("closed_hash", "posting_removed")
+        .await
+        .unwrap();
+
+    assert_eq!(closed.application_id, application_id);
+    assert_eq!(closed.job_title, "Case Manager");
+    assert!(closed.needs_attention());
+    assert!(again.is_none());
+    let events = sqlx::query(
+        "SELECT event_data FROM application_events WHERE application_id = ? AND event_type = 'posting_closed'",
+    )
+    .bind(application_id)
+    .fetch_all(&tracker.db)
+    .await
+    .unwrap();
+    assert_eq!(events.len(), 1);
+
+    let board = tracker.get_applications_by_status().await.unwrap();
+    assert_eq!(
+        board.to_apply[0].posting_closed_at.as_deref(),
+        Some(closed.closed_at.as_str())
+    );
+}
+
+#[tokio::test]
+async fn test_closed_posting_after_applying_does_not_need_attention() {
+    let (tracker, application_id) = tracker_with_application("applied_hash").await;
+    tracker
+        .update_status(application_id, ApplicationStatus::Applied)
+        .await
+        .unwrap();
+
+    let closed = tracker
+        .record_posting_closed("applied_hash", "posting_removed")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(!closed.needs_attention());
+    assert!(tracker
+        .record_posting_closed("no_application_hash", "posting_removed")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_reopened_posting_clears_the_mark() {
+    let (tracker, _) = tracker_with_application("reopened_hash").await;
+    assert!(!tracker
+        .record_posting_reopened("reopened_hash")
+        .await
+        .unwrap());
+    tracker
+        .record_posting_closed("reopened_hash", "posting_removed")
+        .await
+        .unwrap();
+
+    assert!(tracker
+        .record_posting_reopened("reopened_hash")
+        .await
+        .unwrap());
+    let board = tracker.get_applications_by_status().await.unwrap();
+    assert!(board.to_apply[0].posting_closed_at.is_none());
+}
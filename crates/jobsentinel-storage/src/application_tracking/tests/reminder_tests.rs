@@ -119,3 +119,133 @@ async fn test_withdrawn_status_no_auto_reminder() {
     // Withdrawn is not in the auto-reminder list (line 301), so no reminders
     assert_eq!(reminders.len(), 0);
 }
+
+#[tokio::test]
+async fn test_reschedule_reminder_moves_one_reminder() {
+    let pool = crate::test_support::migrated_pool().await;
+
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test123', 'Case Manager', 'CommunityCare', 'http://test.com', 'test')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let tracker = ApplicationTracker::new(pool.clone());
+    let app_id = tracker.create_application("test123").await.unwrap();
+    tracker
+        .set_reminder(app_id, "follow_up", Utc::now(), "Check in")
+        .await
+        .unwrap();
+    let reminder_id: i64 = sqlx::query_scalar("SELECT id FROM application_reminders")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    assert!(tracker
+        .reschedule_reminder(reminder_id, "2030-01-02T09:00:00+00:00")
+        .await
+        .unwrap());
+    let schedule = tracker
+        .get_reminder_schedule(reminder_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(schedule.reminder_time, "2030-01-02T09:00:00+00:00");
+    assert!(!schedule.completed);
+    assert!(tracker.get_pending_reminders().await.unwrap().is_empty());
+
+    assert!(!tracker
+        .reschedule_reminder(reminder_id + 1, "2030-01-02T09:00:00+00:00")
+        .await
+        .unwrap());
+    assert!(tracker
+        .get_reminder_schedule(reminder_id + 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_delete_application_removes_its_reminders() {
+    let pool = crate::test_support::migrated_pool().await;
+
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test123', 'Case Manager', 'CommunityCare', 'http://test.com', 'test')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let tracker = ApplicationTracker::new(pool.clone());
+    let app_id = tracker.create_application("test123").await.unwrap();
+    tracker
+        .set_reminder(app_id, "follow_up", Utc::now(), "Check in")
+        .await
+        .unwrap();
+
+    tracker.delete_application(app_id).await.unwrap();
+
+    assert!(tracker
+        .find_application_id_by_job_hash("test123")
+        .await
+        .unwrap()
+        .is_none());
+    let reminders: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM application_reminders")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(reminders, 0);
+}
+
+#[tokio::test]
+async fn test_due_reminders_are_delivered_once_until_rescheduled() {
+    let pool = crate::test_support::migrated_pool().await;
+
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test123', 'Case Manager', 'CommunityCare', 'http://test.com', 'test')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let tracker = ApplicationTracker::new(pool.clone());
+    let app_id = tracker.create_application("test123").await.unwrap();
+    let now = Utc::now();
+    tracker
+        .set_reminder(app_id, "follow_up", now - Duration::hours(1), "Check in")
+        .await
+        .unwrap();
+    tracker
+        .set_reminder(app_id, "custom", now + Duration::days(1), "Later")
+        .await
+        .unwrap();
+
+    let due = tracker.get_undelivered_reminders(now, 10).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].job_title, "Case Manager");
+    assert_eq!(due[0].message.as_deref(), Some("Check in"));
+
+    tracker
+        .mark_reminder_delivered(due[0].id, now)
+        .await
+        .unwrap();
+    assert!(tracker
+        .get_undelivered_reminders(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    // Delivered reminders stay pending until the user completes them.
+    assert_eq!(tracker.get_pending_reminders().await.unwrap().len(), 1);
+
+    let rescheduled = (now - Duration::minutes(5)).to_rfc3339();
+    assert!(tracker
+        .reschedule_reminder(due[0].id, &rescheduled)
+        .await
+        .unwrap());
+    assert_eq!(
+        tracker.get_undelivered_reminders(now, 10).await.unwrap()[0].id,
+        due[0].id
+    );
+
+    tracker.complete_reminder(due[0].id).await.unwrap();
+    assert!(tracker
+        .get_undelivered_reminders(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -38,6 +38,16 @@ impl ApplicationTracker {
         Ok(result.last_insert_rowid())
     }
 
+    /// Delete an application with its events, reminders, and interviews
+    pub async fn delete_application(&self, application_id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM applications WHERE id = ?")
+            .bind(application_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
     /// Get application by ID
     pub async fn get_application(&self, application_id: i64) -> Result<Application> {
         let row = sqlx::query!(
@@ -189,10 +199,10 @@ impl ApplicationTracker {
         Ok(())
     }
 
-    /// Get all applications grouped by status (for Kanban board)
+    /// Get applications grouped by status (for Kanban board), leaving out
+    /// archived ones
     pub async fn get_applications_by_status(&self) -> Result<ApplicationsByStatus> {
-        let apps = sqlx::query_as!(
-            ApplicationWithJob,
+        let apps = sqlx::query_as::<_, ApplicationWithJob>(
             r#"
             SELECT
                 a.id,
@@ -203,11 +213,13 @@ impl ApplicationTracker {
                 a.notes,
                 j.title as job_title,
                 j.company,
-                j.score as "score!: f64"
+                COALESCE(j.score, 0.0) as score,
+                a.posting_closed_at
             FROM applications a
             JOIN jobs j ON a.job_hash = j.hash
+            WHERE a.archived_at IS NULL
             ORDER BY a.updated_at DESC
-            "#
+            "#,
         )
         .fetch_all(&self.db)
         .await?;
@@ -235,7 +247,8 @@ impl ApplicationTracker {
         Ok(result)
     }
 
-    /// Mark application as ghosted if no contact in 2+ weeks
+    /// Mark application as ghosted if no contact in 2+ weeks, counting
+    /// interactions logged with any of its contacts
     pub async fn auto_detect_ghosted(&self) -> Result<usize> {
         use chrono::Duration;
 
@@ -250,10 +263,17 @@ impl ApplicationTracker {
                   (last_contact IS NOT NULL AND last_contact < ?)
                   OR (last_contact IS NULL AND applied_at IS NOT NULL AND applied_at < ?)
               )
+              AND NOT EXISTS (
+                  SELECT 1 FROM application_contacts ac
+                  JOIN contacts c ON c.id = ac.contact_id
+                  WHERE ac.application_id = applications.id
+                    AND c.last_contacted_at >= ?
+              )
             "#,
         )
         .bind(&two_weeks_ago)
         .bind(&two_weeks_ago)
+        .bind(&two_weeks_ago)
         .execute(&self.db)
         .await?;
 
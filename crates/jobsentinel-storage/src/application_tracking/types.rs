@@ -2,6 +2,7 @@
 
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
+use jobsentinel_domain::PayPeriod;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -103,7 +104,7 @@ pub struct ApplicationsByStatus {
 }
 
 /// Application with job details (for Kanban display)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct ApplicationWithJob {
     pub id: i64,
     pub job_hash: String,
@@ -114,6 +115,19 @@ pub struct ApplicationWithJob {
     pub job_title: String,
     pub company: String,
     pub score: f64,
+    /// When the posting was found closed, for the card badge
+    pub posting_closed_at: Option<String>,
+}
+
+/// An application whose posting was just found closed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosedPostingApplication {
+    pub application_id: i64,
+    pub job_hash: String,
+    pub job_title: String,
+    pub company: String,
+    pub status: ApplicationStatus,
+    pub closed_at: String,
 }
 
 /// Application statistics for analytics dashboard
@@ -214,7 +228,7 @@ pub struct InterviewWithJob {
 }
 
 /// Pending reminder with job details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PendingReminder {
     pub id: i64,
     pub application_id: i64,
@@ -225,3 +239,131 @@ pub struct PendingReminder {
     pub job_title: String,
     pub company: String,
 }
+
+/// Offer terms to save for an application
+///
+/// Amounts are in the offer's currency. Bonus, equity, and benefit values
+/// are yearly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfferInput {
+    pub base_salary: i64,
+    /// ISO 4217 code; US dollars when missing
+    pub currency: Option<String>,
+    /// How often `base_salary` is paid
+    #[serde(default = "default_offer_pay_period")]
+    pub pay_period: PayPeriod,
+    pub annual_bonus: Option<i64>,
+    pub signing_bonus: Option<i64>,
+    /// Expected yearly value of the equity grant
+    pub equity_annual_value: Option<i64>,
+    pub benefits_summary: Option<String>,
+    /// Yearly value of benefits beyond pay, such as retirement matching
+    pub benefits_annual_value: Option<i64>,
+    pub location: Option<String>,
+    /// Cost of living where the job is, where 100 is where you live now
+    pub cost_of_living_index: Option<f64>,
+    pub start_date: Option<String>,
+    /// When the offer has to be answered
+    pub offer_expires_at: Option<String>,
+}
+
+const fn default_offer_pay_period() -> PayPeriod {
+    PayPeriod::Year
+}
+
+/// Offer with job details (for display)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfferWithJob {
+    pub id: i64,
+    pub application_id: i64,
+    pub job_title: String,
+    pub company: String,
+    pub base_salary: i64,
+    pub currency: String,
+    pub pay_period: PayPeriod,
+    pub annual_bonus: Option<i64>,
+    pub signing_bonus: Option<i64>,
+    pub equity_annual_value: Option<i64>,
+    pub benefits_summary: Option<String>,
+    pub benefits_annual_value: Option<i64>,
+    pub location: Option<String>,
+    pub cost_of_living_index: Option<f64>,
+    pub start_date: Option<String>,
+    pub offer_expires_at: Option<String>,
+    pub offer_received_at: Option<String>,
+    /// `None` until the offer is accepted or declined
+    pub accepted: Option<bool>,
+}
+
+/// A recruiter, hiring manager, or other contact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Contact {
+    pub id: i64,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub linkedin_url: Option<String>,
+    pub company: Option<String>,
+    pub notes: Option<String>,
+    /// Most recent logged interaction
+    pub last_contacted_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Contact details to save
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactInput {
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub linkedin_url: Option<String>,
+    pub company: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// A logged email, call, message, or meeting with a contact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ContactInteraction {
+    pub id: i64,
+    pub contact_id: i64,
+    /// Application the interaction was about, if any
+    pub application_id: Option<i64>,
+    /// `email`, `call`, `message`, `meeting`, or `other`
+    pub interaction_type: String,
+    pub occurred_at: String,
+    pub summary: Option<String>,
+}
+
+/// When a reminder is due and whether it has been handled
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReminderSchedule {
+    pub id: i64,
+    pub reminder_time: String,
+    pub completed: bool,
+}
+
+impl ReminderSchedule {
+    /// Parsed due time, or `None` if the stored value is malformed
+    pub fn due_at(&self) -> Option<DateTime<Utc>> {
+        crate::sqlite_time::parse_sqlite_datetime(&self.reminder_time).ok()
+    }
+}
+
+/// A file attached to an application
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ApplicationAttachment {
+    pub id: i64,
+    pub application_id: i64,
+    /// `resume`, `cover_letter`, `take_home`, or `other`
+    pub kind: String,
+    /// Starts at 1 and counts up for each file of the same kind
+    pub version: i64,
+    /// Name of the file the user attached
+    pub file_name: String,
+    /// Path of the stored copy, relative to the attachments folder
+    #[serde(skip)]
+    pub stored_name: String,
+    pub size_bytes: i64,
+    pub created_at: String,
+}
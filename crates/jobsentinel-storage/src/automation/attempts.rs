@@ -4,10 +4,14 @@
 //! submission, and remain responsible for the target site's terms. The app
 //! stops at security challenges and does not bypass site protections.
 
+use crate::application_tracking::{ApplicationStatus, ApplicationTracker};
 use crate::sqlite_time::parse_sqlite_datetime;
 use anyhow::Result;
-use chrono::{DateTime, Utc};
-use jobsentinel_domain::{ApplicationAttempt, AtsPlatform, AutomationStats, AutomationStatus};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use jobsentinel_domain::{
+    ApplicationAttempt, AtsPlatform, AttemptFillSummary, AutomationQuota, AutomationStats,
+    AutomationStatus,
+};
 use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
 /// Manages application automation lifecycle and database tracking.
@@ -56,6 +60,8 @@ fn attempt_from_row(row: SqliteRow) -> Result<ApplicationAttempt> {
         screenshot_path: row.try_get("screenshot_path")?,
         confirmation_screenshot_path: row.try_get("confirmation_screenshot_path")?,
         automation_duration_ms: row.try_get("automation_duration_ms")?,
+        profile_id: row.try_get("profile_id")?,
+        dry_run: row.try_get::<i32, _>("dry_run")? != 0,
         user_approved: row.try_get::<i32, _>("user_approved")? != 0,
         submitted_at,
         created_at,
@@ -127,7 +133,8 @@ impl AutomationManager {
             r#"
             SELECT id, job_hash, application_id, status, ats_platform,
                    error_message, screenshot_path, confirmation_screenshot_path,
-                   automation_duration_ms, user_approved, submitted_at, created_at
+                   automation_duration_ms, profile_id, dry_run, user_approved, submitted_at,
+                   created_at
             FROM application_attempts
             WHERE id = ?
             "#,
@@ -149,7 +156,8 @@ impl AutomationManager {
             r#"
             SELECT id, job_hash, application_id, status, ats_platform,
                    error_message, screenshot_path, confirmation_screenshot_path,
-                   automation_duration_ms, user_approved, submitted_at, created_at
+                   automation_duration_ms, profile_id, dry_run, user_approved, submitted_at,
+                   created_at
             FROM application_attempts
             WHERE job_hash = ?
             ORDER BY created_at DESC
@@ -210,6 +218,116 @@ impl AutomationManager {
         Ok(())
     }
 
+    /// Record the screenshot taken of the filled form for review.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt_id` - Database ID of the attempt
+    /// * `screenshot_path` - Local path of the saved screenshot
+    pub async fn set_screenshot_path(&self, attempt_id: i64, screenshot_path: &str) -> Result<()> {
+        sqlx::query("UPDATE application_attempts SET screenshot_path = ? WHERE id = ?")
+            .bind(screenshot_path)
+            .bind(attempt_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Record which application profile filled the form.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt_id` - Database ID of the attempt
+    /// * `profile_id` - Database ID of the application profile
+    pub async fn set_profile(&self, attempt_id: i64, profile_id: i64) -> Result<()> {
+        sqlx::query("UPDATE application_attempts SET profile_id = ? WHERE id = ?")
+            .bind(profile_id)
+            .bind(attempt_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Record what was entered into the form so the user can review it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt_id` - Database ID of the attempt
+    /// * `summary` - Filled values, unfilled fields, and unanswered questions
+    pub async fn set_fill_summary(
+        &self,
+        attempt_id: i64,
+        summary: &AttemptFillSummary,
+    ) -> Result<()> {
+        sqlx::query("UPDATE application_attempts SET fill_summary = ? WHERE id = ?")
+            .bind(serde_json::to_string(summary)?)
+            .bind(attempt_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get what was entered into the form for an attempt.
+    ///
+    /// Returns `None` when the attempt does not exist or was filled before
+    /// summaries were recorded.
+    pub async fn get_fill_summary(&self, attempt_id: i64) -> Result<Option<AttemptFillSummary>> {
+        let summary: Option<Option<String>> =
+            sqlx::query_scalar("SELECT fill_summary FROM application_attempts WHERE id = ?")
+                .bind(attempt_id)
+                .fetch_optional(&self.db)
+                .await?;
+
+        summary
+            .flatten()
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Record that an attempt was a dry run.
+    ///
+    /// Dry-run attempts are left out of the pending queue.
+    pub async fn mark_dry_run(&self, attempt_id: i64) -> Result<()> {
+        sqlx::query("UPDATE application_attempts SET dry_run = 1 WHERE id = ?")
+            .bind(attempt_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Whether dry-run mode is on.
+    ///
+    /// In dry-run mode forms are filled and recorded, then closed without
+    /// being sent. Off until the user turns it on.
+    pub async fn dry_run_enabled(&self) -> Result<bool> {
+        let enabled: Option<bool> =
+            sqlx::query_scalar("SELECT dry_run FROM automation_settings WHERE id = 1")
+                .fetch_optional(&self.db)
+                .await?;
+
+        Ok(enabled.unwrap_or(false))
+    }
+
+    /// Turn dry-run mode on or off.
+    pub async fn set_dry_run_enabled(&self, enabled: bool) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO automation_settings (id, dry_run) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET dry_run = excluded.dry_run
+            "#,
+        )
+        .bind(enabled)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
     /// Mark an attempt as approved by the user (human-in-the-loop).
     ///
     /// Sets `user_approved = true` and transitions status to `Pending` so the
@@ -250,10 +368,20 @@ impl AutomationManager {
     /// Sets status to `Submitted` and records the submission timestamp.
     /// This is the final state for successful automation.
     ///
+    /// The job's application is created if there is none yet, moved to
+    /// `Applied` if it was still waiting in `To Apply`, and linked to the
+    /// attempt, so the application board matches what was sent. Applications
+    /// already past `To Apply` keep their status.
+    ///
     /// # Arguments
     ///
     /// * `attempt_id` - Database ID of the attempt
-    pub async fn mark_submitted(&self, attempt_id: i64) -> Result<()> {
+    ///
+    /// # Returns
+    ///
+    /// ID of the linked application.
+    pub async fn mark_submitted(&self, attempt_id: i64) -> Result<i64> {
+        let attempt = self.get_attempt(attempt_id).await?;
         let now = Utc::now().to_rfc3339();
 
         sqlx::query(
@@ -269,7 +397,27 @@ impl AutomationManager {
         .execute(&self.db)
         .await?;
 
-        Ok(())
+        let tracker = ApplicationTracker::new(self.db.clone());
+        let application_id = match tracker
+            .find_application_id_by_job_hash(&attempt.job_hash)
+            .await?
+        {
+            Some(application_id) => application_id,
+            None => tracker.create_application(&attempt.job_hash).await?,
+        };
+        if tracker.get_application(application_id).await?.status == ApplicationStatus::ToApply {
+            tracker
+                .update_status(application_id, ApplicationStatus::Applied)
+                .await?;
+        }
+
+        sqlx::query("UPDATE application_attempts SET application_id = ? WHERE id = ?")
+            .bind(application_id)
+            .bind(attempt_id)
+            .execute(&self.db)
+            .await?;
+
+        Ok(application_id)
     }
 
     /// Retrieve pending automation attempts that are ready to process.
@@ -289,9 +437,10 @@ impl AutomationManager {
             r#"
             SELECT id, job_hash, application_id, status, ats_platform,
                    error_message, screenshot_path, confirmation_screenshot_path,
-                   automation_duration_ms, user_approved, submitted_at, created_at
+                   automation_duration_ms, profile_id, dry_run, user_approved, submitted_at,
+                   created_at
             FROM application_attempts
-            WHERE status = ? AND user_approved = 1
+            WHERE status = ? AND user_approved = 1 AND dry_run = 0
             ORDER BY created_at ASC
             LIMIT ?
             "#,
@@ -304,6 +453,71 @@ impl AutomationManager {
         rows.into_iter().map(attempt_from_row).collect()
     }
 
+    /// Check how many more application forms can be prepared right now.
+    ///
+    /// Counts attempts created since midnight UTC and since the start of the
+    /// week (Monday), leaving out cancelled attempts, against the limits from
+    /// the application profile. Callers must not start a new attempt when
+    /// `remaining` is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `daily_limit` - Forms allowed per day
+    /// * `weekly_limit` - Forms allowed per week, or `None` for no weekly cap
+    /// * `now` - Current time
+    pub async fn get_quota(
+        &self,
+        daily_limit: i32,
+        weekly_limit: Option<i32>,
+        now: DateTime<Utc>,
+    ) -> Result<AutomationQuota> {
+        let day_start = now
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default()
+            .and_utc();
+        let week_start =
+            day_start - Duration::days(i64::from(now.weekday().num_days_from_monday()));
+
+        let row = sqlx::query(
+            r#"
+            SELECT
+                COALESCE(SUM(CASE WHEN created_at >= ? THEN 1 ELSE 0 END), 0) AS used_today,
+                COUNT(*) AS used_this_week
+            FROM application_attempts
+            WHERE created_at >= ? AND status != ?
+            "#,
+        )
+        .bind(day_start.format("%Y-%m-%d %H:%M:%S").to_string())
+        .bind(week_start.format("%Y-%m-%d %H:%M:%S").to_string())
+        .bind(AutomationStatus::Cancelled.as_str())
+        .fetch_one(&self.db)
+        .await?;
+
+        let used_today: i64 = row.try_get("used_today")?;
+        let used_this_week: i64 = row.try_get("used_this_week")?;
+        let daily_limit = i64::from(daily_limit.max(0));
+        let weekly_limit = weekly_limit.map(|limit| i64::from(limit.max(0)));
+
+        let left_today = (daily_limit - used_today).max(0);
+        let left_this_week = weekly_limit.map(|limit| (limit - used_this_week).max(0));
+        let remaining = left_this_week.map_or(left_today, |left| left.min(left_today));
+        let resets_at = if left_this_week == Some(0) {
+            week_start + Duration::weeks(1)
+        } else {
+            day_start + Duration::days(1)
+        };
+
+        Ok(AutomationQuota {
+            daily_limit,
+            weekly_limit,
+            used_today,
+            used_this_week,
+            remaining,
+            resets_at,
+        })
+    }
+
     /// Calculate aggregated automation statistics.
     ///
     /// Returns counts for all attempt statuses and calculates success rate.
@@ -359,8 +573,8 @@ mod row_mapper_tests {
                    'pending' AS status, 'lever' AS ats_platform,
                    NULL AS error_message, NULL AS screenshot_path,
                    NULL AS confirmation_screenshot_path,
-                   NULL AS automation_duration_ms, 1 AS user_approved,
-                   NULL AS submitted_at, ? AS created_at
+                   NULL AS automation_duration_ms, NULL AS profile_id, 0 AS dry_run,
+                   1 AS user_approved, NULL AS submitted_at, ? AS created_at
             "#,
         )
         .bind(created_at)
@@ -1,4 +1,5 @@
 use super::*;
+use chrono::TimeZone;
 
 #[tokio::test]
 async fn test_create_automation_attempt() {
@@ -108,3 +109,229 @@ async fn test_approve_and_submit() {
     assert_eq!(attempt.status, AutomationStatus::Submitted);
     assert!(attempt.submitted_at.is_some());
 }
+
+#[tokio::test]
+async fn test_mark_submitted_creates_and_advances_application() {
+    let pool = crate::test_support::migrated_pool().await;
+    let manager = AutomationManager::new(pool.clone());
+    for hash in ["new_hash", "saved_hash", "interview_hash"] {
+        sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES (?, 'Case Manager', 'CommunityCare', 'https://example.com', 'greenhouse')")
+            .bind(hash)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    let tracker = ApplicationTracker::new(pool.clone());
+    let saved_id = tracker.create_application("saved_hash").await.unwrap();
+    let interview_id = tracker.create_application("interview_hash").await.unwrap();
+    tracker
+        .update_status(interview_id, ApplicationStatus::PhoneInterview)
+        .await
+        .unwrap();
+
+    let new_attempt = manager
+        .create_attempt("new_hash", AtsPlatform::Greenhouse)
+        .await
+        .unwrap();
+    let new_app_id = manager.mark_submitted(new_attempt).await.unwrap();
+    let application = tracker.get_application(new_app_id).await.unwrap();
+    assert_eq!(application.job_hash, "new_hash");
+    assert_eq!(application.status, ApplicationStatus::Applied);
+    assert!(application.applied_at.is_some());
+    assert_eq!(
+        manager
+            .get_attempt(new_attempt)
+            .await
+            .unwrap()
+            .application_id,
+        Some(new_app_id)
+    );
+
+    let saved_attempt = manager
+        .create_attempt("saved_hash", AtsPlatform::Greenhouse)
+        .await
+        .unwrap();
+    assert_eq!(
+        manager.mark_submitted(saved_attempt).await.unwrap(),
+        saved_id
+    );
+    assert_eq!(
+        tracker.get_application(saved_id).await.unwrap().status,
+        ApplicationStatus::Applied
+    );
+
+    let interview_attempt = manager
+        .create_attempt("interview_hash", AtsPlatform::Greenhouse)
+        .await
+        .unwrap();
+    assert_eq!(
+        manager.mark_submitted(interview_attempt).await.unwrap(),
+        interview_id
+    );
+    assert_eq!(
+        tracker.get_application(interview_id).await.unwrap().status,
+        ApplicationStatus::PhoneInterview
+    );
+}
+
+#[tokio::test]
+async fn test_set_screenshot_path_keeps_status() {
+    let pool = crate::test_support::migrated_pool().await;
+    let manager = AutomationManager::new(pool.clone());
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test_hash', 'Case Manager', 'CommunityCare', 'https://example.com', 'greenhouse')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let attempt_id = manager
+        .create_attempt("test_hash", AtsPlatform::Greenhouse)
+        .await
+        .unwrap();
+    manager
+        .update_status(attempt_id, AutomationStatus::AwaitingApproval, None)
+        .await
+        .unwrap();
+    manager
+        .set_screenshot_path(attempt_id, "/data/automation-screenshots/1.png")
+        .await
+        .unwrap();
+
+    let attempt = manager.get_attempt(attempt_id).await.unwrap();
+    assert_eq!(attempt.status, AutomationStatus::AwaitingApproval);
+    assert_eq!(
+        attempt.screenshot_path.as_deref(),
+        Some("/data/automation-screenshots/1.png")
+    );
+}
+
+#[tokio::test]
+async fn test_fill_summary_round_trips() {
+    let pool = crate::test_support::migrated_pool().await;
+    let manager = AutomationManager::new(pool);
+
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('summary_hash', 'Scheduler', 'Metro Clinic', 'https://example.com/summary', 'lever')")
+        .execute(&manager.db)
+        .await
+        .unwrap();
+
+    let attempt_id = manager
+        .create_attempt("summary_hash", AtsPlatform::Lever)
+        .await
+        .unwrap();
+    assert_eq!(manager.get_fill_summary(attempt_id).await.unwrap(), None);
+
+    let mut summary = AttemptFillSummary::default();
+    summary
+        .filled_values
+        .insert("email".to_string(), "jordan@example.com".to_string());
+    summary
+        .unanswered_questions
+        .push("Why do you want to work here?".to_string());
+    manager
+        .set_fill_summary(attempt_id, &summary)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        manager.get_fill_summary(attempt_id).await.unwrap(),
+        Some(summary)
+    );
+    assert_eq!(
+        manager.get_fill_summary(attempt_id + 1).await.unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_setting_and_attempts_stay_out_of_the_queue() {
+    let pool = crate::test_support::migrated_pool().await;
+    let manager = AutomationManager::new(pool);
+
+    assert!(!manager.dry_run_enabled().await.unwrap());
+    manager.set_dry_run_enabled(true).await.unwrap();
+    assert!(manager.dry_run_enabled().await.unwrap());
+
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('dry_hash', 'Scheduler', 'Metro Clinic', 'https://example.com/dry', 'lever')")
+        .execute(&manager.db)
+        .await
+        .unwrap();
+    let dry_run_id = manager
+        .create_attempt("dry_hash", AtsPlatform::Lever)
+        .await
+        .unwrap();
+    let real_id = manager
+        .create_attempt("dry_hash", AtsPlatform::Lever)
+        .await
+        .unwrap();
+    manager.mark_dry_run(dry_run_id).await.unwrap();
+    manager.approve_attempt(dry_run_id).await.unwrap();
+    manager.approve_attempt(real_id).await.unwrap();
+
+    assert!(manager.get_attempt(dry_run_id).await.unwrap().dry_run);
+    let pending = manager.get_pending_attempts(10).await.unwrap();
+    assert_eq!(
+        pending.iter().map(|attempt| attempt.id).collect::<Vec<_>>(),
+        vec![real_id]
+    );
+
+    manager.set_dry_run_enabled(false).await.unwrap();
+    assert!(!manager.dry_run_enabled().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_quota_counts_todays_and_this_weeks_attempts() {
+    let pool = crate::test_support::migrated_pool().await;
+    let manager = AutomationManager::new(pool.clone());
+    sqlx::query("INSERT INTO jobs (hash, title, company, url, source) VALUES ('test_hash', 'Case Manager', 'CommunityCare', 'https://example.com', 'greenhouse')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Wednesday; the week started Monday 2026-10-12.
+    let now = Utc.with_ymd_and_hms(2026, 10, 14, 15, 0, 0).unwrap();
+    for (created_at, status) in [
+        ("2026-10-14 09:00:00", AutomationStatus::AwaitingApproval),
+        ("2026-10-14 10:00:00", AutomationStatus::Submitted),
+        ("2026-10-14 11:00:00", AutomationStatus::Cancelled),
+        ("2026-10-12 08:00:00", AutomationStatus::Failed),
+        ("2026-10-11 23:00:00", AutomationStatus::Submitted),
+    ] {
+        let attempt_id = manager
+            .create_attempt("test_hash", AtsPlatform::Greenhouse)
+            .await
+            .unwrap();
+        manager
+            .update_status(attempt_id, status, None)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE application_attempts SET created_at = ? WHERE id = ?")
+            .bind(created_at)
+            .bind(attempt_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let quota = manager.get_quota(10, None, now).await.unwrap();
+    assert_eq!(quota.used_today, 2);
+    assert_eq!(quota.used_this_week, 3);
+    assert_eq!(quota.remaining, 8);
+    assert_eq!(
+        quota.resets_at,
+        Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap()
+    );
+
+    let quota = manager.get_quota(10, Some(3), now).await.unwrap();
+    assert_eq!(quota.remaining, 0);
+    assert_eq!(
+        quota.resets_at,
+        Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap()
+    );
+
+    let quota = manager.get_quota(2, Some(20), now).await.unwrap();
+    assert_eq!(quota.remaining, 0);
+    assert_eq!(
+        quota.resets_at,
+        Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap()
+    );
+}
@@ -2,10 +2,12 @@
 
 mod answer_learning;
 mod attempts;
+mod permissions;
 mod profile;
 
 pub use answer_learning::AnswerLearningManager;
 pub use attempts::AutomationManager;
+pub use permissions::AutomationPermissionManager;
 pub use profile::ProfileManager;
 
 pub use jobsentinel_domain::{
@@ -0,0 +1,101 @@
+//! Per-domain permission grants for Application Assist.
+
+use crate::sqlite_time::parse_sqlite_datetime;
+use anyhow::Result;
+use jobsentinel_domain::AutomationPermission;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+/// Stores which sites the user allowed Application Assist to act on.
+#[derive(Debug)]
+pub struct AutomationPermissionManager {
+    db: SqlitePool,
+}
+
+fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+fn permission_from_row(row: SqliteRow) -> Result<AutomationPermission> {
+    let last_used_at = row
+        .try_get::<Option<String>, _>("last_used_at")?
+        .and_then(|value| parse_sqlite_datetime(&value).ok());
+
+    Ok(AutomationPermission {
+        domain: row.try_get("domain")?,
+        granted_at: parse_sqlite_datetime(&row.try_get::<String, _>("granted_at")?)?,
+        last_used_at,
+    })
+}
+
+impl AutomationPermissionManager {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Grant permission for `domain`. Granting an allowed domain again keeps
+    /// the original grant time.
+    pub async fn grant(&self, domain: &str) -> Result<()> {
+        let domain = normalize_domain(domain);
+        anyhow::ensure!(!domain.is_empty(), "domain is required");
+
+        sqlx::query("INSERT OR IGNORE INTO automation_permissions (domain) VALUES (?)")
+            .bind(&domain)
+            .execute(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Whether the user has allowed Application Assist on `domain`.
+    pub async fn is_granted(&self, domain: &str) -> Result<bool> {
+        let granted = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM automation_permissions WHERE domain = ?",
+        )
+        .bind(normalize_domain(domain))
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(granted > 0)
+    }
+
+    /// Record that Application Assist acted on `domain`.
+    pub async fn mark_used(&self, domain: &str) -> Result<()> {
+        sqlx::query(
+            "UPDATE automation_permissions SET last_used_at = datetime('now') WHERE domain = ?",
+        )
+        .bind(normalize_domain(domain))
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// List every granted domain, most recently granted first.
+    pub async fn list(&self) -> Result<Vec<AutomationPermission>> {
+        sqlx::query(
+            r#"
+            SELECT domain, granted_at, last_used_at
+            FROM automation_permissions
+            ORDER BY granted_at DESC, id DESC
+            "#,
+        )
+        .fetch_all(&self.db)
+        .await?
+        .into_iter()
+        .map(permission_from_row)
+        .collect()
+    }
+
+    /// Revoke permission for `domain`. Returns whether a grant existed.
+    pub async fn revoke(&self, domain: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM automation_permissions WHERE domain = ?")
+            .bind(normalize_domain(domain))
+            .execute(&self.db)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests;
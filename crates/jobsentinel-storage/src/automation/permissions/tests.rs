@@ -0,0 +1,44 @@
+use super::*;
+use crate::test_support::migrated_pool;
+
+#[tokio::test]
+async fn grants_are_per_exact_domain_and_case_insensitive() {
+    let manager = AutomationPermissionManager::new(migrated_pool().await);
+
+    manager.grant("Boards.Greenhouse.io").await.unwrap();
+
+    assert!(manager.is_granted("boards.greenhouse.io").await.unwrap());
+    assert!(!manager.is_granted("jobs.lever.co").await.unwrap());
+    assert!(!manager.is_granted("greenhouse.io").await.unwrap());
+}
+
+#[tokio::test]
+async fn granting_twice_keeps_a_single_entry() {
+    let manager = AutomationPermissionManager::new(migrated_pool().await);
+
+    manager.grant("jobs.lever.co").await.unwrap();
+    manager.grant("jobs.lever.co").await.unwrap();
+    manager.mark_used("jobs.lever.co").await.unwrap();
+
+    let permissions = manager.list().await.unwrap();
+    assert_eq!(permissions.len(), 1);
+    assert_eq!(permissions[0].domain, "jobs.lever.co");
+    assert!(permissions[0].last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn revoke_removes_the_grant() {
+    let manager = AutomationPermissionManager::new(migrated_pool().await);
+    manager.grant("jobs.lever.co").await.unwrap();
+
+    assert!(manager.revoke("jobs.lever.co").await.unwrap());
+    assert!(!manager.revoke("jobs.lever.co").await.unwrap());
+    assert!(!manager.is_granted("jobs.lever.co").await.unwrap());
+}
+
+#[tokio::test]
+async fn blank_domain_is_rejected() {
+    let manager = AutomationPermissionManager::new(migrated_pool().await);
+
+    assert!(manager.grant("  ").await.is_err());
+}
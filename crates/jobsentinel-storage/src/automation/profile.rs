@@ -5,9 +5,24 @@
 use crate::sqlite_time::parse_sqlite_datetime;
 use anyhow::Result;
 use jobsentinel_domain::{
-    screening_question_matches, ApplicationProfile, ApplicationProfileInput, ScreeningAnswer,
+    screening_question_matches, ApplicationProfile, ApplicationProfileInput,
+    ApplicationProfileSummary, ScreeningAnswer,
 };
-use sqlx::SqlitePool;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+/// Name given to a new profile saved without one
+const DEFAULT_PROFILE_NAME: &str = "My profile";
+
+/// Puts the default profile first, falling back to the oldest
+const DEFAULT_PROFILE_ORDER: &str = "ORDER BY is_default DESC, id ASC";
+
+const PROFILE_COLUMNS: &str = r#"
+    id, name, is_default, full_name, email, phone, linkedin_url, github_url,
+    portfolio_url, website_url, default_resume_id,
+    resume_file_path, default_cover_letter_template, us_work_authorized,
+    requires_sponsorship, max_applications_per_day,
+    max_applications_per_week, require_manual_approval, created_at, updated_at
+"#;
 
 /// Profile manager
 #[derive(Debug)]
@@ -25,137 +40,305 @@ fn normalize_screening_answer_type(answer_type: &str) -> Result<&'static str> {
     }
 }
 
+fn profile_name(profile: &ApplicationProfileInput) -> Option<&str> {
+    profile
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+fn profile_from_row(r: SqliteRow) -> Result<ApplicationProfile> {
+    let created_at: String = r.try_get("created_at")?;
+    let updated_at: String = r.try_get("updated_at")?;
+    Ok(ApplicationProfile {
+        id: r.try_get("id")?,
+        name: r.try_get("name")?,
+        is_default: r.try_get::<i32, _>("is_default")? != 0,
+        full_name: r.try_get("full_name")?,
+        email: r.try_get("email")?,
+        phone: r.try_get("phone")?,
+        linkedin_url: r.try_get("linkedin_url")?,
+        github_url: r.try_get("github_url")?,
+        portfolio_url: r.try_get("portfolio_url")?,
+        website_url: r.try_get("website_url")?,
+        default_resume_id: r.try_get("default_resume_id")?,
+        resume_file_path: r.try_get("resume_file_path")?,
+        default_cover_letter_template: r.try_get("default_cover_letter_template")?,
+        us_work_authorized: r.try_get::<i32, _>("us_work_authorized")? != 0,
+        requires_sponsorship: r.try_get::<i32, _>("requires_sponsorship")? != 0,
+        max_applications_per_day: r.try_get("max_applications_per_day")?,
+        max_applications_per_week: r.try_get("max_applications_per_week")?,
+        require_manual_approval: r.try_get::<i32, _>("require_manual_approval")? != 0,
+        created_at: parse_sqlite_datetime(&created_at)?,
+        updated_at: parse_sqlite_datetime(&updated_at)?,
+    })
+}
+
+fn screening_answer_from_row(r: SqliteRow) -> Result<ScreeningAnswer> {
+    let created_at: String = r.get("created_at");
+    let updated_at: String = r.get("updated_at");
+    Ok(ScreeningAnswer {
+        id: r.get("id"),
+        profile_id: r.get("profile_id"),
+        question_pattern: r.get("question_pattern"),
+        answer: r.get("answer"),
+        answer_type: r.get("answer_type"),
+        notes: r.get("notes"),
+        times_used: r.get("times_used"),
+        times_modified: r.get("times_modified"),
+        confidence_score: r.get("confidence_score"),
+        last_used_at: r
+            .get::<Option<String>, _>("last_used_at")
+            .and_then(|date| parse_sqlite_datetime(&date).ok()),
+        created_at: parse_sqlite_datetime(&created_at)?,
+        updated_at: parse_sqlite_datetime(&updated_at)?,
+    })
+}
+
 impl ProfileManager {
     pub fn new(db: SqlitePool) -> Self {
         Self { db }
     }
 
-    /// Create or update application profile
+    /// Create or update the default application profile
+    ///
+    /// Creates the first profile when none exists yet.
     pub async fn upsert_profile(&self, profile: &ApplicationProfileInput) -> Result<i64> {
-        // Check if profile exists
-        let existing = sqlx::query_scalar::<_, i64>("SELECT id FROM application_profile LIMIT 1")
-            .fetch_optional(&self.db)
-            .await?;
+        // SAFETY: DEFAULT_PROFILE_ORDER is a fixed SQL constant
+        let existing = sqlx::query_scalar::<_, i64>(sqlx::AssertSqlSafe(format!(
+            "SELECT id FROM application_profile {DEFAULT_PROFILE_ORDER} LIMIT 1"
+        )))
+        .fetch_optional(&self.db)
+        .await?;
 
         if let Some(id) = existing {
-            // Update existing
-            sqlx::query(
-                r#"
-                UPDATE application_profile
-                SET full_name = ?, email = ?, phone = ?, linkedin_url = ?,
-                    github_url = ?, portfolio_url = ?, website_url = ?,
-                    default_resume_id = ?,
-                    resume_file_path = CASE
-                        WHEN ? != 0 THEN NULL
-                        WHEN ? != 0 THEN ?
-                        ELSE resume_file_path
-                    END,
-                    default_cover_letter_template = ?,
-                    us_work_authorized = ?, requires_sponsorship = ?,
-                    max_applications_per_day = ?, require_manual_approval = ?,
-                    updated_at = datetime('now')
-                WHERE id = ?
-                "#,
-            )
-            .bind(&profile.full_name)
-            .bind(&profile.email)
-            .bind(&profile.phone)
-            .bind(&profile.linkedin_url)
-            .bind(&profile.github_url)
-            .bind(&profile.portfolio_url)
-            .bind(&profile.website_url)
-            .bind(profile.default_resume_id)
-            .bind(profile.clear_resume_file.unwrap_or(false) as i32)
-            .bind(profile.resume_file_path.is_some() as i32)
-            .bind(&profile.resume_file_path)
-            .bind(&profile.default_cover_letter_template)
-            .bind(profile.us_work_authorized as i32)
-            .bind(profile.requires_sponsorship as i32)
-            .bind(profile.max_applications_per_day)
-            .bind(profile.require_manual_approval as i32)
-            .bind(id)
-            .execute(&self.db)
-            .await?;
-
+            self.update_profile(id, profile).await?;
             Ok(id)
         } else {
-            // Insert new
-            let result = sqlx::query(
-                r#"
-                INSERT INTO application_profile (
-                    full_name, email, phone, linkedin_url, github_url,
-                    portfolio_url, website_url, default_resume_id,
-                    resume_file_path, default_cover_letter_template, us_work_authorized,
-                    requires_sponsorship, max_applications_per_day,
-                    require_manual_approval
-                )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                "#,
+            self.create_profile(profile).await
+        }
+    }
+
+    /// Create a new named application profile
+    ///
+    /// The first profile becomes the default.
+    pub async fn create_profile(&self, profile: &ApplicationProfileInput) -> Result<i64> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO application_profile (
+                name, is_default, full_name, email, phone, linkedin_url, github_url,
+                portfolio_url, website_url, default_resume_id,
+                resume_file_path, default_cover_letter_template, us_work_authorized,
+                requires_sponsorship, max_applications_per_day,
+                max_applications_per_week, require_manual_approval
             )
-            .bind(&profile.full_name)
-            .bind(&profile.email)
-            .bind(&profile.phone)
-            .bind(&profile.linkedin_url)
-            .bind(&profile.github_url)
-            .bind(&profile.portfolio_url)
-            .bind(&profile.website_url)
-            .bind(profile.default_resume_id)
-            .bind(&profile.resume_file_path)
-            .bind(&profile.default_cover_letter_template)
-            .bind(profile.us_work_authorized as i32)
-            .bind(profile.requires_sponsorship as i32)
-            .bind(profile.max_applications_per_day)
-            .bind(profile.require_manual_approval as i32)
-            .execute(&self.db)
-            .await?;
+            VALUES (
+                ?, NOT EXISTS (SELECT 1 FROM application_profile),
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+            )
+            "#,
+        )
+        .bind(profile_name(profile).unwrap_or(DEFAULT_PROFILE_NAME))
+        .bind(&profile.full_name)
+        .bind(&profile.email)
+        .bind(&profile.phone)
+        .bind(&profile.linkedin_url)
+        .bind(&profile.github_url)
+        .bind(&profile.portfolio_url)
+        .bind(&profile.website_url)
+        .bind(profile.default_resume_id)
+        .bind(&profile.resume_file_path)
+        .bind(&profile.default_cover_letter_template)
+        .bind(profile.us_work_authorized as i32)
+        .bind(profile.requires_sponsorship as i32)
+        .bind(profile.max_applications_per_day)
+        .bind(profile.max_applications_per_week)
+        .bind(profile.require_manual_approval as i32)
+        .execute(&self.db)
+        .await?;
 
-            Ok(result.last_insert_rowid())
-        }
+        Ok(result.last_insert_rowid())
     }
 
-    /// Get application profile
+    /// Update an application profile
+    ///
+    /// Returns `false` when no profile has this ID.
+    pub async fn update_profile(
+        &self,
+        profile_id: i64,
+        profile: &ApplicationProfileInput,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE application_profile
+            SET name = COALESCE(?, name),
+                full_name = ?, email = ?, phone = ?, linkedin_url = ?,
+                github_url = ?, portfolio_url = ?, website_url = ?,
+                default_resume_id = ?,
+                resume_file_path = CASE
+                    WHEN ? != 0 THEN NULL
+                    WHEN ? != 0 THEN ?
+                    ELSE resume_file_path
+                END,
+                default_cover_letter_template = ?,
+                us_work_authorized = ?, requires_sponsorship = ?,
+                max_applications_per_day = ?, max_applications_per_week = ?,
+                require_manual_approval = ?,
+                updated_at = datetime('now')
+            WHERE id = ?
+            "#,
+        )
+        .bind(profile_name(profile))
+        .bind(&profile.full_name)
+        .bind(&profile.email)
+        .bind(&profile.phone)
+        .bind(&profile.linkedin_url)
+        .bind(&profile.github_url)
+        .bind(&profile.portfolio_url)
+        .bind(&profile.website_url)
+        .bind(profile.default_resume_id)
+        .bind(profile.clear_resume_file.unwrap_or(false) as i32)
+        .bind(profile.resume_file_path.is_some() as i32)
+        .bind(&profile.resume_file_path)
+        .bind(&profile.default_cover_letter_template)
+        .bind(profile.us_work_authorized as i32)
+        .bind(profile.requires_sponsorship as i32)
+        .bind(profile.max_applications_per_day)
+        .bind(profile.max_applications_per_week)
+        .bind(profile.require_manual_approval as i32)
+        .bind(profile_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Get the default application profile
     pub async fn get_profile(&self) -> Result<Option<ApplicationProfile>> {
-        let row = sqlx::query(
+        // SAFETY: PROFILE_COLUMNS and DEFAULT_PROFILE_ORDER are fixed SQL constants
+        let row = sqlx::query(sqlx::AssertSqlSafe(format!(
+            "SELECT {PROFILE_COLUMNS} FROM application_profile {DEFAULT_PROFILE_ORDER} LIMIT 1"
+        )))
+        .fetch_optional(&self.db)
+        .await?;
+
+        row.map(profile_from_row).transpose()
+    }
+
+    /// Get an application profile by ID
+    pub async fn get_profile_by_id(&self, profile_id: i64) -> Result<Option<ApplicationProfile>> {
+        // SAFETY: PROFILE_COLUMNS is a fixed SQL constant; the ID is bound
+        let row = sqlx::query(sqlx::AssertSqlSafe(format!(
+            "SELECT {PROFILE_COLUMNS} FROM application_profile WHERE id = ?"
+        )))
+        .bind(profile_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        row.map(profile_from_row).transpose()
+    }
+
+    /// Get the profile whose default resume is `resume_id`
+    ///
+    /// When several profiles use that resume, the default profile wins,
+    /// then the oldest.
+    pub async fn get_profile_for_resume(
+        &self,
+        resume_id: i64,
+    ) -> Result<Option<ApplicationProfile>> {
+        let row = sqlx::query(sqlx::AssertSqlSafe(format!(
+            "SELECT {PROFILE_COLUMNS} FROM application_profile
+             WHERE default_resume_id = ? {DEFAULT_PROFILE_ORDER} LIMIT 1"
+        )))
+        .bind(resume_id)
+        .fetch_optional(&self.db)
+        .await?;
+
+        row.map(profile_from_row).transpose()
+    }
+
+    /// List saved profiles by name, default first
+    pub async fn list_profiles(&self) -> Result<Vec<ApplicationProfileSummary>> {
+        let rows = sqlx::query(
             r#"
-            SELECT id, full_name, email, phone, linkedin_url, github_url,
-                   portfolio_url, website_url, default_resume_id,
-                   resume_file_path, default_cover_letter_template, us_work_authorized,
-                   requires_sponsorship, max_applications_per_day,
-                   require_manual_approval, created_at, updated_at
+            SELECT id, name, is_default, default_resume_id
             FROM application_profile
-            LIMIT 1
+            ORDER BY is_default DESC, name COLLATE NOCASE ASC, id ASC
             "#,
         )
-        .fetch_optional(&self.db)
+        .fetch_all(&self.db)
         .await?;
 
-        use sqlx::Row;
-        match row {
-            Some(r) => {
-                let created_at: String = r.get("created_at");
-                let updated_at: String = r.get("updated_at");
-                Ok(Some(ApplicationProfile {
-                    id: r.get("id"),
-                    full_name: r.get("full_name"),
-                    email: r.get("email"),
-                    phone: r.get("phone"),
-                    linkedin_url: r.get("linkedin_url"),
-                    github_url: r.get("github_url"),
-                    portfolio_url: r.get("portfolio_url"),
-                    website_url: r.get("website_url"),
-                    default_resume_id: r.get("default_resume_id"),
-                    resume_file_path: r.get("resume_file_path"),
-                    default_cover_letter_template: r.get("default_cover_letter_template"),
-                    us_work_authorized: r.get::<i32, _>("us_work_authorized") != 0,
-                    requires_sponsorship: r.get::<i32, _>("requires_sponsorship") != 0,
-                    max_applications_per_day: r.get("max_applications_per_day"),
-                    require_manual_approval: r.get::<i32, _>("require_manual_approval") != 0,
-                    created_at: parse_sqlite_datetime(&created_at)?,
-                    updated_at: parse_sqlite_datetime(&updated_at)?,
-                }))
-            }
-            None => Ok(None),
+        rows.into_iter()
+            .map(|r| {
+                Ok(ApplicationProfileSummary {
+                    id: r.try_get("id")?,
+                    name: r.try_get("name")?,
+                    is_default: r.try_get::<i32, _>("is_default")? != 0,
+                    default_resume_id: r.try_get("default_resume_id")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Make a profile the default
+    ///
+    /// Returns `false` when no profile has this ID.
+    pub async fn set_default_profile(&self, profile_id: i64) -> Result<bool> {
+        let mut tx = self.db.begin().await?;
+
+        let exists =
+            sqlx::query_scalar::<_, i64>("SELECT id FROM application_profile WHERE id = ?")
+                .bind(profile_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .is_some();
+        if !exists {
+            return Ok(false);
         }
+
+        sqlx::query("UPDATE application_profile SET is_default = 0 WHERE is_default = 1")
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE application_profile SET is_default = 1 WHERE id = ?")
+            .bind(profile_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Delete a profile and the screening answers saved for it
+    ///
+    /// When the default profile is deleted, the oldest remaining profile
+    /// becomes the default. Returns the deleted profile so its resume file can
+    /// be removed.
+    pub async fn delete_profile(&self, profile_id: i64) -> Result<Option<ApplicationProfile>> {
+        let Some(profile) = self.get_profile_by_id(profile_id).await? else {
+            return Ok(None);
+        };
+
+        let mut tx = self.db.begin().await?;
+        sqlx::query("DELETE FROM application_profile WHERE id = ?")
+            .bind(profile_id)
+            .execute(&mut *tx)
+            .await?;
+        if profile.is_default {
+            sqlx::query(
+                r#"
+                UPDATE application_profile
+                SET is_default = 1
+                WHERE id = (SELECT MIN(id) FROM application_profile)
+                "#,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(Some(profile))
     }
 
     /// Check whether an application profile exists without returning private profile data.
@@ -167,7 +350,7 @@ impl ProfileManager {
         Ok(profile_id.is_some())
     }
 
-    /// Add or update screening answer
+    /// Add or update a screening answer shared by every profile
     pub async fn upsert_screening_answer(
         &self,
         question_pattern: &str,
@@ -181,7 +364,7 @@ impl ProfileManager {
             r#"
             INSERT INTO screening_answers (question_pattern, answer, answer_type, notes)
             VALUES (?, ?, ?, ?)
-            ON CONFLICT(question_pattern) DO UPDATE SET
+            ON CONFLICT(question_pattern) WHERE profile_id IS NULL DO UPDATE SET
                 answer = excluded.answer,
                 answer_type = excluded.answer_type,
                 notes = excluded.notes,
@@ -200,14 +383,51 @@ impl ProfileManager {
         Ok(())
     }
 
-    /// Get all screening answers
+    /// Add or update a screening answer used only by one profile
+    ///
+    /// It takes precedence over a shared answer for the same question.
+    pub async fn upsert_profile_screening_answer(
+        &self,
+        profile_id: i64,
+        question_pattern: &str,
+        answer: &str,
+        answer_type: &str,
+        notes: Option<&str>,
+    ) -> Result<()> {
+        let answer_type = normalize_screening_answer_type(answer_type)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO screening_answers (profile_id, question_pattern, answer, answer_type, notes)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(profile_id, question_pattern) DO UPDATE SET
+                answer = excluded.answer,
+                answer_type = excluded.answer_type,
+                notes = excluded.notes,
+                times_modified = times_modified + 1,
+                confidence_score = 1.0,
+                updated_at = datetime('now')
+            "#,
+        )
+        .bind(profile_id)
+        .bind(question_pattern)
+        .bind(answer)
+        .bind(answer_type)
+        .bind(notes)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get all screening answers, shared and per-profile
     ///
     /// OPTIMIZATION: Added reasonable LIMIT (1000) to prevent unbounded result sets.
     /// Most users will have <100 screening patterns; 1000 is a safe upper bound.
     pub async fn get_screening_answers(&self) -> Result<Vec<ScreeningAnswer>> {
         let rows = sqlx::query(
             r#"
-            SELECT id, question_pattern, answer, answer_type, notes,
+            SELECT id, profile_id, question_pattern, answer, answer_type, notes,
                    times_used, times_modified, confidence_score, last_used_at,
                    created_at, updated_at
             FROM screening_answers
@@ -218,43 +438,52 @@ impl ProfileManager {
         .fetch_all(&self.db)
         .await?;
 
-        use sqlx::Row;
-        rows.into_iter()
-            .map(|r| {
-                let created_at: String = r.get("created_at");
-                let updated_at: String = r.get("updated_at");
-                Ok(ScreeningAnswer {
-                    id: r.get("id"),
-                    question_pattern: r.get("question_pattern"),
-                    answer: r.get("answer"),
-                    answer_type: r.get("answer_type"),
-                    notes: r.get("notes"),
-                    times_used: r.get("times_used"),
-                    times_modified: r.get("times_modified"),
-                    confidence_score: r.get("confidence_score"),
-                    last_used_at: r
-                        .get::<Option<String>, _>("last_used_at")
-                        .and_then(|date| parse_sqlite_datetime(&date).ok()),
-                    created_at: parse_sqlite_datetime(&created_at)?,
-                    updated_at: parse_sqlite_datetime(&updated_at)?,
-                })
-            })
-            .collect()
+        rows.into_iter().map(screening_answer_from_row).collect()
+    }
+
+    /// Get the screening answers a profile fills forms with
+    ///
+    /// The profile's own answers come before shared ones, so they are matched
+    /// first when both cover a question.
+    pub async fn get_screening_answers_for_profile(
+        &self,
+        profile_id: i64,
+    ) -> Result<Vec<ScreeningAnswer>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, profile_id, question_pattern, answer, answer_type, notes,
+                   times_used, times_modified, confidence_score, last_used_at,
+                   created_at, updated_at
+            FROM screening_answers
+            WHERE profile_id = ? OR profile_id IS NULL
+            ORDER BY profile_id IS NULL ASC, created_at DESC
+            LIMIT 1000
+            "#,
+        )
+        .bind(profile_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        rows.into_iter().map(screening_answer_from_row).collect()
     }
 
-    /// Find matching screening answer for a question
+    /// Find matching shared screening answer for a question
     ///
     /// OPTIMIZATION: Fetch only pattern+answer columns instead of full rows.
     /// Reduces data transfer and memory allocation for pattern matching.
     pub async fn find_answer_for_question(&self, question: &str) -> Result<Option<String>> {
         // Fetch only needed columns for pattern matching
         let rows = sqlx::query(
-            "SELECT question_pattern, answer FROM screening_answers ORDER BY created_at DESC",
+            r#"
+            SELECT question_pattern, answer
+            FROM screening_answers
+            WHERE profile_id IS NULL
+            ORDER BY created_at DESC
+            "#,
         )
         .fetch_all(&self.db)
         .await?;
 
-        use sqlx::Row;
         for row in rows {
             let pattern: String = row.try_get("question_pattern")?;
             let answer: String = row.try_get("answer")?;
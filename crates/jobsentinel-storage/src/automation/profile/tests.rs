@@ -3,6 +3,7 @@ use crate::test_support::migrated_pool;
 
 fn profile_input() -> ApplicationProfileInput {
     ApplicationProfileInput {
+        name: None,
         full_name: "Jordan Lee".to_string(),
         email: "jordan@example.com".to_string(),
         phone: None,
@@ -18,6 +19,7 @@ fn profile_input() -> ApplicationProfileInput {
         us_work_authorized: true,
         requires_sponsorship: false,
         max_applications_per_day: 10,
+        max_applications_per_week: None,
         require_manual_approval: true,
     }
 }
@@ -133,6 +135,213 @@ async fn test_update_profile_replaces_and_clears_resume_file_explicitly() {
     assert_eq!(profile.resume_file_path, None);
 }
 
+#[tokio::test]
+async fn test_first_profile_is_default_and_others_are_listed() {
+    let pool = migrated_pool().await;
+    let manager = ProfileManager::new(pool);
+
+    let sre_id = manager.upsert_profile(&profile_input()).await.unwrap();
+    let manager_id = manager
+        .create_profile(&ApplicationProfileInput {
+            name: Some("Manager profile".to_string()),
+            linkedin_url: Some("https://linkedin.com/in/jordan-manager".to_string()),
+            ..profile_input()
+        })
+        .await
+        .unwrap();
+
+    let profiles = manager.list_profiles().await.unwrap();
+    assert_eq!(
+        profiles,
+        vec![
+            ApplicationProfileSummary {
+                id: sre_id,
+                name: "My profile".to_string(),
+                is_default: true,
+                default_resume_id: None,
+            },
+            ApplicationProfileSummary {
+                id: manager_id,
+                name: "Manager profile".to_string(),
+                is_default: false,
+                default_resume_id: None,
+            },
+        ]
+    );
+    assert_eq!(manager.get_profile().await.unwrap().unwrap().id, sre_id);
+    assert_eq!(
+        manager
+            .get_profile_by_id(manager_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .linkedin_url
+            .as_deref(),
+        Some("https://linkedin.com/in/jordan-manager")
+    );
+
+    assert!(manager.set_default_profile(manager_id).await.unwrap());
+    assert!(!manager.set_default_profile(9999).await.unwrap());
+    let profile = manager.get_profile().await.unwrap().unwrap();
+    assert_eq!(profile.id, manager_id);
+    assert!(profile.is_default);
+    assert!(
+        !manager
+            .get_profile_by_id(sre_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_default
+    );
+}
+
+#[tokio::test]
+async fn test_get_profile_for_resume_finds_profile_using_that_resume() {
+    let pool = migrated_pool().await;
+    let manager = ProfileManager::new(pool.clone());
+    let resume_id = sqlx::query(
+        "INSERT INTO resumes (name, file_path, parsed_text, is_active) VALUES ('Ops', 'ops.pdf', '', 1)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap()
+    .last_insert_rowid();
+
+    manager.upsert_profile(&profile_input()).await.unwrap();
+    let ops_id = manager
+        .create_profile(&ApplicationProfileInput {
+            name: Some("Operations".to_string()),
+            default_resume_id: Some(resume_id),
+            ..profile_input()
+        })
+        .await
+        .unwrap();
+
+    let profile = manager.get_profile_for_resume(resume_id).await.unwrap();
+    assert_eq!(profile.map(|profile| profile.id), Some(ops_id));
+    assert!(manager
+        .get_profile_for_resume(resume_id + 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_update_profile_keeps_name_unless_given() {
+    let pool = migrated_pool().await;
+    let manager = ProfileManager::new(pool);
+
+    let id = manager
+        .create_profile(&ApplicationProfileInput {
+            name: Some("SRE profile".to_string()),
+            ..profile_input()
+        })
+        .await
+        .unwrap();
+    assert!(manager.update_profile(id, &profile_input()).await.unwrap());
+    assert_eq!(
+        manager.get_profile_by_id(id).await.unwrap().unwrap().name,
+        "SRE profile"
+    );
+
+    assert!(manager
+        .update_profile(
+            id,
+            &ApplicationProfileInput {
+                name: Some("  Platform profile ".to_string()),
+                ..profile_input()
+            },
+        )
+        .await
+        .unwrap());
+    assert_eq!(
+        manager.get_profile_by_id(id).await.unwrap().unwrap().name,
+        "Platform profile"
+    );
+    assert!(!manager
+        .update_profile(9999, &profile_input())
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_deleting_default_profile_promotes_oldest_and_drops_its_answers() {
+    let pool = migrated_pool().await;
+    let manager = ProfileManager::new(pool);
+
+    let first_id = manager.upsert_profile(&profile_input()).await.unwrap();
+    let second_id = manager.create_profile(&profile_input()).await.unwrap();
+    manager
+        .upsert_profile_screening_answer(first_id, "(?i)on.?call", "Yes", "yes_no", None)
+        .await
+        .unwrap();
+
+    let deleted = manager.delete_profile(first_id).await.unwrap().unwrap();
+    assert_eq!(deleted.id, first_id);
+    assert!(manager.delete_profile(first_id).await.unwrap().is_none());
+
+    let profile = manager.get_profile().await.unwrap().unwrap();
+    assert_eq!(profile.id, second_id);
+    assert!(profile.is_default);
+    assert!(manager
+        .get_screening_answers()
+        .await
+        .unwrap()
+        .iter()
+        .all(|answer| answer.profile_id != Some(first_id)));
+}
+
+#[tokio::test]
+async fn test_profile_screening_answers_come_before_shared_answers() {
+    let pool = migrated_pool().await;
+    let manager = ProfileManager::new(pool);
+
+    let sre_id = manager.upsert_profile(&profile_input()).await.unwrap();
+    let manager_id = manager.create_profile(&profile_input()).await.unwrap();
+    manager
+        .upsert_screening_answer("weekend coverage", "Some weekends", "text", None)
+        .await
+        .unwrap();
+    manager
+        .upsert_profile_screening_answer(sre_id, "weekend coverage", "No", "yes_no", None)
+        .await
+        .unwrap();
+    manager
+        .upsert_profile_screening_answer(sre_id, "weekend coverage", "Yes", "yes_no", None)
+        .await
+        .unwrap();
+
+    let sre_answers = manager
+        .get_screening_answers_for_profile(sre_id)
+        .await
+        .unwrap();
+    let coverage_answers: Vec<_> = sre_answers
+        .iter()
+        .filter(|answer| answer.question_pattern == "weekend coverage")
+        .map(|answer| (answer.profile_id, answer.answer.as_str()))
+        .collect();
+    assert_eq!(
+        coverage_answers,
+        vec![(Some(sre_id), "Yes"), (None, "Some weekends")]
+    );
+
+    let manager_answers = manager
+        .get_screening_answers_for_profile(manager_id)
+        .await
+        .unwrap();
+    assert!(manager_answers
+        .iter()
+        .all(|answer| answer.profile_id.is_none()));
+    assert_eq!(
+        manager
+            .find_answer_for_question("Can you provide weekend coverage?")
+            .await
+            .unwrap()
+            .as_deref(),
+        Some("Some weekends")
+    );
+}
+
 #[test]
 fn test_screening_question_matching_treats_symbols_as_literal_text() {
     assert!(screening_question_matches(
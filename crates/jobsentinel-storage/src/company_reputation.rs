@@ -0,0 +1,159 @@
+//! Company posting reputation
+//!
+//! Saved posting-risk analyses are rolled up per company into
+//! `company_reputation`, so a job card can say how an employer's postings
+//! usually go. A posting counts as filled when it closed within
+//! `FILLED_WITHIN_DAYS` of first being seen; postings still open past that
+//! count against the fill rate.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+use super::time_to_fill::CLOSED_AFTER_DAYS;
+
+/// Days a posting may stay open and still count as filled.
+const FILLED_WITHIN_DAYS: i64 = 60;
+/// Settled postings needed before a fill rate is worth showing.
+const MIN_SETTLED_POSTINGS: i64 = 3;
+
+/// How a company's postings have gone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CompanyReputation {
+    /// Company name as most recently seen
+    pub company: String,
+    /// Saved postings from the company
+    pub postings: i64,
+    /// Mean posting-risk score of the analyzed postings
+    pub avg_ghost_score: Option<f64>,
+    /// Share of postings seen reposted
+    pub repost_rate: f64,
+    /// Share of settled postings that closed within 60 days, or `None` with
+    /// fewer than three settled postings
+    pub fill_rate: Option<f64>,
+    /// Postings that closed or stayed open past 60 days
+    pub settled_postings: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Running totals for one company
+#[derive(Default)]
+struct Tally {
+    company: String,
+    postings: i64,
+    ghost_scores: Vec<f64>,
+    reposted: i64,
+    settled: i64,
+    filled: i64,
+}
+
+/// Company, posting-risk score, repost count, status, opened, and last seen
+type PostingRow = (
+    String,
+    Option<f64>,
+    i64,
+    String,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+impl Database {
+    /// Rebuild `company_reputation` from the saved jobs.
+    pub async fn refresh_company_reputation(&self) -> Result<(), sqlx::Error> {
+        let rows: Vec<PostingRow> = sqlx::query_as(
+            r#"
+            SELECT company, ghost_score, repost_count, status,
+                   COALESCE(first_seen, created_at), last_seen
+            FROM jobs
+            ORDER BY last_seen DESC
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let now = Utc::now();
+        let closed_before = now - Duration::days(CLOSED_AFTER_DAYS);
+        let mut tallies: BTreeMap<String, Tally> = BTreeMap::new();
+        for (company, ghost_score, repost_count, status, opened, last_seen) in rows {
+            let Some(key) = company_key(&company) else {
+                continue;
+            };
+            let tally = tallies.entry(key).or_insert_with(|| Tally {
+                company: company.trim().to_string(),
+                ..Tally::default()
+            });
+            tally.postings += 1;
+            tally.ghost_scores.extend(ghost_score);
+            tally.reposted += i64::from(repost_count > 0);
+
+            let closed = status == "closed" || last_seen < closed_before;
+            let open_until = if closed { last_seen } else { now };
+            let open_days = (open_until - opened).num_days();
+            if closed || open_days > FILLED_WITHIN_DAYS {
+                tally.settled += 1;
+                tally.filled += i64::from(closed && open_days <= FILLED_WITHIN_DAYS);
+            }
+        }
+
+        let mut tx = self.pool().begin().await?;
+        sqlx::query("DELETE FROM company_reputation")
+            .execute(&mut *tx)
+            .await?;
+        for (key, tally) in tallies {
+            let avg_ghost_score = (!tally.ghost_scores.is_empty())
+                .then(|| tally.ghost_scores.iter().sum::<f64>() / tally.ghost_scores.len() as f64);
+            let fill_rate = (tally.settled >= MIN_SETTLED_POSTINGS)
+                .then(|| tally.filled as f64 / tally.settled as f64);
+            sqlx::query(
+                r#"
+                INSERT INTO company_reputation
+                    (company_key, company, postings, avg_ghost_score, repost_rate,
+                     fill_rate, settled_postings, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(key)
+            .bind(tally.company)
+            .bind(tally.postings)
+            .bind(avg_ghost_score)
+            .bind(tally.reposted as f64 / tally.postings as f64)
+            .bind(fill_rate)
+            .bind(tally.settled)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// The reputation last computed for `company`, matched ignoring case
+    /// and surrounding spaces
+    pub async fn get_company_reputation(
+        &self,
+        company: &str,
+    ) -> Result<Option<CompanyReputation>, sqlx::Error> {
+        let Some(key) = company_key(company) else {
+            return Ok(None);
+        };
+        sqlx::query_as(
+            r#"
+            SELECT company, postings, avg_ghost_score, repost_rate, fill_rate,
+                   settled_postings, updated_at
+            FROM company_reputation
+            WHERE company_key = ?
+            "#,
+        )
+        .bind(key)
+        .fetch_optional(self.pool())
+        .await
+    }
+}
+
+fn company_key(company: &str) -> Option<String> {
+    let key = company.trim().to_lowercase();
+    (!key.is_empty()).then_some(key)
+}
@@ -53,6 +53,12 @@ impl Database {
             })?;
         }
 
+        // A restore staged last run replaces the file before anything opens it.
+        backups::apply_pending_restore(path).map_err(|e| {
+            tracing::error!(error_kind = ?e.kind(), "Failed to apply staged database restore");
+            sqlx::Error::Io(e)
+        })?;
+
         let key = load_or_create_database_key().await?;
         let pool = match connect_encrypted_pool(path, &key, true).await {
             Ok(pool) => pool,
@@ -237,6 +243,7 @@ impl Database {
 
         sqlx::migrate!("./migrations").run(&self.pool).await?;
         self.verify_integrity().await?;
+        self.seed_bundled_exchange_rates().await?;
         if let Some(db_path) = &self.db_path {
             jobsentinel_platform::ensure_private_sqlite_files(db_path).map_err(sqlx::Error::Io)?;
         }
@@ -332,6 +339,7 @@ impl Database {
     }
 }
 
+mod diagnostics;
 mod maintenance;
 
 #[cfg(test)]
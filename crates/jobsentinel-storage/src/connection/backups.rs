@@ -1,10 +1,169 @@
+use chrono::{DateTime, Utc};
 use sqlx::sqlite::SqlitePool;
 use std::path::{Path, PathBuf};
 
 use super::Database;
+use crate::types::{BackupKind, DatabaseBackup};
 use jobsentinel_security::path_label_for_logging;
 
+/// Backups made from Settings, kept newest first.
+pub(super) const MANUAL_BACKUP_KEEP: usize = 10;
+/// Copies of the database a restore replaced.
+const BEFORE_RESTORE_BACKUP_KEEP: usize = 3;
+/// A verified backup waiting to replace the database at the next start.
+const PENDING_RESTORE_SUFFIX: &str = ".restore-pending";
+
+/// The `backups` directory next to the database file.
+fn backup_dir_for(db_path: &Path) -> PathBuf {
+    db_path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map(|parent| parent.join("backups"))
+        .unwrap_or_else(Database::default_backup_dir)
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+fn backup_error(message: &str) -> sqlx::Error {
+    sqlx::Error::Protocol(message.into())
+}
+
+/// Replace the database at `db_path` with a staged restore, if one is
+/// waiting. Runs before the pool opens, so no connection holds the file.
+///
+/// The replaced database and its WAL are moved into the backup directory
+/// as a `backup_before_restore_*.db` copy.
+pub(super) fn apply_pending_restore(db_path: &Path) -> std::io::Result<bool> {
+    let pending = sibling_path(db_path, PENDING_RESTORE_SUFFIX);
+    if !pending.exists() {
+        return Ok(false);
+    }
+
+    if db_path.exists() {
+        let backup_dir = backup_dir_for(db_path);
+        jobsentinel_platform::ensure_private_dir(&backup_dir)?;
+        let replaced = backup_dir.join(format!(
+            "{}{}.db",
+            BackupKind::BeforeRestore.file_prefix(),
+            Utc::now().format("%Y%m%d_%H%M%S_%3f")
+        ));
+        std::fs::rename(db_path, &replaced)?;
+        for suffix in ["-wal", "-shm"] {
+            let sidecar = sibling_path(db_path, suffix);
+            if sidecar.exists() {
+                std::fs::rename(&sidecar, sibling_path(&replaced, suffix))?;
+            }
+        }
+        Database::prune_backups(
+            &backup_dir,
+            BackupKind::BeforeRestore.file_prefix(),
+            BEFORE_RESTORE_BACKUP_KEEP,
+        );
+    }
+    std::fs::rename(&pending, db_path)?;
+    jobsentinel_platform::ensure_private_file(db_path)?;
+    tracing::info!("Restored database from backup");
+    Ok(true)
+}
+
 impl Database {
+    /// Snapshot the live database into the backup directory and keep the
+    /// newest manual backups.
+    ///
+    /// `VACUUM INTO` copies a consistent snapshot while other connections
+    /// keep reading and writing, including data still in the WAL. The copy
+    /// is encrypted with the same key and passes `quick_check` before it is
+    /// kept.
+    pub async fn create_backup(&self) -> Result<DatabaseBackup, sqlx::Error> {
+        let db_path = self
+            .db_path
+            .as_deref()
+            .ok_or_else(|| backup_error("In-memory databases cannot be backed up"))?;
+        let backup_dir = backup_dir_for(db_path);
+        jobsentinel_platform::ensure_private_dir(&backup_dir).map_err(sqlx::Error::Io)?;
+
+        let backup_path = backup_dir.join(format!(
+            "{}{}.db",
+            BackupKind::Manual.file_prefix(),
+            Utc::now().format("%Y%m%d_%H%M%S_%3f")
+        ));
+        let backup_path_str = backup_path
+            .to_str()
+            .ok_or_else(|| backup_error("Invalid backup path encoding"))?;
+        sqlx::query("VACUUM INTO ?")
+            .bind(backup_path_str)
+            .execute(&self.pool)
+            .await?;
+        jobsentinel_platform::ensure_private_file(&backup_path).map_err(sqlx::Error::Io)?;
+        if let Err(error) = Self::verify_backup(&self.pool, backup_path_str).await {
+            let _ = std::fs::remove_file(&backup_path);
+            return Err(error);
+        }
+        tracing::info!(
+            backup_path = %path_label_for_logging(&backup_path),
+            "Database backup created"
+        );
+
+        Self::prune_backups(
+            &backup_dir,
+            BackupKind::Manual.file_prefix(),
+            MANUAL_BACKUP_KEEP,
+        );
+        backup_entry(&backup_path).ok_or_else(|| backup_error("Database backup was not written"))
+    }
+
+    /// Backups in the backup directory, newest first.
+    pub fn list_backups(&self) -> Result<Vec<DatabaseBackup>, sqlx::Error> {
+        let Some(db_path) = self.db_path.as_deref() else {
+            return Ok(Vec::new());
+        };
+        let entries = match std::fs::read_dir(backup_dir_for(db_path)) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(sqlx::Error::Io(error)),
+        };
+
+        let mut backups: Vec<DatabaseBackup> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| backup_entry(&entry.path()))
+            .collect();
+        backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(backups)
+    }
+
+    /// Check a backup and stage it to replace the database at the next
+    /// start. Restarting the app finishes the restore.
+    ///
+    /// `file_name` must name a backup from [`Database::list_backups`].
+    pub async fn restore_backup(&self, file_name: &str) -> Result<DatabaseBackup, sqlx::Error> {
+        let db_path = self
+            .db_path
+            .as_deref()
+            .ok_or_else(|| backup_error("In-memory databases cannot be restored"))?;
+        let backup_path = backup_dir_for(db_path).join(file_name);
+        let backup = (Path::new(file_name).file_name() == Some(std::ffi::OsStr::new(file_name)))
+            .then(|| backup_entry(&backup_path))
+            .flatten()
+            .ok_or_else(|| backup_error("Backup not found"))?;
+        let backup_path_str = backup_path
+            .to_str()
+            .ok_or_else(|| backup_error("Invalid backup path encoding"))?;
+        Self::verify_backup(&self.pool, backup_path_str).await?;
+
+        let pending = sibling_path(db_path, PENDING_RESTORE_SUFFIX);
+        std::fs::copy(&backup_path, &pending).map_err(sqlx::Error::Io)?;
+        jobsentinel_platform::ensure_private_file(&pending).map_err(sqlx::Error::Io)?;
+        tracing::info!(
+            backup_path = %path_label_for_logging(&backup_path),
+            "Database restore staged for next start"
+        );
+        Ok(backup)
+    }
+
     /// Create a timestamped `backup_pre_migration_YYYYMMDD_HHMMSS_mmm.db`
     /// SQLite snapshot of the database file, then prune old pre-migration
     /// backups so that at most five copies are retained.
@@ -12,11 +171,7 @@ impl Database {
         pool: &SqlitePool,
         db_path: &Path,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let backup_dir = db_path
-            .parent()
-            .filter(|parent| !parent.as_os_str().is_empty())
-            .map(|parent| parent.join("backups"))
-            .unwrap_or_else(Self::default_backup_dir);
+        let backup_dir = backup_dir_for(db_path);
         Self::backup_pre_migration_to_dir(pool, db_path, &backup_dir)
             .await
             .map(|_| ())
@@ -38,7 +193,7 @@ impl Database {
         jobsentinel_platform::ensure_private_dir(backup_dir)?;
 
         let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S_%3f");
-        let backup_name = format!("backup_pre_migration_{}.db", timestamp);
+        let backup_name = format!("{}{}.db", BackupKind::PreMigration.file_prefix(), timestamp);
         let backup_path = backup_dir.join(&backup_name);
         let backup_path_str = backup_path.to_str().ok_or_else(|| {
             std::io::Error::new(
@@ -52,32 +207,33 @@ impl Database {
             .execute(pool)
             .await?;
         jobsentinel_platform::ensure_private_file(&backup_path)?;
-        Self::verify_pre_migration_backup(pool, backup_path_str).await?;
+        Self::verify_backup(pool, backup_path_str).await?;
         tracing::info!(
             backup_path = %path_label_for_logging(&backup_path),
             "Pre-migration backup created"
         );
 
-        Self::prune_pre_migration_backups(backup_dir, PRE_MIGRATION_BACKUP_KEEP);
+        Self::prune_backups(
+            backup_dir,
+            BackupKind::PreMigration.file_prefix(),
+            PRE_MIGRATION_BACKUP_KEEP,
+        );
 
         Ok(Some(backup_path))
     }
 
-    async fn verify_pre_migration_backup(
-        pool: &SqlitePool,
-        backup_path: &str,
-    ) -> Result<(), sqlx::Error> {
+    /// Run `quick_check` on a backup file through the keyed connection.
+    async fn verify_backup(pool: &SqlitePool, backup_path: &str) -> Result<(), sqlx::Error> {
         let mut conn = pool.acquire().await?;
-        sqlx::query("ATTACH DATABASE ? AS pre_migration_backup")
+        sqlx::query("ATTACH DATABASE ? AS backup_check")
             .bind(backup_path)
             .execute(&mut *conn)
             .await?;
 
-        let check_result =
-            sqlx::query_scalar::<_, String>("PRAGMA pre_migration_backup.quick_check")
-                .fetch_one(&mut *conn)
-                .await;
-        let detach_result = sqlx::query("DETACH DATABASE pre_migration_backup")
+        let check_result = sqlx::query_scalar::<_, String>("PRAGMA backup_check.quick_check")
+            .fetch_one(&mut *conn)
+            .await;
+        let detach_result = sqlx::query("DETACH DATABASE backup_check")
             .execute(&mut *conn)
             .await;
 
@@ -88,20 +244,20 @@ impl Database {
             Ok(())
         } else {
             Err(sqlx::Error::Protocol(
-                "Pre-migration backup integrity check failed".into(),
+                "Database backup integrity check failed".into(),
             ))
         }
     }
 
-    /// Delete old `backup_pre_migration_*.db` files, keeping the `keep` newest.
-    fn prune_pre_migration_backups(backup_dir: &Path, keep: usize) {
+    /// Delete old `<prefix>*.db` backups, keeping the `keep` newest.
+    fn prune_backups(backup_dir: &Path, prefix: &str, keep: usize) {
         let mut entries: Vec<_> = match std::fs::read_dir(backup_dir) {
             Ok(rd) => rd
                 .filter_map(|e| e.ok())
                 .filter(|e| {
                     let name = e.file_name();
                     let name = name.to_string_lossy();
-                    name.starts_with("backup_pre_migration_") && name.ends_with(".db")
+                    name.starts_with(prefix) && name.ends_with(".db")
                 })
                 .collect(),
             Err(e) => {
@@ -130,14 +286,35 @@ impl Database {
                 tracing::warn!(
                     backup_path = %path_label_for_logging(entry.path()),
                     error_kind = ?e.kind(),
-                    "Failed to delete old pre-migration backup"
+                    "Failed to delete old database backup"
                 );
             } else {
                 tracing::info!(
                     backup_path = %path_label_for_logging(entry.path()),
-                    "Pruned old pre-migration backup"
+                    "Pruned old database backup"
                 );
             }
         }
     }
 }
+
+/// The backup at `path`, if it is a backup file this module wrote.
+fn backup_entry(path: &Path) -> Option<DatabaseBackup> {
+    let file_name = path.file_name()?.to_str()?;
+    let kind = BackupKind::ALL
+        .into_iter()
+        .find(|kind| file_name.starts_with(kind.file_prefix()))?;
+    if !file_name.ends_with(".db") {
+        return None;
+    }
+    let metadata = std::fs::metadata(path)
+        .ok()
+        .filter(|metadata| metadata.is_file())?;
+    let created_at: DateTime<Utc> = metadata.modified().ok()?.into();
+    Some(DatabaseBackup {
+        file_name: file_name.to_string(),
+        kind,
+        created_at,
+        size_bytes: metadata.len(),
+    })
+}
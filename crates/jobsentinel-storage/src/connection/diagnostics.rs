@@ -0,0 +1,131 @@
+use super::*;
+use crate::types::{DatabaseHealth, IndexUsage};
+
+/// Hot queries whose plans are checked for index use, by name.
+const HOT_QUERIES: &[(&str, &str)] = &[
+    (
+        "recent_jobs",
+        "SELECT * FROM jobs WHERE hidden = 0 AND status <> 'closed' ORDER BY score DESC, created_at DESC LIMIT 50",
+    ),
+    (
+        "job_page",
+        "SELECT * FROM jobs WHERE hidden = 0 AND (created_at < ? OR (created_at = ? AND id < ?)) ORDER BY created_at DESC, id DESC LIMIT 50",
+    ),
+    (
+        "bookmarked_jobs",
+        "SELECT * FROM jobs WHERE bookmarked = 1 AND hidden = 0 ORDER BY score DESC, created_at DESC LIMIT 50",
+    ),
+    (
+        "jobs_by_source",
+        "SELECT * FROM jobs WHERE hidden = 0 AND source = ? ORDER BY created_at DESC LIMIT 50",
+    ),
+    ("job_by_hash", "SELECT id FROM jobs WHERE hash = ?"),
+];
+
+impl Database {
+    /// Size, WAL, connection pool, and index diagnostics.
+    ///
+    /// SQLite keeps no per-index usage counters, so index use is read from
+    /// the query plans of the hot job queries instead.
+    pub async fn db_health(&self) -> Result<DatabaseHealth, sqlx::Error> {
+        let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+            .fetch_one(&self.pool)
+            .await?;
+        let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+            .fetch_one(&self.pool)
+            .await?;
+        let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+            .fetch_one(&self.pool)
+            .await?;
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(&self.pool)
+            .await?;
+        let busy_timeout_ms: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+            .fetch_one(&self.pool)
+            .await?;
+        let wal_size_bytes = self.db_path.as_deref().map(|path| {
+            let mut wal_path = path.as_os_str().to_owned();
+            wal_path.push("-wal");
+            std::fs::metadata(PathBuf::from(wal_path)).map_or(0, |metadata| metadata.len())
+        });
+
+        let mut indexes: Vec<IndexUsage> = sqlx::query_as::<_, (String, String)>(
+            r#"
+            SELECT name, tbl_name FROM sqlite_master
+            WHERE type = 'index' AND name NOT LIKE 'sqlite_%'
+            ORDER BY tbl_name, name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|(name, table)| IndexUsage {
+            name,
+            table,
+            analyzed_rows: None,
+            used_by: Vec::new(),
+        })
+        .collect();
+
+        // Row counts come from the statistics PRAGMA optimize gathers.
+        let has_stats: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = 'sqlite_stat1')",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        if has_stats {
+            let stats: Vec<(String, String)> =
+                sqlx::query_as("SELECT idx, stat FROM sqlite_stat1 WHERE idx IS NOT NULL")
+                    .fetch_all(&self.pool)
+                    .await?;
+            for (index_name, stat) in stats {
+                if let Some(index) = indexes.iter_mut().find(|index| index.name == index_name) {
+                    index.analyzed_rows = stat
+                        .split_whitespace()
+                        .next()
+                        .and_then(|rows| rows.parse().ok());
+                }
+            }
+        }
+
+        let mut full_scans = Vec::new();
+        for &(query_name, sql) in HOT_QUERIES {
+            let plan: Vec<String> =
+                sqlx::query(sqlx::AssertSqlSafe(format!("EXPLAIN QUERY PLAN {sql}")))
+                    .fetch_all(&self.pool)
+                    .await?
+                    .iter()
+                    .filter_map(|row| row.try_get::<String, _>("detail").ok())
+                    .collect();
+            for index in &mut indexes {
+                if plan
+                    .iter()
+                    .any(|step| step.split_whitespace().any(|word| word == index.name))
+                {
+                    index.used_by.push(query_name.to_string());
+                }
+            }
+            if plan
+                .iter()
+                .any(|step| step.starts_with("SCAN jobs") && !step.contains("USING"))
+            {
+                full_scans.push(query_name.to_string());
+            }
+        }
+
+        Ok(DatabaseHealth {
+            page_size,
+            page_count,
+            freelist_count,
+            size_bytes: page_size * page_count,
+            journal_mode,
+            wal_size_bytes,
+            busy_timeout_ms,
+            pool_size: self.pool.size(),
+            pool_idle: u32::try_from(self.pool.num_idle()).unwrap_or(u32::MAX),
+            pool_max: self.pool.options().get_max_connections(),
+            indexes,
+            full_scans,
+        })
+    }
+}
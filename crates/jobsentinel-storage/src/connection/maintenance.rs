@@ -1,4 +1,12 @@
 use super::*;
+use crate::types::{DatabaseMaintenance, MaintenanceStep};
+
+/// Percent of the file in free pages at which maintenance reclaims them.
+const FREELIST_VACUUM_PERCENT: i64 = 10;
+/// Free pages below which reclaiming is not worth the write (1 MB).
+const MIN_FREELIST_PAGES: i64 = 256;
+/// Job search index segments above which they are merged into one.
+const MAX_FTS_SEGMENTS: i64 = 10;
 
 impl Database {
     /// Get default database path
@@ -35,4 +43,86 @@ impl Database {
         tracing::info!("Checkpointed WAL file");
         Ok(())
     }
+
+    /// Refresh query planner statistics, and where fragmentation thresholds
+    /// are hit, merge or rebuild the job search index and reclaim free pages.
+    pub async fn run_maintenance(&self) -> Result<DatabaseMaintenance, sqlx::Error> {
+        let size_before_bytes = self.size_bytes().await?;
+        let mut steps = Vec::new();
+
+        sqlx::query("PRAGMA optimize").execute(&self.pool).await?;
+
+        // Rank 1 also compares the index against the jobs table it covers.
+        let fts_check =
+            sqlx::query("INSERT INTO jobs_fts(jobs_fts, rank) VALUES ('integrity-check', 1)")
+                .execute(&self.pool)
+                .await;
+        if fts_check.is_err() {
+            tracing::warn!("Job search index failed its integrity check; rebuilding");
+            sqlx::query("INSERT INTO jobs_fts(jobs_fts) VALUES ('rebuild')")
+                .execute(&self.pool)
+                .await?;
+            steps.push(MaintenanceStep::FtsRebuild);
+        } else {
+            let segments: i64 =
+                sqlx::query_scalar("SELECT COUNT(DISTINCT segid) FROM jobs_fts_idx")
+                    .fetch_one(&self.pool)
+                    .await?;
+            if segments > MAX_FTS_SEGMENTS {
+                sqlx::query("INSERT INTO jobs_fts(jobs_fts) VALUES ('optimize')")
+                    .execute(&self.pool)
+                    .await?;
+                steps.push(MaintenanceStep::FtsOptimize);
+            }
+        }
+
+        // Measured after the index work, which frees pages of its own.
+        let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+            .fetch_one(&self.pool)
+            .await?;
+        let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+            .fetch_one(&self.pool)
+            .await?;
+        let fragmented = freelist_count >= MIN_FREELIST_PAGES
+            && freelist_count * 100 >= page_count * FREELIST_VACUUM_PERCENT;
+        if fragmented {
+            // 2 = INCREMENTAL. Databases made before it was turned on need a
+            // full VACUUM, which also switches them over.
+            let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+                .fetch_one(&self.pool)
+                .await?;
+            if auto_vacuum == 2 {
+                sqlx::query("PRAGMA incremental_vacuum")
+                    .execute(&self.pool)
+                    .await?;
+                steps.push(MaintenanceStep::IncrementalVacuum);
+            } else {
+                sqlx::query("VACUUM").execute(&self.pool).await?;
+                steps.push(MaintenanceStep::Vacuum);
+            }
+        }
+
+        let maintenance = DatabaseMaintenance {
+            size_before_bytes,
+            size_after_bytes: self.size_bytes().await?,
+            steps,
+        };
+        if !maintenance.steps.is_empty() {
+            tracing::info!(
+                size_before_bytes = maintenance.size_before_bytes,
+                size_after_bytes = maintenance.size_after_bytes,
+                steps = ?maintenance.steps,
+                "Database maintenance complete"
+            );
+        }
+        Ok(maintenance)
+    }
+
+    async fn size_bytes(&self) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
 }
@@ -37,6 +37,100 @@ mod memory_tests {
         assert_eq!(synchronous, 1);
         assert_eq!(cache_size, -128_000);
     }
+
+    #[tokio::test]
+    async fn db_health_reports_wal_pool_and_indexes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let database = Database::connect(&temp_dir.path().join("jobs.db"))
+            .await
+            .unwrap();
+        database.migrate().await.unwrap();
+
+        let health = database.db_health().await.unwrap();
+
+        assert_eq!(health.journal_mode, "wal");
+        assert!(health.wal_size_bytes.is_some());
+        assert_eq!(health.busy_timeout_ms, 15_000);
+        assert_eq!(health.pool_max, 8);
+        assert_eq!(health.size_bytes, health.page_size * health.page_count);
+        assert!(health
+            .indexes
+            .iter()
+            .any(|index| index.name == "idx_jobs_hidden_created_id"));
+    }
+
+    #[tokio::test]
+    async fn db_health_has_no_wal_for_in_memory_databases() {
+        let database = Database::connect_memory().await.unwrap();
+
+        let health = database.db_health().await.unwrap();
+
+        assert_eq!(health.wal_size_bytes, None);
+        assert_eq!(health.pool_max, 1);
+    }
+}
+
+#[cfg(test)]
+mod maintenance_tests {
+    use super::*;
+    use crate::types::MaintenanceStep;
+
+    /// Fill then drop a scratch table, leaving about 8 MB of free pages.
+    async fn leave_free_pages(database: &Database) {
+        sqlx::query("CREATE TABLE filler(data BLOB)")
+            .execute(database.pool())
+            .await
+            .unwrap();
+        sqlx::query(
+            r#"
+            WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 2000)
+            INSERT INTO filler SELECT randomblob(4000) FROM n
+            "#,
+        )
+        .execute(database.pool())
+        .await
+        .unwrap();
+        sqlx::query("DROP TABLE filler")
+            .execute(database.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn maintenance_only_optimizes_a_compact_database() {
+        let database = crate::test_support::migrated_database().await;
+
+        let maintenance = database.run_maintenance().await.unwrap();
+
+        assert!(maintenance.steps.is_empty());
+        assert_eq!(maintenance.size_before_bytes, maintenance.size_after_bytes);
+    }
+
+    #[tokio::test]
+    async fn maintenance_reclaims_free_pages_incrementally() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let database = Database::connect(&temp_dir.path().join("jobs.db"))
+            .await
+            .unwrap();
+        database.migrate().await.unwrap();
+        leave_free_pages(&database).await;
+
+        let maintenance = database.run_maintenance().await.unwrap();
+
+        assert_eq!(maintenance.steps, [MaintenanceStep::IncrementalVacuum]);
+        assert!(maintenance.size_after_bytes < maintenance.size_before_bytes);
+    }
+
+    #[tokio::test]
+    async fn maintenance_vacuums_databases_without_incremental_auto_vacuum() {
+        let database = crate::test_support::migrated_database().await;
+        leave_free_pages(&database).await;
+
+        let maintenance = database.run_maintenance().await.unwrap();
+
+        assert_eq!(maintenance.steps, [MaintenanceStep::Vacuum]);
+        assert!(maintenance.size_after_bytes < maintenance.size_before_bytes);
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +177,77 @@ mod backup_tests {
         assert_eq!(note, "saved in wal");
     }
 
+    #[tokio::test]
+    async fn manual_backups_rotate_and_list_newest_first() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let database = Database::connect(&temp_dir.path().join("jobs.db"))
+            .await
+            .unwrap();
+
+        for _ in 0..=backups::MANUAL_BACKUP_KEEP {
+            database.create_backup().await.unwrap();
+        }
+        let listed = database.list_backups().unwrap();
+
+        assert_eq!(listed.len(), backups::MANUAL_BACKUP_KEEP);
+        assert!(listed
+            .iter()
+            .all(|backup| backup.kind == crate::BackupKind::Manual));
+        assert!(listed
+            .windows(2)
+            .all(|pair| pair[0].created_at >= pair[1].created_at));
+    }
+
+    #[tokio::test]
+    async fn restored_backup_replaces_database_on_next_start() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("jobs.db");
+        let database = Database::connect(&db_path).await.unwrap();
+        sqlx::query("CREATE TABLE qa_restore (note TEXT NOT NULL)")
+            .execute(database.pool())
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO qa_restore (note) VALUES ('before backup')")
+            .execute(database.pool())
+            .await
+            .unwrap();
+        let backup = database.create_backup().await.unwrap();
+        sqlx::query("INSERT INTO qa_restore (note) VALUES ('after backup')")
+            .execute(database.pool())
+            .await
+            .unwrap();
+
+        database.restore_backup(&backup.file_name).await.unwrap();
+        database.pool().close().await;
+        let restored = Database::connect(&db_path).await.unwrap();
+
+        let notes: Vec<String> = sqlx::query_scalar("SELECT note FROM qa_restore")
+            .fetch_all(restored.pool())
+            .await
+            .unwrap();
+        assert_eq!(notes, vec!["before backup"]);
+        assert!(restored
+            .list_backups()
+            .unwrap()
+            .iter()
+            .any(|backup| backup.kind == crate::BackupKind::BeforeRestore));
+    }
+
+    #[tokio::test]
+    async fn restore_only_accepts_backup_file_names() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let database = Database::connect(&temp_dir.path().join("jobs.db"))
+            .await
+            .unwrap();
+        database.create_backup().await.unwrap();
+
+        assert!(database.restore_backup("../jobs.db").await.is_err());
+        assert!(database
+            .restore_backup("backup_manual_../../jobs.db")
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn migration_stops_when_required_backup_cannot_be_created() {
         let temp_dir = tempfile::tempdir().unwrap();
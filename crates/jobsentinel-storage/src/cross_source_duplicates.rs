@@ -0,0 +1,196 @@
+//! Cross-source duplicate linking
+//!
+//! The same role is often posted on a company board and on aggregators under
+//! different URLs, so each copy gets its own hash. This pass links copies from
+//! different sources that share a company, a similar title, and a compatible
+//! location to one canonical job. Recent-job and search queries then show only
+//! the canonical job; its copies reappear if the canonical job is hidden.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+
+use super::connection::Database;
+use super::near_duplicates::{jaccard, normalize_company, words, DisjointSets};
+use super::types::JobRow;
+use jobsentinel_domain::Job;
+
+/// Lowest title word overlap (0.0-1.0) at which two postings count as the
+/// same role.
+const TITLE_SIMILARITY_THRESHOLD: f64 = 0.75;
+/// Pairwise comparison is quadratic per company, so very large employers are
+/// compared on their highest-scoring postings only.
+const MAX_JOBS_PER_COMPANY: usize = 500;
+
+/// One job to collapse into its canonical job.
+#[derive(Debug)]
+struct DuplicateLink {
+    job_id: i64,
+    canonical_job_id: i64,
+    /// Title similarity to the canonical job
+    similarity: f64,
+}
+
+impl Database {
+    /// Link visible jobs that are copies of one role on different sources to
+    /// a canonical job (highest score, then oldest). Returns how many jobs
+    /// were newly linked.
+    ///
+    /// Jobs the user split back out with `unlink_job_duplicate` are left alone.
+    pub async fn link_cross_source_duplicates(&self) -> Result<usize, sqlx::Error> {
+        let jobs: Vec<Job> = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE hidden = 0
+              AND id NOT IN (SELECT job_id FROM job_duplicates)
+            ORDER BY score DESC, created_at ASC
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        let links = cross_source_links(&jobs);
+        if links.is_empty() {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+        let mut tx = self.pool().begin().await?;
+        for link in &links {
+            // A new canonical job takes over the copies linked to the old one.
+            sqlx::query(
+                "UPDATE job_duplicates SET canonical_job_id = ? WHERE canonical_job_id = ?",
+            )
+            .bind(link.canonical_job_id)
+            .bind(link.job_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                "INSERT INTO job_duplicates (job_id, canonical_job_id, similarity, linked_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(link.job_id)
+            .bind(link.canonical_job_id)
+            .bind(link.similarity)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        tracing::info!(linked = links.len(), "Cross-source duplicates linked");
+        Ok(links.len())
+    }
+
+    /// Copies of a canonical job posted on other sources
+    pub async fn get_job_duplicates(&self, job_id: i64) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT j.* FROM job_duplicates d
+            JOIN jobs j ON j.id = d.job_id
+            WHERE d.canonical_job_id = ? AND d.dismissed = 0
+            ORDER BY j.source, j.id
+            "#,
+        )
+        .bind(job_id)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        Ok(jobs)
+    }
+
+    /// Show a linked duplicate as its own job again and never re-link it.
+    /// Returns false if the job was not linked.
+    pub async fn unlink_job_duplicate(&self, job_id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE job_duplicates SET dismissed = 1 WHERE job_id = ? AND dismissed = 0",
+        )
+        .bind(job_id)
+        .execute(self.pool())
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+/// Find cross-source copies among `jobs`.
+///
+/// `jobs` must be ordered by preference (score descending, then oldest), so
+/// the first member of each cluster becomes the canonical job.
+fn cross_source_links(jobs: &[Job]) -> Vec<DuplicateLink> {
+    let mut by_company: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, job) in jobs.iter().enumerate() {
+        let company = normalize_company(&job.company);
+        if company.is_empty() {
+            continue;
+        }
+        let members = by_company.entry(company).or_default();
+        if members.len() < MAX_JOBS_PER_COMPANY {
+            members.push(index);
+        }
+    }
+
+    let mut links = Vec::new();
+    for members in by_company.values().filter(|members| members.len() > 1) {
+        let titles: Vec<HashSet<String>> = members
+            .iter()
+            .map(|&index| words(&jobs[index].title).collect())
+            .collect();
+        let locations: Vec<HashSet<String>> = members
+            .iter()
+            .map(|&index| location_words(jobs[index].location.as_deref()))
+            .collect();
+        let mut sets = DisjointSets::new(members.len());
+
+        for a in 0..members.len() {
+            for b in (a + 1)..members.len() {
+                let same_source = jobs[members[a]].source == jobs[members[b]].source;
+                if same_source
+                    || titles[a].is_empty()
+                    || titles[b].is_empty()
+                    || !locations_compatible(&locations[a], &locations[b])
+                {
+                    continue;
+                }
+                if jaccard(&titles[a], &titles[b]) >= TITLE_SIMILARITY_THRESHOLD {
+                    sets.union(a, b);
+                }
+            }
+        }
+
+        // Roots are the lowest (preferred) position in each set.
+        for local in 0..members.len() {
+            let root = sets.find(local);
+            if root != local {
+                links.push(DuplicateLink {
+                    job_id: jobs[members[local]].id,
+                    canonical_job_id: jobs[members[root]].id,
+                    similarity: jaccard(&titles[local], &titles[root]),
+                });
+            }
+        }
+    }
+
+    links.sort_by_key(|link| link.job_id);
+    links
+}
+
+/// Words of the first part of a location, usually the city ("Austin" in
+/// "Austin, TX, United States").
+fn location_words(location: Option<&str>) -> HashSet<String> {
+    location
+        .and_then(|location| location.split([',', ';', '(', '|']).next())
+        .map(|place| words(place).collect())
+        .unwrap_or_default()
+}
+
+/// Missing locations match anything; otherwise one must contain the other,
+/// so "Remote" matches "Remote US".
+fn locations_compatible(a: &HashSet<String>, b: &HashSet<String>) -> bool {
+    a.is_empty() || b.is_empty() || a.is_subset(b) || b.is_subset(a)
+}
@@ -3,11 +3,14 @@
 //! Create, Read, Update, and Delete operations for job records.
 
 use super::connection::Database;
-use super::types::JobRow;
+use super::exchange_rates::convert_job_salary_to_usd;
+use super::types::{JobRow, JobUpsert};
 use chrono::Utc;
-use jobsentinel_domain::{canonicalize_job_url, Job};
+use jobsentinel_domain::{canonicalize_job_url, Job, PayPeriod};
 use jobsentinel_security::validate_external_https_url;
-fn canonicalize_job_for_storage(job: &Job) -> Result<String, sqlx::Error> {
+use sqlx::{Connection, SqliteConnection};
+
+pub(crate) fn canonicalize_job_for_storage(job: &Job) -> Result<String, sqlx::Error> {
     const MAX_TITLE_LENGTH: usize = 500;
     const MAX_COMPANY_LENGTH: usize = 200;
     const MAX_URL_LENGTH: usize = 2000;
@@ -98,131 +101,72 @@ impl Database {
     )]
     pub async fn upsert_job(&self, job: &Job) -> Result<i64, sqlx::Error> {
         let canonical_job_url = canonicalize_job_for_storage(job)?;
-        let existing: Option<i64> = sqlx::query_scalar("SELECT id FROM jobs WHERE hash = ?")
-            .bind(&job.hash)
-            .fetch_optional(self.pool())
-            .await?;
-
-        if let Some(existing_id) = existing {
-            return self
-                .update_existing_job(existing_id, job, &canonical_job_url)
-                .await;
-        }
+        let mut conn = self.pool().acquire().await?;
+        let upsert = upsert_job_record(&mut conn, job, &canonical_job_url).await?;
+        Ok(upsert.job_id())
+    }
 
-        if let Some(job_id) = self.insert_job_record(job, &canonical_job_url).await? {
-            return Ok(job_id);
+    /// Insert or update a scrape batch in one transaction.
+    ///
+    /// Each job gets the same treatment as [`Database::upsert_job`], but the
+    /// batch shares one connection, so SQLx reuses its prepared statements,
+    /// and one commit instead of a write per statement. Results follow the
+    /// order of `jobs`. A job that fails validation gets its own error and is
+    /// skipped; a database error rolls back the whole batch.
+    #[tracing::instrument(skip_all, level = "debug")]
+    pub async fn upsert_jobs_batch<'a>(
+        &self,
+        jobs: impl IntoIterator<Item = &'a Job>,
+    ) -> Result<Vec<Result<JobUpsert, sqlx::Error>>, sqlx::Error> {
+        let jobs: Vec<&Job> = jobs.into_iter().collect();
+        let mut results = Vec::with_capacity(jobs.len());
+
+        let mut tx = self.pool().begin().await?;
+        for job in &jobs {
+            let upsert = match canonicalize_job_for_storage(job) {
+                Ok(canonical_job_url) => {
+                    Ok(upsert_job_record(&mut tx, job, &canonical_job_url).await?)
+                }
+                Err(e) => Err(e),
+            };
+            results.push(upsert);
         }
+        tx.commit().await?;
 
-        let existing_id: i64 = sqlx::query_scalar("SELECT id FROM jobs WHERE hash = ?")
-            .bind(&job.hash)
-            .fetch_one(self.pool())
-            .await?;
-        self.update_existing_job(existing_id, job, &canonical_job_url)
-            .await
+        let new_jobs = results
+            .iter()
+            .filter(|upsert| matches!(upsert, Ok(JobUpsert::New(_))))
+            .count();
+        tracing::info!(
+            job_count = jobs.len(),
+            new_jobs,
+            rejected = results.iter().filter(|upsert| upsert.is_err()).count(),
+            "Job batch saved"
+        );
+        Ok(results)
     }
 
     pub async fn insert_job_if_new(&self, job: &Job) -> Result<Option<i64>, sqlx::Error> {
         let canonical_job_url = canonicalize_job_for_storage(job)?;
-        self.insert_job_record(job, &canonical_job_url).await
+        let mut conn = self.pool().acquire().await?;
+        let job_id = insert_job_record(&mut conn, job, &canonical_job_url).await?;
+        if job_id.is_some() {
+            store_source_skills(&mut conn, job).await?;
+            store_extracted_skills(&mut conn, job).await?;
+        }
+        Ok(job_id)
     }
 
-    async fn update_existing_job(
-        &self,
-        existing_id: i64,
-        job: &Job,
-        canonical_job_url: &str,
-    ) -> Result<i64, sqlx::Error> {
-        tracing::debug!(
-            job_id = existing_id,
-            "Job already exists, updating and incrementing times_seen"
-        );
-        sqlx::query(
-            r#"
-            UPDATE jobs SET
-                title = ?, company = ?, url = ?, location = ?, description = ?,
-                score = ?, score_reasons = ?, source = ?, remote = ?,
-                salary_min = ?, salary_max = ?, currency = ?, updated_at = ?,
-                last_seen = ?, times_seen = times_seen + 1, ghost_score = ?,
-                ghost_reasons = ?, repost_count = ?
-            WHERE id = ?
-            "#,
-        )
-        .bind(&job.title)
-        .bind(&job.company)
-        .bind(canonical_job_url)
-        .bind(&job.location)
-        .bind(&job.description)
-        .bind(job.score)
-        .bind(&job.score_reasons)
-        .bind(&job.source)
-        .bind(job.remote.map(i64::from))
-        .bind(job.salary_min)
-        .bind(job.salary_max)
-        .bind(&job.currency)
-        .bind(Utc::now())
-        .bind(Utc::now())
-        .bind(job.ghost_score)
-        .bind(&job.ghost_reasons)
-        .bind(job.repost_count)
-        .bind(existing_id)
-        .execute(self.pool())
-        .await?;
-
-        tracing::debug!(job_id = existing_id, "Job update completed");
-        Ok(existing_id)
+    /// Record the skill tags a source attached to the job, next to the skills
+    /// later extracted from its description.
+    pub(crate) async fn store_source_skills(&self, job: &Job) -> Result<(), sqlx::Error> {
+        store_source_skills(&mut *self.pool().acquire().await?, job).await
     }
 
-    async fn insert_job_record(
-        &self,
-        job: &Job,
-        canonical_job_url: &str,
-    ) -> Result<Option<i64>, sqlx::Error> {
-        let result = sqlx::query(
-            r#"
-            INSERT INTO jobs (
-                hash, title, company, url, location, description,
-                score, score_reasons, source, remote,
-                salary_min, salary_max, currency,
-                created_at, updated_at, last_seen, times_seen,
-                immediate_alert_sent, included_in_digest,
-                ghost_score, ghost_reasons, first_seen, repost_count
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ON CONFLICT(hash) DO NOTHING
-            "#,
-        )
-        .bind(&job.hash)
-        .bind(&job.title)
-        .bind(&job.company)
-        .bind(canonical_job_url)
-        .bind(&job.location)
-        .bind(&job.description)
-        .bind(job.score)
-        .bind(&job.score_reasons)
-        .bind(&job.source)
-        .bind(job.remote.map(i64::from))
-        .bind(job.salary_min)
-        .bind(job.salary_max)
-        .bind(&job.currency)
-        .bind(job.created_at)
-        .bind(job.updated_at)
-        .bind(job.last_seen)
-        .bind(job.times_seen)
-        .bind(i64::from(job.immediate_alert_sent))
-        .bind(i64::from(job.included_in_digest))
-        .bind(job.ghost_score)
-        .bind(&job.ghost_reasons)
-        .bind(job.first_seen)
-        .bind(job.repost_count)
-        .execute(self.pool())
-        .await?;
-
-        if result.rows_affected() == 0 {
-            return Ok(None);
-        }
-
-        let job_id = result.last_insert_rowid();
-        tracing::info!(job_id, "New job inserted");
-        Ok(Some(job_id))
+    /// Extract the skills named in the job's title and description so skill
+    /// trends and resume matching see every stored job.
+    pub(crate) async fn store_extracted_skills(&self, job: &Job) -> Result<(), sqlx::Error> {
+        store_extracted_skills(&mut *self.pool().acquire().await?, job).await
     }
 
     /// Get job by ID
@@ -330,3 +274,176 @@ impl Database {
         Ok(())
     }
 }
+
+/// Insert or update one validated job and its skills on `conn`.
+async fn upsert_job_record(
+    conn: &mut SqliteConnection,
+    job: &Job,
+    canonical_job_url: &str,
+) -> Result<JobUpsert, sqlx::Error> {
+    let existing: Option<i64> = sqlx::query_scalar("SELECT id FROM jobs WHERE hash = ?")
+        .bind(&job.hash)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    let upsert = if let Some(existing_id) = existing {
+        JobUpsert::Updated(update_existing_job(conn, existing_id, job, canonical_job_url).await?)
+    } else if let Some(job_id) = insert_job_record(conn, job, canonical_job_url).await? {
+        JobUpsert::New(job_id)
+    } else {
+        let existing_id: i64 = sqlx::query_scalar("SELECT id FROM jobs WHERE hash = ?")
+            .bind(&job.hash)
+            .fetch_one(&mut *conn)
+            .await?;
+        JobUpsert::Updated(update_existing_job(conn, existing_id, job, canonical_job_url).await?)
+    };
+    store_source_skills(conn, job).await?;
+    store_extracted_skills(conn, job).await?;
+    Ok(upsert)
+}
+
+async fn update_existing_job(
+    conn: &mut SqliteConnection,
+    existing_id: i64,
+    job: &Job,
+    canonical_job_url: &str,
+) -> Result<i64, sqlx::Error> {
+    tracing::debug!(
+        job_id = existing_id,
+        "Job already exists, updating and incrementing times_seen"
+    );
+    // A posting a source lists again is open, whatever was recorded before.
+    sqlx::query(
+        r#"
+        UPDATE jobs SET
+            title = ?, company = ?, url = ?, location = ?, description = ?,
+            score = ?, score_reasons = ?, source = ?, remote = ?,
+            salary_min = ?, salary_max = ?, currency = ?, pay_period = ?, updated_at = ?,
+            last_seen = ?, times_seen = times_seen + 1, ghost_score = ?,
+            ghost_reasons = ?, repost_count = ?, status = 'active', status_reason = NULL
+        WHERE id = ?
+        "#,
+    )
+    .bind(&job.title)
+    .bind(&job.company)
+    .bind(canonical_job_url)
+    .bind(&job.location)
+    .bind(&job.description)
+    .bind(job.score)
+    .bind(&job.score_reasons)
+    .bind(&job.source)
+    .bind(job.remote.map(i64::from))
+    .bind(job.salary_min)
+    .bind(job.salary_max)
+    .bind(&job.currency)
+    .bind(job.pay_period.map(PayPeriod::as_str))
+    .bind(Utc::now())
+    .bind(Utc::now())
+    .bind(job.ghost_score)
+    .bind(&job.ghost_reasons)
+    .bind(job.repost_count)
+    .bind(existing_id)
+    .execute(&mut *conn)
+    .await?;
+
+    convert_job_salary_to_usd(&mut *conn, existing_id).await?;
+    tracing::debug!(job_id = existing_id, "Job update completed");
+    Ok(existing_id)
+}
+
+async fn insert_job_record(
+    conn: &mut SqliteConnection,
+    job: &Job,
+    canonical_job_url: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO jobs (
+            hash, title, company, url, location, description,
+            score, score_reasons, source, remote,
+            salary_min, salary_max, currency, pay_period,
+            created_at, updated_at, last_seen, times_seen,
+            immediate_alert_sent, included_in_digest,
+            ghost_score, ghost_reasons, first_seen, repost_count
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(hash) DO NOTHING
+        "#,
+    )
+    .bind(&job.hash)
+    .bind(&job.title)
+    .bind(&job.company)
+    .bind(canonical_job_url)
+    .bind(&job.location)
+    .bind(&job.description)
+    .bind(job.score)
+    .bind(&job.score_reasons)
+    .bind(&job.source)
+    .bind(job.remote.map(i64::from))
+    .bind(job.salary_min)
+    .bind(job.salary_max)
+    .bind(&job.currency)
+    .bind(job.pay_period.map(PayPeriod::as_str))
+    .bind(job.created_at)
+    .bind(job.updated_at)
+    .bind(job.last_seen)
+    .bind(job.times_seen)
+    .bind(i64::from(job.immediate_alert_sent))
+    .bind(i64::from(job.included_in_digest))
+    .bind(job.ghost_score)
+    .bind(&job.ghost_reasons)
+    .bind(job.first_seen)
+    .bind(job.repost_count)
+    .execute(&mut *conn)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(None);
+    }
+
+    let job_id = result.last_insert_rowid();
+    convert_job_salary_to_usd(&mut *conn, job_id).await?;
+    tracing::info!(job_id, "New job inserted");
+    Ok(Some(job_id))
+}
+
+async fn store_source_skills(conn: &mut SqliteConnection, job: &Job) -> Result<(), sqlx::Error> {
+    for skill in &job.skills {
+        sqlx::query(
+            r#"
+            INSERT INTO job_skills (job_hash, skill_name, is_required)
+            VALUES (?, ?, 1)
+            ON CONFLICT(job_hash, skill_name) DO NOTHING
+            "#,
+        )
+        .bind(&job.hash)
+        .bind(skill)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Categorized skills from an earlier extraction are replaced; source tags
+/// carry no category and are kept.
+async fn store_extracted_skills(conn: &mut SqliteConnection, job: &Job) -> Result<(), sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    sqlx::query("DELETE FROM job_skills WHERE job_hash = ? AND skill_category IS NOT NULL")
+        .bind(&job.hash)
+        .execute(&mut *tx)
+        .await?;
+    let extracted_skills = crate::resume::store_job_skills(
+        &mut *tx,
+        &job.hash,
+        &job.title,
+        job.description.as_deref().unwrap_or_default(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    tracing::debug!(
+        job_hash = %job.hash,
+        skill_count = extracted_skills.len(),
+        "Extracted job skills"
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Email digest selection and send history
+//!
+//! A job goes into at most one digest: once sent, `included_in_digest` is set
+//! so the next digest only lists jobs found since.
+
+use super::connection::Database;
+use super::types::JobRow;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+
+impl Database {
+    /// Visible jobs not yet sent in a digest, created since `since` and
+    /// scoring at least `min_score`, best first.
+    pub async fn jobs_for_email_digest(
+        &self,
+        since: DateTime<Utc>,
+        min_score: f64,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE included_in_digest = 0
+              AND hidden = 0
+              AND status <> 'closed'
+              AND score >= ?
+              AND created_at >= ?
+            ORDER BY score DESC, created_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(min_score)
+        .bind(since)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+        Ok(jobs)
+    }
+
+    /// Mark jobs as sent so later digests skip them.
+    pub async fn mark_included_in_digest(&self, job_ids: &[i64]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool().begin().await?;
+        for job_id in job_ids {
+            sqlx::query("UPDATE jobs SET included_in_digest = 1 WHERE id = ?")
+                .bind(job_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Record a scheduled digest, including one that had no jobs to send.
+    pub async fn record_email_digest(
+        &self,
+        sent_at: DateTime<Utc>,
+        job_count: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("INSERT INTO email_digests (sent_at, job_count) VALUES (?, ?)")
+            .bind(sent_at)
+            .bind(job_count)
+            .execute(self.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// When the last scheduled digest was recorded.
+    pub async fn last_email_digest_at(&self) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        sqlx::query_scalar("SELECT sent_at FROM email_digests ORDER BY id DESC LIMIT 1")
+            .fetch_optional(self.pool())
+            .await
+    }
+}
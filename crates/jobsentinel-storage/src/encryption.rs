@@ -11,6 +11,18 @@ use zeroize::Zeroizing;
 
 #[cfg(test)]
 const DATABASE_KEY_LEN: usize = 32;
+/// The scheduler and the UI each need a connection at once. WAL lets readers
+/// run beside the one writer, so a few connections keep UI reads from
+/// queueing behind a scrape.
+const MAX_CONNECTIONS: u32 = 8;
+/// Kept open so the first command after idle skips the SQLCipher key setup.
+const MIN_CONNECTIONS: u32 = 1;
+/// How long a write waits for another writer before failing as busy.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(15);
+/// How long a query waits for a free pooled connection.
+const ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Extra connections are closed after this long unused.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
 const DATABASE_ENCRYPTION_ERROR: &str =
     "JobSentinel could not unlock local database encryption. Check system permission prompts, then try again.";
 
@@ -39,7 +51,7 @@ pub(super) async fn connect_encrypted_pool(
         .journal_mode(SqliteJournalMode::Wal)
         .synchronous(SqliteSynchronous::Normal)
         .foreign_keys(true)
-        .busy_timeout(Duration::from_secs(5))
+        .busy_timeout(BUSY_TIMEOUT)
         .pragma("defer_foreign_keys", "OFF")
         .pragma("cell_size_check", "ON")
         .pragma("checksum_verification", "ON")
@@ -54,7 +66,12 @@ pub(super) async fn connect_encrypted_pool(
     #[cfg(debug_assertions)]
     let options = options.pragma("reverse_unordered_selects", "ON");
     let smoke_logging = package_smoke_logging_enabled();
-    let pool = match SqlitePoolOptions::new().connect_with(options).await {
+    let pool_options = SqlitePoolOptions::new()
+        .max_connections(MAX_CONNECTIONS)
+        .min_connections(MIN_CONNECTIONS)
+        .acquire_timeout(ACQUIRE_TIMEOUT)
+        .idle_timeout(IDLE_TIMEOUT);
+    let pool = match pool_options.connect_with(options).await {
         Ok(pool) => {
             if smoke_logging {
                 tracing::info!("Opened macOS package-smoke encrypted database connection");
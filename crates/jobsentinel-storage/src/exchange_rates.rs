@@ -0,0 +1,201 @@
+//! Exchange rates and US dollar salary columns
+//!
+//! `salary_min_usd` and `salary_max_usd` hold each posting's pay annualized
+//! by its `pay_period` and converted with the rates in `exchange_rates`, so
+//! pay floors and statistics compare postings in any currency and period.
+//! Unknown currencies leave both columns empty.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::{normalize_currency_code, ExchangeRates, BUNDLED_EXCHANGE_RATES};
+use sqlx::Row;
+
+use super::connection::Database;
+
+/// `exchange_rates.source` for rates shipped with the app.
+const BUNDLED_SOURCE: &str = "bundled";
+
+impl Database {
+    /// Write the bundled rates without replacing refreshed ones, then convert
+    /// any salaries that have no US dollar value yet.
+    pub(crate) async fn seed_bundled_exchange_rates(&self) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let mut tx = self.pool().begin().await?;
+        for (currency, usd_per_unit) in BUNDLED_EXCHANGE_RATES {
+            sqlx::query(
+                r#"
+                INSERT INTO exchange_rates (currency, usd_per_unit, source, updated_at)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT(currency) DO UPDATE SET
+                    usd_per_unit = excluded.usd_per_unit,
+                    updated_at = excluded.updated_at
+                WHERE exchange_rates.source = excluded.source
+                "#,
+            )
+            .bind(currency)
+            .bind(usd_per_unit)
+            .bind(BUNDLED_SOURCE)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        self.convert_salaries_to_usd(true).await?;
+        Ok(())
+    }
+
+    /// Current conversion table: bundled rates overlaid with refreshed ones.
+    pub async fn exchange_rates(&self) -> Result<ExchangeRates> {
+        let rows = sqlx::query("SELECT currency, usd_per_unit FROM exchange_rates")
+            .fetch_all(self.pool())
+            .await?;
+
+        let mut rates = ExchangeRates::bundled();
+        for row in rows {
+            let currency: String = row.try_get("currency")?;
+            rates.set_rate(&currency, row.try_get("usd_per_unit")?);
+        }
+        Ok(rates)
+    }
+
+    /// When rates were last refreshed from outside the app, if ever.
+    pub async fn exchange_rates_refreshed_at(&self) -> Result<Option<DateTime<Utc>>> {
+        let refreshed_at = sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+            "SELECT MAX(updated_at) FROM exchange_rates WHERE source != ?",
+        )
+        .bind(BUNDLED_SOURCE)
+        .fetch_one(self.pool())
+        .await?;
+        Ok(refreshed_at)
+    }
+
+    /// Whether any saved posting lists pay in a currency other than US dollars.
+    pub async fn has_non_usd_salaries(&self) -> Result<bool> {
+        let found = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM jobs
+                WHERE (salary_min IS NOT NULL OR salary_max IS NOT NULL)
+                  AND currency IS NOT NULL
+                  AND UPPER(TRIM(currency)) != 'USD'
+            )
+            "#,
+        )
+        .fetch_one(self.pool())
+        .await?;
+        Ok(found != 0)
+    }
+
+    /// Store refreshed `(currency, usd_per_unit)` rates and re-convert every
+    /// saved salary. Invalid codes and rates are skipped; returns how many
+    /// rates were stored.
+    pub async fn save_exchange_rates(
+        &self,
+        rates: &[(String, f64)],
+        source: &str,
+        fetched_at: DateTime<Utc>,
+    ) -> Result<usize> {
+        anyhow::ensure!(
+            source != BUNDLED_SOURCE,
+            "Refreshed rates need their own source"
+        );
+
+        let mut saved = 0;
+        let mut tx = self.pool().begin().await?;
+        for (currency, usd_per_unit) in rates {
+            let Some(code) = normalize_currency_code(currency) else {
+                continue;
+            };
+            if code == "USD" || !usd_per_unit.is_finite() || *usd_per_unit <= 0.0 {
+                continue;
+            }
+            sqlx::query(
+                r#"
+                INSERT INTO exchange_rates (currency, usd_per_unit, source, updated_at)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT(currency) DO UPDATE SET
+                    usd_per_unit = excluded.usd_per_unit,
+                    source = excluded.source,
+                    updated_at = excluded.updated_at
+                "#,
+            )
+            .bind(&code)
+            .bind(usd_per_unit)
+            .bind(source)
+            .bind(fetched_at)
+            .execute(&mut *tx)
+            .await?;
+            saved += 1;
+        }
+        tx.commit().await?;
+
+        let converted = self.convert_salaries_to_usd(false).await?;
+        tracing::info!(saved, converted, "Saved refreshed exchange rates");
+        Ok(saved)
+    }
+
+    /// Convert one job's salary to US dollars with the stored rates.
+    pub(crate) async fn convert_job_salary_to_usd(&self, job_id: i64) -> Result<(), sqlx::Error> {
+        convert_job_salary_to_usd(self.pool(), job_id).await
+    }
+
+    /// Convert saved salaries to US dollars; with `only_missing`, only rows
+    /// that have no US dollar value yet.
+    async fn convert_salaries_to_usd(&self, only_missing: bool) -> Result<u64, sqlx::Error> {
+        update_salaries_usd(self.pool(), None, only_missing).await
+    }
+}
+
+/// Annualize and convert one job's salary, on the pool or inside an open
+/// transaction.
+pub(crate) async fn convert_job_salary_to_usd<'e>(
+    executor: impl sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    job_id: i64,
+) -> Result<(), sqlx::Error> {
+    update_salaries_usd(executor, Some(job_id), false).await?;
+    Ok(())
+}
+
+/// Annualize and convert salaries for one job, or for every job with a
+/// salary when `job_id` is `None`.
+async fn update_salaries_usd<'e>(
+    executor: impl sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    job_id: Option<i64>,
+    only_missing: bool,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE jobs SET
+            salary_min_usd = CAST(ROUND(salary_min * conversion.factor) AS INTEGER),
+            salary_max_usd = CAST(ROUND(salary_max * conversion.factor) AS INTEGER)
+        FROM (
+            SELECT j.id AS job_id, rates.usd_per_unit * (
+                CASE
+                    WHEN j.pay_period = 'hour' THEN 2080
+                    WHEN j.pay_period = 'day' THEN 260
+                    WHEN j.pay_period = 'week' THEN 52
+                    WHEN j.pay_period = 'month' THEN 12
+                    WHEN j.pay_period IS NULL
+                        AND COALESCE(j.salary_max, j.salary_min) BETWEEN 1 AND 999 THEN 2080
+                    ELSE 1
+                END
+            ) AS factor
+            FROM jobs j
+            LEFT JOIN exchange_rates rates
+                ON rates.currency = UPPER(TRIM(COALESCE(j.currency, 'USD')))
+        ) AS conversion
+        WHERE conversion.job_id = jobs.id
+          AND (jobs.id = ?
+               OR (? IS NULL
+                   AND (salary_min IS NOT NULL OR salary_max IS NOT NULL)
+                   AND (? = 0 OR (salary_min_usd IS NULL AND salary_max_usd IS NULL))))
+        "#,
+    )
+    .bind(job_id)
+    .bind(job_id)
+    .bind(only_missing)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,78 @@
+//! Cached geocoding results for job locations
+//!
+//! Keys are location text as normalized by the caller. A cached miss keeps
+//! empty coordinates so it is only retried once it has aged out.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Coordinates;
+use sqlx::Row;
+
+use super::connection::Database;
+
+impl Database {
+    /// Cached lookups for `locations`, including misses (`None`) newer than
+    /// `misses_since`. Locations never looked up are absent from the map.
+    pub async fn cached_geocodes(
+        &self,
+        locations: &[String],
+        misses_since: DateTime<Utc>,
+    ) -> Result<HashMap<String, Option<Coordinates>>, sqlx::Error> {
+        let mut cached = HashMap::with_capacity(locations.len());
+        for location in locations {
+            let row = sqlx::query(
+                "SELECT latitude, longitude, looked_up_at FROM geocode_cache WHERE location = ?",
+            )
+            .bind(location)
+            .fetch_optional(self.pool())
+            .await?;
+            let Some(row) = row else {
+                continue;
+            };
+
+            let latitude: Option<f64> = row.try_get("latitude")?;
+            let longitude: Option<f64> = row.try_get("longitude")?;
+            match latitude.zip(longitude) {
+                Some((latitude, longitude)) => {
+                    cached.insert(
+                        location.clone(),
+                        Some(Coordinates::new(latitude, longitude)),
+                    );
+                }
+                None => {
+                    let looked_up_at: DateTime<Utc> = row.try_get("looked_up_at")?;
+                    if looked_up_at >= misses_since {
+                        cached.insert(location.clone(), None);
+                    }
+                }
+            }
+        }
+        Ok(cached)
+    }
+
+    /// Save a lookup result; `None` records that nothing was found
+    pub async fn save_geocode(
+        &self,
+        location: &str,
+        coordinates: Option<Coordinates>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO geocode_cache (location, latitude, longitude, looked_up_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(location) DO UPDATE SET
+                latitude = excluded.latitude,
+                longitude = excluded.longitude,
+                looked_up_at = excluded.looked_up_at
+            "#,
+        )
+        .bind(location)
+        .bind(coordinates.map(|c| c.latitude))
+        .bind(coordinates.map(|c| c.longitude))
+        .bind(Utc::now())
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+}
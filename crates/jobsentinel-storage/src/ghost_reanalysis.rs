@@ -0,0 +1,115 @@
+//! Ghost detector versions and background re-analysis records
+//!
+//! Each saved ghost score carries the detector version that produced it, so
+//! scores from older heuristics can be found and re-analyzed after upgrades.
+
+use super::connection::Database;
+use super::types::{GhostReanalysisRun, JobRow};
+use jobsentinel_domain::Job;
+
+impl Database {
+    /// Record which detector version produced a job's saved ghost score.
+    pub async fn set_ghost_detector_version(
+        &self,
+        job_hash: &str,
+        detector_version: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE jobs SET ghost_detector_version = ? WHERE hash = ?")
+            .bind(detector_version)
+            .bind(job_hash)
+            .execute(self.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Jobs whose ghost score came from a detector older than
+    /// `detector_version`, or from before versions were recorded.
+    pub async fn jobs_with_outdated_ghost_analysis(
+        &self,
+        detector_version: i64,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE ghost_detector_version IS NULL OR ghost_detector_version < ?
+            ORDER BY id
+            LIMIT ?
+            "#,
+        )
+        .bind(detector_version)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+        Ok(jobs)
+    }
+
+    /// Save a re-analyzed ghost score without marking the job as updated.
+    pub async fn save_reanalyzed_ghost_score(
+        &self,
+        job_id: i64,
+        ghost_score: f64,
+        ghost_reasons: Option<&str>,
+        detector_version: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET ghost_score = ?, ghost_reasons = ?, ghost_detector_version = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(ghost_score)
+        .bind(ghost_reasons)
+        .bind(detector_version)
+        .bind(job_id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Save the outcome of a background re-analysis.
+    pub async fn record_ghost_reanalysis_run(
+        &self,
+        run: &GhostReanalysisRun,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO ghost_reanalysis_runs (
+                detector_version, started_at, finished_at, jobs_reanalyzed,
+                moved_to_clear, moved_to_warning, moved_to_hidden
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(run.detector_version)
+        .bind(&run.started_at)
+        .bind(&run.finished_at)
+        .bind(run.jobs_reanalyzed)
+        .bind(run.moved_to_clear)
+        .bind(run.moved_to_warning)
+        .bind(run.moved_to_hidden)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Most recent background re-analysis, if any has run.
+    pub async fn latest_ghost_reanalysis_run(
+        &self,
+    ) -> Result<Option<GhostReanalysisRun>, sqlx::Error> {
+        sqlx::query_as::<_, GhostReanalysisRun>(
+            r#"
+            SELECT detector_version, started_at, finished_at, jobs_reanalyzed,
+                   moved_to_clear, moved_to_warning, moved_to_hidden
+            FROM ghost_reanalysis_runs
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+}
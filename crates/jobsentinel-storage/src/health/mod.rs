@@ -1,10 +1,12 @@
 //! SQL-backed source health history and metrics.
 
 mod metrics;
+mod scrape_runs;
 mod tracking;
 mod types;
 
 pub use metrics::*;
+pub use scrape_runs::*;
 pub use tracking::*;
 pub use types::*;
 
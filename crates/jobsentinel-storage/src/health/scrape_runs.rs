@@ -0,0 +1,243 @@
+//! Scrape run history - record every full scraping cycle.
+//!
+//! Unlike `scraper_runs`, which tracks one source at a time, these records
+//! cover a whole cycle: when it ran, what each source returned, and which
+//! errors were reported, so an empty result can be traced to its cause.
+
+use crate::{Database, DatabaseMaintenance};
+use anyhow::Result;
+use chrono::{NaiveDateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+use super::types::{
+    ScrapeRunDetails, ScrapeRunSource, ScrapeRunStatus, ScrapeRunSummary, ScrapeRunTotals,
+};
+
+/// Cycles kept in history; older runs are pruned when a new one finishes.
+const MAX_SCRAPE_RUNS: i64 = 500;
+
+macro_rules! scrape_run_query {
+    ($tail:literal) => {
+        concat!(
+            r#"
+            SELECT
+                r.id, r.started_at, r.finished_at, r.status, r.sources_total,
+                r.jobs_found, r.jobs_new, r.jobs_updated, r.high_matches,
+                r.alerts_sent, r.errors, r.maintenance,
+                (SELECT COUNT(*) FROM scrape_run_sources s WHERE s.run_id = r.id)
+                    AS sources_completed,
+                json_array_length(r.errors)
+                    + COALESCE((SELECT SUM(json_array_length(s.errors))
+                                FROM scrape_run_sources s WHERE s.run_id = r.id), 0)
+                    AS error_count
+            FROM scrape_runs r
+            "#,
+            $tail
+        )
+    };
+}
+
+fn count_to_i64(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+fn errors_json(errors: &[String]) -> String {
+    serde_json::to_string(errors).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn parse_errors(value: &str) -> Vec<String> {
+    serde_json::from_str(value).unwrap_or_default()
+}
+
+/// Start recording a scraping cycle and return its run ID.
+pub async fn start_scrape_run(db: &Database, sources_total: usize) -> Result<i64> {
+    let now = Utc::now().naive_utc();
+    let result = sqlx::query(
+        "INSERT INTO scrape_runs (started_at, status, sources_total) VALUES (?, 'running', ?)",
+    )
+    .bind(now)
+    .bind(count_to_i64(sources_total))
+    .execute(db.pool())
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Record the results one source reported during a cycle.
+pub async fn record_scrape_run_source(
+    db: &Database,
+    run_id: i64,
+    source: &str,
+    jobs_found: usize,
+    jobs_new: usize,
+    jobs_updated: usize,
+    errors: &[String],
+) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    sqlx::query(
+        r#"
+        INSERT INTO scrape_run_sources (
+            run_id, source, finished_at, jobs_found, jobs_new, jobs_updated, errors
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(run_id)
+    .bind(source)
+    .bind(now)
+    .bind(count_to_i64(jobs_found))
+    .bind(count_to_i64(jobs_new))
+    .bind(count_to_i64(jobs_updated))
+    .bind(errors_json(errors))
+    .execute(db.pool())
+    .await?;
+
+    Ok(())
+}
+
+/// Finish a cycle with its totals and cycle-level errors, then prune history
+/// beyond the newest 500 runs.
+pub async fn finish_scrape_run(
+    db: &Database,
+    run_id: i64,
+    totals: &ScrapeRunTotals,
+    errors: &[String],
+) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    let source_errors: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(json_array_length(errors)), 0) FROM scrape_run_sources WHERE run_id = ?",
+    )
+    .bind(run_id)
+    .fetch_one(db.pool())
+    .await?;
+    let status = if errors.is_empty() && source_errors == 0 {
+        ScrapeRunStatus::Completed
+    } else {
+        ScrapeRunStatus::CompletedWithErrors
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE scrape_runs
+        SET finished_at = ?, status = ?, jobs_found = ?, jobs_new = ?,
+            jobs_updated = ?, high_matches = ?, alerts_sent = ?, errors = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(now)
+    .bind(status.as_str())
+    .bind(count_to_i64(totals.jobs_found))
+    .bind(count_to_i64(totals.jobs_new))
+    .bind(count_to_i64(totals.jobs_updated))
+    .bind(count_to_i64(totals.high_matches))
+    .bind(count_to_i64(totals.alerts_sent))
+    .bind(errors_json(errors))
+    .bind(run_id)
+    .execute(db.pool())
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM scrape_runs WHERE id NOT IN (SELECT id FROM scrape_runs ORDER BY id DESC LIMIT ?)",
+    )
+    .bind(MAX_SCRAPE_RUNS)
+    .execute(db.pool())
+    .await?;
+
+    Ok(())
+}
+
+/// Record the database maintenance that ran after a cycle.
+pub async fn record_scrape_run_maintenance(
+    db: &Database,
+    run_id: i64,
+    maintenance: &DatabaseMaintenance,
+) -> Result<()> {
+    sqlx::query("UPDATE scrape_runs SET maintenance = ? WHERE id = ?")
+        .bind(serde_json::to_string(maintenance)?)
+        .bind(run_id)
+        .execute(db.pool())
+        .await?;
+
+    Ok(())
+}
+
+/// Retrieve recent scraping cycles, newest first.
+pub async fn get_scrape_history(db: &Database, limit: i32) -> Result<Vec<ScrapeRunSummary>> {
+    let rows = sqlx::query(scrape_run_query!("ORDER BY r.id DESC LIMIT ?"))
+        .bind(limit)
+        .fetch_all(db.pool())
+        .await?;
+
+    rows.iter().map(summary_from_row).collect()
+}
+
+/// Retrieve one scraping cycle with its per-source results and errors.
+pub async fn get_scrape_run_details(
+    db: &Database,
+    run_id: i64,
+) -> Result<Option<ScrapeRunDetails>> {
+    let Some(row) = sqlx::query(scrape_run_query!("WHERE r.id = ?"))
+        .bind(run_id)
+        .fetch_optional(db.pool())
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let sources = sqlx::query(
+        r#"
+        SELECT source, finished_at, jobs_found, jobs_new, jobs_updated, errors
+        FROM scrape_run_sources
+        WHERE run_id = ?
+        ORDER BY id ASC
+        "#,
+    )
+    .bind(run_id)
+    .fetch_all(db.pool())
+    .await?
+    .iter()
+    .map(|row| {
+        Ok(ScrapeRunSource {
+            source: row.try_get("source")?,
+            finished_at: row.try_get::<NaiveDateTime, _>("finished_at")?.and_utc(),
+            jobs_found: row.try_get("jobs_found")?,
+            jobs_new: row.try_get("jobs_new")?,
+            jobs_updated: row.try_get("jobs_updated")?,
+            errors: parse_errors(row.try_get("errors")?),
+        })
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(ScrapeRunDetails {
+        summary: summary_from_row(&row)?,
+        errors: parse_errors(row.try_get("errors")?),
+        sources,
+    }))
+}
+
+fn summary_from_row(row: &SqliteRow) -> Result<ScrapeRunSummary> {
+    let started_at = row.try_get::<NaiveDateTime, _>("started_at")?.and_utc();
+    let finished_at = row
+        .try_get::<Option<NaiveDateTime>, _>("finished_at")?
+        .map(|finished| finished.and_utc());
+
+    Ok(ScrapeRunSummary {
+        id: row.try_get("id")?,
+        started_at,
+        finished_at,
+        duration_ms: finished_at.map(|finished| (finished - started_at).num_milliseconds()),
+        status: ScrapeRunStatus::from_str(row.try_get::<String, _>("status")?.as_str()),
+        sources_total: row.try_get("sources_total")?,
+        sources_completed: row.try_get("sources_completed")?,
+        jobs_found: row.try_get("jobs_found")?,
+        jobs_new: row.try_get("jobs_new")?,
+        jobs_updated: row.try_get("jobs_updated")?,
+        high_matches: row.try_get("high_matches")?,
+        alerts_sent: row.try_get("alerts_sent")?,
+        error_count: row.try_get("error_count")?,
+        maintenance: row
+            .try_get::<Option<String>, _>("maintenance")?
+            .and_then(|value| serde_json::from_str(&value).ok()),
+    })
+}
@@ -2,6 +2,7 @@
 //!
 //! Defines all enums and structs used throughout the health monitoring modules.
 
+use crate::DatabaseMaintenance;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -341,3 +342,110 @@ pub struct SmokeTestResult {
     /// Error message if test failed.
     pub error: Option<String>,
 }
+
+/// Outcome of a full scraping cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrapeRunStatus {
+    /// Cycle still running (or the app closed before it finished).
+    Running,
+    /// Every checked source finished without errors.
+    Completed,
+    /// Cycle finished, but at least one source or step reported an error.
+    CompletedWithErrors,
+}
+
+impl ScrapeRunStatus {
+    /// Convert to database string representation.
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::CompletedWithErrors => "completed_with_errors",
+        }
+    }
+
+    /// Parse from database string.
+    #[inline]
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "completed" => Self::Completed,
+            "completed_with_errors" => Self::CompletedWithErrors,
+            _ => Self::Running,
+        }
+    }
+}
+
+/// Totals recorded when a scraping cycle finishes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrapeRunTotals {
+    pub jobs_found: usize,
+    pub jobs_new: usize,
+    pub jobs_updated: usize,
+    pub high_matches: usize,
+    pub alerts_sent: usize,
+}
+
+/// One scraping cycle in the run history list.
+///
+/// Stored in `scrape_runs`; per-source rows live in `scrape_run_sources`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeRunSummary {
+    /// Database row ID.
+    pub id: i64,
+    /// When the cycle started.
+    pub started_at: DateTime<Utc>,
+    /// When the cycle finished (None if it never finished).
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Total cycle time in milliseconds.
+    pub duration_ms: Option<i64>,
+    /// Final cycle status.
+    pub status: ScrapeRunStatus,
+    /// Sources enabled when the cycle started.
+    pub sources_total: i32,
+    /// Sources that reported results.
+    pub sources_completed: i32,
+    /// Jobs returned by all sources.
+    pub jobs_found: i32,
+    /// New jobs added to the database.
+    pub jobs_new: i32,
+    /// Existing jobs refreshed.
+    pub jobs_updated: i32,
+    /// Jobs at or above the alert threshold.
+    pub high_matches: i32,
+    /// Alerts delivered.
+    pub alerts_sent: i32,
+    /// Cycle-level and per-source errors combined.
+    pub error_count: i32,
+    /// Database maintenance that ran after the cycle, if any.
+    pub maintenance: Option<DatabaseMaintenance>,
+}
+
+/// Results one source reported during a scraping cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeRunSource {
+    /// Source identifier (e.g. "greenhouse").
+    pub source: String,
+    /// When the source's results were saved.
+    pub finished_at: DateTime<Utc>,
+    /// Jobs returned by this source.
+    pub jobs_found: i32,
+    /// New jobs added from this source.
+    pub jobs_new: i32,
+    /// Existing jobs refreshed from this source.
+    pub jobs_updated: i32,
+    /// Errors reported by this source.
+    pub errors: Vec<String>,
+}
+
+/// Full record of one scraping cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeRunDetails {
+    #[serde(flatten)]
+    pub summary: ScrapeRunSummary,
+    /// Errors not tied to a single source.
+    pub errors: Vec<String>,
+    /// Sources in the order they finished.
+    pub sources: Vec<ScrapeRunSource>,
+}
@@ -0,0 +1,121 @@
+//! Job feedback and learned keyword weights
+//!
+//! Users mark jobs as good or bad matches. The verdicts train per-keyword
+//! weights in the application crate, which are stored here and applied the
+//! next time jobs are scored.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sqlx::Row;
+
+use super::connection::Database;
+use crate::{JobFeedback, JobFeedbackExample};
+
+impl Database {
+    /// Record the user's verdict for a job, replacing any earlier one
+    pub async fn set_job_feedback(
+        &self,
+        job_id: i64,
+        feedback: JobFeedback,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO job_feedback (job_id, feedback, created_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT(job_id) DO UPDATE SET
+                feedback = excluded.feedback,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(job_id)
+        .bind(feedback.as_str())
+        .bind(Utc::now())
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Get the user's verdict for a job (None if no feedback given)
+    pub async fn get_job_feedback(&self, job_id: i64) -> Result<Option<JobFeedback>, sqlx::Error> {
+        let feedback: Option<String> =
+            sqlx::query_scalar("SELECT feedback FROM job_feedback WHERE job_id = ?")
+                .bind(job_id)
+                .fetch_optional(self.pool())
+                .await?;
+
+        Ok(feedback.as_deref().and_then(JobFeedback::parse))
+    }
+
+    /// Clear the user's verdict for a job
+    pub async fn clear_job_feedback(&self, job_id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM job_feedback WHERE job_id = ?")
+            .bind(job_id)
+            .execute(self.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Every rated job with the text keyword weights are learned from
+    pub async fn job_feedback_examples(&self) -> Result<Vec<JobFeedbackExample>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT j.title, j.description, f.feedback
+            FROM job_feedback f
+            JOIN jobs j ON j.id = f.job_id
+            ORDER BY f.job_id
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut examples = Vec::with_capacity(rows.len());
+        for row in rows {
+            let feedback: String = row.try_get("feedback")?;
+            let Some(feedback) = JobFeedback::parse(&feedback) else {
+                continue;
+            };
+            examples.push(JobFeedbackExample {
+                title: row.try_get("title")?,
+                description: row.try_get("description")?,
+                feedback,
+            });
+        }
+        Ok(examples)
+    }
+
+    /// Learned weight per lowercased boost keyword
+    pub async fn keyword_feedback_weights(&self) -> Result<HashMap<String, f64>, sqlx::Error> {
+        let rows = sqlx::query("SELECT keyword, weight FROM keyword_feedback_weights")
+            .fetch_all(self.pool())
+            .await?;
+
+        rows.into_iter()
+            .map(|row| Ok((row.try_get("keyword")?, row.try_get("weight")?)))
+            .collect()
+    }
+
+    /// Replace all learned keyword weights in one transaction
+    pub async fn replace_keyword_feedback_weights(
+        &self,
+        weights: &HashMap<String, f64>,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let mut tx = self.pool().begin().await?;
+        sqlx::query("DELETE FROM keyword_feedback_weights")
+            .execute(&mut *tx)
+            .await?;
+        for (keyword, weight) in weights {
+            sqlx::query(
+                "INSERT INTO keyword_feedback_weights (keyword, weight, updated_at) VALUES (?, ?, ?)",
+            )
+            .bind(keyword)
+            .bind(weight)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+//! Structured job queries
+//!
+//! A `JobFilter` covers what the dashboard filters and saved searches hold:
+//! text, score range, sources, remote, salary, posted date, posting risk,
+//! tags, status, bookmarks, and notes. Job agents also filter on when a job
+//! was first saved. It is compiled to one SQL query with bound values, so
+//! filtering happens in the database instead of over a recent-jobs page.
+
+use chrono::{DateTime, Duration, Utc};
+use jobsentinel_domain::{Job, JobStatus};
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+use super::job_tags::normalize_tag;
+use super::types::JobRow;
+use super::user_data::SavedSearch;
+
+/// Score at or above which a job is a strong fit, matching the dashboard.
+const STRONG_FIT_SCORE: f64 = 0.7;
+/// Score below which a job is a weak fit, matching the dashboard.
+const WEAK_FIT_SCORE: f64 = 0.4;
+
+/// Result order, named like the dashboard sort options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JobSort {
+    #[default]
+    ScoreDesc,
+    ScoreAsc,
+    DateDesc,
+    DateAsc,
+    CompanyAsc,
+}
+
+impl JobSort {
+    const fn order_by(self) -> &'static str {
+        match self {
+            Self::ScoreDesc => "score DESC, created_at DESC",
+            Self::ScoreAsc => "score ASC, created_at DESC",
+            Self::DateDesc => "created_at DESC, score DESC",
+            Self::DateAsc => "created_at ASC, score DESC",
+            Self::CompanyAsc => "company COLLATE NOCASE ASC, score DESC",
+        }
+    }
+
+    /// Parse a saved-search sort name such as `date-desc`.
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "score-desc" => Some(Self::ScoreDesc),
+            "score-asc" => Some(Self::ScoreAsc),
+            "date-desc" => Some(Self::DateDesc),
+            "date-asc" => Some(Self::DateAsc),
+            "company-asc" => Some(Self::CompanyAsc),
+            _ => None,
+        }
+    }
+}
+
+/// Structured filter for `Database::query_jobs`. Empty fields match
+/// everything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobFilter {
+    /// Full-text query on title and description
+    pub text: Option<String>,
+    /// Lowest match score, inclusive
+    pub min_score: Option<f64>,
+    /// Match score the job must be below
+    pub max_score: Option<f64>,
+    /// Source adapters to include
+    pub sources: Vec<String>,
+    pub remote: Option<bool>,
+    /// Annual US dollars the top of the listed pay must reach. Jobs without
+    /// listed pay are left out when either salary bound is set.
+    pub salary_min_usd: Option<i64>,
+    /// Annual US dollars the bottom of the listed pay must not exceed
+    pub salary_max_usd: Option<i64>,
+    /// Only jobs first saved within this many days
+    pub posted_within_days: Option<i64>,
+    /// Only jobs first saved after this time
+    pub saved_after: Option<DateTime<Utc>>,
+    /// Highest posting-risk (ghost) score, inclusive; unanalyzed jobs match
+    pub max_ghost_score: Option<f64>,
+    /// Jobs carrying any of these tags
+    pub tags: Vec<String>,
+    /// Statuses to include; empty means every status but closed
+    pub statuses: Vec<JobStatus>,
+    pub bookmarked: Option<bool>,
+    pub has_notes: Option<bool>,
+    pub include_hidden: bool,
+    pub sort: JobSort,
+}
+
+impl JobFilter {
+    /// The filter a saved search describes.
+    ///
+    /// The posting-risk filter is left out: the dashboard decides which
+    /// postings need review from their reasons as well as the score.
+    pub fn from_saved_search(search: &SavedSearch) -> Self {
+        let (min_score, max_score) = match search.score_filter.as_str() {
+            "high" => (Some(STRONG_FIT_SCORE), None),
+            "medium" => (Some(WEAK_FIT_SCORE), Some(STRONG_FIT_SCORE)),
+            "low" => (None, Some(WEAK_FIT_SCORE)),
+            _ => (None, None),
+        };
+        let flag = |value: &str, yes: &str, no: &str| {
+            (value == yes)
+                .then_some(true)
+                .or((value == no).then_some(false))
+        };
+
+        Self {
+            text: search
+                .text_search
+                .as_deref()
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .map(str::to_string),
+            min_score,
+            max_score,
+            sources: match search.source_filter.as_str() {
+                "all" | "" => Vec::new(),
+                source => vec![source.to_string()],
+            },
+            remote: flag(&search.remote_filter, "remote", "onsite"),
+            salary_min_usd: search.salary_min_filter,
+            salary_max_usd: search.salary_max_filter,
+            posted_within_days: match search.posted_date_filter.as_deref() {
+                Some("24h") => Some(1),
+                Some("7d") => Some(7),
+                Some("30d") => Some(30),
+                _ => None,
+            },
+            bookmarked: flag(&search.bookmark_filter, "bookmarked", "not-bookmarked"),
+            has_notes: flag(&search.notes_filter, "has-notes", "no-notes"),
+            sort: JobSort::parse(&search.sort_by).unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A value bound to one `?` in a compiled filter
+pub(super) enum FilterValue {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Timestamp(DateTime<Utc>),
+}
+
+/// `?,?,?` for `count` values
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(",")
+}
+
+impl JobFilter {
+    /// SQL conditions for this filter and the values bound to their `?`s, in
+    /// order.
+    ///
+    /// Cross-source copies are always collapsed into their canonical job, as
+    /// in the recent-jobs list.
+    pub(super) fn conditions(&self) -> Result<(Vec<String>, Vec<FilterValue>), sqlx::Error> {
+        // SAFETY: conditions only contain fixed SQL and "?" placeholders;
+        // every user value is returned for binding.
+        let mut conditions: Vec<String> =
+            vec!["id NOT IN (SELECT job_id FROM collapsed_duplicate_jobs)".to_string()];
+        let mut binds = Vec::new();
+
+        if !self.include_hidden {
+            conditions.push("hidden = 0".to_string());
+        }
+        if self.statuses.is_empty() {
+            conditions.push("status <> 'closed'".to_string());
+        } else {
+            conditions.push(format!("status IN ({})", placeholders(self.statuses.len())));
+            binds.extend(
+                self.statuses
+                    .iter()
+                    .map(|status| FilterValue::Text(status.as_str().to_string())),
+            );
+        }
+        if let Some(text) = self
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+        {
+            conditions
+                .push("id IN (SELECT rowid FROM jobs_fts WHERE jobs_fts MATCH ?)".to_string());
+            binds.push(FilterValue::Text(text.to_string()));
+        }
+        if let Some(min_score) = self.min_score {
+            conditions.push("score >= ?".to_string());
+            binds.push(FilterValue::Real(min_score));
+        }
+        if let Some(max_score) = self.max_score {
+            conditions.push("score < ?".to_string());
+            binds.push(FilterValue::Real(max_score));
+        }
+        if !self.sources.is_empty() {
+            conditions.push(format!("source IN ({})", placeholders(self.sources.len())));
+            binds.extend(self.sources.iter().cloned().map(FilterValue::Text));
+        }
+        if let Some(remote) = self.remote {
+            conditions.push("remote = ?".to_string());
+            binds.push(FilterValue::Integer(i64::from(remote)));
+        }
+        if self.salary_min_usd.is_some() || self.salary_max_usd.is_some() {
+            conditions
+                .push("(salary_min_usd IS NOT NULL OR salary_max_usd IS NOT NULL)".to_string());
+        }
+        if let Some(salary_min) = self.salary_min_usd {
+            conditions.push("(salary_max_usd IS NULL OR salary_max_usd >= ?)".to_string());
+            binds.push(FilterValue::Integer(salary_min));
+        }
+        if let Some(salary_max) = self.salary_max_usd {
+            conditions.push("(salary_min_usd IS NULL OR salary_min_usd <= ?)".to_string());
+            binds.push(FilterValue::Integer(salary_max));
+        }
+        if let Some(days) = self.posted_within_days {
+            conditions.push("created_at >= ?".to_string());
+            binds.push(FilterValue::Timestamp(
+                Utc::now() - Duration::days(days.max(0)),
+            ));
+        }
+        if let Some(saved_after) = self.saved_after {
+            conditions.push("created_at > ?".to_string());
+            binds.push(FilterValue::Timestamp(saved_after));
+        }
+        if let Some(max_ghost_score) = self.max_ghost_score {
+            conditions.push("(ghost_score IS NULL OR ghost_score <= ?)".to_string());
+            binds.push(FilterValue::Real(max_ghost_score));
+        }
+        if !self.tags.is_empty() {
+            conditions.push(format!(
+                "id IN (SELECT job_id FROM job_tags WHERE tag IN ({}))",
+                placeholders(self.tags.len())
+            ));
+            for tag in &self.tags {
+                binds.push(FilterValue::Text(normalize_tag(tag)?));
+            }
+        }
+        if let Some(bookmarked) = self.bookmarked {
+            conditions.push("bookmarked = ?".to_string());
+            binds.push(FilterValue::Integer(i64::from(bookmarked)));
+        }
+        if let Some(has_notes) = self.has_notes {
+            conditions.push(if has_notes {
+                "(notes IS NOT NULL AND notes <> '')".to_string()
+            } else {
+                "(notes IS NULL OR notes = '')".to_string()
+            });
+        }
+
+        Ok((conditions, binds))
+    }
+}
+
+impl Database {
+    /// Jobs matching a structured filter, in the filter's order.
+    pub async fn query_jobs(
+        &self,
+        filter: &JobFilter,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let (conditions, mut binds) = filter.conditions()?;
+        binds.push(FilterValue::Integer(limit));
+        let sql = format!(
+            "SELECT * FROM jobs WHERE {} ORDER BY {} LIMIT ?",
+            conditions.join(" AND "),
+            filter.sort.order_by()
+        );
+
+        self.fetch_filtered_jobs(sql, binds).await
+    }
+
+    /// Run a compiled job query, binding `binds` to its `?`s in order.
+    pub(super) async fn fetch_filtered_jobs(
+        &self,
+        sql: String,
+        binds: Vec<FilterValue>,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let mut query = sqlx::query_as::<_, JobRow>(sqlx::AssertSqlSafe(sql));
+        for value in binds {
+            query = match value {
+                FilterValue::Integer(value) => query.bind(value),
+                FilterValue::Real(value) => query.bind(value),
+                FilterValue::Text(value) => query.bind(value),
+                FilterValue::Timestamp(value) => query.bind(value),
+            };
+        }
+
+        let jobs = query
+            .fetch_all(self.pool())
+            .await?
+            .into_iter()
+            .map(Job::from)
+            .collect();
+
+        Ok(jobs)
+    }
+}
@@ -0,0 +1,127 @@
+//! Keyset pagination for job lists
+//!
+//! Pages are ordered newest first by `(created_at, id)`. Each page carries a
+//! cursor for the last job on it, and the next page starts strictly after
+//! that key, so deep pages cost the same as the first one (no OFFSET scan)
+//! and jobs saved while the user scrolls do not shift later pages.
+
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+use super::job_filter::{FilterValue, JobFilter};
+
+/// Position after which the next page starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: i64,
+}
+
+impl JobCursor {
+    fn after(job: &Job) -> Self {
+        Self {
+            created_at: job.created_at,
+            id: job.id,
+        }
+    }
+}
+
+/// One page of a job list
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobPage {
+    pub jobs: Vec<Job>,
+    /// Cursor for the following page, or `None` on the last page
+    pub next_cursor: Option<JobCursor>,
+}
+
+impl Database {
+    /// A page of jobs matching a structured filter, newest first.
+    ///
+    /// `filter.sort` is ignored: keyset pages always follow `(created_at, id)`.
+    pub async fn query_jobs_page(
+        &self,
+        filter: &JobFilter,
+        cursor: Option<JobCursor>,
+        limit: i64,
+    ) -> Result<JobPage, sqlx::Error> {
+        let (mut conditions, mut binds) = filter.conditions()?;
+        if let Some(cursor) = cursor {
+            conditions.push("(created_at < ? OR (created_at = ? AND id < ?))".to_string());
+            binds.extend([
+                FilterValue::Timestamp(cursor.created_at),
+                FilterValue::Timestamp(cursor.created_at),
+                FilterValue::Integer(cursor.id),
+            ]);
+        }
+        // One extra row tells whether another page follows.
+        binds.push(FilterValue::Integer(limit.saturating_add(1)));
+        let sql = format!(
+            "SELECT * FROM jobs WHERE {} ORDER BY created_at DESC, id DESC LIMIT ?",
+            conditions.join(" AND ")
+        );
+
+        let mut jobs = self.fetch_filtered_jobs(sql, binds).await?;
+        let limit = usize::try_from(limit).unwrap_or(0);
+        let next_cursor = if jobs.len() > limit {
+            jobs.truncate(limit);
+            jobs.last().map(JobCursor::after)
+        } else {
+            None
+        };
+
+        Ok(JobPage { jobs, next_cursor })
+    }
+
+    /// A page of visible jobs, newest first
+    pub async fn get_recent_jobs_page(
+        &self,
+        cursor: Option<JobCursor>,
+        limit: i64,
+    ) -> Result<JobPage, sqlx::Error> {
+        self.query_jobs_page(&JobFilter::default(), cursor, limit)
+            .await
+    }
+
+    /// A page of bookmarked jobs, newest first
+    pub async fn get_bookmarked_jobs_page(
+        &self,
+        cursor: Option<JobCursor>,
+        limit: i64,
+    ) -> Result<JobPage, sqlx::Error> {
+        let filter = JobFilter {
+            bookmarked: Some(true),
+            ..JobFilter::default()
+        };
+        self.query_jobs_page(&filter, cursor, limit).await
+    }
+
+    /// A page of jobs from one source, newest first
+    pub async fn get_jobs_by_source_page(
+        &self,
+        source: &str,
+        cursor: Option<JobCursor>,
+        limit: i64,
+    ) -> Result<JobPage, sqlx::Error> {
+        let filter = JobFilter {
+            sources: vec![source.to_string()],
+            ..JobFilter::default()
+        };
+        self.query_jobs_page(&filter, cursor, limit).await
+    }
+
+    /// A page of jobs carrying a tag, newest first
+    pub async fn get_jobs_by_tag_page(
+        &self,
+        tag: &str,
+        cursor: Option<JobCursor>,
+        limit: i64,
+    ) -> Result<JobPage, sqlx::Error> {
+        let filter = JobFilter {
+            tags: vec![tag.to_string()],
+            ..JobFilter::default()
+        };
+        self.query_jobs_page(&filter, cursor, limit).await
+    }
+}
@@ -0,0 +1,121 @@
+//! On-demand refreshes of saved postings
+//!
+//! A refresh overwrites the posting's content and derived fields with what
+//! the source shows now and keeps a record of which fields changed.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::{Job, PayPeriod};
+use sqlx::Row;
+
+use super::connection::Database;
+use super::crud::canonicalize_job_for_storage;
+use super::types::{JobFieldChange, JobRefreshRecord};
+
+impl Database {
+    /// Save a re-fetched posting over the stored row and record what changed.
+    ///
+    /// The job keeps its id, hash, and URL so bookmarks, notes, and
+    /// applications stay attached. Returns the refresh record id.
+    pub async fn apply_job_refresh(
+        &self,
+        job: &Job,
+        changes: &[JobFieldChange],
+        refreshed_at: DateTime<Utc>,
+    ) -> Result<i64> {
+        canonicalize_job_for_storage(job)?;
+        let changes_json = serde_json::to_string(changes)?;
+
+        let mut tx = self.pool().begin().await?;
+        let updated = sqlx::query(
+            r#"
+            UPDATE jobs SET
+                title = ?, company = ?, location = ?, description = ?,
+                remote = ?, salary_min = ?, salary_max = ?, currency = ?, pay_period = ?,
+                score = ?, score_reasons = ?, ghost_score = ?, ghost_reasons = ?,
+                repost_count = ?, updated_at = ?, last_seen = ?,
+                status = 'active', status_reason = NULL, status_checked_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&job.title)
+        .bind(&job.company)
+        .bind(&job.location)
+        .bind(&job.description)
+        .bind(job.remote.map(i64::from))
+        .bind(job.salary_min)
+        .bind(job.salary_max)
+        .bind(&job.currency)
+        .bind(job.pay_period.map(PayPeriod::as_str))
+        .bind(job.score)
+        .bind(&job.score_reasons)
+        .bind(job.ghost_score)
+        .bind(&job.ghost_reasons)
+        .bind(job.repost_count)
+        .bind(refreshed_at)
+        .bind(refreshed_at)
+        .bind(refreshed_at)
+        .bind(job.id)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            anyhow::bail!("Job with id {} not found", job.id);
+        }
+
+        let refresh_id = sqlx::query(
+            "INSERT INTO job_refreshes (job_id, refreshed_at, changes) VALUES (?, ?, ?)",
+        )
+        .bind(job.id)
+        .bind(refreshed_at)
+        .bind(changes_json)
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        tx.commit().await?;
+
+        self.convert_job_salary_to_usd(job.id).await?;
+        self.store_source_skills(job).await?;
+        self.store_extracted_skills(job).await?;
+        tracing::info!(
+            job_id = job.id,
+            refresh_id,
+            changed_fields = changes.len(),
+            "Saved job refresh"
+        );
+        Ok(refresh_id)
+    }
+
+    /// Most recent refreshes of a job, newest first.
+    pub async fn get_job_refreshes(
+        &self,
+        job_id: i64,
+        limit: i64,
+    ) -> Result<Vec<JobRefreshRecord>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, job_id, refreshed_at, changes
+            FROM job_refreshes
+            WHERE job_id = ?
+            ORDER BY refreshed_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(job_id)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let changes: String = row.try_get("changes")?;
+                Ok(JobRefreshRecord {
+                    id: row.try_get("id")?,
+                    job_id: row.try_get("job_id")?,
+                    refreshed_at: row.try_get("refreshed_at")?,
+                    changes: serde_json::from_str(&changes)
+                        .context("Stored job refresh changes are not valid JSON")?,
+                })
+            })
+            .collect()
+    }
+}
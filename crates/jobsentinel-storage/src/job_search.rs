@@ -0,0 +1,314 @@
+//! Ranked full-text job search
+//!
+//! Queries are split into words and matched against `jobs_fts`, with the
+//! last word treated as a prefix so results follow the user while they type.
+//! Matches are ordered by BM25 with title weighted over company over
+//! description. When nothing matches, a trigram index on title and company
+//! finds near spellings such as `coordinater` for `Coordinator`.
+//!
+//! Ranked matches carry the title and a description snippet split into
+//! matched and unmatched runs, so the UI can show why a job matched.
+
+use std::collections::{HashMap, HashSet};
+
+use jobsentinel_domain::Job;
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+use super::job_tags::normalize_tag;
+use super::types::JobRow;
+
+/// Most job IDs fetched for one search
+const MAX_IDS: usize = 1000;
+/// Most trigram terms in one fallback query
+const MAX_TRIGRAM_TERMS: usize = 64;
+/// Share of trigrams a query word and a job word must have in common (Dice
+/// coefficient) for the fallback to count them as the same word
+const MIN_TRIGRAM_SIMILARITY: f64 = 0.4;
+
+/// Marks the start of a matched run in `highlight()` and `snippet()` output
+const MATCH_START: char = '\u{1}';
+/// Marks the end of a matched run
+const MATCH_END: char = '\u{2}';
+
+/// BM25 column weights for `jobs_fts(title, company, description)`. The
+/// snippet is the best 16-token window of the description. Closed postings
+/// and collapsed cross-source copies are left out before the limit, so a
+/// page is only short when no more jobs match.
+const RANKED_SEARCH_SQL: &str = r#"
+    SELECT rowid,
+           highlight(jobs_fts, 0, char(1), char(2)),
+           snippet(jobs_fts, 2, char(1), char(2), '…', 16)
+    FROM jobs_fts
+    WHERE jobs_fts MATCH ?
+      AND (? IS NULL OR rowid IN (SELECT job_id FROM job_tags WHERE tag = ?))
+      AND (? IS NULL OR rowid NOT IN (SELECT id FROM jobs WHERE ghost_score >= ?))
+      AND rowid IN (SELECT id FROM jobs WHERE status <> 'closed')
+      AND rowid NOT IN (SELECT job_id FROM collapsed_duplicate_jobs)
+    ORDER BY bm25(jobs_fts, 10.0, 5.0, 1.0)
+    LIMIT ?
+"#;
+
+const TRIGRAM_SEARCH_SQL: &str = r#"
+    SELECT rowid, NULL, NULL FROM jobs_trigram
+    WHERE jobs_trigram MATCH ?
+      AND (? IS NULL OR rowid IN (SELECT job_id FROM job_tags WHERE tag = ?))
+      AND (? IS NULL OR rowid NOT IN (SELECT id FROM jobs WHERE ghost_score >= ?))
+      AND rowid IN (SELECT id FROM jobs WHERE status <> 'closed')
+      AND rowid NOT IN (SELECT job_id FROM collapsed_duplicate_jobs)
+    ORDER BY rank
+    LIMIT ?
+"#;
+
+/// A run of matched or unmatched text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSegment {
+    pub text: String,
+    pub matched: bool,
+}
+
+/// Where a search query matched a job. Each field is empty when no query
+/// word matched it, and both are empty for near-spelling matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchMatches {
+    /// The whole title
+    pub title: Vec<TextSegment>,
+    /// A short window of the description around the matches
+    pub description: Vec<TextSegment>,
+}
+
+/// A job found by search, with where the query matched it
+#[derive(Debug, Clone)]
+pub struct JobSearchHit {
+    pub job: Job,
+    pub matches: SearchMatches,
+}
+
+/// A matching rowid with its marked title and description snippet
+type RankedRow = (i64, Option<String>, Option<String>);
+
+impl Database {
+    /// Full-text search on title, company, and description, best match first
+    pub async fn search_jobs(&self, query: &str, limit: i64) -> Result<Vec<Job>, sqlx::Error> {
+        self.search_jobs_tagged(query, None, limit).await
+    }
+
+    /// Full-text search on title, company, and description, limited to jobs
+    /// carrying `tag` when one is given, best match first
+    pub async fn search_jobs_tagged(
+        &self,
+        query: &str,
+        tag: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let hits = self
+            .search_jobs_with_matches(query, tag, None, limit)
+            .await?;
+        Ok(hits.into_iter().map(|hit| hit.job).collect())
+    }
+
+    /// `search_jobs_tagged`, with the matched terms in each job's title and
+    /// description. Jobs with a posting-risk score at or above
+    /// `hide_ghost_score` are left out when it is given.
+    #[tracing::instrument(skip(self))]
+    pub async fn search_jobs_with_matches(
+        &self,
+        query: &str,
+        tag: Option<&str>,
+        hide_ghost_score: Option<f64>,
+        limit: i64,
+    ) -> Result<Vec<JobSearchHit>, sqlx::Error> {
+        let query_chars = query.chars().count();
+        tracing::debug!(query_chars, limit, "Performing full-text search");
+        let words = query_words(query);
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tag = tag.map(normalize_tag).transpose()?;
+
+        let rows = self
+            .ranked_rows(
+                RANKED_SEARCH_SQL,
+                &prefix_match(&words),
+                tag.as_deref(),
+                hide_ghost_score,
+                limit,
+            )
+            .await?;
+        if !rows.is_empty() {
+            return self.hits_in_order(rows).await;
+        }
+
+        let Some(trigram_query) = trigram_match(&words) else {
+            tracing::info!(query_chars, "No jobs found matching search query");
+            return Ok(Vec::new());
+        };
+        // Fetch extra candidates, since loose trigram matches are dropped below.
+        let candidates = limit.saturating_mul(4).min(MAX_IDS as i64);
+        let rows = self
+            .ranked_rows(
+                TRIGRAM_SEARCH_SQL,
+                &trigram_query,
+                tag.as_deref(),
+                hide_ghost_score,
+                candidates,
+            )
+            .await?;
+        let mut hits = self.hits_in_order(rows).await?;
+        hits.retain(|hit| resembles(&words, &hit.job));
+        hits.truncate(usize::try_from(limit).unwrap_or(0));
+        if hits.is_empty() {
+            tracing::info!(query_chars, "No jobs found matching search query");
+        }
+
+        Ok(hits)
+    }
+
+    async fn ranked_rows(
+        &self,
+        sql: &'static str,
+        match_query: &str,
+        tag: Option<&str>,
+        hide_ghost_score: Option<f64>,
+        limit: i64,
+    ) -> Result<Vec<RankedRow>, sqlx::Error> {
+        let rows: Vec<RankedRow> = sqlx::query_as(sql)
+            .bind(match_query)
+            .bind(tag)
+            .bind(tag)
+            .bind(hide_ghost_score)
+            .bind(hide_ghost_score)
+            .bind(limit)
+            .fetch_all(self.pool())
+            .await?;
+
+        // Limit number of IDs to prevent query performance issues
+        if rows.len() > MAX_IDS {
+            return Err(sqlx::Error::Protocol(format!(
+                "Too many job IDs requested: {} (max: {})",
+                rows.len(),
+                MAX_IDS
+            )));
+        }
+
+        Ok(rows)
+    }
+
+    /// Jobs for the ranked rows, in the same order
+    async fn hits_in_order(&self, rows: Vec<RankedRow>) -> Result<Vec<JobSearchHit>, sqlx::Error> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let job_ids: Vec<i64> = rows.iter().map(|(id, _, _)| *id).collect();
+
+        // SAFETY: This is NOT vulnerable to SQL injection. The format! only creates
+        // placeholders ("?"), and actual values are bound using SQLx's parameterization.
+        let placeholders = vec!["?"; job_ids.len()].join(",");
+        let sql = format!("SELECT * FROM jobs WHERE id IN ({})", placeholders);
+
+        let mut query_builder = sqlx::query_as::<_, JobRow>(sqlx::AssertSqlSafe(sql));
+        for id in &job_ids {
+            query_builder = query_builder.bind(*id);
+        }
+
+        let mut jobs: HashMap<i64, Job> = query_builder
+            .fetch_all(self.pool())
+            .await?
+            .into_iter()
+            .map(|row| {
+                let job = Job::from(row);
+                (job.id, job)
+            })
+            .collect();
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, title, description)| {
+                Some(JobSearchHit {
+                    job: jobs.remove(&id)?,
+                    matches: SearchMatches {
+                        title: marked_segments(title.as_deref()),
+                        description: marked_segments(description.as_deref()),
+                    },
+                })
+            })
+            .collect())
+    }
+}
+
+/// Split text marked with `MATCH_START` and `MATCH_END` into runs, or
+/// nothing when no run is marked.
+fn marked_segments(marked: Option<&str>) -> Vec<TextSegment> {
+    let Some(marked) = marked.filter(|text| text.contains(MATCH_START)) else {
+        return Vec::new();
+    };
+    // Markers alternate, so every odd-numbered piece is a match.
+    marked
+        .split([MATCH_START, MATCH_END])
+        .enumerate()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(index, text)| TextSegment {
+            text: text.to_string(),
+            matched: index % 2 == 1,
+        })
+        .collect()
+}
+
+/// Lowercase words of a search query. FTS syntax characters split words, so
+/// any input is a valid query.
+pub(crate) fn query_words(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// FTS5 query matching every word, the last one as a prefix
+pub(crate) fn prefix_match(words: &[String]) -> String {
+    let mut terms: Vec<String> = words.iter().map(|word| format!("\"{word}\"")).collect();
+    if let Some(last) = terms.last_mut() {
+        last.push('*');
+    }
+    terms.join(" ")
+}
+
+/// FTS5 trigram query matching any trigram of the longer words
+fn trigram_match(words: &[String]) -> Option<String> {
+    let mut seen = HashSet::new();
+    let terms: Vec<String> = words
+        .iter()
+        .flat_map(|word| trigrams(word))
+        .filter(|trigram| seen.insert(trigram.clone()))
+        .take(MAX_TRIGRAM_TERMS)
+        .map(|trigram| format!("\"{trigram}\""))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+fn trigrams(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    chars
+        .windows(3)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Whether every query word of three or more characters is close to some
+/// word in the job's title or company
+fn resembles(words: &[String], job: &Job) -> bool {
+    let job_words: Vec<HashSet<String>> = query_words(&format!("{} {}", job.title, job.company))
+        .iter()
+        .map(|word| trigrams(word).into_iter().collect())
+        .collect();
+    words
+        .iter()
+        .map(|word| trigrams(word).into_iter().collect::<HashSet<_>>())
+        .filter(|query_trigrams| !query_trigrams.is_empty())
+        .all(|query_trigrams| {
+            job_words.iter().any(|job_trigrams| {
+                let shared = query_trigrams.intersection(job_trigrams).count();
+                let total = query_trigrams.len() + job_trigrams.len();
+                (2 * shared) as f64 / total as f64 >= MIN_TRIGRAM_SIMILARITY
+            })
+        })
+}
@@ -0,0 +1,84 @@
+//! Job lifecycle status
+//!
+//! Saved postings are active until a liveness check finds their page gone
+//! (closed) or no scrape has seen them for a long time (expired). Closed
+//! postings are left out of recent-job and search queries.
+
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::{Job, JobStatus};
+
+use super::connection::Database;
+use super::types::JobRow;
+
+impl Database {
+    /// Record the outcome of a liveness check. `reason` says why the status
+    /// is what it is, such as `http_410`.
+    pub async fn set_job_status(
+        &self,
+        job_id: i64,
+        status: JobStatus,
+        reason: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE jobs SET status = ?, status_reason = ?, status_checked_at = ? WHERE id = ?",
+        )
+        .bind(status.as_str())
+        .bind(reason)
+        .bind(Utc::now())
+        .bind(job_id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Active jobs whose page has not been checked since `checked_before`.
+    ///
+    /// Jobs with a tracked application come first, then those never checked,
+    /// then the longest unchecked.
+    pub async fn jobs_due_for_liveness_check(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE status = 'active'
+              AND (status_checked_at IS NULL OR status_checked_at < ?)
+            ORDER BY hash IN (SELECT job_hash FROM applications) DESC,
+                     status_checked_at IS NOT NULL,
+                     status_checked_at,
+                     id
+            LIMIT ?
+            "#,
+        )
+        .bind(checked_before)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        Ok(jobs)
+    }
+
+    /// Mark active jobs no scrape has seen since `last_seen_before` as
+    /// expired. Returns how many were marked.
+    pub async fn expire_unseen_jobs(
+        &self,
+        last_seen_before: DateTime<Utc>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE jobs SET status = 'expired', status_reason = 'not_seen'
+            WHERE status = 'active' AND last_seen < ?
+            "#,
+        )
+        .bind(last_seen_before)
+        .execute(self.pool())
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,109 @@
+//! User tags on saved jobs
+//!
+//! Tags are short labels the user attaches to jobs, such as "referral" or
+//! "apply this week". They match regardless of capitalization and can narrow
+//! job lists and search.
+
+use super::connection::Database;
+use super::types::{JobRow, JobTagCount};
+use jobsentinel_domain::Job;
+
+/// Longest tag accepted, in characters.
+const MAX_TAG_CHARS: usize = 40;
+
+/// Trim a tag and collapse inner whitespace, or reject it if it is empty or
+/// too long.
+pub(super) fn normalize_tag(tag: &str) -> Result<String, sqlx::Error> {
+    let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+    if tag.is_empty() {
+        return Err(sqlx::Error::Protocol("Tag cannot be empty".to_string()));
+    }
+    if tag.chars().count() > MAX_TAG_CHARS {
+        return Err(sqlx::Error::Protocol(format!(
+            "Tag is longer than {MAX_TAG_CHARS} characters"
+        )));
+    }
+    Ok(tag)
+}
+
+impl Database {
+    /// Add a tag to a job. Returns false if the job already had it.
+    pub async fn tag_job(&self, job_id: i64, tag: &str) -> Result<bool, sqlx::Error> {
+        let tag = normalize_tag(tag)?;
+        let result = sqlx::query(
+            r#"
+            INSERT INTO job_tags (job_id, tag)
+            SELECT id, ? FROM jobs WHERE id = ?
+            ON CONFLICT(job_id, tag) DO NOTHING
+            "#,
+        )
+        .bind(&tag)
+        .bind(job_id)
+        .execute(self.pool())
+        .await?;
+
+        if result.rows_affected() == 0 && self.get_job_by_id(job_id).await?.is_none() {
+            return Err(sqlx::Error::RowNotFound);
+        }
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Remove a tag from a job. Returns false if the job did not have it.
+    pub async fn untag_job(&self, job_id: i64, tag: &str) -> Result<bool, sqlx::Error> {
+        let tag = normalize_tag(tag)?;
+        let result = sqlx::query("DELETE FROM job_tags WHERE job_id = ? AND tag = ?")
+            .bind(job_id)
+            .bind(&tag)
+            .execute(self.pool())
+            .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Tags on one job, alphabetically
+    pub async fn get_job_tags(&self, job_id: i64) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT tag FROM job_tags WHERE job_id = ? ORDER BY tag")
+            .bind(job_id)
+            .fetch_all(self.pool())
+            .await
+    }
+
+    /// Every tag in use on a visible job, most used first
+    pub async fn list_job_tags(&self) -> Result<Vec<JobTagCount>, sqlx::Error> {
+        sqlx::query_as::<_, JobTagCount>(
+            r#"
+            SELECT t.tag, COUNT(*) AS job_count
+            FROM job_tags t
+            JOIN jobs j ON j.id = t.job_id
+            WHERE j.hidden = 0
+            GROUP BY t.tag
+            ORDER BY job_count DESC, t.tag
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await
+    }
+
+    /// Visible jobs carrying `tag`, best match first
+    pub async fn get_jobs_by_tag(&self, tag: &str, limit: i64) -> Result<Vec<Job>, sqlx::Error> {
+        let tag = normalize_tag(tag)?;
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE hidden = 0
+              AND id IN (SELECT job_id FROM job_tags WHERE tag = ?)
+            ORDER BY score DESC, created_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(&tag)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        Ok(jobs)
+    }
+}
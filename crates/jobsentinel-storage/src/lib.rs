@@ -18,14 +18,38 @@ pub mod user_data;
 
 // Internal modules
 mod analytics;
+mod company_reputation;
 mod connection;
 mod credentials;
+mod cross_source_duplicates;
 mod crud;
+mod email_digests;
 mod encryption;
+mod exchange_rates;
+mod geocode_cache;
 mod ghost;
+mod ghost_reanalysis;
 mod interactions;
+mod job_feedback;
+mod job_filter;
+mod job_pages;
+mod job_refresh;
+mod job_search;
+mod job_status;
+mod job_tags;
+mod near_duplicates;
+mod notification_outbox;
 mod queries;
+mod repost_chains;
+mod rescoring;
+mod retention;
+mod scraping_pause;
+mod setup_import;
+mod source_failures;
+mod time_to_fill;
 mod types;
+mod vacation_mode;
+mod weekly_reports;
 
 // Tests
 #[cfg(test)]
@@ -34,13 +58,27 @@ pub(crate) mod test_support;
 mod tests;
 
 // Re-export public types
-pub use types::{DuplicateGroup, GhostStatistics, Statistics};
+pub use types::{
+    BackupKind, DatabaseBackup, DatabaseHealth, DatabaseImportSummary, DatabaseMaintenance,
+    DuplicateGroup, GhostReanalysisRun, GhostStatistics, IndexUsage, JobFeedback,
+    JobFeedbackExample, JobFieldChange, JobRefreshRecord, JobTagCount, JobUpsert, MaintenanceStep,
+    NearDuplicateGroup, RescoredJob, Statistics, TimeToFillEstimate, TimeToFillScope,
+    VacationHighlight, VacationMode, VacationSummary,
+};
 
 // Re-export Database struct
+pub use company_reputation::CompanyReputation;
 pub use connection::Database;
 pub use credentials::{
     CredentialKeyWrapRecord, CredentialRepository, CredentialSecretRecord, CredentialStorageError,
 };
+pub use job_filter::{JobFilter, JobSort};
+pub use job_pages::{JobCursor, JobPage};
+pub use job_search::{JobSearchHit, SearchMatches, TextSegment};
+pub use notification_outbox::{NotificationHistoryEntry, PendingNotification};
+pub use retention::{PurgeReport, PurgedJob, RetentionPolicy};
+pub use source_failures::SourceFailure;
+pub use weekly_reports::{ApplicationActivity, WeeklyReportRecord};
 
 /// Stable, non-sensitive classification for storage errors used by callers.
 pub fn database_error_kind(error: &sqlx::Error) -> &'static str {
@@ -73,6 +111,11 @@ impl Database {
         automation::AutomationManager::new(self.pool().clone())
     }
 
+    #[must_use]
+    pub fn automation_permission_manager(&self) -> automation::AutomationPermissionManager {
+        automation::AutomationPermissionManager::new(self.pool().clone())
+    }
+
     #[must_use]
     pub fn profile_manager(&self) -> automation::ProfileManager {
         automation::ProfileManager::new(self.pool().clone())
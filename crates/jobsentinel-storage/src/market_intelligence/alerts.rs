@@ -44,8 +44,8 @@ impl AlertType {
     }
 }
 
-/// Alert severity levels
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Alert severity levels, from least to most severe
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AlertSeverity {
     Info,
     Warning,
@@ -204,6 +204,66 @@ pub(super) async fn get_unread_alerts(db: &SqlitePool) -> Result<Vec<MarketAlert
     rows.iter().map(row_to_alert).collect()
 }
 
+/// Get market alerts raised since `since`, read or not
+pub(super) async fn get_alerts_since(
+    db: &SqlitePool,
+    since: DateTime<Utc>,
+) -> Result<Vec<MarketAlert>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            id, alert_type, title, description, severity,
+            related_entity, related_entity_type,
+            metric_value, metric_change_pct, is_read, created_at
+        FROM market_alerts
+        WHERE datetime(created_at) >= datetime(?)
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(since)
+    .fetch_all(db)
+    .await?;
+
+    rows.iter().map(row_to_alert).collect()
+}
+
+/// Get the oldest market alerts not yet sent through notification channels
+pub(super) async fn get_unnotified_alerts(db: &SqlitePool, limit: i64) -> Result<Vec<MarketAlert>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            id, alert_type, title, description, severity,
+            related_entity, related_entity_type,
+            metric_value, metric_change_pct, is_read, created_at
+        FROM market_alerts
+        WHERE notified_at IS NULL
+        ORDER BY created_at ASC, id ASC
+        LIMIT ?
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    rows.iter().map(row_to_alert).collect()
+}
+
+/// Record that an alert was handled by notification delivery, whether or not
+/// any channel sent it
+pub(super) async fn mark_alert_notified(
+    db: &SqlitePool,
+    id: i64,
+    notified_at: DateTime<Utc>,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE market_alerts SET notified_at = ? WHERE id = ?")
+        .bind(notified_at)
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 /// Mark all alerts as read
 pub(super) async fn mark_all_read(db: &SqlitePool) -> Result<u64> {
     let result = sqlx::query("UPDATE market_alerts SET is_read = 1 WHERE is_read = 0")
@@ -223,6 +283,17 @@ pub(super) async fn mark_alert_read(db: &SqlitePool, id: i64) -> Result<bool> {
     Ok(result.rows_affected() > 0)
 }
 
+/// Delete an alert the user dismissed, so it no longer shows in the app or
+/// in weekly reports
+pub(super) async fn dismiss_alert(db: &SqlitePool, id: i64) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM market_alerts WHERE id = ?")
+        .bind(id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 #[cfg(test)]
 #[path = "alerts_tests.rs"]
 mod tests;
@@ -293,7 +293,8 @@ mod async_tests {
                 metric_value REAL,
                 metric_change_pct REAL,
                 created_at TEXT DEFAULT CURRENT_TIMESTAMP,
-                is_read INTEGER DEFAULT 0
+                is_read INTEGER DEFAULT 0,
+                notified_at TEXT
             )
             "#,
         )
@@ -385,6 +386,55 @@ mod async_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_dismiss_alert_removes_it() {
+        let pool = setup_test_db().await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO market_alerts (alert_type, title, description, severity)
+            VALUES ('hiring_spree', 'Dismissed Alert', 'Desc', 'info')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(dismiss_alert(&pool, 1).await.unwrap());
+        assert!(get_unread_alerts(&pool).await.unwrap().is_empty());
+        assert!(load_all_alerts(&pool, 10).await.is_empty());
+        assert!(!dismiss_alert(&pool, 1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unnotified_alerts_are_listed_oldest_first_until_marked() {
+        let pool = setup_test_db().await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO market_alerts (alert_type, title, description, severity, created_at)
+            VALUES
+                ('skill_surge', 'Newer', 'Desc', 'info', '2026-10-15 09:00:00'),
+                ('hiring_spree', 'Older', 'Desc', 'warning', '2026-10-14 09:00:00')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let pending = get_unnotified_alerts(&pool, 10).await.unwrap();
+        let titles: Vec<&str> = pending.iter().map(|alert| alert.title.as_str()).collect();
+        assert_eq!(titles, ["Older", "Newer"]);
+
+        assert!(mark_alert_notified(&pool, pending[0].id, Utc::now())
+            .await
+            .unwrap());
+        let pending = get_unnotified_alerts(&pool, 10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].title, "Newer");
+        assert!(AlertSeverity::Warning > AlertSeverity::Info);
+    }
+
     #[tokio::test]
     async fn test_mark_all_read_empty() {
         let pool = setup_test_db().await;
@@ -1,131 +1,97 @@
 use anyhow::Result;
 use chrono::Utc;
-use sqlx::Row;
 
 use crate::market_intelligence::MarketIntelligence;
 
 impl MarketIntelligence {
     /// Compute company hiring velocity
     ///
-    /// OPTIMIZATION: Reduced 4 queries per company to 1 query per company.
-    /// Batches job counts, observed count, filled count, and top role into single query.
+    /// One set-based upsert covers every company: posting counts, top role
+    /// and location, and the trend against the latest earlier row from the
+    /// past week. Ties for top role or location go to the first
+    /// alphabetically.
     pub(in crate::market_intelligence) async fn compute_company_hiring_velocity(
         &self,
     ) -> Result<()> {
         let today = Utc::now().date_naive();
 
-        // Get companies and their job posting counts
-        let companies = sqlx::query(
+        sqlx::query(
             r#"
-            SELECT DISTINCT company
-            FROM jobs
-            WHERE company IS NOT NULL AND company != ''
-            LIMIT 10000
-            "#,
-        )
-        .fetch_all(&self.db)
-        .await?;
-
-        for company_record in companies {
-            let company: String = company_record.try_get("company")?;
-
-            // Batch all company stats into single query
-            let stats = sqlx::query(
-                r#"
-                SELECT
-                    SUM(CASE WHEN DATE(created_at) = DATE('now') THEN 1 ELSE 0 END) as jobs_posted,
-                    COUNT(*) as jobs_active,
-                    0 as jobs_filled,
-                    (
-                        SELECT title
-                        FROM jobs
-                        WHERE company = ?
-                        GROUP BY title
-                        ORDER BY COUNT(*) DESC
-                        LIMIT 1
-                    ) as top_role,
-                    (
-                        SELECT location
-                        FROM jobs
-                        WHERE company = ?
-                        GROUP BY location
-                        ORDER BY COUNT(*) DESC
-                        LIMIT 1
-                    ) as top_location
+            WITH company_jobs AS (
+                SELECT company, title, location, created_at
                 FROM jobs
-                WHERE company = ?
-                "#,
-            )
-            .bind(&company)
-            .bind(&company)
-            .bind(&company)
-            .fetch_one(&self.db)
-            .await?;
-
-            let jobs_posted: i64 = stats.try_get("jobs_posted")?;
-            let jobs_active: i64 = stats.try_get("jobs_active")?;
-            let jobs_filled: i64 = stats.try_get("jobs_filled")?;
-            let top_role: Option<String> = stats.try_get("top_role").ok();
-            let top_location: Option<String> = stats.try_get("top_location").ok();
-
-            // Determine hiring trend (compare to previous week)
-            let prev_week_velocity = sqlx::query_scalar::<_, Option<i64>>(
-                r#"
-                SELECT jobs_posted_count
+                WHERE company IS NOT NULL AND company != ''
+            ),
+            stats AS (
+                SELECT
+                    company,
+                    SUM(CASE WHEN DATE(created_at) = ?1 THEN 1 ELSE 0 END) as jobs_posted,
+                    COUNT(*) as jobs_active
+                FROM company_jobs
+                GROUP BY company
+            ),
+            roles AS (
+                SELECT
+                    company,
+                    title,
+                    ROW_NUMBER() OVER (PARTITION BY company ORDER BY COUNT(*) DESC, title) as rank
+                FROM company_jobs
+                GROUP BY company, title
+            ),
+            locations AS (
+                SELECT
+                    company,
+                    location,
+                    ROW_NUMBER() OVER (PARTITION BY company ORDER BY COUNT(*) DESC, location) as rank
+                FROM company_jobs
+                GROUP BY company, location
+            ),
+            previous AS (
+                SELECT
+                    company_name,
+                    jobs_posted_count,
+                    ROW_NUMBER() OVER (PARTITION BY company_name ORDER BY date DESC) as rank
                 FROM company_hiring_velocity
-                WHERE company_name = ?
-                  AND date >= date('now', '-7 days')
-                ORDER BY date DESC
-                LIMIT 1
-                "#,
+                WHERE date >= date(?1, '-7 days') AND date < ?1
             )
-            .bind(&company)
-            .fetch_one(&self.db)
-            .await
-            .unwrap_or(None);
-
-            let hiring_trend = if let Some(prev) = prev_week_velocity {
-                if jobs_posted > prev {
-                    "increasing"
-                } else if jobs_posted < prev {
-                    "decreasing"
-                } else {
-                    "stable"
-                }
-            } else {
-                "stable"
-            };
-
-            sqlx::query(
-                r#"
-                INSERT INTO company_hiring_velocity (
-                    company_name, date, jobs_posted_count, jobs_filled_count,
-                    jobs_active_count, top_role, top_location,
-                    is_actively_hiring, hiring_trend
-                )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-                ON CONFLICT(company_name, date) DO UPDATE SET
-                    jobs_posted_count = excluded.jobs_posted_count,
-                    jobs_filled_count = excluded.jobs_filled_count,
-                    jobs_active_count = excluded.jobs_active_count,
-                    top_role = excluded.top_role,
-                    top_location = excluded.top_location,
-                    is_actively_hiring = excluded.is_actively_hiring,
-                    hiring_trend = excluded.hiring_trend
-                "#,
+            INSERT INTO company_hiring_velocity (
+                company_name, date, jobs_posted_count, jobs_filled_count,
+                jobs_active_count, top_role, top_location,
+                is_actively_hiring, hiring_trend
             )
-            .bind(&company)
-            .bind(today.to_string())
-            .bind(jobs_posted)
-            .bind(jobs_filled)
-            .bind(jobs_active)
-            .bind(&top_role)
-            .bind(&top_location)
-            .bind((jobs_active > 0) as i32)
-            .bind(hiring_trend)
-            .execute(&self.db)
-            .await?;
-        }
+            SELECT
+                s.company,
+                ?1,
+                s.jobs_posted,
+                0,
+                s.jobs_active,
+                r.title,
+                l.location,
+                s.jobs_active > 0,
+                CASE
+                    WHEN p.jobs_posted_count IS NULL THEN 'stable'
+                    WHEN s.jobs_posted > p.jobs_posted_count THEN 'increasing'
+                    WHEN s.jobs_posted < p.jobs_posted_count THEN 'decreasing'
+                    ELSE 'stable'
+                END
+            FROM stats s
+            LEFT JOIN roles r ON r.company = s.company AND r.rank = 1
+            LEFT JOIN locations l ON l.company = s.company AND l.rank = 1
+            LEFT JOIN previous p ON p.company_name = s.company AND p.rank = 1
+            WHERE true
+            ON CONFLICT(company_name, date) DO UPDATE SET
+                jobs_posted_count = excluded.jobs_posted_count,
+                jobs_filled_count = excluded.jobs_filled_count,
+                jobs_active_count = excluded.jobs_active_count,
+                top_role = excluded.top_role,
+                top_location = excluded.top_location,
+                is_actively_hiring = excluded.is_actively_hiring,
+                hiring_trend = excluded.hiring_trend
+            "#,
+        )
+        .bind(today.to_string())
+        .execute(&self.db)
+        .await?;
 
         Ok(())
     }
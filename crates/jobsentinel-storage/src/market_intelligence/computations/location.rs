@@ -3,14 +3,18 @@ use chrono::Utc;
 use sqlx::Row;
 
 use crate::analytics_buckets::market_location_bucket;
-use crate::market_intelligence::{statistics::predicted_salary_summary, MarketIntelligence};
+use crate::market_intelligence::MarketIntelligence;
 
 impl MarketIntelligence {
     /// Compute location job density
+    ///
+    /// Raw locations are mapped to market buckets in Rust, then passed to a
+    /// single set-based upsert as JSON. Each bucket takes its city and state
+    /// from its most common raw location. Ties for top skill, company, or
+    /// role go to the first alphabetically.
     pub(in crate::market_intelligence) async fn compute_location_job_density(&self) -> Result<()> {
         let today = Utc::now().date_naive();
 
-        // Get all unique locations
         let locations = sqlx::query(
             r#"
             SELECT DISTINCT location
@@ -20,129 +24,147 @@ impl MarketIntelligence {
         )
         .fetch_all(&self.db)
         .await?;
+        if locations.is_empty() {
+            return Ok(());
+        }
 
+        let mut buckets = Vec::with_capacity(locations.len());
         for location_record in locations {
             let location: String = location_record.try_get("location")?;
-            let normalized = market_location_bucket(&location);
-
-            // Parse city, state from location
             let (city, state) = self.parse_location(&location);
+            buckets.push(serde_json::json!({
+                "location": location,
+                "bucket": market_location_bucket(&location),
+                "city": city,
+                "state": state,
+            }));
+        }
 
-            // Job count for this location
-            let job_count =
-                sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM jobs WHERE location = ?")
-                    .bind(&location)
-                    .fetch_one(&self.db)
-                    .await?;
-
-            // Remote job count
-            let remote_count = sqlx::query_scalar::<_, i64>(
-                r#"
-                SELECT COUNT(*)
-                FROM jobs
-                WHERE location = ?
-                  AND (LOWER(location) LIKE '%remote%' OR LOWER(title) LIKE '%remote%')
-                "#,
-            )
-            .bind(&location)
-            .fetch_one(&self.db)
-            .await?;
-
-            // Salary stats - fetch all and compute in Rust
-            let salary_rows = sqlx::query(
-                r#"
-                SELECT jsp.predicted_median
+        sqlx::query(
+            r#"
+            WITH location_buckets AS (
+                SELECT
+                    json_extract(value, '$.location') as location,
+                    json_extract(value, '$.bucket') as bucket,
+                    json_extract(value, '$.city') as city,
+                    json_extract(value, '$.state') as state
+                FROM json_each(?2)
+            ),
+            bucket_jobs AS (
+                SELECT j.hash, j.title, j.company, j.location, lb.bucket
                 FROM jobs j
-                LEFT JOIN job_salary_predictions jsp ON j.hash = jsp.job_hash
-                WHERE j.location = ?
-                "#,
+                JOIN location_buckets lb ON lb.location = j.location
+            ),
+            counts AS (
+                SELECT
+                    bucket,
+                    COUNT(*) as job_count,
+                    SUM(
+                        CASE
+                            WHEN LOWER(location) LIKE '%remote%' OR LOWER(title) LIKE '%remote%'
+                            THEN 1 ELSE 0
+                        END
+                    ) as remote_job_count
+                FROM bucket_jobs
+                GROUP BY bucket
+            ),
+            salaries AS (
+                SELECT
+                    bj.bucket,
+                    jsp.predicted_median as salary,
+                    ROW_NUMBER() OVER (PARTITION BY bj.bucket ORDER BY jsp.predicted_median) as rn,
+                    COUNT(*) OVER (PARTITION BY bj.bucket) as n
+                FROM bucket_jobs bj
+                JOIN job_salary_predictions jsp ON jsp.job_hash = bj.hash
+                WHERE jsp.predicted_median IS NOT NULL
+            ),
+            salary_stats AS (
+                SELECT
+                    bucket,
+                    AVG(salary) as avg_salary,
+                    AVG(CASE WHEN rn IN ((n + 1) / 2, (n + 2) / 2) THEN salary END) as median_salary
+                FROM salaries
+                GROUP BY bucket
+            ),
+            places AS (
+                SELECT
+                    lb.bucket,
+                    lb.city,
+                    lb.state,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY lb.bucket ORDER BY COUNT(*) DESC, lb.location
+                    ) as rank
+                FROM bucket_jobs bj
+                JOIN location_buckets lb ON lb.location = bj.location
+                GROUP BY lb.bucket, lb.location
+            ),
+            skills AS (
+                SELECT
+                    bj.bucket,
+                    js.skill_name,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY bj.bucket ORDER BY COUNT(*) DESC, js.skill_name
+                    ) as rank
+                FROM bucket_jobs bj
+                JOIN job_skills js ON js.job_hash = bj.hash
+                GROUP BY bj.bucket, js.skill_name
+            ),
+            companies AS (
+                SELECT
+                    bucket,
+                    company,
+                    ROW_NUMBER() OVER (PARTITION BY bucket ORDER BY COUNT(*) DESC, company) as rank
+                FROM bucket_jobs
+                GROUP BY bucket, company
+            ),
+            roles AS (
+                SELECT
+                    bucket,
+                    title,
+                    ROW_NUMBER() OVER (PARTITION BY bucket ORDER BY COUNT(*) DESC, title) as rank
+                FROM bucket_jobs
+                GROUP BY bucket, title
             )
-            .bind(&location)
-            .fetch_all(&self.db)
-            .await?;
-
-            let salaries = predicted_salary_summary(&salary_rows);
-            let avg_salary = salaries.average;
-            let median_salary = salaries.median;
-
-            // Top skill
-            let top_skill = sqlx::query_scalar::<_, Option<String>>(
-                r#"
-                SELECT js.skill_name
-                FROM job_skills js
-                JOIN jobs j ON js.job_hash = j.hash
-                WHERE j.location = ?
-                GROUP BY js.skill_name
-                ORDER BY COUNT(*) DESC
-                LIMIT 1
-                "#,
+            INSERT INTO location_job_density (
+                location_normalized, city, state, date,
+                job_count, remote_job_count, avg_salary, median_salary,
+                top_skill, top_company, top_role
             )
-            .bind(&location)
-            .fetch_one(&self.db)
-            .await?;
-
-            // Top company
-            let top_company = sqlx::query_scalar::<_, Option<String>>(
-                r#"
-                SELECT company
-                FROM jobs
-                WHERE location = ?
-                GROUP BY company
-                ORDER BY COUNT(*) DESC
-                LIMIT 1
-                "#,
-            )
-            .bind(&location)
-            .fetch_one(&self.db)
-            .await?;
-
-            // Top role
-            let top_role = sqlx::query_scalar::<_, Option<String>>(
-                r#"
-                SELECT title
-                FROM jobs
-                WHERE location = ?
-                GROUP BY title
-                ORDER BY COUNT(*) DESC
-                LIMIT 1
-                "#,
-            )
-            .bind(&location)
-            .fetch_one(&self.db)
-            .await?;
-
-            sqlx::query(
-                r#"
-                INSERT INTO location_job_density (
-                    location_normalized, city, state, date,
-                    job_count, remote_job_count, avg_salary, median_salary,
-                    top_skill, top_company, top_role
-                )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                ON CONFLICT(location_normalized, date) DO UPDATE SET
-                    job_count = excluded.job_count,
-                    remote_job_count = excluded.remote_job_count,
-                    avg_salary = excluded.avg_salary,
-                    median_salary = excluded.median_salary,
-                    top_skill = excluded.top_skill,
-                    top_company = excluded.top_company,
-                    top_role = excluded.top_role
-                "#,
-            )
-            .bind(&normalized)
-            .bind(&city)
-            .bind(&state)
-            .bind(today.to_string())
-            .bind(job_count)
-            .bind(remote_count)
-            .bind(avg_salary.map(|v| v as i64))
-            .bind(median_salary.map(|v| v as i64))
-            .bind(&top_skill)
-            .bind(&top_company)
-            .bind(&top_role)
-            .execute(&self.db)
-            .await?;
-        }
+            SELECT
+                c.bucket,
+                p.city,
+                p.state,
+                ?1,
+                c.job_count,
+                c.remote_job_count,
+                CAST(ss.avg_salary AS INTEGER),
+                CAST(ss.median_salary AS INTEGER),
+                s.skill_name,
+                co.company,
+                r.title
+            FROM counts c
+            LEFT JOIN places p ON p.bucket = c.bucket AND p.rank = 1
+            LEFT JOIN salary_stats ss ON ss.bucket = c.bucket
+            LEFT JOIN skills s ON s.bucket = c.bucket AND s.rank = 1
+            LEFT JOIN companies co ON co.bucket = c.bucket AND co.rank = 1
+            LEFT JOIN roles r ON r.bucket = c.bucket AND r.rank = 1
+            WHERE true
+            ON CONFLICT(location_normalized, date) DO UPDATE SET
+                city = excluded.city,
+                state = excluded.state,
+                job_count = excluded.job_count,
+                remote_job_count = excluded.remote_job_count,
+                avg_salary = excluded.avg_salary,
+                median_salary = excluded.median_salary,
+                top_skill = excluded.top_skill,
+                top_company = excluded.top_company,
+                top_role = excluded.top_role
+            "#,
+        )
+        .bind(today.to_string())
+        .bind(serde_json::Value::Array(buckets).to_string())
+        .execute(&self.db)
+        .await?;
 
         Ok(())
     }
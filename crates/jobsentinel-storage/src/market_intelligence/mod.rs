@@ -4,6 +4,7 @@
 //! salary movements, company hiring velocity, and geographic distribution.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use sqlx::SqlitePool;
 
 mod alerts;
@@ -11,6 +12,7 @@ mod analytics;
 
 mod computations;
 mod queries;
+mod series;
 mod statistics;
 mod utils;
 
@@ -21,7 +23,8 @@ use analytics::MarketAnalyzer;
 
 pub use alerts::{AlertSeverity, AlertType, EntityType, MarketAlert};
 pub use analytics::MarketSnapshot;
-pub use queries::{CompanyActivity, LocationHeat, SkillTrend};
+pub use queries::{CompanyActivity, LocationHeat, SkillGap, SkillTrend};
+pub use series::{SeriesGranularity, SeriesPoint};
 
 /// Market intelligence manager
 pub struct MarketIntelligence {
@@ -60,6 +63,21 @@ impl MarketIntelligence {
         alerts::get_unread_alerts(&self.db).await
     }
 
+    /// Get market alerts raised since `since`, read or not
+    pub async fn get_alerts_since(&self, since: DateTime<Utc>) -> Result<Vec<MarketAlert>> {
+        alerts::get_alerts_since(&self.db, since).await
+    }
+
+    /// Get the oldest alerts not yet sent through notification channels
+    pub async fn get_unnotified_alerts(&self, limit: i64) -> Result<Vec<MarketAlert>> {
+        alerts::get_unnotified_alerts(&self.db, limit).await
+    }
+
+    /// Record that an alert went through notification delivery
+    pub async fn mark_alert_notified(&self, id: i64, notified_at: DateTime<Utc>) -> Result<bool> {
+        alerts::mark_alert_notified(&self.db, id, notified_at).await
+    }
+
     /// Get market snapshot (latest)
     pub async fn get_market_snapshot(&self) -> Result<Option<MarketSnapshot>> {
         self.analyzer.get_latest_snapshot().await
@@ -79,4 +97,9 @@ impl MarketIntelligence {
     pub async fn mark_all_alerts_read(&self) -> Result<u64> {
         alerts::mark_all_read(&self.db).await
     }
+
+    /// Dismiss an alert for good
+    pub async fn dismiss_alert(&self, id: i64) -> Result<bool> {
+        alerts::dismiss_alert(&self.db, id).await
+    }
 }
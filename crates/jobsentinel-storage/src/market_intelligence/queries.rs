@@ -16,6 +16,17 @@ pub struct SkillTrend {
     pub trend_direction: String, // "up" | "down" | "flat"
 }
 
+/// A skill in demand that the active resume does not list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillGap {
+    pub skill_name: String,
+    pub total_jobs: i64,
+    pub avg_salary: Option<i64>,
+    /// `avg_salary` minus the average for jobs asking for skills the resume
+    /// already lists; `None` when either side has no salary data
+    pub salary_delta: Option<i64>,
+}
+
 /// Company activity data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyActivity {
@@ -106,6 +117,78 @@ impl MarketIntelligence {
             .collect())
     }
 
+    /// Skills in demand over the last 30 days that the active resume lacks,
+    /// most in demand first
+    ///
+    /// Skill names are compared ignoring case and surrounding spaces. Without
+    /// an active resume there is nothing to compare, so the list is empty.
+    pub async fn get_personal_skill_gaps(&self, limit: usize) -> Result<Vec<SkillGap>> {
+        let rows = sqlx::query(
+            r#"
+            WITH active_resume AS (
+                SELECT id
+                FROM resumes
+                WHERE is_active = 1
+                ORDER BY created_at DESC
+                LIMIT 1
+            ),
+            owned AS (
+                SELECT DISTINCT LOWER(TRIM(skill_name)) as skill_key
+                FROM user_skills
+                WHERE resume_id = (SELECT id FROM active_resume)
+            ),
+            demand AS (
+                SELECT
+                    LOWER(TRIM(skill_name)) as skill_key,
+                    MIN(skill_name) as skill_name,
+                    SUM(job_count) as total_jobs,
+                    AVG(avg_salary) as avg_salary
+                FROM skill_demand_trends
+                WHERE date >= date('now', '-30 days')
+                GROUP BY LOWER(TRIM(skill_name))
+            ),
+            baseline AS (
+                SELECT SUM(d.avg_salary * d.total_jobs) / SUM(d.total_jobs) as avg_salary
+                FROM demand d
+                JOIN owned o ON o.skill_key = d.skill_key
+                WHERE d.avg_salary IS NOT NULL AND d.total_jobs > 0
+            )
+            SELECT
+                d.skill_name,
+                d.total_jobs,
+                d.avg_salary,
+                d.avg_salary - b.avg_salary as salary_delta
+            FROM demand d
+            CROSS JOIN baseline b
+            WHERE EXISTS (SELECT 1 FROM active_resume)
+              AND d.skill_key NOT IN (SELECT skill_key FROM owned)
+            ORDER BY d.total_jobs DESC, d.skill_name ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit as i64)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SkillGap {
+                skill_name: r.try_get("skill_name").unwrap_or_default(),
+                total_jobs: r.try_get("total_jobs").unwrap_or(0),
+                avg_salary: r
+                    .try_get::<Option<f64>, _>("avg_salary")
+                    .ok()
+                    .flatten()
+                    .map(|v| v as i64),
+                salary_delta: r
+                    .try_get::<Option<f64>, _>("salary_delta")
+                    .ok()
+                    .flatten()
+                    .map(|v| v.round() as i64),
+            })
+            .collect())
+    }
+
     /// Get most active companies with salary and growth rate
     pub async fn get_most_active_companies(&self, limit: usize) -> Result<Vec<CompanyActivity>> {
         let rows = sqlx::query(
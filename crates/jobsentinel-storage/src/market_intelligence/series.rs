@@ -0,0 +1,172 @@
+//! Time-bucketed market series for trend charts
+//!
+//! Each series groups the daily trend rows written by the daily analysis into
+//! days, weeks starting on Monday, or calendar months. Buckets with no data
+//! are left out rather than reported as zero.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+
+use super::statistics::median;
+use super::MarketIntelligence;
+
+/// Size of each bucket in a market series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeriesGranularity {
+    Day,
+    Week,
+    Month,
+}
+
+impl SeriesGranularity {
+    /// SQLite expression for the first day of the bucket holding `date`
+    fn bucket_sql(self) -> &'static str {
+        match self {
+            Self::Day => "date(date)",
+            Self::Week => "date(date, 'weekday 0', '-6 days')",
+            Self::Month => "date(date, 'start of month')",
+        }
+    }
+}
+
+/// One bucket of a market series
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesPoint {
+    /// First day of the bucket
+    pub period_start: NaiveDate,
+    pub value: i64,
+}
+
+fn parse_period_start(row: &sqlx::sqlite::SqliteRow) -> Result<NaiveDate> {
+    let period_start: String = row.try_get("period_start")?;
+    Ok(NaiveDate::parse_from_str(&period_start, "%Y-%m-%d")?)
+}
+
+impl MarketIntelligence {
+    /// Skill mentions per bucket between two dates, inclusive
+    ///
+    /// With no skill name, mentions of every skill are added together.
+    pub async fn get_skill_mention_series(
+        &self,
+        skill_name: Option<&str>,
+        start: NaiveDate,
+        end: NaiveDate,
+        granularity: SeriesGranularity,
+    ) -> Result<Vec<SeriesPoint>> {
+        let sql = format!(
+            r#"
+            SELECT {bucket} as period_start, SUM(mention_count) as value
+            FROM skill_demand_trends
+            WHERE date >= ? AND date <= ?
+              AND (? IS NULL OR LOWER(TRIM(skill_name)) = LOWER(TRIM(?)))
+            GROUP BY period_start
+            ORDER BY period_start
+            "#,
+            bucket = granularity.bucket_sql()
+        );
+
+        self.sum_series(sql, skill_name, start, end).await
+    }
+
+    /// Jobs posted per bucket between two dates, inclusive
+    ///
+    /// With no company name, postings from every company are added together.
+    pub async fn get_company_posting_series(
+        &self,
+        company_name: Option<&str>,
+        start: NaiveDate,
+        end: NaiveDate,
+        granularity: SeriesGranularity,
+    ) -> Result<Vec<SeriesPoint>> {
+        let sql = format!(
+            r#"
+            SELECT {bucket} as period_start, SUM(jobs_posted_count) as value
+            FROM company_hiring_velocity
+            WHERE date >= ? AND date <= ?
+              AND (? IS NULL OR LOWER(TRIM(company_name)) = LOWER(TRIM(?)))
+            GROUP BY period_start
+            ORDER BY period_start
+            "#,
+            bucket = granularity.bucket_sql()
+        );
+
+        self.sum_series(sql, company_name, start, end).await
+    }
+
+    /// Median of the daily market median salaries in each bucket between two
+    /// dates, inclusive
+    pub async fn get_median_salary_series(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        granularity: SeriesGranularity,
+    ) -> Result<Vec<SeriesPoint>> {
+        let sql = format!(
+            r#"
+            SELECT {bucket} as period_start, median_salary
+            FROM market_snapshots
+            WHERE date >= ? AND date <= ?
+              AND median_salary IS NOT NULL
+            "#,
+            bucket = granularity.bucket_sql()
+        );
+        let rows = sqlx::query(sqlx::AssertSqlSafe(sql))
+            .bind(start.to_string())
+            .bind(end.to_string())
+            .fetch_all(&self.db)
+            .await?;
+
+        let mut buckets: BTreeMap<NaiveDate, Vec<f64>> = BTreeMap::new();
+        for row in &rows {
+            let median_salary: i64 = row.try_get("median_salary")?;
+            buckets
+                .entry(parse_period_start(row)?)
+                .or_default()
+                .push(median_salary as f64);
+        }
+
+        Ok(buckets
+            .into_iter()
+            .filter_map(|(period_start, mut salaries)| {
+                median(&mut salaries).map(|value| SeriesPoint {
+                    period_start,
+                    value: value.round() as i64,
+                })
+            })
+            .collect())
+    }
+
+    async fn sum_series(
+        &self,
+        sql: String,
+        name: Option<&str>,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<SeriesPoint>> {
+        let rows = sqlx::query(sqlx::AssertSqlSafe(sql))
+            .bind(start.to_string())
+            .bind(end.to_string())
+            .bind(name)
+            .bind(name)
+            .fetch_all(&self.db)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(SeriesPoint {
+                    period_start: parse_period_start(row)?,
+                    value: row.try_get("value")?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "series_tests.rs"]
+mod tests;
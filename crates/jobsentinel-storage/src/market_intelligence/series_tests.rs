@@ -0,0 +1,114 @@
+use super::*;
+use crate::test_support::migrated_pool;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+}
+
+fn point(period_start: NaiveDate, value: i64) -> SeriesPoint {
+    SeriesPoint {
+        period_start,
+        value,
+    }
+}
+
+#[tokio::test]
+async fn test_skill_mention_series_groups_weeks_from_monday() {
+    let pool = migrated_pool().await;
+    sqlx::query(
+        r#"
+        INSERT INTO skill_demand_trends (skill_name, date, mention_count, job_count)
+        VALUES
+            ('Case Management', '2026-09-30', 1, 1),
+            ('Case Management', '2026-10-05', 2, 1),
+            ('Case Management', '2026-10-11', 3, 1),
+            ('Case Management', '2026-10-12', 4, 1),
+            ('Customer Support', '2026-10-12', 10, 5)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let mi = MarketIntelligence::new(pool);
+
+    let weekly = mi
+        .get_skill_mention_series(
+            Some(" case management "),
+            date(10, 1),
+            date(10, 31),
+            SeriesGranularity::Week,
+        )
+        .await
+        .unwrap();
+    assert_eq!(weekly, vec![point(date(10, 5), 5), point(date(10, 12), 4)]);
+
+    let monthly = mi
+        .get_skill_mention_series(None, date(9, 1), date(10, 31), SeriesGranularity::Month)
+        .await
+        .unwrap();
+    assert_eq!(monthly, vec![point(date(9, 1), 1), point(date(10, 1), 19)]);
+}
+
+#[tokio::test]
+async fn test_company_posting_series_by_day() {
+    let pool = migrated_pool().await;
+    sqlx::query(
+        r#"
+        INSERT INTO company_hiring_velocity (company_name, date, jobs_posted_count)
+        VALUES
+            ('CareBridge Health', '2026-10-05', 3),
+            ('CareBridge Health', '2026-10-06', 2),
+            ('Northstar Logistics', '2026-10-06', 7)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let mi = MarketIntelligence::new(pool);
+
+    let series = mi
+        .get_company_posting_series(
+            Some("CareBridge Health"),
+            date(10, 1),
+            date(10, 31),
+            SeriesGranularity::Day,
+        )
+        .await
+        .unwrap();
+    assert_eq!(series, vec![point(date(10, 5), 3), point(date(10, 6), 2)]);
+
+    let all = mi
+        .get_company_posting_series(None, date(10, 6), date(10, 6), SeriesGranularity::Day)
+        .await
+        .unwrap();
+    assert_eq!(all, vec![point(date(10, 6), 9)]);
+}
+
+#[tokio::test]
+async fn test_median_salary_series_takes_median_per_month() {
+    let pool = migrated_pool().await;
+    sqlx::query(
+        r#"
+        INSERT INTO market_snapshots (date, median_salary)
+        VALUES
+            ('2026-09-20', 60000),
+            ('2026-10-01', 61000),
+            ('2026-10-02', 70000),
+            ('2026-10-03', 62000),
+            ('2026-10-04', NULL)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let mi = MarketIntelligence::new(pool);
+
+    let series = mi
+        .get_median_salary_series(date(9, 1), date(10, 31), SeriesGranularity::Month)
+        .await
+        .unwrap();
+    assert_eq!(
+        series,
+        vec![point(date(9, 1), 60000), point(date(10, 1), 62000)]
+    );
+}
@@ -110,6 +110,54 @@ async fn test_get_trending_skills_limit() {
     assert_eq!(trends[1].skill_name, "Inventory Planning");
 }
 
+#[tokio::test]
+async fn test_get_personal_skill_gaps_lists_missing_skills_with_salary_delta() {
+    let pool = migrated_pool().await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO skill_demand_trends (skill_name, date, mention_count, job_count, avg_salary)
+        VALUES
+            ('Customer Support', date('now'), 200, 100, 62000),
+            ('Case Management', date('now'), 100, 50, 65000),
+            ('Inventory Planning', date('now'), 150, 75, NULL),
+            ('Forklift Operation', date('now', '-60 days'), 400, 200, 50000)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let mi = MarketIntelligence::new(pool.clone());
+    assert!(
+        mi.get_personal_skill_gaps(10).await.unwrap().is_empty(),
+        "no active resume means nothing to compare"
+    );
+
+    let resume_id: i64 = sqlx::query_scalar(
+        "INSERT INTO resumes (name, file_path, is_active) VALUES ('Resume', 'resume.pdf', 1) RETURNING id",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO user_skills (resume_id, skill_name) VALUES (?, ' customer support ')")
+        .bind(resume_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let gaps = mi.get_personal_skill_gaps(10).await.unwrap();
+
+    assert_eq!(gaps.len(), 2);
+    assert_eq!(gaps[0].skill_name, "Inventory Planning");
+    assert_eq!(gaps[0].total_jobs, 75);
+    assert_eq!(gaps[0].avg_salary, None);
+    assert_eq!(gaps[0].salary_delta, None);
+    assert_eq!(gaps[1].skill_name, "Case Management");
+    assert_eq!(gaps[1].avg_salary, Some(65000));
+    assert_eq!(gaps[1].salary_delta, Some(3000));
+}
+
 #[tokio::test]
 async fn test_get_most_active_companies_empty() {
     let pool = migrated_pool().await;
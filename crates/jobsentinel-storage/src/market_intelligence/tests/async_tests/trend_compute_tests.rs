@@ -248,6 +248,47 @@ async fn test_compute_location_job_density_with_data() {
     assert_eq!(density.3, 2);
 }
 
+#[tokio::test]
+async fn test_compute_location_job_density_merges_locations_in_a_bucket() {
+    let pool = migrated_pool().await;
+
+    insert_current_test_jobs(
+        &pool,
+        &[
+            (
+                "job1",
+                "Care Coordinator",
+                "Community Care Network",
+                Some("Remote"),
+            ),
+            ("job2", "Care Coordinator", "FreshMart", Some("Remote")),
+            (
+                "job3",
+                "Inventory Planner",
+                "FreshMart",
+                Some("Remote - Anywhere"),
+            ),
+        ],
+    )
+    .await;
+
+    let mi = MarketIntelligence::new(pool.clone());
+    mi.compute_location_job_density().await.unwrap();
+
+    let density: (String, i64, i64, Option<String>, Option<String>) = sqlx::query_as(
+        "SELECT location_normalized, job_count, remote_job_count, top_company, top_role FROM location_job_density",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(density.0, "remote");
+    assert_eq!(density.1, 3);
+    assert_eq!(density.2, 3);
+    assert_eq!(density.3, Some("FreshMart".to_string()));
+    assert_eq!(density.4, Some("Care Coordinator".to_string()));
+}
+
 #[tokio::test]
 async fn test_compute_role_demand_trends_no_data() {
     let pool = migrated_pool().await;
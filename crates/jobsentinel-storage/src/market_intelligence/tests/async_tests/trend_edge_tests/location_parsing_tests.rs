@@ -14,6 +14,12 @@ fn test_market_location_bucket_edge_cases() {
     assert_eq!(market_location_bucket("Remote US"), "remote");
 
     assert_eq!(market_location_bucket("Chicago, IL"), "chicago, il");
+    assert_eq!(
+        market_location_bucket("Greater Chicago Area"),
+        "chicago, il"
+    );
+    assert_eq!(market_location_bucket("Brooklyn, NY"), "new york, ny");
+    assert_eq!(market_location_bucket("Toronto, Canada"), "toronto, on");
 }
 
 #[tokio::test]
@@ -36,4 +42,8 @@ async fn test_parse_location_edge_cases() {
     let (city4, state4) = mi.parse_location("  Seattle  ,  WA  ");
     assert_eq!(city4, Some("Seattle".to_string()));
     assert_eq!(state4, Some("WA".to_string()));
+
+    let (city5, state5) = mi.parse_location("NYC");
+    assert_eq!(city5, Some("New York".to_string()));
+    assert_eq!(state5, Some("NY".to_string()));
 }
@@ -1,10 +1,22 @@
 //! Utility functions for market intelligence
 
+use jobsentinel_domain::geo::find_city;
+
 use super::MarketIntelligence;
 
 impl MarketIntelligence {
     /// Parse location into city and state components
+    ///
+    /// Known cities and their aliases use the gazetteer's name and region
+    /// code, so "NYC" becomes New York, NY.
     pub(super) fn parse_location(&self, location: &str) -> (Option<String>, Option<String>) {
+        if let Some(city) = find_city(location) {
+            return (
+                Some(city.name.to_string()),
+                city.region_code.map(str::to_string),
+            );
+        }
+
         let parts: Vec<&str> = location.split(',').map(|s| s.trim()).collect();
         if parts.len() >= 2 {
             (Some(parts[0].to_string()), Some(parts[1].to_string()))
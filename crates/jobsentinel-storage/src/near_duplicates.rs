@@ -0,0 +1,221 @@
+//! Near-duplicate diagnostics
+//!
+//! Finds postings that exact title + company matching misses: the same role
+//! posted with a reworded title or a lightly edited description, often left
+//! behind by older dedup rules.
+
+use std::collections::{HashMap, HashSet};
+
+use super::connection::Database;
+use super::types::{JobRow, NearDuplicateGroup};
+use jobsentinel_domain::Job;
+
+/// Words per description shingle.
+const SHINGLE_WORDS: usize = 3;
+/// Only the start of long descriptions is compared, which keeps the scan
+/// bounded and is where reposts differ least from the original.
+const MAX_DESCRIPTION_WORDS: usize = 600;
+/// Pairwise comparison is quadratic per company, so very large employers are
+/// compared on their highest-scoring postings only.
+const MAX_JOBS_PER_COMPANY: usize = 500;
+
+const COMPANY_SUFFIXES: &[&str] = &[
+    "inc",
+    "llc",
+    "ltd",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "gmbh",
+    "plc",
+];
+
+impl Database {
+    /// Find clusters of visible jobs from the same company whose title and
+    /// description similarity is at least `threshold` (0.0-1.0)
+    pub async fn find_near_duplicate_groups(
+        &self,
+        threshold: f64,
+    ) -> Result<Vec<NearDuplicateGroup>, sqlx::Error> {
+        let jobs: Vec<Job> = sqlx::query_as::<_, JobRow>(
+            "SELECT * FROM jobs WHERE hidden = 0 ORDER BY score DESC, created_at ASC",
+        )
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        let groups = cluster_near_duplicates(jobs, threshold.clamp(0.0, 1.0));
+        tracing::info!(groups = groups.len(), "Near-duplicate scan complete");
+        Ok(groups)
+    }
+}
+
+/// Comparable features of one posting.
+pub(super) struct Fingerprint {
+    title: HashSet<String>,
+    description: HashSet<String>,
+}
+
+impl Fingerprint {
+    pub(super) fn new(job: &Job) -> Self {
+        let description_words: Vec<String> = job
+            .description
+            .as_deref()
+            .map(|text| words(text).take(MAX_DESCRIPTION_WORDS).collect())
+            .unwrap_or_default();
+        Self {
+            title: words(&job.title).collect(),
+            description: description_words
+                .windows(SHINGLE_WORDS)
+                .map(|window| window.join(" "))
+                .collect(),
+        }
+    }
+
+    /// Title similarity, averaged with description similarity when both
+    /// postings have enough description text to compare.
+    pub(super) fn similarity(&self, other: &Self) -> f64 {
+        let title = jaccard(&self.title, &other.title);
+        if self.description.is_empty() || other.description.is_empty() {
+            title
+        } else {
+            f64::midpoint(title, jaccard(&self.description, &other.description))
+        }
+    }
+}
+
+/// Group jobs into near-duplicate clusters.
+///
+/// `jobs` must be ordered by preference (score descending, then oldest), so
+/// the first member of each cluster becomes its primary.
+fn cluster_near_duplicates(jobs: Vec<Job>, threshold: f64) -> Vec<NearDuplicateGroup> {
+    let mut by_company: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, job) in jobs.iter().enumerate() {
+        let company = normalize_company(&job.company);
+        if company.is_empty() {
+            continue;
+        }
+        let members = by_company.entry(company).or_default();
+        if members.len() < MAX_JOBS_PER_COMPANY {
+            members.push(index);
+        }
+    }
+
+    let mut clusters: Vec<(Vec<usize>, f64)> = Vec::new();
+    for members in by_company.values().filter(|members| members.len() > 1) {
+        let fingerprints: Vec<Fingerprint> = members
+            .iter()
+            .map(|&index| Fingerprint::new(&jobs[index]))
+            .collect();
+        let mut sets = DisjointSets::new(members.len());
+        let mut weakest = vec![1.0_f64; members.len()];
+
+        for a in 0..members.len() {
+            for b in (a + 1)..members.len() {
+                let similarity = fingerprints[a].similarity(&fingerprints[b]);
+                if similarity >= threshold {
+                    let (root_a, root_b) = (sets.find(a), sets.find(b));
+                    let linked = weakest[root_a].min(weakest[root_b]).min(similarity);
+                    let root = sets.union(a, b);
+                    weakest[root] = linked;
+                }
+            }
+        }
+
+        let mut grouped: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (local, &index) in members.iter().enumerate() {
+            grouped.entry(sets.find(local)).or_default().push(index);
+        }
+        clusters.extend(
+            grouped
+                .into_iter()
+                .filter(|(_, indexes)| indexes.len() > 1)
+                .map(|(root, indexes)| (indexes, weakest[root])),
+        );
+    }
+
+    // Members are already in preference order; order clusters by their primary.
+    clusters.sort_by_key(|(indexes, _)| indexes[0]);
+
+    let mut slots: Vec<Option<Job>> = jobs.into_iter().map(Some).collect();
+    clusters
+        .into_iter()
+        .map(|(indexes, similarity)| {
+            let jobs: Vec<Job> = indexes
+                .into_iter()
+                .filter_map(|index| slots[index].take())
+                .collect();
+            let mut sources: Vec<String> = Vec::with_capacity(jobs.len());
+            for job in &jobs {
+                if !sources.contains(&job.source) {
+                    sources.push(job.source.clone());
+                }
+            }
+            NearDuplicateGroup {
+                primary_id: jobs[0].id,
+                similarity,
+                jobs,
+                sources,
+            }
+        })
+        .collect()
+}
+
+pub(super) fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+pub(super) fn normalize_company(company: &str) -> String {
+    let mut words: Vec<String> = words(company).collect();
+    while words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| COMPANY_SUFFIXES.contains(&word.as_str()))
+    {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let shared = a.intersection(b).count();
+    shared as f64 / (a.len() + b.len() - shared) as f64
+}
+
+/// Union-find over positions within one company's postings.
+pub(super) struct DisjointSets {
+    parent: Vec<usize>,
+}
+
+impl DisjointSets {
+    pub(super) fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    pub(super) fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    /// Join two sets, keeping the lower (preferred) position as the root.
+    pub(super) fn union(&mut self, a: usize, b: usize) -> usize {
+        let (a, b) = (self.find(a), self.find(b));
+        let (root, child) = if a <= b { (a, b) } else { (b, a) };
+        self.parent[child] = root;
+        root
+    }
+}
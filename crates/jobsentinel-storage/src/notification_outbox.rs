@@ -0,0 +1,198 @@
+//! Notification outbox and delivery history
+//!
+//! Immediate alerts are queued here before sending. The delivery worker
+//! claims due rows, then marks each one sent, schedules a retry, or gives
+//! up. Alerts held for quiet hours are deferred without using up an attempt.
+//! Rows are kept afterwards as the delivery history.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+
+/// An alert due to be sent or retried
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingNotification {
+    pub id: i64,
+    pub job_hash: String,
+    /// Serialized match score the alert is built from
+    pub score_json: String,
+    /// Delivery attempts made so far
+    pub attempts: i64,
+}
+
+/// One alert in the delivery history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NotificationHistoryEntry {
+    pub id: i64,
+    /// `None` once the job has been deleted
+    pub job_id: Option<i64>,
+    pub job_hash: String,
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub score: f64,
+    /// `pending`, `sent`, or `failed`
+    pub status: String,
+    pub attempts: i64,
+    /// Why the last attempt failed
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// When the next retry is due, for pending alerts
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl Database {
+    /// Queue an alert for delivery as soon as the worker runs.
+    pub async fn enqueue_notification(
+        &self,
+        job_hash: &str,
+        score: f64,
+        score_json: &str,
+        now: DateTime<Utc>,
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO notification_outbox
+                (job_hash, score, score_json, created_at, next_attempt_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(job_hash)
+        .bind(score)
+        .bind(score_json)
+        .bind(now)
+        .bind(now)
+        .execute(self.pool())
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Pending alerts whose next attempt is due, oldest first.
+    pub async fn due_notifications(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingNotification>, sqlx::Error> {
+        sqlx::query_as::<_, PendingNotification>(
+            r#"
+            SELECT id, job_hash, score_json, attempts
+            FROM notification_outbox
+            WHERE status = 'pending' AND next_attempt_at <= ?
+            ORDER BY next_attempt_at ASC, id ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await
+    }
+
+    /// Claim a due alert for one delivery attempt by moving its next attempt
+    /// to `lease_until`. Returns `false` when another worker claimed it
+    /// first. A claim left by a crash expires at `lease_until`.
+    pub async fn claim_notification(
+        &self,
+        id: i64,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE notification_outbox
+            SET next_attempt_at = ?
+            WHERE id = ? AND status = 'pending' AND next_attempt_at <= ?
+            "#,
+        )
+        .bind(lease_until)
+        .bind(id)
+        .bind(now)
+        .execute(self.pool())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Record a successful delivery.
+    pub async fn mark_notification_sent(
+        &self,
+        id: i64,
+        sent_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE notification_outbox
+            SET status = 'sent', attempts = attempts + 1, sent_at = ?,
+                next_attempt_at = NULL, last_error = NULL
+            WHERE id = ?
+            "#,
+        )
+        .bind(sent_at)
+        .bind(id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Hold a claimed alert until `until` without counting an attempt.
+    pub async fn defer_notification(
+        &self,
+        id: i64,
+        until: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE notification_outbox SET next_attempt_at = ? WHERE id = ? AND status = 'pending'",
+        )
+        .bind(until)
+        .bind(id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Record a failed attempt. The alert is retried at `retry_at`, or marked
+    /// failed for good when `retry_at` is `None`.
+    pub async fn mark_notification_failed(
+        &self,
+        id: i64,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE notification_outbox
+            SET status = CASE WHEN ? IS NULL THEN 'failed' ELSE 'pending' END,
+                attempts = attempts + 1, last_error = ?, next_attempt_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(retry_at)
+        .bind(error)
+        .bind(retry_at)
+        .bind(id)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Most recent alerts with their delivery status, newest first.
+    pub async fn notification_history(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<NotificationHistoryEntry>, sqlx::Error> {
+        sqlx::query_as::<_, NotificationHistoryEntry>(
+            r#"
+            SELECT o.id, j.id AS job_id, o.job_hash, j.title, j.company, o.score,
+                   o.status, o.attempts, o.last_error, o.created_at,
+                   o.next_attempt_at, o.sent_at
+            FROM notification_outbox o
+            LEFT JOIN jobs j ON j.hash = o.job_hash
+            ORDER BY o.created_at DESC, o.id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await
+    }
+}
@@ -1,6 +1,6 @@
 //! Database query operations
 //!
-//! Search, filter, and retrieve jobs with various criteria.
+//! Filter and retrieve jobs with various criteria.
 
 use super::connection::Database;
 use super::types::{DuplicateGroup, JobRow};
@@ -12,8 +12,10 @@ impl Database {
         tracing::debug!("Fetching {} recent jobs from database", limit);
         // OPTIMIZATION: Use composite index idx_jobs_hidden_score_created (covering index)
         // Index contains: hidden, score DESC, created_at DESC - perfect for this query
+        // Closed postings are left out and cross-source copies are collapsed
+        // into their canonical job.
         let jobs: Vec<Job> = sqlx::query_as::<_, JobRow>(
-            "SELECT * FROM jobs WHERE hidden = 0 ORDER BY score DESC, created_at DESC LIMIT ?",
+            "SELECT * FROM jobs WHERE hidden = 0 AND status <> 'closed' AND id NOT IN (SELECT job_id FROM collapsed_duplicate_jobs) ORDER BY score DESC, created_at DESC LIMIT ?",
         )
         .bind(limit)
         .fetch_all(self.pool())
@@ -100,62 +102,6 @@ impl Database {
         Ok(jobs)
     }
 
-    /// Full-text search on title and description
-    #[tracing::instrument(skip(self))]
-    pub async fn search_jobs(&self, query: &str, limit: i64) -> Result<Vec<Job>, sqlx::Error> {
-        let query_chars = query.chars().count();
-        tracing::debug!(query_chars, limit, "Performing full-text search");
-        // Use FTS5 virtual table for fast full-text search
-        let job_ids: Vec<i64> =
-            sqlx::query_scalar("SELECT rowid FROM jobs_fts WHERE jobs_fts MATCH ? LIMIT ?")
-                .bind(query)
-                .bind(limit)
-                .fetch_all(self.pool())
-                .await?;
-
-        if job_ids.is_empty() {
-            tracing::info!(query_chars, "No jobs found matching search query");
-            return Ok(Vec::new());
-        }
-
-        // Limit number of IDs to prevent query performance issues
-        const MAX_IDS: usize = 1000;
-        if job_ids.len() > MAX_IDS {
-            return Err(sqlx::Error::Protocol(format!(
-                "Too many job IDs requested: {} (max: {})",
-                job_ids.len(),
-                MAX_IDS
-            )));
-        }
-
-        // Fetch full job records
-        // SAFETY: This is NOT vulnerable to SQL injection. The format! only creates
-        // placeholders ("?"), and actual values are bound using SQLx's parameterization.
-        // This is the recommended pattern for dynamic IN clauses with SQLx.
-        // Pre-allocate string capacity to avoid reallocations
-        let mut placeholders = String::with_capacity(job_ids.len() * 2);
-        for i in 0..job_ids.len() {
-            if i > 0 {
-                placeholders.push(',');
-            }
-            placeholders.push('?');
-        }
-        let sql = format!("SELECT * FROM jobs WHERE id IN ({})", placeholders);
-
-        let mut query_builder = sqlx::query_as::<_, JobRow>(sqlx::AssertSqlSafe(sql));
-        for id in job_ids {
-            query_builder = query_builder.bind(id);
-        }
-
-        let jobs = query_builder
-            .fetch_all(self.pool())
-            .await?
-            .into_iter()
-            .map(Job::from)
-            .collect();
-        Ok(jobs)
-    }
-
     /// Find potential duplicate jobs (same title + company, different sources)
     /// Returns groups of jobs that are likely duplicates
     ///
@@ -176,7 +122,8 @@ impl Database {
                 WHERE hidden = 0
             )
             SELECT id, hash, title, company, url, location, description, score, score_reasons,
-                   source, remote, salary_min, salary_max, currency, created_at, updated_at,
+                   source, remote, salary_min, salary_max, currency, pay_period,
+                   created_at, updated_at,
                    last_seen, times_seen, immediate_alert_sent, included_in_digest, hidden,
                    bookmarked, notes, ghost_score, ghost_reasons, first_seen, repost_count
             FROM duplicate_candidates
@@ -242,7 +189,7 @@ impl Database {
     ) -> Result<Vec<Job>, sqlx::Error> {
         let jobs = if let Some(max_score) = max_ghost_score {
             sqlx::query_as::<_, JobRow>(
-                "SELECT * FROM jobs WHERE hidden = 0 AND (ghost_score IS NULL OR ghost_score < ?) ORDER BY score DESC, created_at DESC LIMIT ?",
+                "SELECT * FROM jobs WHERE hidden = 0 AND (ghost_score IS NULL OR ghost_score < ?) AND status <> 'closed' AND id NOT IN (SELECT job_id FROM collapsed_duplicate_jobs) ORDER BY score DESC, created_at DESC LIMIT ?",
             )
             .bind(max_score)
             .bind(limit)
@@ -250,7 +197,7 @@ impl Database {
             .await?
         } else {
             sqlx::query_as::<_, JobRow>(
-                "SELECT * FROM jobs WHERE hidden = 0 ORDER BY score DESC, created_at DESC LIMIT ?",
+                "SELECT * FROM jobs WHERE hidden = 0 AND status <> 'closed' AND id NOT IN (SELECT job_id FROM collapsed_duplicate_jobs) ORDER BY score DESC, created_at DESC LIMIT ?",
             )
             .bind(limit)
             .fetch_all(self.pool())
@@ -297,20 +244,22 @@ impl Database {
         Ok(rows)
     }
 
-    /// Get salary distribution (jobs grouped by salary ranges)
+    /// Get salary distribution (jobs grouped by US dollar salary ranges)
+    ///
+    /// Postings in currencies without a known rate count as not listed.
     pub async fn get_salary_distribution(&self) -> Result<Vec<(String, i64)>, sqlx::Error> {
         let rows: Vec<(String, i64)> = sqlx::query_as(
             r#"
             SELECT
                 CASE
-                    WHEN salary_min IS NULL AND salary_max IS NULL THEN 'Not Listed'
-                    WHEN COALESCE(salary_min, salary_max) < 50000 THEN '<$50k'
-                    WHEN COALESCE(salary_min, salary_max) < 75000 THEN '$50k-75k'
-                    WHEN COALESCE(salary_min, salary_max) < 100000 THEN '$75k-100k'
-                    WHEN COALESCE(salary_min, salary_max) < 125000 THEN '$100k-125k'
-                    WHEN COALESCE(salary_min, salary_max) < 150000 THEN '$125k-150k'
-                    WHEN COALESCE(salary_min, salary_max) < 175000 THEN '$150k-175k'
-                    WHEN COALESCE(salary_min, salary_max) < 200000 THEN '$175k-200k'
+                    WHEN salary_min_usd IS NULL AND salary_max_usd IS NULL THEN 'Not Listed'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 50000 THEN '<$50k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 75000 THEN '$50k-75k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 100000 THEN '$75k-100k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 125000 THEN '$100k-125k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 150000 THEN '$125k-150k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 175000 THEN '$150k-175k'
+                    WHEN COALESCE(salary_min_usd, salary_max_usd) < 200000 THEN '$175k-200k'
                     ELSE '$200k+'
                 END as range,
                 COUNT(*) as count
@@ -0,0 +1,131 @@
+//! Repost chains
+//!
+//! Taking a posting down and putting it back up with a reworded title or a
+//! light edit gives it a new hash, so exact matching never sees it as a
+//! repost. A posting first seen at least `MIN_REPOST_GAP_DAYS` after a
+//! near-identical one from the same company joins that posting's chain, and
+//! the other postings in the chain count as reposts for ghost detection.
+
+use chrono::{DateTime, Duration, Utc};
+use jobsentinel_domain::Job;
+
+use super::connection::Database;
+use super::near_duplicates::{normalize_company, words, Fingerprint};
+use super::types::JobRow;
+
+/// Title and description similarity (0.0-1.0) for two postings to count as
+/// the same role
+const REPOST_SIMILARITY: f64 = 0.8;
+/// Postings first seen closer together than this are parallel openings, not
+/// reposts.
+const MIN_REPOST_GAP_DAYS: i64 = 7;
+/// Most earlier postings compared against a new one
+const MAX_CANDIDATES: i64 = 500;
+
+impl Database {
+    /// Link `job` to the chain of the earliest near-identical posting from
+    /// the same company, unless it is already in a chain, and return how
+    /// many other postings share its chain.
+    pub async fn link_repost_chain(&self, job: &Job) -> Result<i64, sqlx::Error> {
+        if self.repost_chain_hash(&job.hash).await?.is_none() {
+            if let Some((earlier_hash, similarity)) = self.find_earlier_posting(job).await? {
+                let chain_hash = self
+                    .repost_chain_hash(&earlier_hash)
+                    .await?
+                    .unwrap_or_else(|| earlier_hash.clone());
+                let mut tx = self.pool().begin().await?;
+                for (hash, link_similarity) in [(&earlier_hash, 1.0), (&job.hash, similarity)] {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO job_repost_chains (job_hash, chain_hash, similarity)
+                        VALUES (?, ?, ?)
+                        ON CONFLICT(job_hash) DO NOTHING
+                        "#,
+                    )
+                    .bind(hash)
+                    .bind(&chain_hash)
+                    .bind(link_similarity)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                tx.commit().await?;
+                tracing::debug!(similarity, "Linked posting to repost chain");
+            }
+        }
+
+        self.get_repost_chain_count(&job.hash).await
+    }
+
+    /// Number of other postings in the repost chain of `job_hash`, or 0 when
+    /// it is in none
+    pub async fn get_repost_chain_count(&self, job_hash: &str) -> Result<i64, sqlx::Error> {
+        let members: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM job_repost_chains
+            WHERE chain_hash = (SELECT chain_hash FROM job_repost_chains WHERE job_hash = ?)
+            "#,
+        )
+        .bind(job_hash)
+        .fetch_one(self.pool())
+        .await?;
+
+        Ok((members - 1).max(0))
+    }
+
+    async fn repost_chain_hash(&self, job_hash: &str) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT chain_hash FROM job_repost_chains WHERE job_hash = ?")
+            .bind(job_hash)
+            .fetch_optional(self.pool())
+            .await
+    }
+
+    /// Hash and similarity of the earliest saved posting from the same
+    /// company that `job` reposts
+    async fn find_earlier_posting(&self, job: &Job) -> Result<Option<(String, f64)>, sqlx::Error> {
+        let company = normalize_company(&job.company);
+        let Some(first_word) = words(&company).next() else {
+            return Ok(None);
+        };
+        // A job already saved keeps the date it was first seen, not the date
+        // of this sighting.
+        let saved_opened: Option<DateTime<Utc>> =
+            sqlx::query_scalar("SELECT COALESCE(first_seen, created_at) FROM jobs WHERE hash = ?")
+                .bind(&job.hash)
+                .fetch_optional(self.pool())
+                .await?;
+        let opened = saved_opened.unwrap_or_else(|| job.first_seen.unwrap_or(job.created_at));
+        let opened_before = opened - Duration::days(MIN_REPOST_GAP_DAYS);
+
+        // instr() narrows the scan; the normalized name decides the match.
+        let candidates: Vec<Job> = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE hash <> ? AND instr(lower(company), ?) > 0
+            ORDER BY COALESCE(first_seen, created_at) DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(&job.hash)
+        .bind(&first_word)
+        .bind(MAX_CANDIDATES)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+
+        // Candidates are newest first; check from the oldest.
+        let fingerprint = Fingerprint::new(job);
+        Ok(candidates
+            .iter()
+            .rev()
+            .filter(|candidate| {
+                candidate.first_seen.unwrap_or(candidate.created_at) <= opened_before
+            })
+            .filter(|candidate| normalize_company(&candidate.company) == company)
+            .find_map(|candidate| {
+                let similarity = fingerprint.similarity(&Fingerprint::new(candidate));
+                (similarity >= REPOST_SIMILARITY).then(|| (candidate.hash.clone(), similarity))
+            }))
+    }
+}
@@ -0,0 +1,48 @@
+//! Batch access for re-scoring saved jobs
+//!
+//! Used after scoring weights or search preferences change so every saved
+//! job reflects the settings now in use, not the ones active when it was
+//! first found.
+
+use super::connection::Database;
+use super::types::{JobRow, RescoredJob};
+use jobsentinel_domain::Job;
+
+impl Database {
+    /// Number of saved jobs, hidden ones included.
+    pub async fn count_jobs(&self) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM jobs")
+            .fetch_one(self.pool())
+            .await
+    }
+
+    /// Saved jobs with an id greater than `after_id`, in id order.
+    pub async fn jobs_after_id(&self, after_id: i64, limit: i64) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs =
+            sqlx::query_as::<_, JobRow>("SELECT * FROM jobs WHERE id > ? ORDER BY id LIMIT ?")
+                .bind(after_id)
+                .bind(limit)
+                .fetch_all(self.pool())
+                .await?
+                .into_iter()
+                .map(Job::from)
+                .collect();
+        Ok(jobs)
+    }
+
+    /// Save a batch of recomputed scores in one transaction, without marking
+    /// the jobs as updated.
+    pub async fn save_rescored_jobs(&self, rescored: &[RescoredJob]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool().begin().await?;
+        for job in rescored {
+            sqlx::query("UPDATE jobs SET score = ?, score_reasons = ? WHERE id = ?")
+                .bind(job.score)
+                .bind(&job.score_reasons)
+                .bind(job.job_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+//! Matching one resume against every active job
+
+use super::management::match_with_job_from_row;
+use super::*;
+use serde::Serialize;
+
+/// Jobs matched per batch by [`ResumeMatcher::match_resume_to_all_jobs`]
+const MATCH_BATCH_SIZE: i64 = 100;
+
+/// Progress of a batch resume match, reported after each batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ResumeMatchProgress {
+    /// Jobs matched and saved so far
+    pub jobs_matched: usize,
+    /// Active jobs at or above the minimum score when the run started
+    pub jobs_total: usize,
+}
+
+impl ResumeMatcher {
+    /// Match a resume against every active job whose score is at least
+    /// `min_job_score`, saving each result in `resume_job_matches` and calling
+    /// `on_progress` after each batch.
+    pub async fn match_resume_to_all_jobs(
+        &self,
+        resume_id: i64,
+        min_job_score: f64,
+        on_progress: impl Fn(ResumeMatchProgress) + Send,
+    ) -> Result<ResumeMatchProgress> {
+        // Fail before the loop rather than on every job
+        self.get_resume(resume_id).await?;
+
+        // Active jobs are neither hidden nor closed
+        let jobs_total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM jobs
+             WHERE hidden = 0 AND status <> 'closed' AND COALESCE(score, 0) >= ?",
+        )
+        .bind(min_job_score)
+        .fetch_one(&self.db)
+        .await?;
+        let mut progress = ResumeMatchProgress {
+            jobs_matched: 0,
+            jobs_total: usize::try_from(jobs_total).unwrap_or_default(),
+        };
+        on_progress(progress);
+
+        let mut after_id = 0;
+        loop {
+            let jobs: Vec<(i64, String)> = sqlx::query_as(
+                "SELECT id, hash FROM jobs
+                 WHERE id > ? AND hidden = 0 AND status <> 'closed' AND COALESCE(score, 0) >= ?
+                 ORDER BY id ASC LIMIT ?",
+            )
+            .bind(after_id)
+            .bind(min_job_score)
+            .bind(MATCH_BATCH_SIZE)
+            .fetch_all(&self.db)
+            .await?;
+            let Some((last_id, _)) = jobs.last() else {
+                break;
+            };
+            after_id = *last_id;
+
+            for (_, job_hash) in &jobs {
+                self.match_resume_to_job(resume_id, job_hash).await?;
+            }
+
+            progress.jobs_matched += jobs.len();
+            // Jobs saved while the run was underway can push past the first count
+            progress.jobs_total = progress.jobs_total.max(progress.jobs_matched);
+            on_progress(progress);
+        }
+
+        tracing::info!(
+            resume_id,
+            jobs_matched = progress.jobs_matched,
+            "Matched resume against active jobs"
+        );
+        Ok(progress)
+    }
+
+    /// Saved matches for a resume against active jobs, best fit first
+    pub async fn get_best_matches(
+        &self,
+        resume_id: i64,
+        limit: i64,
+    ) -> Result<Vec<MatchResultWithJob>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT m.id, m.resume_id, m.job_hash, m.overall_match_score, m.skills_match_score,
+                   m.experience_match_score, m.education_match_score,
+                   m.missing_skills, m.matching_skills, m.gap_analysis, m.created_at,
+                   j.title as job_title, j.company
+            FROM resume_job_matches m
+            JOIN jobs j ON m.job_hash = j.hash
+            WHERE m.resume_id = ? AND j.hidden = 0 AND j.status <> 'closed'
+            ORDER BY m.overall_match_score DESC, m.id ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(resume_id)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        rows.iter().map(match_with_job_from_row).collect()
+    }
+}
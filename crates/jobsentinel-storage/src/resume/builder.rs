@@ -10,6 +10,7 @@ use sqlx::SqlitePool;
 mod deserialization;
 mod serialization;
 
+use super::versions;
 use deserialization::string_from_value;
 use serialization::group_skills;
 
@@ -214,6 +215,9 @@ impl ResumeBuilder {
         if result.rows_affected() == 0 {
             anyhow::bail!("Resume not found");
         }
+        versions::record_draft_version(&self.pool, resume_id, &draft.resume)
+            .await
+            .context("Failed to save resume version")?;
         Ok(())
     }
 }
@@ -1,24 +1,7 @@
 use super::*;
 
 async fn setup_test_db() -> SqlitePool {
-    let pool = SqlitePool::connect(":memory:").await.unwrap();
-
-    // Create resume_drafts table
-    sqlx::query(
-        r#"
-            CREATE TABLE resume_drafts (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                data TEXT NOT NULL,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
-            )
-            "#,
-    )
-    .execute(&pool)
-    .await
-    .unwrap();
-
-    pool
+    crate::test_support::migrated_pool().await
 }
 
 #[tokio::test]
@@ -1,5 +1,6 @@
 use super::*;
 use crate::sqlite_time::parse_sqlite_datetime;
+use sqlx::sqlite::SqliteRow;
 
 impl ResumeMatcher {
     /// Get recent match results for a resume with job titles
@@ -26,43 +27,7 @@ impl ResumeMatcher {
         .fetch_all(&self.db)
         .await?;
 
-        let mut results = Vec::new();
-        for r in rows {
-            let created_str = r.try_get::<String, _>("created_at")?;
-
-            let created_at = parse_sqlite_datetime(&created_str)?;
-
-            let missing_skills_str = r
-                .try_get::<Option<String>, _>("missing_skills")
-                .unwrap_or(None)
-                .unwrap_or_else(|| "[]".to_string());
-            let matching_skills_str = r
-                .try_get::<Option<String>, _>("matching_skills")
-                .unwrap_or(None)
-                .unwrap_or_else(|| "[]".to_string());
-
-            results.push(MatchResultWithJob {
-                id: r.try_get::<i64, _>("id")?,
-                resume_id: r.try_get::<i64, _>("resume_id")?,
-                job_hash: r.try_get::<String, _>("job_hash")?,
-                job_title: r
-                    .try_get::<Option<String>, _>("job_title")?
-                    .unwrap_or_else(|| "Unknown Job".to_string()),
-                company: r
-                    .try_get::<Option<String>, _>("company")?
-                    .unwrap_or_else(|| "Unknown Company".to_string()),
-                overall_match_score: r.try_get::<f64, _>("overall_match_score")?,
-                skills_match_score: r.try_get::<Option<f64>, _>("skills_match_score")?,
-                experience_match_score: r.try_get::<Option<f64>, _>("experience_match_score")?,
-                education_match_score: r.try_get::<Option<f64>, _>("education_match_score")?,
-                missing_skills: serde_json::from_str(&missing_skills_str)?,
-                matching_skills: serde_json::from_str(&matching_skills_str)?,
-                gap_analysis: r.try_get::<Option<String>, _>("gap_analysis")?,
-                created_at,
-            });
-        }
-
-        Ok(results)
+        rows.iter().map(match_with_job_from_row).collect()
     }
 
     /// Set resume as active (deactivates all others)
@@ -141,6 +106,14 @@ impl ResumeMatcher {
             .execute(&self.db)
             .await?;
 
+        // Unlink application profiles that apply with it
+        sqlx::query(
+            "UPDATE application_profile SET default_resume_id = NULL WHERE default_resume_id = ?",
+        )
+        .bind(resume_id)
+        .execute(&self.db)
+        .await?;
+
         // Delete the resume
         let result = sqlx::query("DELETE FROM resumes WHERE id = ?")
             .bind(resume_id)
@@ -155,3 +128,39 @@ impl ResumeMatcher {
         Ok(())
     }
 }
+
+/// A `resume_job_matches` row joined with its job title and company
+pub(super) fn match_with_job_from_row(r: &SqliteRow) -> Result<MatchResultWithJob> {
+    let created_str = r.try_get::<String, _>("created_at")?;
+
+    let created_at = parse_sqlite_datetime(&created_str)?;
+
+    let missing_skills_str = r
+        .try_get::<Option<String>, _>("missing_skills")
+        .unwrap_or(None)
+        .unwrap_or_else(|| "[]".to_string());
+    let matching_skills_str = r
+        .try_get::<Option<String>, _>("matching_skills")
+        .unwrap_or(None)
+        .unwrap_or_else(|| "[]".to_string());
+
+    Ok(MatchResultWithJob {
+        id: r.try_get::<i64, _>("id")?,
+        resume_id: r.try_get::<i64, _>("resume_id")?,
+        job_hash: r.try_get::<String, _>("job_hash")?,
+        job_title: r
+            .try_get::<Option<String>, _>("job_title")?
+            .unwrap_or_else(|| "Unknown Job".to_string()),
+        company: r
+            .try_get::<Option<String>, _>("company")?
+            .unwrap_or_else(|| "Unknown Company".to_string()),
+        overall_match_score: r.try_get::<f64, _>("overall_match_score")?,
+        skills_match_score: r.try_get::<Option<f64>, _>("skills_match_score")?,
+        experience_match_score: r.try_get::<Option<f64>, _>("experience_match_score")?,
+        education_match_score: r.try_get::<Option<f64>, _>("education_match_score")?,
+        missing_skills: serde_json::from_str(&missing_skills_str)?,
+        matching_skills: serde_json::from_str(&matching_skills_str)?,
+        gap_analysis: r.try_get::<Option<String>, _>("gap_analysis")?,
+        created_at,
+    })
+}
@@ -3,8 +3,7 @@
 //! Compares resume skills against job requirements and generates match scores.
 #![allow(clippy::unwrap_used, clippy::expect_used)] // Regex patterns are compile-time constants
 
-use super::skills::SkillExtractor;
-use super::types::{DegreeLevel, EducationRequirement, ExperienceRequirement};
+use super::types::{DegreeLevel, EducationMatch, EducationRequirement, ExperienceRequirement};
 use super::{MatchResult, UserSkill};
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -12,6 +11,7 @@ use regex::Regex;
 use sqlx::{Row, SqlitePool};
 use std::collections::HashSet;
 
+mod education;
 mod gap_analysis;
 mod queries;
 
@@ -22,15 +22,11 @@ struct JobInfo {
 
 pub(super) struct JobMatcher {
     db: SqlitePool,
-    skill_extractor: SkillExtractor,
 }
 
 impl JobMatcher {
     pub(super) fn new(db: SqlitePool) -> Self {
-        Self {
-            db,
-            skill_extractor: SkillExtractor::new(),
-        }
+        Self { db }
     }
 
     /// Extract skills from job description and store in database
@@ -38,31 +34,9 @@ impl JobMatcher {
         // Get job details
         let job = self.get_job(job_hash).await?;
 
-        // Extract skills from description
-        let job_text = format!("{} {}", job.title, job.description);
-        let extracted_skills = self.skill_extractor.extract_skills(&job_text);
-
-        // Insert skills into database
-        for skill in &extracted_skills {
-            sqlx::query(
-                r#"
-                INSERT INTO job_skills (job_hash, skill_name, is_required, skill_category)
-                VALUES (?, ?, 1, ?)
-                ON CONFLICT(job_hash, skill_name) DO UPDATE SET
-                    skill_category = excluded.skill_category
-                "#,
-            )
-            .bind(job_hash)
-            .bind(&skill.skill_name)
-            .bind(&skill.skill_category)
-            .execute(&self.db)
-            .await?;
-        }
-
-        Ok(extracted_skills
-            .iter()
-            .map(|s| s.skill_name.clone())
-            .collect())
+        // Extract skills from description and store them
+        let mut conn = self.db.acquire().await?;
+        Ok(super::store_job_skills(&mut conn, job_hash, &job.title, &job.description).await?)
     }
 
     /// Extract experience requirements from job description
@@ -153,95 +127,6 @@ impl JobMatcher {
         requirements
     }
 
-    /// Extract education requirements from job description
-    pub(super) fn extract_education_requirements(
-        &self,
-        text: &str,
-    ) -> Option<EducationRequirement> {
-        let lower = text.to_lowercase();
-
-        // Check if education is explicitly NOT required
-        if lower.contains("no degree required")
-            || lower.contains("degree not required")
-            || lower.contains("no formal education")
-        {
-            return None;
-        }
-
-        // Look for degree mentions with context
-        let degree_patterns = [
-            // PhD patterns
-            (
-                r"(?i)(?:\bph\.?d\b|\bdoctorate\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,]+))?",
-                DegreeLevel::PhD,
-            ),
-            // Master's patterns
-            (
-                r"(?i)(?:\bmaster'?s?\b|\bm\.?s\.?(?=\s|$)|\bm\.?a\.?(?=\s|$)|\bmba\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,]+))?",
-                DegreeLevel::Master,
-            ),
-            // Bachelor's patterns
-            (
-                r"(?i)(?:\bbachelor'?s?\b|\bb\.?s\.?(?=\s|$)|\bb\.?a\.?(?=\s|$)|\bundergraduate\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,]+))?",
-                DegreeLevel::Bachelor,
-            ),
-            // Associate patterns
-            (
-                r"(?i)(?:\bassociate'?s?\b|\ba\.?s\.?(?=\s|$)|\ba\.?a\.?(?=\s|$))\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,]+))?",
-                DegreeLevel::Associate,
-            ),
-        ];
-
-        let mut best_match: Option<(DegreeLevel, Vec<String>)> = None;
-
-        for (pattern, level) in degree_patterns {
-            if let Ok(re) = Regex::new(pattern) {
-                if let Some(cap) = re.captures(text) {
-                    // Extract fields of study if present
-                    let fields: Vec<String> = cap
-                        .get(1)
-                        .map(|m| {
-                            m.as_str()
-                                .split(|c| c == ',' || c == '/')
-                                .map(|s| s.trim().to_string())
-                                .filter(|s| !s.is_empty())
-                                .collect()
-                        })
-                        .unwrap_or_default();
-
-                    // Take highest degree level found
-                    if best_match.is_none()
-                        || level
-                            > best_match
-                                .as_ref()
-                                .map(|(l, _)| *l)
-                                .unwrap_or(DegreeLevel::None)
-                    {
-                        best_match = Some((level, fields));
-                    }
-                }
-            }
-        }
-
-        // Also check for "degree required" without specific type (assume Bachelor's)
-        if best_match.is_none()
-            && (lower.contains("degree required") || lower.contains("college degree"))
-        {
-            best_match = Some((DegreeLevel::Bachelor, vec![]));
-        }
-
-        // Determine if required or preferred
-        let is_required = !lower.contains("preferred")
-            && !lower.contains("or equivalent")
-            && !lower.contains("nice to have");
-
-        best_match.map(|(level, fields)| EducationRequirement {
-            degree_level: level,
-            fields,
-            is_required,
-        })
-    }
-
     /// Calculate experience match score
     fn calculate_experience_match(
         &self,
@@ -295,32 +180,6 @@ impl JobMatcher {
         }
     }
 
-    /// Calculate education match score
-    fn calculate_education_match(
-        &self,
-        user_education: Option<DegreeLevel>,
-        requirement: Option<&EducationRequirement>,
-    ) -> f64 {
-        match (user_education, requirement) {
-            (_, None) => 1.0,       // No requirement = full match
-            (None, Some(_)) => 0.0, // Requirement but no user education
-            (Some(user), Some(req)) => {
-                if user >= req.degree_level {
-                    1.0 // Meets or exceeds
-                } else {
-                    // Partial credit based on level difference
-                    let user_level = user as i32;
-                    let req_level = req.degree_level as i32;
-                    if req_level > 0 {
-                        (user_level as f64) / (req_level as f64)
-                    } else {
-                        1.0
-                    }
-                }
-            }
-        }
-    }
-
     /// Calculate match between resume and job
     pub(super) async fn calculate_match(
         &self,
@@ -380,16 +239,22 @@ impl JobMatcher {
         let experience_match_score =
             self.calculate_experience_match(&user_skills, &experience_reqs);
 
-        // Extract and calculate education match
-        // For now, we'll try to detect user's education from their resume text
+        // Extract and compare education against the resume text
         let education_req = self.extract_education_requirements(&job_text);
-        let user_education = if education_req.is_some() {
-            self.get_user_education(resume_id).await?
-        } else {
-            None
+        let education = match &education_req {
+            Some(requirement) => {
+                let resume_text = self.get_resume_text(resume_id).await?;
+                Some(education::compare_education(
+                    requirement,
+                    resume_text.as_deref(),
+                ))
+            }
+            None => None,
         };
-        let education_match_score =
-            self.calculate_education_match(user_education, education_req.as_ref());
+        let education_match_score = education::calculate_education_match(
+            education.as_ref().and_then(|edu| edu.resume_degree),
+            education_req.as_ref(),
+        );
 
         let overall_match_score = jobsentinel_documents::calculate_resume_match_score(
             &matching_skills,
@@ -425,6 +290,7 @@ impl JobMatcher {
             matching_skills,
             gap_analysis: Some(gap_analysis),
             created_at: Utc::now(),
+            education,
         })
     }
 
@@ -0,0 +1,174 @@
+//! Education requirement extraction and comparison against the resume.
+
+use super::*;
+
+/// Phrases that let experience stand in for the listed degree.
+const EQUIVALENT_EXPERIENCE_PHRASES: &[&str] = &[
+    "or equivalent",
+    "equivalent experience",
+    "equivalent work experience",
+    "equivalent combination",
+    "in lieu of",
+];
+
+/// Words that end a captured field of study ("Social Work required").
+const FIELD_STOP_WORDS: &[&str] = &[
+    "required",
+    "preferred",
+    "degree",
+    "is",
+    "with",
+    "from",
+    "plus",
+    "and",
+    "experience",
+];
+
+impl JobMatcher {
+    /// Extract education requirements from job description
+    pub(super) fn extract_education_requirements(
+        &self,
+        text: &str,
+    ) -> Option<EducationRequirement> {
+        let lower = text.to_lowercase();
+
+        // Check if education is explicitly NOT required
+        if lower.contains("no degree required")
+            || lower.contains("degree not required")
+            || lower.contains("no formal education")
+        {
+            return None;
+        }
+
+        // Look for degree mentions with context
+        let degree_patterns = [
+            // PhD patterns
+            (
+                r"(?i)(?:\bph\.?d\b|\bdoctorate\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,/]+))?",
+                DegreeLevel::PhD,
+            ),
+            // Master's patterns
+            (
+                r"(?i)(?:\bmaster'?s?\b|\bm\.?s\.?(?:\s|$)|\bm\.?a\.?(?:\s|$)|\bmba\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,/]+))?",
+                DegreeLevel::Master,
+            ),
+            // Bachelor's patterns
+            (
+                r"(?i)(?:\bbachelor'?s?\b|\bb\.?s\.?(?:\s|$)|\bb\.?a\.?(?:\s|$)|\bundergraduate\b)\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,/]+))?",
+                DegreeLevel::Bachelor,
+            ),
+            // Associate patterns
+            (
+                r"(?i)(?:\bassociate'?s?\b|\ba\.s\.(?:\s|$)|\ba\.a\.(?:\s|$))\s*(?:degree)?(?:\s+in\s+([a-zA-Z\s,/]+))?",
+                DegreeLevel::Associate,
+            ),
+        ];
+
+        let mut best_match: Option<(DegreeLevel, Vec<String>)> = None;
+
+        for (pattern, level) in degree_patterns {
+            if let Ok(re) = Regex::new(pattern) {
+                if let Some(cap) = re.captures(text) {
+                    let fields = cap
+                        .get(1)
+                        .map(|m| parse_fields(m.as_str()))
+                        .unwrap_or_default();
+
+                    // Take highest degree level found
+                    if best_match
+                        .as_ref()
+                        .is_none_or(|(best_level, _)| level > *best_level)
+                    {
+                        best_match = Some((level, fields));
+                    }
+                }
+            }
+        }
+
+        // Also check for "degree required" without specific type (assume Bachelor's)
+        if best_match.is_none()
+            && (lower.contains("degree required") || lower.contains("college degree"))
+        {
+            best_match = Some((DegreeLevel::Bachelor, vec![]));
+        }
+
+        // Determine if required or preferred
+        let is_required = !lower.contains("preferred") && !lower.contains("nice to have");
+        let equivalent_experience = EQUIVALENT_EXPERIENCE_PHRASES
+            .iter()
+            .any(|phrase| lower.contains(phrase));
+
+        best_match.map(|(level, fields)| EducationRequirement {
+            degree_level: level,
+            fields,
+            is_required,
+            equivalent_experience,
+        })
+    }
+}
+
+/// Split a captured "in ..." phrase into fields of study, dropping
+/// "related field" filler and trailing requirement words.
+fn parse_fields(captured: &str) -> Vec<String> {
+    captured
+        .split([',', '/'])
+        .flat_map(|part| part.split(" or "))
+        .filter_map(|part| {
+            let words: Vec<&str> = part
+                .split_whitespace()
+                .take_while(|word| !FIELD_STOP_WORDS.contains(&word.to_lowercase().as_str()))
+                .collect();
+            let field = words.join(" ");
+            let lower = field.to_lowercase();
+            (!field.is_empty() && !lower.contains("related") && !lower.contains("equivalent"))
+                .then_some(field)
+        })
+        .collect()
+}
+
+/// Compare a job's education requirement with the resume text.
+pub(super) fn compare_education(
+    requirement: &EducationRequirement,
+    resume_text: Option<&str>,
+) -> EducationMatch {
+    let resume_degree = resume_text.and_then(DegreeLevel::from_text);
+    let field_matched = (!requirement.fields.is_empty()).then(|| {
+        let resume_lower = resume_text.unwrap_or_default().to_lowercase();
+        requirement
+            .fields
+            .iter()
+            .any(|field| resume_lower.contains(&field.to_lowercase()))
+    });
+
+    EducationMatch {
+        requirement: requirement.clone(),
+        resume_degree,
+        field_matched,
+        meets_level: resume_degree.is_some_and(|degree| degree >= requirement.degree_level),
+    }
+}
+
+/// Calculate education match score
+pub(super) fn calculate_education_match(
+    user_education: Option<DegreeLevel>,
+    requirement: Option<&EducationRequirement>,
+) -> f64 {
+    match (user_education, requirement) {
+        (_, None) => 1.0,       // No requirement = full match
+        (None, Some(_)) => 0.0, // Requirement but no user education
+        (Some(user), Some(req)) => {
+            if user >= req.degree_level {
+                1.0 // Meets or exceeds
+            } else {
+                // Partial credit based on level difference
+                let user_level = user as i32;
+                let req_level = req.degree_level as i32;
+                if req_level > 0 {
+                    (user_level as f64) / (req_level as f64)
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
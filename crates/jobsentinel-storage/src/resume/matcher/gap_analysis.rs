@@ -118,8 +118,10 @@ fn append_education_section(analysis: &mut String, education_req: Option<&Educat
         return;
     };
 
-    let required_label = if req.is_required {
+    let required_label = if req.is_hard() {
         "required"
+    } else if req.is_required {
+        "required, or equivalent experience"
     } else {
         "preferred"
     };
@@ -1,9 +1,8 @@
 use super::*;
 
 impl JobMatcher {
-    /// Get user's education level from their resume
-    pub(super) async fn get_user_education(&self, resume_id: i64) -> Result<Option<DegreeLevel>> {
-        // Try to get education from parsed resume text
+    /// Get the parsed resume text used for education comparison
+    pub(super) async fn get_resume_text(&self, resume_id: i64) -> Result<Option<String>> {
         let Some(row) = sqlx::query(
             r#"
             SELECT parsed_text
@@ -19,12 +18,8 @@ impl JobMatcher {
             return Ok(None);
         };
 
-        let parsed_text: Option<String> = row
-            .try_get("parsed_text")
-            .with_context(|| format!("failed to decode parsed_text for resume {resume_id}"))?;
-
-        // Extract education level from resume text
-        Ok(parsed_text.as_deref().and_then(DegreeLevel::from_text))
+        row.try_get("parsed_text")
+            .with_context(|| format!("failed to decode parsed_text for resume {resume_id}"))
     }
 
     /// Get job by hash
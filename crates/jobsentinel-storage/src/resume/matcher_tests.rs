@@ -349,6 +349,59 @@ async fn test_education_detection_keeps_degree_requirement() {
     assert!(requirement.is_required);
 }
 
+#[tokio::test]
+async fn test_education_detection_separates_equivalent_experience_and_fields() {
+    let matcher = JobMatcher::new(SqlitePool::connect("sqlite::memory:").await.unwrap());
+
+    let requirement = matcher
+        .extract_education_requirements(
+            "Bachelor's degree in Social Work or related field required, or equivalent experience.",
+        )
+        .unwrap();
+
+    assert_eq!(requirement.degree_level, DegreeLevel::Bachelor);
+    assert_eq!(requirement.fields, vec!["Social Work".to_string()]);
+    assert!(requirement.is_required);
+    assert!(requirement.equivalent_experience);
+    assert!(!requirement.is_hard());
+}
+
+#[tokio::test]
+async fn test_calculate_match_reports_hard_degree_gap() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = JobMatcher::new(pool.clone());
+
+    let job_hash = "job_hard_degree";
+    sqlx::query(
+        "INSERT INTO jobs (hash, title, company, description, url, source) VALUES (?, 'Clinical Case Manager', 'Harbor Community Services', ?, 'https://example.com/job', 'greenhouse')",
+    )
+    .bind(job_hash)
+    .bind("Master's degree in Social Work required. Case Management experience.")
+    .execute(&pool)
+    .await
+    .unwrap();
+    let resume_id = sqlx::query(
+        "INSERT INTO resumes (name, file_path, parsed_text, is_active) VALUES ('Resume', 'fixtures/resumes/r.pdf', ?, 1)",
+    )
+    .bind("Bachelor of Arts in Social Work. Case Management, care coordination.")
+    .execute(&pool)
+    .await
+    .unwrap()
+    .last_insert_rowid();
+
+    let result = matcher.calculate_match(resume_id, job_hash).await.unwrap();
+    let education = result.education.expect("education comparison");
+
+    assert_eq!(education.resume_degree, Some(DegreeLevel::Bachelor));
+    assert_eq!(education.field_matched, Some(true));
+    assert!(education.is_hard_gap());
+    assert!(result.overall_match_score <= 0.6);
+    assert_eq!(
+        education.explanation(),
+        "Education: Master's in Social Work required (resume: Bachelor's; not met)"
+    );
+}
+
 #[tokio::test]
 async fn test_calculate_match_errors_when_education_lookup_fails() {
     let pool = crate::test_support::migrated_pool().await;
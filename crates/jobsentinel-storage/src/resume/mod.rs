@@ -8,8 +8,11 @@
 //! - **Skill Extraction** - Identify technical, workplace, and role-specific skills
 //! - **Semantic Matching** - Compare resume skills against job requirements
 //! - **Gap Analysis** - Identify missing skills and strengths
+//! - **Best Fits** - Match one resume against every active job in batches
+//! - **Resume Routing** - Recommend which of several resumes to send for a job
 //! - **Application-readable Templates** - 5 professional resume templates
 //! - **Resume Builder** - Interactive resume creation with CRUD operations
+//! - **Versions** - Compare uploads and builder saves by skills, sections, and job fit
 //! - **Resume Readability Analyzer** - job-word extraction and format clarity checks
 //!
 //! ## Usage
@@ -36,15 +39,20 @@ use anyhow::Result;
 use sqlx::{Row, SqlitePool};
 use std::path::Path;
 
+mod batch_matching;
 mod builder;
 mod json_import;
 mod json_resume;
 mod management;
 mod matcher;
+mod routing;
+mod skill_evidence;
 mod skill_store;
+mod versions;
 
 use jobsentinel_documents::{ResumeParser, SkillExtractor};
 use matcher::JobMatcher;
+pub(crate) use skill_store::store_job_skills;
 
 pub(crate) mod skills {
     pub(crate) use jobsentinel_documents::SkillExtractor;
@@ -54,21 +62,27 @@ pub(crate) mod types {
     #[cfg(test)]
     pub(crate) use jobsentinel_documents::UserSkill;
     pub(crate) use jobsentinel_documents::{
-        DegreeLevel, EducationRequirement, ExperienceRequirement, NewSkill, NullableFieldUpdate,
-        SkillUpdate,
+        DegreeLevel, EducationMatch, EducationRequirement, ExperienceRequirement, NewSkill,
+        NullableFieldUpdate, SkillUpdate,
     };
 }
 
+pub use batch_matching::ResumeMatchProgress;
 pub use builder::{DraftEducation, DraftExperience, DraftSkill, ResumeBuilder, ResumeDraft};
+pub use routing::{ResumeFit, ResumeSuggestion};
+pub use skill_evidence::{NewSkillEvidence, SkillEvidence, SkillEvidenceKind};
+pub use versions::{ResumeVersion, ResumeVersionComparison, ResumeVersionSource};
 
 pub use jobsentinel_documents::{
-    AtsAnalysisResult, AtsAnalyzer, AtsSuggestion, DegreeLevel, EducationRequirement,
-    ExperienceRequirement, FormatIssue, HardConstraintCategory, HardConstraintRisk, IssueSeverity,
-    JobSkill, KeywordImportance, KeywordMatch, MatchResult, MatchResultWithJob, MissingKeyword,
-    NewSkill, RequirementMatchState, RequirementReview, Resume, ResumeAnalysisInput,
-    ResumeCertification, ResumeEducation, ResumeExperience, ResumeExporter, ResumePersonalInfo,
-    ResumeProject, ResumeSkill, ResumeSkillCategory, SkillUpdate, StructuredResume,
-    SuggestionCategory, Template, TemplateId, TemplateRenderer, UserSkill,
+    redact_resume, redact_resume_text, AtsAnalysisResult, AtsAnalyzer, AtsSuggestion, DegreeLevel,
+    EducationMatch, EducationRequirement, ExperienceRequirement, FormatIssue,
+    HardConstraintCategory, HardConstraintRisk, IssueSeverity, JobSkill, KeywordImportance,
+    KeywordMatch, MatchResult, MatchResultWithJob, MissingKeyword, NewSkill, RequirementMatchState,
+    RequirementReview, Resume, ResumeAnalysisInput, ResumeCertification, ResumeEducation,
+    ResumeExperience, ResumeExporter, ResumePersonalInfo, ResumeProject, ResumeRedactionRules,
+    ResumeSection, ResumeSectionChange, ResumeSectionChangeKind, ResumeSkill, ResumeSkillCategory,
+    SkillUpdate, StructuredResume, SuggestionCategory, Template, TemplateId, TemplateRenderer,
+    UserSkill,
 };
 
 /// Main resume matcher service
@@ -111,6 +125,7 @@ impl ResumeMatcher {
 
         // Extract skills automatically
         self.extract_skills(resume_id).await?;
+        self.record_upload_version(resume_id).await?;
 
         Ok(resume_id)
     }
@@ -313,6 +328,7 @@ impl ResumeMatcher {
                     matching_skills: serde_json::from_str(&matching_skills_str)?,
                     gap_analysis: r.try_get::<Option<String>, _>("gap_analysis")?,
                     created_at,
+                    education: None,
                 }))
             }
             None => Ok(None),
@@ -0,0 +1,64 @@
+//! Choosing which of several resumes to send for a job
+
+use super::*;
+use serde::Serialize;
+
+/// How well one resume fits a job
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResumeFit {
+    pub resume_id: i64,
+    pub name: String,
+    pub is_active: bool,
+    pub overall_match_score: f64,
+    pub skills_match_score: Option<f64>,
+    pub matching_skills: Vec<String>,
+    pub missing_skills: Vec<String>,
+}
+
+/// Every resume's fit for a job, best first, and the one to send
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResumeSuggestion {
+    pub job_hash: String,
+    /// Best-fitting resume; `None` when no resume has been uploaded
+    pub recommended_resume_id: Option<i64>,
+    pub candidates: Vec<ResumeFit>,
+}
+
+impl ResumeMatcher {
+    /// Score every uploaded resume against a job and recommend the best fit.
+    ///
+    /// A saved match for a resume and job is reused; resumes without one are
+    /// matched now and the result is saved. Ties go to the active resume,
+    /// then to the oldest.
+    pub async fn suggest_resume_for_job(&self, job_hash: &str) -> Result<ResumeSuggestion> {
+        let mut candidates = Vec::new();
+        for resume in self.list_all_resumes().await? {
+            let result = match self.get_match_result(resume.id, job_hash).await? {
+                Some(result) => result,
+                None => self.match_resume_to_job(resume.id, job_hash).await?,
+            };
+            candidates.push(ResumeFit {
+                resume_id: resume.id,
+                name: resume.name,
+                is_active: resume.is_active,
+                overall_match_score: result.overall_match_score,
+                skills_match_score: result.skills_match_score,
+                matching_skills: result.matching_skills,
+                missing_skills: result.missing_skills,
+            });
+        }
+
+        candidates.sort_by(|a, b| {
+            b.overall_match_score
+                .total_cmp(&a.overall_match_score)
+                .then(b.is_active.cmp(&a.is_active))
+                .then(a.resume_id.cmp(&b.resume_id))
+        });
+
+        Ok(ResumeSuggestion {
+            job_hash: job_hash.to_string(),
+            recommended_resume_id: candidates.first().map(|fit| fit.resume_id),
+            candidates,
+        })
+    }
+}
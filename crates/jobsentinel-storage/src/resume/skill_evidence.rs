@@ -0,0 +1,227 @@
+//! Evidence backing resume skills
+//!
+//! Projects, repositories, and certifications the user attaches to a skill.
+//! Evidence is shown next to resume matches for jobs that need the skill, and
+//! certifications with an expiry date drive renewal warnings.
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDate, Utc};
+use jobsentinel_security::validate_external_http_url;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+use super::skill_store::{ensure_user_skill_exists, normalize_optional_skill_text};
+use super::ResumeMatcher;
+
+const MAX_EVIDENCE_TITLE_CHARS: usize = 200;
+const MAX_EVIDENCE_URL_CHARS: usize = 2000;
+const EVIDENCE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// What kind of proof backs a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillEvidenceKind {
+    Project,
+    Repository,
+    Certification,
+    Other,
+}
+
+impl SkillEvidenceKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::Repository => "repository",
+            Self::Certification => "certification",
+            Self::Other => "other",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "project" => Self::Project,
+            "repository" => Self::Repository,
+            "certification" => Self::Certification,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Evidence attached to a resume skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillEvidence {
+    pub id: i64,
+    pub skill_id: i64,
+    pub skill_name: String,
+    pub kind: SkillEvidenceKind,
+    pub title: String,
+    pub url: Option<String>,
+    /// `YYYY-MM-DD`
+    pub issued_on: Option<String>,
+    /// `YYYY-MM-DD`; only meaningful for certifications
+    pub expires_on: Option<String>,
+    pub created_at: String,
+}
+
+/// Evidence to attach to a skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewSkillEvidence {
+    pub kind: SkillEvidenceKind,
+    pub title: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub issued_on: Option<String>,
+    #[serde(default)]
+    pub expires_on: Option<String>,
+}
+
+fn evidence_from_row(row: &SqliteRow) -> Result<SkillEvidence> {
+    Ok(SkillEvidence {
+        id: row.try_get("id")?,
+        skill_id: row.try_get("skill_id")?,
+        skill_name: row.try_get("skill_name")?,
+        kind: SkillEvidenceKind::parse(&row.try_get::<String, _>("kind")?),
+        title: row.try_get("title")?,
+        url: row.try_get("url")?,
+        issued_on: row.try_get("issued_on")?,
+        expires_on: row.try_get("expires_on")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn parse_evidence_date(value: Option<String>, label: &str) -> Result<Option<NaiveDate>> {
+    normalize_optional_skill_text(value)
+        .map(|text| {
+            NaiveDate::parse_from_str(&text, EVIDENCE_DATE_FORMAT)
+                .map_err(|_| anyhow::anyhow!("{label} must be a YYYY-MM-DD date"))
+        })
+        .transpose()
+}
+
+fn validate_evidence_url(value: Option<String>) -> Result<Option<String>> {
+    let Some(url) = normalize_optional_skill_text(value) else {
+        return Ok(None);
+    };
+    if url.len() > MAX_EVIDENCE_URL_CHARS {
+        anyhow::bail!("Evidence link is too long");
+    }
+    validate_external_http_url(&url)
+        .map_err(|reason| anyhow::anyhow!("Invalid evidence link: {reason}"))?;
+    Ok(Some(url))
+}
+
+impl ResumeMatcher {
+    /// Attach evidence to a user skill
+    pub async fn add_skill_evidence(
+        &self,
+        skill_id: i64,
+        evidence: NewSkillEvidence,
+    ) -> Result<i64> {
+        let title = evidence.title.trim();
+        if title.is_empty() {
+            anyhow::bail!("Evidence title is required");
+        }
+        if title.chars().count() > MAX_EVIDENCE_TITLE_CHARS {
+            anyhow::bail!("Evidence title must be {MAX_EVIDENCE_TITLE_CHARS} characters or fewer");
+        }
+        let url = validate_evidence_url(evidence.url)?;
+        let issued_on = parse_evidence_date(evidence.issued_on, "Issue date")?;
+        let expires_on = parse_evidence_date(evidence.expires_on, "Expiry date")?;
+        if let (Some(issued), Some(expires)) = (issued_on, expires_on) {
+            if expires < issued {
+                anyhow::bail!("Expiry date cannot be before the issue date");
+            }
+        }
+
+        ensure_user_skill_exists(&self.db, skill_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO skill_evidence (skill_id, kind, title, url, issued_on, expires_on)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(skill_id)
+        .bind(evidence.kind.as_str())
+        .bind(title)
+        .bind(url)
+        .bind(issued_on.map(|date| date.format(EVIDENCE_DATE_FORMAT).to_string()))
+        .bind(expires_on.map(|date| date.format(EVIDENCE_DATE_FORMAT).to_string()))
+        .execute(&self.db)
+        .await?;
+
+        let evidence_id = result.last_insert_rowid();
+        tracing::info!(
+            skill_id,
+            evidence_id,
+            kind = evidence.kind.as_str(),
+            "Added skill evidence"
+        );
+        Ok(evidence_id)
+    }
+
+    /// Get all evidence for a resume's skills
+    pub async fn get_skill_evidence(&self, resume_id: i64) -> Result<Vec<SkillEvidence>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT e.id, e.skill_id, s.skill_name, e.kind, e.title, e.url,
+                   e.issued_on, e.expires_on, e.created_at
+            FROM skill_evidence e
+            JOIN user_skills s ON s.id = e.skill_id
+            WHERE s.resume_id = ?
+            ORDER BY s.skill_name ASC, e.id ASC
+            "#,
+        )
+        .bind(resume_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        rows.iter().map(evidence_from_row).collect()
+    }
+
+    /// Delete a piece of skill evidence
+    pub async fn delete_skill_evidence(&self, evidence_id: i64) -> Result<()> {
+        let result = sqlx::query("DELETE FROM skill_evidence WHERE id = ?")
+            .bind(evidence_id)
+            .execute(&self.db)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Skill evidence with id {} not found", evidence_id);
+        }
+
+        tracing::info!("Deleted skill evidence {}", evidence_id);
+        Ok(())
+    }
+
+    /// Certifications that expire within `within_days`, including lapsed ones
+    pub async fn get_expiring_skill_evidence(
+        &self,
+        resume_id: i64,
+        within_days: u32,
+    ) -> Result<Vec<SkillEvidence>> {
+        let cutoff = Utc::now().date_naive() + Duration::days(i64::from(within_days));
+
+        let rows = sqlx::query(
+            r#"
+            SELECT e.id, e.skill_id, s.skill_name, e.kind, e.title, e.url,
+                   e.issued_on, e.expires_on, e.created_at
+            FROM skill_evidence e
+            JOIN user_skills s ON s.id = e.skill_id
+            WHERE s.resume_id = ?
+              AND e.kind = 'certification'
+              AND e.expires_on IS NOT NULL
+              AND e.expires_on <= ?
+            ORDER BY e.expires_on ASC, s.skill_name ASC
+            "#,
+        )
+        .bind(resume_id)
+        .bind(cutoff.format(EVIDENCE_DATE_FORMAT).to_string())
+        .fetch_all(&self.db)
+        .await?;
+
+        rows.iter().map(evidence_from_row).collect()
+    }
+}
@@ -1,9 +1,46 @@
 use anyhow::Result;
-use sqlx::{Row, SqlitePool};
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::sync::LazyLock;
 
+use super::skills::SkillExtractor;
 use super::{types, ResumeMatcher, UserSkill};
 use types::NullableFieldUpdate;
 
+static JOB_SKILL_EXTRACTOR: LazyLock<SkillExtractor> = LazyLock::new(SkillExtractor::new);
+
+/// Extract the skills named in a job's title and description and upsert them
+/// into `job_skills`, returning the skill names.
+///
+/// Job storage and resume matching both extract through here.
+pub(crate) async fn store_job_skills(
+    conn: &mut SqliteConnection,
+    job_hash: &str,
+    title: &str,
+    description: &str,
+) -> Result<Vec<String>, sqlx::Error> {
+    let extracted_skills = JOB_SKILL_EXTRACTOR.extract_skills(&format!("{title} {description}"));
+    for skill in &extracted_skills {
+        sqlx::query(
+            r#"
+            INSERT INTO job_skills (job_hash, skill_name, is_required, skill_category)
+            VALUES (?, ?, 1, ?)
+            ON CONFLICT(job_hash, skill_name) DO UPDATE SET
+                skill_category = excluded.skill_category
+            "#,
+        )
+        .bind(job_hash)
+        .bind(&skill.skill_name)
+        .bind(&skill.skill_category)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(extracted_skills
+        .into_iter()
+        .map(|skill| skill.skill_name)
+        .collect())
+}
+
 pub(super) async fn query_user_skills(db: &SqlitePool, resume_id: i64) -> Result<Vec<UserSkill>> {
     let rows = sqlx::query(
         r#"
@@ -164,7 +201,7 @@ fn normalize_skill_name(name: &str) -> Result<String> {
     Ok(trimmed.to_string())
 }
 
-async fn ensure_user_skill_exists(db: &SqlitePool, skill_id: i64) -> Result<()> {
+pub(super) async fn ensure_user_skill_exists(db: &SqlitePool, skill_id: i64) -> Result<()> {
     let row = sqlx::query("SELECT id FROM user_skills WHERE id = ?")
         .bind(skill_id)
         .fetch_optional(db)
@@ -177,7 +214,7 @@ async fn ensure_user_skill_exists(db: &SqlitePool, skill_id: i64) -> Result<()>
     Ok(())
 }
 
-fn normalize_optional_skill_text(value: Option<String>) -> Option<String> {
+pub(super) fn normalize_optional_skill_text(value: Option<String>) -> Option<String> {
     value.and_then(|text| {
         let trimmed = text.trim();
         if trimmed.is_empty() {
@@ -369,3 +369,9 @@ mod database_coverage_tests;
 
 #[path = "tests/skill_matching_tests.rs"]
 mod skill_matching_tests;
+
+#[path = "tests/skill_evidence_tests.rs"]
+mod skill_evidence_tests;
+
+#[path = "tests/version_tests.rs"]
+mod version_tests;
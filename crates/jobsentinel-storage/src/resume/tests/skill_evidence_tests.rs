@@ -0,0 +1,177 @@
+use super::*;
+use crate::resume::{NewSkillEvidence, SkillEvidenceKind};
+use chrono::{Duration, Utc};
+
+fn evidence(kind: SkillEvidenceKind, title: &str) -> NewSkillEvidence {
+    NewSkillEvidence {
+        kind,
+        title: title.to_string(),
+        url: None,
+        issued_on: None,
+        expires_on: None,
+    }
+}
+
+async fn skill_id(pool: &SqlitePool, resume_id: i64, skill_name: &str) -> i64 {
+    sqlx::query("SELECT id FROM user_skills WHERE resume_id = ? AND skill_name = ?")
+        .bind(resume_id)
+        .bind(skill_name)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .get("id")
+}
+
+fn days_from_today(days: i64) -> String {
+    (Utc::now().date_naive() + Duration::days(days))
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_add_and_list_skill_evidence() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let resume_id = create_test_resume(&pool, "Evidence Resume", "Python and Rust").await;
+    let rust_id = skill_id(&pool, resume_id, "Rust").await;
+
+    let evidence_id = matcher
+        .add_skill_evidence(
+            rust_id,
+            NewSkillEvidence {
+                url: Some(" https://github.com/example/scheduler ".to_string()),
+                ..evidence(SkillEvidenceKind::Repository, "  Shift scheduler  ")
+            },
+        )
+        .await
+        .unwrap();
+
+    let stored = matcher.get_skill_evidence(resume_id).await.unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].id, evidence_id);
+    assert_eq!(stored[0].skill_name, "Rust");
+    assert_eq!(stored[0].kind, SkillEvidenceKind::Repository);
+    assert_eq!(stored[0].title, "Shift scheduler");
+    assert_eq!(
+        stored[0].url.as_deref(),
+        Some("https://github.com/example/scheduler")
+    );
+
+    matcher.delete_skill_evidence(evidence_id).await.unwrap();
+    assert!(matcher
+        .get_skill_evidence(resume_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(matcher.delete_skill_evidence(evidence_id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_add_skill_evidence_rejects_invalid_input() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let resume_id = create_test_resume(&pool, "Evidence Resume", "Python").await;
+    let python_id = skill_id(&pool, resume_id, "Python").await;
+
+    let blank_title = matcher
+        .add_skill_evidence(python_id, evidence(SkillEvidenceKind::Project, "  "))
+        .await;
+    let bad_url = matcher
+        .add_skill_evidence(
+            python_id,
+            NewSkillEvidence {
+                url: Some("file:///etc/passwd".to_string()),
+                ..evidence(SkillEvidenceKind::Project, "Portfolio")
+            },
+        )
+        .await;
+    let bad_date = matcher
+        .add_skill_evidence(
+            python_id,
+            NewSkillEvidence {
+                expires_on: Some("next spring".to_string()),
+                ..evidence(SkillEvidenceKind::Certification, "PCEP")
+            },
+        )
+        .await;
+    let reversed_dates = matcher
+        .add_skill_evidence(
+            python_id,
+            NewSkillEvidence {
+                issued_on: Some("2026-05-01".to_string()),
+                expires_on: Some("2025-05-01".to_string()),
+                ..evidence(SkillEvidenceKind::Certification, "PCEP")
+            },
+        )
+        .await;
+    let missing_skill = matcher
+        .add_skill_evidence(99_999, evidence(SkillEvidenceKind::Project, "Portfolio"))
+        .await;
+
+    assert!(blank_title.is_err());
+    assert!(bad_url.is_err());
+    assert!(bad_date.is_err());
+    assert!(reversed_dates.is_err());
+    assert!(missing_skill.is_err());
+    assert!(matcher
+        .get_skill_evidence(resume_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_expiring_evidence_includes_lapsed_certifications_only() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let resume_id = create_test_resume(&pool, "Evidence Resume", "Python").await;
+    let python_id = skill_id(&pool, resume_id, "Python").await;
+    for (title, kind, expires_in) in [
+        ("Lapsed cert", SkillEvidenceKind::Certification, -3),
+        ("Soon cert", SkillEvidenceKind::Certification, 10),
+        ("Distant cert", SkillEvidenceKind::Certification, 400),
+        ("Project", SkillEvidenceKind::Project, 5),
+    ] {
+        matcher
+            .add_skill_evidence(
+                python_id,
+                NewSkillEvidence {
+                    expires_on: Some(days_from_today(expires_in)),
+                    ..evidence(kind, title)
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let expiring = matcher
+        .get_expiring_skill_evidence(resume_id, 30)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = expiring.iter().map(|item| item.title.as_str()).collect();
+
+    assert_eq!(titles, vec!["Lapsed cert", "Soon cert"]);
+}
+
+#[tokio::test]
+async fn test_deleting_skill_removes_its_evidence() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let resume_id = create_test_resume(&pool, "Evidence Resume", "Python").await;
+    let python_id = skill_id(&pool, resume_id, "Python").await;
+    matcher
+        .add_skill_evidence(
+            python_id,
+            evidence(SkillEvidenceKind::Other, "Course notes"),
+        )
+        .await
+        .unwrap();
+
+    matcher.delete_user_skill(python_id).await.unwrap();
+
+    assert!(matcher
+        .get_skill_evidence(resume_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::resume::ResumeMatchProgress;
 
 #[tokio::test]
 async fn test_extract_skills_from_resume() {
@@ -190,3 +191,108 @@ async fn test_match_resume_to_job_no_overlap() {
     assert!(match_result.matching_skills.is_empty() || match_result.matching_skills.len() <= 1);
     assert!(!match_result.missing_skills.is_empty());
 }
+
+#[tokio::test]
+async fn test_match_resume_to_all_jobs_skips_inactive_and_low_scoring_jobs() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let resume_id = create_test_resume(&pool, "Operations Resume", "Excel, CRM, reporting").await;
+
+    create_test_job(
+        &pool,
+        "strong_fit",
+        "Reporting Analyst",
+        "Excel, CRM, reporting",
+    )
+    .await;
+    create_test_job(
+        &pool,
+        "weak_fit",
+        "Forklift Operator",
+        "Forklift, warehouse",
+    )
+    .await;
+    create_test_job(&pool, "hidden_job", "Reporting Lead", "Excel, reporting").await;
+    create_test_job(&pool, "closed_job", "CRM Specialist", "CRM, Excel").await;
+    create_test_job(&pool, "low_score_job", "CRM Analyst", "CRM, reporting").await;
+    sqlx::query("UPDATE jobs SET hidden = 1 WHERE hash = 'hidden_job'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE jobs SET status = 'closed' WHERE hash = 'closed_job'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE jobs SET score = 0.2 WHERE hash = 'low_score_job'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let reports = std::sync::Mutex::new(Vec::new());
+    let progress = matcher
+        .match_resume_to_all_jobs(resume_id, 0.5, |progress| {
+            reports.lock().unwrap().push(progress);
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        progress,
+        ResumeMatchProgress {
+            jobs_matched: 2,
+            jobs_total: 2
+        }
+    );
+    assert_eq!(
+        reports.into_inner().unwrap().first().unwrap().jobs_matched,
+        0
+    );
+
+    let best = matcher.get_best_matches(resume_id, 10).await.unwrap();
+    let hashes: Vec<&str> = best.iter().map(|m| m.job_hash.as_str()).collect();
+    assert_eq!(hashes, vec!["strong_fit", "weak_fit"]);
+    assert!(best[0].overall_match_score >= best[1].overall_match_score);
+    assert_eq!(best[0].job_title, "Reporting Analyst");
+}
+
+#[tokio::test]
+async fn test_match_resume_to_all_jobs_rejects_unknown_resume() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    create_test_job(&pool, "job_1", "Reporting Analyst", "Excel").await;
+
+    let result = matcher.match_resume_to_all_jobs(999, 0.0, |_| {}).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_suggest_resume_for_job_recommends_best_fit() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool.clone());
+    let care_id = create_test_resume(&pool, "Care Resume", "Scheduling, case notes").await;
+    let reporting_id = create_test_resume(&pool, "Reporting Resume", "Excel, CRM, reporting").await;
+    create_test_job(
+        &pool,
+        "reporting_job",
+        "Data Reporting Specialist",
+        "Excel, CRM, reporting",
+    )
+    .await;
+
+    let suggestion = matcher
+        .suggest_resume_for_job("reporting_job")
+        .await
+        .unwrap();
+
+    assert_eq!(suggestion.recommended_resume_id, Some(reporting_id));
+    let ids: Vec<i64> = suggestion
+        .candidates
+        .iter()
+        .map(|fit| fit.resume_id)
+        .collect();
+    assert_eq!(ids, vec![reporting_id, care_id]);
+    assert!(
+        suggestion.candidates[0].overall_match_score > suggestion.candidates[1].overall_match_score
+    );
+}
@@ -0,0 +1,103 @@
+use super::*;
+use crate::resume::{DraftSkill, ResumeSectionChangeKind, ResumeSkill, ResumeVersionSource};
+
+fn draft_skill(name: &str) -> DraftSkill {
+    DraftSkill {
+        category: "Technical".to_string(),
+        skill: ResumeSkill {
+            name: name.to_string(),
+            ..ResumeSkill::default()
+        },
+    }
+}
+
+#[tokio::test]
+async fn builder_saves_record_versions_only_when_content_changes() {
+    let pool = crate::test_support::migrated_pool().await;
+    let builder = ResumeBuilder::new(pool.clone());
+    let matcher = ResumeMatcher::new(pool);
+
+    let draft_id = builder.create_resume().await.unwrap();
+    builder
+        .set_skills(draft_id, vec![draft_skill("Python")])
+        .await
+        .unwrap();
+    builder
+        .set_skills(draft_id, vec![draft_skill("Python")])
+        .await
+        .unwrap();
+    builder
+        .update_summary(draft_id, "Data engineer.".to_string())
+        .await
+        .unwrap();
+
+    let versions = matcher.list_resume_versions().await.unwrap();
+
+    assert_eq!(versions.len(), 2);
+    assert!(versions
+        .iter()
+        .all(|version| version.source == ResumeVersionSource::Builder
+            && version.source_key == draft_id.to_string()));
+    assert_eq!(versions[0].version, 2);
+    assert_eq!(versions[1].version, 1);
+}
+
+#[tokio::test]
+async fn compare_resume_versions_reports_skill_section_and_match_changes() {
+    let pool = crate::test_support::migrated_pool().await;
+    let builder = ResumeBuilder::new(pool.clone());
+    let matcher = ResumeMatcher::new(pool.clone());
+    create_test_job(
+        &pool,
+        "versions-job",
+        "Data Engineer",
+        "We need Python, SQL, and Docker experience.",
+    )
+    .await;
+
+    let draft_id = builder.create_resume().await.unwrap();
+    builder
+        .set_skills(draft_id, vec![draft_skill("Python")])
+        .await
+        .unwrap();
+    builder
+        .set_skills(
+            draft_id,
+            vec![
+                draft_skill("Python"),
+                draft_skill("SQL"),
+                draft_skill("Docker"),
+            ],
+        )
+        .await
+        .unwrap();
+    builder
+        .update_summary(draft_id, "Data engineer.".to_string())
+        .await
+        .unwrap();
+    let versions = matcher.list_resume_versions().await.unwrap();
+    let (first, last) = (versions[2].id, versions[0].id);
+
+    let comparison = matcher.compare_resume_versions(first, last).await.unwrap();
+
+    assert_eq!(comparison.added_skills, vec!["SQL", "Docker"]);
+    assert!(comparison.removed_skills.is_empty());
+    assert!(comparison.section_changes.iter().any(|change| {
+        change.heading == "SUMMARY" && change.change == ResumeSectionChangeKind::Added
+    }));
+    assert!(comparison.section_changes.iter().any(|change| {
+        change.heading == "SKILLS" && change.change == ResumeSectionChangeKind::Changed
+    }));
+    assert_eq!(comparison.jobs_compared, 1);
+    let change = comparison.average_match_change.unwrap();
+    assert!(change > 0.0, "expected a better match, got {change}");
+    assert!(comparison.to_average_match.unwrap() > comparison.from_average_match.unwrap());
+}
+
+#[tokio::test]
+async fn compare_resume_versions_rejects_unknown_versions() {
+    let pool = crate::test_support::migrated_pool().await;
+    let matcher = ResumeMatcher::new(pool);
+
+    assert!(matcher.compare_resume_versions(1, 2).await.is_err());
+}
@@ -0,0 +1,313 @@
+//! Resume versions
+//!
+//! Every resume upload and every changed builder save is kept as a version,
+//! so two versions can be compared by skills, sections, and how well each
+//! fits recent jobs.
+
+use anyhow::{Context, Result};
+use jobsentinel_documents::{
+    diff_resume_sections, split_resume_sections, ResumeSection, ResumeSectionChange,
+    StructuredResume, TemplateRenderer,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+
+use super::ResumeMatcher;
+
+/// Most recent active jobs scored when comparing two versions
+const RECENT_JOBS_COMPARED: i64 = 50;
+
+/// Where a resume version came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResumeVersionSource {
+    /// A resume file upload; uploads with the same name share a history
+    Upload,
+    /// A save of a resume builder draft
+    Builder,
+}
+
+impl ResumeVersionSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Upload => "upload",
+            Self::Builder => "builder",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "builder" => Self::Builder,
+            _ => Self::Upload,
+        }
+    }
+}
+
+/// A saved version of an uploaded resume or builder draft
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumeVersion {
+    pub id: i64,
+    pub source: ResumeVersionSource,
+    /// Lowercased name for uploads, draft id for builder saves
+    pub source_key: String,
+    /// 1 for the first version of each upload name or draft
+    pub version: i64,
+    pub name: String,
+    /// Uploaded resume row, while it still exists
+    pub resume_id: Option<i64>,
+    pub skills: Vec<String>,
+    pub sections: Vec<ResumeSection>,
+    pub created_at: String,
+}
+
+/// Differences between two resume versions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResumeVersionComparison {
+    pub from: ResumeVersion,
+    pub to: ResumeVersion,
+    /// Skills in `to` but not in `from`, ignoring case
+    pub added_skills: Vec<String>,
+    /// Skills in `from` but not in `to`, ignoring case
+    pub removed_skills: Vec<String>,
+    pub section_changes: Vec<ResumeSectionChange>,
+    /// Recent active jobs with known skills that both versions were scored against
+    pub jobs_compared: usize,
+    /// Average share of those jobs' skills covered by `from`
+    pub from_average_match: Option<f64>,
+    /// Average share of those jobs' skills covered by `to`
+    pub to_average_match: Option<f64>,
+    /// `to_average_match - from_average_match`
+    pub average_match_change: Option<f64>,
+}
+
+fn version_from_row(row: &SqliteRow) -> Result<ResumeVersion> {
+    let sections: String = row.try_get("sections")?;
+    let skills: String = row.try_get("skills")?;
+    Ok(ResumeVersion {
+        id: row.try_get("id")?,
+        source: ResumeVersionSource::parse(&row.try_get::<String, _>("source")?),
+        source_key: row.try_get("source_key")?,
+        version: row.try_get("version")?,
+        name: row.try_get("name")?,
+        resume_id: row.try_get("resume_id")?,
+        skills: serde_json::from_str(&skills).context("Failed to read resume version skills")?,
+        sections: serde_json::from_str(&sections)
+            .context("Failed to read resume version sections")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+/// Save a new version unless the sections and skills match the latest one.
+///
+/// Returns the new version's id, or `None` when nothing changed.
+pub(super) async fn record_resume_version(
+    db: &SqlitePool,
+    source: ResumeVersionSource,
+    source_key: &str,
+    name: &str,
+    resume_id: Option<i64>,
+    sections: &[ResumeSection],
+    skills: &[String],
+) -> Result<Option<i64>> {
+    let sections_json = serde_json::to_string(sections)?;
+    let skills_json = serde_json::to_string(skills)?;
+
+    let latest: Option<(i64, String, String)> = sqlx::query_as(
+        "SELECT version, sections, skills FROM resume_versions
+         WHERE source = ? AND source_key = ?
+         ORDER BY version DESC LIMIT 1",
+    )
+    .bind(source.as_str())
+    .bind(source_key)
+    .fetch_optional(db)
+    .await?;
+    if let Some((_, latest_sections, latest_skills)) = &latest {
+        if *latest_sections == sections_json && *latest_skills == skills_json {
+            return Ok(None);
+        }
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO resume_versions
+             (source, source_key, version, name, resume_id, sections, skills)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(source.as_str())
+    .bind(source_key)
+    .bind(latest.map_or(1, |(version, _, _)| version + 1))
+    .bind(name)
+    .bind(resume_id)
+    .bind(&sections_json)
+    .bind(&skills_json)
+    .execute(db)
+    .await?;
+    Ok(Some(result.last_insert_rowid()))
+}
+
+/// Save a builder draft's content as a version of that draft.
+pub(super) async fn record_draft_version(
+    db: &SqlitePool,
+    draft_id: i64,
+    resume: &StructuredResume,
+) -> Result<Option<i64>> {
+    let sections = split_resume_sections(&TemplateRenderer::render_plain_text(resume));
+    let skills: Vec<String> = resume
+        .skills
+        .iter()
+        .flat_map(|category| &category.skills)
+        .map(|skill| skill.name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let name = match resume.personal.name.trim() {
+        "" => format!("Resume draft {draft_id}"),
+        name => name.to_string(),
+    };
+    record_resume_version(
+        db,
+        ResumeVersionSource::Builder,
+        &draft_id.to_string(),
+        &name,
+        None,
+        &sections,
+        &skills,
+    )
+    .await
+}
+
+/// Names in `skills` missing from `other`, ignoring case
+fn skills_missing_from(skills: &[String], other: &[String]) -> Vec<String> {
+    let other: HashSet<String> = other.iter().map(|skill| skill.to_lowercase()).collect();
+    skills
+        .iter()
+        .filter(|skill| !other.contains(&skill.to_lowercase()))
+        .cloned()
+        .collect()
+}
+
+/// Share of `job_skills` present in `skills`, ignoring case
+fn skill_coverage(skills: &HashSet<String>, job_skills: &[String]) -> f64 {
+    let job_skills: HashSet<String> = job_skills
+        .iter()
+        .map(|skill| skill.to_lowercase())
+        .collect();
+    let covered = job_skills.intersection(skills).count();
+    covered as f64 / job_skills.len() as f64
+}
+
+impl ResumeMatcher {
+    /// Save an uploaded resume's parsed sections and extracted skills as the
+    /// next version of resumes with the same name.
+    pub(super) async fn record_upload_version(&self, resume_id: i64) -> Result<Option<i64>> {
+        let resume = self.get_resume(resume_id).await?;
+        let sections = split_resume_sections(resume.parsed_text.as_deref().unwrap_or_default());
+        let skills: Vec<String> = self
+            .get_user_skills(resume_id)
+            .await?
+            .into_iter()
+            .map(|skill| skill.skill_name)
+            .collect();
+        record_resume_version(
+            &self.db,
+            ResumeVersionSource::Upload,
+            &resume.name.trim().to_lowercase(),
+            &resume.name,
+            Some(resume_id),
+            &sections,
+            &skills,
+        )
+        .await
+    }
+
+    /// All resume versions, grouped by upload name or draft, newest first
+    pub async fn list_resume_versions(&self) -> Result<Vec<ResumeVersion>> {
+        let rows = sqlx::query(
+            "SELECT id, source, source_key, version, name, resume_id, sections, skills, created_at
+             FROM resume_versions
+             ORDER BY source, source_key, version DESC",
+        )
+        .fetch_all(&self.db)
+        .await?;
+        rows.iter().map(version_from_row).collect()
+    }
+
+    /// Get one resume version
+    pub async fn get_resume_version(&self, version_id: i64) -> Result<ResumeVersion> {
+        let row = sqlx::query(
+            "SELECT id, source, source_key, version, name, resume_id, sections, skills, created_at
+             FROM resume_versions WHERE id = ?",
+        )
+        .bind(version_id)
+        .fetch_optional(&self.db)
+        .await?
+        .context("Resume version not found")?;
+        version_from_row(&row)
+    }
+
+    /// Compare two resume versions, `from_id` being the baseline.
+    ///
+    /// Both versions are scored against the most recent active jobs by the
+    /// share of each job's skills they list. Jobs without any known skills
+    /// are skipped.
+    pub async fn compare_resume_versions(
+        &self,
+        from_id: i64,
+        to_id: i64,
+    ) -> Result<ResumeVersionComparison> {
+        let from = self.get_resume_version(from_id).await?;
+        let to = self.get_resume_version(to_id).await?;
+
+        let job_hashes: Vec<String> = sqlx::query_scalar(
+            "SELECT hash FROM jobs
+             WHERE hidden = 0 AND status <> 'closed'
+             ORDER BY created_at DESC, id DESC LIMIT ?",
+        )
+        .bind(RECENT_JOBS_COMPARED)
+        .fetch_all(&self.db)
+        .await?;
+
+        let from_skills: HashSet<String> = from
+            .skills
+            .iter()
+            .map(|skill| skill.to_lowercase())
+            .collect();
+        let to_skills: HashSet<String> =
+            to.skills.iter().map(|skill| skill.to_lowercase()).collect();
+        let mut jobs_compared = 0_usize;
+        let (mut from_total, mut to_total) = (0.0, 0.0);
+        for job_hash in &job_hashes {
+            let mut job_skills = self.job_matcher.get_job_skills(job_hash).await?;
+            if job_skills.is_empty() {
+                job_skills = self.job_matcher.extract_job_skills(job_hash).await?;
+            }
+            if job_skills.is_empty() {
+                continue;
+            }
+            jobs_compared += 1;
+            from_total += skill_coverage(&from_skills, &job_skills);
+            to_total += skill_coverage(&to_skills, &job_skills);
+        }
+
+        let (from_average_match, to_average_match) = if jobs_compared == 0 {
+            (None, None)
+        } else {
+            let count = jobs_compared as f64;
+            (Some(from_total / count), Some(to_total / count))
+        };
+
+        Ok(ResumeVersionComparison {
+            added_skills: skills_missing_from(&to.skills, &from.skills),
+            removed_skills: skills_missing_from(&from.skills, &to.skills),
+            section_changes: diff_resume_sections(&from.sections, &to.sections),
+            jobs_compared,
+            from_average_match,
+            to_average_match,
+            average_match_change: from_average_match
+                .zip(to_average_match)
+                .map(|(from, to)| to - from),
+            from,
+            to,
+        })
+    }
+}
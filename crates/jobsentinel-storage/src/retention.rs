@@ -0,0 +1,130 @@
+//! Job retention and purging
+//!
+//! Jobs no scrape has seen for a while are deleted so the database does not
+//! grow without bound. Deleting a job cascades to everything stored against
+//! it, so jobs carrying anything the user wrote (an application, automation
+//! attempt, tag, or good/bad or ghost verdict) are always kept, and
+//! bookmarked or annotated jobs are kept unless the policy says otherwise.
+//! Only derived data such as extracted skills and resume matches goes with a
+//! purged job.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+
+/// Jobs listed in a purge report.
+const PURGE_SAMPLE_SIZE: i64 = 20;
+
+/// SQL conditions for a purge. `?1` is the `last_seen` cutoff, `?2` the
+/// optional score ceiling, `?3` and `?4` the keep-bookmarked and
+/// keep-with-notes flags.
+const PURGE_CONDITIONS: &str = r#"
+    last_seen < ?1
+    AND hash NOT IN (SELECT job_hash FROM applications)
+    AND hash NOT IN (SELECT job_hash FROM application_attempts)
+    AND id NOT IN (SELECT job_id FROM job_feedback)
+    AND id NOT IN (SELECT job_id FROM job_tags)
+    AND id NOT IN (SELECT job_id FROM ghost_feedback)
+    AND (?2 IS NULL OR score IS NULL OR score < ?2)
+    AND (?3 = 0 OR bookmarked = 0)
+    AND (?4 = 0 OR notes IS NULL OR notes = '')
+"#;
+
+/// Which jobs a purge deletes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetentionPolicy {
+    /// Jobs no scrape has seen since this time are purged
+    pub last_seen_before: DateTime<Utc>,
+    /// Only jobs scoring below this are purged; unscored jobs always are
+    pub below_score: Option<f64>,
+    pub keep_bookmarked: bool,
+    pub keep_with_notes: bool,
+}
+
+/// A job a purge deleted, or would delete on a dry run
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PurgedJob {
+    pub id: i64,
+    pub title: String,
+    pub company: String,
+    pub source: String,
+    pub score: Option<f64>,
+    pub last_seen: DateTime<Utc>,
+}
+
+/// Outcome of a purge
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeReport {
+    /// Nothing was deleted; the report shows what would have been
+    pub dry_run: bool,
+    /// Jobs matching the policy
+    pub jobs_matched: u64,
+    /// Jobs deleted, always 0 on a dry run
+    pub jobs_deleted: u64,
+    /// The least recently seen matching jobs, up to 20
+    pub sample: Vec<PurgedJob>,
+}
+
+impl Database {
+    /// Delete the jobs `policy` selects, or only report them when `dry_run`
+    /// is set.
+    pub async fn purge_jobs(
+        &self,
+        policy: &RetentionPolicy,
+        dry_run: bool,
+    ) -> Result<PurgeReport, sqlx::Error> {
+        let mut tx = self.pool().begin().await?;
+
+        let jobs_matched: i64 = sqlx::query_scalar(sqlx::AssertSqlSafe(format!(
+            "SELECT COUNT(*) FROM jobs WHERE {PURGE_CONDITIONS}"
+        )))
+        .bind(policy.last_seen_before)
+        .bind(policy.below_score)
+        .bind(policy.keep_bookmarked)
+        .bind(policy.keep_with_notes)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let sample: Vec<PurgedJob> = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+            r#"
+            SELECT id, title, company, source, score, last_seen FROM jobs
+            WHERE {PURGE_CONDITIONS}
+            ORDER BY last_seen ASC, id ASC
+            LIMIT ?5
+            "#
+        )))
+        .bind(policy.last_seen_before)
+        .bind(policy.below_score)
+        .bind(policy.keep_bookmarked)
+        .bind(policy.keep_with_notes)
+        .bind(PURGE_SAMPLE_SIZE)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut report = PurgeReport {
+            dry_run,
+            jobs_matched: u64::try_from(jobs_matched).unwrap_or(0),
+            jobs_deleted: 0,
+            sample,
+        };
+        if dry_run || report.jobs_matched == 0 {
+            return Ok(report);
+        }
+
+        report.jobs_deleted = sqlx::query(sqlx::AssertSqlSafe(format!(
+            "DELETE FROM jobs WHERE {PURGE_CONDITIONS}"
+        )))
+        .bind(policy.last_seen_before)
+        .bind(policy.below_score)
+        .bind(policy.keep_bookmarked)
+        .bind(policy.keep_with_notes)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        tx.commit().await?;
+
+        tracing::info!(jobs_deleted = report.jobs_deleted, "Purged old jobs");
+        Ok(report)
+    }
+}
@@ -3,13 +3,17 @@
 use crate::analytics_buckets::{salary_location_bucket, salary_title_bucket};
 use crate::sqlite_time::parse_sqlite_datetime;
 use anyhow::Result;
+use jobsentinel_domain::{ExchangeRates, PayPeriod};
 use sqlx::{Row, SqlitePool};
 
-use super::benchmarks::SalaryBenchmark;
+use super::benchmarks::{SalaryBenchmark, SalaryTrendDirection};
 use super::negotiation::NegotiationScriptGenerator;
 use super::predictor::SalaryPredictor;
 use super::types::{OfferComparison, SalaryPrediction, SeniorityLevel};
 
+/// Cost of living index for where the user lives now
+const BASELINE_COST_OF_LIVING_INDEX: f64 = 100.0;
+
 /// Main salary analyzer
 pub struct SalaryAnalyzer {
     db: SqlitePool,
@@ -46,11 +50,10 @@ impl SalaryAnalyzer {
     ) -> Result<Option<SalaryBenchmark>> {
         let normalized_title = salary_title_bucket(job_title);
         let normalized_location = salary_location_bucket(location);
-        let seniority_str = seniority.as_str();
 
         let row = sqlx::query(
             r#"
-            SELECT min_salary, p25_salary, median_salary, p75_salary,
+            SELECT seniority_level, min_salary, p25_salary, median_salary, p75_salary,
                    max_salary, average_salary, sample_size, last_updated
             FROM salary_benchmarks
             WHERE job_title_normalized = ?
@@ -62,28 +65,109 @@ impl SalaryAnalyzer {
         )
         .bind(&normalized_title)
         .bind(&normalized_location)
-        .bind(seniority_str)
+        .bind(seniority.as_str())
         .fetch_optional(&self.db)
         .await?;
 
         match row {
-            Some(r) => Ok(Some(SalaryBenchmark {
-                job_title: normalized_title,
-                location: normalized_location,
-                seniority_level: seniority,
-                min_salary: r.try_get::<i64, _>("min_salary").unwrap_or(0),
-                p25_salary: r.try_get::<i64, _>("p25_salary").unwrap_or(0),
-                median_salary: r.try_get::<i64, _>("median_salary").unwrap_or(0),
-                p75_salary: r.try_get::<i64, _>("p75_salary").unwrap_or(0),
-                max_salary: r.try_get::<i64, _>("max_salary").unwrap_or(0),
-                average_salary: r.try_get::<i64, _>("average_salary").unwrap_or(0),
-                sample_size: r.try_get::<i64, _>("sample_size").unwrap_or(0),
-                last_updated: parse_sqlite_datetime(&r.try_get::<String, _>("last_updated")?)?,
-            })),
+            Some(r) => self
+                .benchmark_with_trend(normalized_title, normalized_location, &r)
+                .await
+                .map(Some),
             None => Ok(None),
         }
     }
 
+    /// Get salary benchmark for role and location at any seniority
+    ///
+    /// Uses the seniority level with the most data points.
+    pub async fn get_role_benchmark(
+        &self,
+        job_title: &str,
+        location: &str,
+    ) -> Result<Option<SalaryBenchmark>> {
+        let normalized_title = salary_title_bucket(job_title);
+        let normalized_location = salary_location_bucket(location);
+
+        let row = sqlx::query(
+            r#"
+            SELECT seniority_level, min_salary, p25_salary, median_salary, p75_salary,
+                   max_salary, average_salary, sample_size, last_updated
+            FROM salary_benchmarks
+            WHERE job_title_normalized = ?
+              AND location_normalized = ?
+            ORDER BY sample_size DESC, last_updated DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(&normalized_title)
+        .bind(&normalized_location)
+        .fetch_optional(&self.db)
+        .await?;
+
+        match row {
+            Some(r) => self
+                .benchmark_with_trend(normalized_title, normalized_location, &r)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn benchmark_with_trend(
+        &self,
+        job_title: String,
+        location: String,
+        r: &sqlx::sqlite::SqliteRow,
+    ) -> Result<SalaryBenchmark> {
+        let salary_growth_pct = self.salary_growth_pct(&job_title, &location).await?;
+
+        Ok(SalaryBenchmark {
+            seniority_level: SeniorityLevel::parse(
+                r.try_get::<Option<String>, _>("seniority_level")?
+                    .as_deref()
+                    .unwrap_or_default(),
+            ),
+            job_title,
+            location,
+            min_salary: r.try_get::<i64, _>("min_salary").unwrap_or(0),
+            p25_salary: r.try_get::<i64, _>("p25_salary").unwrap_or(0),
+            median_salary: r.try_get::<i64, _>("median_salary").unwrap_or(0),
+            p75_salary: r.try_get::<i64, _>("p75_salary").unwrap_or(0),
+            max_salary: r.try_get::<i64, _>("max_salary").unwrap_or(0),
+            average_salary: r.try_get::<i64, _>("average_salary").unwrap_or(0),
+            sample_size: r.try_get::<i64, _>("sample_size").unwrap_or(0),
+            last_updated: parse_sqlite_datetime(&r.try_get::<String, _>("last_updated")?)?,
+            salary_growth_pct,
+            trend_direction: salary_growth_pct.map(SalaryTrendDirection::from_growth_pct),
+        })
+    }
+
+    /// Change in median pay between the two latest salary trends, in percent
+    async fn salary_growth_pct(&self, job_title: &str, location: &str) -> Result<Option<f64>> {
+        let medians = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT median_salary
+            FROM salary_trends
+            WHERE job_title_normalized = ?
+              AND location_normalized = ?
+            ORDER BY date DESC
+            LIMIT 2
+            "#,
+        )
+        .bind(job_title)
+        .bind(location)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(match medians.as_slice() {
+            [latest, previous] if *previous > 0 => {
+                Some((latest - previous) as f64 * 100.0 / *previous as f64)
+            }
+            _ => None,
+        })
+    }
+
     /// Generate negotiation script
     pub async fn generate_negotiation_script(
         &self,
@@ -95,9 +179,18 @@ impl SalaryAnalyzer {
 
     /// Compare multiple offers
     ///
+    /// Pay is annualized from its pay period and converted to US dollars,
+    /// then bonus, equity, and benefit values are added. Adjusted pay scales
+    /// that total by each offer's cost of living index, where 100 is where
+    /// the user lives now. The best adjusted offer comes first.
+    ///
     /// OPTIMIZATION: Single batched query with IN clause instead of N queries.
     /// Fetch all offers in one round-trip, then get predictions.
-    pub async fn compare_offers(&self, offer_ids: Vec<i64>) -> Result<Vec<OfferComparison>> {
+    pub async fn compare_offers(
+        &self,
+        offer_ids: Vec<i64>,
+        rates: &ExchangeRates,
+    ) -> Result<Vec<OfferComparison>> {
         if offer_ids.is_empty() {
             return Ok(Vec::new());
         }
@@ -106,7 +199,9 @@ impl SalaryAnalyzer {
         let placeholders = offer_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
         let sql = format!(
             r#"
-            SELECT o.id, o.base_salary, o.annual_bonus, o.equity_shares,
+            SELECT o.id, o.base_salary, o.currency, o.pay_period, o.annual_bonus,
+                   o.signing_bonus, o.equity_annual_value, o.benefits_annual_value,
+                   o.location, o.cost_of_living_index, o.offer_expires_at,
                    a.job_hash, j.title, j.company
             FROM offers o
             JOIN applications a ON a.id = o.application_id
@@ -127,14 +222,37 @@ impl SalaryAnalyzer {
         for offer in offers {
             let id: i64 = offer.try_get("id")?;
             let company: String = offer.try_get("company")?;
+            let job_title: String = offer.try_get("title")?;
             let job_hash: String = offer.try_get("job_hash")?;
-            let base_salary: i64 = offer.try_get::<Option<i64>, _>("base_salary")?.unwrap_or(0);
-            let annual_bonus: i64 = offer
-                .try_get::<Option<i64>, _>("annual_bonus")?
-                .unwrap_or(0);
+            let currency: Option<String> = offer.try_get("currency")?;
+            let pay_period = PayPeriod::parse(&offer.try_get::<String, _>("pay_period")?)
+                .unwrap_or(PayPeriod::Year);
+            let amount = |column: &str| -> Result<i64> {
+                Ok(offer.try_get::<Option<i64>, _>(column)?.unwrap_or(0))
+            };
+            let to_usd = |amount: i64| -> Result<i64> {
+                rates.to_usd(amount, currency.as_deref()).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No exchange rate for {} to compare offer {id}",
+                        currency.as_deref().unwrap_or_default()
+                    )
+                })
+            };
 
-            // Calculate total comp (simplified)
-            let total_comp = base_salary + annual_bonus;
+            let base_salary = to_usd(pay_period.annualize(amount("base_salary")?))?;
+            let total_comp = base_salary
+                + to_usd(
+                    amount("annual_bonus")?
+                        + amount("equity_annual_value")?
+                        + amount("benefits_annual_value")?,
+                )?;
+            let cost_of_living_index = offer
+                .try_get::<Option<f64>, _>("cost_of_living_index")?
+                .filter(|index| *index > 0.0)
+                .unwrap_or(BASELINE_COST_OF_LIVING_INDEX);
+            let adjusted_comp = (total_comp as f64 * BASELINE_COST_OF_LIVING_INDEX
+                / cost_of_living_index)
+                .round() as i64;
 
             // Get market benchmark (still N queries, but could be batched too)
             let prediction = self.predictor.get_prediction(&job_hash).await?;
@@ -172,14 +290,21 @@ impl SalaryAnalyzer {
             comparisons.push(OfferComparison {
                 offer_id: id,
                 company,
+                job_title,
+                location: offer.try_get("location")?,
                 base_salary,
                 total_compensation: total_comp,
+                first_year_compensation: total_comp + to_usd(amount("signing_bonus")?)?,
+                cost_of_living_index,
+                adjusted_compensation: adjusted_comp,
+                offer_expires_at: offer.try_get("offer_expires_at")?,
                 market_median,
                 market_position,
                 recommendation,
             });
         }
 
+        comparisons.sort_by(|a, b| b.adjusted_compensation.cmp(&a.adjusted_compensation));
         Ok(comparisons)
     }
 }
@@ -6,6 +6,30 @@ use super::SeniorityLevel;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Change in median pay, in percent, that still counts as flat
+const FLAT_TREND_PERCENT: f64 = 2.0;
+
+/// Which way median pay moved between the two latest salary trends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SalaryTrendDirection {
+    Up,
+    Down,
+    Flat,
+}
+
+impl SalaryTrendDirection {
+    pub fn from_growth_pct(growth_pct: f64) -> Self {
+        if growth_pct > FLAT_TREND_PERCENT {
+            Self::Up
+        } else if growth_pct < -FLAT_TREND_PERCENT {
+            Self::Down
+        } else {
+            Self::Flat
+        }
+    }
+}
+
 /// Salary benchmark data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SalaryBenchmark {
@@ -20,6 +44,12 @@ pub struct SalaryBenchmark {
     pub average_salary: i64,
     pub sample_size: i64,
     pub last_updated: DateTime<Utc>,
+    /// Change in median pay since the previous salary trend, in percent;
+    /// `None` until two trends exist for the role and location
+    #[serde(default)]
+    pub salary_growth_pct: Option<f64>,
+    #[serde(default)]
+    pub trend_direction: Option<SalaryTrendDirection>,
 }
 
 impl SalaryBenchmark {
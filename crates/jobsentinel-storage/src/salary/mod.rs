@@ -45,7 +45,7 @@ mod predictor;
 mod types;
 
 pub use analyzer::SalaryAnalyzer;
-pub use benchmarks::SalaryBenchmark;
+pub use benchmarks::{SalaryBenchmark, SalaryTrendDirection};
 pub use types::{OfferComparison, SalaryPrediction, SeniorityLevel};
 
 // Tests module
@@ -337,10 +337,10 @@ fn test_normalize_location_boundary_cases() {
     let result = salary_location_bucket(long_location);
     assert_eq!(result, "san francisco, ca"); // Contains "san francisco"
 
-    // Location with newlines - doesn't match "new york" pattern due to newline
+    // Location with newlines - the gazetteer collapses whitespace
     let multiline = "New\nYork";
     let result = salary_location_bucket(multiline);
-    assert_eq!(result, "new\nyork"); // Newlines preserved, no pattern match
+    assert_eq!(result, "new york, ny");
 }
 
 #[test]
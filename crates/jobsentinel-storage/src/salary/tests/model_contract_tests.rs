@@ -61,8 +61,14 @@ fn test_offer_comparison_creation() {
     let comparison = OfferComparison {
         offer_id: 1,
         company: "CommunityCare".to_string(),
+        job_title: "Program Director".to_string(),
+        location: Some("Denver, CO".to_string()),
         base_salary: 150000,
         total_compensation: 180000,
+        first_year_compensation: 190000,
+        cost_of_living_index: 100.0,
+        adjusted_compensation: 180000,
+        offer_expires_at: None,
         market_median: Some(140000),
         market_position: "at_market".to_string(),
         recommendation: "Fair offer. Consider negotiating for 10-15% more.".to_string(),
@@ -81,8 +87,14 @@ fn test_offer_comparison_without_market_data() {
     let comparison = OfferComparison {
         offer_id: 2,
         company: "Harbor Retail".to_string(),
+        job_title: "Store Manager".to_string(),
+        location: None,
         base_salary: 130000,
         total_compensation: 150000,
+        first_year_compensation: 150000,
+        cost_of_living_index: 100.0,
+        adjusted_compensation: 150000,
+        offer_expires_at: None,
         market_median: None,
         market_position: "unknown".to_string(),
         recommendation: "Insufficient data for recommendation.".to_string(),
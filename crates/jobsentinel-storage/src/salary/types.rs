@@ -93,8 +93,19 @@ pub struct SalaryPrediction {
 pub struct OfferComparison {
     pub offer_id: i64,
     pub company: String,
+    pub job_title: String,
+    pub location: Option<String>,
+    /// Annual base salary in US dollars
     pub base_salary: i64,
+    /// Annual base, bonus, equity, and benefits in US dollars
     pub total_compensation: i64,
+    /// Total compensation plus the signing bonus
+    pub first_year_compensation: i64,
+    /// Cost of living index used, where 100 is where the user lives now
+    pub cost_of_living_index: f64,
+    /// Total compensation in the user's current cost of living
+    pub adjusted_compensation: i64,
+    pub offer_expires_at: Option<String>,
     pub market_median: Option<i64>,
     pub market_position: String, // "above_market", "at_market", "below_market"
     pub recommendation: String,
@@ -0,0 +1,39 @@
+//! Paused background scraping
+//!
+//! The pause is stored so it survives restarts. Scrapes started by hand still
+//! run while it is on.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use super::connection::Database;
+
+impl Database {
+    /// When scheduled scraping was paused, or `None` while it is running.
+    pub async fn scraping_paused_at(&self) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        let paused_at: Option<NaiveDateTime> =
+            sqlx::query_scalar("SELECT paused_at FROM scraping_pause WHERE id = 1")
+                .fetch_optional(self.pool())
+                .await?;
+        Ok(paused_at.map(|paused_at| paused_at.and_utc()))
+    }
+
+    /// Pause scheduled scraping. Pausing again keeps the original time.
+    pub async fn pause_scraping(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO scraping_pause (id, paused_at) VALUES (1, ?) ON CONFLICT(id) DO NOTHING",
+        )
+        .bind(now.naive_utc())
+        .execute(self.pool())
+        .await?;
+
+        Ok(self.scraping_paused_at().await?.unwrap_or(now))
+    }
+
+    /// Resume scheduled scraping. Returns whether it was paused.
+    pub async fn resume_scraping(&self) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM scraping_pause WHERE id = 1")
+            .execute(self.pool())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,154 @@
+//! Copy saved jobs from another JobSentinel database during setup
+//!
+//! The source file is copied into a private working folder and opened and
+//! migrated there, so an older install's database is never modified.
+
+use anyhow::{Context, Result};
+use sqlx::Row;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use super::connection::Database;
+use super::types::{DatabaseImportSummary, JobRow};
+use jobsentinel_domain::Job;
+
+impl Database {
+    /// Copy saved jobs and their application records from another
+    /// JobSentinel database file.
+    ///
+    /// Jobs already saved here are skipped. Hidden, bookmarked, and note
+    /// state travel with each job. `work_dir` holds the temporary copy and
+    /// is cleaned up afterwards.
+    pub async fn import_from_database_file(
+        &self,
+        source: &Path,
+        work_dir: &Path,
+    ) -> Result<DatabaseImportSummary> {
+        anyhow::ensure!(source.is_file(), "Database file not found");
+
+        let import_dir = work_dir.join(format!("setup_import_{}", Uuid::new_v4()));
+        jobsentinel_platform::ensure_private_dir(&import_dir)
+            .context("Failed to create import folder")?;
+        let result = self.import_from_copy(source, &import_dir).await;
+        if let Err(error) = std::fs::remove_dir_all(&import_dir) {
+            tracing::warn!(error_kind = ?error.kind(), "Failed to remove setup import copy");
+        }
+        result
+    }
+
+    async fn import_from_copy(
+        &self,
+        source: &Path,
+        import_dir: &Path,
+    ) -> Result<DatabaseImportSummary> {
+        let copy_path = import_dir.join("jobs.db");
+        std::fs::copy(source, &copy_path).context("Failed to copy database file")?;
+        let source_wal = sidecar_path(source, "-wal");
+        if source_wal.is_file() {
+            std::fs::copy(&source_wal, sidecar_path(&copy_path, "-wal"))
+                .context("Failed to copy database journal")?;
+        }
+
+        let imported = Database::connect(&copy_path)
+            .await
+            .context("File is not a readable JobSentinel database")?;
+        imported
+            .migrate()
+            .await
+            .context("File is not a readable JobSentinel database")?;
+        let result = self.copy_saved_jobs(&imported).await;
+        imported.pool().close().await;
+        result
+    }
+
+    async fn copy_saved_jobs(&self, imported: &Database) -> Result<DatabaseImportSummary> {
+        let jobs: Vec<Job> = sqlx::query_as::<_, JobRow>("SELECT * FROM jobs ORDER BY id")
+            .fetch_all(imported.pool())
+            .await?
+            .into_iter()
+            .map(Job::from)
+            .collect();
+
+        let mut summary = DatabaseImportSummary::default();
+        for job in &jobs {
+            let Some(job_id) = self.insert_job_if_new(job).await? else {
+                summary.jobs_skipped += 1;
+                continue;
+            };
+            sqlx::query("UPDATE jobs SET hidden = ?, bookmarked = ?, notes = ? WHERE id = ?")
+                .bind(job.hidden)
+                .bind(job.bookmarked)
+                .bind(&job.notes)
+                .bind(job_id)
+                .execute(self.pool())
+                .await?;
+            summary.jobs_imported += 1;
+        }
+
+        summary.applications_imported = self.copy_applications(imported).await?;
+        tracing::info!(
+            jobs_imported = summary.jobs_imported,
+            jobs_skipped = summary.jobs_skipped,
+            applications_imported = summary.applications_imported,
+            "Imported saved jobs from another database"
+        );
+        Ok(summary)
+    }
+
+    /// Application rows keyed by job hash; resume links are dropped because
+    /// resume ids differ between installs.
+    async fn copy_applications(&self, imported: &Database) -> Result<usize> {
+        let rows = sqlx::query(
+            r#"
+            SELECT job_hash, status, applied_at, last_contact, next_followup, notes,
+                   cover_letter_text, recruiter_name, recruiter_email, recruiter_phone,
+                   salary_expectation, created_at, updated_at
+            FROM applications
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(imported.pool())
+        .await?;
+
+        let mut imported_count = 0;
+        for row in rows {
+            let job_hash: String = row.try_get("job_hash")?;
+            let result = sqlx::query(
+                r#"
+                INSERT INTO applications (
+                    job_hash, status, applied_at, last_contact, next_followup, notes,
+                    cover_letter_text, recruiter_name, recruiter_email, recruiter_phone,
+                    salary_expectation, created_at, updated_at
+                )
+                SELECT ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+                WHERE EXISTS (SELECT 1 FROM jobs WHERE hash = ?)
+                ON CONFLICT(job_hash) DO NOTHING
+                "#,
+            )
+            .bind(&job_hash)
+            .bind(row.try_get::<String, _>("status")?)
+            .bind(row.try_get::<Option<String>, _>("applied_at")?)
+            .bind(row.try_get::<Option<String>, _>("last_contact")?)
+            .bind(row.try_get::<Option<String>, _>("next_followup")?)
+            .bind(row.try_get::<Option<String>, _>("notes")?)
+            .bind(row.try_get::<Option<String>, _>("cover_letter_text")?)
+            .bind(row.try_get::<Option<String>, _>("recruiter_name")?)
+            .bind(row.try_get::<Option<String>, _>("recruiter_email")?)
+            .bind(row.try_get::<Option<String>, _>("recruiter_phone")?)
+            .bind(row.try_get::<Option<i64>, _>("salary_expectation")?)
+            .bind(row.try_get::<String, _>("created_at")?)
+            .bind(row.try_get::<String, _>("updated_at")?)
+            .bind(&job_hash)
+            .execute(self.pool())
+            .await?;
+            imported_count += usize::try_from(result.rows_affected()).unwrap_or(0);
+        }
+        Ok(imported_count)
+    }
+}
+
+fn sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
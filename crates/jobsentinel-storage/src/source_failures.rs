@@ -0,0 +1,87 @@
+//! Consecutive source check failures
+//!
+//! The scheduler records each failed check here and clears the record when a
+//! check succeeds. The count drives per-source backoff and failure alerts.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::connection::Database;
+
+/// A source whose latest checks have all failed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceFailure {
+    pub source_id: String,
+    /// Failed checks in a row
+    pub consecutive_failures: i64,
+    /// Short reason from the latest failure, such as "403 Forbidden"
+    pub last_error: String,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+type SourceFailureRow = (String, i64, String, NaiveDateTime);
+
+fn source_failure(
+    (source_id, consecutive_failures, last_error, last_failed_at): SourceFailureRow,
+) -> SourceFailure {
+    SourceFailure {
+        source_id,
+        consecutive_failures,
+        last_error,
+        last_failed_at: last_failed_at.and_utc(),
+    }
+}
+
+impl Database {
+    /// Count another failed check for `source_id` and return the updated
+    /// record.
+    pub async fn record_source_failure(
+        &self,
+        source_id: &str,
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<SourceFailure, sqlx::Error> {
+        let row: SourceFailureRow = sqlx::query_as(
+            r#"
+            INSERT INTO source_failures
+                (source_id, consecutive_failures, last_error, last_failed_at)
+            VALUES (?, 1, ?, ?)
+            ON CONFLICT(source_id) DO UPDATE SET
+                consecutive_failures = consecutive_failures + 1,
+                last_error = excluded.last_error,
+                last_failed_at = excluded.last_failed_at
+            RETURNING source_id, consecutive_failures, last_error, last_failed_at
+            "#,
+        )
+        .bind(source_id)
+        .bind(error)
+        .bind(now.naive_utc())
+        .fetch_one(self.pool())
+        .await?;
+        Ok(source_failure(row))
+    }
+
+    /// Forget the failures for `source_id` after a successful check. Returns
+    /// whether it had any.
+    pub async fn clear_source_failures(&self, source_id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM source_failures WHERE source_id = ?")
+            .bind(source_id)
+            .execute(self.pool())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Every source that is currently failing, most failures first.
+    pub async fn source_failures(&self) -> Result<Vec<SourceFailure>, sqlx::Error> {
+        let rows: Vec<SourceFailureRow> = sqlx::query_as(
+            r#"
+            SELECT source_id, consecutive_failures, last_error, last_failed_at
+            FROM source_failures
+            ORDER BY consecutive_failures DESC, source_id
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(source_failure).collect())
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
-use crate::{Database, DuplicateGroup, Statistics};
+use crate::{Database, DuplicateGroup, JobTagCount, Statistics};
 #[cfg(test)]
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 #[cfg(test)]
 use {chrono::Utc, jobsentinel_domain::calculate_job_hash as hash};
 
@@ -22,6 +22,7 @@ fn create_test_job(hash: &str, title: &str, score: f64) -> Job {
         salary_min: Some(150000),
         salary_max: Some(200000),
         currency: Some("USD".to_string()),
+        pay_period: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
         last_seen: Utc::now(),
@@ -35,6 +36,8 @@ fn create_test_job(hash: &str, title: &str, score: f64) -> Job {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
     }
 }
 
@@ -63,6 +66,31 @@ mod job_search_tests;
 #[path = "tests/job_duplicate_tests.rs"]
 mod job_duplicate_tests;
 
+#[path = "tests/job_near_duplicate_tests.rs"]
+mod job_near_duplicate_tests;
+
+#[path = "tests/job_cross_source_duplicate_tests.rs"]
+mod job_cross_source_duplicate_tests;
+
+#[path = "tests/job_filter_tests.rs"]
+mod job_filter_tests;
+#[path = "tests/job_page_tests.rs"]
+mod job_page_tests;
+#[path = "tests/job_refresh_tests.rs"]
+mod job_refresh_tests;
+#[path = "tests/job_status_tests.rs"]
+mod job_status_tests;
+#[path = "tests/job_tag_tests.rs"]
+mod job_tag_tests;
+#[path = "tests/job_time_to_fill_tests.rs"]
+mod job_time_to_fill_tests;
+#[path = "tests/retention_tests.rs"]
+mod retention_tests;
+#[path = "tests/setup_import_tests.rs"]
+mod setup_import_tests;
+#[path = "tests/vacation_mode_tests.rs"]
+mod vacation_mode_tests;
+
 #[path = "tests/job_edge_case_tests.rs"]
 mod job_edge_case_tests;
 
@@ -254,3 +282,45 @@ mod job_field_updates;
 
 #[path = "tests/job_insert_tests.rs"]
 mod job_insert_tests;
+
+#[path = "tests/exchange_rate_tests.rs"]
+mod exchange_rate_tests;
+
+#[path = "tests/ghost_reanalysis_tests.rs"]
+mod ghost_reanalysis_tests;
+
+#[path = "tests/rescoring_tests.rs"]
+mod rescoring_tests;
+
+#[path = "tests/job_feedback_tests.rs"]
+mod job_feedback_tests;
+
+#[path = "tests/geocode_cache_tests.rs"]
+mod geocode_cache_tests;
+
+#[path = "tests/company_reputation_tests.rs"]
+mod company_reputation_tests;
+
+#[path = "tests/repost_chain_tests.rs"]
+mod repost_chain_tests;
+
+#[path = "tests/email_digest_tests.rs"]
+mod email_digest_tests;
+
+#[path = "tests/notification_outbox_tests.rs"]
+mod notification_outbox_tests;
+
+#[path = "tests/message_template_tests.rs"]
+mod message_template_tests;
+
+#[path = "tests/weekly_report_tests.rs"]
+mod weekly_report_tests;
+
+#[path = "tests/scraping_pause_tests.rs"]
+mod scraping_pause_tests;
+
+#[path = "tests/source_failure_tests.rs"]
+mod source_failure_tests;
+
+#[path = "tests/search_suggestion_tests.rs"]
+mod search_suggestion_tests;
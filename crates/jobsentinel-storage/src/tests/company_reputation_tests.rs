@@ -0,0 +1,67 @@
+use super::*;
+use chrono::Duration;
+
+async fn insert_posting(
+    db: &Database,
+    hash: &str,
+    opened_days_ago: i64,
+    last_seen_days_ago: i64,
+    ghost_score: Option<f64>,
+) {
+    let mut job = create_test_job(hash, "Care Coordinator", 0.8);
+    job.company = "Example Clinic".to_string();
+    job.created_at = Utc::now() - Duration::days(opened_days_ago);
+    job.last_seen = Utc::now() - Duration::days(last_seen_days_ago);
+    job.ghost_score = ghost_score;
+    db.upsert_job(&job).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_company_reputation_rolls_up_saved_postings() {
+    let db = crate::test_support::migrated_database().await;
+    // Closed within 60 days, twice.
+    insert_posting(&db, "filled_slowly", 100, 50, Some(0.2)).await;
+    insert_posting(&db, "filled_quickly", 30, 20, Some(0.4)).await;
+    // Still listed after 90 days, and seen reposted.
+    let mut lingering = create_test_job("lingering", "Care Coordinator", 0.8);
+    lingering.company = "example clinic ".to_string();
+    lingering.created_at = Utc::now() - Duration::days(90);
+    lingering.repost_count = 2;
+    db.upsert_job(&lingering).await.unwrap();
+    // Too new to say.
+    insert_posting(&db, "new", 2, 0, None).await;
+
+    db.refresh_company_reputation().await.unwrap();
+    let reputation = db
+        .get_company_reputation("  EXAMPLE CLINIC")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(reputation.postings, 4);
+    assert_eq!(reputation.settled_postings, 3);
+    assert!((reputation.fill_rate.unwrap() - 2.0 / 3.0).abs() < 1e-9);
+    assert!((reputation.repost_rate - 0.25).abs() < 1e-9);
+    assert!((reputation.avg_ghost_score.unwrap() - 0.3).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_company_reputation_needs_settled_postings_for_a_fill_rate() {
+    let db = crate::test_support::migrated_database().await;
+    insert_posting(&db, "only", 10, 0, None).await;
+
+    db.refresh_company_reputation().await.unwrap();
+    let reputation = db
+        .get_company_reputation("Example Clinic")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(reputation.fill_rate, None);
+    assert_eq!(reputation.avg_ghost_score, None);
+    assert!(db
+        .get_company_reputation("Unknown Co")
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,61 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_digest_jobs_skip_sent_low_scoring_and_hidden_jobs() {
+    let db = crate::test_support::migrated_database().await;
+    let top_id = db
+        .upsert_job(&create_test_job("digest_top", "Staff Engineer", 0.95))
+        .await
+        .unwrap();
+    let next_id = db
+        .upsert_job(&create_test_job("digest_next", "Senior Engineer", 0.8))
+        .await
+        .unwrap();
+    db.upsert_job(&create_test_job("digest_low", "Junior Engineer", 0.4))
+        .await
+        .unwrap();
+    let hidden_id = db
+        .upsert_job(&create_test_job("digest_hidden", "Hidden Engineer", 0.9))
+        .await
+        .unwrap();
+    db.hide_job(hidden_id).await.unwrap();
+
+    let since = Utc::now() - Duration::days(1);
+    let jobs = db.jobs_for_email_digest(since, 0.7, 10).await.unwrap();
+    let ids: Vec<i64> = jobs.iter().map(|job| job.id).collect();
+    assert_eq!(ids, vec![top_id, next_id]);
+
+    db.mark_included_in_digest(&[top_id]).await.unwrap();
+    let jobs = db.jobs_for_email_digest(since, 0.7, 10).await.unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, next_id);
+    assert!(
+        db.get_job_by_id(top_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .included_in_digest
+    );
+
+    let later = Utc::now() + Duration::hours(1);
+    assert!(db
+        .jobs_for_email_digest(later, 0.7, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_last_email_digest_is_returned() {
+    let db = crate::test_support::migrated_database().await;
+    assert!(db.last_email_digest_at().await.unwrap().is_none());
+
+    let first = Utc::now() - Duration::days(7);
+    let second = Utc::now();
+    db.record_email_digest(first, 3).await.unwrap();
+    db.record_email_digest(second, 0).await.unwrap();
+
+    let last = db.last_email_digest_at().await.unwrap().unwrap();
+    assert_eq!(last.timestamp(), second.timestamp());
+}
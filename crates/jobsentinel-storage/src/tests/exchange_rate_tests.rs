@@ -0,0 +1,115 @@
+use super::*;
+use jobsentinel_domain::PayPeriod;
+
+async fn salary_usd(db: &Database, job_id: i64) -> (Option<i64>, Option<i64>) {
+    sqlx::query_as("SELECT salary_min_usd, salary_max_usd FROM jobs WHERE id = ?")
+        .bind(job_id)
+        .fetch_one(db.pool())
+        .await
+        .unwrap()
+}
+
+fn euro_job(hash: &str) -> Job {
+    let mut job = create_test_job(hash, "Data Analyst", 0.7);
+    job.salary_min = Some(60_000);
+    job.salary_max = Some(70_000);
+    job.currency = Some("EUR".to_string());
+    job
+}
+
+#[tokio::test]
+async fn test_saved_salaries_are_converted_to_usd() {
+    let db = crate::test_support::migrated_database().await;
+    let euro_id = db.upsert_job(&euro_job("euro_hash")).await.unwrap();
+    let mut dollar = create_test_job("dollar_hash", "Support Specialist", 0.6);
+    dollar.salary_min = Some(45_000);
+    dollar.salary_max = None;
+    dollar.currency = None;
+    let dollar_id = db.upsert_job(&dollar).await.unwrap();
+
+    assert_eq!(salary_usd(&db, euro_id).await, (Some(64_800), Some(75_600)));
+    assert_eq!(salary_usd(&db, dollar_id).await, (Some(45_000), None));
+    let distribution = db.get_salary_distribution().await.unwrap();
+    assert!(distribution.contains(&("$50k-75k".to_string(), 1)));
+    assert!(distribution.contains(&("<$50k".to_string(), 1)));
+}
+
+#[tokio::test]
+async fn test_unknown_currencies_are_not_compared_as_usd() {
+    let db = crate::test_support::migrated_database().await;
+    let mut job = euro_job("unknown_hash");
+    job.currency = Some("ABC".to_string());
+    let job_id = db.upsert_job(&job).await.unwrap();
+
+    assert_eq!(salary_usd(&db, job_id).await, (None, None));
+    assert!(db.has_non_usd_salaries().await.unwrap());
+    assert!(db
+        .get_salary_distribution()
+        .await
+        .unwrap()
+        .contains(&("Not Listed".to_string(), 1)));
+}
+
+#[tokio::test]
+async fn test_refreshed_rates_reconvert_saved_salaries() {
+    let db = crate::test_support::migrated_database().await;
+    let job_id = db.upsert_job(&euro_job("refresh_hash")).await.unwrap();
+    assert!(db.exchange_rates_refreshed_at().await.unwrap().is_none());
+
+    let saved = db
+        .save_exchange_rates(
+            &[
+                ("eur".to_string(), 1.2),
+                ("USD".to_string(), 3.0),
+                ("GBP".to_string(), -1.0),
+                ("euro".to_string(), 1.1),
+            ],
+            "test-feed",
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+    db.seed_bundled_exchange_rates().await.unwrap();
+
+    assert_eq!(saved, 1);
+    assert_eq!(salary_usd(&db, job_id).await, (Some(72_000), Some(84_000)));
+    let rates = db.exchange_rates().await.unwrap();
+    assert_eq!(rates.usd_per_unit("EUR"), Some(1.2));
+    assert_eq!(rates.usd_per_unit("USD"), Some(1.0));
+    assert!(db.exchange_rates_refreshed_at().await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_bundled_source_name_is_reserved() {
+    let db = crate::test_support::migrated_database().await;
+
+    let result = db
+        .save_exchange_rates(&[("EUR".to_string(), 1.2)], "bundled", Utc::now())
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_pay_period_is_saved_and_annualized_in_usd() {
+    let db = crate::test_support::migrated_database().await;
+    let mut daily = euro_job("daily_hash");
+    daily.salary_min = Some(400);
+    daily.salary_max = Some(500);
+    daily.pay_period = Some(PayPeriod::Day);
+    let daily_id = db.upsert_job(&daily).await.unwrap();
+    let mut hourly = create_test_job("hourly_hash", "Contract Editor", 0.6);
+    hourly.salary_min = Some(40);
+    hourly.salary_max = None;
+    hourly.currency = None;
+    let hourly_id = db.upsert_job(&hourly).await.unwrap();
+
+    assert_eq!(
+        salary_usd(&db, daily_id).await,
+        (Some(112_320), Some(140_400))
+    );
+    assert_eq!(salary_usd(&db, hourly_id).await, (Some(83_200), None));
+    let saved = db.get_job_by_id(daily_id).await.unwrap().unwrap();
+    assert_eq!(saved.pay_period, Some(PayPeriod::Day));
+    assert_eq!(saved.salary_min, Some(400));
+}
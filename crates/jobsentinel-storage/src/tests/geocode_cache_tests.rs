@@ -0,0 +1,45 @@
+use super::*;
+use chrono::Duration;
+use jobsentinel_domain::Coordinates;
+
+#[tokio::test]
+async fn test_cached_geocodes_return_hits_and_recent_misses() {
+    let db = crate::test_support::migrated_database().await;
+    db.save_geocode("seattle, wa", Some(Coordinates::new(47.61, -122.33)))
+        .await
+        .unwrap();
+    db.save_geocode("nowhere town", None).await.unwrap();
+    let locations = vec![
+        "seattle, wa".to_string(),
+        "nowhere town".to_string(),
+        "portland, or".to_string(),
+    ];
+
+    let cached = db
+        .cached_geocodes(&locations, Utc::now() - Duration::days(1))
+        .await
+        .unwrap();
+
+    assert_eq!(cached.len(), 2);
+    assert_eq!(
+        cached["seattle, wa"],
+        Some(Coordinates::new(47.61, -122.33))
+    );
+    assert_eq!(cached["nowhere town"], None);
+}
+
+#[tokio::test]
+async fn test_old_geocode_misses_are_retried() {
+    let db = crate::test_support::migrated_database().await;
+    db.save_geocode("nowhere town", None).await.unwrap();
+
+    let cached = db
+        .cached_geocodes(
+            &["nowhere town".to_string()],
+            Utc::now() + Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+
+    assert!(cached.is_empty());
+}
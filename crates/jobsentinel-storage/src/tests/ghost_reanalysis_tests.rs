@@ -0,0 +1,51 @@
+use super::*;
+use crate::GhostReanalysisRun;
+
+#[tokio::test]
+async fn test_outdated_ghost_analyses_are_found_until_reanalyzed() {
+    let db = crate::test_support::migrated_database().await;
+    let old_id = db
+        .upsert_job(&create_test_job("old_hash", "Case Manager", 0.7))
+        .await
+        .unwrap();
+    db.upsert_job(&create_test_job("current_hash", "Care Navigator", 0.6))
+        .await
+        .unwrap();
+    db.set_ghost_detector_version("current_hash", 2)
+        .await
+        .unwrap();
+
+    let outdated = db.jobs_with_outdated_ghost_analysis(2, 10).await.unwrap();
+    assert_eq!(outdated.len(), 1);
+    assert_eq!(outdated[0].id, old_id);
+
+    db.save_reanalyzed_ghost_score(old_id, 0.45, Some("[]"), 2)
+        .await
+        .unwrap();
+    assert!(db
+        .jobs_with_outdated_ghost_analysis(2, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    let job = db.get_job_by_id(old_id).await.unwrap().unwrap();
+    assert_eq!(job.ghost_score, Some(0.45));
+}
+
+#[tokio::test]
+async fn test_latest_reanalysis_run_is_returned() {
+    let db = crate::test_support::migrated_database().await;
+    assert!(db.latest_ghost_reanalysis_run().await.unwrap().is_none());
+
+    let run = GhostReanalysisRun {
+        detector_version: 2,
+        started_at: "2026-10-15T10:00:00Z".to_string(),
+        finished_at: "2026-10-15T10:00:05Z".to_string(),
+        jobs_reanalyzed: 12,
+        moved_to_clear: 1,
+        moved_to_warning: 3,
+        moved_to_hidden: 2,
+    };
+    db.record_ghost_reanalysis_run(&run).await.unwrap();
+
+    assert_eq!(db.latest_ghost_reanalysis_run().await.unwrap(), Some(run));
+}
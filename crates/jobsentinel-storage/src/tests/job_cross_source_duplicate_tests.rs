@@ -0,0 +1,202 @@
+use super::*;
+
+fn posting(hash: &str, title: &str, source: &str, location: &str, score: f64) -> Job {
+    let mut job = create_test_job(hash, title, score);
+    job.company = "Harbor Health Inc.".to_string();
+    job.source = source.to_string();
+    job.location = Some(location.to_string());
+    job
+}
+
+fn ids(jobs: &[Job]) -> Vec<i64> {
+    let mut ids: Vec<i64> = jobs.iter().map(|job| job.id).collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[tokio::test]
+async fn test_cross_source_copies_collapse_into_canonical_job() {
+    let db = crate::test_support::migrated_database().await;
+    let canonical = db
+        .upsert_job(&posting(
+            "xs1",
+            "Senior Case Manager",
+            "greenhouse",
+            "Austin, TX",
+            0.9,
+        ))
+        .await
+        .unwrap();
+    let mut copy = posting(
+        "xs2",
+        "Senior Case Manager (Remote)",
+        "linkedin",
+        "Austin, Texas, United States",
+        0.8,
+    );
+    copy.company = "Harbor Health".to_string();
+    let copy = db.upsert_job(&copy).await.unwrap();
+    let other_city = db
+        .upsert_job(&posting(
+            "xs3",
+            "Senior Case Manager",
+            "indeed",
+            "Denver, CO",
+            0.7,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(db.link_cross_source_duplicates().await.unwrap(), 1);
+
+    let duplicates = db.get_job_duplicates(canonical).await.unwrap();
+    assert_eq!(ids(&duplicates), vec![copy]);
+    assert_eq!(
+        ids(&db.get_recent_jobs(10).await.unwrap()),
+        vec![canonical, other_city]
+    );
+    assert_eq!(
+        ids(&db.search_jobs("Case", 10).await.unwrap()),
+        vec![canonical, other_city]
+    );
+
+    // Linking again finds nothing new.
+    assert_eq!(db.link_cross_source_duplicates().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_same_source_reposts_are_not_linked() {
+    let db = crate::test_support::migrated_database().await;
+    db.upsert_job(&posting(
+        "same1",
+        "Payroll Specialist",
+        "lever",
+        "Remote",
+        0.9,
+    ))
+    .await
+    .unwrap();
+    db.upsert_job(&posting(
+        "same2",
+        "Payroll Specialist",
+        "lever",
+        "Remote",
+        0.8,
+    ))
+    .await
+    .unwrap();
+
+    assert_eq!(db.link_cross_source_duplicates().await.unwrap(), 0);
+    assert_eq!(db.get_recent_jobs(10).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_higher_scoring_copy_takes_over_as_canonical() {
+    let db = crate::test_support::migrated_database().await;
+    let first = db
+        .upsert_job(&posting(
+            "take1",
+            "Payroll Specialist",
+            "lever",
+            "Remote",
+            0.7,
+        ))
+        .await
+        .unwrap();
+    let second = db
+        .upsert_job(&posting(
+            "take2",
+            "Payroll Specialist",
+            "indeed",
+            "Remote",
+            0.6,
+        ))
+        .await
+        .unwrap();
+    db.link_cross_source_duplicates().await.unwrap();
+
+    let best = db
+        .upsert_job(&posting(
+            "take3",
+            "Payroll Specialist",
+            "greenhouse",
+            "Remote US",
+            0.95,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(db.link_cross_source_duplicates().await.unwrap(), 1);
+
+    assert_eq!(
+        ids(&db.get_job_duplicates(best).await.unwrap()),
+        vec![first, second]
+    );
+    assert!(db.get_job_duplicates(first).await.unwrap().is_empty());
+    assert_eq!(ids(&db.get_recent_jobs(10).await.unwrap()), vec![best]);
+}
+
+#[tokio::test]
+async fn test_unlinked_duplicate_stays_visible() {
+    let db = crate::test_support::migrated_database().await;
+    let canonical = db
+        .upsert_job(&posting(
+            "split1",
+            "Payroll Specialist",
+            "lever",
+            "Remote",
+            0.9,
+        ))
+        .await
+        .unwrap();
+    let copy = db
+        .upsert_job(&posting(
+            "split2",
+            "Payroll Specialist",
+            "indeed",
+            "Remote",
+            0.8,
+        ))
+        .await
+        .unwrap();
+    db.link_cross_source_duplicates().await.unwrap();
+
+    assert!(db.unlink_job_duplicate(copy).await.unwrap());
+    assert!(!db.unlink_job_duplicate(copy).await.unwrap());
+    assert_eq!(db.link_cross_source_duplicates().await.unwrap(), 0);
+
+    assert!(db.get_job_duplicates(canonical).await.unwrap().is_empty());
+    assert_eq!(
+        ids(&db.get_recent_jobs(10).await.unwrap()),
+        vec![canonical, copy]
+    );
+}
+
+#[tokio::test]
+async fn test_hiding_canonical_job_shows_its_copies() {
+    let db = crate::test_support::migrated_database().await;
+    let canonical = db
+        .upsert_job(&posting(
+            "hide1",
+            "Payroll Specialist",
+            "lever",
+            "Remote",
+            0.9,
+        ))
+        .await
+        .unwrap();
+    let copy = db
+        .upsert_job(&posting(
+            "hide2",
+            "Payroll Specialist",
+            "indeed",
+            "Remote",
+            0.8,
+        ))
+        .await
+        .unwrap();
+    db.link_cross_source_duplicates().await.unwrap();
+
+    db.hide_job(canonical).await.unwrap();
+
+    assert_eq!(ids(&db.get_recent_jobs(10).await.unwrap()), vec![copy]);
+}
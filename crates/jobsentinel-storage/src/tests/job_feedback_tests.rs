@@ -0,0 +1,51 @@
+use super::*;
+use crate::JobFeedback;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_job_feedback_replaces_earlier_verdict() {
+    let db = crate::test_support::migrated_database().await;
+    let job_id = db
+        .upsert_job(&create_test_job("feedback_hash", "Rust Engineer", 0.7))
+        .await
+        .unwrap();
+    assert_eq!(db.get_job_feedback(job_id).await.unwrap(), None);
+
+    db.set_job_feedback(job_id, JobFeedback::Good)
+        .await
+        .unwrap();
+    db.set_job_feedback(job_id, JobFeedback::Bad).await.unwrap();
+
+    assert_eq!(
+        db.get_job_feedback(job_id).await.unwrap(),
+        Some(JobFeedback::Bad)
+    );
+    let examples = db.job_feedback_examples().await.unwrap();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].title, "Rust Engineer");
+    assert_eq!(examples[0].feedback, JobFeedback::Bad);
+
+    db.clear_job_feedback(job_id).await.unwrap();
+    assert_eq!(db.get_job_feedback(job_id).await.unwrap(), None);
+    assert!(db.job_feedback_examples().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_replace_keyword_feedback_weights_drops_old_keywords() {
+    let db = crate::test_support::migrated_database().await;
+    db.replace_keyword_feedback_weights(&HashMap::from([
+        ("rust".to_string(), 0.8),
+        ("java".to_string(), -0.4),
+    ]))
+    .await
+    .unwrap();
+
+    db.replace_keyword_feedback_weights(&HashMap::from([("rust".to_string(), 1.2)]))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        db.keyword_feedback_weights().await.unwrap(),
+        HashMap::from([("rust".to_string(), 1.2)])
+    );
+}
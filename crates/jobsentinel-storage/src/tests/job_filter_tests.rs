@@ -0,0 +1,256 @@
+use super::*;
+use crate::user_data::{
+    MessageTemplateChannel, SavedSearch, SearchAgentFrequency, SearchAgentSettings,
+};
+use crate::{JobFilter, JobSort};
+use chrono::{Duration, Utc};
+
+fn filtered_job(hash: &str, title: &str, score: f64) -> Job {
+    let mut job = create_test_job(hash, title, score);
+    job.company = format!("{title} Co");
+    job
+}
+
+fn ids(jobs: &[Job]) -> Vec<i64> {
+    jobs.iter().map(|job| job.id).collect()
+}
+
+#[tokio::test]
+async fn test_query_jobs_combines_structured_filters() {
+    let db = crate::test_support::migrated_database().await;
+    let mut match_job = filtered_job("filter_match", "Support Manager", 0.82);
+    match_job.source = "greenhouse".to_string();
+    match_job.remote = Some(true);
+    match_job.salary_min = Some(90_000);
+    match_job.salary_max = Some(120_000);
+    match_job.currency = Some("USD".to_string());
+    match_job.ghost_score = Some(0.2);
+    let match_id = db.upsert_job(&match_job).await.unwrap();
+
+    let mut onsite = match_job.clone();
+    onsite.hash = "filter_onsite".to_string();
+    onsite.company = "Onsite Co".to_string();
+    onsite.remote = Some(false);
+    db.upsert_job(&onsite).await.unwrap();
+
+    let mut underpaid = match_job.clone();
+    underpaid.hash = "filter_underpaid".to_string();
+    underpaid.company = "Underpaid Co".to_string();
+    underpaid.salary_max = Some(70_000);
+    underpaid.salary_min = Some(60_000);
+    db.upsert_job(&underpaid).await.unwrap();
+
+    let mut risky = match_job.clone();
+    risky.hash = "filter_risky".to_string();
+    risky.company = "Risky Co".to_string();
+    risky.ghost_score = Some(0.9);
+    db.upsert_job(&risky).await.unwrap();
+
+    let mut old = match_job.clone();
+    old.hash = "filter_old".to_string();
+    old.company = "Old Co".to_string();
+    old.created_at = Utc::now() - Duration::days(40);
+    db.upsert_job(&old).await.unwrap();
+
+    let mut lever = match_job.clone();
+    lever.hash = "filter_lever".to_string();
+    lever.company = "Lever Co".to_string();
+    lever.source = "lever".to_string();
+    db.upsert_job(&lever).await.unwrap();
+
+    let filter = JobFilter {
+        text: Some("Support".to_string()),
+        min_score: Some(0.7),
+        sources: vec!["greenhouse".to_string()],
+        remote: Some(true),
+        salary_min_usd: Some(100_000),
+        posted_within_days: Some(30),
+        max_ghost_score: Some(0.5),
+        ..JobFilter::default()
+    };
+    let jobs = db.query_jobs(&filter, 50).await.unwrap();
+
+    assert_eq!(ids(&jobs), vec![match_id]);
+}
+
+#[tokio::test]
+async fn test_query_jobs_filters_tags_status_and_notes() {
+    let db = crate::test_support::migrated_database().await;
+    let tagged = db
+        .upsert_job(&filtered_job("filter_tagged", "Case Manager", 0.6))
+        .await
+        .unwrap();
+    let closed = db
+        .upsert_job(&filtered_job("filter_closed", "Care Coordinator", 0.9))
+        .await
+        .unwrap();
+    let noted = db
+        .upsert_job(&filtered_job("filter_noted", "Intake Specialist", 0.5))
+        .await
+        .unwrap();
+    db.tag_job(tagged, "Referral").await.unwrap();
+    db.tag_job(closed, "referral").await.unwrap();
+    db.set_job_status(closed, JobStatus::Closed, Some("http_410"))
+        .await
+        .unwrap();
+    db.set_job_notes(noted, Some("Call the recruiter"))
+        .await
+        .unwrap();
+
+    let by_tag = JobFilter {
+        tags: vec!["referral".to_string()],
+        ..JobFilter::default()
+    };
+    assert_eq!(
+        ids(&db.query_jobs(&by_tag, 50).await.unwrap()),
+        vec![tagged]
+    );
+
+    let closed_only = JobFilter {
+        statuses: vec![JobStatus::Closed],
+        ..JobFilter::default()
+    };
+    assert_eq!(
+        ids(&db.query_jobs(&closed_only, 50).await.unwrap()),
+        vec![closed]
+    );
+
+    let with_notes = JobFilter {
+        has_notes: Some(true),
+        ..JobFilter::default()
+    };
+    assert_eq!(
+        ids(&db.query_jobs(&with_notes, 50).await.unwrap()),
+        vec![noted]
+    );
+
+    let by_score_ascending = JobFilter {
+        sort: JobSort::ScoreAsc,
+        ..JobFilter::default()
+    };
+    assert_eq!(
+        ids(&db.query_jobs(&by_score_ascending, 50).await.unwrap()),
+        vec![noted, tagged]
+    );
+}
+
+#[test]
+fn test_saved_search_maps_to_job_filter() {
+    let search = SavedSearch {
+        id: "search-1".to_string(),
+        name: "Remote support".to_string(),
+        sort_by: "date-desc".to_string(),
+        score_filter: "medium".to_string(),
+        source_filter: "greenhouse".to_string(),
+        remote_filter: "remote".to_string(),
+        bookmark_filter: "not-bookmarked".to_string(),
+        notes_filter: "all".to_string(),
+        posted_date_filter: Some("7d".to_string()),
+        salary_min_filter: Some(80_000),
+        salary_max_filter: None,
+        ghost_filter: Some("real".to_string()),
+        text_search: Some("  support ".to_string()),
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        last_used_at: None,
+        agent: SearchAgentSettings::default(),
+    };
+
+    let filter = JobFilter::from_saved_search(&search);
+
+    assert_eq!(filter.text.as_deref(), Some("support"));
+    assert_eq!((filter.min_score, filter.max_score), (Some(0.4), Some(0.7)));
+    assert_eq!(filter.sources, vec!["greenhouse".to_string()]);
+    assert_eq!(filter.remote, Some(true));
+    assert_eq!(filter.bookmarked, Some(false));
+    assert_eq!(filter.has_notes, None);
+    assert_eq!(filter.posted_within_days, Some(7));
+    assert_eq!(filter.salary_min_usd, Some(80_000));
+    assert_eq!(filter.sort, JobSort::DateDesc);
+}
+
+#[tokio::test]
+async fn test_query_jobs_only_returns_jobs_saved_after_a_time() {
+    let db = crate::test_support::migrated_database().await;
+    let mut older = filtered_job("filter_older", "Support Lead", 0.8);
+    older.created_at = Utc::now() - Duration::hours(2);
+    db.upsert_job(&older).await.unwrap();
+    let newer_id = db
+        .upsert_job(&filtered_job("filter_newer", "Support Manager", 0.8))
+        .await
+        .unwrap();
+
+    let filter = JobFilter {
+        saved_after: Some(Utc::now() - Duration::hours(1)),
+        ..JobFilter::default()
+    };
+
+    assert_eq!(
+        ids(&db.query_jobs(&filter, 50).await.unwrap()),
+        vec![newer_id]
+    );
+}
+
+#[tokio::test]
+async fn test_search_agent_settings_start_from_when_turned_on() {
+    let db = crate::test_support::migrated_database().await;
+    let manager = db.user_data_manager();
+    let search = manager
+        .create_saved_search(SavedSearch {
+            id: String::new(),
+            name: "Support".to_string(),
+            sort_by: "score-desc".to_string(),
+            score_filter: "all".to_string(),
+            source_filter: "all".to_string(),
+            remote_filter: "all".to_string(),
+            bookmark_filter: "all".to_string(),
+            notes_filter: "all".to_string(),
+            posted_date_filter: None,
+            salary_min_filter: None,
+            salary_max_filter: None,
+            ghost_filter: None,
+            text_search: Some("support".to_string()),
+            created_at: String::new(),
+            last_used_at: None,
+            agent: SearchAgentSettings::default(),
+        })
+        .await
+        .unwrap();
+    assert!(manager.list_search_agents().await.unwrap().is_empty());
+
+    let settings = SearchAgentSettings {
+        enabled: true,
+        channels: vec![MessageTemplateChannel::Slack],
+        frequency: SearchAgentFrequency::Daily,
+        last_checked_at: None,
+    };
+    assert!(manager
+        .update_saved_search_agent(&search.id, &settings)
+        .await
+        .unwrap());
+    assert!(!manager
+        .update_saved_search_agent("missing", &settings)
+        .await
+        .unwrap());
+
+    let agents = manager.list_search_agents().await.unwrap();
+    assert_eq!(agents.len(), 1);
+    let agent = &agents[0].agent;
+    assert_eq!(agent.channels, vec![MessageTemplateChannel::Slack]);
+    assert_eq!(agent.frequency, SearchAgentFrequency::Daily);
+    let started = agent.last_checked_at.clone().expect("agent start time");
+
+    // Saving other settings keeps the agent's place
+    manager
+        .update_saved_search_agent(
+            &search.id,
+            &SearchAgentSettings {
+                channels: Vec::new(),
+                ..settings
+            },
+        )
+        .await
+        .unwrap();
+    let agents = manager.list_search_agents().await.unwrap();
+    assert_eq!(agents[0].agent.last_checked_at, Some(started));
+    assert!(agents[0].agent.channels.is_empty());
+}
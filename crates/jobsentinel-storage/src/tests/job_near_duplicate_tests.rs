@@ -0,0 +1,114 @@
+use super::*;
+
+const POSTING: &str = "Join our care team to coordinate services for adults in community \
+    programs. You will manage a caseload, document visits, and partner with families \
+    and providers to plan support.";
+
+fn posting(hash: &str, title: &str, company: &str, source: &str, score: f64) -> Job {
+    let mut job = create_test_job(hash, title, score);
+    job.company = company.to_string();
+    job.source = source.to_string();
+    job.description = Some(POSTING.to_string());
+    job
+}
+
+#[tokio::test]
+async fn test_near_duplicates_match_reworded_titles_and_company_suffixes() {
+    let db = crate::test_support::migrated_database().await;
+
+    let primary = db
+        .upsert_job(&posting(
+            "near1",
+            "Senior Case Manager - Remote",
+            "CommunityCare Inc.",
+            "greenhouse",
+            0.95,
+        ))
+        .await
+        .unwrap();
+    db.upsert_job(&posting(
+        "near2",
+        "Senior Case Manager (Remote)",
+        "CommunityCare",
+        "lever",
+        0.90,
+    ))
+    .await
+    .unwrap();
+    db.upsert_job(&posting(
+        "near3",
+        "Payroll Specialist",
+        "CommunityCare",
+        "lever",
+        0.80,
+    ))
+    .await
+    .unwrap();
+
+    let groups = db.find_near_duplicate_groups(0.8).await.unwrap();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].primary_id, primary);
+    assert_eq!(groups[0].jobs.len(), 2);
+    assert_eq!(groups[0].sources, ["greenhouse", "lever"]);
+    assert!(groups[0].similarity >= 0.8);
+}
+
+#[tokio::test]
+async fn test_near_duplicates_require_same_company_and_similar_description() {
+    let db = crate::test_support::migrated_database().await;
+
+    db.upsert_job(&posting(
+        "co1",
+        "Case Manager",
+        "CommunityCare",
+        "greenhouse",
+        0.9,
+    ))
+    .await
+    .unwrap();
+    db.upsert_job(&posting("co2", "Case Manager", "HealthFirst", "lever", 0.9))
+        .await
+        .unwrap();
+    let mut rewritten = posting("co3", "Case Manager", "CommunityCare", "lever", 0.9);
+    rewritten.description =
+        Some("Warehouse shift lead overseeing forklift operations and inventory counts.".into());
+    db.upsert_job(&rewritten).await.unwrap();
+
+    assert!(db.find_near_duplicate_groups(0.8).await.unwrap().is_empty());
+    // A lower threshold lets the matching title carry the pair.
+    assert_eq!(db.find_near_duplicate_groups(0.5).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_near_duplicates_skip_hidden_jobs_and_merge_clears_them() {
+    let db = crate::test_support::migrated_database().await;
+
+    let primary = db
+        .upsert_job(&posting(
+            "m1",
+            "Case Manager",
+            "CommunityCare",
+            "greenhouse",
+            0.9,
+        ))
+        .await
+        .unwrap();
+    let duplicate = db
+        .upsert_job(&posting(
+            "m2",
+            "Case Manager II",
+            "CommunityCare",
+            "lever",
+            0.8,
+        ))
+        .await
+        .unwrap();
+
+    let groups = db.find_near_duplicate_groups(0.6).await.unwrap();
+    assert_eq!(groups.len(), 1);
+
+    db.merge_duplicates(primary, &[duplicate]).await.unwrap();
+
+    assert!(db.find_near_duplicate_groups(0.6).await.unwrap().is_empty());
+}
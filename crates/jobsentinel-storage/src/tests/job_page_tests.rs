@@ -0,0 +1,95 @@
+use super::*;
+use crate::JobCursor;
+use chrono::Duration;
+
+async fn insert_dated_jobs(db: &Database, count: i64) -> Vec<i64> {
+    let now = Utc::now();
+    let mut ids = Vec::new();
+    for day in 0..count {
+        let mut job = create_test_job(&format!("page_{day}"), &format!("Role {day}"), 0.5);
+        job.company = format!("Company {day}");
+        job.created_at = now - Duration::days(day);
+        ids.push(db.upsert_job(&job).await.unwrap());
+    }
+    ids
+}
+
+#[tokio::test]
+async fn test_recent_job_pages_cover_every_job_once_newest_first() {
+    let db = crate::test_support::migrated_database().await;
+    let ids = insert_dated_jobs(&db, 5).await;
+
+    let first = db.get_recent_jobs_page(None, 2).await.unwrap();
+    let second = db.get_recent_jobs_page(first.next_cursor, 2).await.unwrap();
+    let last = db
+        .get_recent_jobs_page(second.next_cursor, 2)
+        .await
+        .unwrap();
+
+    let paged: Vec<i64> = [&first, &second, &last]
+        .iter()
+        .flat_map(|page| page.jobs.iter().map(|job| job.id))
+        .collect();
+    assert_eq!(paged, ids);
+    assert!(first.next_cursor.is_some());
+    assert_eq!(last.jobs.len(), 1);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[tokio::test]
+async fn test_job_pages_break_created_at_ties_by_id() {
+    let db = crate::test_support::migrated_database().await;
+    let created_at = Utc::now();
+    let mut ids = Vec::new();
+    for index in 0..3 {
+        let mut job = create_test_job(&format!("tie_{index}"), &format!("Tie {index}"), 0.5);
+        job.company = format!("Tie Company {index}");
+        job.created_at = created_at;
+        ids.push(db.upsert_job(&job).await.unwrap());
+    }
+
+    let first = db.get_recent_jobs_page(None, 2).await.unwrap();
+    let second = db.get_recent_jobs_page(first.next_cursor, 2).await.unwrap();
+
+    let first_ids: Vec<i64> = first.jobs.iter().map(|job| job.id).collect();
+    assert_eq!(first_ids, vec![ids[2], ids[1]]);
+    assert_eq!(
+        first.next_cursor,
+        Some(JobCursor {
+            created_at,
+            id: ids[1]
+        })
+    );
+    assert_eq!(second.jobs.len(), 1);
+    assert_eq!(second.jobs[0].id, ids[0]);
+}
+
+#[tokio::test]
+async fn test_filtered_job_pages_keep_their_filter() {
+    let db = crate::test_support::migrated_database().await;
+    let ids = insert_dated_jobs(&db, 4).await;
+    for &id in &ids[1..] {
+        db.tag_job(id, "follow up").await.unwrap();
+    }
+    db.toggle_bookmark(ids[3]).await.unwrap();
+
+    let tagged = db.get_jobs_by_tag_page("Follow Up", None, 2).await.unwrap();
+    let rest = db
+        .get_jobs_by_tag_page("follow up", tagged.next_cursor, 2)
+        .await
+        .unwrap();
+    let bookmarked = db.get_bookmarked_jobs_page(None, 10).await.unwrap();
+    let by_source = db.get_jobs_by_source_page("other", None, 10).await.unwrap();
+
+    let tagged_ids: Vec<i64> = tagged
+        .jobs
+        .iter()
+        .chain(&rest.jobs)
+        .map(|job| job.id)
+        .collect();
+    assert_eq!(tagged_ids, ids[1..].to_vec());
+    assert_eq!(rest.next_cursor, None);
+    assert_eq!(bookmarked.jobs.len(), 1);
+    assert_eq!(bookmarked.jobs[0].id, ids[3]);
+    assert!(by_source.jobs.is_empty());
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::JobFieldChange;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_apply_job_refresh_updates_row_and_records_changes() {
+    let db = crate::test_support::migrated_database().await;
+    let job = create_test_job("refresh_hash", "Case Manager", 0.7);
+    let job_id = db.upsert_job(&job).await.unwrap();
+
+    let mut fresh = db.get_job_by_id(job_id).await.unwrap().unwrap();
+    fresh.title = "Senior Case Manager".to_string();
+    fresh.salary_max = Some(210000);
+    fresh.score = Some(0.82);
+    fresh.skills = vec!["Case Management".to_string()];
+    let changes = vec![JobFieldChange {
+        field: "title".to_string(),
+        before: Some("Case Manager".to_string()),
+        after: Some("Senior Case Manager".to_string()),
+    }];
+    let earlier = Utc::now() - Duration::hours(1);
+    db.apply_job_refresh(&fresh, &[], earlier).await.unwrap();
+    db.apply_job_refresh(&fresh, &changes, Utc::now())
+        .await
+        .unwrap();
+
+    let stored = db.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert_eq!(stored.title, "Senior Case Manager");
+    assert_eq!(stored.salary_max, Some(210000));
+    assert_eq!(stored.score, Some(0.82));
+    assert_eq!(stored.hash, "refresh_hash");
+    assert_eq!(stored.times_seen, job.times_seen);
+
+    let refreshes = db.get_job_refreshes(job_id, 10).await.unwrap();
+    assert_eq!(refreshes.len(), 2);
+    assert_eq!(refreshes[0].changes, changes);
+    assert!(refreshes[1].changes.is_empty());
+    assert_eq!(db.get_job_refreshes(job_id, 1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_apply_job_refresh_rejects_unknown_job() {
+    let db = crate::test_support::migrated_database().await;
+    let mut job = create_test_job("refresh_missing", "Case Manager", 0.7);
+    job.id = 9999;
+
+    let error = db
+        .apply_job_refresh(&job, &[], Utc::now())
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("not found"));
+    assert!(db.get_job_refreshes(9999, 10).await.unwrap().is_empty());
+}
@@ -213,3 +213,183 @@ mod search_error_paths {
         assert!(result.is_ok());
     }
 }
+
+mod search_ranking_tests {
+    use super::*;
+
+    fn titles(jobs: &[Job]) -> Vec<&str> {
+        jobs.iter().map(|job| job.title.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_title_matches_above_description_matches() {
+        let db = crate::test_support::migrated_database().await;
+        let mut described = create_test_job("described", "Intake Specialist", 0.9);
+        described.description = Some("Works beside the care coordinator team".to_string());
+        db.upsert_job(&described).await.unwrap();
+        db.upsert_job(&create_test_job("titled", "Care Coordinator", 0.5))
+            .await
+            .unwrap();
+
+        let results = db.search_jobs("coordinator", 10).await.unwrap();
+
+        assert_eq!(titles(&results), ["Care Coordinator", "Intake Specialist"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_matches_the_last_word_as_a_prefix() {
+        let db = crate::test_support::migrated_database().await;
+        db.upsert_job(&create_test_job("prefix", "Care Coordinator", 0.8))
+            .await
+            .unwrap();
+
+        let results = db.search_jobs("care coord", 10).await.unwrap();
+
+        assert_eq!(titles(&results), ["Care Coordinator"]);
+        assert!(db.search_jobs("coord care", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_falls_back_to_near_spellings() {
+        let db = crate::test_support::migrated_database().await;
+        db.upsert_job(&create_test_job("typo", "Care Coordinator", 0.8))
+            .await
+            .unwrap();
+        db.upsert_job(&create_test_job("other", "Retail Supervisor", 0.8))
+            .await
+            .unwrap();
+
+        let results = db.search_jobs("coordinater", 10).await.unwrap();
+
+        assert_eq!(titles(&results), ["Care Coordinator"]);
+        assert!(db.search_jobs("plumber", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_marks_matched_terms_in_title_and_description() {
+        let db = crate::test_support::migrated_database().await;
+        let mut job = create_test_job("marked", "Care Coordinator", 0.8);
+        job.description = Some("Coordinate care plans with the clinic team".to_string());
+        db.upsert_job(&job).await.unwrap();
+
+        let hits = db
+            .search_jobs_with_matches("care", None, None, 10)
+            .await
+            .unwrap();
+
+        let matched = |segments: &[crate::TextSegment]| -> Vec<String> {
+            segments
+                .iter()
+                .filter(|segment| segment.matched)
+                .map(|segment| segment.text.clone())
+                .collect()
+        };
+        assert_eq!(hits.len(), 1);
+        assert_eq!(matched(&hits[0].matches.title), ["Care"]);
+        assert_eq!(hits[0].matches.title[1].text, " Coordinator");
+        assert_eq!(matched(&hits[0].matches.description), ["care"]);
+    }
+
+    #[tokio::test]
+    async fn test_near_spelling_matches_have_no_marked_terms() {
+        let db = crate::test_support::migrated_database().await;
+        db.upsert_job(&create_test_job("unmarked", "Care Coordinator", 0.8))
+            .await
+            .unwrap();
+
+        let hits = db
+            .search_jobs_with_matches("coordinater", None, None, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].matches, crate::SearchMatches::default());
+    }
+
+    #[tokio::test]
+    async fn test_search_leaves_out_postings_at_the_hide_score() {
+        let db = crate::test_support::migrated_database().await;
+        let mut risky = create_test_job("risky", "Care Coordinator", 0.8);
+        risky.ghost_score = Some(0.7);
+        db.upsert_job(&risky).await.unwrap();
+        let mut unanalyzed = create_test_job("unanalyzed", "Care Manager", 0.8);
+        unanalyzed.ghost_score = None;
+        db.upsert_job(&unanalyzed).await.unwrap();
+
+        let hits = db
+            .search_jobs_with_matches("care", None, Some(0.7), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].job.title, "Care Manager");
+        assert_eq!(db.search_jobs("care", 10).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_fills_the_limit_past_closed_and_collapsed_jobs() {
+        let db = crate::test_support::migrated_database().await;
+        let closed = db
+            .upsert_job(&create_test_job("closed", "Care Coordinator", 0.9))
+            .await
+            .unwrap();
+        db.set_job_status(closed, JobStatus::Closed, None)
+            .await
+            .unwrap();
+        let mut described = create_test_job("described", "Intake Specialist", 0.5);
+        described.description = Some("Works beside the care coordinator team".to_string());
+        db.upsert_job(&described).await.unwrap();
+
+        let results = db.search_jobs("coordinator", 1).await.unwrap();
+
+        assert_eq!(titles(&results), ["Intake Specialist"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_treats_fts_syntax_as_words() {
+        let db = crate::test_support::migrated_database().await;
+        db.upsert_job(&create_test_job("syntax", "Case Manager", 0.8))
+            .await
+            .unwrap();
+
+        let results = db.search_jobs("case NOT \"manager", 10).await.unwrap();
+
+        assert!(results.is_empty());
+        assert_eq!(db.search_jobs("case: manager", 10).await.unwrap().len(), 1);
+    }
+}
+
+mod search_index_maintenance_tests {
+    use super::*;
+    use crate::MaintenanceStep;
+
+    async fn indexed_case_jobs(db: &Database) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM jobs_fts WHERE jobs_fts MATCH 'case'")
+            .fetch_one(db.pool())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_rebuilds_a_search_index_out_of_step_with_jobs() {
+        let db = crate::test_support::migrated_database().await;
+        let job = create_test_job("fts_hash", "Case Manager", 0.8);
+        let job_id = db.upsert_job(&job).await.unwrap();
+        sqlx::query(
+            "INSERT INTO jobs_fts(jobs_fts, rowid, title, company, description) VALUES ('delete', ?, ?, ?, ?)",
+        )
+        .bind(job_id)
+        .bind(&job.title)
+        .bind(&job.company)
+        .bind(&job.description)
+        .execute(db.pool())
+        .await
+        .unwrap();
+        assert_eq!(indexed_case_jobs(&db).await, 0);
+
+        let maintenance = db.run_maintenance().await.unwrap();
+
+        assert_eq!(maintenance.steps, [MaintenanceStep::FtsRebuild]);
+        assert_eq!(indexed_case_jobs(&db).await, 1);
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_closed_jobs_leave_recent_and_search_results() {
+    let db = crate::test_support::migrated_database().await;
+    let open = db
+        .upsert_job(&create_test_job("open_hash", "Case Manager", 0.8))
+        .await
+        .unwrap();
+    let closed = db
+        .upsert_job(&create_test_job("closed_hash", "Case Manager Lead", 0.9))
+        .await
+        .unwrap();
+
+    db.set_job_status(closed, JobStatus::Closed, Some("http_410"))
+        .await
+        .unwrap();
+
+    let recent = db.get_recent_jobs(10).await.unwrap();
+    assert_eq!(
+        recent.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![open]
+    );
+    let filtered = db.get_recent_jobs_filtered(10, Some(0.5)).await.unwrap();
+    assert_eq!(filtered.len(), 1);
+    let found = db.search_jobs("Case", 10).await.unwrap();
+    assert_eq!(
+        found.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![open]
+    );
+    let stored = db.get_job_by_id(closed).await.unwrap().unwrap();
+    assert_eq!(stored.status, JobStatus::Closed);
+}
+
+#[tokio::test]
+async fn test_listing_a_job_again_reopens_it() {
+    let db = crate::test_support::migrated_database().await;
+    let job = create_test_job("relisted_hash", "Care Coordinator", 0.7);
+    let job_id = db.upsert_job(&job).await.unwrap();
+    db.set_job_status(job_id, JobStatus::Closed, Some("redirected"))
+        .await
+        .unwrap();
+
+    db.upsert_job(&job).await.unwrap();
+
+    let stored = db.get_job_by_id(job_id).await.unwrap().unwrap();
+    assert_eq!(stored.status, JobStatus::Active);
+}
+
+#[tokio::test]
+async fn test_liveness_check_prefers_applied_and_unchecked_jobs() {
+    let db = crate::test_support::migrated_database().await;
+    let checked = db
+        .upsert_job(&create_test_job("checked_hash", "Case Manager", 0.9))
+        .await
+        .unwrap();
+    let unchecked = db
+        .upsert_job(&create_test_job("unchecked_hash", "Care Coordinator", 0.8))
+        .await
+        .unwrap();
+    let applied = db
+        .upsert_job(&create_test_job("applied_hash", "Intake Specialist", 0.7))
+        .await
+        .unwrap();
+    let closed = db
+        .upsert_job(&create_test_job("gone_hash", "Outreach Worker", 0.6))
+        .await
+        .unwrap();
+    db.set_job_status(checked, JobStatus::Active, None)
+        .await
+        .unwrap();
+    db.set_job_status(applied, JobStatus::Active, None)
+        .await
+        .unwrap();
+    db.set_job_status(closed, JobStatus::Closed, Some("http_404"))
+        .await
+        .unwrap();
+    db.application_tracker()
+        .create_application("applied_hash")
+        .await
+        .unwrap();
+
+    let due = db
+        .jobs_due_for_liveness_check(Utc::now() + Duration::minutes(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        due.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![applied, unchecked, checked]
+    );
+
+    let not_yet = db
+        .jobs_due_for_liveness_check(Utc::now() - Duration::days(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        not_yet.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![unchecked]
+    );
+}
+
+#[tokio::test]
+async fn test_expire_unseen_jobs_marks_only_stale_active_jobs() {
+    let db = crate::test_support::migrated_database().await;
+    let mut stale = create_test_job("stale_hash", "Case Manager", 0.8);
+    stale.last_seen = Utc::now() - Duration::days(90);
+    let stale = db.upsert_job(&stale).await.unwrap();
+    let fresh = db
+        .upsert_job(&create_test_job("fresh_hash", "Care Coordinator", 0.8))
+        .await
+        .unwrap();
+
+    let expired = db
+        .expire_unseen_jobs(Utc::now() - Duration::days(60))
+        .await
+        .unwrap();
+
+    assert_eq!(expired, 1);
+    let stale = db.get_job_by_id(stale).await.unwrap().unwrap();
+    assert_eq!(stale.status, JobStatus::Expired);
+    let fresh = db.get_job_by_id(fresh).await.unwrap().unwrap();
+    assert_eq!(fresh.status, JobStatus::Active);
+    // Expired jobs stay in the default list.
+    assert_eq!(db.get_recent_jobs(10).await.unwrap().len(), 2);
+}
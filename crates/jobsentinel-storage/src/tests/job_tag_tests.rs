@@ -0,0 +1,89 @@
+use super::*;
+
+#[tokio::test]
+async fn test_tags_are_added_once_and_match_any_case() {
+    let db = crate::test_support::migrated_database().await;
+    let job_id = db
+        .upsert_job(&create_test_job("tag_hash", "Case Manager", 0.8))
+        .await
+        .unwrap();
+
+    assert!(db.tag_job(job_id, "  Apply   this week ").await.unwrap());
+    assert!(!db.tag_job(job_id, "apply this week").await.unwrap());
+    assert!(db.tag_job(job_id, "Referral").await.unwrap());
+
+    assert_eq!(
+        db.get_job_tags(job_id).await.unwrap(),
+        vec!["Apply this week".to_string(), "Referral".to_string()]
+    );
+    assert!(db.untag_job(job_id, "REFERRAL").await.unwrap());
+    assert!(!db.untag_job(job_id, "referral").await.unwrap());
+    assert_eq!(db.get_job_tags(job_id).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_tag_job_rejects_blank_long_and_missing_jobs() {
+    let db = crate::test_support::migrated_database().await;
+    let job_id = db
+        .upsert_job(&create_test_job("tag_reject_hash", "Case Manager", 0.8))
+        .await
+        .unwrap();
+
+    assert!(db.tag_job(job_id, "   ").await.is_err());
+    assert!(db.tag_job(job_id, &"x".repeat(41)).await.is_err());
+    assert!(matches!(
+        db.tag_job(job_id + 100, "referral").await,
+        Err(sqlx::Error::RowNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_tag_filters_lists_counts_and_search() {
+    let db = crate::test_support::migrated_database().await;
+    let tagged = db
+        .upsert_job(&create_test_job("tagged_hash", "Support Manager", 0.7))
+        .await
+        .unwrap();
+    let other = db
+        .upsert_job(&create_test_job("untagged_hash", "Support Lead", 0.9))
+        .await
+        .unwrap();
+    let hidden = db
+        .upsert_job(&create_test_job("hidden_tag_hash", "Support Analyst", 0.8))
+        .await
+        .unwrap();
+    for job_id in [tagged, hidden] {
+        db.tag_job(job_id, "referral").await.unwrap();
+    }
+    db.tag_job(other, "remote ok").await.unwrap();
+    db.hide_job(hidden).await.unwrap();
+
+    let by_tag = db.get_jobs_by_tag("Referral", 10).await.unwrap();
+    assert_eq!(
+        by_tag.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![tagged]
+    );
+
+    let found = db
+        .search_jobs_tagged("Support", Some("referral"), 10)
+        .await
+        .unwrap();
+    assert!(found.iter().any(|job| job.id == tagged));
+    assert!(found.iter().all(|job| job.id != other));
+    let all = db.search_jobs("Support", 10).await.unwrap();
+    assert!(all.iter().any(|job| job.id == other));
+
+    assert_eq!(
+        db.list_job_tags().await.unwrap(),
+        vec![
+            JobTagCount {
+                tag: "referral".to_string(),
+                job_count: 1,
+            },
+            JobTagCount {
+                tag: "remote ok".to_string(),
+                job_count: 1,
+            },
+        ]
+    );
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::TimeToFillScope;
+use chrono::Duration;
+
+/// A posting first seen `opened_days_ago` that stayed open for `days_open`.
+fn posting(hash: &str, title: &str, company: &str, opened_days_ago: i64, days_open: i64) -> Job {
+    let mut job = create_test_job(hash, title, 0.8);
+    job.company = company.to_string();
+    job.created_at = Utc::now() - Duration::days(opened_days_ago);
+    job.last_seen = job.created_at + Duration::days(days_open);
+    job
+}
+
+#[tokio::test]
+async fn test_time_to_fill_uses_median_of_closed_postings_in_role_family() {
+    let db = crate::test_support::migrated_database().await;
+
+    for (hash, title, days_open) in [
+        ("ttf1", "Case Manager", 10),
+        ("ttf2", "Senior Case Manager", 12),
+        ("ttf3", "Case Manager II (Remote)", 30),
+        ("ttf4", "Payroll Specialist", 60),
+    ] {
+        db.upsert_job(&posting(hash, title, "CommunityCare", 90, days_open))
+            .await
+            .unwrap();
+    }
+    // Still being seen, so not closed yet.
+    db.upsert_job(&posting("ttf5", "Case Manager", "CommunityCare", 2, 2))
+        .await
+        .unwrap();
+    let open = db
+        .upsert_job(&posting("ttf6", "Sr. Case Manager", "communitycare", 1, 0))
+        .await
+        .unwrap();
+
+    let estimate = db.get_time_to_fill_estimate(open).await.unwrap().unwrap();
+
+    assert_eq!(estimate.scope, TimeToFillScope::Role);
+    assert_eq!(estimate.role_family, "case manager");
+    assert_eq!(estimate.sample_size, 3);
+    assert!((estimate.median_days - 12.0).abs() < 0.01);
+}
+
+#[tokio::test]
+async fn test_time_to_fill_falls_back_to_company_and_needs_enough_history() {
+    let db = crate::test_support::migrated_database().await;
+
+    for (hash, title, days_open) in [
+        ("co1", "Payroll Specialist", 5),
+        ("co2", "Office Manager", 9),
+    ] {
+        db.upsert_job(&posting(hash, title, "HealthFirst", 60, days_open))
+            .await
+            .unwrap();
+    }
+    let open = db
+        .upsert_job(&posting("co3", "Case Manager", "HealthFirst", 1, 0))
+        .await
+        .unwrap();
+    assert!(db.get_time_to_fill_estimate(open).await.unwrap().is_none());
+
+    db.upsert_job(&posting("co4", "Receptionist", "HealthFirst", 60, 20))
+        .await
+        .unwrap();
+    let estimate = db.get_time_to_fill_estimate(open).await.unwrap().unwrap();
+    assert_eq!(estimate.scope, TimeToFillScope::Company);
+    assert_eq!(estimate.sample_size, 3);
+    assert!((estimate.median_days - 9.0).abs() < 0.01);
+
+    assert!(db.get_time_to_fill_estimate(9_999).await.unwrap().is_none());
+}
@@ -64,4 +64,114 @@ mod upsert_coverage_tests {
         assert!(fetched.score_reasons.is_some());
         assert!(fetched.score_reasons.unwrap().contains("skill_match"));
     }
+
+    #[tokio::test]
+    async fn test_upsert_records_source_skill_tags() {
+        let db = crate::test_support::migrated_database().await;
+
+        let mut job = create_test_job("skills_test", "Support Engineer", 0.7);
+        job.skills = vec!["zendesk".to_string(), "customer support".to_string()];
+        db.upsert_job(&job).await.unwrap();
+        job.skills.push("intercom".to_string());
+        db.upsert_job(&job).await.unwrap();
+
+        let skills: Vec<String> = sqlx::query_scalar(
+            "SELECT skill_name FROM job_skills WHERE job_hash = ? ORDER BY skill_name",
+        )
+        .bind(&job.hash)
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+        assert_eq!(skills, vec!["customer support", "intercom", "zendesk"]);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_extracts_skills_from_description() {
+        let db = crate::test_support::migrated_database().await;
+
+        let mut job = create_test_job("extract_test", "Case Manager", 0.7);
+        job.description = Some("Own case management and keep CRM records current".to_string());
+        job.skills = vec!["board tag".to_string()];
+        db.upsert_job(&job).await.unwrap();
+
+        let skills = db
+            .resume_matcher()
+            .get_job_skill_names("extract_test")
+            .await
+            .unwrap();
+        assert!(skills.contains(&"Case Management".to_string()));
+        assert!(skills.contains(&"CRM".to_string()));
+
+        job.description = Some("Own case management for new clients".to_string());
+        db.upsert_job(&job).await.unwrap();
+
+        let skills = db
+            .resume_matcher()
+            .get_job_skill_names("extract_test")
+            .await
+            .unwrap();
+        assert!(skills.contains(&"Case Management".to_string()));
+        assert!(skills.contains(&"board tag".to_string()));
+        assert!(!skills.contains(&"CRM".to_string()));
+    }
+}
+
+mod upsert_batch_tests {
+    use super::*;
+    use crate::JobUpsert;
+
+    #[tokio::test]
+    async fn test_batch_reports_new_and_updated_jobs_in_order() {
+        let db = crate::test_support::migrated_database().await;
+        let existing = create_test_job("batch_existing", "Support Lead", 0.6);
+        let existing_id = db.upsert_job(&existing).await.unwrap();
+        let mut fresh = create_test_job("batch_fresh", "Rust Engineer", 0.8);
+        fresh.company = "Fresh Co".to_string();
+
+        let results = db.upsert_jobs_batch([&fresh, &existing]).await.unwrap();
+
+        let fresh_id = match results[0] {
+            Ok(JobUpsert::New(job_id)) => job_id,
+            ref other => panic!("expected a new job, got {other:?}"),
+        };
+        assert!(matches!(results[1], Ok(JobUpsert::Updated(id)) if id == existing_id));
+        let saved = db.get_job_by_id(fresh_id).await.unwrap().unwrap();
+        assert_eq!(saved.title, "Rust Engineer");
+        let updated = db.get_job_by_id(existing_id).await.unwrap().unwrap();
+        assert_eq!(updated.times_seen, 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_skips_invalid_jobs_and_saves_the_rest() {
+        let db = crate::test_support::migrated_database().await;
+        let mut invalid = create_test_job("batch_invalid", "Broken Link", 0.5);
+        invalid.url = "http://insecure.example.com/job".to_string();
+        let valid = create_test_job("batch_valid", "Data Analyst", 0.7);
+
+        let results = db.upsert_jobs_batch([&invalid, &valid]).await.unwrap();
+
+        assert!(results[0].is_err());
+        assert!(matches!(results[1], Ok(JobUpsert::New(_))));
+        assert!(db.get_job_by_hash("batch_invalid").await.unwrap().is_none());
+        assert!(db.get_job_by_hash("batch_valid").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_batch_records_source_and_extracted_skills() {
+        let db = crate::test_support::migrated_database().await;
+        let mut job = create_test_job("batch_skills", "Case Manager", 0.7);
+        job.description = Some("Own case management for new clients".to_string());
+        job.skills = vec!["board tag".to_string()];
+
+        db.upsert_jobs_batch([&job]).await.unwrap();
+
+        let skills: Vec<String> =
+            sqlx::query_scalar("SELECT skill_name FROM job_skills WHERE job_hash = ?")
+                .bind(&job.hash)
+                .fetch_all(db.pool())
+                .await
+                .unwrap();
+        assert!(skills.contains(&"Case Management".to_string()));
+        assert!(skills.contains(&"board tag".to_string()));
+    }
 }
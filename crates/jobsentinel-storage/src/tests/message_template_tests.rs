@@ -0,0 +1,46 @@
+use crate::user_data::{MessageTemplateChannel, NotificationMessageTemplates};
+
+#[tokio::test]
+async fn test_message_templates_save_replace_and_reset() {
+    let db = crate::test_support::migrated_database().await;
+    let manager = db.user_data_manager();
+    assert_eq!(
+        manager.get_message_templates().await.unwrap(),
+        NotificationMessageTemplates::default()
+    );
+
+    manager
+        .save_message_template(MessageTemplateChannel::Slack, Some("{{title}}"))
+        .await
+        .unwrap();
+    manager
+        .save_message_template(
+            MessageTemplateChannel::Slack,
+            Some("*{{title}}* {{salary}}"),
+        )
+        .await
+        .unwrap();
+    manager
+        .save_message_template(MessageTemplateChannel::Email, Some("{{company}}"))
+        .await
+        .unwrap();
+
+    let templates = manager.get_message_templates().await.unwrap();
+    assert_eq!(
+        templates.get(MessageTemplateChannel::Slack),
+        Some("*{{title}}* {{salary}}")
+    );
+    assert_eq!(templates.get(MessageTemplateChannel::Discord), None);
+    assert_eq!(
+        templates.get(MessageTemplateChannel::Email),
+        Some("{{company}}")
+    );
+
+    manager
+        .save_message_template(MessageTemplateChannel::Slack, None)
+        .await
+        .unwrap();
+    let templates = manager.get_message_templates().await.unwrap();
+    assert_eq!(templates.slack, None);
+    assert_eq!(templates.email.as_deref(), Some("{{company}}"));
+}
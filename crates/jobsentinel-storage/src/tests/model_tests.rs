@@ -24,6 +24,7 @@ mod job_struct_tests {
             salary_min: Some(150000),
             salary_max: Some(200000),
             currency: Some("USD".to_string()),
+            pay_period: None,
             created_at: now,
             updated_at: now,
             last_seen: now,
@@ -37,6 +38,8 @@ mod job_struct_tests {
             ghost_reasons: None,
             first_seen: None,
             repost_count: 0,
+            status: JobStatus::Active,
+            skills: Vec::new(),
         };
 
         assert_eq!(job.id, 42);
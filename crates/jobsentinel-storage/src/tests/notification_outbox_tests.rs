@@ -0,0 +1,106 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_outbox_delivers_due_alerts_and_schedules_retries() {
+    let db = crate::test_support::migrated_database().await;
+    let job_id = db
+        .upsert_job(&create_test_job("outbox_job", "Staff Engineer", 0.95))
+        .await
+        .unwrap();
+    let now = Utc::now();
+
+    let id = db
+        .enqueue_notification("outbox_job", 0.95, "{}", now)
+        .await
+        .unwrap();
+    let due = db.due_notifications(now, 10).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id, id);
+    assert_eq!(due[0].attempts, 0);
+
+    let lease = now + Duration::minutes(5);
+    assert!(db.claim_notification(id, now, lease).await.unwrap());
+    assert!(!db.claim_notification(id, now, lease).await.unwrap());
+    assert!(db.due_notifications(now, 10).await.unwrap().is_empty());
+
+    let retry_at = now + Duration::minutes(2);
+    db.mark_notification_failed(id, "Slack: delivery failed", Some(retry_at))
+        .await
+        .unwrap();
+    assert!(db.due_notifications(now, 10).await.unwrap().is_empty());
+    let due = db.due_notifications(retry_at, 10).await.unwrap();
+    assert_eq!(due[0].attempts, 1);
+
+    db.mark_notification_sent(id, retry_at).await.unwrap();
+    assert!(db
+        .due_notifications(retry_at + Duration::hours(1), 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let history = db.notification_history(10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].job_id, Some(job_id));
+    assert_eq!(history[0].title.as_deref(), Some("Staff Engineer"));
+    assert_eq!(history[0].status, "sent");
+    assert_eq!(history[0].attempts, 2);
+    assert_eq!(history[0].last_error, None);
+    assert_eq!(history[0].next_attempt_at, None);
+    assert_eq!(history[0].sent_at, Some(retry_at));
+}
+
+#[tokio::test]
+async fn test_outbox_gives_up_without_retry_time_and_keeps_history_for_deleted_jobs() {
+    let db = crate::test_support::migrated_database().await;
+    let now = Utc::now();
+    let older = db
+        .enqueue_notification("gone_job", 0.91, "{}", now - Duration::minutes(5))
+        .await
+        .unwrap();
+    let newer = db
+        .enqueue_notification("gone_job_2", 0.92, "{}", now)
+        .await
+        .unwrap();
+
+    db.mark_notification_failed(older, "Slack: not configured", None)
+        .await
+        .unwrap();
+    let due = db.due_notifications(now, 10).await.unwrap();
+    assert_eq!(
+        due.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+        [newer]
+    );
+
+    let history = db.notification_history(10).await.unwrap();
+    let ids: Vec<i64> = history.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![newer, older]);
+    assert_eq!(history[1].status, "failed");
+    assert_eq!(history[1].job_id, None);
+    assert_eq!(history[1].title, None);
+    assert_eq!(
+        history[1].last_error.as_deref(),
+        Some("Slack: not configured")
+    );
+    assert_eq!(db.notification_history(1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_deferred_alert_waits_without_using_an_attempt() {
+    let db = crate::test_support::migrated_database().await;
+    let now = Utc::now();
+    let id = db
+        .enqueue_notification("quiet_job", 0.93, "{}", now)
+        .await
+        .unwrap();
+    let until = now + Duration::hours(8);
+
+    db.defer_notification(id, until).await.unwrap();
+
+    assert!(db.due_notifications(now, 10).await.unwrap().is_empty());
+    let due = db.due_notifications(until, 10).await.unwrap();
+    assert_eq!(due[0].attempts, 0);
+    let history = db.notification_history(10).await.unwrap();
+    assert_eq!(history[0].status, "pending");
+    assert_eq!(history[0].next_attempt_at, Some(until));
+}
@@ -0,0 +1,56 @@
+use super::*;
+use chrono::Duration;
+
+const DESCRIPTION: &str = "Coordinate patient intake, schedule follow-up visits, and keep care \
+                           plans current for the outpatient clinic team";
+
+fn posting(hash: &str, company: &str, days_ago: i64, description: &str) -> Job {
+    let mut job = create_test_job(hash, "Care Coordinator", 0.8);
+    job.company = company.to_string();
+    job.description = Some(description.to_string());
+    job.created_at = Utc::now() - Duration::days(days_ago);
+    job
+}
+
+#[tokio::test]
+async fn test_reworded_reposts_join_one_chain() {
+    let db = crate::test_support::migrated_database().await;
+    let original = posting("original", "Example Clinic", 60, DESCRIPTION);
+    db.upsert_job(&original).await.unwrap();
+    let first_repost = posting(
+        "first_repost",
+        "Example Clinic, Inc.",
+        30,
+        &format!("{DESCRIPTION} today"),
+    );
+    db.upsert_job(&first_repost).await.unwrap();
+    let second_repost = posting("second_repost", "EXAMPLE CLINIC", 0, DESCRIPTION);
+
+    assert_eq!(db.link_repost_chain(&first_repost).await.unwrap(), 1);
+    assert_eq!(db.link_repost_chain(&second_repost).await.unwrap(), 2);
+    assert_eq!(db.link_repost_chain(&second_repost).await.unwrap(), 2);
+    assert_eq!(db.get_repost_chain_count("original").await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_repost_chains_skip_parallel_openings_and_other_roles() {
+    let db = crate::test_support::migrated_database().await;
+    db.upsert_job(&posting("earlier", "Example Clinic", 3, DESCRIPTION))
+        .await
+        .unwrap();
+    db.upsert_job(&posting("elsewhere", "Other Clinic", 30, DESCRIPTION))
+        .await
+        .unwrap();
+    let mut other_role = posting(
+        "other_role",
+        "Example Clinic",
+        30,
+        "Answer phones and greet visitors at the front desk of a busy office",
+    );
+    other_role.title = "Receptionist".to_string();
+    db.upsert_job(&other_role).await.unwrap();
+
+    let job = posting("new", "Example Clinic", 0, DESCRIPTION);
+    assert_eq!(db.link_repost_chain(&job).await.unwrap(), 0);
+    assert_eq!(db.get_repost_chain_count("earlier").await.unwrap(), 0);
+}
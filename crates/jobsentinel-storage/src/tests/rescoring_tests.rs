@@ -0,0 +1,64 @@
+use super::*;
+use crate::RescoredJob;
+
+#[tokio::test]
+async fn test_jobs_after_id_pages_in_id_order() {
+    let db = crate::test_support::migrated_database().await;
+    let first_id = db
+        .upsert_job(&create_test_job("first_hash", "Case Manager", 0.7))
+        .await
+        .unwrap();
+    let second_id = db
+        .upsert_job(&create_test_job("second_hash", "Care Navigator", 0.6))
+        .await
+        .unwrap();
+
+    let page = db.jobs_after_id(0, 1).await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, first_id);
+
+    let page = db.jobs_after_id(first_id, 10).await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, second_id);
+    assert!(db.jobs_after_id(second_id, 10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_save_rescored_jobs_keeps_updated_at() {
+    let db = crate::test_support::migrated_database().await;
+    let first_id = db
+        .upsert_job(&create_test_job("rescore_hash", "Case Manager", 0.7))
+        .await
+        .unwrap();
+    let second_id = db
+        .upsert_job(&create_test_job("other_hash", "Care Navigator", 0.6))
+        .await
+        .unwrap();
+    let before = db.get_job_by_id(first_id).await.unwrap().unwrap();
+    assert_eq!(db.count_jobs().await.unwrap(), 2);
+
+    db.save_rescored_jobs(&[
+        RescoredJob {
+            job_id: first_id,
+            score: 0.35,
+            score_reasons: r#"["Lower skills weight"]"#.to_string(),
+        },
+        RescoredJob {
+            job_id: second_id,
+            score: 0.8,
+            score_reasons: "[]".to_string(),
+        },
+    ])
+    .await
+    .unwrap();
+
+    let after = db.get_job_by_id(first_id).await.unwrap().unwrap();
+    assert_eq!(after.score, Some(0.35));
+    assert_eq!(
+        after.score_reasons.as_deref(),
+        Some(r#"["Lower skills weight"]"#)
+    );
+    assert_eq!(after.updated_at, before.updated_at);
+    let other = db.get_job_by_id(second_id).await.unwrap().unwrap();
+    assert_eq!(other.score, Some(0.8));
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::RetentionPolicy;
+use chrono::Duration;
+
+async fn insert_stale_job(db: &Database, hash: &str, title: &str, score: Option<f64>) -> i64 {
+    let mut job = create_test_job(hash, title, 0.5);
+    job.score = score;
+    job.last_seen = Utc::now() - Duration::days(120);
+    db.upsert_job(&job).await.unwrap()
+}
+
+fn policy() -> RetentionPolicy {
+    RetentionPolicy {
+        last_seen_before: Utc::now() - Duration::days(90),
+        below_score: None,
+        keep_bookmarked: true,
+        keep_with_notes: true,
+    }
+}
+
+#[tokio::test]
+async fn test_purge_deletes_only_unseen_jobs_without_user_data() {
+    let db = crate::test_support::migrated_database().await;
+    let stale = insert_stale_job(&db, "stale_hash", "Case Manager", Some(0.3)).await;
+    let bookmarked = insert_stale_job(&db, "saved_hash", "Care Coordinator", Some(0.3)).await;
+    let noted = insert_stale_job(&db, "noted_hash", "Intake Specialist", Some(0.3)).await;
+    insert_stale_job(&db, "applied_hash", "Outreach Worker", Some(0.3)).await;
+    let fresh = db
+        .upsert_job(&create_test_job("fresh_hash", "Program Aide", 0.3))
+        .await
+        .unwrap();
+    db.set_bookmark(bookmarked, true).await.unwrap();
+    db.set_job_notes(noted, Some("Call back in spring"))
+        .await
+        .unwrap();
+    db.application_tracker()
+        .create_application("applied_hash")
+        .await
+        .unwrap();
+
+    let report = db.purge_jobs(&policy(), false).await.unwrap();
+
+    assert!(!report.dry_run);
+    assert_eq!(report.jobs_matched, 1);
+    assert_eq!(report.jobs_deleted, 1);
+    assert_eq!(
+        report.sample.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![stale]
+    );
+    assert!(db.get_job_by_id(stale).await.unwrap().is_none());
+    for kept in [bookmarked, noted, fresh] {
+        assert!(db.get_job_by_id(kept).await.unwrap().is_some());
+    }
+    assert!(db.get_job_by_hash("applied_hash").await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_purge_keeps_jobs_with_tags_or_feedback() {
+    let db = crate::test_support::migrated_database().await;
+    let stale = insert_stale_job(&db, "stale_hash", "Case Manager", Some(0.3)).await;
+    let tagged = insert_stale_job(&db, "tagged_hash", "Care Coordinator", Some(0.3)).await;
+    let rated = insert_stale_job(&db, "rated_hash", "Intake Specialist", Some(0.3)).await;
+    let ghosted = insert_stale_job(&db, "ghosted_hash", "Outreach Worker", Some(0.3)).await;
+    db.tag_job(tagged, "follow up").await.unwrap();
+    db.set_job_feedback(rated, crate::JobFeedback::Bad)
+        .await
+        .unwrap();
+    db.mark_job_as_ghost(ghosted).await.unwrap();
+
+    let report = db.purge_jobs(&policy(), false).await.unwrap();
+
+    assert_eq!(report.jobs_deleted, 1);
+    assert!(db.get_job_by_id(stale).await.unwrap().is_none());
+    for kept in [tagged, rated, ghosted] {
+        assert!(db.get_job_by_id(kept).await.unwrap().is_some());
+    }
+    assert_eq!(
+        db.get_job_feedback(rated).await.unwrap(),
+        Some(crate::JobFeedback::Bad)
+    );
+}
+
+#[tokio::test]
+async fn test_purge_dry_run_reports_without_deleting() {
+    let db = crate::test_support::migrated_database().await;
+    let stale = insert_stale_job(&db, "stale_hash", "Case Manager", Some(0.3)).await;
+
+    let report = db.purge_jobs(&policy(), true).await.unwrap();
+
+    assert!(report.dry_run);
+    assert_eq!(report.jobs_matched, 1);
+    assert_eq!(report.jobs_deleted, 0);
+    assert_eq!(report.sample[0].title, "Case Manager");
+    assert!(db.get_job_by_id(stale).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_purge_score_ceiling_keeps_strong_matches() {
+    let db = crate::test_support::migrated_database().await;
+    let weak = insert_stale_job(&db, "weak_hash", "Case Manager", Some(0.2)).await;
+    let unscored = insert_stale_job(&db, "unscored_hash", "Care Coordinator", None).await;
+    let strong = insert_stale_job(&db, "strong_hash", "Intake Specialist", Some(0.9)).await;
+    let bookmarked = insert_stale_job(&db, "saved_hash", "Outreach Worker", Some(0.2)).await;
+    db.set_bookmark(bookmarked, true).await.unwrap();
+
+    let report = db
+        .purge_jobs(
+            &RetentionPolicy {
+                below_score: Some(0.5),
+                keep_bookmarked: false,
+                ..policy()
+            },
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(report.jobs_deleted, 3);
+    for purged in [weak, unscored, bookmarked] {
+        assert!(db.get_job_by_id(purged).await.unwrap().is_none());
+    }
+    assert!(db.get_job_by_id(strong).await.unwrap().is_some());
+}
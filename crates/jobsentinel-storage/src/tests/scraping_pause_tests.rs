@@ -0,0 +1,28 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_scraping_pause_keeps_first_time_and_clears_on_resume() {
+    let db = crate::test_support::migrated_database().await;
+    let paused = Utc::now() - Duration::hours(2);
+
+    assert_eq!(db.scraping_paused_at().await.unwrap(), None);
+    assert!(!db.resume_scraping().await.unwrap());
+
+    let first = db.pause_scraping(paused).await.unwrap();
+    assert_eq!(first.timestamp(), paused.timestamp());
+
+    // Pausing again keeps the original time.
+    let again = db.pause_scraping(Utc::now()).await.unwrap();
+    assert_eq!(again.timestamp(), paused.timestamp());
+    assert_eq!(
+        db.scraping_paused_at()
+            .await
+            .unwrap()
+            .map(|t| t.timestamp()),
+        Some(paused.timestamp())
+    );
+
+    assert!(db.resume_scraping().await.unwrap());
+    assert_eq!(db.scraping_paused_at().await.unwrap(), None);
+}
@@ -0,0 +1,117 @@
+use super::*;
+use crate::user_data::{
+    history_suggestion_score, job_suggestion_score, QuerySuggestion, QuerySuggestionSource,
+};
+
+fn texts(suggestions: &[QuerySuggestion]) -> Vec<&str> {
+    suggestions
+        .iter()
+        .map(|suggestion| suggestion.text.as_str())
+        .collect()
+}
+
+#[test]
+fn test_history_score_rewards_frequency_and_recency() {
+    assert!(history_suggestion_score(10, 1.0) > history_suggestion_score(1, 1.0));
+    assert!(history_suggestion_score(3, 0.0) > history_suggestion_score(3, 30.0));
+    // A query searched once today outranks a title shared by many jobs
+    assert!(history_suggestion_score(1, 0.0) > job_suggestion_score(20));
+}
+
+#[tokio::test]
+async fn test_suggest_queries_ranks_history_before_job_titles_and_companies() {
+    let db = crate::test_support::migrated_database().await;
+    let manager = db.user_data_manager();
+    for query in [
+        "care coordinator",
+        "care coordinator",
+        "cashier",
+        "remote care",
+    ] {
+        manager.add_search_history(query).await.unwrap();
+    }
+    let mut job = create_test_job("suggest_1", "Care Manager", 0.8);
+    job.company = "CareBridge".to_string();
+    db.upsert_job(&job).await.unwrap();
+    let hidden_id = db
+        .upsert_job(&create_test_job("suggest_2", "Care Aide", 0.8))
+        .await
+        .unwrap();
+    db.hide_job(hidden_id).await.unwrap();
+
+    let suggestions = manager.suggest_queries("car", 10).await.unwrap();
+
+    assert_eq!(
+        texts(&suggestions),
+        vec![
+            "care coordinator",
+            "remote care",
+            "Care Manager",
+            "CareBridge"
+        ]
+    );
+    assert_eq!(suggestions[0].source, QuerySuggestionSource::History);
+    assert_eq!(suggestions[0].count, 2);
+    assert_eq!(
+        texts(&manager.suggest_queries("", 2).await.unwrap()),
+        vec!["care coordinator", "cashier"]
+    );
+}
+
+#[tokio::test]
+async fn test_pinned_and_removed_suggestions() {
+    let db = crate::test_support::migrated_database().await;
+    let manager = db.user_data_manager();
+    manager
+        .add_search_history("care coordinator")
+        .await
+        .unwrap();
+    manager
+        .add_search_history("care coordinator")
+        .await
+        .unwrap();
+    db.upsert_job(&create_test_job("pin_1", "Care Manager", 0.8))
+        .await
+        .unwrap();
+
+    assert!(manager
+        .pin_search_suggestion("Care Manager", true)
+        .await
+        .unwrap());
+    let suggestions = manager.suggest_queries("care", 10).await.unwrap();
+    assert_eq!(
+        texts(&suggestions),
+        vec!["Care Manager", "care coordinator"]
+    );
+    assert!(suggestions[0].pinned);
+
+    // Removing a pinned title also stops suggesting it from jobs
+    manager
+        .remove_search_suggestion("Care Manager")
+        .await
+        .unwrap();
+    assert_eq!(
+        texts(&manager.suggest_queries("care", 10).await.unwrap()),
+        vec!["care coordinator"]
+    );
+    manager
+        .remove_search_suggestion("care coordinator")
+        .await
+        .unwrap();
+    assert!(manager
+        .suggest_queries("care", 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(manager.get_search_history(10).await.unwrap().is_empty());
+
+    // Searching again brings a removed query back
+    manager
+        .add_search_history("care coordinator")
+        .await
+        .unwrap();
+    assert_eq!(
+        texts(&manager.suggest_queries("coord", 10).await.unwrap()),
+        vec!["care coordinator"]
+    );
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+async fn write_old_install(path: &std::path::Path) {
+    let old = Database::connect(path).await.unwrap();
+    old.migrate().await.unwrap();
+
+    let saved = create_test_job("old_saved_hash", "Case Manager", 0.7);
+    let saved_id = old.upsert_job(&saved).await.unwrap();
+    old.set_bookmark(saved_id, true).await.unwrap();
+    old.set_job_notes(saved_id, Some("Call back Monday"))
+        .await
+        .unwrap();
+    old.upsert_job(&create_test_job("shared_hash", "Care Navigator", 0.6))
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO applications (job_hash, status, notes) VALUES (?, 'applied', ?)")
+        .bind("old_saved_hash")
+        .bind("Applied through the careers page")
+        .execute(old.pool())
+        .await
+        .unwrap();
+    old.checkpoint_wal().await.unwrap();
+    old.pool().close().await;
+}
+
+#[tokio::test]
+async fn test_import_from_database_file_copies_jobs_and_applications() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let old_path = temp_dir.path().join("old").join("jobs.db");
+    write_old_install(&old_path).await;
+
+    let db = crate::test_support::migrated_database().await;
+    db.upsert_job(&create_test_job("shared_hash", "Care Navigator", 0.6))
+        .await
+        .unwrap();
+
+    let work_dir = temp_dir.path().join("work");
+    let summary = db
+        .import_from_database_file(&old_path, &work_dir)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.jobs_imported, 1);
+    assert_eq!(summary.jobs_skipped, 1);
+    assert_eq!(summary.applications_imported, 1);
+
+    let imported = db.get_job_by_hash("old_saved_hash").await.unwrap().unwrap();
+    assert!(imported.bookmarked);
+    assert_eq!(imported.notes.as_deref(), Some("Call back Monday"));
+    let status: String = sqlx::query_scalar("SELECT status FROM applications WHERE job_hash = ?")
+        .bind("old_saved_hash")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(status, "applied");
+    assert_eq!(std::fs::read_dir(&work_dir).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_import_from_database_file_rejects_non_database_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let bogus = temp_dir.path().join("jobs.db");
+    std::fs::write(&bogus, "not a database").unwrap();
+    let db = crate::test_support::migrated_database().await;
+
+    let error = db
+        .import_from_database_file(&bogus, temp_dir.path())
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("not a readable JobSentinel database"));
+    assert!(db
+        .get_job_by_hash("old_saved_hash")
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,45 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_source_failures_count_in_a_row_and_clear_on_success() {
+    let db = crate::test_support::migrated_database().await;
+    let first = Utc::now() - Duration::hours(3);
+    let second = Utc::now() - Duration::hours(1);
+
+    assert!(db.source_failures().await.unwrap().is_empty());
+    assert!(!db.clear_source_failures("greenhouse").await.unwrap());
+
+    let failure = db
+        .record_source_failure("greenhouse", "network error", first)
+        .await
+        .unwrap();
+    assert_eq!(failure.consecutive_failures, 1);
+
+    let failure = db
+        .record_source_failure("greenhouse", "403 Forbidden", second)
+        .await
+        .unwrap();
+    assert_eq!(failure.consecutive_failures, 2);
+    assert_eq!(failure.last_error, "403 Forbidden");
+    assert_eq!(failure.last_failed_at.timestamp(), second.timestamp());
+
+    db.record_source_failure("lever", "timed out", second)
+        .await
+        .unwrap();
+    let failures = db.source_failures().await.unwrap();
+    assert_eq!(
+        failures
+            .iter()
+            .map(|failure| (failure.source_id.as_str(), failure.consecutive_failures))
+            .collect::<Vec<_>>(),
+        [("greenhouse", 2), ("lever", 1)]
+    );
+
+    assert!(db.clear_source_failures("greenhouse").await.unwrap());
+    let failure = db
+        .record_source_failure("greenhouse", "timed out", Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(failure.consecutive_failures, 1);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_vacation_mode_summarizes_held_alerts_and_shifts_reminders() {
+    let db = crate::test_support::migrated_database().await;
+    let now = Utc::now();
+    let started = now - Duration::days(5);
+
+    let mut old_job = create_test_job("vac_old", "Case Manager", 0.91);
+    old_job.created_at = now - Duration::days(10);
+    db.upsert_job(&old_job).await.unwrap();
+    db.upsert_job(&create_test_job("vac_new", "Care Coordinator", 0.95))
+        .await
+        .unwrap();
+
+    assert!(!db.get_vacation_mode().await.unwrap().enabled);
+    let mode = db.start_vacation_mode(false, started).await.unwrap();
+    assert!(mode.enabled && !mode.keep_scraping);
+
+    // Enabling again keeps the original start.
+    let mode = db.start_vacation_mode(true, now).await.unwrap();
+    assert!(mode.keep_scraping);
+    assert_eq!(
+        mode.started_at.map(|t| t.timestamp()),
+        Some(started.timestamp())
+    );
+
+    db.queue_vacation_alert("vac_old", 0.91).await.unwrap();
+    db.queue_vacation_alert("vac_new", 0.95).await.unwrap();
+    db.queue_vacation_alert("vac_new", 0.90).await.unwrap();
+    assert_eq!(db.get_vacation_mode().await.unwrap().queued_alerts, 2);
+
+    let tracker = db.application_tracker();
+    let application_id = tracker.create_application("vac_old").await.unwrap();
+    for (kind, due) in [
+        ("during", now - Duration::days(2)),
+        ("before", now - Duration::days(8)),
+        ("after", now + Duration::days(10)),
+    ] {
+        tracker
+            .set_reminder(application_id, kind, due, "")
+            .await
+            .unwrap();
+    }
+
+    let summary = db.end_vacation_mode(now).await.unwrap().unwrap();
+
+    assert_eq!(summary.new_jobs, 1);
+    assert_eq!(summary.queued_alerts, 2);
+    assert_eq!(summary.highlights[0].job_hash, "vac_new");
+    assert!((summary.highlights[0].score - 0.95).abs() < f64::EPSILON);
+    assert_eq!(summary.reminders_shifted, 1);
+
+    let times: Vec<(String, String)> = sqlx::query_as(
+        "SELECT reminder_type, reminder_time FROM application_reminders ORDER BY id",
+    )
+    .fetch_all(db.pool())
+    .await
+    .unwrap();
+    let during = crate::sqlite_time::parse_sqlite_datetime(&times[0].1).unwrap();
+    assert_eq!(during.timestamp(), (now + Duration::days(3)).timestamp());
+    let after = crate::sqlite_time::parse_sqlite_datetime(&times[2].1).unwrap();
+    assert_eq!(after.timestamp(), (now + Duration::days(10)).timestamp());
+
+    let mode = db.get_vacation_mode().await.unwrap();
+    assert!(!mode.enabled);
+    assert_eq!(mode.queued_alerts, 0);
+    assert!(db.end_vacation_mode(now).await.unwrap().is_none());
+}
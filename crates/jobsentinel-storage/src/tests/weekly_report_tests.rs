@@ -0,0 +1,137 @@
+use super::*;
+use crate::application_tracking::ApplicationStatus;
+use chrono::Duration;
+
+#[tokio::test]
+async fn test_report_counts_new_jobs_and_orders_top_matches() {
+    let db = crate::test_support::migrated_database().await;
+    let top_id = db
+        .upsert_job(&create_test_job("report_top", "Staff Engineer", 0.92))
+        .await
+        .unwrap();
+    db.upsert_job(&create_test_job("report_low", "Junior Engineer", 0.41))
+        .await
+        .unwrap();
+    let hidden_id = db
+        .upsert_job(&create_test_job("report_hidden", "Hidden Engineer", 0.99))
+        .await
+        .unwrap();
+    db.hide_job(hidden_id).await.unwrap();
+
+    let since = Utc::now() - Duration::weeks(1);
+    assert_eq!(db.count_jobs_found_since(since).await.unwrap(), 2);
+
+    let top = db.top_jobs_found_since(since, 1).await.unwrap();
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].id, top_id);
+
+    let later = Utc::now() + Duration::hours(1);
+    assert_eq!(db.count_jobs_found_since(later).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_application_activity_counts_status_changes_since() {
+    let db = crate::test_support::migrated_database().await;
+    db.upsert_job(&create_test_job("report_app", "Staff Engineer", 0.9))
+        .await
+        .unwrap();
+    let tracker = db.application_tracker();
+    let application_id = tracker.create_application("report_app").await.unwrap();
+    tracker
+        .update_status(application_id, ApplicationStatus::Applied)
+        .await
+        .unwrap();
+    tracker
+        .update_status(application_id, ApplicationStatus::PhoneInterview)
+        .await
+        .unwrap();
+
+    let activity = db
+        .application_activity_since(Utc::now() - Duration::weeks(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        activity,
+        ApplicationActivity {
+            applied: 1,
+            interviews: 1,
+            offers: 0,
+            rejections: 0,
+            active: 1,
+        }
+    );
+
+    let later = db
+        .application_activity_since(Utc::now() + Duration::hours(1))
+        .await
+        .unwrap();
+    assert_eq!(later.applied, 0);
+    assert_eq!(later.active, 1);
+}
+
+#[tokio::test]
+async fn test_market_alerts_since_include_read_alerts() {
+    let db = crate::test_support::migrated_database().await;
+    sqlx::query(
+        r#"
+        INSERT INTO market_alerts (alert_type, title, description, severity, is_read, created_at)
+        VALUES
+            ('skill_surge', 'Rust demand up', 'More Rust roles', 'info', 1, datetime('now')),
+            ('hiring_freeze', 'Old alert', 'Last month', 'warning', 0, datetime('now', '-30 days'))
+        "#,
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let alerts = db
+        .market_intelligence()
+        .get_alerts_since(Utc::now() - Duration::weeks(1))
+        .await
+        .unwrap();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].title, "Rust demand up");
+}
+
+#[tokio::test]
+async fn test_only_scheduled_reports_set_last_report_time() {
+    let db = crate::test_support::migrated_database().await;
+    let end = Utc::now();
+    let start = end - Duration::weeks(1);
+    assert!(db
+        .last_scheduled_weekly_report_at()
+        .await
+        .unwrap()
+        .is_none());
+
+    db.save_weekly_report(start, end, false, "# On demand", "<h1>On demand</h1>")
+        .await
+        .unwrap();
+    assert!(db
+        .last_scheduled_weekly_report_at()
+        .await
+        .unwrap()
+        .is_none());
+
+    let id = db
+        .save_weekly_report(start, end, true, "# Weekly", "<h1>Weekly</h1>")
+        .await
+        .unwrap();
+    assert!(db
+        .last_scheduled_weekly_report_at()
+        .await
+        .unwrap()
+        .is_some());
+
+    let reports = db.weekly_reports(10).await.unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].id, id);
+    assert!(reports[0].scheduled);
+    assert_eq!(reports[0].markdown, "# Weekly");
+    assert!(!reports[1].scheduled);
+    assert_eq!(
+        db.weekly_report(id).await.unwrap(),
+        Some(reports[0].clone())
+    );
+    assert!(db.weekly_report(id + 1).await.unwrap().is_none());
+}
@@ -0,0 +1,137 @@
+//! Time-to-fill estimation
+//!
+//! A posting counts as closed once scrapers have not seen it for
+//! `CLOSED_AFTER_DAYS`; how long it stayed open (first seen to last seen)
+//! is its time to fill. Estimates use the median of a company's closed
+//! postings in the same role family, falling back to all of its postings.
+
+use chrono::{Duration, Utc};
+
+use super::connection::Database;
+use super::types::{JobRow, TimeToFillEstimate, TimeToFillScope};
+use jobsentinel_domain::Job;
+
+/// Days without a sighting before a posting is treated as closed.
+pub(super) const CLOSED_AFTER_DAYS: i64 = 7;
+/// Closed postings needed before a median is worth showing.
+const MIN_SAMPLES: usize = 3;
+
+/// Title words that change level, not the kind of work.
+const SENIORITY_WORDS: &[&str] = &[
+    "senior",
+    "sr",
+    "junior",
+    "jr",
+    "lead",
+    "principal",
+    "staff",
+    "head",
+    "chief",
+    "associate",
+    "assistant",
+    "entry",
+    "level",
+    "intern",
+    "i",
+    "ii",
+    "iii",
+    "iv",
+    "v",
+    "1",
+    "2",
+    "3",
+    "remote",
+    "hybrid",
+    "onsite",
+];
+
+impl Database {
+    /// Estimate how quickly the company behind `job_id` usually closes
+    /// postings like it.
+    ///
+    /// Returns `None` when the job does not exist or the company has fewer
+    /// than three closed postings on record.
+    pub async fn get_time_to_fill_estimate(
+        &self,
+        job_id: i64,
+    ) -> Result<Option<TimeToFillEstimate>, sqlx::Error> {
+        let Some(job) = self.get_job_by_id(job_id).await? else {
+            return Ok(None);
+        };
+
+        let closed_before = Utc::now() - Duration::days(CLOSED_AFTER_DAYS);
+        let closed: Vec<Job> = sqlx::query_as::<_, JobRow>(
+            "SELECT * FROM jobs WHERE LOWER(TRIM(company)) = LOWER(TRIM(?)) AND id != ?",
+        )
+        .bind(&job.company)
+        .bind(job_id)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .filter(|posting| posting.last_seen < closed_before)
+        .collect();
+
+        Ok(estimate_time_to_fill(&job, &closed))
+    }
+}
+
+fn estimate_time_to_fill(job: &Job, closed: &[Job]) -> Option<TimeToFillEstimate> {
+    let family = role_family(&job.title);
+    let role_days: Vec<f64> = closed
+        .iter()
+        .filter(|posting| !family.is_empty() && role_family(&posting.title) == family)
+        .map(days_open)
+        .collect();
+
+    let (scope, days) = if role_days.len() >= MIN_SAMPLES {
+        (TimeToFillScope::Role, role_days)
+    } else {
+        (
+            TimeToFillScope::Company,
+            closed.iter().map(days_open).collect(),
+        )
+    };
+    let sample_size = days.len();
+    let median_days = median(days).filter(|_| sample_size >= MIN_SAMPLES)?;
+
+    Some(TimeToFillEstimate {
+        company: job.company.clone(),
+        role_family: family,
+        scope,
+        median_days,
+        sample_size,
+    })
+}
+
+/// Lowercased title with seniority, level, and work-mode words removed, so
+/// "Sr. Case Manager II (Remote)" and "Case Manager" share a family.
+fn role_family(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !SENIORITY_WORDS.contains(&word.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn days_open(posting: &Job) -> f64 {
+    let opened = posting.first_seen.unwrap_or(posting.created_at);
+    let seconds = (posting.last_seen - opened).num_seconds().max(0);
+    seconds as f64 / 86_400.0
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        f64::midpoint(values[middle - 1], values[middle])
+    } else {
+        values[middle]
+    })
+}
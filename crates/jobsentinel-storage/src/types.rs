@@ -3,7 +3,7 @@
 //! Contains all struct definitions for database models.
 
 use chrono::{DateTime, Utc};
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus, PayPeriod};
 use serde::{Deserialize, Serialize};
 
 #[derive(sqlx::FromRow)]
@@ -22,6 +22,7 @@ pub(super) struct JobRow {
     salary_min: Option<i64>,
     salary_max: Option<i64>,
     currency: Option<String>,
+    pay_period: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     last_seen: DateTime<Utc>,
@@ -35,6 +36,7 @@ pub(super) struct JobRow {
     ghost_reasons: Option<String>,
     first_seen: Option<DateTime<Utc>>,
     repost_count: i64,
+    status: String,
 }
 
 impl From<JobRow> for Job {
@@ -56,6 +58,7 @@ impl From<JobRow> for Job {
         job.salary_min = row.salary_min;
         job.salary_max = row.salary_max;
         job.currency = row.currency;
+        job.pay_period = row.pay_period.as_deref().and_then(PayPeriod::parse);
         job.updated_at = row.updated_at;
         job.last_seen = row.last_seen;
         job.times_seen = row.times_seen;
@@ -68,6 +71,7 @@ impl From<JobRow> for Job {
         job.ghost_reasons = row.ghost_reasons;
         job.first_seen = row.first_seen;
         job.repost_count = row.repost_count;
+        job.status = JobStatus::parse(&row.status).unwrap_or_default();
         job
     }
 }
@@ -96,6 +100,31 @@ pub struct GhostStatistics {
     pub total_reposts: i64,
 }
 
+/// Outcome of re-analyzing saved postings with a newer ghost detector
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct GhostReanalysisRun {
+    /// Detector version the postings were re-analyzed with
+    pub detector_version: i64,
+    pub started_at: String,
+    pub finished_at: String,
+    pub jobs_reanalyzed: i64,
+    /// Postings that left the warning or hidden bucket
+    pub moved_to_clear: i64,
+    /// Postings that newly reached the warning bucket
+    pub moved_to_warning: i64,
+    /// Postings that newly reached the hide bucket
+    pub moved_to_hidden: i64,
+}
+
+/// Recomputed score for one saved job
+#[derive(Debug, Clone, PartialEq)]
+pub struct RescoredJob {
+    pub job_id: i64,
+    pub score: f64,
+    /// Serialized score reasons
+    pub score_reasons: String,
+}
+
 /// A group of duplicate jobs (same title + company from different sources)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DuplicateGroup {
@@ -106,3 +135,250 @@ pub struct DuplicateGroup {
     /// Sources where this job appears
     pub sources: Vec<String>,
 }
+
+/// A cluster of near-identical postings (same company, similar title and description)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearDuplicateGroup {
+    /// The ID of the job to keep (highest score, then oldest)
+    pub primary_id: i64,
+    /// Lowest pairwise similarity that linked this cluster (0.0-1.0)
+    pub similarity: f64,
+    /// All jobs in this cluster, primary first
+    pub jobs: Vec<Job>,
+    /// Distinct sources where this posting appears
+    pub sources: Vec<String>,
+}
+
+/// Which postings a time-to-fill median was drawn from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeToFillScope {
+    /// Closed postings in the same role family at this company
+    Role,
+    /// All closed postings at this company
+    Company,
+}
+
+/// Thumbs-up or thumbs-down a user gave a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobFeedback {
+    Good,
+    Bad,
+}
+
+impl JobFeedback {
+    /// Value stored in `job_feedback.feedback`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Good => "good",
+            Self::Bad => "bad",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "good" => Some(Self::Good),
+            "bad" => Some(Self::Bad),
+            _ => None,
+        }
+    }
+}
+
+/// Database size, WAL, connection pool, and index diagnostics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseHealth {
+    pub page_size: i64,
+    pub page_count: i64,
+    /// Unused pages that incremental vacuum can reclaim
+    pub freelist_count: i64,
+    pub size_bytes: i64,
+    pub journal_mode: String,
+    /// Size of the write-ahead log; `None` for in-memory databases
+    pub wal_size_bytes: Option<u64>,
+    pub busy_timeout_ms: i64,
+    /// Open pooled connections
+    pub pool_size: u32,
+    pub pool_idle: u32,
+    pub pool_max: u32,
+    pub indexes: Vec<IndexUsage>,
+    /// Hot queries that scan the whole jobs table
+    pub full_scans: Vec<String>,
+}
+
+/// One index and the hot queries whose plans use it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexUsage {
+    pub name: String,
+    pub table: String,
+    /// Rows in the index when statistics were last gathered
+    pub analyzed_rows: Option<i64>,
+    pub used_by: Vec<String>,
+}
+
+/// A maintenance step beyond the `PRAGMA optimize` every pass runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStep {
+    /// Free pages returned to the file system
+    IncrementalVacuum,
+    /// Whole file rebuilt, for databases made before incremental auto-vacuum
+    Vacuum,
+    /// Job search index segments merged into one
+    FtsOptimize,
+    /// Job search index rebuilt from the jobs table after failing its check
+    FtsRebuild,
+}
+
+/// What a maintenance pass did, with the database size before and after
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseMaintenance {
+    pub size_before_bytes: i64,
+    pub size_after_bytes: i64,
+    pub steps: Vec<MaintenanceStep>,
+}
+
+/// Why a database backup was made
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    /// Made by the user
+    Manual,
+    /// Made automatically before schema migrations
+    PreMigration,
+    /// The database a restore replaced
+    BeforeRestore,
+}
+
+impl BackupKind {
+    pub(crate) const ALL: [Self; 3] = [Self::Manual, Self::PreMigration, Self::BeforeRestore];
+
+    /// File name prefix of this kind of backup
+    #[must_use]
+    pub const fn file_prefix(self) -> &'static str {
+        match self {
+            Self::Manual => "backup_manual_",
+            Self::PreMigration => "backup_pre_migration_",
+            Self::BeforeRestore => "backup_before_restore_",
+        }
+    }
+}
+
+/// A database backup file in the backup directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseBackup {
+    pub file_name: String,
+    pub kind: BackupKind,
+    pub created_at: DateTime<Utc>,
+    pub size_bytes: u64,
+}
+
+/// What saving one scraped job did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobUpsert {
+    /// First time this posting was seen; holds the new job id
+    New(i64),
+    /// The posting was already saved; holds its job id
+    Updated(i64),
+}
+
+impl JobUpsert {
+    #[must_use]
+    pub const fn job_id(self) -> i64 {
+        match self {
+            Self::New(job_id) | Self::Updated(job_id) => job_id,
+        }
+    }
+}
+
+/// A job the user rated, as training data for keyword weights
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobFeedbackExample {
+    pub title: String,
+    pub description: Option<String>,
+    pub feedback: JobFeedback,
+}
+
+/// How long a company's postings usually stay open
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeToFillEstimate {
+    pub company: String,
+    /// Title with seniority and work-mode words removed
+    pub role_family: String,
+    pub scope: TimeToFillScope,
+    /// Median days from first seen to last seen
+    pub median_days: f64,
+    /// Closed postings behind the median
+    pub sample_size: usize,
+}
+
+/// Job search pause settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VacationMode {
+    pub enabled: bool,
+    /// Keep running scheduled scraping while notifications are held
+    pub keep_scraping: bool,
+    pub started_at: Option<DateTime<Utc>>,
+    /// High-match alerts held since the pause started
+    pub queued_alerts: i64,
+}
+
+/// One field that changed when a saved posting was fetched again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A recorded on-demand refresh of a saved posting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRefreshRecord {
+    pub id: i64,
+    pub job_id: i64,
+    pub refreshed_at: DateTime<Utc>,
+    pub changes: Vec<JobFieldChange>,
+}
+
+/// A tag in use and how many saved jobs carry it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct JobTagCount {
+    pub tag: String,
+    pub job_count: i64,
+}
+
+/// Rows copied from another JobSentinel database during setup
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseImportSummary {
+    pub jobs_imported: usize,
+    pub jobs_skipped: usize,
+    pub applications_imported: usize,
+}
+
+/// A high-match alert held while vacation mode was on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacationHighlight {
+    pub job_id: i64,
+    pub job_hash: String,
+    pub title: String,
+    pub company: String,
+    pub score: f64,
+    pub queued_at: DateTime<Utc>,
+}
+
+/// "While you were away" summary generated when vacation mode ends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacationSummary {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// Jobs first saved while away
+    pub new_jobs: i64,
+    /// Alerts held while away (all of them, not only `highlights`)
+    pub queued_alerts: i64,
+    /// Best held alerts, highest score first
+    pub highlights: Vec<VacationHighlight>,
+    /// Pending reminders moved past the pause
+    pub reminders_shifted: u64,
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+impl UserDataManager {
+    // ========== Alert Message Templates ==========
+
+    /// Get the saved alert message templates
+    #[instrument(skip(self))]
+    pub async fn get_message_templates(&self) -> Result<NotificationMessageTemplates, sqlx::Error> {
+        debug!("Getting alert message templates");
+
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT channel, template
+            FROM notification_message_templates
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut templates = NotificationMessageTemplates::default();
+        for (channel, template) in rows {
+            match channel.as_str() {
+                "slack" => templates.slack = Some(template),
+                "discord" => templates.discord = Some(template),
+                "email" => templates.email = Some(template),
+                _ => {}
+            }
+        }
+        Ok(templates)
+    }
+
+    /// Save the message template for `channel`, or remove it when `template`
+    /// is `None` so the default layout is used again
+    #[instrument(skip(self, template))]
+    pub async fn save_message_template(
+        &self,
+        channel: MessageTemplateChannel,
+        template: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        debug!(channel = channel.as_str(), "Saving alert message template");
+
+        let Some(template) = template else {
+            sqlx::query("DELETE FROM notification_message_templates WHERE channel = ?")
+                .bind(channel.as_str())
+                .execute(&self.pool)
+                .await?;
+            return Ok(());
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO notification_message_templates (channel, template, updated_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (channel)
+            DO UPDATE SET template = excluded.template, updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(channel.as_str())
+        .bind(template)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Handles persistence of user preferences and data that was previously stored in localStorage.
 //! This includes cover letter templates, interview prep checklists, saved searches,
-//! and notification preferences.
+//! notification preferences, and alert message templates.
 //!
 //! ## Migration from localStorage
 //!
@@ -10,8 +10,11 @@
 //! localStorage data to SQLite.
 
 use chrono::Utc;
+mod message_templates;
 mod models;
 mod notifications;
+mod search_agents;
+mod search_suggestions;
 mod template_store;
 
 use sqlx::SqlitePool;
@@ -20,8 +23,14 @@ use uuid::Uuid;
 
 pub use models::{
     AdvancedFilters, CoverLetterTemplate, FollowUpReminder, GlobalNotificationSettings,
-    NotificationPreferences, PrepChecklistItem, SavedSearch, SourceConfigs,
-    SourceNotificationConfig, TemplateCategory,
+    MarketAlertNotificationConfig, MarketAlertPreferences, MessageTemplateChannel,
+    NotificationMessageTemplates, NotificationPreferences, PrepChecklistItem, SavedSearch,
+    SearchAgentFrequency, SearchAgentSettings, SourceConfigs, SourceNotificationConfig,
+    TemplateCategory,
+};
+
+pub use search_suggestions::{
+    history_suggestion_score, job_suggestion_score, QuerySuggestion, QuerySuggestionSource,
 };
 
 use models::{
@@ -181,7 +190,8 @@ impl UserDataManager {
             r#"
             SELECT id, name, sort_by, score_filter, source_filter, remote_filter,
                    bookmark_filter, notes_filter, posted_date_filter, salary_min_filter,
-                   salary_max_filter, ghost_filter, text_search, created_at, last_used_at
+                   salary_max_filter, ghost_filter, text_search, created_at, last_used_at,
+                   agent_enabled, agent_channels, agent_frequency, agent_last_checked_at
             FROM saved_searches
             ORDER BY last_used_at DESC NULLS LAST, created_at DESC
             "#,
@@ -204,6 +214,12 @@ impl UserDataManager {
             search.id.clone()
         };
         let now = Utc::now().to_rfc3339();
+        // A new agent starts with jobs saved from now on
+        let agent = SearchAgentSettings {
+            last_checked_at: search.agent.enabled.then(|| now.clone()),
+            ..search.agent.clone()
+        };
+        let agent_channels = search_agents::channels_json(&agent.channels)?;
 
         debug!(
             id = %id,
@@ -220,8 +236,9 @@ impl UserDataManager {
             INSERT INTO saved_searches (
                 id, name, sort_by, score_filter, source_filter, remote_filter,
                 bookmark_filter, notes_filter, posted_date_filter, salary_min_filter,
-                salary_max_filter, ghost_filter, text_search, created_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                salary_max_filter, ghost_filter, text_search, created_at,
+                agent_enabled, agent_channels, agent_frequency, agent_last_checked_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&id)
@@ -238,6 +255,10 @@ impl UserDataManager {
         .bind(&search.ghost_filter)
         .bind(&search.text_search)
         .bind(&now)
+        .bind(i64::from(agent.enabled))
+        .bind(&agent_channels)
+        .bind(agent.frequency.as_str())
+        .bind(&agent.last_checked_at)
         .execute(&self.pool)
         .await?;
 
@@ -245,6 +266,7 @@ impl UserDataManager {
             id,
             created_at: now,
             last_used_at: None,
+            agent,
             ..search
         })
     }
@@ -304,7 +326,7 @@ impl UserDataManager {
             INSERT INTO search_history (query, use_count, last_used_at)
             VALUES (?, 1, ?)
             ON CONFLICT (query)
-            DO UPDATE SET use_count = use_count + 1, last_used_at = ?
+            DO UPDATE SET use_count = use_count + 1, last_used_at = ?, dismissed = 0
             "#,
         )
         .bind(query)
@@ -316,7 +338,7 @@ impl UserDataManager {
         Ok(())
     }
 
-    /// Get search history (most recent first)
+    /// Get search history (pinned, then most recent first)
     #[instrument(skip(self))]
     pub async fn get_search_history(&self, limit: i64) -> Result<Vec<String>, sqlx::Error> {
         debug!("Getting search history (limit: {})", limit);
@@ -324,7 +346,8 @@ impl UserDataManager {
         let rows: Vec<(String,)> = sqlx::query_as(
             r#"
             SELECT query FROM search_history
-            ORDER BY last_used_at DESC
+            WHERE dismissed = 0
+            ORDER BY pinned DESC, last_used_at DESC
             LIMIT ?
             "#,
         )
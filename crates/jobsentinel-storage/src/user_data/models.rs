@@ -1,3 +1,4 @@
+use crate::market_intelligence::{AlertSeverity, AlertType, MarketAlert};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -169,6 +170,51 @@ pub struct SavedSearch {
     pub text_search: Option<String>,
     pub created_at: String,
     pub last_used_at: Option<String>,
+    /// Alerts for new jobs matching this search
+    #[serde(default)]
+    pub agent: SearchAgentSettings,
+}
+
+/// How often a job agent sends its new matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchAgentFrequency {
+    /// After every scrape cycle that finds new matches
+    #[default]
+    EachScrape,
+    /// At most once a day, with everything matched since the last alert
+    Daily,
+}
+
+impl SearchAgentFrequency {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EachScrape => "each_scrape",
+            Self::Daily => "daily",
+        }
+    }
+
+    /// Parse a stored frequency, falling back to each scrape
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "daily" => Self::Daily,
+            _ => Self::EachScrape,
+        }
+    }
+}
+
+/// Settings that turn a saved search into a job agent, which alerts on new
+/// jobs matching the search
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SearchAgentSettings {
+    pub enabled: bool,
+    /// Channels to alert through; empty uses every enabled channel
+    pub channels: Vec<MessageTemplateChannel>,
+    pub frequency: SearchAgentFrequency,
+    /// When the agent last checked for new jobs (RFC 3339); jobs saved
+    /// before this have been handled
+    pub last_checked_at: Option<String>,
 }
 
 /// Database row for saved search
@@ -189,6 +235,10 @@ pub(super) struct SavedSearchRow {
     text_search: Option<String>,
     created_at: String,
     last_used_at: Option<String>,
+    agent_enabled: i64,
+    agent_channels: String,
+    agent_frequency: String,
+    agent_last_checked_at: Option<String>,
 }
 
 impl From<SavedSearchRow> for SavedSearch {
@@ -209,6 +259,12 @@ impl From<SavedSearchRow> for SavedSearch {
             text_search: row.text_search,
             created_at: row.created_at,
             last_used_at: row.last_used_at,
+            agent: SearchAgentSettings {
+                enabled: row.agent_enabled != 0,
+                channels: serde_json::from_str(&row.agent_channels).unwrap_or_default(),
+                frequency: SearchAgentFrequency::parse(&row.agent_frequency),
+                last_checked_at: row.agent_last_checked_at,
+            },
         }
     }
 }
@@ -322,6 +378,58 @@ pub(super) fn disable_linkedin_notification_source(
     }
 }
 
+/// Notification settings for one kind of market alert
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketAlertNotificationConfig {
+    pub enabled: bool,
+    /// Lowest severity sent: `info`, `warning`, or `critical`
+    pub min_severity: String,
+}
+
+impl Default for MarketAlertNotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_severity: AlertSeverity::Info.as_str().to_string(),
+        }
+    }
+}
+
+/// Which market alerts are sent through the email, Slack, and Discord
+/// channels, by alert type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MarketAlertPreferences {
+    pub skill_surge: MarketAlertNotificationConfig,
+    pub salary_spike: MarketAlertNotificationConfig,
+    pub hiring_freeze: MarketAlertNotificationConfig,
+    pub hiring_spree: MarketAlertNotificationConfig,
+    pub location_boom: MarketAlertNotificationConfig,
+    pub role_obsolete: MarketAlertNotificationConfig,
+}
+
+impl MarketAlertPreferences {
+    /// Settings for `alert_type`
+    pub fn for_type(&self, alert_type: &AlertType) -> &MarketAlertNotificationConfig {
+        match alert_type {
+            AlertType::SkillSurge => &self.skill_surge,
+            AlertType::SalarySpike => &self.salary_spike,
+            AlertType::HiringFreeze => &self.hiring_freeze,
+            AlertType::HiringSpree => &self.hiring_spree,
+            AlertType::LocationBoom => &self.location_boom,
+            AlertType::RoleObsolete => &self.role_obsolete,
+        }
+    }
+
+    /// Whether `alert` should be sent: its type is on and it is at least
+    /// as severe as that type's minimum
+    pub fn allows(&self, alert: &MarketAlert) -> bool {
+        let config = self.for_type(&alert.alert_type);
+        config.enabled && alert.severity >= AlertSeverity::parse(&config.min_severity)
+    }
+}
+
 /// Notification preferences (combines all settings)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -333,6 +441,8 @@ pub struct NotificationPreferences {
     pub jobswithgpt: SourceNotificationConfig,
     pub global: GlobalNotificationSettings,
     pub advanced_filters: AdvancedFilters,
+    #[serde(default)]
+    pub market_alerts: MarketAlertPreferences,
 }
 
 pub(super) fn notification_preferences_serialization_error() -> sqlx::Error {
@@ -350,6 +460,46 @@ impl Default for NotificationPreferences {
             jobswithgpt: defaults.jobswithgpt,
             global: GlobalNotificationSettings::default(),
             advanced_filters: AdvancedFilters::default(),
+            market_alerts: MarketAlertPreferences::default(),
+        }
+    }
+}
+
+/// Alert channels whose message can be customized with a template
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageTemplateChannel {
+    Slack,
+    Discord,
+    Email,
+}
+
+impl MessageTemplateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Slack => "slack",
+            Self::Discord => "discord",
+            Self::Email => "email",
+        }
+    }
+}
+
+/// Saved message templates. `None` uses the default message layout.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationMessageTemplates {
+    pub slack: Option<String>,
+    pub discord: Option<String>,
+    pub email: Option<String>,
+}
+
+impl NotificationMessageTemplates {
+    /// The template saved for `channel`
+    pub fn get(&self, channel: MessageTemplateChannel) -> Option<&str> {
+        match channel {
+            MessageTemplateChannel::Slack => self.slack.as_deref(),
+            MessageTemplateChannel::Discord => self.discord.as_deref(),
+            MessageTemplateChannel::Email => self.email.as_deref(),
         }
     }
 }
@@ -363,4 +513,5 @@ pub(super) struct NotificationPreferencesRow {
     pub(super) quiet_hours_end: String,
     pub(super) source_configs: String,
     pub(super) advanced_filters: String,
+    pub(super) market_alerts: String,
 }
@@ -13,7 +13,7 @@ impl UserDataManager {
         let row: Option<NotificationPreferencesRow> = sqlx::query_as(
             r#"
             SELECT global_enabled, quiet_hours_enabled, quiet_hours_start, quiet_hours_end,
-                   source_configs, advanced_filters
+                   source_configs, advanced_filters, market_alerts
             FROM notification_preferences
             WHERE id = 1
             "#,
@@ -31,6 +31,9 @@ impl UserDataManager {
                 let advanced_filters: AdvancedFilters =
                     serde_json::from_str(&r.advanced_filters).unwrap_or_default();
 
+                let market_alerts: MarketAlertPreferences =
+                    serde_json::from_str(&r.market_alerts).unwrap_or_default();
+
                 Ok(NotificationPreferences {
                     linkedin: disable_linkedin_notification_source(source_configs.linkedin),
                     indeed: source_configs.indeed,
@@ -44,6 +47,7 @@ impl UserDataManager {
                         quiet_hours_enabled: r.quiet_hours_enabled != 0,
                     },
                     advanced_filters,
+                    market_alerts,
                 })
             }
             None => {
@@ -77,6 +81,9 @@ impl UserDataManager {
         let advanced_filters_json = serde_json::to_string(&prefs.advanced_filters)
             .map_err(|_| notification_preferences_serialization_error())?;
 
+        let market_alerts_json = serde_json::to_string(&prefs.market_alerts)
+            .map_err(|_| notification_preferences_serialization_error())?;
+
         let now = Utc::now().to_rfc3339();
         let global_enabled: i64 = if prefs.global.enabled { 1 } else { 0 };
         let quiet_hours_enabled: i64 = if prefs.global.quiet_hours_enabled {
@@ -89,8 +96,8 @@ impl UserDataManager {
             r#"
             INSERT INTO notification_preferences (
                 id, global_enabled, quiet_hours_enabled, quiet_hours_start, quiet_hours_end,
-                source_configs, advanced_filters, updated_at
-            ) VALUES (1, ?, ?, ?, ?, ?, ?, ?)
+                source_configs, advanced_filters, market_alerts, updated_at
+            ) VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT (id)
             DO UPDATE SET
                 global_enabled = ?,
@@ -99,6 +106,7 @@ impl UserDataManager {
                 quiet_hours_end = ?,
                 source_configs = ?,
                 advanced_filters = ?,
+                market_alerts = ?,
                 updated_at = ?
             "#,
         )
@@ -108,6 +116,7 @@ impl UserDataManager {
         .bind(&prefs.global.quiet_hours_end)
         .bind(&source_configs_json)
         .bind(&advanced_filters_json)
+        .bind(&market_alerts_json)
         .bind(&now)
         .bind(global_enabled)
         .bind(quiet_hours_enabled)
@@ -115,6 +124,7 @@ impl UserDataManager {
         .bind(&prefs.global.quiet_hours_end)
         .bind(&source_configs_json)
         .bind(&advanced_filters_json)
+        .bind(&market_alerts_json)
         .bind(&now)
         .execute(&self.pool)
         .await?;
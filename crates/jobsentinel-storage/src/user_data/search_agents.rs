@@ -0,0 +1,90 @@
+use super::*;
+
+/// `channels` as stored in `saved_searches.agent_channels`
+pub(super) fn channels_json(channels: &[MessageTemplateChannel]) -> Result<String, sqlx::Error> {
+    serde_json::to_string(channels)
+        .map_err(|_| sqlx::Error::Protocol("Could not save job agent channels".to_string()))
+}
+
+impl UserDataManager {
+    // ========== Job Agents ==========
+
+    /// Save a saved search's job agent settings.
+    ///
+    /// Turning an agent on starts it from now, so jobs saved before that are
+    /// not sent. Returns false when the search does not exist.
+    #[instrument(skip(self, settings))]
+    pub async fn update_saved_search_agent(
+        &self,
+        id: &str,
+        settings: &SearchAgentSettings,
+    ) -> Result<bool, sqlx::Error> {
+        debug!(
+            enabled = settings.enabled,
+            frequency = settings.frequency.as_str(),
+            "Updating job agent for saved search {}",
+            id
+        );
+
+        let now = Utc::now().to_rfc3339();
+        let result = sqlx::query(
+            r#"
+            UPDATE saved_searches
+            SET agent_last_checked_at = CASE
+                    WHEN ? = 1 AND (agent_enabled = 0 OR agent_last_checked_at IS NULL) THEN ?
+                    ELSE agent_last_checked_at
+                END,
+                agent_enabled = ?,
+                agent_channels = ?,
+                agent_frequency = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(i64::from(settings.enabled))
+        .bind(&now)
+        .bind(i64::from(settings.enabled))
+        .bind(channels_json(&settings.channels)?)
+        .bind(settings.frequency.as_str())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Saved searches whose job agent is turned on, oldest first
+    #[instrument(skip(self))]
+    pub async fn list_search_agents(&self) -> Result<Vec<SavedSearch>, sqlx::Error> {
+        let rows: Vec<SavedSearchRow> = sqlx::query_as(
+            r#"
+            SELECT id, name, sort_by, score_filter, source_filter, remote_filter,
+                   bookmark_filter, notes_filter, posted_date_filter, salary_min_filter,
+                   salary_max_filter, ghost_filter, text_search, created_at, last_used_at,
+                   agent_enabled, agent_channels, agent_frequency, agent_last_checked_at
+            FROM saved_searches
+            WHERE agent_enabled = 1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Record that a job agent has handled jobs saved up to `checked_at`
+    #[instrument(skip(self))]
+    pub async fn mark_search_agent_checked(
+        &self,
+        id: &str,
+        checked_at: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE saved_searches SET agent_last_checked_at = ? WHERE id = ?")
+            .bind(checked_at)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
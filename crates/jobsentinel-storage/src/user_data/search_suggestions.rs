@@ -0,0 +1,214 @@
+//! Search box suggestions
+//!
+//! Suggestions for a typed prefix come from search history, ranked by how
+//! often and how recently each query was used, and from the titles and
+//! companies of active jobs. A prefix matches the start of any word.
+
+use super::*;
+use chrono::DateTime;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Where a search suggestion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuerySuggestionSource {
+    History,
+    JobTitle,
+    Company,
+}
+
+/// One search box suggestion
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuerySuggestion {
+    pub text: String,
+    pub source: QuerySuggestionSource,
+    pub pinned: bool,
+    /// Searches for a history query, or active jobs with the title or company
+    pub count: i64,
+    pub score: f64,
+}
+
+/// Days after which a search's recency counts for half
+const RECENCY_HALF_LIFE_DAYS: f64 = 7.0;
+
+/// Score for a history query from how often and how recently it was used.
+///
+/// Frequency grows with the log of the use count, so a query used every day
+/// does not bury everything else; recency halves every week.
+#[must_use]
+pub fn history_suggestion_score(use_count: i64, days_since_used: f64) -> f64 {
+    let frequency = (use_count.max(0) as f64).ln_1p();
+    let recency = 0.5_f64.powf(days_since_used.max(0.0) / RECENCY_HALF_LIFE_DAYS);
+    frequency + 2.0 * recency
+}
+
+/// Score for a job title or company matched by `job_count` active jobs.
+/// Below a query searched once today, so history leads.
+#[must_use]
+pub fn job_suggestion_score(job_count: i64) -> f64 {
+    0.5 * (job_count.max(0) as f64).ln_1p()
+}
+
+impl UserDataManager {
+    /// Suggestions for a search box prefix, best first.
+    ///
+    /// Pinned queries come first. With an empty prefix only search history is
+    /// suggested. Removed queries are left out, and a job title or company is
+    /// left out when the same text is already suggested from history.
+    #[instrument(skip(self, prefix))]
+    pub async fn suggest_queries(
+        &self,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<QuerySuggestion>, sqlx::Error> {
+        let prefix = prefix.trim();
+        debug!(
+            prefix_len = prefix.chars().count(),
+            "Suggesting search queries"
+        );
+
+        let history: Vec<(String, i64, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT query, use_count, last_used_at, pinned
+            FROM search_history
+            WHERE dismissed = 0
+              AND (? = '' OR instr(' ' || lower(query), ' ' || lower(?)) > 0)
+            "#,
+        )
+        .bind(prefix)
+        .bind(prefix)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let now = Utc::now();
+        let mut suggestions: Vec<QuerySuggestion> = history
+            .into_iter()
+            .map(|(query, use_count, last_used_at, pinned)| {
+                let days_since_used = DateTime::parse_from_rfc3339(&last_used_at)
+                    .map(|used| (now - used.with_timezone(&Utc)).num_minutes() as f64 / 1440.0)
+                    .unwrap_or(f64::MAX);
+                QuerySuggestion {
+                    score: history_suggestion_score(use_count, days_since_used),
+                    text: query,
+                    source: QuerySuggestionSource::History,
+                    pinned: pinned != 0,
+                    count: use_count,
+                }
+            })
+            .collect();
+
+        if !prefix.is_empty() {
+            for (column, source) in [
+                ("title", QuerySuggestionSource::JobTitle),
+                ("company", QuerySuggestionSource::Company),
+            ] {
+                // SAFETY: `column` is one of two fixed names; the prefix is bound.
+                let rows: Vec<(String, i64)> = sqlx::query_as(sqlx::AssertSqlSafe(format!(
+                    r#"
+                    SELECT {column}, COUNT(*) AS job_count
+                    FROM jobs
+                    WHERE hidden = 0 AND status <> 'closed'
+                      AND instr(' ' || lower({column}), ' ' || lower(?)) > 0
+                      AND lower({column}) NOT IN (
+                          SELECT lower(query) FROM search_history WHERE dismissed = 1
+                      )
+                    GROUP BY lower({column})
+                    ORDER BY job_count DESC
+                    LIMIT ?
+                    "#
+                )))
+                .bind(prefix)
+                .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+                .fetch_all(&self.pool)
+                .await?;
+
+                suggestions.extend(rows.into_iter().map(|(text, job_count)| QuerySuggestion {
+                    text,
+                    source,
+                    pinned: false,
+                    count: job_count,
+                    score: job_suggestion_score(job_count),
+                }));
+            }
+        }
+
+        // History comes first here, so it wins when the same text repeats
+        let mut seen = HashSet::new();
+        suggestions.retain(|suggestion| seen.insert(suggestion.text.to_lowercase()));
+        suggestions.sort_by(|a, b| {
+            b.pinned
+                .cmp(&a.pinned)
+                .then(b.score.total_cmp(&a.score))
+                .then_with(|| a.text.cmp(&b.text))
+        });
+        suggestions.truncate(limit);
+
+        Ok(suggestions)
+    }
+
+    /// Pin or unpin a search suggestion. Pinning a job title or company adds
+    /// it to search history. Returns false when unpinning a query that is
+    /// not in history.
+    #[instrument(skip(self, query))]
+    pub async fn pin_search_suggestion(
+        &self,
+        query: &str,
+        pinned: bool,
+    ) -> Result<bool, sqlx::Error> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(false);
+        }
+        debug!(pinned, "Pinning search suggestion");
+
+        let result = if pinned {
+            sqlx::query(
+                r#"
+                INSERT INTO search_history (query, use_count, last_used_at, pinned)
+                VALUES (?, 0, ?, 1)
+                ON CONFLICT (query)
+                DO UPDATE SET pinned = 1, dismissed = 0
+                "#,
+            )
+            .bind(query)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?
+        } else {
+            sqlx::query("UPDATE search_history SET pinned = 0 WHERE query = ?")
+                .bind(query)
+                .execute(&self.pool)
+                .await?
+        };
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Stop suggesting a query, from history or from job titles and
+    /// companies, until it is searched for again
+    #[instrument(skip(self, query))]
+    pub async fn remove_search_suggestion(&self, query: &str) -> Result<(), sqlx::Error> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+        debug!("Removing search suggestion");
+
+        sqlx::query(
+            r#"
+            INSERT INTO search_history (query, use_count, last_used_at, dismissed)
+            VALUES (?, 0, ?, 1)
+            ON CONFLICT (query)
+            DO UPDATE SET dismissed = 1, pinned = 0
+            "#,
+        )
+        .bind(query)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
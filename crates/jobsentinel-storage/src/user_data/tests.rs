@@ -1,4 +1,5 @@
 use super::*;
+use crate::market_intelligence::{AlertSeverity, AlertType, MarketAlert};
 
 #[test]
 fn test_template_category_display() {
@@ -98,3 +99,58 @@ fn test_advanced_filters_preserve_pre_refactor_company_preferences() {
         serde_json::json!(["AvoidMe"])
     );
 }
+
+#[test]
+fn test_market_alert_preferences_filter_by_type_and_severity() {
+    let alert = |alert_type: AlertType, severity: AlertSeverity| MarketAlert {
+        id: 1,
+        alert_type,
+        title: "Care Coordinator salaries jumping".to_string(),
+        description: "Up 30%".to_string(),
+        severity,
+        related_entity: None,
+        related_entity_type: None,
+        metric_value: None,
+        metric_change_pct: None,
+        is_read: false,
+        created_at: Utc::now(),
+    };
+    let mut prefs: NotificationPreferences = serde_json::from_value(serde_json::json!({
+        "linkedin": { "enabled": false, "minScoreThreshold": 70, "soundEnabled": false },
+        "indeed": { "enabled": true, "minScoreThreshold": 70, "soundEnabled": false },
+        "greenhouse": { "enabled": true, "minScoreThreshold": 80, "soundEnabled": false },
+        "lever": { "enabled": true, "minScoreThreshold": 80, "soundEnabled": false },
+        "jobswithgpt": { "enabled": true, "minScoreThreshold": 75, "soundEnabled": false },
+        "global": {
+            "enabled": true,
+            "quietHoursStart": "22:00",
+            "quietHoursEnd": "08:00",
+            "quietHoursEnabled": false
+        },
+        "advancedFilters": {
+            "includeKeywords": [],
+            "excludeKeywords": [],
+            "minSalary": null,
+            "remoteOnly": false,
+            "includedCompanies": [],
+            "excludedCompanies": []
+        }
+    }))
+    .expect("preferences saved before market alerts should deserialize");
+    assert_eq!(prefs.market_alerts, MarketAlertPreferences::default());
+    assert!(prefs
+        .market_alerts
+        .allows(&alert(AlertType::SalarySpike, AlertSeverity::Info)));
+
+    prefs.market_alerts.salary_spike.min_severity = "warning".to_string();
+    prefs.market_alerts.hiring_freeze.enabled = false;
+    assert!(!prefs
+        .market_alerts
+        .allows(&alert(AlertType::SalarySpike, AlertSeverity::Info)));
+    assert!(prefs
+        .market_alerts
+        .allows(&alert(AlertType::SalarySpike, AlertSeverity::Critical)));
+    assert!(!prefs
+        .market_alerts
+        .allows(&alert(AlertType::HiringFreeze, AlertSeverity::Critical)));
+}
@@ -0,0 +1,156 @@
+//! Job search pause ("vacation mode")
+//!
+//! While the pause is on, the scheduler holds high-match alerts here instead
+//! of sending them and, unless told to keep going, skips scheduled scraping.
+//! Ending the pause shifts reminders that came due, builds a "while you were
+//! away" summary from the held alerts, and clears the queue.
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::Row;
+
+use super::connection::Database;
+use super::types::{VacationHighlight, VacationMode, VacationSummary};
+
+/// Held alerts listed individually in the summary.
+const MAX_SUMMARY_HIGHLIGHTS: i64 = 20;
+
+impl Database {
+    /// Current pause settings and how many alerts are held.
+    pub async fn get_vacation_mode(&self) -> Result<VacationMode> {
+        let row = sqlx::query(
+            r#"
+            SELECT enabled, keep_scraping, started_at,
+                   (SELECT COUNT(*) FROM vacation_queued_alerts) AS queued_alerts
+            FROM vacation_mode
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool())
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(VacationMode {
+                keep_scraping: true,
+                ..VacationMode::default()
+            });
+        };
+
+        Ok(VacationMode {
+            enabled: row.try_get("enabled")?,
+            keep_scraping: row.try_get("keep_scraping")?,
+            started_at: row
+                .try_get::<Option<NaiveDateTime>, _>("started_at")?
+                .map(|started| started.and_utc()),
+            queued_alerts: row.try_get("queued_alerts")?,
+        })
+    }
+
+    /// Turn the pause on. Enabling again only updates `keep_scraping` and
+    /// keeps the original start time.
+    pub async fn start_vacation_mode(
+        &self,
+        keep_scraping: bool,
+        now: DateTime<Utc>,
+    ) -> Result<VacationMode> {
+        sqlx::query(
+            r#"
+            INSERT INTO vacation_mode (id, enabled, keep_scraping, started_at)
+            VALUES (1, 1, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                keep_scraping = excluded.keep_scraping,
+                started_at = CASE WHEN vacation_mode.enabled = 1
+                    THEN vacation_mode.started_at ELSE excluded.started_at END,
+                enabled = 1
+            "#,
+        )
+        .bind(keep_scraping)
+        .bind(now.naive_utc())
+        .execute(self.pool())
+        .await?;
+
+        self.get_vacation_mode().await
+    }
+
+    /// Hold a high-match alert until the pause ends.
+    pub async fn queue_vacation_alert(&self, job_hash: &str, score: f64) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO vacation_queued_alerts (job_hash, score) VALUES (?, ?)
+            ON CONFLICT(job_hash) DO UPDATE SET score = MAX(score, excluded.score)
+            "#,
+        )
+        .bind(job_hash)
+        .bind(score)
+        .execute(self.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Turn the pause off and summarize what happened while away.
+    ///
+    /// Returns `None` when vacation mode was not on.
+    pub async fn end_vacation_mode(&self, now: DateTime<Utc>) -> Result<Option<VacationSummary>> {
+        let mode = self.get_vacation_mode().await?;
+        if !mode.enabled {
+            return Ok(None);
+        }
+        let started_at = mode.started_at.unwrap_or(now);
+
+        let new_jobs: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM jobs WHERE datetime(created_at) >= datetime(?)",
+        )
+        .bind(started_at.naive_utc())
+        .fetch_one(self.pool())
+        .await?;
+
+        let highlights = sqlx::query(
+            r#"
+            SELECT j.id, q.job_hash, j.title, j.company, q.score, q.queued_at
+            FROM vacation_queued_alerts q
+            JOIN jobs j ON j.hash = q.job_hash
+            ORDER BY q.score DESC, q.queued_at ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(MAX_SUMMARY_HIGHLIGHTS)
+        .fetch_all(self.pool())
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(VacationHighlight {
+                job_id: row.try_get("id")?,
+                job_hash: row.try_get("job_hash")?,
+                title: row.try_get("title")?,
+                company: row.try_get("company")?,
+                score: row.try_get("score")?,
+                queued_at: row.try_get::<NaiveDateTime, _>("queued_at")?.and_utc(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+        let reminders_shifted = self
+            .application_tracker()
+            .shift_pending_reminders(started_at, now)
+            .await?;
+
+        let mut tx = self.pool().begin().await?;
+        sqlx::query("UPDATE vacation_mode SET enabled = 0, started_at = NULL WHERE id = 1")
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM vacation_queued_alerts")
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(Some(VacationSummary {
+            started_at,
+            ended_at: now,
+            new_jobs,
+            queued_alerts: mode.queued_alerts,
+            highlights,
+            reminders_shifted,
+        }))
+    }
+}
@@ -0,0 +1,189 @@
+//! Weekly summary report data and saved reports
+//!
+//! The scheduler builds a report from these queries, then saves the rendered
+//! Markdown and HTML here so earlier weeks can be read again.
+
+use super::connection::Database;
+use super::types::JobRow;
+use chrono::{DateTime, Utc};
+use jobsentinel_domain::Job;
+use serde::{Deserialize, Serialize};
+
+/// Application tracking activity over a report period
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationActivity {
+    /// Applications marked applied
+    pub applied: i64,
+    /// Applications moved to a screening call or interview stage
+    pub interviews: i64,
+    pub offers: i64,
+    pub rejections: i64,
+    /// Applications still in progress at the end of the period
+    pub active: i64,
+}
+
+/// A saved weekly report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WeeklyReportRecord {
+    pub id: i64,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+    /// `false` for reports made on demand
+    pub scheduled: bool,
+    pub markdown: String,
+    pub html: String,
+}
+
+impl Database {
+    /// Visible jobs found since `since`.
+    pub async fn count_jobs_found_since(&self, since: DateTime<Utc>) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM jobs WHERE hidden = 0 AND created_at >= ?")
+            .bind(since)
+            .fetch_one(self.pool())
+            .await
+    }
+
+    /// The best scoring visible jobs found since `since`, best first.
+    pub async fn top_jobs_found_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let jobs = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT * FROM jobs
+            WHERE hidden = 0
+              AND status <> 'closed'
+              AND score IS NOT NULL
+              AND created_at >= ?
+            ORDER BY score DESC, created_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(since)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?
+        .into_iter()
+        .map(Job::from)
+        .collect();
+        Ok(jobs)
+    }
+
+    /// Status changes logged since `since`, plus the applications still in
+    /// progress now.
+    pub async fn application_activity_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<ApplicationActivity, sqlx::Error> {
+        let (applied, interviews, offers, rejections): (i64, i64, i64, i64) = sqlx::query_as(
+            r#"
+            SELECT
+                COALESCE(SUM(status = 'applied'), 0),
+                COALESCE(SUM(status IN (
+                    'screening_call', 'phone_interview', 'technical_interview', 'onsite_interview'
+                )), 0),
+                COALESCE(SUM(status = 'offer_received'), 0),
+                COALESCE(SUM(status = 'rejected'), 0)
+            FROM (
+                SELECT json_extract(event_data, '$.to') AS status
+                FROM application_events
+                WHERE event_type = 'status_change'
+                  AND json_valid(event_data)
+                  AND datetime(created_at) >= datetime(?)
+            )
+            "#,
+        )
+        .bind(since)
+        .fetch_one(self.pool())
+        .await?;
+
+        let active: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM applications
+            WHERE status IN (
+                'applied', 'screening_call', 'phone_interview',
+                'technical_interview', 'onsite_interview', 'offer_received'
+            )
+            "#,
+        )
+        .fetch_one(self.pool())
+        .await?;
+
+        Ok(ApplicationActivity {
+            applied,
+            interviews,
+            offers,
+            rejections,
+            active,
+        })
+    }
+
+    /// Save a rendered report and return its id.
+    pub async fn save_weekly_report(
+        &self,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+        scheduled: bool,
+        markdown: &str,
+        html: &str,
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO weekly_reports
+                (period_start, period_end, generated_at, scheduled, markdown, html)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(period_start)
+        .bind(period_end)
+        .bind(Utc::now())
+        .bind(scheduled)
+        .bind(markdown)
+        .bind(html)
+        .execute(self.pool())
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    /// When the last scheduled report was made.
+    pub async fn last_scheduled_weekly_report_at(
+        &self,
+    ) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT generated_at FROM weekly_reports WHERE scheduled = 1 ORDER BY id DESC LIMIT 1",
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+
+    /// A saved report by id.
+    pub async fn weekly_report(&self, id: i64) -> Result<Option<WeeklyReportRecord>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT id, period_start, period_end, generated_at, scheduled, markdown, html
+            FROM weekly_reports
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.pool())
+        .await
+    }
+
+    /// Saved reports, newest first.
+    pub async fn weekly_reports(&self, limit: i64) -> Result<Vec<WeeklyReportRecord>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT id, period_start, period_end, generated_at, scheduled, markdown, html
+            FROM weekly_reports
+            ORDER BY id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await
+    }
+}
@@ -7,7 +7,7 @@
 //! - Transaction isolation
 //! - Integrity checks
 
-use jobsentinel_domain::Job;
+use jobsentinel_domain::{Job, JobStatus};
 use jobsentinel_storage::Database;
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
@@ -54,6 +54,7 @@ fn create_test_job(hash: &str, title: &str, company: &str) -> Job {
         salary_min: Some(120000),
         salary_max: Some(180000),
         currency: Some("USD".to_string()),
+        pay_period: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
         last_seen: chrono::Utc::now(),
@@ -67,6 +68,8 @@ fn create_test_job(hash: &str, title: &str, company: &str) -> Job {
         ghost_reasons: None,
         first_seen: None,
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
     }
 }
 
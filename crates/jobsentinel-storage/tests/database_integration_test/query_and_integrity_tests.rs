@@ -46,6 +46,7 @@ async fn test_job_data_preserved_on_upsert() {
         salary_min: Some(65000),
         salary_max: Some(85000),
         currency: Some("USD".to_string()),
+        pay_period: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
         last_seen: chrono::Utc::now(),
@@ -59,6 +60,8 @@ async fn test_job_data_preserved_on_upsert() {
         ghost_reasons: Some(r#"{"age": "ok"}"#.to_string()),
         first_seen: Some(chrono::Utc::now()),
         repost_count: 0,
+        status: JobStatus::Active,
+        skills: Vec::new(),
     };
 
     db.upsert_job(&job).await.unwrap();
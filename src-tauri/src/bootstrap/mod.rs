@@ -1,5 +1,6 @@
 mod state;
 
+use crate::application::scheduler::POWER_SAVING_RECHECK_INTERVAL;
 use crate::desktop;
 use crate::desktop::DesktopServices;
 use crate::ipc;
@@ -31,7 +32,7 @@ pub(crate) fn run() {
     }
 
     desktop::preserve_main_window_on_close(policy::builder())
-        .invoke_handler(crate::ipc::jobsentinel_command_handlers!())
+        .invoke_handler(guard_guest_mode(crate::ipc::jobsentinel_command_handlers!()))
         .setup(|app| {
             let services =
                 tauri::async_runtime::block_on(DesktopServices::initialize()).map_err(|error| {
@@ -45,6 +46,19 @@ pub(crate) fn run() {
             let scheduler_arc = Arc::clone(&services.scheduler);
             let scheduler_status = Arc::clone(&services.scheduler_status);
             app.manage(AppState::from(services));
+            forward_scrape_events(app.handle().clone(), &scheduler_arc);
+            reanalyze_outdated_ghost_scores(&scheduler_arc);
+            check_job_liveness_periodically(
+                app.handle().clone(),
+                &scheduler_arc,
+                Arc::clone(&config_arc),
+            );
+            purge_jobs_periodically(&scheduler_arc, Arc::clone(&config_arc));
+            send_email_digests_periodically(&scheduler_arc);
+            send_weekly_reports_periodically(&scheduler_arc);
+            run_market_analysis_periodically(&scheduler_arc);
+            deliver_notifications_periodically(&scheduler_arc);
+            deliver_reminders_periodically(app.handle().clone(), &scheduler_arc);
 
             if !is_first_run {
                 tracing::info!("Starting background scheduler");
@@ -65,14 +79,23 @@ pub(crate) fn run() {
                             config.auto_refresh.enabled
                         };
 
-                        if !auto_refresh_enabled {
+                        let scraping_paused = scheduler_clone
+                            .scraping_paused_at()
+                            .await
+                            .is_ok_and(|paused_at| paused_at.is_some());
+
+                        if !auto_refresh_enabled || scraping_paused {
                             {
                                 let mut status = status_clone.write().await;
                                 status.is_running = false;
                                 status.next_run = None;
                             }
 
-                            tracing::info!("Background scheduler is disabled; waiting before rechecking");
+                            if scraping_paused {
+                                tracing::info!("Background scraping is paused; waiting before rechecking");
+                            } else {
+                                tracing::info!("Background scheduler is disabled; waiting before rechecking");
+                            }
                             tokio::select! {
                                 sleep_done = tokio::time::sleep(tokio::time::Duration::from_mins(1)) => {
                                     let () = sleep_done;
@@ -96,19 +119,26 @@ pub(crate) fn run() {
                             // Keep last_run from previous cycle for continuity
                         }
 
-                        // Run scraping cycle
-                        match scheduler_clone.run_scraping_cycle().await {
-                            Ok(result) => {
-                                tracing::info!(
-                                    "Background scraping complete: {} jobs found, {} new",
-                                    result.jobs_found,
-                                    result.jobs_new
-                                );
-                                // Emit event to frontend so it can refresh
-                                let _ = app_handle.emit("jobs-updated", serde_json::json!({
-                                    "jobs_found": result.jobs_found,
-                                    "jobs_new": result.jobs_new
-                                }));
+                        // Run scraping cycle, cut down or skipped on a low battery or metered connection
+                        let mut skip_reason = None;
+                        let mut skipped = false;
+                        match scheduler_clone.run_scheduled_cycle().await {
+                            Ok(scrape) => {
+                                skip_reason = scrape.skip_reason;
+                                if let Some(result) = scrape.result {
+                                    tracing::info!(
+                                        "Background scraping complete: {} jobs found, {} new",
+                                        result.jobs_found,
+                                        result.jobs_new
+                                    );
+                                    // Emit event to frontend so it can refresh
+                                    let _ = app_handle.emit("jobs-updated", serde_json::json!({
+                                        "jobs_found": result.jobs_found,
+                                        "jobs_new": result.jobs_new
+                                    }));
+                                } else {
+                                    skipped = true;
+                                }
                             }
                             Err(e) => {
                                 tracing::error!("Background scraping failed: {}", e);
@@ -122,19 +152,30 @@ pub(crate) fn run() {
 
                         // Calculate next run time first to ensure consistency
                         let now = Utc::now();
-                        let next_run_time = auto_refresh_enabled
-                            .then(|| now + Duration::hours(interval_hours as i64));
+                        let next_run_time = if skipped {
+                            Duration::from_std(POWER_SAVING_RECHECK_INTERVAL)
+                                .ok()
+                                .map(|recheck| now + recheck)
+                        } else {
+                            auto_refresh_enabled
+                                .then(|| now + Duration::hours(interval_hours as i64))
+                        };
 
                         // Update status atomically: completed, set times
                         {
                             let mut status = status_clone.write().await;
                             status.is_running = false;
-                            status.last_run = Some(now);
+                            if !skipped {
+                                status.last_run = Some(now);
+                            }
                             status.next_run = next_run_time;
+                            status.skip_reason = skip_reason;
                         }
 
                         // Wait for next interval or shutdown signal
-                        let sleep_duration = if auto_refresh_enabled {
+                        let sleep_duration = if skipped {
+                            POWER_SAVING_RECHECK_INTERVAL
+                        } else if auto_refresh_enabled {
                             tokio::time::Duration::from_secs(interval_hours.saturating_mul(3600))
                         } else {
                             tokio::time::Duration::from_mins(1)
@@ -177,3 +218,292 @@ pub(crate) fn run() {
         })
         .ok();
 }
+
+/// Reject commands that guest mode does not allow before they reach their
+/// handler, so the restriction holds regardless of what the UI shows.
+fn guard_guest_mode(
+    handlers: impl Fn(tauri::ipc::Invoke) -> bool + Send + Sync + 'static,
+) -> impl Fn(tauri::ipc::Invoke) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        let allowed = invoke
+            .message
+            .webview_ref()
+            .try_state::<AppState>()
+            .is_none_or(|state| state.guest_mode.allows_command(invoke.message.command()));
+        if allowed {
+            return handlers(invoke);
+        }
+        tracing::info!(
+            command = invoke.message.command(),
+            "Command blocked by guest mode"
+        );
+        invoke
+            .resolver
+            .reject(jobsentinel_application::GUEST_MODE_BLOCKED_MESSAGE);
+        true
+    }
+}
+
+/// Re-score saved postings analyzed by an older ghost detector.
+fn reanalyze_outdated_ghost_scores(scheduler: &Arc<crate::application::scheduler::Scheduler>) {
+    let scheduler = Arc::clone(scheduler);
+    tauri::async_runtime::spawn(async move {
+        match scheduler.reanalyze_outdated_ghost_scores().await {
+            Ok(Some(run)) => tracing::info!(
+                jobs_reanalyzed = run.jobs_reanalyzed,
+                "Background ghost re-analysis complete"
+            ),
+            Ok(None) => {}
+            Err(error) => tracing::warn!(error = %error, "Background ghost re-analysis failed"),
+        }
+    });
+}
+
+/// Re-check saved postings every few hours while auto-refresh is on, and
+/// emit `application:posting_closed` when a tracked application still waiting
+/// in `to_apply` loses its posting.
+fn check_job_liveness_periodically(
+    app_handle: tauri::AppHandle,
+    scheduler: &Arc<crate::application::scheduler::Scheduler>,
+    config: Arc<tokio::sync::RwLock<crate::application::Config>>,
+) {
+    use crate::application::scheduler::LIVENESS_CHECK_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(LIVENESS_CHECK_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            if !config.read().await.auto_refresh.enabled {
+                continue;
+            }
+            match scheduler.check_job_liveness().await {
+                Ok(check) => {
+                    for application in &check.closed_applications {
+                        if application.needs_attention() {
+                            let _ = app_handle.emit("application:posting_closed", application);
+                        }
+                    }
+                }
+                Err(error) => tracing::warn!(error = %error, "Background liveness check failed"),
+            }
+        }
+    });
+}
+
+/// Delete old jobs once a day while the retention rules are enabled.
+fn purge_jobs_periodically(
+    scheduler: &Arc<crate::application::scheduler::Scheduler>,
+    config: Arc<tokio::sync::RwLock<crate::application::Config>>,
+) {
+    use crate::application::scheduler::RETENTION_PURGE_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(RETENTION_PURGE_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            if !config.read().await.retention.enabled {
+                continue;
+            }
+            match scheduler.purge_jobs(false).await {
+                Ok(report) => tracing::info!(
+                    jobs_deleted = report.jobs_deleted,
+                    "Background job purge complete"
+                ),
+                Err(error) => tracing::warn!(error = %error, "Background job purge failed"),
+            }
+        }
+    });
+}
+
+/// Send the email digest when its scheduled time comes around.
+fn send_email_digests_periodically(scheduler: &Arc<crate::application::scheduler::Scheduler>) {
+    use crate::application::scheduler::EMAIL_DIGEST_CHECK_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(EMAIL_DIGEST_CHECK_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            match scheduler.send_email_digest_if_due().await {
+                Ok(Some(job_count)) => {
+                    tracing::info!(job_count, "Scheduled email digest complete")
+                }
+                Ok(None) => {}
+                Err(error) => tracing::warn!(error = %error, "Scheduled email digest failed"),
+            }
+        }
+    });
+}
+
+/// Make and send the weekly report when its scheduled time comes around.
+fn send_weekly_reports_periodically(scheduler: &Arc<crate::application::scheduler::Scheduler>) {
+    use crate::application::scheduler::WEEKLY_REPORT_CHECK_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(WEEKLY_REPORT_CHECK_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            match scheduler.send_weekly_report_if_due().await {
+                Ok(Some(run)) => tracing::info!(
+                    report_id = run.report.id,
+                    delivered = run.delivery.delivered.len(),
+                    failed = run.delivery.failed.len(),
+                    "Scheduled weekly report complete"
+                ),
+                Ok(None) => {}
+                Err(error) => tracing::warn!(error = %error, "Scheduled weekly report failed"),
+            }
+        }
+    });
+}
+
+/// Refresh market trends and alerts once a day, and send new market alerts.
+fn run_market_analysis_periodically(scheduler: &Arc<crate::application::scheduler::Scheduler>) {
+    use crate::application::scheduler::MARKET_ANALYSIS_CHECK_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(MARKET_ANALYSIS_CHECK_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            match scheduler.run_market_analysis_if_due().await {
+                Ok(Some(snapshot)) => tracing::info!(
+                    date = %snapshot.date,
+                    total_jobs = snapshot.total_jobs,
+                    "Scheduled market analysis complete"
+                ),
+                Ok(None) => {}
+                Err(error) => tracing::warn!(error = %error, "Scheduled market analysis failed"),
+            }
+            if let Err(error) = scheduler.deliver_market_alerts().await {
+                tracing::warn!(error = %error, "Market alert delivery failed");
+            }
+        }
+    });
+}
+
+/// Send queued alerts and retry failed ones as their backoff passes.
+fn deliver_notifications_periodically(scheduler: &Arc<crate::application::scheduler::Scheduler>) {
+    use crate::application::scheduler::NOTIFICATION_OUTBOX_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(NOTIFICATION_OUTBOX_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            match scheduler.deliver_pending_notifications().await {
+                Ok(delivery) if delivery.sent + delivery.failed + delivery.deferred > 0 => {
+                    tracing::info!(
+                        sent = delivery.sent,
+                        failed = delivery.failed,
+                        deferred = delivery.deferred,
+                        "Queued notification delivery complete"
+                    )
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    tracing::warn!(error = %error, "Queued notification delivery failed")
+                }
+            }
+        }
+    });
+}
+
+/// Send application reminders as they come due and emit
+/// `application:reminder_due` for each one so the app can show a desktop
+/// notification. The event names the job but leaves out the reminder's note.
+fn deliver_reminders_periodically(
+    app_handle: tauri::AppHandle,
+    scheduler: &Arc<crate::application::scheduler::Scheduler>,
+) {
+    use crate::application::scheduler::REMINDER_CHECK_INTERVAL;
+
+    let scheduler = Arc::clone(scheduler);
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(REMINDER_CHECK_INTERVAL) => {}
+                _ = shutdown_rx.recv() => break,
+            }
+            match scheduler.deliver_due_reminders().await {
+                Ok(reminders) => {
+                    for reminder in &reminders {
+                        let _ = app_handle.emit(
+                            "application:reminder_due",
+                            serde_json::json!({
+                                "id": reminder.id,
+                                "application_id": reminder.application_id,
+                                "reminder_type": reminder.reminder_type,
+                                "job_title": reminder.job_title,
+                                "company": reminder.company,
+                            }),
+                        );
+                    }
+                }
+                Err(error) => tracing::warn!(error = %error, "Reminder delivery failed"),
+            }
+        }
+    });
+}
+
+/// Relay live scraping updates to the frontend as `scrape:progress`,
+/// `scrape:source_complete`, `scrape:error`, and `scrape:source_failing`
+/// events.
+fn forward_scrape_events(
+    app_handle: tauri::AppHandle,
+    scheduler: &crate::application::scheduler::Scheduler,
+) {
+    use crate::application::scheduler::ScrapeEvent;
+    use tokio::sync::broadcast::error::RecvError;
+
+    let mut events_rx = scheduler.subscribe_scrape_events();
+    let mut shutdown_rx = scheduler.subscribe_shutdown();
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                event = events_rx.recv() => match event {
+                    Ok(ScrapeEvent::Progress(progress)) => {
+                        let _ = app_handle.emit("scrape:progress", progress);
+                    }
+                    Ok(ScrapeEvent::SourceComplete(summary)) => {
+                        let _ = app_handle.emit("scrape:source_complete", summary);
+                    }
+                    Ok(ScrapeEvent::Error(error)) => {
+                        let _ = app_handle.emit("scrape:error", error);
+                    }
+                    Ok(ScrapeEvent::SourceFailing(alert)) => {
+                        let _ = app_handle.emit("scrape:source_failing", alert);
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::debug!(skipped, "Scrape event receiver lagged");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                _ = shutdown_rx.recv() => break,
+            }
+        }
+    });
+}
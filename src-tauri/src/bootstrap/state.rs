@@ -2,9 +2,14 @@ use std::sync::Arc;
 
 use tokio::sync::RwLock;
 
-use crate::application::{config::Config, credentials::CredentialService, scheduler::Scheduler};
+use crate::application::{
+    config::{Config, ConfigRecovery},
+    credentials::CredentialService,
+    scheduler::Scheduler,
+};
 use crate::desktop::{BookmarkletServer, Database, DesktopServices, SchedulerStatus};
-use jobsentinel_application::PendingUrlImports;
+use jobsentinel_application::quick_actions::QuickActions;
+use jobsentinel_application::{GuestMode, PendingUrlImports};
 
 pub(crate) struct AppState {
     pub config: Arc<RwLock<Config>>,
@@ -14,6 +19,9 @@ pub(crate) struct AppState {
     pub scheduler_status: Arc<RwLock<SchedulerStatus>>,
     pub bookmarklet_server: Arc<RwLock<BookmarkletServer>>,
     pub pending_url_imports: PendingUrlImports,
+    pub quick_actions: QuickActions,
+    pub guest_mode: GuestMode,
+    pub config_recovery: Option<ConfigRecovery>,
 }
 
 impl From<DesktopServices> for AppState {
@@ -26,6 +34,9 @@ impl From<DesktopServices> for AppState {
             scheduler_status: services.scheduler_status,
             bookmarklet_server: services.bookmarklet_server,
             pending_url_imports: services.pending_url_imports,
+            quick_actions: QuickActions::default(),
+            guest_mode: GuestMode::default(),
+            config_recovery: services.config_recovery,
         }
     }
 }
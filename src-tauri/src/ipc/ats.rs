@@ -1,14 +1,94 @@
 //! Application Tracking System (ATS) Tauri commands
 //!
-//! Commands for managing job applications, interviews, reminders, and ghosting detection.
+//! Commands for managing job applications, interviews, offers, reminders,
+//! cover letter and follow-up drafts, and ghosting detection.
 
 use crate::application::ats::{
-    ApplicationStats, ApplicationStatus, ApplicationsByStatus, InterviewWithJob, PendingReminder,
+    cover_letter_draft, followup_draft, interview_calendar_feed, latest_past_interview,
+    ApplicationStats, ApplicationStatus, ApplicationsByStatus, CoverLetterContext,
+    CoverLetterDraft, FollowUpDraft, FollowUpDraftContext, FollowUpDraftKind, InterviewWithJob,
+    OfferInput, OfferWithJob, PendingReminder, INTERVIEW_CALENDAR_FILE,
 };
+use crate::application::notify::{CalendarUpdate, NotificationService};
+use crate::application::resume::UserSkill;
+use crate::application::salary::{load_exchange_rates, normalize_currency_code};
 use crate::bootstrap::AppState;
+use crate::desktop;
 use crate::ipc::errors::user_friendly_error;
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tauri::State;
 
+/// Email calendar invites in the background so SMTP problems never fail the
+/// interview change itself.
+async fn send_interview_calendar_updates(
+    state: &AppState,
+    updates: Vec<(InterviewWithJob, CalendarUpdate)>,
+) {
+    let config = {
+        let config = state.config.read().await;
+        if updates.is_empty()
+            || !config.alerts.email.enabled
+            || !config.alerts.email.send_interview_calendar
+        {
+            return;
+        }
+        Arc::new(config.clone())
+    };
+    let service = NotificationService::with_credentials(config, state.credentials.clone());
+    tauri::async_runtime::spawn(async move {
+        for (interview, update) in updates {
+            if let Err(error) = service
+                .send_interview_calendar_update(&interview, update)
+                .await
+            {
+                tracing::warn!(
+                    interview_id = interview.id,
+                    ?update,
+                    error = %error,
+                    "Failed to email interview calendar update"
+                );
+            }
+        }
+    });
+}
+
+/// The interview calendar feed for everything from the last 90 days onward.
+async fn interview_calendar(state: &AppState) -> anyhow::Result<String> {
+    let interviews = state
+        .database
+        .application_tracker()
+        .get_calendar_interviews()
+        .await?;
+    Ok(interview_calendar_feed(
+        &state.guest_mode.mask(interviews),
+        Utc::now(),
+    ))
+}
+
+fn interview_calendar_path() -> PathBuf {
+    desktop::get_data_dir().join(INTERVIEW_CALENDAR_FILE)
+}
+
+async fn write_interview_calendar(state: &AppState, path: &Path) -> anyhow::Result<()> {
+    let ics = interview_calendar(state).await?;
+    tokio::fs::write(path, ics).await?;
+    Ok(())
+}
+
+/// Rewrite the subscribed calendar file after an interview changes. Does
+/// nothing until the user has subscribed.
+async fn refresh_interview_calendar(state: &AppState) {
+    let path = interview_calendar_path();
+    if !path.exists() {
+        return;
+    }
+    if let Err(error) = write_interview_calendar(state, &path).await {
+        tracing::warn!(error = %error, "Failed to refresh interview calendar");
+    }
+}
+
 /// Create a new application from a job
 #[tauri::command]
 pub(crate) async fn create_application(
@@ -36,6 +116,7 @@ pub(crate) async fn get_applications_kanban(
     tracker
         .get_applications_by_status()
         .await
+        .map(|value| state.guest_mode.mask(value))
         .map_err(|e| user_friendly_error("Failed to get applications", e))
 }
 
@@ -64,6 +145,88 @@ pub(crate) async fn update_application_status(
         .map_err(|e| user_friendly_error("Failed to update status", e))
 }
 
+/// Most applications or jobs handled by one bulk command
+const MAX_BULK_APPLICATIONS: usize = 500;
+
+fn validate_bulk_selection(ids: &[i64]) -> Result<(), String> {
+    if ids.len() > MAX_BULK_APPLICATIONS {
+        return Err(format!(
+            "Choose up to {MAX_BULK_APPLICATIONS} items at a time."
+        ));
+    }
+    Ok(())
+}
+
+/// Start applications for several jobs at once
+///
+/// Jobs that already have an application are skipped. Returns the ids of the
+/// new applications.
+#[tauri::command]
+pub(crate) async fn bulk_create_applications(
+    job_ids: Vec<i64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<i64>, String> {
+    validate_bulk_selection(&job_ids)?;
+    tracing::info!(
+        job_count = job_ids.len(),
+        "Command: bulk_create_applications"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .bulk_create_applications(&job_ids)
+        .await
+        .map_err(|e| user_friendly_error("Failed to create applications", e))
+}
+
+/// Move several applications to one status
+///
+/// Returns how many moved; applications already in that status are skipped.
+#[tauri::command]
+pub(crate) async fn bulk_update_application_status(
+    application_ids: Vec<i64>,
+    status: String,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    validate_bulk_selection(&application_ids)?;
+    let new_status: ApplicationStatus = status
+        .parse()
+        .map_err(|e| user_friendly_error("Invalid status", e))?;
+    tracing::info!(
+        application_count = application_ids.len(),
+        status = %new_status,
+        "Command: bulk_update_application_status"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .bulk_update_status(&application_ids, new_status)
+        .await
+        .map_err(|e| user_friendly_error("Failed to update status", e))
+}
+
+/// Archive rejected applications so they leave the board
+///
+/// Selected applications in any other status are skipped. Returns how many
+/// were archived.
+#[tauri::command]
+pub(crate) async fn bulk_archive_rejected_applications(
+    application_ids: Vec<i64>,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    validate_bulk_selection(&application_ids)?;
+    tracing::info!(
+        application_count = application_ids.len(),
+        "Command: bulk_archive_rejected_applications"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .archive_rejected_applications(&application_ids)
+        .await
+        .map_err(|e| user_friendly_error("Failed to archive applications", e))
+}
+
 /// Add notes to an application
 #[tauri::command]
 pub(crate) async fn add_application_notes(
@@ -87,13 +250,52 @@ pub(crate) async fn get_pending_reminders(
 ) -> Result<Vec<PendingReminder>, String> {
     tracing::info!("Command: get_pending_reminders");
 
+    // Reminders stay quiet while away; ending vacation mode shifts them.
+    let vacation = state
+        .database
+        .get_vacation_mode()
+        .await
+        .map_err(|e| user_friendly_error("Failed to get reminders", e))?;
+    if vacation.enabled {
+        return Ok(Vec::new());
+    }
+
     let tracker = state.database.application_tracker();
     tracker
         .get_pending_reminders()
         .await
+        .map(|value| state.guest_mode.mask(value))
         .map_err(|e| user_friendly_error("Failed to get reminders", e))
 }
 
+/// Reminder kinds an application reminder can have
+const REMINDER_TYPES: &[&str] = &["follow_up", "interview_prep", "custom"];
+
+/// Set a reminder on an application
+#[tauri::command]
+pub(crate) async fn set_reminder(
+    application_id: i64,
+    reminder_type: String,
+    reminder_time: DateTime<Utc>,
+    message: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    if !REMINDER_TYPES.contains(&reminder_type.as_str()) {
+        return Err("Choose a follow-up, interview prep, or custom reminder.".to_string());
+    }
+    tracing::info!(
+        application_id,
+        reminder_type = %reminder_type,
+        "Command: set_reminder"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .set_reminder(application_id, &reminder_type, reminder_time, &message)
+        .await
+        .map_err(|e| user_friendly_error("Failed to set reminder", e))
+}
+
 /// Mark reminder as completed
 #[tauri::command]
 pub(crate) async fn complete_reminder(
@@ -165,7 +367,7 @@ pub(crate) async fn schedule_interview(
     );
 
     let tracker = state.database.application_tracker();
-    tracker
+    let interview_id = tracker
         .schedule_interview(
             application_id,
             &interview_type,
@@ -177,7 +379,55 @@ pub(crate) async fn schedule_interview(
             notes.as_deref(),
         )
         .await
-        .map_err(|e| user_friendly_error("Failed to schedule interview", e))
+        .map_err(|e| user_friendly_error("Failed to schedule interview", e))?;
+
+    if let Ok(Some(interview)) = tracker.get_interview(interview_id).await {
+        send_interview_calendar_updates(&state, vec![(interview, CalendarUpdate::Scheduled)]).await;
+    }
+    refresh_interview_calendar(&state).await;
+    Ok(interview_id)
+}
+
+/// Move an interview to a new time
+///
+/// With interview calendar emails on, the old slot is cancelled and a fresh
+/// invite is sent for the new one.
+#[tauri::command]
+pub(crate) async fn reschedule_interview(
+    interview_id: i64,
+    scheduled_at: String,
+    duration_minutes: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let scheduled_at_chars = scheduled_at.chars().count();
+    tracing::info!(
+        interview_id,
+        scheduled_at_chars,
+        ?duration_minutes,
+        "Command: reschedule_interview"
+    );
+
+    let tracker = state.database.application_tracker();
+    let previous = tracker
+        .get_interview(interview_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to reschedule interview", e))?
+        .ok_or_else(|| "Interview not found".to_string())?;
+    tracker
+        .reschedule_interview(interview_id, &scheduled_at, duration_minutes)
+        .await
+        .map_err(|e| user_friendly_error("Failed to reschedule interview", e))?;
+
+    let mut updates = Vec::new();
+    if let Ok(Some(interview)) = tracker.get_interview(interview_id).await {
+        if interview.scheduled_at != previous.scheduled_at {
+            updates.push((previous, CalendarUpdate::Cancelled));
+        }
+        updates.push((interview, CalendarUpdate::Scheduled));
+    }
+    send_interview_calendar_updates(&state, updates).await;
+    refresh_interview_calendar(&state).await;
+    Ok(())
 }
 
 /// Get upcoming interviews
@@ -191,6 +441,7 @@ pub(crate) async fn get_upcoming_interviews(
     tracker
         .get_upcoming_interviews()
         .await
+        .map(|value| state.guest_mode.mask(value))
         .map_err(|e| user_friendly_error("Failed to get interviews", e))
 }
 
@@ -205,6 +456,7 @@ pub(crate) async fn get_past_interviews(
     tracker
         .get_past_interviews()
         .await
+        .map(|value| state.guest_mode.mask(value))
         .map_err(|e| user_friendly_error("Failed to get past interviews", e))
 }
 
@@ -228,7 +480,9 @@ pub(crate) async fn complete_interview(
     tracker
         .complete_interview(interview_id, &outcome, notes.as_deref())
         .await
-        .map_err(|e| user_friendly_error("Failed to complete interview", e))
+        .map_err(|e| user_friendly_error("Failed to complete interview", e))?;
+    refresh_interview_calendar(&state).await;
+    Ok(())
 }
 
 /// Delete an interview
@@ -240,8 +494,317 @@ pub(crate) async fn delete_interview(
     tracing::info!("Command: delete_interview (id: {})", interview_id);
 
     let tracker = state.database.application_tracker();
+    let interview = tracker.get_interview(interview_id).await.ok().flatten();
     tracker
         .delete_interview(interview_id)
         .await
-        .map_err(|e| user_friendly_error("Failed to delete interview", e))
+        .map_err(|e| user_friendly_error("Failed to delete interview", e))?;
+
+    if let Some(interview) = interview.filter(|interview| !interview.completed) {
+        send_interview_calendar_updates(&state, vec![(interview, CalendarUpdate::Cancelled)]).await;
+    }
+    refresh_interview_calendar(&state).await;
+    Ok(())
+}
+
+/// Export interviews from the last 90 days onward as an iCalendar (.ics) file
+#[tauri::command]
+pub(crate) async fn export_interview_calendar(
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    tracing::info!("Command: export_interview_calendar");
+
+    interview_calendar(&state)
+        .await
+        .map_err(|e| user_friendly_error("Failed to export interview calendar", e))
+}
+
+/// Write the interview calendar to the data folder and return its path
+///
+/// Calendar apps can subscribe to the file. It is rewritten whenever an
+/// interview is scheduled, moved, completed, or deleted.
+#[tauri::command]
+pub(crate) async fn subscribe_interview_calendar(
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    tracing::info!("Command: subscribe_interview_calendar");
+
+    let path = interview_calendar_path();
+    write_interview_calendar(&state, &path)
+        .await
+        .map_err(|e| user_friendly_error("Failed to save interview calendar", e))?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Save the offer for an application, replacing any offer saved before
+///
+/// Returns the offer id.
+#[tauri::command]
+pub(crate) async fn save_offer(
+    application_id: i64,
+    mut offer: OfferInput,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    tracing::info!(application_id, "Command: save_offer");
+
+    let extras = [
+        offer.annual_bonus,
+        offer.signing_bonus,
+        offer.equity_annual_value,
+        offer.benefits_annual_value,
+    ];
+    if offer.base_salary <= 0 || extras.into_iter().flatten().any(|amount| amount < 0) {
+        return Err(
+            "Enter a base salary above zero and no negative bonus, equity, or benefit amounts."
+                .to_string(),
+        );
+    }
+    if offer
+        .cost_of_living_index
+        .is_some_and(|index| !index.is_finite() || index <= 0.0)
+    {
+        return Err(
+            "Cost of living index must be above zero, where 100 is where you live now.".to_string(),
+        );
+    }
+    if let Some(currency) = offer.currency.take() {
+        let rates = load_exchange_rates(&state.database).await;
+        let code = normalize_currency_code(&currency)
+            .filter(|code| rates.usd_per_unit(code).is_some())
+            .ok_or_else(|| format!("No exchange rate is available for {}.", currency.trim()))?;
+        offer.currency = Some(code);
+    }
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .save_offer(application_id, &offer)
+        .await
+        .map_err(|e| user_friendly_error("Failed to save offer", e))
+}
+
+/// Get saved offers, soonest deadline first
+#[tauri::command]
+pub(crate) async fn get_offers(state: State<'_, AppState>) -> Result<Vec<OfferWithJob>, String> {
+    tracing::info!("Command: get_offers");
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .get_offers()
+        .await
+        .map_err(|e| user_friendly_error("Failed to get offers", e))
+}
+
+/// Delete an offer
+#[tauri::command]
+pub(crate) async fn delete_offer(offer_id: i64, state: State<'_, AppState>) -> Result<(), String> {
+    tracing::info!("Command: delete_offer (id: {})", offer_id);
+
+    let tracker = state.database.application_tracker();
+    let deleted = tracker
+        .delete_offer(offer_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to delete offer", e))?;
+    if deleted {
+        Ok(())
+    } else {
+        Err("Offer not found".to_string())
+    }
+}
+
+/// Draft a thank-you, follow-up, or withdrawal email for an application
+///
+/// Uses `template_id`, or the most recently edited email template of the
+/// draft's kind. The draft is addressed to the contact linked to the latest
+/// past interview, or else to the application, and is only returned for the
+/// user to copy; nothing is sent.
+#[tauri::command]
+pub(crate) async fn generate_followup_draft(
+    application_id: i64,
+    kind: FollowUpDraftKind,
+    template_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<FollowUpDraft, String> {
+    tracing::info!(application_id, ?kind, "Command: generate_followup_draft");
+
+    let template = match template_id {
+        Some(template_id) => state
+            .database
+            .user_data_manager()
+            .get_template(&template_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to load email template", e))?
+            .ok_or_else(|| "Email template not found".to_string())?,
+        None => state
+            .database
+            .user_data_manager()
+            .list_templates()
+            .await
+            .map_err(|e| user_friendly_error("Failed to load email templates", e))?
+            .into_iter()
+            .find(|template| template.category == kind.template_category())
+            .ok_or_else(|| {
+                "No email template of this kind yet. Add one under cover letter templates."
+                    .to_string()
+            })?,
+    };
+
+    let tracker = state.database.application_tracker();
+    let application = tracker
+        .get_application(application_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load application", e))?;
+    let job = state
+        .database
+        .get_job_by_hash(&application.job_hash)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load application", e))?
+        .ok_or_else(|| "The job for this application no longer exists".to_string())?;
+    let interviews = tracker
+        .get_application_interviews(application_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load interviews", e))?;
+    let interview = latest_past_interview(&interviews, Utc::now());
+
+    let mut contacts = match interview {
+        Some(interview) => tracker
+            .get_interview_contacts(interview.id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to load contacts", e))?,
+        None => Vec::new(),
+    };
+    if contacts.is_empty() {
+        contacts = tracker
+            .get_application_contacts(application_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to load contacts", e))?;
+    }
+    let profile = state
+        .database
+        .profile_manager()
+        .get_profile()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load profile", e))?;
+
+    Ok(followup_draft(
+        kind,
+        &template,
+        &FollowUpDraftContext {
+            job_title: &job.title,
+            company: &job.company,
+            location: job.location.as_deref(),
+            applied_at: application.applied_at,
+            contact: contacts.first(),
+            interview,
+            your_name: profile.as_ref().map(|profile| profile.full_name.as_str()),
+        },
+    ))
+}
+
+/// Resume skills for a cover letter: skills the job asks for first, then the
+/// rest by how confident the extraction was
+fn cover_letter_skills(
+    matching_skills: Vec<String>,
+    mut user_skills: Vec<UserSkill>,
+) -> Vec<String> {
+    user_skills.sort_by(|a, b| b.confidence_score.total_cmp(&a.confidence_score));
+    let mut skills = matching_skills;
+    for skill in user_skills {
+        if !skills
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(&skill.skill_name))
+        {
+            skills.push(skill.skill_name);
+        }
+    }
+    skills
+}
+
+/// Fill a cover letter template for a job from a resume
+///
+/// When `application_id` is given, the draft is also saved to that
+/// application as a new cover letter attachment.
+#[tauri::command]
+pub(crate) async fn generate_cover_letter(
+    template_id: String,
+    job_hash: String,
+    resume_id: i64,
+    application_id: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<CoverLetterDraft, String> {
+    tracing::info!(resume_id, ?application_id, "Command: generate_cover_letter");
+
+    let template = state
+        .database
+        .user_data_manager()
+        .get_template(&template_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load cover letter template", e))?
+        .ok_or_else(|| "Cover letter template not found".to_string())?;
+    let job = state
+        .database
+        .get_job_by_hash(&job_hash)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load job", e))?
+        .ok_or_else(|| "Job not found".to_string())?;
+    if let Some(application_id) = application_id {
+        let application = state
+            .database
+            .application_tracker()
+            .get_application(application_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to load application", e))?;
+        if application.job_hash != job.hash {
+            return Err("That application is for a different job".to_string());
+        }
+    }
+
+    let matcher = state.database.resume_matcher();
+    let match_result = matcher
+        .match_resume_to_job(resume_id, &job.hash)
+        .await
+        .map_err(|e| user_friendly_error("Failed to match resume", e))?;
+    let user_skills = matcher
+        .get_user_skills(resume_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load resume skills", e))?;
+    let years_experience = user_skills
+        .iter()
+        .filter_map(|skill| skill.years_experience)
+        .max_by(f64::total_cmp);
+    let skills = cover_letter_skills(match_result.matching_skills, user_skills);
+    let profile = state
+        .database
+        .profile_manager()
+        .get_profile()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load profile", e))?;
+
+    let mut draft = cover_letter_draft(
+        &template,
+        &CoverLetterContext {
+            job_title: &job.title,
+            company: &job.company,
+            location: job.location.as_deref(),
+            skills: &skills,
+            years_experience,
+            your_name: profile.as_ref().map(|profile| profile.full_name.as_str()),
+        },
+        Utc::now().date_naive(),
+    );
+
+    if let Some(application_id) = application_id {
+        let file_name = format!("Cover letter - {}.txt", job.company);
+        draft.attachment = Some(
+            super::attachments::attach_text_file(
+                &state,
+                application_id,
+                "cover_letter",
+                &file_name,
+                &draft.body,
+            )
+            .await?,
+        );
+    }
+
+    Ok(draft)
 }
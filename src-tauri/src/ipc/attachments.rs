@@ -0,0 +1,300 @@
+//! Application attachment Tauri commands
+//!
+//! Attached files are copied into the `application-attachments` folder in the
+//! data directory, one subfolder per application, so the exact resume or cover
+//! letter that was sent stays available even if the original file moves.
+
+use crate::application::ats::ApplicationAttachment;
+use crate::bootstrap::AppState;
+use crate::desktop;
+use crate::desktop::path_label_for_logging;
+use crate::ipc::errors::user_friendly_error;
+use crate::ipc::resume_file_names::safe_resume_file_stem;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use tauri_plugin_dialog::DialogExt;
+use tauri_plugin_shell::ShellExt;
+use uuid::Uuid;
+
+const ATTACHMENTS_DIR: &str = "application-attachments";
+const MAX_ATTACHMENT_BYTES: u64 = 25 * 1024 * 1024;
+const ATTACHMENT_KINDS: &[&str] = &["resume", "cover_letter", "take_home", "other"];
+const SUPPORTED_ATTACHMENT_EXTENSIONS: &[&str] = &[
+    "pdf", "doc", "docx", "odt", "rtf", "txt", "md", "html", "htm", "png", "jpg", "jpeg", "zip",
+];
+
+fn attachments_dir() -> PathBuf {
+    desktop::get_data_dir().join(ATTACHMENTS_DIR)
+}
+
+fn supported_attachment_extension(path: &Path) -> Option<String> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    SUPPORTED_ATTACHMENT_EXTENSIONS
+        .contains(&extension.as_str())
+        .then_some(extension)
+}
+
+/// Check the selected file and return its size
+fn validate_selected_attachment(path: &Path) -> Result<u64, String> {
+    if supported_attachment_extension(path).is_none() {
+        return Err(
+            "Choose a document, image, or ZIP file, such as a PDF, DOCX, or TXT file.".to_string(),
+        );
+    }
+
+    let metadata =
+        std::fs::metadata(path).map_err(|_| "JobSentinel could not read that file.".to_string())?;
+    if !metadata.is_file() {
+        return Err("Choose a file, not a folder.".to_string());
+    }
+    if metadata.len() > MAX_ATTACHMENT_BYTES {
+        return Err("That file is too large to attach. Choose a file under 25 MB.".to_string());
+    }
+
+    Ok(metadata.len())
+}
+
+/// Path of the stored copy, relative to the attachments folder
+fn attachment_stored_name(application_id: i64, path: &Path) -> String {
+    let safe_stem = safe_resume_file_stem(path, "attachment");
+    let extension = supported_attachment_extension(path).unwrap_or_else(|| "bin".to_string());
+    format!(
+        "{application_id}/{}--{safe_stem}.{extension}",
+        Uuid::new_v4()
+    )
+}
+
+/// Resolve a stored name to a file inside the attachments folder
+///
+/// Returns `None` for names that could point outside the folder.
+fn stored_attachment_path(stored_name: &str, dir: &Path) -> Option<PathBuf> {
+    let (folder, file_name) = stored_name.split_once('/')?;
+    if folder.parse::<i64>().is_err()
+        || file_name.is_empty()
+        || file_name.contains(['/', '\\', ':'])
+        || file_name.contains("..")
+    {
+        return None;
+    }
+
+    Some(dir.join(folder).join(file_name))
+}
+
+fn remove_attachment_file(stored_name: &str, dir: &Path) -> Result<(), String> {
+    let Some(path) = stored_attachment_path(stored_name, dir) else {
+        return Ok(());
+    };
+
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(_) => Err(
+            "Attachment was removed, but JobSentinel could not remove its local file copy."
+                .to_string(),
+        ),
+    }
+}
+
+/// Save `contents` as a text file attached to an application
+///
+/// Used for drafts JobSentinel writes itself, such as cover letters.
+pub(crate) async fn attach_text_file(
+    state: &AppState,
+    application_id: i64,
+    kind: &str,
+    file_name: &str,
+    contents: &str,
+) -> Result<ApplicationAttachment, String> {
+    let stored_name = attachment_stored_name(application_id, Path::new(file_name));
+    let dir = attachments_dir();
+    let destination = stored_attachment_path(&stored_name, &dir)
+        .ok_or_else(|| "Could not prepare local attachment storage.".to_string())?;
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|_| "Could not prepare local attachment storage.".to_string())?;
+    }
+    std::fs::write(&destination, contents)
+        .map_err(|_| "Could not save the attachment.".to_string())?;
+
+    let tracker = state.database.application_tracker();
+    match tracker
+        .add_attachment(
+            application_id,
+            kind,
+            file_name,
+            &stored_name,
+            contents.len() as i64,
+        )
+        .await
+    {
+        Ok(attachment) => Ok(attachment),
+        Err(e) => {
+            remove_attachment_file(&stored_name, &dir).ok();
+            Err(user_friendly_error("Failed to attach file", e))
+        }
+    }
+}
+
+/// Pick a file and attach a copy of it to an application
+///
+/// Returns `None` when the user cancels. Attaching another file of the same
+/// kind adds a new version and keeps the earlier ones.
+#[tauri::command]
+pub(crate) async fn select_and_attach_file(
+    app: tauri::AppHandle,
+    application_id: i64,
+    kind: String,
+    state: State<'_, AppState>,
+) -> Result<Option<ApplicationAttachment>, String> {
+    if !ATTACHMENT_KINDS.contains(&kind.as_str()) {
+        return Err("Choose a resume, cover letter, take-home, or other attachment.".to_string());
+    }
+
+    let Some(file_path) = app
+        .dialog()
+        .file()
+        .add_filter("Attachment", SUPPORTED_ATTACHMENT_EXTENSIONS)
+        .blocking_pick_file()
+    else {
+        return Ok(None);
+    };
+
+    let source_path = file_path
+        .into_path()
+        .map_err(|_| "Could not read the selected file.".to_string())?;
+    let size_bytes = validate_selected_attachment(&source_path)?;
+    tracing::info!(
+        application_id,
+        kind = %kind,
+        file_path = %path_label_for_logging(&source_path),
+        "Command: select_and_attach_file"
+    );
+
+    let file_name = source_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("attachment")
+        .to_string();
+    let stored_name = attachment_stored_name(application_id, &source_path);
+    let dir = attachments_dir();
+    let destination = stored_attachment_path(&stored_name, &dir)
+        .ok_or_else(|| "Could not prepare local attachment storage.".to_string())?;
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|_| "Could not prepare local attachment storage.".to_string())?;
+    }
+    std::fs::copy(&source_path, &destination)
+        .map_err(|_| "Could not copy the selected file.".to_string())?;
+
+    let tracker = state.database.application_tracker();
+    match tracker
+        .add_attachment(
+            application_id,
+            &kind,
+            &file_name,
+            &stored_name,
+            size_bytes as i64,
+        )
+        .await
+    {
+        Ok(attachment) => Ok(Some(attachment)),
+        Err(e) => {
+            remove_attachment_file(&stored_name, &dir).ok();
+            Err(user_friendly_error("Failed to attach file", e))
+        }
+    }
+}
+
+/// Files attached to an application, newest version of each kind first
+#[tauri::command]
+pub(crate) async fn get_attachments(
+    application_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<ApplicationAttachment>, String> {
+    tracing::info!(application_id, "Command: get_attachments");
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .get_attachments(application_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get attachments", e))
+}
+
+/// Open an attachment's stored copy in the default app for its file type
+#[tauri::command]
+#[allow(deprecated)]
+pub(crate) async fn open_attachment(
+    app: tauri::AppHandle,
+    attachment_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!("Command: open_attachment (id: {})", attachment_id);
+
+    let tracker = state.database.application_tracker();
+    let attachment = tracker
+        .get_attachment(attachment_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to open attachment", e))?
+        .ok_or_else(|| "Attachment not found".to_string())?;
+    let path = stored_attachment_path(&attachment.stored_name, &attachments_dir())
+        .filter(|path| path.is_file())
+        .ok_or_else(|| {
+            "The attached file is missing from JobSentinel's data folder. Attach it again."
+                .to_string()
+        })?;
+
+    app.shell().open(path.to_string_lossy(), None).map_err(|_| {
+        "Could not open the attachment. Check that an app can open this file type.".to_string()
+    })
+}
+
+/// Delete an attachment and its stored copy
+#[tauri::command]
+pub(crate) async fn delete_attachment(
+    attachment_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!("Command: delete_attachment (id: {})", attachment_id);
+
+    let tracker = state.database.application_tracker();
+    let attachment = tracker
+        .delete_attachment(attachment_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to delete attachment", e))?
+        .ok_or_else(|| "Attachment not found".to_string())?;
+
+    remove_attachment_file(&attachment.stored_name, &attachments_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_names_stay_inside_the_application_folder() {
+        let stored_name = attachment_stored_name(7, Path::new("/tmp/My Resume (final).PDF"));
+        let (folder, file_name) = stored_name.split_once('/').unwrap();
+        assert_eq!(folder, "7");
+        assert!(file_name.ends_with("--My-Resume-final.pdf"));
+
+        let dir = Path::new("/data/application-attachments");
+        assert_eq!(
+            stored_attachment_path(&stored_name, dir),
+            Some(dir.join(&stored_name))
+        );
+        assert!(stored_attachment_path("7/../../secrets.txt", dir).is_none());
+        assert!(stored_attachment_path("../7/a.pdf", dir).is_none());
+        assert!(stored_attachment_path("7/sub/a.pdf", dir).is_none());
+        assert!(stored_attachment_path("a.pdf", dir).is_none());
+    }
+
+    #[test]
+    fn only_document_like_files_can_be_attached() {
+        assert_eq!(
+            supported_attachment_extension(Path::new("letter.DOCX")).as_deref(),
+            Some("docx")
+        );
+        assert!(supported_attachment_extension(Path::new("setup.exe")).is_none());
+        assert!(supported_attachment_extension(Path::new("notes")).is_none());
+    }
+}
@@ -3,7 +3,7 @@
 //! Commands for Application Assist with user-controlled submit.
 //!
 //! **Features:**
-//! - Profile management (contact info, work authorization)
+//! - Profile management (named profiles, contact info, work authorization)
 //! - Screening answer configuration
 //! - Automation attempt tracking
 //! - ATS platform detection
@@ -11,7 +11,8 @@
 #[cfg(test)]
 use crate::application::automation::AutomationStatus;
 use crate::application::automation::{
-    ApplicationProfileInput, AtsDetector, AtsPlatform, AutomationStats, ProfileManager,
+    ApplicationProfile, ApplicationProfileInput, AtsDetector, AtsPlatform, AutomationStats,
+    ProfileManager,
 };
 use crate::bootstrap::AppState;
 use crate::desktop::sanitize_url_for_logging;
@@ -29,10 +30,17 @@ pub(crate) use responses::{
 
 #[path = "automation_browser_commands.rs"]
 pub(crate) mod automation_browser_commands;
+pub(crate) mod dry_run;
+pub(crate) mod permissions;
 mod profile_resume;
+pub(crate) mod profiles;
+pub(crate) mod quota;
+pub(crate) mod review;
 
 #[cfg(test)]
-use automation_browser_commands::{application_page_matches_platform, prepare_form_target};
+use automation_browser_commands::{
+    application_page_matches_platform, prepare_form_target, review_screenshot_path, uploads_resume,
+};
 #[cfg(test)]
 use profile_resume::trusted_application_resume_path;
 use profile_resume::{
@@ -46,6 +54,17 @@ fn has_stored_path(path: Option<&str>) -> bool {
     path.is_some_and(|path| !path.trim().is_empty())
 }
 
+/// The chosen profile, or the default profile when none is chosen
+async fn find_profile(
+    manager: &ProfileManager,
+    profile_id: Option<i64>,
+) -> anyhow::Result<Option<ApplicationProfile>> {
+    match profile_id {
+        Some(id) => manager.get_profile_by_id(id).await,
+        None => manager.get_profile().await,
+    }
+}
+
 // ============================================================================
 // Profile Management Commands
 // ============================================================================
@@ -58,36 +77,50 @@ pub(crate) async fn select_application_resume_file(
     select_application_resume_file_impl(app).await
 }
 
-/// Upsert (create or update) the application profile
+/// Upsert (create or update) an application profile
+///
+/// Updates the profile with `profile_id`, or the default profile when it is
+/// omitted. The first profile is created when none exists yet.
 #[tauri::command]
 pub(crate) async fn upsert_application_profile(
     input: ApplicationProfileInput,
+    profile_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<i64, String> {
-    tracing::info!("Command: upsert_application_profile");
+    tracing::info!(?profile_id, "Command: upsert_application_profile");
 
     let manager = state.database.profile_manager();
     let managed_resume_dir = application_resume_dir();
-    upsert_application_profile_with_resume_cleanup(input, &manager, &managed_resume_dir).await
+    upsert_application_profile_with_resume_cleanup(input, profile_id, &manager, &managed_resume_dir)
+        .await
 }
 
 async fn upsert_application_profile_with_resume_cleanup(
     input: ApplicationProfileInput,
+    profile_id: Option<i64>,
     manager: &ProfileManager,
     managed_resume_dir: &Path,
 ) -> Result<i64, String> {
-    let previous_resume_path = manager
-        .get_profile()
+    profiles::validate_profile_name(input.name.as_deref())?;
+    let previous_profile = find_profile(manager, profile_id)
         .await
-        .map_err(|e| user_friendly_error("Failed to save profile", e))?
-        .and_then(|profile| profile.resume_file_path);
+        .map_err(|e| user_friendly_error("Failed to save profile", e))?;
+    if profile_id.is_some() && previous_profile.is_none() {
+        return Err("Application profile not found".to_string());
+    }
+    let previous_resume_path = previous_profile.and_then(|profile| profile.resume_file_path);
 
     let input = prepare_application_profile_resume_input(input, managed_resume_dir)?;
     let requested_resume_change =
         input.clear_resume_file.unwrap_or(false) || input.resume_file_path.is_some();
     let next_resume_path = input.resume_file_path.clone();
 
-    match manager.upsert_profile(&input).await {
+    let saved = match profile_id {
+        Some(id) => manager.update_profile(id, &input).await.map(|_| id),
+        None => manager.upsert_profile(&input).await,
+    };
+
+    match saved {
         Ok(profile_id) => {
             if requested_resume_change
                 && previous_resume_path.as_deref().map(str::trim)
@@ -108,15 +141,16 @@ async fn upsert_application_profile_with_resume_cleanup(
     }
 }
 
-/// Get the current application profile
+/// Get an application profile, or the default profile when none is chosen
 #[tauri::command]
 pub(crate) async fn get_application_profile(
+    profile_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<Option<ApplicationProfileResponse>, String> {
-    tracing::info!("Command: get_application_profile");
+    tracing::info!(?profile_id, "Command: get_application_profile");
 
     let manager = state.database.profile_manager();
-    match manager.get_profile().await {
+    match find_profile(&manager, profile_id).await {
         Ok(Some(profile)) => Ok(Some(ApplicationProfileResponse::from(profile))),
         Ok(None) => Ok(None),
         Err(e) => Err(user_friendly_error("Failed to get profile", e)),
@@ -155,37 +189,65 @@ pub(crate) async fn get_application_profile_preview(
 // ============================================================================
 
 /// Add or update a screening answer pattern
+///
+/// With a `profile_id` the answer is used only by that profile, ahead of any
+/// shared answer for the same question. Without one it is shared.
 #[tauri::command]
 pub(crate) async fn upsert_screening_answer(
     question_pattern: String,
     answer: String,
     answer_type: String,
     notes: Option<String>,
+    profile_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     tracing::info!(
         question_pattern_chars = question_pattern.chars().count(),
         answer_type,
         has_notes = notes.is_some(),
+        ?profile_id,
         "Command: upsert_screening_answer"
     );
 
     let manager = state.database.profile_manager();
-    manager
-        .upsert_screening_answer(&question_pattern, &answer, &answer_type, notes.as_deref())
-        .await
-        .map_err(|e| user_friendly_error("Failed to save screening answer", e))
+    let saved = match profile_id {
+        Some(profile_id) => {
+            manager
+                .upsert_profile_screening_answer(
+                    profile_id,
+                    &question_pattern,
+                    &answer,
+                    &answer_type,
+                    notes.as_deref(),
+                )
+                .await
+        }
+        None => {
+            manager
+                .upsert_screening_answer(&question_pattern, &answer, &answer_type, notes.as_deref())
+                .await
+        }
+    };
+    saved.map_err(|e| user_friendly_error("Failed to save screening answer", e))
 }
 
-/// Get all screening answers
+/// Get screening answers
+///
+/// With a `profile_id`, returns the answers that profile fills forms with:
+/// its own answers first, then shared ones. Otherwise returns every answer.
 #[tauri::command]
 pub(crate) async fn get_screening_answers(
+    profile_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<Vec<ScreeningAnswerResponse>, String> {
-    tracing::info!("Command: get_screening_answers");
+    tracing::info!(?profile_id, "Command: get_screening_answers");
 
     let manager = state.database.profile_manager();
-    match manager.get_screening_answers().await {
+    let answers = match profile_id {
+        Some(profile_id) => manager.get_screening_answers_for_profile(profile_id).await,
+        None => manager.get_screening_answers().await,
+    };
+    match answers {
         Ok(answers) => Ok(answers
             .into_iter()
             .map(ScreeningAnswerResponse::from)
@@ -262,6 +324,7 @@ pub(crate) async fn approve_automation_attempt(
 ) -> Result<(), String> {
     tracing::info!("Command: approve_automation_attempt (id: {})", attempt_id);
 
+    dry_run::reject_dry_run_attempt(&state, attempt_id).await?;
     let manager = state.database.automation_manager();
     manager
         .approve_attempt(attempt_id)
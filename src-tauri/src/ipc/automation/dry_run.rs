@@ -0,0 +1,59 @@
+//! Dry-run mode for Application Assist.
+//!
+//! A dry run detects the site, opens and fills the form, and records what was
+//! entered, then closes the form. It lets the user check what would be sent
+//! before preparing forms they can submit.
+
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+const DRY_RUN_ATTEMPT_MESSAGE: &str =
+    "This form was a dry run and was closed without being sent. Turn off dry-run mode and prepare the form again to apply.";
+
+/// Refuse to approve or record as submitted an attempt that was a dry run
+pub(super) async fn reject_dry_run_attempt(
+    state: &AppState,
+    attempt_id: i64,
+) -> Result<(), String> {
+    let attempt = state
+        .database
+        .automation_manager()
+        .get_attempt(attempt_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get attempt", e))?;
+    if attempt.dry_run {
+        Err(DRY_RUN_ATTEMPT_MESSAGE.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether dry-run mode is on
+#[tauri::command]
+pub(crate) async fn get_automation_dry_run(state: State<'_, AppState>) -> Result<bool, String> {
+    tracing::info!("Command: get_automation_dry_run");
+
+    state
+        .database
+        .automation_manager()
+        .dry_run_enabled()
+        .await
+        .map_err(|e| user_friendly_error("Failed to check dry-run mode", e))
+}
+
+/// Turn dry-run mode on or off
+#[tauri::command]
+pub(crate) async fn set_automation_dry_run(
+    enabled: bool,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!(enabled, "Command: set_automation_dry_run");
+
+    state
+        .database
+        .automation_manager()
+        .set_dry_run_enabled(enabled)
+        .await
+        .map_err(|e| user_friendly_error("Failed to update dry-run mode", e))
+}
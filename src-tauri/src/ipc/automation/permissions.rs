@@ -0,0 +1,144 @@
+//! Per-domain permission grants for Application Assist.
+//!
+//! The browser never opens, fills, or attaches a resume on a host the user
+//! has not allowed. Grants are stored locally and can be revoked at any time.
+
+use crate::application::automation::AutomationPermission;
+use crate::bootstrap::AppState;
+use crate::desktop::validate_external_https_url;
+use crate::ipc::errors::user_friendly_error;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+pub(super) const AUTOMATION_PERMISSION_REQUIRED: &str =
+    "Allow Application Assist on this site before preparing the form.";
+
+/// Permission state for one site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AutomationPermissionStatus {
+    pub domain: String,
+    pub granted: bool,
+}
+
+/// Stored permission grant (frontend-friendly)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AutomationPermissionResponse {
+    pub domain: String,
+    pub granted_at: String,
+    pub last_used_at: Option<String>,
+}
+
+impl From<AutomationPermission> for AutomationPermissionResponse {
+    fn from(permission: AutomationPermission) -> Self {
+        Self {
+            domain: permission.domain,
+            granted_at: permission.granted_at.to_rfc3339(),
+            last_used_at: permission.last_used_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
+
+/// Host that a permission grant covers for `url`.
+pub(super) fn permission_domain_for_url(url: &str) -> Result<String, String> {
+    validate_external_https_url(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+        .ok_or_else(|| "Cannot use that link for Application Assist.".to_string())
+}
+
+/// Fail unless the user has allowed Application Assist on `url`'s host.
+pub(super) async fn require_automation_permission(
+    state: &AppState,
+    url: &str,
+) -> Result<String, String> {
+    let domain = permission_domain_for_url(url)?;
+    let granted = state
+        .database
+        .automation_permission_manager()
+        .is_granted(&domain)
+        .await
+        .map_err(|e| user_friendly_error("Failed to check site permission", e))?;
+
+    if granted {
+        Ok(domain)
+    } else {
+        Err(AUTOMATION_PERMISSION_REQUIRED.to_string())
+    }
+}
+
+/// Check whether Application Assist may act on the site for `url`
+#[tauri::command]
+pub(crate) async fn check_automation_permission(
+    url: String,
+    state: State<'_, AppState>,
+) -> Result<AutomationPermissionStatus, String> {
+    let domain = permission_domain_for_url(&url)?;
+    let granted = state
+        .database
+        .automation_permission_manager()
+        .is_granted(&domain)
+        .await
+        .map_err(|e| user_friendly_error("Failed to check site permission", e))?;
+
+    Ok(AutomationPermissionStatus { domain, granted })
+}
+
+/// Allow Application Assist to open, fill, and attach a resume on the site for `url`
+#[tauri::command]
+pub(crate) async fn grant_automation_permission(
+    url: String,
+    state: State<'_, AppState>,
+) -> Result<AutomationPermissionStatus, String> {
+    let domain = permission_domain_for_url(&url)?;
+    tracing::info!(domain = %domain, "Command: grant_automation_permission");
+
+    state
+        .database
+        .automation_permission_manager()
+        .grant(&domain)
+        .await
+        .map_err(|e| user_friendly_error("Failed to save site permission", e))?;
+
+    Ok(AutomationPermissionStatus {
+        domain,
+        granted: true,
+    })
+}
+
+/// List every site Application Assist is allowed to act on
+#[tauri::command]
+pub(crate) async fn list_automation_permissions(
+    state: State<'_, AppState>,
+) -> Result<Vec<AutomationPermissionResponse>, String> {
+    let permissions = state
+        .database
+        .automation_permission_manager()
+        .list()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load site permissions", e))?;
+
+    Ok(permissions
+        .into_iter()
+        .map(AutomationPermissionResponse::from)
+        .collect())
+}
+
+/// Revoke Application Assist permission for a site
+///
+/// Returns `false` when the site had no grant.
+#[tauri::command]
+pub(crate) async fn revoke_permission(
+    domain: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(domain = %domain, "Command: revoke_permission");
+
+    state
+        .database
+        .automation_permission_manager()
+        .revoke(&domain)
+        .await
+        .map_err(|e| user_friendly_error("Failed to revoke site permission", e))
+}
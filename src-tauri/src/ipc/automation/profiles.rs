@@ -0,0 +1,142 @@
+//! Named application profiles.
+//!
+//! A user can keep several profiles, such as one for SRE roles and one for
+//! management roles, each with its own resume, links, and screening answers.
+//! The default profile fills forms unless another one is chosen.
+
+use super::profile_resume::{
+    application_resume_dir, delete_managed_application_resume_file,
+    prepare_application_profile_resume_input,
+};
+use crate::application::automation::{ApplicationProfileInput, ApplicationProfileSummary};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+const MAX_PROFILE_NAME_CHARS: usize = 80;
+
+pub(super) fn validate_profile_name(name: Option<&str>) -> Result<(), String> {
+    let Some(name) = name.map(str::trim) else {
+        return Ok(());
+    };
+    if name.is_empty() {
+        return Err("Give the profile a name.".to_string());
+    }
+    if name.chars().count() > MAX_PROFILE_NAME_CHARS {
+        return Err(format!(
+            "Keep the profile name under {MAX_PROFILE_NAME_CHARS} characters."
+        ));
+    }
+    Ok(())
+}
+
+/// List saved application profiles, default first
+#[tauri::command]
+pub(crate) async fn list_application_profiles(
+    state: State<'_, AppState>,
+) -> Result<Vec<ApplicationProfileSummary>, String> {
+    tracing::info!("Command: list_application_profiles");
+
+    state
+        .database
+        .profile_manager()
+        .list_profiles()
+        .await
+        .map_err(|e| user_friendly_error("Failed to list profiles", e))
+}
+
+/// Create another named application profile
+///
+/// The first profile created becomes the default.
+#[tauri::command]
+pub(crate) async fn create_application_profile(
+    input: ApplicationProfileInput,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    tracing::info!("Command: create_application_profile");
+
+    validate_profile_name(input.name.as_deref())?;
+    let managed_resume_dir = application_resume_dir();
+    let input = prepare_application_profile_resume_input(input, &managed_resume_dir)?;
+
+    match state
+        .database
+        .profile_manager()
+        .create_profile(&input)
+        .await
+    {
+        Ok(profile_id) => Ok(profile_id),
+        Err(error) => {
+            delete_managed_application_resume_file(
+                input.resume_file_path.as_deref(),
+                &managed_resume_dir,
+            )
+            .ok();
+            Err(user_friendly_error("Failed to create profile", error))
+        }
+    }
+}
+
+/// Use a profile when an application does not name one
+#[tauri::command]
+pub(crate) async fn set_default_application_profile(
+    profile_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!(profile_id, "Command: set_default_application_profile");
+
+    let updated = state
+        .database
+        .profile_manager()
+        .set_default_profile(profile_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to set default profile", e))?;
+    if updated {
+        Ok(())
+    } else {
+        Err("Application profile not found".to_string())
+    }
+}
+
+/// Delete a profile, its own screening answers, and its saved resume copy
+///
+/// Deleting the default profile makes the oldest remaining profile the default.
+#[tauri::command]
+pub(crate) async fn delete_application_profile(
+    profile_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!(profile_id, "Command: delete_application_profile");
+
+    let profile = state
+        .database
+        .profile_manager()
+        .delete_profile(profile_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to delete profile", e))?
+        .ok_or_else(|| "Application profile not found".to_string())?;
+
+    delete_managed_application_resume_file(
+        profile.resume_file_path.as_deref(),
+        &application_resume_dir(),
+    )
+    .map_err(|_| {
+        "Profile was deleted, but JobSentinel could not remove its local resume copy.".to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_names_must_be_present_and_short() {
+        assert!(validate_profile_name(None).is_ok());
+        assert!(validate_profile_name(Some("SRE profile")).is_ok());
+        assert_eq!(
+            validate_profile_name(Some("   ")).unwrap_err(),
+            "Give the profile a name."
+        );
+        assert!(validate_profile_name(Some(&"a".repeat(81))).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! Daily and weekly limits on prepared application forms.
+//!
+//! The limits come from the application profile's review pace. A form is not
+//! prepared once the day's or the week's limit is used up.
+
+use crate::application::automation::AutomationQuota;
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use chrono::Utc;
+use tauri::State;
+
+/// Daily limit used before an application profile is saved
+const DEFAULT_MAX_APPLICATIONS_PER_DAY: i32 = 10;
+
+pub(super) async fn automation_quota(state: &AppState) -> Result<AutomationQuota, String> {
+    let (daily_limit, weekly_limit) = state
+        .database
+        .profile_manager()
+        .get_profile()
+        .await
+        .map_err(|e| user_friendly_error("Failed to check application limits", e))?
+        .map_or((DEFAULT_MAX_APPLICATIONS_PER_DAY, None), |profile| {
+            (
+                profile.max_applications_per_day,
+                profile.max_applications_per_week,
+            )
+        });
+
+    state
+        .database
+        .automation_manager()
+        .get_quota(daily_limit, weekly_limit, Utc::now())
+        .await
+        .map_err(|e| user_friendly_error("Failed to check application limits", e))
+}
+
+/// Error for a form that would go over the daily or weekly limit
+pub(super) fn quota_reached_message(quota: &AutomationQuota) -> String {
+    let weekly_used_up = quota
+        .weekly_limit
+        .is_some_and(|limit| quota.used_this_week >= limit);
+    if weekly_used_up {
+        format!(
+            "You have prepared {} application forms this week, your weekly limit. Try again next week, or change the limit in Application Assist.",
+            quota.used_this_week
+        )
+    } else {
+        format!(
+            "You have prepared {} application forms today, your daily limit. Try again tomorrow, or change the limit in Application Assist.",
+            quota.used_today
+        )
+    }
+}
+
+/// Forms that can still be prepared today and this week, and when more open up
+#[tauri::command]
+pub(crate) async fn get_automation_quota(
+    state: State<'_, AppState>,
+) -> Result<AutomationQuota, String> {
+    tracing::info!("Command: get_automation_quota");
+
+    automation_quota(&state).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn quota(used_today: i64, used_this_week: i64, weekly_limit: Option<i64>) -> AutomationQuota {
+        AutomationQuota {
+            daily_limit: 5,
+            weekly_limit,
+            used_today,
+            used_this_week,
+            remaining: 0,
+            resets_at: Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn quota_message_names_the_limit_that_ran_out() {
+        assert!(quota_reached_message(&quota(5, 12, Some(20)))
+            .starts_with("You have prepared 5 application forms today"));
+        assert!(quota_reached_message(&quota(5, 12, None)).contains("tomorrow"));
+        assert!(quota_reached_message(&quota(2, 20, Some(20)))
+            .starts_with("You have prepared 20 application forms this week"));
+    }
+}
@@ -6,6 +6,8 @@ use crate::application::automation::{
 fn profile_with_resume_path(path: Option<String>) -> ApplicationProfile {
     ApplicationProfile {
         id: 1,
+        name: "My profile".to_string(),
+        is_default: true,
         full_name: "Jordan Lee".to_string(),
         email: "jordan@example.com".to_string(),
         phone: None,
@@ -19,6 +21,7 @@ fn profile_with_resume_path(path: Option<String>) -> ApplicationProfile {
         us_work_authorized: true,
         requires_sponsorship: false,
         max_applications_per_day: 10,
+        max_applications_per_week: None,
         require_manual_approval: true,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
@@ -53,7 +56,6 @@ fn application_profile_response_omits_unused_backend_metadata() {
     let object = json.as_object().unwrap();
 
     assert!(!object.contains_key("id"));
-    assert!(!object.contains_key("defaultResumeId"));
     assert!(!object.contains_key("defaultCoverLetterTemplate"));
     assert!(!object.contains_key("createdAt"));
     assert!(!object.contains_key("updatedAt"));
@@ -72,6 +74,7 @@ fn application_profile_response_handles_windows_resume_paths() {
 
 fn valid_profile_input() -> ApplicationProfileInput {
     ApplicationProfileInput {
+        name: None,
         full_name: "Jordan Lee".to_string(),
         email: "jordan@example.com".to_string(),
         phone: None,
@@ -87,6 +90,7 @@ fn valid_profile_input() -> ApplicationProfileInput {
         us_work_authorized: true,
         requires_sponsorship: false,
         max_applications_per_day: 10,
+        max_applications_per_week: None,
         require_manual_approval: true,
     }
 }
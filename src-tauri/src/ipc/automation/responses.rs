@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ApplicationProfileResponse {
+    pub name: String,
+    pub is_default: bool,
     pub full_name: String,
     pub email: String,
     pub phone: Option<String>,
@@ -13,17 +15,22 @@ pub(crate) struct ApplicationProfileResponse {
     pub github_url: Option<String>,
     pub portfolio_url: Option<String>,
     pub website_url: Option<String>,
+    /// Uploaded resume this profile applies with, for resume suggestions
+    pub default_resume_id: Option<i64>,
     pub has_resume_file: bool,
     pub resume_file_name: Option<String>,
     pub us_work_authorized: bool,
     pub requires_sponsorship: bool,
     pub max_applications_per_day: i32,
+    pub max_applications_per_week: Option<i32>,
     pub require_manual_approval: bool,
 }
 
 impl From<ApplicationProfile> for ApplicationProfileResponse {
     fn from(p: ApplicationProfile) -> Self {
         Self {
+            name: p.name,
+            is_default: p.is_default,
             full_name: p.full_name,
             email: p.email,
             phone: p.phone,
@@ -31,6 +38,7 @@ impl From<ApplicationProfile> for ApplicationProfileResponse {
             github_url: p.github_url,
             portfolio_url: p.portfolio_url,
             website_url: p.website_url,
+            default_resume_id: p.default_resume_id,
             has_resume_file: p
                 .resume_file_path
                 .as_deref()
@@ -42,6 +50,7 @@ impl From<ApplicationProfile> for ApplicationProfileResponse {
             us_work_authorized: p.us_work_authorized,
             requires_sponsorship: p.requires_sponsorship,
             max_applications_per_day: p.max_applications_per_day,
+            max_applications_per_week: p.max_applications_per_week,
             require_manual_approval: p.require_manual_approval,
         }
     }
@@ -83,6 +92,7 @@ impl From<ApplicationProfile> for ApplicationProfilePreviewResponse {
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ScreeningAnswerResponse {
     pub id: i64,
+    pub profile_id: Option<i64>,
     pub question_pattern: String,
     pub answer: String,
     pub answer_type: Option<String>,
@@ -99,6 +109,7 @@ impl From<ScreeningAnswer> for ScreeningAnswerResponse {
     fn from(a: ScreeningAnswer) -> Self {
         Self {
             id: a.id,
+            profile_id: a.profile_id,
             question_pattern: a.question_pattern,
             answer: a.answer,
             answer_type: a.answer_type,
@@ -126,6 +137,8 @@ pub(crate) struct AttemptResponse {
     pub has_screenshot: bool,
     pub has_confirmation_screenshot: bool,
     pub automation_duration_ms: Option<i64>,
+    pub profile_id: Option<i64>,
+    pub dry_run: bool,
     pub user_approved: bool,
     pub submitted_at: Option<String>,
     pub created_at: String,
@@ -147,6 +160,8 @@ impl From<ApplicationAttempt> for AttemptResponse {
             has_screenshot,
             has_confirmation_screenshot,
             automation_duration_ms: a.automation_duration_ms,
+            profile_id: a.profile_id,
+            dry_run: a.dry_run,
             user_approved: a.user_approved,
             submitted_at: a.submitted_at.map(|dt| dt.to_rfc3339()),
             created_at: a.created_at.to_rfc3339(),
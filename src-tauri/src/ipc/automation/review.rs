@@ -0,0 +1,133 @@
+//! Review data for a filled application form.
+//!
+//! Before approving an attempt, the user checks what was entered, the
+//! screenshots taken of the form, and the questions left for them to answer.
+
+use super::automation_browser_commands::screenshots_dir;
+use crate::application::automation::AttemptFillSummary;
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Screenshots of an attempt, as base64-encoded PNG data
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AttemptScreenshotsResponse {
+    /// The filled form, taken before the user submits it
+    pub review: Option<String>,
+    /// The confirmation page, taken after submission
+    pub confirmation: Option<String>,
+}
+
+/// A stored screenshot path, if it points at a file in the screenshots folder
+fn trusted_screenshot_path(stored_path: Option<&str>, dir: &Path) -> Option<PathBuf> {
+    let stored_path = stored_path.map(str::trim).filter(|path| !path.is_empty())?;
+    let canonical_dir = dir.canonicalize().ok()?;
+    let canonical_path = Path::new(stored_path).canonicalize().ok()?;
+    if canonical_path.parent()? != canonical_dir || !canonical_path.is_file() {
+        return None;
+    }
+    Some(canonical_path)
+}
+
+async fn read_screenshot(stored_path: Option<&str>, dir: &Path) -> Option<String> {
+    let path = trusted_screenshot_path(stored_path, dir)?;
+    match tokio::fs::read(&path).await {
+        Ok(bytes) => Some(STANDARD.encode(bytes)),
+        Err(e) => {
+            tracing::warn!(
+                error = %user_friendly_error("Failed to read form screenshot", e),
+                "Skipped form screenshot"
+            );
+            None
+        }
+    }
+}
+
+async fn fill_summary(state: &AppState, attempt_id: i64) -> Result<AttemptFillSummary, String> {
+    state
+        .database
+        .automation_manager()
+        .get_fill_summary(attempt_id)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| user_friendly_error("Failed to load form review", e))
+}
+
+/// What was entered into an attempt's form, keyed by field name or question
+///
+/// Attempts filled before summaries were kept come back empty.
+#[tauri::command]
+pub(crate) async fn get_attempt_fill_summary(
+    attempt_id: i64,
+    state: State<'_, AppState>,
+) -> Result<AttemptFillSummary, String> {
+    tracing::info!(attempt_id, "Command: get_attempt_fill_summary");
+
+    fill_summary(&state, attempt_id).await
+}
+
+/// Questions on an attempt's form that had no saved answer
+#[tauri::command]
+pub(crate) async fn get_attempt_unanswered_questions(
+    attempt_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    tracing::info!(attempt_id, "Command: get_attempt_unanswered_questions");
+
+    Ok(fill_summary(&state, attempt_id).await?.unanswered_questions)
+}
+
+/// Screenshots of an attempt's form and confirmation page
+///
+/// Only files in JobSentinel's screenshot folder are read. A screenshot that
+/// was never taken, or has since been removed, comes back empty.
+#[tauri::command]
+pub(crate) async fn get_attempt_screenshots(
+    attempt_id: i64,
+    state: State<'_, AppState>,
+) -> Result<AttemptScreenshotsResponse, String> {
+    tracing::info!(attempt_id, "Command: get_attempt_screenshots");
+
+    let attempt = state
+        .database
+        .automation_manager()
+        .get_attempt(attempt_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get attempt", e))?;
+    let dir = screenshots_dir();
+
+    Ok(AttemptScreenshotsResponse {
+        review: read_screenshot(attempt.screenshot_path.as_deref(), &dir).await,
+        confirmation: read_screenshot(attempt.confirmation_screenshot_path.as_deref(), &dir).await,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_screenshots_in_the_screenshot_folder_are_read() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("automation-screenshots");
+        std::fs::create_dir(&dir).unwrap();
+        let inside = dir.join("attempt-1.png");
+        let outside = root.path().join("secrets.txt");
+        std::fs::write(&inside, b"png").unwrap();
+        std::fs::write(&outside, b"secret").unwrap();
+
+        assert_eq!(
+            trusted_screenshot_path(inside.to_str(), &dir),
+            Some(inside.canonicalize().unwrap())
+        );
+        assert_eq!(trusted_screenshot_path(outside.to_str(), &dir), None);
+        let escaped = dir.join("..").join("secrets.txt");
+        assert_eq!(trusted_screenshot_path(escaped.to_str(), &dir), None);
+        assert_eq!(trusted_screenshot_path(Some("  "), &dir), None);
+        assert_eq!(trusted_screenshot_path(None, &dir), None);
+    }
+}
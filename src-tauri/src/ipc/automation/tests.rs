@@ -3,6 +3,7 @@ use crate::application::automation::ApplicationAttempt;
 
 fn valid_application_profile_input() -> ApplicationProfileInput {
     ApplicationProfileInput {
+        name: None,
         full_name: "Jordan Lee".to_string(),
         email: "jordan@example.com".to_string(),
         phone: None,
@@ -18,6 +19,7 @@ fn valid_application_profile_input() -> ApplicationProfileInput {
         us_work_authorized: true,
         requires_sponsorship: false,
         max_applications_per_day: 10,
+        max_applications_per_week: None,
         require_manual_approval: true,
     }
 }
@@ -117,6 +119,19 @@ fn application_page_match_rejects_unknown_or_lookalike_url() {
     ));
 }
 
+#[test]
+fn only_greenhouse_and_lever_forms_attach_the_saved_resume() {
+    assert!(uploads_resume(&AtsPlatform::Greenhouse));
+    assert!(uploads_resume(&AtsPlatform::Lever));
+    assert!(!uploads_resume(&AtsPlatform::Workday));
+}
+
+#[test]
+fn review_screenshots_are_named_by_attempt() {
+    let dir = std::path::Path::new("/data/automation-screenshots");
+    assert_eq!(review_screenshot_path(dir, 42), dir.join("attempt-42.png"));
+}
+
 #[test]
 fn attempt_response_exposes_screenshot_presence_not_paths() {
     let attempt = ApplicationAttempt {
@@ -129,6 +144,8 @@ fn attempt_response_exposes_screenshot_presence_not_paths() {
         screenshot_path: Some("private/apply.png".to_string()),
         confirmation_screenshot_path: None,
         automation_duration_ms: Some(1200),
+        profile_id: None,
+        dry_run: false,
         user_approved: false,
         submitted_at: None,
         created_at: chrono::Utc::now(),
@@ -173,6 +190,7 @@ async fn upsert_application_profile_cleans_replaced_and_cleared_managed_resume_f
             resume_file_token: Some(new_token.to_string()),
             ..valid_application_profile_input()
         },
+        None,
         &manager,
         managed_dir.path(),
     )
@@ -196,6 +214,7 @@ async fn upsert_application_profile_cleans_replaced_and_cleared_managed_resume_f
             clear_resume_file: Some(true),
             ..valid_application_profile_input()
         },
+        None,
         &manager,
         managed_dir.path(),
     )
@@ -213,3 +232,13 @@ async fn upsert_application_profile_cleans_replaced_and_cleared_managed_resume_f
         None
     );
 }
+
+#[test]
+fn automation_permission_domain_is_the_lowercase_https_host() {
+    assert_eq!(
+        permissions::permission_domain_for_url("https://Boards.Greenhouse.io/acme/jobs/1").unwrap(),
+        "boards.greenhouse.io"
+    );
+    assert!(permissions::permission_domain_for_url("http://boards.greenhouse.io/acme").is_err());
+    assert!(permissions::permission_domain_for_url("not a url").is_err());
+}
@@ -1,19 +1,27 @@
 use crate::application::automation::{
-    AtsDetector, AtsPlatform, AutomationStatus, BrowserManager, FillResult, FormFiller,
+    ApplicationProfile, AtsDetector, AtsPlatform, AutomationPage, AutomationStatus, BrowserManager,
+    FillResult, FormFiller,
 };
 use crate::bootstrap::AppState;
+use crate::desktop;
 use crate::desktop::sanitize_url_for_logging;
 #[cfg(test)]
 use crate::desktop::validate_external_https_url;
 use crate::desktop::validate_external_https_url_for_fetch;
 use crate::ipc::errors::user_friendly_error;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
 use tauri::State;
 use tokio::sync::Mutex;
 
+use super::dry_run::reject_dry_run_attempt;
+use super::permissions::require_automation_permission;
 use super::profile_resume::{application_resume_dir, trusted_application_resume_path};
-use super::AttemptResponse;
+use super::quota::{automation_quota, quota_reached_message};
+use super::{find_profile, AttemptResponse};
+
+const SCREENSHOTS_DIR: &str = "automation-screenshots";
 
 const UNSUPPORTED_PREPARE_FORM_TARGET: &str =
     "Prepare Form only works on recognized application sites. Open this page yourself, or apply manually.";
@@ -114,18 +122,95 @@ async fn verify_application_form_page_url(
     Ok(())
 }
 
+/// Whether the saved resume is attached on this platform's forms
+///
+/// Other platforms leave the resume for the user to attach.
+pub(super) fn uploads_resume(platform: &AtsPlatform) -> bool {
+    matches!(platform, AtsPlatform::Greenhouse | AtsPlatform::Lever)
+}
+
+/// The profile whose default resume fits the job best, if any
+///
+/// Lets a form go out with the best-fitting resume when no profile was
+/// chosen. Lookup failures are logged and leave the choice to the default
+/// profile.
+async fn profile_for_suggested_resume(
+    state: &AppState,
+    job_hash: &str,
+) -> Option<ApplicationProfile> {
+    let suggestion = match state
+        .database
+        .resume_matcher()
+        .suggest_resume_for_job(job_hash)
+        .await
+    {
+        Ok(suggestion) => suggestion,
+        Err(_e) => {
+            tracing::warn!(
+                error_kind = "database",
+                "Skipped resume suggestion for profile selection"
+            );
+            return None;
+        }
+    };
+    let resume_id = suggestion.recommended_resume_id?;
+    match state
+        .database
+        .profile_manager()
+        .get_profile_for_resume(resume_id)
+        .await
+    {
+        Ok(profile) => profile,
+        Err(_e) => {
+            tracing::warn!(
+                error_kind = "database",
+                resume_id,
+                "Skipped profile lookup for suggested resume"
+            );
+            None
+        }
+    }
+}
+
+pub(super) fn screenshots_dir() -> PathBuf {
+    desktop::get_data_dir().join(SCREENSHOTS_DIR)
+}
+
+pub(super) fn review_screenshot_path(dir: &Path, attempt_id: i64) -> PathBuf {
+    dir.join(format!("attempt-{attempt_id}.png"))
+}
+
+async fn save_review_screenshot(page: &AutomationPage, path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    page.screenshot(path).await
+}
+
 /// Fill a job application form
 ///
+/// Requires a per-domain grant from `grant_automation_permission` for the job
+/// URL and for the page the browser lands on, and refuses once the profile's
+/// daily or weekly form limit is used up. Uses the profile with `profile_id`.
+/// When it is omitted and `job_hash` is given, uses the profile whose default
+/// resume best fits the job, falling back to the default profile. In dry-run
+/// mode the filled form
+/// is recorded and then closed so nothing from it can be sent.
+///
 /// 1. Navigates to the job URL
 /// 2. Detects ATS platform
-/// 3. Fills form fields from profile and screening answers
+/// 3. Fills form fields from the profile and its screening answers, attaching
+///    the saved resume on Greenhouse and Lever forms
 /// 4. Creates automation attempt for tracking
-/// 5. Returns what was filled
-/// 6. User reviews and clicks submit manually
+/// 5. Saves a screenshot of the filled form and leaves the attempt awaiting
+///    approval
+/// 6. Returns what was filled
+/// 7. User reviews and clicks submit manually
 #[tauri::command]
 pub(crate) async fn fill_application_form(
     job_url: String,
     job_hash: Option<String>,
+    profile_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<FillResultWithAttempt, String> {
     tracing::info!(
@@ -135,6 +220,17 @@ pub(crate) async fn fill_application_form(
     let start_time = std::time::Instant::now();
     let (job_url, platform) = prepare_form_target_for_fill(&job_url).await?;
     tracing::info!("Detected application platform: {}", platform.as_str());
+    let domain = require_automation_permission(&state, &job_url).await?;
+    let quota = automation_quota(&state).await?;
+    if quota.remaining == 0 {
+        return Err(quota_reached_message(&quota));
+    }
+    let dry_run = state
+        .database
+        .automation_manager()
+        .dry_run_enabled()
+        .await
+        .map_err(|e| user_friendly_error("Failed to check dry-run mode", e))?;
 
     let manager = BROWSER_MANAGER.lock().await;
     if !manager.is_running().await {
@@ -149,13 +245,19 @@ pub(crate) async fn fill_application_form(
         .await
         .map_err(|e| user_friendly_error("Failed to open job page", e))?;
 
-    verify_application_form_page_url(
-        page.current_url()
-            .await
-            .map_err(|e| user_friendly_error("Failed to confirm job page", e))?,
-        &platform,
-    )
-    .await?;
+    let page_url = page
+        .current_url()
+        .await
+        .map_err(|e| user_friendly_error("Failed to confirm job page", e))?;
+    verify_application_form_page_url(page_url.clone(), &platform).await?;
+    if let Some(page_url) = page_url.as_deref() {
+        require_automation_permission(&state, page_url).await?;
+    }
+    let _ = state
+        .database
+        .automation_permission_manager()
+        .mark_used(&domain)
+        .await;
 
     let attempt_id = if let Some(ref hash) = job_hash {
         let automation_manager = state.database.automation_manager();
@@ -180,14 +282,32 @@ pub(crate) async fn fill_application_form(
     };
 
     let profile_manager = state.database.profile_manager();
-    let profile = profile_manager
-        .get_profile()
-        .await
-        .map_err(|e| user_friendly_error("Failed to load profile", e))?
-        .ok_or("No application profile configured. Open Application Assist from the sidebar and save your profile details first.")?;
+    let routed_profile = match (profile_id, job_hash.as_deref()) {
+        (None, Some(hash)) => profile_for_suggested_resume(&state, hash).await,
+        _ => None,
+    };
+    let profile = match routed_profile {
+        Some(profile) => profile,
+        None => find_profile(&profile_manager, profile_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to load profile", e))?
+            .ok_or(if profile_id.is_some() {
+                "That application profile no longer exists. Choose another profile."
+            } else {
+                "No application profile configured. Open Application Assist from the sidebar and save your profile details first."
+            })?,
+    };
+
+    if let Some(id) = attempt_id {
+        let automation_manager = state.database.automation_manager();
+        let _ = automation_manager.set_profile(id, profile.id).await;
+        if dry_run {
+            let _ = automation_manager.mark_dry_run(id).await;
+        }
+    }
 
     let screening_answers = profile_manager
-        .get_screening_answers()
+        .get_screening_answers_for_profile(profile.id)
         .await
         .map_err(|e| user_friendly_error("Failed to load screening answers", e))?;
 
@@ -196,12 +316,13 @@ pub(crate) async fn fill_application_form(
         screening_answers.len()
     );
 
-    let _resume_path = trusted_application_resume_path(
+    let resume_path = trusted_application_resume_path(
         profile.resume_file_path.as_deref(),
         &application_resume_dir(),
-    )?;
+    )?
+    .filter(|_| uploads_resume(&platform));
 
-    let filler = FormFiller::new(profile, None).with_screening_answers(screening_answers);
+    let filler = FormFiller::new(profile, resume_path).with_screening_answers(screening_answers);
 
     let result = filler
         .fill_page(&page, &platform)
@@ -237,6 +358,31 @@ pub(crate) async fn fill_application_form(
         let _ = automation_manager
             .update_status(id, status, result.error_message.as_deref())
             .await;
+        let _ = automation_manager
+            .set_fill_summary(id, &result.summary())
+            .await;
+
+        let screenshot_path = review_screenshot_path(&screenshots_dir(), id);
+        match save_review_screenshot(&page, &screenshot_path).await {
+            Ok(()) => {
+                let _ = automation_manager
+                    .set_screenshot_path(id, &screenshot_path.to_string_lossy())
+                    .await;
+            }
+            Err(e) => tracing::warn!(
+                error = %user_friendly_error("Failed to save form screenshot", e),
+                "Skipped form screenshot"
+            ),
+        }
+    }
+
+    if dry_run {
+        if let Err(e) = page.close().await {
+            tracing::warn!(
+                error = %user_friendly_error("Failed to close dry-run form", e),
+                "Dry-run form left open"
+            );
+        }
     }
 
     Ok(FillResultWithAttempt {
@@ -244,6 +390,7 @@ pub(crate) async fn fill_application_form(
         attempt_id,
         duration_ms,
         ats_platform: platform.as_str().to_string(),
+        dry_run,
     })
 }
 
@@ -259,11 +406,14 @@ pub(crate) struct FillResultWithAttempt {
     pub duration_ms: i64,
     /// Detected ATS platform
     pub ats_platform: String,
+    /// Whether the form was closed after filling because dry-run mode is on
+    pub dry_run: bool,
 }
 
 /// Mark an automation attempt as submitted by the user
 ///
 /// Called when user confirms they clicked the submit button on the form.
+/// The job's application is created or moved to Applied on the board.
 #[tauri::command]
 pub(crate) async fn mark_attempt_submitted(
     attempt_id: i64,
@@ -271,11 +421,18 @@ pub(crate) async fn mark_attempt_submitted(
 ) -> Result<(), String> {
     tracing::info!("Command: mark_attempt_submitted (id: {})", attempt_id);
 
+    reject_dry_run_attempt(&state, attempt_id).await?;
     let manager = state.database.automation_manager();
-    manager
+    let application_id = manager
         .mark_submitted(attempt_id)
         .await
-        .map_err(|e| user_friendly_error("Failed to mark attempt as submitted", e))
+        .map_err(|e| user_friendly_error("Failed to mark attempt as submitted", e))?;
+    tracing::info!(
+        attempt_id,
+        application_id,
+        "Linked submitted attempt to application"
+    );
+    Ok(())
 }
 
 /// Get all automation attempts for a job
@@ -2,7 +2,11 @@
 //!
 //! Commands for saving, retrieving, and validating app configuration.
 
-use crate::application::config::{AutoRefreshConfig, Config, EmailConfig};
+use crate::application::config::{
+    discover_company_board as core_discover_company_board, AutoRefreshConfig,
+    CompanyBoardDiscovery, Config, ConfigRecovery, EmailConfig, NtfyConfig, PushoverConfig,
+    TelegramConfig,
+};
 use crate::application::credentials::{
     decode_smtp_password_for_binding, CredentialKey, CredentialService, SmtpCredentialBinding,
 };
@@ -12,7 +16,7 @@ use crate::desktop::Database;
 use crate::ipc::errors::user_friendly_error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::{fmt, path::Path};
+use std::{fmt, path::Path, sync::Arc};
 use tauri::State;
 use tokio::sync::RwLock;
 
@@ -75,19 +79,42 @@ async fn get_stored_credential_for_test(
     }
 }
 
-async fn resolve_slack_webhook_for_test(
-    webhook_url: String,
+/// A stored optional secret, or an empty string when none is saved
+async fn get_optional_stored_credential(
+    key: CredentialKey,
+    label: &str,
+    credentials: &CredentialService,
+) -> Result<String, String> {
+    match credentials.retrieve(key).await {
+        Ok(value) => Ok(value.unwrap_or_default()),
+        Err(e) => {
+            let message = user_friendly_error("Stored credential unavailable", &e);
+            tracing::error!(
+                credential = label,
+                error = %message,
+                "Stored credential unavailable"
+            );
+            Err(format!("{label} credential is unavailable"))
+        }
+    }
+}
+
+/// The entered secret, or the stored one when the field was left blank
+async fn resolve_credential_for_test(
+    value: String,
+    key: CredentialKey,
+    label: &str,
     credentials: &CredentialService,
 ) -> Result<String, String> {
-    let webhook_url = webhook_url.trim().to_string();
-    if !webhook_url.is_empty() {
-        return Ok(webhook_url);
+    let value = value.trim().to_string();
+    if !value.is_empty() {
+        return Ok(value);
     }
 
-    get_stored_credential_for_test(CredentialKey::SlackWebhook, "Slack webhook", credentials).await
+    get_stored_credential_for_test(key, label, credentials).await
 }
 
-fn is_first_run_for_path(config_path: &Path) -> Result<bool, String> {
+pub(super) fn is_first_run_for_path(config_path: &Path) -> Result<bool, String> {
     config_path.try_exists().map(|exists| !exists).map_err(|e| {
         tracing::error!(
             config_path = %path_label_for_logging(config_path),
@@ -258,7 +285,11 @@ pub(crate) async fn get_dashboard_preferences(
 ) -> Result<DashboardPreferences, String> {
     tracing::info!("Command: get_dashboard_preferences");
     let config = state.config.read().await;
-    Ok(DashboardPreferences::from_config(&config))
+    let mut preferences = DashboardPreferences::from_config(&config);
+    if state.guest_mode.is_active() {
+        preferences.salary_floor_usd = 0;
+    }
+    Ok(preferences)
 }
 
 #[tauri::command]
@@ -293,6 +324,12 @@ fn any_job_source_enabled(config: &Config) -> bool {
         || config.usajobs.enabled
         || config.simplyhired.enabled
         || config.glassdoor.enabled
+        || config
+            .location_preferences
+            .country_board_enabled("arbeitnow")
+        || config
+            .location_preferences
+            .country_board_enabled("guardian_jobs")
         || config
             .greenhouse_urls
             .iter()
@@ -308,17 +345,173 @@ pub(crate) async fn validate_slack_webhook(
     state: State<'_, AppState>,
 ) -> Result<bool, String> {
     tracing::info!("Command: validate_slack_webhook");
-    let webhook_url =
-        resolve_slack_webhook_for_test(webhook_url, state.credentials.as_ref()).await?;
+    let webhook_url = resolve_credential_for_test(
+        webhook_url,
+        CredentialKey::SlackWebhook,
+        "Slack webhook",
+        state.credentials.as_ref(),
+    )
+    .await?;
+
+    validation_result(crate::application::notify::validate_slack_webhook(&webhook_url).await)
+}
 
-    match crate::application::notify::validate_slack_webhook(&webhook_url).await {
-        Ok(valid) => Ok(valid),
-        Err(e) => {
-            let message = user_friendly_error("Validation failed", &e);
-            tracing::error!(error = %message, "Webhook validation failed");
-            Err(message)
-        }
-    }
+/// Validate Discord webhook URL
+#[tauri::command]
+pub(crate) async fn validate_discord_webhook(
+    webhook_url: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_discord_webhook");
+    let webhook_url = resolve_credential_for_test(
+        webhook_url,
+        CredentialKey::DiscordWebhook,
+        "Discord webhook",
+        state.credentials.as_ref(),
+    )
+    .await?;
+
+    validation_result(crate::application::notify::validate_discord_webhook(&webhook_url).await)
+}
+
+/// Validate Microsoft Teams webhook URL
+#[tauri::command]
+pub(crate) async fn validate_teams_webhook(
+    webhook_url: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_teams_webhook");
+    let webhook_url = resolve_credential_for_test(
+        webhook_url,
+        CredentialKey::TeamsWebhook,
+        "Teams webhook",
+        state.credentials.as_ref(),
+    )
+    .await?;
+
+    validation_result(crate::application::notify::validate_teams_webhook(&webhook_url).await)
+}
+
+/// Validate a custom webhook URL by sending a test event
+///
+/// The event is signed when a `custom_webhook_secret` credential is saved.
+#[tauri::command]
+pub(crate) async fn validate_custom_webhook(
+    url: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_custom_webhook");
+    let signing_secret = get_optional_stored_credential(
+        CredentialKey::CustomWebhookSecret,
+        "Webhook signing secret",
+        state.credentials.as_ref(),
+    )
+    .await?;
+
+    validation_result(
+        crate::application::notify::validate_custom_webhook(url.trim(), &signing_secret).await,
+    )
+}
+
+/// Validate Telegram bot token and chat ID by sending a test message
+///
+/// A blank `bot_token` uses the stored token.
+#[tauri::command]
+pub(crate) async fn validate_telegram_bot(
+    bot_token: String,
+    chat_id: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_telegram_bot");
+    let bot_token = resolve_credential_for_test(
+        bot_token,
+        CredentialKey::TelegramBotToken,
+        "Telegram bot token",
+        state.credentials.as_ref(),
+    )
+    .await?;
+    let telegram_config = TelegramConfig {
+        enabled: true,
+        bot_token,
+        chat_id: chat_id.trim().to_string(),
+    };
+
+    validation_result(crate::application::notify::validate_telegram_bot(&telegram_config).await)
+}
+
+/// Validate ntfy settings by publishing a test message
+///
+/// A blank `topic` uses the stored topic. The server and priority come from
+/// the saved config.
+#[tauri::command]
+pub(crate) async fn validate_ntfy_topic(
+    topic: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_ntfy_topic");
+    let topic = resolve_credential_for_test(
+        topic,
+        CredentialKey::NtfyTopic,
+        "ntfy topic",
+        state.credentials.as_ref(),
+    )
+    .await?;
+    let access_token = get_optional_stored_credential(
+        CredentialKey::NtfyAccessToken,
+        "ntfy access token",
+        state.credentials.as_ref(),
+    )
+    .await?;
+    let ntfy_config = NtfyConfig {
+        enabled: true,
+        topic,
+        access_token,
+        ..state.config.read().await.alerts.ntfy.clone()
+    };
+
+    validation_result(crate::application::notify::validate_ntfy_config(&ntfy_config).await)
+}
+
+/// Validate Pushover keys by sending a test message
+///
+/// Blank keys use the stored ones.
+#[tauri::command]
+pub(crate) async fn validate_pushover(
+    user_key: String,
+    app_token: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: validate_pushover");
+    let user_key = resolve_credential_for_test(
+        user_key,
+        CredentialKey::PushoverUserKey,
+        "Pushover user key",
+        state.credentials.as_ref(),
+    )
+    .await?;
+    let app_token = resolve_credential_for_test(
+        app_token,
+        CredentialKey::PushoverAppToken,
+        "Pushover application token",
+        state.credentials.as_ref(),
+    )
+    .await?;
+    let pushover_config = PushoverConfig {
+        enabled: true,
+        user_key,
+        app_token,
+        ..state.config.read().await.alerts.pushover.clone()
+    };
+
+    validation_result(crate::application::notify::validate_pushover_config(&pushover_config).await)
+}
+
+fn validation_result(result: anyhow::Result<bool>) -> Result<bool, String> {
+    result.map_err(|e| {
+        let message = user_friendly_error("Validation failed", &e);
+        tracing::error!(error = %message, "Webhook validation failed");
+        message
+    })
 }
 
 /// Check if first-run setup is complete
@@ -334,6 +527,15 @@ pub(crate) async fn is_first_run() -> Result<bool, String> {
     Ok(first_run)
 }
 
+/// Backup restored at startup because the saved config was corrupt
+#[tauri::command]
+pub(crate) async fn get_config_recovery_notice(
+    state: State<'_, AppState>,
+) -> Result<Option<ConfigRecovery>, String> {
+    tracing::info!("Command: get_config_recovery_notice");
+    Ok(state.config_recovery.clone())
+}
+
 /// Complete first-run setup
 #[tauri::command]
 pub(crate) async fn complete_setup(
@@ -371,6 +573,8 @@ pub(crate) async fn test_email_notification(
         from_email: email_config.from_email,
         to_emails: email_config.to_emails,
         use_starttls: email_config.use_starttls,
+        send_interview_calendar: false,
+        digest: Default::default(),
     };
 
     crate::application::notify::validate_email_config(&config)
@@ -381,5 +585,44 @@ pub(crate) async fn test_email_notification(
     Ok(())
 }
 
+/// Send an email digest of the current top new jobs using the saved email
+/// settings
+///
+/// Returns the number of jobs listed. The jobs still go out in the next
+/// scheduled digest.
+#[tauri::command]
+pub(crate) async fn send_test_email_digest(state: State<'_, AppState>) -> Result<usize, String> {
+    tracing::info!("Command: send_test_email_digest");
+
+    let scheduler = state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(
+            crate::application::scheduler::Scheduler::new_shared_with_credentials(
+                state.config.clone(),
+                state.database.clone(),
+                state.credentials.clone(),
+            ),
+        )
+    });
+    scheduler
+        .send_test_email_digest()
+        .await
+        .map_err(|e| user_friendly_error("Failed to send test digest", e))
+}
+
+/// Find verified Greenhouse, Lever, Ashby, Workable, or SmartRecruiters
+/// boards for a company so the user can add one to their sources
+#[tauri::command]
+pub(crate) async fn discover_company_board(
+    company_name: String,
+    state: State<'_, AppState>,
+) -> Result<CompanyBoardDiscovery, String> {
+    tracing::info!("Command: discover_company_board");
+
+    let config = state.config.read().await.clone();
+    core_discover_company_board(&config, &company_name)
+        .await
+        .map_err(|e| user_friendly_error("Failed to discover company job board", e))
+}
+
 #[cfg(test)]
 mod tests;
@@ -21,17 +21,22 @@ fn create_dashboard_test_config() -> Config {
             cities: vec![],
             states: vec![],
             country: "US".to_string(),
+            countries: Vec::new(),
         },
+        commute: Default::default(),
         salary_floor_usd: 70_000,
         salary_target_usd: None,
+        salary_target_max_usd: None,
         penalize_missing_salary: false,
         auto_refresh: AutoRefreshConfig::default(),
         bookmarklet_port: 4321,
         immediate_alert_threshold: 0.8,
         scraping_interval_hours: 2,
+        scraping_concurrency: 3,
         alerts: AlertConfig::default(),
         greenhouse_urls: vec![],
         lever_urls: vec![],
+        career_pages: vec![],
         linkedin: Default::default(),
         restricted_source_acknowledgements: Default::default(),
         remoteok: Default::default(),
@@ -43,11 +48,17 @@ fn create_dashboard_test_config() -> Config {
         usajobs: Default::default(),
         simplyhired: Default::default(),
         glassdoor: Default::default(),
+        proxy: Default::default(),
+        source_limits: Default::default(),
+        rate_limits: Default::default(),
+        power_saving: Default::default(),
         jobswithgpt_endpoint: String::new(),
         jobswithgpt_approval: Default::default(),
         external_ai: Default::default(),
         ghost_config: None,
         use_resume_matching: false,
+        scoring: Default::default(),
+        retention: Default::default(),
         preferred_companies: vec![],
         blocked_companies: vec![],
     }
@@ -0,0 +1,269 @@
+//! Recruiter and contact Tauri commands
+//!
+//! Contacts are shared across applications and interviews. Saving a contact
+//! that matches an existing one by email, LinkedIn profile, or name and
+//! company updates it instead of adding a duplicate.
+
+use crate::application::ats::{Contact, ContactInput, ContactInteraction};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use crate::ipc::limits::validate_optional_command_limit_i64;
+use chrono::{DateTime, Utc};
+use tauri::State;
+
+const DEFAULT_CONTACT_LIMIT: i64 = 50;
+
+/// Kinds of logged contact interactions
+const INTERACTION_TYPES: &[&str] = &["email", "call", "message", "meeting", "other"];
+
+fn validate_contact(contact: &ContactInput) -> Result<(), String> {
+    if contact.name.trim().is_empty() {
+        return Err("Enter the contact's name.".to_string());
+    }
+    if contact
+        .email
+        .as_deref()
+        .map(str::trim)
+        .is_some_and(|email| !email.is_empty() && !email.contains('@'))
+    {
+        return Err("Enter a full email address, such as name@example.com.".to_string());
+    }
+    Ok(())
+}
+
+fn require_link_target(
+    application_id: Option<i64>,
+    interview_id: Option<i64>,
+) -> Result<(), String> {
+    if application_id.is_none() && interview_id.is_none() {
+        return Err("Choose an application or interview.".to_string());
+    }
+    Ok(())
+}
+
+/// Save a contact, merging it into an existing match, and return its id
+#[tauri::command]
+pub(crate) async fn save_contact(
+    contact: ContactInput,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    validate_contact(&contact)?;
+    tracing::info!("Command: save_contact");
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .save_contact(&contact)
+        .await
+        .map_err(|e| user_friendly_error("Failed to save contact", e))
+}
+
+/// Replace a contact's details
+#[tauri::command]
+pub(crate) async fn update_contact(
+    contact_id: i64,
+    contact: ContactInput,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    validate_contact(&contact)?;
+    tracing::info!("Command: update_contact (id: {})", contact_id);
+
+    let tracker = state.database.application_tracker();
+    let updated = tracker
+        .update_contact(contact_id, &contact)
+        .await
+        .map_err(|e| user_friendly_error("Failed to update contact", e))?;
+    if updated {
+        Ok(())
+    } else {
+        Err("Contact not found".to_string())
+    }
+}
+
+/// Delete a contact with its interaction log
+#[tauri::command]
+pub(crate) async fn delete_contact(
+    contact_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!("Command: delete_contact (id: {})", contact_id);
+
+    let tracker = state.database.application_tracker();
+    let deleted = tracker
+        .delete_contact(contact_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to delete contact", e))?;
+    if deleted {
+        Ok(())
+    } else {
+        Err("Contact not found".to_string())
+    }
+}
+
+/// Search contacts by name, email, company, and notes
+///
+/// A blank query lists the most recently contacted first.
+#[tauri::command]
+pub(crate) async fn search_contacts(
+    query: Option<String>,
+    limit: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Contact>, String> {
+    let limit = validate_optional_command_limit_i64(limit, DEFAULT_CONTACT_LIMIT)?;
+    let query = query.unwrap_or_default();
+    tracing::info!(
+        query_chars = query.chars().count(),
+        limit,
+        "Command: search_contacts"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .search_contacts(&query, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to search contacts", e))
+}
+
+/// Contacts linked to an application
+#[tauri::command]
+pub(crate) async fn get_application_contacts(
+    application_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<Contact>, String> {
+    tracing::info!(application_id, "Command: get_application_contacts");
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .get_application_contacts(application_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get contacts", e))
+}
+
+/// Contacts linked to an interview
+#[tauri::command]
+pub(crate) async fn get_interview_contacts(
+    interview_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<Contact>, String> {
+    tracing::info!(interview_id, "Command: get_interview_contacts");
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .get_interview_contacts(interview_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get contacts", e))
+}
+
+/// Link a contact to an application, an interview, or both
+#[tauri::command]
+pub(crate) async fn link_contact(
+    contact_id: i64,
+    application_id: Option<i64>,
+    interview_id: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    require_link_target(application_id, interview_id)?;
+    tracing::info!(
+        contact_id,
+        ?application_id,
+        ?interview_id,
+        "Command: link_contact"
+    );
+
+    let tracker = state.database.application_tracker();
+    if let Some(application_id) = application_id {
+        tracker
+            .link_contact_to_application(contact_id, application_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to link contact", e))?;
+    }
+    if let Some(interview_id) = interview_id {
+        tracker
+            .link_contact_to_interview(contact_id, interview_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to link contact", e))?;
+    }
+    Ok(())
+}
+
+/// Remove a contact from an application, an interview, or both
+#[tauri::command]
+pub(crate) async fn unlink_contact(
+    contact_id: i64,
+    application_id: Option<i64>,
+    interview_id: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    require_link_target(application_id, interview_id)?;
+    tracing::info!(
+        contact_id,
+        ?application_id,
+        ?interview_id,
+        "Command: unlink_contact"
+    );
+
+    let tracker = state.database.application_tracker();
+    if let Some(application_id) = application_id {
+        tracker
+            .unlink_contact_from_application(contact_id, application_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to unlink contact", e))?;
+    }
+    if let Some(interview_id) = interview_id {
+        tracker
+            .unlink_contact_from_interview(contact_id, interview_id)
+            .await
+            .map_err(|e| user_friendly_error("Failed to unlink contact", e))?;
+    }
+    Ok(())
+}
+
+/// Log an email, call, message, or meeting with a contact
+///
+/// Updates when the contact, and the application if one is given, were last
+/// contacted, which holds off ghosted detection. Returns the interaction id.
+#[tauri::command]
+pub(crate) async fn log_contact_interaction(
+    contact_id: i64,
+    application_id: Option<i64>,
+    interaction_type: String,
+    occurred_at: DateTime<Utc>,
+    summary: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    if !INTERACTION_TYPES.contains(&interaction_type.as_str()) {
+        return Err("Choose an email, call, message, meeting, or other interaction.".to_string());
+    }
+    tracing::info!(
+        contact_id,
+        ?application_id,
+        interaction_type = %interaction_type,
+        "Command: log_contact_interaction"
+    );
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .log_contact_interaction(
+            contact_id,
+            application_id,
+            &interaction_type,
+            occurred_at,
+            summary.as_deref(),
+        )
+        .await
+        .map_err(|e| user_friendly_error("Failed to log interaction", e))
+}
+
+/// A contact's logged interactions, newest first
+#[tauri::command]
+pub(crate) async fn get_contact_interactions(
+    contact_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<ContactInteraction>, String> {
+    tracing::info!("Command: get_contact_interactions (id: {})", contact_id);
+
+    let tracker = state.database.application_tracker();
+    tracker
+        .get_contact_interactions(contact_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get interactions", e))
+}
@@ -131,6 +131,18 @@ pub(super) fn summarize_config(config: &Config) -> ConfigSummary {
         if config.glassdoor.enabled {
             count += 1;
         }
+        if config
+            .location_preferences
+            .country_board_enabled("arbeitnow")
+        {
+            count += 1;
+        }
+        if config
+            .location_preferences
+            .country_board_enabled("guardian_jobs")
+        {
+            count += 1;
+        }
         count
     };
 
@@ -139,6 +151,7 @@ pub(super) fn summarize_config(config: &Config) -> ConfigSummary {
         keywords_count: config.title_allowlist.len() + config.keywords_boost.len(),
         has_location_prefs: !config.location_preferences.cities.is_empty()
             || !config.location_preferences.states.is_empty()
+            || !config.location_preferences.countries.is_empty()
             || !config.location_preferences.allow_remote,
         has_salary_prefs: config.salary_floor_usd > 0,
         has_blocked_companies: !config.blocked_companies.is_empty(),
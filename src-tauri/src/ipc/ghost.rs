@@ -2,16 +2,26 @@
 //!
 //! Commands for identifying and filtering ghost/fake job postings.
 
+use crate::application::ghost_patterns::{import_pattern_pack, GhostPatternImportError};
+use crate::application::scheduler::{GhostBatchProgress, Scheduler};
 use crate::bootstrap::AppState;
+use crate::desktop;
 use crate::desktop::path_label_for_logging;
+use crate::desktop::CompanyReputation;
 use crate::desktop::GhostConfig;
+use crate::desktop::GhostReanalysisRun;
 use crate::ipc::errors::user_friendly_error;
 use crate::ipc::limits::{validate_command_limit_i64, validate_optional_command_limit_i64};
 use serde_json::Value;
-use tauri::State;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_dialog::DialogExt;
 
-fn validate_ghost_threshold(threshold: Option<f64>) -> Result<f64, String> {
-    let threshold = threshold.unwrap_or(0.5);
+/// Largest pattern pack file accepted for import
+const MAX_PATTERN_PACK_IMPORT_BYTES: u64 = 2 * 1024 * 1024;
+
+fn validate_ghost_threshold(threshold: Option<f64>, default: f64) -> Result<f64, String> {
+    let threshold = threshold.unwrap_or(default);
 
     if threshold.is_finite() && (0.0..=1.0).contains(&threshold) {
         Ok(threshold)
@@ -20,16 +30,38 @@ fn validate_ghost_threshold(threshold: Option<f64>) -> Result<f64, String> {
     }
 }
 
+/// Ghost detection settings in effect, or the defaults when none are saved
+pub(crate) async fn current_ghost_config(state: &AppState) -> GhostConfig {
+    state
+        .config
+        .read()
+        .await
+        .ghost_config
+        .clone()
+        .unwrap_or_default()
+}
+
 /// Get jobs flagged as potential ghost jobs
 ///
-/// Returns jobs with ghost_score >= threshold (default 0.5)
+/// Returns jobs with ghost_score >= threshold (default: the configured
+/// warning threshold)
 #[tauri::command]
 pub(crate) async fn get_ghost_jobs(
     threshold: Option<f64>,
     limit: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<Vec<Value>, String> {
-    let threshold = validate_ghost_threshold(threshold)?;
+    ghost_jobs_json(&state, threshold, limit).await
+}
+
+/// Flagged jobs as JSON, with private notes masked while guest mode is on
+pub(crate) async fn ghost_jobs_json(
+    state: &AppState,
+    threshold: Option<f64>,
+    limit: Option<i64>,
+) -> Result<Vec<Value>, String> {
+    let default_threshold = current_ghost_config(state).await.warning_threshold;
+    let threshold = validate_ghost_threshold(threshold, default_threshold)?;
     let limit = validate_optional_command_limit_i64(limit, 100)?;
     tracing::info!(
         "Command: get_ghost_jobs (threshold: {}, limit: {})",
@@ -39,7 +71,9 @@ pub(crate) async fn get_ghost_jobs(
 
     match state.database.get_ghost_jobs(threshold, limit).await {
         Ok(jobs) => {
-            let jobs_json: Vec<Value> = jobs
+            let jobs_json: Vec<Value> = state
+                .guest_mode
+                .mask(jobs)
                 .into_iter()
                 .filter_map(|job| serde_json::to_value(&job).ok())
                 .collect();
@@ -73,12 +107,22 @@ pub(crate) async fn get_ghost_statistics(state: State<'_, AppState>) -> Result<V
 
 /// Get recent jobs with optional ghost filtering
 ///
-/// When `exclude_ghost` is true, jobs with ghost_score >= 0.5 are excluded.
+/// When `exclude_ghost` is true, jobs at or above the configured hide
+/// threshold are excluded.
 #[tauri::command]
 pub(crate) async fn get_recent_jobs_filtered(
     limit: i64,
     exclude_ghost: bool,
     state: State<'_, AppState>,
+) -> Result<Vec<Value>, String> {
+    recent_jobs_filtered_json(&state, limit, exclude_ghost).await
+}
+
+/// Recent jobs as JSON, with private notes masked while guest mode is on
+pub(crate) async fn recent_jobs_filtered_json(
+    state: &AppState,
+    limit: i64,
+    exclude_ghost: bool,
 ) -> Result<Vec<Value>, String> {
     let limit = validate_command_limit_i64(limit)?;
     tracing::info!(
@@ -87,8 +131,11 @@ pub(crate) async fn get_recent_jobs_filtered(
         exclude_ghost
     );
 
-    // Convert bool to max_ghost_score: exclude ghosts means max score of 0.5
-    let max_ghost_score = if exclude_ghost { Some(0.5) } else { None };
+    let max_ghost_score = if exclude_ghost {
+        Some(current_ghost_config(state).await.hide_threshold)
+    } else {
+        None
+    };
 
     match state
         .database
@@ -96,7 +143,9 @@ pub(crate) async fn get_recent_jobs_filtered(
         .await
     {
         Ok(jobs) => {
-            let jobs_json: Vec<Value> = jobs
+            let jobs_json: Vec<Value> = state
+                .guest_mode
+                .mask(jobs)
                 .into_iter()
                 .filter_map(|job| serde_json::to_value(&job).ok())
                 .collect();
@@ -115,17 +164,191 @@ pub(crate) async fn get_recent_jobs_filtered(
 pub(crate) async fn get_ghost_config(state: State<'_, AppState>) -> Result<Value, String> {
     tracing::info!("Command: get_ghost_config");
 
-    // Get from main config, or use default if not present
-    let config = state.config.read().await;
-    let ghost_config = config
-        .ghost_config
-        .clone()
-        .unwrap_or_else(GhostConfig::default);
-
-    serde_json::to_value(&ghost_config)
+    serde_json::to_value(current_ghost_config(&state).await)
         .map_err(|e| user_friendly_error("Failed to serialize ghost config", e))
 }
 
+/// Get the saved posting-risk analysis for one job
+///
+/// Returns the score, the reasons behind it, its bucket under the current
+/// warning and hide thresholds, and the user's verdict, or null when the job
+/// does not exist. `bucket` is null until the job has been analyzed.
+#[tauri::command]
+pub(crate) async fn get_ghost_analysis(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<Value>, String> {
+    tracing::info!("Command: get_ghost_analysis (job_id: {})", job_id);
+
+    let job = state.database.get_job_by_id(job_id).await.map_err(|e| {
+        let message = user_friendly_error("Failed to get posting analysis", &e);
+        tracing::error!(error = %message, "Failed to get job for ghost analysis");
+        user_friendly_error("Database operation failed", e)
+    })?;
+    let Some(job) = job else {
+        return Ok(None);
+    };
+    let feedback = state
+        .database
+        .get_ghost_feedback(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get posting feedback", e))?;
+
+    let ghost_config = current_ghost_config(&state).await;
+    let reasons = job
+        .ghost_reasons
+        .as_deref()
+        .and_then(|reasons| serde_json::from_str::<Value>(reasons).ok())
+        .unwrap_or_else(|| Value::Array(Vec::new()));
+    Ok(Some(serde_json::json!({
+        "job_id": job.id,
+        "ghost_score": job.ghost_score,
+        "reasons": reasons,
+        "bucket": job.ghost_score.map(|score| ghost_config.bucket(score)),
+        "feedback": feedback,
+    })))
+}
+
+/// Get how a company's saved postings have gone
+///
+/// Returns the average posting-risk score, the share of postings seen
+/// reposted, and the share filled within 60 days, or null when no saved job
+/// is from the company. Matching ignores case and surrounding spaces. The
+/// figures are refreshed after each scraping run.
+#[tauri::command]
+pub(crate) async fn get_company_reputation(
+    company: String,
+    state: State<'_, AppState>,
+) -> Result<Option<CompanyReputation>, String> {
+    tracing::info!("Command: get_company_reputation");
+
+    state
+        .database
+        .get_company_reputation(&company)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get company reputation", e))
+}
+
+/// Get the most recent background re-analysis after a detector upgrade
+///
+/// Reports how many saved jobs moved between warning and hide buckets.
+#[tauri::command]
+pub(crate) async fn get_ghost_reanalysis_summary(
+    state: State<'_, AppState>,
+) -> Result<Option<GhostReanalysisRun>, String> {
+    tracing::info!("Command: get_ghost_reanalysis_summary");
+
+    state
+        .database
+        .latest_ghost_reanalysis_run()
+        .await
+        .map_err(|e| user_friendly_error("Failed to get ghost re-analysis summary", e))
+}
+
+/// Re-analyze every saved job with the current ghost settings and patterns
+///
+/// Scores are saved in batches; `ghost_analysis:progress` is emitted with a
+/// `GhostBatchProgress` after each batch so the UI can show a progress bar.
+/// Returns the run, including how many jobs moved between buckets.
+#[tauri::command]
+pub(crate) async fn analyze_ghosts_batch(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<GhostReanalysisRun, String> {
+    tracing::info!("Command: analyze_ghosts_batch");
+
+    scheduler_for(&state)
+        .analyze_all_ghost_scores(|progress: GhostBatchProgress| {
+            let _ = app.emit("ghost_analysis:progress", progress);
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to re-analyze saved ghost scores");
+            user_friendly_error("Failed to re-analyze posting risk", &e)
+        })
+}
+
+/// Pick a ghost pattern pack file and import it
+///
+/// The pack must be newer than the one in use. It replaces the patterns for
+/// new analyses right away and is kept for later launches; saved postings
+/// are re-analyzed in the background. Returns the new pack version, or null
+/// when no file was chosen.
+#[tauri::command]
+pub(crate) async fn import_ghost_pattern_pack(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<i64>, String> {
+    let Some(file_path) = app
+        .dialog()
+        .file()
+        .add_filter("Pattern Pack", &["json"])
+        .blocking_pick_file()
+    else {
+        return Ok(None);
+    };
+    let path = file_path
+        .into_path()
+        .map_err(|_| "Could not read the selected pattern pack.".to_string())?;
+    tracing::info!(
+        file_path = %path_label_for_logging(&path),
+        "Command: import_ghost_pattern_pack"
+    );
+
+    let is_json = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let metadata = tokio::fs::metadata(&path)
+        .await
+        .map_err(|_| "JobSentinel could not read that pattern pack.".to_string())?;
+    if !is_json || !metadata.is_file() {
+        return Err("Choose a pattern pack JSON file.".to_string());
+    }
+    if metadata.len() > MAX_PATTERN_PACK_IMPORT_BYTES {
+        return Err("That pattern pack is too large to import.".to_string());
+    }
+    let json = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|_| "JobSentinel could not read that pattern pack.".to_string())?;
+
+    let version =
+        import_pattern_pack(&desktop::get_data_dir(), &json).map_err(|error| match error {
+            GhostPatternImportError::Io(error) => {
+                user_friendly_error("Failed to save pattern pack", error)
+            }
+            error => error.to_string(),
+        })?;
+    reanalyze_in_background(&state);
+
+    Ok(Some(version))
+}
+
+fn scheduler_for(state: &AppState) -> Arc<Scheduler> {
+    state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(Scheduler::new_shared_with_credentials(
+            state.config.clone(),
+            state.database.clone(),
+            state.credentials.clone(),
+        ))
+    })
+}
+
+/// Re-analyze saved postings with the new patterns without blocking the
+/// command.
+fn reanalyze_in_background(state: &AppState) {
+    let scheduler = scheduler_for(state);
+    tauri::async_runtime::spawn(async move {
+        match scheduler.reanalyze_outdated_ghost_scores().await {
+            Ok(run) => tracing::info!(
+                jobs_reanalyzed = run.map_or(0, |run| run.jobs_reanalyzed),
+                "Background ghost re-analysis complete"
+            ),
+            Err(error) => tracing::warn!(error = %error, "Background ghost re-analysis failed"),
+        }
+    });
+}
+
 /// Update ghost detection configuration
 #[tauri::command]
 pub(crate) async fn set_ghost_config(
@@ -321,24 +544,24 @@ mod tests {
 
     #[test]
     fn validates_default_ghost_threshold() {
-        assert_eq!(validate_ghost_threshold(None).unwrap(), 0.5);
+        assert_eq!(validate_ghost_threshold(None, 0.3).unwrap(), 0.3);
     }
 
     #[test]
     fn validates_explicit_ghost_threshold_range() {
-        assert_eq!(validate_ghost_threshold(Some(0.0)).unwrap(), 0.0);
-        assert_eq!(validate_ghost_threshold(Some(1.0)).unwrap(), 1.0);
+        assert_eq!(validate_ghost_threshold(Some(0.0), 0.3).unwrap(), 0.0);
+        assert_eq!(validate_ghost_threshold(Some(1.0), 0.3).unwrap(), 1.0);
     }
 
     #[test]
     fn rejects_out_of_range_ghost_thresholds() {
-        assert!(validate_ghost_threshold(Some(-0.1)).is_err());
-        assert!(validate_ghost_threshold(Some(1.1)).is_err());
+        assert!(validate_ghost_threshold(Some(-0.1), 0.3).is_err());
+        assert!(validate_ghost_threshold(Some(1.1), 0.3).is_err());
     }
 
     #[test]
     fn rejects_non_finite_ghost_thresholds() {
-        assert!(validate_ghost_threshold(Some(f64::NAN)).is_err());
-        assert!(validate_ghost_threshold(Some(f64::INFINITY)).is_err());
+        assert!(validate_ghost_threshold(Some(f64::NAN), 0.3).is_err());
+        assert!(validate_ghost_threshold(Some(f64::INFINITY), 0.3).is_err());
     }
 }
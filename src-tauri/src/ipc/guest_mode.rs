@@ -0,0 +1,40 @@
+//! Guest mode Tauri commands
+//!
+//! Guest mode is enforced in the invoke handler (see `bootstrap`), so these
+//! commands only switch it on and off.
+
+use crate::bootstrap::AppState;
+use chrono::Utc;
+use jobsentinel_application::GuestModeStatus;
+use tauri::State;
+
+/// Get whether guest mode is active and whether unlocking needs a PIN
+#[tauri::command]
+pub(crate) async fn get_guest_mode_status(
+    state: State<'_, AppState>,
+) -> Result<GuestModeStatus, String> {
+    Ok(state.guest_mode.status())
+}
+
+/// Turn on read-only guest mode for screen sharing
+#[tauri::command]
+pub(crate) async fn enable_guest_mode(
+    pin: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<GuestModeStatus, String> {
+    tracing::info!(pin_set = pin.is_some(), "Command: enable_guest_mode");
+    Ok(state.guest_mode.enable(pin.as_deref(), Utc::now()))
+}
+
+/// Turn off guest mode
+#[tauri::command]
+pub(crate) async fn unlock_guest_mode(
+    pin: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<GuestModeStatus, String> {
+    tracing::info!("Command: unlock_guest_mode");
+    state.guest_mode.unlock(pin.as_deref()).map_err(|error| {
+        tracing::warn!("Guest mode unlock rejected");
+        error.to_string()
+    })
+}
@@ -3,15 +3,16 @@
 //! Provides frontend access to scraper health metrics, run history,
 //! and smoke tests.
 
-use crate::application::config::Config;
+use crate::application::config::{Config, CountryPreference};
 use crate::application::health::{
     get_all_scraper_health, get_health_summary as health_summary,
-    get_latest_source_request as latest_source_request, get_scraper_configs as scraper_configs,
+    get_latest_source_request as latest_source_request, get_scrape_history as scrape_history,
+    get_scrape_run_details as scrape_run_details, get_scraper_configs as scraper_configs,
     get_scraper_runs as scraper_runs, is_known_scraper_name,
     run_all_smoke_tests_with_credentials_and_acknowledgement as all_smoke_tests,
     run_smoke_test_with_credentials_and_acknowledgement as run_smoke_test,
-    set_scraper_enabled as scraper_enabled, HealthSummary, ScraperConfig, ScraperHealthMetrics,
-    ScraperRun, SmokeTestResult, SourceRequestSummary,
+    set_scraper_enabled as scraper_enabled, HealthSummary, ScrapeRunDetails, ScrapeRunSummary,
+    ScraperConfig, ScraperHealthMetrics, ScraperRun, SmokeTestResult, SourceRequestSummary,
 };
 use crate::bootstrap::AppState;
 use crate::desktop::path_label_for_logging;
@@ -36,6 +37,7 @@ fn validate_scraper_name(scraper_name: &str) -> Result<(), String> {
 const DEFAULT_SCRAPER_LIMIT: usize = 50;
 const DEFAULT_USAJOBS_LIMIT: usize = 100;
 const DEFAULT_USAJOBS_DATE_POSTED_DAYS: u8 = 30;
+const MAX_DEFAULT_SOURCE_CITIES: usize = 10;
 
 fn ensure_source_limit(limit: &mut usize, default_limit: usize) {
     if *limit == 0 {
@@ -53,6 +55,47 @@ fn default_source_query(config: &Config) -> Option<String> {
         .map(ToOwned::to_owned)
 }
 
+/// Search cities for boards that filter by city, from the location
+/// preferences when in-person work is wanted.
+fn default_source_cities(config: &Config) -> Vec<String> {
+    let prefs = &config.location_preferences;
+    if !(prefs.allow_onsite || prefs.allow_hybrid) {
+        return Vec::new();
+    }
+    prefs
+        .cities
+        .iter()
+        .map(|city| city.trim())
+        .filter(|city| !city.is_empty())
+        .take(MAX_DEFAULT_SOURCE_CITIES)
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Attach a country board to its default country, or detach it everywhere.
+fn set_country_board_enabled(config: &mut Config, board: &str, country: &str, enabled: bool) {
+    if enabled && config.location_preferences.country_board_enabled(board) {
+        return;
+    }
+    let countries = &mut config.location_preferences.countries;
+    if !enabled {
+        for preference in countries.iter_mut() {
+            preference.boards.retain(|existing| existing != board);
+        }
+        return;
+    }
+    match countries
+        .iter_mut()
+        .find(|preference| preference.code.eq_ignore_ascii_case(country))
+    {
+        Some(preference) => preference.boards.push(board.to_string()),
+        None => countries.push(CountryPreference {
+            code: country.to_string(),
+            boards: vec![board.to_string()],
+        }),
+    }
+}
+
 fn apply_config_backed_scraper_toggle(
     config: &mut Config,
     scraper_name: &str,
@@ -68,6 +111,12 @@ fn apply_config_backed_scraper_toggle(
             ensure_source_limit(&mut config.weworkremotely.limit, DEFAULT_SCRAPER_LIMIT);
         }
         "builtin" => {
+            if enabled && config.builtin.query.trim().is_empty() {
+                config.builtin.query = default_source_query(config).unwrap_or_default();
+            }
+            if enabled && config.builtin.cities.is_empty() && !config.builtin.remote_only {
+                config.builtin.cities = default_source_cities(config);
+            }
             config.builtin.enabled = enabled;
             ensure_source_limit(&mut config.builtin.limit, DEFAULT_SCRAPER_LIMIT);
         }
@@ -109,6 +158,8 @@ fn apply_config_backed_scraper_toggle(
             config.simplyhired.enabled = enabled;
             ensure_source_limit(&mut config.simplyhired.limit, DEFAULT_SCRAPER_LIMIT);
         }
+        "arbeitnow" => set_country_board_enabled(config, "arbeitnow", "DE", enabled),
+        "guardian_jobs" => set_country_board_enabled(config, "guardian_jobs", "GB", enabled),
         "glassdoor" => {
             if enabled && config.glassdoor.query.trim().is_empty() {
                 let Some(query) = default_source_query(config) else {
@@ -223,6 +274,29 @@ pub(crate) async fn get_scraper_runs(
         .map_err(|e| health_command_error("Failed to load scraper run history", e))
 }
 
+/// Get recent full scraping cycles, newest first
+#[tauri::command]
+pub(crate) async fn get_scrape_history(
+    state: State<'_, AppState>,
+    limit: Option<i32>,
+) -> Result<Vec<ScrapeRunSummary>, String> {
+    let limit = validate_optional_command_limit_i32(limit, 20)?;
+    scrape_history(&state.database, limit)
+        .await
+        .map_err(|e| health_command_error("Failed to load scrape history", e))
+}
+
+/// Get one scraping cycle with per-source counts and errors
+#[tauri::command]
+pub(crate) async fn get_scrape_run_details(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<Option<ScrapeRunDetails>, String> {
+    scrape_run_details(&state.database, run_id)
+        .await
+        .map_err(|e| health_command_error("Failed to load scrape run details", e))
+}
+
 /// Get the latest minimized request record for an optional external source.
 #[tauri::command]
 pub(crate) async fn get_latest_source_request(
@@ -290,17 +364,22 @@ mod tests {
                 cities: vec![],
                 states: vec![],
                 country: "US".to_string(),
+                countries: Vec::new(),
             },
+            commute: Default::default(),
             salary_floor_usd: 70_000,
             salary_target_usd: None,
+            salary_target_max_usd: None,
             penalize_missing_salary: false,
             auto_refresh: AutoRefreshConfig::default(),
             bookmarklet_port: 4321,
             immediate_alert_threshold: 0.8,
             scraping_interval_hours: 2,
+            scraping_concurrency: 3,
             alerts: AlertConfig::default(),
             greenhouse_urls: vec![],
             lever_urls: vec![],
+            career_pages: vec![],
             linkedin: Default::default(),
             restricted_source_acknowledgements: Default::default(),
             remoteok: Default::default(),
@@ -312,11 +391,17 @@ mod tests {
             usajobs: Default::default(),
             simplyhired: Default::default(),
             glassdoor: Default::default(),
+            proxy: Default::default(),
+            source_limits: Default::default(),
+            rate_limits: Default::default(),
+            power_saving: Default::default(),
             jobswithgpt_endpoint: String::new(),
             jobswithgpt_approval: Default::default(),
             external_ai: Default::default(),
             ghost_config: None,
             use_resume_matching: false,
+            scoring: Default::default(),
+            retention: Default::default(),
             preferred_companies: vec![],
             blocked_companies: vec![],
         }
@@ -360,4 +445,78 @@ mod tests {
         assert!(runtime_config.read().await.remoteok.enabled);
         assert!(Config::load(&config_path).unwrap().remoteok.enabled);
     }
+
+    #[test]
+    fn builtin_toggle_fills_search_from_preferences() {
+        let mut config = create_health_toggle_test_config();
+        config.location_preferences.allow_hybrid = true;
+        config.location_preferences.cities = vec!["Denver, CO".to_string(), " ".to_string()];
+
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "builtin",
+            true
+        ));
+
+        assert_eq!(config.builtin.query, "Program Coordinator");
+        assert_eq!(config.builtin.cities, vec!["Denver, CO"]);
+    }
+
+    #[test]
+    fn arbeitnow_toggle_attaches_board_to_germany() {
+        let mut config = create_health_toggle_test_config();
+
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "arbeitnow",
+            true
+        ));
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "arbeitnow",
+            true
+        ));
+        assert_eq!(config.location_preferences.countries.len(), 1);
+        assert_eq!(config.location_preferences.countries[0].code, "DE");
+        assert_eq!(
+            config.location_preferences.countries[0].boards,
+            vec!["arbeitnow"]
+        );
+
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "arbeitnow",
+            false
+        ));
+        assert!(!config
+            .location_preferences
+            .country_board_enabled("arbeitnow"));
+        assert_eq!(config.location_preferences.countries.len(), 1);
+    }
+
+    #[test]
+    fn guardian_jobs_toggle_attaches_board_to_the_united_kingdom() {
+        let mut config = create_health_toggle_test_config();
+
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "guardian_jobs",
+            true
+        ));
+        assert_eq!(config.location_preferences.countries.len(), 1);
+        assert_eq!(config.location_preferences.countries[0].code, "GB");
+        assert_eq!(
+            config.location_preferences.countries[0].boards,
+            vec!["guardian_jobs"]
+        );
+
+        assert!(apply_config_backed_scraper_toggle(
+            &mut config,
+            "guardian_jobs",
+            false
+        ));
+        assert!(!config
+            .location_preferences
+            .country_board_enabled("guardian_jobs"));
+    }
 }
@@ -44,7 +44,7 @@ pub(crate) async fn confirm_job_import(
     .map_err(|error| format_import_error(&error))
 }
 
-fn format_import_error(error: &ImportError) -> String {
+pub(super) fn format_import_error(error: &ImportError) -> String {
     match error {
         ImportError::NoSchemaOrgData => {
             "Could not read this page as a single job posting. Open one job posting, copy its browser address, or save the job with the details JobSentinel can find.".to_string()
@@ -0,0 +1,68 @@
+//! Thin Tauri adapter for refreshing one saved posting on demand.
+
+use crate::application::scheduler::{
+    refresh_job as refresh_saved_job, JobRefresh, JobRefreshError,
+};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use crate::ipc::import::format_import_error;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+
+/// Re-fetch a job posting from its source now, outside the scrape schedule
+///
+/// When the posting is gone and its application is still waiting in
+/// `to_apply`, emits `application:posting_closed` so the UI can alert.
+#[tauri::command]
+#[tracing::instrument(skip(app, state), fields(job_id), level = "info")]
+pub(crate) async fn refresh_job(
+    job_id: i64,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<JobRefresh, String> {
+    let config = Arc::new(state.config.read().await.clone());
+    refresh_saved_job(&config, &state.database, job_id)
+        .await
+        .map_err(|error| {
+            if let JobRefreshError::PostingRemoved {
+                closed_application: Some(application),
+                ..
+            } = &error
+            {
+                if application.needs_attention() {
+                    let _ = app.emit("application:posting_closed", application);
+                }
+            }
+            format_refresh_error(&error)
+        })
+}
+
+fn format_refresh_error(error: &JobRefreshError) -> String {
+    match error {
+        JobRefreshError::NotFound => "This job is no longer in your saved jobs.".to_string(),
+        JobRefreshError::PostingRemoved { .. } => {
+            "This posting is no longer online. The employer may have filled or closed it."
+                .to_string()
+        }
+        JobRefreshError::Fetch(import_error) => format_import_error(import_error),
+        JobRefreshError::Database(details) => {
+            user_friendly_error("Failed to save the refreshed job", details)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removed_postings_get_a_plain_message() {
+        let message = format_refresh_error(&JobRefreshError::PostingRemoved {
+            status: 410,
+            closed_application: None,
+        });
+
+        assert!(message.contains("no longer online"));
+        assert!(!message.contains("410"));
+    }
+}
@@ -1,14 +1,28 @@
 //! Job-related Tauri commands
 //!
-//! Commands for job searching, retrieval, bookmarking, notes, and deduplication.
+//! Commands for job searching, retrieval, bookmarking, notes, tags,
+//! deduplication, and the cross-source copies collapsed into each job.
 
+use crate::application::export::{self, ExportColumn, ExportFormat};
+use crate::application::scheduler::ScrapingResult;
 use crate::bootstrap::AppState;
-use crate::desktop::DuplicateGroup;
+use crate::desktop::{
+    DatabaseBackup, DatabaseHealth, DuplicateGroup, JobCursor, JobFilter, JobPage, JobTagCount,
+    NearDuplicateGroup, PurgeReport, TimeToFillEstimate,
+};
 use crate::ipc::errors::user_friendly_error;
+use crate::ipc::ghost::current_ghost_config;
 use crate::ipc::limits::validate_command_limit_usize_as_i64;
 use serde_json::Value;
 use std::sync::Arc;
 use tauri::State;
+use tauri_plugin_dialog::DialogExt;
+
+const DEFAULT_NEAR_DUPLICATE_THRESHOLD: f64 = 0.8;
+/// Longest tag accepted, matching storage.
+const MAX_TAG_CHARS: usize = 40;
+/// Below this, clusters are mostly unrelated roles at the same company.
+const MIN_NEAR_DUPLICATE_THRESHOLD: f64 = 0.5;
 
 fn serialize_job(job_id: i64, job: &impl serde::Serialize) -> Option<Value> {
     serde_json::to_value(job)
@@ -23,6 +37,20 @@ fn serialize_job(job_id: i64, job: &impl serde::Serialize) -> Option<Value> {
         .ok()
 }
 
+/// The result of a manual scrape as returned to the frontend
+pub(crate) fn scraping_result_json(result: &ScrapingResult) -> Value {
+    serde_json::json!({
+        "success": true,
+        "jobs_found": result.jobs_found,
+        "jobs_new": result.jobs_new,
+        "jobs_updated": result.jobs_updated,
+        "high_matches": result.high_matches,
+        "alerts_sent": result.alerts_sent,
+        "errors": result.errors,
+        "throttle_events": result.throttle_events,
+    })
+}
+
 /// Search for jobs from all enabled sources
 ///
 /// This triggers a full scraping cycle across Greenhouse, Lever, and JobsWithGPT.
@@ -51,15 +79,7 @@ pub(crate) async fn search_jobs(state: State<'_, AppState>) -> Result<Value, Str
                 "Manual search completed successfully"
             );
 
-            Ok(serde_json::json!({
-                "success": true,
-                "jobs_found": result.jobs_found,
-                "jobs_new": result.jobs_new,
-                "jobs_updated": result.jobs_updated,
-                "high_matches": result.high_matches,
-                "alerts_sent": result.alerts_sent,
-                "errors": result.errors,
-            }))
+            Ok(scraping_result_json(&result))
         }
         Err(e) => {
             let message = user_friendly_error("Search failed", e);
@@ -83,7 +103,9 @@ pub(crate) async fn get_recent_jobs(
     let limit = validate_command_limit_usize_as_i64(limit)?;
     match state.database.get_recent_jobs(limit).await {
         Ok(jobs) => {
-            let jobs_json: Vec<Value> = jobs
+            let jobs_json: Vec<Value> = state
+                .guest_mode
+                .mask(jobs)
                 .into_iter()
                 .filter_map(|job| serialize_job(job.id, &job))
                 .collect();
@@ -112,7 +134,10 @@ pub(crate) async fn get_job_by_id(
         Ok(job) => {
             let found = job.is_some();
             tracing::debug!(found, "Job lookup complete");
-            Ok(job.and_then(|job| serialize_job(job.id, &job)))
+            Ok(state
+                .guest_mode
+                .mask(job)
+                .and_then(|job| serialize_job(job.id, &job)))
         }
         Err(e) => {
             tracing::error!(
@@ -125,25 +150,48 @@ pub(crate) async fn get_job_by_id(
 }
 
 /// Search jobs with filter
+///
+/// When `tag` is given, only jobs carrying that tag are searched. Jobs at or
+/// above the configured posting-risk hide threshold are left out unless
+/// `include_ghost` is set. Each job carries a `matches` field with its title
+/// and a description snippet split into matched and unmatched runs.
 #[tauri::command]
 #[tracing::instrument(skip(state))]
 pub(crate) async fn search_jobs_query(
     query: String,
     limit: usize,
+    tag: Option<String>,
+    include_ghost: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<Vec<Value>, String> {
     tracing::info!(
         query_chars = query.chars().count(),
         limit,
+        tagged = tag.is_some(),
         "Command: search_jobs_query"
     );
 
     let limit = validate_command_limit_usize_as_i64(limit)?;
-    match state.database.search_jobs(&query, limit).await {
-        Ok(jobs) => {
-            let jobs_json: Vec<Value> = jobs
+    let tag = tag.as_deref().map(tag_arg).transpose()?;
+    let hide_ghost_score = if include_ghost.unwrap_or(false) {
+        None
+    } else {
+        Some(current_ghost_config(&state).await.hide_threshold)
+    };
+    match state
+        .database
+        .search_jobs_with_matches(&query, tag, hide_ghost_score, limit)
+        .await
+    {
+        Ok(hits) => {
+            let jobs_json: Vec<Value> = hits
                 .into_iter()
-                .filter_map(|job| serialize_job(job.id, &job))
+                .filter_map(|hit| {
+                    let job = state.guest_mode.mask(hit.job);
+                    let mut value = serialize_job(job.id, &job)?;
+                    value["matches"] = serde_json::to_value(hit.matches).ok()?;
+                    Some(value)
+                })
                 .collect();
 
             Ok(jobs_json)
@@ -229,7 +277,9 @@ pub(crate) async fn get_bookmarked_jobs(
     let limit = validate_command_limit_usize_as_i64(limit)?;
     match state.database.get_bookmarked_jobs(limit).await {
         Ok(jobs) => {
-            let jobs_json: Vec<Value> = jobs
+            let jobs_json: Vec<Value> = state
+                .guest_mode
+                .mask(jobs)
                 .into_iter()
                 .filter_map(|job| serialize_job(job.id, &job))
                 .collect();
@@ -294,6 +344,405 @@ pub(crate) async fn get_job_notes(
     }
 }
 
+fn tag_arg(tag: &str) -> Result<&str, String> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err("Tag is required".to_string());
+    }
+    if tag.chars().count() > MAX_TAG_CHARS {
+        return Err(format!("Tags can be up to {MAX_TAG_CHARS} characters"));
+    }
+    Ok(tag)
+}
+
+/// Add a tag to a job; returns false if the job already had it
+#[tauri::command]
+pub(crate) async fn tag_job(
+    id: i64,
+    tag: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(job_id = id, "Command: tag_job");
+
+    state
+        .database
+        .tag_job(id, tag_arg(&tag)?)
+        .await
+        .map_err(|e| user_friendly_error("Failed to save tag", e))
+}
+
+/// Remove a tag from a job; returns false if the job did not have it
+#[tauri::command]
+pub(crate) async fn untag_job(
+    id: i64,
+    tag: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(job_id = id, "Command: untag_job");
+
+    state
+        .database
+        .untag_job(id, tag_arg(&tag)?)
+        .await
+        .map_err(|e| user_friendly_error("Failed to remove tag", e))
+}
+
+/// Get the tags on a job
+#[tauri::command]
+pub(crate) async fn get_job_tags(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    tracing::debug!(job_id = id, "Command: get_job_tags");
+
+    state
+        .database
+        .get_job_tags(id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load tags", e))
+}
+
+/// List every tag in use with how many visible jobs carry it
+#[tauri::command]
+pub(crate) async fn list_job_tags(state: State<'_, AppState>) -> Result<Vec<JobTagCount>, String> {
+    tracing::debug!("Command: list_job_tags");
+
+    state
+        .database
+        .list_job_tags()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load tags", e))
+}
+
+/// Get visible jobs carrying a tag
+#[tauri::command]
+pub(crate) async fn get_jobs_by_tag(
+    tag: String,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Vec<Value>, String> {
+    tracing::info!(limit, "Command: get_jobs_by_tag");
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    let jobs = state
+        .database
+        .get_jobs_by_tag(tag_arg(&tag)?, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load tagged jobs", e))?;
+    Ok(state
+        .guest_mode
+        .mask(jobs)
+        .into_iter()
+        .filter_map(|job| serialize_job(job.id, &job))
+        .collect())
+}
+
+fn validate_job_filter(filter: &JobFilter) -> Result<(), String> {
+    for score in [filter.min_score, filter.max_score, filter.max_ghost_score]
+        .into_iter()
+        .flatten()
+    {
+        if !(0.0..=1.0).contains(&score) {
+            return Err("Score filters must be between 0 and 1".to_string());
+        }
+    }
+    for tag in &filter.tags {
+        tag_arg(tag)?;
+    }
+    Ok(())
+}
+
+/// Query jobs with structured filters (score range, sources, remote, salary,
+/// posted date, posting risk, tags, status)
+#[tauri::command]
+pub(crate) async fn query_jobs(
+    filter: JobFilter,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Vec<Value>, String> {
+    tracing::info!(
+        limit,
+        sources = filter.sources.len(),
+        tags = filter.tags.len(),
+        "Command: query_jobs"
+    );
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    validate_job_filter(&filter)?;
+
+    let jobs = state
+        .database
+        .query_jobs(&filter, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to query jobs", e))?;
+    Ok(state
+        .guest_mode
+        .mask(jobs)
+        .into_iter()
+        .filter_map(|job| serialize_job(job.id, &job))
+        .collect())
+}
+
+/// A page of jobs with `jobs` serialized like the other job lists
+fn serialize_job_page(page: JobPage, state: &AppState) -> Value {
+    let jobs: Vec<Value> = state
+        .guest_mode
+        .mask(page.jobs)
+        .into_iter()
+        .filter_map(|job| serialize_job(job.id, &job))
+        .collect();
+    serde_json::json!({ "jobs": jobs, "next_cursor": page.next_cursor })
+}
+
+/// Get a page of recent jobs, newest first. Pass the previous page's
+/// `next_cursor` to continue.
+#[tauri::command]
+pub(crate) async fn get_recent_jobs_page(
+    cursor: Option<JobCursor>,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(
+        limit,
+        paged = cursor.is_some(),
+        "Command: get_recent_jobs_page"
+    );
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    let page = state
+        .database
+        .get_recent_jobs_page(cursor, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load jobs", e))?;
+    Ok(serialize_job_page(page, &state))
+}
+
+/// Get a page of bookmarked jobs, newest first
+#[tauri::command]
+pub(crate) async fn get_bookmarked_jobs_page(
+    cursor: Option<JobCursor>,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(
+        limit,
+        paged = cursor.is_some(),
+        "Command: get_bookmarked_jobs_page"
+    );
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    let page = state
+        .database
+        .get_bookmarked_jobs_page(cursor, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load bookmarked jobs", e))?;
+    Ok(serialize_job_page(page, &state))
+}
+
+/// Get a page of jobs from one source, newest first
+#[tauri::command]
+pub(crate) async fn get_jobs_by_source_page(
+    source: String,
+    cursor: Option<JobCursor>,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(
+        limit,
+        paged = cursor.is_some(),
+        "Command: get_jobs_by_source_page"
+    );
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    let page = state
+        .database
+        .get_jobs_by_source_page(&source, cursor, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load jobs by source", e))?;
+    Ok(serialize_job_page(page, &state))
+}
+
+/// Get a page of jobs carrying a tag, newest first
+#[tauri::command]
+pub(crate) async fn get_jobs_by_tag_page(
+    tag: String,
+    cursor: Option<JobCursor>,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(
+        limit,
+        paged = cursor.is_some(),
+        "Command: get_jobs_by_tag_page"
+    );
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    let page = state
+        .database
+        .get_jobs_by_tag_page(tag_arg(&tag)?, cursor, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load tagged jobs", e))?;
+    Ok(serialize_job_page(page, &state))
+}
+
+/// Get a page of jobs matching structured filters, newest first
+#[tauri::command]
+pub(crate) async fn query_jobs_page(
+    filter: JobFilter,
+    cursor: Option<JobCursor>,
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(limit, paged = cursor.is_some(), "Command: query_jobs_page");
+
+    let limit = validate_command_limit_usize_as_i64(limit)?;
+    validate_job_filter(&filter)?;
+    let page = state
+        .database
+        .query_jobs_page(&filter, cursor, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to query jobs", e))?;
+    Ok(serialize_job_page(page, &state))
+}
+
+/// Get database size, WAL, connection pool, and index diagnostics
+#[tauri::command]
+pub(crate) async fn db_health(state: State<'_, AppState>) -> Result<DatabaseHealth, String> {
+    tracing::info!("Command: db_health");
+
+    state
+        .database
+        .db_health()
+        .await
+        .map_err(|e| user_friendly_error("Failed to check database health", e))
+}
+
+/// Snapshot the database into the backups folder next to it
+///
+/// Only the newest manual backups are kept.
+#[tauri::command]
+pub(crate) async fn create_backup(state: State<'_, AppState>) -> Result<DatabaseBackup, String> {
+    tracing::info!("Command: create_backup");
+
+    state
+        .database
+        .create_backup()
+        .await
+        .map_err(|e| user_friendly_error("Failed to back up database", e))
+}
+
+/// List database backups, newest first
+#[tauri::command]
+pub(crate) async fn list_backups(
+    state: State<'_, AppState>,
+) -> Result<Vec<DatabaseBackup>, String> {
+    tracing::info!("Command: list_backups");
+
+    state
+        .database
+        .list_backups()
+        .map_err(|e| user_friendly_error("Failed to list database backups", e))
+}
+
+/// Stage a backup to replace the database on the next app start
+///
+/// The open database is left alone; the current file is kept as a
+/// before-restore backup when the restore is applied.
+#[tauri::command]
+pub(crate) async fn restore_backup(
+    file_name: String,
+    state: State<'_, AppState>,
+) -> Result<DatabaseBackup, String> {
+    tracing::info!("Command: restore_backup");
+
+    state
+        .database
+        .restore_backup(&file_name)
+        .await
+        .map_err(|e| user_friendly_error("Failed to restore database backup", e))
+}
+
+/// Apply the retention rules now
+///
+/// With `dry_run` set, nothing is deleted and the report lists what would be.
+#[tauri::command]
+pub(crate) async fn purge_now(
+    dry_run: bool,
+    state: State<'_, AppState>,
+) -> Result<PurgeReport, String> {
+    tracing::info!(dry_run, "Command: purge_now");
+
+    let scheduler = state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(
+            crate::application::scheduler::Scheduler::new_shared_with_credentials(
+                state.config.clone(),
+                state.database.clone(),
+                state.credentials.clone(),
+            ),
+        )
+    });
+    scheduler
+        .purge_jobs(dry_run)
+        .await
+        .map_err(|e| user_friendly_error("Failed to purge old jobs", e))
+}
+
+/// Export the jobs matching a filter to a file the user picks
+///
+/// Returns the number of jobs written, or `None` when the save dialog is
+/// cancelled. Guest mode redactions apply to the exported rows.
+#[tauri::command]
+pub(crate) async fn export_jobs(
+    app: tauri::AppHandle,
+    filter: JobFilter,
+    columns: Vec<ExportColumn>,
+    format: ExportFormat,
+    state: State<'_, AppState>,
+) -> Result<Option<u64>, String> {
+    tracing::info!(?format, columns = columns.len(), "Command: export_jobs");
+    validate_job_filter(&filter)?;
+
+    let extension = format.extension();
+    let Some(file_path) = app
+        .dialog()
+        .file()
+        .add_filter(extension.to_uppercase(), &[extension])
+        .set_file_name(format!(
+            "jobsentinel-jobs-{}.{extension}",
+            chrono::Local::now().format("%Y-%m-%d")
+        ))
+        .blocking_save_file()
+    else {
+        return Ok(None);
+    };
+    let path = file_path
+        .into_path()
+        .map_err(|_| "Invalid export file path".to_string())?;
+
+    let file = std::fs::File::create(&path)
+        .map_err(|e| user_friendly_error("Failed to create export file", e))?;
+    let mut out = std::io::BufWriter::new(file);
+    let exported = export::export_jobs(
+        &state.database,
+        &filter,
+        &columns,
+        format,
+        &mut out,
+        |jobs| state.guest_mode.mask(jobs),
+    )
+    .await;
+    drop(out);
+
+    match exported {
+        Ok(rows) => Ok(Some(rows)),
+        Err(e) => {
+            // Leave no partial file behind.
+            let _ = std::fs::remove_file(&path);
+            Err(user_friendly_error("Failed to export jobs", e))
+        }
+    }
+}
+
 /// Get application statistics
 #[tauri::command]
 pub(crate) async fn get_statistics(state: State<'_, AppState>) -> Result<Value, String> {
@@ -317,14 +766,24 @@ pub(crate) async fn get_statistics(state: State<'_, AppState>) -> Result<Value,
 pub(crate) async fn get_scraping_status(state: State<'_, AppState>) -> Result<Value, String> {
     tracing::info!("Command: get_scraping_status");
 
+    let paused_at = state
+        .database
+        .scraping_paused_at()
+        .await
+        .map_err(|e| user_friendly_error("Failed to read the scraping pause", e))?;
     let status = state.scheduler_status.read().await;
     let config = state.config.read().await;
 
+    // No scheduled run happens while paused, whatever the last plan was.
+    let next_run = status.next_run.filter(|_| paused_at.is_none());
     Ok(serde_json::json!({
         "is_running": status.is_running,
         "last_scrape": status.last_run.map(|dt| dt.to_rfc3339()),
-        "next_scrape": status.next_run.map(|dt| dt.to_rfc3339()),
+        "next_scrape": next_run.map(|dt| dt.to_rfc3339()),
         "interval_hours": config.scraping_interval_hours,
+        "paused": paused_at.is_some(),
+        "paused_at": paused_at.map(|dt| dt.to_rfc3339()),
+        "skip_reason": status.skip_reason,
     }))
 }
 
@@ -342,6 +801,46 @@ pub(crate) async fn find_duplicates(
         .map_err(|e| user_friendly_error("Database operation failed", e))
 }
 
+/// Find clusters of near-identical postings (same company, similar title and
+/// description). Merge a cluster with `merge_duplicates`.
+#[tauri::command]
+pub(crate) async fn find_near_duplicates(
+    threshold: Option<f64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<NearDuplicateGroup>, String> {
+    let threshold = threshold.unwrap_or(DEFAULT_NEAR_DUPLICATE_THRESHOLD);
+    tracing::info!(threshold, "Command: find_near_duplicates");
+
+    if !(MIN_NEAR_DUPLICATE_THRESHOLD..=1.0).contains(&threshold) {
+        return Err(format!(
+            "Similarity threshold must be between {MIN_NEAR_DUPLICATE_THRESHOLD} and 1.0"
+        ));
+    }
+
+    state
+        .database
+        .find_near_duplicate_groups(threshold)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
+/// Estimate how quickly the job's company usually closes similar postings
+///
+/// Returns `None` until the company has enough closed postings on record.
+#[tauri::command]
+pub(crate) async fn get_time_to_fill_estimate(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<TimeToFillEstimate>, String> {
+    tracing::debug!(job_id, "Command: get_time_to_fill_estimate");
+
+    state
+        .database
+        .get_time_to_fill_estimate(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to estimate time to fill", e))
+}
+
 /// Merge duplicate jobs: keep primary, hide duplicates
 #[tauri::command]
 pub(crate) async fn merge_duplicates(
@@ -362,6 +861,43 @@ pub(crate) async fn merge_duplicates(
         .map_err(|e| user_friendly_error("Database operation failed", e))
 }
 
+/// Get the copies of a job found on other sources
+///
+/// Copies are linked after each search and left out of job lists and search
+/// results in favor of the job they were linked to.
+#[tauri::command]
+pub(crate) async fn get_job_duplicates(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<Value>, String> {
+    tracing::debug!(job_id, "Command: get_job_duplicates");
+
+    let jobs = state
+        .database
+        .get_job_duplicates(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load job copies", e))?;
+    Ok(jobs
+        .iter()
+        .filter_map(|job| serialize_job(job.id, job))
+        .collect())
+}
+
+/// Show a linked copy as its own job again; it is not linked again later
+#[tauri::command]
+pub(crate) async fn unlink_job_duplicate(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(job_id, "Command: unlink_job_duplicate");
+
+    state
+        .database
+        .unlink_job_duplicate(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
 /// Job count by source for analytics
 #[derive(serde::Serialize)]
 pub(crate) struct JobsBySource {
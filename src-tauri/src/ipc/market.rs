@@ -3,13 +3,16 @@
 //! Commands for skill trends, company activity, location analysis, and market alerts.
 
 use crate::application::market_intelligence::{
-    CompanyActivity, LocationHeat, MarketAlert, MarketSnapshot, SkillTrend,
+    build_market_report, CompanyActivity, LocationHeat, MarketAlert, MarketReportFormat,
+    MarketReportPeriod, MarketSnapshot, SeriesGranularity, SeriesPoint, SkillGap, SkillTrend,
 };
 use crate::bootstrap::AppState;
 use crate::ipc::errors::user_friendly_error;
 use crate::ipc::limits::validate_command_limit_usize;
+use chrono::NaiveDate;
 use serde_json::Value;
 use tauri::State;
+use tauri_plugin_dialog::DialogExt;
 
 const MAX_HISTORICAL_SNAPSHOT_DAYS: i64 = 3_650;
 
@@ -24,6 +27,19 @@ fn validate_historical_snapshot_days(days: i64) -> Result<usize, String> {
     usize::try_from(days).map_err(|_| "days is outside supported range".to_string())
 }
 
+fn validate_series_range(start_date: NaiveDate, end_date: NaiveDate) -> Result<(), String> {
+    if end_date < start_date {
+        return Err("endDate must be on or after startDate".to_string());
+    }
+    if (end_date - start_date).num_days() >= MAX_HISTORICAL_SNAPSHOT_DAYS {
+        return Err(format!(
+            "date range must be shorter than {} days",
+            MAX_HISTORICAL_SNAPSHOT_DAYS
+        ));
+    }
+    Ok(())
+}
+
 /// Get trending skills
 #[tauri::command]
 pub(crate) async fn get_trending_skills(
@@ -40,13 +56,29 @@ pub(crate) async fn get_trending_skills(
         .map_err(|e| user_friendly_error("Failed to get trending skills", e))
 }
 
+/// Get in-demand skills the active resume does not list
+#[tauri::command]
+pub(crate) async fn get_personal_skill_gaps(
+    limit: usize,
+    state: State<'_, AppState>,
+) -> Result<Vec<SkillGap>, String> {
+    tracing::info!("Command: get_personal_skill_gaps (limit: {})", limit);
+
+    let limit = validate_command_limit_usize(limit)?;
+    let intel = state.database.market_intelligence();
+    intel
+        .get_personal_skill_gaps(limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get skill gaps", e))
+}
+
 /// Get most active hiring companies
 #[tauri::command]
-pub(crate) async fn get_active_companies(
+pub(crate) async fn get_most_active_companies(
     limit: usize,
     state: State<'_, AppState>,
 ) -> Result<Vec<CompanyActivity>, String> {
-    tracing::info!("Command: get_active_companies (limit: {})", limit);
+    tracing::info!("Command: get_most_active_companies (limit: {})", limit);
 
     let limit = validate_command_limit_usize(limit)?;
     let intel = state.database.market_intelligence();
@@ -129,6 +161,118 @@ pub(crate) async fn get_historical_snapshots(
         .map_err(|e| user_friendly_error("Failed to get historical snapshots", e))
 }
 
+/// Get skill mentions per day, week, or month for a chart
+///
+/// Without a skill name, mentions of every skill are added together.
+#[tauri::command]
+pub(crate) async fn get_skill_mention_series(
+    skill_name: Option<String>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    granularity: SeriesGranularity,
+    state: State<'_, AppState>,
+) -> Result<Vec<SeriesPoint>, String> {
+    tracing::info!(
+        skill_named = skill_name.is_some(),
+        %start_date,
+        %end_date,
+        ?granularity,
+        "Command: get_skill_mention_series"
+    );
+
+    validate_series_range(start_date, end_date)?;
+    let intel = state.database.market_intelligence();
+    intel
+        .get_skill_mention_series(skill_name.as_deref(), start_date, end_date, granularity)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get skill mention series", e))
+}
+
+/// Get the median market salary per day, week, or month for a chart
+#[tauri::command]
+pub(crate) async fn get_median_salary_series(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    granularity: SeriesGranularity,
+    state: State<'_, AppState>,
+) -> Result<Vec<SeriesPoint>, String> {
+    tracing::info!(
+        %start_date,
+        %end_date,
+        ?granularity,
+        "Command: get_median_salary_series"
+    );
+
+    validate_series_range(start_date, end_date)?;
+    let intel = state.database.market_intelligence();
+    intel
+        .get_median_salary_series(start_date, end_date, granularity)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get median salary series", e))
+}
+
+/// Get jobs posted per day, week, or month for a chart
+///
+/// Without a company name, postings from every company are added together.
+#[tauri::command]
+pub(crate) async fn get_company_posting_series(
+    company_name: Option<String>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    granularity: SeriesGranularity,
+    state: State<'_, AppState>,
+) -> Result<Vec<SeriesPoint>, String> {
+    tracing::info!(
+        company_named = company_name.is_some(),
+        %start_date,
+        %end_date,
+        ?granularity,
+        "Command: get_company_posting_series"
+    );
+
+    validate_series_range(start_date, end_date)?;
+    let intel = state.database.market_intelligence();
+    intel
+        .get_company_posting_series(company_name.as_deref(), start_date, end_date, granularity)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get company posting series", e))
+}
+
+/// Save a market report for `period` to a file the user picks
+///
+/// Returns the saved file path, or `None` when the save dialog is cancelled.
+#[tauri::command]
+pub(crate) async fn export_market_report(
+    app: tauri::AppHandle,
+    period: MarketReportPeriod,
+    format: MarketReportFormat,
+    state: State<'_, AppState>,
+) -> Result<Option<String>, String> {
+    tracing::info!(?period, ?format, "Command: export_market_report");
+
+    let report = build_market_report(&state.database, period, chrono::Utc::now())
+        .await
+        .map_err(|e| user_friendly_error("Failed to build market report", e))?;
+
+    let extension = format.extension();
+    let Some(file_path) = app
+        .dialog()
+        .file()
+        .add_filter(extension.to_uppercase(), &[extension])
+        .set_file_name(format!("{}.{extension}", report.file_stem()))
+        .blocking_save_file()
+    else {
+        return Ok(None);
+    };
+    let path = file_path
+        .into_path()
+        .map_err(|_| "Invalid report file path".to_string())?;
+
+    std::fs::write(&path, format.render(&report))
+        .map_err(|e| user_friendly_error("Failed to save market report", e))?;
+    Ok(Some(path.display().to_string()))
+}
+
 /// Mark a single alert as read
 #[tauri::command]
 pub(crate) async fn mark_alert_read(id: i64, state: State<'_, AppState>) -> Result<bool, String> {
@@ -153,9 +297,27 @@ pub(crate) async fn mark_all_alerts_read(state: State<'_, AppState>) -> Result<u
         .map_err(|e| user_friendly_error("Failed to mark all alerts as read", e))
 }
 
+/// Dismiss an alert, removing it from the alert list and weekly reports
+#[tauri::command]
+pub(crate) async fn dismiss_market_alert(
+    id: i64,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!("Command: dismiss_market_alert (id: {})", id);
+
+    let intel = state.database.market_intelligence();
+    intel
+        .dismiss_alert(id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to dismiss alert", e))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{validate_historical_snapshot_days, MAX_HISTORICAL_SNAPSHOT_DAYS};
+    use super::{
+        validate_historical_snapshot_days, validate_series_range, MAX_HISTORICAL_SNAPSHOT_DAYS,
+    };
+    use chrono::{Duration, NaiveDate};
 
     #[test]
     fn validates_positive_historical_snapshot_days() {
@@ -172,4 +334,16 @@ mod tests {
     fn rejects_unbounded_historical_snapshot_days() {
         assert!(validate_historical_snapshot_days(MAX_HISTORICAL_SNAPSHOT_DAYS + 1).is_err());
     }
+
+    #[test]
+    fn series_ranges_must_be_ordered_and_bounded() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+
+        assert!(validate_series_range(start, start).is_ok());
+        assert!(validate_series_range(start, start - Duration::days(1)).is_err());
+        assert!(
+            validate_series_range(start, start + Duration::days(MAX_HISTORICAL_SNAPSHOT_DAYS))
+                .is_err()
+        );
+    }
 }
@@ -1,10 +1,12 @@
 //! Private Tauri command adapters grouped by product behavior.
 
 pub(crate) mod ats;
+pub(crate) mod attachments;
 pub(crate) mod automation;
 pub(crate) mod bookmarklet;
 pub(crate) mod cache;
 pub(crate) mod config;
+pub(crate) mod contacts;
 pub(crate) mod credentials;
 pub(crate) mod deeplinks;
 pub(crate) mod errors;
@@ -12,19 +14,28 @@ pub(crate) mod external_ai;
 pub(crate) mod feedback;
 pub(crate) mod geo;
 pub(crate) mod ghost;
+pub(crate) mod guest_mode;
 pub(crate) mod health;
 pub(crate) mod import;
+pub(crate) mod job_refresh;
 pub(crate) mod jobs;
 pub(crate) mod limits;
 pub(crate) mod linkedin_auth;
 pub(crate) mod linkedin_workbench;
 pub(crate) mod market;
+pub(crate) mod notification_history;
+pub(crate) mod notification_templates;
+pub(crate) mod quick_actions;
 pub(crate) mod resume;
 mod resume_file_names;
 pub(crate) mod salary;
 pub(crate) mod scoring;
+pub(crate) mod scraping_control;
 pub(crate) mod semantic_matching;
+pub(crate) mod setup;
 pub(crate) mod user_data;
+pub(crate) mod vacation_mode;
+pub(crate) mod weekly_reports;
 
 #[cfg(feature = "embedded-ml")]
 pub(crate) mod ml;
@@ -0,0 +1,65 @@
+//! Notification delivery history and routing Tauri commands
+//!
+//! Every immediate alert goes through the notification outbox, so users can
+//! check whether an alert was sent, is waiting to be retried, or failed, and
+//! preview which channels a job would alert before it does.
+
+use crate::application::notify::{preview_route, RoutePreview};
+use crate::bootstrap::AppState;
+use crate::desktop::NotificationHistoryEntry;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+const DEFAULT_HISTORY_LIMIT: i64 = 100;
+const MAX_HISTORY_LIMIT: i64 = 500;
+
+/// Recent alerts with their delivery status, newest first
+#[tauri::command]
+pub(crate) async fn get_notification_history(
+    limit: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<NotificationHistoryEntry>, String> {
+    tracing::info!(?limit, "Command: get_notification_history");
+
+    let limit = limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT);
+    state
+        .database
+        .notification_history(limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load notification history", e))
+}
+
+/// Dry run of notification routing for a saved job
+///
+/// Uses the job's saved match score and the current settings and
+/// notification preferences. Nothing is sent.
+#[tauri::command]
+pub(crate) async fn preview_notification_route(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<RoutePreview, String> {
+    tracing::info!(job_id, "Command: preview_notification_route");
+
+    let job = state
+        .database
+        .get_job_by_id(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load job details", e))?
+        .ok_or_else(|| "Job not found".to_string())?;
+    let preferences = state
+        .database
+        .user_data_manager()
+        .get_notification_preferences()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load notification preferences", e))?;
+    let config = state.config.read().await;
+
+    Ok(preview_route(
+        &config,
+        &preferences,
+        &job,
+        job.score.unwrap_or(0.0),
+    ))
+}
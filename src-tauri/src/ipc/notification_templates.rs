@@ -0,0 +1,69 @@
+//! Alert message template Tauri commands
+//!
+//! Slack, Discord, and email alerts can use a `{{field}}` message template
+//! instead of the default layout. Templates are saved with the user data.
+
+use crate::application::notify::{preview_message_template, validate_message_template};
+use crate::application::user_data::{MessageTemplateChannel, NotificationMessageTemplates};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+/// Saved message templates. Channels without one use the default layout.
+#[tauri::command]
+pub(crate) async fn get_notification_message_templates(
+    state: State<'_, AppState>,
+) -> Result<NotificationMessageTemplates, String> {
+    tracing::info!("Command: get_notification_message_templates");
+
+    state
+        .database
+        .user_data_manager()
+        .get_message_templates()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load message templates", e))
+}
+
+/// Save the message template for one channel
+///
+/// An empty or missing template goes back to the default layout.
+#[tauri::command]
+pub(crate) async fn save_notification_message_template(
+    channel: MessageTemplateChannel,
+    template: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!(
+        channel = channel.as_str(),
+        "Command: save_notification_message_template"
+    );
+
+    let template = template.filter(|template| !template.trim().is_empty());
+    if let Some(template) = &template {
+        validate_message_template(template)?;
+    }
+    state
+        .database
+        .user_data_manager()
+        .save_message_template(channel, template.as_deref())
+        .await
+        .map_err(|e| user_friendly_error("Failed to save message template", e))
+}
+
+/// Render a message template with a saved job without sending it
+#[tauri::command]
+pub(crate) async fn preview_notification_message(
+    template: String,
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    tracing::info!(job_id, "Command: preview_notification_message");
+
+    let job = state
+        .database
+        .get_job_by_id(job_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load job details", e))?
+        .ok_or_else(|| "Job not found".to_string())?;
+    preview_message_template(&template, job)
+}
@@ -0,0 +1,80 @@
+//! Thin Tauri adapter for keyboard-first triage actions.
+
+use crate::application::quick_actions::{
+    QuickAction, QuickActionError, QuickActionOutcome, QuickActionRequest, QuickActionUndo,
+};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+/// Run one triage action: bookmark, dismiss, open, create application, or
+/// snooze reminder
+///
+/// Pass the `revision` from the previous outcome as `expected_revision` to
+/// reject the action if the target changed in the meantime, and reuse a
+/// `batch_id` to undo several actions together.
+#[tauri::command]
+#[tracing::instrument(skip(state, expected_revision), level = "info")]
+pub(crate) async fn quick_action(
+    action: QuickAction,
+    target_id: i64,
+    expected_revision: Option<String>,
+    batch_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<QuickActionOutcome, String> {
+    let request = QuickActionRequest {
+        action,
+        target_id,
+        expected_revision,
+        batch_id,
+    };
+    state
+        .quick_actions
+        .apply(&state.database, request)
+        .await
+        .map_err(|error| format_quick_action_error(&error))
+}
+
+/// Undo the latest batch of quick actions, or return `null` if there is none
+#[tauri::command]
+pub(crate) async fn undo_quick_action(
+    state: State<'_, AppState>,
+) -> Result<Option<QuickActionUndo>, String> {
+    tracing::info!("Command: undo_quick_action");
+
+    state
+        .quick_actions
+        .undo_latest(&state.database)
+        .await
+        .map_err(|error| format_quick_action_error(&error))
+}
+
+fn format_quick_action_error(error: &QuickActionError) -> String {
+    match error {
+        QuickActionError::NotFound => "This item is no longer available.".to_string(),
+        QuickActionError::Conflict { .. } => {
+            "This item changed since it was loaded. Refresh and try again.".to_string()
+        }
+        QuickActionError::ReminderCompleted => {
+            "This reminder is already done, so it cannot be snoozed.".to_string()
+        }
+        QuickActionError::Database(details) => {
+            user_friendly_error("Failed to apply the quick action", details)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicts_ask_for_a_refresh() {
+        let message = format_quick_action_error(&QuickActionError::Conflict {
+            current_revision: "job:1:0:1".to_string(),
+        });
+
+        assert!(message.contains("Refresh"));
+        assert!(!message.contains("job:1"));
+    }
+}
@@ -4,17 +4,44 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::jobs::search_jobs,
             jobsentinel::ipc::jobs::get_recent_jobs,
             jobsentinel::ipc::jobs::get_job_by_id,
+            jobsentinel::ipc::job_refresh::refresh_job,
             jobsentinel::ipc::jobs::search_jobs_query,
             jobsentinel::ipc::jobs::hide_job,
             jobsentinel::ipc::jobs::unhide_job,
             jobsentinel::ipc::jobs::toggle_bookmark,
             jobsentinel::ipc::jobs::get_bookmarked_jobs,
+            jobsentinel::ipc::quick_actions::quick_action,
+            jobsentinel::ipc::quick_actions::undo_quick_action,
             jobsentinel::ipc::jobs::set_job_notes,
             jobsentinel::ipc::jobs::get_job_notes,
+            jobsentinel::ipc::jobs::tag_job,
+            jobsentinel::ipc::jobs::untag_job,
+            jobsentinel::ipc::jobs::get_job_tags,
+            jobsentinel::ipc::jobs::list_job_tags,
+            jobsentinel::ipc::jobs::get_jobs_by_tag,
+            jobsentinel::ipc::jobs::query_jobs,
+            jobsentinel::ipc::jobs::query_jobs_page,
+            jobsentinel::ipc::jobs::get_recent_jobs_page,
+            jobsentinel::ipc::jobs::get_bookmarked_jobs_page,
+            jobsentinel::ipc::jobs::get_jobs_by_source_page,
+            jobsentinel::ipc::jobs::get_jobs_by_tag_page,
             jobsentinel::ipc::jobs::get_statistics,
+            jobsentinel::ipc::jobs::db_health,
+            jobsentinel::ipc::jobs::create_backup,
+            jobsentinel::ipc::jobs::list_backups,
+            jobsentinel::ipc::jobs::restore_backup,
+            jobsentinel::ipc::jobs::purge_now,
+            jobsentinel::ipc::jobs::export_jobs,
             jobsentinel::ipc::jobs::get_scraping_status,
+            jobsentinel::ipc::scraping_control::pause_scraping,
+            jobsentinel::ipc::scraping_control::resume_scraping,
+            jobsentinel::ipc::scraping_control::run_scrape_now,
             jobsentinel::ipc::jobs::find_duplicates,
+            jobsentinel::ipc::jobs::find_near_duplicates,
             jobsentinel::ipc::jobs::merge_duplicates,
+            jobsentinel::ipc::jobs::get_job_duplicates,
+            jobsentinel::ipc::jobs::unlink_job_duplicate,
+            jobsentinel::ipc::jobs::get_time_to_fill_estimate,
             jobsentinel::ipc::jobs::get_jobs_by_source,
             jobsentinel::ipc::jobs::get_salary_distribution,
             jobsentinel::ipc::config::save_config,
@@ -23,24 +50,74 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::config::get_resume_matching_preference,
             jobsentinel::ipc::config::set_resume_matching_enabled,
             jobsentinel::ipc::config::validate_slack_webhook,
+            jobsentinel::ipc::config::validate_discord_webhook,
+            jobsentinel::ipc::config::validate_teams_webhook,
+            jobsentinel::ipc::config::validate_custom_webhook,
+            jobsentinel::ipc::config::validate_telegram_bot,
+            jobsentinel::ipc::config::validate_ntfy_topic,
+            jobsentinel::ipc::config::validate_pushover,
             jobsentinel::ipc::config::is_first_run,
+            jobsentinel::ipc::config::get_config_recovery_notice,
             jobsentinel::ipc::config::complete_setup,
+            jobsentinel::ipc::setup::get_setup_presets,
+            jobsentinel::ipc::setup::apply_setup_preset,
+            jobsentinel::ipc::setup::check_setup_source,
+            jobsentinel::ipc::setup::import_setup_folder,
             jobsentinel::ipc::config::test_email_notification,
+            jobsentinel::ipc::config::send_test_email_digest,
+            jobsentinel::ipc::weekly_reports::generate_report_now,
+            jobsentinel::ipc::weekly_reports::get_weekly_reports,
+            jobsentinel::ipc::config::discover_company_board,
             jobsentinel::ipc::external_ai::send_external_ai_request,
             jobsentinel::ipc::geo::detect_location,
+            jobsentinel::ipc::guest_mode::get_guest_mode_status,
+            jobsentinel::ipc::guest_mode::enable_guest_mode,
+            jobsentinel::ipc::guest_mode::unlock_guest_mode,
+            jobsentinel::ipc::vacation_mode::set_vacation_mode,
+            jobsentinel::ipc::notification_history::get_notification_history,
+            jobsentinel::ipc::notification_history::preview_notification_route,
+            jobsentinel::ipc::notification_templates::get_notification_message_templates,
+            jobsentinel::ipc::notification_templates::save_notification_message_template,
+            jobsentinel::ipc::notification_templates::preview_notification_message,
             jobsentinel::ipc::ats::create_application,
             jobsentinel::ipc::ats::get_applications_kanban,
             jobsentinel::ipc::ats::update_application_status,
             jobsentinel::ipc::ats::add_application_notes,
+            jobsentinel::ipc::ats::set_reminder,
             jobsentinel::ipc::ats::get_pending_reminders,
             jobsentinel::ipc::ats::complete_reminder,
             jobsentinel::ipc::ats::detect_ghosted_applications,
             jobsentinel::ipc::ats::get_application_stats,
             jobsentinel::ipc::ats::schedule_interview,
+            jobsentinel::ipc::ats::reschedule_interview,
             jobsentinel::ipc::ats::get_upcoming_interviews,
             jobsentinel::ipc::ats::get_past_interviews,
             jobsentinel::ipc::ats::complete_interview,
             jobsentinel::ipc::ats::delete_interview,
+            jobsentinel::ipc::ats::export_interview_calendar,
+            jobsentinel::ipc::ats::subscribe_interview_calendar,
+            jobsentinel::ipc::ats::save_offer,
+            jobsentinel::ipc::ats::get_offers,
+            jobsentinel::ipc::ats::delete_offer,
+            jobsentinel::ipc::ats::bulk_create_applications,
+            jobsentinel::ipc::ats::bulk_update_application_status,
+            jobsentinel::ipc::ats::bulk_archive_rejected_applications,
+            jobsentinel::ipc::ats::generate_followup_draft,
+            jobsentinel::ipc::ats::generate_cover_letter,
+            jobsentinel::ipc::attachments::select_and_attach_file,
+            jobsentinel::ipc::attachments::get_attachments,
+            jobsentinel::ipc::attachments::open_attachment,
+            jobsentinel::ipc::attachments::delete_attachment,
+            jobsentinel::ipc::contacts::save_contact,
+            jobsentinel::ipc::contacts::update_contact,
+            jobsentinel::ipc::contacts::delete_contact,
+            jobsentinel::ipc::contacts::search_contacts,
+            jobsentinel::ipc::contacts::get_application_contacts,
+            jobsentinel::ipc::contacts::get_interview_contacts,
+            jobsentinel::ipc::contacts::link_contact,
+            jobsentinel::ipc::contacts::unlink_contact,
+            jobsentinel::ipc::contacts::log_contact_interaction,
+            jobsentinel::ipc::contacts::get_contact_interactions,
             jobsentinel::ipc::resume::resume_file_commands::select_and_upload_resume,
             jobsentinel::ipc::resume::resume_file_commands::import_json_resume,
             jobsentinel::ipc::resume::resume_file_commands::select_and_import_json_resume,
@@ -50,10 +127,19 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::resume::match_resume_to_job,
             jobsentinel::ipc::resume::get_match_result,
             jobsentinel::ipc::resume::get_recent_matches,
+            jobsentinel::ipc::resume::match_resume_to_all_jobs,
+            jobsentinel::ipc::resume::get_best_resume_matches,
+            jobsentinel::ipc::resume::suggest_resume_for_job,
+            jobsentinel::ipc::resume::list_resume_versions,
+            jobsentinel::ipc::resume::compare_resume_versions,
             jobsentinel::ipc::resume::get_resume_text_preview,
             jobsentinel::ipc::resume::update_user_skill,
             jobsentinel::ipc::resume::delete_user_skill,
             jobsentinel::ipc::resume::add_user_skill,
+            jobsentinel::ipc::resume::resume_skill_evidence_commands::add_skill_evidence,
+            jobsentinel::ipc::resume::resume_skill_evidence_commands::get_skill_evidence,
+            jobsentinel::ipc::resume::resume_skill_evidence_commands::delete_skill_evidence,
+            jobsentinel::ipc::resume::resume_skill_evidence_commands::get_expiring_skill_evidence,
             jobsentinel::ipc::resume::list_all_resumes,
             jobsentinel::ipc::resume::resume_file_commands::delete_resume,
             jobsentinel::ipc::resume::resume_builder_commands::create_resume_draft,
@@ -72,6 +158,7 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::resume::export_resume_docx,
             jobsentinel::ipc::resume::export_resume_html,
             jobsentinel::ipc::resume::export_resume_text,
+            jobsentinel::ipc::resume::export_redacted_resume_text,
             jobsentinel::ipc::resume::analyze_active_resume_for_job,
             jobsentinel::ipc::resume::analyze_resume_for_job,
             jobsentinel::ipc::resume::analyze_resume_format,
@@ -86,16 +173,31 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::scoring::update_scoring_config,
             jobsentinel::ipc::scoring::reset_scoring_config_cmd,
             jobsentinel::ipc::scoring::validate_scoring_config,
+            jobsentinel::ipc::scoring::get_scoring_profiles,
+            jobsentinel::ipc::scoring::save_scoring_profile,
+            jobsentinel::ipc::scoring::delete_scoring_profile,
+            jobsentinel::ipc::scoring::set_active_scoring_profile,
+            jobsentinel::ipc::scoring::rescore_all_jobs,
+            jobsentinel::ipc::scoring::record_job_feedback,
+            jobsentinel::ipc::scoring::get_job_feedback,
+            jobsentinel::ipc::scoring::block_company,
+            jobsentinel::ipc::scoring::unblock_company,
             jobsentinel::ipc::semantic_matching::get_semantic_matching_diagnostics,
             jobsentinel::ipc::market::get_trending_skills,
-            jobsentinel::ipc::market::get_active_companies,
+            jobsentinel::ipc::market::get_personal_skill_gaps,
+            jobsentinel::ipc::market::get_most_active_companies,
             jobsentinel::ipc::market::get_hottest_locations,
             jobsentinel::ipc::market::get_market_alerts,
             jobsentinel::ipc::market::run_market_analysis,
             jobsentinel::ipc::market::get_market_snapshot,
             jobsentinel::ipc::market::get_historical_snapshots,
+            jobsentinel::ipc::market::get_skill_mention_series,
+            jobsentinel::ipc::market::get_median_salary_series,
+            jobsentinel::ipc::market::get_company_posting_series,
             jobsentinel::ipc::market::mark_alert_read,
             jobsentinel::ipc::market::mark_all_alerts_read,
+            jobsentinel::ipc::market::dismiss_market_alert,
+            jobsentinel::ipc::market::export_market_report,
             jobsentinel::ipc::ghost::get_ghost_jobs,
             jobsentinel::ipc::ghost::get_ghost_statistics,
             jobsentinel::ipc::ghost::get_recent_jobs_filtered,
@@ -103,9 +205,14 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::ghost::mark_job_as_ghost,
             jobsentinel::ipc::ghost::get_ghost_feedback,
             jobsentinel::ipc::ghost::clear_ghost_feedback,
+            jobsentinel::ipc::ghost::get_ghost_analysis,
+            jobsentinel::ipc::ghost::get_company_reputation,
             jobsentinel::ipc::ghost::get_ghost_config,
             jobsentinel::ipc::ghost::set_ghost_config,
             jobsentinel::ipc::ghost::reset_ghost_config,
+            jobsentinel::ipc::ghost::get_ghost_reanalysis_summary,
+            jobsentinel::ipc::ghost::analyze_ghosts_batch,
+            jobsentinel::ipc::ghost::import_ghost_pattern_pack,
             jobsentinel::ipc::user_data::list_cover_letter_templates,
             jobsentinel::ipc::user_data::get_cover_letter_template,
             jobsentinel::ipc::user_data::create_cover_letter_template,
@@ -120,12 +227,16 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::user_data::create_saved_search,
             jobsentinel::ipc::user_data::use_saved_search,
             jobsentinel::ipc::user_data::delete_saved_search,
+            jobsentinel::ipc::user_data::update_saved_search_agent,
             jobsentinel::ipc::user_data::get_notification_preferences,
             jobsentinel::ipc::user_data::save_notification_preferences,
             jobsentinel::ipc::user_data::import_cover_letter_templates,
             jobsentinel::ipc::user_data::import_saved_searches,
             jobsentinel::ipc::user_data::add_search_history,
             jobsentinel::ipc::user_data::get_search_history,
+            jobsentinel::ipc::user_data::suggest_queries,
+            jobsentinel::ipc::user_data::pin_search_suggestion,
+            jobsentinel::ipc::user_data::remove_search_suggestion,
             jobsentinel::ipc::user_data::clear_search_history,
             jobsentinel::ipc::credentials::store_credential,
             jobsentinel::ipc::credentials::delete_credential,
@@ -148,6 +259,10 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::automation::get_application_profile,
             jobsentinel::ipc::automation::has_application_profile,
             jobsentinel::ipc::automation::get_application_profile_preview,
+            jobsentinel::ipc::automation::profiles::list_application_profiles,
+            jobsentinel::ipc::automation::profiles::create_application_profile,
+            jobsentinel::ipc::automation::profiles::set_default_application_profile,
+            jobsentinel::ipc::automation::profiles::delete_application_profile,
             jobsentinel::ipc::automation::upsert_screening_answer,
             jobsentinel::ipc::automation::get_screening_answers,
             jobsentinel::ipc::automation::find_answer_for_question,
@@ -169,12 +284,24 @@ macro_rules! jobsentinel_command_handlers {
             jobsentinel::ipc::automation::automation_browser_commands::fill_application_form,
             jobsentinel::ipc::automation::automation_browser_commands::mark_attempt_submitted,
             jobsentinel::ipc::automation::automation_browser_commands::get_attempts_for_job,
+            jobsentinel::ipc::automation::permissions::check_automation_permission,
+            jobsentinel::ipc::automation::permissions::grant_automation_permission,
+            jobsentinel::ipc::automation::permissions::list_automation_permissions,
+            jobsentinel::ipc::automation::permissions::revoke_permission,
+            jobsentinel::ipc::automation::quota::get_automation_quota,
+            jobsentinel::ipc::automation::dry_run::get_automation_dry_run,
+            jobsentinel::ipc::automation::dry_run::set_automation_dry_run,
+            jobsentinel::ipc::automation::review::get_attempt_fill_summary,
+            jobsentinel::ipc::automation::review::get_attempt_unanswered_questions,
+            jobsentinel::ipc::automation::review::get_attempt_screenshots,
             jobsentinel::ipc::health::get_scraper_health,
             jobsentinel::ipc::health::get_health_summary,
             jobsentinel::ipc::health::get_scraper_configs,
             jobsentinel::ipc::health::set_scraper_enabled,
             jobsentinel::ipc::health::get_scraper_runs,
             jobsentinel::ipc::health::get_latest_source_request,
+            jobsentinel::ipc::health::get_scrape_history,
+            jobsentinel::ipc::health::get_scrape_run_details,
             jobsentinel::ipc::health::run_scraper_smoke_test,
             jobsentinel::ipc::health::run_all_smoke_tests,
             jobsentinel::ipc::cache::get_score_cache_stats,
@@ -1,12 +1,17 @@
 //! Resume matching Tauri commands
 //!
 //! Commands for resume upload, skill extraction, job-resume matching,
-//! resume builder, and ATS analysis.
+//! resume builder, and ATS analysis. `match_resume_to_all_jobs` matches a
+//! resume against every active job so `get_best_resume_matches` can list the
+//! best fits. Each upload and builder save is kept as a version that
+//! `compare_resume_versions` can diff. Exports take optional redaction rules
+//! to produce a shareable copy without contact details.
 
 use crate::application::resume::{
-    AtsAnalysisResult, AtsAnalyzer, MatchResult, MatchResultWithJob, NewSkill, Resume,
-    ResumeAnalysisInput, ResumeExporter, SkillUpdate, StructuredResume, Template, TemplateId,
-    TemplateRenderer, UserSkill,
+    redact_resume, redact_resume_text, AtsAnalysisResult, AtsAnalyzer, MatchResult,
+    MatchResultWithJob, NewSkill, Resume, ResumeAnalysisInput, ResumeExporter, ResumeMatchProgress,
+    ResumeRedactionRules, ResumeSuggestion, ResumeVersion, ResumeVersionComparison, SkillUpdate,
+    StructuredResume, Template, TemplateId, TemplateRenderer, UserSkill,
 };
 use crate::bootstrap::AppState;
 use crate::ipc::errors::user_friendly_error;
@@ -14,11 +19,14 @@ use crate::ipc::limits::validate_optional_command_limit_i64;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::path::Path;
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 
 #[path = "resume_builder_commands.rs"]
 pub(crate) mod resume_builder_commands;
 
+#[path = "resume_skill_evidence_commands.rs"]
+pub(crate) mod resume_skill_evidence_commands;
+
 #[path = "resume_file_commands.rs"]
 pub(crate) mod resume_file_commands;
 use resume_file_commands::read_html_resume_source_for_format_review;
@@ -236,6 +244,113 @@ pub(crate) async fn get_recent_matches(
         .map_err(|e| user_friendly_error("Failed to get recent matches", e))
 }
 
+fn validate_min_job_score(min_job_score: Option<f64>) -> Result<f64, String> {
+    let min_job_score = min_job_score.unwrap_or(0.0);
+
+    if min_job_score.is_finite() && (0.0..=1.0).contains(&min_job_score) {
+        Ok(min_job_score)
+    } else {
+        Err("minJobScore must be between 0.0 and 1.0".to_string())
+    }
+}
+
+/// Match a resume against every active job scoring at least `min_job_score`
+///
+/// Hidden and closed jobs are skipped. Jobs are matched in batches;
+/// `resume_match:progress` is emitted with a `ResumeMatchProgress` after each
+/// batch so the UI can show a progress bar.
+#[tauri::command]
+pub(crate) async fn match_resume_to_all_jobs(
+    app: AppHandle,
+    resume_id: i64,
+    min_job_score: Option<f64>,
+    state: State<'_, AppState>,
+) -> Result<ResumeMatchProgress, String> {
+    tracing::info!(resume_id, "Command: match_resume_to_all_jobs");
+
+    let min_job_score = validate_min_job_score(min_job_score)?;
+    let matcher = state.database.resume_matcher();
+    matcher
+        .match_resume_to_all_jobs(resume_id, min_job_score, |progress| {
+            let _ = app.emit("resume_match:progress", progress);
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!(resume_id, "Failed to match resume against all jobs");
+            user_friendly_error("Failed to match resume to jobs", e)
+        })
+}
+
+/// Get saved matches for a resume against active jobs, best fit first
+#[tauri::command]
+pub(crate) async fn get_best_resume_matches(
+    resume_id: i64,
+    limit: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<MatchResultWithJob>, String> {
+    tracing::info!(resume_id, ?limit, "Command: get_best_resume_matches");
+
+    let limit = validate_optional_command_limit_i64(limit, 25)?;
+    let matcher = state.database.resume_matcher();
+    matcher
+        .get_best_matches(resume_id, limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get best resume matches", e))
+}
+
+/// Score every resume against a job and recommend which one to send
+///
+/// Application Assist uses the recommendation to pick the profile whose
+/// default resume fits best when no profile is chosen.
+#[tauri::command]
+pub(crate) async fn suggest_resume_for_job(
+    job_hash: String,
+    state: State<'_, AppState>,
+) -> Result<ResumeSuggestion, String> {
+    tracing::info!("Command: suggest_resume_for_job");
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .suggest_resume_for_job(&job_hash)
+        .await
+        .map_err(|e| user_friendly_error("Failed to suggest a resume", e))
+}
+
+/// List saved versions of uploaded resumes and builder drafts
+#[tauri::command]
+pub(crate) async fn list_resume_versions(
+    state: State<'_, AppState>,
+) -> Result<Vec<ResumeVersion>, String> {
+    tracing::info!("Command: list_resume_versions");
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .list_resume_versions()
+        .await
+        .map_err(|e| user_friendly_error("Failed to list resume versions", e))
+}
+
+/// Compare two resume versions by skills, sections, and average match
+/// against recent active jobs, `from_version_id` being the baseline
+#[tauri::command]
+pub(crate) async fn compare_resume_versions(
+    from_version_id: i64,
+    to_version_id: i64,
+    state: State<'_, AppState>,
+) -> Result<ResumeVersionComparison, String> {
+    tracing::info!(
+        from_version_id,
+        to_version_id,
+        "Command: compare_resume_versions"
+    );
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .compare_resume_versions(from_version_id, to_version_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to compare resume versions", e))
+}
+
 // ============================================================================
 // Skill Management Commands (Phase 1: Skill Validation UI)
 // ============================================================================
@@ -336,31 +451,89 @@ pub(crate) fn render_resume_text(resume: StructuredResume) -> String {
 // Export Commands
 // ============================================================================
 
+/// The resume to export, with personal details removed when rules are given
+fn resume_for_export(
+    resume: StructuredResume,
+    redaction: Option<&ResumeRedactionRules>,
+) -> StructuredResume {
+    match redaction {
+        Some(rules) => redact_resume(&resume, rules),
+        None => resume,
+    }
+}
+
 /// Export resume to DOCX format
 #[tauri::command]
 pub(crate) fn export_resume_docx(
     resume: StructuredResume,
     template: TemplateId,
+    redaction: Option<ResumeRedactionRules>,
 ) -> Result<Vec<u8>, String> {
-    tracing::info!("Command: export_resume_docx");
+    tracing::info!(
+        redacted = redaction.is_some(),
+        "Command: export_resume_docx"
+    );
+    let resume = resume_for_export(resume, redaction.as_ref());
     ResumeExporter::export_docx(&resume, template)
         .map_err(|e| user_friendly_error("Failed to export resume", e))
 }
 
 /// Export resume to HTML format for browser-based PDF generation
 #[tauri::command]
-pub(crate) fn export_resume_html(resume: StructuredResume, template: TemplateId) -> String {
-    tracing::info!("Command: export_resume_html (template: {:?})", template);
+pub(crate) fn export_resume_html(
+    resume: StructuredResume,
+    template: TemplateId,
+    redaction: Option<ResumeRedactionRules>,
+) -> String {
+    tracing::info!(
+        redacted = redaction.is_some(),
+        "Command: export_resume_html (template: {:?})",
+        template
+    );
+    let resume = resume_for_export(resume, redaction.as_ref());
     ResumeExporter::export_html(&resume, template)
 }
 
 /// Export resume to plain text
 #[tauri::command]
-pub(crate) fn export_resume_text(resume: StructuredResume) -> String {
-    tracing::info!("Command: export_resume_text");
+pub(crate) fn export_resume_text(
+    resume: StructuredResume,
+    redaction: Option<ResumeRedactionRules>,
+) -> String {
+    tracing::info!(
+        redacted = redaction.is_some(),
+        "Command: export_resume_text"
+    );
+    let resume = resume_for_export(resume, redaction.as_ref());
     ResumeExporter::export_text(&resume)
 }
 
+/// Export an uploaded resume's text with personal details masked
+///
+/// Without rules, the default rules mask email, phone, street address,
+/// references, and credential numbers.
+#[tauri::command]
+pub(crate) async fn export_redacted_resume_text(
+    resume_id: i64,
+    redaction: Option<ResumeRedactionRules>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    tracing::info!(resume_id, "Command: export_redacted_resume_text");
+
+    let resume = state
+        .database
+        .resume_matcher()
+        .get_resume(resume_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to export resume", e))?;
+    let text = resume
+        .parsed_text
+        .filter(|text| !text.trim().is_empty())
+        .ok_or_else(|| "This resume has no readable text to export.".to_string())?;
+
+    Ok(redact_resume_text(&text, &redaction.unwrap_or_default()))
+}
+
 // ============================================================================
 // Resume Analysis Commands
 // ============================================================================
@@ -119,6 +119,10 @@ fn resume_upload_extension_validation_accepts_supported_local_formats() {
         supported_resume_extension(Path::new("resume.md")).as_deref(),
         Some("md")
     );
+    assert_eq!(
+        supported_resume_extension(Path::new("resume.Markdown")).as_deref(),
+        Some("markdown")
+    );
     assert_eq!(
         supported_resume_extension(Path::new("resume.HTML")).as_deref(),
         Some("html")
@@ -222,3 +226,11 @@ fn html_resume_source_is_available_only_for_format_review() {
     assert!(source.contains("Jordan Lee"));
     assert!(read_html_resume_source_for_format_review(&txt_path.to_string_lossy()).is_none());
 }
+
+#[test]
+fn min_job_score_defaults_to_every_job_and_rejects_out_of_range_values() {
+    assert_eq!(validate_min_job_score(None), Ok(0.0));
+    assert_eq!(validate_min_job_score(Some(0.7)), Ok(0.7));
+    assert!(validate_min_job_score(Some(1.5)).is_err());
+    assert!(validate_min_job_score(Some(f64::NAN)).is_err());
+}
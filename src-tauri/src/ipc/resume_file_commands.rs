@@ -12,7 +12,8 @@ use uuid::Uuid;
 const MAX_JSON_RESUME_IMPORT_BYTES: u64 = 5 * 1024 * 1024;
 pub(super) const MAX_SELECTED_RESUME_UPLOAD_BYTES: u64 = 10 * 1024 * 1024;
 const MANAGED_RESUME_UPLOAD_DIR: &str = "resume-uploads";
-const SUPPORTED_RESUME_UPLOAD_EXTENSIONS: &[&str] = &["pdf", "docx", "txt", "md", "html", "htm"];
+const SUPPORTED_RESUME_UPLOAD_EXTENSIONS: &[&str] =
+    &["pdf", "docx", "txt", "md", "markdown", "html", "htm"];
 
 /// Select, copy, and parse a local resume without exposing source paths to renderer IPC.
 #[tauri::command]
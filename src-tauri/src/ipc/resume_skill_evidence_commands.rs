@@ -0,0 +1,82 @@
+//! Skill evidence Tauri commands.
+
+use crate::application::resume::{NewSkillEvidence, SkillEvidence};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use tauri::State;
+
+/// Default warning window for expiring certifications.
+const DEFAULT_EXPIRY_WARNING_DAYS: u32 = 60;
+const MAX_EXPIRY_WARNING_DAYS: u32 = 730;
+
+/// Attach evidence to a resume skill
+#[tauri::command]
+pub(crate) async fn add_skill_evidence(
+    skill_id: i64,
+    evidence: NewSkillEvidence,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    tracing::info!("Command: add_skill_evidence (skill: {})", skill_id);
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .add_skill_evidence(skill_id, evidence)
+        .await
+        .map_err(|e| user_friendly_error("Failed to add skill evidence", e))
+}
+
+/// Get all evidence for a resume's skills
+#[tauri::command]
+pub(crate) async fn get_skill_evidence(
+    resume_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Vec<SkillEvidence>, String> {
+    tracing::info!("Command: get_skill_evidence (resume: {})", resume_id);
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .get_skill_evidence(resume_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to get skill evidence", e))
+}
+
+/// Delete a piece of skill evidence
+#[tauri::command]
+pub(crate) async fn delete_skill_evidence(
+    evidence_id: i64,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!("Command: delete_skill_evidence (id: {})", evidence_id);
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .delete_skill_evidence(evidence_id)
+        .await
+        .map_err(|e| user_friendly_error("Failed to delete skill evidence", e))
+}
+
+/// Get certifications backing resume skills that expire soon or have lapsed
+#[tauri::command]
+pub(crate) async fn get_expiring_skill_evidence(
+    resume_id: i64,
+    within_days: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<Vec<SkillEvidence>, String> {
+    let within_days = within_days.unwrap_or(DEFAULT_EXPIRY_WARNING_DAYS);
+    if within_days > MAX_EXPIRY_WARNING_DAYS {
+        return Err(format!(
+            "within_days must be {MAX_EXPIRY_WARNING_DAYS} or fewer"
+        ));
+    }
+    tracing::info!(
+        "Command: get_expiring_skill_evidence (resume: {}, days: {})",
+        resume_id,
+        within_days
+    );
+
+    let matcher = state.database.resume_matcher();
+    matcher
+        .get_expiring_skill_evidence(resume_id, within_days)
+        .await
+        .map_err(|e| user_friendly_error("Failed to check expiring certifications", e))
+}
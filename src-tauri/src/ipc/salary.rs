@@ -2,10 +2,11 @@
 //!
 //! Commands for salary prediction, benchmarking, and offer comparison.
 
-use crate::application::salary::{OfferComparison, SalaryPrediction, SeniorityLevel};
+use crate::application::salary::{
+    load_exchange_rates, OfferComparison, SalaryBenchmark, SalaryPrediction, SeniorityLevel,
+};
 use crate::bootstrap::AppState;
 use crate::ipc::errors::user_friendly_error;
-use serde_json::Value;
 use std::collections::HashMap;
 use tauri::State;
 
@@ -30,18 +31,22 @@ pub(crate) async fn predict_salary(
 }
 
 /// Get salary benchmark for a role
+///
+/// Years of experience pick the seniority level when given. With neither
+/// years nor a seniority level, the level with the most data is used.
 #[tauri::command]
 pub(crate) async fn get_salary_benchmark(
     job_title: String,
     location: String,
-    seniority: String,
+    seniority: Option<String>,
     years_experience: Option<i32>,
     state: State<'_, AppState>,
-) -> Result<Option<Value>, String> {
+) -> Result<Option<SalaryBenchmark>, String> {
+    let seniority = seniority.filter(|seniority| !seniority.trim().is_empty());
     tracing::info!(
         job_title_len = job_title.len(),
         location_len = location.len(),
-        seniority_requested = !seniority.trim().is_empty(),
+        seniority_requested = seniority.is_some(),
         years_provided = years_experience.is_some(),
         "Command: get_salary_benchmark"
     );
@@ -49,18 +54,13 @@ pub(crate) async fn get_salary_benchmark(
     let analyzer = state.database.salary_analyzer();
     let seniority_level = years_experience
         .map(SeniorityLevel::from_years_of_experience)
-        .unwrap_or_else(|| SeniorityLevel::parse(&seniority));
+        .or_else(|| seniority.as_deref().map(SeniorityLevel::parse));
 
-    match analyzer
-        .get_benchmark(&job_title, &location, seniority_level)
-        .await
-    {
-        Ok(Some(benchmark)) => serde_json::to_value(&benchmark)
-            .map(Some)
-            .map_err(|e| user_friendly_error("Failed to serialize benchmark", e)),
-        Ok(None) => Ok(None),
-        Err(e) => Err(user_friendly_error("Failed to get benchmark", e)),
+    match seniority_level {
+        Some(level) => analyzer.get_benchmark(&job_title, &location, level).await,
+        None => analyzer.get_role_benchmark(&job_title, &location).await,
     }
+    .map_err(|e| user_friendly_error("Failed to get benchmark", e))
 }
 
 /// Generate negotiation script
@@ -83,6 +83,9 @@ pub(crate) async fn generate_negotiation_script(
 }
 
 /// Compare multiple job offers
+///
+/// Pay is annualized, converted to US dollars, and adjusted for each offer's
+/// cost of living. The best adjusted offer comes first.
 #[tauri::command]
 pub(crate) async fn compare_offers(
     offer_ids: Vec<i64>,
@@ -90,9 +93,10 @@ pub(crate) async fn compare_offers(
 ) -> Result<Vec<OfferComparison>, String> {
     tracing::info!("Command: compare_offers (count: {})", offer_ids.len());
 
+    let rates = load_exchange_rates(&state.database).await;
     let analyzer = state.database.salary_analyzer();
     analyzer
-        .compare_offers(offer_ids)
+        .compare_offers(offer_ids, &rates)
         .await
         .map_err(|e| user_friendly_error("Failed to compare offers", e))
 }
@@ -1,29 +1,141 @@
 //! Scoring configuration Tauri commands
 //!
-//! Commands for managing user-configurable scoring weights.
+//! Commands for managing user-configurable scoring weights and the named
+//! scoring profiles a user can switch between per search. Weights live in
+//! the saved config; every change re-scores saved jobs in the background.
+//! `rescore_all_jobs` re-scores on demand, e.g. after editing keywords.
+//! `record_job_feedback` stores thumbs-up/thumbs-down verdicts that teach
+//! future scores which boost keywords matter most. `block_company` adds a
+//! job's company to the blocklist straight from its job card.
 
-use crate::application::scoring::ScoringConfig;
+use crate::application::config::{Config, ScoringPreferences, ScoringProfile};
+use crate::application::scheduler::{RescoreProgress, Scheduler};
+use crate::application::scoring::{self, ScoringConfig};
 use crate::bootstrap::AppState;
+use crate::desktop::{path_label_for_logging, JobFeedback};
 use crate::ipc::errors::user_friendly_error;
-use tauri::State;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+use tokio::sync::RwLock;
 
-/// Get current scoring configuration
+/// Apply `change` to the scoring preferences, then save and publish the
+/// updated config. Saving validates the profiles and their weights.
+async fn update_scoring_in_runtime_and_path(
+    runtime_config: &RwLock<Config>,
+    config_path: &Path,
+    change: impl FnOnce(&mut ScoringPreferences) -> Result<(), String>,
+) -> Result<ScoringPreferences, String> {
+    let mut next_config = {
+        let config = runtime_config.read().await;
+        config.clone()
+    };
+    change(&mut next_config.scoring)?;
+
+    next_config.save(config_path).map_err(|e| {
+        let message = user_friendly_error("Failed to save scoring settings", &e);
+        tracing::error!(
+            config_path = %path_label_for_logging(config_path),
+            error = %message,
+            "Failed to save scoring settings"
+        );
+        message
+    })?;
+
+    let scoring = next_config.scoring.clone();
+    {
+        let mut runtime_config = runtime_config.write().await;
+        *runtime_config = next_config;
+    }
+
+    Ok(scoring)
+}
+
+/// Apply `change` to the config's company blocklist, then save and publish
+/// the updated config. Nothing is saved when `change` reports no change.
+async fn update_blocked_companies_in_runtime_and_path(
+    runtime_config: &RwLock<Config>,
+    config_path: &Path,
+    change: impl FnOnce(&mut Config) -> bool,
+) -> Result<(Vec<String>, bool), String> {
+    let mut next_config = {
+        let config = runtime_config.read().await;
+        config.clone()
+    };
+    if !change(&mut next_config) {
+        return Ok((next_config.blocked_companies, false));
+    }
+
+    next_config.save(config_path).map_err(|e| {
+        let message = user_friendly_error("Failed to save company blocklist", &e);
+        tracing::error!(
+            config_path = %path_label_for_logging(config_path),
+            error = %message,
+            "Failed to save company blocklist"
+        );
+        message
+    })?;
+
+    let blocked_companies = next_config.blocked_companies.clone();
+    {
+        let mut runtime_config = runtime_config.write().await;
+        *runtime_config = next_config;
+    }
+
+    Ok((blocked_companies, true))
+}
+
+fn company_name_arg(company: &str) -> Result<&str, String> {
+    let company = company.trim();
+    if company.is_empty() {
+        return Err("Company name is required".to_string());
+    }
+    Ok(company)
+}
+
+/// Running scheduler, or one sharing the app's config and database.
+fn scheduler_for(state: &AppState) -> Arc<Scheduler> {
+    state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(Scheduler::new_shared_with_credentials(
+            state.config.clone(),
+            state.database.clone(),
+            state.credentials.clone(),
+        ))
+    })
+}
+
+/// Re-score saved jobs with the new weights without blocking the command.
+fn rescore_in_background(state: &AppState) {
+    let scheduler = scheduler_for(state);
+    tauri::async_runtime::spawn(async move {
+        match scheduler.rescore_saved_jobs(|_| {}).await {
+            Ok(progress) => {
+                tracing::info!(
+                    jobs_rescored = progress.jobs_rescored,
+                    "Background re-scoring complete"
+                );
+            }
+            Err(error) => tracing::warn!(error = %error, "Background re-scoring failed"),
+        }
+    });
+}
+
+/// Get the scoring weights currently in use
 #[tauri::command]
 pub(crate) async fn get_scoring_config(
     state: State<'_, AppState>,
 ) -> Result<ScoringConfig, String> {
     tracing::info!("Command: get_scoring_config");
 
-    state.database.load_scoring_config().await.map_err(|e| {
-        let message = user_friendly_error("Failed to load scoring config", &e);
-        tracing::error!(error = %message, "Failed to load scoring config");
-        message
-    })
+    let config = state.config.read().await;
+    Ok(config.scoring.active_weights().clone())
 }
 
-/// Update scoring configuration
+/// Update the scoring weights currently in use
 ///
-/// Validates that weights sum to approximately 1.0 before saving.
+/// Changes the active scoring profile when one is selected, otherwise the
+/// default weights. Validates that weights sum to approximately 1.0 before
+/// saving.
 #[tauri::command]
 pub(crate) async fn update_scoring_config(
     config: ScoringConfig,
@@ -36,22 +148,19 @@ pub(crate) async fn update_scoring_config(
         tracing::error!("Invalid scoring config");
     })?;
 
-    // Save to database
-    state
-        .database
-        .save_scoring_config(&config)
-        .await
-        .map_err(|e| {
-            let message = user_friendly_error("Failed to save scoring config", &e);
-            tracing::error!(error = %message, "Failed to save scoring config");
-            message
-        })?;
+    let config_path = Config::default_path();
+    update_scoring_in_runtime_and_path(state.config.as_ref(), &config_path, |scoring| {
+        set_active_weights(scoring, config);
+        Ok(())
+    })
+    .await?;
+    rescore_in_background(&state);
 
     tracing::info!("Scoring config updated successfully");
     Ok(())
 }
 
-/// Reset scoring configuration to defaults
+/// Reset the scoring weights currently in use to defaults
 ///
 /// Resets all weights to:
 /// - Skills: 40%
@@ -65,11 +174,13 @@ pub(crate) async fn reset_scoring_config_cmd(
 ) -> Result<ScoringConfig, String> {
     tracing::info!("Command: reset_scoring_config");
 
-    state.database.reset_scoring_config().await.map_err(|e| {
-        let message = user_friendly_error("Failed to reset scoring config", &e);
-        tracing::error!(error = %message, "Failed to reset scoring config");
-        message
-    })?;
+    let config_path = Config::default_path();
+    update_scoring_in_runtime_and_path(state.config.as_ref(), &config_path, |scoring| {
+        set_active_weights(scoring, ScoringConfig::default());
+        Ok(())
+    })
+    .await?;
+    rescore_in_background(&state);
 
     // Return the default config
     Ok(ScoringConfig::default())
@@ -93,3 +204,363 @@ pub(crate) async fn validate_scoring_config(config: ScoringConfig) -> Result<boo
         }
     }
 }
+
+/// Re-score every saved job with the current keywords, allowlists, and weights
+///
+/// Jobs are saved in batches; `rescore:progress` is emitted with a
+/// `RescoreProgress` after each batch so the UI can show a progress bar.
+#[tauri::command]
+pub(crate) async fn rescore_all_jobs(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<RescoreProgress, String> {
+    tracing::info!("Command: rescore_all_jobs");
+
+    scheduler_for(&state)
+        .rescore_saved_jobs(|progress| {
+            let _ = app.emit("rescore:progress", progress);
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to re-score saved jobs");
+            user_friendly_error("Failed to re-score jobs", &e)
+        })
+}
+
+/// Mark a job as a good or bad match, or clear the verdict with `None`
+///
+/// Keyword weights are retrained from all feedback and used for every score
+/// computed afterwards. Returns how many jobs are rated.
+#[tauri::command]
+pub(crate) async fn record_job_feedback(
+    job_id: i64,
+    feedback: Option<JobFeedback>,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    tracing::info!(
+        job_id,
+        feedback = feedback.map(JobFeedback::as_str),
+        "Command: record_job_feedback"
+    );
+
+    let config = state.config.read().await.clone();
+    scoring::record_job_feedback(&config, &state.database, job_id, feedback)
+        .await
+        .map_err(|e| {
+            let message = user_friendly_error("Failed to save job feedback", &e);
+            tracing::error!(error = %message, "Failed to record job feedback");
+            message
+        })
+}
+
+/// Get the user's verdict for a job (null if no feedback given)
+#[tauri::command]
+pub(crate) async fn get_job_feedback(
+    job_id: i64,
+    state: State<'_, AppState>,
+) -> Result<Option<JobFeedback>, String> {
+    tracing::info!(job_id, "Command: get_job_feedback");
+
+    state.database.get_job_feedback(job_id).await.map_err(|e| {
+        let message = user_friendly_error("Failed to get job feedback", &e);
+        tracing::error!(error = %message, "Failed to get job feedback");
+        message
+    })
+}
+
+/// Add a company to the blocklist, e.g. from a job card
+///
+/// Matching ignores case and suffixes such as "Inc." or "LLC", so a company
+/// that is already blocked under another spelling is not added twice. Jobs
+/// from blocked companies score zero for the company factor and never send
+/// alerts; saved jobs are re-scored in the background. Returns the blocklist.
+#[tauri::command]
+pub(crate) async fn block_company(
+    company: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    tracing::info!("Command: block_company");
+    let company = company_name_arg(&company)?;
+
+    let config_path = Config::default_path();
+    let (blocked_companies, changed) = update_blocked_companies_in_runtime_and_path(
+        state.config.as_ref(),
+        &config_path,
+        |config| config.block_company(company),
+    )
+    .await?;
+    if changed {
+        rescore_in_background(&state);
+    }
+
+    Ok(blocked_companies)
+}
+
+/// Remove every blocklist entry matching a company. Returns the blocklist.
+#[tauri::command]
+pub(crate) async fn unblock_company(
+    company: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    tracing::info!("Command: unblock_company");
+    let company = company_name_arg(&company)?;
+
+    let config_path = Config::default_path();
+    let (blocked_companies, changed) = update_blocked_companies_in_runtime_and_path(
+        state.config.as_ref(),
+        &config_path,
+        |config| config.unblock_company(company),
+    )
+    .await?;
+    if changed {
+        rescore_in_background(&state);
+    }
+
+    Ok(blocked_companies)
+}
+
+/// Get the default weights, saved scoring profiles, and the active profile
+#[tauri::command]
+pub(crate) async fn get_scoring_profiles(
+    state: State<'_, AppState>,
+) -> Result<ScoringPreferences, String> {
+    tracing::info!("Command: get_scoring_profiles");
+
+    let config = state.config.read().await;
+    Ok(config.scoring.clone())
+}
+
+/// Save a named scoring profile, replacing the weights of one with the same name
+#[tauri::command]
+pub(crate) async fn save_scoring_profile(
+    profile: ScoringProfile,
+    state: State<'_, AppState>,
+) -> Result<ScoringPreferences, String> {
+    tracing::info!("Command: save_scoring_profile");
+
+    let config_path = Config::default_path();
+    let mut affects_active_profile = false;
+    let scoring =
+        update_scoring_in_runtime_and_path(state.config.as_ref(), &config_path, |scoring| {
+            affects_active_profile = scoring
+                .active_profile
+                .as_deref()
+                .is_some_and(|active| active.trim().eq_ignore_ascii_case(profile.name.trim()));
+            scoring.upsert_profile(profile);
+            Ok(())
+        })
+        .await?;
+    if affects_active_profile {
+        rescore_in_background(&state);
+    }
+
+    Ok(scoring)
+}
+
+/// Delete a named scoring profile
+///
+/// Deleting the active profile switches scoring back to the default weights.
+#[tauri::command]
+pub(crate) async fn delete_scoring_profile(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<ScoringPreferences, String> {
+    tracing::info!("Command: delete_scoring_profile");
+
+    let config_path = Config::default_path();
+    let mut was_active = false;
+    let scoring =
+        update_scoring_in_runtime_and_path(state.config.as_ref(), &config_path, |scoring| {
+            was_active = scoring.active_profile.is_some();
+            if !scoring.remove_profile(&name) {
+                return Err("Scoring profile not found".to_string());
+            }
+            was_active &= scoring.active_profile.is_none();
+            Ok(())
+        })
+        .await?;
+    if was_active {
+        rescore_in_background(&state);
+    }
+
+    Ok(scoring)
+}
+
+/// Switch scoring to a saved profile, or back to the default weights with `None`
+///
+/// The choice is saved and saved jobs are re-scored in the background.
+#[tauri::command]
+pub(crate) async fn set_active_scoring_profile(
+    name: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ScoringPreferences, String> {
+    tracing::info!(
+        profile_selected = name.is_some(),
+        "Command: set_active_scoring_profile"
+    );
+
+    let config_path = Config::default_path();
+    let scoring =
+        update_scoring_in_runtime_and_path(state.config.as_ref(), &config_path, |scoring| {
+            set_active_profile(scoring, name.as_deref())
+        })
+        .await?;
+    rescore_in_background(&state);
+
+    Ok(scoring)
+}
+
+/// Replace the weights of the active profile, or the default weights when no
+/// profile is active.
+fn set_active_weights(scoring: &mut ScoringPreferences, weights: ScoringConfig) {
+    let active_name = scoring
+        .active_profile
+        .as_deref()
+        .and_then(|name| scoring.profile(name))
+        .map(|profile| profile.name.clone());
+    match active_name {
+        Some(name) => scoring.upsert_profile(ScoringProfile { name, weights }),
+        None => scoring.weights = weights,
+    }
+}
+
+/// Select a saved profile by name, storing its saved spelling.
+fn set_active_profile(scoring: &mut ScoringPreferences, name: Option<&str>) -> Result<(), String> {
+    scoring.active_profile = match name {
+        Some(name) => Some(
+            scoring
+                .profile(name)
+                .map(|profile| profile.name.clone())
+                .ok_or_else(|| "Scoring profile not found".to_string())?,
+        ),
+        None => None,
+    };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str, skills_weight: f64) -> ScoringProfile {
+        ScoringProfile {
+            name: name.to_string(),
+            weights: ScoringConfig {
+                skills_weight,
+                salary_weight: 0.95 - skills_weight,
+                location_weight: 0.0,
+                company_weight: 0.0,
+                recency_weight: 0.05,
+            },
+        }
+    }
+
+    #[test]
+    fn active_weights_update_the_selected_profile() {
+        let mut scoring = ScoringPreferences::default();
+        scoring.upsert_profile(profile("Backend roles", 0.6));
+        set_active_profile(&mut scoring, Some("backend ROLES")).unwrap();
+
+        set_active_weights(&mut scoring, profile("", 0.8).weights);
+
+        assert_eq!(scoring.active_profile.as_deref(), Some("Backend roles"));
+        assert_eq!(scoring.active_weights().skills_weight, 0.8);
+        assert_eq!(
+            scoring.weights.skills_weight,
+            ScoringConfig::default().skills_weight
+        );
+    }
+
+    #[test]
+    fn unknown_profiles_cannot_be_selected() {
+        let mut scoring = ScoringPreferences::default();
+
+        assert!(set_active_profile(&mut scoring, Some("Management roles")).is_err());
+        assert!(scoring.active_profile.is_none());
+    }
+
+    #[tokio::test]
+    async fn switching_profiles_is_saved_to_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let mut config = Config::first_run();
+        config.title_allowlist = vec!["Platform Engineer".to_string()];
+        config.scoring.upsert_profile(profile("Backend roles", 0.6));
+        config
+            .scoring
+            .upsert_profile(profile("Management roles", 0.3));
+        let runtime_config = RwLock::new(config);
+
+        let scoring =
+            update_scoring_in_runtime_and_path(&runtime_config, &config_path, |scoring| {
+                set_active_profile(scoring, Some("Management roles"))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(scoring.active_weights().skills_weight, 0.3);
+        let saved = Config::load(&config_path).unwrap();
+        assert_eq!(
+            saved.scoring.active_profile.as_deref(),
+            Some("Management roles")
+        );
+        assert_eq!(
+            runtime_config
+                .read()
+                .await
+                .scoring
+                .active_profile
+                .as_deref(),
+            Some("Management roles")
+        );
+    }
+
+    #[tokio::test]
+    async fn blocking_a_company_is_saved_once_per_spelling() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let mut config = Config::first_run();
+        config.title_allowlist = vec!["Platform Engineer".to_string()];
+        let runtime_config = RwLock::new(config);
+
+        let (blocked, changed) =
+            update_blocked_companies_in_runtime_and_path(&runtime_config, &config_path, |config| {
+                config.block_company("Harbor Retail, Inc.")
+            })
+            .await
+            .unwrap();
+        assert!(changed);
+        assert_eq!(blocked, vec!["Harbor Retail, Inc."]);
+
+        let (blocked, changed) =
+            update_blocked_companies_in_runtime_and_path(&runtime_config, &config_path, |config| {
+                config.block_company("harbor retail llc")
+            })
+            .await
+            .unwrap();
+        assert!(!changed);
+        assert_eq!(blocked, vec!["Harbor Retail, Inc."]);
+        assert_eq!(
+            Config::load(&config_path).unwrap().blocked_companies,
+            vec!["Harbor Retail, Inc."]
+        );
+
+        let (blocked, _) =
+            update_blocked_companies_in_runtime_and_path(&runtime_config, &config_path, |config| {
+                config.unblock_company("HARBOR RETAIL")
+            })
+            .await
+            .unwrap();
+        assert!(blocked.is_empty());
+        assert!(runtime_config.read().await.blocked_companies.is_empty());
+    }
+
+    #[test]
+    fn blank_company_names_are_rejected() {
+        assert!(company_name_arg("   ").is_err());
+        assert_eq!(
+            company_name_arg("  Harbor Retail ").unwrap(),
+            "Harbor Retail"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Background scraping control Tauri commands
+//!
+//! Pause and resume scheduled scraping, or scrape some or all sources right
+//! away. The pause is saved and still applies after a restart.
+
+use crate::application::scheduler::{Scheduler, ScrapeNowError};
+use crate::bootstrap::AppState;
+use crate::ipc::errors::user_friendly_error;
+use crate::ipc::jobs::scraping_result_json;
+use chrono::Utc;
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::State;
+
+fn scheduler_for(state: &AppState) -> Arc<Scheduler> {
+    state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(Scheduler::new_shared_with_credentials(
+            state.config.clone(),
+            state.database.clone(),
+            state.credentials.clone(),
+        ))
+    })
+}
+
+/// Stop scheduled scraping until `resume_scraping` is called
+///
+/// Returns when the pause began. Manual scrapes still run.
+#[tauri::command]
+pub(crate) async fn pause_scraping(state: State<'_, AppState>) -> Result<String, String> {
+    tracing::info!("Command: pause_scraping");
+
+    scheduler_for(&state)
+        .pause_scraping()
+        .await
+        .map(|paused_at| paused_at.to_rfc3339())
+        .map_err(|e| user_friendly_error("Failed to pause scraping", e))
+}
+
+/// Let scheduled scraping run again
+///
+/// Returns whether scraping was paused. The next scheduled run starts within
+/// a minute if one came due during the pause.
+#[tauri::command]
+pub(crate) async fn resume_scraping(state: State<'_, AppState>) -> Result<bool, String> {
+    tracing::info!("Command: resume_scraping");
+
+    scheduler_for(&state)
+        .resume_scraping()
+        .await
+        .map_err(|e| user_friendly_error("Failed to resume scraping", e))
+}
+
+/// Scrape the given sources now, or every enabled source when `sources` is
+/// empty
+///
+/// Runs even while scheduled scraping is paused and does not change the
+/// schedule.
+#[tauri::command]
+pub(crate) async fn run_scrape_now(
+    sources: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<Value, String> {
+    tracing::info!(source_count = sources.len(), "Command: run_scrape_now");
+
+    state.scheduler_status.write().await.is_running = true;
+    let result = scheduler_for(&state).run_scrape_now(&sources).await;
+    {
+        let mut status = state.scheduler_status.write().await;
+        status.is_running = false;
+        if result.is_ok() {
+            status.last_run = Some(Utc::now());
+        }
+    }
+
+    match result {
+        Ok(result) => {
+            tracing::info!(
+                jobs_found = result.jobs_found,
+                jobs_new = result.jobs_new,
+                high_matches = result.high_matches,
+                "Manual scrape completed"
+            );
+            Ok(scraping_result_json(&result))
+        }
+        Err(ScrapeNowError::UnknownSource(source_id)) => {
+            Err(format!("\"{source_id}\" is not an available job source."))
+        }
+        Err(ScrapeNowError::SourceNotEnabled(source_id)) => Err(format!(
+            "Turn {source_id} on and fill in its details before scraping it."
+        )),
+        Err(ScrapeNowError::Failed(e)) => {
+            let message = user_friendly_error("Scrape failed", e);
+            tracing::error!(error = %message, "Manual scrape failed");
+            Err(message)
+        }
+    }
+}
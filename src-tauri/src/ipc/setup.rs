@@ -0,0 +1,125 @@
+//! First-run setup Tauri commands
+//!
+//! Role presets, a one-source check of the draft config, and importing an
+//! existing JobSentinel folder. None of these save the config; the wizard
+//! still finishes with `complete_setup`.
+
+use crate::application::config::{
+    import_setup_folder as core_import_setup_folder, setup_preset, Config, SetupFolderImport,
+    SetupImportError, SetupPreset, SETUP_PRESETS,
+};
+use crate::application::scheduler::{check_source_now, SourceCheck, SourceCheckError};
+use crate::bootstrap::AppState;
+use crate::desktop::Database;
+use crate::ipc::config::is_first_run_for_path;
+use crate::ipc::errors::user_friendly_error;
+use serde_json::Value;
+use std::path::PathBuf;
+use tauri::State;
+
+fn parse_draft_config(config: Value) -> Result<Config, String> {
+    serde_json::from_value(config).map_err(|e| user_friendly_error("Invalid configuration", e))
+}
+
+/// List the role templates offered during setup
+#[tauri::command]
+pub(crate) async fn get_setup_presets() -> Result<Vec<SetupPreset>, String> {
+    Ok(SETUP_PRESETS.to_vec())
+}
+
+/// Fill a draft setup config from a role template
+#[tauri::command]
+#[tracing::instrument(skip(config), level = "info")]
+pub(crate) async fn apply_setup_preset(config: Value, preset_id: String) -> Result<Config, String> {
+    let preset = setup_preset(&preset_id)
+        .ok_or_else(|| "That setup template is not available.".to_string())?;
+    let mut config = parse_draft_config(config)?;
+    preset.apply_to(&mut config);
+    Ok(config)
+}
+
+/// Try one source with the draft setup config before it is saved
+#[tauri::command]
+#[tracing::instrument(skip(config, state), level = "info")]
+pub(crate) async fn check_setup_source(
+    config: Value,
+    source_id: String,
+    state: State<'_, AppState>,
+) -> Result<SourceCheck, String> {
+    let config = parse_draft_config(config)?;
+    check_source_now(config, &state.database, &state.credentials, &source_id)
+        .await
+        .map_err(|error| format_source_check_error(&error))
+}
+
+/// Bring settings and saved jobs from an existing JobSentinel folder
+///
+/// Only allowed before setup is complete, so it can never merge into a
+/// database the user has already been using.
+#[tauri::command]
+#[tracing::instrument(skip(folder, state), level = "info")]
+pub(crate) async fn import_setup_folder(
+    folder: String,
+    state: State<'_, AppState>,
+) -> Result<SetupFolderImport, String> {
+    if !is_first_run_for_path(&Config::default_path())? {
+        return Err("Importing a folder is only available during first-run setup.".to_string());
+    }
+    core_import_setup_folder(
+        &PathBuf::from(folder),
+        &state.database,
+        &Database::default_backup_dir(),
+    )
+    .await
+    .map_err(|error| format_setup_import_error(&error))
+}
+
+fn format_source_check_error(error: &SourceCheckError) -> String {
+    match error {
+        SourceCheckError::UnknownSource => "That job source is not available.".to_string(),
+        SourceCheckError::SourceNotEnabled => {
+            "Turn this source on and fill in its details before checking it.".to_string()
+        }
+        SourceCheckError::InvalidConfig(details) => {
+            user_friendly_error("Settings need attention before checking", details)
+        }
+    }
+}
+
+fn format_setup_import_error(error: &SetupImportError) -> String {
+    match error {
+        SetupImportError::NotAFolder | SetupImportError::NothingToImport => {
+            format!("{error}. Choose the folder that holds config.json or jobs.db.")
+        }
+        SetupImportError::InvalidConfig(details) => {
+            user_friendly_error("Saved settings could not be read", details)
+        }
+        SetupImportError::Database(details) => {
+            user_friendly_error("Saved jobs could not be imported", details)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn apply_setup_preset_rejects_unknown_templates_and_bad_drafts() {
+        let unknown = apply_setup_preset(Value::Null, "astronaut".to_string()).await;
+        let bad_draft = apply_setup_preset(Value::Null, "security".to_string()).await;
+
+        assert_eq!(
+            unknown.unwrap_err(),
+            "That setup template is not available."
+        );
+        assert!(bad_draft.unwrap_err().contains("Invalid configuration"));
+    }
+
+    #[test]
+    fn import_errors_point_at_the_expected_files() {
+        let message = format_setup_import_error(&SetupImportError::NothingToImport);
+
+        assert!(message.contains("config.json"));
+    }
+}
@@ -9,7 +9,7 @@ mod tests {
     use crate::bootstrap::AppState;
     use crate::desktop::Database;
     use chrono::Utc;
-    use jobsentinel_application::Job;
+    use jobsentinel_application::{Job, JobStatus};
     use std::sync::Arc;
     use tokio::sync::RwLock;
 
@@ -29,13 +29,17 @@ mod tests {
                 cities: vec![],
                 states: vec![],
                 country: "US".to_string(),
+                countries: Vec::new(),
             },
+            commute: Default::default(),
             salary_floor_usd: 100000,
             immediate_alert_threshold: 0.9,
             scraping_interval_hours: 2,
+            scraping_concurrency: 3,
             alerts: AlertConfig::default(),
             greenhouse_urls: vec![],
             lever_urls: vec![],
+            career_pages: vec![],
             linkedin: Default::default(),
             restricted_source_acknowledgements: Default::default(),
             auto_refresh: Default::default(),
@@ -52,11 +56,18 @@ mod tests {
             usajobs: Default::default(),
             simplyhired: Default::default(),
             glassdoor: Default::default(),
+            proxy: Default::default(),
+            source_limits: Default::default(),
+            rate_limits: Default::default(),
+            power_saving: Default::default(),
             ghost_config: None,
             preferred_companies: vec![],
             blocked_companies: vec![],
             use_resume_matching: false,
+            scoring: Default::default(),
+            retention: Default::default(),
             salary_target_usd: None,
+            salary_target_max_usd: None,
             penalize_missing_salary: false,
         };
 
@@ -85,6 +96,9 @@ mod tests {
             scheduler_status: Arc::new(RwLock::new(SchedulerStatus::default())),
             bookmarklet_server: Arc::new(RwLock::new(bookmarklet_server)),
             pending_url_imports: Default::default(),
+            quick_actions: Default::default(),
+            guest_mode: Default::default(),
+            config_recovery: None,
         }
     }
 
@@ -105,6 +119,7 @@ mod tests {
             salary_min: Some(150000),
             salary_max: Some(200000),
             currency: Some("USD".to_string()),
+            pay_period: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             last_seen: Utc::now(),
@@ -118,6 +133,8 @@ mod tests {
             ghost_reasons: None,
             first_seen: None,
             repost_count: 0,
+            status: JobStatus::Active,
+            skills: Vec::new(),
         }
     }
 
@@ -249,13 +266,17 @@ mod tests {
                 cities: vec![],
                 states: vec![],
                 country: "US".to_string(),
+                countries: Vec::new(),
             },
+            commute: Default::default(),
             salary_floor_usd: 120000,
             immediate_alert_threshold: 0.85,
             scraping_interval_hours: 3,
+            scraping_concurrency: 3,
             alerts: AlertConfig::default(),
             greenhouse_urls: vec![],
             lever_urls: vec![],
+            career_pages: vec![],
             linkedin: Default::default(),
             restricted_source_acknowledgements: Default::default(),
             auto_refresh: Default::default(),
@@ -272,11 +293,18 @@ mod tests {
             usajobs: Default::default(),
             simplyhired: Default::default(),
             glassdoor: Default::default(),
+            proxy: Default::default(),
+            source_limits: Default::default(),
+            rate_limits: Default::default(),
+            power_saving: Default::default(),
             ghost_config: None,
             preferred_companies: vec![],
             blocked_companies: vec![],
             use_resume_matching: false,
+            scoring: Default::default(),
+            retention: Default::default(),
             salary_target_usd: None,
+            salary_target_max_usd: None,
             penalize_missing_salary: false,
         };
 
@@ -372,13 +400,17 @@ mod tests {
                 cities: vec![],
                 states: vec![],
                 country: "US".to_string(),
+                countries: Vec::new(),
             },
+            commute: Default::default(),
             salary_floor_usd: 100000,
             immediate_alert_threshold: 0.9,
             scraping_interval_hours: 2,
+            scraping_concurrency: 3,
             alerts: AlertConfig::default(),
             greenhouse_urls: vec![],
             lever_urls: vec![],
+            career_pages: vec![],
             linkedin: Default::default(),
             restricted_source_acknowledgements: Default::default(),
             auto_refresh: Default::default(),
@@ -395,11 +427,18 @@ mod tests {
             usajobs: Default::default(),
             simplyhired: Default::default(),
             glassdoor: Default::default(),
+            proxy: Default::default(),
+            source_limits: Default::default(),
+            rate_limits: Default::default(),
+            power_saving: Default::default(),
             ghost_config: None,
             preferred_companies: vec![],
             blocked_companies: vec![],
             use_resume_matching: false,
+            scoring: Default::default(),
+            retention: Default::default(),
             salary_target_usd: None,
+            salary_target_max_usd: None,
             penalize_missing_salary: false,
         };
 
@@ -440,4 +479,40 @@ mod tests {
             "search_jobs should not panic"
         );
     }
+
+    #[tokio::test]
+    async fn test_ghost_job_lists_mask_notes_in_guest_mode() {
+        let state = create_test_app_state().await;
+        let mut job = create_test_job(0, "Care Coordinator", 0.9);
+        job.hash = "ghost_notes_hash".to_string();
+        let job_id = state
+            .database
+            .upsert_job(&job)
+            .await
+            .expect("Failed to insert job");
+        state
+            .database
+            .set_job_notes(job_id, Some("Private salary notes"))
+            .await
+            .expect("Failed to save notes");
+        state
+            .database
+            .update_ghost_analysis(job_id, 0.9, "[]")
+            .await
+            .expect("Failed to save ghost analysis");
+
+        state.guest_mode.enable(None, Utc::now());
+
+        let ghost_jobs = crate::ipc::ghost::ghost_jobs_json(&state, None, None)
+            .await
+            .expect("ghost jobs should load");
+        let recent_jobs = crate::ipc::ghost::recent_jobs_filtered_json(&state, 10, false)
+            .await
+            .expect("recent jobs should load");
+
+        assert_eq!(ghost_jobs.len(), 1);
+        assert_eq!(recent_jobs.len(), 1);
+        assert!(ghost_jobs[0]["notes"].is_null());
+        assert!(recent_jobs[0]["notes"].is_null());
+    }
 }
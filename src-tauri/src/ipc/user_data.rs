@@ -3,12 +3,12 @@
 //! Commands for cover letter templates, interview prep, saved searches, and notification preferences.
 
 use crate::application::user_data::{
-    CoverLetterTemplate, FollowUpReminder, NotificationPreferences, PrepChecklistItem, SavedSearch,
-    TemplateCategory,
+    CoverLetterTemplate, FollowUpReminder, NotificationPreferences, PrepChecklistItem,
+    QuerySuggestion, SavedSearch, SearchAgentSettings, TemplateCategory,
 };
 use crate::bootstrap::AppState;
 use crate::ipc::errors::user_friendly_error;
-use crate::ipc::limits::validate_command_limit_i64;
+use crate::ipc::limits::{validate_command_limit_i64, validate_optional_command_limit_usize};
 use tauri::State;
 
 // ============================================================================
@@ -292,6 +292,29 @@ pub(crate) async fn delete_saved_search(
         .map_err(|e| user_friendly_error("Database operation failed", e))
 }
 
+/// Turn a saved search's job agent on or off and set its channels and
+/// frequency
+#[tauri::command]
+pub(crate) async fn update_saved_search_agent(
+    id: String,
+    settings: SearchAgentSettings,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(
+        enabled = settings.enabled,
+        channel_count = settings.channels.len(),
+        frequency = settings.frequency.as_str(),
+        "Command: update_saved_search_agent (id: {})",
+        id
+    );
+
+    let manager = state.database.user_data_manager();
+    manager
+        .update_saved_search_agent(&id, &settings)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
 /// Import saved searches from localStorage migration
 #[tauri::command]
 pub(crate) async fn import_saved_searches(
@@ -347,6 +370,9 @@ pub(crate) async fn save_notification_preferences(
 // Search History
 // ============================================================================
 
+/// Suggestions shown when no limit is given
+const DEFAULT_QUERY_SUGGESTION_LIMIT: usize = 8;
+
 /// Add search query to history
 #[tauri::command]
 pub(crate) async fn add_search_history(
@@ -378,6 +404,58 @@ pub(crate) async fn get_search_history(
         .map_err(|e| user_friendly_error("Database operation failed", e))
 }
 
+/// Search box suggestions for a prefix, from search history and active job
+/// titles and companies
+#[tauri::command]
+pub(crate) async fn suggest_queries(
+    prefix: String,
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<Vec<QuerySuggestion>, String> {
+    tracing::info!(
+        prefix_len = prefix.chars().count(),
+        "Command: suggest_queries"
+    );
+
+    let limit = validate_optional_command_limit_usize(limit, DEFAULT_QUERY_SUGGESTION_LIMIT)?;
+    let manager = state.database.user_data_manager();
+    manager
+        .suggest_queries(&prefix, limit)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
+/// Pin or unpin a search suggestion
+#[tauri::command]
+pub(crate) async fn pin_search_suggestion(
+    query: String,
+    pinned: bool,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    tracing::info!(pinned, "Command: pin_search_suggestion");
+
+    let manager = state.database.user_data_manager();
+    manager
+        .pin_search_suggestion(&query, pinned)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
+/// Stop suggesting a query until it is searched for again
+#[tauri::command]
+pub(crate) async fn remove_search_suggestion(
+    query: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    tracing::info!("Command: remove_search_suggestion");
+
+    let manager = state.database.user_data_manager();
+    manager
+        .remove_search_suggestion(&query)
+        .await
+        .map_err(|e| user_friendly_error("Database operation failed", e))
+}
+
 /// Clear search history
 #[tauri::command]
 pub(crate) async fn clear_search_history(state: State<'_, AppState>) -> Result<(), String> {
@@ -0,0 +1,57 @@
+//! Vacation mode Tauri command
+//!
+//! While vacation mode is on, the scheduler holds alerts instead of sending
+//! them and reminders stay quiet. Turning it off returns the "while you were
+//! away" summary.
+
+use crate::bootstrap::AppState;
+use crate::desktop::{VacationMode, VacationSummary};
+use crate::ipc::errors::user_friendly_error;
+use chrono::Utc;
+use tauri::State;
+
+/// Vacation mode state after a change, plus the summary when it just ended
+#[derive(serde::Serialize)]
+pub(crate) struct VacationModeStatus {
+    #[serde(flatten)]
+    pub mode: VacationMode,
+    pub summary: Option<VacationSummary>,
+}
+
+/// Turn vacation mode on or off
+///
+/// `keep_scraping` defaults to `true`, so new jobs keep arriving for the
+/// summary while alerts are held.
+#[tauri::command]
+pub(crate) async fn set_vacation_mode(
+    enabled: bool,
+    keep_scraping: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<VacationModeStatus, String> {
+    tracing::info!(enabled, ?keep_scraping, "Command: set_vacation_mode");
+
+    let now = Utc::now();
+    if enabled {
+        let mode = state
+            .database
+            .start_vacation_mode(keep_scraping.unwrap_or(true), now)
+            .await
+            .map_err(|e| user_friendly_error("Failed to start vacation mode", e))?;
+        return Ok(VacationModeStatus {
+            mode,
+            summary: None,
+        });
+    }
+
+    let summary = state
+        .database
+        .end_vacation_mode(now)
+        .await
+        .map_err(|e| user_friendly_error("Failed to end vacation mode", e))?;
+    let mode = state
+        .database
+        .get_vacation_mode()
+        .await
+        .map_err(|e| user_friendly_error("Failed to load vacation mode", e))?;
+    Ok(VacationModeStatus { mode, summary })
+}
@@ -0,0 +1,55 @@
+//! Weekly summary report Tauri commands
+//!
+//! Reports are made on a schedule when turned on in settings; users can also
+//! make one right away and read earlier reports saved on this computer.
+
+use crate::application::scheduler::{Scheduler, WeeklyReportRun};
+use crate::bootstrap::AppState;
+use crate::desktop::WeeklyReportRecord;
+use crate::ipc::errors::user_friendly_error;
+use std::sync::Arc;
+use tauri::State;
+
+const DEFAULT_REPORT_LIMIT: i64 = 12;
+const MAX_REPORT_LIMIT: i64 = 104;
+
+/// Make a report for the past seven days, save it, and send it through the
+/// enabled email, Slack, and Discord channels
+///
+/// The next scheduled report still goes out at its usual time.
+#[tauri::command]
+pub(crate) async fn generate_report_now(
+    state: State<'_, AppState>,
+) -> Result<WeeklyReportRun, String> {
+    tracing::info!("Command: generate_report_now");
+
+    let scheduler = state.scheduler.clone().unwrap_or_else(|| {
+        Arc::new(Scheduler::new_shared_with_credentials(
+            state.config.clone(),
+            state.database.clone(),
+            state.credentials.clone(),
+        ))
+    });
+    scheduler
+        .generate_weekly_report_now()
+        .await
+        .map_err(|e| user_friendly_error("Failed to generate the weekly report", e))
+}
+
+/// Saved weekly reports, newest first
+#[tauri::command]
+pub(crate) async fn get_weekly_reports(
+    limit: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<Vec<WeeklyReportRecord>, String> {
+    tracing::info!(?limit, "Command: get_weekly_reports");
+
+    let limit = limit
+        .unwrap_or(DEFAULT_REPORT_LIMIT)
+        .clamp(1, MAX_REPORT_LIMIT);
+    state
+        .database
+        .weekly_reports(limit)
+        .await
+        .map_err(|e| user_friendly_error("Failed to load weekly reports", e))
+}
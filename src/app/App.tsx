@@ -13,6 +13,7 @@ import {
 } from "./onboarding/OnboardingProvider";
 import { useOnboarding } from "./onboarding/useOnboarding";
 import { Navigation } from "./Navigation";
+import { ConfigRecoveryNotice } from "./ConfigRecoveryNotice";
 import { KeyboardShortcutsProvider } from "./keyboard/KeyboardShortcutsProvider";
 import { useKeyboardShortcuts } from "./keyboard/useKeyboardShortcuts";
 import { logError } from "../shared/errorReporting/logger";
@@ -296,6 +297,7 @@ function App() {
             id="main-content"
             tabIndex={-1}
           >
+            <ConfigRecoveryNotice />
             <Suspense fallback={<PageLoader />}>
               {currentPage === "dashboard" && (
                 <PageErrorBoundary pageName="Dashboard">
@@ -0,0 +1,48 @@
+import { render, screen } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { ConfigRecoveryNotice } from "./ConfigRecoveryNotice";
+
+const mockInvoke = vi.mocked(invoke);
+
+vi.mock("../shared/errorReporting/logger", () => ({
+  logError: vi.fn(),
+}));
+
+describe("ConfigRecoveryNotice", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("explains which backup was restored and can be dismissed", async () => {
+    const user = userEvent.setup();
+    mockInvoke.mockResolvedValueOnce({
+      reason: "EOF while parsing an object at line 1 column 14",
+      restored_from: "config.json.bak.1",
+      corrupt_copy: "config.json.corrupt",
+    });
+
+    render(<ConfigRecoveryNotice />);
+
+    expect(
+      await screen.findByText("Your settings were restored from a backup"),
+    ).toBeInTheDocument();
+    expect(screen.getByText("config.json.bak.1")).toBeInTheDocument();
+    expect(screen.getByText("config.json.corrupt")).toBeInTheDocument();
+
+    await user.click(screen.getByRole("button", { name: "Dismiss" }));
+
+    expect(screen.queryByRole("alert")).not.toBeInTheDocument();
+  });
+
+  it("renders nothing when startup loaded the saved settings", async () => {
+    mockInvoke.mockResolvedValueOnce(null);
+
+    render(<ConfigRecoveryNotice />);
+
+    await Promise.resolve();
+    expect(mockInvoke).toHaveBeenCalledWith("get_config_recovery_notice");
+    expect(screen.queryByRole("alert")).not.toBeInTheDocument();
+  });
+});
@@ -0,0 +1,64 @@
+import { useEffect, useState } from "react";
+import { invoke } from "../platform/tauri";
+import { logError } from "../shared/errorReporting/logger";
+
+export interface ConfigRecovery {
+  reason: string;
+  restored_from: string;
+  corrupt_copy: string | null;
+}
+
+/**
+ * Banner shown when the saved settings file was damaged and JobSentinel
+ * started from a backup instead.
+ */
+export function ConfigRecoveryNotice() {
+  const [recovery, setRecovery] = useState<ConfigRecovery | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    invoke<ConfigRecovery | null>("get_config_recovery_notice")
+      .then((notice) => {
+        if (!cancelled) setRecovery(notice ?? null);
+      })
+      .catch((error: unknown) => {
+        logError("Failed to check settings recovery:", error);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  if (!recovery) return null;
+
+  return (
+    <div
+      role="alert"
+      className="m-4 flex items-start justify-between gap-4 rounded-card border border-warning/40 bg-warning/15 p-4 text-sm text-surface-800 dark:text-surface-100"
+    >
+      <div>
+        <p className="font-semibold">
+          Your settings were restored from a backup
+        </p>
+        <p className="mt-1">
+          The saved settings file could not be read, so JobSentinel loaded{" "}
+          <code>{recovery.restored_from}</code>. Recent settings changes may be
+          missing; review Settings before the next search.
+          {recovery.corrupt_copy && (
+            <>
+              {" "}
+              The damaged file was kept as <code>{recovery.corrupt_copy}</code>.
+            </>
+          )}
+        </p>
+      </div>
+      <button
+        type="button"
+        onClick={() => setRecovery(null)}
+        className="shrink-0 rounded px-3 py-1 text-sm font-medium hover:bg-warning/20 focus:outline-none focus-visible:ring-2 focus-visible:ring-sentinel-400"
+      >
+        Dismiss
+      </button>
+    </div>
+  );
+}
@@ -22,6 +22,7 @@ export interface MockApplicationAssistState {
   applicationProfile: MockApplicationProfile | null;
   screeningAnswers: MockScreeningAnswer[];
   automationBrowserRunning: boolean;
+  automationDryRun: boolean;
   nextAutomationAttemptId: number;
 }
 
@@ -32,6 +33,55 @@ interface MockApplicationAssistCommandResult {
   shouldSave: boolean;
 }
 
+function getMockAutomationQuota(state: MockApplicationAssistState) {
+  const dailyLimit = state.applicationProfile?.maxApplicationsPerDay ?? 10;
+  const resetsAt = new Date();
+  resetsAt.setUTCHours(24, 0, 0, 0);
+  return {
+    dailyLimit,
+    weeklyLimit: state.applicationProfile?.maxApplicationsPerWeek ?? null,
+    usedToday: 0,
+    usedThisWeek: 0,
+    remaining: dailyLimit,
+    resetsAt: resetsAt.toISOString(),
+  };
+}
+
+function getMockFilledValues(state: MockApplicationAssistState): Record<string, string> {
+  const profile = state.applicationProfile;
+  const nameParts = profile?.fullName.split(/\s+/).filter(Boolean) ?? [];
+  const values: Record<string, string> = {
+    first_name: nameParts[0] ?? "",
+    last_name: nameParts[nameParts.length - 1] ?? "",
+    email: profile?.email ?? "",
+  };
+  if (profile?.phone) {
+    values.phone = profile.phone;
+  }
+  for (const answer of state.screeningAnswers.slice(0, 2)) {
+    values[answer.questionPattern] = answer.answer;
+  }
+  return values;
+}
+
+function getMockAttemptFillSummary(state: MockApplicationAssistState) {
+  return {
+    filledValues: getMockFilledValues(state),
+    unfilledFields: [],
+    unansweredQuestions: ["Why do you want to work here?"],
+  };
+}
+
+function requireMockApplicationProfile(
+  args: Record<string, unknown> | undefined,
+  state: MockApplicationAssistState,
+): void {
+  const profileId = getArg(args, "profileId") ?? getArg(args, "profile_id");
+  if (!state.applicationProfile || profileId !== state.applicationProfile.id) {
+    throw new Error("Application profile not found");
+  }
+}
+
 function fillMockApplicationForm(
   args: Record<string, unknown> | undefined,
   state: MockApplicationAssistState,
@@ -51,6 +101,7 @@ function fillMockApplicationForm(
   return {
     value: {
       filledFields: ["firstName", "lastName", "email", "phone", "resume", ...screeningFields],
+      filledValues: getMockFilledValues(state),
       unfilledFields: platform === "unknown" ? ["customQuestion"] : [],
       captchaDetected: false,
       readyForReview: true,
@@ -58,6 +109,7 @@ function fillMockApplicationForm(
       attemptId,
       durationMs: 1250,
       atsPlatform: platform,
+      dryRun: state.automationDryRun,
     },
     state: {
       ...state,
@@ -97,6 +149,34 @@ export function handleMockApplicationAssistCommand(
       );
       return result(applicationProfile.id, { ...state, applicationProfile }, true);
     }
+    case "list_application_profiles":
+      return result(
+        state.applicationProfile
+          ? [
+              {
+                id: state.applicationProfile.id,
+                name: state.applicationProfile.name,
+                isDefault: true,
+                defaultResumeId: state.applicationProfile.defaultResumeId,
+              },
+            ]
+          : [],
+        state,
+      );
+    case "create_application_profile": {
+      if (state.applicationProfile) {
+        throw new Error("The preview keeps one application profile.");
+      }
+      const input = normalizeProfileInput(getArg(args, "input"));
+      const applicationProfile = buildMockApplicationProfileFromInput(input, null);
+      return result(applicationProfile.id, { ...state, applicationProfile }, true);
+    }
+    case "set_default_application_profile":
+      requireMockApplicationProfile(args, state);
+      return result(undefined, state);
+    case "delete_application_profile":
+      requireMockApplicationProfile(args, state);
+      return result(undefined, { ...state, applicationProfile: null }, true);
     case "get_screening_answers":
       return result(state.screeningAnswers, state);
     case "upsert_screening_answer":
@@ -119,12 +199,34 @@ export function handleMockApplicationAssistCommand(
         },
         state,
       );
+    case "get_automation_quota":
+      return result(getMockAutomationQuota(state), state);
+    case "get_automation_dry_run":
+      return result(state.automationDryRun, state);
+    case "set_automation_dry_run":
+      return result(undefined, {
+        ...state,
+        automationDryRun: getArg(args, "enabled") === true,
+      });
+    case "get_attempt_fill_summary":
+      return result(getMockAttemptFillSummary(state), state);
+    case "get_attempt_unanswered_questions":
+      return result(getMockAttemptFillSummary(state).unansweredQuestions, state);
+    case "get_attempt_screenshots":
+      return result({ review: null, confirmation: null }, state);
     case "detect_ats_platform":
       return result(getMockAtsPlatformDetection(getStringArg(args, "url") ?? ""), state);
     case "fill_application_form": {
       const fill = fillMockApplicationForm(args, state);
       return result(fill.value, fill.state);
     }
+    case "check_automation_permission":
+    case "grant_automation_permission":
+      return result(getMockAutomationPermission(getStringArg(args, "url") ?? ""), state);
+    case "list_automation_permissions":
+      return result([], state);
+    case "revoke_permission":
+      return result(false, state);
     case "is_browser_running":
       return result(state.automationBrowserRunning, state);
     case "close_automation_browser":
@@ -138,6 +240,16 @@ export function handleMockApplicationAssistCommand(
   }
 }
 
+function getMockAutomationPermission(url: string) {
+  let domain = "";
+  try {
+    domain = new URL(url).hostname;
+  } catch {
+    // Leave blank for unparseable mock links
+  }
+  return { domain, granted: true };
+}
+
 function result(
   value: unknown,
   state: MockApplicationAssistState,
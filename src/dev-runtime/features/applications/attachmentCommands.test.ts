@@ -0,0 +1,37 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke, resetMockData } from "../../mocks/handlers";
+
+describe("Applications attachment mock commands", () => {
+  beforeEach(() => {
+    resetMockData();
+  });
+
+  it("versions attachments of the same kind", async () => {
+    const first = await mockInvoke<{ id: number; version: number }>(
+      "select_and_attach_file",
+      { applicationId: 2, kind: "resume" },
+    );
+    const second = await mockInvoke<{ id: number; version: number }>(
+      "select_and_attach_file",
+      { applicationId: 2, kind: "resume" },
+    );
+    await mockInvoke("select_and_attach_file", {
+      applicationId: 2,
+      kind: "cover_letter",
+    });
+
+    expect([first.version, second.version]).toEqual([1, 2]);
+    await expect(
+      mockInvoke("get_attachments", { applicationId: 2 }),
+    ).resolves.toEqual([
+      expect.objectContaining({ kind: "cover_letter", version: 1 }),
+      expect.objectContaining({ id: second.id, kind: "resume", version: 2 }),
+      expect.objectContaining({ id: first.id, kind: "resume", version: 1 }),
+    ]);
+
+    await mockInvoke("delete_attachment", { attachmentId: second.id });
+    await expect(
+      mockInvoke("get_attachments", { applicationId: 2 }),
+    ).resolves.toHaveLength(2);
+  });
+});
@@ -0,0 +1,98 @@
+import {
+  getNextId,
+  getNumericArg,
+  getStringArg,
+} from "../../mocks/handlers/commandHelpers";
+import type { MockAttachment } from "../../mocks/handlers/types";
+
+interface MockAttachmentCommandResult {
+  handled: boolean;
+  value: unknown;
+  state: MockAttachment[];
+  shouldSave: boolean;
+}
+
+const MOCK_FILE_NAMES: Record<MockAttachment["kind"], string> = {
+  resume: "mock-resume.pdf",
+  cover_letter: "mock-cover-letter.docx",
+  take_home: "mock-take-home.zip",
+  other: "mock-attachment.pdf",
+};
+
+export function handleMockAttachmentCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockAttachment[],
+): MockAttachmentCommandResult {
+  switch (command) {
+    case "select_and_attach_file":
+      return attachFile(args, state);
+
+    case "get_attachments": {
+      const applicationId = getNumericArg(args, "applicationId");
+      return withoutSave(
+        state,
+        state
+          .filter((attachment) => attachment.application_id === applicationId)
+          .sort(
+            (a, b) => a.kind.localeCompare(b.kind) || b.version - a.version,
+          ),
+      );
+    }
+
+    // The dev runtime has no stored files to open.
+    case "open_attachment":
+      return withoutSave(state, undefined);
+
+    case "delete_attachment": {
+      const attachmentId = getNumericArg(args, "attachmentId");
+      return {
+        handled: true,
+        shouldSave: true,
+        state: state.filter((attachment) => attachment.id !== attachmentId),
+        value: undefined,
+      };
+    }
+
+    default:
+      return { handled: false, shouldSave: false, state, value: undefined };
+  }
+}
+
+function withoutSave(
+  state: MockAttachment[],
+  value: unknown,
+): MockAttachmentCommandResult {
+  return { handled: true, shouldSave: false, state, value };
+}
+
+function attachFile(
+  args: Record<string, unknown> | undefined,
+  state: MockAttachment[],
+): MockAttachmentCommandResult {
+  const applicationId = getNumericArg(args, "applicationId");
+  const kind = getStringArg(args, "kind") as MockAttachment["kind"] | undefined;
+  if (applicationId === undefined || !kind || !(kind in MOCK_FILE_NAMES)) {
+    return withoutSave(state, null);
+  }
+
+  const attachment: MockAttachment = {
+    id: getNextId(state),
+    application_id: applicationId,
+    kind,
+    version:
+      state.filter(
+        (entry) =>
+          entry.application_id === applicationId && entry.kind === kind,
+      ).length + 1,
+    file_name: MOCK_FILE_NAMES[kind],
+    size_bytes: 24576,
+    created_at: new Date().toISOString(),
+  };
+  return {
+    handled: true,
+    shouldSave: true,
+    state: [...state, attachment],
+    value: attachment,
+  };
+}
@@ -65,6 +65,78 @@ describe("Applications mock commands", () => {
     );
   });
 
+  it("creates, moves, and archives applications in bulk", () => {
+    const initial = createState();
+    const trackedHashes = new Set(
+      Object.values(initial.applications)
+        .flat()
+        .map((application) => application.job_hash),
+    );
+    const untracked = initial.jobs.filter(
+      (job) => !trackedHashes.has(job.hash),
+    );
+    const tracked = initial.jobs.find((job) => trackedHashes.has(job.hash));
+
+    const created = handleMockApplicationsCommand(
+      "bulk_create_applications",
+      { jobIds: [...untracked.map((job) => job.id), tracked?.id, 9999] },
+      initial,
+    );
+    const createdIds = created.value as number[];
+    expect(createdIds).toHaveLength(untracked.length);
+    expect(created.state.applications.to_apply).toEqual(
+      expect.arrayContaining(
+        createdIds.map((id) => expect.objectContaining({ id })),
+      ),
+    );
+
+    const moved = handleMockApplicationsCommand(
+      "bulk_update_application_status",
+      { applicationIds: createdIds, status: "rejected" },
+      created.state,
+    );
+    expect(moved.value).toBe(createdIds.length);
+
+    const archived = handleMockApplicationsCommand(
+      "bulk_archive_rejected_applications",
+      { applicationIds: [...createdIds, 1] },
+      moved.state,
+    );
+    expect(archived.value).toBe(createdIds.length);
+    expect(archived.state.applications.rejected).not.toEqual(
+      expect.arrayContaining([expect.objectContaining({ id: createdIds[0] })]),
+    );
+  });
+
+  it("adds a reminder for an existing application", () => {
+    const initial = createState();
+    const reminderTime = new Date(Date.now() + 86400000).toISOString();
+
+    const result = handleMockApplicationsCommand(
+      "set_reminder",
+      {
+        applicationId: 1,
+        reminderType: "follow_up",
+        reminderTime,
+        message: "Check in",
+      },
+      initial,
+    );
+
+    expect(result).toMatchObject({ handled: true, shouldSave: true });
+    expect(result.state.pendingReminders).toHaveLength(
+      initial.pendingReminders.length + 1,
+    );
+    expect(result.state.pendingReminders.at(-1)).toMatchObject({
+      application_id: 1,
+      reminder_type: "follow_up",
+      reminder_time: reminderTime,
+      message: "Check in",
+      job_title: "SEO Manager",
+      company: "Shopify",
+    });
+  });
+
   it("returns an unhandled result for Dashboard commands", () => {
     const state = createState();
 
@@ -45,9 +45,21 @@ export function handleMockApplicationsCommand(
     case "update_application_status":
       return updateApplicationStatus(args, state);
 
+    case "bulk_create_applications":
+      return bulkCreateApplications(args, state);
+
+    case "bulk_update_application_status":
+      return bulkUpdateApplicationStatus(args, state);
+
+    case "bulk_archive_rejected_applications":
+      return bulkArchiveRejectedApplications(args, state);
+
     case "add_application_notes":
       return addApplicationNotes(args, state);
 
+    case "set_reminder":
+      return setReminder(args, state);
+
     case "get_pending_reminders":
       return withoutSave(state, state.pendingReminders);
 
@@ -115,6 +127,27 @@ export function handleMockApplicationsCommand(
     case "schedule_interview":
       return scheduleInterview(args, state);
 
+    case "reschedule_interview":
+      return {
+        handled: true,
+        shouldSave: true,
+        state: {
+          ...state,
+          interviews: state.interviews.map((interview): MockInterview =>
+            interview.id === getArg(args, "interviewId")
+              ? {
+                  ...interview,
+                  scheduled_at: getArg(args, "scheduledAt") as string,
+                  duration_minutes:
+                    (getArg(args, "durationMinutes") as number | undefined) ??
+                    interview.duration_minutes,
+                }
+              : interview,
+          ),
+        },
+        value: undefined,
+      };
+
     case "complete_interview":
       return {
         handled: true,
@@ -134,6 +167,12 @@ export function handleMockApplicationsCommand(
         value: undefined,
       };
 
+    case "export_interview_calendar":
+      return withoutSave(state, mockInterviewCalendar(state.interviews));
+
+    case "subscribe_interview_calendar":
+      return withoutSave(state, "JobSentinel/interviews.ics");
+
     case "delete_interview":
       return {
         handled: true,
@@ -148,11 +187,15 @@ export function handleMockApplicationsCommand(
       };
 
     case "find_duplicates":
+    case "find_near_duplicates":
       return withoutSave(state, []);
 
     case "merge_duplicates":
       return withoutSave(state, undefined);
 
+    case "get_time_to_fill_estimate":
+      return withoutSave(state, null);
+
     default:
       return { handled: false, shouldSave: false, state, value: undefined };
   }
@@ -228,6 +271,89 @@ function updateApplicationStatus(
   };
 }
 
+function getIdListArg(
+  args: Record<string, unknown> | undefined,
+  key: string,
+): number[] {
+  const value = getArg(args, key);
+  return Array.isArray(value)
+    ? value.filter((id): id is number => typeof id === "number")
+    : [];
+}
+
+function bulkCreateApplications(
+  args: Record<string, unknown> | undefined,
+  state: MockApplicationsCommandState,
+): MockApplicationsCommandResult {
+  let next = state;
+  const created: number[] = [];
+  for (const jobId of getIdListArg(args, "jobIds")) {
+    const job = next.jobs.find((candidate) => candidate.id === jobId);
+    const tracked = APPLICATION_STATUS_KEYS.some((status) =>
+      next.applications[status].some(
+        (application) => application.job_hash === job?.hash,
+      ),
+    );
+    if (!job || tracked) continue;
+
+    const result = createApplication({ jobHash: job.hash }, next);
+    next = result.state;
+    created.push(result.value as number);
+  }
+
+  return { handled: true, shouldSave: true, state: next, value: created };
+}
+
+function bulkUpdateApplicationStatus(
+  args: Record<string, unknown> | undefined,
+  state: MockApplicationsCommandState,
+): MockApplicationsCommandResult {
+  const status = getArg(args, "status");
+  if (
+    typeof status !== "string" ||
+    !APPLICATION_STATUS_KEYS.includes(status as MockApplicationStatus)
+  ) {
+    throw new Error("Invalid status");
+  }
+
+  let applications = state.applications;
+  let updated = 0;
+  for (const applicationId of getIdListArg(args, "applicationIds")) {
+    const current = findApplication(applications, applicationId);
+    if (!current || current.status === status) continue;
+
+    applications = moveApplicationStatus(applications, applicationId, status);
+    updated += 1;
+  }
+
+  return {
+    handled: true,
+    shouldSave: true,
+    state: { ...state, applications },
+    value: updated,
+  };
+}
+
+function bulkArchiveRejectedApplications(
+  args: Record<string, unknown> | undefined,
+  state: MockApplicationsCommandState,
+): MockApplicationsCommandResult {
+  const selected = new Set(getIdListArg(args, "applicationIds"));
+  const rejected = state.applications.rejected.filter(
+    (application) => !selected.has(application.id),
+  );
+
+  return {
+    handled: true,
+    shouldSave: true,
+    state: {
+      ...state,
+      applications: { ...state.applications, rejected },
+    },
+    value: state.applications.rejected.length - rejected.length,
+  };
+}
+
 function addApplicationNotes(
   args: Record<string, unknown> | undefined,
   state: MockApplicationsCommandState,
@@ -256,6 +382,41 @@ function addApplicationNotes(
   };
 }
 
+function setReminder(
+  args: Record<string, unknown> | undefined,
+  state: MockApplicationsCommandState,
+): MockApplicationsCommandResult {
+  const applicationId = getNumericArg(args, "applicationId");
+  const current =
+    applicationId === undefined
+      ? null
+      : findApplication(state.applications, applicationId);
+  if (!current) {
+    return withoutSave(state, undefined);
+  }
+
+  const reminder: MockPendingReminder = {
+    id: Math.max(...state.pendingReminders.map((entry) => entry.id), 0) + 1,
+    application_id: current.application.id,
+    reminder_type: getArg(args, "reminderType") as string,
+    reminder_time: getArg(args, "reminderTime") as string,
+    message: (getArg(args, "message") as string) ?? "",
+    job_hash: current.application.job_hash,
+    job_title: current.application.job_title,
+    company: current.application.company,
+  };
+
+  return {
+    handled: true,
+    shouldSave: true,
+    state: {
+      ...state,
+      pendingReminders: [...state.pendingReminders, reminder],
+    },
+    value: undefined,
+  };
+}
+
 function scheduleInterview(
   args: Record<string, unknown> | undefined,
   state: MockApplicationsCommandState,
@@ -285,6 +446,23 @@ function scheduleInterview(
   };
 }
 
+function mockInterviewCalendar(interviews: MockInterview[]): string {
+  return [
+    "BEGIN:VCALENDAR",
+    "VERSION:2.0",
+    "PRODID:-//JobSentinel//Interview Scheduler//EN",
+    "METHOD:PUBLISH",
+    ...interviews.flatMap((interview) => [
+      "BEGIN:VEVENT",
+      `UID:interview-${interview.id}@jobsentinel`,
+      `SUMMARY:${interview.job_title} at ${interview.company}`,
+      "END:VEVENT",
+    ]),
+    "END:VCALENDAR",
+    "",
+  ].join("\r\n");
+}
+
 function findApplication(
   applications: MockApplications,
   applicationId: number,
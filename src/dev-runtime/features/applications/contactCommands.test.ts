@@ -0,0 +1,63 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke, resetMockData } from "../../mocks/handlers";
+
+describe("Applications contact mock commands", () => {
+  beforeEach(() => {
+    resetMockData();
+  });
+
+  it("merges duplicate contacts and finds them by prefix", async () => {
+    const id = await mockInvoke<number>("save_contact", {
+      contact: {
+        name: "Jordan Lee",
+        email: "Jordan.Lee@CommunityCare.org",
+        company: "CommunityCare",
+      },
+    });
+    await expect(
+      mockInvoke<number>("save_contact", {
+        contact: { name: "Jordan Lee", email: "jordan.lee@communitycare.org" },
+      }),
+    ).resolves.toBe(id);
+
+    await expect(
+      mockInvoke("search_contacts", { query: "communityc" }),
+    ).resolves.toEqual([expect.objectContaining({ id, name: "Jordan Lee" })]);
+  });
+
+  it("links contacts and records interactions", async () => {
+    const id = await mockInvoke<number>("save_contact", {
+      contact: { name: "Sam Rivera" },
+    });
+
+    await mockInvoke("log_contact_interaction", {
+      contactId: id,
+      applicationId: 2,
+      interactionType: "call",
+      occurredAt: "2026-02-01T10:00:00Z",
+      summary: " Phone screen ",
+    });
+
+    await expect(
+      mockInvoke("get_application_contacts", { applicationId: 2 }),
+    ).resolves.toEqual([
+      expect.objectContaining({
+        id,
+        last_contacted_at: "2026-02-01T10:00:00Z",
+      }),
+    ]);
+    await expect(
+      mockInvoke("get_contact_interactions", { contactId: id }),
+    ).resolves.toEqual([
+      expect.objectContaining({
+        interaction_type: "call",
+        summary: "Phone screen",
+      }),
+    ]);
+
+    await mockInvoke("unlink_contact", { contactId: id, applicationId: 2 });
+    await expect(
+      mockInvoke("get_application_contacts", { applicationId: 2 }),
+    ).resolves.toEqual([]);
+  });
+});
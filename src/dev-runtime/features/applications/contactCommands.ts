@@ -0,0 +1,354 @@
+import {
+  getArg,
+  getNextId,
+  getNumericArg,
+  getStringArg,
+  trimmedStringOrNull,
+} from "../../mocks/handlers/commandHelpers";
+import type {
+  MockContact,
+  MockContactBook,
+  MockContactInteraction,
+} from "../../mocks/handlers/types";
+
+interface MockContactCommandResult {
+  handled: boolean;
+  value: unknown;
+  state: MockContactBook;
+  shouldSave: boolean;
+}
+
+type MockContactInput = Partial<
+  Pick<
+    MockContact,
+    "name" | "email" | "phone" | "linkedin_url" | "company" | "notes"
+  >
+>;
+
+export function getDefaultMockContactBook(): MockContactBook {
+  return {
+    contacts: [],
+    interactions: [],
+    applicationLinks: [],
+    interviewLinks: [],
+  };
+}
+
+export function normalizeMockContactBook(value: unknown): MockContactBook {
+  const book = (value ?? {}) as Partial<MockContactBook>;
+  return {
+    contacts: Array.isArray(book.contacts) ? book.contacts : [],
+    interactions: Array.isArray(book.interactions) ? book.interactions : [],
+    applicationLinks: Array.isArray(book.applicationLinks)
+      ? book.applicationLinks
+      : [],
+    interviewLinks: Array.isArray(book.interviewLinks)
+      ? book.interviewLinks
+      : [],
+  };
+}
+
+export function handleMockContactCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockContactBook,
+): MockContactCommandResult {
+  switch (command) {
+    case "save_contact":
+      return saveContact(args, state);
+
+    case "update_contact": {
+      const contactId = getNumericArg(args, "contactId");
+      const existing = state.contacts.find((entry) => entry.id === contactId);
+      if (!existing) return withoutSave(state, undefined);
+      const input = normalizeInput(getArg(args, "contact"));
+      return saved(
+        {
+          ...state,
+          contacts: state.contacts.map((entry) =>
+            entry.id === contactId
+              ? { ...entry, ...input, updated_at: new Date().toISOString() }
+              : entry,
+          ),
+        },
+        undefined,
+      );
+    }
+
+    case "delete_contact": {
+      const contactId = getNumericArg(args, "contactId");
+      return saved(
+        {
+          contacts: state.contacts.filter((entry) => entry.id !== contactId),
+          interactions: state.interactions.filter(
+            (entry) => entry.contact_id !== contactId,
+          ),
+          applicationLinks: state.applicationLinks.filter(
+            (link) => link.contact_id !== contactId,
+          ),
+          interviewLinks: state.interviewLinks.filter(
+            (link) => link.contact_id !== contactId,
+          ),
+        },
+        undefined,
+      );
+    }
+
+    case "search_contacts":
+      return withoutSave(state, searchContacts(args, state.contacts));
+
+    case "get_application_contacts": {
+      const applicationId = getNumericArg(args, "applicationId");
+      const ids = state.applicationLinks
+        .filter((link) => link.application_id === applicationId)
+        .map((link) => link.contact_id);
+      return withoutSave(state, contactsByName(state.contacts, ids));
+    }
+
+    case "get_interview_contacts": {
+      const interviewId = getNumericArg(args, "interviewId");
+      const ids = state.interviewLinks
+        .filter((link) => link.interview_id === interviewId)
+        .map((link) => link.contact_id);
+      return withoutSave(state, contactsByName(state.contacts, ids));
+    }
+
+    case "link_contact":
+      return saved(setLinks(args, state, true), undefined);
+
+    case "unlink_contact":
+      return saved(setLinks(args, state, false), undefined);
+
+    case "log_contact_interaction":
+      return logInteraction(args, state);
+
+    case "get_contact_interactions": {
+      const contactId = getNumericArg(args, "contactId");
+      return withoutSave(
+        state,
+        state.interactions
+          .filter((entry) => entry.contact_id === contactId)
+          .sort(
+            (a, b) => b.occurred_at.localeCompare(a.occurred_at) || b.id - a.id,
+          ),
+      );
+    }
+
+    default:
+      return { handled: false, shouldSave: false, state, value: undefined };
+  }
+}
+
+function withoutSave(
+  state: MockContactBook,
+  value: unknown,
+): MockContactCommandResult {
+  return { handled: true, shouldSave: false, state, value };
+}
+
+function saved(
+  state: MockContactBook,
+  value: unknown,
+): MockContactCommandResult {
+  return { handled: true, shouldSave: true, state, value };
+}
+
+function normalizeInput(value: unknown): MockContactInput {
+  const input = (value ?? {}) as MockContactInput;
+  return {
+    name: trimmedStringOrNull(input.name) ?? "",
+    email: trimmedStringOrNull(input.email)?.toLowerCase() ?? null,
+    phone: trimmedStringOrNull(input.phone),
+    linkedin_url:
+      trimmedStringOrNull(input.linkedin_url)?.replace(/\/+$/, "") ?? null,
+    company: trimmedStringOrNull(input.company),
+    notes: trimmedStringOrNull(input.notes),
+  };
+}
+
+function sameText(
+  a: string | null | undefined,
+  b: string | null | undefined,
+): boolean {
+  return (a ?? "").toLowerCase() === (b ?? "").toLowerCase();
+}
+
+function saveContact(
+  args: Record<string, unknown> | undefined,
+  state: MockContactBook,
+): MockContactCommandResult {
+  const input = normalizeInput(getArg(args, "contact"));
+  const now = new Date().toISOString();
+  const existing = state.contacts.find(
+    (entry) =>
+      (input.email && entry.email === input.email) ||
+      (input.linkedin_url &&
+        sameText(entry.linkedin_url, input.linkedin_url)) ||
+      ((!entry.email || !input.email) &&
+        sameText(entry.name, input.name) &&
+        sameText(entry.company, input.company)),
+  );
+
+  if (existing) {
+    const merged: MockContact = {
+      ...existing,
+      name: input.name ?? existing.name,
+      email: input.email ?? existing.email,
+      phone: input.phone ?? existing.phone,
+      linkedin_url: input.linkedin_url ?? existing.linkedin_url,
+      company: input.company ?? existing.company,
+      notes: input.notes ?? existing.notes,
+      updated_at: now,
+    };
+    return saved(
+      {
+        ...state,
+        contacts: state.contacts.map((entry) =>
+          entry.id === existing.id ? merged : entry,
+        ),
+      },
+      existing.id,
+    );
+  }
+
+  const contact: MockContact = {
+    id: getNextId(state.contacts),
+    name: input.name ?? "",
+    email: input.email ?? null,
+    phone: input.phone ?? null,
+    linkedin_url: input.linkedin_url ?? null,
+    company: input.company ?? null,
+    notes: input.notes ?? null,
+    last_contacted_at: null,
+    created_at: now,
+    updated_at: now,
+  };
+  return saved(
+    { ...state, contacts: [...state.contacts, contact] },
+    contact.id,
+  );
+}
+
+function searchContacts(
+  args: Record<string, unknown> | undefined,
+  contacts: MockContact[],
+): MockContact[] {
+  const limit = getNumericArg(args, "limit") ?? 50;
+  const words = (getStringArg(args, "query") ?? "")
+    .toLowerCase()
+    .split(/\s+/)
+    .filter(Boolean);
+  if (words.length === 0) {
+    return [...contacts]
+      .sort((a, b) => {
+        if (a.last_contacted_at === b.last_contacted_at) {
+          return a.name.localeCompare(b.name);
+        }
+        if (a.last_contacted_at === null) return 1;
+        if (b.last_contacted_at === null) return -1;
+        return b.last_contacted_at.localeCompare(a.last_contacted_at);
+      })
+      .slice(0, limit);
+  }
+
+  return contacts
+    .filter((contact) => {
+      const tokens = [
+        contact.name,
+        contact.email,
+        contact.company,
+        contact.notes,
+      ]
+        .join(" ")
+        .toLowerCase()
+        .split(/[^a-z0-9]+/);
+      return words.every((word) =>
+        tokens.some((token) => token.startsWith(word)),
+      );
+    })
+    .slice(0, limit);
+}
+
+function contactsByName(
+  contacts: MockContact[],
+  ids: number[],
+): MockContact[] {
+  return contacts
+    .filter((contact) => ids.includes(contact.id))
+    .sort((a, b) => a.name.localeCompare(b.name));
+}
+
+function setLinks(
+  args: Record<string, unknown> | undefined,
+  state: MockContactBook,
+  linked: boolean,
+): MockContactBook {
+  const contactId = getNumericArg(args, "contactId");
+  const applicationId = getNumericArg(args, "applicationId");
+  const interviewId = getNumericArg(args, "interviewId");
+  if (contactId === undefined) return state;
+
+  let { applicationLinks, interviewLinks } = state;
+  if (applicationId !== undefined) {
+    applicationLinks = applicationLinks.filter(
+      (link) =>
+        link.contact_id !== contactId || link.application_id !== applicationId,
+    );
+    if (linked) {
+      applicationLinks = [
+        ...applicationLinks,
+        { contact_id: contactId, application_id: applicationId },
+      ];
+    }
+  }
+  if (interviewId !== undefined) {
+    interviewLinks = interviewLinks.filter(
+      (link) =>
+        link.contact_id !== contactId || link.interview_id !== interviewId,
+    );
+    if (linked) {
+      interviewLinks = [
+        ...interviewLinks,
+        { contact_id: contactId, interview_id: interviewId },
+      ];
+    }
+  }
+  return { ...state, applicationLinks, interviewLinks };
+}
+
+function logInteraction(
+  args: Record<string, unknown> | undefined,
+  state: MockContactBook,
+): MockContactCommandResult {
+  const contactId = getNumericArg(args, "contactId");
+  const applicationId = getNumericArg(args, "applicationId") ?? null;
+  const occurredAt =
+    getStringArg(args, "occurredAt") ?? new Date().toISOString();
+  if (contactId === undefined) return withoutSave(state, undefined);
+
+  const interaction: MockContactInteraction = {
+    id: getNextId(state.interactions),
+    contact_id: contactId,
+    application_id: applicationId,
+    interaction_type: getStringArg(args, "interactionType") ?? "other",
+    occurred_at: occurredAt,
+    summary: trimmedStringOrNull(getArg(args, "summary")),
+  };
+  const withLink =
+    applicationId === null
+      ? state
+      : setLinks({ contactId, applicationId }, state, true);
+
+  return saved(
+    {
+      ...withLink,
+      contacts: state.contacts.map((entry) =>
+        entry.id === contactId && (entry.last_contacted_at ?? "") < occurredAt
+          ? { ...entry, last_contacted_at: occurredAt }
+          : entry,
+      ),
+      interactions: [...state.interactions, interaction],
+    },
+    interaction.id,
+  );
+}
@@ -0,0 +1,40 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke, resetMockData } from "../../mocks/handlers";
+
+describe("Applications cover letter draft mock commands", () => {
+  beforeEach(() => {
+    resetMockData();
+  });
+
+  it("fills a template for a job and saves it to the application", async () => {
+    const template = await mockInvoke<{ id: string }>(
+      "create_cover_letter_template",
+      {
+        name: "Targeted letter",
+        content: "Dear {hiring_manager}, I want to be {position} at {company}.",
+        category: "custom",
+      },
+    );
+
+    const draft = await mockInvoke<{
+      body: string;
+      attachment: { kind: string; file_name: string } | null;
+    }>("generate_cover_letter", {
+      templateId: template.id,
+      jobHash: "job-hash-2",
+      resumeId: 1,
+      applicationId: 2,
+    });
+
+    expect(draft.body).toBe(
+      "Dear Hiring Manager, I want to be Customer Success Manager at BrightPath Health.",
+    );
+    expect(draft.attachment).toMatchObject({
+      kind: "cover_letter",
+      file_name: "Cover letter - BrightPath Health.txt",
+    });
+    await expect(
+      mockInvoke("get_attachments", { applicationId: 2 }),
+    ).resolves.toEqual([expect.objectContaining({ kind: "cover_letter" })]);
+  });
+});
@@ -0,0 +1,105 @@
+import {
+  getNextId,
+  getNumericArg,
+  getStringArg,
+} from "../../mocks/handlers/commandHelpers";
+import type {
+  MockAttachment,
+  MockCoverLetterTemplate,
+  MockJob,
+  MockUserSkill,
+} from "../../mocks/handlers/types";
+
+interface MockCoverLetterDraftState {
+  jobs: MockJob[];
+  coverLetterTemplates: MockCoverLetterTemplate[];
+  userSkills: MockUserSkill[];
+  attachments: MockAttachment[];
+}
+
+interface MockCoverLetterDraftResult {
+  handled: boolean;
+  value: unknown;
+  attachments: MockAttachment[];
+  shouldSave: boolean;
+}
+
+// Mock skills are ordered by confidence; the real command puts skills the
+// job asks for first.
+export function handleMockCoverLetterDraftCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockCoverLetterDraftState,
+): MockCoverLetterDraftResult {
+  const unchanged = { attachments: state.attachments, shouldSave: false };
+  if (command !== "generate_cover_letter") {
+    return { handled: false, value: undefined, ...unchanged };
+  }
+
+  const template = state.coverLetterTemplates.find(
+    (candidate) => candidate.id === getStringArg(args, "templateId"),
+  );
+  const job = state.jobs.find(
+    (candidate) => candidate.hash === getStringArg(args, "jobHash"),
+  );
+  if (!template || !job) {
+    return { handled: true, value: undefined, ...unchanged };
+  }
+
+  const resumeId = getNumericArg(args, "resumeId");
+  const skills = state.userSkills
+    .filter((skill) => skill.resume_id === resumeId)
+    .sort((a, b) => b.confidence_score - a.confidence_score);
+  const years = Math.max(
+    0,
+    ...skills.map((skill) => skill.years_experience ?? 0),
+  );
+  const today = new Date().toLocaleDateString("en-US", {
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+  });
+  const body = template.content
+    .replace(/\{company\}/g, job.company)
+    .replace(/\{position\}/g, job.title)
+    .replace(/\{location\}/g, job.location || "Remote")
+    .replace(/\{hiring_manager\}/g, "Hiring Manager")
+    .replace(/\{your_name\}/g, "[Your Name]")
+    .replace(/\{date\}/g, today)
+    .replace(/\{skill1\}/g, skills[0]?.skill_name ?? "[Your Primary Skill]")
+    .replace(/\{skill2\}/g, skills[1]?.skill_name ?? "[Your Secondary Skill]")
+    .replace(/\{years_experience\}/g, years >= 1 ? years.toFixed(0) : "[X]");
+
+  const applicationId = getNumericArg(args, "applicationId");
+  const attachment: MockAttachment | null =
+    applicationId === undefined
+      ? null
+      : {
+          id: getNextId(state.attachments),
+          application_id: applicationId,
+          kind: "cover_letter",
+          version:
+            state.attachments.filter(
+              (entry) =>
+                entry.application_id === applicationId &&
+                entry.kind === "cover_letter",
+            ).length + 1,
+          file_name: `Cover letter - ${job.company}.txt`,
+          size_bytes: body.length,
+          created_at: new Date().toISOString(),
+        };
+
+  return {
+    handled: true,
+    value: {
+      template_id: template.id,
+      template_name: template.name,
+      body,
+      attachment,
+    },
+    attachments: attachment
+      ? [...state.attachments, attachment]
+      : state.attachments,
+    shouldSave: attachment !== null,
+  };
+}
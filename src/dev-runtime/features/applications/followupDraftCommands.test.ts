@@ -0,0 +1,32 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke, resetMockData } from "../../mocks/handlers";
+
+describe("Applications follow-up draft mock commands", () => {
+  beforeEach(() => {
+    resetMockData();
+  });
+
+  it("fills the follow-up template for a linked contact", async () => {
+    await mockInvoke("seed_default_templates");
+    const contactId = await mockInvoke<number>("save_contact", {
+      contact: { name: "Jordan Lee", email: "jordan.lee@wayfair.com" },
+    });
+    await mockInvoke("link_contact", { contactId, applicationId: 2 });
+
+    const draft = await mockInvoke<{
+      to: string | null;
+      subject: string;
+      body: string;
+    }>("generate_followup_draft", { applicationId: 2, kind: "follow_up" });
+
+    expect(draft.to).toBe("jordan.lee@wayfair.com");
+    expect(draft.subject).toBe(
+      "Following up on my E-Commerce Manager application",
+    );
+    expect(draft.body).toContain("Dear Jordan Lee,");
+    expect(draft.body).toContain(
+      "application for the E-Commerce Manager role at Wayfair",
+    );
+    expect(draft.body).not.toMatch(/\{[a-z_0-9]+\}/);
+  });
+});
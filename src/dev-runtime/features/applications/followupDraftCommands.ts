@@ -0,0 +1,93 @@
+import {
+  getArg,
+  getNumericArg,
+  getStringArg,
+} from "../../mocks/handlers/commandHelpers";
+import type {
+  MockApplications,
+  MockContactBook,
+  MockCoverLetterTemplate,
+  MockTemplateCategory,
+} from "../../mocks/handlers/types";
+
+interface MockFollowUpDraftState {
+  applications: MockApplications;
+  coverLetterTemplates: MockCoverLetterTemplate[];
+  contacts: MockContactBook;
+}
+
+const DRAFT_CATEGORIES: Record<string, MockTemplateCategory> = {
+  thank_you: "thankyou",
+  follow_up: "followup",
+  withdrawal: "withdrawal",
+};
+
+const DRAFT_SUBJECTS: Record<string, (position: string) => string> = {
+  thank_you: (position) => `Thank you - ${position} interview`,
+  follow_up: (position) => `Following up on my ${position} application`,
+  withdrawal: (position) => `Withdrawing my ${position} application`,
+};
+
+// Mock drafts have no interviews, so {date} is always the applied date.
+export function handleMockFollowUpDraftCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockFollowUpDraftState,
+): { handled: boolean; value: unknown } {
+  if (command !== "generate_followup_draft") {
+    return { handled: false, value: undefined };
+  }
+
+  const applicationId = getNumericArg(args, "applicationId");
+  const kind = getStringArg(args, "kind") ?? "";
+  const templateId = getArg(args, "templateId");
+  const application = Object.values(state.applications)
+    .flat()
+    .find((candidate) => candidate.id === applicationId);
+  const template = [...state.coverLetterTemplates]
+    .sort((a, b) => b.updatedAt.localeCompare(a.updatedAt))
+    .find((candidate) =>
+      typeof templateId === "string"
+        ? candidate.id === templateId
+        : candidate.category === DRAFT_CATEGORIES[kind],
+    );
+  if (!application || !template || !DRAFT_SUBJECTS[kind]) {
+    return { handled: true, value: undefined };
+  }
+
+  const contactId = state.contacts.applicationLinks.find(
+    (link) => link.application_id === application.id,
+  )?.contact_id;
+  const contact = state.contacts.contacts.find(
+    (candidate) => candidate.id === contactId,
+  );
+  const date = application.applied_at
+    ? new Date(application.applied_at).toLocaleDateString("en-US", {
+        year: "numeric",
+        month: "long",
+        day: "numeric",
+        timeZone: "UTC",
+      })
+    : "[Date]";
+  const body = template.content
+    .replace(/\{company\}/g, application.company)
+    .replace(/\{position\}/g, application.job_title)
+    .replace(/\{location\}/g, "Remote")
+    .replace(/\{hiring_manager\}/g, contact?.name ?? "Hiring Manager")
+    .replace(/\{your_name\}/g, "[Your Name]")
+    .replace(/\{date\}/g, date)
+    .replace(/\{skill1\}/g, "[Your Primary Skill]")
+    .replace(/\{skill2\}/g, "[Your Secondary Skill]")
+    .replace(/\{years_experience\}/g, "[X]");
+
+  return {
+    handled: true,
+    value: {
+      template_id: template.id,
+      template_name: template.name,
+      to: contact?.email ?? null,
+      subject: DRAFT_SUBJECTS[kind](application.job_title),
+      body,
+    },
+  };
+}
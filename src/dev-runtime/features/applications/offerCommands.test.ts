@@ -0,0 +1,50 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke, resetMockData } from "../../mocks/handlers";
+
+describe("Applications offer mock commands", () => {
+  beforeEach(() => {
+    resetMockData();
+  });
+
+  it("saves offers and compares them by cost of living", async () => {
+    const salaried = await mockInvoke<number>("save_offer", {
+      applicationId: 2,
+      offer: {
+        base_salary: 100000,
+        annual_bonus: 10000,
+        cost_of_living_index: 125,
+        offer_expires_at: "2026-03-01T17:00:00Z",
+      },
+    });
+    const hourly = await mockInvoke<number>("save_offer", {
+      applicationId: 3,
+      offer: { base_salary: 45, pay_period: "hour", cost_of_living_index: 80 },
+    });
+
+    await expect(
+      mockInvoke<Array<{ id: number }>>("get_offers"),
+    ).resolves.toEqual([
+      expect.objectContaining({ id: salaried, company: "Wayfair" }),
+      expect.objectContaining({ id: hourly, company: "Mailchimp" }),
+    ]);
+    await expect(
+      mockInvoke("compare_offers", { offerIds: [salaried, hourly] }),
+    ).resolves.toEqual([
+      expect.objectContaining({
+        offer_id: hourly,
+        total_compensation: 93600,
+        adjusted_compensation: 117000,
+      }),
+      expect.objectContaining({
+        offer_id: salaried,
+        total_compensation: 110000,
+        adjusted_compensation: 88000,
+      }),
+    ]);
+
+    await mockInvoke("delete_offer", { offerId: salaried });
+    await expect(mockInvoke("get_offers")).resolves.toEqual([
+      expect.objectContaining({ id: hourly }),
+    ]);
+  });
+});
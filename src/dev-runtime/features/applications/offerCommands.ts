@@ -0,0 +1,164 @@
+import { getArg, getNumericArg } from "../../mocks/handlers/commandHelpers";
+import type {
+  MockApplications,
+  MockOffer,
+} from "../../mocks/handlers/types";
+
+interface MockOfferCommandState {
+  applications: MockApplications;
+  offers: MockOffer[];
+}
+
+interface MockOfferCommandResult {
+  handled: boolean;
+  value: unknown;
+  state: MockOfferCommandState;
+  shouldSave: boolean;
+}
+
+const PERIODS_PER_YEAR: Record<MockOffer["pay_period"], number> = {
+  hour: 2080,
+  day: 260,
+  week: 52,
+  month: 12,
+  year: 1,
+};
+
+export function handleMockOfferCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockOfferCommandState,
+): MockOfferCommandResult {
+  switch (command) {
+    case "save_offer":
+      return saveOffer(args, state);
+
+    case "get_offers":
+      return withoutSave(state, sortByDeadline(state.offers));
+
+    case "delete_offer": {
+      const offerId = getNumericArg(args, "offerId");
+      return {
+        handled: true,
+        shouldSave: true,
+        state: {
+          ...state,
+          offers: state.offers.filter((offer) => offer.id !== offerId),
+        },
+        value: undefined,
+      };
+    }
+
+    case "compare_offers":
+      return withoutSave(state, compareOffers(args, state.offers));
+
+    default:
+      return { handled: false, shouldSave: false, state, value: undefined };
+  }
+}
+
+function withoutSave(
+  state: MockOfferCommandState,
+  value: unknown,
+): MockOfferCommandResult {
+  return { handled: true, shouldSave: false, state, value };
+}
+
+function saveOffer(
+  args: Record<string, unknown> | undefined,
+  state: MockOfferCommandState,
+): MockOfferCommandResult {
+  const applicationId = getNumericArg(args, "applicationId");
+  const application = Object.values(state.applications)
+    .flat()
+    .find((candidate) => candidate.id === applicationId);
+  const input = getArg(args, "offer") as Partial<MockOffer> | undefined;
+  if (!application || !input) {
+    return withoutSave(state, undefined);
+  }
+
+  const existing = state.offers.find(
+    (offer) => offer.application_id === application.id,
+  );
+  const offer: MockOffer = {
+    id:
+      existing?.id ?? Math.max(...state.offers.map((entry) => entry.id), 0) + 1,
+    application_id: application.id,
+    job_title: application.job_title,
+    company: application.company,
+    base_salary: input.base_salary ?? 0,
+    currency: input.currency?.toUpperCase() ?? "USD",
+    pay_period: input.pay_period ?? "year",
+    annual_bonus: input.annual_bonus ?? null,
+    signing_bonus: input.signing_bonus ?? null,
+    equity_annual_value: input.equity_annual_value ?? null,
+    benefits_summary: input.benefits_summary ?? null,
+    benefits_annual_value: input.benefits_annual_value ?? null,
+    location: input.location ?? null,
+    cost_of_living_index: input.cost_of_living_index ?? null,
+    start_date: input.start_date ?? null,
+    offer_expires_at: input.offer_expires_at ?? null,
+    offer_received_at: existing?.offer_received_at ?? new Date().toISOString(),
+    accepted: existing?.accepted ?? null,
+  };
+
+  return {
+    handled: true,
+    shouldSave: true,
+    state: {
+      ...state,
+      offers: [
+        ...state.offers.filter((entry) => entry.id !== offer.id),
+        offer,
+      ],
+    },
+    value: offer.id,
+  };
+}
+
+function sortByDeadline(offers: MockOffer[]): MockOffer[] {
+  return [...offers].sort((a, b) => {
+    if (a.offer_expires_at === b.offer_expires_at) return a.id - b.id;
+    if (a.offer_expires_at === null) return 1;
+    if (b.offer_expires_at === null) return -1;
+    return a.offer_expires_at.localeCompare(b.offer_expires_at);
+  });
+}
+
+// Mock amounts are treated as US dollars; the app converts other currencies.
+function compareOffers(
+  args: Record<string, unknown> | undefined,
+  offers: MockOffer[],
+) {
+  const offerIds = (getArg(args, "offerIds") as number[] | undefined) ?? [];
+  return offers
+    .filter((offer) => offerIds.includes(offer.id))
+    .map((offer) => {
+      const baseSalary =
+        offer.base_salary * PERIODS_PER_YEAR[offer.pay_period];
+      const totalCompensation =
+        baseSalary +
+        (offer.annual_bonus ?? 0) +
+        (offer.equity_annual_value ?? 0) +
+        (offer.benefits_annual_value ?? 0);
+      const costOfLivingIndex = offer.cost_of_living_index ?? 100;
+      return {
+        offer_id: offer.id,
+        company: offer.company,
+        job_title: offer.job_title,
+        location: offer.location,
+        base_salary: baseSalary,
+        total_compensation: totalCompensation,
+        first_year_compensation: totalCompensation + (offer.signing_bonus ?? 0),
+        cost_of_living_index: costOfLivingIndex,
+        adjusted_compensation: Math.round(
+          (totalCompensation * 100) / costOfLivingIndex,
+        ),
+        offer_expires_at: offer.offer_expires_at,
+        market_median: null,
+        market_position: "unknown",
+        recommendation: "Insufficient data for recommendation.",
+      };
+    })
+    .sort((a, b) => b.adjusted_compensation - a.adjusted_compensation);
+}
@@ -1,14 +1,37 @@
 import { mockStatistics } from "../../mocks/data";
 import {
   getArg,
+  getDefaultGhostConfig,
   getJobId,
+  getStringArg,
 } from "../../mocks/handlers/commandHelpers";
 import type { MockJob } from "../../mocks/handlers/types";
+import { handleMockQuickAction } from "./quickActionCommands";
 
 export interface MockDashboardCommandState {
   jobs: MockJob[];
 }
 
+// Tags live only for the dev session, keyed by job id.
+const mockJobTags = new Map<number, string[]>();
+
+interface MockDatabaseBackup {
+  file_name: string;
+  kind: "manual" | "pre_migration" | "before_restore";
+  created_at: string;
+  size_bytes: number;
+}
+
+// Set on mock jobs once they are marked real or ghost.
+interface MockGhostFields {
+  ghost_score?: number | null;
+  ghost_reasons?: string | null;
+  user_ghost_verdict?: string;
+}
+
+// Backups live only for the dev session, newest first.
+const mockBackups: MockDatabaseBackup[] = [];
+
 export interface MockDashboardCommandResult {
   handled: boolean;
   shouldSave: boolean;
@@ -50,6 +73,12 @@ export function handleMockDashboardCommand(
     case "toggle_bookmark":
       return toggleBookmark(args, state);
 
+    case "quick_action":
+      return handleMockQuickAction(args, state);
+
+    case "undo_quick_action":
+      return withoutSave(state, null);
+
     case "get_bookmarked_jobs":
       return withoutSave(
         state,
@@ -66,6 +95,79 @@ export function handleMockDashboardCommand(
         ),
       );
 
+    case "tag_job":
+      return withoutSave(state, updateJobTags(args, true));
+
+    case "untag_job":
+      return withoutSave(state, updateJobTags(args, false));
+
+    case "get_job_tags":
+      return withoutSave(state, mockJobTags.get(getJobId(args) ?? -1) ?? []);
+
+    case "list_job_tags":
+      return withoutSave(state, listJobTags(state.jobs));
+
+    case "get_jobs_by_tag": {
+      const tag = getStringArg(args, "tag")?.trim().toLowerCase() ?? "";
+      return withoutSave(
+        state,
+        state.jobs.filter(
+          (job) =>
+            !job.hidden &&
+            (mockJobTags.get(job.id) ?? []).some(
+              (jobTag) => jobTag.toLowerCase() === tag,
+            ),
+        ),
+      );
+    }
+
+    case "query_jobs":
+      return withoutSave(state, queryJobs(state.jobs, args));
+
+    case "query_jobs_page":
+      return withoutSave(
+        state,
+        pageJobs(
+          queryJobs(state.jobs, { filter: getArg(args, "filter") }),
+          args,
+        ),
+      );
+
+    case "get_recent_jobs_page":
+      return withoutSave(
+        state,
+        pageJobs(state.jobs.filter((job) => !job.hidden), args),
+      );
+
+    case "get_bookmarked_jobs_page":
+      return withoutSave(
+        state,
+        pageJobs(
+          state.jobs.filter((job) => !job.hidden && job.bookmarked),
+          args,
+        ),
+      );
+
+    case "get_jobs_by_source_page":
+      return withoutSave(
+        state,
+        pageJobs(
+          filterJobs(state.jobs, { source: getStringArg(args, "source") }),
+          args,
+        ),
+      );
+
+    case "get_jobs_by_tag_page":
+      return withoutSave(
+        state,
+        pageJobs(
+          queryJobs(state.jobs, {
+            filter: { tags: [getStringArg(args, "tag") ?? ""] },
+          }),
+          args,
+        ),
+      );
+
     case "mark_job_as_real":
       return withJobs(
         state,
@@ -103,12 +205,141 @@ export function handleMockDashboardCommand(
         ),
       );
 
+    // Buckets use the default thresholds; settings live in another mock.
+    case "get_ghost_analysis": {
+      const job = state.jobs.find((entry) => entry.id === getJobId(args)) as
+        | (MockJob & MockGhostFields)
+        | undefined;
+      if (!job) return withoutSave(state, null);
+      const { warning_threshold, hide_threshold } = getDefaultGhostConfig();
+      const score = job.ghost_score ?? null;
+      let bucket: string | null = null;
+      if (score !== null) {
+        bucket =
+          score >= hide_threshold
+            ? "hidden"
+            : score >= warning_threshold
+              ? "warning"
+              : "clear";
+      }
+      return withoutSave(state, {
+        job_id: job.id,
+        ghost_score: score,
+        reasons: job.ghost_reasons ? JSON.parse(job.ghost_reasons) : [],
+        bucket,
+        feedback: job.user_ghost_verdict ?? null,
+      });
+    }
+
+    // Mock jobs carry no posting history to roll up.
+    case "get_company_reputation":
+      return withoutSave(state, null);
+
     case "get_job_notes":
       return withoutSave(
         state,
         state.jobs.find((job) => job.id === getJobId(args))?.notes || null,
       );
 
+    case "get_ghost_reanalysis_summary":
+      return withoutSave(state, null);
+
+    // Mock ghost scores are fixed, so a batch run moves nothing.
+    case "analyze_ghosts_batch": {
+      const now = new Date().toISOString();
+      return withoutSave(state, {
+        detector_version: 2000001,
+        started_at: now,
+        finished_at: now,
+        jobs_reanalyzed: state.jobs.length,
+        moved_to_clear: 0,
+        moved_to_warning: 0,
+        moved_to_hidden: 0,
+      });
+    }
+
+    // No file dialog in the browser runtime; treat it as cancelled.
+    case "import_ghost_pattern_pack":
+      return withoutSave(state, null);
+
+    // Mock jobs have no cross-source copies.
+    case "get_job_duplicates":
+      return withoutSave(state, []);
+
+    case "unlink_job_duplicate":
+      return withoutSave(state, false);
+
+    case "record_job_feedback":
+      return withoutSave(state, getArg(args, "feedback") === null ? 0 : 1);
+
+    case "refresh_job": {
+      const job = state.jobs.find((item) => item.id === getArg(args, "jobId"));
+      if (!job) throw new Error("Job not found");
+      return withoutSave(state, {
+        job,
+        changes: [],
+        refreshed_at: new Date().toISOString(),
+      });
+    }
+
+    case "db_health":
+      return withoutSave(state, {
+        page_size: 4096,
+        page_count: 2048,
+        freelist_count: 12,
+        size_bytes: 4096 * 2048,
+        journal_mode: "wal",
+        wal_size_bytes: 131072,
+        busy_timeout_ms: 15000,
+        pool_size: 2,
+        pool_idle: 1,
+        pool_max: 8,
+        indexes: [
+          {
+            name: "idx_jobs_hidden_created_id",
+            table: "jobs",
+            analyzed_rows: state.jobs.length,
+            used_by: ["job_page"],
+          },
+        ],
+        full_scans: [],
+      });
+
+    case "create_backup": {
+      const createdAt = new Date();
+      const stamp = createdAt
+        .toISOString()
+        .replace(/[-:]/g, "")
+        .replace("T", "_")
+        .slice(0, 15);
+      const backup: MockDatabaseBackup = {
+        file_name: `backup_manual_${stamp}.db`,
+        kind: "manual",
+        created_at: createdAt.toISOString(),
+        size_bytes: 4096 * 2048,
+      };
+      mockBackups.unshift(backup);
+      mockBackups.splice(10);
+      return withoutSave(state, backup);
+    }
+
+    case "list_backups":
+      return withoutSave(state, [...mockBackups]);
+
+    case "restore_backup": {
+      const fileName = getStringArg(args, "fileName");
+      const backup = mockBackups.find((entry) => entry.file_name === fileName);
+      if (!backup) throw new Error("Backup not found");
+      return withoutSave(state, backup);
+    }
+
+    case "purge_now":
+      return purgeJobs(args, state);
+
+    // The browser runtime has no save dialog; report what would be written.
+    case "export_jobs":
+      return withoutSave(state, state.jobs.filter((job) => !job.hidden).length);
+
     case "get_statistics":
       return withoutSave(state, {
         ...mockStatistics,
@@ -128,7 +359,14 @@ export function handleMockDashboardCommand(
         jobs_found: state.jobs.length,
       });
 
+    case "pause_scraping":
+      return withoutSave(state, new Date().toISOString());
+
+    case "resume_scraping":
+      return withoutSave(state, true);
+
     case "search_jobs":
+    case "run_scrape_now":
       return withoutSave(state, {
         jobs_found: Math.floor(Math.random() * 20) + 5,
         duration_ms: 1500,
@@ -146,6 +384,38 @@ function withoutSave(
   return { handled: true, shouldSave: false, state, value };
 }
 
+// Mock jobs carry no last_seen, so created_at stands in for it.
+function purgeJobs(
+  args: Record<string, unknown> | undefined,
+  state: MockDashboardCommandState,
+): MockDashboardCommandResult {
+  const dryRun = getArg(args, "dryRun") !== false;
+  const cutoff = Date.now() - 90 * 86400000;
+  const purged = state.jobs.filter(
+    (job) =>
+      Date.parse(job.created_at) < cutoff && !job.bookmarked && !job.notes,
+  );
+  const report = {
+    dry_run: dryRun,
+    jobs_matched: purged.length,
+    jobs_deleted: dryRun ? 0 : purged.length,
+    sample: purged.slice(0, 20).map((job) => ({
+      id: job.id,
+      title: job.title,
+      company: job.company,
+      source: job.source,
+      score: job.score,
+      last_seen: job.created_at,
+    })),
+  };
+  if (dryRun || purged.length === 0) return withoutSave(state, report);
+  return withJobs(
+    state,
+    state.jobs.filter((job) => !purged.includes(job)),
+    report,
+  );
+}
+
 function withJobs(
   state: MockDashboardCommandState,
   jobs: MockJob[],
@@ -173,6 +443,113 @@ function toggleBookmark(
   return withJobs(state, jobs, nextState);
 }
 
+function updateJobTags(
+  args: Record<string, unknown> | undefined,
+  add: boolean,
+): boolean {
+  const jobId = getJobId(args);
+  const tag = getStringArg(args, "tag")?.trim().replace(/\s+/g, " ");
+  if (jobId === undefined || !tag) throw new Error("Tag is required");
+
+  const tags = mockJobTags.get(jobId) ?? [];
+  const hasTag = tags.some((item) => item.toLowerCase() === tag.toLowerCase());
+  if (add === hasTag) return false;
+  mockJobTags.set(
+    jobId,
+    add
+      ? [...tags, tag].sort()
+      : tags.filter((item) => item.toLowerCase() !== tag.toLowerCase()),
+  );
+  return true;
+}
+
+function listJobTags(
+  jobs: MockJob[],
+): Array<{ tag: string; job_count: number }> {
+  const counts = new Map<string, number>();
+  for (const job of jobs.filter((item) => !item.hidden)) {
+    for (const tag of mockJobTags.get(job.id) ?? []) {
+      counts.set(tag, (counts.get(tag) ?? 0) + 1);
+    }
+  }
+  return [...counts]
+    .map(([tag, job_count]) => ({ tag, job_count }))
+    .sort((a, b) => b.job_count - a.job_count || a.tag.localeCompare(b.tag));
+}
+
+interface MockJobFilter {
+  text?: string | null;
+  min_score?: number | null;
+  max_score?: number | null;
+  sources?: string[];
+  remote?: boolean | null;
+  tags?: string[];
+  bookmarked?: boolean | null;
+}
+
+function queryJobs(
+  jobs: MockJob[],
+  args?: Record<string, unknown>,
+): MockJob[] {
+  const filter = (getArg(args, "filter") ?? {}) as MockJobFilter;
+  const limit = (getArg(args, "limit") as number | undefined) ?? Infinity;
+  const text = filter.text?.trim().toLowerCase();
+  const tags = (filter.tags ?? []).map((tag) => tag.trim().toLowerCase());
+
+  return jobs
+    .filter(
+      (job) =>
+        !job.hidden &&
+        (filter.min_score == null || job.score >= filter.min_score) &&
+        (filter.max_score == null || job.score < filter.max_score) &&
+        (!filter.sources?.length || filter.sources.includes(job.source)) &&
+        (filter.remote == null || job.remote === filter.remote) &&
+        (filter.bookmarked == null || job.bookmarked === filter.bookmarked) &&
+        (!tags.length ||
+          (mockJobTags.get(job.id) ?? []).some((tag) =>
+            tags.includes(tag.toLowerCase()),
+          )) &&
+        (!text ||
+          job.title.toLowerCase().includes(text) ||
+          job.description.toLowerCase().includes(text)),
+    )
+    .sort((a, b) => b.score - a.score)
+    .slice(0, limit);
+}
+
+interface MockJobCursor {
+  created_at: string;
+  id: number;
+}
+
+// Newest first by (created_at, id), like the keyset pages.
+function pageJobs(
+  jobs: MockJob[],
+  args?: Record<string, unknown>,
+): { jobs: MockJob[]; next_cursor: MockJobCursor | null } {
+  const cursor = getArg(args, "cursor") as MockJobCursor | null | undefined;
+  const limit = (getArg(args, "limit") as number | undefined) ?? 50;
+  const key = (job: MockJob) => Date.parse(job.created_at);
+  const ordered = [...jobs]
+    .sort((a, b) => key(b) - key(a) || b.id - a.id)
+    .filter(
+      (job) =>
+        !cursor ||
+        key(job) < Date.parse(cursor.created_at) ||
+        (key(job) === Date.parse(cursor.created_at) && job.id < cursor.id),
+    );
+  const page = ordered.slice(0, limit);
+  const last = page[page.length - 1];
+
+  return {
+    jobs: page,
+    next_cursor:
+      ordered.length > limit && last
+        ? { created_at: last.created_at, id: last.id }
+        : null,
+  };
+}
+
 function filterJobs(
   jobs: MockJob[],
   args?: Record<string, unknown>,
@@ -0,0 +1,96 @@
+import {
+  getNumericArg,
+  getStringArg,
+} from "../../mocks/handlers/commandHelpers";
+import type { MockJob } from "../../mocks/handlers/types";
+import type {
+  MockDashboardCommandResult,
+  MockDashboardCommandState,
+} from "./commands";
+
+const SNOOZE_MS = 24 * 60 * 60 * 1000;
+
+export function handleMockQuickAction(
+  args: Record<string, unknown> | undefined,
+  state: MockDashboardCommandState,
+): MockDashboardCommandResult {
+  const action = getStringArg(args, "action");
+  const targetId = getNumericArg(args, "targetId");
+  const batchId = getStringArg(args, "batchId") ?? "mock-quick-action-batch";
+
+  if (action === "snooze_reminder") {
+    return withoutSave(state, {
+      action,
+      target_id: targetId,
+      revision: `reminder:${targetId}`,
+      batch_id: batchId,
+      snoozed_until: new Date(Date.now() + SNOOZE_MS).toISOString(),
+    });
+  }
+
+  const job = state.jobs.find((item) => item.id === targetId);
+  if (!job) throw new Error("This item is no longer available.");
+
+  const expectedRevision = getStringArg(args, "expectedRevision");
+  if (expectedRevision && expectedRevision !== revision(job)) {
+    throw new Error("This item changed since it was loaded. Refresh and try again.");
+  }
+
+  switch (action) {
+    case "bookmark":
+      return withJob(state, { ...job, bookmarked: !job.bookmarked }, action, batchId);
+    case "dismiss":
+      return withJob(state, { ...job, hidden: true }, action, batchId);
+    case "open":
+      return withoutSave(state, {
+        action,
+        target_id: job.id,
+        revision: revision(job),
+        batch_id: null,
+        url: job.url,
+      });
+    case "create_application":
+      return withoutSave(state, {
+        action,
+        target_id: job.id,
+        revision: revision(job),
+        batch_id: batchId,
+        application_id: job.id,
+      });
+    default:
+      throw new Error(`Unknown quick action: ${String(action)}`);
+  }
+}
+
+function revision(job: MockJob): string {
+  return `job:${job.id}:${Number(job.hidden)}:${Number(job.bookmarked)}`;
+}
+
+function withJob(
+  state: MockDashboardCommandState,
+  updated: MockJob,
+  action: string,
+  batchId: string,
+): MockDashboardCommandResult {
+  return {
+    handled: true,
+    shouldSave: true,
+    state: {
+      jobs: state.jobs.map((job) => (job.id === updated.id ? updated : job)),
+    },
+    value: {
+      action,
+      target_id: updated.id,
+      revision: revision(updated),
+      batch_id: batchId,
+      ...(action === "bookmark" ? { bookmarked: updated.bookmarked } : {}),
+    },
+  };
+}
+
+function withoutSave(
+  state: MockDashboardCommandState,
+  value: unknown,
+): MockDashboardCommandResult {
+  return { handled: true, shouldSave: false, state, value };
+}
@@ -19,8 +19,21 @@ interface BackendSavedSearch {
   textSearch: string | null;
   createdAt: string;
   lastUsedAt: string | null;
+  agent?: {
+    enabled: boolean;
+    channels: string[];
+    frequency: "each_scrape" | "daily";
+    lastCheckedAt: string | null;
+  };
 }
 
+const agentOff = {
+  enabled: false,
+  channels: [],
+  frequency: "each_scrape",
+  lastCheckedAt: null,
+};
+
 const savedSearchInput: BackendSavedSearch = {
   id: "",
   name: "Remote Support",
@@ -109,12 +122,74 @@ describe("Dashboard saved-search mock commands", () => {
       }),
     ).toBe(1);
     expect(await mockInvoke<BackendSavedSearch[]>("list_saved_searches")).toEqual([
-      search,
+      { ...search, agent: agentOff },
+    ]);
+  });
+
+  it("turns a saved search into a job agent from the time it is turned on", async () => {
+    const created = await mockInvoke<BackendSavedSearch>("create_saved_search", {
+      search: savedSearchInput,
+    });
+    expect(created.agent).toEqual(agentOff);
+
+    expect(
+      await mockInvoke<boolean>("update_saved_search_agent", {
+        id: created.id,
+        settings: { enabled: true, channels: ["slack", "sms"], frequency: "daily" },
+      }),
+    ).toBe(true);
+    expect(
+      await mockInvoke<boolean>("update_saved_search_agent", {
+        id: "missing",
+        settings: { enabled: true },
+      }),
+    ).toBe(false);
+
+    const [agentSearch] = await mockInvoke<BackendSavedSearch[]>("list_saved_searches");
+    expect(agentSearch.agent).toEqual({
+      enabled: true,
+      channels: ["slack"],
+      frequency: "daily",
+      lastCheckedAt: expect.any(String),
+    });
+  });
+
+  it("suggests pinned and recent queries before matching job titles and companies", async () => {
+    await mockInvoke("add_search_history", { query: "manager remote" });
+    await mockInvoke("add_search_history", { query: "seo lead" });
+    await mockInvoke("add_search_history", { query: "customer success" });
+    expect(
+      await mockInvoke<boolean>("pin_search_suggestion", {
+        query: "seo lead",
+        pinned: true,
+      }),
+    ).toBe(true);
+
+    const suggestions = await mockInvoke<Array<{ text: string; source: string; pinned: boolean }>>(
+      "suggest_queries",
+      { prefix: "man" },
+    );
+    expect(suggestions[0]).toMatchObject({ text: "manager remote", source: "history" });
+    expect(suggestions).toContainEqual(
+      expect.objectContaining({ text: "SEO Manager", source: "job_title" }),
+    );
+    expect(suggestions.some(({ text }) => text === "seo lead")).toBe(false);
+
+    const recent = await mockInvoke<Array<{ text: string; pinned: boolean }>>(
+      "suggest_queries",
+      { prefix: "" },
+    );
+    expect(recent[0]).toMatchObject({ text: "seo lead", pinned: true });
+
+    await mockInvoke("remove_search_suggestion", { query: "seo lead" });
+    expect(await mockInvoke<string[]>("get_search_history")).toEqual([
+      "customer success",
+      "manager remote",
     ]);
   });
 
   it("rejects commands owned by another feature", () => {
-    const state = { savedSearches: [], searchHistory: [] };
+    const state = { savedSearches: [], searchHistory: [], pinnedSearches: [] };
 
     expect(
       handleMockSavedSearchCommand("get_notification_preferences", undefined, state),
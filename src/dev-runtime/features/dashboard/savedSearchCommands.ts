@@ -4,13 +4,20 @@ import {
   getNumericArg,
   getStringArg,
 } from "../../mocks/handlers/commandHelpers";
-import type { MockSavedSearch } from "../../mocks/handlers/types";
+import type {
+  MockJob,
+  MockSavedSearch,
+  MockSearchAgentSettings,
+} from "../../mocks/handlers/types";
 
 export type { MockSavedSearch } from "../../mocks/handlers/types";
 
 interface MockSavedSearchCommandState {
   savedSearches: MockSavedSearch[];
   searchHistory: string[];
+  pinnedSearches: string[];
+  /** Read-only; job titles and companies feed query suggestions */
+  jobs?: readonly Pick<MockJob, "title" | "company" | "hidden">[];
 }
 
 export interface MockSavedSearchCommandResult {
@@ -29,6 +36,11 @@ const SORT_OPTIONS: readonly SortOption[] = [
 ];
 const SCORE_FILTERS: readonly ScoreFilter[] = ["all", "high", "medium", "low"];
 const POSTED_DATE_FILTERS: readonly PostedDateFilter[] = ["all", "24h", "7d", "30d"];
+const AGENT_CHANNELS: readonly MockSearchAgentSettings["channels"][number][] = [
+  "email",
+  "slack",
+  "discord",
+];
 
 export function handleMockSavedSearchCommand(
   command: string,
@@ -51,12 +63,20 @@ export function handleMockSavedSearchCommand(
       return markSavedSearchUsed(args, state);
     case "delete_saved_search":
       return deleteSavedSearch(args, state);
+    case "update_saved_search_agent":
+      return updateSavedSearchAgent(args, state);
     case "import_saved_searches":
       return importSavedSearches(args, state);
     case "add_search_history":
       return addSearchHistory(args, state);
     case "clear_search_history":
       return saved({ ...state, searchHistory: [] }, undefined);
+    case "suggest_queries":
+      return withoutSave(state, suggestQueries(args, state));
+    case "pin_search_suggestion":
+      return pinSearchSuggestion(args, state);
+    case "remove_search_suggestion":
+      return removeSearchSuggestion(args, state);
     default:
       return { handled: false, shouldSave: false, state, value: undefined };
   }
@@ -101,6 +121,19 @@ export function normalizeMockSavedSearch(
       ? source.createdAt
       : now,
     lastUsedAt: nullableString(source.lastUsedAt),
+    agent: normalizeMockSearchAgent(source.agent),
+  };
+}
+
+function normalizeMockSearchAgent(value: unknown): MockSearchAgentSettings {
+  const source = isRecord(value) ? value : {};
+  const channels = Array.isArray(source.channels) ? source.channels : [];
+
+  return {
+    enabled: source.enabled === true,
+    channels: AGENT_CHANNELS.filter((channel) => channels.includes(channel)),
+    frequency: source.frequency === "daily" ? "daily" : "each_scrape",
+    lastCheckedAt: nullableString(source.lastCheckedAt),
   };
 }
 
@@ -108,13 +141,19 @@ function createSavedSearch(
   args: Record<string, unknown> | undefined,
   state: MockSavedSearchCommandState,
 ): MockSavedSearchCommandResult {
+  const now = new Date().toISOString();
+  const normalized = normalizeMockSavedSearch(
+    getArg(args, "search"),
+    getNextMockSavedSearchId(state.savedSearches),
+  );
   const search = {
-    ...normalizeMockSavedSearch(
-      getArg(args, "search"),
-      getNextMockSavedSearchId(state.savedSearches),
-    ),
-    createdAt: new Date().toISOString(),
+    ...normalized,
+    createdAt: now,
     lastUsedAt: null,
+    agent: {
+      ...normalized.agent,
+      lastCheckedAt: normalized.agent.enabled ? now : null,
+    },
   };
   return saved(
     {
@@ -146,6 +185,34 @@ function markSavedSearchUsed(
     : withoutSave(state, false);
 }
 
+function updateSavedSearchAgent(
+  args: Record<string, unknown> | undefined,
+  state: MockSavedSearchCommandState,
+): MockSavedSearchCommandResult {
+  let found = false;
+  const id = getStringArg(args, "id");
+  const savedSearches = state.savedSearches.map((search) => {
+    if (search.id !== id) return search;
+    found = true;
+    // Turning an agent on starts it from now, as in the backend
+    const startedAt = search.agent.enabled && search.agent.lastCheckedAt
+      ? search.agent.lastCheckedAt
+      : new Date().toISOString();
+    const agent = normalizeMockSearchAgent(getArg(args, "settings"));
+    return {
+      ...search,
+      agent: {
+        ...agent,
+        lastCheckedAt: agent.enabled ? startedAt : search.agent.lastCheckedAt,
+      },
+    };
+  });
+
+  return found
+    ? saved({ ...state, savedSearches }, true)
+    : withoutSave(state, false);
+}
+
 function deleteSavedSearch(
   args: Record<string, unknown> | undefined,
   state: MockSavedSearchCommandState,
@@ -200,6 +267,108 @@ function addSearchHistory(
   );
 }
 
+interface MockQuerySuggestion {
+  text: string;
+  source: "history" | "job_title" | "company";
+  pinned: boolean;
+  count: number;
+  score: number;
+}
+
+/** Pinned and recent history first, then matching job titles and companies */
+function suggestQueries(
+  args: Record<string, unknown> | undefined,
+  state: MockSavedSearchCommandState,
+): MockQuerySuggestion[] {
+  const prefix = (getStringArg(args, "prefix") ?? "").trim().toLowerCase();
+  const limit = Math.max(1, Math.min(getNumericArg(args, "limit") ?? 8, 50));
+  const matches = (text: string) =>
+    !prefix || ` ${text.toLowerCase()}`.includes(` ${prefix}`);
+  const pinned = new Set(state.pinnedSearches.map((query) => query.toLowerCase()));
+
+  const history = [
+    ...state.pinnedSearches,
+    ...state.searchHistory.filter((query) => !pinned.has(query.toLowerCase())),
+  ]
+    .filter(matches)
+    .map((text, index): MockQuerySuggestion => ({
+      text,
+      source: "history",
+      pinned: pinned.has(text.toLowerCase()),
+      count: 1,
+      score: 2 - index / 100,
+    }));
+
+  const jobSuggestions = prefix
+    ? (["job_title", "company"] as const).flatMap((source) => {
+        const counts = new Map<string, MockQuerySuggestion>();
+        for (const job of state.jobs ?? []) {
+          if (job.hidden) continue;
+          const text = source === "job_title" ? job.title : job.company;
+          if (!matches(text)) continue;
+          const existing = counts.get(text.toLowerCase());
+          const count = (existing?.count ?? 0) + 1;
+          counts.set(text.toLowerCase(), {
+            text: existing?.text ?? text,
+            source,
+            pinned: false,
+            count,
+            score: 0.5 * Math.log1p(count),
+          });
+        }
+        return [...counts.values()];
+      })
+    : [];
+
+  const seen = new Set<string>();
+  return [...history, ...jobSuggestions.sort((a, b) => b.score - a.score)]
+    .filter((suggestion) => {
+      const key = suggestion.text.toLowerCase();
+      if (seen.has(key)) return false;
+      seen.add(key);
+      return true;
+    })
+    .slice(0, limit);
+}
+
+function pinSearchSuggestion(
+  args: Record<string, unknown> | undefined,
+  state: MockSavedSearchCommandState,
+): MockSavedSearchCommandResult {
+  const query = getStringArg(args, "query")?.trim();
+  if (!query || query.length < 2) return withoutSave(state, false);
+  const pinned = getArg(args, "pinned") === true;
+  const isPinned = state.pinnedSearches.includes(query);
+  if (pinned === isPinned) return withoutSave(state, isPinned);
+
+  return saved(
+    {
+      ...state,
+      pinnedSearches: pinned
+        ? [query, ...state.pinnedSearches]
+        : state.pinnedSearches.filter((entry) => entry !== query),
+    },
+    true,
+  );
+}
+
+function removeSearchSuggestion(
+  args: Record<string, unknown> | undefined,
+  state: MockSavedSearchCommandState,
+): MockSavedSearchCommandResult {
+  const query = getStringArg(args, "query")?.trim();
+  if (!query) return withoutSave(state, undefined);
+
+  return saved(
+    {
+      ...state,
+      searchHistory: state.searchHistory.filter((entry) => entry !== query),
+      pinnedSearches: state.pinnedSearches.filter((entry) => entry !== query),
+    },
+    undefined,
+  );
+}
+
 function saved(
   state: MockSavedSearchCommandState,
   value: unknown,
@@ -2,9 +2,11 @@ import {
   getMockActiveCompanies,
   getMockHottestLocations,
   getMockMarketSnapshot,
+  getMockSeries,
+  getMockSkillGaps,
   getMockTrendingSkills,
 } from "../../../features/market/mockHandlers";
-import { getNumericArg } from "../../mocks/handlers/commandHelpers";
+import { getNumericArg, getStringArg } from "../../mocks/handlers/commandHelpers";
 import type { MockMarketAlert } from "../../mocks/handlers/types";
 
 interface MockMarketState {
@@ -26,7 +28,15 @@ export function handleMockMarketCommand(
   switch (command) {
     case "get_trending_skills":
       return result(getMockTrendingSkills(), state);
-    case "get_active_companies":
+    case "get_personal_skill_gaps":
+      return result(getMockSkillGaps(), state);
+    case "get_skill_mention_series":
+      return result(getMockSeries(args, 420, 35), state);
+    case "get_median_salary_series":
+      return result(getMockSeries(args, 64000, 500), state);
+    case "get_company_posting_series":
+      return result(getMockSeries(args, 120, 12), state);
+    case "get_most_active_companies":
       return result(getMockActiveCompanies(), state);
     case "get_hottest_locations":
       return result(getMockHottestLocations(), state);
@@ -59,6 +69,22 @@ export function handleMockMarketCommand(
         },
         true,
       );
+    case "dismiss_market_alert":
+      return result(
+        true,
+        {
+          marketAlerts: state.marketAlerts.filter(
+            (alert) => alert.id !== getNumericArg(args, "id"),
+          ),
+        },
+        true,
+      );
+    // The browser runtime has no save dialog; report the file name it would use.
+    case "export_market_report": {
+      const extension = getStringArg(args, "format") === "pdf" ? "pdf" : "md";
+      const today = new Date().toISOString().slice(0, 10);
+      return result(`jobsentinel-market-report-${today}.${extension}`, state);
+    }
     default:
       return { handled: false, value: undefined, state, shouldSave: false };
   }
@@ -12,6 +12,24 @@ export interface MockOnboardingCommandResult {
   value: unknown;
 }
 
+const MOCK_SETUP_PRESETS = [
+  {
+    id: "customer-support",
+    name: "Customer Support",
+    description: "Support specialists, customer success, and help desk roles",
+    titles: ["Customer Support Specialist", "Customer Success Manager"],
+    keywords_boost: ["Zendesk", "Customer Service"],
+    keywords_exclude: ["commission only", "unpaid"],
+    salary_floor_usd: 40000,
+    allow_remote: true,
+    allow_hybrid: true,
+    allow_onsite: true,
+    remoteok_tags: ["support"],
+    weworkremotely_category: "remote-customer-support-jobs",
+    hn_hiring: false,
+  },
+];
+
 export function handleMockOnboardingCommand(
   command: string,
   args: Record<string, unknown> | undefined,
@@ -20,6 +38,8 @@ export function handleMockOnboardingCommand(
   switch (command) {
     case "is_first_run":
       return withoutSave(state, false);
+    case "get_config_recovery_notice":
+      return withoutSave(state, null);
     case "complete_setup": {
       const setupConfig = getArg(args, "config");
       if (!setupConfig || typeof setupConfig !== "object") {
@@ -34,6 +54,35 @@ export function handleMockOnboardingCommand(
         value: undefined,
       };
     }
+    case "get_setup_presets":
+      return withoutSave(state, MOCK_SETUP_PRESETS);
+    case "apply_setup_preset": {
+      const preset = MOCK_SETUP_PRESETS.find(
+        (candidate) => candidate.id === getArg(args, "presetId"),
+      );
+      const draft = getArg(args, "config");
+      if (!preset || !draft || typeof draft !== "object") {
+        throw new Error("That setup template is not available.");
+      }
+      return withoutSave(state, {
+        ...draft,
+        title_allowlist: preset.titles,
+        keywords_boost: preset.keywords_boost,
+        keywords_exclude: preset.keywords_exclude,
+        salary_floor_usd: preset.salary_floor_usd,
+      });
+    }
+    case "check_setup_source":
+      return withoutSave(state, {
+        source_id: getArg(args, "sourceId"),
+        jobs_found: 3,
+        sample_titles: ["Support Specialist", "Care Coordinator", "Data Analyst"],
+        errors: [],
+      });
+    case "import_setup_folder":
+      throw new Error(
+        "Folder has no JobSentinel settings or database. Choose the folder that holds config.json or jobs.db.",
+      );
     default:
       return { handled: false, shouldSave: false, state, value: undefined };
   }
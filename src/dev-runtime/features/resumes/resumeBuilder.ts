@@ -322,7 +322,8 @@ export function renderMockResumeHtml(value: unknown): string {
 
 export function exportMockResumeText(value: unknown): string {
   const draft = normalizeResumeDraft(value);
-  return `${draft.contact.name}\n${draft.contact.email}\n\n${draft.summary}`;
+  const contact = [draft.contact.name, draft.contact.email].filter(Boolean).join("\n");
+  return `${contact}\n\n${draft.summary}`;
 }
 
 function isBuilderProficiency(value: unknown): value is MockBuilderSkill["proficiency"] {
@@ -21,6 +21,7 @@ import {
 import { toMockResumeTextPreview } from "./resumeSummaryViews";
 import type {
   MockBuilderSkill,
+  MockJob,
   MockMatchResult,
   MockResumeData,
   MockResumeDraft,
@@ -133,6 +134,11 @@ export function deleteResume(
       userSkills: state.userSkills.filter(
         (skill) => skill.resume_id !== resumeId,
       ),
+      skillEvidence: state.skillEvidence.filter((item) =>
+        state.userSkills.some(
+          (skill) => skill.id === item.skill_id && skill.resume_id !== resumeId,
+        ),
+      ),
       recentMatches: state.recentMatches.filter(
         (match) => match.resume_id !== resumeId,
       ),
@@ -209,25 +215,17 @@ export function updateUserSkill(
   );
 }
 
-export function matchResumeToJob(
-  args: Record<string, unknown> | undefined,
-  state: MockResumeCommandState,
-): MockResumeCommandResult {
-  const resumeId = getResumeIdArg(args);
-  const jobHash = getStringArg(args, "jobHash") ?? getStringArg(args, "job_hash");
-  const job = state.jobs.find((item) => item.hash === jobHash);
-  if (typeof resumeId !== "number" || !jobHash || !job) {
-    return withoutSave(state, undefined);
-  }
-
-  const skills = state.userSkills
-    .filter((skill) => skill.resume_id === resumeId)
-    .map((skill) => skill.skill_name);
+function buildMockMatch(
+  id: number,
+  resumeId: number,
+  job: MockJob,
+  skills: string[],
+): MockMatchResult {
   const matchScore = toScoreFraction(job.score);
-  const match: MockMatchResult = {
-    id: getNextId(state.recentMatches),
+  return {
+    id,
     resume_id: resumeId,
-    job_hash: jobHash,
+    job_hash: job.hash,
     job_title: job.title,
     company: job.company,
     overall_match_score: matchScore,
@@ -239,6 +237,34 @@ export function matchResumeToJob(
     gap_analysis: "Matching: Existing skills align\nMissing: Add one role-specific example",
     created_at: new Date().toISOString(),
   };
+}
+
+function getResumeSkillNames(
+  resumeId: number,
+  state: MockResumeCommandState,
+): string[] {
+  return state.userSkills
+    .filter((skill) => skill.resume_id === resumeId)
+    .map((skill) => skill.skill_name);
+}
+
+export function matchResumeToJob(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const resumeId = getResumeIdArg(args);
+  const jobHash = getStringArg(args, "jobHash") ?? getStringArg(args, "job_hash");
+  const job = state.jobs.find((item) => item.hash === jobHash);
+  if (typeof resumeId !== "number" || !jobHash || !job) {
+    return withoutSave(state, undefined);
+  }
+
+  const match = buildMockMatch(
+    getNextId(state.recentMatches),
+    resumeId,
+    job,
+    getResumeSkillNames(resumeId, state),
+  );
 
   return withSave(
     {
@@ -252,6 +278,59 @@ export function matchResumeToJob(
   );
 }
 
+export function matchResumeToAllJobs(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const resumeId = getResumeIdArg(args);
+  if (
+    typeof resumeId !== "number" ||
+    !state.resumes.some((resume) => resume.id === resumeId)
+  ) {
+    throw new Error("Resume not found");
+  }
+
+  const minJobScore = getNumericArg(args, "minJobScore") ?? 0;
+  const jobs = state.jobs.filter(
+    (job) => !job.hidden && toScoreFraction(job.score) >= minJobScore,
+  );
+  const skills = getResumeSkillNames(resumeId, state);
+  const matchedHashes = new Set(jobs.map((job) => job.hash));
+  const keptMatches = state.recentMatches.filter(
+    (item) => item.resume_id !== resumeId || !matchedHashes.has(item.job_hash),
+  );
+  let nextId = getNextId(state.recentMatches);
+  const matches = jobs.map((job) => buildMockMatch(nextId++, resumeId, job, skills));
+
+  return withSave(
+    { ...state, recentMatches: [...matches, ...keptMatches] },
+    { jobs_matched: jobs.length, jobs_total: jobs.length },
+  );
+}
+
+export function getBestResumeMatches(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const resumeId = getResumeIdArg(args);
+  const limit = getNumericArg(args, "limit") ?? 25;
+  const activeHashes = new Set(
+    state.jobs.filter((job) => !job.hidden).map((job) => job.hash),
+  );
+  return withoutSave(
+    state,
+    state.recentMatches
+      .filter(
+        (match) =>
+          match.resume_id === resumeId && activeHashes.has(match.job_hash),
+      )
+      .sort(
+        (a, b) => b.overall_match_score - a.overall_match_score || a.id - b.id,
+      )
+      .slice(0, limit),
+  );
+}
+
 export function createMockResumeDraft(
   state: MockResumeCommandState,
 ): MockResumeCommandResult {
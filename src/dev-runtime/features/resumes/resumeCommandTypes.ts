@@ -3,6 +3,7 @@ import type {
   MockMatchResult,
   MockResumeData,
   MockResumeDraft,
+  MockSkillEvidence,
   MockUserSkill,
 } from "../../mocks/handlers/types";
 
@@ -10,6 +11,7 @@ export interface MockResumeCommandState {
   jobs: MockJob[];
   resumes: MockResumeData[];
   userSkills: MockUserSkill[];
+  skillEvidence: MockSkillEvidence[];
   resumeDrafts: MockResumeDraft[];
   recentMatches: MockMatchResult[];
 }
@@ -9,9 +9,11 @@ import {
   deleteResumeDraft,
   deleteResumeEducation,
   deleteResumeExperience,
+  getBestResumeMatches,
   getMockActiveResume,
   getResumeDraft,
   getResumeTextPreview,
+  matchResumeToAllJobs,
   matchResumeToJob,
   setActiveResume,
   setResumeSkills,
@@ -47,6 +49,13 @@ import {
 } from "../../mocks/handlers/commandHelpers";
 import { extractMockAtsKeywords } from "./resumeKeywordMatching";
 import { toMockResumeSummary } from "./resumeSummaryViews";
+import {
+  handleMockResumeRedactionCommand,
+  redactMockResumeForExport,
+} from "./resumeRedactionCommands";
+import { handleMockResumeRoutingCommand } from "./resumeRoutingCommands";
+import { handleMockResumeVersionCommand } from "./resumeVersionCommands";
+import { handleMockSkillEvidenceCommand } from "./skillEvidenceCommands";
 
 export function handleMockResumeCommand(
   command: string,
@@ -113,6 +122,9 @@ export function handleMockResumeCommand(
         {
           ...state,
           userSkills: state.userSkills.filter((skill) => skill.id !== skillId),
+          skillEvidence: state.skillEvidence.filter(
+            (item) => item.skill_id !== skillId,
+          ),
         },
         undefined,
       );
@@ -132,6 +144,12 @@ export function handleMockResumeCommand(
     case "match_resume_to_job":
       return matchResumeToJob(args, state);
 
+    case "match_resume_to_all_jobs":
+      return matchResumeToAllJobs(args, state);
+
+    case "get_best_resume_matches":
+      return getBestResumeMatches(args, state);
+
     case "create_resume_draft":
       return createMockResumeDraft(state);
 
@@ -210,17 +228,22 @@ export function handleMockResumeCommand(
       return withoutSave(state, [80, 75, 3, 4, 20, 0, 0, 0]);
 
     case "export_resume_html":
-      return withoutSave(state, renderMockResumeHtml(getArg(args, "resume")));
+      return withoutSave(state, renderMockResumeHtml(redactMockResumeForExport(args)));
 
     case "export_resume_text":
-      return withoutSave(state, exportMockResumeText(getArg(args, "resume")));
+      return withoutSave(state, exportMockResumeText(redactMockResumeForExport(args)));
 
     default:
-      return {
-        handled: false,
-        shouldSave: false,
-        state,
-        value: undefined,
-      };
+      return (
+        handleMockSkillEvidenceCommand(command, args, state) ??
+        handleMockResumeVersionCommand(command, args, state) ??
+        handleMockResumeRoutingCommand(command, args, state) ??
+        handleMockResumeRedactionCommand(command, args, state) ?? {
+          handled: false,
+          shouldSave: false,
+          state,
+          value: undefined,
+        }
+      );
   }
 }
@@ -0,0 +1,42 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke } from "../../mocks/handlers";
+import { setupResumeRuntimeMocks } from "./resumeRuntimeTestSupport";
+
+describe("mock resume redaction commands", () => {
+  beforeEach(setupResumeRuntimeMocks);
+
+  it("leaves contact details out of a shareable export", async () => {
+    const text = await mockInvoke<string>("export_resume_text", {
+      resume: {
+        personal: {
+          name: "Jordan Lee",
+          email: "jordan@example.com",
+          phone: "555-123-4567",
+        },
+        summary: "Support leader",
+      },
+      redaction: { name_replacement: "Candidate" },
+    });
+
+    expect(text).toBe("Candidate\n\nSupport leader");
+  });
+
+  it("masks an uploaded resume's name line", async () => {
+    const resumeId = await mockInvoke<number>("select_and_upload_resume");
+
+    const text = await mockInvoke<string>("export_redacted_resume_text", {
+      resumeId,
+      redaction: { name_replacement: "Candidate" },
+    });
+
+    const [firstLine, ...rest] = text.split("\n");
+    expect(firstLine).toBe("Candidate");
+    expect(rest.join("\n")).toContain("Care coordinator");
+  });
+
+  it("rejects unknown resumes", async () => {
+    await expect(
+      mockInvoke("export_redacted_resume_text", { resumeId: 999 }),
+    ).rejects.toThrow("Resume not found");
+  });
+});
@@ -0,0 +1,115 @@
+import { getArg, getResumeIdArg } from "../../mocks/handlers/commandHelpers";
+import { withoutSave } from "./resumeCommandHandlers";
+import type {
+  MockResumeCommandResult,
+  MockResumeCommandState,
+} from "./resumeCommandTypes";
+
+const REDACTED_TEXT = "[redacted]";
+const EMAIL_PATTERN = /[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}/g;
+const SECTION_HEADINGS = new Set([
+  "summary",
+  "experience",
+  "education",
+  "skills",
+  "projects",
+  "certifications",
+  "references",
+]);
+const PHONE_PATTERN = /(?:\+\d{1,3}[\s.-]?)?(?:\(\d{3}\)|\b\d{3})[\s.-]?\d{3}[\s.-]?\d{4}\b/g;
+
+interface MockRedactionRules {
+  nameReplacement: string | null;
+  removeEmail: boolean;
+  removePhone: boolean;
+  removeAddress: boolean;
+  removeReferences: boolean;
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+/** Rules as the backend reads them; missing fields use the backend defaults. */
+function toMockRedactionRules(value: unknown): MockRedactionRules {
+  const rules = isRecord(value) ? value : {};
+  const flag = (key: string) => (typeof rules[key] === "boolean" ? rules[key] : true);
+  const nameReplacement =
+    typeof rules.name_replacement === "string" ? rules.name_replacement.trim() : "";
+  return {
+    nameReplacement: nameReplacement || null,
+    removeEmail: flag("remove_email"),
+    removePhone: flag("remove_phone"),
+    removeAddress: flag("remove_address"),
+    removeReferences: flag("remove_references"),
+  };
+}
+
+/** The structured resume an export receives, with contact details cleared. */
+export function redactMockResumeForExport(args: Record<string, unknown> | undefined): unknown {
+  const resume = getArg(args, "resume");
+  const redaction = getArg(args, "redaction");
+  if (!isRecord(resume) || redaction === undefined || redaction === null) {
+    return resume;
+  }
+
+  const rules = toMockRedactionRules(redaction);
+  const personal = isRecord(resume.personal) ? resume.personal : {};
+  return {
+    ...resume,
+    personal: {
+      ...personal,
+      name: rules.nameReplacement ?? personal.name,
+      email: rules.removeEmail ? "" : personal.email,
+      phone: rules.removePhone ? null : personal.phone,
+      location: rules.removeAddress ? null : personal.location,
+    },
+  };
+}
+
+function exportRedactedResumeText(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const resumeId = getResumeIdArg(args);
+  const resume = state.resumes.find((item) => item.id === resumeId);
+  if (!resume) {
+    throw new Error("Resume not found");
+  }
+  const text = resume.parsed_text ?? "";
+  if (!text.trim()) {
+    throw new Error("This resume has no readable text to export.");
+  }
+
+  const rules = toMockRedactionRules(getArg(args, "redaction"));
+  const lines: string[] = [];
+  let inReferences = false;
+  text.split("\n").forEach((line, index) => {
+    const heading = line.trim().replace(/:$/, "").toLowerCase();
+    if (SECTION_HEADINGS.has(heading)) {
+      inReferences = rules.removeReferences && heading === "references";
+    }
+    if (inReferences) return;
+    let masked = line;
+    if (index === 0 && rules.nameReplacement) masked = rules.nameReplacement;
+    if (rules.removeEmail) masked = masked.replace(EMAIL_PATTERN, REDACTED_TEXT);
+    if (rules.removePhone) masked = masked.replace(PHONE_PATTERN, REDACTED_TEXT);
+    lines.push(masked);
+  });
+
+  return withoutSave(state, lines.join("\n"));
+}
+
+export function handleMockResumeRedactionCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult | null {
+  switch (command) {
+    case "export_redacted_resume_text":
+      return exportRedactedResumeText(args, state);
+
+    default:
+      return null;
+  }
+}
@@ -0,0 +1,45 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke } from "../../mocks/handlers";
+import { setupResumeRuntimeMocks } from "./resumeRuntimeTestSupport";
+
+type ResumeSuggestion = {
+  recommended_resume_id: number | null;
+  candidates: { resume_id: number; overall_match_score: number; missing_skills: string[] }[];
+};
+
+async function uploadWithSkills(skills: string[]): Promise<number> {
+  const resumeId = await mockInvoke<number>("select_and_upload_resume");
+  for (const skillName of skills) {
+    await mockInvoke("add_user_skill", {
+      resumeId,
+      skill: { skill_name: skillName },
+    });
+  }
+  return resumeId;
+}
+
+describe("mock resume routing commands", () => {
+  beforeEach(setupResumeRuntimeMocks);
+
+  it("recommends the resume that lists more of the job's skills", async () => {
+    const general = await uploadWithSkills(["Google Analytics"]);
+    const seo = await uploadWithSkills(["Google Analytics", "Semrush"]);
+
+    const suggestion = await mockInvoke<ResumeSuggestion>("suggest_resume_for_job", {
+      jobHash: "job-hash-1",
+    });
+
+    expect(suggestion.recommended_resume_id).toBe(seo);
+    const generalFit = suggestion.candidates.find(
+      (candidate) => candidate.resume_id === general,
+    );
+    expect(generalFit?.missing_skills).toEqual(["Semrush"]);
+    expect(suggestion.candidates[0].overall_match_score).toBe(1);
+  });
+
+  it("rejects unknown jobs", async () => {
+    await expect(
+      mockInvoke("suggest_resume_for_job", { jobHash: "missing-job" }),
+    ).rejects.toThrow("Job not found");
+  });
+});
@@ -0,0 +1,83 @@
+import { getStringArg } from "../../mocks/handlers/commandHelpers";
+import { withoutSave } from "./resumeCommandHandlers";
+import type {
+  MockResumeCommandResult,
+  MockResumeCommandState,
+} from "./resumeCommandTypes";
+
+interface MockResumeFit {
+  resume_id: number;
+  name: string;
+  is_active: boolean;
+  overall_match_score: number;
+  skills_match_score: number | null;
+  matching_skills: string[];
+  missing_skills: string[];
+}
+
+/** Each resume scores the share of the posting's resume skills it lists. */
+function suggestResumeForJob(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const jobHash = getStringArg(args, "jobHash") ?? getStringArg(args, "job_hash");
+  const job = state.jobs.find((item) => item.hash === jobHash);
+  if (!jobHash || !job) {
+    throw new Error("Job not found");
+  }
+
+  const posting = `${job.title} ${job.description}`.toLowerCase();
+  const skillsFor = (resumeId: number) =>
+    state.userSkills
+      .filter((skill) => skill.resume_id === resumeId)
+      .map((skill) => skill.skill_name);
+  const jobSkills = [
+    ...new Set(
+      state.userSkills
+        .map((skill) => skill.skill_name)
+        .filter((skill) => posting.includes(skill.toLowerCase())),
+    ),
+  ];
+
+  const candidates: MockResumeFit[] = state.resumes
+    .map((resume) => {
+      const skills = skillsFor(resume.id).map((skill) => skill.toLowerCase());
+      const matching = jobSkills.filter((skill) => skills.includes(skill.toLowerCase()));
+      const score = jobSkills.length > 0 ? matching.length / jobSkills.length : 0;
+      return {
+        resume_id: resume.id,
+        name: resume.name,
+        is_active: resume.is_active,
+        overall_match_score: score,
+        skills_match_score: score,
+        matching_skills: matching,
+        missing_skills: jobSkills.filter((skill) => !matching.includes(skill)),
+      };
+    })
+    .sort(
+      (a, b) =>
+        b.overall_match_score - a.overall_match_score ||
+        Number(b.is_active) - Number(a.is_active) ||
+        a.resume_id - b.resume_id,
+    );
+
+  return withoutSave(state, {
+    job_hash: jobHash,
+    recommended_resume_id: candidates[0]?.resume_id ?? null,
+    candidates,
+  });
+}
+
+export function handleMockResumeRoutingCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult | null {
+  switch (command) {
+    case "suggest_resume_for_job":
+      return suggestResumeForJob(args, state);
+
+    default:
+      return null;
+  }
+}
@@ -46,6 +46,29 @@ describe("mock resume runtime output commands", () => {
     expect(match.experience_match_score).toBeLessThanOrEqual(1);
   });
 
+  it("matches every visible job and lists the best fits first", async () => {
+    const jobs = await mockInvoke<MockJobSummary[]>("get_jobs", {});
+    const resumeId = await mockInvoke<number>("select_and_upload_resume");
+    const progress = await mockInvoke<{ jobs_matched: number; jobs_total: number }>(
+      "match_resume_to_all_jobs",
+      { resumeId, minJobScore: 0 },
+    );
+    const best = await mockInvoke<Array<MockMatchResult & { job_hash: string }>>(
+      "get_best_resume_matches",
+      { resumeId, limit: 3 },
+    );
+
+    expect(progress.jobs_matched).toBeGreaterThan(0);
+    expect(progress.jobs_matched).toBe(progress.jobs_total);
+    expect(best).toHaveLength(Math.min(3, progress.jobs_matched));
+    expect(best[0].overall_match_score).toBe(
+      Math.max(...jobs.map((job) => job.score)),
+    );
+    expect(best[0].overall_match_score).toBeGreaterThanOrEqual(
+      best[best.length - 1].overall_match_score,
+    );
+  });
+
   it("returns a readable preview without path details", async () => {
     const resumeId = await mockInvoke<number>("select_and_upload_resume");
     const summary = await mockInvoke<Record<string, unknown>>("get_active_resume");
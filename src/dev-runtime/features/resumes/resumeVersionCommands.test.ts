@@ -0,0 +1,64 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { mockInvoke } from "../../mocks/handlers";
+import { setupResumeRuntimeMocks } from "./resumeRuntimeTestSupport";
+
+type ResumeVersion = {
+  id: number;
+  source: string;
+  version: number;
+};
+
+type ResumeVersionComparison = {
+  added_skills: string[];
+  removed_skills: string[];
+  section_changes: unknown[];
+  jobs_compared: number;
+  average_match_change: number | null;
+};
+
+async function uploadWithSkills(skills: string[]): Promise<number> {
+  const resumeId = await mockInvoke<number>("select_and_upload_resume");
+  for (const skillName of skills) {
+    await mockInvoke("add_user_skill", {
+      resumeId,
+      skill: { skill_name: skillName },
+    });
+  }
+  return resumeId;
+}
+
+describe("mock resume version commands", () => {
+  beforeEach(setupResumeRuntimeMocks);
+
+  it("lists each upload of a resume name as a new version", async () => {
+    await uploadWithSkills([]);
+    await uploadWithSkills([]);
+
+    const versions = await mockInvoke<ResumeVersion[]>("list_resume_versions");
+
+    expect(versions.map((version) => version.version)).toEqual([2, 1]);
+    expect(versions.every((version) => version.source === "upload")).toBe(true);
+  });
+
+  it("compares skills and average match across recent jobs", async () => {
+    const first = await uploadWithSkills(["Google Analytics"]);
+    const second = await uploadWithSkills(["Google Analytics", "Semrush"]);
+
+    const comparison = await mockInvoke<ResumeVersionComparison>(
+      "compare_resume_versions",
+      { fromVersionId: first, toVersionId: second },
+    );
+
+    expect(comparison.added_skills).toEqual(["Semrush"]);
+    expect(comparison.removed_skills).toEqual([]);
+    expect(comparison.section_changes).toEqual([]);
+    expect(comparison.jobs_compared).toBeGreaterThan(0);
+    expect(comparison.average_match_change).toBeGreaterThan(0);
+  });
+
+  it("rejects unknown versions", async () => {
+    await expect(
+      mockInvoke("compare_resume_versions", { fromVersionId: 1, toVersionId: 99 }),
+    ).rejects.toThrow("Resume version not found");
+  });
+});
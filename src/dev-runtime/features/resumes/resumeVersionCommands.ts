@@ -0,0 +1,213 @@
+import { getNumericArg } from "../../mocks/handlers/commandHelpers";
+import type { MockResumeData } from "../../mocks/handlers/types";
+import { withoutSave } from "./resumeCommandHandlers";
+import type {
+  MockResumeCommandResult,
+  MockResumeCommandState,
+} from "./resumeCommandTypes";
+
+const RECENT_JOBS_COMPARED = 50;
+const SECTION_HEADINGS = new Set([
+  "summary",
+  "profile",
+  "skills",
+  "technical skills",
+  "experience",
+  "work experience",
+  "professional experience",
+  "education",
+  "projects",
+  "certifications",
+]);
+
+interface MockResumeSection {
+  heading: string;
+  lines: string[];
+}
+
+interface MockResumeVersion {
+  id: number;
+  source: "upload" | "builder";
+  source_key: string;
+  version: number;
+  name: string;
+  resume_id: number | null;
+  skills: string[];
+  sections: MockResumeSection[];
+  created_at: string;
+}
+
+interface MockResumeSectionChange {
+  heading: string;
+  change: "added" | "removed" | "changed";
+  added_lines: string[];
+  removed_lines: string[];
+}
+
+function splitSections(text: string): MockResumeSection[] {
+  const sections: MockResumeSection[] = [];
+  let current: MockResumeSection | undefined;
+  for (const line of text.split("\n").map((item) => item.trim())) {
+    if (!line) {
+      continue;
+    }
+    const heading = line.replace(/:$/, "").trim();
+    if (SECTION_HEADINGS.has(heading.toLowerCase())) {
+      current = { heading, lines: [] };
+      sections.push(current);
+      continue;
+    }
+    if (!current) {
+      current = { heading: "Contact", lines: [] };
+      sections.push(current);
+    }
+    current.lines.push(line);
+  }
+  return sections;
+}
+
+/** Uploads are the mock's only version source; each upload is a version of its name. */
+function buildVersions(state: MockResumeCommandState): MockResumeVersion[] {
+  const byKey = new Map<string, MockResumeData[]>();
+  for (const resume of [...state.resumes].sort((a, b) => a.id - b.id)) {
+    const key = resume.name.trim().toLowerCase();
+    byKey.set(key, [...(byKey.get(key) ?? []), resume]);
+  }
+  return [...byKey.entries()].flatMap(([key, resumes]) =>
+    resumes
+      .map((resume, index) => ({
+        id: resume.id,
+        source: "upload" as const,
+        source_key: key,
+        version: index + 1,
+        name: resume.name,
+        resume_id: resume.id,
+        skills: state.userSkills
+          .filter((skill) => skill.resume_id === resume.id)
+          .map((skill) => skill.skill_name),
+        sections: splitSections(resume.parsed_text ?? ""),
+        created_at: resume.created_at,
+      }))
+      .reverse(),
+  );
+}
+
+function missingFrom(values: string[], other: string[]): string[] {
+  const lowered = new Set(other.map((value) => value.toLowerCase()));
+  return values.filter((value) => !lowered.has(value.toLowerCase()));
+}
+
+function diffSections(
+  older: MockResumeSection[],
+  newer: MockResumeSection[],
+): MockResumeSectionChange[] {
+  const find = (sections: MockResumeSection[], heading: string) =>
+    sections.find(
+      (section) => section.heading.toLowerCase() === heading.toLowerCase(),
+    );
+  const changes: MockResumeSectionChange[] = [];
+  for (const section of newer) {
+    const previous = find(older, section.heading);
+    if (!previous) {
+      changes.push({
+        heading: section.heading,
+        change: "added",
+        added_lines: section.lines,
+        removed_lines: [],
+      });
+      continue;
+    }
+    const added = section.lines.filter((line) => !previous.lines.includes(line));
+    const removed = previous.lines.filter((line) => !section.lines.includes(line));
+    if (added.length > 0 || removed.length > 0) {
+      changes.push({
+        heading: section.heading,
+        change: "changed",
+        added_lines: added,
+        removed_lines: removed,
+      });
+    }
+  }
+  for (const section of older) {
+    if (!find(newer, section.heading)) {
+      changes.push({
+        heading: section.heading,
+        change: "removed",
+        added_lines: [],
+        removed_lines: section.lines,
+      });
+    }
+  }
+  return changes;
+}
+
+function compareVersions(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const versions = buildVersions(state);
+  const from = versions.find(
+    (version) => version.id === getNumericArg(args, "fromVersionId"),
+  );
+  const to = versions.find(
+    (version) => version.id === getNumericArg(args, "toVersionId"),
+  );
+  if (!from || !to) {
+    throw new Error("Resume version not found");
+  }
+
+  // Job skills are the compared skills named in each job's posting
+  const candidateSkills = [...new Set([...from.skills, ...to.skills])];
+  const coverage = (skills: string[], jobSkills: string[]) =>
+    jobSkills.length - missingFrom(jobSkills, skills).length;
+  let jobsCompared = 0;
+  let fromTotal = 0;
+  let toTotal = 0;
+  for (const job of state.jobs
+    .filter((item) => !item.hidden)
+    .sort((a, b) => b.created_at.localeCompare(a.created_at))
+    .slice(0, RECENT_JOBS_COMPARED)) {
+    const posting = `${job.title} ${job.description}`.toLowerCase();
+    const jobSkills = candidateSkills.filter((skill) =>
+      posting.includes(skill.toLowerCase()),
+    );
+    if (jobSkills.length === 0) {
+      continue;
+    }
+    jobsCompared += 1;
+    fromTotal += coverage(from.skills, jobSkills) / jobSkills.length;
+    toTotal += coverage(to.skills, jobSkills) / jobSkills.length;
+  }
+  const fromAverage = jobsCompared > 0 ? fromTotal / jobsCompared : null;
+  const toAverage = jobsCompared > 0 ? toTotal / jobsCompared : null;
+
+  return withoutSave(state, {
+    from,
+    to,
+    added_skills: missingFrom(to.skills, from.skills),
+    removed_skills: missingFrom(from.skills, to.skills),
+    section_changes: diffSections(from.sections, to.sections),
+    jobs_compared: jobsCompared,
+    from_average_match: fromAverage,
+    to_average_match: toAverage,
+    average_match_change:
+      fromAverage !== null && toAverage !== null ? toAverage - fromAverage : null,
+  });
+}
+
+export function handleMockResumeVersionCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult | null {
+  switch (command) {
+    case "list_resume_versions":
+      return withoutSave(state, buildVersions(state));
+
+    case "compare_resume_versions":
+      return compareVersions(args, state);
+
+    default:
+      return null;
+  }
+}
@@ -0,0 +1,130 @@
+import {
+  getArg,
+  getNextId,
+  getNumericArg,
+  getResumeIdArg,
+  getSkillIdArg,
+  trimmedStringOrNull,
+} from "../../mocks/handlers/commandHelpers";
+import type { MockSkillEvidence } from "../../mocks/handlers/types";
+import { withSave, withoutSave } from "./resumeCommandHandlers";
+import type {
+  MockResumeCommandResult,
+  MockResumeCommandState,
+} from "./resumeCommandTypes";
+
+const EVIDENCE_KINDS: MockSkillEvidence["kind"][] = [
+  "project",
+  "repository",
+  "certification",
+  "other",
+];
+const DAY_MS = 24 * 60 * 60 * 1_000;
+
+function evidenceForResume(
+  state: MockResumeCommandState,
+  resumeId: number | undefined,
+): MockSkillEvidence[] {
+  const skillIds = new Set(
+    state.userSkills
+      .filter((skill) => skill.resume_id === resumeId)
+      .map((skill) => skill.id),
+  );
+  return state.skillEvidence.filter((item) => skillIds.has(item.skill_id));
+}
+
+function dateOrNull(value: unknown): string | null {
+  const text = trimmedStringOrNull(value);
+  if (text && !/^\d{4}-\d{2}-\d{2}$/.test(text)) {
+    throw new Error("Dates must use YYYY-MM-DD");
+  }
+  return text;
+}
+
+function addSkillEvidence(
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult {
+  const skillId = getSkillIdArg(args);
+  const skill = state.userSkills.find((candidate) => candidate.id === skillId);
+  if (!skill) {
+    throw new Error("Skill not found");
+  }
+  const input = (getArg(args, "evidence") ?? {}) as Record<string, unknown>;
+  const title = trimmedStringOrNull(input.title);
+  if (!title) {
+    throw new Error("Evidence title is required");
+  }
+  const kind =
+    EVIDENCE_KINDS.find((candidate) => candidate === input.kind) ?? "other";
+
+  const evidence: MockSkillEvidence = {
+    id: getNextId(state.skillEvidence),
+    skill_id: skill.id,
+    skill_name: skill.skill_name,
+    kind,
+    title,
+    url: trimmedStringOrNull(input.url),
+    issued_on: dateOrNull(input.issued_on),
+    expires_on: dateOrNull(input.expires_on),
+    created_at: new Date().toISOString(),
+  };
+
+  return withSave(
+    { ...state, skillEvidence: [...state.skillEvidence, evidence] },
+    evidence.id,
+  );
+}
+
+export function handleMockSkillEvidenceCommand(
+  command: string,
+  args: Record<string, unknown> | undefined,
+  state: MockResumeCommandState,
+): MockResumeCommandResult | null {
+  switch (command) {
+    case "add_skill_evidence":
+      return addSkillEvidence(args, state);
+
+    case "get_skill_evidence":
+      return withoutSave(state, evidenceForResume(state, getResumeIdArg(args)));
+
+    case "delete_skill_evidence": {
+      const evidenceId = getNumericArg(args, "evidenceId");
+      if (!state.skillEvidence.some((item) => item.id === evidenceId)) {
+        throw new Error("Skill evidence not found");
+      }
+      return withSave(
+        {
+          ...state,
+          skillEvidence: state.skillEvidence.filter(
+            (item) => item.id !== evidenceId,
+          ),
+        },
+        undefined,
+      );
+    }
+
+    case "get_expiring_skill_evidence": {
+      const withinDays = getNumericArg(args, "withinDays") ?? 60;
+      const cutoff = new Date(Date.now() + withinDays * DAY_MS)
+        .toISOString()
+        .slice(0, 10);
+      return withoutSave(
+        state,
+        evidenceForResume(state, getResumeIdArg(args))
+          .filter(
+            (item) =>
+              item.kind === "certification" &&
+              item.expires_on !== null &&
+              item.expires_on <= cutoff,
+          )
+          .sort((a, b) =>
+            (a.expires_on ?? "").localeCompare(b.expires_on ?? ""),
+          ),
+      );
+    }
+
+    default:
+      return null;
+  }
+}
@@ -2,6 +2,8 @@ import {
   hasConfiguredJobsWithGpt,
   hasEnabledMockScraperSource,
 } from "./sources/scraperHealth";
+import { getMockGuestModeStatus } from "./guestModeCommands";
+import { getMockVacationModeStatus } from "./vacationModeCommands";
 import {
   getArg,
   getDefaultGhostConfig,
@@ -61,6 +63,34 @@ export function handleMockSettingsCommand(
     case "get_config":
       return withoutSave(state, state.config);
 
+    case "get_guest_mode_status":
+    case "unlock_guest_mode":
+    case "enable_guest_mode":
+      return withoutSave(state, getMockGuestModeStatus(command, args));
+
+    case "set_vacation_mode":
+      return withoutSave(state, getMockVacationModeStatus(args));
+
+    case "get_notification_history":
+      return withoutSave(state, []);
+
+    case "preview_notification_route":
+      return withoutSave(state, {
+        would_notify: false,
+        skipped_because: "below_alert_threshold",
+        reason: "The match score is below the alert threshold",
+        channels: [],
+      });
+
+    case "get_notification_message_templates":
+      return withoutSave(state, { slack: null, discord: null, email: null });
+
+    case "save_notification_message_template":
+      return withoutSave(state, null);
+
+    case "preview_notification_message":
+      return withoutSave(state, String(getArg(args, "template") ?? ""));
+
     case "get_dashboard_preferences":
       return withoutSave(state, getMockDashboardPreferences(state.config));
 
@@ -83,6 +113,30 @@ export function handleMockSettingsCommand(
         value: { enabled: Boolean(getArg(args, "enabled")) },
       };
 
+    case "block_company":
+    case "unblock_company": {
+      const company = getStringArg(args, "company")?.trim();
+      if (!company) throw new Error("Company name is required");
+      const matches = (blocked: string) =>
+        blocked.trim().toLowerCase() === company.toLowerCase();
+      const current = state.config.blocked_companies;
+      const blockedCompanies =
+        command === "block_company"
+          ? current.some(matches)
+            ? current
+            : [...current, company]
+          : current.filter((blocked) => !matches(blocked));
+      return {
+        handled: true,
+        shouldSave: true,
+        state: {
+          ...state,
+          config: { ...state.config, blocked_companies: blockedCompanies },
+        },
+        value: blockedCompanies,
+      };
+    }
+
     case "save_config":
       return {
         handled: true,
@@ -189,10 +243,40 @@ export function handleMockSettingsCommand(
       };
 
     case "validate_slack_webhook":
+    case "validate_discord_webhook":
+    case "validate_teams_webhook":
+    case "validate_custom_webhook":
+    case "validate_telegram_bot":
+    case "validate_ntfy_topic":
+    case "validate_pushover":
     case "test_email_notification":
     case "copy_bookmarklet_code":
       return withoutSave(state, undefined);
 
+    case "send_test_email_digest":
+      return withoutSave(state, 3);
+
+    case "generate_report_now": {
+      const now = new Date();
+      const weekAgo = new Date(now.getTime() - 7 * 24 * 60 * 60 * 1000);
+      return withoutSave(state, {
+        report: {
+          id: 1,
+          period_start: weekAgo.toISOString(),
+          period_end: now.toISOString(),
+          generated_at: now.toISOString(),
+          scheduled: false,
+          markdown:
+            "# JobSentinel weekly report\n\n## New jobs\n3 new jobs found this week.\n",
+          html: "<h1>Your weekly report</h1><p>3 new jobs found this week.</p>",
+        },
+        delivery: { delivered: [], failed: [] },
+      });
+    }
+
+    case "get_weekly_reports":
+      return withoutSave(state, []);
+
     case "get_bookmarklet_config":
       return withoutSave(state, state.bookmarkletConfig);
 
@@ -0,0 +1,22 @@
+import { getStringArg } from "../../mocks/handlers/commandHelpers";
+
+export interface MockGuestModeStatus {
+  active: boolean;
+  pinRequired: boolean;
+  since: string | null;
+}
+
+export function getMockGuestModeStatus(
+  command: string,
+  args: Record<string, unknown> | undefined,
+): MockGuestModeStatus {
+  if (command !== "enable_guest_mode") {
+    return { active: false, pinRequired: false, since: null };
+  }
+
+  return {
+    active: true,
+    pinRequired: Boolean(getStringArg(args, "pin")?.trim()),
+    since: new Date().toISOString(),
+  };
+}
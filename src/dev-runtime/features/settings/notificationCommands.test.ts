@@ -25,6 +25,14 @@ const notificationPreferencesInput: NotificationPreferences = {
     includedCompanies: ["CareBridge Health"],
     excludedCompanies: ["Legacy Staffing"],
   },
+  marketAlerts: {
+    skillSurge: { enabled: true, minSeverity: "info" },
+    salarySpike: { enabled: true, minSeverity: "warning" },
+    hiringFreeze: { enabled: false, minSeverity: "info" },
+    hiringSpree: { enabled: true, minSeverity: "critical" },
+    locationBoom: { enabled: true, minSeverity: "info" },
+    roleObsolete: { enabled: false, minSeverity: "info" },
+  },
 };
 
 describe("Settings notification-preference mock commands", () => {
@@ -1,7 +1,11 @@
 import { getArg } from "../../mocks/handlers/commandHelpers";
-import type {
-  NotificationPreferences,
-  SourceNotificationConfig,
+import {
+  DEFAULT_MARKET_ALERT_PREFERENCES,
+  MARKET_ALERT_TYPES,
+  type MarketAlertNotificationConfig,
+  type MarketAlertPreferences,
+  type NotificationPreferences,
+  type SourceNotificationConfig,
 } from "../../../shared/notificationPreferences";
 
 interface MockNotificationCommandState {
@@ -98,9 +102,33 @@ export function normalizeMockNotificationPreferences(
       includedCompanies: stringArray(advancedFilters.includedCompanies),
       excludedCompanies: stringArray(advancedFilters.excludedCompanies),
     },
+    marketAlerts: normalizeMarketAlertPreferences(source.marketAlerts),
   };
 }
 
+function normalizeMarketAlertPreferences(value: unknown): MarketAlertPreferences {
+  const source = isRecord(value) ? value : {};
+  const normalized = { ...DEFAULT_MARKET_ALERT_PREFERENCES };
+  for (const alertType of MARKET_ALERT_TYPES) {
+    const entry = source[alertType];
+    const config = isRecord(entry) ? entry : {};
+    const fallback = DEFAULT_MARKET_ALERT_PREFERENCES[alertType];
+    normalized[alertType] = {
+      enabled: booleanValue(config.enabled, fallback.enabled),
+      minSeverity: isMarketAlertSeverity(config.minSeverity)
+        ? config.minSeverity
+        : fallback.minSeverity,
+    };
+  }
+  return normalized;
+}
+
+function isMarketAlertSeverity(
+  value: unknown,
+): value is MarketAlertNotificationConfig["minSeverity"] {
+  return value === "info" || value === "warning" || value === "critical";
+}
+
 function getDefaultNotificationPreferences(): NotificationPreferences {
   return {
     linkedin: { enabled: false, minScoreThreshold: 70, soundEnabled: false },
@@ -122,6 +150,7 @@ function getDefaultNotificationPreferences(): NotificationPreferences {
       includedCompanies: [],
       excludedCompanies: [],
     },
+    marketAlerts: DEFAULT_MARKET_ALERT_PREFERENCES,
   };
 }
 
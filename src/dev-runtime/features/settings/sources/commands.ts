@@ -45,6 +45,15 @@ export function handleMockSourceHealthCommand(
     }
     case "get_scraper_runs":
       return result(getMockScraperRuns(args), state);
+    case "get_scrape_history":
+      return result([], state);
+    case "get_scrape_run_details":
+      return result(null, state);
+    case "discover_company_board":
+      return result(
+        { company_name: String(args?.companyName ?? "").trim(), boards: [] },
+        state,
+      );
     case "get_latest_source_request":
       return result(getMockLatestSourceRequest(args, state.config), state);
     case "run_scraper_smoke_test":
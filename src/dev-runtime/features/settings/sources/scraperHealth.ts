@@ -167,6 +167,20 @@ const MOCK_SCRAPERS: readonly MockScraperDefinition[] = [
     scraper_type: "html",
     rate_limit_per_hour: 45,
   },
+  {
+    scraper_name: "arbeitnow",
+    display_name: "Arbeitnow",
+    requires_auth: false,
+    scraper_type: "api",
+    rate_limit_per_hour: 300,
+  },
+  {
+    scraper_name: "guardian_jobs",
+    display_name: "Guardian Jobs",
+    requires_auth: false,
+    scraper_type: "rss",
+    rate_limit_per_hour: 300,
+  },
 ] as const;
 
 export function hasEnabledMockScraperSource(configRecord: Record<string, unknown>): boolean {
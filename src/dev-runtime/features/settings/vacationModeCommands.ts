@@ -0,0 +1,21 @@
+export interface MockVacationModeStatus {
+  enabled: boolean;
+  keep_scraping: boolean;
+  started_at: string | null;
+  queued_alerts: number;
+  summary: null;
+}
+
+export function getMockVacationModeStatus(
+  args: Record<string, unknown> | undefined,
+): MockVacationModeStatus {
+  const enabled = args?.enabled === true;
+
+  return {
+    enabled,
+    keep_scraping: args?.keepScraping !== false,
+    started_at: enabled ? new Date().toISOString() : null,
+    queued_alerts: 0,
+    summary: null,
+  };
+}
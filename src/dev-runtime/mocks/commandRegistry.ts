@@ -1,13 +1,18 @@
 import {
   applyMockApplicationAssistCommand,
   applyMockApplicationsCommand,
+  applyMockAttachmentCommand,
+  applyMockContactCommand,
+  applyMockCoverLetterDraftCommand,
   applyMockCoverLetterTemplateCommand,
   applyMockDashboardCommand,
+  applyMockFollowUpDraftCommand,
   applyMockInterviewCommand,
   applyMockJobImportCommand,
   applyMockLinkedInCommand,
   applyMockMarketCommand,
   applyMockNotificationCommand,
+  applyMockOfferCommand,
   applyMockOnboardingCommand,
   applyMockResumeCommand,
   applyMockSalaryCommand,
@@ -33,19 +38,58 @@ const commandGroups: readonly MockCommandGroup[] = [
       "unhide_job",
       "toggle_bookmark",
       "get_bookmarked_jobs",
+      "quick_action",
+      "undo_quick_action",
       "set_job_notes",
       "mark_job_as_real",
       "mark_job_as_ghost",
+      "get_ghost_analysis",
+      "get_company_reputation",
       "get_job_notes",
+      "tag_job",
+      "untag_job",
+      "get_job_tags",
+      "list_job_tags",
+      "get_jobs_by_tag",
+      "query_jobs",
+      "query_jobs_page",
+      "get_recent_jobs_page",
+      "get_bookmarked_jobs_page",
+      "get_jobs_by_source_page",
+      "get_jobs_by_tag_page",
+      "get_ghost_reanalysis_summary",
+      "analyze_ghosts_batch",
+      "import_ghost_pattern_pack",
+      "record_job_feedback",
+      "get_job_duplicates",
+      "unlink_job_duplicate",
+      "refresh_job",
       "get_statistics",
+      "db_health",
+      "create_backup",
+      "list_backups",
+      "restore_backup",
+      "purge_now",
+      "export_jobs",
       "get_recent_jobs",
       "get_scraping_status",
+      "pause_scraping",
+      "resume_scraping",
       "search_jobs",
+      "run_scrape_now",
     ],
     adapter: applyMockDashboardCommand,
   },
   {
-    commands: ["is_first_run", "complete_setup"],
+    commands: [
+      "is_first_run",
+      "get_config_recovery_notice",
+      "complete_setup",
+      "get_setup_presets",
+      "apply_setup_preset",
+      "check_setup_source",
+      "import_setup_folder",
+    ],
     adapter: applyMockOnboardingCommand,
   },
   {
@@ -54,6 +98,8 @@ const commandGroups: readonly MockCommandGroup[] = [
       "get_dashboard_preferences",
       "get_resume_matching_preference",
       "set_resume_matching_enabled",
+      "block_company",
+      "unblock_company",
       "save_config",
       "get_credential_status",
       "has_credential",
@@ -70,7 +116,16 @@ const commandGroups: readonly MockCommandGroup[] = [
       "set_ghost_config",
       "reset_ghost_config",
       "validate_slack_webhook",
+      "validate_discord_webhook",
+      "validate_teams_webhook",
+      "validate_custom_webhook",
+      "validate_telegram_bot",
+      "validate_ntfy_topic",
+      "validate_pushover",
       "test_email_notification",
+      "send_test_email_digest",
+      "generate_report_now",
+      "get_weekly_reports",
       "get_bookmarklet_config",
       "get_pending_bookmarklet_imports",
       "confirm_pending_bookmarklet_imports",
@@ -81,6 +136,15 @@ const commandGroups: readonly MockCommandGroup[] = [
       "set_bookmarklet_port",
       "send_external_ai_request",
       "get_semantic_matching_diagnostics",
+      "get_guest_mode_status",
+      "enable_guest_mode",
+      "unlock_guest_mode",
+      "set_vacation_mode",
+      "get_notification_history",
+      "preview_notification_route",
+      "get_notification_message_templates",
+      "save_notification_message_template",
+      "preview_notification_message",
     ],
     adapter: applyMockSettingsCommand,
   },
@@ -121,7 +185,11 @@ const commandGroups: readonly MockCommandGroup[] = [
       "get_applications_kanban",
       "create_application",
       "update_application_status",
+      "bulk_create_applications",
+      "bulk_update_application_status",
+      "bulk_archive_rejected_applications",
       "add_application_notes",
+      "set_reminder",
       "get_pending_reminders",
       "complete_reminder",
       "detect_ghosted_applications",
@@ -131,10 +199,15 @@ const commandGroups: readonly MockCommandGroup[] = [
       "get_upcoming_interviews",
       "get_past_interviews",
       "schedule_interview",
+      "reschedule_interview",
       "complete_interview",
       "delete_interview",
+      "export_interview_calendar",
+      "subscribe_interview_calendar",
       "find_duplicates",
+      "find_near_duplicates",
       "merge_duplicates",
+      "get_time_to_fill_estimate",
     ],
     adapter: applyMockApplicationsCommand,
   },
@@ -152,8 +225,17 @@ const commandGroups: readonly MockCommandGroup[] = [
       "add_user_skill",
       "update_user_skill",
       "delete_user_skill",
+      "add_skill_evidence",
+      "get_skill_evidence",
+      "delete_skill_evidence",
+      "get_expiring_skill_evidence",
       "get_recent_matches",
       "match_resume_to_job",
+      "match_resume_to_all_jobs",
+      "get_best_resume_matches",
+      "suggest_resume_for_job",
+      "list_resume_versions",
+      "compare_resume_versions",
       "create_resume_draft",
       "get_resume_draft",
       "update_resume_contact",
@@ -174,6 +256,7 @@ const commandGroups: readonly MockCommandGroup[] = [
       "export_resume_docx",
       "export_resume_html",
       "export_resume_text",
+      "export_redacted_resume_text",
     ],
     adapter: applyMockResumeCommand,
   },
@@ -188,13 +271,19 @@ const commandGroups: readonly MockCommandGroup[] = [
   {
     commands: [
       "get_trending_skills",
-      "get_active_companies",
+      "get_personal_skill_gaps",
+      "get_skill_mention_series",
+      "get_median_salary_series",
+      "get_company_posting_series",
+      "get_most_active_companies",
       "get_hottest_locations",
       "get_market_alerts",
       "get_market_snapshot",
       "run_market_analysis",
       "mark_alert_read",
       "mark_all_alerts_read",
+      "dismiss_market_alert",
+      "export_market_report",
     ],
     adapter: applyMockMarketCommand,
   },
@@ -203,13 +292,27 @@ const commandGroups: readonly MockCommandGroup[] = [
       "has_application_profile",
       "get_application_profile_preview",
       "get_application_profile",
+      "list_application_profiles",
+      "create_application_profile",
+      "set_default_application_profile",
+      "delete_application_profile",
       "select_application_resume_file",
       "upsert_application_profile",
       "get_screening_answers",
       "upsert_screening_answer",
       "get_automation_stats",
+      "get_automation_quota",
+      "get_automation_dry_run",
+      "set_automation_dry_run",
+      "get_attempt_fill_summary",
+      "get_attempt_unanswered_questions",
+      "get_attempt_screenshots",
       "detect_ats_platform",
       "fill_application_form",
+      "check_automation_permission",
+      "grant_automation_permission",
+      "list_automation_permissions",
+      "revoke_permission",
       "is_browser_running",
       "close_automation_browser",
       "mark_attempt_submitted",
@@ -224,6 +327,9 @@ const commandGroups: readonly MockCommandGroup[] = [
       "set_scraper_enabled",
       "get_scraper_runs",
       "get_latest_source_request",
+      "get_scrape_history",
+      "get_scrape_run_details",
+      "discover_company_board",
       "run_scraper_smoke_test",
       "run_all_smoke_tests",
     ],
@@ -238,6 +344,42 @@ const commandGroups: readonly MockCommandGroup[] = [
     ],
     adapter: applyMockInterviewCommand,
   },
+  {
+    commands: ["save_offer", "get_offers", "delete_offer", "compare_offers"],
+    adapter: applyMockOfferCommand,
+  },
+  {
+    commands: [
+      "save_contact",
+      "update_contact",
+      "delete_contact",
+      "search_contacts",
+      "get_application_contacts",
+      "get_interview_contacts",
+      "link_contact",
+      "unlink_contact",
+      "log_contact_interaction",
+      "get_contact_interactions",
+    ],
+    adapter: applyMockContactCommand,
+  },
+  {
+    commands: [
+      "select_and_attach_file",
+      "get_attachments",
+      "open_attachment",
+      "delete_attachment",
+    ],
+    adapter: applyMockAttachmentCommand,
+  },
+  {
+    commands: ["generate_followup_draft"],
+    adapter: applyMockFollowUpDraftCommand,
+  },
+  {
+    commands: ["generate_cover_letter"],
+    adapter: applyMockCoverLetterDraftCommand,
+  },
   {
     commands: [
       "seed_default_templates",
@@ -261,9 +403,13 @@ const commandGroups: readonly MockCommandGroup[] = [
       "create_saved_search",
       "use_saved_search",
       "delete_saved_search",
+      "update_saved_search_agent",
       "import_saved_searches",
       "add_search_history",
       "clear_search_history",
+      "suggest_queries",
+      "pin_search_suggestion",
+      "remove_search_suggestion",
     ],
     adapter: applyMockSavedSearchCommand,
   },
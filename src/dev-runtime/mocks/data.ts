@@ -164,7 +164,7 @@ export const mockConfig = {
   salary_floor_usd: 80000,
   bookmarklet_port: 4321,
   preferred_companies: [],
-  blocked_companies: [],
+  blocked_companies: [] as string[],
   use_resume_matching: false,
   alerts: {
     slack: {
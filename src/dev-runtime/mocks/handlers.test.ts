@@ -12,6 +12,7 @@ type SalaryBenchmark = {
   p75_salary: number;
   max_salary: number;
   sample_size: number;
+  trend_direction: string | null;
 };
 type AtsDetectionResponse = {
   platform: string;
@@ -28,6 +29,15 @@ type FillResultWithAttempt = {
   durationMs: number;
   atsPlatform: string;
 };
+type AttemptFillSummary = {
+  filledValues: Record<string, string>;
+  unfilledFields: string[];
+  unansweredQuestions: string[];
+};
+type AttemptScreenshots = {
+  review: string | null;
+  confirmation: string | null;
+};
 type AnswerSuggestion = {
   answer: string;
   source: { type: "manual"; answerId: number };
@@ -127,6 +137,7 @@ describe("mock Tauri command facade", () => {
       job_title: "Training Coordinator",
       location: "Chicago, IL",
       seniority_level: "Mid",
+      trend_direction: "up",
       p25_salary: expect.any(Number),
       median_salary: expect.any(Number),
       p75_salary: expect.any(Number),
@@ -174,6 +185,16 @@ describe("mock Tauri command facade", () => {
       atsPlatform: "greenhouse",
     });
 
+    await expect(mockInvoke<AttemptFillSummary>("get_attempt_fill_summary", {
+      attemptId: fillResult.attemptId,
+    })).resolves.toMatchObject({
+      filledValues: expect.objectContaining({ email: expect.any(String) }),
+      unansweredQuestions: expect.any(Array),
+    });
+    await expect(mockInvoke<AttemptScreenshots>("get_attempt_screenshots", {
+      attemptId: fillResult.attemptId,
+    })).resolves.toEqual({ review: null, confirmation: null });
+
     await expect(mockInvoke<boolean>("is_browser_running")).resolves.toBe(true);
     await expect(mockInvoke<void>("mark_attempt_submitted", {
       attemptId: fillResult.attemptId,
@@ -89,8 +89,11 @@ describe("mock core command handlers", () => {
       "fullName",
       "githubUrl",
       "hasResumeFile",
+      "isDefault",
       "linkedinUrl",
       "maxApplicationsPerDay",
+      "maxApplicationsPerWeek",
+      "name",
       "phone",
       "portfolioUrl",
       "requireManualApproval",
@@ -77,6 +77,14 @@ export interface MockSavedSearch {
   textSearch: string | null;
   createdAt: string;
   lastUsedAt: string | null;
+  agent: MockSearchAgentSettings;
+}
+
+export interface MockSearchAgentSettings {
+  enabled: boolean;
+  channels: Array<"email" | "slack" | "discord">;
+  frequency: "each_scrape" | "daily";
+  lastCheckedAt: string | null;
 }
 
 export type MockCredentialKey =
@@ -162,6 +170,18 @@ export interface MockUserSkill {
   source: string;
 }
 
+export interface MockSkillEvidence {
+  id: number;
+  skill_id: number;
+  skill_name: string;
+  kind: "project" | "repository" | "certification" | "other";
+  title: string;
+  url: string | null;
+  issued_on: string | null;
+  expires_on: string | null;
+  created_at: string;
+}
+
 export interface MockSkillInput {
   skill_name?: unknown;
   skill_category?: unknown;
@@ -201,6 +221,66 @@ export interface MockInterview {
   company: string;
 }
 
+export interface MockOffer {
+  id: number;
+  application_id: number;
+  job_title: string;
+  company: string;
+  base_salary: number;
+  currency: string;
+  pay_period: "hour" | "day" | "week" | "month" | "year";
+  annual_bonus: number | null;
+  signing_bonus: number | null;
+  equity_annual_value: number | null;
+  benefits_summary: string | null;
+  benefits_annual_value: number | null;
+  location: string | null;
+  cost_of_living_index: number | null;
+  start_date: string | null;
+  offer_expires_at: string | null;
+  offer_received_at: string | null;
+  accepted: boolean | null;
+}
+
+export interface MockContact {
+  id: number;
+  name: string;
+  email: string | null;
+  phone: string | null;
+  linkedin_url: string | null;
+  company: string | null;
+  notes: string | null;
+  last_contacted_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface MockContactInteraction {
+  id: number;
+  contact_id: number;
+  application_id: number | null;
+  interaction_type: string;
+  occurred_at: string;
+  summary: string | null;
+}
+
+export interface MockContactBook {
+  contacts: MockContact[];
+  interactions: MockContactInteraction[];
+  applicationLinks: Array<{ contact_id: number; application_id: number }>;
+  interviewLinks: Array<{ contact_id: number; interview_id: number }>;
+}
+
+export interface MockAttachment {
+  id: number;
+  application_id: number;
+  kind: "resume" | "cover_letter" | "take_home" | "other";
+  version: number;
+  file_name: string;
+  size_bytes: number;
+  created_at: string;
+}
+
 export interface MockDashboardPreferences {
   autoRefresh: MockConfig["auto_refresh"];
   salaryFloorUsd: number;
@@ -209,6 +289,7 @@ export interface MockDashboardPreferences {
 
 export interface MockFillResultWithAttempt {
   filledFields: string[];
+  filledValues: Record<string, string>;
   unfilledFields: string[];
   captchaDetected: boolean;
   readyForReview: boolean;
@@ -216,6 +297,7 @@ export interface MockFillResultWithAttempt {
   attemptId: number | null;
   durationMs: number;
   atsPlatform: string;
+  dryRun: boolean;
 }
 
 export interface MockState {
@@ -227,6 +309,7 @@ export interface MockState {
   coverLetterTemplates: MockCoverLetterTemplate[];
   savedSearches: MockSavedSearch[];
   searchHistory: string[];
+  pinnedSearches: string[];
   notificationPreferences: NotificationPreferences | null;
   credentials: Partial<Record<MockCredentialKey, string>>;
   credentialUnlock: MockCredentialUnlockState;
@@ -235,6 +318,7 @@ export interface MockState {
   pendingBookmarkletImports: MockPendingBookmarkletImport[];
   resumes: MockResumeData[];
   userSkills: MockUserSkill[];
+  skillEvidence: MockSkillEvidence[];
   resumeDrafts: MockResumeDraft[];
   recentMatches: MockMatchResult[];
   marketAlerts: MockMarketAlert[];
@@ -243,6 +327,9 @@ export interface MockState {
   scraperEnabledOverrides: MockScraperEnabledOverrides;
   interviewPrepChecklists: MockInterviewPrepState;
   interviewFollowups: MockInterviewFollowUpState;
+  offers: MockOffer[];
+  contacts: MockContactBook;
+  attachments: MockAttachment[];
 }
 
 export type {
@@ -1,7 +1,12 @@
 import { handleMockApplicationAssistCommand } from "../features/application-assist/commands";
+import { handleMockAttachmentCommand } from "../features/applications/attachmentCommands";
 import { handleMockApplicationsCommand } from "../features/applications/commands";
+import { handleMockContactCommand } from "../features/applications/contactCommands";
+import { handleMockCoverLetterDraftCommand } from "../features/applications/coverLetterDraftCommands";
 import { handleMockCoverLetterTemplateCommand } from "../features/applications/coverLetterTemplateCommands";
+import { handleMockFollowUpDraftCommand } from "../features/applications/followupDraftCommands";
 import { handleMockInterviewCommand } from "../features/applications/interviewCommands";
+import { handleMockOfferCommand } from "../features/applications/offerCommands";
 import { handleMockDashboardCommand } from "../features/dashboard/commands";
 import { handleMockJobImportCommand } from "../features/dashboard/jobImportCommands";
 import { handleMockSavedSearchCommand } from "../features/dashboard/savedSearchCommands";
@@ -133,6 +138,7 @@ export const applyMockResumeCommand: MockCommandAdapter = (command, args) => {
     jobs: mockRuntimeState.jobs,
     resumes: mockRuntimeState.resumes,
     userSkills: mockRuntimeState.userSkills,
+    skillEvidence: mockRuntimeState.skillEvidence,
     resumeDrafts: mockRuntimeState.resumeDrafts,
     recentMatches: mockRuntimeState.recentMatches,
   });
@@ -165,6 +171,7 @@ export const applyMockApplicationAssistCommand: MockCommandAdapter = (
     applicationProfile: mockRuntimeState.applicationProfile,
     screeningAnswers: mockRuntimeState.screeningAnswers,
     automationBrowserRunning: mockRuntimeState.automationBrowserRunning,
+    automationDryRun: mockRuntimeState.automationDryRun,
     nextAutomationAttemptId: mockRuntimeState.nextAutomationAttemptId,
   });
   if (!result.handled) return undefined;
@@ -201,6 +208,72 @@ export const applyMockInterviewCommand: MockCommandAdapter = (
   return result.value;
 };
 
+export const applyMockOfferCommand: MockCommandAdapter = (command, args) => {
+  const result = handleMockOfferCommand(command, args, {
+    applications: mockRuntimeState.applications,
+    offers: mockRuntimeState.offers,
+  });
+  if (!result.handled) return undefined;
+  Object.assign(mockRuntimeState, result.state);
+  if (result.shouldSave) saveMockState();
+  return result.value;
+};
+
+export const applyMockAttachmentCommand: MockCommandAdapter = (
+  command,
+  args,
+) => {
+  const result = handleMockAttachmentCommand(
+    command,
+    args,
+    mockRuntimeState.attachments,
+  );
+  if (!result.handled) return undefined;
+  mockRuntimeState.attachments = result.state;
+  if (result.shouldSave) saveMockState();
+  return result.value;
+};
+
+export const applyMockContactCommand: MockCommandAdapter = (command, args) => {
+  const result = handleMockContactCommand(
+    command,
+    args,
+    mockRuntimeState.contacts,
+  );
+  if (!result.handled) return undefined;
+  mockRuntimeState.contacts = result.state;
+  if (result.shouldSave) saveMockState();
+  return result.value;
+};
+
+export const applyMockFollowUpDraftCommand: MockCommandAdapter = (
+  command,
+  args,
+) => {
+  const result = handleMockFollowUpDraftCommand(command, args, {
+    applications: mockRuntimeState.applications,
+    coverLetterTemplates: mockRuntimeState.coverLetterTemplates,
+    contacts: mockRuntimeState.contacts,
+  });
+  return result.handled ? result.value : undefined;
+};
+
+export const applyMockCoverLetterDraftCommand: MockCommandAdapter = (
+  command,
+  args,
+) => {
+  const result = handleMockCoverLetterDraftCommand(command, args, {
+    jobs: mockRuntimeState.jobs,
+    coverLetterTemplates: mockRuntimeState.coverLetterTemplates,
+    userSkills: mockRuntimeState.userSkills,
+    attachments: mockRuntimeState.attachments,
+  });
+  if (!result.handled) return undefined;
+  mockRuntimeState.attachments = result.attachments;
+  if (result.shouldSave) saveMockState();
+  return result.value;
+};
+
 export const applyMockCoverLetterTemplateCommand: MockCommandAdapter = (
   command,
   args,
@@ -235,9 +308,13 @@ export const applyMockSavedSearchCommand: MockCommandAdapter = (
   const result = handleMockSavedSearchCommand(command, args, {
     savedSearches: mockRuntimeState.savedSearches,
     searchHistory: mockRuntimeState.searchHistory,
+    pinnedSearches: mockRuntimeState.pinnedSearches,
+    jobs: mockRuntimeState.jobs,
   });
   if (!result.handled) return undefined;
-  Object.assign(mockRuntimeState, result.state);
+  mockRuntimeState.savedSearches = result.state.savedSearches;
+  mockRuntimeState.searchHistory = result.state.searchHistory;
+  mockRuntimeState.pinnedSearches = result.state.pinnedSearches;
   if (result.shouldSave) saveMockState();
   return result.value;
 };
@@ -5,6 +5,10 @@ import {
   mockPendingReminders,
   mockUpcomingInterviews,
 } from "./data";
+import {
+  getDefaultMockContactBook,
+  normalizeMockContactBook,
+} from "../features/applications/contactCommands";
 import {
   getNextMockCoverLetterTemplateId,
   normalizeMockCoverLetterTemplate,
@@ -43,6 +47,7 @@ const defaultCredentialUnlock: MockCredentialUnlockState = {
 
 interface MockRuntimeState extends MockState {
   automationBrowserRunning: boolean;
+  automationDryRun: boolean;
   nextAutomationAttemptId: number;
   pendingUrlImports: MockPendingUrlImport[];
 }
@@ -76,6 +81,7 @@ function createDefaultState(): MockRuntimeState {
     coverLetterTemplates: [],
     savedSearches: [],
     searchHistory: [],
+    pinnedSearches: [],
     notificationPreferences: null,
     credentials: {},
     credentialUnlock: { ...defaultCredentialUnlock },
@@ -85,6 +91,7 @@ function createDefaultState(): MockRuntimeState {
     pendingUrlImports: [],
     resumes: [],
     userSkills: [],
+    skillEvidence: [],
     resumeDrafts: [],
     recentMatches: [],
     marketAlerts: getDefaultMarketAlerts(),
@@ -93,7 +100,11 @@ function createDefaultState(): MockRuntimeState {
     scraperEnabledOverrides: {},
     interviewPrepChecklists: {},
     interviewFollowups: {},
+    offers: [],
+    contacts: getDefaultMockContactBook(),
+    attachments: [],
     automationBrowserRunning: false,
+    automationDryRun: false,
     nextAutomationAttemptId: 1,
   };
 }
@@ -105,6 +116,7 @@ export function saveMockState(): void {
 
   const persistedState: Partial<MockRuntimeState> = { ...mockRuntimeState };
   delete persistedState.automationBrowserRunning;
+  delete persistedState.automationDryRun;
   delete persistedState.nextAutomationAttemptId;
   delete persistedState.pendingUrlImports;
   window.localStorage.setItem(MOCK_STATE_KEY, JSON.stringify(persistedState));
@@ -155,6 +167,12 @@ export function loadMockState(): void {
           typeof query === "string" && query.trim().length >= 2,
       );
     }
+    if (Array.isArray(state.pinnedSearches)) {
+      mockRuntimeState.pinnedSearches = state.pinnedSearches.filter(
+        (query): query is string =>
+          typeof query === "string" && query.trim().length >= 2,
+      );
+    }
     if (
       state.notificationPreferences &&
       typeof state.notificationPreferences === "object"
@@ -190,6 +208,8 @@ export function loadMockState(): void {
     if (Array.isArray(state.resumes)) mockRuntimeState.resumes = state.resumes;
     if (Array.isArray(state.userSkills))
       mockRuntimeState.userSkills = state.userSkills;
+    if (Array.isArray(state.skillEvidence))
+      mockRuntimeState.skillEvidence = state.skillEvidence;
     if (Array.isArray(state.resumeDrafts)) {
       mockRuntimeState.resumeDrafts = state.resumeDrafts
         .filter((draft) => draft && typeof draft === "object")
@@ -232,6 +252,13 @@ export function loadMockState(): void {
         state.interviewFollowups,
       );
     }
+    if (Array.isArray(state.offers)) mockRuntimeState.offers = state.offers;
+    if (state.contacts) {
+      mockRuntimeState.contacts = normalizeMockContactBook(state.contacts);
+    }
+    if (Array.isArray(state.attachments)) {
+      mockRuntimeState.attachments = state.attachments;
+    }
   } catch {
     window.localStorage.removeItem(MOCK_STATE_KEY);
   }
@@ -248,6 +275,7 @@ export function resetMockState(): void {
     coverLetterTemplates: defaults.coverLetterTemplates,
     savedSearches: defaults.savedSearches,
     searchHistory: defaults.searchHistory,
+    pinnedSearches: defaults.pinnedSearches,
     notificationPreferences: defaults.notificationPreferences,
     credentials: defaults.credentials,
     credentialUnlock: defaults.credentialUnlock,
@@ -262,6 +290,9 @@ export function resetMockState(): void {
     marketAlerts: defaults.marketAlerts,
     applicationProfile: defaults.applicationProfile,
     screeningAnswers: defaults.screeningAnswers,
+    offers: defaults.offers,
+    contacts: defaults.contacts,
+    attachments: defaults.attachments,
   });
   saveMockState();
 }
@@ -56,6 +56,19 @@ describe("ApplicationProfile", () => {
     expect(screen.queryByText("Submission Rate")).not.toBeInTheDocument();
   });
 
+  it("turns dry-run mode on from the settings page", async () => {
+    const user = userEvent.setup();
+    render(<ApplicationProfilePage onBack={vi.fn()} />);
+
+    const toggle = screen.getByRole("checkbox", { name: /Dry run/ });
+    expect(toggle).not.toBeChecked();
+
+    await user.click(toggle);
+
+    expect(mockInvoke).toHaveBeenCalledWith("set_automation_dry_run", { enabled: true });
+    await waitFor(() => expect(toggle).toBeChecked());
+  });
+
   it("keeps the visible selected tab state aligned with the active panel", async () => {
     const user = userEvent.setup();
 
@@ -29,6 +29,8 @@ export default function ApplicationProfilePage({ onBack }: ApplicationProfilePro
   const [activeTab, setActiveTab] = useState<Tab>("profile");
   const [stats, setStats] = useState<AutomationStats | null>(null);
   const [loadingStats, setLoadingStats] = useState(false);
+  const [dryRun, setDryRun] = useState(false);
+  const [savingDryRun, setSavingDryRun] = useState(false);
   const tabRefs = useRef<Record<Tab, HTMLButtonElement | null>>({
     profile: null,
     screening: null,
@@ -90,6 +92,33 @@ export default function ApplicationProfilePage({ onBack }: ApplicationProfilePro
     return () => controller.abort();
   }, [loadStats]);
 
+  useEffect(() => {
+    let cancelled = false;
+
+    invoke<boolean>("get_automation_dry_run")
+      .then((enabled) => {
+        if (!cancelled) setDryRun(enabled === true);
+      })
+      .catch((error: unknown) => logError("Failed to load dry-run mode:", error));
+
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const handleDryRunChange = async (enabled: boolean) => {
+    try {
+      setSavingDryRun(true);
+      await invoke("set_automation_dry_run", { enabled });
+      setDryRun(enabled);
+    } catch (error: unknown) {
+      logError("Failed to update dry-run mode:", error);
+      toast.error("Could not change dry-run mode", "Try again in a moment.");
+    } finally {
+      setSavingDryRun(false);
+    }
+  };
+
   return (
     <div className="min-h-screen bg-surface-50 dark:bg-surface-900">
       {/* Header */}
@@ -149,6 +178,28 @@ export default function ApplicationProfilePage({ onBack }: ApplicationProfilePro
           )}
         </div>
 
+        <label
+          htmlFor="automation-dry-run"
+          className="flex items-start gap-3 mb-8 rounded-card border border-surface-200 dark:border-surface-700 bg-white dark:bg-surface-800 p-4"
+        >
+          <input
+            id="automation-dry-run"
+            type="checkbox"
+            className="mt-1"
+            checked={dryRun}
+            disabled={savingDryRun}
+            onChange={(event) => void handleDryRunChange(event.target.checked)}
+          />
+          <span>
+            <span className="block font-medium text-surface-900 dark:text-white">
+              Dry run
+            </span>
+            <span className="block text-sm text-surface-500 dark:text-surface-400">
+              Fill forms and record what was entered, then close them so nothing is sent.
+            </span>
+          </span>
+        </label>
+
         {/* Tabs */}
         <div className="border-b border-surface-200 dark:border-surface-700 mb-6">
           <nav className="app-section-tabs" aria-label="Application assist settings" role="tablist">
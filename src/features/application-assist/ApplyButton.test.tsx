@@ -372,6 +372,131 @@ describe("ApplyButton", () => {
         expect(mockOnApplied).toHaveBeenCalledTimes(1);
       });
     });
+
+    it("fills with the profile chosen when several are saved", async () => {
+      const user = userEvent.setup();
+
+      mockInvoke.mockImplementation((cmd) => {
+        if (cmd === "detect_ats_platform") return Promise.resolve(mockAtsDetection);
+        if (cmd === "has_application_profile") return Promise.resolve(true);
+        if (cmd === "is_browser_running") return Promise.resolve(false);
+        if (cmd === "list_application_profiles") {
+          return Promise.resolve([
+            { id: 1, name: "SRE profile", isDefault: true },
+            { id: 2, name: "Manager profile", isDefault: false },
+          ]);
+        }
+        if (cmd === "fill_application_form") {
+          return Promise.resolve({
+            filledFields: ["name"],
+            unfilledFields: [],
+            captchaDetected: false,
+            readyForReview: true,
+            errorMessage: null,
+            attemptId: 124,
+            durationMs: 900,
+            atsPlatform: "greenhouse",
+          });
+        }
+        return Promise.resolve(null);
+      });
+
+      renderWithToast(<ApplyButton job={mockJob} />);
+
+      await waitFor(() => {
+        expect(screen.getByRole("button", { name: /prepare form/i })).not.toBeDisabled();
+      });
+
+      await user.click(screen.getByRole("button", { name: /prepare form/i }));
+
+      const select = await screen.findByLabelText(/fill with profile/i);
+      expect(select).toHaveValue("1");
+      await user.selectOptions(select, "2");
+      await user.click(screen.getByRole("button", { name: /prepare details/i }));
+
+      await waitFor(() => {
+        expect(mockInvoke).toHaveBeenCalledWith("fill_application_form", {
+          jobUrl: mockJob.url,
+          jobHash: mockJob.hash,
+          profileId: 2,
+        });
+      });
+    });
+
+    it("preselects the profile linked to the best-fitting resume", async () => {
+      const user = userEvent.setup();
+
+      mockInvoke.mockImplementation((cmd) => {
+        if (cmd === "detect_ats_platform") return Promise.resolve(mockAtsDetection);
+        if (cmd === "has_application_profile") return Promise.resolve(true);
+        if (cmd === "is_browser_running") return Promise.resolve(false);
+        if (cmd === "list_application_profiles") {
+          return Promise.resolve([
+            { id: 1, name: "SRE profile", isDefault: true, defaultResumeId: 10 },
+            { id: 2, name: "Manager profile", isDefault: false, defaultResumeId: 20 },
+          ]);
+        }
+        if (cmd === "suggest_resume_for_job") {
+          return Promise.resolve({
+            job_hash: mockJob.hash,
+            recommended_resume_id: 20,
+            candidates: [],
+          });
+        }
+        return Promise.resolve(null);
+      });
+
+      renderWithToast(<ApplyButton job={mockJob} />);
+
+      await waitFor(() => {
+        expect(screen.getByRole("button", { name: /prepare form/i })).not.toBeDisabled();
+      });
+
+      await user.click(screen.getByRole("button", { name: /prepare form/i }));
+
+      expect(await screen.findByLabelText(/fill with profile/i)).toHaveValue("2");
+      expect(mockInvoke).toHaveBeenCalledWith("suggest_resume_for_job", {
+        jobHash: mockJob.hash,
+      });
+    });
+
+    it("says when a dry run closed the form without sending it", async () => {
+      const user = userEvent.setup();
+
+      mockInvoke.mockImplementation((cmd) => {
+        if (cmd === "detect_ats_platform") return Promise.resolve(mockAtsDetection);
+        if (cmd === "has_application_profile") return Promise.resolve(true);
+        if (cmd === "is_browser_running") return Promise.resolve(false);
+        if (cmd === "fill_application_form") {
+          return Promise.resolve({
+            filledFields: ["first_name", "email"],
+            unfilledFields: [],
+            captchaDetected: false,
+            readyForReview: true,
+            errorMessage: null,
+            attemptId: 125,
+            durationMs: 900,
+            atsPlatform: "greenhouse",
+            dryRun: true,
+          });
+        }
+        return Promise.resolve(null);
+      });
+
+      renderWithToast(<ApplyButton job={mockJob} />);
+
+      await waitFor(() => {
+        expect(screen.getByRole("button", { name: /prepare form/i })).not.toBeDisabled();
+      });
+
+      await user.click(screen.getByRole("button", { name: /prepare form/i }));
+      await user.click(await screen.findByRole("button", { name: /prepare details/i }));
+
+      await waitFor(() => {
+        expect(screen.getByText("Dry run recorded")).toBeInTheDocument();
+      });
+      expect(screen.queryByText("Form ready for review")).not.toBeInTheDocument();
+    });
   });
 
   describe("loading states", () => {
@@ -4,10 +4,15 @@ import { Button } from "../../ui/Button";
 import { Modal, ModalFooter } from "../../ui/Modal";
 import { useToast } from "../../shared/toast/useToast";
 import { logError } from "../../shared/errorReporting/logger";
-import { invoke, safeInvoke, safeInvokeWithToast } from "../../platform/tauri";
+import { invalidateCacheByCommand, invoke, safeInvoke, safeInvokeWithToast } from "../../platform/tauri";
 import { getUserFriendlyError } from "../../shared/errorReporting/messages";
 import { ApplicationPreview } from "./ApplicationPreview";
-import { getApplicationFormDisplayName } from "./applicationFormLabels";
+import { getApplicationFormColorClass, getApplicationFormDisplayName } from "./applicationFormLabels";
+import { SitePermissionNotice } from "./SitePermissionNotice";
+import { notifySecurityCheck } from "./securityCheckNotification";
+import { findUngrantedSiteDomain } from "./siteAutomationPermission";
+import type { ApplicationProfileSummary } from "./profileModel";
+import type { ResumeSuggestion } from "../../shared/resumeSuggestion";
 import { readStorageValue, removeStorageValue, writeStorageValue } from "../../shared/browserStorage";
 
 interface Job {
@@ -67,28 +72,6 @@ function formatScreeningTopicList(topics: string[]) {
   }`;
 }
 
-const APPLICATION_FORM_COLORS: Record<string, string> = {
-  greenhouse: "bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300",
-  lever: "bg-blue-100 text-blue-800 dark:bg-blue-900/30 dark:text-blue-300",
-  workday: "bg-orange-100 text-orange-800 dark:bg-orange-900/30 dark:text-orange-300",
-  taleo: "bg-purple-100 text-purple-800 dark:bg-purple-900/30 dark:text-purple-300",
-  icims: "bg-cyan-100 text-cyan-800 dark:bg-cyan-900/30 dark:text-cyan-300",
-  bamboohr: "bg-emerald-100 text-emerald-800 dark:bg-emerald-900/30 dark:text-emerald-300",
-  ashbyhq: "bg-indigo-100 text-indigo-800 dark:bg-indigo-900/30 dark:text-indigo-300",
-  smartrecruiters: "bg-sky-100 text-sky-800 dark:bg-sky-900/30 dark:text-sky-300",
-  workable: "bg-teal-100 text-teal-800 dark:bg-teal-900/30 dark:text-teal-300",
-  recruitee: "bg-lime-100 text-lime-800 dark:bg-lime-900/30 dark:text-lime-300",
-  breezyhr: "bg-emerald-100 text-emerald-800 dark:bg-emerald-900/30 dark:text-emerald-300",
-  jazzhr: "bg-violet-100 text-violet-800 dark:bg-violet-900/30 dark:text-violet-300",
-  bullhorn: "bg-amber-100 text-amber-800 dark:bg-amber-900/30 dark:text-amber-300",
-  jobvite: "bg-rose-100 text-rose-800 dark:bg-rose-900/30 dark:text-rose-300",
-  teamtailor: "bg-blue-100 text-blue-800 dark:bg-blue-900/30 dark:text-blue-300",
-  successfactors: "bg-cyan-100 text-cyan-800 dark:bg-cyan-900/30 dark:text-cyan-300",
-  oracle_recruiting: "bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300",
-  eightfold: "bg-indigo-100 text-indigo-800 dark:bg-indigo-900/30 dark:text-indigo-300",
-  unknown: "bg-surface-100 text-surface-600 dark:bg-surface-700 dark:text-surface-300",
-};
-
 export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApplicationAssist }: ApplyButtonProps) {
   const [atsPlatform, setAtsPlatform] = useState<string | null>(null);
   const [atsLoading, setAtsLoading] = useState(true);
@@ -99,6 +82,9 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
   const [lastAttemptId, setLastAttemptId] = useState<number | null>(null);
   const [showSubmitConfirm, setShowSubmitConfirm] = useState(false);
   const [fillError, setFillError] = useState<string | null>(null);
+  const [permissionDomain, setPermissionDomain] = useState<string | null>(null);
+  const [profiles, setProfiles] = useState<ApplicationProfileSummary[]>([]);
+  const [selectedProfileId, setSelectedProfileId] = useState<number | null>(null);
   const toast = useToast();
 
   // Check for previous attempt on mount
@@ -167,6 +153,50 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
     );
   };
 
+  // Profile linked to the resume that best fits this job, if any
+  const findSuggestedProfile = async (saved: ApplicationProfileSummary[]) => {
+    if (!saved.some((profile) => profile.defaultResumeId != null)) return undefined;
+    try {
+      const suggestion = await safeInvoke<ResumeSuggestion>(
+        "suggest_resume_for_job",
+        { jobHash: job.hash },
+        { silent: true },
+      );
+      const resumeId = suggestion?.recommended_resume_id;
+      return resumeId == null
+        ? undefined
+        : saved.find((profile) => profile.defaultResumeId === resumeId);
+    } catch {
+      // Silently fail - fall back to the default profile
+      return undefined;
+    }
+  };
+
+  // Offer a profile choice only when more than one profile is saved
+  const loadProfiles = async () => {
+    try {
+      const result = await safeInvoke<ApplicationProfileSummary[]>(
+        "list_application_profiles",
+        undefined,
+        { silent: true },
+      );
+      const saved = Array.isArray(result) ? result : [];
+      setProfiles(saved);
+      if (saved.length <= 1) {
+        setSelectedProfileId(null);
+        return;
+      }
+      const suggested = await findSuggestedProfile(saved);
+      setSelectedProfileId(
+        (suggested ?? saved.find((profile) => profile.isDefault) ?? saved[0]).id,
+      );
+    } catch {
+      // Silently fail - the default profile is used
+      setProfiles([]);
+      setSelectedProfileId(null);
+    }
+  };
+
   const handlePrepareApplication = () => {
     if (!hasProfile) {
       toast.error(
@@ -175,10 +205,22 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
       );
       return;
     }
+    void loadProfiles();
     setShowPreview(true);
   };
 
+  // Application Assist only acts on sites the user has allowed once.
+  const needsSitePermission = async () => {
+    const domain = await findUngrantedSiteDomain(job.url);
+    setPermissionDomain(domain);
+    return domain !== null;
+  };
+
   const handleFillForm = async () => {
+    if (await needsSitePermission()) {
+      return;
+    }
+
     try {
       setIsFilling(true);
       setFillError(null);
@@ -191,16 +233,23 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
         readyForReview: boolean;
         errorMessage: string | null;
         screeningAnswerTopics?: string[];
+        unansweredQuestions?: string[];
         attemptId: number | null;
         durationMs: number;
         atsPlatform: string;
-      }>("fill_application_form", { jobUrl: job.url, jobHash: job.hash });
+        dryRun?: boolean;
+      }>("fill_application_form", {
+        jobUrl: job.url,
+        jobHash: job.hash,
+        ...(selectedProfileId !== null ? { profileId: selectedProfileId } : {}),
+      });
 
       // Count screening questions filled
       const screeningCount = result.filledFields.filter(f => f.startsWith("screening:")).length;
       const basicCount = result.filledFields.length - screeningCount;
 
       if (result.captchaDetected) {
+        void notifySecurityCheck();
         toast.warning(
           "Site asked for a human check",
           "Complete the check in the browser, then continue review"
@@ -212,6 +261,11 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
         setFillError(safeError.message + actionHint);
         toast.error("Could not prepare details", safeError.message + actionHint);
         return; // Don't close modal
+      } else if (result.dryRun) {
+        toast.success(
+          "Dry run recorded",
+          `Prepared ${result.filledFields.length} fields and closed the form without sending it. Turn off dry-run mode to apply.`,
+        );
       } else {
         const unfilled = result.unfilledFields.length;
         let message = `Prepared ${basicCount} profile fields`;
@@ -227,6 +281,12 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
         if (unfilled > 0) {
           message += `. ${unfilled} fields need attention`;
         }
+        const unanswered = result.unansweredQuestions?.length ?? 0;
+        if (unanswered > 0) {
+          message += `. ${unanswered} ${
+            unanswered === 1 ? "question needs" : "questions need"
+          } your answer`;
+        }
         message += `. Review every field and submit it yourself.`;
 
         toast.success("Form ready for review", message);
@@ -268,6 +328,18 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
     }
   };
 
+  const handleAllowSite = async () => {
+    try {
+      await safeInvokeWithToast("grant_automation_permission", { url: job.url }, toast, {
+        logContext: "Allow Application Assist on site",
+      });
+      setPermissionDomain(null);
+      await handleFillForm();
+    } catch {
+      // Error already logged and shown to user
+    }
+  };
+
   const closeBrowser = async () => {
     try {
       await safeInvokeWithToast("close_automation_browser", undefined, toast, {
@@ -293,6 +365,7 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
       await safeInvokeWithToast("mark_attempt_submitted", { attemptId: lastAttemptId }, toast, {
         logContext: "Mark application submitted",
       });
+      invalidateCacheByCommand("get_applications_kanban");
       toast.success("Marked as submitted", "Your application has been tracked");
       removeStorageValue("local", `lastAttempt_${job.hash}`);
       setLastAttemptId(null);
@@ -366,12 +439,34 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
       {/* Preview Modal */}
       <Modal
         isOpen={showPreview}
-        onClose={() => { setShowPreview(false); setFillError(null); }}
+        onClose={() => { setShowPreview(false); setFillError(null); setPermissionDomain(null); }}
         title="Review Application"
         size="lg"
       >
         <ApplicationPreview job={job} atsPlatform={atsPlatform} />
 
+        {profiles.length > 1 && selectedProfileId !== null && (
+          <div className="mt-4 flex items-center gap-3">
+            <label htmlFor="apply-profile-select" className="text-sm text-surface-700 dark:text-surface-300">
+              Fill with profile:
+            </label>
+            <select
+              id="apply-profile-select"
+              value={selectedProfileId}
+              onChange={(e) => setSelectedProfileId(parseInt(e.target.value))}
+              className="px-3 py-1.5 text-sm border border-surface-300 dark:border-surface-600 rounded-lg bg-white dark:bg-surface-800 text-surface-900 dark:text-surface-100"
+            >
+              {profiles.map((profile) => (
+                <option key={profile.id} value={profile.id}>
+                  {profile.isDefault ? `${profile.name} (default)` : profile.name}
+                </option>
+              ))}
+            </select>
+          </div>
+        )}
+
+        {permissionDomain && <SitePermissionNotice domain={permissionDomain} />}
+
         {/* Error state with retry */}
         {fillError && (
           <div className="mt-4 p-4 bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 rounded-lg">
@@ -394,13 +489,20 @@ export const ApplyButton = memo(function ApplyButton({ job, onApplied, onOpenApp
         )}
 
         <ModalFooter>
-          <Button variant="secondary" onClick={() => { setShowPreview(false); setFillError(null); }}>
+          <Button variant="secondary" onClick={() => { setShowPreview(false); setFillError(null); setPermissionDomain(null); }}>
             Cancel
           </Button>
-          <Button onClick={handleFillForm} loading={isFilling} loadingText="Preparing...">
-            <BoltIcon className="w-4 h-4 mr-2" />
-            {fillError ? "Try Again" : "Prepare Details"}
-          </Button>
+          {permissionDomain ? (
+            <Button onClick={handleAllowSite} loading={isFilling} loadingText="Preparing...">
+              <CheckIcon className="w-4 h-4 mr-2" />
+              Allow and Prepare
+            </Button>
+          ) : (
+            <Button onClick={handleFillForm} loading={isFilling} loadingText="Preparing...">
+              <BoltIcon className="w-4 h-4 mr-2" />
+              {fillError ? "Try Again" : "Prepare Details"}
+            </Button>
+          )}
         </ModalFooter>
       </Modal>
 
@@ -454,7 +556,7 @@ export const AtsBadge = memo(function AtsBadge({ url }: { url: string }) {
   // Use inline styles since Badge doesn't support className
   return (
     <span
-      className={`px-2 py-1 text-xs font-medium rounded-full ${(platform && APPLICATION_FORM_COLORS[platform]) || APPLICATION_FORM_COLORS.unknown}`}
+      className={`px-2 py-1 text-xs font-medium rounded-full ${getApplicationFormColorClass(platform)}`}
     >
       {applicationFormName}
     </span>
@@ -217,6 +217,33 @@ describe("ProfileForm resume privacy", () => {
     });
   });
 
+  it("saves the uploaded resume linked to the profile", async () => {
+    const user = userEvent.setup();
+    mockInvoke
+      .mockResolvedValueOnce(mockProfile({ defaultResumeId: 3 }))
+      .mockResolvedValueOnce([
+        { id: 3, name: "Operations Resume" },
+        { id: 4, name: "Data Resume" },
+      ])
+      .mockResolvedValueOnce(1);
+
+    renderProfileForm();
+
+    const matchingResume = await screen.findByLabelText("Matching resume");
+    expect(matchingResume).toHaveValue("3");
+    await user.selectOptions(matchingResume, "4");
+    await user.click(screen.getByRole("button", { name: "Save Profile" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith(
+        "upsert_application_profile",
+        expect.objectContaining({
+          input: expect.objectContaining({ default_resume_id: 4 }),
+        }),
+      );
+    });
+  });
+
   it("uses plain validation copy for missing required fields", async () => {
     const user = userEvent.setup();
     mockInvoke.mockResolvedValueOnce(mockProfile());
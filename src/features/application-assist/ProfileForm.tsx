@@ -23,6 +23,7 @@ import type {
   ApplicationProfileInput,
   ApplicationResumeFileSelection,
   FormSnapshot,
+  MatchingResumeOption,
   ProfileFormProps,
 } from "./profileModel";
 
@@ -45,9 +46,12 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
   const [selectedResumeFileName, setSelectedResumeFileName] = useState("");
   const [savedResumeFileName, setSavedResumeFileName] = useState("");
   const [resumeFileMarkedForClear, setResumeFileMarkedForClear] = useState(false);
+  const [defaultResumeId, setDefaultResumeId] = useState<number | null>(null);
+  const [matchingResumes, setMatchingResumes] = useState<MatchingResumeOption[] | null>(null);
   const [usWorkAuthorized, setUsWorkAuthorized] = useState(true);
   const [requiresSponsorship, setRequiresSponsorship] = useState(false);
   const [maxApplicationsPerDay, setMaxApplicationsPerDay] = useState(10);
+  const [maxApplicationsPerWeek, setMaxApplicationsPerWeek] = useState<number | null>(null);
   const [requireManualApproval, setRequireManualApproval] = useState(true);
 
   // Track original values for dirty detection
@@ -104,16 +108,29 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
       websiteUrl !== originalValues.websiteUrl ||
       selectedResumeFileToken.trim().length > 0 ||
       resumeFileMarkedForClear ||
+      defaultResumeId !== originalValues.defaultResumeId ||
       usWorkAuthorized !== originalValues.usWorkAuthorized ||
       requiresSponsorship !== originalValues.requiresSponsorship ||
       maxApplicationsPerDay !== originalValues.maxApplicationsPerDay ||
+      maxApplicationsPerWeek !== originalValues.maxApplicationsPerWeek ||
       requireManualApproval !== originalValues.requireManualApproval
     );
-  }, [originalValues, fullName, email, phone, linkedinUrl, githubUrl, portfolioUrl, websiteUrl, selectedResumeFileToken, resumeFileMarkedForClear, usWorkAuthorized, requiresSponsorship, maxApplicationsPerDay, requireManualApproval]);
+  }, [originalValues, fullName, email, phone, linkedinUrl, githubUrl, portfolioUrl, websiteUrl, selectedResumeFileToken, resumeFileMarkedForClear, defaultResumeId, usWorkAuthorized, requiresSponsorship, maxApplicationsPerDay, maxApplicationsPerWeek, requireManualApproval]);
 
   // Compute if form has unsaved changes
   const isDirty = useMemo(() => hasPendingChanges(), [hasPendingChanges]);
 
+  // Uploaded resumes are listed only once a link is shown or asked for
+  const loadMatchingResumes = useCallback(async () => {
+    try {
+      const resumes = await invoke<MatchingResumeOption[]>("list_all_resumes");
+      setMatchingResumes(resumes.map(({ id, name }) => ({ id, name })));
+    } catch (error: unknown) {
+      logError("Could not list resumes:", error);
+      setMatchingResumes([]);
+    }
+  }, []);
+
   const loadProfile = useCallback(async () => {
     try {
       setLoading(true);
@@ -133,9 +150,14 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
         setSelectedResumeFileToken("");
         setSelectedResumeFileName("");
         setResumeFileMarkedForClear(false);
+        setDefaultResumeId(data.defaultResumeId ?? null);
+        if (data.defaultResumeId != null) {
+          void loadMatchingResumes();
+        }
         setUsWorkAuthorized(data.usWorkAuthorized);
         setRequiresSponsorship(data.requiresSponsorship);
         setMaxApplicationsPerDay(data.maxApplicationsPerDay);
+        setMaxApplicationsPerWeek(data.maxApplicationsPerWeek ?? null);
         setRequireManualApproval(data.requireManualApproval);
         // Store original values for dirty detection
         setOriginalValues({
@@ -146,9 +168,11 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
           githubUrl: data.githubUrl || "",
           portfolioUrl: data.portfolioUrl || "",
           websiteUrl: data.websiteUrl || "",
+          defaultResumeId: data.defaultResumeId ?? null,
           usWorkAuthorized: data.usWorkAuthorized,
           requiresSponsorship: data.requiresSponsorship,
           maxApplicationsPerDay: data.maxApplicationsPerDay,
+          maxApplicationsPerWeek: data.maxApplicationsPerWeek ?? null,
           requireManualApproval: data.requireManualApproval,
         });
       } else {
@@ -161,9 +185,11 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
           githubUrl: "",
           portfolioUrl: "",
           websiteUrl: "",
+          defaultResumeId: null,
           usWorkAuthorized: true,
           requiresSponsorship: false,
           maxApplicationsPerDay: 10,
+          maxApplicationsPerWeek: null,
           requireManualApproval: true,
         });
         setSavedResumeFileName("");
@@ -180,7 +206,7 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
     } finally {
       setLoading(false);
     }
-  }, [showError]);
+  }, [showError, loadMatchingResumes]);
 
   useEffect(() => {
     loadProfile();
@@ -262,11 +288,13 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
         github_url: githubUrl.trim() || null,
         portfolio_url: portfolioUrl.trim() || null,
         website_url: websiteUrl.trim() || null,
+        default_resume_id: defaultResumeId,
         resume_file_token: selectedResumeFileToken.trim() || null,
         clear_resume_file: resumeFileMarkedForClear,
         us_work_authorized: usWorkAuthorized,
         requires_sponsorship: requiresSponsorship,
         max_applications_per_day: maxApplicationsPerDay,
+        max_applications_per_week: maxApplicationsPerWeek,
         require_manual_approval: requireManualApproval,
       };
 
@@ -290,9 +318,11 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
         githubUrl: githubUrl.trim(),
         portfolioUrl: portfolioUrl.trim(),
         websiteUrl: websiteUrl.trim(),
+        defaultResumeId,
         usWorkAuthorized,
         requiresSponsorship,
         maxApplicationsPerDay,
+        maxApplicationsPerWeek,
         requireManualApproval,
       });
       onSaved?.();
@@ -317,9 +347,11 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
     selectedResumeFileName,
     savedResumeFileName,
     resumeFileMarkedForClear,
+    defaultResumeId,
     usWorkAuthorized,
     requiresSponsorship,
     maxApplicationsPerDay,
+    maxApplicationsPerWeek,
     requireManualApproval,
     validateField,
     hasPendingChanges,
@@ -412,6 +444,10 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
 
         <ResumeFileSection
           resumeFileLabel={resumeFileLabel}
+          matchingResumes={matchingResumes}
+          defaultResumeId={defaultResumeId}
+          onDefaultResumeChange={setDefaultResumeId}
+          onLoadMatchingResumes={() => void loadMatchingResumes()}
           onSelectResume={handleSelectResume}
           onClearResume={() => {
             setSelectedResumeFileToken("");
@@ -429,8 +465,10 @@ export const ProfileForm = memo(function ProfileForm({ onSaved }: ProfileFormPro
 
         <ReviewPaceSection
           maxApplicationsPerDay={maxApplicationsPerDay}
+          maxApplicationsPerWeek={maxApplicationsPerWeek}
           requireManualApproval={requireManualApproval}
           setMaxApplicationsPerDay={setMaxApplicationsPerDay}
+          setMaxApplicationsPerWeek={setMaxApplicationsPerWeek}
           setRequireManualApproval={setRequireManualApproval}
         />
 
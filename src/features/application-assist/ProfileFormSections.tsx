@@ -1,8 +1,10 @@
 import { Button } from "../../ui/Button";
 import { HelpIcon } from "../../ui/HelpIcon";
 import { Input } from "../../ui/Input";
+import type { MatchingResumeOption } from "./profileModel";
 
 const REVIEW_PACE_OPTIONS = [3, 5, 10, 15] as const;
+const WEEKLY_LIMIT_OPTIONS = [15, 25, 40, 60] as const;
 
 export type ProfileFieldErrors = {
   fullName?: string;
@@ -183,12 +185,21 @@ export function ProfessionalLinksSection({
 
 interface ResumeFileSectionProps {
   resumeFileLabel: string;
+  /** `null` until the uploaded resumes are loaded */
+  matchingResumes: MatchingResumeOption[] | null;
+  defaultResumeId: number | null;
+  onDefaultResumeChange: (resumeId: number | null) => void;
+  onLoadMatchingResumes: () => void;
   onSelectResume: () => void;
   onClearResume: () => void;
 }
 
 export function ResumeFileSection({
   resumeFileLabel,
+  matchingResumes,
+  defaultResumeId,
+  onDefaultResumeChange,
+  onLoadMatchingResumes,
   onSelectResume,
   onClearResume,
 }: ResumeFileSectionProps) {
@@ -232,8 +243,48 @@ export function ResumeFileSection({
         )}
       </div>
       <p className="text-xs text-surface-500 dark:text-surface-400 mt-2">
-        Supported formats: PDF, DOCX, DOC. You choose whether to attach this file while reviewing an application.
+        Supported formats: PDF, DOCX, DOC. Greenhouse and Lever forms get this file attached for your review; on other sites you attach it yourself.
       </p>
+      {matchingResumes === null ? (
+        <button
+          type="button"
+          onClick={onLoadMatchingResumes}
+          className="mt-3 text-sm text-sentinel-600 hover:underline dark:text-sentinel-400"
+        >
+          Link an uploaded resume
+        </button>
+      ) : matchingResumes.length === 0 ? (
+        <p className="mt-3 text-xs text-surface-500 dark:text-surface-400">
+          Upload a resume under Resumes to link it to this profile.
+        </p>
+      ) : (
+        <div className="mt-4 space-y-1">
+          <label
+            htmlFor="matching-resume-select"
+            className="text-surface-700 dark:text-surface-300 text-sm"
+          >
+            Matching resume
+          </label>
+          <select
+            id="matching-resume-select"
+            value={defaultResumeId ?? ""}
+            onChange={(e) =>
+              onDefaultResumeChange(e.target.value ? parseInt(e.target.value) : null)
+            }
+            className="block w-full px-3 py-1.5 text-sm border border-surface-300 dark:border-surface-600 rounded-lg bg-white dark:bg-surface-800 text-surface-900 dark:text-surface-100"
+          >
+            <option value="">None</option>
+            {matchingResumes.map((resume) => (
+              <option key={resume.id} value={resume.id}>
+                {resume.name}
+              </option>
+            ))}
+          </select>
+          <p className="text-xs text-surface-500 dark:text-surface-400">
+            The uploaded resume this file matches. When you prepare a form without choosing a profile, the profile whose resume fits the job best is used.
+          </p>
+        </div>
+      )}
     </section>
   );
 }
@@ -297,20 +348,27 @@ export function WorkAuthorizationSection({
 
 interface ReviewPaceSectionProps {
   maxApplicationsPerDay: number;
+  maxApplicationsPerWeek: number | null;
   requireManualApproval: boolean;
   setMaxApplicationsPerDay: (value: number) => void;
+  setMaxApplicationsPerWeek: (value: number | null) => void;
   setRequireManualApproval: (value: boolean) => void;
 }
 
 export function ReviewPaceSection({
   maxApplicationsPerDay,
+  maxApplicationsPerWeek,
   requireManualApproval,
   setMaxApplicationsPerDay,
+  setMaxApplicationsPerWeek,
   setRequireManualApproval,
 }: ReviewPaceSectionProps) {
   const hasStandardReviewPace = REVIEW_PACE_OPTIONS.some(
     (option) => option === maxApplicationsPerDay,
   );
+  const hasStandardWeeklyLimit =
+    maxApplicationsPerWeek === null ||
+    WEEKLY_LIMIT_OPTIONS.some((option) => option === maxApplicationsPerWeek);
 
   return (
     <section role="group" aria-labelledby="review-settings-heading">
@@ -352,6 +410,37 @@ export function ReviewPaceSection({
             </p>
           )}
         </div>
+        <div className="space-y-2">
+          <div className="flex items-center gap-4">
+            <label htmlFor="max-applications-week-select" className="text-surface-700 dark:text-surface-300 text-sm">
+              Applications per week:
+            </label>
+            <select
+              id="max-applications-week-select"
+              value={maxApplicationsPerWeek ?? ""}
+              onChange={(e) =>
+                setMaxApplicationsPerWeek(e.target.value ? parseInt(e.target.value) : null)
+              }
+              className="px-3 py-1.5 text-sm border border-surface-300 dark:border-surface-600 rounded-lg bg-white dark:bg-surface-800 text-surface-900 dark:text-surface-100"
+              aria-label="Applications per week"
+            >
+              <option value="">No weekly limit</option>
+              {WEEKLY_LIMIT_OPTIONS.map((option) => (
+                <option key={option} value={option}>
+                  {option}
+                </option>
+              ))}
+              {!hasStandardWeeklyLimit && (
+                <option value={maxApplicationsPerWeek}>
+                  {maxApplicationsPerWeek} (current saved limit)
+                </option>
+              )}
+            </select>
+          </div>
+          <p className="text-xs text-surface-500 dark:text-surface-400">
+            JobSentinel stops preparing forms once either limit is reached and tells you when you can continue.
+          </p>
+        </div>
         <label className="flex items-start gap-3 cursor-pointer">
           <input
             type="checkbox"
@@ -0,0 +1,13 @@
+export function SitePermissionNotice({ domain }: { domain: string }) {
+  return (
+    <div className="mt-4 p-4 bg-amber-50 dark:bg-amber-900/20 border border-amber-200 dark:border-amber-800 rounded-lg">
+      <h4 className="text-sm font-medium text-amber-800 dark:text-amber-200">
+        Allow Application Assist on {domain}?
+      </h4>
+      <p className="mt-1 text-sm text-amber-700 dark:text-amber-300">
+        JobSentinel will open this site, fill your saved details, and attach your resume.
+        You still submit the form yourself. You can revoke this at any time.
+      </p>
+    </div>
+  );
+}
@@ -1,5 +1,6 @@
 export interface MockApplicationProfile {
   id: number;
+  name: string;
   fullName: string;
   email: string;
   phone: string | null;
@@ -14,6 +15,7 @@ export interface MockApplicationProfile {
   usWorkAuthorized: boolean;
   requiresSponsorship: boolean;
   maxApplicationsPerDay: number;
+  maxApplicationsPerWeek: number | null;
   requireManualApproval: boolean;
   createdAt: string;
   updatedAt: string;
@@ -34,6 +36,7 @@ export type MockApplicationProfilePreview = Pick<
 
 export type MockApplicationProfileEdit = Pick<
   MockApplicationProfile,
+  | "name"
   | "fullName"
   | "email"
   | "phone"
@@ -46,8 +49,9 @@ export type MockApplicationProfileEdit = Pick<
   | "usWorkAuthorized"
   | "requiresSponsorship"
   | "maxApplicationsPerDay"
+  | "maxApplicationsPerWeek"
   | "requireManualApproval"
->;
+> & { isDefault: boolean };
 
 export interface MockScreeningAnswer {
   id: number;
@@ -105,6 +109,7 @@ export function getDefaultMockApplicationProfile(): MockApplicationProfile {
   const now = "2026-05-19T16:00:00.000Z";
   return {
     id: 1,
+    name: "My profile",
     fullName: "Jordan Lee",
     email: "jordan@example.com",
     phone: "+1 (555) 123-4567",
@@ -119,6 +124,7 @@ export function getDefaultMockApplicationProfile(): MockApplicationProfile {
     usWorkAuthorized: true,
     requiresSponsorship: false,
     maxApplicationsPerDay: 10,
+    maxApplicationsPerWeek: null,
     requireManualApproval: true,
     createdAt: now,
     updatedAt: now,
@@ -168,6 +174,8 @@ export function getMockApplicationProfileEdit(
   if (!profile) return null;
 
   return {
+    name: profile.name,
+    isDefault: true,
     fullName: profile.fullName,
     email: profile.email,
     phone: profile.phone,
@@ -180,6 +188,7 @@ export function getMockApplicationProfileEdit(
     usWorkAuthorized: profile.usWorkAuthorized,
     requiresSponsorship: profile.requiresSponsorship,
     maxApplicationsPerDay: profile.maxApplicationsPerDay,
+    maxApplicationsPerWeek: profile.maxApplicationsPerWeek,
     requireManualApproval: profile.requireManualApproval,
   };
 }
@@ -192,6 +201,7 @@ export function normalizeMockApplicationProfile(
     ...defaults,
     ...value,
     id: typeof value.id === "number" ? value.id : defaults.id,
+    name: typeof value.name === "string" && value.name.trim() ? value.name : defaults.name,
     fullName: typeof value.fullName === "string" ? value.fullName : defaults.fullName,
     email: typeof value.email === "string" ? value.email : defaults.email,
     phone: nullableString(value.phone),
@@ -206,6 +216,7 @@ export function normalizeMockApplicationProfile(
     usWorkAuthorized: typeof value.usWorkAuthorized === "boolean" ? value.usWorkAuthorized : defaults.usWorkAuthorized,
     requiresSponsorship: typeof value.requiresSponsorship === "boolean" ? value.requiresSponsorship : defaults.requiresSponsorship,
     maxApplicationsPerDay: typeof value.maxApplicationsPerDay === "number" ? value.maxApplicationsPerDay : defaults.maxApplicationsPerDay,
+    maxApplicationsPerWeek: nullableNumber(value.maxApplicationsPerWeek),
     requireManualApproval: typeof value.requireManualApproval === "boolean" ? value.requireManualApproval : defaults.requireManualApproval,
     createdAt: typeof value.createdAt === "string" ? value.createdAt : defaults.createdAt,
     updatedAt: typeof value.updatedAt === "string" ? value.updatedAt : defaults.updatedAt,
@@ -223,6 +234,7 @@ export function buildMockApplicationProfileFromInput(
 
   return {
     id: existing.id,
+    name: typeof input.name === "string" && input.name.trim() ? input.name.trim() : existing.name,
     fullName: String(input.full_name ?? ""),
     email: String(input.email ?? ""),
     phone: nullableString(input.phone),
@@ -241,6 +253,7 @@ export function buildMockApplicationProfileFromInput(
     usWorkAuthorized: booleanValue(input.us_work_authorized, true),
     requiresSponsorship: booleanValue(input.requires_sponsorship, false),
     maxApplicationsPerDay: numberValue(input.max_applications_per_day, 10),
+    maxApplicationsPerWeek: nullableNumber(input.max_applications_per_week),
     requireManualApproval: booleanValue(input.require_manual_approval, true),
     createdAt: existing.createdAt,
     updatedAt: now,
@@ -7,14 +7,18 @@ export interface FormSnapshot {
   githubUrl: string;
   portfolioUrl: string;
   websiteUrl: string;
+  defaultResumeId: number | null;
   usWorkAuthorized: boolean;
   requiresSponsorship: boolean;
   maxApplicationsPerDay: number;
+  maxApplicationsPerWeek: number | null;
   requireManualApproval: boolean;
 }
 
 // Types matching the Rust backend
 export interface ApplicationProfile {
+  name: string;
+  isDefault: boolean;
   fullName: string;
   email: string;
   phone: string | null;
@@ -22,15 +26,19 @@ export interface ApplicationProfile {
   githubUrl: string | null;
   portfolioUrl: string | null;
   websiteUrl: string | null;
+  /** Uploaded resume this profile applies with, for resume suggestions */
+  defaultResumeId?: number | null;
   hasResumeFile: boolean;
   resumeFileName: string | null;
   usWorkAuthorized: boolean;
   requiresSponsorship: boolean;
   maxApplicationsPerDay: number;
+  maxApplicationsPerWeek: number | null;
   requireManualApproval: boolean;
 }
 
 export interface ApplicationProfileInput {
+  name?: string | null;
   full_name: string;
   email: string;
   phone?: string | null;
@@ -45,9 +53,38 @@ export interface ApplicationProfileInput {
   us_work_authorized: boolean;
   requires_sponsorship: boolean;
   max_applications_per_day?: number;
+  max_applications_per_week?: number | null;
   require_manual_approval?: boolean;
 }
 
+/** Uploaded resume a profile can be linked to */
+export interface MatchingResumeOption {
+  id: number;
+  name: string;
+}
+
+export interface ApplicationProfileSummary {
+  id: number;
+  name: string;
+  isDefault: boolean;
+  /** Uploaded resume this profile applies with, if linked */
+  defaultResumeId?: number | null;
+}
+
+/** What an automation attempt entered into a form, for review before approval */
+export interface AttemptFillSummary {
+  /** Value entered per field, keyed by field name or question text */
+  filledValues: Record<string, string>;
+  unfilledFields: string[];
+  unansweredQuestions: string[];
+}
+
+/** Base64-encoded PNG screenshots of an automation attempt */
+export interface AttemptScreenshots {
+  review: string | null;
+  confirmation: string | null;
+}
+
 export interface ProfileFormProps {
   onSaved?: () => void;
 }
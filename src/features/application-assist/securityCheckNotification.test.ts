@@ -0,0 +1,39 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { notifySecurityCheck } from "./securityCheckNotification";
+
+vi.mock("@tauri-apps/plugin-notification", () => ({
+  isPermissionGranted: vi.fn(),
+  requestPermission: vi.fn(),
+  sendNotification: vi.fn(),
+}));
+
+const notificationPlugin = await import("@tauri-apps/plugin-notification");
+const isPermissionGranted = vi.mocked(notificationPlugin.isPermissionGranted);
+const requestPermission = vi.mocked(notificationPlugin.requestPermission);
+const sendNotification = vi.mocked(notificationPlugin.sendNotification);
+
+describe("notifySecurityCheck", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("sends a notification without the job or site", async () => {
+    isPermissionGranted.mockResolvedValue(true);
+
+    await notifySecurityCheck();
+
+    expect(sendNotification).toHaveBeenCalledWith({
+      title: "JobSentinel update",
+      body: "The application site asked for a human check. Finish it in the browser, then review the form.",
+    });
+  });
+
+  it("does not ask for permission mid-form", async () => {
+    isPermissionGranted.mockResolvedValue(false);
+
+    await notifySecurityCheck();
+
+    expect(requestPermission).not.toHaveBeenCalled();
+    expect(sendNotification).not.toHaveBeenCalled();
+  });
+});
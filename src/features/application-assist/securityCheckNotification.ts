@@ -0,0 +1,24 @@
+import {
+  isPermissionGranted,
+  sendNotification,
+} from "../../platform/tauri/notifications";
+import { logError } from "../../shared/errorReporting/logger";
+
+/**
+ * Send a desktop notification when a site stops form help for a human check.
+ *
+ * The browser window may be in front of JobSentinel, so the in-app toast can
+ * go unseen. Does not ask for permission mid-form; the toast still shows.
+ */
+export async function notifySecurityCheck(): Promise<void> {
+  try {
+    if (!(await isPermissionGranted())) return;
+
+    sendNotification({
+      title: "JobSentinel update",
+      body: "The application site asked for a human check. Finish it in the browser, then review the form.",
+    });
+  } catch (error: unknown) {
+    logError("Failed to send notification:", error);
+  }
+}
@@ -0,0 +1,24 @@
+import { safeInvoke } from "../../platform/tauri";
+
+interface AutomationPermissionStatus {
+  domain: string;
+  granted: boolean;
+}
+
+// Application Assist only acts on sites the user has allowed once.
+// Returns the domain still waiting for permission, or null when allowed.
+export async function findUngrantedSiteDomain(url: string): Promise<string | null> {
+  try {
+    const permission = await safeInvoke<AutomationPermissionStatus | null>(
+      "check_automation_permission",
+      { url },
+      { silent: true },
+    );
+    if (permission && !permission.granted) {
+      return permission.domain;
+    }
+  } catch {
+    // fill_application_form enforces the grant and reports a clear error
+  }
+  return null;
+}
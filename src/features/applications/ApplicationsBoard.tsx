@@ -88,6 +88,16 @@ const SortableApplicationCard = memo(function SortableApplicationCard({
       >
         {app.company}
       </p>
+      {app.posting_closed_at && (
+        <Badge
+          variant="danger"
+          size="sm"
+          className="mb-2"
+          data-testid="application-posting-closed"
+        >
+          Posting closed
+        </Badge>
+      )}
       <p
         className="text-xs text-surface-400 dark:text-surface-500"
         data-testid="application-date"
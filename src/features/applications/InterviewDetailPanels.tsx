@@ -19,6 +19,7 @@ import {
   SearchIcon,
   UserIcon,
 } from "./InterviewSchedulerIcons";
+import { InterviewRescheduleForm } from "./InterviewRescheduleForm";
 
 interface InterviewDetailPanelsProps {
   completing: boolean;
@@ -29,8 +30,10 @@ interface InterviewDetailPanelsProps {
   onDelete: (interviewId: number) => void;
   onExportICal: (interview: Interview) => void;
   onPrepToggle: (itemId: string) => void;
+  onReschedule: (interview: Interview, scheduledAt: string) => void;
   prepProgress: PrepProgress;
   renderCompanyResearch?: RenderCompanyResearch;
+  rescheduling: boolean;
 }
 
 export function InterviewDetailPanels({
@@ -42,8 +45,10 @@ export function InterviewDetailPanels({
   onDelete,
   onExportICal,
   onPrepToggle,
+  onReschedule,
   prepProgress,
   renderCompanyResearch,
+  rescheduling,
 }: InterviewDetailPanelsProps) {
   const [feedbackNotes, setFeedbackNotes] = useState("");
   const [feedbackOutcome, setFeedbackOutcome] = useState("");
@@ -159,6 +164,17 @@ export function InterviewDetailPanels({
                 )}
               </div>
 
+              {!interview.completed && (
+                <InterviewRescheduleForm
+                  key={interview.scheduled_at}
+                  rescheduling={rescheduling}
+                  scheduledAt={interview.scheduled_at}
+                  onReschedule={(scheduledAt) =>
+                    onReschedule(interview, scheduledAt)
+                  }
+                />
+              )}
+
               <div className="border-t border-surface-200 dark:border-surface-600 pt-4">
                 {!showFeedbackForm ? (
                   <>
@@ -0,0 +1,63 @@
+import { useState } from "react";
+import { Button } from "../../ui/Button";
+
+interface InterviewRescheduleFormProps {
+  rescheduling: boolean;
+  scheduledAt: string;
+  onReschedule: (scheduledAt: string) => void;
+}
+
+export function InterviewRescheduleForm({
+  rescheduling,
+  scheduledAt,
+  onReschedule,
+}: InterviewRescheduleFormProps) {
+  const [open, setOpen] = useState(false);
+  const [newTime, setNewTime] = useState(scheduledAt.slice(0, 16));
+  const unchanged = !newTime || newTime === scheduledAt.slice(0, 16);
+
+  if (!open) {
+    return (
+      <Button variant="secondary" onClick={() => setOpen(true)}>
+        Reschedule
+      </Button>
+    );
+  }
+
+  return (
+    <div className="flex flex-wrap items-end gap-2">
+      <div>
+        <label
+          htmlFor="reschedule-at"
+          className="block text-sm font-medium text-surface-700 dark:text-surface-300 mb-1"
+        >
+          New time
+        </label>
+        <input
+          type="datetime-local"
+          id="reschedule-at"
+          value={newTime}
+          onChange={(e) => setNewTime(e.target.value)}
+          min={new Date().toISOString().slice(0, 16)}
+          className="px-3 py-2 border border-surface-300 dark:border-surface-600 rounded-lg bg-white dark:bg-surface-700 text-surface-900 dark:text-surface-100"
+        />
+      </div>
+      <Button
+        variant="secondary"
+        onClick={() => setOpen(false)}
+        disabled={rescheduling}
+      >
+        Cancel
+      </Button>
+      <Button
+        variant="primary"
+        onClick={() => onReschedule(newTime)}
+        disabled={unchanged}
+        loading={rescheduling}
+        loadingText="Saving..."
+      >
+        Save time
+      </Button>
+    </div>
+  );
+}
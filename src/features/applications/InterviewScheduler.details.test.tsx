@@ -325,6 +325,20 @@ describe("InterviewScheduler", () => {
         expect(screen.getByText("Delete this interview?")).toBeInTheDocument();
       });
     });
+
+    it("shows a new time picker when Reschedule is clicked", async () => {
+      render(<InterviewScheduler onClose={mockOnClose} />);
+
+      await waitFor(() => {
+        expect(screen.getByText("Customer Support Coordinator")).toBeInTheDocument();
+      });
+
+      fireEvent.click(screen.getByText("Customer Support Coordinator"));
+      fireEvent.click(await screen.findByText("Reschedule"));
+
+      expect(screen.getByLabelText("New time")).toBeInTheDocument();
+      expect(screen.getByRole("button", { name: "Save time" })).toBeDisabled();
+    });
   });
 
 });
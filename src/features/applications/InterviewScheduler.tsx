@@ -9,6 +9,7 @@ import { Modal } from "../../ui/Modal";
 import { useToast } from "../../shared/toast/useToast";
 import { getSafeErrorToastCopy } from "../../shared/errorReporting/safeToastCopy";
 import { downloadInterviewICalFile } from "./InterviewCalendarExport";
+import { formatInterviewDate } from "../../shared/dateFormatting";
 import {
   InterviewSchedulerTabs,
   InterviewScheduleList,
@@ -48,6 +49,7 @@ export const InterviewScheduler = memo(function InterviewScheduler({
   const [scheduling, setScheduling] = useState(false);
   const [completing, setCompleting] = useState(false);
   const [deleting, setDeleting] = useState(false);
+  const [rescheduling, setRescheduling] = useState(false);
   const [dateError, setDateError] = useState<string | null>(null);
   const toast = useToast();
 
@@ -306,6 +308,33 @@ export const InterviewScheduler = memo(function InterviewScheduler({
     toast.success("Calendar downloaded", "Add to your calendar app");
   };
 
+  const handleRescheduleInterview = async (
+    interview: Interview,
+    scheduledAt: string,
+  ) => {
+    if (new Date(scheduledAt) < new Date()) {
+      toast.error("Pick a time that has not passed", "Choose a future time.");
+      return;
+    }
+    try {
+      setRescheduling(true);
+      await safeInvokeWithToast("reschedule_interview", {
+        interviewId: interview.id,
+        scheduledAt,
+      }, toast, {
+        logContext: "Reschedule interview"
+      });
+      invalidateCacheByCommand("get_upcoming_interviews");
+      toast.success("Interview moved", formatInterviewDate(scheduledAt));
+      setSelectedInterview(null);
+      fetchInterviews();
+    } catch {
+      // Error already logged and shown to user
+    } finally {
+      setRescheduling(false);
+    }
+  };
+
   const handleDeleteInterview = async (interviewId: number) => {
     try {
       setDeleting(true);
@@ -404,8 +433,10 @@ export const InterviewScheduler = memo(function InterviewScheduler({
           onDelete={handleDeleteInterview}
           onExportICal={handleExportICal}
           onPrepToggle={handlePrepToggle}
+          onReschedule={handleRescheduleInterview}
           prepProgress={prepProgress}
           renderCompanyResearch={renderCompanyResearch}
+          rescheduling={rescheduling}
         />
       )}
     </>
@@ -103,6 +103,6 @@ export const PREP_CHECKLIST = [
   { id: "research", label: "Research company background", icon: "search" },
   { id: "review_jd", label: "Review job description", icon: "doc" },
   { id: "prepare_questions", label: "Prepare questions to ask", icon: "question" },
-  { id: "work_examples", label: "Prepare 2-3 examples from past work", icon: "star" },
+  { id: "star_stories", label: "Prepare 2-3 examples from past work", icon: "star" },
   { id: "tech_review", label: "Review role requirements", icon: "code" },
 ];
@@ -7,6 +7,7 @@ export interface Application {
   applied_at: string | null;
   notes: string | null;
   last_contact: string | null;
+  posting_closed_at?: string | null;
 }
 
 export interface ApplicationsByStatus {
@@ -21,15 +21,20 @@ import type {
 import { useDashboardFilters } from "./hooks/useDashboardFilters";
 import { useDashboardSearch } from "./hooks/useDashboardSearch";
 import { useDashboardJobOps } from "./hooks/useDashboardJobOps";
+import { useDashboardJobRefresh } from "./hooks/useDashboardJobRefresh";
+import { useReminderDueEvents } from "./hooks/useReminderDueEvents";
 import { useDashboardSavedSearches } from "./hooks/useDashboardSavedSearches";
 import { useDashboardAutoRefresh } from "./hooks/useDashboardAutoRefresh";
 import { useDashboardDataLifecycle } from "./hooks/useDashboardDataLifecycle";
 import { useDashboardKeyboard } from "./hooks/useDashboardKeyboard";
 import { useDashboardManualSearch } from "./hooks/useDashboardManualSearch";
+import { useScrapeProgress } from "./hooks/useScrapeProgress";
 import { DashboardFiltersBar } from "./components/DashboardFiltersBar";
 import { DashboardHeader } from "./components/DashboardHeader";
 import { DashboardStats } from "./components/DashboardStats";
+import { ScrapeProgressPanel } from "./components/ScrapeProgressPanel";
 import { DashboardCompareModal } from "./components/DashboardCompareModal";
+import { DashboardJobActions } from "./components/DashboardJobActions";
 import { DashboardJobList } from "./components/DashboardJobList";
 import { DashboardNotesModal } from "./components/DashboardNotesModal";
 import { DashboardSaveSearchModal } from "./components/DashboardSaveSearchModal";
@@ -114,8 +119,14 @@ export default function Dashboard({
     clearSearchHistory,
     showSearchHistory,
     setShowSearchHistory,
+    querySuggestions,
+    loadQuerySuggestions,
+    pinSearchSuggestion,
+    removeSearchSuggestion,
   } = useDashboardSearch();
   const jobOps = useDashboardJobOps(jobs, setJobs);
+  const jobRefresh = useDashboardJobRefresh(setJobs);
+  useReminderDueEvents();
   const savedSearches = useDashboardSavedSearches();
   const { cooldownSeconds, handleSearchNow, searchCooldown, searching } =
     useDashboardManualSearch({
@@ -136,6 +147,7 @@ export default function Dashboard({
     onDataUpdate: handleDataUpdate,
   });
   const { setAutoRefreshEnabled, setAutoRefreshInterval } = autoRefresh;
+  const scrapeProgress = useScrapeProgress();
   const { fetchData, fetchDataRef } = useDashboardDataLifecycle({
     autoRefreshEnabled: autoRefresh.autoRefreshEnabled,
     jobs,
@@ -309,6 +321,8 @@ export default function Dashboard({
       <main className="max-w-7xl mx-auto px-4 py-8 sm:px-6">
         <DashboardStats statistics={statistics} />
 
+        <ScrapeProgressPanel progress={scrapeProgress} />
+
         <DashboardWidgetsSection />
 
         {/* Quick Actions */}
@@ -337,6 +351,10 @@ export default function Dashboard({
             searchHistory={searchHistory}
             addToSearchHistory={addToSearchHistory}
             clearSearchHistory={clearSearchHistory}
+            querySuggestions={querySuggestions}
+            loadQuerySuggestions={loadQuerySuggestions}
+            pinSearchSuggestion={pinSearchSuggestion}
+            removeSearchSuggestion={removeSearchSuggestion}
             sortBy={filters.sortBy}
             setSortBy={filters.setSortBy}
             scoreFilter={filters.scoreFilter}
@@ -398,14 +416,16 @@ export default function Dashboard({
             onToggleBookmark={jobOps.handleToggleBookmark}
             onEditNotes={jobOps.handleEditNotes}
             onResearchCompany={setResearchCompany}
-            renderApplicationAssistAction={
-              renderApplicationAssistAction && onNavigate
-                ? (job) =>
-                    renderApplicationAssistAction(job, () =>
-                      onNavigate("automation"),
-                    )
-                : undefined
-            }
+            onBlockCompany={jobOps.handleBlockCompany}
+            renderApplicationAssistAction={(job) => (
+              <DashboardJobActions
+                job={job}
+                refreshingJobId={jobRefresh.refreshingJobId}
+                onRefreshJob={jobRefresh.handleRefreshJob}
+                renderApplicationAssistAction={renderApplicationAssistAction}
+                onNavigate={onNavigate}
+              />
+            )}
           />
         </section>
       </main>
@@ -444,6 +464,7 @@ export default function Dashboard({
         }}
         onNameChange={savedSearches.setNewSearchName}
         onSave={() => savedSearches.handleSaveSearch(filters.getCurrentFilters)}
+        onUpdateSearchAgent={savedSearches.handleUpdateSearchAgent}
       />
 
       <DashboardDuplicateGroupsModal
@@ -78,7 +78,11 @@ export const DashboardCompareModal = memo(function DashboardCompareModal({
                   <CompareRow
                     label="Listed Pay"
                     values={comparedJobs.map((job) =>
-                      formatDashboardListedPay(job.salary_min, job.salary_max),
+                      formatDashboardListedPay(
+                        job.salary_min,
+                        job.salary_max,
+                        job.pay_period,
+                      ),
                     )}
                   />
                   <CompareRow
@@ -43,6 +43,10 @@ function renderFilters(overrides: Partial<ComponentProps<typeof DashboardFilters
       searchHistory={[]}
       addToSearchHistory={noop}
       clearSearchHistory={noop}
+      querySuggestions={[]}
+      loadQuerySuggestions={noop}
+      pinSearchSuggestion={noop}
+      removeSearchSuggestion={noop}
       sortBy="score-desc"
       setSortBy={noop}
       scoreFilter="all"
@@ -117,6 +121,35 @@ describe("DashboardFiltersBar plain-language actions", () => {
     expect(screen.queryByText(/minus sign/i)).not.toBeInTheDocument();
   });
 
+  it("shows ranked suggestions with pin and remove controls", () => {
+    const loadQuerySuggestions = vi.fn();
+    const pinSearchSuggestion = vi.fn();
+    const removeSearchSuggestion = vi.fn();
+    const setTextSearch = vi.fn();
+    renderFilters({
+      showSearchHistory: true,
+      setTextSearch,
+      loadQuerySuggestions,
+      pinSearchSuggestion,
+      removeSearchSuggestion,
+      querySuggestions: [
+        { text: "care coordinator", source: "history", pinned: true, count: 3, score: 2 },
+        { text: "CareBridge Health", source: "company", pinned: false, count: 1, score: 0.3 },
+      ],
+    });
+
+    expect(loadQuerySuggestions).toHaveBeenCalledWith("");
+    expect(screen.getByText("Recent Searches")).toBeInTheDocument();
+    expect(screen.getByText("Company")).toBeInTheDocument();
+
+    fireEvent.click(screen.getByRole("button", { name: "Unpin search: care coordinator" }));
+    expect(pinSearchSuggestion).toHaveBeenCalledWith("care coordinator", false);
+    fireEvent.click(screen.getByRole("button", { name: "Remove suggestion: CareBridge Health" }));
+    expect(removeSearchSuggestion).toHaveBeenCalledWith("CareBridge Health");
+    fireEvent.click(screen.getByRole("button", { name: "Use search: CareBridge Health" }));
+    expect(setTextSearch).toHaveBeenCalledWith("CareBridge Health");
+  });
+
   it("uses plain download copy for selected jobs", () => {
     renderFilters({
       bulkMode: true,
@@ -14,8 +14,17 @@ import {
   SaveIcon,
   HistoryIcon,
 } from "./DashboardIcons";
-import type { Job, SortOption, ScoreFilter, PostedDateFilter, GhostFilter, SavedSearch } from "../types";
+import type {
+  Job,
+  SortOption,
+  ScoreFilter,
+  PostedDateFilter,
+  GhostFilter,
+  SavedSearch,
+  QuerySuggestion,
+} from "../types";
 import { DashboardBulkActions } from "./DashboardBulkActions";
+import { DashboardSearchSuggestions } from "./DashboardSearchSuggestions";
 
 interface DashboardFiltersBarProps {
   jobs: Job[];
@@ -28,6 +37,10 @@ interface DashboardFiltersBarProps {
   searchHistory: string[];
   addToSearchHistory: (query: string) => void;
   clearSearchHistory: () => void;
+  querySuggestions: QuerySuggestion[];
+  loadQuerySuggestions: (prefix: string) => void;
+  pinSearchSuggestion: (query: string, pinned: boolean) => void;
+  removeSearchSuggestion: (query: string) => void;
   sortBy: SortOption;
   setSortBy: (value: SortOption) => void;
   scoreFilter: ScoreFilter;
@@ -80,6 +93,10 @@ export const DashboardFiltersBar = memo(function DashboardFiltersBar({
   searchHistory,
   addToSearchHistory,
   clearSearchHistory,
+  querySuggestions,
+  loadQuerySuggestions,
+  pinSearchSuggestion,
+  removeSearchSuggestion,
   sortBy,
   setSortBy,
   scoreFilter,
@@ -147,6 +164,13 @@ export const DashboardFiltersBar = memo(function DashboardFiltersBar({
     }
   }, [debouncedSearch, setTextSearch]);
 
+  // Refresh ranked suggestions while the search box is focused
+  useEffect(() => {
+    if (showSearchHistory) {
+      loadQuerySuggestions(debouncedSearch);
+    }
+  }, [showSearchHistory, debouncedSearch, loadQuerySuggestions]);
+
   return (
     <div className="flex flex-col gap-4 mb-4">
       <div className="flex items-center justify-between">
@@ -226,37 +250,51 @@ export const DashboardFiltersBar = memo(function DashboardFiltersBar({
               </button>
             )}
 
-            {/* Search History Dropdown */}
-            {showSearchHistory && searchHistory.length > 0 && !textSearch && (
-              <div className="absolute top-full left-0 right-0 mt-1 bg-white dark:bg-surface-800 border border-surface-200 dark:border-surface-600 rounded-lg shadow-lg z-20 overflow-hidden">
-                <div className="flex items-center justify-between px-3 py-1.5 border-b border-surface-200 dark:border-surface-600">
-                  <span className="text-xs text-surface-500 font-medium">Recent Searches</span>
-                  <button
-                    onClick={clearSearchHistory}
-                    className="text-xs text-surface-400 hover:text-surface-600 dark:hover:text-surface-300"
-                    aria-label="Clear search history"
-                  >
-                    Clear
-                  </button>
+            {/* Search Suggestions Dropdown */}
+            {showSearchHistory && querySuggestions.length > 0 ? (
+              <DashboardSearchSuggestions
+                suggestions={querySuggestions}
+                showingHistory={!localSearch.trim()}
+                onUse={(query) => {
+                  setTextSearch(query);
+                  setShowSearchHistory(false);
+                }}
+                onPin={pinSearchSuggestion}
+                onRemove={removeSearchSuggestion}
+                onClearHistory={clearSearchHistory}
+              />
+            ) : (
+              showSearchHistory && searchHistory.length > 0 && !textSearch && (
+                <div className="absolute top-full left-0 right-0 mt-1 bg-white dark:bg-surface-800 border border-surface-200 dark:border-surface-600 rounded-lg shadow-lg z-20 overflow-hidden">
+                  <div className="flex items-center justify-between px-3 py-1.5 border-b border-surface-200 dark:border-surface-600">
+                    <span className="text-xs text-surface-500 font-medium">Recent Searches</span>
+                    <button
+                      onClick={clearSearchHistory}
+                      className="text-xs text-surface-400 hover:text-surface-600 dark:hover:text-surface-300"
+                      aria-label="Clear search history"
+                    >
+                      Clear
+                    </button>
+                  </div>
+                  <ul className="max-h-40 overflow-y-auto">
+                    {searchHistory.map((query, idx) => (
+                      <li key={idx}>
+                        <button
+                          onClick={() => {
+                            setTextSearch(query);
+                            setShowSearchHistory(false);
+                          }}
+                          className="w-full px-3 py-2 text-left text-sm text-surface-700 dark:text-surface-300 hover:bg-surface-100 dark:hover:bg-surface-700 flex items-center gap-2"
+                          aria-label={`Use search: ${query}`}
+                        >
+                          <HistoryIcon className="w-3 h-3 text-surface-400" />
+                          {query}
+                        </button>
+                      </li>
+                    ))}
+                  </ul>
                 </div>
-                <ul className="max-h-40 overflow-y-auto">
-                  {searchHistory.map((query, idx) => (
-                    <li key={idx}>
-                      <button
-                        onClick={() => {
-                          setTextSearch(query);
-                          setShowSearchHistory(false);
-                        }}
-                        className="w-full px-3 py-2 text-left text-sm text-surface-700 dark:text-surface-300 hover:bg-surface-100 dark:hover:bg-surface-700 flex items-center gap-2"
-                        aria-label={`Use search: ${query}`}
-                      >
-                        <HistoryIcon className="w-3 h-3 text-surface-400" />
-                        {query}
-                      </button>
-                    </li>
-                  ))}
-                </ul>
-              </div>
+              )
             )}
           </div>
 
@@ -0,0 +1,53 @@
+// Per-job actions the dashboard adds beside each job card's own buttons
+
+import { memo } from "react";
+import type { DashboardProps, Job } from "../types";
+
+interface DashboardJobActionsProps {
+  job: Job;
+  refreshingJobId: number | null;
+  onRefreshJob: (id: number) => void;
+  renderApplicationAssistAction?: DashboardProps["renderApplicationAssistAction"];
+  onNavigate?: DashboardProps["onNavigate"];
+}
+
+export const DashboardJobActions = memo(function DashboardJobActions({
+  job,
+  refreshingJobId,
+  onRefreshJob,
+  renderApplicationAssistAction,
+  onNavigate,
+}: DashboardJobActionsProps) {
+  const refreshing = refreshingJobId === job.id;
+
+  return (
+    <>
+      <button
+        onClick={() => onRefreshJob(job.id)}
+        disabled={refreshingJobId !== null}
+        className="p-2 transition-colors text-surface-400 hover:text-sentinel-500 dark:hover:text-sentinel-400 opacity-40 group-hover:opacity-100 focus-visible:opacity-100 disabled:cursor-wait"
+        aria-label={refreshing ? "Refreshing posting" : "Refresh posting"}
+        title="Check the posting for changes now"
+        data-testid="btn-refresh-job"
+      >
+        <svg
+          className={`w-5 h-5 ${refreshing ? "animate-spin" : ""}`}
+          fill="none"
+          viewBox="0 0 24 24"
+          stroke="currentColor"
+          aria-hidden="true"
+        >
+          <path
+            strokeLinecap="round"
+            strokeLinejoin="round"
+            strokeWidth={2}
+            d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15"
+          />
+        </svg>
+      </button>
+      {renderApplicationAssistAction && onNavigate
+        ? renderApplicationAssistAction(job, () => onNavigate("automation"))
+        : null}
+    </>
+  );
+});
@@ -27,6 +27,7 @@ interface DashboardJobListProps {
   onToggleBookmark: (id: number) => void;
   onEditNotes: (id: number, currentNotes?: string | null) => void;
   onResearchCompany: (company: string) => void;
+  onBlockCompany: (company: string) => void;
   renderApplicationAssistAction?: (job: Job) => ReactNode;
 }
 
@@ -51,6 +52,7 @@ export function DashboardJobList({
   onToggleBookmark,
   onEditNotes,
   onResearchCompany,
+  onBlockCompany,
   renderApplicationAssistAction,
 }: DashboardJobListProps) {
   if (jobs.length === 0) {
@@ -199,6 +201,7 @@ export function DashboardJobList({
                 onToggleBookmark={bulkMode ? undefined : onToggleBookmark}
                 onEditNotes={bulkMode ? undefined : onEditNotes}
                 onResearchCompany={bulkMode ? undefined : onResearchCompany}
+                onBlockCompany={bulkMode ? undefined : onBlockCompany}
                 renderApplicationAssistAction={
                   bulkMode ? undefined : renderApplicationAssistAction
                 }
@@ -11,10 +11,12 @@ import {
   formatSortOption,
 } from "./filterLabels";
 import { TrashIcon } from "./DashboardIcons";
+import { DashboardSearchAgentControls } from "./DashboardSearchAgentControls";
 import type {
   PostedDateFilter,
   SavedSearch,
   ScoreFilter,
+  SearchAgentSettings,
   SortOption,
 } from "../types";
 
@@ -40,6 +42,7 @@ interface DashboardSaveSearchModalProps {
   onLoadSearch: (search: SavedSearch) => void;
   onNameChange: (name: string) => void;
   onSave: () => void;
+  onUpdateSearchAgent: (id: string, agent: SearchAgentSettings) => void;
 }
 
 export function DashboardSaveSearchModal({
@@ -52,6 +55,7 @@ export function DashboardSaveSearchModal({
   onLoadSearch,
   onNameChange,
   onSave,
+  onUpdateSearchAgent,
 }: DashboardSaveSearchModalProps) {
   const saveSearchNameId = useId();
 
@@ -127,26 +131,31 @@ export function DashboardSaveSearchModal({
             <p className="text-xs font-medium text-surface-500 dark:text-surface-400 mb-2">
               Saved searches ({savedSearches.length})
             </p>
-            <div className="space-y-1 max-h-32 overflow-y-auto">
+            <div className="space-y-1 max-h-48 overflow-y-auto">
               {savedSearches.map((search) => (
-                <div
-                  key={search.id}
-                  className="flex items-center justify-between px-2 py-1 rounded hover:bg-surface-100 dark:hover:bg-surface-700 group"
-                >
-                  <button
-                    onClick={() => onLoadSearch(search)}
-                    className="text-sm text-surface-600 dark:text-surface-300 hover:text-sentinel-600 dark:hover:text-sentinel-400 text-left flex-1"
-                    aria-label={`Load saved search: ${search.name}`}
+                <div key={search.id}>
+                  <div
+                    className="flex items-center justify-between px-2 py-1 rounded hover:bg-surface-100 dark:hover:bg-surface-700 group"
                   >
-                    {search.name}
-                  </button>
-                  <button
-                    onClick={() => onDeleteSearch(search.id)}
-                    className="p-1 text-surface-400 hover:text-red-500 opacity-0 group-hover:opacity-100 transition-opacity cursor-pointer"
-                    aria-label={`Delete "${search.name}"`}
-                  >
-                    <TrashIcon className="w-4 h-4" />
-                  </button>
+                    <button
+                      onClick={() => onLoadSearch(search)}
+                      className="text-sm text-surface-600 dark:text-surface-300 hover:text-sentinel-600 dark:hover:text-sentinel-400 text-left flex-1"
+                      aria-label={`Load saved search: ${search.name}`}
+                    >
+                      {search.name}
+                    </button>
+                    <button
+                      onClick={() => onDeleteSearch(search.id)}
+                      className="p-1 text-surface-400 hover:text-red-500 opacity-0 group-hover:opacity-100 transition-opacity cursor-pointer"
+                      aria-label={`Delete "${search.name}"`}
+                    >
+                      <TrashIcon className="w-4 h-4" />
+                    </button>
+                  </div>
+                  <DashboardSearchAgentControls
+                    search={search}
+                    onChange={onUpdateSearchAgent}
+                  />
                 </div>
               ))}
             </div>
@@ -0,0 +1,91 @@
+import type {
+  SavedSearch,
+  SearchAgentChannel,
+  SearchAgentFrequency,
+  SearchAgentSettings,
+} from "../types";
+
+const DEFAULT_AGENT: SearchAgentSettings = {
+  enabled: false,
+  channels: [],
+  frequency: "each_scrape",
+};
+
+const AGENT_CHANNELS: Array<{ value: SearchAgentChannel; label: string }> = [
+  { value: "email", label: "Email" },
+  { value: "slack", label: "Slack" },
+  { value: "discord", label: "Discord" },
+];
+
+const AGENT_FREQUENCIES: Array<{ value: SearchAgentFrequency; label: string }> = [
+  { value: "each_scrape", label: "After each scrape" },
+  { value: "daily", label: "Once a day" },
+];
+
+interface DashboardSearchAgentControlsProps {
+  search: SavedSearch;
+  onChange: (id: string, agent: SearchAgentSettings) => void;
+}
+
+/** Turns a saved search into a job agent that alerts on new matches. */
+export function DashboardSearchAgentControls({
+  search,
+  onChange,
+}: DashboardSearchAgentControlsProps) {
+  const agent = search.agent ?? DEFAULT_AGENT;
+  const update = (changes: Partial<SearchAgentSettings>) =>
+    onChange(search.id, { ...agent, ...changes });
+  const toggleChannel = (channel: SearchAgentChannel, checked: boolean) =>
+    update({
+      channels: checked
+        ? [...agent.channels, channel]
+        : agent.channels.filter((value) => value !== channel),
+    });
+
+  return (
+    <div
+      className="px-2 pb-1 text-xs text-surface-500 dark:text-surface-400"
+      data-testid={`search-agent-${search.id}`}
+    >
+      <label className="flex items-center gap-2">
+        <input
+          type="checkbox"
+          checked={agent.enabled}
+          onChange={(event) => update({ enabled: event.target.checked })}
+          className="rounded border-surface-300 text-sentinel-600 focus:ring-sentinel-500"
+        />
+        <span>Alert me about new matches</span>
+      </label>
+      {agent.enabled && (
+        <div className="mt-1 ml-6 flex flex-wrap items-center gap-3">
+          <select
+            value={agent.frequency}
+            onChange={(event) =>
+              update({ frequency: event.target.value as SearchAgentFrequency })
+            }
+            aria-label={`How often to alert for "${search.name}"`}
+            className="px-2 py-0.5 rounded border border-surface-200 dark:border-surface-600 bg-white dark:bg-surface-700"
+          >
+            {AGENT_FREQUENCIES.map((option) => (
+              <option key={option.value} value={option.value}>
+                {option.label}
+              </option>
+            ))}
+          </select>
+          {AGENT_CHANNELS.map((channel) => (
+            <label key={channel.value} className="flex items-center gap-1">
+              <input
+                type="checkbox"
+                checked={agent.channels.includes(channel.value)}
+                onChange={(event) => toggleChannel(channel.value, event.target.checked)}
+                className="rounded border-surface-300 text-sentinel-600 focus:ring-sentinel-500"
+              />
+              <span>{channel.label}</span>
+            </label>
+          ))}
+          {agent.channels.length === 0 && <span>All enabled channels</span>}
+        </div>
+      )}
+    </div>
+  );
+}
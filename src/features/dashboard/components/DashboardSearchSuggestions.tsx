@@ -0,0 +1,94 @@
+import type { MouseEvent } from "react";
+import { HistoryIcon } from "./DashboardIcons";
+import type { QuerySuggestion, QuerySuggestionSource } from "../types";
+
+const SOURCE_LABELS: Record<QuerySuggestionSource, string> = {
+  history: "Recent",
+  job_title: "Job title",
+  company: "Company",
+};
+
+interface DashboardSearchSuggestionsProps {
+  suggestions: QuerySuggestion[];
+  /** True when the search box is empty, so only history is shown */
+  showingHistory: boolean;
+  onUse: (query: string) => void;
+  onPin: (query: string, pinned: boolean) => void;
+  onRemove: (query: string) => void;
+  onClearHistory: () => void;
+}
+
+// Keep focus in the search box so pinning or removing leaves the list open
+const keepSearchFocus = (event: MouseEvent) => event.preventDefault();
+
+/** Ranked search suggestions with pin and remove controls. */
+export function DashboardSearchSuggestions({
+  suggestions,
+  showingHistory,
+  onUse,
+  onPin,
+  onRemove,
+  onClearHistory,
+}: DashboardSearchSuggestionsProps) {
+  return (
+    <div
+      className="absolute top-full left-0 right-0 min-w-64 mt-1 bg-white dark:bg-surface-800 border border-surface-200 dark:border-surface-600 rounded-lg shadow-lg z-20 overflow-hidden"
+      data-testid="search-suggestions"
+    >
+      <div className="flex items-center justify-between px-3 py-1.5 border-b border-surface-200 dark:border-surface-600">
+        <span className="text-xs text-surface-500 font-medium">
+          {showingHistory ? "Recent Searches" : "Suggestions"}
+        </span>
+        {showingHistory && (
+          <button
+            onClick={onClearHistory}
+            className="text-xs text-surface-400 hover:text-surface-600 dark:hover:text-surface-300"
+            aria-label="Clear search history"
+          >
+            Clear
+          </button>
+        )}
+      </div>
+      <ul className="max-h-48 overflow-y-auto">
+        {suggestions.map((suggestion) => (
+          <li key={suggestion.text} className="flex items-center group">
+            <button
+              onClick={() => onUse(suggestion.text)}
+              className="flex-1 min-w-0 px-3 py-2 text-left text-sm text-surface-700 dark:text-surface-300 hover:bg-surface-100 dark:hover:bg-surface-700 flex items-center gap-2"
+              aria-label={`Use search: ${suggestion.text}`}
+            >
+              <HistoryIcon className="w-3 h-3 shrink-0 text-surface-400" />
+              <span className="truncate">{suggestion.text}</span>
+              {suggestion.source !== "history" && (
+                <span className="ml-auto shrink-0 text-xs text-surface-400">
+                  {SOURCE_LABELS[suggestion.source]}
+                </span>
+              )}
+            </button>
+            <button
+              onMouseDown={keepSearchFocus}
+              onClick={() => onPin(suggestion.text, !suggestion.pinned)}
+              className={`px-1.5 text-xs ${
+                suggestion.pinned
+                  ? "text-sentinel-600 dark:text-sentinel-400"
+                  : "text-surface-400 opacity-0 group-hover:opacity-100 focus:opacity-100"
+              }`}
+              aria-label={`${suggestion.pinned ? "Unpin" : "Pin"} search: ${suggestion.text}`}
+              aria-pressed={suggestion.pinned}
+            >
+              {suggestion.pinned ? "Pinned" : "Pin"}
+            </button>
+            <button
+              onMouseDown={keepSearchFocus}
+              onClick={() => onRemove(suggestion.text)}
+              className="px-2 text-surface-400 hover:text-surface-600 dark:hover:text-surface-300 opacity-0 group-hover:opacity-100 focus:opacity-100"
+              aria-label={`Remove suggestion: ${suggestion.text}`}
+            >
+              ×
+            </button>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
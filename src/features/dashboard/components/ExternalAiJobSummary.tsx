@@ -11,6 +11,7 @@ import {
 } from "../../../shared/externalAi/externalAiBackendTransport";
 import type { ExternalAiRequest } from "../../../shared/externalAi/externalAiTypes";
 import { formatSalaryRange } from "../jobDisplayFormatting";
+import type { PayPeriod } from "../types";
 
 interface ExternalAiSummaryJob {
   id: number;
@@ -24,6 +25,7 @@ interface ExternalAiSummaryJob {
   description?: string | null;
   salary_min?: number | null;
   salary_max?: number | null;
+  pay_period?: PayPeriod | null;
   remote?: boolean | null;
 }
 
@@ -54,7 +56,11 @@ function buildPublicJobPayload(job: ExternalAiSummaryJob): Record<string, unknow
     jobId: String(job.id),
     firstSeenAt: job.created_at,
   };
-  const salaryRange = formatSalaryRange(job.salary_min, job.salary_max);
+  const salaryRange = formatSalaryRange(
+    job.salary_min,
+    job.salary_max,
+    job.pay_period,
+  );
 
   appendString(payload, "location", job.location);
   appendString(payload, "description", job.description);
@@ -110,6 +110,28 @@ describe("JobCard actions and accessibility", () => {
     });
   });
 
+  describe("block company button", () => {
+    it("blocks the job's company when clicked", async () => {
+      const user = userEvent.setup();
+      const onBlockCompany = vi.fn();
+      renderWithToast(
+        <JobCard job={mockJob} onBlockCompany={onBlockCompany} />,
+      );
+
+      await user.click(screen.getByTestId("btn-block-company"));
+
+      expect(onBlockCompany).toHaveBeenCalledWith("CareBridge Services");
+    });
+
+    it("is hidden without a handler", () => {
+      renderWithToast(<JobCard job={mockJob} />);
+
+      expect(
+        screen.queryByTestId("btn-block-company"),
+      ).not.toBeInTheDocument();
+    });
+  });
+
   describe("selected state", () => {
     it("applies selected styles when isSelected is true", () => {
       renderWithToast(<JobCard job={mockJob} isSelected={true} />);
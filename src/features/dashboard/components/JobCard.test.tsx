@@ -178,6 +178,44 @@ describe("JobCard", () => {
       expect(await screen.findByRole("button", { name: /prepare form/i })).toBeInTheDocument();
     });
 
+    it("names the best-fitting resume when several are uploaded", async () => {
+      mockInvoke.mockImplementation((cmd) => {
+        if (cmd === "suggest_resume_for_job") {
+          return Promise.resolve({
+            job_hash: "resume-suggestion-job",
+            recommended_resume_id: 2,
+            candidates: [
+              {
+                resume_id: 2,
+                name: "Support Lead Resume",
+                is_active: false,
+                overall_match_score: 0.78,
+                skills_match_score: 0.78,
+                matching_skills: ["Zendesk"],
+                missing_skills: [],
+              },
+              {
+                resume_id: 1,
+                name: "General Resume",
+                is_active: true,
+                overall_match_score: 0.41,
+                skills_match_score: 0.41,
+                matching_skills: [],
+                missing_skills: ["Zendesk"],
+              },
+            ],
+          });
+        }
+        return Promise.resolve(null);
+      });
+
+      renderWithToast(<JobCard job={{ ...mockJob, hash: "resume-suggestion-job" }} />);
+
+      expect(await screen.findByTestId("resume-suggestion-hint")).toHaveTextContent(
+        "Best resume for this job: Support Lead Resume (78% match)",
+      );
+    });
+
     it("truncates long descriptions", () => {
       const longDesc = "A".repeat(200);
       const jobWithLongDesc = { ...mockJob, description: longDesc };
@@ -1,6 +1,7 @@
 import { useEffect, useState, memo, type ReactNode } from "react";
 import { ScoreDisplay } from "../../../ui/score-display/ScoreDisplay";
 import { GhostIndicatorCompact } from "./GhostIndicator";
+import { safeInvoke } from "../../../platform/tauri";
 import { ExternalAiJobSummary } from "./ExternalAiJobSummary";
 import {
   JobFitFeedbackControls,
@@ -31,6 +32,7 @@ import {
 } from "../../../shared/browserAssistLearning";
 import {
   ArrowIcon,
+  BlockIcon,
   BookmarkIcon,
   ClockIcon,
   DuplicateIcon,
@@ -62,6 +64,7 @@ interface JobCardProps {
   onToggleBookmark?: (id: number) => void;
   onEditNotes?: (id: number, currentNotes?: string | null) => void;
   onResearchCompany?: (company: string) => void;
+  onBlockCompany?: (company: string) => void;
   renderApplicationAssistAction?: (job: Job) => ReactNode;
   isSelected?: boolean;
   salaryFloorUsd?: number | null;
@@ -74,6 +77,7 @@ export const JobCard = memo(function JobCard({
   onToggleBookmark,
   onEditNotes,
   onResearchCompany,
+  onBlockCompany,
   renderApplicationAssistAction,
   isSelected = false,
   salaryFloorUsd,
@@ -152,7 +156,7 @@ export const JobCard = memo(function JobCard({
   const displayedScoreValue = hasValidScore ? displayedScore : job.score;
   const isHighMatch = displayedScore >= STRONG_JOB_MATCH_THRESHOLD;
   const isGoodMatch = displayedScore >= GOOD_JOB_MATCH_THRESHOLD;
-  const salaryText = formatSalaryRange(job.salary_min, job.salary_max);
+  const salaryText = formatSalaryRange(job.salary_min, job.salary_max, job.pay_period);
   const descSnippet = truncateJobDescription(job.description);
   const rawPostingRiskScore = job.ghost_score;
   const postingRiskScore =
@@ -207,6 +211,15 @@ export const JobCard = memo(function JobCard({
     salaryRangeQualityGuidance ? `, ${salaryRangeQualityGuidance.ariaLabel}` : ""
   }`;
 
+  // Teaches future scores which search words matter; the card works without it.
+  const recordScoreFeedback = (feedback: "good" | "bad" | null) => {
+    safeInvoke(
+      "record_job_feedback",
+      { jobId: job.id, feedback },
+      { logContext: "Record job feedback", silent: true },
+    ).catch(() => undefined);
+  };
+
   const updateJobFeedback = (
     verdict: JobFeedbackVerdict,
     mode: "set" | "toggle" = "toggle",
@@ -215,6 +228,7 @@ export const JobCard = memo(function JobCard({
     if (mode === "toggle" && jobFeedback?.verdict === verdict) {
       clearJobFeedbackSignal(jobFeedbackKey);
       setJobFeedback(null);
+      recordScoreFeedback(null);
       return;
     }
 
@@ -228,6 +242,8 @@ export const JobCard = memo(function JobCard({
       }),
     );
 
+    recordScoreFeedback(verdict === "useful" ? "good" : "bad");
+
     if (recordLearning) {
       recordBrowserAssistLearningSignalIfEnabled({
         source: "job-feedback",
@@ -325,6 +341,8 @@ export const JobCard = memo(function JobCard({
               <JobCardGuidancePanels
                 feedbackScoreAdjustment={feedbackScoreAdjustment}
                 hasSafeJobUrl={hasSafeJobUrl}
+                jobHash={job.hash}
+                jobId={job.id}
                 onOpenJob={openJobPosting}
                 payFloorGuidance={payFloorGuidance}
                 payTransparencyGuidance={payTransparencyGuidance}
@@ -417,6 +435,21 @@ export const JobCard = memo(function JobCard({
                 </button>
               )}
 
+              {onBlockCompany && (
+                <button
+                  onClick={() => onBlockCompany(job.company)}
+                  onKeyDown={(e) =>
+                    handleKeyDown(e, () => onBlockCompany(job.company))
+                  }
+                  className="p-2 text-surface-400 hover:text-red-500 dark:hover:text-red-400 opacity-40 group-hover:opacity-100 focus-visible:opacity-100 transition-colors"
+                  aria-label={`Block ${job.company}`}
+                  title="Block company: lower its scores and stop its alerts"
+                  data-testid="btn-block-company"
+                >
+                  <BlockIcon />
+                </button>
+              )}
+
               {onEditNotes && (
                 <button
                   onClick={() => onEditNotes(job.id, job.notes)}
@@ -9,10 +9,14 @@ import type {
   ScamRiskGuidance,
 } from "./jobCardGuidance";
 import { ArrowIcon, RiskIcon, SalaryIcon, SourceIcon } from "./JobCardIcons";
+import { ResumeSuggestionHint } from "./ResumeSuggestionHint";
+import { TimeToFillHint } from "./TimeToFillHint";
 
 interface JobCardGuidancePanelsProps {
   feedbackScoreAdjustment: JobFeedbackScoreAdjustment | null;
   hasSafeJobUrl: boolean;
+  jobHash?: string;
+  jobId: number;
   onOpenJob: () => void;
   payFloorGuidance: PayFloorGuidance | null;
   payTransparencyGuidance: PayTransparencyGuidance | null;
@@ -25,6 +29,8 @@ interface JobCardGuidancePanelsProps {
 export function JobCardGuidancePanels({
   feedbackScoreAdjustment,
   hasSafeJobUrl,
+  jobHash,
+  jobId,
   onOpenJob,
   payFloorGuidance,
   payTransparencyGuidance,
@@ -45,6 +51,9 @@ export function JobCardGuidancePanels({
 
   return (
     <>
+      <TimeToFillHint jobId={jobId} />
+      {jobHash && <ResumeSuggestionHint jobHash={jobHash} />}
+
       {scamRiskGuidance && (
         <div
           data-testid="scam-risk-guidance"
@@ -190,6 +190,25 @@ export const ResearchIcon = memo(function ResearchIcon() {
   );
 });
 
+export const BlockIcon = memo(function BlockIcon() {
+  return (
+    <svg
+      className="w-5 h-5"
+      fill="none"
+      viewBox="0 0 24 24"
+      stroke="currentColor"
+      aria-hidden="true"
+    >
+      <path
+        strokeLinecap="round"
+        strokeLinejoin="round"
+        strokeWidth={1.5}
+        d="M18.364 18.364A9 9 0 005.636 5.636m12.728 12.728A9 9 0 015.636 5.636m12.728 12.728L5.636 5.636"
+      />
+    </svg>
+  );
+});
+
 export const RiskIcon = memo(function RiskIcon({ className = "" }: { className?: string }) {
   return (
     <svg
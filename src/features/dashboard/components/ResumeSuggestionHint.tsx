@@ -0,0 +1,48 @@
+import { useEffect, useState } from "react";
+import { cachedInvoke } from "../../../platform/tauri";
+import {
+  describeResumeRecommendation,
+  getRecommendedResume,
+  type ResumeSuggestion,
+} from "../../../shared/resumeSuggestion";
+
+const RESUME_SUGGESTION_CACHE_TTL_MS = 10 * 60_000;
+
+export function ResumeSuggestionHint({ jobHash }: { jobHash: string }) {
+  const [suggestion, setSuggestion] = useState<ResumeSuggestion | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    cachedInvoke<ResumeSuggestion>(
+      "suggest_resume_for_job",
+      { jobHash },
+      RESUME_SUGGESTION_CACHE_TTL_MS,
+    )
+      .then((result) => {
+        if (!cancelled) setSuggestion(result ?? null);
+      })
+      .catch(() => {
+        // The hint is optional; a failed lookup just leaves it hidden.
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [jobHash]);
+
+  const recommended = getRecommendedResume(suggestion);
+  if (!recommended) return null;
+
+  return (
+    <p
+      data-testid="resume-suggestion-hint"
+      className="mb-2 text-xs text-surface-500 dark:text-surface-400"
+      title={
+        recommended.missing_skills.length > 0
+          ? `Missing: ${recommended.missing_skills.slice(0, 5).join(", ")}`
+          : undefined
+      }
+    >
+      {describeResumeRecommendation(recommended)}
+    </p>
+  );
+}
@@ -0,0 +1,67 @@
+// Live scraping progress
+// Shows one row per source while a job check runs, fed by scrape:* events
+
+import { memo } from "react";
+import { Card } from "../../../ui/Card";
+import { Progress, ProgressIndeterminate } from "../../../ui/Progress";
+import { formatJobSourceLabel } from "../../../shared/jobSourceGuidance";
+import type { ScrapeProgressState, ScrapeSourceProgress } from "../hooks/useScrapeProgress";
+
+interface ScrapeProgressPanelProps {
+  progress: ScrapeProgressState;
+}
+
+const STATUS_LABELS: Record<ScrapeSourceProgress["status"], string> = {
+  running: "Checking",
+  complete: "Done",
+  failed: "Problem",
+};
+
+function sourceSummary(source: ScrapeSourceProgress) {
+  if (source.status === "running") return STATUS_LABELS.running;
+  return `${STATUS_LABELS[source.status]} · ${source.jobs_found} found, ${source.jobs_new} new, ${source.jobs_updated} updated`;
+}
+
+export const ScrapeProgressPanel = memo(function ScrapeProgressPanel({
+  progress,
+}: ScrapeProgressPanelProps) {
+  if (!progress.active || progress.sourcesTotal === 0) return null;
+
+  return (
+    <Card className="mb-6 dark:bg-surface-800" aria-live="polite">
+      <div className="flex items-center justify-between mb-2">
+        <p className="text-sm font-medium text-surface-700 dark:text-surface-300">
+          Checking job sources
+        </p>
+        <p className="text-sm font-mono text-surface-600 dark:text-surface-400">
+          {progress.sourcesCompleted} of {progress.sourcesTotal}
+        </p>
+      </div>
+      <Progress value={progress.sourcesCompleted} max={progress.sourcesTotal} />
+      <p className="mt-2 text-xs text-surface-500 dark:text-surface-400">
+        {progress.totals.jobs_found} found, {progress.totals.jobs_new} new,{" "}
+        {progress.totals.jobs_updated} updated so far
+      </p>
+      <ul className="mt-3 space-y-2">
+        {progress.sources.map((source) => (
+          <li key={source.source}>
+            <div className="flex justify-between text-xs text-surface-600 dark:text-surface-400 mb-1">
+              <span>{formatJobSourceLabel(source.source)}</span>
+              <span>{sourceSummary(source)}</span>
+            </div>
+            {source.status === "running" ? (
+              <ProgressIndeterminate size="sm" />
+            ) : (
+              <Progress
+                value={1}
+                max={1}
+                size="sm"
+                variant={source.status === "failed" ? "danger" : "success"}
+              />
+            )}
+          </li>
+        ))}
+      </ul>
+    </Card>
+  );
+});
@@ -0,0 +1,40 @@
+import { useEffect, useState } from "react";
+import { cachedInvoke } from "../../../platform/tauri";
+import { describeTimeToFillBasis, formatTimeToFill } from "../timeToFillCopy";
+import type { TimeToFillEstimate } from "../types";
+
+const TIME_TO_FILL_CACHE_TTL_MS = 10 * 60_000;
+
+export function TimeToFillHint({ jobId }: { jobId: number }) {
+  const [estimate, setEstimate] = useState<TimeToFillEstimate | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    cachedInvoke<TimeToFillEstimate | null>(
+      "get_time_to_fill_estimate",
+      { jobId },
+      TIME_TO_FILL_CACHE_TTL_MS,
+    )
+      .then((result) => {
+        if (!cancelled) setEstimate(result);
+      })
+      .catch(() => {
+        // The hint is optional; a failed lookup just leaves it hidden.
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [jobId]);
+
+  if (!estimate) return null;
+
+  return (
+    <p
+      data-testid="time-to-fill-hint"
+      className="mb-2 text-xs text-surface-500 dark:text-surface-400"
+      title={describeTimeToFillBasis(estimate)}
+    >
+      {formatTimeToFill(estimate)}
+    </p>
+  );
+}
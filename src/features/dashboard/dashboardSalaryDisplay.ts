@@ -1,8 +1,10 @@
 import { formatSalaryRange } from "./jobDisplayFormatting";
+import type { PayPeriod } from "./types";
 
 export function formatDashboardListedPay(
   salaryMin?: number | null,
   salaryMax?: number | null,
+  payPeriod?: PayPeriod | null,
 ): string {
-  return formatSalaryRange(salaryMin, salaryMax) ?? "Not listed";
+  return formatSalaryRange(salaryMin, salaryMax, payPeriod) ?? "Not listed";
 }
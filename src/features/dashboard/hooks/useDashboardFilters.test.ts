@@ -59,6 +59,19 @@ describe("useDashboardFilters — score edge cases", () => {
       expect(result.current.filteredAndSortedJobs.map((j) => j.id)).toEqual([1]);
     });
 
+    it("annualizes hourly and day rates before comparing", () => {
+      const jobs: Job[] = [
+        makeJob({ id: 1, salary_min: 40, salary_max: 45, pay_period: "hour" }),
+        makeJob({ id: 2, salary_min: 150, salary_max: 200, pay_period: "day" }),
+      ];
+
+      const { result } = renderHook(() => useDashboardFilters(jobs));
+
+      act(() => result.current.setSalaryMinFilter(60000));
+
+      expect(result.current.filteredAndSortedJobs.map((j) => j.id)).toEqual([1]);
+    });
+
     it("keeps minimum-only pay visible when top listed pay is unknown", () => {
       const jobs: Job[] = [
         makeJob({
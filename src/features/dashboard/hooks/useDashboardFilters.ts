@@ -12,6 +12,7 @@ import type {
 } from "../types";
 import { GOOD_JOB_MATCH_THRESHOLD } from "../../../shared/jobMatchScore";
 import { hasPostingReviewAlert } from "../postingRisk";
+import { annualizeSalary } from "../jobDisplayFormatting";
 
 // Coerce score to a finite number for safe comparison (null/NaN/Infinity → -1)
 const safeScore = (s: number | null | undefined): number =>
@@ -41,7 +42,10 @@ function getListedSalaryBounds(
     return null;
   }
 
-  return { min, max };
+  return {
+    min: min === undefined ? undefined : annualizeSalary(min, job.pay_period),
+    max: max === undefined ? undefined : annualizeSalary(max, job.pay_period),
+  };
 }
 
 // Sort comparators lookup (better performance than switch)
@@ -249,7 +253,8 @@ export function useDashboardFilters(jobs: Job[]): FilterState &
         const bounds = getListedSalaryBounds(job);
         if (bounds === null) return false;
 
-        // Salary filters use full yearly dollars, matching job salary storage.
+        // Salary filters use full yearly dollars; hourly and day rates are
+        // annualized the same way the backend scores them.
         if (salaryMinFilter !== null) {
           if (bounds.max !== undefined && bounds.max < salaryMinFilter) return false;
         }
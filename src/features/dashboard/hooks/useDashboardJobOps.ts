@@ -1,5 +1,6 @@
 // Dashboard Job Operations Hook
-// Manages hide, bookmark, notes, bulk operations, duplicates, and comparison
+// Manages hide, company blocking, bookmark, notes, bulk operations, duplicates,
+// and comparison
 
 import { useState, useCallback } from "react";
 import type { Job } from "../types";
@@ -92,6 +93,61 @@ export function useDashboardJobOps(
     [jobs, setJobs, toast, pushAction],
   );
 
+  // Blocked companies score zero for company fit and never send alerts.
+  // Saved jobs are re-scored in the background, so refresh cached lists.
+  const handleBlockCompany = useCallback(
+    async (company: string) => {
+      const invalidateScores = () => {
+        invalidateCacheByCommand("get_config");
+        invalidateCacheByCommand("get_recent_jobs");
+        invalidateCacheByCommand("get_statistics");
+      };
+
+      try {
+        await safeInvokeWithToast("block_company", { company }, toast, {
+          logContext: "Block company",
+        });
+        invalidateScores();
+        toast.success(
+          "Company blocked",
+          `Jobs from ${company} will score lower and won't send alerts.`,
+        );
+
+        pushAction({
+          type: "hide",
+          description: `Blocked: ${company}`,
+          undo: async () => {
+            try {
+              await invoke("unblock_company", { company });
+              invalidateScores();
+            } catch (err) {
+              logError("Failed to undo company block:", err);
+              toast.error(
+                "Could not undo change",
+                "Company is still blocked. Remove it in Settings, then copy a safe support report if this keeps happening.",
+              );
+            }
+          },
+          redo: async () => {
+            try {
+              await invoke("block_company", { company });
+              invalidateScores();
+            } catch (err) {
+              logError("Failed to redo company block:", err);
+              toast.error(
+                "Could not redo change",
+                "Company was not blocked again. Check Settings, then copy a safe support report if this keeps happening.",
+              );
+            }
+          },
+        });
+      } catch {
+        // Error already logged and shown to user
+      }
+    },
+    [toast, pushAction],
+  );
+
   const handleToggleBookmark = useCallback(
     async (id: number) => {
       const job = jobs.find((j) => j.id === id);
@@ -433,6 +489,7 @@ export function useDashboardJobOps(
 
     // Job operations
     handleHideJob,
+    handleBlockCompany,
     handleToggleBookmark,
 
     // Bulk selection state
@@ -0,0 +1,136 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { renderHook, act } from "@testing-library/react";
+import { useDashboardJobRefresh } from "./useDashboardJobRefresh";
+import type { Job } from "../types";
+import {
+  invalidateCacheByCommand,
+  safeInvokeWithToast,
+} from "../../../platform/tauri";
+import { listen } from "../../../platform/tauri/events";
+import { notifyPostingClosed } from "../notifications";
+
+const mockToast = {
+  error: vi.fn(),
+  success: vi.fn(),
+  info: vi.fn(),
+  warning: vi.fn(),
+};
+vi.mock("../../../shared/toast/useToast", () => ({ useToast: () => mockToast }));
+
+vi.mock("../../../platform/tauri", () => ({
+  invalidateCacheByCommand: vi.fn(),
+  safeInvokeWithToast: vi.fn(),
+}));
+
+vi.mock("../../../platform/tauri/events", () => ({
+  listen: vi.fn(() => Promise.resolve(() => undefined)),
+}));
+
+vi.mock("../notifications", () => ({
+  notifyPostingClosed: vi.fn(() => Promise.resolve()),
+}));
+
+const mockSafeInvokeWithToast = vi.mocked(safeInvokeWithToast);
+const mockListen = vi.mocked(listen);
+
+function makeJob(overrides: Partial<Job> = {}): Job {
+  return {
+    id: 1,
+    title: "Care Coordinator",
+    company: "Example Health",
+    location: "Remote",
+    url: "https://example.com/job/1",
+    source: "greenhouse",
+    score: 0.6,
+    created_at: new Date().toISOString(),
+    ...overrides,
+  };
+}
+
+beforeEach(() => {
+  vi.clearAllMocks();
+});
+
+describe("handleRefreshJob", () => {
+  it("replaces the job with the refreshed posting and names changed fields", async () => {
+    const setJobs = vi.fn();
+    const { result } = renderHook(() => useDashboardJobRefresh(setJobs));
+    mockSafeInvokeWithToast.mockResolvedValueOnce({
+      job: makeJob({ title: "Senior Care Coordinator", score: 0.8 }),
+      changes: [
+        {
+          field: "title",
+          before: "Care Coordinator",
+          after: "Senior Care Coordinator",
+        },
+        { field: "salary_min", before: null, after: "90000" },
+      ],
+      refreshed_at: new Date().toISOString(),
+    });
+
+    await act(async () => {
+      await result.current.handleRefreshJob(1);
+    });
+
+    expect(mockSafeInvokeWithToast).toHaveBeenCalledWith(
+      "refresh_job",
+      { jobId: 1 },
+      mockToast,
+      { logContext: "Refresh job posting" },
+    );
+    const update = setJobs.mock.calls[0][0] as (prev: Job[]) => Job[];
+    const updated = update([makeJob(), makeJob({ id: 2 })]);
+    expect(updated[0].title).toBe("Senior Care Coordinator");
+    expect(updated[1].title).toBe("Care Coordinator");
+    expect(mockToast.info).toHaveBeenCalledWith(
+      "Posting refreshed",
+      "Updated title, salary min",
+    );
+    expect(result.current.refreshingJobId).toBeNull();
+  });
+
+  it("keeps the list unchanged when the refresh fails", async () => {
+    const setJobs = vi.fn();
+    const { result } = renderHook(() => useDashboardJobRefresh(setJobs));
+    mockSafeInvokeWithToast.mockRejectedValueOnce(new Error("gone"));
+
+    await act(async () => {
+      await result.current.handleRefreshJob(1);
+    });
+
+    expect(setJobs).not.toHaveBeenCalled();
+    expect(result.current.refreshingJobId).toBeNull();
+  });
+});
+
+describe("posting closed events", () => {
+  it("warns and sends a desktop notification for to-apply postings", () => {
+    renderHook(() => useDashboardJobRefresh(vi.fn()));
+    const [eventName, handler] = mockListen.mock.calls[0];
+    expect(eventName).toBe("application:posting_closed");
+
+    act(() => {
+      handler({
+        event: "application:posting_closed",
+        id: 1,
+        payload: {
+          application_id: 7,
+          job_hash: "hash-1",
+          job_title: "Care Coordinator",
+          company: "Example Health",
+          status: "to_apply",
+          closed_at: new Date().toISOString(),
+        },
+      });
+    });
+
+    expect(invalidateCacheByCommand).toHaveBeenCalledWith(
+      "get_applications_kanban",
+    );
+    expect(mockToast.warning).toHaveBeenCalledWith(
+      "Posting closed",
+      expect.stringContaining("Care Coordinator"),
+    );
+    expect(notifyPostingClosed).toHaveBeenCalledTimes(1);
+  });
+});
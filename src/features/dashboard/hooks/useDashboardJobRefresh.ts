@@ -0,0 +1,102 @@
+import {
+  useCallback,
+  useEffect,
+  useState,
+  type Dispatch,
+  type SetStateAction,
+} from "react";
+import { useToast } from "../../../shared/toast/useToast";
+import {
+  invalidateCacheByCommand,
+  safeInvokeWithToast,
+} from "../../../platform/tauri";
+import { listen } from "../../../platform/tauri/events";
+import { notifyPostingClosed } from "../notifications";
+import type { Job } from "../types";
+
+export interface JobFieldChange {
+  field: string;
+  before: string | null;
+  after: string | null;
+}
+
+export interface JobRefreshResult {
+  job: Job;
+  changes: JobFieldChange[];
+  refreshed_at: string;
+}
+
+/** Emitted when a refresh finds the posting gone for a to-apply application. */
+export interface ClosedPostingApplication {
+  application_id: number;
+  job_hash: string;
+  job_title: string;
+  company: string;
+  status: string;
+  closed_at: string;
+}
+
+export function useDashboardJobRefresh(
+  setJobs: Dispatch<SetStateAction<Job[]>>,
+) {
+  const [refreshingJobId, setRefreshingJobId] = useState<number | null>(null);
+  const toast = useToast();
+
+  useEffect(() => {
+    const unlisten = listen<ClosedPostingApplication>(
+      "application:posting_closed",
+      (event) => {
+        invalidateCacheByCommand("get_applications_kanban");
+        toast.warning(
+          "Posting closed",
+          `${event.payload.job_title} is no longer online. It is still on your to-apply list.`,
+        );
+        void notifyPostingClosed();
+      },
+    );
+
+    return () => {
+      void unlisten.then((stopListening) => stopListening());
+    };
+  }, [toast]);
+
+  const handleRefreshJob = useCallback(
+    async (jobId: number) => {
+      try {
+        setRefreshingJobId(jobId);
+        const refresh = await safeInvokeWithToast<JobRefreshResult>(
+          "refresh_job",
+          { jobId },
+          toast,
+          { logContext: "Refresh job posting" },
+        );
+        setJobs((previous) =>
+          previous.map((item) =>
+            item.id === jobId ? { ...item, ...refresh.job } : item,
+          ),
+        );
+        invalidateCacheByCommand("get_recent_jobs");
+        invalidateCacheByCommand("get_statistics");
+
+        if (refresh.changes.length === 0) {
+          toast.success(
+            "Posting is up to date",
+            "Nothing changed since the last check.",
+          );
+        } else {
+          const fields = refresh.changes
+            .map((change) => change.field.replace(/_/g, " "))
+            .join(", ");
+          toast.info("Posting refreshed", `Updated ${fields}`);
+        }
+      } catch {
+        // Error already logged and shown to user.
+      } finally {
+        setRefreshingJobId(null);
+      }
+    },
+    [setJobs, toast],
+  );
+
+  return { refreshingJobId, handleRefreshJob };
+}
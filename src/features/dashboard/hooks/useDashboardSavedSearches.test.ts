@@ -1,7 +1,7 @@
 import { describe, it, expect, vi, beforeEach } from "vitest";
 import { renderHook, waitFor, act } from "@testing-library/react";
 import { useDashboardSavedSearches } from "./useDashboardSavedSearches";
-import { safeInvoke } from "../../../platform/tauri";
+import { safeInvoke, safeInvokeWithToast } from "../../../platform/tauri";
 import {
   BROWSER_ASSIST_LEARNING_ENABLED_STORAGE_KEY,
   BROWSER_ASSIST_LEARNING_STORAGE_KEY,
@@ -32,6 +32,7 @@ vi.mock("@tauri-apps/api/core", () => ({
 }));
 
 const mockSafeInvoke = vi.mocked(safeInvoke);
+const mockSafeInvokeWithToast = vi.mocked(safeInvokeWithToast);
 
 const currentFilters = {
   sortBy: "score-desc" as const,
@@ -190,4 +191,48 @@ describe("useDashboardSavedSearches", () => {
       expect.anything(),
     );
   });
+
+  it("saves job agent settings and confirms when an agent is turned on", async () => {
+    mockSafeInvoke.mockResolvedValueOnce([
+      {
+        id: "search-1",
+        name: "Remote Support",
+        sortBy: "score-desc",
+        scoreFilter: "all",
+        sourceFilter: "all",
+        remoteFilter: "remote",
+        bookmarkFilter: "all",
+        notesFilter: "all",
+        postedDateFilter: null,
+        salaryMinFilter: null,
+        salaryMaxFilter: null,
+        ghostFilter: null,
+        textSearch: null,
+        createdAt: "2026-05-20T00:00:00Z",
+        lastUsedAt: null,
+        agent: { enabled: false, channels: [], frequency: "each_scrape", lastCheckedAt: null },
+      },
+    ]);
+    mockSafeInvokeWithToast.mockResolvedValueOnce(true);
+    const agent = { enabled: true, channels: ["slack" as const], frequency: "daily" as const };
+
+    const { result } = renderHook(() => useDashboardSavedSearches());
+    await waitFor(() => expect(result.current.savedSearches).toHaveLength(1));
+
+    await act(async () => {
+      await result.current.handleUpdateSearchAgent("search-1", agent);
+    });
+
+    expect(mockSafeInvokeWithToast).toHaveBeenCalledWith(
+      "update_saved_search_agent",
+      { id: "search-1", settings: agent },
+      mockToast,
+      { logContext: "Update job agent" },
+    );
+    expect(result.current.savedSearches[0].agent).toEqual(agent);
+    expect(mockToast.success).toHaveBeenCalledWith(
+      "Job agent on",
+      'New jobs matching "Remote Support" will be sent once a day',
+    );
+  });
 });
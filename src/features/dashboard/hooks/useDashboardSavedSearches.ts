@@ -8,6 +8,7 @@ import type {
   ScoreFilter,
   PostedDateFilter,
   GhostFilter,
+  SearchAgentSettings,
 } from "../types";
 import { useToast } from "../../../shared/toast/useToast";
 import { useUndo } from "../../../shared/undo/useUndo";
@@ -31,13 +32,18 @@ type BackendSavedSearch = {
   textSearch: string | null;
   createdAt: string;
   lastUsedAt: string | null;
+  agent?: SearchAgentSettings;
 };
 
 function isGhostFilter(value: unknown): value is GhostFilter {
   return value === "all" || value === "real" || value === "ghost";
 }
 
-function toBackendSavedSearch(name: string, filters: SavedSearch["filters"]): BackendSavedSearch {
+function toBackendSavedSearch(
+  name: string,
+  filters: SavedSearch["filters"],
+  agent?: SearchAgentSettings,
+): BackendSavedSearch {
   return {
     id: "",
     name,
@@ -54,6 +60,7 @@ function toBackendSavedSearch(name: string, filters: SavedSearch["filters"]): Ba
     textSearch: null,
     createdAt: "",
     lastUsedAt: null,
+    ...(agent ? { agent } : {}),
   };
 }
 
@@ -89,6 +96,7 @@ export function useDashboardSavedSearches() {
             salaryMaxFilter: s.salaryMaxFilter,
           },
           createdAt: s.createdAt,
+          agent: s.agent,
         }));
         setSavedSearches(transformed);
       } catch {
@@ -185,7 +193,11 @@ export function useDashboardSavedSearches() {
         undo: async () => {
           // Re-create the saved search
           const result = await invoke<{ id: string; name: string; createdAt: string }>('create_saved_search', {
-            search: toBackendSavedSearch(deletedSearch.name, deletedSearch.filters),
+            search: toBackendSavedSearch(
+              deletedSearch.name,
+              deletedSearch.filters,
+              deletedSearch.agent,
+            ),
           });
           const restoredSearch: SavedSearch = {
             ...deletedSearch,
@@ -204,6 +216,31 @@ export function useDashboardSavedSearches() {
     }
   }, [savedSearches, toast, pushAction]);
 
+  const handleUpdateSearchAgent = useCallback(async (
+    id: string,
+    agent: SearchAgentSettings,
+  ) => {
+    const search = savedSearches.find((s) => s.id === id);
+    if (!search) return;
+
+    try {
+      await safeInvokeWithToast('update_saved_search_agent', { id, settings: agent }, toast, {
+        logContext: "Update job agent"
+      });
+      setSavedSearches(prev => prev.map((s) => (s.id === id ? { ...s, agent } : s)));
+      if (agent.enabled && !search.agent?.enabled) {
+        toast.success(
+          "Job agent on",
+          `New jobs matching "${search.name}" will be sent ${
+            agent.frequency === "daily" ? "once a day" : "after each scrape"
+          }`,
+        );
+      }
+    } catch {
+      // Error already logged and shown to user
+    }
+  }, [savedSearches, toast]);
+
   return {
     savedSearches,
     saveSearchModalOpen,
@@ -213,5 +250,6 @@ export function useDashboardSavedSearches() {
     handleSaveSearch,
     handleLoadSearch,
     handleDeleteSearch,
+    handleUpdateSearchAgent,
   };
 }
@@ -1,5 +1,5 @@
 import { describe, it, expect, vi, beforeEach } from "vitest";
-import { renderHook, waitFor } from "@testing-library/react";
+import { act, renderHook, waitFor } from "@testing-library/react";
 import { invoke } from "@tauri-apps/api/core";
 import { useDashboardSearch } from "./useDashboardSearch";
 
@@ -27,4 +27,47 @@ describe("useDashboardSearch", () => {
       expect(result.current.searchHistory).toEqual(["remote customer support"]);
     });
   });
+
+  it("loads ranked suggestions and pins or removes them", async () => {
+    mockInvoke.mockResolvedValueOnce(["care coordinator"]);
+    const { result } = renderHook(() => useDashboardSearch());
+    await waitFor(() => {
+      expect(result.current.searchHistory).toEqual(["care coordinator"]);
+    });
+
+    mockInvoke.mockResolvedValueOnce([
+      { text: "care coordinator", source: "history", pinned: false, count: 3, score: 2.1 },
+      { text: "Care Manager", source: "job_title", pinned: false, count: 2, score: 0.5 },
+    ]);
+    await act(async () => {
+      await result.current.loadQuerySuggestions(" care ");
+    });
+    expect(mockInvoke).toHaveBeenLastCalledWith("suggest_queries", {
+      prefix: "care",
+      limit: 8,
+    });
+
+    mockInvoke.mockResolvedValueOnce(true);
+    await act(async () => {
+      await result.current.pinSearchSuggestion("Care Manager", true);
+    });
+    expect(mockInvoke).toHaveBeenLastCalledWith("pin_search_suggestion", {
+      query: "Care Manager",
+      pinned: true,
+    });
+    expect(result.current.querySuggestions.map((s) => [s.text, s.pinned])).toEqual([
+      ["Care Manager", true],
+      ["care coordinator", false],
+    ]);
+
+    mockInvoke.mockResolvedValueOnce(undefined);
+    await act(async () => {
+      await result.current.removeSearchSuggestion("care coordinator");
+    });
+    expect(mockInvoke).toHaveBeenLastCalledWith("remove_search_suggestion", {
+      query: "care coordinator",
+    });
+    expect(result.current.querySuggestions.map((s) => s.text)).toEqual(["Care Manager"]);
+    expect(result.current.searchHistory).toEqual([]);
+  });
 });
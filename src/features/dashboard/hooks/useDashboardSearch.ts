@@ -1,15 +1,19 @@
 // Dashboard Search History Hook
-// Manages search history persistence and query handling
+// Manages search history persistence, query suggestions, and query handling
 
-import { useState, useEffect, useCallback } from "react";
+import { useState, useEffect, useCallback, useRef } from "react";
 import { invoke } from "../../../platform/tauri";
 import { logError } from "../../../shared/errorReporting/logger";
+import type { QuerySuggestion } from "../types";
 
 const SEARCH_HISTORY_LIMIT = 20;
+const QUERY_SUGGESTION_LIMIT = 8;
 
 export function useDashboardSearch() {
   const [searchHistory, setSearchHistory] = useState<string[]>([]);
   const [showSearchHistory, setShowSearchHistory] = useState(false);
+  const [querySuggestions, setQuerySuggestions] = useState<QuerySuggestion[]>([]);
+  const suggestionRequestRef = useRef(0);
 
   // Load search history from backend on mount
   useEffect(() => {
@@ -45,16 +49,61 @@ export function useDashboardSearch() {
     try {
       await invoke('clear_search_history');
       setSearchHistory([]);
+      setQuerySuggestions(prev => prev.filter(s => s.source !== "history"));
     } catch (err: unknown) {
       logError("Failed to clear search history:", err);
     }
   }, []);
 
+  // Only the latest request updates suggestions, so slow replies for an
+  // older prefix never replace newer ones
+  const loadQuerySuggestions = useCallback(async (prefix: string) => {
+    const request = ++suggestionRequestRef.current;
+    try {
+      const suggestions = await invoke<QuerySuggestion[]>('suggest_queries', {
+        prefix: prefix.trim(),
+        limit: QUERY_SUGGESTION_LIMIT,
+      });
+      if (request === suggestionRequestRef.current) {
+        setQuerySuggestions(suggestions);
+      }
+    } catch (err: unknown) {
+      logError("Failed to load search suggestions:", err);
+    }
+  }, []);
+
+  const pinSearchSuggestion = useCallback(async (query: string, pinned: boolean) => {
+    try {
+      await invoke('pin_search_suggestion', { query, pinned });
+      setQuerySuggestions(prev =>
+        prev
+          .map(s => (s.text === query ? { ...s, pinned } : s))
+          .sort((a, b) => Number(b.pinned) - Number(a.pinned)),
+      );
+    } catch (err: unknown) {
+      logError("Failed to pin search suggestion:", err);
+    }
+  }, []);
+
+  const removeSearchSuggestion = useCallback(async (query: string) => {
+    try {
+      await invoke('remove_search_suggestion', { query });
+      setQuerySuggestions(prev => prev.filter(s => s.text !== query));
+      setSearchHistory(prev => prev.filter(h => h !== query));
+    } catch (err: unknown) {
+      logError("Failed to remove search suggestion:", err);
+    }
+  }, []);
+
   return {
     searchHistory,
     showSearchHistory,
     setShowSearchHistory,
     addToSearchHistory,
     clearSearchHistory,
+    querySuggestions,
+    loadQuerySuggestions,
+    pinSearchSuggestion,
+    removeSearchSuggestion,
   };
 }
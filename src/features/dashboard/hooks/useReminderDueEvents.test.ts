@@ -0,0 +1,67 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { renderHook, act } from "@testing-library/react";
+import { useReminderDueEvents } from "./useReminderDueEvents";
+import { invalidateCacheByCommand } from "../../../platform/tauri";
+import { listen } from "../../../platform/tauri/events";
+import { notifyReminder } from "../notifications";
+
+const mockToast = {
+  error: vi.fn(),
+  success: vi.fn(),
+  info: vi.fn(),
+  warning: vi.fn(),
+};
+vi.mock("../../../shared/toast/useToast", () => ({ useToast: () => mockToast }));
+
+vi.mock("../../../platform/tauri", () => ({
+  invalidateCacheByCommand: vi.fn(),
+}));
+
+vi.mock("../../../platform/tauri/events", () => ({
+  listen: vi.fn(() => Promise.resolve(() => undefined)),
+}));
+
+vi.mock("../notifications", () => ({
+  notifyReminder: vi.fn(() => Promise.resolve()),
+}));
+
+const mockListen = vi.mocked(listen);
+
+beforeEach(() => {
+  vi.clearAllMocks();
+});
+
+describe("reminder due events", () => {
+  it("refreshes reminders and sends a desktop notification", () => {
+    renderHook(() => useReminderDueEvents());
+    const [eventName, handler] = mockListen.mock.calls[0];
+    expect(eventName).toBe("application:reminder_due");
+
+    act(() => {
+      handler({
+        event: "application:reminder_due",
+        id: 1,
+        payload: {
+          id: 3,
+          application_id: 7,
+          reminder_type: "follow_up",
+          job_title: "Care Coordinator",
+          company: "Example Health",
+        },
+      });
+    });
+
+    expect(invalidateCacheByCommand).toHaveBeenCalledWith(
+      "get_pending_reminders",
+    );
+    expect(mockToast.info).toHaveBeenCalledWith(
+      "Reminder due",
+      "Care Coordinator at Example Health",
+    );
+    expect(notifyReminder).toHaveBeenCalledWith(
+      "Care Coordinator",
+      "Example Health",
+      "",
+    );
+  });
+});
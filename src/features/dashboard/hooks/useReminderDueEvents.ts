@@ -0,0 +1,34 @@
+import { useEffect } from "react";
+import { useToast } from "../../../shared/toast/useToast";
+import { invalidateCacheByCommand } from "../../../platform/tauri";
+import { listen } from "../../../platform/tauri/events";
+import { notifyReminder } from "../notifications";
+
+/** Emitted when the background task sends an application reminder. */
+export interface ReminderDueEvent {
+  id: number;
+  application_id: number;
+  reminder_type: string;
+  job_title: string;
+  company: string;
+}
+
+export function useReminderDueEvents() {
+  const toast = useToast();
+
+  useEffect(() => {
+    const unlisten = listen<ReminderDueEvent>(
+      "application:reminder_due",
+      (event) => {
+        const { job_title: jobTitle, company } = event.payload;
+        invalidateCacheByCommand("get_pending_reminders");
+        toast.info("Reminder due", `${jobTitle} at ${company}`);
+        void notifyReminder(jobTitle, company, "");
+      },
+    );
+
+    return () => {
+      void unlisten.then((stopListening) => stopListening());
+    };
+  }, [toast]);
+}
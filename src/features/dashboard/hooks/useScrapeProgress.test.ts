@@ -0,0 +1,72 @@
+import { describe, expect, it } from "vitest";
+import {
+  IDLE_SCRAPE_PROGRESS,
+  applyScrapeError,
+  applyScrapeProgress,
+  applyScrapeSourceComplete,
+} from "./useScrapeProgress";
+
+const started = applyScrapeProgress(IDLE_SCRAPE_PROGRESS, {
+  sources_completed: 0,
+  sources_total: 2,
+  pending_sources: ["greenhouse", "dice"],
+  jobs_found: 0,
+  jobs_new: 0,
+  jobs_updated: 0,
+});
+
+describe("scrape progress events", () => {
+  it("lists every pending source as running when a cycle starts", () => {
+    expect(started.active).toBe(true);
+    expect(started.sources.map((source) => [source.source, source.status])).toEqual([
+      ["greenhouse", "running"],
+      ["dice", "running"],
+    ]);
+  });
+
+  it("records per-source counts and keeps failures visible", () => {
+    let state = applyScrapeError(started, {
+      source: "dice",
+      message: "Dice source check failed (network)",
+      jobs_found: 0,
+      jobs_new: 0,
+      jobs_updated: 0,
+    });
+    state = applyScrapeSourceComplete(state, {
+      source: "dice",
+      sources_completed: 1,
+      sources_total: 2,
+      jobs_found: 0,
+      jobs_new: 0,
+      jobs_updated: 0,
+    });
+    state = applyScrapeSourceComplete(state, {
+      source: "greenhouse",
+      sources_completed: 2,
+      sources_total: 2,
+      jobs_found: 12,
+      jobs_new: 4,
+      jobs_updated: 8,
+    });
+
+    expect(state.sources.find((source) => source.source === "dice")?.status).toBe("failed");
+    expect(state.sources.find((source) => source.source === "greenhouse")).toMatchObject({
+      status: "complete",
+      jobs_new: 4,
+    });
+  });
+
+  it("ends the cycle on the final progress event", () => {
+    const finished = applyScrapeProgress(started, {
+      sources_completed: 2,
+      sources_total: 2,
+      pending_sources: [],
+      jobs_found: 12,
+      jobs_new: 4,
+      jobs_updated: 8,
+    });
+
+    expect(finished.active).toBe(false);
+    expect(finished.totals).toEqual({ jobs_found: 12, jobs_new: 4, jobs_updated: 8 });
+  });
+});
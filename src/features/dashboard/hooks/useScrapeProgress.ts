@@ -0,0 +1,136 @@
+import { useEffect, useState } from "react";
+import { listen } from "../../../platform/tauri/events";
+import { notifySourceFailing } from "../notifications";
+import type {
+  ScrapeCounts,
+  ScrapeErrorEvent,
+  ScrapeProgressEvent,
+  ScrapeSourceCompleteEvent,
+  ScrapeSourceFailingEvent,
+} from "../types";
+
+export type ScrapeSourceStatus = "running" | "complete" | "failed";
+
+export interface ScrapeSourceProgress extends ScrapeCounts {
+  source: string;
+  status: ScrapeSourceStatus;
+}
+
+export interface ScrapeProgressState {
+  active: boolean;
+  sourcesCompleted: number;
+  sourcesTotal: number;
+  totals: ScrapeCounts;
+  sources: ScrapeSourceProgress[];
+}
+
+const EMPTY_COUNTS: ScrapeCounts = { jobs_found: 0, jobs_new: 0, jobs_updated: 0 };
+
+export const IDLE_SCRAPE_PROGRESS: ScrapeProgressState = {
+  active: false,
+  sourcesCompleted: 0,
+  sourcesTotal: 0,
+  totals: EMPTY_COUNTS,
+  sources: [],
+};
+
+function countsOf(event: ScrapeCounts): ScrapeCounts {
+  return {
+    jobs_found: event.jobs_found,
+    jobs_new: event.jobs_new,
+    jobs_updated: event.jobs_updated,
+  };
+}
+
+export function applyScrapeProgress(
+  state: ScrapeProgressState,
+  event: ScrapeProgressEvent,
+): ScrapeProgressState {
+  // A cycle restarts when nothing has completed yet.
+  const known = event.sources_completed === 0 ? [] : state.sources;
+  const sources = [...known];
+  for (const source of event.pending_sources) {
+    if (!sources.some((entry) => entry.source === source)) {
+      sources.push({ source, status: "running", ...EMPTY_COUNTS });
+    }
+  }
+  return {
+    active: event.sources_completed < event.sources_total,
+    sourcesCompleted: event.sources_completed,
+    sourcesTotal: event.sources_total,
+    totals: countsOf(event),
+    sources,
+  };
+}
+
+export function applyScrapeSourceComplete(
+  state: ScrapeProgressState,
+  event: ScrapeSourceCompleteEvent,
+): ScrapeProgressState {
+  const previous = state.sources.find((entry) => entry.source === event.source);
+  const completed: ScrapeSourceProgress = {
+    source: event.source,
+    status: previous?.status === "failed" ? "failed" : "complete",
+    ...countsOf(event),
+  };
+  return {
+    ...state,
+    sourcesCompleted: event.sources_completed,
+    sourcesTotal: event.sources_total,
+    sources: previous
+      ? state.sources.map((entry) => (entry.source === event.source ? completed : entry))
+      : [...state.sources, completed],
+  };
+}
+
+export function applyScrapeError(
+  state: ScrapeProgressState,
+  event: ScrapeErrorEvent,
+): ScrapeProgressState {
+  if (!event.source) return state;
+  const failed: ScrapeSourceProgress = {
+    source: event.source,
+    status: "failed",
+    ...countsOf(event),
+  };
+  const exists = state.sources.some((entry) => entry.source === event.source);
+  return {
+    ...state,
+    sources: exists
+      ? state.sources.map((entry) => (entry.source === event.source ? failed : entry))
+      : [...state.sources, failed],
+  };
+}
+
+/**
+ * Live per-source progress for the running scraping cycle. Also raises a
+ * desktop notification when a source keeps failing.
+ */
+export function useScrapeProgress(): ScrapeProgressState {
+  const [progress, setProgress] = useState(IDLE_SCRAPE_PROGRESS);
+
+  useEffect(() => {
+    const listeners = [
+      listen<ScrapeProgressEvent>("scrape:progress", (event) => {
+        setProgress((state) => applyScrapeProgress(state, event.payload));
+      }),
+      listen<ScrapeSourceCompleteEvent>("scrape:source_complete", (event) => {
+        setProgress((state) => applyScrapeSourceComplete(state, event.payload));
+      }),
+      listen<ScrapeErrorEvent>("scrape:error", (event) => {
+        setProgress((state) => applyScrapeError(state, event.payload));
+      }),
+      listen<ScrapeSourceFailingEvent>("scrape:source_failing", () => {
+        void notifySourceFailing();
+      }),
+    ];
+
+    return () => {
+      for (const unlisten of listeners) {
+        void unlisten.then((stopListening) => stopListening());
+      }
+    };
+  }, []);
+
+  return progress;
+}
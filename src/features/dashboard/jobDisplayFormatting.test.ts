@@ -1,5 +1,6 @@
 import { describe, expect, it } from "vitest";
 import {
+  annualizeSalary,
   formatSalaryNumber,
   formatSalaryRange,
   hasMalformedSalaryRangeInput,
@@ -20,6 +21,14 @@ describe("job display formatting", () => {
       expect(formatSalaryRange(null, 150000)).toBe("Up to $150k");
     });
 
+    it("labels hourly and day rates and annualizes them", () => {
+      expect(formatSalaryRange(45, 55, "hour")).toBe("$45 - $55/hr");
+      expect(formatSalaryRange(400, null, "day")).toBe("$400+/day");
+      expect(formatSalaryRange(90000, null, "year")).toBe("$90k+");
+      expect(annualizeSalary(45, "hour")).toBe(93600);
+      expect(annualizeSalary(90000, null)).toBe(90000);
+    });
+
     it("omits absent or malformed ranges", () => {
       expect(formatSalaryRange()).toBeNull();
       expect(formatSalaryRange(null, null)).toBeNull();
@@ -1,3 +1,29 @@
+import type { PayPeriod } from "./types";
+
+const PAY_PERIOD_SUFFIX: Record<PayPeriod, string> = {
+  hour: "/hr",
+  day: "/day",
+  week: "/wk",
+  month: "/mo",
+  year: "",
+};
+
+// Full-time periods in a year, matching the backend's annualization.
+const PERIODS_PER_YEAR: Record<PayPeriod, number> = {
+  hour: 2080,
+  day: 260,
+  week: 52,
+  month: 12,
+  year: 1,
+};
+
+export function annualizeSalary(
+  amount: number,
+  payPeriod?: PayPeriod | null,
+): number {
+  return amount * PERIODS_PER_YEAR[payPeriod ?? "year"];
+}
+
 export function formatSalaryNumber(salary: number): string {
   return salary >= 1000 ? `$${Math.round(salary / 1000)}k` : `$${salary}`;
 }
@@ -30,17 +56,19 @@ export function hasMalformedSalaryRangeInput(
 export function formatSalaryRange(
   min?: number | null,
   max?: number | null,
+  payPeriod?: PayPeriod | null,
 ): string | null {
   if (hasMalformedSalaryRangeInput(min, max)) return null;
 
   const hasMin = typeof min === "number" && Number.isFinite(min) && min > 0;
   const hasMax = typeof max === "number" && Number.isFinite(max) && max > 0;
+  const suffix = PAY_PERIOD_SUFFIX[payPeriod ?? "year"];
 
   if (hasMin && hasMax) {
-    return `${formatSalaryNumber(min)} - ${formatSalaryNumber(max)}`;
+    return `${formatSalaryNumber(min)} - ${formatSalaryNumber(max)}${suffix}`;
   }
-  if (hasMin) return `${formatSalaryNumber(min)}+`;
-  if (hasMax) return `Up to ${formatSalaryNumber(max)}`;
+  if (hasMin) return `${formatSalaryNumber(min)}+${suffix}`;
+  if (hasMax) return `Up to ${formatSalaryNumber(max)}${suffix}`;
   return null;
 }
 
@@ -92,6 +92,40 @@ export async function notifyReminder(
   }
 }
 
+/**
+ * Send a desktop notification when a posting closed before the user applied.
+ */
+export async function notifyPostingClosed(): Promise<void> {
+  const hasPermission = await hasNotificationPermission();
+  if (!hasPermission) return;
+
+  try {
+    sendNotification({
+      title: "JobSentinel update",
+      body: "A posting on your to-apply list has closed. Review it before you spend more prep time.",
+    });
+  } catch (error: unknown) {
+    logError("Failed to send notification:", error);
+  }
+}
+
+/**
+ * Send a desktop notification when a job source keeps failing.
+ */
+export async function notifySourceFailing(): Promise<void> {
+  const hasPermission = await hasNotificationPermission();
+  if (!hasPermission) return;
+
+  try {
+    sendNotification({
+      title: "JobSentinel update",
+      body: "A job source keeps failing. Open JobSentinel to see which one.",
+    });
+  } catch (error: unknown) {
+    logError("Failed to send notification:", error);
+  }
+}
+
 /**
  * Select jobs that are new or have a higher score than the prior snapshot.
  */
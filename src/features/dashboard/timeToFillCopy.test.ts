@@ -0,0 +1,25 @@
+import { describe, expect, it } from "vitest";
+import { formatTimeToFill } from "./timeToFillCopy";
+import type { TimeToFillEstimate } from "./types";
+
+const estimate: TimeToFillEstimate = {
+  company: "CommunityCare",
+  role_family: "case manager",
+  scope: "role",
+  median_days: 11.6,
+  sample_size: 4,
+};
+
+describe("time to fill copy", () => {
+  it("names the role family when the median is role specific", () => {
+    expect(formatTimeToFill(estimate)).toBe(
+      "CommunityCare usually closes case manager postings within 12 days",
+    );
+  });
+
+  it("falls back to company-wide wording and never shows zero days", () => {
+    expect(
+      formatTimeToFill({ ...estimate, scope: "company", median_days: 0.2 }),
+    ).toBe("CommunityCare usually closes postings within 1 day");
+  });
+});
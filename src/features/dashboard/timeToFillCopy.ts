@@ -0,0 +1,15 @@
+import type { TimeToFillEstimate } from "./types";
+
+export function formatTimeToFill(estimate: TimeToFillEstimate): string {
+  const days = Math.max(1, Math.round(estimate.median_days));
+  const dayLabel = days === 1 ? "day" : "days";
+  const postings =
+    estimate.scope === "role" && estimate.role_family
+      ? `${estimate.role_family} postings`
+      : "postings";
+  return `${estimate.company} usually closes ${postings} within ${days} ${dayLabel}`;
+}
+
+export function describeTimeToFillBasis(estimate: TimeToFillEstimate): string {
+  return `Median of ${estimate.sample_size} closed postings seen by JobSentinel`;
+}
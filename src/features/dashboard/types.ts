@@ -12,6 +12,8 @@ export type GhostFilter = "all" | "real" | "ghost";
 export type Page =
   "dashboard" | "applications" | "resume" | "automation" | "salary" | "market";
 
+export type PayPeriod = "hour" | "day" | "week" | "month" | "year";
+
 export interface Job {
   id: number;
   hash?: string;
@@ -26,6 +28,8 @@ export interface Job {
   description?: string | null;
   salary_min?: number | null;
   salary_max?: number | null;
+  /** How often the posted salary is paid; amounts are per this period. */
+  pay_period?: PayPeriod | null;
   remote?: boolean | null;
   bookmarked?: boolean;
   notes?: string | null;
@@ -45,6 +49,56 @@ export interface ScrapingStatus {
   last_scrape: string | null;
   next_scrape: string | null;
   is_running: boolean;
+  /** Scheduled scraping is paused; manual scrapes still run */
+  paused?: boolean;
+  paused_at?: string | null;
+  /** Why the last scheduled scrape was skipped or cut down */
+  skip_reason?: string | null;
+}
+
+export interface ScrapeCounts {
+  jobs_found: number;
+  jobs_new: number;
+  jobs_updated: number;
+}
+
+/** Payload of the `scrape:progress` event. */
+export interface ScrapeProgressEvent extends ScrapeCounts {
+  sources_completed: number;
+  sources_total: number;
+  pending_sources: string[];
+}
+
+/** Payload of the `scrape:source_complete` event. */
+export interface ScrapeSourceCompleteEvent extends ScrapeCounts {
+  source: string;
+  sources_completed: number;
+  sources_total: number;
+}
+
+/** Payload of the `scrape:error` event. */
+export interface ScrapeErrorEvent extends ScrapeCounts {
+  source: string | null;
+  message: string;
+}
+
+/** Payload of the `scrape:source_failing` event. */
+export interface ScrapeSourceFailingEvent {
+  source: string;
+  consecutive_failures: number;
+  message: string;
+}
+
+export type SearchAgentFrequency = "each_scrape" | "daily";
+export type SearchAgentChannel = "email" | "slack" | "discord";
+
+/** Job agent settings: alerts for new jobs matching a saved search. */
+export interface SearchAgentSettings {
+  enabled: boolean;
+  /** Channels to alert through; empty uses every enabled channel. */
+  channels: SearchAgentChannel[];
+  frequency: SearchAgentFrequency;
+  lastCheckedAt?: string | null;
 }
 
 export interface SavedSearch {
@@ -63,6 +117,18 @@ export interface SavedSearch {
     salaryMaxFilter?: number | null;
   };
   createdAt: string;
+  agent?: SearchAgentSettings;
+}
+
+export type QuerySuggestionSource = "history" | "job_title" | "company";
+
+/** A ranked search suggestion from history or matching job titles and companies. */
+export interface QuerySuggestion {
+  text: string;
+  source: QuerySuggestionSource;
+  pinned: boolean;
+  count: number;
+  score: number;
 }
 
 export interface DuplicateGroup {
@@ -106,3 +172,11 @@ export interface SearchQuery {
   excludes: string[];
   isOr: boolean;
 }
+
+export interface TimeToFillEstimate {
+  company: string;
+  role_family: string;
+  scope: "role" | "company";
+  median_days: number;
+  sample_size: number;
+}
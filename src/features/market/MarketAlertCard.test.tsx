@@ -243,6 +243,16 @@ describe("MarketAlertCard", () => {
       
       expect(onMarkRead).toHaveBeenCalledWith(1);
     });
+
+    it("calls onDismiss when Dismiss button is clicked, even for read alerts", async () => {
+      const user = userEvent.setup();
+      const onDismiss = vi.fn();
+      render(<MarketAlertCard alert={{ ...mockAlert, is_read: true }} onDismiss={onDismiss} />);
+
+      await user.click(screen.getByRole("button", { name: /dismiss/i }));
+
+      expect(onDismiss).toHaveBeenCalledWith(1);
+    });
   });
 
   describe("accessibility", () => {
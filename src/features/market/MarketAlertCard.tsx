@@ -6,6 +6,7 @@ import type { MarketAlert } from "./model";
 interface MarketAlertCardProps {
   alert: MarketAlert;
   onMarkRead?: (id: number) => void;
+  onDismiss?: (id: number) => void;
 }
 
 type AlertTypeIconName = "skill" | "salary" | "freeze" | "spree" | "location" | "obsolete" | "default";
@@ -99,7 +100,7 @@ function AlertTypeIcon({ type }: { type: AlertTypeIconName }) {
   }
 }
 
-export const MarketAlertCard = memo(function MarketAlertCard({ alert, onMarkRead }: MarketAlertCardProps) {
+export const MarketAlertCard = memo(function MarketAlertCard({ alert, onMarkRead, onDismiss }: MarketAlertCardProps) {
   const getSeverityStyles = (severity: string) =>
     SEVERITY_STYLES[severity.toLowerCase()] ?? DEFAULT_SEVERITY_STYLE;
 
@@ -175,15 +176,29 @@ export const MarketAlertCard = memo(function MarketAlertCard({ alert, onMarkRead
             </div>
           </div>
         </div>
-        {!alert.is_read && onMarkRead && (
-          <Button
-            variant="ghost"
-            size="sm"
-            onClick={() => onMarkRead(alert.id)}
-            aria-label={`Mark ${alert.title} as read`}
-          >
-            Mark Read
-          </Button>
+        {((!alert.is_read && onMarkRead) || onDismiss) && (
+          <div className="flex flex-shrink-0 items-center gap-1">
+            {!alert.is_read && onMarkRead && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onMarkRead(alert.id)}
+                aria-label={`Mark ${alert.title} as read`}
+              >
+                Mark Read
+              </Button>
+            )}
+            {onDismiss && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onDismiss(alert.id)}
+                aria-label={`Dismiss ${alert.title}`}
+              >
+                Dismiss
+              </Button>
+            )}
+          </div>
         )}
       </div>
     </div>
@@ -194,6 +209,7 @@ interface MarketAlertListProps {
   alerts: MarketAlert[];
   onMarkRead?: (id: number) => void;
   onMarkAllRead?: () => void;
+  onDismiss?: (id: number) => void;
   loading?: boolean;
 }
 
@@ -201,6 +217,7 @@ export const MarketAlertList = memo(function MarketAlertList({
   alerts = [],
   onMarkRead,
   onMarkAllRead,
+  onDismiss,
   loading = false,
 }: MarketAlertListProps) {
   const unreadCount = (alerts ?? []).filter((a) => !a.is_read).length;
@@ -238,7 +255,7 @@ export const MarketAlertList = memo(function MarketAlertList({
         </div>
       )}
       {alerts.map((alert) => (
-        <MarketAlertCard key={alert.id} alert={alert} onMarkRead={onMarkRead} />
+        <MarketAlertCard key={alert.id} alert={alert} onMarkRead={onMarkRead} onDismiss={onDismiss} />
       ))}
     </div>
   );
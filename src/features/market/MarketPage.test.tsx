@@ -51,12 +51,14 @@ function makeSnapshot(overrides: Partial<{
 
 function mockMarketData({
   skills = [],
+  skillGaps = [],
   companies = [],
   locations = [],
   alerts = [],
   snapshot = makeSnapshot(),
 }: {
   skills?: unknown[];
+  skillGaps?: unknown[];
   companies?: unknown[];
   locations?: unknown[];
   alerts?: unknown[];
@@ -64,7 +66,8 @@ function mockMarketData({
 }) {
   mockInvoke.mockImplementation(async (command: string) => {
     if (command === "get_trending_skills") return skills;
-    if (command === "get_active_companies") return companies;
+    if (command === "get_personal_skill_gaps") return skillGaps;
+    if (command === "get_most_active_companies") return companies;
     if (command === "get_hottest_locations") return locations;
     if (command === "get_market_alerts") return alerts;
     if (command === "get_market_snapshot") return snapshot;
@@ -155,4 +158,46 @@ describe("Market safe error copy", () => {
       expect(screen.getByRole("tabpanel")).toHaveAttribute("id", "skills-panel");
     });
   });
+
+  it("lists skills the active resume lacks with their pay difference", async () => {
+    const user = userEvent.setup();
+    mockMarketData({
+      snapshot: makeSnapshot({ total_jobs: 3 }),
+      skillGaps: [
+        { skill_name: "Data Reporting", total_jobs: 12, avg_salary: 88000, salary_delta: 24000 },
+        { skill_name: "Forklift Operation", total_jobs: 4, avg_salary: null, salary_delta: null },
+      ],
+    });
+
+    render(<MarketPage onBack={vi.fn()} />);
+    await user.click(await screen.findByRole("tab", { name: /skills/i }));
+
+    const list = await screen.findByRole("list", { name: "Skills to build" });
+    expect(list).toHaveTextContent("Data Reporting");
+    expect(list).toHaveTextContent("+$24,000 vs. your skills");
+    expect(list).toHaveTextContent("Forklift Operation");
+  });
+
+  it("loads trends over time and reloads them for another period", async () => {
+    const user = userEvent.setup();
+    mockMarketData({ snapshot: makeSnapshot({ total_jobs: 3 }) });
+
+    render(<MarketPage onBack={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith(
+        "get_skill_mention_series",
+        expect.objectContaining({ granularity: "week" }),
+      );
+    });
+    await user.click(await screen.findByRole("button", { name: "Monthly" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith(
+        "get_median_salary_series",
+        expect.objectContaining({ granularity: "month" }),
+      );
+    });
+    expect(screen.getByRole("button", { name: "Monthly" })).toHaveAttribute("aria-pressed", "true");
+  });
 });
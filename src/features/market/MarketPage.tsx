@@ -83,6 +83,7 @@ export default function MarketPage({ onBack }: MarketPageProps) {
             locations={market.locations}
             onMarkAlertRead={market.markAlertRead}
             onShowAlerts={() => setActiveTab("alerts")}
+            seriesRefreshKey={market.lastFetched?.getTime()}
             skillEmptyMessage={skillEmptyMessage}
             skills={market.skills}
             snapshot={market.snapshot}
@@ -90,7 +91,9 @@ export default function MarketPage({ onBack }: MarketPageProps) {
             unreadAlertCount={market.unreadAlertCount}
           />
         )}
-        {activeTab === "skills" && <MarketSkillsPanel skills={market.skills} emptyMessage={skillEmptyMessage} />}
+        {activeTab === "skills" && (
+          <MarketSkillsPanel skills={market.skills} skillGaps={market.skillGaps} emptyMessage={skillEmptyMessage} />
+        )}
         {activeTab === "companies" && (
           <MarketCompaniesPanel companies={market.companies} emptyMessage={companyEmptyMessage} />
         )}
@@ -106,6 +109,7 @@ export default function MarketPage({ onBack }: MarketPageProps) {
               alerts={market.alerts}
               onMarkRead={market.markAlertRead}
               onMarkAllRead={market.markAllAlertsRead}
+              onDismiss={market.dismissAlert}
             />
           </Card>
         )}
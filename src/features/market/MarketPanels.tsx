@@ -5,6 +5,7 @@ import { ChartSkeleton } from "../../ui/LoadingFallbacks";
 import { formatCurrency } from "../../shared/currencyFormatting";
 import { LocationHeatmap } from "./LocationHeatmap";
 import { MarketAlertList } from "./MarketAlertCard";
+import { MarketSeriesCharts } from "./MarketSeriesCharts";
 import { MarketSnapshotCard } from "./MarketSnapshotCard";
 import { TrendIndicator } from "./MarketPrimitives";
 import type {
@@ -12,6 +13,7 @@ import type {
   LocationHeat,
   MarketAlert,
   MarketSnapshot,
+  SkillGap,
   SkillTrend,
 } from "./model";
 
@@ -25,6 +27,7 @@ interface OverviewPanelProps {
   locations: LocationHeat[];
   onMarkAlertRead: (id: number) => void;
   onShowAlerts: () => void;
+  seriesRefreshKey?: number;
   skillEmptyMessage: string;
   skills: SkillTrend[];
   snapshot: MarketSnapshot | null;
@@ -40,6 +43,7 @@ export function MarketOverviewPanel({
   locations,
   onMarkAlertRead,
   onShowAlerts,
+  seriesRefreshKey,
   skillEmptyMessage,
   skills,
   snapshot,
@@ -49,6 +53,7 @@ export function MarketOverviewPanel({
   return (
     <div className="space-y-6">
       <MarketSnapshotCard snapshot={snapshot} emptyMessage={snapshotEmptyMessage} />
+      <MarketSeriesCharts refreshKey={seriesRefreshKey} />
       <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
         <Suspense fallback={<ChartSkeleton />}>
           <TrendChart
@@ -91,7 +96,47 @@ export function MarketOverviewPanel({
   );
 }
 
-export function MarketSkillsPanel({ skills, emptyMessage }: { skills: SkillTrend[]; emptyMessage: string }) {
+function formatSalaryDelta(delta: number) {
+  if (delta === 0) return "Same pay as your skills";
+  return `${delta > 0 ? "+" : "-"}${formatCurrency(Math.abs(delta))} vs. your skills`;
+}
+
+function SkillGapList({ gaps }: { gaps: SkillGap[] }) {
+  return (
+    <Card className="dark:bg-surface-800">
+      <h3 className="font-display text-display-sm text-surface-900 dark:text-white mb-1">Skills to Build</h3>
+      <p className="text-sm text-surface-500 dark:text-surface-400 mb-4">
+        In-demand skills your active resume does not list, compared with the pay for skills it does.
+      </p>
+      <ul className="space-y-2" aria-label="Skills to build">
+        {gaps.map((gap) => (
+          <li
+            key={gap.skill_name}
+            className="flex items-center justify-between p-3 bg-surface-50 dark:bg-surface-700 rounded-lg"
+          >
+            <div>
+              <p className="font-medium text-surface-800 dark:text-surface-200">{gap.skill_name}</p>
+              <p className="text-sm text-surface-500 dark:text-surface-400">{gap.total_jobs.toLocaleString()} jobs</p>
+            </div>
+            {gap.salary_delta !== null && (
+              <p className="text-sm text-surface-600 dark:text-surface-300">{formatSalaryDelta(gap.salary_delta)}</p>
+            )}
+          </li>
+        ))}
+      </ul>
+    </Card>
+  );
+}
+
+export function MarketSkillsPanel({
+  skills,
+  skillGaps = [],
+  emptyMessage,
+}: {
+  skills: SkillTrend[];
+  skillGaps?: SkillGap[];
+  emptyMessage: string;
+}) {
   return (
     <div className="space-y-6">
       <Suspense fallback={<ChartSkeleton />}>
@@ -142,6 +187,7 @@ export function MarketSkillsPanel({ skills, emptyMessage }: { skills: SkillTrend
           </div>
         )}
       </Card>
+      {skillGaps.length > 0 && <SkillGapList gaps={skillGaps} />}
     </div>
   );
 }
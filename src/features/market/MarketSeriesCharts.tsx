@@ -0,0 +1,84 @@
+import { lazy, Suspense, useState } from "react";
+import { Button } from "../../ui/Button";
+import { ChartSkeleton } from "../../ui/LoadingFallbacks";
+import type { SeriesGranularity } from "./model";
+import { useMarketSeries } from "./useMarketSeries";
+
+const TrendChart = lazy(() => import("./TrendChart").then((module) => ({ default: module.TrendChart })));
+
+const GRANULARITY_OPTIONS: { value: SeriesGranularity; label: string }[] = [
+  { value: "day", label: "Daily" },
+  { value: "week", label: "Weekly" },
+  { value: "month", label: "Monthly" },
+];
+
+const SERIES_EMPTY_MESSAGE = "Trends over time appear after a few daily refreshes.";
+
+export function MarketSeriesCharts({ refreshKey }: { refreshKey?: number }) {
+  const [granularity, setGranularity] = useState<SeriesGranularity>("week");
+  const { series, loading } = useMarketSeries(granularity, refreshKey);
+
+  return (
+    <section className="space-y-4" aria-labelledby="market-series-heading">
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <h3 id="market-series-heading" className="font-display text-display-sm text-surface-900 dark:text-white">
+          Trends Over Time
+        </h3>
+        <div className="flex gap-1" role="group" aria-label="Chart period">
+          {GRANULARITY_OPTIONS.map((option) => (
+            <Button
+              key={option.value}
+              variant={granularity === option.value ? "secondary" : "ghost"}
+              size="sm"
+              aria-pressed={granularity === option.value}
+              onClick={() => setGranularity(option.value)}
+            >
+              {option.label}
+            </Button>
+          ))}
+        </div>
+      </div>
+      <div className="grid grid-cols-1 lg:grid-cols-3 gap-6">
+        <Suspense fallback={<ChartSkeleton />}>
+          <TrendChart
+            data={series.skillMentions}
+            type="line"
+            title="Skill Mentions"
+            xKey="period_start"
+            yKey="value"
+            yLabel="Mentions"
+            color="#6366f1"
+            loading={loading}
+            emptyMessage={SERIES_EMPTY_MESSAGE}
+          />
+        </Suspense>
+        <Suspense fallback={<ChartSkeleton />}>
+          <TrendChart
+            data={series.medianSalary}
+            type="line"
+            title="Median Salary"
+            xKey="period_start"
+            yKey="value"
+            yLabel="Median salary"
+            color="#10b981"
+            loading={loading}
+            emptyMessage={SERIES_EMPTY_MESSAGE}
+          />
+        </Suspense>
+        <Suspense fallback={<ChartSkeleton />}>
+          <TrendChart
+            data={series.companyPostings}
+            type="line"
+            title="Company Postings"
+            xKey="period_start"
+            yKey="value"
+            yLabel="Jobs posted"
+            color="#f59e0b"
+            loading={loading}
+            emptyMessage={SERIES_EMPTY_MESSAGE}
+          />
+        </Suspense>
+      </div>
+    </section>
+  );
+}
@@ -34,7 +34,7 @@ describe("mock market intelligence commands", () => {
     });
 
     const companies = await mockInvoke<Array<{ company_name: string }>>(
-      "get_active_companies",
+      "get_most_active_companies",
       {},
     );
     expect(companies.map((company) => company.company_name)).toContain(
@@ -47,6 +47,19 @@ describe("mock market intelligence commands", () => {
     );
     expect(locations.map((location) => location.location)).toContain("Remote");
 
+    const skillGaps = await mockInvoke<Array<{ skill_name: string; salary_delta: number | null }>>(
+      "get_personal_skill_gaps",
+      { limit: 10 },
+    );
+    expect(skillGaps[0]).toMatchObject({ skill_name: "Patient Care", salary_delta: 4000 });
+
+    const salarySeries = await mockInvoke<Array<{ period_start: string; value: number }>>(
+      "get_median_salary_series",
+      { startDate: "2026-01-01", endDate: "2026-10-01", granularity: "month" },
+    );
+    expect(salarySeries).toHaveLength(6);
+    expect(salarySeries[5]).toEqual({ period_start: "2026-10-01", value: 66500 });
+
     const snapshot = await mockInvoke<MarketSnapshot>("get_market_snapshot", {});
     expect(snapshot).toMatchObject({
       total_jobs: 911,
@@ -70,4 +83,13 @@ describe("mock market intelligence commands", () => {
       is_read: true,
     });
   });
+
+  it("removes dismissed market alerts from the main mock store", async () => {
+    const alerts = await mockInvoke<MarketAlert[]>("get_market_alerts", {});
+
+    await expect(mockInvoke<boolean>("dismiss_market_alert", { id: alerts[0].id })).resolves.toBe(true);
+
+    const updatedAlerts = await mockInvoke<MarketAlert[]>("get_market_alerts", {});
+    expect(updatedAlerts.map((alert) => alert.id)).not.toContain(alerts[0].id);
+  });
 });
@@ -41,6 +41,25 @@ export function getMockTrendingSkills() {
   ];
 }
 
+export function getMockSkillGaps() {
+  return [
+    { skill_name: "Patient Care", total_jobs: 334, avg_salary: 68000, salary_delta: 4000 },
+    { skill_name: "Data Reporting", total_jobs: 276, avg_salary: 88000, salary_delta: 24000 },
+    { skill_name: "Bilingual Communication", total_jobs: 189, avg_salary: 64000, salary_delta: 0 },
+  ];
+}
+
+export function getMockSeries(args: Record<string, unknown> | undefined, baseValue: number, step: number) {
+  const granularity = args?.granularity === "day" || args?.granularity === "month" ? args.granularity : "week";
+  const stepDays = granularity === "day" ? 1 : granularity === "week" ? 7 : 30;
+  const end = typeof args?.endDate === "string" ? new Date(`${args.endDate}T00:00:00Z`) : new Date();
+
+  return Array.from({ length: 6 }, (_, index) => {
+    const periodStart = new Date(end.getTime() - (5 - index) * stepDays * 24 * 60 * 60 * 1000);
+    return { period_start: periodStart.toISOString().slice(0, 10), value: baseValue + index * step };
+  });
+}
+
 export function getMockActiveCompanies() {
   return [
     { company_name: "CareBridge Health", total_posted: 45, avg_active: 15, hiring_trend: "up", avg_salary: 76000, growth_rate: 25 },
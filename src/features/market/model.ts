@@ -6,6 +6,13 @@ export interface SkillTrend {
   trend_direction: string;
 }
 
+export interface SkillGap {
+  skill_name: string;
+  total_jobs: number;
+  avg_salary: number | null;
+  salary_delta: number | null;
+}
+
 export interface CompanyActivity {
   company_name: string;
   total_posted: number;
@@ -60,6 +67,34 @@ export interface MarketDataResult {
   snapshotData: MarketSnapshot | null;
 }
 
+export type SeriesGranularity = "day" | "week" | "month";
+
+export interface SeriesPoint {
+  period_start: string;
+  value: number;
+}
+
+export interface MarketSeries {
+  skillMentions: SeriesPoint[];
+  medianSalary: SeriesPoint[];
+  companyPostings: SeriesPoint[];
+}
+
+const SERIES_RANGE_DAYS: Record<SeriesGranularity, number> = {
+  day: 30,
+  week: 84,
+  month: 365,
+};
+
+/** UTC date range ending today, sized for the chosen bucket. Trend rows are dated in UTC. */
+export function getSeriesRange(granularity: SeriesGranularity, now: Date): { startDate: string; endDate: string } {
+  const start = new Date(now.getTime() - (SERIES_RANGE_DAYS[granularity] - 1) * 24 * 60 * 60 * 1000);
+  return {
+    startDate: start.toISOString().slice(0, 10),
+    endDate: now.toISOString().slice(0, 10),
+  };
+}
+
 export type MarketTabId = "overview" | "skills" | "companies" | "locations" | "alerts";
 
 export function marketDataHasInputs(
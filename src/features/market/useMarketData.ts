@@ -9,11 +9,13 @@ import {
   type MarketAlert,
   type MarketDataResult,
   type MarketSnapshot,
+  type SkillGap,
   type SkillTrend,
 } from "./model";
 
 export function useMarketData() {
   const [skills, setSkills] = useState<SkillTrend[]>([]);
+  const [skillGaps, setSkillGaps] = useState<SkillGap[]>([]);
   const [companies, setCompanies] = useState<CompanyActivity[]>([]);
   const [locations, setLocations] = useState<LocationHeat[]>([]);
   const [alerts, setAlerts] = useState<MarketAlert[]>([]);
@@ -28,9 +30,10 @@ export function useMarketData() {
     try {
       setLoading(true);
       setError(null);
-      const [skillsData, companiesData, locationsData, alertsData, snapshotData] = await Promise.all([
+      const [skillsData, skillGapsData, companiesData, locationsData, alertsData, snapshotData] = await Promise.all([
         safeInvoke<SkillTrend[]>("get_trending_skills", { limit: 15 }, { logContext: "Get trending skills" }),
-        safeInvoke<CompanyActivity[]>("get_active_companies", { limit: 15 }, { logContext: "Get active companies" }),
+        safeInvoke<SkillGap[] | null>("get_personal_skill_gaps", { limit: 10 }, { logContext: "Get skill gaps" }),
+        safeInvoke<CompanyActivity[]>("get_most_active_companies", { limit: 15 }, { logContext: "Get active companies" }),
         safeInvoke<LocationHeat[]>("get_hottest_locations", { limit: 12 }, { logContext: "Get hottest locations" }),
         safeInvoke<MarketAlert[]>("get_market_alerts", {}, { logContext: "Get hiring alerts" }),
         safeInvoke<MarketSnapshot | null>("get_market_snapshot", {}, { logContext: "Get market snapshot" }),
@@ -39,6 +42,7 @@ export function useMarketData() {
       if (signal?.aborted) return null;
 
       setSkills(skillsData);
+      setSkillGaps(skillGapsData ?? []);
       setCompanies(companiesData);
       setLocations(locationsData);
       setAlerts(alertsData);
@@ -100,6 +104,15 @@ export function useMarketData() {
     }
   };
 
+  const dismissAlert = async (id: number) => {
+    try {
+      await safeInvokeWithToast("dismiss_market_alert", { id }, toast, { logContext: "Dismiss market alert" });
+      setAlerts((current) => current.filter((alert) => alert.id !== id));
+    } catch {
+      // The invocation helper already reports the error.
+    }
+  };
+
   const unreadAlertCount = useMemo(() => alerts.filter((alert) => !alert.is_read).length, [alerts]);
   const hasTrendInputs = marketDataHasInputs({
     skillsData: skills,
@@ -120,7 +133,9 @@ export function useMarketData() {
     locations,
     markAlertRead,
     markAllAlertsRead,
+    dismissAlert,
     runAnalysis,
+    skillGaps,
     skills,
     snapshot,
     unreadAlertCount,
@@ -0,0 +1,43 @@
+import { useEffect, useState } from "react";
+import { safeInvoke } from "../../platform/tauri";
+import { getSeriesRange, type MarketSeries, type SeriesGranularity, type SeriesPoint } from "./model";
+
+const EMPTY_SERIES: MarketSeries = { skillMentions: [], medianSalary: [], companyPostings: [] };
+
+export function useMarketSeries(granularity: SeriesGranularity, refreshKey?: number) {
+  const [series, setSeries] = useState<MarketSeries>(EMPTY_SERIES);
+  const [loading, setLoading] = useState(true);
+
+  useEffect(() => {
+    let cancelled = false;
+    const args = { ...getSeriesRange(granularity, new Date()), granularity };
+    setLoading(true);
+
+    Promise.all([
+      safeInvoke<SeriesPoint[] | null>("get_skill_mention_series", args, { logContext: "Get skill mention series" }),
+      safeInvoke<SeriesPoint[] | null>("get_median_salary_series", args, { logContext: "Get median salary series" }),
+      safeInvoke<SeriesPoint[] | null>("get_company_posting_series", args, { logContext: "Get company posting series" }),
+    ])
+      .then(([skillMentions, medianSalary, companyPostings]) => {
+        if (cancelled) return;
+        setSeries({
+          skillMentions: skillMentions ?? [],
+          medianSalary: medianSalary ?? [],
+          companyPostings: companyPostings ?? [],
+        });
+      })
+      .catch(() => {
+        // Charts fall back to their empty state; the invocation helper logs the error.
+        if (!cancelled) setSeries(EMPTY_SERIES);
+      })
+      .finally(() => {
+        if (!cancelled) setLoading(false);
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [granularity, refreshKey]);
+
+  return { series, loading };
+}
@@ -0,0 +1,74 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { safeInvoke } from "../../platform/tauri";
+import { createDefaultSetupConfig } from "./setupWizardPreferences";
+import {
+  applySetupPreset,
+  checkSetupSource,
+  describeSetupSourceCheck,
+} from "./setupWizardShortcuts";
+
+vi.mock("../../platform/tauri", () => ({
+  safeInvoke: vi.fn(),
+}));
+
+const mockSafeInvoke = vi.mocked(safeInvoke);
+
+beforeEach(() => {
+  vi.clearAllMocks();
+});
+
+describe("applySetupPreset", () => {
+  it("merges template fields into the draft config", async () => {
+    const draft = createDefaultSetupConfig();
+    mockSafeInvoke.mockResolvedValueOnce({
+      title_allowlist: ["Security Engineer"],
+    });
+
+    const applied = await applySetupPreset(draft, "security");
+
+    expect(mockSafeInvoke).toHaveBeenCalledWith(
+      "apply_setup_preset",
+      { config: draft, presetId: "security" },
+      expect.any(Object),
+    );
+    expect(applied.title_allowlist).toEqual(["Security Engineer"]);
+    expect(applied.alerts).toEqual(draft.alerts);
+  });
+});
+
+describe("checkSetupSource", () => {
+  it("sends the draft config with the source id", async () => {
+    const draft = createDefaultSetupConfig();
+    mockSafeInvoke.mockResolvedValueOnce({
+      source_id: "remoteok",
+      jobs_found: 2,
+      sample_titles: ["Support Specialist"],
+      errors: [],
+    });
+
+    const check = await checkSetupSource(draft, "remoteok");
+
+    expect(mockSafeInvoke).toHaveBeenCalledWith(
+      "check_setup_source",
+      { config: draft, sourceId: "remoteok" },
+      expect.any(Object),
+    );
+    expect(describeSetupSourceCheck(check)).toBe("Found 2 matching jobs.");
+  });
+});
+
+describe("describeSetupSourceCheck", () => {
+  it("tells empty searches apart from failed checks", () => {
+    const empty = {
+      source_id: "remoteok",
+      jobs_found: 0,
+      sample_titles: [],
+      errors: [],
+    };
+
+    expect(describeSetupSourceCheck(empty)).toContain("broader titles");
+    expect(
+      describeSetupSourceCheck({ ...empty, errors: ["timed out"] }),
+    ).toContain("could not be checked");
+  });
+});
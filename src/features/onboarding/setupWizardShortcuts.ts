@@ -0,0 +1,84 @@
+import { safeInvoke } from "../../platform/tauri";
+import type { SetupConfig } from "./setupWizardPreferences";
+
+export interface SetupPreset {
+  id: string;
+  name: string;
+  description: string;
+  titles: string[];
+  keywords_boost: string[];
+  keywords_exclude: string[];
+  salary_floor_usd: number;
+  allow_remote: boolean;
+  allow_hybrid: boolean;
+  allow_onsite: boolean;
+  remoteok_tags: string[];
+  weworkremotely_category: string | null;
+  hn_hiring: boolean;
+}
+
+export interface SetupSourceCheck {
+  source_id: string;
+  jobs_found: number;
+  sample_titles: string[];
+  errors: string[];
+}
+
+export interface SetupFolderImport {
+  config: SetupConfig | null;
+  config_imported: boolean;
+  database: {
+    jobs_imported: number;
+    jobs_skipped: number;
+    applications_imported: number;
+  } | null;
+}
+
+export function loadSetupPresets(): Promise<SetupPreset[]> {
+  return safeInvoke<SetupPreset[]>("get_setup_presets", {}, {
+    logContext: "Load setup templates",
+    silent: true,
+  });
+}
+
+export async function applySetupPreset(
+  config: SetupConfig,
+  presetId: string,
+): Promise<SetupConfig> {
+  const applied = await safeInvoke<SetupConfig>(
+    "apply_setup_preset",
+    { config, presetId },
+    { logContext: "Apply setup template" },
+  );
+  // Keep wizard-only fields the backend config does not carry
+  return { ...config, ...applied };
+}
+
+export function checkSetupSource(
+  config: SetupConfig,
+  sourceId: string,
+): Promise<SetupSourceCheck> {
+  return safeInvoke<SetupSourceCheck>(
+    "check_setup_source",
+    { config, sourceId },
+    { logContext: "Check setup job source" },
+  );
+}
+
+export function importSetupFolder(folder: string): Promise<SetupFolderImport> {
+  return safeInvoke<SetupFolderImport>(
+    "import_setup_folder",
+    { folder },
+    { logContext: "Import existing JobSentinel folder" },
+  );
+}
+
+export function describeSetupSourceCheck(check: SetupSourceCheck): string {
+  if (check.jobs_found === 0) {
+    return check.errors.length > 0
+      ? "This source could not be checked right now."
+      : "No matching jobs yet. Try broader titles or skills.";
+  }
+  const noun = check.jobs_found === 1 ? "job" : "jobs";
+  return `Found ${check.jobs_found} matching ${noun}.`;
+}
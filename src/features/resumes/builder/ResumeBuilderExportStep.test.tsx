@@ -11,9 +11,11 @@ describe("ResumeBuilderExportStep", () => {
     render(
       <ResumeBuilderExportStep
         exporting={false}
+        shareableCopy={false}
         onExportDocx={vi.fn()}
         onExportJson={onExportJson}
         onExportPdf={vi.fn()}
+        onShareableCopyChange={vi.fn()}
       />,
     );
 
@@ -21,4 +23,24 @@ describe("ResumeBuilderExportStep", () => {
 
     expect(onExportJson).toHaveBeenCalledOnce();
   });
+
+  it("turns on the shareable copy from its checkbox", async () => {
+    const user = userEvent.setup();
+    const onShareableCopyChange = vi.fn();
+
+    render(
+      <ResumeBuilderExportStep
+        exporting={false}
+        shareableCopy={false}
+        onExportDocx={vi.fn()}
+        onExportJson={vi.fn()}
+        onExportPdf={vi.fn()}
+        onShareableCopyChange={onShareableCopyChange}
+      />,
+    );
+
+    await user.click(screen.getByRole("checkbox", { name: /shareable copy/i }));
+
+    expect(onShareableCopyChange).toHaveBeenCalledWith(true);
+  });
 });
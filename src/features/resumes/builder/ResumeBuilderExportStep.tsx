@@ -4,16 +4,20 @@ import { CheckCircleIcon, DocxIcon, JsonIcon, PdfIcon } from "./ResumeBuilderVis
 
 interface ResumeBuilderExportStepProps {
   exporting: boolean;
+  shareableCopy: boolean;
   onExportDocx: () => void;
   onExportJson: () => void;
   onExportPdf: () => void;
+  onShareableCopyChange: (shareableCopy: boolean) => void;
 }
 
 export function ResumeBuilderExportStep({
   exporting,
+  shareableCopy,
   onExportDocx,
   onExportJson,
   onExportPdf,
+  onShareableCopyChange,
 }: ResumeBuilderExportStepProps) {
   return (
     <div className="space-y-6 text-center">
@@ -53,6 +57,22 @@ export function ResumeBuilderExportStep({
             Download JSON
           </Button>
         </div>
+        <label className="mt-6 inline-flex items-start gap-2 text-left text-sm text-surface-700 dark:text-surface-300">
+          <input
+            type="checkbox"
+            checked={shareableCopy}
+            onChange={(event) => onShareableCopyChange(event.target.checked)}
+            className="mt-0.5 h-4 w-4 rounded border-surface-300 text-sentinel-600 focus:ring-sentinel-500"
+          />
+          <span>
+            Shareable copy
+            <span className="block text-xs text-surface-500 dark:text-surface-400">
+              Leaves out your email, phone, location, and credential numbers in
+              the PDF and DOCX, for portfolios and public uploads. JSON always
+              keeps them.
+            </span>
+          </span>
+        </label>
         <p className="text-xs text-surface-500 dark:text-surface-400 mt-4">
           PDF export opens your browser's print dialog - select "Save as PDF"
         </p>
@@ -382,6 +382,8 @@ export default function ResumeBuilderPage({ onBack }: ResumeBuilderPageProps) {
     handleExportJson,
     handleExportPdf,
     previewHtml,
+    setShareableCopy,
+    shareableCopy,
   } = useResumeBuilderExports({
     contactName: contact.name,
     currentStep,
@@ -422,6 +424,7 @@ export default function ResumeBuilderPage({ onBack }: ResumeBuilderPageProps) {
         previewHtml={previewHtml}
         saving={saving}
         selectedTemplate={selectedTemplate}
+        shareableCopy={shareableCopy}
         showContactValidation={showContactValidation}
         skills={skills}
         summary={summary}
@@ -440,6 +443,7 @@ export default function ResumeBuilderPage({ onBack }: ResumeBuilderPageProps) {
         onNext={handleNext}
         onPrevious={handlePrevious}
         onSelectTemplate={setSelectedTemplate}
+        onShareableCopyChange={setShareableCopy}
         setContact={setContact}
         setNewSkill={setNewSkill}
         setSummary={setSummary}
@@ -33,6 +33,7 @@ interface ResumeBuilderWorkspaceProps {
   previewHtml: string;
   saving: boolean;
   selectedTemplate: TemplateId;
+  shareableCopy: boolean;
   showContactValidation: boolean;
   skills: SkillEntry[];
   summary: string;
@@ -51,6 +52,7 @@ interface ResumeBuilderWorkspaceProps {
   onNext: () => void;
   onPrevious: () => void;
   onSelectTemplate: (templateId: TemplateId) => void;
+  onShareableCopyChange: (shareableCopy: boolean) => void;
   setContact: Dispatch<SetStateAction<ContactInfo>>;
   setNewSkill: Dispatch<SetStateAction<SkillEntry>>;
   setSummary: Dispatch<SetStateAction<string>>;
@@ -69,6 +71,7 @@ export function ResumeBuilderWorkspace({
   previewHtml,
   saving,
   selectedTemplate,
+  shareableCopy,
   showContactValidation,
   skills,
   summary,
@@ -87,6 +90,7 @@ export function ResumeBuilderWorkspace({
   onNext,
   onPrevious,
   onSelectTemplate,
+  onShareableCopyChange,
   setContact,
   setNewSkill,
   setSummary,
@@ -149,9 +153,11 @@ export function ResumeBuilderWorkspace({
             {currentStep === 7 && (
               <ResumeBuilderExportStep
                 exporting={exporting}
+                shareableCopy={shareableCopy}
                 onExportDocx={onExportDocx}
                 onExportJson={onExportJson}
                 onExportPdf={onExportPdf}
+                onShareableCopyChange={onShareableCopyChange}
               />
             )}
 
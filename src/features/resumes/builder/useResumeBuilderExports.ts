@@ -20,6 +20,10 @@ import {
   toStructuredResume,
 } from "./resumeBuilderTransforms";
 
+// Empty rules use the backend defaults: no email, phone, address,
+// references, or credential numbers
+const SHAREABLE_REDACTION = {};
+
 interface UseResumeBuilderExportsOptions {
   contactName: string;
   currentStep: number;
@@ -40,6 +44,7 @@ export function useResumeBuilderExports({
   const [previewHtml, setPreviewHtml] = useState("");
   const [exporting, setExporting] = useState(false);
   const [atsAnalysis, setAtsAnalysis] = useState<ATSAnalysis | null>(null);
+  const [shareableCopy, setShareableCopy] = useState(false);
   const toast = useToast();
 
   // Preview handlers
@@ -95,6 +100,7 @@ export function useResumeBuilderExports({
       const docxData = await safeInvoke<number[]>("export_resume_docx", {
         resume: toStructuredResume(resumeData),
         template: selectedTemplate,
+        ...(shareableCopy ? { redaction: SHAREABLE_REDACTION } : {}),
       }, {
         logContext: "Export resume to DOCX"
       });
@@ -136,12 +142,20 @@ export function useResumeBuilderExports({
       setExporting(true);
 
       // Generate HTML using the selected template
-      const html = await safeInvoke<string>("render_resume_html", {
-        resume: toStructuredResume(resumeData),
-        templateId: selectedTemplate,
-      }, {
-        logContext: "Render resume for PDF export"
-      });
+      const html = shareableCopy
+        ? await safeInvoke<string>("export_resume_html", {
+          resume: toStructuredResume(resumeData),
+          template: selectedTemplate,
+          redaction: SHAREABLE_REDACTION,
+        }, {
+          logContext: "Render shareable resume for PDF export"
+        })
+        : await safeInvoke<string>("render_resume_html", {
+          resume: toStructuredResume(resumeData),
+          templateId: selectedTemplate,
+        }, {
+          logContext: "Render resume for PDF export"
+        });
 
       openResumePrintDialog(html);
 
@@ -167,5 +181,7 @@ export function useResumeBuilderExports({
     handleExportJson,
     handleExportPdf,
     previewHtml,
+    setShareableCopy,
+    shareableCopy,
   };
 }
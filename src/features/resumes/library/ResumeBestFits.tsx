@@ -0,0 +1,69 @@
+import { Button } from "../../../ui/Button";
+import { Card } from "../../../ui/Card";
+import { ScoreDisplay } from "../../../ui/score-display/ScoreDisplay";
+import type { MatchResult } from "./resumePageModel";
+
+interface ResumeBestFitsProps {
+  matches: MatchResult[];
+  comparing: boolean;
+  onCompareAllJobs: () => void;
+}
+
+export function ResumeBestFits({
+  matches,
+  comparing,
+  onCompareAllJobs,
+}: ResumeBestFitsProps) {
+  return (
+    <Card className="lg:col-span-3 dark:bg-surface-800">
+      <div className="flex flex-col gap-3 sm:flex-row sm:items-start sm:justify-between mb-4">
+        <div>
+          <h2 className="font-display text-display-sm text-surface-900 dark:text-white">
+            Best Fits
+          </h2>
+          <p className="text-sm text-surface-500 dark:text-surface-400">
+            Jobs whose posted skills best match this resume. Hidden and closed
+            jobs are left out.
+          </p>
+        </div>
+        <Button
+          size="sm"
+          className="w-full sm:w-auto"
+          onClick={onCompareAllJobs}
+          loading={comparing}
+          loadingText="Comparing..."
+        >
+          Compare with all jobs
+        </Button>
+      </div>
+
+      {matches.length === 0 ? (
+        <p className="text-center py-6 text-sm text-surface-500 dark:text-surface-400">
+          Compare this resume with all jobs to see your best fits here.
+        </p>
+      ) : (
+        <ol className="divide-y divide-surface-200 dark:divide-surface-700">
+          {matches.map((match) => (
+            <li
+              key={match.job_hash}
+              className="flex items-center justify-between gap-3 py-3"
+            >
+              <div className="min-w-0">
+                <h3 className="font-medium text-surface-800 dark:text-surface-200 truncate">
+                  {match.job_title}
+                </h3>
+                <p className="text-sm text-surface-500 dark:text-surface-400 truncate">
+                  {match.company}
+                  {" · "}
+                  {match.matching_skills.length} shared{" "}
+                  {match.matching_skills.length === 1 ? "skill" : "skills"}
+                </p>
+              </div>
+              <ScoreDisplay score={match.overall_match_score} size="sm" />
+            </li>
+          ))}
+        </ol>
+      )}
+    </Card>
+  );
+}
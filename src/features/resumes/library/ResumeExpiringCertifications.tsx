@@ -0,0 +1,46 @@
+import { describeCertificationExpiry, type SkillEvidence } from "./skillEvidenceModel";
+
+interface ResumeExpiringCertificationsProps {
+  certifications: SkillEvidence[];
+}
+
+export function ResumeExpiringCertifications({
+  certifications,
+}: ResumeExpiringCertificationsProps) {
+  if (certifications.length === 0) {
+    return null;
+  }
+
+  return (
+    <div
+      role="status"
+      className="mb-6 rounded-lg border border-alert-200 dark:border-alert-800 bg-alert-50 dark:bg-alert-900/20 p-4"
+    >
+      <h2 className="text-sm font-semibold text-surface-800 dark:text-surface-200">
+        Certifications to renew
+      </h2>
+      <p className="mt-1 text-xs text-surface-600 dark:text-surface-400">
+        These certifications back skills on your resume. Renew them or update
+        the skill before you apply.
+      </p>
+      <ul className="mt-2 space-y-1">
+        {certifications.map((item) => (
+          <li
+            key={item.id}
+            className="text-sm text-surface-700 dark:text-surface-300"
+          >
+            <span className="font-medium">{item.title}</span>
+            {" for "}
+            {item.skill_name}
+            {item.expires_on && (
+              <span className="text-surface-500 dark:text-surface-400">
+                {" · "}
+                {describeCertificationExpiry(item.expires_on).label}
+              </span>
+            )}
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
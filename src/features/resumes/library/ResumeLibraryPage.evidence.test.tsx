@@ -0,0 +1,118 @@
+import { render, screen, waitFor, within } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import {
+  makeResumeSummary,
+  makeUserSkill,
+  mockResumeLibraryResponses,
+  mockSafeInvokeWithToast,
+  resetResumeLibraryMocks,
+} from "./ResumeLibraryPage.testSupport";
+import ResumeLibraryPage from "./ResumeLibraryPage";
+
+const certification = {
+  id: 4,
+  skill_id: 1,
+  skill_name: "Patient Scheduling",
+  kind: "certification",
+  title: "Certified Medical Scheduler",
+  url: null,
+  issued_on: "2024-06-01",
+  expires_on: "2020-06-01",
+  created_at: "2024-06-01 12:00:00",
+};
+
+describe("Resume page skill evidence", () => {
+  beforeEach(resetResumeLibraryMocks);
+
+  it("warns about lapsed certifications and lists them under the skill", async () => {
+    mockResumeLibraryResponses({
+      get_active_resume: makeResumeSummary(),
+      get_user_skills: [makeUserSkill()],
+      get_skill_evidence: [certification],
+      get_expiring_skill_evidence: [certification],
+    });
+
+    render(<ResumeLibraryPage onBack={vi.fn()} />);
+
+    const heading = await screen.findByText("Certifications to renew");
+    const warning = heading.closest("[role='status']") as HTMLElement;
+    expect(within(warning).getByText("Certified Medical Scheduler")).toBeInTheDocument();
+    const list = screen.getByRole("list", { name: "Evidence for Patient Scheduling" });
+    expect(within(list).getByText("Expired 2020-06-01")).toBeInTheDocument();
+  });
+
+  it("shows evidence for skills a recent match shares with the job", async () => {
+    mockResumeLibraryResponses({
+      get_active_resume: makeResumeSummary(),
+      get_user_skills: [makeUserSkill()],
+      get_skill_evidence: [
+        { ...certification, expires_on: null, kind: "project", title: "Clinic intake rollout" },
+      ],
+      get_recent_matches: [
+        {
+          id: 1,
+          resume_id: 1,
+          job_hash: "job-1",
+          job_title: "Scheduling Coordinator",
+          company: "Harbor Clinic",
+          overall_match_score: 0.8,
+          skills_match_score: null,
+          experience_match_score: null,
+          education_match_score: null,
+          missing_skills: [],
+          matching_skills: ["patient scheduling"],
+          gap_analysis: null,
+          created_at: "2026-05-21T12:00:00Z",
+        },
+      ],
+    });
+
+    render(<ResumeLibraryPage onBack={vi.fn()} />);
+
+    expect(await screen.findByText("Evidence to mention")).toBeInTheDocument();
+    expect(screen.getByText("Patient Scheduling:")).toBeInTheDocument();
+  });
+
+  it("saves new evidence for a skill", async () => {
+    const user = userEvent.setup();
+    mockSafeInvokeWithToast.mockResolvedValue(1);
+    mockResumeLibraryResponses({
+      get_active_resume: makeResumeSummary(),
+      get_user_skills: [makeUserSkill()],
+    });
+
+    render(<ResumeLibraryPage onBack={vi.fn()} />);
+
+    await user.click(
+      await screen.findByRole("button", { name: "Add evidence for Patient Scheduling" }),
+    );
+    await user.selectOptions(
+      screen.getByLabelText("Evidence type for Patient Scheduling"),
+      "certification",
+    );
+    await user.type(
+      screen.getByLabelText("Evidence title for Patient Scheduling"),
+      "  Certified Medical Scheduler ",
+    );
+    await user.click(screen.getByRole("button", { name: "Save evidence" }));
+
+    await waitFor(() => {
+      expect(mockSafeInvokeWithToast).toHaveBeenCalledWith(
+        "add_skill_evidence",
+        {
+          skillId: 1,
+          evidence: {
+            kind: "certification",
+            title: "Certified Medical Scheduler",
+            url: undefined,
+            issued_on: undefined,
+            expires_on: undefined,
+          },
+        },
+        expect.anything(),
+        { logContext: "Add skill evidence" },
+      );
+    });
+  });
+});
@@ -97,6 +97,50 @@ describe("Resume page", () => {
     expect(screen.queryByText(/skills extracted|extract skills automatically/i)).not.toBeInTheDocument();
   });
 
+  it("compares the resume with all jobs and lists the best fits", async () => {
+    const user = userEvent.setup();
+    mockResumeLibraryResponses({
+      get_active_resume: makeResumeSummary(),
+      match_resume_to_all_jobs: { jobs_matched: 12, jobs_total: 12 },
+      get_best_resume_matches: [
+        {
+          id: 21,
+          resume_id: 1,
+          job_hash: "best-fit",
+          job_title: "Patient Services Lead",
+          company: "Lakeside Clinic",
+          overall_match_score: 0.91,
+          matching_skills: ["Scheduling", "Intake"],
+          missing_skills: [],
+          gap_analysis: null,
+          created_at: "2026-05-21T12:00:00Z",
+        },
+      ],
+    });
+
+    render(<ResumeLibraryPage onBack={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(screen.getByText("Patient Services Lead")).toBeInTheDocument();
+    });
+    expect(screen.getByText("Lakeside Clinic · 2 shared skills")).toBeInTheDocument();
+
+    await user.click(screen.getByRole("button", { name: "Compare with all jobs" }));
+
+    await waitFor(() => {
+      expect(mockSafeInvoke).toHaveBeenCalledWith(
+        "match_resume_to_all_jobs",
+        { resumeId: 1 },
+        { logContext: "Compare resume with all jobs" },
+      );
+    });
+    expect(mockSafeInvoke).toHaveBeenCalledWith(
+      "get_best_resume_matches",
+      { resumeId: 1, limit: 10 },
+      { logContext: "Load best resume matches" },
+    );
+  });
+
   it("does not show invalid percentages when recent matches omit optional sub-scores", async () => {
     mockResumeLibraryResponses({
       get_active_resume: makeResumeSummary(),
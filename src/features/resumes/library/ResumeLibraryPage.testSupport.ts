@@ -82,7 +82,10 @@ export function mockResumeLibraryResponses(
     if (
       command === "list_all_resumes" ||
       command === "get_user_skills" ||
-      command === "get_recent_matches"
+      command === "get_recent_matches" ||
+      command === "get_best_resume_matches" ||
+      command === "get_skill_evidence" ||
+      command === "get_expiring_skill_evidence"
     ) {
       return Promise.resolve([]);
     }
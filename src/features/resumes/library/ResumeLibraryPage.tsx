@@ -20,10 +20,14 @@ import {
 import { useResumeLibraryController } from "./useResumeLibraryController";
 import { ResumeLibraryDropdown } from "./ResumeLibraryDropdown";
 import { ResumeEmptyState } from "./ResumeEmptyState";
+import { ResumeBestFits } from "./ResumeBestFits";
 import { ResumeRecentMatches } from "./ResumeRecentMatches";
 import { ResumeTextPreviewModal } from "./ResumeTextPreviewModal";
 import { ResumeSkillsManagementCard } from "./ResumeSkillsManagementCard";
 import { ResumeSkillStrengthMix } from "./ResumeSkillStrengthMix";
+import { ResumeExpiringCertifications } from "./ResumeExpiringCertifications";
+import { groupEvidenceBySkill } from "./skillEvidenceModel";
+import { useSkillEvidence } from "./useSkillEvidence";
 
 interface ResumeLibraryPageProps {
   onBack: () => void;
@@ -36,6 +40,7 @@ export default function ResumeLibraryPage({ onBack }: ResumeLibraryPageProps) {
       handleCopyResumeText,
       handleDeleteResume,
       handleImportJsonResume,
+      handleMatchAllJobs,
       handlePreviewResumeText,
       handleSetActiveResume,
       handleSetResumeMatching,
@@ -60,8 +65,10 @@ export default function ResumeLibraryPage({ onBack }: ResumeLibraryPageProps) {
     },
     resumeState: {
       allResumes,
+      bestMatches,
       deleteConfirm,
       loading,
+      matchingAllJobs,
       recentMatches,
       resume,
       resumeMatchingEnabled,
@@ -72,6 +79,8 @@ export default function ResumeLibraryPage({ onBack }: ResumeLibraryPageProps) {
     skillState: { categoryFilter, editForm, editingSkillId, newSkillForm, showAddSkill, skills },
     previewState: { showTextPreview, textPreview, textPreviewLoading },
   } = useResumeLibraryController();
+  const { evidence, expiringEvidence, handleAddEvidence, handleDeleteEvidence } =
+    useSkillEvidence(resume?.id ?? null, skills);
 
   if (loading) {
     return <ResumeSkeleton />;
@@ -150,153 +159,165 @@ export default function ResumeLibraryPage({ onBack }: ResumeLibraryPageProps) {
             onUploadResume={handleUploadResume}
           />
         ) : (
-          <div className="grid grid-cols-1 lg:grid-cols-3 gap-6">
-            {/* Resume Info */}
-            <Card className="lg:col-span-1 dark:bg-surface-800">
-              <h2 className="font-display text-display-sm text-surface-900 dark:text-white mb-4">
-                Active Resume
-              </h2>
-              <div className="flex items-center gap-3 p-4 bg-surface-50 dark:bg-surface-700 rounded-lg mb-4">
-                <div className="w-12 h-12 shrink-0 bg-sentinel-100 dark:bg-sentinel-900/30 rounded-lg flex items-center justify-center">
-                  <DocumentIcon className="w-6 h-6 text-sentinel-600 dark:text-sentinel-400" />
+          <>
+            <ResumeExpiringCertifications certifications={expiringEvidence} />
+            <div className="grid grid-cols-1 lg:grid-cols-3 gap-6">
+              {/* Resume Info */}
+              <Card className="lg:col-span-1 dark:bg-surface-800">
+                <h2 className="font-display text-display-sm text-surface-900 dark:text-white mb-4">
+                  Active Resume
+                </h2>
+                <div className="flex items-center gap-3 p-4 bg-surface-50 dark:bg-surface-700 rounded-lg mb-4">
+                  <div className="w-12 h-12 shrink-0 bg-sentinel-100 dark:bg-sentinel-900/30 rounded-lg flex items-center justify-center">
+                    <DocumentIcon className="w-6 h-6 text-sentinel-600 dark:text-sentinel-400" />
+                  </div>
+                  <div className="min-w-0">
+                    <p className="break-words [overflow-wrap:anywhere] font-medium text-surface-800 dark:text-surface-200">
+                      {resume.name}
+                    </p>
+                    <p className="text-sm text-surface-500 dark:text-surface-400">
+                      Added: {new Date(resume.created_at).toLocaleDateString("en-US")}
+                    </p>
+                  </div>
                 </div>
-                <div className="min-w-0">
-                  <p className="break-words [overflow-wrap:anywhere] font-medium text-surface-800 dark:text-surface-200">
-                    {resume.name}
-                  </p>
-                  <p className="text-sm text-surface-500 dark:text-surface-400">
-                    Added: {new Date(resume.created_at).toLocaleDateString("en-US")}
+                <div
+                  data-testid="resume-import-status"
+                  className="mb-4 border-l-2 border-surface-200 dark:border-surface-700 pl-3 py-1"
+                >
+                  <div className="flex flex-wrap items-center gap-2">
+                    <Badge variant="surface" size="sm">
+                      {getResumeFormatLabel(resume)}
+                    </Badge>
+                    <Badge variant={getReadableTextBadgeVariant(resume)} size="sm">
+                      {getReadableTextLabel(resume)}
+                    </Badge>
+                  </div>
+                  <p className="mt-2 text-xs text-surface-500 dark:text-surface-400">
+                    {getReadableTextDescription(resume)}
                   </p>
                 </div>
-              </div>
-              <div
-                data-testid="resume-import-status"
-                className="mb-4 border-l-2 border-surface-200 dark:border-surface-700 pl-3 py-1"
-              >
-                <div className="flex flex-wrap items-center gap-2">
-                  <Badge variant="surface" size="sm">
-                    {getResumeFormatLabel(resume)}
-                  </Badge>
-                  <Badge variant={getReadableTextBadgeVariant(resume)} size="sm">
-                    {getReadableTextLabel(resume)}
-                  </Badge>
-                </div>
-                <p className="mt-2 text-xs text-surface-500 dark:text-surface-400">
-                  {getReadableTextDescription(resume)}
-                </p>
-              </div>
-              <Button
-                variant="secondary"
-                size="sm"
-                className="w-full mb-4"
-                onClick={handlePreviewResumeText}
-                loading={textPreviewLoading}
-                loadingText="Reading..."
-              >
-                See what JobSentinel read
-              </Button>
+                <Button
+                  variant="secondary"
+                  size="sm"
+                  className="w-full mb-4"
+                  onClick={handlePreviewResumeText}
+                  loading={textPreviewLoading}
+                  loadingText="Reading..."
+                >
+                  See what JobSentinel read
+                </Button>
 
-              <div className="mb-4 rounded-lg border border-surface-200 dark:border-surface-700 bg-white dark:bg-surface-800 p-3">
-                <div className="flex items-start justify-between gap-3 mb-2">
-                  <div>
-                    <h3 className="text-sm font-medium text-surface-800 dark:text-surface-200">
-                      Resume Skills Sorting
-                    </h3>
-                    <p className="text-xs text-surface-500 dark:text-surface-400">
-                      Use reviewed local skills as one signal when sorting jobs.
-                    </p>
+                <div className="mb-4 rounded-lg border border-surface-200 dark:border-surface-700 bg-white dark:bg-surface-800 p-3">
+                  <div className="flex items-start justify-between gap-3 mb-2">
+                    <div>
+                      <h3 className="text-sm font-medium text-surface-800 dark:text-surface-200">
+                        Resume Skills Sorting
+                      </h3>
+                      <p className="text-xs text-surface-500 dark:text-surface-400">
+                        Use reviewed local skills as one signal when sorting jobs.
+                      </p>
+                    </div>
+                    {resumeMatchingEnabled && (
+                      <Badge variant="success" size="sm">
+                        On
+                      </Badge>
+                    )}
                   </div>
-                  {resumeMatchingEnabled && (
-                    <Badge variant="success" size="sm">
-                      On
-                    </Badge>
-                  )}
-                </div>
-                {resumeMatchingEnabled ? (
-                  <div className="space-y-3">
-                    <p className="text-sm text-surface-600 dark:text-surface-300">
-                      Resume skills are helping sort jobs.
-                    </p>
+                  {resumeMatchingEnabled ? (
+                    <div className="space-y-3">
+                      <p className="text-sm text-surface-600 dark:text-surface-300">
+                        Resume skills are helping sort jobs.
+                      </p>
+                      <Button
+                        variant="secondary"
+                        size="sm"
+                        className="w-full"
+                        onClick={() => handleSetResumeMatching(false)}
+                        loading={resumeMatchingLoading}
+                        loadingText="Saving..."
+                      >
+                        Stop using resume skills
+                      </Button>
+                    </div>
+                  ) : skills.length > 0 ? (
                     <Button
-                      variant="secondary"
                       size="sm"
                       className="w-full"
-                      onClick={() => handleSetResumeMatching(false)}
+                      onClick={() => handleSetResumeMatching(true)}
                       loading={resumeMatchingLoading}
                       loadingText="Saving..."
                     >
-                      Stop using resume skills
+                      Use these skills to sort jobs
                     </Button>
-                  </div>
-                ) : skills.length > 0 ? (
-                  <Button
-                    size="sm"
-                    className="w-full"
-                    onClick={() => handleSetResumeMatching(true)}
-                    loading={resumeMatchingLoading}
-                    loadingText="Saving..."
-                  >
-                    Use these skills to sort jobs
-                  </Button>
-                ) : (
-                  <p className="text-sm text-surface-500 dark:text-surface-400">
-                    Review or add skills before using them to sort jobs.
-                  </p>
-                )}
-              </div>
-
-              <div className="mb-4">
-                <div className="flex items-center justify-between mb-2">
-                  <h3 className="text-sm font-medium text-surface-700 dark:text-surface-300">
-                    Saved Skills ({skills.length})
-                  </h3>
-                  <Button
-                    variant="ghost"
-                    size="sm"
-                    onClick={() => setShowAddSkill(true)}
-                  >
-                    <PlusIcon className="w-4 h-4 mr-1" />
-                    Add
-                  </Button>
-                </div>
-                <div className="flex flex-wrap gap-2">
-                  {skills.slice(0, 15).map((skill) => (
-                    <Badge key={skill.id} variant={getSkillStrengthColor(skill.proficiency_level)}>
-                      {skill.skill_name}
-                      {skill.years_experience && ` • ${skill.years_experience}y`}
-                      <span className="ml-1 text-xs opacity-70">
-                        {getSkillSourceLabel(skill.source)}
-                      </span>
-                    </Badge>
-                  ))}
-                  {skills.length > 15 && (
-                    <Badge variant="surface">+{skills.length - 15} more</Badge>
+                  ) : (
+                    <p className="text-sm text-surface-500 dark:text-surface-400">
+                      Review or add skills before using them to sort jobs.
+                    </p>
                   )}
                 </div>
-                <ResumeSkillStrengthMix skills={skills} />
-              </div>
-            </Card>
 
-            <ResumeSkillsManagementCard
-              skills={skills}
-              categoryFilter={categoryFilter}
-              setCategoryFilter={setCategoryFilter}
-              showAddSkill={showAddSkill}
-              setShowAddSkill={setShowAddSkill}
-              newSkillForm={newSkillForm}
-              setNewSkillForm={setNewSkillForm}
-              editForm={editForm}
-              setEditForm={setEditForm}
-              editingSkillId={editingSkillId}
-              setEditingSkillId={setEditingSkillId}
-              onAddSkill={handleAddSkill}
-              onUpdateSkill={handleUpdateSkill}
-              onStartEditingSkill={startEditingSkill}
-              onConfirmDeleteSkill={confirmDeleteSkill}
-            />
+                <div className="mb-4">
+                  <div className="flex items-center justify-between mb-2">
+                    <h3 className="text-sm font-medium text-surface-700 dark:text-surface-300">
+                      Saved Skills ({skills.length})
+                    </h3>
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => setShowAddSkill(true)}
+                    >
+                      <PlusIcon className="w-4 h-4 mr-1" />
+                      Add
+                    </Button>
+                  </div>
+                  <div className="flex flex-wrap gap-2">
+                    {skills.slice(0, 15).map((skill) => (
+                      <Badge key={skill.id} variant={getSkillStrengthColor(skill.proficiency_level)}>
+                        {skill.skill_name}
+                        {skill.years_experience && ` • ${skill.years_experience}y`}
+                        <span className="ml-1 text-xs opacity-70">
+                          {getSkillSourceLabel(skill.source)}
+                        </span>
+                      </Badge>
+                    ))}
+                    {skills.length > 15 && (
+                      <Badge variant="surface">+{skills.length - 15} more</Badge>
+                    )}
+                  </div>
+                  <ResumeSkillStrengthMix skills={skills} />
+                </div>
+              </Card>
 
-            <ResumeRecentMatches matches={recentMatches} />
-          </div>
+              <ResumeSkillsManagementCard
+                skills={skills}
+                categoryFilter={categoryFilter}
+                setCategoryFilter={setCategoryFilter}
+                showAddSkill={showAddSkill}
+                setShowAddSkill={setShowAddSkill}
+                newSkillForm={newSkillForm}
+                setNewSkillForm={setNewSkillForm}
+                editForm={editForm}
+                setEditForm={setEditForm}
+                editingSkillId={editingSkillId}
+                setEditingSkillId={setEditingSkillId}
+                onAddSkill={handleAddSkill}
+                onUpdateSkill={handleUpdateSkill}
+                onStartEditingSkill={startEditingSkill}
+                onConfirmDeleteSkill={confirmDeleteSkill}
+                evidenceBySkill={groupEvidenceBySkill(evidence)}
+                onAddEvidence={handleAddEvidence}
+                onDeleteEvidence={handleDeleteEvidence}
+              />
+
+              <ResumeBestFits
+                matches={bestMatches}
+                comparing={matchingAllJobs}
+                onCompareAllJobs={handleMatchAllJobs}
+              />
+
+              <ResumeRecentMatches matches={recentMatches} evidence={evidence} />
+            </div>
+          </>
         )}
       </main>
 
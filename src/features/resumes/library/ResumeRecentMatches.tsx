@@ -7,12 +7,18 @@ import {
   parseGapAnalysisLine,
   type MatchResult,
 } from "./resumePageModel";
+import {
+  getEvidenceForMatchedSkills,
+  getSkillEvidenceKindLabel,
+  type SkillEvidence,
+} from "./skillEvidenceModel";
 
 interface ResumeRecentMatchesProps {
   matches: MatchResult[];
+  evidence: SkillEvidence[];
 }
 
-export function ResumeRecentMatches({ matches }: ResumeRecentMatchesProps) {
+export function ResumeRecentMatches({ matches, evidence }: ResumeRecentMatchesProps) {
   return (
     <Card className="lg:col-span-3 dark:bg-surface-800">
       <h2 className="font-display text-display-sm text-surface-900 dark:text-white mb-4">
@@ -40,6 +46,10 @@ export function ResumeRecentMatches({ matches }: ResumeRecentMatchesProps) {
             const educationScore = isScoreFraction(match.education_match_score)
               ? match.education_match_score
               : null;
+            const matchEvidence = getEvidenceForMatchedSkills(
+              evidence,
+              match.matching_skills,
+            );
 
             return (
               <div
@@ -141,6 +151,29 @@ export function ResumeRecentMatches({ matches }: ResumeRecentMatchesProps) {
                   </div>
                 </div>
 
+                {matchEvidence.length > 0 && (
+                  <div className="mt-3 pt-3 border-t border-surface-200 dark:border-surface-700">
+                    <p className="text-xs font-medium text-surface-600 dark:text-surface-400 mb-2">
+                      Evidence to mention
+                    </p>
+                    <ul className="space-y-1">
+                      {matchEvidence.map((item) => (
+                        <li
+                          key={item.id}
+                          className="text-sm text-surface-600 dark:text-surface-300"
+                        >
+                          <span className="font-medium">{item.skill_name}:</span>{" "}
+                          {item.title}
+                          <span className="text-xs text-surface-400 dark:text-surface-500">
+                            {" · "}
+                            {getSkillEvidenceKindLabel(item.kind)}
+                          </span>
+                        </li>
+                      ))}
+                    </ul>
+                  </div>
+                )}
+
                 {match.gap_analysis && (
                   <div className="mt-3 pt-3 border-t border-surface-200 dark:border-surface-700">
                     <p className="text-xs font-medium text-surface-600 dark:text-surface-400 mb-2">
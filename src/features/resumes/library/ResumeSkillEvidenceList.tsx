@@ -0,0 +1,187 @@
+import { useState } from "react";
+import { Badge } from "../../../ui/Badge";
+import { Button } from "../../../ui/Button";
+import {
+  SKILL_EVIDENCE_KIND_OPTIONS,
+  describeCertificationExpiry,
+  getSkillEvidenceKindLabel,
+  type NewSkillEvidence,
+  type SkillEvidence,
+  type SkillEvidenceKind,
+} from "./skillEvidenceModel";
+import { TrashIcon } from "./ResumeIcons";
+
+const INPUT_CLASS =
+  "px-2 py-1.5 text-sm rounded border border-surface-300 dark:border-surface-600 bg-white dark:bg-surface-700 text-surface-800 dark:text-surface-200";
+
+const EMPTY_FORM: NewSkillEvidence = { kind: "project", title: "" };
+
+interface ResumeSkillEvidenceListProps {
+  skillName: string;
+  evidence: SkillEvidence[];
+  onAddEvidence: (evidence: NewSkillEvidence) => Promise<boolean>;
+  onDeleteEvidence: (evidenceId: number) => void;
+}
+
+function optionalValue(value: string | undefined): string | undefined {
+  const trimmed = value?.trim();
+  return trimmed ? trimmed : undefined;
+}
+
+export function ResumeSkillEvidenceList({
+  skillName,
+  evidence,
+  onAddEvidence,
+  onDeleteEvidence,
+}: ResumeSkillEvidenceListProps) {
+  const [showForm, setShowForm] = useState(false);
+  const [form, setForm] = useState<NewSkillEvidence>(EMPTY_FORM);
+  const [saving, setSaving] = useState(false);
+  const isCertification = form.kind === "certification";
+
+  const handleSave = async () => {
+    if (!form.title.trim()) return;
+    setSaving(true);
+    const saved = await onAddEvidence({
+      kind: form.kind,
+      title: form.title.trim(),
+      url: optionalValue(form.url),
+      issued_on: optionalValue(form.issued_on),
+      expires_on: isCertification ? optionalValue(form.expires_on) : undefined,
+    });
+    setSaving(false);
+    if (saved) {
+      setForm(EMPTY_FORM);
+      setShowForm(false);
+    }
+  };
+
+  return (
+    <div className="mt-2 space-y-2">
+      {evidence.length > 0 && (
+        <ul className="space-y-1" aria-label={`Evidence for ${skillName}`}>
+          {evidence.map((item) => (
+            <li
+              key={item.id}
+              className="flex flex-wrap items-center gap-2 text-xs text-surface-600 dark:text-surface-300"
+            >
+              <Badge variant="surface" size="sm">
+                {getSkillEvidenceKindLabel(item.kind)}
+              </Badge>
+              {item.url ? (
+                <a
+                  href={item.url}
+                  target="_blank"
+                  rel="noopener noreferrer"
+                  className="break-all text-sentinel-600 dark:text-sentinel-400 hover:underline"
+                >
+                  {item.title}
+                </a>
+              ) : (
+                <span className="break-words">{item.title}</span>
+              )}
+              {item.kind === "certification" && item.expires_on && (
+                <Badge
+                  variant={describeCertificationExpiry(item.expires_on).variant}
+                  size="sm"
+                >
+                  {describeCertificationExpiry(item.expires_on).label}
+                </Badge>
+              )}
+              <button
+                onClick={() => onDeleteEvidence(item.id)}
+                className="p-1 text-surface-400 hover:text-red-500 transition-colors"
+                aria-label={`Remove evidence: ${item.title}`}
+              >
+                <TrashIcon className="w-3.5 h-3.5" />
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {showForm ? (
+        <div className="space-y-2 rounded-lg bg-surface-50 dark:bg-surface-700/50 p-2">
+          <div className="grid grid-cols-1 gap-2 sm:grid-cols-2">
+            <select
+              aria-label={`Evidence type for ${skillName}`}
+              value={form.kind}
+              onChange={(e) =>
+                setForm({ ...form, kind: e.target.value as SkillEvidenceKind })
+              }
+              className={INPUT_CLASS}
+            >
+              {SKILL_EVIDENCE_KIND_OPTIONS.map((option) => (
+                <option key={option.value} value={option.value}>
+                  {option.label}
+                </option>
+              ))}
+            </select>
+            <input
+              aria-label={`Evidence title for ${skillName}`}
+              type="text"
+              placeholder="Title (e.g., Clinic scheduling rollout)"
+              value={form.title}
+              onChange={(e) => setForm({ ...form, title: e.target.value })}
+              className={INPUT_CLASS}
+            />
+            <input
+              aria-label={`Evidence link for ${skillName}`}
+              type="url"
+              placeholder="Link (optional)"
+              value={form.url ?? ""}
+              onChange={(e) => setForm({ ...form, url: e.target.value })}
+              className={INPUT_CLASS}
+            />
+            <input
+              aria-label={`Evidence date for ${skillName}`}
+              type="date"
+              value={form.issued_on ?? ""}
+              onChange={(e) => setForm({ ...form, issued_on: e.target.value })}
+              className={INPUT_CLASS}
+            />
+            {isCertification && (
+              <input
+                aria-label={`Certification expiry for ${skillName}`}
+                type="date"
+                value={form.expires_on ?? ""}
+                onChange={(e) =>
+                  setForm({ ...form, expires_on: e.target.value })
+                }
+                className={INPUT_CLASS}
+              />
+            )}
+          </div>
+          <div className="flex gap-2">
+            <Button
+              size="sm"
+              onClick={handleSave}
+              loading={saving}
+              loadingText="Saving..."
+              disabled={!form.title.trim()}
+            >
+              Save evidence
+            </Button>
+            <Button
+              size="sm"
+              variant="ghost"
+              onClick={() => {
+                setForm(EMPTY_FORM);
+                setShowForm(false);
+              }}
+            >
+              Cancel
+            </Button>
+          </div>
+        </div>
+      ) : (
+        <button
+          onClick={() => setShowForm(true)}
+          className="text-xs text-sentinel-600 dark:text-sentinel-400 hover:underline"
+        >
+          Add evidence for {skillName}
+        </button>
+      )}
+    </div>
+  );
+}
@@ -14,6 +14,8 @@ import {
   type UserSkill,
 } from "./resumePageModel";
 import { EditIcon, TrashIcon } from "./ResumeIcons";
+import { ResumeSkillEvidenceList } from "./ResumeSkillEvidenceList";
+import type { NewSkillEvidence, SkillEvidence } from "./skillEvidenceModel";
 
 interface ResumeSkillsManagementCardProps {
   skills: UserSkill[];
@@ -31,6 +33,9 @@ interface ResumeSkillsManagementCardProps {
   onUpdateSkill: (skillId: number) => void;
   onStartEditingSkill: (skill: UserSkill) => void;
   onConfirmDeleteSkill: (skill: UserSkill) => void;
+  evidenceBySkill: Map<number, SkillEvidence[]>;
+  onAddEvidence: (skillId: number, evidence: NewSkillEvidence) => Promise<boolean>;
+  onDeleteEvidence: (evidenceId: number) => void;
 }
 
 export function ResumeSkillsManagementCard({
@@ -49,6 +54,9 @@ export function ResumeSkillsManagementCard({
   onUpdateSkill,
   onStartEditingSkill,
   onConfirmDeleteSkill,
+  evidenceBySkill,
+  onAddEvidence,
+  onDeleteEvidence,
 }: ResumeSkillsManagementCardProps) {
   return (
     <Card className="lg:col-span-2 dark:bg-surface-800">
@@ -336,6 +344,12 @@ export function ResumeSkillsManagementCard({
                           </Badge>
                         </div>
                       </div>
+                      <ResumeSkillEvidenceList
+                        skillName={skill.skill_name}
+                        evidence={evidenceBySkill.get(skill.id) ?? []}
+                        onAddEvidence={(evidence) => onAddEvidence(skill.id, evidence)}
+                        onDeleteEvidence={onDeleteEvidence}
+                      />
                     </div>
                     <div className="flex shrink-0 items-center gap-1">
                       <button
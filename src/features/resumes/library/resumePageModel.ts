@@ -89,6 +89,11 @@ export interface MatchResult {
   created_at: string;
 }
 
+export interface ResumeMatchProgress {
+  jobs_matched: number;
+  jobs_total: number;
+}
+
 export function normalizeSkillStrength(value: string | null | undefined) {
   return value?.trim().toLowerCase() ?? "";
 }
@@ -0,0 +1,59 @@
+import { describe, expect, it } from "vitest";
+import {
+  describeCertificationExpiry,
+  getDaysUntilExpiry,
+  getEvidenceForMatchedSkills,
+  type SkillEvidence,
+} from "./skillEvidenceModel";
+
+function makeEvidence(overrides: Partial<SkillEvidence> = {}): SkillEvidence {
+  return {
+    id: 1,
+    skill_id: 1,
+    skill_name: "Patient Scheduling",
+    kind: "certification",
+    title: "Certified Medical Scheduler",
+    url: null,
+    issued_on: null,
+    expires_on: null,
+    created_at: "2026-05-21 12:00:00",
+    ...overrides,
+  };
+}
+
+describe("skill evidence model", () => {
+  const today = new Date(2026, 4, 21);
+
+  it("counts whole days until a certification expires", () => {
+    expect(getDaysUntilExpiry("2026-05-31", today)).toBe(10);
+    expect(getDaysUntilExpiry("2026-05-20", today)).toBe(-1);
+    expect(getDaysUntilExpiry("next spring", today)).toBeNull();
+  });
+
+  it("warns about lapsed and soon-expiring certifications", () => {
+    expect(describeCertificationExpiry("2026-05-01", today)).toEqual({
+      label: "Expired 2026-05-01",
+      variant: "danger",
+    });
+    expect(describeCertificationExpiry("2026-06-01", today)).toEqual({
+      label: "Expires in 11 days",
+      variant: "alert",
+    });
+    expect(describeCertificationExpiry("2027-05-01", today).variant).toBe(
+      "success",
+    );
+  });
+
+  it("keeps only evidence for skills the job asks for", () => {
+    const evidence = [
+      makeEvidence(),
+      makeEvidence({ id: 2, skill_id: 2, skill_name: "Medical Billing" }),
+    ];
+
+    expect(
+      getEvidenceForMatchedSkills(evidence, ["patient scheduling"]).map(
+        (item) => item.id,
+      ),
+    ).toEqual([1]);
+  });
+});
@@ -0,0 +1,117 @@
+import type { BadgeVariant } from "./resumePageModel";
+
+export type SkillEvidenceKind =
+  | "project"
+  | "repository"
+  | "certification"
+  | "other";
+
+export interface SkillEvidence {
+  id: number;
+  skill_id: number;
+  skill_name: string;
+  kind: SkillEvidenceKind;
+  title: string;
+  url: string | null;
+  issued_on: string | null;
+  expires_on: string | null;
+  created_at: string;
+}
+
+export interface NewSkillEvidence {
+  kind: SkillEvidenceKind;
+  title: string;
+  url?: string;
+  issued_on?: string;
+  expires_on?: string;
+}
+
+export const SKILL_EVIDENCE_KIND_OPTIONS: {
+  value: SkillEvidenceKind;
+  label: string;
+}[] = [
+  { value: "project", label: "Project" },
+  { value: "repository", label: "Code repository" },
+  { value: "certification", label: "Certification" },
+  { value: "other", label: "Other proof" },
+];
+
+/** Days ahead to warn about certifications that back a skill. */
+export const CERTIFICATION_EXPIRY_WARNING_DAYS = 60;
+
+const DAY_MS = 24 * 60 * 60 * 1_000;
+
+export function getSkillEvidenceKindLabel(kind: SkillEvidenceKind): string {
+  return (
+    SKILL_EVIDENCE_KIND_OPTIONS.find((option) => option.value === kind)
+      ?.label ?? "Other proof"
+  );
+}
+
+/** Whole days from `today` until a `YYYY-MM-DD` expiry; negative once lapsed. */
+export function getDaysUntilExpiry(
+  expiresOn: string,
+  today = new Date(),
+): number | null {
+  const match = /^(\d{4})-(\d{2})-(\d{2})$/.exec(expiresOn);
+  if (!match) {
+    return null;
+  }
+  const expiry = Date.UTC(
+    Number(match[1]),
+    Number(match[2]) - 1,
+    Number(match[3]),
+  );
+  const start = Date.UTC(
+    today.getFullYear(),
+    today.getMonth(),
+    today.getDate(),
+  );
+  return Math.round((expiry - start) / DAY_MS);
+}
+
+export function describeCertificationExpiry(
+  expiresOn: string,
+  today = new Date(),
+): { label: string; variant: BadgeVariant } {
+  const days = getDaysUntilExpiry(expiresOn, today);
+  if (days === null) {
+    return { label: `Expires ${expiresOn}`, variant: "surface" };
+  }
+  if (days < 0) {
+    return { label: `Expired ${expiresOn}`, variant: "danger" };
+  }
+  if (days === 0) {
+    return { label: "Expires today", variant: "danger" };
+  }
+  if (days <= CERTIFICATION_EXPIRY_WARNING_DAYS) {
+    return {
+      label: `Expires in ${days} day${days === 1 ? "" : "s"}`,
+      variant: "alert",
+    };
+  }
+  return { label: `Valid until ${expiresOn}`, variant: "success" };
+}
+
+export function groupEvidenceBySkill(
+  evidence: SkillEvidence[],
+): Map<number, SkillEvidence[]> {
+  const grouped = new Map<number, SkillEvidence[]>();
+  for (const item of evidence) {
+    grouped.set(item.skill_id, [...(grouped.get(item.skill_id) ?? []), item]);
+  }
+  return grouped;
+}
+
+/** Evidence for the skills a matched job asks for. */
+export function getEvidenceForMatchedSkills(
+  evidence: SkillEvidence[],
+  matchingSkills: string[],
+): SkillEvidence[] {
+  const wanted = new Set(
+    matchingSkills.map((skill) => skill.trim().toLowerCase()),
+  );
+  return evidence.filter((item) =>
+    wanted.has(item.skill_name.trim().toLowerCase()),
+  );
+}
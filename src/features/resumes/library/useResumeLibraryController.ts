@@ -12,6 +12,7 @@ import {
   type NewSkill,
   type ResumeData,
   type ResumeMatchingPreference,
+  type ResumeMatchProgress,
   type ResumeTextPreview,
   type SkillUpdate,
   type UserSkill,
@@ -22,6 +23,8 @@ export function useResumeLibraryController() {
   const [allResumes, setAllResumes] = useState<ResumeData[]>([]);
   const [skills, setSkills] = useState<UserSkill[]>([]);
   const [recentMatches, setRecentMatches] = useState<MatchResult[]>([]);
+  const [bestMatches, setBestMatches] = useState<MatchResult[]>([]);
+  const [matchingAllJobs, setMatchingAllJobs] = useState(false);
   const [loading, setLoading] = useState(true);
   const [uploading, setUploading] = useState(false);
   const [editingSkillId, setEditingSkillId] = useState<number | null>(null);
@@ -70,18 +73,21 @@ export function useResumeLibraryController() {
         setResumeMatchingEnabled(isResumeMatchingEnabled(preferenceData));
 
         if (resumeData) {
-          const [skillsData, matchesData] = await Promise.all([
+          const [skillsData, matchesData, bestMatchesData] = await Promise.all([
             safeInvoke<UserSkill[]>("get_user_skills", { resumeId: resumeData.id }, { logContext: "Load user skills" }),
             safeInvoke<MatchResult[]>("get_recent_matches", { resumeId: resumeData.id, limit: 10 }, { logContext: "Load recent matches" }),
+            safeInvoke<MatchResult[]>("get_best_resume_matches", { resumeId: resumeData.id, limit: 10 }, { logContext: "Load best resume matches" }),
           ]);
 
           if (cancelled) return;
 
           setSkills(skillsData);
           setRecentMatches(matchesData);
+          setBestMatches(bestMatchesData);
         } else {
           setSkills([]);
           setRecentMatches([]);
+          setBestMatches([]);
         }
       } catch (error: unknown) {
         if (cancelled) return;
@@ -121,15 +127,18 @@ export function useResumeLibraryController() {
       setResumeMatchingEnabled(isResumeMatchingEnabled(preferenceData));
 
       if (resumeData) {
-        const [skillsData, matchesData] = await Promise.all([
+        const [skillsData, matchesData, bestMatchesData] = await Promise.all([
           safeInvoke<UserSkill[]>("get_user_skills", { resumeId: resumeData.id }, { logContext: "Refetch user skills" }),
           safeInvoke<MatchResult[]>("get_recent_matches", { resumeId: resumeData.id, limit: 10 }, { logContext: "Refetch recent matches" }),
+          safeInvoke<MatchResult[]>("get_best_resume_matches", { resumeId: resumeData.id, limit: 10 }, { logContext: "Refetch best resume matches" }),
         ]);
         setSkills(skillsData);
         setRecentMatches(matchesData);
+        setBestMatches(bestMatchesData);
       } else {
         setSkills([]);
         setRecentMatches([]);
+        setBestMatches([]);
       }
     } catch (error: unknown) {
       const safeError = getSafeErrorToastCopy(error, {
@@ -246,6 +255,36 @@ export function useResumeLibraryController() {
     }
   };
 
+  const handleMatchAllJobs = async () => {
+    if (!resume) return;
+
+    try {
+      setMatchingAllJobs(true);
+      const progress = await safeInvoke<ResumeMatchProgress>(
+        "match_resume_to_all_jobs",
+        { resumeId: resume.id },
+        { logContext: "Compare resume with all jobs" },
+      );
+      const matches = await safeInvoke<MatchResult[]>(
+        "get_best_resume_matches",
+        { resumeId: resume.id, limit: 10 },
+        { logContext: "Load best resume matches" },
+      );
+      setBestMatches(matches);
+      toast.success(
+        "Best fits updated",
+        `Compared this resume with ${progress.jobs_matched} ${progress.jobs_matched === 1 ? "job" : "jobs"}.`,
+      );
+    } catch (error: unknown) {
+      const safeError = getSafeErrorToastCopy(error, {
+        fallbackTitle: "Could not compare jobs",
+      });
+      toast.error(safeError.title, safeError.message);
+    } finally {
+      setMatchingAllJobs(false);
+    }
+  };
+
   const handleSetResumeMatching = async (enabled: boolean) => {
     if (enabled && (!resume || skills.length === 0)) {
       toast.error("Review skills first", "Add or review at least one skill before using it to sort jobs.");
@@ -370,8 +409,10 @@ export function useResumeLibraryController() {
   return {
     resumeState: {
       allResumes,
+      bestMatches,
       deleteConfirm,
       loading,
+      matchingAllJobs,
       recentMatches,
       resume,
       resumeMatchingEnabled,
@@ -403,6 +444,7 @@ export function useResumeLibraryController() {
       handleCopyResumeText,
       handleDeleteResume,
       handleImportJsonResume,
+      handleMatchAllJobs,
       handlePreviewResumeText,
       handleSetActiveResume,
       handleSetResumeMatching,
@@ -0,0 +1,98 @@
+import { useCallback, useEffect, useState } from "react";
+import { useToast } from "../../../shared/toast/useToast";
+import { safeInvoke, safeInvokeWithToast } from "../../../platform/tauri";
+import {
+  CERTIFICATION_EXPIRY_WARNING_DAYS,
+  type NewSkillEvidence,
+  type SkillEvidence,
+} from "./skillEvidenceModel";
+import type { UserSkill } from "./resumePageModel";
+
+/**
+ * Loads evidence for the active resume's skills along with certifications
+ * that are close to lapsing. Reloads whenever the skill list changes so
+ * deleted skills drop their evidence.
+ */
+export function useSkillEvidence(
+  resumeId: number | null,
+  skills: UserSkill[],
+) {
+  const [evidence, setEvidence] = useState<SkillEvidence[]>([]);
+  const [expiringEvidence, setExpiringEvidence] = useState<SkillEvidence[]>(
+    [],
+  );
+  const toast = useToast();
+
+  const loadEvidence = useCallback(async () => {
+    if (resumeId === null) {
+      setEvidence([]);
+      setExpiringEvidence([]);
+      return;
+    }
+    try {
+      const [evidenceData, expiringData] = await Promise.all([
+        safeInvoke<SkillEvidence[]>(
+          "get_skill_evidence",
+          { resumeId },
+          { logContext: "Load skill evidence" },
+        ),
+        safeInvoke<SkillEvidence[]>(
+          "get_expiring_skill_evidence",
+          { resumeId, withinDays: CERTIFICATION_EXPIRY_WARNING_DAYS },
+          { logContext: "Check expiring certifications" },
+        ),
+      ]);
+      setEvidence(evidenceData ?? []);
+      setExpiringEvidence(expiringData ?? []);
+    } catch {
+      // Evidence is optional context; the skills list still works without it
+      setEvidence([]);
+      setExpiringEvidence([]);
+    }
+  }, [resumeId]);
+
+  useEffect(() => {
+    void loadEvidence();
+  }, [loadEvidence, skills]);
+
+  const handleAddEvidence = async (
+    skillId: number,
+    newEvidence: NewSkillEvidence,
+  ): Promise<boolean> => {
+    try {
+      await safeInvokeWithToast(
+        "add_skill_evidence",
+        { skillId, evidence: newEvidence },
+        toast,
+        { logContext: "Add skill evidence" },
+      );
+      toast.success("Evidence added", `Saved "${newEvidence.title}"`);
+      await loadEvidence();
+      return true;
+    } catch {
+      // Error already logged and shown to user
+      return false;
+    }
+  };
+
+  const handleDeleteEvidence = async (evidenceId: number) => {
+    try {
+      await safeInvokeWithToast(
+        "delete_skill_evidence",
+        { evidenceId },
+        toast,
+        { logContext: "Delete skill evidence" },
+      );
+      await loadEvidence();
+    } catch {
+      // Error already logged and shown to user
+    }
+  };
+
+  return {
+    evidence,
+    expiringEvidence,
+    handleAddEvidence,
+    handleDeleteEvidence,
+  };
+}
@@ -7,6 +7,7 @@ import { OfferReviewPanel } from "./OfferReviewPanel";
 import { ChartIcon } from "./SalaryPrimitives";
 import {
   getSalaryStageLabel,
+  getSalaryTrendLabel,
   type OfferReviewInput,
   type SalaryBenchmark,
   type SalarySampleQuality,
@@ -88,6 +89,7 @@ export function SalaryEvidenceCard({
                 <div className="flex flex-wrap gap-2 sm:justify-end">
                   <Badge variant="sentinel">{benchmark.sample_size} salary records</Badge>
                   <Badge variant={sampleQuality.variant}>{sampleQuality.label}</Badge>
+                  {getSalaryTrendLabel(benchmark) && <Badge variant="surface">{getSalaryTrendLabel(benchmark)}</Badge>}
                 </div>
                 <p className="max-w-sm text-sm text-surface-600 dark:text-surface-400 sm:text-right">
                   {sampleQuality.detail}
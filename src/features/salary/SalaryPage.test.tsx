@@ -166,6 +166,22 @@ describe("Salary", () => {
     expect(screen.getByText(/use this as a weak signal/i)).toBeInTheDocument();
   });
 
+  it("shows which way median pay is moving when trend data exists", async () => {
+    const user = userEvent.setup();
+    mockInvoke.mockResolvedValueOnce({
+      ...salaryBenchmark,
+      salary_growth_pct: -3.4,
+      trend_direction: "down",
+    });
+    renderSalary();
+
+    await user.type(screen.getByLabelText("Job Title"), "Registered Nurse");
+    await user.type(screen.getByLabelText("Location"), "Denver, CO");
+    await user.click(screen.getByRole("button", { name: "Check Pay Range" }));
+
+    expect(await screen.findByText("Median pay down 3.4%")).toBeInTheDocument();
+  });
+
   it("shows a past-pay guardrail without making legal claims", async () => {
     const user = userEvent.setup();
     renderSalary();
@@ -10,6 +10,8 @@ interface MockSalaryBenchmark {
   average_salary: number;
   sample_size: number;
   last_updated: string;
+  salary_growth_pct: number | null;
+  trend_direction: "up" | "down" | "flat" | null;
 }
 
 export function getMockSalaryBenchmark(args?: Record<string, unknown>): MockSalaryBenchmark {
@@ -41,6 +43,8 @@ export function getMockSalaryBenchmark(args?: Record<string, unknown>): MockSala
     average_salary: base + 5000,
     sample_size: 128,
     last_updated: new Date().toISOString(),
+    salary_growth_pct: 3.2,
+    trend_direction: "up",
   };
 }
 
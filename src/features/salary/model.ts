@@ -13,6 +13,19 @@ export interface SalaryBenchmark {
   average_salary: number;
   sample_size: number;
   last_updated: string;
+  salary_growth_pct?: number | null;
+  trend_direction?: SalaryTrendDirection | null;
+}
+
+export type SalaryTrendDirection = "up" | "down" | "flat";
+
+export function getSalaryTrendLabel(benchmark: SalaryBenchmark): string | null {
+  if (!benchmark.trend_direction) return null;
+  if (benchmark.trend_direction === "flat") return "Median pay holding steady";
+  const change = Math.abs(benchmark.salary_growth_pct ?? 0).toFixed(1);
+  return benchmark.trend_direction === "up"
+    ? `Median pay up ${change}%`
+    : `Median pay down ${change}%`;
 }
 
 export type SalarySeniority = "entry" | "mid" | "senior" | "staff" | "principal";
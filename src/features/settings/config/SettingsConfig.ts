@@ -21,6 +21,12 @@ export interface SettingsProps {
   linkedinWorkbench?: ReactNode;
 }
 
+/** A country to search beyond the home country, with its country boards. */
+export interface CountryPreference {
+  code: string;
+  boards: string[];
+}
+
 export interface JobsWithGptPayload {
   endpoint: string;
   titles: string[];
@@ -50,6 +56,9 @@ export interface SourceRequestSummary {
   outcome: SourceRequestOutcome;
 }
 
+/** What a scheduled scrape does on a low battery or metered connection */
+export type PowerSavingAction = "run" | "reduce" | "skip";
+
 // Config interface without sensitive credential fields (stored through secure storage)
 export interface Config {
   title_allowlist: string[];
@@ -61,9 +70,11 @@ export interface Config {
     allow_hybrid: boolean;
     allow_onsite: boolean;
     cities: string[];
+    countries?: CountryPreference[];
   };
   salary_floor_usd: number;
   salary_target_usd?: number;
+  salary_target_max_usd?: number;
   preferred_companies: string[];
   blocked_companies: string[];
   auto_refresh: {
@@ -85,6 +96,16 @@ export interface Config {
       from_email: string;
       to_emails: string[];
       use_starttls: boolean;
+      send_interview_calendar?: boolean;
+      digest?: {
+        enabled: boolean;
+        frequency: "daily" | "weekly";
+        send_hour: number;
+        send_weekday: number;
+        recipients: string[];
+        min_score: number;
+        attach_market_report?: boolean;
+      };
     };
     discord: {
       enabled: boolean;
@@ -100,11 +121,34 @@ export interface Config {
       enabled: boolean;
       // webhook_url stored securely
     };
+    webhook?: {
+      enabled: boolean;
+      urls: string[];
+      payload_template?: Record<string, unknown> | null;
+      // signing secret stored securely
+    };
+    ntfy?: {
+      enabled: boolean;
+      server_url: string;
+      priority: number;
+      // topic and access token stored securely
+    };
+    pushover?: {
+      enabled: boolean;
+      priority: number;
+      // user key and app token stored securely
+    };
     desktop: {
       enabled: boolean;
       show_when_focused: boolean;
       play_sound: boolean;
     };
+    weekly_report?: {
+      enabled: boolean;
+      send_weekday: number;
+      send_hour: number;
+    };
+    include_resume_match?: boolean;
   };
   linkedin: {
     enabled: boolean;
@@ -127,6 +171,8 @@ export interface Config {
   };
   builtin: {
     enabled: boolean;
+    remote_only?: boolean;
+    query?: string;
     cities: string[];
     category?: string;
     limit: number;
@@ -173,6 +219,12 @@ export interface Config {
     location?: string;
     limit: number;
   };
+  power_saving?: {
+    low_battery: PowerSavingAction;
+    battery_threshold_percent: number;
+    metered_connection: PowerSavingAction;
+    reduced_source_count: number;
+  };
   jobswithgpt_endpoint: string;
   jobswithgpt_approval: JobsWithGptApproval;
   external_ai: ExternalAiSettings;
@@ -299,6 +351,27 @@ export function getSettingsSourceLocation(
     .find((city) => city.length > 0);
 }
 
+/** BuiltIn keyword and cities, filled from the search preferences when unset. */
+export function getSettingsBuiltInSearch(
+  config: Pick<
+    Config,
+    "builtin" | "title_allowlist" | "keywords_boost" | "location_preferences"
+  >,
+): { query: string; cities: string[] } {
+  const { allow_hybrid, allow_onsite, cities } = config.location_preferences;
+  return {
+    query: config.builtin?.query?.trim() || buildSettingsSourceQuery(config),
+    cities: config.builtin?.cities?.length
+      ? config.builtin.cities
+      : allow_hybrid || allow_onsite
+        ? cities
+            .map((city) => city.trim())
+            .filter((city) => city.length > 0)
+            .slice(0, 10)
+        : [],
+  };
+}
+
 export const isValidEmail = (email: string): boolean => {
   if (!email) return true;
   return /^[^\s@]+@[^\s@]+\.[^\s@]+$/.test(email);
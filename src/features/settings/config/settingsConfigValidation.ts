@@ -92,6 +92,19 @@ function isOptionalJobsWithGptPayload(value: unknown): boolean {
   );
 }
 
+function isOptionalCountryPreferences(value: unknown): boolean {
+  if (value === undefined) return true;
+  return (
+    Array.isArray(value) &&
+    value.every(
+      (country) =>
+        isPlainRecord(country) &&
+        hasStringField(country, "code") &&
+        hasStringArrayField(country, "boards"),
+    )
+  );
+}
+
 function isExternalAiSettings(value: unknown): value is ExternalAiSettings {
   if (!isPlainRecord(value)) return false;
   const redaction = recordField(value, "redaction");
@@ -153,15 +166,18 @@ export function isSettingsBackupConfig(value: unknown): value is Config {
     hasStringArrayField(value, "blocked_companies") &&
     hasNumberField(value, "salary_floor_usd") &&
     hasOptionalNumberField(value, "salary_target_usd") &&
+    hasOptionalNumberField(value, "salary_target_max_usd") &&
     !!location &&
     hasBooleanField(location, "allow_remote") &&
     hasBooleanField(location, "allow_hybrid") &&
     hasBooleanField(location, "allow_onsite") &&
     hasStringArrayField(location, "cities") &&
+    isOptionalCountryPreferences(location.countries) &&
     !!autoRefresh &&
     hasBooleanField(autoRefresh, "enabled") &&
     hasNumberField(autoRefresh, "interval_minutes") &&
     !!alerts &&
+    hasOptionalBooleanField(alerts, "include_resume_match") &&
     !!slack &&
     hasBooleanField(slack, "enabled") &&
     !!email &&
@@ -172,6 +188,7 @@ export function isSettingsBackupConfig(value: unknown): value is Config {
     hasStringField(email, "from_email") &&
     hasStringArrayField(email, "to_emails") &&
     hasBooleanField(email, "use_starttls") &&
+    hasOptionalBooleanField(email, "send_interview_calendar") &&
     !!discord &&
     hasBooleanField(discord, "enabled") &&
     hasOptionalStringField(discord, "user_id_to_mention") &&
@@ -213,6 +230,8 @@ export function isSettingsBackupConfig(value: unknown): value is Config {
     !!builtin &&
     hasBooleanField(builtin, "enabled") &&
     hasStringArrayField(builtin, "cities") &&
+    hasOptionalBooleanField(builtin, "remote_only") &&
+    hasOptionalStringField(builtin, "query") &&
     hasOptionalStringField(builtin, "category") &&
     hasNumberField(builtin, "limit") &&
     !!hnHiring &&
@@ -84,6 +84,32 @@ export function SettingsResumeMatchingSection({
               <div className="w-11 h-6 bg-surface-200 peer-focus:outline-none peer-focus-visible:ring-4 peer-focus-visible:ring-sentinel-300 dark:peer-focus-visible:ring-sentinel-800 rounded-full peer dark:bg-surface-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-surface-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-surface-600 peer-checked:bg-sentinel-500"></div>
             </label>
           </div>
+          <div className="mt-3 pt-3 border-t border-surface-200 dark:border-surface-700 space-y-1">
+            <label className="flex items-center gap-2 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={config.alerts.include_resume_match ?? false}
+                onChange={(e) =>
+                  onConfigChange({
+                    ...config,
+                    alerts: {
+                      ...config.alerts,
+                      include_resume_match: e.target.checked,
+                    },
+                  })
+                }
+                className="w-4 h-4 rounded border-surface-300 text-sentinel-500 focus-visible:ring-sentinel-500"
+              />
+              <span className="text-sm text-surface-700 dark:text-surface-300">
+                Show resume match in job alerts
+              </span>
+            </label>
+            <p className="text-xs text-surface-500 dark:text-surface-400">
+              Compares your active resume with each new high match before its
+              alert is sent, so alerts read like &quot;92% config match, 78%
+              resume match&quot;.
+            </p>
+          </div>
           <div className="mt-3 pt-3 border-t border-surface-200 dark:border-surface-700">
             <p className="flex items-start gap-1.5 text-xs text-surface-500 dark:text-surface-400">
               <SettingsSymbol
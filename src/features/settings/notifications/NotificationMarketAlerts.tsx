@@ -0,0 +1,84 @@
+import { HelpIcon } from "../../../ui/HelpIcon";
+import {
+  MARKET_ALERT_TYPES,
+  type MarketAlertNotificationConfig,
+  type MarketAlertPreferences,
+  type MarketAlertSeverity,
+  type MarketAlertType,
+} from "../../../shared/notificationPreferences";
+
+const MARKET_ALERT_LABELS: Record<MarketAlertType, string> = {
+  skillSurge: "Skill demand surges",
+  salarySpike: "Pay jumps",
+  hiringFreeze: "Hiring freezes",
+  hiringSpree: "Hiring sprees",
+  locationBoom: "Busy locations",
+  roleObsolete: "Roles in decline",
+};
+
+const SEVERITY_OPTIONS: Array<{ value: MarketAlertSeverity; label: string }> = [
+  { value: "info", label: "All alerts" },
+  { value: "warning", label: "Warnings and critical" },
+  { value: "critical", label: "Critical only" },
+];
+
+interface MarketAlertsSectionProps {
+  prefs: MarketAlertPreferences;
+  onChange: (alertType: MarketAlertType, config: MarketAlertNotificationConfig) => void;
+}
+
+export function MarketAlertsSection({ prefs, onChange }: MarketAlertsSectionProps) {
+  return (
+    <div className="mt-4 pt-4 border-t border-surface-200 dark:border-surface-700">
+      <div className="text-xs font-medium text-surface-500 dark:text-surface-400 uppercase tracking-wide flex items-center gap-1 mb-1">
+        Hiring Trends alerts
+        <HelpIcon
+          text="New Hiring Trends alerts are sent once to the email, Slack, and Discord alerts you turned on. Quiet hours and vacation mode hold them."
+          size="sm"
+        />
+      </div>
+      <p className="text-xs text-surface-500 dark:text-surface-400 mb-3">
+        Choose which market changes reach your alert channels. They always
+        stay on the Hiring Trends page.
+      </p>
+      {MARKET_ALERT_TYPES.map((alertType) => {
+        const config = prefs[alertType];
+        const label = MARKET_ALERT_LABELS[alertType];
+        return (
+          <div
+            key={alertType}
+            className="flex flex-wrap items-center justify-between gap-2 py-2 border-b border-surface-200 dark:border-surface-700 last:border-b-0"
+          >
+            <label className="flex items-center gap-2 text-sm text-surface-700 dark:text-surface-300 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={config.enabled}
+                onChange={(e) => onChange(alertType, { ...config, enabled: e.target.checked })}
+                className="w-4 h-4 rounded border-surface-300 text-sentinel-500 focus-visible:ring-sentinel-500"
+              />
+              {label}
+            </label>
+            <select
+              aria-label={`${label}: which alerts to send`}
+              value={config.minSeverity}
+              onChange={(e) =>
+                onChange(alertType, {
+                  ...config,
+                  minSeverity: e.target.value as MarketAlertSeverity,
+                })
+              }
+              disabled={!config.enabled}
+              className="px-2 py-1 text-sm border border-surface-300 dark:border-surface-600 rounded bg-white dark:bg-surface-800 text-surface-900 dark:text-surface-100 disabled:opacity-50"
+            >
+              {SEVERITY_OPTIONS.map((option) => (
+                <option key={option.value} value={option.value}>
+                  {option.label}
+                </option>
+              ))}
+            </select>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
@@ -37,6 +37,24 @@ describe("shouldNotifyForJob", () => {
     expect(normalized.jobswithgpt.soundEnabled).toBe(false);
   });
 
+  it("fills in market alert settings saved before they existed", () => {
+    const legacyPrefs = {
+      ...DEFAULT_PREFS,
+      marketAlerts: { hiringSpree: { enabled: false, minSeverity: "warning" } },
+    } as unknown as NotificationPreferences;
+
+    const normalized = normalizeNotificationPreferences(legacyPrefs);
+
+    expect(normalized.marketAlerts.hiringSpree).toEqual({
+      enabled: false,
+      minSeverity: "warning",
+    });
+    expect(normalized.marketAlerts.skillSurge).toEqual({
+      enabled: true,
+      minSeverity: "info",
+    });
+  });
+
   describe("global settings", () => {
     it("returns false when global.enabled is false", () => {
       const prefs = {
@@ -290,6 +290,35 @@ describe("NotificationPreferences Component", () => {
     });
   });
 
+  describe("hiring trends alerts", () => {
+    it("saves a market alert type switched off", async () => {
+      mockInvoke
+        .mockResolvedValueOnce(DEFAULT_PREFS)
+        .mockResolvedValueOnce(true);
+
+      render(<NotificationPreferencesComponent />);
+
+      const checkbox = await screen.findByRole("checkbox", {
+        name: "Hiring sprees",
+      });
+      fireEvent.click(checkbox);
+
+      await waitFor(() => {
+        expect(mockInvoke).toHaveBeenCalledWith(
+          "save_notification_preferences",
+          expect.objectContaining({
+            prefs: expect.objectContaining({
+              marketAlerts: expect.objectContaining({
+                hiringSpree: { enabled: false, minSeverity: "info" },
+                skillSurge: { enabled: true, minSeverity: "info" },
+              }),
+            }),
+          }),
+        );
+      });
+    });
+  });
+
   describe("company filters", () => {
     beforeEach(() => {
       mockInvoke.mockResolvedValue(DEFAULT_PREFS);
@@ -3,10 +3,13 @@ import { Card } from "../../../ui/Card";
 import { Badge } from "../../../ui/Badge";
 import { HelpIcon } from "../../../ui/HelpIcon";
 import { AdvancedFiltersSection } from "./NotificationAdvancedFilters";
+import { MarketAlertsSection } from "./NotificationMarketAlerts";
 import { useToast } from "../../../shared/toast/useToast";
 import {
   type SourceNotificationConfig,
   type AdvancedFilters,
+  type MarketAlertNotificationConfig,
+  type MarketAlertType,
   type NotificationPreferences as NotificationPreferencesType,
   DEFAULT_PREFERENCES,
   loadNotificationPreferencesAsync,
@@ -213,6 +216,17 @@ export const NotificationPreferences = memo(function NotificationPreferences() {
     [prefs, savePrefs],
   );
 
+  const handleMarketAlertChange = useCallback(
+    (alertType: MarketAlertType, config: MarketAlertNotificationConfig) => {
+      const updated = {
+        ...prefs,
+        marketAlerts: { ...prefs.marketAlerts, [alertType]: config },
+      };
+      savePrefs(updated);
+    },
+    [prefs, savePrefs],
+  );
+
   if (loading) {
     return (
       <Card>
@@ -369,6 +383,12 @@ export const NotificationPreferences = memo(function NotificationPreferences() {
           onChange={handleAdvancedFiltersChange}
           disabled={!prefs.global.enabled}
         />
+
+        {/* Hiring Trends alerts */}
+        <MarketAlertsSection
+          prefs={prefs.marketAlerts}
+          onChange={handleMarketAlertChange}
+        />
       </div>
     </Card>
   );
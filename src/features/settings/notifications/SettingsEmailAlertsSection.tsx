@@ -357,6 +357,35 @@ export function SettingsEmailAlertsSection({
               autoComplete="email"
             />
           </div>
+          <div className="space-y-1">
+            <label className="flex items-center gap-2 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={config.alerts.email?.send_interview_calendar ?? false}
+                onChange={(e) =>
+                  setConfig({
+                    ...config,
+                    alerts: {
+                      ...config.alerts,
+                      email: {
+                        ...config.alerts.email,
+                        send_interview_calendar: e.target.checked,
+                      },
+                    },
+                  })
+                }
+                className="w-4 h-4 rounded border-surface-300 text-sentinel-500 focus-visible:ring-sentinel-500"
+              />
+              <span className="text-sm text-surface-700 dark:text-surface-300">
+                Email calendar invites for interviews
+              </span>
+            </label>
+            <p className="text-xs text-surface-500 dark:text-surface-400">
+              Sends an invite when you schedule or move an interview, and a
+              cancellation when you move or delete one, so your phone calendar
+              stays current.
+            </p>
+          </div>
         </div>
       )}
     </div>
@@ -1,4 +1,7 @@
-import type { NotificationPreferences } from "../../../shared/notificationPreferences";
+import {
+  DEFAULT_MARKET_ALERT_PREFERENCES,
+  type NotificationPreferences,
+} from "../../../shared/notificationPreferences";
 
 export const DEFAULT_PREFS: NotificationPreferences = {
   linkedin: { enabled: false, minScoreThreshold: 70, soundEnabled: false },
@@ -20,4 +23,5 @@ export const DEFAULT_PREFS: NotificationPreferences = {
     includedCompanies: [],
     excludedCompanies: [],
   },
+  marketAlerts: DEFAULT_MARKET_ALERT_PREFERENCES,
 };
@@ -0,0 +1,111 @@
+import { useState } from "react";
+import { Badge } from "../../../ui/Badge";
+import { Button } from "../../../ui/Button";
+import { HelpIcon } from "../../../ui/HelpIcon";
+import { Input } from "../../../ui/Input";
+import type { Config, CountryPreference } from "../config/SettingsConfig";
+import {
+  addCountry,
+  boardsForCountry,
+  normalizeCountryCode,
+  removeCountry,
+  toggleCountryBoard,
+} from "./countryPreferences";
+
+interface SettingsCountryPreferencesProps {
+  config: Config;
+  onConfigChange: (config: Config) => void;
+}
+
+export function SettingsCountryPreferences({
+  config,
+  onConfigChange,
+}: SettingsCountryPreferencesProps) {
+  const [countryInput, setCountryInput] = useState("");
+  const countries = config.location_preferences.countries ?? [];
+
+  const updateCountries = (next: CountryPreference[]) => {
+    onConfigChange({
+      ...config,
+      location_preferences: {
+        ...config.location_preferences,
+        countries: next,
+      },
+    });
+  };
+
+  const handleAddCountry = () => {
+    if (!normalizeCountryCode(countryInput)) return;
+    updateCountries(addCountry(countries, countryInput));
+    setCountryInput("");
+  };
+
+  return (
+    <section className="mb-6">
+      <h3 className="font-medium text-surface-800 dark:text-surface-200 mb-3 flex items-center gap-2">
+        Other Countries
+        <HelpIcon text="Add countries beyond your home country. On-site and hybrid jobs in countries you have not added rank lower. Some countries have their own job boards you can check." />
+      </h3>
+      <div className="flex gap-2 mb-2">
+        <Input
+          aria-label="Country code"
+          placeholder="Two-letter code, e.g. DE or GB"
+          value={countryInput}
+          maxLength={2}
+          onChange={(e) => setCountryInput(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") {
+              e.preventDefault();
+              handleAddCountry();
+            }
+          }}
+        />
+        <Button
+          onClick={handleAddCountry}
+          disabled={!normalizeCountryCode(countryInput)}
+        >
+          Add
+        </Button>
+      </div>
+      {countries.length === 0 ? (
+        <p className="text-sm text-surface-400">
+          Only your home country is searched
+        </p>
+      ) : (
+        <ul className="space-y-2">
+          {countries.map((country) => (
+            <li key={country.code} className="flex flex-wrap items-center gap-3">
+              <Badge
+                variant="surface"
+                removable
+                onRemove={() =>
+                  updateCountries(removeCountry(countries, country.code))
+                }
+              >
+                {country.code}
+              </Badge>
+              {boardsForCountry(country.code).map((board) => (
+                <label
+                  key={board.id}
+                  className="flex items-center gap-2 text-sm text-surface-700 dark:text-surface-300 cursor-pointer"
+                >
+                  <input
+                    type="checkbox"
+                    checked={country.boards.includes(board.id)}
+                    onChange={() =>
+                      updateCountries(
+                        toggleCountryBoard(countries, country.code, board.id),
+                      )
+                    }
+                    className="w-4 h-4 rounded border-surface-300 text-sentinel-500 focus-visible:ring-sentinel-500"
+                  />
+                  Check {board.name}
+                </label>
+              ))}
+            </li>
+          ))}
+        </ul>
+      )}
+    </section>
+  );
+}
@@ -7,6 +7,7 @@ import type { Config } from "../config/SettingsConfig";
 import { SettingsSymbol } from "../shared/SettingsIcons";
 import { SettingsAutoSearchSection } from "./SettingsAutoSearchSection";
 import { SettingsCompanyPreferences } from "./SettingsCompanyPreferences";
+import { SettingsCountryPreferences } from "./SettingsCountryPreferences";
 
 interface SettingsSearchPreferencesSectionProps {
   config: Config;
@@ -399,6 +400,11 @@ export function SettingsSearchPreferencesSection({
         )}
       </section>
 
+      <SettingsCountryPreferences
+        config={config}
+        onConfigChange={onConfigChange}
+      />
+
       <section className="mb-6">
         <h3 className="font-medium text-surface-800 dark:text-surface-200 mb-3 flex items-center gap-2">
           Salary Preferences
@@ -439,6 +445,23 @@ export function SettingsSearchPreferencesSection({
               hint="Your ideal salary - jobs at or above this show stronger pay fit"
             />
           </div>
+          <div>
+            <label className="block text-sm font-medium text-surface-700 dark:text-surface-300 mb-1">
+              Top of Target Range
+            </label>
+            <Input
+              type="number"
+              value={config.salary_target_max_usd || ""}
+              onChange={(e) =>
+                onConfigChange({
+                  ...config,
+                  salary_target_max_usd: parseInt(e.target.value) || undefined,
+                })
+              }
+              placeholder="e.g., 120000"
+              hint="Optional - scores pay by how much a posted range overlaps your target range"
+            />
+          </div>
         </div>
       </section>
 
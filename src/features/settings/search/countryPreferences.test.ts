@@ -0,0 +1,48 @@
+import { describe, expect, it } from "vitest";
+import {
+  addCountry,
+  boardsForCountry,
+  normalizeCountryCode,
+  removeCountry,
+  toggleCountryBoard,
+} from "./countryPreferences";
+
+describe("countryPreferences", () => {
+  it("accepts only two-letter country codes", () => {
+    expect(normalizeCountryCode(" de ")).toBe("DE");
+    expect(normalizeCountryCode("Germany")).toBeNull();
+    expect(normalizeCountryCode("D1")).toBeNull();
+  });
+
+  it("adds each country once and removes it by code", () => {
+    const added = addCountry(addCountry([], "gb"), "GB");
+    expect(added).toEqual([{ code: "GB", boards: [] }]);
+    expect(addCountry(added, "United Kingdom")).toBe(added);
+    expect(removeCountry(added, "GB")).toEqual([]);
+  });
+
+  it("toggles boards only on the chosen country", () => {
+    const countries = [
+      { code: "DE", boards: [] },
+      { code: "AT", boards: ["arbeitnow"] },
+    ];
+
+    expect(toggleCountryBoard(countries, "DE", "arbeitnow")).toEqual([
+      { code: "DE", boards: ["arbeitnow"] },
+      { code: "AT", boards: ["arbeitnow"] },
+    ]);
+    expect(toggleCountryBoard(countries, "AT", "arbeitnow")[1].boards).toEqual(
+      [],
+    );
+  });
+
+  it("lists the boards that cover a country", () => {
+    expect(boardsForCountry("DE").map((board) => board.id)).toEqual([
+      "arbeitnow",
+    ]);
+    expect(boardsForCountry("GB").map((board) => board.id)).toEqual([
+      "guardian_jobs",
+    ]);
+    expect(boardsForCountry("FR")).toEqual([]);
+  });
+});
@@ -0,0 +1,61 @@
+import type { CountryPreference } from "../config/SettingsConfig";
+
+export interface CountryBoard {
+  id: string;
+  name: string;
+  countries: string[];
+}
+
+/** Mirrors `COUNTRY_BOARDS` in the application config. */
+export const COUNTRY_BOARDS: CountryBoard[] = [
+  { id: "arbeitnow", name: "Arbeitnow", countries: ["DE", "AT", "CH"] },
+  { id: "guardian_jobs", name: "Guardian Jobs", countries: ["GB"] },
+];
+
+export const MAX_EXTRA_COUNTRIES = 30;
+
+/** Uppercase two-letter code, or null when the input is not one. */
+export function normalizeCountryCode(input: string): string | null {
+  const code = input.trim().toUpperCase();
+  return /^[A-Z]{2}$/.test(code) ? code : null;
+}
+
+export function boardsForCountry(code: string): CountryBoard[] {
+  return COUNTRY_BOARDS.filter((board) => board.countries.includes(code));
+}
+
+export function addCountry(
+  countries: CountryPreference[],
+  input: string,
+): CountryPreference[] {
+  const code = normalizeCountryCode(input);
+  if (
+    !code ||
+    countries.length >= MAX_EXTRA_COUNTRIES ||
+    countries.some((country) => country.code.toUpperCase() === code)
+  ) {
+    return countries;
+  }
+  return [...countries, { code, boards: [] }];
+}
+
+export function removeCountry(
+  countries: CountryPreference[],
+  code: string,
+): CountryPreference[] {
+  return countries.filter((country) => country.code !== code);
+}
+
+export function toggleCountryBoard(
+  countries: CountryPreference[],
+  code: string,
+  boardId: string,
+): CountryPreference[] {
+  return countries.map((country) => {
+    if (country.code !== code) return country;
+    const boards = country.boards.includes(boardId)
+      ? country.boards.filter((board) => board !== boardId)
+      : [...country.boards, boardId];
+    return { ...country, boards };
+  });
+}
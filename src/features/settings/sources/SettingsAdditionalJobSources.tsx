@@ -15,6 +15,7 @@ import type {
 } from "../config/SettingsConfig";
 import {
   buildSettingsSourceQuery,
+  getSettingsBuiltInSearch,
   getSettingsSourceLocation,
 } from "../config/SettingsConfig";
 
@@ -241,7 +242,7 @@ export function SettingsAdditionalJobSources({
                     builtin: {
                       ...config.builtin,
                       enabled: e.target.checked,
-                      cities: config.builtin?.cities ?? [],
+                      ...getSettingsBuiltInSearch(config),
                       limit: config.builtin?.limit ?? 50,
                     },
                     restricted_source_acknowledgements: e.target.checked
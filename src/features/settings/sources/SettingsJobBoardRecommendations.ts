@@ -8,6 +8,7 @@ import {
 import { searchLooksTechFocused } from "../../../shared/jobSourceRecommendations";
 import {
   buildSettingsSourceQuery,
+  getSettingsBuiltInSearch,
   type Config,
 } from "../config/SettingsConfig";
 
@@ -165,7 +166,7 @@ export function useJobBoardRecommendations(
               builtin: {
                 ...config.builtin,
                 enabled: true,
-                cities: config.builtin?.cities ?? [],
+                ...getSettingsBuiltInSearch(config),
                 limit: 50,
               },
             }),
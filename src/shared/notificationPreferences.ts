@@ -21,6 +21,30 @@ export interface AdvancedFilters {
   excludedCompanies: string[]; // Companies to skip - never notify for these
 }
 
+export type MarketAlertSeverity = "info" | "warning" | "critical";
+
+export interface MarketAlertNotificationConfig {
+  enabled: boolean;
+  minSeverity: MarketAlertSeverity; // Lowest severity sent
+}
+
+export const MARKET_ALERT_TYPES = [
+  "skillSurge",
+  "salarySpike",
+  "hiringFreeze",
+  "hiringSpree",
+  "locationBoom",
+  "roleObsolete",
+] as const;
+
+export type MarketAlertType = (typeof MARKET_ALERT_TYPES)[number];
+
+// Which Hiring Trends alerts go to email, Slack, and Discord
+export type MarketAlertPreferences = Record<
+  MarketAlertType,
+  MarketAlertNotificationConfig
+>;
+
 export interface NotificationPreferences {
   linkedin: SourceNotificationConfig;
   indeed: SourceNotificationConfig;
@@ -35,6 +59,7 @@ export interface NotificationPreferences {
   };
   // Advanced filters (v1.3)
   advancedFilters: AdvancedFilters;
+  marketAlerts: MarketAlertPreferences;
 }
 
 export const DEFAULT_ADVANCED_FILTERS: AdvancedFilters = {
@@ -46,6 +71,15 @@ export const DEFAULT_ADVANCED_FILTERS: AdvancedFilters = {
   excludedCompanies: [],
 };
 
+export const DEFAULT_MARKET_ALERT_PREFERENCES: MarketAlertPreferences = {
+  skillSurge: { enabled: true, minSeverity: "info" },
+  salarySpike: { enabled: true, minSeverity: "info" },
+  hiringFreeze: { enabled: true, minSeverity: "info" },
+  hiringSpree: { enabled: true, minSeverity: "info" },
+  locationBoom: { enabled: true, minSeverity: "info" },
+  roleObsolete: { enabled: true, minSeverity: "info" },
+};
+
 export const DEFAULT_PREFERENCES: NotificationPreferences = {
   linkedin: { enabled: false, minScoreThreshold: 70, soundEnabled: false },
   indeed: { enabled: true, minScoreThreshold: 70, soundEnabled: false },
@@ -59,9 +93,10 @@ export const DEFAULT_PREFERENCES: NotificationPreferences = {
     quietHoursEnabled: false,
   },
   advancedFilters: DEFAULT_ADVANCED_FILTERS,
+  marketAlerts: DEFAULT_MARKET_ALERT_PREFERENCES,
 };
 
-// Type for source keys only (excluding global and advancedFilters)
+// Type for source keys only (excluding global, advancedFilters, and marketAlerts)
 type SourceKey = "linkedin" | "indeed" | "greenhouse" | "lever" | "jobswithgpt";
 
 // Extended job info for advanced filtering
@@ -129,9 +164,23 @@ export function normalizeNotificationPreferences(
       ...DEFAULT_ADVANCED_FILTERS,
       ...prefs.advancedFilters,
     },
+    marketAlerts: normalizeMarketAlertPreferences(prefs.marketAlerts),
   };
 }
 
+function normalizeMarketAlertPreferences(
+  prefs: Partial<MarketAlertPreferences> | undefined,
+): MarketAlertPreferences {
+  const normalized = { ...DEFAULT_MARKET_ALERT_PREFERENCES };
+  for (const alertType of MARKET_ALERT_TYPES) {
+    normalized[alertType] = {
+      ...DEFAULT_MARKET_ALERT_PREFERENCES[alertType],
+      ...prefs?.[alertType],
+    };
+  }
+  return normalized;
+}
+
 // Async save to backend
 export async function saveNotificationPreferencesAsync(
   prefs: NotificationPreferences,
@@ -0,0 +1,37 @@
+import { describe, expect, it } from "vitest";
+import {
+  describeResumeRecommendation,
+  getRecommendedResume,
+  type ResumeFit,
+} from "./resumeSuggestion";
+
+const fit = (resumeId: number, name: string, score: number): ResumeFit => ({
+  resume_id: resumeId,
+  name,
+  is_active: false,
+  overall_match_score: score,
+  skills_match_score: score,
+  matching_skills: [],
+  missing_skills: [],
+});
+
+describe("resumeSuggestion", () => {
+  it("recommends a resume only when there is more than one to choose from", () => {
+    const sre = fit(1, "SRE", 0.82);
+    const data = fit(2, "Data", 0.4);
+
+    expect(
+      getRecommendedResume({ job_hash: "a", recommended_resume_id: 1, candidates: [sre, data] }),
+    ).toBe(sre);
+    expect(
+      getRecommendedResume({ job_hash: "a", recommended_resume_id: 1, candidates: [sre] }),
+    ).toBeNull();
+    expect(getRecommendedResume(null)).toBeNull();
+  });
+
+  it("describes the recommendation with a rounded match percentage", () => {
+    expect(describeResumeRecommendation(fit(1, "SRE", 0.816))).toBe(
+      "Best resume for this job: SRE (82% match)",
+    );
+  });
+});
@@ -0,0 +1,34 @@
+/** How well one uploaded resume fits a job (`suggest_resume_for_job`) */
+export interface ResumeFit {
+  resume_id: number;
+  name: string;
+  is_active: boolean;
+  /** Match score as a fraction (0-1) */
+  overall_match_score: number;
+  skills_match_score: number | null;
+  matching_skills: string[];
+  missing_skills: string[];
+}
+
+/** Every resume's fit for a job, best first, and the one to send */
+export interface ResumeSuggestion {
+  job_hash: string;
+  recommended_resume_id: number | null;
+  candidates: ResumeFit[];
+}
+
+/** The recommended resume, or null when there is nothing to choose between */
+export function getRecommendedResume(suggestion: ResumeSuggestion | null): ResumeFit | null {
+  if (!suggestion || suggestion.candidates.length < 2) return null;
+  return (
+    suggestion.candidates.find(
+      (candidate) => candidate.resume_id === suggestion.recommended_resume_id,
+    ) ?? null
+  );
+}
+
+/** Plain one-line recommendation, e.g. "Best resume for this job: SRE (82% match)" */
+export function describeResumeRecommendation(fit: ResumeFit): string {
+  const percent = Math.round(Math.min(1, Math.max(0, fit.overall_match_score)) * 100);
+  return `Best resume for this job: ${fit.name} (${percent}% match)`;
+}